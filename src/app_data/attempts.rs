@@ -0,0 +1,99 @@
+use std::collections::VecDeque;
+
+use super::DockerControls;
+
+/// Maximum number of attempts kept for each container, the oldest are dropped first
+pub const MAX_ATTEMPTS: usize = 8;
+
+/// The longest wait, in seconds, before a failed control is retried
+const MAX_BACKOFF: u64 = 60;
+
+/// A single attempt at sending a control to a container
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attempt {
+    pub control: DockerControls,
+    /// Unix timestamp, in seconds
+    pub time: u64,
+    /// The daemon's error, None if the control succeeded
+    pub error: Option<String>,
+}
+
+/// The recent attempts at sending controls to a container, oldest first
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Attempts(VecDeque<Attempt>);
+
+impl Attempts {
+    pub fn push(&mut self, control: DockerControls, time: u64, error: Option<String>) {
+        if self.0.len() >= MAX_ATTEMPTS {
+            self.0.pop_front();
+        }
+        self.0.push_back(Attempt {
+            control,
+            time,
+            error,
+        });
+    }
+
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &Attempt> {
+        self.0.iter()
+    }
+
+    /// The newest attempt, if it failed
+    pub fn last_failure(&self) -> Option<&Attempt> {
+        self.0.back().filter(|i| i.error.is_some())
+    }
+
+    /// The number of times in a row a control has failed, counted back from the newest attempt
+    pub fn failures(&self, control: DockerControls) -> usize {
+        self.0
+            .iter()
+            .rev()
+            .take_while(|i| i.control == control && i.error.is_some())
+            .count()
+    }
+
+    /// Seconds to wait before retrying a control, doubling with each failure in a row, from 1 second, up to MAX_BACKOFF
+    pub fn backoff(&self, control: DockerControls) -> u64 {
+        match self.failures(control) {
+            0 => 0,
+            x => 2u64
+                .saturating_pow(u32::try_from(x - 1).unwrap_or(u32::MAX))
+                .min(MAX_BACKOFF),
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::{Attempts, MAX_ATTEMPTS};
+    use crate::app_data::DockerControls;
+
+    #[test]
+    /// The backoff doubles with each failure in a row, of the same control, & is reset by a success, or by another control
+    fn test_attempts_backoff() {
+        let mut attempts = Attempts::default();
+        assert_eq!(attempts.backoff(DockerControls::Start), 0);
+        for (i, expected) in [1, 2, 4, 8, 16, 32, 60, 60].into_iter().enumerate() {
+            attempts.push(
+                DockerControls::Start,
+                u64::try_from(i).unwrap(),
+                Some("no such image".to_owned()),
+            );
+            assert_eq!(attempts.backoff(DockerControls::Start), expected);
+        }
+        assert_eq!(attempts.iter().count(), MAX_ATTEMPTS);
+        assert_eq!(attempts.failures(DockerControls::Start), MAX_ATTEMPTS);
+        assert_eq!(attempts.backoff(DockerControls::Stop), 0);
+        assert_eq!(attempts.last_failure().unwrap().time, 7);
+
+        attempts.push(DockerControls::Stop, 8, Some("timeout".to_owned()));
+        assert_eq!(attempts.backoff(DockerControls::Start), 0);
+        assert_eq!(attempts.backoff(DockerControls::Stop), 1);
+        assert_eq!(attempts.iter().next().unwrap().time, 1);
+
+        attempts.push(DockerControls::Stop, 9, None);
+        assert_eq!(attempts.backoff(DockerControls::Stop), 0);
+        assert!(attempts.last_failure().is_none());
+    }
+}
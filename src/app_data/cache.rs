@@ -0,0 +1,166 @@
+use std::{
+    fmt::Write as _,
+    path::{Path, PathBuf},
+};
+
+use super::{ContainerId, ContainerItem, State};
+
+/// First line of the cache file, changed whenever the format changes, so that old caches are ignored
+const CACHE_HEADER: &str = "oxker-cache 1";
+
+/// Location of the cache file for a given host, each host has its own cache, so that containers from one daemon are never shown as another's
+pub fn path(host: &str) -> Option<PathBuf> {
+    let name = host
+        .chars()
+        .map(|i| if i.is_ascii_alphanumeric() { i } else { '_' })
+        .collect::<String>();
+    directories::BaseDirs::new().map(|i| {
+        i.cache_dir()
+            .join(env!("CARGO_PKG_NAME"))
+            .join(format!("containers-{name}"))
+    })
+}
+
+/// Lowercase state name, as used by the Docker API, so it can be parsed with State::from
+const fn state_key(state: State) -> &'static str {
+    match state {
+        State::Dead => "dead",
+        State::Exited => "exited",
+        State::Paused => "paused",
+        State::Removing => "removing",
+        State::Restarting => "restarting",
+        State::Running => "running",
+        State::Unknown => "unknown",
+    }
+}
+
+/// Escape tabs, newlines, and backslashes, so that each container is a single tab separated line
+pub fn escape(input: &str) -> String {
+    input
+        .replace('\\', "\\\\")
+        .replace('\t', "\\t")
+        .replace('\n', "\\n")
+}
+
+pub fn unescape(input: &str) -> String {
+    let mut output = String::with_capacity(input.len());
+    let mut chars = input.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            match chars.next() {
+                Some('t') => output.push('\t'),
+                Some('n') => output.push('\n'),
+                Some(c) => output.push(c),
+                None => (),
+            }
+        } else {
+            output.push(c);
+        }
+    }
+    output
+}
+
+/// Convert containers into the cache file format, one container per line, id, name, image, state, status, & created
+fn to_cache(items: &[ContainerItem]) -> String {
+    let mut output = format!("{CACHE_HEADER}\n");
+    for i in items {
+        writeln!(
+            output,
+            "{}\t{}\t{}\t{}\t{}\t{}",
+            escape(i.id.get()),
+            escape(i.name.get()),
+            escape(i.image.get()),
+            state_key(i.state),
+            escape(&i.status),
+            i.created
+        )
+        .ok();
+    }
+    output
+}
+
+/// Parse the cache file format, None if the header doesn't match, invalid lines are skipped
+fn from_cache(input: &str) -> Option<Vec<ContainerItem>> {
+    let mut lines = input.lines();
+    if lines.next() != Some(CACHE_HEADER) {
+        return None;
+    }
+    Some(
+        lines
+            .filter_map(|line| {
+                let fields = line.split('\t').collect::<Vec<_>>();
+                let [id, name, image, state, status, created] = fields.as_slice() else {
+                    return None;
+                };
+                Some(ContainerItem::new(
+                    created.parse().ok()?,
+                    ContainerId::from(unescape(id).as_str()),
+                    unescape(image),
+                    false,
+                    unescape(name),
+                    vec![],
+                    State::from(*state),
+                    unescape(status),
+                ))
+            })
+            .collect(),
+    )
+}
+
+/// Load the cached containers, empty if there is no cache, or it can't be read
+pub fn load(path: &Path) -> Vec<ContainerItem> {
+    std::fs::read_to_string(path)
+        .ok()
+        .and_then(|i| from_cache(&i))
+        .unwrap_or_default()
+}
+
+/// Save the containers to the cache file, creating the cache directory if needed
+pub fn save(path: &Path, items: &[ContainerItem]) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(path, to_cache(items))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::{escape, from_cache, to_cache, unescape, CACHE_HEADER};
+    use crate::{
+        app_data::{ContainerId, State},
+        tests::gen_item,
+    };
+
+    #[test]
+    /// Containers survive a round trip through the cache format, including tabs & newlines in their details
+    fn test_cache_round_trip() {
+        let mut first = gen_item(&ContainerId::from("1"), 1);
+        first.status = "Up\t2\nhours \\o/".to_owned();
+        let mut second = gen_item(&ContainerId::from("2"), 2);
+        second.state = State::Exited;
+
+        let result = from_cache(&to_cache(&[first.clone(), second.clone()])).unwrap();
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].status, first.status);
+        assert_eq!(result[0].name, first.name);
+        assert_eq!(result[0].image, first.image);
+        assert_eq!(result[0].created, first.created);
+        assert_eq!(result[1].id, second.id);
+        assert_eq!(result[1].state, State::Exited);
+        assert!(result[1].ports.is_empty());
+    }
+
+    #[test]
+    /// Unknown headers ignored, invalid lines skipped
+    fn test_cache_invalid() {
+        assert!(from_cache("oxker-cache 0\n1\ta\tb\trunning\tUp\t1").is_none());
+        assert!(from_cache("").is_none());
+        let result = from_cache(&format!(
+            "{CACHE_HEADER}\n1\ta\tb\trunning\tUp\t1\ntoo\tfew\n2\ta\tb\trunning\tUp\tnan"
+        ))
+        .unwrap();
+        assert_eq!(result.len(), 1);
+        assert_eq!(unescape(&escape("a\\t\tb")), "a\\t\tb");
+    }
+}
@@ -0,0 +1,95 @@
+use std::fmt::Write as _;
+
+/// The fewest samples that a zoomed in chart shows
+const MIN_WINDOW: usize = 10;
+
+/// How the cpu & memory charts show the stats history, zoomed into the most recent samples, & frozen, samples are still collected while frozen
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ChartView {
+    /// Each level halves the samples shown
+    zoom: u32,
+    /// Unix timestamp of when the charts were frozen, later samples aren't shown
+    frozen: Option<u64>,
+}
+
+impl ChartView {
+    /// The number of samples shown, of a history of the given length
+    pub fn window(self, history: usize) -> usize {
+        history
+            .checked_shr(self.zoom)
+            .unwrap_or_default()
+            .max(MIN_WINDOW.min(history))
+    }
+
+    /// Halve the samples shown, until only MIN_WINDOW are shown
+    pub fn zoom_in(&mut self, history: usize) {
+        if self.window(history) / 2 >= MIN_WINDOW {
+            self.zoom += 1;
+        }
+    }
+
+    pub const fn zoom_out(&mut self) {
+        self.zoom = self.zoom.saturating_sub(1);
+    }
+
+    pub const fn toggle_freeze(&mut self, now: u64) {
+        self.frozen = match self.frozen {
+            Some(_) => None,
+            None => Some(now),
+        };
+    }
+
+    pub const fn frozen(self) -> Option<u64> {
+        self.frozen
+    }
+
+    /// Shown in the chart titles, the span shown when zoomed in, & whether frozen
+    pub fn title(self, history: usize, interval: u32) -> String {
+        let mut output = String::new();
+        if self.zoom > 0 {
+            let secs = self.window(history) as u64 * u64::from(interval) / 1000;
+            let span = match secs {
+                0..=119 => format!("{secs}s"),
+                120..=7199 => format!("{}m", secs / 60),
+                _ => format!("{}h", secs / 3600),
+            };
+            write!(output, " - last {span}").ok();
+        }
+        if self.frozen.is_some() {
+            output.push_str(" - frozen");
+        }
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ChartView;
+
+    #[test]
+    /// Each zoom level halves the window, down to the minimum, & the title shows the span, & when frozen
+    fn test_chart_view_zoom() {
+        let mut view = ChartView::default();
+        assert_eq!(view.window(60), 60);
+        assert_eq!(view.title(60, 1000), "");
+        view.zoom_in(60);
+        assert_eq!(view.window(60), 30);
+        assert_eq!(view.title(60, 1000), " - last 30s");
+        view.zoom_in(60);
+        view.zoom_in(60);
+        assert_eq!(view.window(60), 15);
+        assert_eq!(view.window(4), 4);
+        view.zoom_out();
+        view.zoom_out();
+        view.zoom_out();
+        assert_eq!(view, ChartView::default());
+
+        view.toggle_freeze(100);
+        assert_eq!(view.frozen(), Some(100));
+        assert_eq!(view.title(600, 1000), " - frozen");
+        view.zoom_in(600);
+        assert_eq!(view.title(600, 1000), " - last 5m - frozen");
+        view.toggle_freeze(200);
+        assert_eq!(view.frozen(), None);
+    }
+}
@@ -0,0 +1,146 @@
+use std::mem::discriminant;
+
+use super::{DockerControls, Signal};
+
+/// Every docker control, used to parse a control by its displayed name, a kill rule applies to every signal
+const CONTROLS: [DockerControls; 9] = [
+    DockerControls::Delete,
+    DockerControls::Kill(Signal::Kill),
+    DockerControls::Pause,
+    DockerControls::Recreate,
+    DockerControls::Restart,
+    DockerControls::Resume,
+    DockerControls::Start,
+    DockerControls::Stop,
+    DockerControls::Update,
+];
+
+/// A single `--confirm` rule, a container, or control, of None applies to every container, or control
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ConfirmRule {
+    container: Option<String>,
+    control: Option<DockerControls>,
+    confirm: bool,
+}
+
+impl ConfirmRule {
+    /// Higher is more specific, a container beats a control, which beats neither
+    fn specificity(&self) -> u8 {
+        u8::from(self.container.is_some()) * 2 + u8::from(self.control.is_some())
+    }
+}
+
+/// Which docker controls require confirmation, before being sent, set with `--confirm`
+/// With no rules, only delete, & update, require confirmation
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ConfirmPolicy {
+    rules: Vec<ConfirmRule>,
+}
+
+impl ConfirmPolicy {
+    fn parse_control(input: &str) -> Result<Option<DockerControls>, String> {
+        match input.trim() {
+            "*" => Ok(None),
+            i => CONTROLS
+                .into_iter()
+                .find(|c| c.to_string() == i)
+                .map(Some)
+                .ok_or_else(|| {
+                    format!(
+                        "{i}: expected *, or one of {}",
+                        CONTROLS.map(|c| c.to_string()).join(", ")
+                    )
+                }),
+        }
+    }
+
+    /// Add a rule, `[container:]control=always|never`, a control of `*` applies to every control
+    pub fn set(&mut self, input: &str) -> Result<(), String> {
+        let (target, policy) = input
+            .split_once('=')
+            .ok_or_else(|| format!("{input}: expected [container:]control=always|never"))?;
+        let confirm = match policy.trim() {
+            "always" => true,
+            "never" => false,
+            i => return Err(format!("{i}: expected always, or never")),
+        };
+        let (container, control) = target.rsplit_once(':').map_or((None, target), |(c, i)| {
+            (Some(c.trim().to_owned()).filter(|c| !c.is_empty()), i)
+        });
+        self.rules.push(ConfirmRule {
+            container,
+            control: Self::parse_control(control)?,
+            confirm,
+        });
+        Ok(())
+    }
+
+    /// Check if a control, sent to the named container, requires confirmation, the most specific rule applies, the last given when equally specific
+    pub fn requires(&self, control: DockerControls, name: &str) -> bool {
+        self.rules
+            .iter()
+            .filter(|i| i.container.is_none() || i.container.as_deref() == Some(name))
+            .filter(|i| {
+                i.control
+                    .is_none_or(|c| discriminant(&c) == discriminant(&control))
+            })
+            .max_by_key(|i| i.specificity())
+            .map_or(
+                matches!(control, DockerControls::Delete | DockerControls::Update),
+                |i| i.confirm,
+            )
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::ConfirmPolicy;
+    use crate::app_data::{DockerControls, Signal};
+
+    #[test]
+    /// Only delete, & update, are confirmed by default, container rules override control rules, which override `*`
+    fn test_confirm_policy() {
+        let mut policy = ConfirmPolicy::default();
+        assert!(policy.requires(DockerControls::Delete, "api"));
+        assert!(policy.requires(DockerControls::Update, "api"));
+        assert!(!policy.requires(DockerControls::Restart, "api"));
+
+        policy.set("*=always").unwrap();
+        policy.set("restart=never").unwrap();
+        policy.set("db:*=always").unwrap();
+        policy.set("scratch:delete=never").unwrap();
+        assert!(policy.requires(DockerControls::Stop, "api"));
+        assert!(!policy.requires(DockerControls::Restart, "api"));
+        assert!(policy.requires(DockerControls::Restart, "db"));
+        assert!(!policy.requires(DockerControls::Delete, "scratch"));
+        assert!(policy.requires(DockerControls::Stop, "scratch"));
+
+        // Equally specific, so the last given applies
+        policy.set("restart=always").unwrap();
+        assert!(policy.requires(DockerControls::Restart, "api"));
+
+        policy.set("api:update=never").unwrap();
+        assert!(!policy.requires(DockerControls::Update, "api"));
+        assert!(policy.requires(DockerControls::Update, "db"));
+
+        assert!(policy.set("restart").is_err());
+        assert!(policy.set("reboot=always").is_err());
+        assert!(policy.set("stop=sometimes").is_err());
+    }
+
+    #[test]
+    /// Kill isn't confirmed by default, a kill rule applies to every signal
+    fn test_confirm_policy_kill() {
+        let mut policy = ConfirmPolicy::default();
+        assert!(!policy.requires(DockerControls::Kill(Signal::Term), "api"));
+
+        policy.set("kill=always").unwrap();
+        policy.set("api:kill=never").unwrap();
+        for signal in Signal::ALL {
+            assert!(policy.requires(DockerControls::Kill(signal), "db"));
+            assert!(!policy.requires(DockerControls::Kill(signal), "api"));
+        }
+        assert!(!policy.requires(DockerControls::Stop, "db"));
+    }
+}
@@ -0,0 +1,254 @@
+use std::fmt;
+
+use super::{ContainerItem, State};
+
+/// Container states that a status rule can be given, `created` is shown as an unknown state
+const STATUSES: [&str; 7] = [
+    "created",
+    "dead",
+    "exited",
+    "paused",
+    "removing",
+    "restarting",
+    "running",
+];
+
+/// Match a glob against the whole of a text, `*` matches any number of chars, `?` a single char
+pub fn glob(pattern: &str, text: &str) -> bool {
+    let pattern = pattern.chars().collect::<Vec<_>>();
+    let text = text.chars().collect::<Vec<_>>();
+    let (mut p, mut t) = (0, 0);
+    // The position of the last `*`, & the char of the text it's matched up to, to backtrack to
+    let mut star = None;
+    while t < text.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                star = Some((p, t));
+                p += 1;
+            }
+            Some(c) if *c == '?' || *c == text[t] => {
+                p += 1;
+                t += 1;
+            }
+            _ => match star {
+                Some((star_p, star_t)) => {
+                    p = star_p + 1;
+                    t = star_t + 1;
+                    star = Some((star_p, star_t + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|i| *i == '*')
+}
+
+/// What a filter rule matches
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FilterKind {
+    /// A glob of the container name
+    Name(String),
+    /// A label, & a glob of its value, any value if None
+    Label(String, Option<String>),
+    Status(State),
+}
+
+/// A rule to show, or hide, containers, `key=value` shows only the matching containers, `key!=value` hides them, as `docker ps --filter` would
+/// The key is `name`, `label`, or `status`, a container must match a shown rule of each key that has one, & no hidden rule
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContainerFilter {
+    pub enabled: bool,
+    pub exclude: bool,
+    pub kind: FilterKind,
+}
+
+impl ContainerFilter {
+    /// Parse a rule, e.g. `name=web-*`, `label!=com.example.role=sidecar`, or `status!=exited`
+    pub fn parse(input: &str) -> Result<Self, String> {
+        let input = input.trim();
+        let (key, exclude, value) = match (input.split_once("!="), input.split_once('=')) {
+            (Some((key, value)), Some((before, _))) if key.len() <= before.len() => {
+                (key, true, value)
+            }
+            (_, Some((key, value))) => (key, false, value),
+            _ => return Err(format!("{input}: expected key=value, or key!=value")),
+        };
+        let value = value.trim();
+        if value.is_empty() {
+            return Err(format!("{input}: expected a value"));
+        }
+        let kind = match key.trim() {
+            "name" => FilterKind::Name(value.to_owned()),
+            "label" => match value.split_once('=') {
+                Some((label, value)) => {
+                    FilterKind::Label(label.trim().to_owned(), Some(value.trim().to_owned()))
+                }
+                None => FilterKind::Label(value.to_owned(), None),
+            },
+            "status" if STATUSES.contains(&value) => FilterKind::Status(State::from(value)),
+            "status" => {
+                return Err(format!(
+                    "{input}: expected a status of {}",
+                    STATUSES.join(", ")
+                ))
+            }
+            key => return Err(format!("{key}: expected a key of name, label, or status")),
+        };
+        Ok(Self {
+            enabled: true,
+            exclude,
+            kind,
+        })
+    }
+
+    /// The label that the rule is matched against, so that its value is kept for each container
+    pub const fn label(&self) -> Option<&String> {
+        match &self.kind {
+            FilterKind::Label(label, _) => Some(label),
+            _ => None,
+        }
+    }
+
+    fn matches(&self, item: &ContainerItem) -> bool {
+        match &self.kind {
+            FilterKind::Name(pattern) => glob(pattern, item.name.get()),
+            FilterKind::Label(label, pattern) => item
+                .filter_labels
+                .get(label)
+                .is_some_and(|value| pattern.as_ref().is_none_or(|pattern| glob(pattern, value))),
+            FilterKind::Status(state) => item.state == *state,
+        }
+    }
+
+    /// If a container is shown by every enabled rule
+    pub fn shows(filters: &[Self], item: &ContainerItem) -> bool {
+        let mut filters = filters.iter().filter(|i| i.enabled);
+        let shown = |kind: fn(&FilterKind) -> bool| {
+            let mut rules = filters
+                .clone()
+                .filter(|i| !i.exclude && kind(&i.kind))
+                .peekable();
+            rules.peek().is_none() || rules.any(|i| i.matches(item))
+        };
+        shown(|i| matches!(i, FilterKind::Name(_)))
+            && shown(|i| matches!(i, FilterKind::Label(..)))
+            && shown(|i| matches!(i, FilterKind::Status(_)))
+            && !filters.any(|i| i.exclude && i.matches(item))
+    }
+}
+
+impl fmt::Display for ContainerFilter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let operator = if self.exclude { "!=" } else { "=" };
+        match &self.kind {
+            FilterKind::Name(pattern) => write!(f, "name{operator}{pattern}"),
+            FilterKind::Label(label, Some(pattern)) => {
+                write!(f, "label{operator}{label}={pattern}")
+            }
+            FilterKind::Label(label, None) => write!(f, "label{operator}{label}"),
+            FilterKind::Status(state) => write!(
+                f,
+                "status{operator}{}",
+                STATUSES
+                    .iter()
+                    .find(|i| State::from(**i) == *state)
+                    .unwrap_or(&"created")
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::{glob, ContainerFilter, FilterKind};
+    use crate::app_data::{ContainerId, ContainerItem, State};
+
+    fn gen_item(name: &str, state: State, role: Option<&str>) -> ContainerItem {
+        let mut item = ContainerItem::new(
+            0,
+            ContainerId::from(name),
+            "image".to_owned(),
+            false,
+            name.to_owned(),
+            vec![],
+            state,
+            String::new(),
+        );
+        if let Some(role) = role {
+            item.filter_labels
+                .insert("role".to_owned(), role.to_owned());
+        }
+        item
+    }
+
+    #[test]
+    /// Globs match the whole text, backtracking over each `*`
+    fn test_container_filter_glob() {
+        assert!(glob("web", "web"));
+        assert!(!glob("web", "web-1"));
+        assert!(glob("web-*", "web-1"));
+        assert!(glob("*-sidecar", "api-log-sidecar"));
+        assert!(glob("a*b*c", "axxbyybzc"));
+        assert!(!glob("a*b*c", "axxbyyb"));
+        assert!(glob("db-?", "db-2"));
+        assert!(!glob("db-?", "db-12"));
+        assert!(glob("*", ""));
+    }
+
+    #[test]
+    /// Rules are parsed as key=value, or key!=value, & shown again as given
+    fn test_container_filter_parse() {
+        let rule = ContainerFilter::parse("label!=com.example.role=sidecar").unwrap();
+        assert!(rule.exclude);
+        assert_eq!(
+            rule.kind,
+            FilterKind::Label("com.example.role".to_owned(), Some("sidecar".to_owned()))
+        );
+        assert_eq!(rule.to_string(), "label!=com.example.role=sidecar");
+        let rule = ContainerFilter::parse("name!=*-sidecar").unwrap();
+        assert_eq!(rule.kind, FilterKind::Name("*-sidecar".to_owned()));
+        assert_eq!(rule.to_string(), "name!=*-sidecar");
+
+        assert_eq!(
+            ContainerFilter::parse(" status!=exited ")
+                .unwrap()
+                .to_string(),
+            "status!=exited"
+        );
+        assert_eq!(
+            ContainerFilter::parse("status=created").unwrap().kind,
+            FilterKind::Status(State::Unknown)
+        );
+        assert!(ContainerFilter::parse("status=up").is_err());
+        assert!(ContainerFilter::parse("image=nginx").is_err());
+        assert!(ContainerFilter::parse("name=").is_err());
+        assert!(ContainerFilter::parse("web").is_err());
+    }
+
+    #[test]
+    /// Shown rules of the same key are any of, of different keys all of, & a hidden rule always hides, disabled rules are ignored
+    fn test_container_filter_shows() {
+        let web = gen_item("web-1", State::Running, None);
+        let sidecar = gen_item("web-log", State::Running, Some("sidecar"));
+        let db = gen_item("db", State::Exited, Some("db"));
+        let mut filters = vec![ContainerFilter::parse("status!=exited").unwrap()];
+        assert!(ContainerFilter::shows(&filters, &web));
+        assert!(!ContainerFilter::shows(&filters, &db));
+
+        filters.push(ContainerFilter::parse("label!=role=side*").unwrap());
+        assert!(!ContainerFilter::shows(&filters, &sidecar));
+
+        filters.push(ContainerFilter::parse("name=db").unwrap());
+        filters.push(ContainerFilter::parse("name=web-*").unwrap());
+        filters[1].enabled = false;
+        assert!(ContainerFilter::shows(&filters, &web));
+        assert!(ContainerFilter::shows(&filters, &sidecar));
+        filters[0].enabled = false;
+        assert!(ContainerFilter::shows(&filters, &db));
+
+        filters.push(ContainerFilter::parse("label=role").unwrap());
+        assert!(!ContainerFilter::shows(&filters, &web));
+        assert!(ContainerFilter::shows(&filters, &db));
+    }
+}
@@ -0,0 +1,54 @@
+/// A container addressed by its compose project & service, `project/service`, or on a single host, `host/project/service`
+/// Container names can't contain a `/`, so a target with one is always a path, the host is whatever is left of the last two parts, as a host address, `ssh://build`, can contain a `/` itself
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContainerPath {
+    /// None matches the service on any host
+    pub host: Option<String>,
+    pub project: String,
+    pub service: String,
+}
+
+impl ContainerPath {
+    /// Parse a target, None if it isn't a path, or the project, or service, is empty
+    pub fn parse(target: &str) -> Option<Self> {
+        let mut parts = target.rsplitn(3, '/');
+        let service = parts.next().filter(|i| !i.is_empty())?;
+        let project = parts.next().filter(|i| !i.is_empty())?;
+        let host = parts.next();
+        if host == Some("") {
+            return None;
+        }
+        Some(Self {
+            host: host.map(ToOwned::to_owned),
+            project: project.to_owned(),
+            service: service.to_owned(),
+        })
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::ContainerPath;
+
+    #[test]
+    /// The last two parts are the project, & service, any before them are the host, which can itself contain a `/`
+    fn test_container_path_parse() {
+        let path = ContainerPath::parse("shop/web").unwrap();
+        assert_eq!(path.host, None);
+        assert_eq!(path.project, "shop");
+        assert_eq!(path.service, "web");
+
+        let path = ContainerPath::parse("local/shop/web").unwrap();
+        assert_eq!(path.host.as_deref(), Some("local"));
+
+        let path = ContainerPath::parse("ssh://deploy@build/shop/web").unwrap();
+        assert_eq!(path.host.as_deref(), Some("ssh://deploy@build"));
+        assert_eq!(path.project, "shop");
+        assert_eq!(path.service, "web");
+
+        for target in ["web", "shop/", "/web", "//web", "/shop/web", ""] {
+            assert!(ContainerPath::parse(target).is_none(), "{target}");
+        }
+    }
+}
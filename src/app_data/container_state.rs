@@ -1,21 +1,65 @@
 use std::{
     cmp::Ordering,
-    collections::{HashSet, VecDeque},
-    fmt,
+    collections::{HashMap, HashSet, VecDeque},
+    fmt::{self, Write as _},
 };
 
-use bollard::service::Port;
+use bollard::{
+    container::{CPUStats, MemoryStats, MemoryStatsStats},
+    service::Port,
+};
+use clap::ValueEnum;
 use ratatui::{
-    style::Color,
+    style::{Color, Modifier, Style},
+    text::{Line, Span, Text},
     widgets::{ListItem, ListState},
 };
 
-use super::Header;
+use super::{
+    chart_view::ChartView,
+    json_log::{JsonLog, JsonView},
+    limits::Limits,
+    log_search,
+    port_conflict::{HostPort, PortConflict},
+    throughput::{IoChart, IoCounters, IoRates},
+    Attempts, Header, LogFilter, LogSearch, Readiness,
+};
+use crate::{
+    docker_data::{format_rfc3339, parse_rfc3339, LogFallback},
+    ui::format_duration,
+};
 
 const ONE_KB: f64 = 1000.0;
 const ONE_MB: f64 = ONE_KB * 1000.0;
 const ONE_GB: f64 = ONE_MB * 1000.0;
 
+/// Label set by compose on every container of a project
+pub const PROJECT_LABEL: &str = "com.docker.compose.project";
+
+/// Label set by compose on every container of a service, the name of the service in the project
+pub const SERVICE_LABEL: &str = "com.docker.compose.service";
+
+/// Label set by compose on every container of a service, the services it depends on, as comma separated `service:condition:restart`
+pub const DEPENDS_ON_LABEL: &str = "com.docker.compose.depends_on";
+
+/// Label set by podman-compose on every container of a project, older versions set only this, & not the docker compose label
+pub const PODMAN_PROJECT_LABEL: &str = "io.podman.compose.project";
+
+/// The compose project of a container, from the docker compose label, else the podman-compose label
+pub fn compose_project(labels: Option<&HashMap<String, String>>) -> Option<String> {
+    let labels = labels?;
+    labels
+        .get(PROJECT_LABEL)
+        .or_else(|| labels.get(PODMAN_PROJECT_LABEL))
+        .cloned()
+}
+
+/// Group key of the containers that aren't part of a compose project, compose project names can't contain a space, so can't clash
+const NO_PROJECT: &str = "no project";
+
+/// Group key of the containers without an `oxker.group` label, when grouped by label
+const NO_GROUP: &str = "no group";
+
 #[derive(Debug, Clone, Eq, Hash, PartialEq)]
 pub struct ContainerId(String);
 
@@ -26,7 +70,7 @@ impl From<&str> for ContainerId {
 }
 
 impl ContainerId {
-    pub fn get(&self) -> &str {
+    pub const fn get(&self) -> &str {
         self.0.as_str()
     }
 
@@ -68,7 +112,7 @@ macro_rules! unit_struct {
         }
 
         impl$name {
-            pub fn get(&self) -> &str {
+            pub const fn get(&self) -> &str {
                 self.0.as_str()
             }
 
@@ -207,6 +251,34 @@ impl<T> StatefulList<T> {
     }
 }
 
+/// Isolation technology of a container running on a Windows daemon, Linux containers have none
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Isolation {
+    HyperV,
+    Process,
+}
+
+impl Isolation {
+    /// Parse the isolation reported by the Docker API, "default" & empty mean the daemon's default isolation is used
+    pub fn from_api(input: &str, default: Self) -> Self {
+        match input {
+            "hyperv" => Self::HyperV,
+            "process" => Self::Process,
+            _ => default,
+        }
+    }
+}
+
+impl fmt::Display for Isolation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let disp = match self {
+            Self::HyperV => "hyperv",
+            Self::Process => "process",
+        };
+        write!(f, "{disp}")
+    }
+}
+
 /// States of the container
 #[derive(Debug, Clone, Copy, Eq, PartialEq, PartialOrd)]
 pub enum State {
@@ -281,50 +353,164 @@ impl fmt::Display for State {
     }
 }
 
+/// The result of a container's health check, only containers with a health check report one
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Health {
+    Healthy,
+    Starting,
+    Unhealthy,
+}
+
+impl Health {
+    /// Docker appends the health to the status, e.g. `Up 2 hours (healthy)`, or `Up 3 seconds (health: starting)`
+    pub fn from_status(status: &str) -> Option<Self> {
+        if status.contains("(healthy)") {
+            Some(Self::Healthy)
+        } else if status.contains("(unhealthy)") {
+            Some(Self::Unhealthy)
+        } else if status.contains("(health: starting)") {
+            Some(Self::Starting)
+        } else {
+            None
+        }
+    }
+
+    /// Shown beside the state, so that the health is visible without reading the status
+    pub const fn icon(self) -> char {
+        match self {
+            Self::Healthy => '♥',
+            Self::Starting => '…',
+            Self::Unhealthy => '!',
+        }
+    }
+
+    pub const fn get_color(self) -> Color {
+        match self {
+            Self::Healthy => Color::Green,
+            Self::Starting => Color::Yellow,
+            Self::Unhealthy => Color::LightRed,
+        }
+    }
+}
+
+/// The signal that a kill sends to a container, picked once kill is chosen
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Signal {
+    Term,
+    Kill,
+    Hup,
+}
+
+impl Signal {
+    pub const ALL: [Self; 3] = [Self::Term, Self::Kill, Self::Hup];
+
+    /// What the signal usually does to the main process of a container
+    pub const fn description(self) -> &'static str {
+        match self {
+            Self::Term => "ask the process to exit",
+            Self::Kill => "end the process immediately",
+            Self::Hup => "reload the config, for processes that support it",
+        }
+    }
+}
+
+impl fmt::Display for Signal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let disp = match self {
+            Self::Term => "SIGTERM",
+            Self::Kill => "SIGKILL",
+            Self::Hup => "SIGHUP",
+        };
+        write!(f, "{disp}")
+    }
+}
+
 /// Items for the container control list
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum DockerControls {
+    /// Send a signal to the main process of the container, the signal is picked when kill is chosen
+    Kill(Signal),
     Pause,
+    Recreate,
+    /// Give the container a new name, typed when rename is chosen
+    Rename,
     Restart,
     Start,
     Stop,
     Resume,
+    /// Pull the latest image, & recreate the container if it has changed
+    Update,
     Delete,
 }
 
 impl DockerControls {
     pub const fn get_color(self) -> Color {
         match self {
+            Self::Kill(_) => Color::LightRed,
             Self::Pause => Color::Yellow,
+            Self::Recreate => Color::Cyan,
+            Self::Rename => Color::White,
             Self::Restart => Color::Magenta,
             Self::Start => Color::Green,
             Self::Stop => Color::Red,
             Self::Delete => Color::Gray,
             Self::Resume => Color::Blue,
+            Self::Update => Color::LightCyan,
         }
     }
 
     /// Docker commands available depending on the containers state
     pub fn gen_vec(state: State) -> Vec<Self> {
         match state {
-            State::Dead | State::Exited => vec![Self::Start, Self::Restart, Self::Delete],
-            State::Paused => vec![Self::Resume, Self::Stop, Self::Delete],
+            State::Dead | State::Exited => {
+                vec![Self::Start, Self::Restart, Self::Rename, Self::Delete]
+            }
+            // The daemon refuses to kill a paused container, it has to be resumed first
+            State::Paused => vec![Self::Resume, Self::Stop, Self::Rename, Self::Delete],
             State::Restarting => vec![Self::Stop, Self::Delete],
-            State::Running => vec![Self::Pause, Self::Restart, Self::Stop, Self::Delete],
+            State::Running => vec![
+                Self::Pause,
+                Self::Restart,
+                Self::Stop,
+                Self::Kill(Signal::Term),
+                Self::Rename,
+                Self::Delete,
+            ],
             _ => vec![Self::Delete],
         }
     }
+
+    /// Docker commands that can be sent to every container of a compose project at once
+    pub fn gen_project_vec() -> Vec<Self> {
+        vec![Self::Start, Self::Restart, Self::Stop]
+    }
+
+    /// Docker commands that can be sent to every marked container at once, recreate, & update, need a form, or a pull, for each container
+    pub fn gen_batch_vec() -> Vec<Self> {
+        vec![
+            Self::Start,
+            Self::Pause,
+            Self::Resume,
+            Self::Restart,
+            Self::Stop,
+            Self::Delete,
+        ]
+    }
 }
 
 impl fmt::Display for DockerControls {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let disp = match self {
+            Self::Kill(_) => "kill",
             Self::Pause => "pause",
+            Self::Recreate => "recreate",
+            Self::Rename => "rename",
             Self::Delete => "delete",
             Self::Restart => "restart",
             Self::Start => "start",
             Self::Stop => "stop",
             Self::Resume => "resume",
+            Self::Update => "update",
         };
         write!(f, "{disp}")
     }
@@ -413,9 +599,12 @@ impl ByteStats {
     pub const fn new(value: u64) -> Self {
         Self(value)
     }
-    pub fn update(&mut self, value: u64) {
+    pub const fn update(&mut self, value: u64) {
         self.0 = value;
     }
+    pub const fn get(self) -> u64 {
+        self.0
+    }
 }
 
 #[allow(clippy::cast_precision_loss)]
@@ -438,9 +627,367 @@ impl fmt::Display for ByteStats {
     }
 }
 
+/// When oxker started waiting for an update, cleared once the update is received
+/// A retry doesn't restart the wait, so that both a stalled, and a repeatedly failing, update are shown as stale
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Waiting(Option<u64>);
+
+impl Waiting {
+    pub fn start(&mut self, now: u64) {
+        self.0.get_or_insert(now);
+    }
+
+    pub const fn done(&mut self) {
+        self.0 = None;
+    }
+
+    /// Seconds waited, only once it's at, or over, the limit
+    pub fn stale(self, now: u64, limit: u64) -> Option<u64> {
+        self.0
+            .map(|i| now.saturating_sub(i))
+            .filter(|i| *i >= limit)
+    }
+}
+
+/// Network traffic counted by oxker, since oxker started, or since the count was last reset
+/// Unlike the daemon's counters, which start again from zero when the container restarts, these keep counting across restarts
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Traffic {
+    /// The last counters from the daemon, each new sample is counted from these
+    last: Option<(u64, u64)>,
+    pub rx: ByteStats,
+    /// Unix timestamp, in seconds, that the count started from
+    pub since: u64,
+    pub tx: ByteStats,
+}
+
+impl Traffic {
+    /// Count a sample of the daemon's counters, the first sample only sets the start point
+    pub fn count(&mut self, rx: u64, tx: u64, now: u64) {
+        // A counter lower than the last means the container has restarted, so everything in the new counter is new traffic
+        let delta = |new: u64, last: u64| new.checked_sub(last).unwrap_or(new);
+        if let Some((last_rx, last_tx)) = self.last {
+            self.rx = ByteStats::new(self.rx.get() + delta(rx, last_rx));
+            self.tx = ByteStats::new(self.tx.get() + delta(tx, last_tx));
+        } else {
+            self.since = now;
+        }
+        self.last = Some((rx, tx));
+    }
+
+    /// Reset the count to zero, the next sample is counted from the last counters
+    pub fn reset(&mut self, now: u64) {
+        self.rx = ByteStats::default();
+        self.tx = ByteStats::default();
+        self.since = now;
+    }
+
+    pub const fn total(&self) -> u64 {
+        self.rx.get() + self.tx.get()
+    }
+}
+
+/// CPU throttling of a container with a cpu quota, from the cgroup throttling data
+/// A container held at its quota can look fine by cpu usage alone, while most of its scheduling periods are throttled
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Throttling {
+    /// The last counters from the daemon, periods & throttled periods, each new sample is counted from these
+    last: Option<(u64, u64)>,
+    /// Scheduling periods since the last sample
+    pub periods: u64,
+    /// Scheduling periods, since the last sample, in which the container was throttled
+    pub throttled: u64,
+    /// Total time throttled, in nanoseconds, counted by the daemon since the container started
+    pub throttled_time: u64,
+}
+
+impl Throttling {
+    /// Count a sample of the daemon's counters, the first sample only sets the start point
+    pub fn count(&mut self, periods: u64, throttled: u64, throttled_time: u64) {
+        // A counter lower than the last means the container has restarted, so everything in the new counter is new
+        let delta = |new: u64, last: u64| new.checked_sub(last).unwrap_or(new);
+        if let Some((last_periods, last_throttled)) = self.last {
+            self.periods = delta(periods, last_periods);
+            self.throttled = delta(throttled, last_throttled);
+        }
+        self.last = Some((periods, throttled));
+        self.throttled_time = throttled_time;
+    }
+
+    /// The daemon only counts periods for a container with a cpu quota
+    pub const fn has_quota(&self) -> bool {
+        matches!(self.last, Some((periods, _)) if periods > 0)
+    }
+
+    /// Percentage of the periods, since the last sample, that were throttled, None if there were no periods
+    pub const fn percent(&self) -> Option<u64> {
+        (self.throttled * 100).checked_div(self.periods)
+    }
+}
+
+/// The recent throttled percentage, & the total time throttled, empty without a cpu quota
+impl fmt::Display for Throttling {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.has_quota() {
+            write!(
+                f,
+                "{}% {}",
+                self.percent().unwrap_or_default(),
+                format_duration(self.throttled_time / 1_000_000_000)
+            )
+        } else {
+            write!(f, "")
+        }
+    }
+}
+
+/// The memory of a container broken down by type, from the cgroup memory stats
+/// The usage includes the page cache, which the kernel reclaims when memory is needed, so is often far higher than the memory the processes are actually using
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct MemoryBreakdown {
+    pub active_file: ByteStats,
+    /// Anonymous memory, such as heaps & stacks, the rss on cgroup v1
+    pub anon: ByteStats,
+    /// File backed memory, the page cache, the cache on cgroup v1
+    pub file: ByteStats,
+    pub inactive_file: ByteStats,
+    /// Kernel stacks, slab, & socket buffers, only reported on cgroup v2
+    pub kernel: Option<ByteStats>,
+    pub limit: ByteStats,
+    /// Memory mapped files, such as shared libraries
+    pub mapped_file: ByteStats,
+    /// tmpfs & shared memory, counted in the file memory, yet can't be reclaimed
+    pub shmem: ByteStats,
+    pub usage: ByteStats,
+    pub v2: bool,
+}
+
+impl MemoryBreakdown {
+    /// None without the cgroup stats, such as for Windows containers
+    pub fn new(stats: &MemoryStats) -> Option<Self> {
+        let breakdown = match stats.stats.as_ref()? {
+            MemoryStatsStats::V1(x) => Self {
+                active_file: ByteStats::new(x.active_file),
+                anon: ByteStats::new(x.rss),
+                file: ByteStats::new(x.cache),
+                inactive_file: ByteStats::new(x.inactive_file),
+                kernel: None,
+                mapped_file: ByteStats::new(x.mapped_file),
+                shmem: ByteStats::new(x.shmem.unwrap_or_default()),
+                v2: false,
+                ..Self::default()
+            },
+            MemoryStatsStats::V2(x) => Self {
+                active_file: ByteStats::new(x.active_file),
+                anon: ByteStats::new(x.anon),
+                file: ByteStats::new(x.file),
+                inactive_file: ByteStats::new(x.inactive_file),
+                kernel: Some(ByteStats::new(x.kernel_stack + x.slab + x.sock)),
+                mapped_file: ByteStats::new(x.file_mapped),
+                shmem: ByteStats::new(x.shmem),
+                v2: true,
+                ..Self::default()
+            },
+        };
+        Some(Self {
+            limit: ByteStats::new(stats.limit.unwrap_or_default()),
+            usage: ByteStats::new(stats.usage.unwrap_or_default()),
+            ..breakdown
+        })
+    }
+
+    /// The usage without the inactive file cache, as shown in the memory column, & as used by `docker stats`
+    pub const fn working_set(&self) -> ByteStats {
+        ByteStats::new(self.usage.get().saturating_sub(self.inactive_file.get()))
+    }
+}
+
+/// The cpu usage of a container on each core of the host, from the cgroup v1 per cpu usage, 100% is the whole of a core
+/// A single threaded process held at one core can look idle by the total usage alone on a host with many cores
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct CpuCores {
+    pub cores: Vec<CpuStats>,
+}
+
+impl CpuCores {
+    /// The usage of each core between the previous, & current, samples, None without per cpu usage, which cgroup v2, & Windows, daemons don't report
+    #[allow(clippy::cast_precision_loss)]
+    pub fn new(cpu: &CPUStats, precpu: &CPUStats) -> Option<Self> {
+        let (current, previous) = (
+            cpu.cpu_usage
+                .percpu_usage
+                .as_ref()
+                .filter(|i| !i.is_empty())?,
+            precpu.cpu_usage.percpu_usage.as_ref()?,
+        );
+        // The system usage is summed over every online core, so a core's share of it is the delta over the system delta, per core
+        let system_delta = cpu
+            .system_cpu_usage?
+            .saturating_sub(precpu.system_cpu_usage?) as f64;
+        let online = cpu.online_cpus.unwrap_or(current.len() as u64) as f64;
+        let cores = current
+            .iter()
+            .enumerate()
+            .map(|(index, usage)| {
+                let delta = usage.saturating_sub(previous.get(index).copied().unwrap_or_default());
+                let percent = if system_delta > 0.0 {
+                    (delta as f64 / system_delta * online * 100.0).min(100.0)
+                } else {
+                    0.0
+                };
+                CpuStats::new(percent)
+            })
+            .collect();
+        Some(Self { cores })
+    }
+
+    /// The index, & usage, of the most used core
+    pub fn busiest(&self) -> Option<(usize, CpuStats)> {
+        self.cores
+            .iter()
+            .copied()
+            .enumerate()
+            .max_by(|a, b| a.1.cmp(&b.1).then_with(|| b.0.cmp(&a.0)))
+    }
+
+    /// The usage summed over every core, as in the cpu column
+    pub fn total(&self) -> CpuStats {
+        CpuStats::new(self.cores.iter().map(Stats::get_value).sum())
+    }
+}
+
+/// Restarts within this many seconds of each other count towards a crash loop
+const CRASH_LOOP_WINDOW: u64 = 600;
+
+/// Number of restarts, within CRASH_LOOP_WINDOW, that make a crash loop
+const CRASH_LOOP_RESTARTS: usize = 3;
+
+/// The last exit code, & restart count, of a container, from inspect, shown in the optional `--restarts` columns
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ExitInfo {
+    pub exit_code: Option<i64>,
+    /// None until the container has been inspected
+    pub restart_count: Option<i64>,
+    /// The container has changed state, or had a lifecycle event, since it was inspected, so needs inspecting again
+    pub stale: bool,
+    /// Unix timestamps, in seconds, of the restarts seen since oxker started, the oldest are dropped once outside of CRASH_LOOP_WINDOW
+    restarts: VecDeque<u64>,
+}
+
+impl ExitInfo {
+    /// Set from an inspect, each increase in the restart count, since the last inspect, is recorded as a restart at `now`
+    pub fn set(&mut self, exit_code: Option<i64>, restart_count: i64, now: u64) {
+        if let Some(previous) = self.restart_count {
+            let new = usize::try_from(restart_count.saturating_sub(previous)).unwrap_or_default();
+            self.restarts
+                .extend(std::iter::repeat_n(now, new.min(CRASH_LOOP_RESTARTS)));
+        }
+        while self
+            .restarts
+            .front()
+            .is_some_and(|i| now.saturating_sub(*i) > CRASH_LOOP_WINDOW)
+        {
+            self.restarts.pop_front();
+        }
+        self.exit_code = exit_code;
+        self.restart_count = Some(restart_count);
+        self.stale = false;
+    }
+
+    /// Restarted at least CRASH_LOOP_RESTARTS times in the last CRASH_LOOP_WINDOW seconds, or restarting after at least as many restarts, when the restarts happened before oxker started
+    pub fn is_crash_loop(&self, restarting: bool, now: u64) -> bool {
+        let recent = self
+            .restarts
+            .iter()
+            .filter(|i| now.saturating_sub(**i) <= CRASH_LOOP_WINDOW)
+            .count();
+        recent >= CRASH_LOOP_RESTARTS
+            || restarting
+                && self
+                    .restart_count
+                    .and_then(|i| usize::try_from(i).ok())
+                    .is_some_and(|i| i >= CRASH_LOOP_RESTARTS)
+    }
+}
+
+/// The restarts of a container, from the events stream, each start that follows a die, so that a crash loop quicker than the update interval, which would look like a container that's been running for a few seconds, is still seen
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct RestartStorm {
+    /// The container has died, so its next start is a restart
+    died: bool,
+    /// Unix timestamps, in seconds, of the restarts within CRASH_LOOP_WINDOW
+    times: VecDeque<u64>,
+    /// The restarts within CRASH_LOOP_WINDOW, once there are at least CRASH_LOOP_RESTARTS of them
+    storm: Option<usize>,
+}
+
+impl RestartStorm {
+    pub const fn die(&mut self) {
+        self.died = true;
+    }
+
+    /// Record a start, a restart if the container had died, returns true if the restart has made a storm, so that it's only alerted once, until the restarts have calmed down
+    pub fn start(&mut self, now: u64) -> bool {
+        if !std::mem::take(&mut self.died) {
+            return false;
+        }
+        let was_storm = self.storm.is_some();
+        self.times.push_back(now);
+        self.expire(now);
+        !was_storm && self.storm.is_some()
+    }
+
+    /// Drop the restarts outside of CRASH_LOOP_WINDOW, the storm ends once there are fewer than CRASH_LOOP_RESTARTS left
+    pub fn expire(&mut self, now: u64) {
+        while self
+            .times
+            .front()
+            .is_some_and(|i| now.saturating_sub(*i) > CRASH_LOOP_WINDOW)
+        {
+            self.times.pop_front();
+        }
+        self.storm = (self.times.len() >= CRASH_LOOP_RESTARTS).then_some(self.times.len());
+    }
+
+    /// The restarts within CRASH_LOOP_WINDOW, None unless it's a storm
+    pub const fn storm(&self) -> Option<usize> {
+        self.storm
+    }
+
+    /// The badge shown in place of the status, e.g. `restarted 4x in 10m`, None unless it's a storm
+    pub fn badge(&self) -> Option<String> {
+        self.storm
+            .map(|count| format!("restarted {count}x in {}m", CRASH_LOOP_WINDOW / 60))
+    }
+
+    /// The alert of a storm, as shown in the info box, e.g. `restarted 3 times in the last 10 minutes`
+    pub fn alert_text(&self) -> Option<String> {
+        self.storm.map(|count| {
+            format!(
+                "restarted {count} times in the last {} minutes",
+                CRASH_LOOP_WINDOW / 60
+            )
+        })
+    }
+}
+
 pub type MemTuple = (Vec<(f64, f64)>, ByteStats, State);
 pub type CpuTuple = (Vec<(f64, f64)>, CpuStats, State);
 
+/// A stats history as csv rows, oldest first, the time of each sample, as RFC 3339 & as a unix timestamp, its cpu percentage, & memory bytes
+pub fn stats_csv(samples: &[(u64, f64, u64)]) -> String {
+    let mut output = "time,timestamp,cpu_percent,memory_bytes\n".to_owned();
+    for (time, cpu, memory) in samples {
+        writeln!(
+            output,
+            "{},{time},{cpu:.2},{memory}",
+            format_rfc3339(i64::try_from(*time).unwrap_or_default())
+        )
+        .ok();
+    }
+    output
+}
+
 /// Used to make sure that each log entry, for each container, is unique,
 /// will only push a log entry into the logs vec if timetstamp of said log entry isn't in the hashset
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
@@ -461,12 +1008,47 @@ impl fmt::Display for LogsTz {
     }
 }
 
+/// A single line of docker log output
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LogLine {
+    /// Output that was detected as binary, kept as bytes so that it can be shown as a hexdump
+    Binary(Vec<u8>),
+    Text(String),
+}
+
+impl From<String> for LogLine {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+
+/// The full content of a log line that isn't shown as is in the logs panel, viewable by expanding the line
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LogDetail {
+    /// Output that was detected as binary, shown as a hexdump
+    Binary(Vec<u8>),
+    /// A line that was too long, and so was truncated
+    Truncated(String),
+}
+
 /// Store the logs alongside a HashSet, each log *should* generate a unique timestamp,
 /// so if we store the timestamp separately in a HashSet, we can then check if we should insert a log line into the
 /// stateful list dependent on whethere the timestamp is in the HashSet or not
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[allow(clippy::struct_field_names)]
 pub struct Logs {
-    logs: StatefulList<ListItem<'static>>,
+    details: HashMap<usize, LogDetail>,
+    filter: Option<LogFilter>,
+    /// Each line of a single JSON object, parsed, so that it can be shown pretty-printed, or flattened
+    json: HashMap<usize, JsonLog>,
+    last_timestamp: Option<i64>,
+    /// Kept as text, rather than list items, so that the lines can be searched, & the matches highlighted
+    logs: StatefulList<Text<'static>>,
+    search: Option<LogSearch>,
+    /// Index of each line that the filter shows, with its own selected line, only while the filter is active
+    shown: Option<StatefulList<usize>>,
+    /// The timestamp of each line, gap markers have none, so that the timestamps of dropped lines can be forgotten
+    stamps: Vec<Option<LogsTz>>,
     tz: HashSet<LogsTz>,
 }
 
@@ -475,71 +1057,571 @@ impl Default for Logs {
         let mut logs = StatefulList::new(vec![]);
         logs.end();
         Self {
+            details: HashMap::new(),
+            filter: None,
+            json: HashMap::new(),
+            last_timestamp: None,
             logs,
+            search: None,
+            shown: None,
+            stamps: vec![],
             tz: HashSet::new(),
         }
     }
 }
 
 impl Logs {
-    /// Only allow a new log line to be inserted if the log timestamp isn't in the tz HashSet
-    pub fn insert(&mut self, line: ListItem<'static>, tz: LogsTz) {
-        if self.tz.insert(tz) {
-            self.logs.items.push(line);
+    /// Insert a gap marker, e.g. `— 2h 13m silence —`, before a new line that was logged at least threshold seconds after the previous line
+    /// Must be called before the line itself is inserted, lines already inserted, or without a valid timestamp, are ignored
+    pub fn insert_gap(&mut self, tz: &LogsTz, threshold: u64) {
+        if self.tz.contains(tz) {
+            return;
+        }
+        let Some(timestamp) = parse_rfc3339(tz.to_string().trim()) else {
+            return;
         };
+        if let Some(last) = self.last_timestamp {
+            let gap = u64::try_from(timestamp - last).unwrap_or_default();
+            if gap >= threshold {
+                self.push(
+                    Text::from(format!("— {} silence —", format_duration(gap)))
+                        .style(Style::default().add_modifier(Modifier::DIM | Modifier::ITALIC)),
+                    None,
+                );
+            }
+        }
+        self.last_timestamp = Some(self.last_timestamp.map_or(timestamp, |i| i.max(timestamp)));
+    }
+
+    /// Parse a line as JSON, using the full content of a truncated line, binary lines aren't parsed
+    fn parse_json(line: &Text<'static>, detail: Option<&LogDetail>) -> Option<JsonLog> {
+        match detail {
+            Some(LogDetail::Truncated(full)) => JsonLog::parse(full),
+            Some(LogDetail::Binary(_)) => None,
+            None => JsonLog::parse(&log_search::plain(line)),
+        }
+    }
+
+    /// Push a line, a line that the filter hides isn't matched against the search, gap markers aren't parsed as JSON
+    fn push(&mut self, line: Text<'static>, tz: Option<LogsTz>) {
+        let index = self.logs.items.len();
+        if let Some(json) = tz
+            .as_ref()
+            .and_then(|_| Self::parse_json(&line, self.details.get(&index)))
+        {
+            self.json.insert(index, json);
+        }
+        let shown = self
+            .filter
+            .as_ref()
+            .is_none_or(|i| i.is_match(&log_search::plain(&line)));
+        if shown {
+            if let Some(shown) = self.shown.as_mut() {
+                shown.items.push(index);
+            }
+            if let Some(search) = self.search.as_mut() {
+                search.scan(index, &line);
+            }
+        }
+        self.logs.items.push(line);
+        self.stamps.push(tz);
+    }
+
+    /// Only allow a new log line to be inserted if the log timestamp isn't in the tz HashSet, returns true if it was inserted
+    pub fn insert(&mut self, line: Text<'static>, tz: LogsTz) -> bool {
+        let inserted = self.tz.insert(tz.clone());
+        if inserted {
+            self.push(line, Some(tz));
+        }
+        inserted
     }
 
-    pub fn to_vec(&self) -> Vec<ListItem<'static>> {
-        self.logs.items.clone()
+    /// Insert a line that isn't displayed as is, the full content is kept so that it can be expanded
+    pub fn insert_detail(&mut self, line: Text<'static>, tz: LogsTz, detail: LogDetail) -> bool {
+        let inserted = self.tz.insert(tz.clone());
+        if inserted {
+            self.details.insert(self.logs.items.len(), detail);
+            self.push(line, Some(tz));
+        }
+        inserted
+    }
+
+    /// Insert older lines before the first line, in the order given, lines with a timestamp that was already inserted are skipped
+    /// Returns the number of lines inserted, the selected line is kept
+    pub fn prepend(&mut self, lines: Vec<(Text<'static>, LogsTz, Option<LogDetail>)>) -> usize {
+        let at_end = self.at_end();
+        let selected = self.selected();
+        let mut details = HashMap::new();
+        let mut json = HashMap::new();
+        let mut items = vec![];
+        let mut stamps = vec![];
+        for (line, tz, detail) in lines {
+            if self.tz.insert(tz.clone()) {
+                if let Some(parsed) = Self::parse_json(&line, detail.as_ref()) {
+                    json.insert(items.len(), parsed);
+                }
+                if let Some(detail) = detail {
+                    details.insert(items.len(), detail);
+                }
+                items.push(line);
+                stamps.push(Some(tz));
+            }
+        }
+        let count = items.len();
+        if count == 0 {
+            return 0;
+        }
+        details.extend(
+            std::mem::take(&mut self.details)
+                .into_iter()
+                .map(|(index, detail)| (index + count, detail)),
+        );
+        self.details = details;
+        json.extend(
+            std::mem::take(&mut self.json)
+                .into_iter()
+                .map(|(index, parsed)| (index + count, parsed)),
+        );
+        self.json = json;
+        items.append(&mut self.logs.items);
+        stamps.append(&mut self.stamps);
+        self.logs.items = items;
+        self.stamps = stamps;
+        self.rebuild(at_end, selected.map(|i| i + count));
+        count
+    }
+
+    /// Drop the oldest lines once there are more than max, down to nine tenths of max, so that lines aren't moved on every update, 0 keeps every line
+    /// The selected line, & the lines after it, are never dropped, the timestamps of dropped lines are forgotten, so that they can be loaded again
+    /// Returns the number of lines dropped
+    pub fn trim(&mut self, max: usize) -> usize {
+        let len = self.logs.items.len();
+        if max == 0 || len <= max {
+            return 0;
+        }
+        let selected = self.selected();
+        let excess = (len - max + max / 10).min(selected.unwrap_or(len));
+        if excess == 0 {
+            return 0;
+        }
+        self.logs.items.drain(..excess);
+        for tz in self.stamps.drain(..excess).flatten() {
+            self.tz.remove(&tz);
+        }
+        self.details = std::mem::take(&mut self.details)
+            .into_iter()
+            .filter_map(|(index, detail)| index.checked_sub(excess).map(|i| (i, detail)))
+            .collect();
+        self.json = std::mem::take(&mut self.json)
+            .into_iter()
+            .filter_map(|(index, parsed)| index.checked_sub(excess).map(|i| (i, parsed)))
+            .collect();
+        // Indices are shifted, rather than the filter & search matched again, as this happens on any update of a busy container
+        if let Some(shown) = self.shown.as_mut() {
+            let dropped = shown.items.partition_point(|i| *i < excess);
+            shown.items.drain(..dropped);
+            for index in &mut shown.items {
+                *index -= excess;
+            }
+            let position = shown.state.selected().map(|i| i.saturating_sub(dropped));
+            shown.state.select(position);
+        }
+        if let Some(search) = self.search.as_mut() {
+            search.drop_lines(excess);
+        }
+        let position = self.logs.state.selected().map(|i| i.saturating_sub(excess));
+        self.logs.state.select(position);
+        excess
     }
 
-    /// The rest of the methods are basically forwarding from the underlying StatefulList
+    /// The unix timestamp, in seconds, of the oldest line, so that older lines can be requested
+    pub fn oldest(&self) -> Option<i64> {
+        self.stamps
+            .iter()
+            .flatten()
+            .find_map(|i| parse_rfc3339(i.to_string().trim()))
+    }
+
+    /// Index of the selected line, in every line, rather than in the lines that the filter shows
+    fn selected(&self) -> Option<usize> {
+        self.shown.as_ref().map_or_else(
+            || self.logs.state.selected(),
+            |shown| {
+                shown
+                    .state
+                    .selected()
+                    .and_then(|i| shown.items.get(i))
+                    .copied()
+            },
+        )
+    }
+
+    /// Select a line by its index in every line, a line that the filter hides can't be selected
+    fn select(&mut self, index: usize) {
+        match self.shown.as_mut() {
+            Some(shown) => {
+                if let Ok(position) = shown.items.binary_search(&index) {
+                    shown.state.select(Some(position));
+                }
+            }
+            None => self.logs.state.select(Some(index)),
+        }
+    }
+
+    /// Every line, or just the lines that the filter shows, with their index
+    fn lines(&self) -> Box<dyn Iterator<Item = (usize, &Text<'static>)> + '_> {
+        match self.shown.as_ref() {
+            Some(shown) => Box::new(shown.items.iter().map(|i| (*i, &self.logs.items[*i]))),
+            None => Box::new(self.logs.items.iter().enumerate()),
+        }
+    }
+
+    /// Get the full content of the selected line, if it was truncated, or is binary
+    pub fn get_selected_detail(&self) -> Option<&LogDetail> {
+        self.selected().and_then(|i| self.details.get(&i))
+    }
+
+    /// The style of a line is used as the style of its list item, so that it fills the whole row
+    /// The timestamp, as rendered by stamp, is put before the line, lines are kept without one, so that how it's shown can be changed at any time
+    fn to_item(
+        &self,
+        index: usize,
+        mut line: Text<'static>,
+        stamp: &impl Fn(&LogsTz) -> Option<String>,
+    ) -> ListItem<'static> {
+        if let Some(tz) = self
+            .stamps
+            .get(index)
+            .and_then(|i| i.as_ref().and_then(stamp))
+        {
+            match line.lines.first_mut() {
+                Some(first) => first.spans.insert(0, Span::from(tz)),
+                None => line.lines.push(Line::from(tz)),
+            }
+        }
+        let style = std::mem::take(&mut line.style);
+        ListItem::new(line).style(style)
+    }
+
+    /// Every line that the filter shows, with the matches of the search highlighted
+    /// With a JSON view, lines of a JSON object are rendered in that view instead, without the highlights, as the matches are of the line as logged
+    pub fn to_vec(
+        &self,
+        json: Option<JsonView>,
+        stamp: impl Fn(&LogsTz) -> Option<String>,
+    ) -> Vec<ListItem<'static>> {
+        self.lines()
+            .map(|(index, line)| {
+                let rendered =
+                    json.and_then(|view| self.json.get(&index).map(|parsed| parsed.render(view)));
+                let line = rendered.unwrap_or_else(|| {
+                    self.search
+                        .as_ref()
+                        .and_then(|i| i.ranges(index))
+                        .map_or_else(|| line.clone(), |i| log_search::highlight(line, i))
+                });
+                self.to_item(index, line, &stamp)
+            })
+            .collect()
+    }
+
+    /// The lines either side of the line at index, and the position of that line in the returned lines, the filter isn't applied
+    pub fn context(
+        &self,
+        index: usize,
+        lines: usize,
+        stamp: impl Fn(&LogsTz) -> Option<String>,
+    ) -> Option<(Vec<ListItem<'static>>, usize)> {
+        if index >= self.logs.items.len() {
+            return None;
+        }
+        let start = index.saturating_sub(lines);
+        let end = index.saturating_add(lines + 1).min(self.logs.items.len());
+        Some((
+            (start..end)
+                .map(|i| self.to_item(i, self.logs.items[i].clone(), &stamp))
+                .collect(),
+            index - start,
+        ))
+    }
+
+    pub const fn get_search(&self) -> Option<&LogSearch> {
+        self.search.as_ref()
+    }
+
+    /// Set, or clear, the search, every line that the filter shows is matched against the new search
+    pub fn set_search(&mut self, search: Option<LogSearch>) {
+        self.search = search.map(|mut i| {
+            i.scan_all(self.lines());
+            i
+        });
+    }
+
+    /// The search, & the position of the selected line in the lines with a match, as shown in the logs title
+    pub fn search_title(&self) -> Option<String> {
+        self.search.as_ref().map(|i| i.title(self.selected()))
+    }
+
+    /// Select the next, or previous, line with a match of the search, the selected line is unchanged if there isn't one
+    pub fn search_jump(&mut self, forward: bool) {
+        if let Some(line) = self
+            .search
+            .as_ref()
+            .and_then(|i| i.jump(self.selected(), forward))
+        {
+            self.select(line);
+        }
+    }
+
+    pub const fn get_filter(&self) -> Option<&LogFilter> {
+        self.filter.as_ref()
+    }
+
+    /// Set, or clear, the filter, the selected line is kept if it's still shown, otherwise the closest shown line before it is selected
+    /// The search is matched again, against just the lines that are now shown
+    pub fn set_filter(&mut self, filter: Option<LogFilter>) {
+        let at_end = self.at_end();
+        let selected = self.selected();
+        self.filter = filter;
+        self.rebuild(at_end, selected);
+    }
+
+    /// Match the filter, & then the search, against every line, & select the line at the index in every line, or the last line
+    fn rebuild(&mut self, at_end: bool, selected: Option<usize>) {
+        self.shown = self
+            .filter
+            .as_ref()
+            .filter(|i| i.is_active())
+            .map(|filter| {
+                StatefulList::new(
+                    self.logs
+                        .items
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, line)| filter.is_match(&log_search::plain(line)))
+                        .map(|(index, _)| index)
+                        .collect(),
+                )
+            });
+        match (self.shown.as_mut(), selected) {
+            (_, _) if at_end => self.end(),
+            (Some(shown), Some(selected)) => {
+                let position = shown.items.partition_point(|i| *i <= selected);
+                shown.state.select(Some(position.saturating_sub(1)));
+            }
+            (None, Some(selected)) => self.logs.state.select(Some(selected)),
+            (_, None) => self.end(),
+        }
+        if let Some(mut search) = self.search.take() {
+            search.scan_all(self.lines());
+            self.search = Some(search);
+        }
+    }
+
+    /// The filter, so that it isn't forgotten that lines are hidden, as shown in the logs title
+    pub fn filter_title(&self) -> Option<String> {
+        self.filter.as_ref().map(LogFilter::title)
+    }
+
+    /// No line is selected, or the last shown line is, so that newly inserted lines can be followed
+    pub fn at_end(&self) -> bool {
+        let (selected, len) = self.shown.as_ref().map_or_else(
+            || (self.logs.state.selected(), self.logs.items.len()),
+            |i| (i.state.selected(), i.items.len()),
+        );
+        selected.is_none_or(|i| i + 1 >= len)
+    }
+
+    /// The rest of the methods are basically forwarding from the underlying StatefulList, or the shown lines while filtered
     pub fn get_state_title(&self) -> String {
-        self.logs.get_state_title()
+        self.shown.as_ref().map_or_else(
+            || self.logs.get_state_title(),
+            StatefulList::get_state_title,
+        )
     }
 
     pub fn next(&mut self) {
-        self.logs.next();
+        match self.shown.as_mut() {
+            Some(shown) => shown.next(),
+            None => self.logs.next(),
+        }
     }
 
     pub fn previous(&mut self) {
-        self.logs.previous();
+        match self.shown.as_mut() {
+            Some(shown) => shown.previous(),
+            None => self.logs.previous(),
+        }
     }
 
     pub fn end(&mut self) {
-        self.logs.end();
+        match self.shown.as_mut() {
+            Some(shown) => shown.end(),
+            None => self.logs.end(),
+        }
     }
     pub fn start(&mut self) {
-        self.logs.start();
+        match self.shown.as_mut() {
+            Some(shown) => shown.start(),
+            None => self.logs.start(),
+        }
     }
 
-    pub fn len(&self) -> usize {
+    pub const fn len(&self) -> usize {
         self.logs.items.len()
     }
 
-    pub fn state(&mut self) -> &mut ListState {
-        &mut self.logs.state
+    pub const fn state(&mut self) -> &mut ListState {
+        match self.shown.as_mut() {
+            Some(shown) => &mut shown.state,
+            None => &mut self.logs.state,
+        }
+    }
+}
+
+/// Kubernetes pod information, taken from the `io.kubernetes.*` labels that kind, minikube, k3d, and cri-dockerd attach to their containers
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PodInfo {
+    pub namespace: String,
+    pub pod: String,
+    pub container: ContainerName,
+}
+
+impl PodInfo {
+    const POD_NAME: &'static str = "io.kubernetes.pod.name";
+    const POD_NAMESPACE: &'static str = "io.kubernetes.pod.namespace";
+    const CONTAINER_NAME: &'static str = "io.kubernetes.container.name";
+
+    /// Only generate PodInfo if the container has a pod name label, a missing container name label is a pod sandbox/pause container
+    pub fn from_labels(labels: Option<&HashMap<String, String>>) -> Option<Self> {
+        let labels = labels?;
+        let pod = labels.get(Self::POD_NAME)?;
+        Some(Self {
+            namespace: labels
+                .get(Self::POD_NAMESPACE)
+                .map_or_else(|| "default".to_owned(), std::clone::Clone::clone),
+            pod: pod.clone(),
+            container: ContainerName::from(
+                labels
+                    .get(Self::CONTAINER_NAME)
+                    .map_or_else(|| "POD".to_owned(), std::clone::Clone::clone),
+            ),
+        })
+    }
+
+    /// namespace/pod, used as the group key
+    pub fn key(&self) -> String {
+        format!("{}/{}", self.namespace, self.pod)
+    }
+}
+
+/// How a container is shown, taken from the `oxker.*` labels, so that deployment tooling can set it without any user config
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct LabelHints {
+    /// `oxker.group`, the group of the container when grouped by label
+    pub group: Option<String>,
+    /// `oxker.color`, the color of the container's name, a color name, or `#rrggbb`
+    pub color: Option<Color>,
+    /// `oxker.logs`, where the logs are read from when the daemon can't return them, in place of `--log-driver-fallback`
+    pub logs: Option<LogFallback>,
+    /// `oxker.url`, opened in place of the url of the first published port
+    pub url: Option<String>,
+}
+
+impl LabelHints {
+    const HIDE: &'static str = "oxker.hide";
+    const GROUP: &'static str = "oxker.group";
+    const COLOR: &'static str = "oxker.color";
+    const URL: &'static str = "oxker.url";
+    const LOGS: &'static str = "oxker.logs";
+
+    /// `oxker.hide` is `true`, or `1`, the container isn't listed, unless `--show-hidden` is set
+    pub fn is_hidden(labels: Option<&HashMap<String, String>>) -> bool {
+        labels
+            .and_then(|i| i.get(Self::HIDE))
+            .is_some_and(|i| matches!(i.trim().to_lowercase().as_str(), "true" | "1"))
+    }
+
+    /// Empty labels, & colors that can't be parsed, are ignored
+    pub fn from_labels(labels: Option<&HashMap<String, String>>) -> Self {
+        let get = |label: &str| {
+            labels
+                .and_then(|i| i.get(label))
+                .map(|i| i.trim())
+                .filter(|i| !i.is_empty())
+        };
+        Self {
+            group: get(Self::GROUP).map(ToOwned::to_owned),
+            color: get(Self::COLOR).and_then(|i| i.parse::<Color>().ok()),
+            logs: get(Self::LOGS).and_then(|i| LogFallback::from_str(i, true).ok()),
+            url: get(Self::URL).map(ToOwned::to_owned),
+        }
     }
 }
 
 /// Info for each container
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ContainerItem {
+    /// The recent controls sent to the container, & whether they failed
+    pub attempts: Attempts,
+    /// The host ports bound in the config, inspected once, only for a container that isn't running, as a running container lists its ports
+    pub bindings: Option<Vec<HostPort>>,
+    /// The host ports that other containers on the same host also publish to
+    pub conflicts: Vec<PortConflict>,
+    /// The latest cpu usage of each core, None if the container isn't running, or the daemon doesn't report it
+    pub cores: Option<CpuCores>,
     pub cpu_stats: VecDeque<CpuStats>,
     pub created: u64,
     pub docker_controls: StatefulList<DockerControls>,
+    /// Only inspected with `--restarts`
+    pub exit_info: ExitInfo,
+    /// Values of the labels that the `--filter` rules match against
+    pub filter_labels: HashMap<String, String>,
+    pub hints: LabelHints,
+    pub host: Option<String>,
     pub id: ContainerId,
     pub image: ContainerImage,
+    pub io_counters: IoCounters,
+    /// Network & disk throughput, each sampled alongside the cpu & mem stats
+    pub io_stats: VecDeque<IoRates>,
     pub is_oxker: bool,
+    pub isolation: Option<Isolation>,
+    /// Values of the `--label-column` labels, in the order given, None where the container doesn't have the label
+    pub label_values: Vec<Option<String>>,
     pub last_updated: u64,
+    /// The configured cpu & memory limits, None until inspected
+    pub limits: Option<Limits>,
     pub logs: Logs,
+    pub logs_waiting: Waiting,
     pub mem_limit: ByteStats,
     pub mem_stats: VecDeque<ByteStats>,
+    /// The latest memory stats, broken down by type, None if the container isn't running
+    pub memory: Option<MemoryBreakdown>,
     pub name: ContainerName,
+    pub pod: Option<PodInfo>,
     pub ports: Vec<ContainerPorts>,
+    /// The host ports that a running container publishes to, with their protocol, to compare against other containers
+    pub published: Vec<HostPort>,
+    /// Name of the compose project, from the `com.docker.compose.project` label
+    pub project: Option<String>,
+    /// Value of the `--order-label` label, lower is listed first in the default order
+    pub priority: Option<i64>,
+    /// Only set for running containers with a `--probe`
+    pub readiness: Option<Readiness>,
+    /// Restarts seen in the events stream, whether it's restarting so often that it's crash looping
+    pub restart_storm: RestartStorm,
     pub rx: ByteStats,
+    /// Size of the writable layer, only checked with `--disk-alert`
+    pub rw_size: Option<ByteStats>,
+    /// Name of the compose service, from the `com.docker.compose.service` label
+    pub service: Option<String>,
     pub state: State,
+    /// Unix timestamp of each sample of the cpu & mem stats
+    pub stats_times: VecDeque<u64>,
+    pub stats_waiting: Waiting,
     pub status: String,
+    pub throttling: Throttling,
+    pub traffic: Traffic,
     pub tx: ByteStats,
 }
 
@@ -574,90 +1656,298 @@ impl ContainerItem {
         docker_controls.start();
 
         Self {
+            attempts: Attempts::default(),
+            bindings: None,
+            conflicts: vec![],
+            cores: None,
             cpu_stats: VecDeque::with_capacity(60),
             created,
             docker_controls,
+            exit_info: ExitInfo::default(),
+            filter_labels: HashMap::new(),
+            hints: LabelHints::default(),
+            host: None,
             id,
             image: image.into(),
+            io_counters: IoCounters::default(),
+            io_stats: VecDeque::with_capacity(60),
             is_oxker,
+            isolation: None,
+            label_values: vec![],
             last_updated: 0,
+            limits: None,
             logs: Logs::default(),
+            logs_waiting: Waiting::default(),
             mem_limit: ByteStats::default(),
             mem_stats: VecDeque::with_capacity(60),
+            memory: None,
             name: name.into(),
+            pod: None,
             ports,
+            project: None,
+            published: vec![],
+            priority: None,
+            readiness: None,
+            restart_storm: RestartStorm::default(),
             rx: ByteStats::default(),
+            rw_size: None,
+            service: None,
             state,
+            stats_times: VecDeque::with_capacity(60),
+            stats_waiting: Waiting::default(),
             status,
+            throttling: Throttling::default(),
+            traffic: Traffic::default(),
             tx: ByteStats::default(),
         }
     }
 
-    /// Find the max value in the cpu stats VecDeque
-    fn max_cpu_stats(&self) -> CpuStats {
-        self.cpu_stats
-            .iter()
-            .max()
-            .map_or_else(CpuStats::default, |value| *value)
-    }
-
-    /// Find the max value in the mem stats VecDeque
-    fn max_mem_stats(&self) -> ByteStats {
-        self.mem_stats
+    /// The status, as listed by the daemon, e.g. `Up 3 seconds`, or the restart storm badge, as a crash loop can otherwise look like a container that's running
+    pub fn status_text(&self) -> String {
+        self.restart_storm
+            .badge()
+            .unwrap_or_else(|| self.status.clone())
+    }
+
+    /// The samples of a stats VecDeque that are shown, the most recent window of them, ignoring any taken since the charts were frozen
+    fn shown<T: Copy>(&self, stats: &VecDeque<T>, view: ChartView, window: usize) -> Vec<T> {
+        let since = view.frozen().map_or(0, |frozen| {
+            self.stats_times
+                .iter()
+                .rev()
+                .take_while(|i| **i > frozen)
+                .count()
+        });
+        let end = stats.len().saturating_sub(since);
+        stats
             .iter()
-            .max()
-            .map_or_else(ByteStats::default, |value| *value)
+            .take(end)
+            .skip(end.saturating_sub(window))
+            .copied()
+            .collect()
     }
 
-    /// Convert cpu stats into a vec for the charts function
+    /// Convert the shown samples of a stats VecDeque into a vec for the charts function, alongside the max shown value
     #[allow(clippy::cast_precision_loss)]
-    fn get_cpu_dataset(&self) -> Vec<(f64, f64)> {
-        self.cpu_stats
+    fn get_dataset<T: Stats + Ord + Copy + Default>(
+        &self,
+        stats: &VecDeque<T>,
+        view: ChartView,
+        window: usize,
+    ) -> (Vec<(f64, f64)>, T) {
+        let shown = self.shown(stats, view, window);
+        let max = shown.iter().max().copied().unwrap_or_default();
+        let dataset = shown
             .iter()
             .enumerate()
-            .map(|i| (i.0 as f64, i.1 .0))
-            .collect::<Vec<_>>()
+            .map(|(index, value)| (index as f64, value.get_value()))
+            .collect::<Vec<_>>();
+        (dataset, max)
     }
 
-    /// Convert mem stats into a Vec for the charts function
-    #[allow(clippy::cast_precision_loss)]
-    fn get_mem_dataset(&self) -> Vec<(f64, f64)> {
-        self.mem_stats
-            .iter()
-            .enumerate()
-            .map(|i| (i.0 as f64, i.1 .0 as f64))
-            .collect::<Vec<_>>()
+    /// Compare the two most recent values of a stats VecDeque, None if there aren't yet two values
+    fn delta<T: Ord>(stats: &VecDeque<T>) -> Option<Ordering> {
+        let mut latest = stats.iter().rev();
+        match (latest.next(), latest.next()) {
+            (Some(current), Some(previous)) => Some(current.cmp(previous)),
+            _ => None,
+        }
     }
 
-    /// Get all cpu chart data
-    fn get_cpu_chart_data(&self) -> CpuTuple {
-        (self.get_cpu_dataset(), self.max_cpu_stats(), self.state)
+    /// Direction of the most recent cpu change, used to highlight movement when in top mode
+    pub fn cpu_delta(&self) -> Option<Ordering> {
+        Self::delta(&self.cpu_stats)
     }
 
-    /// Get all mem chart data
-    fn get_mem_chart_data(&self) -> MemTuple {
-        (self.get_mem_dataset(), self.max_mem_stats(), self.state)
+    /// Direction of the most recent memory change, used to highlight movement when in top mode
+    pub fn mem_delta(&self) -> Option<Ordering> {
+        Self::delta(&self.mem_stats)
     }
 
     /// Get chart info for cpu & memory in one function
     /// So only need to call .lock() once
-    pub fn get_chart_data(&self) -> (CpuTuple, MemTuple) {
-        (self.get_cpu_chart_data(), self.get_mem_chart_data())
+    pub fn get_chart_data(&self, view: ChartView, window: usize) -> (CpuTuple, MemTuple) {
+        let (cpu, cpu_max) = self.get_dataset(&self.cpu_stats, view, window);
+        let (mem, mem_max) = self.get_dataset(&self.mem_stats, view, window);
+        ((cpu, cpu_max, self.state), (mem, mem_max, self.state))
+    }
+
+    /// Get chart info for network & disk throughput
+    pub fn get_io_chart_data(&self, view: ChartView, window: usize) -> (IoChart, State) {
+        let shown = self.shown(&self.io_stats, view, window);
+        (IoChart::from(shown.as_slice()), self.state)
+    }
+
+    /// The most recent network & disk throughput
+    pub fn io_rates(&self) -> IoRates {
+        self.io_stats.back().copied().unwrap_or_default()
+    }
+
+    /// Every sample of the stats history, oldest first, the time of each sample, its cpu percentage, & memory bytes
+    pub fn stats_history(&self) -> Vec<(u64, f64, u64)> {
+        let mut output = self
+            .stats_times
+            .iter()
+            .rev()
+            .zip(self.cpu_stats.iter().rev())
+            .zip(self.mem_stats.iter().rev())
+            .map(|((time, cpu), mem)| (*time, cpu.0, mem.0))
+            .collect::<Vec<_>>();
+        output.reverse();
+        output
+    }
+
+    /// The cpu usage, followed by the cpu limit, if there is one, e.g. `12.00% / 150%`
+    pub fn cpu_text(&self) -> String {
+        let cpu = self.cpu_stats.back().copied().unwrap_or_default();
+        self.limits
+            .and_then(Limits::cpu_text)
+            .map_or_else(|| cpu.to_string(), |limit| format!("{cpu} / {limit}"))
+    }
+
+    /// The health check result, from the status, None if the container has no health check
+    pub fn health(&self) -> Option<Health> {
+        Health::from_status(&self.status)
+    }
+
+    /// Docker appends the health check result to the status, e.g. `Up 2 hours (unhealthy)`
+    pub fn is_unhealthy(&self) -> bool {
+        self.health() == Some(Health::Unhealthy)
+    }
+
+    /// Name to display in the containers panel, when grouped by pod, use the kubernetes container name instead of the generated docker name
+    pub fn display_name(&self, group_by: Option<GroupBy>) -> String {
+        match (group_by, self.pod.as_ref()) {
+            (Some(GroupBy::Pod), Some(pod)) => pod.container.to_string(),
+            _ => self.name.to_string(),
+        }
     }
 }
 
-/// Container information panel headings + widths, for nice pretty formatting
+/// Alternate layouts of the containers panel, where containers are listed under a collapsible group header
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GroupBy {
+    Image,
+    /// The `oxker.group` label
+    Label,
+    Pod,
+    Project,
+}
+
+impl GroupBy {
+    /// Cycle through the group layouts, off -> image -> pod -> project -> label -> off
+    pub const fn next(current: Option<Self>) -> Option<Self> {
+        match current {
+            None => Some(Self::Image),
+            Some(Self::Image) => Some(Self::Pod),
+            Some(Self::Pod) => Some(Self::Project),
+            Some(Self::Project) => Some(Self::Label),
+            Some(Self::Label) => None,
+        }
+    }
+
+    /// The key used to place a container into a group, containers without kubernetes, compose, or `oxker.group`, labels are placed together in a single group
+    pub fn key(self, item: &ContainerItem) -> String {
+        match self {
+            Self::Image => item.image.get().to_owned(),
+            Self::Label => item
+                .hints
+                .group
+                .clone()
+                .unwrap_or_else(|| NO_GROUP.to_owned()),
+            Self::Pod => item
+                .pod
+                .as_ref()
+                .map_or_else(|| "no pod".to_owned(), PodInfo::key),
+            Self::Project => item
+                .project
+                .clone()
+                .unwrap_or_else(|| NO_PROJECT.to_owned()),
+        }
+    }
+
+    /// The compose project of a group, None for the group of containers that aren't part of a project, or if not grouped by project
+    pub fn project(self, key: &str) -> Option<&str> {
+        (self == Self::Project && key != NO_PROJECT).then_some(key)
+    }
+}
+
+impl fmt::Display for GroupBy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let disp = match self {
+            Self::Image => "image",
+            Self::Label => "label",
+            Self::Pod => "pod",
+            Self::Project => "project",
+        };
+        write!(f, "{disp}")
+    }
+}
+
+/// Header row for a group of containers, with the aggregate usage of all the containers it contains
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContainerGroup {
+    pub key: String,
+    pub count: usize,
+    pub running: usize,
+    pub cpu: CpuStats,
+    pub mem: ByteStats,
+    pub expanded: bool,
+}
+
+impl ContainerGroup {
+    /// Group name, with an expanded/collapsed indicator, truncated in the same manner as ContainerName
+    pub fn label(&self) -> String {
+        let arrow = if self.expanded { "▾" } else { "▸" };
+        let key = if self.key.chars().count() >= 30 {
+            format!("{}…", self.key.chars().take(29).collect::<String>())
+        } else {
+            self.key.clone()
+        };
+        format!("{arrow} {key}")
+    }
+
+    /// Running count of the group, displayed in the state column
+    pub fn count_text(&self) -> String {
+        format!("{}/{} running", self.running, self.count)
+    }
+}
+
+/// A single row in the containers panel, either a group header, or a container, as an index of the containers vec
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ContainerRow {
+    Group(ContainerGroup),
+    Container(usize),
+}
+
+/// Container information panel headings + widths, for nice pretty formatting
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Columns {
     pub name: (Header, u8),
+    /// Only shown when more than one host is being monitored
+    pub host: Option<(Header, u8)>,
     pub state: (Header, u8),
     pub status: (Header, u8),
+    /// Only shown when a `--probe` is set
+    pub ready: Option<(Header, u8)>,
+    /// Only shown with `--restarts`
+    pub exit_code: Option<(Header, u8)>,
+    /// Only shown with `--restarts`
+    pub restarts: Option<(Header, u8)>,
     pub cpu: (Header, u8),
+    /// Only shown when a container has a cpu quota
+    pub throttled: Option<(Header, u8)>,
     pub mem: (Header, u8, u8),
     pub id: (Header, u8),
     pub image: (Header, u8),
     pub net_rx: (Header, u8),
     pub net_tx: (Header, u8),
+    /// Only shown when the throughput is toggled on
+    pub net_io: Option<(Header, u8)>,
+    /// Only shown when the throughput is toggled on
+    pub disk_io: Option<(Header, u8)>,
+    /// One for each `--label-column`
+    pub labels: Vec<(Header, u8)>,
 }
 
 impl Columns {
@@ -665,28 +1955,46 @@ impl Columns {
     pub const fn new() -> Self {
         Self {
             name: (Header::Name, 4),
+            host: None,
             state: (Header::State, 11),
             status: (Header::Status, 16),
+            ready: None,
+            exit_code: None,
+            restarts: None,
             cpu: (Header::Cpu, 7),
+            throttled: None,
             mem: (Header::Memory, 7, 7),
             id: (Header::Id, 8),
             image: (Header::Image, 5),
             net_rx: (Header::Rx, 7),
             net_tx: (Header::Tx, 7),
+            net_io: None,
+            disk_io: None,
+            labels: vec![],
         }
     }
 }
 
 #[cfg(test)]
+#[allow(clippy::unwrap_used)]
 mod tests {
-    use ratatui::widgets::ListItem;
+    use std::collections::HashMap;
+
+    use bollard::container::{CPUStats, MemoryStats};
+    use ratatui::{style::Color, text::Text};
 
     use crate::{
-        app_data::{ContainerImage, Logs},
+        app_data::{ContainerImage, JsonView, Logs},
+        docker_data::LogFallback,
         ui::log_sanitizer,
     };
+    use ratatui::widgets::ListItem;
 
-    use super::{ByteStats, ContainerName, CpuStats, LogsTz};
+    use super::{
+        compose_project, ByteStats, ContainerName, CpuCores, CpuStats, ExitInfo, GroupBy, Health,
+        LabelHints, LogFilter, LogSearch, LogsTz, MemoryBreakdown, PodInfo, RestartStorm, Stats,
+        Throttling, Traffic, Waiting,
+    };
 
     #[test]
     /// Display CpuStats as a string
@@ -701,6 +2009,25 @@ mod tests {
         test(150.15, "150.15%");
     }
 
+    #[test]
+    /// The health is parsed from the end of the status, a container without a health check has none
+    fn test_container_state_health_from_status() {
+        assert_eq!(
+            Health::from_status("Up 2 hours (healthy)"),
+            Some(Health::Healthy)
+        );
+        assert_eq!(
+            Health::from_status("Up 2 hours (unhealthy)"),
+            Some(Health::Unhealthy)
+        );
+        assert_eq!(
+            Health::from_status("Up 3 seconds (health: starting)"),
+            Some(Health::Starting)
+        );
+        assert_eq!(Health::from_status("Up 2 hours"), None);
+        assert_eq!(Health::from_status("Exited (0) 5 minutes ago"), None);
+    }
+
     #[test]
     /// Display bytestats as a string, convert into correct data unit (Kb, MB, GB)
     fn test_container_state_bytestats_to_string() {
@@ -746,6 +2073,352 @@ mod tests {
         assert_eq!(result, "name_01_name_01_name_01_name_01_");
     }
 
+    #[test]
+    /// Cpu & memory deltas compare the two most recent values
+    fn test_container_state_stats_delta() {
+        let mut item = crate::tests::gen_item(&crate::app_data::ContainerId::from("1"), 1);
+        assert!(item.cpu_delta().is_none());
+        item.cpu_stats.push_back(CpuStats::new(1.0));
+        assert!(item.cpu_delta().is_none());
+        item.cpu_stats.push_back(CpuStats::new(5.0));
+        assert_eq!(item.cpu_delta(), Some(std::cmp::Ordering::Greater));
+        item.cpu_stats.push_back(CpuStats::new(5.0));
+        assert_eq!(item.cpu_delta(), Some(std::cmp::Ordering::Equal));
+
+        item.mem_stats.push_back(ByteStats::new(10));
+        item.mem_stats.push_back(ByteStats::new(5));
+        assert_eq!(item.mem_delta(), Some(std::cmp::Ordering::Less));
+    }
+
+    #[test]
+    /// Waiting is only stale at the limit, a retry keeps the original start, & is cleared once done
+    fn test_container_state_waiting() {
+        let mut waiting = Waiting::default();
+        assert!(waiting.stale(100, 5).is_none());
+        waiting.start(10);
+        waiting.start(12);
+        assert!(waiting.stale(14, 5).is_none());
+        assert_eq!(waiting.stale(15, 5), Some(5));
+        assert_eq!(waiting.stale(130, 5), Some(120));
+        waiting.done();
+        assert!(waiting.stale(130, 5).is_none());
+    }
+
+    #[test]
+    /// The breakdown is read from the cgroup v2 stats, the working set is the usage without the inactive file cache
+    fn test_container_state_memory_breakdown() {
+        let mut stats = [
+            "anon",
+            "file",
+            "kernel_stack",
+            "slab",
+            "sock",
+            "shmem",
+            "file_mapped",
+            "file_dirty",
+            "file_writeback",
+            "anon_thp",
+            "inactive_anon",
+            "active_anon",
+            "inactive_file",
+            "active_file",
+            "unevictable",
+            "slab_reclaimable",
+            "slab_unreclaimable",
+            "pgfault",
+            "pgmajfault",
+            "workingset_refault",
+            "workingset_activate",
+            "workingset_nodereclaim",
+            "pgrefill",
+            "pgscan",
+            "pgsteal",
+            "pgactivate",
+            "pgdeactivate",
+            "pglazyfree",
+            "pglazyfreed",
+            "thp_fault_alloc",
+            "thp_collapse_alloc",
+        ]
+        .into_iter()
+        .map(|i| (i.to_owned(), serde_json::Value::from(0)))
+        .collect::<serde_json::Map<_, _>>();
+        for (key, value) in [
+            ("anon", 250),
+            ("file", 700),
+            ("active_file", 300),
+            ("inactive_file", 400),
+            ("kernel_stack", 10),
+            ("slab", 30),
+            ("sock", 10),
+            ("file_mapped", 20),
+            ("shmem", 5),
+        ] {
+            stats.insert(key.to_owned(), value.into());
+        }
+        let memory_stats = serde_json::from_value::<MemoryStats>(
+            serde_json::json!({"stats": stats, "usage": 1000, "limit": 2000}),
+        )
+        .unwrap();
+
+        let result = MemoryBreakdown::new(&memory_stats).unwrap();
+        assert!(result.v2);
+        assert_eq!(result.anon.get(), 250);
+        assert_eq!(result.file.get(), 700);
+        assert_eq!(result.kernel.map(ByteStats::get), Some(50));
+        assert_eq!(result.mapped_file.get(), 20);
+        assert_eq!(result.shmem.get(), 5);
+        assert_eq!(result.limit.get(), 2000);
+        assert_eq!(result.working_set().get(), 600);
+
+        // Windows containers have no cgroup stats
+        let memory_stats =
+            serde_json::from_value::<MemoryStats>(serde_json::json!({"privateworkingset": 100}))
+                .unwrap();
+        assert!(MemoryBreakdown::new(&memory_stats).is_none());
+    }
+
+    #[test]
+    /// Each core's usage is its share of the system usage, per core, & nothing is reported without the per cpu usage
+    fn test_container_state_cpu_cores() {
+        let cpu_stats = |percpu: Option<Vec<u64>>, system: u64| {
+            serde_json::from_value::<CPUStats>(serde_json::json!({
+                "cpu_usage": {
+                    "percpu_usage": percpu,
+                    "usage_in_usermode": 0,
+                    "total_usage": 0,
+                    "usage_in_kernelmode": 0
+                },
+                "system_cpu_usage": system,
+                "online_cpus": 4,
+                "throttling_data": {"periods": 0, "throttled_periods": 0, "throttled_time": 0}
+            }))
+            .unwrap()
+        };
+        let cores = CpuCores::new(
+            &cpu_stats(Some(vec![1100, 350, 100, 200]), 5000),
+            &cpu_stats(Some(vec![100, 100, 100, 150]), 1000),
+        )
+        .unwrap();
+        let usage = cores.cores.iter().map(Stats::get_value).collect::<Vec<_>>();
+        assert_eq!(usage, [100.0, 25.0, 0.0, 5.0]);
+        assert_eq!(cores.busiest().unwrap().0, 0);
+        assert!((cores.total().get_value() - 130.0).abs() < 0.01);
+
+        // cgroup v2 doesn't report the per cpu usage
+        assert!(CpuCores::new(&cpu_stats(None, 5000), &cpu_stats(None, 1000)).is_none());
+        assert!(CpuCores::default().busiest().is_none());
+    }
+
+    #[test]
+    /// Traffic is counted from the first sample, keeps counting across a container restart, & can be reset
+    fn test_container_state_traffic() {
+        let mut traffic = Traffic::default();
+        traffic.count(1000, 500, 10);
+        assert_eq!(traffic.total(), 0);
+        assert_eq!(traffic.since, 10);
+
+        traffic.count(1500, 700, 20);
+        assert_eq!((traffic.rx.get(), traffic.tx.get()), (500, 200));
+
+        // restarted, so the counters start again from zero
+        traffic.count(100, 50, 30);
+        assert_eq!((traffic.rx.get(), traffic.tx.get()), (600, 250));
+        assert_eq!(traffic.since, 10);
+
+        traffic.reset(40);
+        assert_eq!(traffic.total(), 0);
+        assert_eq!(traffic.since, 40);
+        traffic.count(300, 50, 50);
+        assert_eq!((traffic.rx.get(), traffic.tx.get()), (200, 0));
+    }
+
+    #[test]
+    /// Throttling is a percentage of the periods since the last sample, only shown with a cpu quota, & counts again from zero after a restart
+    fn test_container_state_throttling() {
+        let mut throttling = Throttling::default();
+        throttling.count(0, 0, 0);
+        assert!(!throttling.has_quota());
+        assert_eq!(throttling.to_string(), "");
+
+        // the first sample only sets the start point
+        let mut throttling = Throttling::default();
+        throttling.count(100, 10, 2_000_000_000);
+        assert!(throttling.has_quota());
+        assert_eq!(throttling.percent(), None);
+        assert_eq!(throttling.to_string(), "0% 2s");
+
+        throttling.count(200, 85, 90_000_000_000);
+        assert_eq!(throttling.percent(), Some(75));
+        assert_eq!(throttling.to_string(), "75% 1m 30s");
+
+        // idle, so no periods
+        throttling.count(200, 85, 90_000_000_000);
+        assert_eq!(throttling.percent(), None);
+
+        // restarted, so the counters start again from zero
+        throttling.count(40, 10, 1_000_000_000);
+        assert_eq!(throttling.percent(), Some(25));
+        assert_eq!(throttling.to_string(), "25% 1s");
+    }
+
+    #[test]
+    /// Restarts are counted from the second inspect, three within the window is a crash loop, as is restarting after three restarts before oxker started
+    fn test_container_state_exit_info() {
+        let mut info = ExitInfo::default();
+        info.set(Some(1), 5, 100);
+        assert!(!info.is_crash_loop(false, 100));
+        assert!(info.is_crash_loop(true, 100));
+
+        let mut info = ExitInfo::default();
+        info.set(Some(0), 0, 100);
+        info.stale = true;
+        info.set(Some(1), 1, 110);
+        assert!(!info.stale);
+        info.set(Some(1), 3, 120);
+        assert_eq!((info.exit_code, info.restart_count), (Some(1), Some(3)));
+        assert!(info.is_crash_loop(false, 120));
+        assert!(!info.is_crash_loop(false, 711));
+
+        // restarts outside of the window are dropped
+        info.set(Some(137), 4, 800);
+        assert!(!info.is_crash_loop(false, 800));
+        assert!(info.is_crash_loop(true, 800));
+    }
+
+    #[test]
+    /// Only a start after a die is a restart, three within the window is a storm, which is alerted once, until it calms down
+    fn test_container_state_restart_storm() {
+        let mut storm = RestartStorm::default();
+        assert!(!storm.start(100));
+        for now in [110, 120] {
+            storm.die();
+            assert!(!storm.start(now));
+        }
+        assert_eq!(storm.storm(), None);
+        assert_eq!(storm.badge(), None);
+
+        storm.die();
+        assert!(storm.start(130));
+        assert_eq!(storm.storm(), Some(3));
+        assert_eq!(storm.badge().as_deref(), Some("restarted 3x in 10m"));
+        assert_eq!(
+            storm.alert_text().as_deref(),
+            Some("restarted 3 times in the last 10 minutes")
+        );
+        storm.die();
+        assert!(!storm.start(140));
+        assert_eq!(storm.storm(), Some(4));
+
+        // The restarts outside of the window are dropped, & the next storm is alerted again
+        storm.expire(745);
+        assert_eq!(storm.storm(), None);
+        for now in [746, 747] {
+            storm.die();
+            assert!(!storm.start(now));
+        }
+        storm.die();
+        assert!(storm.start(748));
+    }
+
+    #[test]
+    /// The oxker labels are read, empty labels, & colors that can't be parsed, are ignored
+    fn test_container_state_label_hints() {
+        assert!(!LabelHints::is_hidden(None));
+        assert_eq!(LabelHints::from_labels(None), LabelHints::default());
+        let mut labels = HashMap::from([
+            ("oxker.hide".to_owned(), " True ".to_owned()),
+            ("oxker.group".to_owned(), "backend".to_owned()),
+            ("oxker.color".to_owned(), "#ff8800".to_owned()),
+            ("oxker.url".to_owned(), " ".to_owned()),
+        ]);
+        assert!(LabelHints::is_hidden(Some(&labels)));
+        let hints = LabelHints::from_labels(Some(&labels));
+        assert_eq!(hints.group.as_deref(), Some("backend"));
+        assert_eq!(hints.color, Some(Color::Rgb(255, 136, 0)));
+        assert!(hints.url.is_none());
+
+        labels.insert("oxker.hide".to_owned(), "false".to_owned());
+        labels.insert("oxker.color".to_owned(), "magenta".to_owned());
+        assert!(!LabelHints::is_hidden(Some(&labels)));
+        assert_eq!(
+            LabelHints::from_labels(Some(&labels)).color,
+            Some(Color::Magenta)
+        );
+        labels.insert("oxker.color".to_owned(), "not a color".to_owned());
+        assert!(LabelHints::from_labels(Some(&labels)).color.is_none());
+
+        assert!(LabelHints::from_labels(Some(&labels)).logs.is_none());
+        labels.insert("oxker.logs".to_owned(), " Journald ".to_owned());
+        assert_eq!(
+            LabelHints::from_labels(Some(&labels)).logs,
+            Some(LogFallback::Journald)
+        );
+        labels.insert("oxker.logs".to_owned(), "syslog".to_owned());
+        assert!(LabelHints::from_labels(Some(&labels)).logs.is_none());
+    }
+
+    #[test]
+    /// PodInfo only generated when the pod name label is present, and used for the display name when grouped by pod
+    fn test_container_state_pod_info() {
+        assert!(PodInfo::from_labels(None).is_none());
+        let mut labels = std::collections::HashMap::from([(
+            "io.kubernetes.pod.namespace".to_owned(),
+            "kube-system".to_owned(),
+        )]);
+        assert!(PodInfo::from_labels(Some(&labels)).is_none());
+
+        labels.insert(
+            "io.kubernetes.pod.name".to_owned(),
+            "coredns-abc".to_owned(),
+        );
+        let result = PodInfo::from_labels(Some(&labels)).unwrap();
+        assert_eq!(result.key(), "kube-system/coredns-abc");
+        assert_eq!(result.container.get(), "POD");
+
+        labels.insert(
+            "io.kubernetes.container.name".to_owned(),
+            "coredns".to_owned(),
+        );
+        let mut item = crate::tests::gen_item(&crate::app_data::ContainerId::from("1"), 1);
+        item.pod = PodInfo::from_labels(Some(&labels));
+        assert_eq!(item.display_name(None), "container_1");
+        assert_eq!(item.display_name(Some(GroupBy::Image)), "container_1");
+        assert_eq!(item.display_name(Some(GroupBy::Pod)), "coredns");
+        assert_eq!(GroupBy::Pod.key(&item), "kube-system/coredns-abc");
+    }
+
+    #[test]
+    /// The project is read from the docker compose label, else from the podman-compose label
+    fn test_container_state_compose_project() {
+        assert!(compose_project(None).is_none());
+        let mut labels = HashMap::from([(
+            "io.podman.compose.project".to_owned(),
+            "pod_site".to_owned(),
+        )]);
+        assert_eq!(compose_project(Some(&labels)).as_deref(), Some("pod_site"));
+        labels.insert("com.docker.compose.project".to_owned(), "site".to_owned());
+        assert_eq!(compose_project(Some(&labels)).as_deref(), Some("site"));
+        assert!(compose_project(Some(&HashMap::new())).is_none());
+    }
+
+    #[test]
+    /// Containers are grouped by compose project, those without the label share a group, which has no project
+    fn test_container_state_group_by_project() {
+        let mut item = crate::tests::gen_item(&crate::app_data::ContainerId::from("1"), 1);
+        assert_eq!(GroupBy::Project.key(&item), "no project");
+        assert!(GroupBy::Project.project("no project").is_none());
+
+        item.project = Some("site".to_owned());
+        assert_eq!(GroupBy::Project.key(&item), "site");
+        assert_eq!(GroupBy::Project.project("site"), Some("site"));
+        assert!(GroupBy::Image.project("site").is_none());
+        assert_eq!(item.display_name(Some(GroupBy::Project)), "container_1");
+        assert_eq!(GroupBy::next(Some(GroupBy::Pod)), Some(GroupBy::Project));
+        assert_eq!(GroupBy::next(Some(GroupBy::Project)), Some(GroupBy::Label));
+        assert!(GroupBy::next(Some(GroupBy::Label)).is_none());
+    }
+
     #[test]
     /// Logs can only contain 1 entry per LogzTz
     fn test_container_state_logz() {
@@ -754,9 +2427,9 @@ mod tests {
         let mut logs = Logs::default();
         let line = log_sanitizer::remove_ansi(input);
 
-        logs.insert(ListItem::new(line.clone()), tz.clone());
-        logs.insert(ListItem::new(line.clone()), tz.clone());
-        logs.insert(ListItem::new(line), tz);
+        logs.insert(Text::from(line.clone()), tz.clone());
+        logs.insert(Text::from(line.clone()), tz.clone());
+        logs.insert(Text::from(line), tz);
 
         assert_eq!(logs.logs.items.len(), 1);
 
@@ -764,10 +2437,143 @@ mod tests {
         let tz = LogsTz::from(input);
         let line = log_sanitizer::remove_ansi(input);
 
-        logs.insert(ListItem::new(line.clone()), tz.clone());
-        logs.insert(ListItem::new(line.clone()), tz.clone());
-        logs.insert(ListItem::new(line), tz);
+        logs.insert(Text::from(line.clone()), tz.clone());
+        logs.insert(Text::from(line.clone()), tz.clone());
+        logs.insert(Text::from(line), tz);
 
         assert_eq!(logs.logs.items.len(), 2);
     }
+
+    #[test]
+    /// The filter hides lines, both those already inserted & new lines, the selected line is kept if still shown, & only shown lines are searched
+    fn test_container_state_logs_filter() {
+        let mut logs = Logs::default();
+        let insert = |logs: &mut Logs, second: usize, line: &str| {
+            let tz = LogsTz::from(format!("2023-01-14T19:13:{second:02}.0Z ").as_str());
+            logs.insert(Text::from(line.to_owned()), tz);
+        };
+        for (second, line) in ["GET /health", "error: a", "GET /users", "error: b"]
+            .iter()
+            .enumerate()
+        {
+            insert(&mut logs, second, line);
+        }
+        logs.end();
+        logs.previous();
+        logs.previous();
+        assert_eq!(logs.selected(), Some(1));
+
+        logs.set_filter(Some(LogFilter::new("error".to_owned())));
+        assert_eq!(logs.to_vec(None, |_| None).len(), 2);
+        assert_eq!(logs.get_state_title(), " 1/2");
+        assert_eq!(logs.selected(), Some(1));
+        assert_eq!(logs.filter_title().unwrap(), " - filter &error");
+
+        logs.next();
+        assert!(logs.at_end());
+        insert(&mut logs, 10, "GET /health");
+        insert(&mut logs, 11, "error: c");
+        assert_eq!(logs.len(), 6);
+        assert_eq!(logs.to_vec(None, |_| None).len(), 3);
+        assert_eq!(logs.get_state_title(), " 2/3");
+
+        logs.set_search(Some(LogSearch::new(":".to_owned(), false)));
+        assert_eq!(logs.search_title().unwrap(), " - /: 2/3");
+        logs.set_filter(Some(LogFilter::new("!b".to_owned())));
+        assert_eq!(logs.search_title().unwrap(), " - /: -/2");
+
+        logs.set_filter(Some(LogFilter::new("!GET".to_owned())));
+        assert_eq!(logs.selected(), Some(1));
+        logs.search_jump(true);
+        assert_eq!(logs.selected(), Some(3));
+
+        logs.set_filter(None);
+        assert_eq!(logs.to_vec(None, |_| None).len(), 6);
+        assert_eq!(logs.selected(), Some(3));
+        assert_eq!(logs.search_title().unwrap(), " - /: 2/3");
+    }
+
+    #[test]
+    /// The oldest lines are dropped, with the filter & search shifted to match, & can be prepended again, the selected line is never dropped
+    fn test_container_state_logs_trim() {
+        let mut logs = Logs::default();
+        let tz = |second: usize| LogsTz::from(format!("2023-01-14T19:13:{second:02}.0Z ").as_str());
+        for second in 0..20 {
+            logs.insert(Text::from(format!("line {second}")), tz(second));
+        }
+        logs.end();
+        logs.set_filter(Some(LogFilter::new("!0".to_owned())));
+        logs.set_search(Some(LogSearch::new("9".to_owned(), false)));
+        let oldest = logs.oldest().unwrap();
+
+        assert_eq!(logs.trim(0), 0);
+        assert_eq!(logs.trim(10), 11);
+        assert_eq!(logs.len(), 9);
+        assert!(logs.at_end());
+        assert_eq!(logs.to_vec(None, |_| None).len(), 9);
+        assert_eq!(logs.search_title().unwrap(), " - /9 1/1");
+        assert_eq!(logs.oldest(), Some(oldest + 11));
+
+        let lines = (5..=11)
+            .map(|second| (Text::from(format!("line {second}")), tz(second), None))
+            .collect();
+        assert_eq!(logs.prepend(lines), 6);
+        assert_eq!(logs.len(), 15);
+        assert_eq!(logs.oldest(), Some(oldest + 5));
+        assert!(logs.at_end());
+        assert_eq!(logs.search_title().unwrap(), " - /9 2/2");
+
+        logs.start();
+        assert_eq!(logs.trim(5), 0);
+        logs.next();
+        logs.next();
+        assert_eq!(logs.trim(5), 2);
+        assert_eq!(logs.selected(), Some(0));
+        assert_eq!(logs.to_vec(None, |_| None).len(), 12);
+    }
+
+    #[test]
+    /// Lines of a JSON object are rendered in the view, other lines, & gap markers, are shown as logged, the parsed lines follow a trim
+    fn test_container_state_logs_json() {
+        let mut logs = Logs::default();
+        let tz = |second: usize| LogsTz::from(format!("2023-01-14T19:13:{second:02}.0Z ").as_str());
+        logs.insert_gap(&tz(0), 1);
+        logs.insert(Text::from("plain"), tz(0));
+        logs.insert_gap(&tz(30), 1);
+        logs.insert(Text::from(r#"{"msg":"hi","level":"info"}"#), tz(30));
+        let plain = |logs: &Logs, view| {
+            logs.to_vec(view, |_| None)
+                .into_iter()
+                .map(|i| format!("{i:?}").contains("msg="))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(plain(&logs, None), [false, false, false]);
+        assert_eq!(plain(&logs, Some(JsonView::Flat)), [false, false, true]);
+        assert_eq!(logs.json.len(), 1);
+
+        assert_eq!(logs.trim(1), 2);
+        assert_eq!(plain(&logs, Some(JsonView::Flat)), [true]);
+    }
+
+    #[test]
+    /// The timestamp is put before each line as it's drawn, gap markers have none
+    fn test_container_state_logs_stamp() {
+        let mut logs = Logs::default();
+        let tz = |second: usize| LogsTz::from(format!("2023-01-14T19:13:{second:02}.0Z ").as_str());
+        logs.insert_gap(&tz(0), 1);
+        logs.insert(Text::from("first"), tz(0));
+        logs.insert_gap(&tz(30), 1);
+        logs.insert(Text::from(""), tz(30));
+        let items = logs.to_vec(None, |tz| Some(format!("[{}]", tz.to_string().trim())));
+        let plain = logs.to_vec(None, |_| None);
+        let shown = |item: &ListItem| format!("{item:?}");
+        assert_eq!(items.len(), 3);
+        assert!(shown(&items[0]).contains("[2023-01-14T19:13:00.0Z]"));
+        assert!(!shown(&plain[0]).contains("2023"));
+        assert_eq!(items[1], plain[1]);
+        assert!(shown(&items[2]).contains("[2023-01-14T19:13:30.0Z]"));
+        let (context, _) = logs.context(0, 1, |_| Some("x ".to_owned())).unwrap();
+        assert!(shown(&context[0]).contains("\"x \""));
+        assert_eq!(context[1], plain[1]);
+    }
 }
@@ -0,0 +1,132 @@
+use std::fmt;
+
+use super::ContainerId;
+use crate::ui::TimelineKind;
+
+/// Maximum number of events kept for the events pane, the oldest are dropped first
+pub const MAX_EVENTS: usize = 200;
+
+/// Seconds after a control from elsewhere, e.g. another user's oxker, or the docker cli, that a control sent to the same container is confirmed first
+pub const CONCURRENT_WINDOW: u64 = 60;
+
+/// Events up to this many seconds before one of oxker's own controls completed are taken to be caused by it, a stop waits up to 10 seconds for the container to exit
+pub const OWN_CONTROL: u64 = 30;
+
+/// What happened, a container lifecycle event, as drawn on the timeline, or an image pull
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventKind {
+    Container(TimelineKind),
+    Pull,
+}
+
+/// A single event from the Docker events stream, as listed in the events pane
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DockerEvent {
+    /// Unix timestamp, in seconds, according to the daemon
+    pub time: i64,
+    /// None for the primary host
+    pub host: Option<String>,
+    /// None for an image event
+    pub id: Option<ContainerId>,
+    /// The container name, or the image
+    pub name: String,
+    pub kind: EventKind,
+}
+
+impl DockerEvent {
+    /// The exit code of a container that has died with a non-zero exit code
+    pub const fn failed_exit(&self) -> Option<i64> {
+        match self.kind {
+            EventKind::Container(TimelineKind::Die(Some(code))) if code != 0 => Some(code),
+            _ => None,
+        }
+    }
+
+    /// A change of state that a control causes, rather than the container itself, a start is left out, as a restart policy starts a container too
+    pub const fn is_control(&self) -> bool {
+        matches!(
+            self.kind,
+            EventKind::Container(
+                TimelineKind::Kill
+                    | TimelineKind::Pause
+                    | TimelineKind::Restart
+                    | TimelineKind::Stop
+                    | TimelineKind::Unpause
+            )
+        )
+    }
+
+    /// A container has failed, it died with a non-zero exit code, was killed for running out of memory, or turned unhealthy
+    pub const fn is_alert(&self) -> bool {
+        self.failed_exit().is_some()
+            || matches!(
+                self.kind,
+                EventKind::Container(TimelineKind::Oom | TimelineKind::Unhealthy)
+            )
+    }
+}
+
+impl fmt::Display for DockerEvent {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.kind {
+            EventKind::Container(kind) => write!(f, "{} {kind}", self.name),
+            EventKind::Pull => write!(f, "{} pulled", self.name),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DockerEvent, EventKind};
+    use crate::{app_data::ContainerId, ui::TimelineKind};
+
+    #[test]
+    /// Only a die event with a non-zero exit code is a failed exit
+    fn test_docker_event_failed_exit() {
+        let event = |kind| DockerEvent {
+            time: 0,
+            host: None,
+            id: Some(ContainerId::from("1")),
+            name: "api".to_owned(),
+            kind,
+        };
+        let died = event(EventKind::Container(TimelineKind::Die(Some(137))));
+        assert_eq!(died.failed_exit(), Some(137));
+        assert_eq!(died.to_string(), "api died, exit code 137");
+        for kind in [
+            EventKind::Container(TimelineKind::Die(Some(0))),
+            EventKind::Container(TimelineKind::Die(None)),
+            EventKind::Container(TimelineKind::Oom),
+            EventKind::Pull,
+        ] {
+            assert!(event(kind).failed_exit().is_none());
+        }
+        assert_eq!(event(EventKind::Pull).to_string(), "api pulled");
+    }
+
+    #[test]
+    /// A failed exit, an out of memory kill, & turning unhealthy, are alerts, a clean exit isn't
+    fn test_docker_event_is_alert() {
+        let event = |kind| DockerEvent {
+            time: 0,
+            host: None,
+            id: Some(ContainerId::from("1")),
+            name: "api".to_owned(),
+            kind: EventKind::Container(kind),
+        };
+        for kind in [
+            TimelineKind::Die(Some(1)),
+            TimelineKind::Oom,
+            TimelineKind::Unhealthy,
+        ] {
+            assert!(event(kind).is_alert());
+        }
+        for kind in [
+            TimelineKind::Die(Some(0)),
+            TimelineKind::Healthy,
+            TimelineKind::Stop,
+        ] {
+            assert!(!event(kind).is_alert());
+        }
+    }
+}
@@ -0,0 +1,237 @@
+use std::{
+    collections::HashMap,
+    fmt::Write as _,
+    path::{Path, PathBuf},
+};
+
+use super::cache::{escape, unescape};
+
+/// First line of the exec history file, changed whenever the format changes
+const HISTORY_HEADER: &str = "oxker-exec-history 2";
+
+/// Number of commands kept for each image
+const HISTORY_LEN: usize = 20;
+
+/// A command run in a container, with the options of its exec, as typed into the command form
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ExecEntry {
+    pub command: String,
+    /// Space separated `KEY=VALUE` variables
+    pub env: String,
+    /// Run by the container's shell, else exec'd as is, as a distroless container has no shell
+    pub shell: bool,
+    /// As with `docker exec -u`, a name, or uid, with an optional group
+    pub user: String,
+    pub working_dir: String,
+}
+
+impl ExecEntry {
+    /// A command, without any options, run by the shell
+    pub fn shell(command: &str) -> Self {
+        Self {
+            command: command.to_owned(),
+            shell: true,
+            ..Self::default()
+        }
+    }
+
+    /// The variables, each must be `KEY=VALUE`, with a key
+    pub fn env_pairs(&self) -> Result<Vec<String>, String> {
+        self.env
+            .split_whitespace()
+            .map(|i| match i.split_once('=') {
+                Some((key, _)) if !key.is_empty() => Ok(i.to_owned()),
+                _ => Err(format!("{i}: expected a variable as KEY=VALUE")),
+            })
+            .collect()
+    }
+
+    /// The options, as `docker exec` flags, e.g. `-u postgres -w /tmp`, a command run without the shell is marked
+    pub fn flags(&self) -> String {
+        let mut flags = vec![];
+        if !self.user.trim().is_empty() {
+            flags.push(format!("-u {}", self.user.trim()));
+        }
+        if !self.working_dir.trim().is_empty() {
+            flags.push(format!("-w {}", self.working_dir.trim()));
+        }
+        flags.extend(self.env.split_whitespace().map(|i| format!("-e {i}")));
+        if !self.shell {
+            flags.push("no shell".to_owned());
+        }
+        flags.join(" ")
+    }
+}
+
+/// Location of the exec history file, in the state directory, or the local data directory on platforms without one
+/// Commands are keyed by image, so that they survive a container being recreated, & are shared by every container of the image
+pub fn path() -> Option<PathBuf> {
+    directories::BaseDirs::new().map(|i| {
+        i.state_dir()
+            .unwrap_or_else(|| i.data_local_dir())
+            .join(env!("CARGO_PKG_NAME"))
+            .join("exec_history")
+    })
+}
+
+/// Add a command to the front of an image's history, a command that was already run, with the same options, is moved to the front, rather than repeated
+pub fn record(history: &mut Vec<ExecEntry>, entry: &ExecEntry) {
+    history.retain(|i| i != entry);
+    history.insert(0, entry.clone());
+    history.truncate(HISTORY_LEN);
+}
+
+/// Convert the history into the file format, one command per line, image, command, user, working directory, env, & shell, most recent first, sorted by image so that the file is stable
+fn to_history(history: &HashMap<String, Vec<ExecEntry>>) -> String {
+    let mut output = format!("{HISTORY_HEADER}\n");
+    let mut images = history.keys().collect::<Vec<_>>();
+    images.sort();
+    for image in images {
+        for entry in &history[image] {
+            writeln!(
+                output,
+                "{}\t{}\t{}\t{}\t{}\t{}",
+                escape(image),
+                escape(&entry.command),
+                escape(&entry.user),
+                escape(&entry.working_dir),
+                escape(&entry.env),
+                entry.shell
+            )
+            .ok();
+        }
+    }
+    output
+}
+
+/// Parse a single line of the history file
+fn from_line(line: &str) -> Option<(String, ExecEntry)> {
+    let fields = line.split('\t').collect::<Vec<_>>();
+    let [image, command, user, working_dir, env, shell] = fields.as_slice() else {
+        return None;
+    };
+    let entry = ExecEntry {
+        command: unescape(command),
+        env: unescape(env),
+        shell: shell.parse().ok()?,
+        user: unescape(user),
+        working_dir: unescape(working_dir),
+    };
+    Some((unescape(image), entry))
+        .filter(|(image, entry)| !image.is_empty() && !entry.command.is_empty())
+}
+
+/// Parse the history file format, None if the header doesn't match, invalid lines are skipped
+fn from_history(input: &str) -> Option<HashMap<String, Vec<ExecEntry>>> {
+    let mut lines = input.lines();
+    if lines.next() != Some(HISTORY_HEADER) {
+        return None;
+    }
+    let mut output = HashMap::<String, Vec<ExecEntry>>::new();
+    for (image, entry) in lines.filter_map(from_line) {
+        let entries = output.entry(image).or_default();
+        if entries.len() < HISTORY_LEN && !entries.contains(&entry) {
+            entries.push(entry);
+        }
+    }
+    Some(output)
+}
+
+/// Load the exec history, empty if there is no history file, or it can't be read
+pub fn load(path: &Path) -> HashMap<String, Vec<ExecEntry>> {
+    std::fs::read_to_string(path)
+        .ok()
+        .and_then(|i| from_history(&i))
+        .unwrap_or_default()
+}
+
+/// Save the exec history, creating the state directory if needed
+pub fn save(path: &Path, history: &HashMap<String, Vec<ExecEntry>>) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(path, to_history(history))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::{from_history, record, to_history, ExecEntry, HISTORY_HEADER, HISTORY_LEN};
+
+    #[test]
+    /// Commands are recorded most recent first, without repeats, & survive a round trip through the file format
+    fn test_exec_history_round_trip() {
+        let mut commands = vec![];
+        record(&mut commands, &ExecEntry::shell("ls -la /tmp"));
+        record(&mut commands, &ExecEntry::shell("cat /etc/hosts"));
+        record(&mut commands, &ExecEntry::shell("ls -la /tmp"));
+        assert_eq!(
+            commands,
+            [
+                ExecEntry::shell("ls -la /tmp"),
+                ExecEntry::shell("cat /etc/hosts")
+            ]
+        );
+        for i in 0..HISTORY_LEN {
+            record(&mut commands, &ExecEntry::shell(&format!("echo {i}")));
+        }
+        assert_eq!(commands.len(), HISTORY_LEN);
+        assert_eq!(commands[0].command, format!("echo {}", HISTORY_LEN - 1));
+
+        let history = HashMap::from([
+            (
+                "nginx:latest".to_owned(),
+                vec![ExecEntry::shell("curl -s localhost:8080/health")],
+            ),
+            (
+                "postgres:16".to_owned(),
+                vec![
+                    ExecEntry {
+                        command: "psql".to_owned(),
+                        env: "PGPASSWORD=secret PAGER=".to_owned(),
+                        shell: false,
+                        user: "postgres".to_owned(),
+                        working_dir: "/tmp".to_owned(),
+                    },
+                    ExecEntry::shell("printf 'a\tb'"),
+                ],
+            ),
+        ]);
+        let output = to_history(&history);
+        assert!(output.starts_with(&format!("{HISTORY_HEADER}\nnginx:latest\t")));
+        assert_eq!(from_history(&output).unwrap(), history);
+
+        assert!(from_history("oxker-exec-history 1\napi\tls").is_none());
+        let result = from_history(&format!(
+            "{HISTORY_HEADER}\napi\tls\napi\tls\t\t\t\ttrue\napi\tls\t\t\t\ttrue\n\tls\t\t\t\ttrue\napi\tls\t\t\t\tmaybe"
+        ))
+        .unwrap();
+        assert_eq!(result.len(), 1);
+        assert_eq!(result["api"], [ExecEntry::shell("ls")]);
+    }
+
+    #[test]
+    /// Variables need a key, the options are shown as docker exec flags
+    fn test_exec_history_entry() {
+        let mut entry = ExecEntry {
+            command: "/app/server --check".to_owned(),
+            env: "RUST_LOG=debug EMPTY=".to_owned(),
+            shell: false,
+            user: "1000:1000".to_owned(),
+            working_dir: String::new(),
+        };
+        assert_eq!(entry.env_pairs().unwrap(), ["RUST_LOG=debug", "EMPTY="]);
+        assert_eq!(
+            entry.flags(),
+            "-u 1000:1000 -e RUST_LOG=debug -e EMPTY= no shell"
+        );
+        entry.env = "RUST_LOG=debug =x".to_owned();
+        assert_eq!(
+            entry.env_pairs().unwrap_err(),
+            "=x: expected a variable as KEY=VALUE"
+        );
+        assert_eq!(ExecEntry::shell("ls").flags(), "");
+    }
+}
@@ -0,0 +1,108 @@
+use super::ByteStats;
+
+/// Length of the short image id, as shown by `docker images`
+const SHORT_ID: usize = 12;
+
+/// A local image, as listed in the images panel
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImageItem {
+    /// Names of the containers, running or not, that were created from the image
+    pub containers: Vec<String>,
+    /// Unix timestamp, in seconds
+    pub created: i64,
+    pub id: String,
+    pub size: ByteStats,
+    /// Each `repository:tag`, empty for a dangling image
+    pub tags: Vec<String>,
+}
+
+impl ImageItem {
+    /// A dangling image has no tags, usually an image that was replaced by a newer build or pull of its tag
+    pub const fn is_dangling(&self) -> bool {
+        self.tags.is_empty()
+    }
+
+    /// The image id, without the `sha256:` prefix, truncated, as shown by `docker images`
+    pub fn short_id(&self) -> &str {
+        let id = self.id.strip_prefix("sha256:").unwrap_or(&self.id);
+        id.get(..SHORT_ID).unwrap_or(id)
+    }
+
+    /// The name of the image in messages, its first tag, or its short id if dangling
+    pub fn name(&self) -> &str {
+        self.tags
+            .first()
+            .map_or_else(|| self.short_id(), String::as_str)
+    }
+
+    /// The repository & tag of the first tag, `<none>` for a dangling image, a registry port, e.g. `localhost:5000/app`, isn't mistaken for a tag
+    pub fn repository_tag(&self) -> (&str, &str) {
+        self.tags
+            .first()
+            .map_or(("<none>", "<none>"), |image| match image.rsplit_once(':') {
+                Some((repository, tag)) if !tag.contains('/') => (repository, tag),
+                _ => (image.as_str(), "latest"),
+            })
+    }
+
+    /// Whether the image is in use, and by how many containers, or dangling, as shown in the images panel
+    pub fn state_text(&self) -> String {
+        match (self.containers.len(), self.is_dangling()) {
+            (0, true) => "dangling".to_owned(),
+            (x, _) => in_use_text(x),
+        }
+    }
+}
+
+/// How many containers use an image, volume, or network, as shown in the images, volumes, & networks panels
+pub fn in_use_text(containers: usize) -> String {
+    match containers {
+        0 => "unused".to_owned(),
+        1 => "1 container".to_owned(),
+        x => format!("{x} containers"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ImageItem;
+    use crate::app_data::ByteStats;
+
+    fn gen_image(tags: &[&str], containers: &[&str]) -> ImageItem {
+        ImageItem {
+            containers: containers.iter().map(|i| (*i).to_owned()).collect(),
+            created: 0,
+            id: "sha256:4b40a3e09a3c8bd9716b3da8b1f9fc2e3ae9e2f3a0a7b2d7e1c3f5a6b7c8d9e0"
+                .to_owned(),
+            size: ByteStats::new(0),
+            tags: tags.iter().map(|i| (*i).to_owned()).collect(),
+        }
+    }
+
+    #[test]
+    /// Tags are split into repository & tag, including those with a registry port, dangling images have no tag
+    fn test_image_item() {
+        let image = gen_image(&["redis:7"], &["cache"]);
+        assert_eq!(image.short_id(), "4b40a3e09a3c");
+        assert_eq!(image.name(), "redis:7");
+        assert_eq!(image.repository_tag(), ("redis", "7"));
+        assert_eq!(image.state_text(), "1 container");
+
+        let image = gen_image(&["localhost:5000/team/api"], &["api-1", "api-2"]);
+        assert_eq!(
+            image.repository_tag(),
+            ("localhost:5000/team/api", "latest")
+        );
+        assert_eq!(image.state_text(), "2 containers");
+
+        let image = gen_image(&["localhost:5000/api:v1.2"], &[]);
+        assert_eq!(image.repository_tag(), ("localhost:5000/api", "v1.2"));
+        assert_eq!(image.state_text(), "unused");
+
+        let image = gen_image(&[], &[]);
+        assert!(image.is_dangling());
+        assert_eq!(image.name(), "4b40a3e09a3c");
+        assert_eq!(image.repository_tag(), ("<none>", "<none>"));
+        assert_eq!(image.state_text(), "dangling");
+    }
+}
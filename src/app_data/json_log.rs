@@ -0,0 +1,278 @@
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::{Line, Span, Text},
+};
+use serde_json::{Map, Value};
+
+/// Keys of the level of a structured log line, as set by the common logging libraries, the first that's found is used
+const LEVEL_KEYS: [&str; 5] = ["level", "severity", "lvl", "log.level", "loglevel"];
+
+/// Keys shown before any other key, the time, level, & message, the rest are in name order
+const FIRST_KEYS: [&str; 11] = [
+    "time",
+    "timestamp",
+    "ts",
+    "@timestamp",
+    "level",
+    "severity",
+    "lvl",
+    "log.level",
+    "loglevel",
+    "msg",
+    "message",
+];
+
+/// How log lines of a single JSON object are shown, set with ( { ), otherwise they're shown as logged
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum JsonView {
+    /// Indented, a key on each line
+    Pretty,
+    /// A `key=value` pair for each key, nested keys are joined with a dot
+    Flat,
+}
+
+impl JsonView {
+    /// Cycle through the views, off -> pretty -> flat -> off
+    pub const fn next(current: Option<Self>) -> Option<Self> {
+        match current {
+            None => Some(Self::Pretty),
+            Some(Self::Pretty) => Some(Self::Flat),
+            Some(Self::Flat) => None,
+        }
+    }
+
+    /// Shown in the logs title, so that it's clear the lines aren't as logged
+    pub const fn title(self) -> &'static str {
+        match self {
+            Self::Pretty => " - json",
+            Self::Flat => " - key=value",
+        }
+    }
+}
+
+/// The style of the level of a log line, errors in red, warnings in yellow, info in green, & debug dimmed
+fn level_style(level: &Value) -> Option<Style> {
+    let color = |i: Color| Some(Style::default().fg(i).add_modifier(Modifier::BOLD));
+    // Pino, & bunyan, levels are numbers, 10 is trace, up to 60, fatal
+    if let Some(number) = level.as_u64() {
+        return match number {
+            50.. => color(Color::Red),
+            40..=49 => color(Color::Yellow),
+            30..=39 => color(Color::Green),
+            _ => Some(Style::default().add_modifier(Modifier::DIM)),
+        };
+    }
+    match level.as_str()?.to_lowercase().as_str() {
+        "error" | "err" | "fatal" | "critical" | "crit" | "panic" | "alert" | "emerg"
+        | "emergency" => color(Color::Red),
+        "warn" | "warning" => color(Color::Yellow),
+        "info" | "information" | "notice" => color(Color::Green),
+        "debug" | "trace" => Some(Style::default().add_modifier(Modifier::DIM)),
+        _ => None,
+    }
+}
+
+/// A string value is shown without quotes, unless it's empty, or contains a space, a `=`, or a quote
+fn flat_value(value: &Value) -> String {
+    match value {
+        Value::String(text)
+            if !text.is_empty()
+                && !text
+                    .chars()
+                    .any(|i| i.is_whitespace() || i == '=' || i == '"') =>
+        {
+            text.clone()
+        }
+        value => value.to_string(),
+    }
+}
+
+/// A log line of a single JSON object, any text before the object, such as a timestamp, is kept
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JsonLog {
+    fields: Map<String, Value>,
+    prefix: String,
+}
+
+impl JsonLog {
+    /// Parse a line that ends with a JSON object, None if it doesn't
+    pub fn parse(line: &str) -> Option<Self> {
+        let line = line.trim_end();
+        if !line.ends_with('}') {
+            return None;
+        }
+        let start = line.find('{')?;
+        let Ok(Value::Object(fields)) = serde_json::from_str(&line[start..]) else {
+            return None;
+        };
+        Some(Self {
+            fields,
+            prefix: line[..start].to_owned(),
+        })
+    }
+
+    /// The keys in the order shown, the time, level, & message first
+    fn keys(&self) -> impl Iterator<Item = &String> {
+        let first = FIRST_KEYS
+            .iter()
+            .filter_map(|i| self.fields.get_key_value(*i).map(|(key, _)| key));
+        let rest = self
+            .fields
+            .keys()
+            .filter(|i| !FIRST_KEYS.contains(&i.as_str()));
+        first.chain(rest)
+    }
+
+    /// The style of the level, if the line has a level
+    fn level(&self) -> Option<(&str, Style)> {
+        LEVEL_KEYS.iter().find_map(|key| {
+            self.fields
+                .get(*key)
+                .and_then(level_style)
+                .map(|style| (*key, style))
+        })
+    }
+
+    /// Flatten nested objects into `parent.child` keys
+    fn flatten(key: String, value: &Value, output: &mut Vec<(String, String)>) {
+        match value {
+            Value::Object(fields) if !fields.is_empty() => {
+                for (child, value) in fields {
+                    Self::flatten(format!("{key}.{child}"), value, output);
+                }
+            }
+            value => output.push((key, flat_value(value))),
+        }
+    }
+
+    pub fn render(&self, view: JsonView) -> Text<'static> {
+        let level = self.level();
+        let style = |key: &str| {
+            level
+                .filter(|(level, _)| *level == key)
+                .map(|(_, style)| style)
+                .unwrap_or_default()
+        };
+        match view {
+            JsonView::Pretty => {
+                let mut lines = vec![Line::from(format!("{}{{", self.prefix))];
+                let len = self.fields.len();
+                for (index, key) in self.keys().enumerate() {
+                    let value = serde_json::to_string_pretty(&self.fields[key])
+                        .unwrap_or_default()
+                        .replace('\n', "\n  ");
+                    let comma = if index + 1 < len { "," } else { "" };
+                    let mut value_lines = value.lines();
+                    lines.push(Line::from(vec![
+                        Span::from(format!("  {}: ", Value::from(key.as_str()))),
+                        Span::styled(
+                            value_lines.next().unwrap_or_default().to_owned(),
+                            style(key),
+                        ),
+                    ]));
+                    lines.extend(value_lines.map(|i| Line::from(i.to_owned())));
+                    if let Some(last) = lines.last_mut() {
+                        last.spans.push(Span::from(comma));
+                    }
+                }
+                lines.push(Line::from("}"));
+                Text::from(lines)
+            }
+            JsonView::Flat => {
+                let mut spans = vec![Span::from(self.prefix.clone())];
+                for (index, key) in self.keys().enumerate() {
+                    let mut pairs = vec![];
+                    Self::flatten(key.clone(), &self.fields[key], &mut pairs);
+                    for (pair, (flat_key, value)) in pairs.into_iter().enumerate() {
+                        if index + pair > 0 {
+                            spans.push(Span::from(" "));
+                        }
+                        spans.push(Span::styled(
+                            format!("{flat_key}="),
+                            Style::default().add_modifier(Modifier::DIM),
+                        ));
+                        spans.push(Span::styled(value, style(key)));
+                    }
+                }
+                Text::from(Line::from(spans))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use ratatui::style::{Color, Modifier};
+
+    use super::{JsonLog, JsonView};
+    use crate::app_data::log_search;
+
+    #[test]
+    /// Only a line that ends with a JSON object is parsed, a prefix, such as a timestamp, is kept
+    fn test_json_log_parse() {
+        assert!(JsonLog::parse("plain text").is_none());
+        assert!(JsonLog::parse("[1, 2]").is_none());
+        assert!(JsonLog::parse("{not json}").is_none());
+        let line = JsonLog::parse("2024-01-01T00:00:00Z {\"a\":1}\n").unwrap();
+        assert_eq!(line.prefix, "2024-01-01T00:00:00Z ");
+        assert_eq!(JsonView::next(None), Some(JsonView::Pretty));
+        assert_eq!(JsonView::next(Some(JsonView::Flat)), None);
+    }
+
+    #[test]
+    /// The time, level, & message come first, nested keys are flattened, & the level is colored
+    fn test_json_log_render() {
+        let line = JsonLog::parse(
+            r#"{"z":true,"msg":"db down","level":"error","ctx":{"id":7,"host":"a b"},"tags":[]}"#,
+        )
+        .unwrap();
+        let text = line.render(JsonView::Flat);
+        assert_eq!(
+            log_search::plain(&text),
+            r#"level=error msg="db down" ctx.host="a b" ctx.id=7 tags=[] z=true"#
+        );
+        let level = &text.lines[0].spans[2];
+        assert_eq!(level.content, "error");
+        assert_eq!(level.style.fg, Some(Color::Red));
+
+        let text = line.render(JsonView::Pretty);
+        let lines = text
+            .lines
+            .iter()
+            .map(|i| {
+                i.spans
+                    .iter()
+                    .map(|i| i.content.as_ref())
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            lines,
+            [
+                "{",
+                r#"  "level": "error","#,
+                r#"  "msg": "db down","#,
+                r#"  "ctx": {"#,
+                r#"    "host": "a b","#,
+                r#"    "id": 7"#,
+                "  },",
+                r#"  "tags": [],"#,
+                r#"  "z": true"#,
+                "}",
+            ]
+        );
+
+        let text = JsonLog::parse(r#"{"level":30,"msg":"ok"}"#)
+            .unwrap()
+            .render(JsonView::Flat);
+        assert_eq!(text.lines[0].spans[2].style.fg, Some(Color::Green));
+        let text = JsonLog::parse(r#"{"severity":"debug"}"#)
+            .unwrap()
+            .render(JsonView::Flat);
+        assert!(text.lines[0].spans[2]
+            .style
+            .add_modifier
+            .contains(Modifier::DIM));
+    }
+}
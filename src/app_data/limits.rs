@@ -0,0 +1,185 @@
+use bollard::service::HostConfig;
+
+const NANOS: u64 = 1_000_000_000;
+
+/// The cpu & memory limits configured on a container, from an inspect, None where the container is unlimited
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Limits {
+    /// Billionths of a cpu, as set by `--cpus`, or the equivalent of `--cpu-quota` over `--cpu-period`
+    pub nano_cpus: Option<u64>,
+    /// Bytes, as set by `--memory`
+    pub memory: Option<u64>,
+}
+
+impl From<&HostConfig> for Limits {
+    fn from(config: &HostConfig) -> Self {
+        let positive = |i: Option<i64>| i.and_then(|i| u64::try_from(i).ok()).filter(|i| *i > 0);
+        let quota = positive(config.cpu_quota).map(|quota| {
+            // The daemon uses a period of 100ms when only a quota is set
+            let period = positive(config.cpu_period).unwrap_or(100_000);
+            quota * NANOS / period
+        });
+        Self {
+            nano_cpus: positive(config.nano_cpus).or(quota),
+            memory: positive(config.memory),
+        }
+    }
+}
+
+impl Limits {
+    /// The cpu limit as a percentage, of a single cpu, to match the cpu usage, e.g. `150%` for `--cpus 1.5`
+    pub fn cpu_text(self) -> Option<String> {
+        self.nano_cpus.map(|i| format!("{}%", i / 10_000_000))
+    }
+
+    /// The cpu limit as given to `--cpus`
+    pub fn cpus(self) -> Option<String> {
+        self.nano_cpus.map(|i| {
+            let whole = i / NANOS;
+            let fraction = format!("{:09}", i % NANOS);
+            let fraction = fraction.trim_end_matches('0');
+            if fraction.is_empty() {
+                whole.to_string()
+            } else {
+                format!("{whole}.{fraction}")
+            }
+        })
+    }
+
+    /// The memory limit as given to `--memory`, in the largest unit that it's a whole number of
+    pub fn memory_text(self) -> Option<String> {
+        self.memory.map(|bytes| {
+            ["g", "m", "k"]
+                .iter()
+                .zip([1 << 30, 1 << 20, 1 << 10])
+                .find(|(_, size)| bytes % size == 0)
+                .map_or_else(
+                    || bytes.to_string(),
+                    |(unit, size)| format!("{}{unit}", bytes / size),
+                )
+        })
+    }
+}
+
+/// Parse a number of cpus, as given to `--cpus`, e.g. `1.5`, into billionths of a cpu, an empty value leaves the limit unchanged
+pub fn parse_cpus(input: &str) -> Result<Option<u64>, String> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Ok(None);
+    }
+    let invalid = || format!("{input}: expected a number of cpus, e.g. 1.5");
+    let (whole, fraction) = input.split_once('.').unwrap_or((input, ""));
+    if fraction.len() > 9
+        || !whole
+            .chars()
+            .chain(fraction.chars())
+            .all(|i| i.is_ascii_digit())
+        || whole.is_empty() && fraction.is_empty()
+    {
+        return Err(invalid());
+    }
+    let whole = if whole.is_empty() {
+        0
+    } else {
+        whole.parse::<u64>().map_err(|_| invalid())?
+    };
+    let fraction = format!("{fraction:0<9}")
+        .parse::<u64>()
+        .map_err(|_| invalid())?;
+    let nanos = whole
+        .checked_mul(NANOS)
+        .and_then(|i| i.checked_add(fraction))
+        .filter(|i| *i > 0)
+        .ok_or_else(invalid)?;
+    Ok(Some(nanos))
+}
+
+/// Parse a size of memory, as given to `--memory`, e.g. `512m`, into bytes, an empty value leaves the limit unchanged
+/// The daemon refuses a limit under 6MB
+pub fn parse_memory(input: &str) -> Result<Option<u64>, String> {
+    let input = input.trim().to_lowercase();
+    if input.is_empty() {
+        return Ok(None);
+    }
+    let invalid = || format!("{input}: expected a size of memory, e.g. 512m");
+    let number = input.trim_end_matches(['b', 'k', 'm', 'g']);
+    let size = match input[number.len()..].trim_end_matches('b') {
+        "" => 1,
+        "k" => 1 << 10,
+        "m" => 1 << 20,
+        "g" => 1 << 30,
+        _ => return Err(invalid()),
+    };
+    number
+        .parse::<u64>()
+        .ok()
+        .and_then(|i| i.checked_mul(size))
+        .filter(|i| *i > 0)
+        .map(Some)
+        .ok_or_else(invalid)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use bollard::service::HostConfig;
+
+    use super::{parse_cpus, parse_memory, Limits};
+
+    #[test]
+    /// The cpu limit is from the nano cpus, or else the quota over the period, & unset limits are None
+    fn test_limits_from_host_config() {
+        let limits = Limits::from(&HostConfig {
+            nano_cpus: Some(1_500_000_000),
+            memory: Some(1 << 30),
+            ..Default::default()
+        });
+        assert_eq!(limits.cpu_text().unwrap(), "150%");
+        assert_eq!(limits.cpus().unwrap(), "1.5");
+        assert_eq!(limits.memory_text().unwrap(), "1g");
+
+        let limits = Limits::from(&HostConfig {
+            cpu_quota: Some(50_000),
+            memory: Some(0),
+            ..Default::default()
+        });
+        assert_eq!(limits.cpus().unwrap(), "0.5");
+        assert_eq!(limits.memory, None);
+
+        let limits = Limits::from(&HostConfig {
+            cpu_quota: Some(25_000),
+            cpu_period: Some(50_000),
+            memory: Some(300 << 20),
+            ..Default::default()
+        });
+        assert_eq!(limits.cpus().unwrap(), "0.5");
+        assert_eq!(limits.memory_text().unwrap(), "300m");
+
+        assert_eq!(Limits::from(&HostConfig::default()), Limits::default());
+    }
+
+    #[test]
+    /// Cpus are parsed to the nanocpu, an empty value is unchanged, & a zero, or malformed, value is an error
+    fn test_limits_parse_cpus() {
+        assert_eq!(parse_cpus(" 2 ").unwrap(), Some(2_000_000_000));
+        assert_eq!(parse_cpus("0.25").unwrap(), Some(250_000_000));
+        assert_eq!(parse_cpus(".5").unwrap(), Some(500_000_000));
+        assert_eq!(parse_cpus("").unwrap(), None);
+        for input in ["0", "-1", "1.5.5", "a", ".", "0.0000000001"] {
+            assert!(parse_cpus(input).is_err(), "{input}");
+        }
+    }
+
+    #[test]
+    /// Memory is parsed with an optional unit, as docker does, an empty value is unchanged
+    fn test_limits_parse_memory() {
+        assert_eq!(parse_memory("512m").unwrap(), Some(512 << 20));
+        assert_eq!(parse_memory("1G").unwrap(), Some(1 << 30));
+        assert_eq!(parse_memory("64kb").unwrap(), Some(64 << 10));
+        assert_eq!(parse_memory("1048576").unwrap(), Some(1 << 20));
+        assert_eq!(parse_memory("").unwrap(), None);
+        for input in ["0", "m", "1t", "1.5g", "-1g"] {
+            assert!(parse_memory(input).is_err(), "{input}");
+        }
+    }
+}
@@ -0,0 +1,124 @@
+use super::log_watch::Pattern;
+
+/// Split on each whitespace that isn't escaped, so that `\ ` can match a space
+fn split_terms(input: &str) -> Vec<String> {
+    let mut output = vec![];
+    let mut term = String::new();
+    let mut chars = input.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                term.push(c);
+                if let Some(c) = chars.next() {
+                    term.push(c);
+                }
+            }
+            c if c.is_whitespace() => {
+                if !term.is_empty() {
+                    output.push(std::mem::take(&mut term));
+                }
+            }
+            c => term.push(c),
+        }
+    }
+    if !term.is_empty() {
+        output.push(term);
+    }
+    output
+}
+
+/// A filter of the logs of a container, only the lines that match it are shown, e.g. `error warn !healthcheck`
+/// Each term is a pattern, as for `--watch`, a line is shown if it matches any of the include terms, or there are none, and none of the `!` exclude terms
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogFilter {
+    pub input: String,
+    /// The include & exclude patterns, None when the input is empty, or any term is invalid, so every line is shown
+    terms: Option<(Vec<Pattern>, Vec<Pattern>)>,
+}
+
+impl LogFilter {
+    pub fn new(input: String) -> Self {
+        let mut include = vec![];
+        let mut exclude = vec![];
+        let mut valid = true;
+        for term in split_terms(&input) {
+            let excluded = term.strip_prefix('!');
+            match Pattern::new(excluded.unwrap_or(&term)) {
+                Ok(pattern) if excluded.is_some() => exclude.push(pattern),
+                Ok(pattern) => include.push(pattern),
+                Err(_) => valid = false,
+            }
+        }
+        let empty = include.is_empty() && exclude.is_empty();
+        Self {
+            input,
+            terms: (valid && !empty).then_some((include, exclude)),
+        }
+    }
+
+    /// The filter is applied, rather than showing every line
+    pub const fn is_active(&self) -> bool {
+        self.terms.is_some()
+    }
+
+    /// A term can't be compiled, so the filter isn't applied
+    pub fn is_invalid(&self) -> bool {
+        self.terms.is_none() && !split_terms(&self.input).is_empty()
+    }
+
+    /// Check if a line is shown, every line is shown when the filter isn't active
+    pub fn is_match(&self, line: &str) -> bool {
+        self.terms.as_ref().is_none_or(|(include, exclude)| {
+            (include.is_empty() || include.iter().any(|i| i.is_match(line)))
+                && !exclude.iter().any(|i| i.is_match(line))
+        })
+    }
+
+    /// Shown in the logs title, so that it isn't forgotten that lines are hidden, e.g. ` - filter &error !healthcheck`
+    pub fn title(&self) -> String {
+        let invalid = if self.is_invalid() { " invalid" } else { "" };
+        format!(" - filter &{}{invalid}", self.input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LogFilter;
+
+    #[test]
+    /// Lines are shown when they match any include term, or there are none, and no exclude term
+    fn test_log_filter() {
+        let filter = LogFilter::new("error warn !healthcheck".to_owned());
+        assert!(filter.is_active());
+        assert!(filter.is_match("error: disk full"));
+        assert!(filter.is_match("warn: slow"));
+        assert!(!filter.is_match("info: started"));
+        assert!(!filter.is_match("error: healthcheck failed"));
+        assert_eq!(filter.title(), " - filter &error warn !healthcheck");
+
+        let filter = LogFilter::new("  !GET\\ /health  ".to_owned());
+        assert!(filter.is_match("POST /health"));
+        assert!(!filter.is_match("GET /health 200"));
+
+        let filter = LogFilter::new("(?i)^error\\ \\d+$".to_owned());
+        assert!(filter.is_match("ERROR 42"));
+        assert!(!filter.is_match("ERROR 42!"));
+    }
+
+    #[test]
+    /// An empty, or invalid, filter shows every line
+    fn test_log_filter_inactive() {
+        let filter = LogFilter::new("  ".to_owned());
+        assert!(!filter.is_active());
+        assert!(!filter.is_invalid());
+        assert!(filter.is_match("anything"));
+
+        let filter = LogFilter::new("error [a".to_owned());
+        assert!(!filter.is_active());
+        assert!(filter.is_invalid());
+        assert!(filter.is_match("anything"));
+        assert_eq!(filter.title(), " - filter &error [a invalid");
+
+        assert!(!LogFilter::new("!".to_owned()).is_active());
+    }
+}
@@ -0,0 +1,261 @@
+use ratatui::{
+    style::{Color, Style},
+    text::{Line, Span, Text},
+};
+
+use super::log_watch::Pattern;
+
+/// Style of the matched text in the logs panel
+const HIGHLIGHT: Style = Style::new().fg(Color::Black).bg(Color::Yellow);
+
+/// The text of a log line, as shown in the logs panel, without any styling
+pub fn plain(text: &Text) -> String {
+    text.lines
+        .iter()
+        .flat_map(|i| &i.spans)
+        .map(|i| i.content.as_ref())
+        .collect()
+}
+
+/// Split each span at the edges of the byte ranges, patching the highlight style onto the parts that are inside a range
+pub fn highlight(text: &Text<'static>, ranges: &[(usize, usize)]) -> Text<'static> {
+    let mut offset = 0;
+    let mut output = text.clone();
+    for line in &mut output.lines {
+        let mut spans = vec![];
+        for span in line.spans.drain(..) {
+            let start = offset;
+            offset += span.content.len();
+            let mut edges = vec![start, offset];
+            for (from, to) in ranges {
+                edges.extend(
+                    [*from, *to]
+                        .into_iter()
+                        .filter(|i| (start..offset).contains(i)),
+                );
+            }
+            edges.sort_unstable();
+            edges.dedup();
+            for pair in edges.windows(2) {
+                let (from, to) = (pair[0], pair[1]);
+                let Some(content) = span.content.get(from - start..to - start) else {
+                    continue;
+                };
+                let style = if ranges.iter().any(|(x, y)| *x <= from && to <= *y) {
+                    span.style.patch(HIGHLIGHT)
+                } else {
+                    span.style
+                };
+                spans.push(Span::styled(content.to_owned(), style));
+            }
+        }
+        *line = Line::from(spans).style(line.style);
+    }
+    output
+}
+
+/// A search of the logs of a container, each match is highlighted, & the selected line can jump between the lines with a match
+/// Plain text ignores case, unless it contains an uppercase char, a regex is as for `--watch`, `(?i)` to ignore case
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogSearch {
+    pub input: String,
+    pub regex: bool,
+    /// None when the input is empty, or an invalid regex
+    pattern: Option<Pattern>,
+    /// Index of each line with a match, & the byte ranges of its matches, in line order
+    matches: Vec<(usize, Vec<(usize, usize)>)>,
+}
+
+impl LogSearch {
+    pub const fn new(input: String, regex: bool) -> Self {
+        Self {
+            input,
+            regex,
+            pattern: None,
+            matches: vec![],
+        }
+    }
+
+    /// Compile the input, and find the matches in each line, with its index
+    pub fn scan_all<'a>(&mut self, lines: impl Iterator<Item = (usize, &'a Text<'static>)>) {
+        self.pattern = if self.regex {
+            Pattern::new(&self.input).ok()
+        } else {
+            let ignore_case = !self.input.chars().any(char::is_uppercase);
+            Pattern::literal(&self.input, ignore_case).ok()
+        };
+        self.matches.clear();
+        for (index, line) in lines {
+            self.scan(index, line);
+        }
+    }
+
+    /// Forget the matches of the first count lines, which have been dropped, the index of every other match is shifted to match
+    pub fn drop_lines(&mut self, count: usize) {
+        let dropped = self.matches.partition_point(|(i, _)| *i < count);
+        self.matches.drain(..dropped);
+        for (index, _) in &mut self.matches {
+            *index -= count;
+        }
+    }
+
+    /// Find the matches in a single line, lines must be scanned in order
+    pub fn scan(&mut self, index: usize, line: &Text<'static>) {
+        if let Some(pattern) = self.pattern.as_ref() {
+            let ranges = pattern.find_all(&plain(line));
+            if !ranges.is_empty() {
+                self.matches.push((index, ranges));
+            }
+        }
+    }
+
+    /// Check if a line, of plain text, has a match
+    pub fn is_match(&self, line: &str) -> bool {
+        self.pattern.as_ref().is_some_and(|i| i.is_match(line))
+    }
+
+    /// A regex that can't be compiled, so matches nothing
+    pub const fn is_invalid(&self) -> bool {
+        self.regex && !self.input.is_empty() && self.pattern.is_none()
+    }
+
+    /// The byte ranges of the matches in a line, if it has any
+    pub fn ranges(&self, index: usize) -> Option<&[(usize, usize)]> {
+        self.matches
+            .binary_search_by_key(&index, |i| i.0)
+            .ok()
+            .map(|i| self.matches[i].1.as_slice())
+    }
+
+    /// The next line with a match, after, or before, the selected line, wrapping around at either end
+    pub fn jump(&self, selected: Option<usize>, forward: bool) -> Option<usize> {
+        let mut lines = self.matches.iter().map(|i| i.0);
+        if forward {
+            lines
+                .clone()
+                .find(|i| selected.is_none_or(|selected| *i > selected))
+                .or_else(|| lines.next())
+        } else {
+            lines
+                .clone()
+                .rev()
+                .find(|i| selected.is_none_or(|selected| *i < selected))
+                .or_else(|| lines.next_back())
+        }
+    }
+
+    /// Shown in the logs title, the position of the selected line in the lines with a match, e.g. ` - /error 3/17`
+    pub fn title(&self, selected: Option<usize>) -> String {
+        let kind = if self.regex { "regex " } else { "" };
+        let count = if self.is_invalid() {
+            "invalid".to_owned()
+        } else if self.input.is_empty() {
+            String::new()
+        } else if self.matches.is_empty() {
+            "no matches".to_owned()
+        } else {
+            let position = selected
+                .and_then(|selected| self.matches.binary_search_by_key(&selected, |i| i.0).ok())
+                .map_or_else(|| "-".to_owned(), |i| (i + 1).to_string());
+            format!("{position}/{}", self.matches.len())
+        };
+        format!(" - {kind}/{} {count}", self.input)
+            .trim_end()
+            .to_owned()
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use ratatui::{
+        style::{Color, Style},
+        text::{Line, Span, Text},
+    };
+
+    use super::{highlight, plain, LogSearch, HIGHLIGHT};
+
+    fn gen_lines(lines: &[&str]) -> Vec<Text<'static>> {
+        lines.iter().map(|i| Text::from((*i).to_owned())).collect()
+    }
+
+    #[test]
+    /// Plain text ignores case, unless it has an uppercase char, regex chars are only special in a regex
+    fn test_log_search_scan() {
+        let lines = gen_lines(&["GET /a 200", "error: x", "ERROR [db]", "ok", "an error"]);
+        let mut search = LogSearch::new("error".to_owned(), false);
+        search.scan_all(lines.iter().enumerate());
+        assert_eq!(search.ranges(1), Some([(0, 5)].as_slice()));
+        assert_eq!(search.ranges(2), Some([(0, 5)].as_slice()));
+        assert!(search.ranges(3).is_none());
+        assert_eq!(search.title(Some(2)), " - /error 2/3");
+        assert_eq!(search.title(Some(0)), " - /error -/3");
+
+        search.input = "ERROR".to_owned();
+        search.scan_all(lines.iter().enumerate());
+        assert_eq!(search.title(None), " - /ERROR -/1");
+
+        search.input = "[db]".to_owned();
+        search.scan_all(lines.iter().enumerate());
+        assert_eq!(search.ranges(2), Some([(6, 10)].as_slice()));
+
+        search.regex = true;
+        search.scan_all(lines.iter().enumerate());
+        assert_eq!(search.ranges(2), Some([(7, 8), (8, 9)].as_slice()));
+        search.input = "\\d+$".to_owned();
+        search.scan_all(lines.iter().enumerate());
+        assert_eq!(search.title(Some(0)), " - regex /\\d+$ 1/1");
+        search.input = "[a".to_owned();
+        search.scan_all(lines.iter().enumerate());
+        assert!(search.is_invalid());
+        assert_eq!(search.title(None), " - regex /[a invalid");
+        search.input = "zzz".to_owned();
+        search.scan_all(lines.iter().enumerate());
+        assert_eq!(search.title(None), " - regex /zzz no matches");
+
+        let lines = gen_lines(&[]);
+        search.input.clear();
+        search.scan_all(lines.iter().enumerate());
+        assert_eq!(search.title(None), " - regex /");
+    }
+
+    #[test]
+    /// Jumping goes to the next, or previous, line with a match, wrapping around at either end
+    fn test_log_search_jump() {
+        let lines = gen_lines(&["a", "x", "a", "x", "a"]);
+        let mut search = LogSearch::new("a".to_owned(), false);
+        search.scan_all(lines.iter().enumerate());
+        assert_eq!(search.jump(None, true), Some(0));
+        assert_eq!(search.jump(Some(0), true), Some(2));
+        assert_eq!(search.jump(Some(1), true), Some(2));
+        assert_eq!(search.jump(Some(4), true), Some(0));
+        assert_eq!(search.jump(Some(2), false), Some(0));
+        assert_eq!(search.jump(Some(0), false), Some(4));
+        assert_eq!(search.jump(None, false), Some(4));
+
+        search.input = "b".to_owned();
+        search.scan_all(lines.iter().enumerate());
+        assert!(search.jump(Some(0), true).is_none());
+    }
+
+    #[test]
+    /// Matches are highlighted inside, & across, styled spans, the rest of the styling is kept
+    fn test_log_search_highlight() {
+        let red = Style::default().fg(Color::Red);
+        let text = Text::from(Line::from(vec![
+            Span::styled("ERR", red),
+            Span::raw("OR: disk error"),
+        ]));
+        let result = highlight(&text, &[(0, 5), (12, 17)]);
+        assert_eq!(plain(&result), plain(&text));
+        assert_eq!(
+            result.lines[0].spans,
+            [
+                Span::styled("ERR", red.patch(HIGHLIGHT)),
+                Span::styled("OR", HIGHLIGHT),
+                Span::raw(": disk "),
+                Span::styled("error", HIGHLIGHT),
+            ]
+        );
+    }
+}
@@ -0,0 +1,606 @@
+use std::path::{Component, Path, PathBuf};
+
+use crate::docker_data::{civil_from_days, days_from_civil, parse_rfc3339_nanos};
+
+/// The format of a timestamp in local time, when `--timestamp-format` isn't set, RFC 3339 with the offset of the timezone
+const LOCAL_FORMAT: &str = "%Y-%m-%dT%H:%M:%S.%f%:z";
+
+/// Where the tz database is installed, unless `$TZDIR` is set
+const TZDIR: &str = "/usr/share/zoneinfo";
+
+/// The rules of the US, used by a POSIX TZ string with daylight saving time but no rules, e.g. `EST5EDT`
+const DEFAULT_RULES: &str = "M3.2.0,M11.1.0";
+
+const WEEKDAYS: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+const MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// How the timestamp of each log line is shown, cycled with ( T )
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogTime {
+    Off,
+    /// As logged by the daemon, in UTC, unless there's a `--timestamp-format`
+    Utc,
+    /// In the `--timezone`, or else the timezone of the system
+    Local,
+}
+
+impl LogTime {
+    /// Cycle through the modes, off -> utc -> local -> off
+    pub const fn next(self) -> Self {
+        match self {
+            Self::Off => Self::Utc,
+            Self::Utc => Self::Local,
+            Self::Local => Self::Off,
+        }
+    }
+
+    /// The timestamp of a line, in the mode, followed by a space, None when off, a timestamp that can't be parsed is shown as logged
+    pub fn render(self, tz: &str, zone: &Zone, format: Option<&str>) -> Option<String> {
+        let (zone, format) = match (self, format) {
+            (Self::Off, _) => return None,
+            (Self::Utc, None) => return Some(tz.to_owned()),
+            (Self::Utc, Some(format)) => (&Zone::utc(), format),
+            (Self::Local, format) => (zone, format.unwrap_or(LOCAL_FORMAT)),
+        };
+        let Some(nanos) = parse_rfc3339_nanos(tz.trim()) else {
+            return Some(tz.to_owned());
+        };
+        let timestamp = i64::try_from(nanos.div_euclid(1_000_000_000)).unwrap_or_default();
+        let fraction = u32::try_from(nanos.rem_euclid(1_000_000_000)).unwrap_or_default();
+        Some(format!(
+            "{} ",
+            strftime(format, timestamp, fraction, zone.offset(timestamp))
+        ))
+    }
+}
+
+/// An offset from UTC, in seconds, east of UTC is positive, & its abbreviation, e.g. `BST`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Offset {
+    abbr: String,
+    seconds: i64,
+}
+
+/// The day that a POSIX TZ rule changes on
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RuleDay {
+    /// `Mm.w.d`, day d, 0 is Sunday, of week w, 5 is the last, of month m
+    Month { month: i64, week: i64, weekday: i64 },
+    /// `Jn`, 1 to 365, the 29th of February is never counted
+    Julian(i64),
+    /// `n`, 0 to 365, the 29th of February is counted
+    Day(i64),
+}
+
+impl RuleDay {
+    /// Days since the unix epoch of the day, in the year
+    fn days(self, year: i64) -> i64 {
+        let january = days_from_civil(year, 1, 1);
+        match self {
+            Self::Month {
+                month,
+                week,
+                weekday,
+            } => {
+                let first = days_from_civil(year, month, 1);
+                let next = days_from_civil(year + month / 12, month % 12 + 1, 1);
+                let mut day = first + (weekday - (first + 4)).rem_euclid(7) + (week - 1) * 7;
+                while day >= next {
+                    day -= 7;
+                }
+                day
+            }
+            Self::Julian(day) => {
+                let leap = days_from_civil(year, 3, 1) - days_from_civil(year, 2, 1) == 29;
+                january + day - 1 + i64::from(leap && day >= 60)
+            }
+            Self::Day(day) => january + day,
+        }
+    }
+}
+
+/// Daylight saving time of a POSIX TZ rule, each change is at a local time, in seconds, on its day
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Dst {
+    offset: Offset,
+    start: (RuleDay, i64),
+    end: (RuleDay, i64),
+}
+
+/// A POSIX TZ string, e.g. `CET-1CEST,M3.5.0,M10.5.0/3`, as in `$TZ`, & at the end of a tz database file, for times after its last transition
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Rule {
+    std: Offset,
+    dst: Option<Dst>,
+}
+
+/// Split a name, alphabetic, or quoted, e.g. `<+0530>`, off the start of a TZ string
+fn tz_name(input: &str) -> Option<(String, &str)> {
+    if let Some(rest) = input.strip_prefix('<') {
+        let (name, rest) = rest.split_once('>')?;
+        return Some((name.to_owned(), rest));
+    }
+    let end = input
+        .find(|i: char| !i.is_ascii_alphabetic())
+        .unwrap_or(input.len());
+    (end >= 3).then(|| (input[..end].to_owned(), &input[end..]))
+}
+
+/// Split a time, `[+-]hh[:mm[:ss]]`, in seconds, off the start of a TZ string
+fn tz_time(input: &str) -> Option<(i64, &str)> {
+    let (sign, rest) = match input.as_bytes().first() {
+        Some(b'-') => (-1, &input[1..]),
+        Some(b'+') => (1, &input[1..]),
+        _ => (1, input),
+    };
+    let end = rest
+        .find(|i: char| !i.is_ascii_digit() && i != ':')
+        .unwrap_or(rest.len());
+    let mut seconds = 0;
+    for (index, part) in rest[..end].split(':').enumerate() {
+        let multiplier = [3600, 60, 1].get(index)?;
+        seconds += part.parse::<i64>().ok()? * multiplier;
+    }
+    Some((sign * seconds, &rest[end..]))
+}
+
+/// Parse a rule day, & its optional time, 2am by default
+fn tz_change(input: &str) -> Option<(RuleDay, i64)> {
+    let (day, time) = input.split_once('/').unwrap_or((input, "2"));
+    let day = if let Some(rule) = day.strip_prefix('M') {
+        let mut parts = rule.splitn(3, '.').map(str::parse::<i64>);
+        let (month, week, weekday) = (
+            parts.next()?.ok()?,
+            parts.next()?.ok()?,
+            parts.next()?.ok()?,
+        );
+        if !(1..=12).contains(&month) || !(1..=5).contains(&week) || !(0..=6).contains(&weekday) {
+            return None;
+        }
+        RuleDay::Month {
+            month,
+            week,
+            weekday,
+        }
+    } else if let Some(day) = day.strip_prefix('J') {
+        RuleDay::Julian(day.parse().ok().filter(|i| (1..=365).contains(i))?)
+    } else {
+        RuleDay::Day(day.parse().ok().filter(|i| (0..=365).contains(i))?)
+    };
+    let (time, rest) = tz_time(time)?;
+    rest.is_empty().then_some((day, time))
+}
+
+impl Rule {
+    /// Parse a POSIX TZ string, its offsets are west of UTC, so are negated
+    fn parse(input: &str) -> Option<Self> {
+        let (abbr, rest) = tz_name(input)?;
+        let (offset, rest) = tz_time(rest)?;
+        let std = Offset {
+            abbr,
+            seconds: -offset,
+        };
+        if rest.is_empty() {
+            return Some(Self { std, dst: None });
+        }
+        let (abbr, rest) = tz_name(rest)?;
+        // Daylight saving time is an hour ahead, unless its offset is given
+        let (seconds, rest) =
+            tz_time(rest).map_or((std.seconds + 3600, rest), |(offset, rest)| (-offset, rest));
+        let rules = rest.strip_prefix(',').unwrap_or(DEFAULT_RULES);
+        let (start, end) = rules.split_once(',')?;
+        Some(Self {
+            std,
+            dst: Some(Dst {
+                offset: Offset { abbr, seconds },
+                start: tz_change(start)?,
+                end: tz_change(end)?,
+            }),
+        })
+    }
+
+    fn offset(&self, timestamp: i64) -> &Offset {
+        let Some(dst) = self.dst.as_ref() else {
+            return &self.std;
+        };
+        let (year, _, _) = civil_from_days((timestamp + self.std.seconds).div_euclid(86400));
+        let start = dst.start.0.days(year) * 86400 + dst.start.1 - self.std.seconds;
+        let end = dst.end.0.days(year) * 86400 + dst.end.1 - dst.offset.seconds;
+        // In the southern hemisphere daylight saving time starts later in the year than it ends
+        let in_dst = if start < end {
+            (start..end).contains(&timestamp)
+        } else {
+            !(end..start).contains(&timestamp)
+        };
+        if in_dst {
+            &dst.offset
+        } else {
+            &self.std
+        }
+    }
+}
+
+/// A timezone, from the tz database, a POSIX TZ string, or a fixed offset
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Zone {
+    rule: Option<Rule>,
+    /// The unix timestamp of each transition, & the index of the offset from then
+    transitions: Vec<(i64, usize)>,
+    offsets: Vec<Offset>,
+}
+
+impl Zone {
+    pub fn utc() -> Self {
+        Self::fixed("UTC", 0)
+    }
+
+    fn fixed(abbr: &str, seconds: i64) -> Self {
+        Self {
+            rule: None,
+            transitions: vec![],
+            offsets: vec![Offset {
+                abbr: abbr.to_owned(),
+                seconds,
+            }],
+        }
+    }
+
+    /// Parse a `--timezone`, `local`, `UTC`, an offset, e.g. `+05:30`, a tz database name, e.g. `Europe/London`, or a POSIX TZ string
+    pub fn parse(input: &str) -> Result<Self, String> {
+        let input = input.trim();
+        match input {
+            "local" => return Ok(Self::local()),
+            "UTC" | "utc" | "Z" => return Ok(Self::utc()),
+            _ => (),
+        }
+        if let Some(sign) = input.strip_prefix(['+', '-']).map(|_| &input[..1]) {
+            return tz_time(input)
+                .filter(|(seconds, rest)| rest.is_empty() && seconds.abs() < 86400)
+                .map(|(seconds, _)| Self::fixed(&format!("{sign}{}", &input[1..]), seconds))
+                .ok_or_else(|| format!("{input}: expected an offset, e.g. +05:30"));
+        }
+        Self::from_name(input)
+            .or_else(|| Rule::parse(input).map(Self::from_rule))
+            .ok_or_else(|| format!("{input}: not a known timezone, e.g. Europe/London, or +05:30"))
+    }
+
+    /// The timezone of the system, from `$TZ`, or `/etc/localtime`, UTC if neither can be read
+    pub fn local() -> Self {
+        if let Some(tz) = std::env::var("TZ").ok().filter(|i| !i.is_empty()) {
+            let tz = tz.strip_prefix(':').unwrap_or(&tz);
+            if let Some(zone) = Self::from_name(tz).or_else(|| Rule::parse(tz).map(Self::from_rule))
+            {
+                return zone;
+            }
+        }
+        std::fs::read("/etc/localtime")
+            .ok()
+            .and_then(|i| Self::from_tzif(&i))
+            .unwrap_or_else(Self::utc)
+    }
+
+    fn from_rule(rule: Rule) -> Self {
+        Self {
+            offsets: vec![rule.std.clone()],
+            rule: Some(rule),
+            transitions: vec![],
+        }
+    }
+
+    /// Read a tz database file, by its name, or path, a name can't leave the database directory
+    fn from_name(name: &str) -> Option<Self> {
+        let path = Path::new(name);
+        let path = if path.is_absolute() {
+            path.to_owned()
+        } else if path.components().all(|i| matches!(i, Component::Normal(_))) {
+            std::env::var_os("TZDIR")
+                .map_or_else(|| PathBuf::from(TZDIR), PathBuf::from)
+                .join(path)
+        } else {
+            return None;
+        };
+        Self::from_tzif(&std::fs::read(path).ok()?)
+    }
+
+    /// Parse a TZif file, RFC 8536, the 64-bit data of version 2 onwards is used when present, along with the TZ string of its footer
+    fn from_tzif(bytes: &[u8]) -> Option<Self> {
+        let read = |start: usize, len: usize| bytes.get(start..start.checked_add(len)?);
+        let counts = |start: usize| -> Option<[usize; 6]> {
+            if read(start, 4)? != b"TZif" {
+                return None;
+            }
+            let mut counts = [0; 6];
+            for (index, count) in counts.iter_mut().enumerate() {
+                let value = u32::from_be_bytes(read(start + 20 + index * 4, 4)?.try_into().ok()?);
+                *count = usize::try_from(value).ok()?;
+            }
+            Some(counts)
+        };
+        // The length of a header & its data, with times of the size, checked against the file before anything is allocated, as the counts can claim far more than there is
+        let block = |start: usize, size: usize| -> Option<[usize; 6]> {
+            let counts @ [utc, std, leap, times, types, chars] = counts(start)?;
+            let len = [
+                times.checked_mul(size + 1)?,
+                types.checked_mul(6)?,
+                chars,
+                leap.checked_mul(size + 4)?,
+                std,
+                utc,
+            ]
+            .into_iter()
+            .try_fold(44usize, usize::checked_add)?;
+            (start.checked_add(len)? <= bytes.len()).then_some(counts)
+        };
+        let [utc, std, leap, times, types, chars] = block(0, 4)?;
+        let (start, size) = if *bytes.get(4)? >= b'2' {
+            (44 + times * 5 + types * 6 + chars + leap * 8 + std + utc, 8)
+        } else {
+            (0, 4)
+        };
+        let [utc, std, leap, times, types, chars] = block(start, size)?;
+
+        let mut cursor = start + 44;
+        let mut transitions = Vec::with_capacity(times);
+        for index in 0..times {
+            let time = read(cursor + index * size, size)?;
+            transitions.push(if size == 8 {
+                i64::from_be_bytes(time.try_into().ok()?)
+            } else {
+                i64::from(i32::from_be_bytes(time.try_into().ok()?))
+            });
+        }
+        cursor += times * size;
+        let indices = read(cursor, times)?;
+        cursor += times;
+        let abbrs = read(cursor + types * 6, chars)?;
+        let mut offsets = Vec::with_capacity(types);
+        for index in 0..types {
+            let offset = read(cursor + index * 6, 6)?;
+            let start = usize::from(offset[5]);
+            let abbr = abbrs.get(start..)?;
+            let end = abbr.iter().position(|i| *i == 0).unwrap_or(abbr.len());
+            offsets.push(Offset {
+                abbr: String::from_utf8_lossy(&abbr[..end]).into_owned(),
+                seconds: i64::from(i32::from_be_bytes(offset[..4].try_into().ok()?)),
+            });
+        }
+        if offsets.is_empty() {
+            return None;
+        }
+        cursor += types * 6 + chars + leap * (size + 4) + std + utc;
+
+        let rule = (size == 8)
+            .then(|| read(cursor, bytes.len().saturating_sub(cursor)))
+            .flatten()
+            .and_then(|i| std::str::from_utf8(i).ok())
+            .and_then(|i| i.trim_matches('\n').lines().next())
+            .and_then(Rule::parse);
+        Some(Self {
+            rule,
+            transitions: transitions
+                .into_iter()
+                .zip(indices.iter().map(|i| usize::from(*i)))
+                .filter(|(_, index)| *index < offsets.len())
+                .collect(),
+            offsets,
+        })
+    }
+
+    /// The offset at a unix timestamp, before the first transition it's the first offset, after the last the rule is used, if there is one
+    fn offset(&self, timestamp: i64) -> &Offset {
+        let index = self.transitions.partition_point(|i| i.0 <= timestamp);
+        match (index, self.rule.as_ref()) {
+            (index, Some(rule)) if index == self.transitions.len() => rule.offset(timestamp),
+            (0, _) => &self.offsets[0],
+            (index, _) => &self.offsets[self.transitions[index - 1].1],
+        }
+    }
+}
+
+/// Format a unix timestamp, in an offset, with the common strftime specifiers
+/// `%Y %y %m %d %e %j %H %I %M %S %p %a %b %z %:z %Z %s %F %T %%`, & `%f`, or `%3f %6f %9f`, for the fraction of a second, any other specifier is kept as is
+fn strftime(format: &str, timestamp: i64, nanos: u32, offset: &Offset) -> String {
+    let local = timestamp + offset.seconds;
+    let (days, seconds) = (local.div_euclid(86400), local.rem_euclid(86400));
+    let (year, month, day) = civil_from_days(days);
+    let (hour, minute, second) = (seconds / 3600, seconds % 3600 / 60, seconds % 60);
+    let zone = |colon: &str| {
+        let sign = if offset.seconds < 0 { '-' } else { '+' };
+        let seconds = offset.seconds.abs();
+        format!(
+            "{sign}{:02}{colon}{:02}",
+            seconds / 3600,
+            seconds % 3600 / 60
+        )
+    };
+    let index = |i: i64| usize::try_from(i).unwrap_or_default();
+
+    let mut output = String::with_capacity(format.len() + 16);
+    let mut chars = format.chars();
+    while let Some(char) = chars.next() {
+        if char != '%' {
+            output.push(char);
+            continue;
+        }
+        let next = chars.next();
+        let piece = match next {
+            Some('Y') => format!("{year:04}"),
+            Some('y') => format!("{:02}", year.rem_euclid(100)),
+            Some('m') => format!("{month:02}"),
+            Some('d') => format!("{day:02}"),
+            Some('e') => format!("{day:>2}"),
+            Some('j') => format!("{:03}", days - days_from_civil(year, 1, 1) + 1),
+            Some('H') => format!("{hour:02}"),
+            Some('I') => format!("{:02}", (hour + 11) % 12 + 1),
+            Some('M') => format!("{minute:02}"),
+            Some('S') => format!("{second:02}"),
+            Some('p') => (if hour < 12 { "AM" } else { "PM" }).to_owned(),
+            Some('a') => WEEKDAYS[index((days + 4).rem_euclid(7))].to_owned(),
+            Some('b') => MONTHS[index(month - 1)].to_owned(),
+            Some('f') => format!("{nanos:09}"),
+            Some(digits @ ('3' | '6' | '9')) if chars.clone().next() == Some('f') => {
+                chars.next();
+                let len = digits.to_digit(10).unwrap_or(9);
+                format!("{nanos:09}")[..index(i64::from(len))].to_owned()
+            }
+            Some('z') => zone(""),
+            Some(':') if chars.clone().next() == Some('z') => {
+                chars.next();
+                zone(":")
+            }
+            Some('Z') => offset.abbr.clone(),
+            Some('s') => timestamp.to_string(),
+            Some('F') => format!("{year:04}-{month:02}-{day:02}"),
+            Some('T') => format!("{hour:02}:{minute:02}:{second:02}"),
+            Some('%') | None => "%".to_owned(),
+            Some(other) => format!("%{other}"),
+        };
+        output.push_str(&piece);
+    }
+    output
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::{strftime, LogTime, Offset, Rule, Zone};
+
+    /// 2024-07-01T12:34:56Z
+    const SUMMER: i64 = 1_719_837_296;
+    /// 2024-01-01T12:34:56Z
+    const WINTER: i64 = 1_704_112_496;
+
+    fn offset(zone: &Zone, timestamp: i64) -> (&str, i64) {
+        let offset = zone.offset(timestamp);
+        (offset.abbr.as_str(), offset.seconds)
+    }
+
+    #[test]
+    /// Each specifier is replaced, in the offset, unknown specifiers are kept
+    fn test_log_time_strftime() {
+        let utc = Offset {
+            abbr: "UTC".to_owned(),
+            seconds: 0,
+        };
+        assert_eq!(
+            strftime("%F %T.%3f %Z %z %%%q", SUMMER, 123_456_789, &utc),
+            "2024-07-01 12:34:56.123 UTC +0000 %%q"
+        );
+        let india = Offset {
+            abbr: "IST".to_owned(),
+            seconds: 19800,
+        };
+        assert_eq!(
+            strftime("%a %e %b %y %I:%M %p %j %:z", SUMMER, 0, &india),
+            "Mon  1 Jul 24 06:04 PM 183 +05:30"
+        );
+        let west = Offset {
+            abbr: "-03".to_owned(),
+            seconds: -10800,
+        };
+        assert_eq!(
+            strftime("%Y-%m-%dT%H:%M:%S.%f%:z", 0, 5, &west),
+            "1969-12-31T21:00:00.000000005-03:00"
+        );
+    }
+
+    #[test]
+    /// The rules of POSIX TZ strings, in both hemispheres, & a fixed offset
+    fn test_log_time_rule() {
+        let london = Zone::from_rule(Rule::parse("GMT0BST,M3.5.0/1,M10.5.0").unwrap());
+        assert_eq!(offset(&london, SUMMER), ("BST", 3600));
+        assert_eq!(offset(&london, WINTER), ("GMT", 0));
+        // 2024-03-31T00:59:59Z, & a second later
+        assert_eq!(offset(&london, 1_711_846_799), ("GMT", 0));
+        assert_eq!(offset(&london, 1_711_846_800), ("BST", 3600));
+
+        let sydney = Zone::from_rule(Rule::parse("AEST-10AEDT,M10.1.0,M4.1.0/3").unwrap());
+        assert_eq!(offset(&sydney, SUMMER), ("AEST", 36000));
+        assert_eq!(offset(&sydney, WINTER), ("AEDT", 39600));
+
+        let new_york = Zone::from_rule(Rule::parse("EST5EDT").unwrap());
+        assert_eq!(offset(&new_york, SUMMER), ("EDT", -14400));
+
+        let india = Zone::parse("+05:30").unwrap();
+        assert_eq!(offset(&india, SUMMER), ("+05:30", 19800));
+        assert_eq!(
+            Zone::parse("<+0530>-5:30").unwrap().offset(WINTER).seconds,
+            19800
+        );
+        assert!(Zone::parse("+25:00").is_err());
+        assert!(Zone::parse("../etc/passwd").is_err());
+        assert!(Zone::parse("Not/AZone").is_err());
+    }
+
+    #[test]
+    /// A version 2 TZif file, with the transitions, & the footer's rule for times after them
+    fn test_log_time_tzif() {
+        let types = [(0, 0, "GMT"), (3600, 1, "BST")];
+        let transitions: [(i64, u8); 2] = [(WINTER, 1), (WINTER + 86400, 0)];
+        let abbrs = b"GMT\0BST\0";
+        let data = |size: usize| {
+            let mut data = b"TZif2".to_vec();
+            data.extend([0; 15]);
+            for count in [0, 0, 0, transitions.len(), types.len(), abbrs.len()] {
+                data.extend(u32::try_from(count).unwrap().to_be_bytes());
+            }
+            for (time, _) in transitions {
+                if size == 8 {
+                    data.extend(time.to_be_bytes());
+                } else {
+                    data.extend(i32::try_from(time).unwrap().to_be_bytes());
+                }
+            }
+            data.extend(transitions.map(|i| i.1));
+            for (index, (seconds, dst, _)) in types.iter().enumerate() {
+                data.extend(i32::to_be_bytes(*seconds));
+                data.extend([*dst, u8::try_from(index * 4).unwrap()]);
+            }
+            data.extend(abbrs);
+            data
+        };
+        let mut file = data(4);
+        file.extend(data(8));
+        file.extend(b"\nGMT0BST,M3.5.0/1,M10.5.0\n");
+        let zone = Zone::from_tzif(&file).unwrap();
+        assert_eq!(offset(&zone, WINTER - 1), ("GMT", 0));
+        assert_eq!(offset(&zone, WINTER), ("BST", 3600));
+        assert_eq!(offset(&zone, WINTER + 86400), ("GMT", 0));
+        assert_eq!(offset(&zone, SUMMER), ("BST", 3600));
+        assert_eq!(offset(&zone, SUMMER + 183 * 86400), ("GMT", 0));
+        assert!(Zone::from_tzif(b"TZif2").is_none());
+
+        // Counts that claim more data than the file has, or a truncated file, aren't read
+        let mut huge = b"TZif2".to_vec();
+        huge.extend([0; 15]);
+        for count in [0, 0, 0, u32::MAX, u32::MAX, 4] {
+            huge.extend(count.to_be_bytes());
+        }
+        huge.extend(data(4));
+        assert!(Zone::from_tzif(&huge).is_none());
+        file.truncate(data(4).len() + data(8).len() - 1);
+        assert!(Zone::from_tzif(&file).is_none());
+    }
+
+    #[test]
+    /// Off shows nothing, utc is as logged unless there's a format, local uses the zone
+    fn test_log_time_render() {
+        let tz = "2024-07-01T12:34:56.123456789Z ";
+        let zone = Zone::from_rule(Rule::parse("CET-1CEST,M3.5.0,M10.5.0/3").unwrap());
+        assert_eq!(LogTime::Off.render(tz, &zone, None), None);
+        assert_eq!(LogTime::Utc.render(tz, &zone, None).unwrap(), tz);
+        assert_eq!(
+            LogTime::Utc.render(tz, &zone, Some("%T %Z")).unwrap(),
+            "12:34:56 UTC "
+        );
+        assert_eq!(
+            LogTime::Local.render(tz, &zone, None).unwrap(),
+            "2024-07-01T14:34:56.123456789+02:00 "
+        );
+        assert_eq!(LogTime::Local.render("x ", &zone, None).unwrap(), "x ");
+        assert_eq!(LogTime::Off.next(), LogTime::Utc);
+        assert_eq!(LogTime::Local.next(), LogTime::Off);
+    }
+}
@@ -0,0 +1,542 @@
+use std::fmt;
+
+use super::ContainerId;
+
+/// Maximum number of matches that are kept, the oldest are removed first
+pub const MAX_LOG_MATCHES: usize = 500;
+
+/// A single item of a character class, `a`, `a-z`, or one of the `\d`, `\w`, `\s` shorthands, or their negated `\D`, `\W`, `\S`
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum ClassItem {
+    Range(char, char),
+    Digit,
+    Word,
+    Space,
+    /// Any char that none of the items match
+    Not(Vec<Self>),
+}
+
+impl ClassItem {
+    fn matches(&self, c: char) -> bool {
+        match self {
+            Self::Range(start, end) => (*start..=*end).contains(&c),
+            Self::Digit => c.is_ascii_digit(),
+            Self::Word => c.is_alphanumeric() || c == '_',
+            Self::Space => c.is_whitespace(),
+            Self::Not(items) => !items.iter().any(|i| i.matches(c)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Atom {
+    Any,
+    Char(char),
+    Class {
+        items: Vec<ClassItem>,
+        negated: bool,
+    },
+}
+
+impl Atom {
+    fn matches(&self, c: char, ignore_case: bool) -> bool {
+        match self {
+            Self::Any => true,
+            Self::Char(x) => *x == c || (ignore_case && x.to_lowercase().eq(c.to_lowercase())),
+            Self::Class { items, negated } => {
+                let found = items.iter().any(|i| i.matches(c))
+                    || (ignore_case
+                        && c.to_lowercase()
+                            .chain(c.to_uppercase())
+                            .any(|c| items.iter().any(|i| i.matches(c))));
+                found != *negated
+            }
+        }
+    }
+}
+
+/// An atom, and how many times it can repeat, `max` of None is unlimited
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Piece {
+    atom: Atom,
+    min: usize,
+    max: Option<usize>,
+}
+
+/// A step of a branch, an atom that can be skipped, or repeated, `x+` is the two steps `x` then `x*`
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Step {
+    atom: Atom,
+    optional: bool,
+    repeat: bool,
+}
+
+impl From<Piece> for Vec<Step> {
+    fn from(piece: Piece) -> Self {
+        let step = |optional, repeat| Step {
+            atom: piece.atom.clone(),
+            optional,
+            repeat,
+        };
+        match (piece.min, piece.max) {
+            (0, Some(_)) => vec![step(true, false)],
+            (0, None) => vec![step(true, true)],
+            (_, Some(_)) => vec![step(false, false)],
+            (_, None) => vec![step(false, false), step(true, true)],
+        }
+    }
+}
+
+/// One side of a `|` alternation
+/// Matched as an NFA, every step that the line could be at is followed at once, so each char is only looked at once per step, rather than backtracking, which a pattern such as `a*a*a*b` would take exponential time to do
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Branch {
+    steps: Vec<Step>,
+    start: bool,
+    end: bool,
+}
+
+impl Branch {
+    fn new(pieces: Vec<Piece>, start: bool, end: bool) -> Self {
+        Self {
+            steps: pieces.into_iter().flat_map(Vec::<Step>::from).collect(),
+            start,
+            end,
+        }
+    }
+
+    /// Add a state, the index of the next step to match, & every state reached from it by skipping optional steps
+    fn add(&self, states: &mut [bool], mut state: usize) {
+        while !states[state] {
+            states[state] = true;
+            match self.steps.get(state) {
+                Some(step) if step.optional => state += 1,
+                _ => break,
+            }
+        }
+    }
+
+    /// The states after a char, from the states before it
+    fn next(&self, states: &[bool], c: char, ignore_case: bool) -> Vec<bool> {
+        let mut next = vec![false; states.len()];
+        for (state, step) in self.steps.iter().enumerate() {
+            if states[state] && step.atom.matches(c, ignore_case) {
+                if step.repeat {
+                    self.add(&mut next, state);
+                }
+                self.add(&mut next, state + 1);
+            }
+        }
+        next
+    }
+
+    /// Every step has been matched, at the end of the line if the branch ends with `$`
+    fn accepts(&self, states: &[bool], index: usize, len: usize) -> bool {
+        states[self.steps.len()] && (!self.end || index == len)
+    }
+}
+
+/// A regular expression, limited to what's needed to watch log lines, there are no dependencies available for a full regex engine
+/// Supports literals, `.`, `[a-z]` & `[^a-z]` classes, `\d \w \s` & their negated `\D \W \S`, the `* + ?` quantifiers, `^ $` anchors, `|` alternation, and a leading `(?i)` to ignore case
+/// Groups, and counted repetition, aren't supported
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pattern {
+    branches: Vec<Branch>,
+    ignore_case: bool,
+    source: String,
+}
+
+impl fmt::Display for Pattern {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.source)
+    }
+}
+
+/// Convert an escaped char, the char after a `\`, into an atom
+fn escaped(c: char) -> Atom {
+    let class = |item: ClassItem, negated: bool| Atom::Class {
+        items: vec![item],
+        negated,
+    };
+    match c {
+        'd' => class(ClassItem::Digit, false),
+        'D' => class(ClassItem::Digit, true),
+        'w' => class(ClassItem::Word, false),
+        'W' => class(ClassItem::Word, true),
+        's' => class(ClassItem::Space, false),
+        'S' => class(ClassItem::Space, true),
+        't' => Atom::Char('\t'),
+        c => Atom::Char(c),
+    }
+}
+
+/// Parse the inside of a `[...]` class, the opening bracket has already been consumed
+fn parse_class(chars: &mut std::iter::Peekable<std::str::Chars>) -> Result<Atom, String> {
+    let negated = chars.next_if_eq(&'^').is_some();
+    let mut items = vec![];
+    let mut first = true;
+    loop {
+        let c = chars.next().ok_or("unclosed [")?;
+        // A `]` straight after the opening bracket is a literal
+        if c == ']' && !first {
+            break;
+        }
+        first = false;
+        let start = if c == '\\' {
+            match escaped(chars.next().ok_or("trailing \\")?) {
+                // A negated shorthand, `[\D]`, is only negated within the class
+                Atom::Class {
+                    items: class,
+                    negated,
+                } => {
+                    if negated {
+                        items.push(ClassItem::Not(class));
+                    } else {
+                        items.extend(class);
+                    }
+                    continue;
+                }
+                Atom::Char(c) => c,
+                Atom::Any => continue,
+            }
+        } else {
+            c
+        };
+        let is_range = chars.peek() == Some(&'-') && chars.clone().nth(1).is_some_and(|i| i != ']');
+        if is_range {
+            chars.next();
+            let end = match chars.next() {
+                Some('\\') => chars.next().ok_or("trailing \\")?,
+                Some(c) => c,
+                None => return Err("unclosed [".to_owned()),
+            };
+            if end < start {
+                return Err(format!("invalid range {start}-{end}"));
+            }
+            items.push(ClassItem::Range(start, end));
+        } else {
+            items.push(ClassItem::Range(start, start));
+        }
+    }
+    Ok(Atom::Class { items, negated })
+}
+
+fn parse_branch(input: &str) -> Result<Branch, String> {
+    let mut chars = input.chars().peekable();
+    let start = chars.next_if_eq(&'^').is_some();
+    let mut pieces: Vec<Piece> = vec![];
+    let mut end = false;
+    while let Some(c) = chars.next() {
+        if end {
+            return Err("$ must be at the end".to_owned());
+        }
+        let atom = match c {
+            '.' => Atom::Any,
+            '\\' => escaped(chars.next().ok_or("trailing \\")?),
+            '[' => parse_class(&mut chars)?,
+            '$' => {
+                end = true;
+                continue;
+            }
+            '*' | '+' | '?' => {
+                let Some(piece) = pieces.last_mut().filter(|i| i.min == 1 && i.max == Some(1))
+                else {
+                    return Err(format!("nothing to repeat before {c}"));
+                };
+                (piece.min, piece.max) = match c {
+                    '*' => (0, None),
+                    '+' => (1, None),
+                    _ => (0, Some(1)),
+                };
+                continue;
+            }
+            '(' | ')' => return Err("groups aren't supported".to_owned()),
+            c => Atom::Char(c),
+        };
+        pieces.push(Piece {
+            atom,
+            min: 1,
+            max: Some(1),
+        });
+    }
+    Ok(Branch::new(pieces, start, end))
+}
+
+/// Split on each `|` that isn't escaped, or inside a class
+fn split_alternation(input: &str) -> Vec<&str> {
+    let mut output = vec![];
+    let mut from = 0;
+    let mut in_class = false;
+    let mut chars = input.char_indices();
+    while let Some((index, c)) = chars.next() {
+        match c {
+            '\\' => {
+                chars.next();
+            }
+            '[' => in_class = true,
+            ']' => in_class = false,
+            '|' if !in_class => {
+                output.push(&input[from..index]);
+                from = index + 1;
+            }
+            _ => (),
+        }
+    }
+    output.push(&input[from..]);
+    output
+}
+
+impl Pattern {
+    pub fn new(input: &str) -> Result<Self, String> {
+        let (ignore_case, pattern) = input
+            .strip_prefix("(?i)")
+            .map_or((false, input), |i| (true, i));
+        if pattern.is_empty() {
+            return Err("empty pattern".to_owned());
+        }
+        Ok(Self {
+            branches: split_alternation(pattern)
+                .into_iter()
+                .map(parse_branch)
+                .collect::<Result<_, _>>()?,
+            ignore_case,
+            source: input.to_owned(),
+        })
+    }
+
+    /// A pattern that matches the input as is, with none of its chars treated as regex
+    pub fn literal(input: &str, ignore_case: bool) -> Result<Self, String> {
+        if input.is_empty() {
+            return Err("empty pattern".to_owned());
+        }
+        Ok(Self {
+            branches: vec![Branch::new(
+                input
+                    .chars()
+                    .map(|c| Piece {
+                        atom: Atom::Char(c),
+                        min: 1,
+                        max: Some(1),
+                    })
+                    .collect(),
+                false,
+                false,
+            )],
+            ignore_case,
+            source: input.to_owned(),
+        })
+    }
+
+    /// The char index that the longest match of the branch ends at, for a match starting at index
+    fn match_branch(&self, branch: &Branch, chars: &[char], index: usize) -> Option<usize> {
+        let mut states = vec![false; branch.steps.len() + 1];
+        branch.add(&mut states, 0);
+        let mut matched = branch.accepts(&states, index, chars.len()).then_some(index);
+        for (offset, c) in chars[index..].iter().enumerate() {
+            states = branch.next(&states, *c, self.ignore_case);
+            if !states.contains(&true) {
+                break;
+            }
+            let end = index + offset + 1;
+            if branch.accepts(&states, end, chars.len()) {
+                matched = Some(end);
+            }
+        }
+        matched
+    }
+
+    /// The char index that the first matching branch ends at, for a match starting at index
+    fn match_at(&self, chars: &[char], index: usize) -> Option<usize> {
+        self.branches
+            .iter()
+            .filter(|branch| !branch.start || index == 0)
+            .find_map(|branch| self.match_branch(branch, chars, index))
+    }
+
+    /// Check if the pattern is found anywhere in the line, a match is started at every char in a single pass, so the line is only read once per branch
+    pub fn is_match(&self, line: &str) -> bool {
+        let chars = line.chars().collect::<Vec<_>>();
+        self.branches.iter().any(|branch| {
+            let mut states = vec![false; branch.steps.len() + 1];
+            for index in 0..=chars.len() {
+                if !branch.start || index == 0 {
+                    branch.add(&mut states, 0);
+                }
+                if branch.accepts(&states, index, chars.len()) {
+                    return true;
+                }
+                let Some(c) = chars.get(index) else {
+                    break;
+                };
+                states = branch.next(&states, *c, self.ignore_case);
+            }
+            false
+        })
+    }
+
+    /// Byte ranges of each match in the line, leftmost first & not overlapping, empty matches are skipped, as there's nothing to highlight
+    pub fn find_all(&self, line: &str) -> Vec<(usize, usize)> {
+        let chars = line.chars().collect::<Vec<_>>();
+        let bytes = line
+            .char_indices()
+            .map(|(i, _)| i)
+            .chain(std::iter::once(line.len()))
+            .collect::<Vec<_>>();
+        let mut output = vec![];
+        let mut index = 0;
+        while index < chars.len() {
+            match self.match_at(&chars, index) {
+                Some(end) if end > index => {
+                    output.push((bytes[index], bytes[end]));
+                    index = end;
+                }
+                _ => index += 1,
+            }
+        }
+        output
+    }
+}
+
+/// A watch on the logs of a container, `--watch [container]=[pattern]`, a container of `*` watches every container
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogWatch {
+    container: Option<String>,
+    pattern: Pattern,
+}
+
+impl LogWatch {
+    pub fn parse(input: &str) -> Result<Self, String> {
+        let (container, pattern) = input
+            .split_once('=')
+            .ok_or_else(|| format!("{input}: expected [container]=[pattern]"))?;
+        let pattern = Pattern::new(pattern).map_err(|e| format!("{input}: {e}"))?;
+        Ok(Self {
+            container: Some(container.trim().to_owned()).filter(|i| !i.is_empty() && i != "*"),
+            pattern,
+        })
+    }
+
+    /// Check if the watch applies to the named container
+    pub fn applies(&self, name: &str) -> bool {
+        !matches!(&self.container, Some(i) if i != name)
+    }
+
+    /// Check if a log line, from the named container, matches the watch
+    pub fn is_match(&self, name: &str, line: &str) -> bool {
+        self.applies(name) && self.pattern.is_match(line)
+    }
+}
+
+impl fmt::Display for LogWatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.pattern)
+    }
+}
+
+/// A log line that matched a watch, as shown in the matches view
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogMatch {
+    pub id: ContainerId,
+    /// Index of the line in the container's logs, so that the lines around it can be shown, None once the line has been dropped by `--max-log-lines`
+    pub index: Option<usize>,
+    pub line: String,
+    pub name: String,
+    pub pattern: String,
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::{LogWatch, Pattern};
+
+    #[test]
+    /// Literals, classes, quantifiers, anchors, & alternation
+    fn test_log_watch_pattern() {
+        let is_match = |pattern: &str, line: &str| Pattern::new(pattern).unwrap().is_match(line);
+
+        assert!(is_match(
+            "OutOfMemoryError",
+            "java.lang.OutOfMemoryError: heap"
+        ));
+        assert!(!is_match("OutOfMemoryError", "outofmemoryerror"));
+        assert!(is_match("(?i)OutOfMemoryError", "outofmemoryerror"));
+        assert!(is_match(
+            "timeout after \\d+ms",
+            "read timeout after 3000ms"
+        ));
+        assert!(!is_match("timeout after \\d+ms", "read timeout after ms"));
+        assert!(is_match("^ERROR|FATAL", "ERROR: x"));
+        assert!(is_match("^ERROR|FATAL", "a FATAL b"));
+        assert!(!is_match("^ERROR|FATAL", "an ERROR"));
+        assert!(is_match("done$", "all done"));
+        assert!(!is_match("done$", "done already"));
+        assert!(is_match("colou?r", "color"));
+        assert!(is_match("a.*z", "a then z"));
+        assert!(is_match("[A-Z][a-z]+Exception", "NullPointerException"));
+        assert!(!is_match("[^0-9]x", "1x"));
+        assert!(is_match("\\[warn\\]", "[warn] disk"));
+        assert!(is_match("[a|b]", "|"));
+        assert!(is_match("(?i)[a-c]", "B"));
+        assert!(is_match("status=5\\d\\d", "status=503"));
+        assert!(is_match("[\\D]", "a"));
+        assert!(!is_match("[\\D]", "1"));
+        assert!(is_match("^[\\W0-9]+$", "- 42"));
+        assert!(!is_match("^[\\S]+$", "a b"));
+        assert!(is_match("^[^\\s]+$", "a-b"));
+        assert!(!is_match("[^\\s]", " \t"));
+    }
+
+    #[test]
+    /// Each match is found, as byte ranges, including after multi-byte chars, and a literal pattern treats regex chars as is
+    fn test_log_watch_pattern_find_all() {
+        let pattern = Pattern::new("(?i)err\\w*").unwrap();
+        assert_eq!(
+            pattern.find_all("ERROR: é error, no errs"),
+            [(0, 5), (10, 15), (20, 24)]
+        );
+        assert_eq!(Pattern::new("^a").unwrap().find_all("aaa"), [(0, 1)]);
+        assert!(Pattern::new("x*").unwrap().find_all("abc").is_empty());
+
+        let pattern = Pattern::literal("[warn]", true).unwrap();
+        assert_eq!(pattern.find_all("[WARN] x [warn]"), [(0, 6), (9, 15)]);
+        assert!(!pattern.is_match("w"));
+        assert!(!Pattern::literal("Warn", false).unwrap().is_match("warn"));
+        assert!(Pattern::literal("", true).is_err());
+    }
+
+    #[test]
+    /// Patterns that would take exponential time to backtrack through are matched in a single pass
+    fn test_log_watch_pattern_nested_stars() {
+        let line = "a".repeat(5_000);
+        let pattern = Pattern::new("a*a*a*a*a*a*a*a*b").unwrap();
+        assert!(!pattern.is_match(&line));
+        assert!(pattern.is_match(&format!("{line}b")));
+        assert_eq!(Pattern::new("a+a+b|a+").unwrap().find_all("aaab"), [(0, 4)]);
+        assert_eq!(Pattern::new("a?ab?").unwrap().find_all("ab"), [(0, 2)]);
+    }
+
+    #[test]
+    /// Unsupported, & invalid, patterns are errors
+    fn test_log_watch_pattern_invalid() {
+        assert!(Pattern::new("").is_err());
+        assert!(Pattern::new("*a").is_err());
+        assert!(Pattern::new("a**").is_err());
+        assert!(Pattern::new("(a|b)").is_err());
+        assert!(Pattern::new("[abc").is_err());
+        assert!(Pattern::new("[z-a]").is_err());
+        assert!(Pattern::new("a$b").is_err());
+        assert!(Pattern::new("a\\").is_err());
+    }
+
+    #[test]
+    /// Watch only applies to the named container, or every container with `*`
+    fn test_log_watch_parse() {
+        let watch = LogWatch::parse("api=OutOfMemory").unwrap();
+        assert!(watch.is_match("api", "OutOfMemory"));
+        assert!(!watch.is_match("web", "OutOfMemory"));
+        let watch = LogWatch::parse("*=level=error").unwrap();
+        assert!(watch.is_match("web", "level=error"));
+        assert!(LogWatch::parse("no_pattern").is_err());
+        assert!(LogWatch::parse("api=(x)").is_err());
+    }
+}
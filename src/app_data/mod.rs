@@ -1,21 +1,98 @@
-use bollard::models::ContainerSummary;
+use bollard::{container::ThrottlingData, models::ContainerSummary};
 use core::fmt;
 use parking_lot::Mutex;
-use ratatui::widgets::{ListItem, ListState};
+use ratatui::{
+    style::{Modifier, Style},
+    text::Text,
+    widgets::{ListItem, ListState},
+};
 use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    path::PathBuf,
     sync::Arc,
-    time::{SystemTime, UNIX_EPOCH},
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
 };
 
+mod attempts;
+pub mod cache;
+mod chart_view;
+mod confirm;
+mod container_filter;
+mod container_path;
 mod container_state;
+mod events;
+pub mod exec_history;
+mod images;
+mod json_log;
+mod limits;
+mod log_filter;
+mod log_search;
+mod log_time;
+mod log_watch;
+mod networks;
+pub mod notes;
+mod output;
+mod port_conflict;
+mod probe;
+mod recent;
+mod recreated;
+mod redact;
+mod schedule;
+mod services;
+mod throughput;
+mod volumes;
 
 use crate::{
     app_error::AppError,
+    docker_data::LogFallback,
     parse_args::CliArgs,
-    ui::{log_sanitizer, GuiState, Status},
+    ui::{
+        format_duration, log_sanitizer, GuiState, HostPicker, HostRow, PortRow, PortView,
+        RecentPicker, RecentRow, SelectablePanel, Status, TimelineKind,
+    },
     ENTRY_POINT,
 };
+pub use attempts::Attempts;
+pub use chart_view::ChartView;
+pub use confirm::ConfirmPolicy;
+pub use container_filter::ContainerFilter;
+use container_path::ContainerPath;
 pub use container_state::*;
+pub use events::{DockerEvent, EventKind};
+pub use exec_history::ExecEntry;
+pub use images::ImageItem;
+pub use json_log::JsonView;
+pub use limits::{parse_cpus, parse_memory, Limits};
+pub use log_filter::LogFilter;
+pub use log_search::LogSearch;
+pub use log_time::{LogTime, Zone};
+pub use log_watch::{LogMatch, LogWatch};
+pub use networks::NetworkItem;
+pub use output::{OutputFormat, StatusFormat};
+pub use port_conflict::{HostPort, PortConflict};
+pub use probe::{Probe, ProbeCheck, ProbeTarget, Readiness};
+use recent::Recent;
+use recreated::Recreated;
+pub use redact::{redact, Redact};
+pub use schedule::{Schedule, ScheduleAction, ScheduleRun};
+pub use services::ServiceItem;
+pub use throughput::{IoChart, IoView};
+pub use volumes::VolumeItem;
+
+/// Number of update intervals that a container's stats, or logs, can be waited on before they're shown as stale
+const STALE_INTERVALS: u32 = 3;
+
+/// Minimum seconds that an update can be waited on before it's shown as stale, so a short `-d` doesn't mark every slow request
+const STALE_MIN: u64 = 5;
+
+/// Clock skew, in seconds, between the Docker daemon and the local machine, at which a warning is shown
+const CLOCK_SKEW_LIMIT: u64 = 5;
+
+/// How often a boosted container's stats & logs are refreshed, whatever the `-d` interval
+pub const BOOST_INTERVAL: Duration = Duration::from_millis(250);
+
+/// How long a container is boosted for, before falling back to the `-d` interval
+pub const BOOST_DURATION: Duration = Duration::from_mins(2);
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub enum SortedOrder {
@@ -23,25 +100,127 @@ pub enum SortedOrder {
     Desc,
 }
 
+/// Cpu count, & total memory, of the primary Docker host, as reported by the daemon, to give container percentages some context
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HostResources {
+    pub cpus: u64,
+    pub memory: u64,
+}
+
+/// Details of the primary Docker daemon, & the warnings it reports, such as no swap limit support, which explain why some container limits are ignored
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DaemonInfo {
+    pub cgroup: Option<String>,
+    pub kernel: Option<String>,
+    pub name: Option<String>,
+    pub os: Option<String>,
+    pub storage_driver: Option<String>,
+    pub version: Option<String>,
+    pub warnings: Vec<String>,
+}
+
+/// Combined cpu & memory use of the primary host's containers, as a share of the host's resources
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HostUsage {
+    pub resources: HostResources,
+    /// Percentage of all of the host's cpus, whereas each container's cpu percentage is of a single cpu
+    pub cpu: f64,
+    pub memory: ByteStats,
+}
+
+impl fmt::Display for HostUsage {
+    #[allow(clippy::cast_precision_loss)]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let memory = ByteStats::new(self.resources.memory);
+        let memory_percent = if self.resources.memory > 0 {
+            self.memory.get() as f64 / self.resources.memory as f64 * 100.0
+        } else {
+            0.0
+        };
+        write!(
+            f,
+            "{} cpu {:.0}%, {} {memory_percent:.0}%",
+            self.resources.cpus,
+            self.cpu,
+            memory.to_string().trim()
+        )
+    }
+}
+
+/// Top-like mode, continuously re-sort the containers by the latest cpu or memory value, highest first
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum TopMode {
+    Cpu,
+    Memory,
+}
+
+impl TopMode {
+    /// Cycle through the modes, off -> cpu -> memory -> off
+    pub const fn next(current: Option<Self>) -> Option<Self> {
+        match current {
+            None => Some(Self::Cpu),
+            Some(Self::Cpu) => Some(Self::Memory),
+            Some(Self::Memory) => None,
+        }
+    }
+
+    pub const fn header(self) -> Header {
+        match self {
+            Self::Cpu => Header::Cpu,
+            Self::Memory => Header::Memory,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
 pub enum Header {
+    Host,
     State,
     Status,
+    Ready,
+    ExitCode,
+    Restarts,
     Cpu,
+    Throttled,
     Memory,
     Id,
     Name,
     Image,
     Rx,
     Tx,
+    NetIo,
+    DiskIo,
+    /// The index of a `--label-column`
+    Label(usize),
+}
+
+impl Header {
+    /// The column is of a container's stats, so the order of containers sorted by it changes as each container's stats are updated
+    pub const fn is_stat(self) -> bool {
+        matches!(
+            self,
+            Self::Cpu
+                | Self::Throttled
+                | Self::Memory
+                | Self::Rx
+                | Self::Tx
+                | Self::NetIo
+                | Self::DiskIo
+        )
+    }
 }
 
 /// Convert Header enum into strings to display
 impl fmt::Display for Header {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let disp = match self {
+            Self::Host => "host",
             Self::State => "state",
             Self::Status => "status",
+            Self::Ready => "ready",
+            Self::ExitCode => "exit",
+            Self::Restarts => "restarts",
+            Self::Throttled => "throttled",
             Self::Cpu => "cpu",
             Self::Memory => "memory/limit",
             Self::Id => "id",
@@ -49,6 +228,9 @@ impl fmt::Display for Header {
             Self::Image => "image",
             Self::Rx => "↓ rx",
             Self::Tx => "↑ tx",
+            Self::NetIo => "net/s",
+            Self::DiskIo => "disk/s",
+            Self::Label(_) => "label",
         };
         write!(f, "{disp:>x$}", x = f.width().unwrap_or(1))
     }
@@ -58,29 +240,172 @@ impl fmt::Display for Header {
 #[derive(Debug, Clone)]
 #[cfg(not(test))]
 pub struct AppData {
+    /// The container refreshed every BOOST_INTERVAL, & when it falls back to the `-d` interval
+    boost: Option<(ContainerId, Instant)>,
+    clock_skew: Option<i64>,
+    collapsed_groups: HashSet<String>,
+    chart_view: ChartView,
     containers: StatefulList<ContainerItem>,
+    daemon_info: Option<DaemonInfo>,
     error: Option<AppError>,
+    events: VecDeque<DockerEvent>,
+    exec_history: HashMap<String, Vec<ExecEntry>>,
+    exec_history_path: Option<PathBuf>,
+    /// The `--filter` rules, & the rules added since, a container is only listed if it's shown by every enabled rule
+    filters: Vec<ContainerFilter>,
+    group_by: Option<GroupBy>,
+    group_state: ListState,
+    host_resources: Option<HostResources>,
+    host_tab: Option<String>,
+    images: StatefulList<ImageItem>,
+    io_view: Option<IoView>,
+    last_update: Option<Instant>,
+    latency: Option<Duration>,
+    log_json: Option<JsonView>,
+    /// The container whose logs are shown, whichever container is selected, until unlocked
+    log_lock: Option<ContainerId>,
+    log_matches: VecDeque<LogMatch>,
+    log_time: LogTime,
+    /// Containers marked with ( space ), a control is sent to every marked container at once
+    marked: HashSet<ContainerId>,
+    marked_controls: StatefulList<DockerControls>,
+    networks: StatefulList<NetworkItem>,
+    notes: HashMap<String, String>,
+    notes_path: Option<PathBuf>,
+    project_controls: StatefulList<DockerControls>,
+    recent: Recent,
+    recreated: Recreated,
+    refresh_stretch: Option<Duration>,
+    resources_changed: HashSet<(Option<String>, SelectablePanel)>,
+    schedule_key: usize,
+    schedules: Vec<Schedule>,
+    search: Option<String>,
+    /// Hosts that have yet to first list their containers, while the `--select` container hasn't been found, None once it has, or every host has been looked in
+    select_pending: Option<usize>,
+    selected_group: Option<String>,
+    services: StatefulList<ServiceItem>,
     sorted_by: Option<(Header, SortedOrder)>,
+    stale: bool,
+    /// Hosts that are swarm managers, from the daemon's info, the primary host is None
+    swarm: HashSet<Option<String>>,
+    top_mode: Option<TopMode>,
+    unseen_matches: usize,
+    /// The containers have been listed by the Docker daemon at least once
+    updated: bool,
+    visible_containers: HashSet<ContainerId>,
+    volumes: StatefulList<VolumeItem>,
     pub args: CliArgs,
 }
 
 #[derive(Debug, Clone)]
 #[cfg(test)]
 pub struct AppData {
+    pub boost: Option<(ContainerId, Instant)>,
+    pub clock_skew: Option<i64>,
+    pub collapsed_groups: HashSet<String>,
+    pub chart_view: ChartView,
     pub containers: StatefulList<ContainerItem>,
+    pub daemon_info: Option<DaemonInfo>,
     pub error: Option<AppError>,
+    pub events: VecDeque<DockerEvent>,
+    pub exec_history: HashMap<String, Vec<ExecEntry>>,
+    pub exec_history_path: Option<PathBuf>,
+    pub filters: Vec<ContainerFilter>,
+    pub group_by: Option<GroupBy>,
+    pub group_state: ListState,
+    pub host_resources: Option<HostResources>,
+    pub host_tab: Option<String>,
+    pub images: StatefulList<ImageItem>,
+    pub io_view: Option<IoView>,
+    pub last_update: Option<Instant>,
+    pub latency: Option<Duration>,
+    pub log_json: Option<JsonView>,
+    pub log_lock: Option<ContainerId>,
+    pub log_matches: VecDeque<LogMatch>,
+    pub log_time: LogTime,
+    pub marked: HashSet<ContainerId>,
+    pub marked_controls: StatefulList<DockerControls>,
+    pub networks: StatefulList<NetworkItem>,
+    pub notes: HashMap<String, String>,
+    pub notes_path: Option<PathBuf>,
+    pub project_controls: StatefulList<DockerControls>,
+    pub recent: Recent,
+    pub recreated: Recreated,
+    pub refresh_stretch: Option<Duration>,
+    pub resources_changed: HashSet<(Option<String>, SelectablePanel)>,
+    pub schedule_key: usize,
+    pub schedules: Vec<Schedule>,
+    pub search: Option<String>,
+    pub select_pending: Option<usize>,
+    pub selected_group: Option<String>,
+    pub services: StatefulList<ServiceItem>,
     pub sorted_by: Option<(Header, SortedOrder)>,
+    pub stale: bool,
+    pub swarm: HashSet<Option<String>>,
+    pub top_mode: Option<TopMode>,
+    pub unseen_matches: usize,
+    pub updated: bool,
+    pub visible_containers: HashSet<ContainerId>,
+    pub volumes: StatefulList<VolumeItem>,
     pub args: CliArgs,
 }
 
 impl AppData {
     /// Generate a default app_state
     pub fn default(args: CliArgs) -> Self {
+        let mut project_controls = StatefulList::new(DockerControls::gen_project_vec());
+        project_controls.start();
+        let mut marked_controls = StatefulList::new(DockerControls::gen_batch_vec());
+        marked_controls.start();
         Self {
+            filters: args.filters.clone(),
+            log_time: args.log_time,
             args,
+            boost: None,
+            clock_skew: None,
+            collapsed_groups: HashSet::new(),
+            chart_view: ChartView::default(),
             containers: StatefulList::new(vec![]),
+            daemon_info: None,
             error: None,
+            events: VecDeque::new(),
+            exec_history: HashMap::new(),
+            exec_history_path: None,
+            group_by: None,
+            group_state: ListState::default(),
+            host_resources: None,
+            host_tab: None,
+            images: StatefulList::new(vec![]),
+            io_view: None,
+            last_update: None,
+            latency: None,
+            log_json: None,
+            log_lock: None,
+            log_matches: VecDeque::new(),
+            marked: HashSet::new(),
+            marked_controls,
+            networks: StatefulList::new(vec![]),
+            notes: HashMap::new(),
+            notes_path: None,
+            project_controls,
+            recent: Recent::default(),
+            recreated: Recreated::default(),
+            refresh_stretch: None,
+            resources_changed: HashSet::new(),
+            schedule_key: 0,
+            schedules: vec![],
+            search: None,
+            select_pending: Some(1),
+            selected_group: None,
+            services: StatefulList::new(vec![]),
             sorted_by: None,
+            stale: false,
+            swarm: HashSet::new(),
+            top_mode: None,
+            unseen_matches: 0,
+            updated: false,
+            visible_containers: HashSet::new(),
+            volumes: StatefulList::new(vec![]),
         }
     }
 
@@ -93,1757 +418,6083 @@ impl AppData {
             .as_secs()
     }
 
-    /// Container sort related methods
-
-    /// Change the sorted order, also set the selected container state to match new order
-    fn set_sorted(&mut self, x: Option<(Header, SortedOrder)>) {
-        self.sorted_by = x;
-        self.sort_containers();
-        self.containers
-            .state
-            .select(self.containers.items.iter().position(|i| {
-                self.get_selected_container_id()
-                    .map_or(false, |id| i.id == id)
-            }));
+    /// Set the difference, in seconds, between the Docker daemon's clock and the local clock, positive means the daemon is ahead
+    pub const fn set_clock_skew(&mut self, skew: Option<i64>) {
+        self.clock_skew = skew;
     }
 
-    /// Remove the sorted header & order, and sort by default - created datetime
-    pub fn reset_sorted(&mut self) {
-        self.set_sorted(None);
+    /// Get the clock skew, only if it's large enough to make log timestamps & uptimes misleading
+    pub fn get_clock_skew(&self) -> Option<i64> {
+        self.clock_skew
+            .filter(|i| i.unsigned_abs() >= CLOCK_SKEW_LIMIT)
     }
 
-    /// Sort containers based on a given header, if headings match, and already ascending, remove sorting
-    pub fn set_sort_by_header(&mut self, selected_header: Header) {
-        let mut output = Some((selected_header, SortedOrder::Asc));
-        if let Some((current_header, order)) = self.get_sorted() {
-            if current_header == selected_header {
-                match order {
-                    SortedOrder::Desc => output = None,
-                    SortedOrder::Asc => output = Some((selected_header, SortedOrder::Desc)),
-                }
-            }
-        }
-        self.set_sorted(output);
+    /// Set the details of the primary daemon, true if this is the first time they're known, & the daemon has warnings, so that they're only announced once
+    pub fn set_daemon_info(&mut self, info: DaemonInfo) -> bool {
+        let announce = self.daemon_info.is_none() && !info.warnings.is_empty();
+        self.daemon_info = Some(info);
+        announce
     }
 
-    pub const fn get_sorted(&self) -> Option<(Header, SortedOrder)> {
-        self.sorted_by
+    pub const fn get_daemon_info(&self) -> Option<&DaemonInfo> {
+        self.daemon_info.as_ref()
     }
 
-    /// Sort the containers vec, based on a heading (and if clash, then by name), either ascending or descending,
-    /// If not sort set, then sort by created time
-    pub fn sort_containers(&mut self) {
-        if let Some((head, ord)) = self.sorted_by {
-            let sort_closure = |a: &ContainerItem, b: &ContainerItem| -> std::cmp::Ordering {
-                let item_ord = match ord {
-                    SortedOrder::Asc => (a, b),
-                    SortedOrder::Desc => (b, a),
-                };
-                match head {
-                    Header::State => item_ord
-                        .0
-                        .state
-                        .order()
-                        .cmp(&item_ord.1.state.order())
-                        .then_with(|| item_ord.0.name.get().cmp(item_ord.1.name.get())),
-                    Header::Status => item_ord
-                        .0
-                        .status
-                        .cmp(&item_ord.1.status)
-                        .then_with(|| item_ord.0.name.get().cmp(item_ord.1.name.get())),
-                    Header::Cpu => item_ord
-                        .0
-                        .cpu_stats
-                        .back()
-                        .cmp(&item_ord.1.cpu_stats.back())
-                        .then_with(|| item_ord.0.name.get().cmp(item_ord.1.name.get())),
-                    Header::Memory => item_ord
-                        .0
-                        .mem_stats
-                        .back()
-                        .cmp(&item_ord.1.mem_stats.back())
-                        .then_with(|| item_ord.0.name.get().cmp(item_ord.1.name.get())),
+    /// Number of warnings reported by the primary daemon
+    pub fn get_daemon_warnings_len(&self) -> usize {
+        self.daemon_info.as_ref().map_or(0, |i| i.warnings.len())
+    }
 
-                    Header::Id => item_ord
-                        .0
-                        .id
-                        .cmp(&item_ord.1.id)
-                        .then_with(|| item_ord.0.name.get().cmp(item_ord.1.name.get())),
-                    Header::Image => item_ord
-                        .0
-                        .image
-                        .get()
-                        .cmp(item_ord.1.image.get())
-                        .then_with(|| item_ord.0.name.get().cmp(item_ord.1.name.get())),
-                    Header::Rx => item_ord
-                        .0
-                        .rx
-                        .cmp(&item_ord.1.rx)
-                        .then_with(|| item_ord.0.name.get().cmp(item_ord.1.name.get())),
-                    Header::Tx => item_ord
-                        .0
-                        .tx
-                        .cmp(&item_ord.1.tx)
-                        .then_with(|| item_ord.0.name.get().cmp(item_ord.1.name.get())),
+    /// Set the cpu count, & total memory, of the primary host, as reported by the daemon
+    pub const fn set_host_resources(&mut self, resources: Option<HostResources>) {
+        self.host_resources = resources;
+    }
 
-                    Header::Name => item_ord
-                        .0
-                        .name
-                        .get()
-                        .cmp(item_ord.1.name.get())
-                        .then_with(|| item_ord.0.id.cmp(&item_ord.1.id)),
-                }
-            };
-            self.containers.items.sort_by(sort_closure);
-        } else {
-            self.containers.items.sort_by(|a, b| {
-                a.created
-                    .cmp(&b.created)
-                    .then_with(|| a.name.get().cmp(b.name.get()))
+    /// Combined cpu & memory use of the primary host's containers, None until the host's resources are known
+    #[allow(clippy::cast_precision_loss)]
+    pub fn get_host_usage(&self) -> Option<HostUsage> {
+        let resources = self.host_resources.filter(|i| i.cpus > 0)?;
+        let (cpu, memory) = self
+            .containers
+            .items
+            .iter()
+            .filter(|i| i.host.is_none() && i.state.is_alive() && self.is_filter_shown(i))
+            .fold((0.0, 0), |(cpu, memory), i| {
+                (
+                    cpu + i.cpu_stats.back().map_or(0.0, Stats::get_value),
+                    memory + i.mem_stats.back().map_or(0, |i| i.get()),
+                )
             });
-        }
+        Some(HostUsage {
+            resources,
+            cpu: cpu / resources.cpus as f64,
+            memory: ByteStats::new(memory),
+        })
     }
 
-    /// Container state methods
-
-    /// Just get the total number of containers
-    pub fn get_container_len(&self) -> usize {
-        self.containers.items.len()
+    /// Set the round-trip time of the most recent container list request, None if the request failed
+    pub const fn set_latency(&mut self, latency: Option<Duration>) {
+        self.latency = latency;
     }
 
-    /// Get all the ContainerItems
-    pub fn get_container_items(&self) -> &[ContainerItem] {
-        &self.containers.items
+    pub const fn get_latency(&self) -> Option<Duration> {
+        self.latency
     }
 
-    /// Get title for containers section
-    pub fn container_title(&self) -> String {
-        self.containers.get_state_title()
+    /// Set the effective refresh interval, when it has been stretched to stay under the `--api-limit`, None if it hasn't been
+    pub const fn set_refresh_stretch(&mut self, interval: Option<Duration>) {
+        self.refresh_stretch = interval;
     }
 
-    /// Select the first container
-    pub fn containers_start(&mut self) {
-        self.containers.start();
+    pub const fn get_refresh_stretch(&self) -> Option<Duration> {
+        self.refresh_stretch
     }
 
-    /// select the last container
-    pub fn containers_end(&mut self) {
-        self.containers.end();
+    /// Seconds that an update can be waited on before it's stale, a few update intervals, which are longer when the api is rate limited
+    pub fn get_stale_limit(&self) -> u64 {
+        let interval = self
+            .refresh_stretch
+            .unwrap_or_else(|| Duration::from_millis(u64::from(self.args.docker_interval)));
+        (interval * STALE_INTERVALS).as_secs().max(STALE_MIN)
     }
 
-    /// Select the next container
-    pub fn containers_next(&mut self) {
-        self.containers.next();
+    /// Seconds that the selected container's stats, & logs, have been waited on, only once they're stale
+    pub fn get_selected_stale(&self) -> (Option<u64>, Option<u64>) {
+        let (now, limit) = (Self::get_systemtime(), self.get_stale_limit());
+        self.get_selected_container().map_or((None, None), |i| {
+            (
+                i.stats_waiting.stale(now, limit),
+                i.logs_waiting.stale(now, limit),
+            )
+        })
     }
 
-    /// select the previous container
-    pub fn containers_previous(&mut self) {
-        self.containers.previous();
+    /// Start waiting for a container's stats, called as each stats request is sent
+    pub fn wait_for_stats(&mut self, id: &ContainerId) {
+        if let Some(container) = self.get_container_by_id(id) {
+            container.stats_waiting.start(Self::get_systemtime());
+        }
     }
 
-    /// Get ListState of containers
-    pub fn get_container_state(&mut self) -> &mut ListState {
-        &mut self.containers.state
+    /// Start waiting for a container's logs, called as each log request is sent
+    pub fn wait_for_logs(&mut self, id: &ContainerId) {
+        if let Some(container) = self.get_container_by_id(id) {
+            container.logs_waiting.start(Self::get_systemtime());
+        }
     }
 
-    /// Get Option of the current selected container
-    pub fn get_selected_container(&self) -> Option<&ContainerItem> {
+    /// The containers whose logs are updated, with when they were last updated, the selected container, the container the logs panel is locked to, and every running container with a `--watch`
+    pub fn get_log_update_ids(&self) -> Vec<(ContainerId, u64)> {
+        let selected = self.get_selected_container_id();
         self.containers
-            .state
-            .selected()
-            .and_then(|i| self.containers.items.get(i))
+            .items
+            .iter()
+            .filter(|i| !i.is_oxker)
+            .filter(|i| {
+                selected.as_ref() == Some(&i.id)
+                    || self.log_lock.as_ref() == Some(&i.id)
+                    || (i.state.is_alive()
+                        && self.args.watch.iter().any(|w| w.applies(i.name.get())))
+            })
+            .map(|i| (i.id.clone(), i.last_updated))
+            .collect()
     }
 
-    /// Find the longest port when it's transformed into a string, defaults are header lens (ip, private, public)
-    pub fn get_longest_port(&self) -> (usize, usize, usize) {
-        let mut longest_ip = 5;
-        let mut longest_private = 10;
-        let mut longest_public = 9;
-
-        for item in &self.containers.items {
-            // if let Some(ports) = item.ports.as_ref() {
-            longest_ip = longest_ip.max(
-                item.ports
-                    .iter()
-                    .map(ContainerPorts::len_ip)
-                    .max()
-                    .unwrap_or(3),
-            );
-            longest_private = longest_private.max(
-                item.ports
-                    .iter()
-                    .map(ContainerPorts::len_private)
-                    .max()
-                    .unwrap_or(8),
-            );
-            longest_public = longest_public.max(
-                item.ports
-                    .iter()
-                    .map(ContainerPorts::len_public)
-                    .max()
-                    .unwrap_or(6),
-            );
-        }
-        // }
+    /// Log lines that matched a `--watch`, oldest first
+    pub const fn get_log_matches(&self) -> &VecDeque<LogMatch> {
+        &self.log_matches
+    }
 
-        (longest_ip, longest_private, longest_public)
-        // )
+    /// The lines logged around a match, None if the container has since been removed
+    pub fn get_log_context(
+        &self,
+        found: &LogMatch,
+        lines: usize,
+    ) -> Option<(Vec<ListItem<'static>>, usize)> {
+        self.containers
+            .items
+            .iter()
+            .find(|i| i.id == found.id)
+            .zip(found.index)
+            .and_then(|(i, index)| i.logs.context(index, lines, |tz| self.render_stamp(tz)))
     }
-    /// Get Option of the current selected container's ports, sorted by private port
-    pub fn get_selected_ports(&mut self) -> Option<(Vec<ContainerPorts>, State)> {
-        if let Some(item) = self.get_mut_selected_container() {
-            let mut ports = item.ports.clone();
-            ports.sort_by(|a, b| a.private.cmp(&b.private));
-            return Some((ports, item.state));
-        }
-        None
+
+    /// Number of matches, of new log lines, since the matches were last viewed
+    pub const fn get_unseen_matches(&self) -> usize {
+        self.unseen_matches
     }
 
-    /// Get mutable Option of the current selected container
-    fn get_mut_selected_container(&mut self) -> Option<&mut ContainerItem> {
-        self.containers
-            .state
-            .selected()
-            .and_then(|i| self.containers.items.get_mut(i))
+    pub const fn clear_unseen_matches(&mut self) {
+        self.unseen_matches = 0;
     }
 
-    /// return a mutable container by given id
-    fn get_container_by_id(&mut self, id: &ContainerId) -> Option<&mut ContainerItem> {
-        self.containers.items.iter_mut().find(|i| &i.id == id)
+    /// Add an event from the Docker events stream, only the most recent MAX_EVENTS are kept
+    pub fn push_event(&mut self, event: DockerEvent) {
+        self.events.push_back(event);
+        let excess = self.events.len().saturating_sub(events::MAX_EVENTS);
+        self.events.drain(..excess);
     }
 
-    /// Get the ContainerName of by ID
-    pub fn get_container_name_by_id(&mut self, id: &ContainerId) -> Option<ContainerName> {
-        self.containers
+    /// Record a die, or start, of a container, from the events stream, as its restarts, returns the alert, once the restarts make a restart storm
+    pub fn record_restart(&mut self, event: &DockerEvent, now: u64) -> Option<String> {
+        let id = event.id.as_ref()?;
+        let item = self
+            .containers
             .items
             .iter_mut()
-            .find(|i| &i.id == id)
-            .map(|i| i.name.clone())
+            .find(|i| i.id == *id && i.host == event.host)?;
+        match event.kind {
+            EventKind::Container(TimelineKind::Die(_)) => {
+                item.restart_storm.die();
+                None
+            }
+            EventKind::Container(TimelineKind::Start) if item.restart_storm.start(now) => item
+                .restart_storm
+                .alert_text()
+                .map(|text| format!("{} {text}", item.name.get())),
+            _ => None,
+        }
     }
 
-    /// Find the id of the currently selected container.
-    /// If any containers on system, will always return a ContainerId
-    /// Only returns None when no containers found.
-    pub fn get_selected_container_id(&self) -> Option<ContainerId> {
-        self.get_selected_container().map(|i| i.id.clone())
+    /// Containers that are in a restart storm, restarting so often that they're crash looping
+    pub fn get_restart_storms_len(&self) -> usize {
+        self.containers
+            .items
+            .iter()
+            .filter(|i| i.restart_storm.storm().is_some())
+            .count()
     }
 
-    /// Get the Id and State for the currently selected container - used by the exec check method
-    pub fn get_selected_container_id_state_name(&self) -> Option<(ContainerId, State, String)> {
-        self.get_selected_container()
-            .map(|i| (i.id.clone(), i.state, i.name.get().to_owned()))
+    pub const fn get_events(&self) -> &VecDeque<DockerEvent> {
+        &self.events
     }
-    /// Selected DockerCommand methods
 
-    /// Get the current selected docker command
-    /// So know which command to execute
-    pub fn selected_docker_controls(&self) -> Option<DockerControls> {
-        self.get_selected_container().and_then(|i| {
-            i.docker_controls.state.selected().and_then(|x| {
-                i.docker_controls
-                    .items
-                    .get(x)
-                    .map(std::borrow::ToOwned::to_owned)
+    /// The latest control of a container, from elsewhere, within the last CONCURRENT_WINDOW seconds, & how many seconds ago it was
+    /// Any event within OWN_CONTROL seconds of one of oxker's own control attempts is taken to be caused by it, so isn't from elsewhere
+    pub fn concurrent_event(&self, id: &ContainerId) -> Option<(&DockerEvent, u64)> {
+        let item = self.containers.items.iter().find(|i| &i.id == id)?;
+        let now = Self::get_systemtime();
+        // Event times are the daemon's, its skew is only known for the primary host
+        let skew = if item.host.is_none() {
+            self.clock_skew.unwrap_or_default()
+        } else {
+            0
+        };
+        self.events
+            .iter()
+            .rev()
+            .filter(|i| i.id.as_ref() == Some(id) && i.host == item.host && i.is_control())
+            .filter_map(|i| u64::try_from(i.time - skew).ok().map(|time| (i, time)))
+            .take_while(|(_, time)| now.saturating_sub(*time) <= events::CONCURRENT_WINDOW)
+            .find(|(_, time)| {
+                !item.attempts.iter().any(|attempt| {
+                    (attempt.time.saturating_sub(events::OWN_CONTROL)..=attempt.time + 1)
+                        .contains(time)
+                })
             })
-        })
+            .map(|(i, time)| (i, now.saturating_sub(time)))
     }
 
-    /// Change selected choice of docker commands of selected container
-    pub fn docker_controls_next(&mut self) {
-        if let Some(i) = self.get_mut_selected_container() {
-            i.docker_controls.next();
+    /// Set the containers loaded from the cache, shown until the first update from the primary host replaces them
+    pub fn load_cached(&mut self, items: Vec<ContainerItem>) {
+        if items.is_empty() {
+            return;
         }
+        self.containers.items = items;
+        self.containers.start();
+        self.stale = true;
     }
 
-    /// Change selected choice of docker commands of selected container
-    pub fn docker_controls_previous(&mut self) {
-        if let Some(i) = self.get_mut_selected_container() {
-            i.docker_controls.previous();
-        }
+    /// The containers shown are from the cache, and haven't yet been updated by the Docker daemon
+    pub const fn is_stale(&self) -> bool {
+        self.stale
     }
 
-    /// Change selected choice of docker commands of selected container
-    pub fn docker_controls_start(&mut self) {
-        if let Some(i) = self.get_mut_selected_container() {
-            i.docker_controls.start();
-        }
+    /// The containers have been listed by the Docker daemon, so that `--output` isn't printed before there's anything to print
+    pub const fn is_updated(&self) -> bool {
+        self.updated
     }
 
-    /// Change selected choice of docker commands of selected container
-    pub fn docker_controls_end(&mut self) {
-        if let Some(i) = self.get_mut_selected_container() {
-            i.docker_controls.end();
-        }
+    /// The Docker host currently connected to, "local" when using the default socket
+    pub fn get_host(&self) -> &str {
+        self.args.host.as_deref().unwrap_or("local")
     }
 
-    /// Get mutable Option of the currently selected container DockerControls state
-    pub fn get_control_state(&mut self) -> Option<&mut ListState> {
-        self.get_mut_selected_container()
-            .map(|i| &mut i.docker_controls.state)
+    /// More than one host is being monitored, so containers are shown in a merged view, with a host column
+    pub const fn is_multi_host(&self) -> bool {
+        !self.args.extra_hosts.is_empty()
     }
 
-    /// Get mutable Option of the currently selected container DockerControls items
-    /// TODO command or control, need a uniform name across the application
-    pub fn get_control_items(&mut self) -> Option<&mut Vec<DockerControls>> {
-        self.get_mut_selected_container()
-            .map(|i| &mut i.docker_controls.items)
+    /// Get the name of the host that a container is running on
+    pub fn container_host<'a>(&'a self, container: &'a ContainerItem) -> &'a str {
+        container.host.as_deref().unwrap_or_else(|| self.get_host())
     }
 
-    /// Logs related methods
+    /// Get the extra host of a container, None if it's on the primary host, or if the container can't be found
+    pub fn get_host_by_id(&self, id: &ContainerId) -> Option<String> {
+        self.containers
+            .items
+            .iter()
+            .find(|i| &i.id == id)
+            .and_then(|i| i.host.clone())
+    }
 
-    /// Get the title for log panel for selected container, will be either
-    /// 1) "logs x/x - container_name" where container_name is 32 chars max
-    /// 2) "logs - container_name" when no logs found, again 32 chars max
-    /// 3) "" no container currently selected - aka no containers on system
-    pub fn get_log_title(&self) -> String {
-        self.get_selected_container()
-            .map_or_else(String::new, |ci| {
-                let logs_len = ci.logs.get_state_title();
-                let prefix = if logs_len.is_empty() {
-                    String::from(" ")
-                } else {
-                    format!("{logs_len} ")
-                };
-                format!("{}- {}", prefix, ci.name.get())
-            })
+    /// Get where the logs of a container are read from when the daemon can't return them, the label of the container has precedence over the cli arg
+    pub fn get_log_fallback(&self, id: &ContainerId) -> Option<LogFallback> {
+        self.containers
+            .items
+            .iter()
+            .find(|i| &i.id == id)
+            .and_then(|i| i.hints.logs)
+            .or(self.args.log_driver_fallback)
     }
 
-    /// select next selected log line
-    pub fn log_next(&mut self) {
-        if let Some(i) = self.get_mut_selected_container() {
-            i.logs.next();
-        }
+    /// Get the currently selected host tab, None is the merged view of all hosts
+    pub fn get_host_tab(&self) -> Option<&str> {
+        self.host_tab.as_deref()
     }
 
-    /// select previous selected log line
-    pub fn log_previous(&mut self) {
-        if let Some(i) = self.get_mut_selected_container() {
-            i.logs.previous();
-        }
+    /// The host that the images, volumes, & networks panels list the resources of, the selected host tab, the primary host is None, as is the merged view of all hosts
+    pub fn get_resources_host(&self) -> Option<&str> {
+        self.host_tab.as_deref().filter(|i| *i != self.get_host())
     }
 
-    /// select last selected log line
-    pub fn log_end(&mut self) {
-        if let Some(i) = self.get_mut_selected_container() {
-            i.logs.end();
-        }
+    /// An event has changed the images, volumes, or networks, of a host, so the panel is listed again if it's shown
+    pub fn set_resources_changed(&mut self, host: Option<&str>, panel: SelectablePanel) {
+        self.resources_changed
+            .insert((host.map(ToOwned::to_owned), panel));
     }
 
-    /// select first selected log line
-    pub fn log_start(&mut self) {
-        if let Some(i) = self.get_mut_selected_container() {
-            i.logs.start();
-        }
+    /// Check, & clear, whether an event has changed the resources of the panel, on the given host, since it was last listed
+    pub fn take_resources_changed(&mut self, host: Option<&str>, panel: SelectablePanel) -> bool {
+        self.resources_changed
+            .remove(&(host.map(ToOwned::to_owned), panel))
     }
 
-    /// Get mutable Vec of current containers logs
-    pub fn get_logs(&mut self) -> Vec<ListItem<'static>> {
-        self.containers
+    /// Replace the listed images, newest first, the selected image stays selected, if it still exists
+    pub fn set_images(&mut self, mut images: Vec<ImageItem>) {
+        images.sort_by(|a, b| b.created.cmp(&a.created).then_with(|| a.id.cmp(&b.id)));
+        let selected = self.get_selected_image().map(|i| i.id.clone());
+        let index = selected
+            .and_then(|id| images.iter().position(|i| i.id == id))
+            .or_else(|| (!images.is_empty()).then_some(0));
+        self.images.items = images;
+        self.images.state.select(index);
+    }
+
+    pub fn get_images(&self) -> &[ImageItem] {
+        &self.images.items
+    }
+
+    pub fn get_selected_image(&self) -> Option<&ImageItem> {
+        self.images
             .state
             .selected()
-            .and_then(|i| self.containers.items.get_mut(i))
-            .map_or(vec![], |i| i.logs.to_vec())
+            .and_then(|i| self.images.items.get(i))
     }
 
-    /// Get mutable Option of the currently selected container Logs state
-    pub fn get_log_state(&mut self) -> Option<&mut ListState> {
-        self.containers
+    pub const fn get_images_state(&mut self) -> &mut ListState {
+        &mut self.images.state
+    }
+
+    pub fn get_images_title(&self) -> String {
+        self.images.get_state_title()
+    }
+
+    pub fn images_next(&mut self) {
+        self.images.next();
+    }
+
+    pub fn images_previous(&mut self) {
+        self.images.previous();
+    }
+
+    pub fn images_start(&mut self) {
+        self.images.start();
+    }
+
+    pub fn images_end(&mut self) {
+        self.images.end();
+    }
+
+    /// Replace the listed volumes, by name, the selected volume stays selected, if it still exists
+    /// A volume without a size keeps its previous size, as sizes are only checked every so often
+    pub fn set_volumes(&mut self, mut volumes: Vec<VolumeItem>) {
+        volumes.sort_by(|a, b| a.name.cmp(&b.name));
+        for volume in volumes.iter_mut().filter(|i| i.size.is_none()) {
+            volume.size = self
+                .volumes
+                .items
+                .iter()
+                .find(|i| i.name == volume.name)
+                .and_then(|i| i.size);
+        }
+        let selected = self.get_selected_volume().map(|i| i.name.clone());
+        let index = selected
+            .and_then(|name| volumes.iter().position(|i| i.name == name))
+            .or_else(|| (!volumes.is_empty()).then_some(0));
+        self.volumes.items = volumes;
+        self.volumes.state.select(index);
+    }
+
+    pub fn get_volumes(&self) -> &[VolumeItem] {
+        &self.volumes.items
+    }
+
+    pub fn get_selected_volume(&self) -> Option<&VolumeItem> {
+        self.volumes
             .state
             .selected()
-            .and_then(|i| self.containers.items.get_mut(i))
-            .map(|i| i.logs.state())
+            .and_then(|i| self.volumes.items.get(i))
     }
 
-    /// Chart data related methods
+    pub const fn get_volumes_state(&mut self) -> &mut ListState {
+        &mut self.volumes.state
+    }
 
-    /// Get mutable Option of the currently selected container chart data
-    pub fn get_chart_data(&mut self) -> Option<(CpuTuple, MemTuple)> {
-        self.containers
+    pub fn get_volumes_title(&self) -> String {
+        self.volumes.get_state_title()
+    }
+
+    pub fn volumes_next(&mut self) {
+        self.volumes.next();
+    }
+
+    pub fn volumes_previous(&mut self) {
+        self.volumes.previous();
+    }
+
+    pub fn volumes_start(&mut self) {
+        self.volumes.start();
+    }
+
+    pub fn volumes_end(&mut self) {
+        self.volumes.end();
+    }
+
+    /// Replace the listed networks, by name, the selected network stays selected, if it still exists
+    pub fn set_networks(&mut self, mut networks: Vec<NetworkItem>) {
+        networks.sort_by(|a, b| a.name.cmp(&b.name).then_with(|| a.id.cmp(&b.id)));
+        let selected = self.get_selected_network().map(|i| i.id.clone());
+        let index = selected
+            .and_then(|id| networks.iter().position(|i| i.id == id))
+            .or_else(|| (!networks.is_empty()).then_some(0));
+        self.networks.items = networks;
+        self.networks.state.select(index);
+    }
+
+    pub fn get_networks(&self) -> &[NetworkItem] {
+        &self.networks.items
+    }
+
+    pub fn get_selected_network(&self) -> Option<&NetworkItem> {
+        self.networks
             .state
             .selected()
-            .and_then(|i| self.containers.items.get_mut(i))
-            .map(|i| i.get_chart_data())
+            .and_then(|i| self.networks.items.get(i))
     }
 
-    /// Error related methods
+    pub const fn get_networks_state(&mut self) -> &mut ListState {
+        &mut self.networks.state
+    }
 
-    /// return single app_state error
-    pub const fn get_error(&self) -> Option<AppError> {
-        self.error
+    pub fn get_networks_title(&self) -> String {
+        self.networks.get_state_title()
     }
 
-    /// remove single app_state error
-    pub fn remove_error(&mut self) {
-        self.error = None;
+    pub fn networks_next(&mut self) {
+        self.networks.next();
     }
 
-    /// insert single app_state error
-    pub fn set_error(&mut self, error: AppError, gui_state: &Arc<Mutex<GuiState>>, status: Status) {
-        gui_state.lock().status_push(status);
-        self.error = Some(error);
+    pub fn networks_previous(&mut self) {
+        self.networks.previous();
     }
 
-    /// Check if the selected container is a dockerised version of oxker
-    /// So that can disallow commands to be send
-    /// Is a shabby way of implementing this
-    pub fn is_oxker(&self) -> bool {
-        self.get_selected_container().map_or(false, |i| i.is_oxker)
+    pub fn networks_start(&mut self) {
+        self.networks.start();
     }
 
-    /// Check if selected container is oxker and also that oxker is being run in a container
-    pub fn is_oxker_in_container(&self) -> bool {
-        self.get_selected_container()
-            .map_or(false, |i| i.is_oxker && self.args.in_container)
+    pub fn networks_end(&mut self) {
+        self.networks.end();
     }
 
-    /// Find the widths for the strings in the containers panel.
-    /// So can display nicely and evenly
-    pub fn get_width(&self) -> Columns {
-        let mut columns = Columns::new();
-        let count = |x: &str| u8::try_from(x.chars().count()).unwrap_or(12);
+    /// Record whether the host is a swarm manager, a worker can't list the services, so isn't counted
+    pub fn set_swarm(&mut self, host: Option<&str>, manager: bool) {
+        let host = host.map(ToOwned::to_owned);
+        if manager {
+            self.swarm.insert(host);
+        } else {
+            self.swarm.remove(&host);
+        }
+    }
 
-        // Should probably find a refactor here somewhere
-        for container in &self.containers.items {
-            let cpu_count = count(
-                &container
-                    .cpu_stats
-                    .back()
-                    .unwrap_or(&CpuStats::default())
-                    .to_string(),
-            );
+    /// The host is a swarm manager, so its services panel can be shown
+    pub fn is_swarm(&self, host: Option<&str>) -> bool {
+        self.swarm.contains(&host.map(ToOwned::to_owned))
+    }
 
-            let mem_current_count = count(
-                &container
-                    .mem_stats
-                    .back()
-                    .unwrap_or(&ByteStats::default())
-                    .to_string(),
-            );
+    /// Replace the listed services, grouped by stack, then by name, services that aren't in a stack are listed last, the selected service stays selected, if it still exists
+    pub fn set_services(&mut self, mut services: Vec<ServiceItem>) {
+        services.sort_by(|a, b| {
+            (a.stack.is_none(), &a.stack, &a.name, &a.id).cmp(&(
+                b.stack.is_none(),
+                &b.stack,
+                &b.name,
+                &b.id,
+            ))
+        });
+        let selected = self.get_selected_service().map(|i| i.id.clone());
+        let index = selected
+            .and_then(|id| services.iter().position(|i| i.id == id))
+            .or_else(|| (!services.is_empty()).then_some(0));
+        self.services.items = services;
+        self.services.state.select(index);
+    }
 
-            // Issue here!
-            columns.cpu.1 = columns.cpu.1.max(cpu_count);
-            columns.image.1 = columns.image.1.max(count(&container.image.to_string()));
-            columns.mem.1 = columns.mem.1.max(mem_current_count);
-            columns.mem.2 = columns.mem.2.max(count(&container.mem_limit.to_string()));
-            columns.name.1 = columns.name.1.max(count(&container.name.to_string()));
-            columns.net_rx.1 = columns.net_rx.1.max(count(&container.rx.to_string()));
-            columns.net_tx.1 = columns.net_tx.1.max(count(&container.tx.to_string()));
-            columns.state.1 = columns.state.1.max(count(&container.state.to_string()));
-            columns.status.1 = columns.status.1.max(count(&container.status));
-        }
-        columns
+    pub fn get_services(&self) -> &[ServiceItem] {
+        &self.services.items
     }
 
-    /// Update related methods
+    /// The listed services of the stack
+    pub fn get_stack_services(&self, stack: &str) -> Vec<ServiceItem> {
+        self.services
+            .items
+            .iter()
+            .filter(|i| i.stack.as_deref() == Some(stack))
+            .cloned()
+            .collect()
+    }
 
-    /// Update container mem, cpu, & network stats, in single function so only need to call .lock() once
-    /// Will also, if a sort is set, sort the containers
-    pub fn update_stats_by_id(
-        &mut self,
-        id: &ContainerId,
-        cpu_stat: Option<f64>,
-        mem_stat: Option<u64>,
-        mem_limit: u64,
-        rx: u64,
-        tx: u64,
-    ) {
-        if let Some(container) = self.get_container_by_id(id) {
-            if container.cpu_stats.len() >= 60 {
-                container.cpu_stats.pop_front();
-            }
-            if container.mem_stats.len() >= 60 {
-                container.mem_stats.pop_front();
-            }
+    pub fn get_selected_service(&self) -> Option<&ServiceItem> {
+        self.services
+            .state
+            .selected()
+            .and_then(|i| self.services.items.get(i))
+    }
 
-            if let Some(cpu) = cpu_stat {
-                container.cpu_stats.push_back(CpuStats::new(cpu));
-            }
-            if let Some(mem) = mem_stat {
-                container.mem_stats.push_back(ByteStats::new(mem));
-            }
+    pub const fn get_services_state(&mut self) -> &mut ListState {
+        &mut self.services.state
+    }
 
-            container.rx.update(rx);
-            container.tx.update(tx);
-            container.mem_limit.update(mem_limit);
+    pub fn get_services_title(&self) -> String {
+        self.services.get_state_title()
+    }
+
+    pub fn services_next(&mut self) {
+        self.services.next();
+    }
+
+    pub fn services_previous(&mut self) {
+        self.services.previous();
+    }
+
+    pub fn services_start(&mut self) {
+        self.services.start();
+    }
+
+    pub fn services_end(&mut self) {
+        self.services.end();
+    }
+
+    /// The host tabs, with the running & total containers of each, the current tab is marked, None with a single host, as there are no tabs
+    pub fn host_picker(&self) -> Option<HostPicker> {
+        if !self.is_multi_host() {
+            return None;
         }
-        // need to benchmark this?
-        self.sort_containers();
+        let hosts = std::iter::once(None)
+            .chain(std::iter::once(Some(self.get_host())))
+            .chain(self.args.extra_hosts.iter().map(|i| Some(i.as_str())));
+        let rows = hosts
+            .map(|host| {
+                let containers = self
+                    .containers
+                    .items
+                    .iter()
+                    .filter(|i| host.is_none_or(|host| self.container_host(i) == host))
+                    .collect::<Vec<_>>();
+                HostRow {
+                    host: host.map(ToOwned::to_owned),
+                    running: containers.iter().filter(|i| i.state.is_alive()).count(),
+                    total: containers.len(),
+                }
+            })
+            .collect::<Vec<_>>();
+        let current = rows
+            .iter()
+            .position(|i| i.host.as_deref() == self.host_tab.as_deref())
+            .unwrap_or_default();
+        Some(HostPicker::new(rows, current))
     }
 
-    /// Update, or insert, containers
-    pub fn update_containers(&mut self, all_containers: &mut [ContainerSummary]) {
-        let all_ids = self
-            .containers
+    /// Switch to the tab of the given host, None is the merged view of all hosts
+    /// Returns false if the host isn't monitored, with a single host there are no tabs, so only that host is accepted
+    pub fn set_host_tab(&mut self, host: Option<&str>) -> bool {
+        let Some(host) = host else {
+            self.host_tab = None;
+            self.selected_group = None;
+            self.ensure_visible();
+            return true;
+        };
+        if !self.is_multi_host() {
+            return host == self.get_host();
+        }
+        if host != self.get_host() && !self.args.extra_hosts.iter().any(|i| i == host) {
+            return false;
+        }
+        self.host_tab = Some(host.to_owned());
+        self.selected_group = None;
+        self.ensure_visible();
+        true
+    }
+
+    /// Check if a container should be displayed in the currently selected host tab, is shown by the filters, and matches the search, if there is one
+    fn is_visible(&self, container: &ContainerItem) -> bool {
+        (self.host_tab.is_none()
+            || self.host_tab.as_deref() == Some(self.container_host(container)))
+            && self.is_filter_shown(container)
+            && !matches!(&self.search, Some(search) if !self.is_search_match(container, search))
+    }
+
+    /// The containers shown by the filter rules, as printed by `--output`
+    pub fn render_output(&self, format: OutputFormat, stats: bool, now: i64) -> String {
+        output::render(format, &self.get_filter_shown(), stats, now)
+    }
+
+    /// The summary that `oxker status` prints, of the containers that the filters show
+    pub fn render_status(&self, format: StatusFormat) -> String {
+        output::status(format, &self.get_filter_shown())
+    }
+
+    /// The containers that every enabled filter rule shows, in order
+    fn get_filter_shown(&self) -> Vec<&ContainerItem> {
+        self.containers
             .items
             .iter()
-            .map(|i| i.id.clone())
-            .collect::<Vec<_>>();
+            .filter(|i| self.is_filter_shown(i))
+            .collect()
+    }
 
-        // Only sort it no containers currently set, as afterwards the order is fixed
-        if self.containers.items.is_empty() {
-            all_containers.sort_by(|a, b| a.created.cmp(&b.created));
+    /// Check if a container is shown by every enabled filter rule
+    pub fn is_filter_shown(&self, container: &ContainerItem) -> bool {
+        ContainerFilter::shows(&self.filters, container)
+    }
+
+    /// The filter rules, in the order given, or added
+    pub fn get_filters(&self) -> &[ContainerFilter] {
+        &self.filters
+    }
+
+    /// Add a filter rule, e.g. `status!=exited`, the labels of a new label rule are only known once the containers are next updated
+    pub fn add_filter(&mut self, input: &str) -> Result<(), String> {
+        self.filters.push(ContainerFilter::parse(input)?);
+        self.selected_group = None;
+        self.ensure_visible();
+        Ok(())
+    }
+
+    /// Turn a filter rule off, or back on
+    pub fn toggle_filter(&mut self, index: usize) {
+        if let Some(filter) = self.filters.get_mut(index) {
+            filter.enabled = !filter.enabled;
+            self.selected_group = None;
+            self.ensure_visible();
         }
+    }
 
-        if !all_containers.is_empty() && self.containers.state.selected().is_none() {
-            self.containers.start();
+    /// Remove a filter rule, the containers it hid are shown again
+    pub fn remove_filter(&mut self, index: usize) {
+        if index < self.filters.len() {
+            self.filters.remove(index);
+            self.ensure_visible();
         }
+    }
+
+    /// The number of running containers, & of every container, shown by the filters
+    pub fn get_shown_len(&self) -> (usize, usize) {
+        self.containers
+            .items
+            .iter()
+            .filter(|i| self.is_filter_shown(i))
+            .fold((0, 0), |(running, total), i| {
+                (running + usize::from(i.state == State::Running), total + 1)
+            })
+    }
 
-        for (index, id) in all_ids.iter().enumerate() {
-            if !all_containers
+    /// Case insensitive match of the search against a container's name, image, note, or `--label-column` values
+    /// A search of "label=value", for a `--label-column`, only matches against the value of that label
+    fn is_search_match(&self, container: &ContainerItem, search: &str) -> bool {
+        let search = search.to_lowercase();
+        if let Some((label, value)) = search.split_once('=') {
+            if let Some(index) = self
+                .args
+                .label_columns
                 .iter()
-                .filter_map(|i| i.id.as_ref())
-                .any(|x| x == id.get())
+                .position(|i| i.to_lowercase() == label.trim())
             {
-                // If removed container is currently selected, then change selected to previous
-                // This will default to 0 in any edge cases
-                if self.containers.state.selected().is_some() {
-                    self.containers.previous();
-                }
-                // Check is some, else can cause out of bounds error, if containers get removed before a docker update
-                if self.containers.items.get(index).is_some() {
-                    self.containers.items.remove(index);
-                }
+                return container
+                    .label_values
+                    .get(index)
+                    .and_then(Option::as_deref)
+                    .is_some_and(|i| i.to_lowercase().contains(value.trim()));
             }
         }
+        [
+            Some(container.name.get()),
+            Some(container.image.get()),
+            self.get_note(container.name.get()),
+        ]
+        .into_iter()
+        .chain(container.label_values.iter().map(Option::as_deref))
+        .flatten()
+        .any(|i| i.to_lowercase().contains(&search))
+    }
 
-        for i in all_containers {
-            if let Some(id) = i.id.as_ref() {
-                let name = i.names.as_mut().map_or(String::new(), |names| {
-                    names.first_mut().map_or(String::new(), |f| {
-                        if f.starts_with('/') {
-                            f.remove(0);
-                        }
-                        (*f).to_string()
-                    })
-                });
+    /// Get the current containers search, None if not searching
+    pub fn get_search(&self) -> Option<&str> {
+        self.search.as_deref()
+    }
 
-                let ports = i.ports.as_ref().map_or(vec![], |i| {
-                    i.iter().map(ContainerPorts::from).collect::<Vec<_>>()
-                });
+    /// Set, or clear, the containers search, an empty search still hides nothing, but keeps the search open
+    pub fn set_search(&mut self, search: Option<String>) {
+        self.search = search;
+        self.selected_group = None;
+        self.ensure_visible();
+    }
 
-                let id = ContainerId::from(id.as_str());
+    /// Type a char into the search
+    pub fn search_push(&mut self, c: char) {
+        let search = format!("{}{c}", self.search.as_deref().unwrap_or_default());
+        self.set_search(Some(search));
+    }
 
-                let is_oxker = i
-                    .command
-                    .as_ref()
-                    .map_or(false, |i| i.starts_with(ENTRY_POINT));
+    /// Remove the last char of the search, the search remains, even once empty
+    pub fn search_pop(&mut self) {
+        if let Some(search) = self.search.as_mut() {
+            search.pop();
+        }
+    }
 
-                let state = State::from(i.state.as_ref().map_or("dead", |z| z));
-                let status = i
-                    .status
-                    .as_ref()
-                    .map_or(String::new(), std::clone::Clone::clone);
+    /// Schedule an action on a container, by name, e.g. `restart at 03:00`, schedules are kept until oxker quits
+    pub fn add_schedule(
+        &mut self,
+        name: &str,
+        host: Option<&str>,
+        input: &str,
+        now: u64,
+    ) -> Result<(), String> {
+        let schedule = Schedule::new(
+            self.schedule_key,
+            name.to_owned(),
+            host.map(ToOwned::to_owned),
+            input,
+            now,
+        )?;
+        self.schedule_key += 1;
+        self.schedules.push(schedule);
+        Ok(())
+    }
 
-                let image = i
-                    .image
-                    .as_ref()
-                    .map_or(String::new(), std::clone::Clone::clone);
+    pub fn get_schedules(&self) -> &[Schedule] {
+        &self.schedules
+    }
 
-                let created = i
-                    .created
-                    .map_or(0, |i| u64::try_from(i).unwrap_or_default());
-                // If container info already in containers Vec, then just update details
-                if let Some(item) = self.get_container_by_id(&id) {
-                    if item.name.get() != name {
-                        item.name.set(name);
-                    };
-                    if item.status != status {
-                        item.status = status;
-                    };
-                    if item.state != state {
-                        item.docker_controls.items = DockerControls::gen_vec(state);
-                        // Update the list state, needs to be None if the gen_vec returns an empty vec
-                        match state {
-                            State::Removing | State::Restarting | State::Unknown => {
-                                item.docker_controls.state.select(None);
-                            }
-                            _ => item.docker_controls.start(),
-                        };
-                        item.state = state;
-                    };
+    pub fn remove_schedule(&mut self, index: usize) {
+        if index < self.schedules.len() {
+            self.schedules.remove(index);
+        }
+    }
 
-                    item.ports = ports;
+    /// The schedules, of containers on the given host, that are due, a schedule whose container can't be found is failed straight away
+    pub fn take_due_schedules(&mut self, host: Option<&str>, now: u64) -> Vec<ScheduleRun> {
+        let mut output = vec![];
+        for schedule in &mut self.schedules {
+            if schedule.host.as_deref() != host || !schedule.take_due(now) {
+                continue;
+            }
+            let id = self
+                .containers
+                .items
+                .iter()
+                .find(|i| i.host.as_deref() == host && i.name.get() == schedule.name)
+                .map(|i| i.id.clone());
+            if let Some(id) = id {
+                output.push(ScheduleRun {
+                    key: schedule.key,
+                    id,
+                    name: schedule.name.clone(),
+                    action: schedule.action,
+                });
+            } else {
+                schedule.last = Some((now, Err("container not found".to_owned())));
+            }
+        }
+        output
+    }
 
-                    if item.image.get() != image {
-                        item.image.set(image);
-                    };
-                } else {
-                    // container not known, so make new ContainerItem and push into containers Vec
-                    let container = ContainerItem::new(
-                        created, id, image, is_oxker, name, ports, state, status,
-                    );
-                    self.containers.items.push(container);
+    /// Set the outcome of a schedule's run, ignored if the schedule has since been removed
+    pub fn set_schedule_outcome(&mut self, key: usize, now: u64, outcome: Result<(), String>) {
+        if let Some(schedule) = self.schedules.iter_mut().find(|i| i.key == key) {
+            schedule.last = Some((now, outcome));
+        }
+    }
+
+    /// Set the location of the notes file, and load any notes saved in it
+    pub fn load_notes(&mut self, path: Option<PathBuf>) {
+        if let Some(path) = path.as_ref() {
+            self.notes = notes::load(path);
+        }
+        self.notes_path = path;
+    }
+
+    /// Get the note attached to a container, by name
+    pub fn get_note(&self, name: &str) -> Option<&str> {
+        self.notes.get(name).map(String::as_str)
+    }
+
+    /// Get the note attached to the selected container
+    pub fn get_selected_note(&self) -> Option<&str> {
+        self.get_selected_container()
+            .and_then(|i| self.get_note(i.name.get()))
+    }
+
+    /// Attach a note to a container, by name, an empty note removes it, the notes are then saved, if there's a notes file
+    pub fn set_note(&mut self, name: &str, note: &str) -> std::io::Result<()> {
+        let note = note.trim();
+        if note.is_empty() {
+            self.notes.remove(name);
+        } else {
+            self.notes.insert(name.to_owned(), note.to_owned());
+        }
+        self.notes_path
+            .as_ref()
+            .map_or(Ok(()), |path| notes::save(path, &self.notes))
+    }
+
+    /// Set the location of the exec history file, and load any history saved in it
+    pub fn load_exec_history(&mut self, path: Option<PathBuf>) {
+        if let Some(path) = path.as_ref() {
+            self.exec_history = exec_history::load(path);
+        }
+        self.exec_history_path = path;
+    }
+
+    /// Get the commands run in containers of an image, most recent first
+    pub fn get_exec_history(&self, image: &str) -> &[ExecEntry] {
+        self.exec_history.get(image).map_or(&[], Vec::as_slice)
+    }
+
+    /// Record a command run in a container, by its image, the history is then saved, if there's a history file
+    pub fn record_exec(&mut self, image: &str, entry: &ExecEntry) -> std::io::Result<()> {
+        exec_history::record(
+            self.exec_history.entry(image.to_owned()).or_default(),
+            entry,
+        );
+        self.exec_history_path
+            .as_ref()
+            .map_or(Ok(()), |path| exec_history::save(path, &self.exec_history))
+    }
+
+    /// Containers are hidden, either by a host tab, a search, or a filter rule
+    fn is_filtered(&self) -> bool {
+        self.host_tab.is_some() || self.search.is_some() || self.filters.iter().any(|i| i.enabled)
+    }
+
+    /// If the selected container is hidden in the current host tab, or by the search, select the first visible row
+    fn ensure_visible(&mut self) {
+        if self.is_filtered() {
+            let rows = self.get_container_rows();
+            if self.get_selected_row(&rows).is_none() {
+                self.select_row(Some(0));
+            }
+        }
+    }
+
+    /// Containers panel is navigated by row, rather than by container, as either grouped, or filtered by host
+    fn is_row_view(&self) -> bool {
+        self.group_by.is_some() || self.is_filtered()
+    }
+
+    /// Container sort related methods
+
+    /// Change the sorted order, also set the selected container state to match new order
+    fn set_sorted(&mut self, x: Option<(Header, SortedOrder)>) {
+        self.sorted_by = x;
+        self.sort_containers();
+        self.containers
+            .state
+            .select(self.containers.items.iter().position(|i| {
+                self.get_selected_container_id()
+                    .map_or(false, |id| i.id == id)
+            }));
+    }
+
+    /// Remove the sorted header & order, and sort by default - the preferred order, then created datetime
+    pub fn reset_sorted(&mut self) {
+        self.set_sorted(None);
+    }
+
+    /// Sort containers based on a given header, if headings match, and already ascending, remove sorting
+    pub fn set_sort_by_header(&mut self, selected_header: Header) {
+        let mut output = Some((selected_header, SortedOrder::Asc));
+        if let Some((current_header, order)) = self.get_sorted() {
+            if current_header == selected_header {
+                match order {
+                    SortedOrder::Desc => output = None,
+                    SortedOrder::Asc => output = Some((selected_header, SortedOrder::Desc)),
                 }
             }
         }
+        self.set_sorted(output);
     }
 
-    /// update logs of a given container, based on id
-    pub fn update_log_by_id(&mut self, logs: Vec<String>, id: &ContainerId) {
-        let color = self.args.color;
-        let raw = self.args.raw;
+    pub const fn get_sorted(&self) -> Option<(Header, SortedOrder)> {
+        self.sorted_by
+    }
 
-        let timestamp = self.args.timestamp;
+    pub const fn get_top_mode(&self) -> Option<TopMode> {
+        self.top_mode
+    }
 
-        if let Some(container) = self.get_container_by_id(id) {
-            if !container.is_oxker {
-                container.last_updated = Self::get_systemtime();
-                let current_len = container.logs.len();
+    /// Cycle the top-like mode, the normal sorted_by value is left untouched, so is restored once top mode is turned off
+    pub fn toggle_top_mode(&mut self) {
+        self.top_mode = TopMode::next(self.top_mode);
+        self.sort_containers();
+    }
 
-                for mut i in logs {
-                    let tz = LogsTz::from(i.as_str());
-                    if !timestamp {
-                        i = i.replace(&tz.to_string(), "");
-                    }
-                    let lines = if color {
-                        log_sanitizer::colorize_logs(&i)
-                    } else if raw {
-                        log_sanitizer::raw(&i)
-                    } else {
-                        log_sanitizer::remove_ansi(&i)
-                    };
-                    container.logs.insert(ListItem::new(lines), tz);
+    /// The sort that is currently in effect, top mode takes priority over the user selected header sort
+    pub fn get_active_sort(&self) -> Option<(Header, SortedOrder)> {
+        self.top_mode
+            .map(|i| (i.header(), SortedOrder::Desc))
+            .or(self.sorted_by)
+    }
+
+    /// Sort the containers vec, based on a heading (and if clash, then by name), either ascending or descending,
+    /// If not sort set, then sort by created time
+    /// When in top mode, the selected container is tracked by id, so that the selection follows the container as the list re-orders
+    #[allow(clippy::too_many_lines)]
+    pub fn sort_containers(&mut self) {
+        let selected_id = self.top_mode.and_then(|_| self.get_selected_container_id());
+        if let Some((head, ord)) = self.get_active_sort() {
+            let primary = self.get_host().to_owned();
+            let sort_closure = |a: &ContainerItem, b: &ContainerItem| -> std::cmp::Ordering {
+                let item_ord = match ord {
+                    SortedOrder::Asc => (a, b),
+                    SortedOrder::Desc => (b, a),
+                };
+                match head {
+                    Header::State => item_ord
+                        .0
+                        .state
+                        .order()
+                        .cmp(&item_ord.1.state.order())
+                        .then_with(|| item_ord.0.name.get().cmp(item_ord.1.name.get())),
+                    Header::Status => item_ord
+                        .0
+                        .status
+                        .cmp(&item_ord.1.status)
+                        .then_with(|| item_ord.0.name.get().cmp(item_ord.1.name.get())),
+                    Header::Cpu => item_ord
+                        .0
+                        .cpu_stats
+                        .back()
+                        .cmp(&item_ord.1.cpu_stats.back())
+                        .then_with(|| item_ord.0.name.get().cmp(item_ord.1.name.get())),
+                    Header::Memory => item_ord
+                        .0
+                        .mem_stats
+                        .back()
+                        .cmp(&item_ord.1.mem_stats.back())
+                        .then_with(|| item_ord.0.name.get().cmp(item_ord.1.name.get())),
+
+                    Header::Id => item_ord
+                        .0
+                        .id
+                        .cmp(&item_ord.1.id)
+                        .then_with(|| item_ord.0.name.get().cmp(item_ord.1.name.get())),
+                    Header::Image => item_ord
+                        .0
+                        .image
+                        .get()
+                        .cmp(item_ord.1.image.get())
+                        .then_with(|| item_ord.0.name.get().cmp(item_ord.1.name.get())),
+                    Header::Rx => item_ord
+                        .0
+                        .rx
+                        .cmp(&item_ord.1.rx)
+                        .then_with(|| item_ord.0.name.get().cmp(item_ord.1.name.get())),
+                    Header::Tx => item_ord
+                        .0
+                        .tx
+                        .cmp(&item_ord.1.tx)
+                        .then_with(|| item_ord.0.name.get().cmp(item_ord.1.name.get())),
+                    Header::NetIo => item_ord
+                        .0
+                        .io_rates()
+                        .net()
+                        .cmp(&item_ord.1.io_rates().net())
+                        .then_with(|| item_ord.0.name.get().cmp(item_ord.1.name.get())),
+                    Header::DiskIo => item_ord
+                        .0
+                        .io_rates()
+                        .disk()
+                        .cmp(&item_ord.1.io_rates().disk())
+                        .then_with(|| item_ord.0.name.get().cmp(item_ord.1.name.get())),
+                    Header::Label(index) => item_ord
+                        .0
+                        .label_values
+                        .get(index)
+                        .cmp(&item_ord.1.label_values.get(index))
+                        .then_with(|| item_ord.0.name.get().cmp(item_ord.1.name.get())),
+
+                    Header::Name => item_ord
+                        .0
+                        .name
+                        .get()
+                        .cmp(item_ord.1.name.get())
+                        .then_with(|| item_ord.0.id.cmp(&item_ord.1.id)),
+                    Header::Ready => item_ord
+                        .0
+                        .readiness
+                        .cmp(&item_ord.1.readiness)
+                        .then_with(|| item_ord.0.name.get().cmp(item_ord.1.name.get())),
+                    Header::ExitCode => item_ord
+                        .0
+                        .exit_info
+                        .exit_code
+                        .cmp(&item_ord.1.exit_info.exit_code)
+                        .then_with(|| item_ord.0.name.get().cmp(item_ord.1.name.get())),
+                    Header::Restarts => item_ord
+                        .0
+                        .exit_info
+                        .restart_count
+                        .cmp(&item_ord.1.exit_info.restart_count)
+                        .then_with(|| item_ord.0.name.get().cmp(item_ord.1.name.get())),
+                    Header::Throttled => item_ord
+                        .0
+                        .throttling
+                        .percent()
+                        .cmp(&item_ord.1.throttling.percent())
+                        .then_with(|| {
+                            item_ord
+                                .0
+                                .throttling
+                                .throttled_time
+                                .cmp(&item_ord.1.throttling.throttled_time)
+                        })
+                        .then_with(|| item_ord.0.name.get().cmp(item_ord.1.name.get())),
+                    Header::Host => item_ord
+                        .0
+                        .host
+                        .as_deref()
+                        .unwrap_or(&primary)
+                        .cmp(item_ord.1.host.as_deref().unwrap_or(&primary))
+                        .then_with(|| item_ord.0.name.get().cmp(item_ord.1.name.get())),
                 }
+            };
+            self.containers.items.sort_by(sort_closure);
+        } else {
+            // `--order` names come first, in the order given, then containers with an `--order-label` priority, lowest first, then the rest by created datetime
+            let order = &self.args.order;
+            let rank = |item: &ContainerItem| {
+                let position = order.iter().position(|i| i == item.name.get());
+                (
+                    position.unwrap_or(usize::MAX),
+                    item.priority.unwrap_or(i64::MAX),
+                )
+            };
+            self.containers.items.sort_by(|a, b| {
+                rank(a)
+                    .cmp(&rank(b))
+                    .then_with(|| a.created.cmp(&b.created))
+                    .then_with(|| a.name.get().cmp(b.name.get()))
+            });
+        }
+        if let Some(id) = selected_id {
+            self.containers
+                .state
+                .select(self.containers.items.iter().position(|i| i.id == id));
+        }
+    }
 
-                // Set the logs selected row for each container
-                // Either when no long currently selected, or currently selected (before updated) is already at end
-                if container.logs.state().selected().is_none()
-                    || container.logs.state().selected().map_or(1, |f| f + 1) == current_len
-                {
-                    container.logs.end();
+    /// Container state methods
+
+    /// Just get the total number of containers
+    pub const fn get_container_len(&self) -> usize {
+        self.containers.items.len()
+    }
+
+    /// Get the number of running containers
+    pub fn get_running_len(&self) -> usize {
+        self.containers
+            .items
+            .iter()
+            .filter(|i| i.state == State::Running)
+            .count()
+    }
+
+    /// Time since the container list was last updated from Docker
+    pub fn get_last_update(&self) -> Option<Duration> {
+        self.last_update.map(|i| i.elapsed())
+    }
+
+    /// Get all the ContainerItems
+    pub fn get_container_items(&self) -> &[ContainerItem] {
+        &self.containers.items
+    }
+
+    /// Get title for containers section, when a group header is selected there is no selected container, so show a dash
+    /// When monitoring multiple hosts, the current host tab is also shown
+    pub fn container_title(&self) -> String {
+        let title = if self.is_filtered() {
+            let rows = self.get_container_rows();
+            let visible = rows
+                .iter()
+                .filter(|i| matches!(i, ContainerRow::Container(_)))
+                .collect::<Vec<_>>();
+            let position = self.containers.state.selected().and_then(|selected| {
+                visible
+                    .iter()
+                    .position(|i| *i == &ContainerRow::Container(selected))
+            });
+            match position {
+                Some(position) if self.selected_group.is_none() => {
+                    format!(" {}/{}", position + 1, visible.len())
                 }
+                _ if visible.is_empty() => String::new(),
+                _ => format!(" -/{}", visible.len()),
             }
+        } else if self.selected_group.is_some() && !self.containers.items.is_empty() {
+            format!(" -/{}", self.containers.items.len())
+        } else {
+            self.containers.get_state_title()
+        };
+        let title = if self.stale {
+            format!("{title} - cached")
+        } else {
+            title
+        };
+        let title = match &self.search {
+            Some(search) => format!("{title} - /{search}"),
+            None => title,
+        };
+        let filtered = self.get_container_len() - self.get_shown_len().1;
+        let title = if filtered > 0 {
+            format!("{title} - {filtered} filtered")
+        } else {
+            title
+        };
+        let title = match self.get_marked_len() {
+            0 => title,
+            marked => format!("{title} - {marked} marked"),
+        };
+        if self.is_multi_host() {
+            format!("{title} - {}", self.get_host_tab().unwrap_or("all hosts"))
+        } else {
+            title
         }
     }
-}
 
-#[cfg(test)]
-#[allow(clippy::unwrap_used, clippy::many_single_char_names)]
-mod tests {
+    /// Select the first container
+    pub fn containers_start(&mut self) {
+        if self.is_row_view() {
+            self.select_row(Some(0));
+        } else {
+            self.containers.start();
+        }
+    }
 
-    use super::*;
-    use crate::tests::{gen_appdata, gen_container_summary, gen_containers};
-    use std::collections::VecDeque;
+    /// select the last container
+    pub fn containers_end(&mut self) {
+        if self.is_row_view() {
+            let len = self.get_container_rows().len();
+            self.select_row(len.checked_sub(1));
+        } else {
+            self.containers.end();
+        }
+    }
+
+    /// Select the next container
+    pub fn containers_next(&mut self) {
+        if self.is_row_view() {
+            let rows = self.get_container_rows();
+            let next = self
+                .get_selected_row(&rows)
+                .map_or(0, |i| (i + 1).min(rows.len().saturating_sub(1)));
+            self.select_row(Some(next));
+        } else {
+            self.containers.next();
+        }
+    }
+
+    /// select the previous container
+    pub fn containers_previous(&mut self) {
+        if self.is_row_view() {
+            let rows = self.get_container_rows();
+            let previous = self
+                .get_selected_row(&rows)
+                .map_or(0, |i| i.saturating_sub(1));
+            self.select_row(Some(previous));
+        } else {
+            self.containers.previous();
+        }
+    }
+
+    /// Select a row of the containers panel, by its index, as displayed
+    pub fn containers_select(&mut self, row: usize) {
+        self.select_row(Some(row));
+    }
+
+    /// Get ListState of the rows in the containers panel, when grouped the state is synced to the row of currently selected container or group header
+    pub fn get_container_state(&mut self) -> &mut ListState {
+        if self.is_row_view() {
+            let rows = self.get_container_rows();
+            let selected = self.get_selected_row(&rows);
+            self.group_state.select(selected);
+            &mut self.group_state
+        } else {
+            &mut self.containers.state
+        }
+    }
+
+    /// Get Option of the current selected container, will be None if a group header is selected
+    pub fn get_selected_container(&self) -> Option<&ContainerItem> {
+        if self.selected_group.is_some() {
+            return None;
+        }
+        self.containers
+            .state
+            .selected()
+            .and_then(|i| self.containers.items.get(i))
+    }
+
+    /// Get the current group layout of the containers panel
+    pub const fn get_group_by(&self) -> Option<GroupBy> {
+        self.group_by
+    }
+
+    /// Cycle the group layout of the containers panel, the selected container remains selected
+    pub fn toggle_group_by(&mut self) {
+        self.group_by = GroupBy::next(self.group_by);
+        // Only offer pod grouping when there are kubernetes containers
+        if self.group_by == Some(GroupBy::Pod)
+            && !self.containers.items.iter().any(|i| i.pod.is_some())
+        {
+            self.group_by = GroupBy::next(self.group_by);
+        }
+        // Only offer project grouping when there are compose containers
+        if self.group_by == Some(GroupBy::Project)
+            && !self.containers.items.iter().any(|i| i.project.is_some())
+        {
+            self.group_by = GroupBy::next(self.group_by);
+        }
+        // Only offer label grouping when there are containers with an oxker.group label
+        if self.group_by == Some(GroupBy::Label)
+            && !self
+                .containers
+                .items
+                .iter()
+                .any(|i| i.hints.group.is_some())
+        {
+            self.group_by = GroupBy::next(self.group_by);
+        }
+        self.selected_group = None;
+        self.group_state = ListState::default();
+        if let Some(group_by) = self.group_by {
+            // Make sure the selected container isn't hidden inside a collapsed group
+            if let Some(item) = self.get_selected_container() {
+                let key = group_by.key(item);
+                self.collapsed_groups.remove(&key);
+            }
+        }
+    }
+
+    /// Get the name of the selected group header, if one is selected
+    pub fn get_selected_group(&self) -> Option<&str> {
+        self.selected_group.as_deref()
+    }
+
+    /// Get the compose project of the selected group header, when grouped by project
+    pub fn get_selected_project(&self) -> Option<&str> {
+        self.group_by?.project(self.selected_group.as_deref()?)
+    }
+
+    /// Mark, or unmark, the selected container, oxker's own container can't be marked
+    pub fn toggle_marked(&mut self) {
+        let Some(id) = self
+            .get_selected_container()
+            .filter(|i| !i.is_oxker)
+            .map(|i| i.id.clone())
+        else {
+            return;
+        };
+        if !self.marked.remove(&id) {
+            self.marked.insert(id);
+        }
+    }
+
+    pub fn clear_marked(&mut self) {
+        self.marked.clear();
+    }
+
+    pub fn is_marked(&self, id: &ContainerId) -> bool {
+        self.marked.contains(id)
+    }
+
+    /// Number of marked containers that still exist
+    pub fn get_marked_len(&self) -> usize {
+        self.containers
+            .items
+            .iter()
+            .filter(|i| self.marked.contains(&i.id))
+            .count()
+    }
+
+    /// Ids, & names, of the marked containers that a control can be sent to in their current state, in the order they're listed
+    pub fn get_marked(&self, control: DockerControls) -> Vec<(ContainerId, ContainerName)> {
+        self.containers
+            .items
+            .iter()
+            .filter(|i| {
+                self.marked.contains(&i.id) && DockerControls::gen_vec(i.state).contains(&control)
+            })
+            .map(|i| (i.id.clone(), i.name.clone()))
+            .collect()
+    }
+
+    /// Ids of every container of a compose project, including those hidden by the search, that a control can be sent to in its current state, e.g. a stopped container isn't stopped again
+    pub fn get_project_ids(&self, project: &str, control: DockerControls) -> Vec<ContainerId> {
+        self.containers
+            .items
+            .iter()
+            .filter(|i| {
+                i.project.as_deref() == Some(project)
+                    && DockerControls::gen_vec(i.state).contains(&control)
+            })
+            .map(|i| i.id.clone())
+            .collect()
+    }
+
+    /// Generate the rows of the containers panel, if grouped, each group is placed in the order of its first member in the sorted containers vec
+    pub fn get_container_rows(&self) -> Vec<ContainerRow> {
+        let Some(group_by) = self.group_by else {
+            return self
+                .containers
+                .items
+                .iter()
+                .enumerate()
+                .filter(|(_, item)| self.is_visible(item))
+                .map(|(index, _)| ContainerRow::Container(index))
+                .collect();
+        };
+
+        let mut groups: Vec<(ContainerGroup, Vec<usize>)> = vec![];
+        for (index, item) in self.containers.items.iter().enumerate() {
+            if !self.is_visible(item) {
+                continue;
+            }
+            let key = group_by.key(item);
+            let position = groups
+                .iter()
+                .position(|i| i.0.key == key)
+                .unwrap_or_else(|| {
+                    groups.push((
+                        ContainerGroup {
+                            expanded: !self.collapsed_groups.contains(&key),
+                            key,
+                            count: 0,
+                            running: 0,
+                            cpu: CpuStats::default(),
+                            mem: ByteStats::default(),
+                        },
+                        vec![],
+                    ));
+                    groups.len() - 1
+                });
+            if let Some((group, members)) = groups.get_mut(position) {
+                group.count += 1;
+                if item.state.is_alive() {
+                    group.running += 1;
+                }
+                group.cpu = CpuStats::new(
+                    group.cpu.get_value() + item.cpu_stats.back().map_or(0.0, Stats::get_value),
+                );
+                group.mem.update(
+                    group
+                        .mem
+                        .get()
+                        .saturating_add(item.mem_stats.back().map_or(0, |i| i.get())),
+                );
+                members.push(index);
+            }
+        }
+
+        let mut output = vec![];
+        for (group, members) in groups {
+            let expanded = group.expanded;
+            output.push(ContainerRow::Group(group));
+            if expanded {
+                output.extend(members.into_iter().map(ContainerRow::Container));
+            }
+        }
+        output
+    }
+
+    /// Find the index of the row that is currently selected, either a group header, or a container
+    fn get_selected_row(&self, rows: &[ContainerRow]) -> Option<usize> {
+        self.selected_group.as_ref().map_or_else(
+            || {
+                self.containers.state.selected().and_then(|selected| {
+                    rows.iter()
+                        .position(|i| i == &ContainerRow::Container(selected))
+                })
+            },
+            |key| {
+                rows.iter()
+                    .position(|i| matches!(i, ContainerRow::Group(g) if &g.key == key))
+            },
+        )
+    }
+
+    /// Select a given row, if the row is a group header, the containers state is left as is, so that it can't be automatically reset on the next docker update
+    fn select_row(&mut self, index: Option<usize>) {
+        let rows = self.get_container_rows();
+        match index.and_then(|i| rows.get(i)) {
+            Some(ContainerRow::Group(group)) => self.selected_group = Some(group.key.clone()),
+            Some(ContainerRow::Container(i)) => {
+                self.selected_group = None;
+                self.containers.state.select(Some(*i));
+            }
+            None => (),
+        }
+    }
+
+    /// Select the container with the given name, returns false if there's no such container, or it isn't shown, as it's filtered out or in a collapsed group
+    pub fn select_container_by_name(&mut self, name: &str) -> bool {
+        self.containers
+            .items
+            .iter()
+            .position(|i| i.name.get() == name)
+            .is_some_and(|index| self.select_container_index(index))
+    }
+
+    /// Select the container of a compose service, the replica first by name, if the service is scaled
+    /// Without a host, a service on more than one host selects nothing, as it's ambiguous, with a host, its tab is switched to if another host's tab is shown
+    fn select_container_by_path(&mut self, path: &ContainerPath) -> bool {
+        let found = self
+            .containers
+            .items
+            .iter()
+            .enumerate()
+            .filter(|(_, i)| {
+                i.project.as_deref() == Some(path.project.as_str())
+                    && i.service.as_deref() == Some(path.service.as_str())
+                    && path
+                        .host
+                        .as_deref()
+                        .is_none_or(|host| self.container_host(i) == host)
+            })
+            .collect::<Vec<_>>();
+        let Some((index, first)) = found
+            .iter()
+            .min_by(|a, b| a.1.name.get().cmp(b.1.name.get()))
+        else {
+            return false;
+        };
+        let host = self.container_host(first).to_owned();
+        if found.iter().any(|(_, i)| self.container_host(i) != host) {
+            return false;
+        }
+        let index = *index;
+        if path.host.is_some()
+            && self
+                .host_tab
+                .as_deref()
+                .is_some_and(|tab| tab != host.as_str())
+        {
+            self.set_host_tab(Some(&host));
+        }
+        self.select_container_index(index)
+    }
+
+    /// Select a container by a `host/project/service`, or `project/service`, path, or else by its name, or id, as with `--select`
+    pub fn select_container_by_target(&mut self, target: &str) -> bool {
+        match ContainerPath::parse(target) {
+            Some(path) => self.select_container_by_path(&path),
+            None => self.select_container_by_name_or_id(target),
+        }
+    }
+
+    /// Set how many hosts the `--select` container is looked for in, it's only missing once none of them have it
+    pub const fn set_select_hosts(&mut self, hosts: usize) {
+        self.select_pending = Some(hosts);
+    }
+
+    /// Look for the `--select` container, once a host has first listed its containers
+    /// Some(true) once it's selected, Some(false) once the last host has listed its containers without it, None while it's still to be found, or has already been looked for
+    pub fn select_on_start(&mut self, target: &str) -> Option<bool> {
+        let pending = self.select_pending?;
+        if self.select_container_by_target(target) {
+            self.select_pending = None;
+            return Some(true);
+        }
+        let pending = pending.saturating_sub(1);
+        self.select_pending = (pending > 0).then_some(pending);
+        (pending == 0).then_some(false)
+    }
+
+    /// Select a container by its name, or else by its id, or a prefix of its id, as docker accepts, a prefix of more than one id selects nothing
+    pub fn select_container_by_name_or_id(&mut self, target: &str) -> bool {
+        if self.select_container_by_name(target) {
+            return true;
+        }
+        let mut found = self
+            .containers
+            .items
+            .iter()
+            .enumerate()
+            .filter(|(_, i)| !target.is_empty() && i.id.get().starts_with(target))
+            .map(|(index, _)| index);
+        match (found.next(), found.next()) {
+            (Some(index), None) => self.select_container_index(index),
+            _ => false,
+        }
+    }
+
+    /// Track the selected container, so that it's added to the recently viewed containers once it has been viewed for long enough
+    pub fn update_recent(&mut self) {
+        let selected = self.get_selected_container_id();
+        self.recent.update(selected.as_ref(), Instant::now());
+    }
+
+    /// The recently viewed containers that still exist, most recent first, None if there aren't any
+    pub fn recent_picker(&self) -> Option<RecentPicker> {
+        let rows = self
+            .recent
+            .ids()
+            .filter_map(|id| self.containers.items.iter().find(|i| &i.id == id))
+            .map(|i| RecentRow {
+                id: i.id.clone(),
+                name: i.name.clone(),
+                state: i.state,
+            })
+            .collect::<Vec<_>>();
+        (!rows.is_empty()).then(|| RecentPicker::new(rows))
+    }
+
+    /// Jump to a recently viewed container, revealing it if it's hidden, None if it no longer exists
+    pub fn select_recent(&mut self, id: &ContainerId) -> Option<ContainerName> {
+        if !self.reveal_container(id) {
+            return None;
+        }
+        self.recent.jump(id, Instant::now());
+        self.get_selected_container().map(|i| i.name.clone())
+    }
+
+    /// Select a container, even if it's hidden, its group is expanded, the host tab switched to its host, & then the search cleared, as needed
+    pub fn reveal_container(&mut self, id: &ContainerId) -> bool {
+        let Some(index) = self.containers.items.iter().position(|i| &i.id == id) else {
+            return false;
+        };
+        if self.select_container_index(index) {
+            return true;
+        }
+        let item = &self.containers.items[index];
+        if let Some(group_by) = self.group_by {
+            self.collapsed_groups.remove(&group_by.key(item));
+        }
+        if self.host_tab.is_some() {
+            self.host_tab = Some(
+                item.host
+                    .clone()
+                    .unwrap_or_else(|| self.get_host().to_owned()),
+            );
+        }
+        if self.select_container_index(index) {
+            return true;
+        }
+        self.search = None;
+        self.select_container_index(index)
+    }
+
+    /// Select the container at an index of the containers, returns false if it isn't shown
+    fn select_container_index(&mut self, index: usize) -> bool {
+        if self.is_row_view() {
+            let rows = self.get_container_rows();
+            let Some(row) = rows
+                .iter()
+                .position(|i| i == &ContainerRow::Container(index))
+            else {
+                return false;
+            };
+            self.select_row(Some(row));
+        } else {
+            self.containers.state.select(Some(index));
+        }
+        true
+    }
+
+    /// Expand or collapse the selected group, if a container is selected then collapsing will collapse its parent group, and select the group header
+    /// None will toggle the current expanded state
+    pub fn set_group_expanded(&mut self, expand: Option<bool>) {
+        let Some(group_by) = self.group_by else {
+            return;
+        };
+        if let Some(key) = self.selected_group.clone() {
+            let collapsed = self.collapsed_groups.contains(&key);
+            if expand.unwrap_or(collapsed) {
+                self.collapsed_groups.remove(&key);
+            } else {
+                self.collapsed_groups.insert(key);
+            }
+        } else if expand == Some(false) {
+            if let Some(key) = self.get_selected_container().map(|i| group_by.key(i)) {
+                self.collapsed_groups.insert(key.clone());
+                self.selected_group = Some(key);
+            }
+        }
+    }
+
+    /// Find the longest port when it's transformed into a string, defaults are header lens (ip, private, public)
+    pub fn get_longest_port(&self) -> (usize, usize, usize) {
+        let mut longest_ip = 5;
+        let mut longest_private = 10;
+        let mut longest_public = 9;
+
+        for item in &self.containers.items {
+            // if let Some(ports) = item.ports.as_ref() {
+            longest_ip = longest_ip.max(
+                item.ports
+                    .iter()
+                    .map(ContainerPorts::len_ip)
+                    .max()
+                    .unwrap_or(3),
+            );
+            longest_private = longest_private.max(
+                item.ports
+                    .iter()
+                    .map(ContainerPorts::len_private)
+                    .max()
+                    .unwrap_or(8),
+            );
+            longest_public = longest_public.max(
+                item.ports
+                    .iter()
+                    .map(ContainerPorts::len_public)
+                    .max()
+                    .unwrap_or(6),
+            );
+        }
+        // }
+
+        (longest_ip, longest_private, longest_public)
+        // )
+    }
+    /// Get Option of the current selected container's ports, sorted by private port
+    pub fn get_selected_ports(&mut self) -> Option<(Vec<ContainerPorts>, State)> {
+        if let Some(item) = self.get_mut_selected_container() {
+            let mut ports = item.ports.clone();
+            ports.sort_by(|a, b| a.private.cmp(&b.private));
+            return Some((ports, item.state));
+        }
+        None
+    }
+
+    /// The address that a port, published on the given ip, is reached at, a port bound to all interfaces uses the address of the Docker host
+    fn published_address(&self, container: &ContainerItem, ip: Option<&str>) -> String {
+        match ip {
+            Some(ip) if !ip.is_empty() && ip != "0.0.0.0" && ip != "::" => ip.to_owned(),
+            _ => {
+                let host = self.container_host(container);
+                let host = host.rsplit_once("://").map_or(host, |i| i.1);
+                let host = host.rsplit_once('@').map_or(host, |i| i.1);
+                match host.split([':', '/']).next() {
+                    Some(host) if !host.is_empty() && host != "local" => host.to_owned(),
+                    _ => "localhost".to_owned(),
+                }
+            }
+        }
+    }
+
+    /// Get the url of the selected container, its `oxker.url` label, or else a url for its first published port, a port bound to all interfaces uses the address of the Docker host
+    pub fn get_selected_url(&self) -> Option<String> {
+        let container = self.get_selected_container()?;
+        if let Some(url) = container.hints.url.as_ref() {
+            return Some(url.clone());
+        }
+        let mut ports = container
+            .ports
+            .iter()
+            .filter_map(|i| i.public.map(|public| (i.ip.as_deref(), public)))
+            .collect::<Vec<_>>();
+        ports.sort_by_key(|i| i.1);
+        let (ip, public) = ports.first()?;
+        Some(self.port_url(container, *ip, *public))
+    }
+
+    /// The url a published port is opened at
+    fn port_url(&self, container: &ContainerItem, ip: Option<&str>, public: u16) -> String {
+        let address = self.published_address(container, ip);
+        if address.contains(':') {
+            format!("http://[{address}]:{public}")
+        } else {
+            format!("http://{address}:{public}")
+        }
+    }
+
+    /// The ports of the selected container, published ports first, with the url each is opened at, a port published on both ipv4, & ipv6, is only listed once
+    pub fn get_selected_port_view(&self) -> Option<PortView> {
+        let container = self.get_selected_container()?;
+        let mut ports = container.ports.clone();
+        ports.sort_by(|a, b| {
+            (a.public.is_none(), a.private, a.public).cmp(&(
+                b.public.is_none(),
+                b.private,
+                b.public,
+            ))
+        });
+        ports.dedup_by_key(|i| (i.private, i.public));
+        let rows = ports
+            .into_iter()
+            .map(|i| {
+                let url = i
+                    .public
+                    .map(|public| self.port_url(container, i.ip.as_deref(), public));
+                PortRow {
+                    ip: i.ip,
+                    private: i.private,
+                    public: i.public,
+                    url,
+                }
+            })
+            .collect::<Vec<_>>();
+        Some(PortView {
+            id: container.id.clone(),
+            name: container.name.get().to_owned(),
+            rows,
+            selected: 0,
+        })
+    }
+
+    /// Get mutable Option of the current selected container
+    fn get_mut_selected_container(&mut self) -> Option<&mut ContainerItem> {
+        if self.selected_group.is_some() {
+            return None;
+        }
+        self.containers
+            .state
+            .selected()
+            .and_then(|i| self.containers.items.get_mut(i))
+    }
+
+    /// return a mutable container by given id
+    fn get_container_by_id(&mut self, id: &ContainerId) -> Option<&mut ContainerItem> {
+        self.containers.items.iter_mut().find(|i| &i.id == id)
+    }
+
+    /// Get the ContainerName of by ID
+    pub fn get_container_name_by_id(&mut self, id: &ContainerId) -> Option<ContainerName> {
+        self.containers
+            .items
+            .iter_mut()
+            .find(|i| &i.id == id)
+            .map(|i| i.name.clone())
+    }
+
+    /// Reset the network traffic counted by oxker, of the given container, or of every container if None
+    pub fn reset_traffic(&mut self, id: Option<&ContainerId>) {
+        let now = Self::get_systemtime();
+        for container in self
+            .containers
+            .items
+            .iter_mut()
+            .filter(|i| id.is_none() || id == Some(&i.id))
+        {
+            container.traffic.reset(now);
+        }
+    }
+
+    /// Find the id of the currently selected container.
+    /// If any containers on system, will always return a ContainerId
+    /// Only returns None when no containers found.
+    pub fn get_selected_container_id(&self) -> Option<ContainerId> {
+        self.get_selected_container().map(|i| i.id.clone())
+    }
+
+    /// Get the Id and State for the currently selected container - used by the exec check method
+    pub fn get_selected_container_id_state_name(&self) -> Option<(ContainerId, State, String)> {
+        self.get_selected_container()
+            .map(|i| (i.id.clone(), i.state, i.name.get().to_owned()))
+    }
+
+    /// Check if the selected container is a Windows container, only Windows containers have an isolation mode
+    pub fn is_windows(&self) -> bool {
+        self.get_selected_container()
+            .is_some_and(|i| i.isolation.is_some())
+    }
+
+    /// Get the ids of the containers, on the given host, whose isolation mode hasn't yet been inspected
+    pub fn get_unknown_isolation(&self, host: Option<&str>) -> Vec<ContainerId> {
+        self.containers
+            .items
+            .iter()
+            .filter(|i| i.host.as_deref() == host && i.isolation.is_none())
+            .map(|i| i.id.clone())
+            .collect()
+    }
+
+    /// Get the probes to run for the running containers on the given host, resolving the published address of http & tcp checks
+    /// Containers that aren't running have their readiness cleared, and a check of an unpublished port is set straight to `NoPort`
+    pub fn get_probe_targets(&mut self, host: Option<&str>) -> Vec<ProbeTarget> {
+        let mut output = vec![];
+        for index in 0..self.containers.items.len() {
+            let container = &self.containers.items[index];
+            if container.host.as_deref() != host {
+                continue;
+            }
+            let probe = Probe::find(&self.args.probes, container.name.get())
+                .filter(|_| container.state == State::Running);
+            let readiness = probe.map(|probe| {
+                let port = match &probe.check {
+                    ProbeCheck::Http { port, .. } | ProbeCheck::Tcp(port) => Some(*port),
+                    ProbeCheck::Exec(_) => None,
+                };
+                let address = port.and_then(|port| {
+                    container
+                        .ports
+                        .iter()
+                        .find(|i| i.private == port && i.public.is_some())
+                        .map(|i| {
+                            (
+                                self.published_address(container, i.ip.as_deref()),
+                                i.public.unwrap_or_default(),
+                            )
+                        })
+                });
+                if port.is_some() && address.is_none() {
+                    Readiness::NoPort
+                } else {
+                    output.push(ProbeTarget {
+                        address,
+                        check: probe.check.clone(),
+                        id: container.id.clone(),
+                    });
+                    container.readiness.unwrap_or(Readiness::Pending)
+                }
+            });
+            self.containers.items[index].readiness = readiness;
+        }
+        output
+    }
+
+    /// Set the result of a container's readiness probe, ignored if the container has stopped since the probe started
+    pub fn set_readiness(&mut self, id: &ContainerId, readiness: Readiness) {
+        if let Some(item) = self.get_container_by_id(id) {
+            if item.readiness.is_some() {
+                item.readiness = Some(readiness);
+            }
+        }
+    }
+
+    /// Get the ids of the containers, on the given host, that haven't been inspected for their exit code, & restart count, or have changed since they were
+    pub fn get_stale_exit_info(&self, host: Option<&str>) -> Vec<ContainerId> {
+        self.containers
+            .items
+            .iter()
+            .filter(|i| {
+                i.host.as_deref() == host
+                    && (i.exit_info.stale || i.exit_info.restart_count.is_none())
+            })
+            .map(|i| i.id.clone())
+            .collect()
+    }
+
+    /// Set the exit code, & restart count, of a container, from an inspect
+    pub fn set_exit_info(
+        &mut self,
+        id: &ContainerId,
+        exit_code: Option<i64>,
+        restart_count: i64,
+        now: u64,
+    ) {
+        if let Some(item) = self.get_container_by_id(id) {
+            item.exit_info.set(exit_code, restart_count, now);
+        }
+    }
+
+    /// Record an attempt at sending a control to a container, with the daemon's error if it failed
+    pub fn push_attempt(
+        &mut self,
+        id: &ContainerId,
+        host: Option<&str>,
+        control: DockerControls,
+        error: Option<String>,
+        now: u64,
+    ) {
+        if let Some(item) = self
+            .containers
+            .items
+            .iter_mut()
+            .find(|i| &i.id == id && i.host.as_deref() == host)
+        {
+            item.attempts.push(control, now, error);
+        }
+    }
+
+    /// The name, & recent control attempts, of a container
+    pub fn get_attempts(
+        &self,
+        id: &ContainerId,
+        host: Option<&str>,
+    ) -> Option<(ContainerName, Attempts)> {
+        self.containers
+            .items
+            .iter()
+            .find(|i| &i.id == id && i.host.as_deref() == host)
+            .map(|i| (i.name.clone(), i.attempts.clone()))
+    }
+
+    /// A lifecycle event of a container, so that it's inspected again, a restart may be too quick to be seen as a change of state
+    pub fn set_exit_info_stale(&mut self, id: &ContainerId, host: Option<&str>) {
+        if let Some(item) = self
+            .containers
+            .items
+            .iter_mut()
+            .find(|i| &i.id == id && i.host.as_deref() == host)
+        {
+            item.exit_info.stale = true;
+        }
+    }
+
+    pub fn set_isolation(&mut self, id: &ContainerId, isolation: Isolation) {
+        if let Some(item) = self.get_container_by_id(id) {
+            item.isolation = Some(isolation);
+        }
+    }
+
+    /// Get the ids of the containers, on the given host, whose cpu & memory limits haven't yet been inspected
+    pub fn get_unknown_limits(&self, host: Option<&str>) -> Vec<ContainerId> {
+        self.containers
+            .items
+            .iter()
+            .filter(|i| i.host.as_deref() == host && i.limits.is_none())
+            .map(|i| i.id.clone())
+            .collect()
+    }
+
+    /// Set the cpu & memory limits of a container, from an inspect, or None so that they are inspected again, after they've been updated
+    pub fn set_limits(&mut self, id: &ContainerId, limits: Option<Limits>) {
+        if let Some(item) = self.get_container_by_id(id) {
+            item.limits = limits;
+        }
+    }
+
+    /// Get the ids of the containers, on the given host, that aren't running, & whose port bindings haven't yet been inspected
+    pub fn get_unknown_bindings(&self, host: Option<&str>) -> Vec<ContainerId> {
+        self.containers
+            .items
+            .iter()
+            .filter(|i| {
+                i.host.as_deref() == host
+                    && i.bindings.is_none()
+                    && !matches!(i.state, State::Running | State::Paused)
+            })
+            .map(|i| i.id.clone())
+            .collect()
+    }
+
+    /// Set the port bindings of a container, from an inspect, & find the conflicts again
+    pub fn set_bindings(&mut self, id: &ContainerId, bindings: Vec<HostPort>) {
+        if let Some(item) = self.get_container_by_id(id) {
+            item.bindings = Some(bindings);
+        }
+        self.update_port_conflicts();
+    }
+
+    /// Find the host ports that more than one container, on the same host, publishes to, including containers that would on start
+    fn update_port_conflicts(&mut self) {
+        let conflicts = port_conflict::find(&self.containers.items);
+        for (item, conflicts) in self.containers.items.iter_mut().zip(conflicts) {
+            item.conflicts = conflicts;
+        }
+    }
+
+    /// The port conflicts of the selected container
+    pub fn get_selected_port_conflicts(&self) -> Vec<PortConflict> {
+        self.get_selected_container()
+            .map(|i| i.conflicts.clone())
+            .unwrap_or_default()
+    }
+    /// Selected DockerCommand methods
+
+    /// The docker controls shown in the commands panel, those of the marked containers, of the selected compose project, or of the selected container
+    fn get_controls(&self) -> Option<&StatefulList<DockerControls>> {
+        if self.get_marked_len() > 0 {
+            Some(&self.marked_controls)
+        } else if self.get_selected_project().is_some() {
+            Some(&self.project_controls)
+        } else {
+            self.get_selected_container().map(|i| &i.docker_controls)
+        }
+    }
+
+    /// Mutable version of get_controls
+    fn get_mut_controls(&mut self) -> Option<&mut StatefulList<DockerControls>> {
+        if self.get_marked_len() > 0 {
+            return Some(&mut self.marked_controls);
+        }
+        if self.get_selected_project().is_some() {
+            return Some(&mut self.project_controls);
+        }
+        self.get_mut_selected_container()
+            .map(|i| &mut i.docker_controls)
+    }
+
+    /// Get the current selected docker command, of the selected container, or compose project
+    /// So know which command to execute
+    pub fn selected_docker_controls(&self) -> Option<DockerControls> {
+        self.get_controls().and_then(|i| {
+            i.state
+                .selected()
+                .and_then(|x| i.items.get(x).map(std::borrow::ToOwned::to_owned))
+        })
+    }
+
+    /// Check if a docker control, sent to the selected container, requires confirmation, as set by `--confirm`
+    pub fn requires_confirm(&self, control: DockerControls) -> bool {
+        self.get_selected_container()
+            .is_some_and(|i| self.args.confirm.requires(control, i.name.get()))
+    }
+
+    /// Change selected choice of docker commands of selected container, or compose project
+    pub fn docker_controls_next(&mut self) {
+        if let Some(i) = self.get_mut_controls() {
+            i.next();
+        }
+    }
+
+    /// Change selected choice of docker commands of selected container, or compose project
+    pub fn docker_controls_previous(&mut self) {
+        if let Some(i) = self.get_mut_controls() {
+            i.previous();
+        }
+    }
+
+    /// Change selected choice of docker commands of selected container, or compose project
+    pub fn docker_controls_start(&mut self) {
+        if let Some(i) = self.get_mut_controls() {
+            i.start();
+        }
+    }
+
+    /// Change selected choice of docker commands of selected container, or compose project
+    pub fn docker_controls_end(&mut self) {
+        if let Some(i) = self.get_mut_controls() {
+            i.end();
+        }
+    }
+
+    /// Get mutable Option of the currently selected container, or compose project, DockerControls state
+    pub fn get_control_state(&mut self) -> Option<&mut ListState> {
+        self.get_mut_controls().map(|i| &mut i.state)
+    }
+
+    /// Get mutable Option of the currently selected container, or compose project, DockerControls items
+    /// TODO command or control, need a uniform name across the application
+    pub fn get_control_items(&mut self) -> Option<&mut Vec<DockerControls>> {
+        self.get_mut_controls().map(|i| &mut i.items)
+    }
+
+    /// Logs related methods
+
+    /// Get the title for log panel for selected container, will be either
+    /// 1) "logs x/x - container_name" where container_name is 32 chars max
+    /// 2) "logs - container_name" when no logs found, again 32 chars max
+    /// 3) "" no container currently selected - aka no containers on system
+    ///
+    /// Windows containers also show their isolation mode, e.g. "logs x/x - container_name - hyperv isolation"
+    pub fn get_log_title(&self) -> String {
+        self.get_log_container().map_or_else(String::new, |ci| {
+            let logs_len = ci.logs.get_state_title();
+            let prefix = if logs_len.is_empty() {
+                String::from(" ")
+            } else {
+                format!("{logs_len} ")
+            };
+            let title = ci.isolation.map_or_else(
+                || format!("{}- {}", prefix, ci.name.get()),
+                |isolation| format!("{}- {} - {isolation} isolation", prefix, ci.name.get()),
+            );
+            format!(
+                "{title}{}{}{}{}{}",
+                ci.logs.filter_title().unwrap_or_default(),
+                ci.logs.search_title().unwrap_or_default(),
+                self.log_json.map(JsonView::title).unwrap_or_default(),
+                if self.is_log_locked() {
+                    " - locked"
+                } else {
+                    ""
+                },
+                self.get_boost_remaining(&ci.id)
+                    .map(|i| format!(" - boosted {}", format_duration(i.as_secs())))
+                    .unwrap_or_default()
+            )
+        })
+    }
+
+    /// select next selected log line
+    pub fn log_next(&mut self) {
+        if let Some(i) = self.get_mut_log_container() {
+            i.logs.next();
+        }
+    }
+
+    /// select previous selected log line
+    pub fn log_previous(&mut self) {
+        if let Some(i) = self.get_mut_log_container() {
+            i.logs.previous();
+        }
+    }
+
+    /// select last selected log line
+    pub fn log_end(&mut self) {
+        if let Some(i) = self.get_mut_log_container() {
+            i.logs.end();
+        }
+    }
+
+    /// select first selected log line
+    pub fn log_start(&mut self) {
+        if let Some(i) = self.get_mut_log_container() {
+            i.logs.start();
+        }
+    }
+
+    /// The container whose logs are shown, the container the logs panel is locked to, while it still exists, otherwise the selected container
+    pub fn get_log_container(&self) -> Option<&ContainerItem> {
+        self.log_lock
+            .as_ref()
+            .and_then(|id| self.containers.items.iter().find(|i| &i.id == id))
+            .or_else(|| self.get_selected_container())
+    }
+
+    fn get_mut_log_container(&mut self) -> Option<&mut ContainerItem> {
+        match self.log_lock.clone() {
+            Some(id) if self.containers.items.iter().any(|i| i.id == id) => {
+                self.get_container_by_id(&id)
+            }
+            _ => self.get_mut_selected_container(),
+        }
+    }
+
+    /// Lock the logs panel to the selected container, so its logs are still shown while other containers are selected, or unlock it
+    pub fn toggle_log_lock(&mut self) {
+        self.log_lock = if self.is_log_locked() {
+            None
+        } else {
+            self.get_selected_container_id()
+        };
+    }
+
+    /// The logs panel is locked to a container that still exists
+    pub fn is_log_locked(&self) -> bool {
+        self.log_lock
+            .as_ref()
+            .is_some_and(|id| self.containers.items.iter().any(|i| &i.id == id))
+    }
+
+    /// Refresh a container's stats & logs every BOOST_INTERVAL, until the deadline, only a single container is boosted at once
+    pub fn set_boost(&mut self, boost: Option<(ContainerId, Instant)>) {
+        self.boost = boost;
+    }
+
+    /// How long is left of a container's boost, None if it isn't boosted, or the boost has expired
+    pub fn get_boost_remaining(&self, id: &ContainerId) -> Option<Duration> {
+        self.boost
+            .as_ref()
+            .filter(|(boosted, _)| boosted == id)
+            .and_then(|(_, deadline)| deadline.checked_duration_since(Instant::now()))
+            .filter(|i| !i.is_zero())
+    }
+
+    pub fn get_boost(&self) -> Option<(ContainerId, Instant)> {
+        self.boost.clone()
+    }
+
+    pub fn is_boosted(&self, id: &ContainerId) -> bool {
+        self.get_boost_remaining(id).is_some()
+    }
+
+    /// The state of a boosted container, & when its logs were last updated, None once the boost has expired, or the container has been removed
+    pub fn get_boost_update(&self, id: &ContainerId) -> Option<(State, u64)> {
+        self.containers
+            .items
+            .iter()
+            .find(|i| &i.id == id && !i.is_oxker)
+            .filter(|_| self.is_boosted(id))
+            .map(|i| (i.state, i.last_updated))
+    }
+
+    /// Get Vec of current containers logs, with their timestamps as currently shown
+    pub fn get_logs(&self) -> Vec<ListItem<'static>> {
+        self.get_log_container().map_or(vec![], |i| {
+            i.logs.to_vec(self.log_json, |tz| self.render_stamp(tz))
+        })
+    }
+
+    /// The timestamp of a log line, as it's currently shown
+    fn render_stamp(&self, tz: &LogsTz) -> Option<String> {
+        self.log_time.render(
+            &tz.to_string(),
+            &self.args.timezone,
+            self.args.timestamp_format.as_deref(),
+        )
+    }
+
+    pub const fn get_log_time(&self) -> LogTime {
+        self.log_time
+    }
+
+    /// Cycle how the timestamps of log lines are shown, off, in UTC, or in local time, for every container
+    pub const fn cycle_log_time(&mut self) {
+        self.log_time = self.log_time.next();
+    }
+
+    pub const fn get_log_json(&self) -> Option<JsonView> {
+        self.log_json
+    }
+
+    /// Cycle how log lines of a JSON object are shown, as logged, pretty-printed, or flattened into key=value pairs, for every container
+    pub const fn cycle_log_json(&mut self) {
+        self.log_json = JsonView::next(self.log_json);
+    }
+
+    /// Get the full content of the selected log line, if it was truncated, or is binary
+    pub fn get_selected_log_detail(&self) -> Option<LogDetail> {
+        self.get_log_container()
+            .and_then(|i| i.logs.get_selected_detail())
+            .cloned()
+    }
+
+    /// Get mutable Option of the Logs state of the container whose logs are shown
+    pub fn get_log_state(&mut self) -> Option<&mut ListState> {
+        self.get_mut_log_container().map(|i| i.logs.state())
+    }
+
+    /// Get the logs search of the container whose logs are shown
+    pub fn get_log_search(&self) -> Option<&LogSearch> {
+        self.get_log_container().and_then(|i| i.logs.get_search())
+    }
+
+    /// Set, or clear, the logs search of the container whose logs are shown
+    pub fn set_log_search(&mut self, search: Option<LogSearch>) {
+        if let Some(i) = self.get_mut_log_container() {
+            i.logs.set_search(search);
+        }
+    }
+
+    /// Edit the logs search of the container whose logs are shown, the lines are matched again after each edit
+    pub fn log_search_edit(&mut self, edit: impl FnOnce(&mut LogSearch)) {
+        if let Some(mut search) = self.get_log_search().cloned() {
+            edit(&mut search);
+            self.set_log_search(Some(search));
+        }
+    }
+
+    /// Get the logs filter of the container whose logs are shown
+    pub fn get_log_filter(&self) -> Option<&LogFilter> {
+        self.get_log_container().and_then(|i| i.logs.get_filter())
+    }
+
+    /// Set, or clear, the logs filter of the container whose logs are shown
+    pub fn set_log_filter(&mut self, filter: Option<LogFilter>) {
+        if let Some(i) = self.get_mut_log_container() {
+            i.logs.set_filter(filter);
+        }
+    }
+
+    /// Type a char into the logs filter of the container whose logs are shown, or remove the last char, the lines are filtered again after each edit
+    pub fn log_filter_edit(&mut self, c: Option<char>) {
+        if let Some(filter) = self.get_log_filter() {
+            let mut input = filter.input.clone();
+            match c {
+                Some(c) => input.push(c),
+                None => {
+                    input.pop();
+                }
+            }
+            self.set_log_filter(Some(LogFilter::new(input)));
+        }
+    }
+
+    /// Select the next, or previous, log line with a match of the search
+    pub fn log_search_jump(&mut self, forward: bool) {
+        if let Some(i) = self.get_mut_log_container() {
+            i.logs.search_jump(forward);
+        }
+    }
+
+    /// Chart data related methods
+
+    /// Get mutable Option of the currently selected container chart data
+    pub fn get_chart_data(&mut self) -> Option<(CpuTuple, MemTuple)> {
+        let window = self.get_chart_window();
+        self.containers
+            .state
+            .selected()
+            .and_then(|i| self.containers.items.get_mut(i))
+            .map(|i| i.get_chart_data(self.chart_view, window))
+    }
+
+    /// The number of samples shown in the charts
+    pub fn get_chart_window(&self) -> usize {
+        self.chart_view.window(self.args.chart_history)
+    }
+
+    /// Shown in the chart titles, when zoomed in, or frozen
+    pub fn get_chart_title(&self) -> String {
+        self.chart_view
+            .title(self.args.chart_history, self.args.docker_interval)
+    }
+
+    /// Zoom the charts in, to the more recent samples, or back out, towards the full history
+    pub fn chart_zoom(&mut self, zoom_in: bool) {
+        if zoom_in {
+            self.chart_view.zoom_in(self.args.chart_history);
+        } else {
+            self.chart_view.zoom_out();
+        }
+    }
+
+    /// Freeze the charts, of every container, as they are now, or unfreeze them, samples are still collected while frozen
+    pub fn chart_freeze(&mut self) {
+        self.chart_view.toggle_freeze(Self::get_systemtime());
+    }
+
+    pub const fn is_chart_frozen(&self) -> bool {
+        self.chart_view.frozen().is_some()
+    }
+
+    pub const fn get_io_view(&self) -> Option<IoView> {
+        self.io_view
+    }
+
+    /// Cycle where the network & disk throughput is shown, off -> columns -> columns & charts -> off
+    pub const fn cycle_io_view(&mut self) -> Option<IoView> {
+        self.io_view = IoView::next(self.io_view);
+        self.io_view
+    }
+
+    /// Get network & disk throughput chart data of the selected container, using the same window as the cpu & memory charts
+    pub fn get_io_chart_data(&self) -> Option<(IoChart, State)> {
+        self.get_selected_container()
+            .map(|i| i.get_io_chart_data(self.chart_view, self.get_chart_window()))
+    }
+
+    /// The name, & the full stats history as csv, of the selected container
+    pub fn get_selected_stats_csv(&self) -> Option<(String, String)> {
+        self.get_selected_container()
+            .map(|i| (i.name.get().to_owned(), stats_csv(&i.stats_history())))
+    }
+
+    /// Error related methods
+
+    /// return single app_state error
+    pub const fn get_error(&self) -> Option<AppError> {
+        self.error
+    }
+
+    /// remove single app_state error
+    pub const fn remove_error(&mut self) {
+        self.error = None;
+    }
+
+    /// insert single app_state error
+    pub fn set_error(&mut self, error: AppError, gui_state: &Arc<Mutex<GuiState>>, status: Status) {
+        gui_state.lock().status_push(status);
+        self.error = Some(error);
+    }
+
+    /// Check if the selected container is a dockerised version of oxker
+    /// So that can disallow commands to be send
+    /// Is a shabby way of implementing this
+    pub fn is_oxker(&self) -> bool {
+        self.get_selected_container().map_or(false, |i| i.is_oxker)
+    }
+
+    /// Check if selected container is oxker and also that oxker is being run in a container
+    pub fn is_oxker_in_container(&self) -> bool {
+        self.get_selected_container()
+            .map_or(false, |i| i.is_oxker && self.args.in_container)
+    }
+
+    /// Find the widths for the strings in the containers panel.
+    /// So can display nicely and evenly
+    pub fn get_width(&self) -> Columns {
+        let mut columns = Columns::new();
+        let count = |x: &str| u8::try_from(x.chars().count()).unwrap_or(12);
+        columns.labels = self
+            .args
+            .label_columns
+            .iter()
+            .enumerate()
+            .map(|(index, label)| (Header::Label(index), count(label)))
+            .collect();
+
+        // Should probably find a refactor here somewhere
+        for container in &self.containers.items {
+            let cpu_count = count(&container.cpu_text());
+            for (column, value) in columns.labels.iter_mut().zip(&container.label_values) {
+                column.1 = column.1.max(value.as_deref().map_or(0, count));
+            }
+
+            let mem_current_count = count(
+                &container
+                    .mem_stats
+                    .back()
+                    .unwrap_or(&ByteStats::default())
+                    .to_string(),
+            );
+
+            // Issue here!
+            columns.cpu.1 = columns.cpu.1.max(cpu_count);
+            columns.image.1 = columns.image.1.max(count(&container.image.to_string()));
+            columns.mem.1 = columns.mem.1.max(mem_current_count);
+            columns.mem.2 = columns.mem.2.max(count(&container.mem_limit.to_string()));
+            columns.name.1 = columns
+                .name
+                .1
+                .max(count(&container.display_name(self.group_by)));
+            columns.net_rx.1 = columns.net_rx.1.max(count(&container.rx.to_string()));
+            columns.net_tx.1 = columns.net_tx.1.max(count(&container.tx.to_string()));
+            columns.state.1 = columns.state.1.max(count(
+                &self
+                    .args
+                    .state_colors
+                    .label(container.state, container.is_unhealthy()),
+            ));
+            columns.status.1 = columns.status.1.max(count(&container.status_text()));
+            if !self.args.probes.is_empty() {
+                let ready = container.readiness.map_or(0, |i| count(&i.to_string()));
+                columns.ready = Some((Header::Ready, columns.ready.map_or(5, |i| i.1).max(ready)));
+            }
+            if self.args.restarts {
+                let info = &container.exit_info;
+                let exit_code = info.exit_code.map_or(0, |i| count(&i.to_string()));
+                let restarts = info.restart_count.map_or(0, |i| count(&i.to_string()));
+                columns.exit_code = Some((
+                    Header::ExitCode,
+                    columns.exit_code.map_or(4, |i| i.1).max(exit_code),
+                ));
+                columns.restarts = Some((
+                    Header::Restarts,
+                    columns.restarts.map_or(8, |i| i.1).max(restarts),
+                ));
+            }
+            if container.throttling.has_quota() {
+                let throttled = count(&container.throttling.to_string());
+                columns.throttled = Some((
+                    Header::Throttled,
+                    columns.throttled.map_or(9, |i| i.1).max(throttled),
+                ));
+            }
+            if self.io_view.is_some() {
+                let rates = container.io_rates();
+                columns.net_io = Some((
+                    Header::NetIo,
+                    columns
+                        .net_io
+                        .map_or(7, |i| i.1)
+                        .max(count(&rates.net().to_string())),
+                ));
+                columns.disk_io = Some((
+                    Header::DiskIo,
+                    columns
+                        .disk_io
+                        .map_or(7, |i| i.1)
+                        .max(count(&rates.disk().to_string())),
+                ));
+            }
+            if self.is_multi_host() {
+                let host = count(self.container_host(container));
+                columns.host = Some((Header::Host, columns.host.map_or(4, |i| i.1).max(host)));
+            }
+        }
+        for row in self.get_container_rows() {
+            if let ContainerRow::Group(group) = row {
+                columns.name.1 = columns.name.1.max(count(&group.label()));
+                columns.state.1 = columns.state.1.max(count(&group.count_text()));
+                columns.cpu.1 = columns.cpu.1.max(count(&group.cpu.to_string()));
+                columns.mem.1 = columns.mem.1.max(count(&group.mem.to_string()));
+            }
+        }
+        columns
+    }
+
+    /// Update related methods
+
+    /// Update container mem, cpu, & network stats, in single function so only need to call .lock() once
+    /// Will also, if a sort is set, sort the containers
+    pub fn update_stats_by_id(
+        &mut self,
+        id: &ContainerId,
+        cpu_stat: Option<f64>,
+        mem_stat: Option<u64>,
+        mem_limit: u64,
+        rx: u64,
+        tx: u64,
+    ) {
+        let history = self.args.chart_history;
+        if let Some(container) = self.get_container_by_id(id) {
+            // A paused container reports zeroed stats, which aren't real samples, so the charts are frozen until it's resumed
+            let paused = container.state == State::Paused;
+            if let Some(cpu) = cpu_stat.filter(|_| !paused) {
+                if container.cpu_stats.len() >= history {
+                    container.cpu_stats.pop_front();
+                }
+                container.cpu_stats.push_back(CpuStats::new(cpu));
+            }
+            if let Some(mem) = mem_stat.filter(|_| !paused) {
+                if container.mem_stats.len() >= history {
+                    container.mem_stats.pop_front();
+                }
+                container.mem_stats.push_back(ByteStats::new(mem));
+            }
+            if (cpu_stat.is_some() || mem_stat.is_some()) && !paused {
+                if container.stats_times.len() >= history {
+                    container.stats_times.pop_front();
+                }
+                container.stats_times.push_back(Self::get_systemtime());
+            }
+
+            container.stats_waiting.done();
+            container.rx.update(rx);
+            container.tx.update(tx);
+            container.traffic.count(rx, tx, Self::get_systemtime());
+            container.mem_limit.update(mem_limit);
+        }
+        // Each update would otherwise sort every container, once per container, only the stats columns change the order
+        if self
+            .get_active_sort()
+            .is_some_and(|(head, _)| head.is_stat())
+        {
+            self.sort_containers();
+        }
+    }
+
+    /// Count a sample of the network & block io counters of a container, as rx, tx, read, & write, taken at the given nanosecond timestamp
+    /// Like the cpu & mem stats, a paused container isn't sampled
+    pub fn update_io_by_id(&mut self, id: &ContainerId, counters: [u64; 4], nanos: i128) {
+        let history = self.args.chart_history;
+        if let Some(container) = self.get_container_by_id(id) {
+            if container.state == State::Paused {
+                return;
+            }
+            if let Some(rates) = container.io_counters.rates(counters, nanos) {
+                if container.io_stats.len() >= history {
+                    container.io_stats.pop_front();
+                }
+                container.io_stats.push_back(rates);
+            }
+        }
+    }
+
+    /// Count a sample of the cpu throttling data of a container, only a container with a cpu quota reports any periods
+    pub fn update_throttling_by_id(&mut self, id: &ContainerId, throttling: &ThrottlingData) {
+        if let Some(container) = self.get_container_by_id(id) {
+            container.throttling.count(
+                throttling.periods,
+                throttling.throttled_periods,
+                throttling.throttled_time,
+            );
+        }
+    }
+
+    /// Set the latest memory breakdown of a container, None once it's no longer running
+    pub fn update_memory_by_id(&mut self, id: &ContainerId, memory: Option<MemoryBreakdown>) {
+        if let Some(container) = self.get_container_by_id(id) {
+            container.memory = memory;
+        }
+    }
+
+    /// Set the latest per core cpu usage of a container, None once it's no longer running
+    pub fn update_cores_by_id(&mut self, id: &ContainerId, cores: Option<CpuCores>) {
+        if let Some(container) = self.get_container_by_id(id) {
+            container.cores = cores;
+        }
+    }
+
+    /// Set the writable layer size of each listed container, returns the containers whose layer has grown past the `--disk-alert` since the last check, with their size
+    /// A container that's already over the size isn't returned again, unless its layer shrinks back under it first
+    pub fn update_rw_sizes(
+        &mut self,
+        sizes: &HashMap<ContainerId, u64>,
+    ) -> Vec<(ContainerName, ByteStats)> {
+        let Some(limit) = self.args.disk_alert else {
+            return vec![];
+        };
+        let mut over = vec![];
+        for container in &mut self.containers.items {
+            let Some(size) = sizes.get(&container.id) else {
+                continue;
+            };
+            let was_over = container.rw_size.is_some_and(|i| i.get() > limit);
+            if *size > limit && !was_over {
+                over.push((container.name.clone(), ByteStats::new(*size)));
+            }
+            container.rw_size = Some(ByteStats::new(*size));
+        }
+        over
+    }
+
+    /// Number of containers whose writable layer is over the `--disk-alert`
+    pub fn get_rw_over_len(&self) -> usize {
+        self.args.disk_alert.map_or(0, |limit| {
+            self.containers
+                .items
+                .iter()
+                .filter(|i| i.rw_size.is_some_and(|i| i.get() > limit))
+                .count()
+        })
+    }
+
+    /// Set the containers whose rows were drawn in the containers panel, so that their stats are requested
+    pub fn set_visible_containers(&mut self, ids: HashSet<ContainerId>) {
+        self.visible_containers = ids;
+    }
+
+    /// Stats are only requested for the visible, and selected, containers once more containers are running than `--lazy-stats`
+    /// Every container's stats are needed when sorted by a stats column, including in top mode
+    /// Without the gui no rows are drawn, so no container would be visible, & every container is printed, or summarised, so their stats are all requested
+    /// The `--metrics-port` serves every container's stats, which would otherwise be frozen for the containers scrolled out of view
+    pub fn is_lazy_stats(&self) -> bool {
+        self.args.gui
+            && self.args.metrics.is_none()
+            && self.args.lazy_stats > 0
+            && self.get_running_len() > self.args.lazy_stats
+            && !self
+                .get_active_sort()
+                .is_some_and(|(head, _)| head.is_stat())
+    }
+
+    /// Check if a container's stats should be requested on this update
+    pub fn wants_stats(&self, id: &ContainerId) -> bool {
+        !self.is_lazy_stats()
+            || self.visible_containers.contains(id)
+            || self.get_selected_container().is_some_and(|i| &i.id == id)
+    }
+
+    /// Update, or insert, containers
+    /// Only containers from the given host are removed, or inserted, None is the primary host
+    #[allow(clippy::too_many_lines)]
+    pub fn update_containers(
+        &mut self,
+        host: Option<&str>,
+        all_containers: &mut [ContainerSummary],
+    ) {
+        let all_ids = self
+            .containers
+            .items
+            .iter()
+            .map(|i| (i.id.clone(), i.host.as_deref() == host))
+            .collect::<Vec<_>>();
+
+        // Cached containers have now been replaced, or removed, by the primary host's containers
+        if host.is_none() {
+            self.stale = false;
+        }
+        self.updated = true;
+
+        // Only sort it no containers currently set, as afterwards the order is fixed
+        if self.containers.items.is_empty() {
+            all_containers.sort_by(|a, b| a.created.cmp(&b.created));
+        }
+
+        if !all_containers.is_empty() && self.containers.state.selected().is_none() {
+            self.containers.start();
+        }
+
+        let current_ids = all_containers
+            .iter()
+            .filter_map(|i| i.id.as_deref())
+            .collect::<HashSet<_>>();
+        let mut removed = vec![];
+        for (id, same_host) in &all_ids {
+            if *same_host && !current_ids.contains(id.get()) {
+                let selected = self.get_selected_container_id().as_ref() == Some(id);
+                // If removed container is currently selected, then change selected to previous
+                // This will default to 0 in any edge cases
+                if self.containers.state.selected().is_some() {
+                    self.containers.previous();
+                }
+                // Find by id, as the index shifts once an earlier container has been removed
+                if let Some(index) = self.containers.items.iter().position(|i| &i.id == id) {
+                    removed.push((self.containers.items.remove(index), selected));
+                }
+            }
+        }
+        // Kept in case the container is being recreated, so that its state carries over to the replacement
+        let fallback = self.get_selected_container_id();
+        for (item, selected) in removed {
+            self.recreated.insert(item, selected, fallback.clone());
+        }
+        let mut arrived = vec![];
+
+        // Known containers are found by id, rather than by searching the containers vec for every container, new containers are pushed onto the end, so the indexes remain valid
+        let indexes = self
+            .containers
+            .items
+            .iter()
+            .enumerate()
+            .map(|(index, i)| (i.id.clone(), index))
+            .collect::<HashMap<_, _>>();
+
+        for i in all_containers {
+            if let Some(id) = i.id.as_ref() {
+                let name = i.names.as_mut().map_or(String::new(), |names| {
+                    names.first_mut().map_or(String::new(), |f| {
+                        if f.starts_with('/') {
+                            f.remove(0);
+                        }
+                        (*f).to_string()
+                    })
+                });
+
+                let ports = i.ports.as_ref().map_or(vec![], |i| {
+                    i.iter().map(ContainerPorts::from).collect::<Vec<_>>()
+                });
+                let published = i.ports.as_ref().map_or(vec![], |i| {
+                    i.iter().filter_map(HostPort::from_summary).collect()
+                });
+
+                let id = ContainerId::from(id.as_str());
+
+                let is_oxker = i
+                    .command
+                    .as_ref()
+                    .map_or(false, |i| i.starts_with(ENTRY_POINT));
+
+                let state = State::from(i.state.as_ref().map_or("dead", |z| z));
+                let status = i
+                    .status
+                    .as_ref()
+                    .map_or(String::new(), std::clone::Clone::clone);
+
+                let image = i
+                    .image
+                    .as_ref()
+                    .map_or(String::new(), std::clone::Clone::clone);
+
+                let created = i
+                    .created
+                    .map_or(0, |i| u64::try_from(i).unwrap_or_default());
+
+                let pod = PodInfo::from_labels(i.labels.as_ref());
+                let hints = LabelHints::from_labels(i.labels.as_ref());
+                let project = compose_project(i.labels.as_ref());
+                let service = i
+                    .labels
+                    .as_ref()
+                    .and_then(|labels| labels.get(SERVICE_LABEL).cloned());
+                let priority =
+                    self.args.order_label.as_ref().and_then(|label| {
+                        i.labels.as_ref()?.get(label)?.trim().parse::<i64>().ok()
+                    });
+                let label_values = self
+                    .args
+                    .label_columns
+                    .iter()
+                    .map(|label| i.labels.as_ref()?.get(label).cloned())
+                    .collect::<Vec<_>>();
+                let filter_labels = self
+                    .filters
+                    .iter()
+                    .filter_map(ContainerFilter::label)
+                    .filter_map(|label| {
+                        Some((label.clone(), i.labels.as_ref()?.get(label)?.clone()))
+                    })
+                    .collect::<HashMap<_, _>>();
+                // If container info already in containers Vec, then just update details
+                if let Some(item) = indexes
+                    .get(&id)
+                    .and_then(|i| self.containers.items.get_mut(*i))
+                {
+                    if item.name.get() != name {
+                        item.name.set(name);
+                        // Compose creates the replacement under a temporary name, then renames it once the old container is removed
+                        arrived.push(id);
+                    };
+                    if item.status != status {
+                        item.status = status;
+                    };
+                    if item.state != state {
+                        item.docker_controls.items = DockerControls::gen_vec(state);
+                        // Update the list state, needs to be None if the gen_vec returns an empty vec
+                        match state {
+                            State::Removing | State::Restarting | State::Unknown => {
+                                item.docker_controls.state.select(None);
+                            }
+                            _ => item.docker_controls.start(),
+                        };
+                        // Stats are only requested while alive, so a wait from before the change no longer applies
+                        item.stats_waiting.done();
+                        item.exit_info.stale = true;
+                        item.state = state;
+                    };
+
+                    item.ports = ports;
+                    item.published = published;
+
+                    if item.pod != pod {
+                        item.pod = pod;
+                    }
+
+                    item.filter_labels = filter_labels;
+                    item.hints = hints;
+                    item.label_values = label_values;
+                    item.priority = priority;
+                    item.project = project;
+                    item.service = service;
+
+                    if item.image.get() != image {
+                        item.image.set(image);
+                    };
+                } else {
+                    // container not known, so make new ContainerItem and push into containers Vec
+                    let mut container = ContainerItem::new(
+                        created, id, image, is_oxker, name, ports, state, status,
+                    );
+                    container.filter_labels = filter_labels;
+                    container.hints = hints;
+                    container.host = host.map(ToOwned::to_owned);
+                    container.label_values = label_values;
+                    container.pod = pod;
+                    container.priority = priority;
+                    container.project = project;
+                    container.published = published;
+                    container.service = service;
+                    arrived.push(container.id.clone());
+                    self.containers.items.push(container);
+                }
+            }
+        }
+        for id in arrived {
+            self.carry_over(&id);
+        }
+        // The restarts of each container are counted by the events stream, the oldest are dropped here, so that a storm ends once the container is stable
+        let now = Self::get_systemtime();
+        for item in &mut self.containers.items {
+            item.restart_storm.expire(now);
+        }
+        self.update_port_conflicts();
+
+        // Group header may no longer exist, if all of its containers have been removed
+        if let (Some(group_by), Some(key)) = (self.group_by, self.selected_group.as_ref()) {
+            if !self
+                .containers
+                .items
+                .iter()
+                .any(|i| &group_by.key(i) == key)
+            {
+                self.selected_group = None;
+            }
+        }
+        self.ensure_visible();
+        self.last_update = Some(Instant::now());
+    }
+
+    /// Carry the state of a removed container, with the same name, on the same host, over to its replacement, as when recreated by `docker compose up`
+    /// The chart history, the log lock, the `--watch` matches, & the selection, carry over, notes, & watches, are already kept by name
+    fn carry_over(&mut self, id: &ContainerId) {
+        let Some(index) = self.containers.items.iter().position(|i| &i.id == id) else {
+            return;
+        };
+        let item = &self.containers.items[index];
+        let Some(carried) = self.recreated.take(item.host.as_deref(), item.name.get()) else {
+            return;
+        };
+        if self.log_lock.as_ref() == Some(&carried.id) {
+            self.log_lock = Some(id.clone());
+        }
+        // The lines of the old container's logs are gone, so the lines around a match can no longer be shown
+        for found in self.log_matches.iter_mut().filter(|i| i.id == carried.id) {
+            found.id = id.clone();
+            found.index = None;
+        }
+        if carried.selected
+            && self.selected_group.is_none()
+            && self.get_selected_container_id() == carried.fallback
+        {
+            self.containers.state.select(Some(index));
+        }
+        let history = self.args.chart_history;
+        carried.apply(&mut self.containers.items[index], history);
+    }
+
+    /// Convert a log line into Text, and its LogsTz, along with the full content if it can't be displayed as is
+    fn format_log(args: &CliArgs, line: LogLine) -> (Text<'static>, LogsTz, Option<LogDetail>) {
+        match line {
+            LogLine::Binary(bytes) => {
+                let split = bytes.iter().position(|i| *i == b' ').map_or(0, |i| i + 1);
+                let tz = LogsTz::from(String::from_utf8_lossy(&bytes[..split]).as_ref());
+                let body = bytes[split..].to_vec();
+                let text = format!(
+                    "[binary output, {} bytes, enter to view as hex]",
+                    body.len()
+                );
+                let item = Text::from(log_sanitizer::raw(&text))
+                    .style(Style::default().add_modifier(Modifier::DIM));
+                (item, tz, Some(LogDetail::Binary(body)))
+            }
+            LogLine::Text(i) => {
+                let tz = LogsTz::from(i.as_str());
+                let line = log_sanitizer::carriage_return(&i[tz.to_string().len()..]);
+                // The timestamp is put before the line when it's drawn, as set with ( T )
+                let mut i = line.to_owned();
+                let detail = log_sanitizer::truncate(&i, args.max_line_length)
+                    .map(|truncated| LogDetail::Truncated(std::mem::replace(&mut i, truncated)));
+                let lines = if args.color {
+                    log_sanitizer::colorize_logs(&i)
+                } else if args.raw {
+                    log_sanitizer::raw(&i)
+                } else {
+                    log_sanitizer::remove_ansi(&i)
+                };
+                (Text::from(lines), tz, detail)
+            }
+        }
+    }
+
+    /// Find the first watch that a log line matches, the line is matched without its timestamp, or any ansi formatting
+    /// The index of the line is only known once inserted, so is set by the caller
+    fn watch_match(
+        args: &CliArgs,
+        id: &ContainerId,
+        name: &str,
+        line: &LogLine,
+    ) -> Option<LogMatch> {
+        let LogLine::Text(text) = line else {
+            return None;
+        };
+        if args.watch.is_empty() {
+            return None;
+        }
+        let tz = LogsTz::from(text.as_str());
+        let text = log_sanitizer::plain(log_sanitizer::carriage_return(
+            &text[tz.to_string().len()..],
+        ));
+        args.watch
+            .iter()
+            .find(|i| i.is_match(name, &text))
+            .map(|i| LogMatch {
+                id: id.clone(),
+                index: None,
+                line: text.clone(),
+                name: name.to_owned(),
+                pattern: i.to_string(),
+            })
+    }
+
+    /// update logs of a given container, based on id
+    /// Returns the latest log line that matched a `--watch`, only for lines received after the first update of the containers logs, so that old matches aren't alerted
+    pub fn update_log_by_id<T: Into<LogLine>>(
+        &mut self,
+        logs: Vec<T>,
+        id: &ContainerId,
+    ) -> Option<LogMatch> {
+        let args = self.args.clone();
+        let mut matches = vec![];
+        let mut initial = true;
+        let mut dropped = 0;
+
+        if let Some(container) = self.get_container_by_id(id) {
+            if !container.is_oxker {
+                initial = container.last_updated == 0;
+                container.last_updated = Self::get_systemtime();
+                container.logs_waiting.done();
+                let at_end = container.logs.at_end();
+                let name = container.name.get().to_owned();
+
+                for i in logs {
+                    let line = i.into();
+                    let found = Self::watch_match(&args, id, &name, &line);
+                    let (item, tz, detail) = Self::format_log(&args, line);
+                    if let Some(threshold) = args.log_gap {
+                        container.logs.insert_gap(&tz, threshold);
+                    }
+                    let inserted = match (item, tz, detail) {
+                        (item, tz, Some(detail)) => container.logs.insert_detail(item, tz, detail),
+                        (item, tz, None) => container.logs.insert(item, tz),
+                    };
+                    if let (true, Some(mut found)) = (inserted, found) {
+                        found.index = Some(container.logs.len() - 1);
+                        matches.push(found);
+                    }
+                }
+
+                // Set the logs selected row for each container
+                // Either when no long currently selected, or currently selected (before updated) is already at end
+                if at_end {
+                    container.logs.end();
+                }
+                dropped = container.logs.trim(args.max_log_lines);
+            }
+        }
+        if dropped > 0 {
+            self.shift_log_matches(id, |i| i.checked_sub(dropped));
+            for found in &mut matches {
+                found.index = found.index.and_then(|i| i.checked_sub(dropped));
+            }
+        }
+
+        if !initial {
+            self.unseen_matches += matches.len();
+        }
+        let latest = matches.last().cloned();
+        self.log_matches.extend(matches);
+        let excess = self
+            .log_matches
+            .len()
+            .saturating_sub(log_watch::MAX_LOG_MATCHES);
+        self.log_matches.drain(..excess);
+        latest.filter(|_| !initial)
+    }
+
+    /// Change the index of each `--watch` match in the logs of a container, after lines were inserted before, or dropped from, the start
+    fn shift_log_matches(&mut self, id: &ContainerId, shift: impl Fn(usize) -> Option<usize>) {
+        for found in self.log_matches.iter_mut().filter(|i| &i.id == id) {
+            found.index = found.index.and_then(&shift);
+        }
+    }
+
+    /// The unix timestamp, in seconds, of the oldest log line of a container, None if it has no lines with a timestamp
+    pub fn get_oldest_log(&self, id: &ContainerId) -> Option<i64> {
+        self.containers
+            .items
+            .iter()
+            .find(|i| &i.id == id && !i.is_oxker)
+            .and_then(|i| i.logs.oldest())
+    }
+
+    /// Insert lines logged before the oldest line of a container, as loaded on demand, oldest first, returns the number of lines inserted
+    /// Older lines aren't matched against `--watch`, nor dropped by `--max-log-lines` until the selected line moves past them
+    pub fn prepend_log_by_id<T: Into<LogLine>>(&mut self, logs: Vec<T>, id: &ContainerId) -> usize {
+        let args = self.args.clone();
+        let count = self
+            .get_container_by_id(id)
+            .filter(|i| !i.is_oxker)
+            .map_or(0, |container| {
+                container.logs.prepend(
+                    logs.into_iter()
+                        .map(|i| Self::format_log(&args, i.into()))
+                        .collect(),
+                )
+            });
+        if count > 0 {
+            self.shift_log_matches(id, |i| i.checked_add(count));
+        }
+        count
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::many_single_char_names)]
+mod tests {
+
+    use super::*;
+    use crate::{
+        tests::{gen_appdata, gen_container_summary, gen_containers},
+        ui::TimelineKind,
+    };
+    use std::collections::VecDeque;
+
+    // ******* //
+    // Sort by //
+    // ******* //
+
+    #[test]
+    /// With no sort, `--order` names come first, then the `--order-label` priority, then created datetime
+    fn test_app_data_sort_preferred_order() {
+        let ids = (1..=4)
+            .map(|i| ContainerId::from(i.to_string().as_str()))
+            .collect::<Vec<_>>();
+        let mut containers = ids
+            .iter()
+            .enumerate()
+            .map(|(index, id)| crate::tests::gen_item(id, index + 1))
+            .collect::<Vec<_>>();
+        containers[0].priority = Some(5);
+        containers[1].priority = Some(-1);
+        containers[3].priority = Some(0);
+        let mut app_data = gen_appdata(&containers);
+        app_data.args.order = vec!["container_4".to_owned(), "container_3".to_owned()];
+
+        app_data.sort_containers();
+        let order = |app_data: &AppData| {
+            app_data
+                .get_container_items()
+                .iter()
+                .map(|i| i.id.get().to_owned())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(order(&app_data), ["4", "3", "2", "1"]);
+
+        app_data.set_sorted(Some((Header::Name, SortedOrder::Asc)));
+        assert_eq!(order(&app_data), ["1", "2", "3", "4"]);
+        app_data.reset_sorted();
+        assert_eq!(order(&app_data), ["4", "3", "2", "1"]);
+    }
+
+    #[test]
+    /// Sort by header: name
+    fn test_app_data_set_sort_by_header_name() {
+        let (_ids, containers) = gen_containers();
+
+        let mut app_data = gen_appdata(&containers);
+
+        let result = app_data.get_container_items();
+        assert_eq!(result, &containers);
+
+        // descending
+        app_data.set_sorted(Some((Header::Name, SortedOrder::Desc)));
+        let result = app_data.get_container_items();
+        let (a, b, c) = (&result[0], &result[1], &result[2]);
+        assert_eq!(a.id, ContainerId::from("3"));
+        assert_eq!(b.id, ContainerId::from("2"));
+        assert_eq!(c.id, ContainerId::from("1"));
+
+        // ascending
+        app_data.set_sorted(Some((Header::Name, SortedOrder::Asc)));
+        let result = app_data.get_container_items();
+        let (a, b, c) = (&result[0], &result[1], &result[2]);
+        assert_eq!(a.id, ContainerId::from("1"));
+        assert_eq!(b.id, ContainerId::from("2"));
+        assert_eq!(c.id, ContainerId::from("3"));
+    }
+
+    #[test]
+    /// Sort by header: state
+    fn test_app_data_set_sort_by_header_state() {
+        let (_ids, containers) = gen_containers();
+
+        let mut app_data = gen_appdata(&containers);
+
+        let result = app_data.get_container_items();
+        assert_eq!(result, &containers);
+
+        if let Some(i) = app_data.get_container_by_id(&ContainerId::from("1")) {
+            i.state = State::Exited;
+        }
+        if let Some(i) = app_data.get_container_by_id(&ContainerId::from("2")) {
+            i.state = State::Running;
+        }
+        if let Some(i) = app_data.get_container_by_id(&ContainerId::from("3")) {
+            i.state = State::Paused;
+        }
+
+        // descending
+        app_data.set_sorted(Some((Header::State, SortedOrder::Desc)));
+        let result = app_data.get_container_items();
+        let (a, b, c) = (&result[0], &result[1], &result[2]);
+        assert_eq!(a.id, ContainerId::from("1"));
+        assert_eq!(b.id, ContainerId::from("3"));
+        assert_eq!(c.id, ContainerId::from("2"));
+
+        // ascending
+        app_data.set_sorted(Some((Header::State, SortedOrder::Asc)));
+        let result = app_data.get_container_items();
+        let (a, b, c) = (&result[0], &result[1], &result[2]);
+        assert_eq!(a.id, ContainerId::from("2"));
+        assert_eq!(b.id, ContainerId::from("3"));
+        assert_eq!(c.id, ContainerId::from("1"));
+    }
+
+    #[test]
+    /// Sort by header: status
+    fn test_app_data_set_sort_by_header_status() {
+        let (_ids, containers) = gen_containers();
+
+        let mut app_data = gen_appdata(&containers);
+
+        let result = app_data.get_container_items();
+        assert_eq!(result, &containers);
+
+        if let Some(i) = app_data.get_container_by_id(&ContainerId::from("2")) {
+            i.status = "Exited (0) 10 minutes ago".to_owned();
+        }
+
+        if let Some(i) = app_data.get_container_by_id(&ContainerId::from("3")) {
+            i.status = "Up 2 hours (Paused)".to_owned();
+        }
+
+        // Sort by status
+        // descending
+        app_data.set_sorted(Some((Header::Status, SortedOrder::Desc)));
+        let result = app_data.get_container_items();
+        let (a, b, c) = (&result[0], &result[1], &result[2]);
+        assert_eq!(a.id, ContainerId::from("3"));
+        assert_eq!(b.id, ContainerId::from("1"));
+        assert_eq!(c.id, ContainerId::from("2"));
+
+        // ascending
+        app_data.set_sorted(Some((Header::Status, SortedOrder::Asc)));
+        let result = app_data.get_container_items();
+        let (a, b, c) = (&result[0], &result[1], &result[2]);
+        assert_eq!(a.id, ContainerId::from("2"));
+        assert_eq!(b.id, ContainerId::from("1"));
+        assert_eq!(c.id, ContainerId::from("3"));
+    }
+
+    #[test]
+    /// Sort by header: cpu
+    fn test_app_data_set_sort_by_header_cpu() {
+        let (_ids, containers) = gen_containers();
+
+        let mut app_data = gen_appdata(&containers);
+
+        let result = app_data.get_container_items();
+        assert_eq!(result, &containers);
+
+        if let Some(i) = app_data.get_container_by_id(&ContainerId::from("1")) {
+            i.cpu_stats = VecDeque::from([CpuStats::new(10.1)]);
+        }
+        if let Some(i) = app_data.get_container_by_id(&ContainerId::from("2")) {
+            i.cpu_stats = VecDeque::from([CpuStats::new(8.1)]);
+        }
+        if let Some(i) = app_data.get_container_by_id(&ContainerId::from("3")) {
+            i.cpu_stats = VecDeque::from([CpuStats::new(20.3)]);
+        }
+
+        // descending
+        app_data.set_sorted(Some((Header::Cpu, SortedOrder::Desc)));
+        let result = app_data.get_container_items();
+        let (a, b, c) = (&result[0], &result[1], &result[2]);
+        assert_eq!(a.id, ContainerId::from("3"));
+        assert_eq!(b.id, ContainerId::from("1"));
+        assert_eq!(c.id, ContainerId::from("2"));
+
+        // ascending
+        app_data.set_sorted(Some((Header::Cpu, SortedOrder::Asc)));
+        let result = app_data.get_container_items();
+        let (a, b, c) = (&result[0], &result[1], &result[2]);
+        assert_eq!(a.id, ContainerId::from("2"));
+        assert_eq!(b.id, ContainerId::from("1"));
+        assert_eq!(c.id, ContainerId::from("3"));
+    }
+
+    #[test]
+    /// Sort by header: memory
+    fn test_app_data_set_sort_by_header_mem() {
+        let (_ids, containers) = gen_containers();
+
+        let mut app_data = gen_appdata(&containers);
+
+        let result = app_data.get_container_items();
+        assert_eq!(result, &containers);
+
+        if let Some(i) = app_data.get_container_by_id(&ContainerId::from("1")) {
+            i.mem_stats = VecDeque::from([ByteStats::new(40)]);
+        }
+        if let Some(i) = app_data.get_container_by_id(&ContainerId::from("2")) {
+            i.mem_stats = VecDeque::from([ByteStats::new(80)]);
+        }
+        if let Some(i) = app_data.get_container_by_id(&ContainerId::from("3")) {
+            i.mem_stats = VecDeque::from([ByteStats::new(2)]);
+        }
+
+        // descending
+        app_data.set_sorted(Some((Header::Memory, SortedOrder::Desc)));
+        let result = app_data.get_container_items();
+        let (a, b, c) = (&result[0], &result[1], &result[2]);
+        assert_eq!(a.id, ContainerId::from("2"));
+        assert_eq!(b.id, ContainerId::from("1"));
+        assert_eq!(c.id, ContainerId::from("3"));
+
+        // ascending
+        app_data.set_sorted(Some((Header::Memory, SortedOrder::Asc)));
+        let result = app_data.get_container_items();
+        let (a, b, c) = (&result[0], &result[1], &result[2]);
+        assert_eq!(a.id, ContainerId::from("3"));
+        assert_eq!(b.id, ContainerId::from("1"));
+        assert_eq!(c.id, ContainerId::from("2"));
+    }
+
+    #[test]
+    /// Sort by header: id
+    fn test_app_data_set_sort_by_header_id() {
+        let (_ids, containers) = gen_containers();
+
+        let mut app_data = gen_appdata(&containers);
+
+        let result = app_data.get_container_items();
+        assert_eq!(result, &containers);
+
+        // descending
+        app_data.set_sorted(Some((Header::Id, SortedOrder::Desc)));
+        let result = app_data.get_container_items();
+        let (a, b, c) = (&result[0], &result[1], &result[2]);
+        assert_eq!(a.id, ContainerId::from("3"));
+        assert_eq!(b.id, ContainerId::from("2"));
+        assert_eq!(c.id, ContainerId::from("1"));
+
+        // ascending
+        app_data.set_sorted(Some((Header::Id, SortedOrder::Asc)));
+        let result = app_data.get_container_items();
+        let (a, b, c) = (&result[0], &result[1], &result[2]);
+        assert_eq!(a.id, ContainerId::from("1"));
+        assert_eq!(b.id, ContainerId::from("2"));
+        assert_eq!(c.id, ContainerId::from("3"));
+    }
+
+    #[test]
+    /// Sort by header: image
+    fn test_app_data_set_sort_by_header_image() {
+        let (_ids, containers) = gen_containers();
+
+        let mut app_data = gen_appdata(&containers);
+
+        let result = app_data.get_container_items();
+        assert_eq!(result, &containers);
+
+        // descending
+        app_data.set_sorted(Some((Header::Image, SortedOrder::Desc)));
+        let result = app_data.get_container_items();
+        let (a, b, c) = (&result[0], &result[1], &result[2]);
+        assert_eq!(a.id, ContainerId::from("3"));
+        assert_eq!(b.id, ContainerId::from("2"));
+        assert_eq!(c.id, ContainerId::from("1"));
+
+        // ascending
+        app_data.set_sorted(Some((Header::Image, SortedOrder::Asc)));
+        let result = app_data.get_container_items();
+        let (a, b, c) = (&result[0], &result[1], &result[2]);
+        assert_eq!(a.id, ContainerId::from("1"));
+        assert_eq!(b.id, ContainerId::from("2"));
+        assert_eq!(c.id, ContainerId::from("3"));
+    }
+
+    #[test]
+    /// Sort by header: rx
+    fn test_app_data_set_sort_by_header_rx() {
+        let (_ids, containers) = gen_containers();
+
+        let mut app_data = gen_appdata(&containers);
+
+        let result = app_data.get_container_items();
+        assert_eq!(result, &containers);
+
+        if let Some(i) = app_data.get_container_by_id(&ContainerId::from("1")) {
+            i.rx = ByteStats::new(40);
+        }
+        if let Some(i) = app_data.get_container_by_id(&ContainerId::from("2")) {
+            i.rx = ByteStats::new(80);
+        }
+        if let Some(i) = app_data.get_container_by_id(&ContainerId::from("3")) {
+            i.rx = ByteStats::new(2);
+        }
+
+        // descending
+        app_data.set_sorted(Some((Header::Rx, SortedOrder::Desc)));
+        let result = app_data.get_container_items();
+        let (a, b, c) = (&result[0], &result[1], &result[2]);
+        assert_eq!(a.id, ContainerId::from("2"));
+        assert_eq!(b.id, ContainerId::from("1"));
+        assert_eq!(c.id, ContainerId::from("3"));
+
+        // ascending
+        app_data.set_sorted(Some((Header::Rx, SortedOrder::Asc)));
+        let result = app_data.get_container_items();
+        let (a, b, c) = (&result[0], &result[1], &result[2]);
+        assert_eq!(a.id, ContainerId::from("3"));
+        assert_eq!(b.id, ContainerId::from("1"));
+        assert_eq!(c.id, ContainerId::from("2"));
+    }
+
+    #[test]
+    /// Sort by header: tx
+    fn test_app_data_set_sort_by_header_tx() {
+        let (_ids, containers) = gen_containers();
+
+        let mut app_data = gen_appdata(&containers);
+
+        let result = app_data.get_container_items();
+        assert_eq!(result, &containers);
+
+        if let Some(i) = app_data.get_container_by_id(&ContainerId::from("1")) {
+            i.rx = ByteStats::new(400);
+        }
+        if let Some(i) = app_data.get_container_by_id(&ContainerId::from("2")) {
+            i.rx = ByteStats::new(80);
+        }
+        if let Some(i) = app_data.get_container_by_id(&ContainerId::from("3")) {
+            i.rx = ByteStats::new(83);
+        }
+
+        // descending
+        app_data.set_sorted(Some((Header::Rx, SortedOrder::Desc)));
+        let result = app_data.get_container_items();
+        let (a, b, c) = (&result[0], &result[1], &result[2]);
+        assert_eq!(a.id, ContainerId::from("1"));
+        assert_eq!(b.id, ContainerId::from("3"));
+        assert_eq!(c.id, ContainerId::from("2"));
+
+        // ascending
+        app_data.set_sorted(Some((Header::Rx, SortedOrder::Asc)));
+        let result = app_data.get_container_items();
+        let (a, b, c) = (&result[0], &result[1], &result[2]);
+        assert_eq!(a.id, ContainerId::from("2"));
+        assert_eq!(b.id, ContainerId::from("3"));
+        assert_eq!(c.id, ContainerId::from("1"));
+    }
+
+    #[test]
+    /// Sort by header when selected headers match
+    fn test_app_data_set_sort_by_header_match() {
+        let (_ids, containers) = gen_containers();
+
+        let mut app_data = gen_appdata(&containers);
+
+        let result = app_data.get_container_items();
+        assert_eq!(result, &containers);
+
+        // descending
+        app_data.set_sorted(Some((Header::Rx, SortedOrder::Desc)));
+        let result = app_data.get_container_items();
+        let (a, b, c) = (&result[0], &result[1], &result[2]);
+        assert_eq!(a.id, ContainerId::from("3"));
+        assert_eq!(b.id, ContainerId::from("2"));
+        assert_eq!(c.id, ContainerId::from("1"));
+
+        // ascending
+        app_data.set_sorted(Some((Header::Rx, SortedOrder::Asc)));
+        let result = app_data.get_container_items();
+        let (a, b, c) = (&result[0], &result[1], &result[2]);
+        assert_eq!(a.id, ContainerId::from("1"));
+        assert_eq!(b.id, ContainerId::from("2"));
+        assert_eq!(c.id, ContainerId::from("3"));
+    }
+
+    #[test]
+    /// reset sorted
+    fn test_app_data_reset_sorted() {
+        let (_ids, containers) = gen_containers();
+
+        let mut app_data = gen_appdata(&containers);
+
+        let result = app_data.get_container_items();
+        assert_eq!(result, &containers);
+
+        if let Some(i) = app_data.get_container_by_id(&ContainerId::from("1")) {
+            i.rx = ByteStats::new(400);
+        }
+        if let Some(i) = app_data.get_container_by_id(&ContainerId::from("2")) {
+            i.rx = ByteStats::new(80);
+        }
+        if let Some(i) = app_data.get_container_by_id(&ContainerId::from("3")) {
+            i.rx = ByteStats::new(83);
+        }
+
+        app_data.set_sorted(Some((Header::Rx, SortedOrder::Asc)));
+        let result = app_data.get_container_items();
+        let (a, b, c) = (&result[0], &result[1], &result[2]);
+        assert_eq!(a.id, ContainerId::from("2"));
+        assert_eq!(b.id, ContainerId::from("3"));
+        assert_eq!(c.id, ContainerId::from("1"));
+
+        app_data.set_sorted(None);
+        let result = app_data.get_container_items();
+        let (a, b, c) = (&result[0], &result[1], &result[2]);
+        assert_eq!(a.id, ContainerId::from("1"));
+        assert_eq!(b.id, ContainerId::from("2"));
+        assert_eq!(c.id, ContainerId::from("3"));
+    }
+
+    #[test]
+    /// Top mode sorts by cpu, then memory, descending, selection follows the container, and header sort is restored when turned off
+    fn test_app_data_toggle_top_mode() {
+        let (_ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        app_data.set_sorted(Some((Header::Name, SortedOrder::Asc)));
+        app_data.containers_start();
+
+        for (index, (cpu, mem)) in [(10.1, 2), (8.1, 80), (20.3, 40)].into_iter().enumerate() {
+            app_data.containers.items[index].cpu_stats = VecDeque::from([CpuStats::new(cpu)]);
+            app_data.containers.items[index].mem_stats = VecDeque::from([ByteStats::new(mem)]);
+        }
+
+        app_data.toggle_top_mode();
+        assert_eq!(app_data.get_top_mode(), Some(TopMode::Cpu));
+        assert_eq!(
+            app_data.get_active_sort(),
+            Some((Header::Cpu, SortedOrder::Desc))
+        );
+        let result = app_data.get_container_items();
+        assert_eq!(result[0].id, ContainerId::from("3"));
+        assert_eq!(result[1].id, ContainerId::from("1"));
+        assert_eq!(result[2].id, ContainerId::from("2"));
+        assert_eq!(
+            app_data.get_selected_container_id(),
+            Some(ContainerId::from("1"))
+        );
+
+        // New stats re-order the list, and the selected container is still tracked
+        app_data.update_stats_by_id(&ContainerId::from("1"), Some(50.0), Some(2), 10, 0, 0);
+        assert_eq!(app_data.get_container_items()[0].id, ContainerId::from("1"));
+        assert_eq!(app_data.get_container_state().selected(), Some(0));
+
+        app_data.toggle_top_mode();
+        assert_eq!(app_data.get_top_mode(), Some(TopMode::Memory));
+        let result = app_data.get_container_items();
+        assert_eq!(result[0].id, ContainerId::from("2"));
+        assert_eq!(result[1].id, ContainerId::from("3"));
+        assert_eq!(result[2].id, ContainerId::from("1"));
+
+        app_data.toggle_top_mode();
+        assert!(app_data.get_top_mode().is_none());
+        assert_eq!(
+            app_data.get_active_sort(),
+            Some((Header::Name, SortedOrder::Asc))
+        );
+        let result = app_data.get_container_items();
+        assert_eq!(result[0].id, ContainerId::from("1"));
+        assert_eq!(result[1].id, ContainerId::from("2"));
+        assert_eq!(result[2].id, ContainerId::from("3"));
+    }
+
+    #[test]
+    /// Briefly selected containers aren't recent, a jump always adds the container being viewed, so that jumping again flips back, & removed containers aren't listed
+    fn test_app_data_recent() {
+        let (ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        assert!(app_data.recent_picker().is_none());
+        app_data.containers_start();
+        app_data.update_recent();
+        app_data.containers_next();
+        app_data.update_recent();
+        assert!(app_data.recent_picker().is_none());
+
+        assert_eq!(
+            app_data.select_recent(&ids[2]),
+            Some(ContainerName::from("container_3"))
+        );
+        let picker = app_data.recent_picker().unwrap();
+        assert_eq!(picker.rows.len(), 1);
+        assert_eq!(picker.chosen().unwrap().id, ids[1]);
+
+        app_data.select_recent(&ids[1]);
+        assert_eq!(app_data.get_selected_container_id(), Some(ids[1].clone()));
+        assert_eq!(app_data.recent_picker().unwrap().rows[0].id, ids[2]);
+
+        app_data.containers.items.retain(|i| i.id != ids[2]);
+        assert!(app_data.recent_picker().is_none());
+        assert!(app_data.select_recent(&ids[2]).is_none());
+    }
+
+    #[test]
+    /// Marked containers are only sent a control that they can take in their current state, & the controls, & title, follow the marks
+    fn test_app_data_marked() {
+        let (_ids, mut containers) = gen_containers();
+        containers[1].state = State::Exited;
+        let mut app_data = gen_appdata(&containers);
+        app_data.containers_start();
+        app_data.toggle_marked();
+        app_data.containers_next();
+        app_data.toggle_marked();
+        app_data.containers_next();
+        app_data.toggle_marked();
+        app_data.toggle_marked();
+
+        assert_eq!(app_data.get_marked_len(), 2);
+        assert!(app_data.is_marked(&ContainerId::from("2")));
+        assert!(!app_data.is_marked(&ContainerId::from("3")));
+        assert_eq!(app_data.container_title(), " 3/3 - 2 marked");
+        assert_eq!(
+            app_data.selected_docker_controls(),
+            Some(DockerControls::Start)
+        );
+        assert_eq!(
+            app_data.get_marked(DockerControls::Stop),
+            vec![(ContainerId::from("1"), ContainerName::from("container_1"))]
+        );
+        assert_eq!(
+            app_data.get_marked(DockerControls::Start),
+            vec![(ContainerId::from("2"), ContainerName::from("container_2"))]
+        );
+        assert_eq!(app_data.get_marked(DockerControls::Delete).len(), 2);
+
+        app_data.clear_marked();
+        assert_eq!(app_data.get_marked_len(), 0);
+        assert_eq!(app_data.container_title(), " 3/3");
+    }
+
+    #[test]
+    /// Clock skew is only returned when it's at, or over, the limit
+    fn test_app_data_clock_skew() {
+        let (_ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        assert!(app_data.get_clock_skew().is_none());
+        app_data.set_clock_skew(Some(4));
+        assert!(app_data.get_clock_skew().is_none());
+        app_data.set_clock_skew(Some(-4));
+        assert!(app_data.get_clock_skew().is_none());
+        app_data.set_clock_skew(Some(5));
+        assert_eq!(app_data.get_clock_skew(), Some(5));
+        app_data.set_clock_skew(Some(-3600));
+        assert_eq!(app_data.get_clock_skew(), Some(-3600));
+    }
+
+    #[test]
+    /// Daemon warnings are only announced the first time the daemon's details are known
+    fn test_app_data_daemon_info() {
+        let (_ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        assert!(app_data.get_daemon_info().is_none());
+        let info = DaemonInfo {
+            warnings: vec!["No swap limit support".to_owned()],
+            ..DaemonInfo::default()
+        };
+        assert!(app_data.set_daemon_info(info.clone()));
+        assert!(!app_data.set_daemon_info(info.clone()));
+        assert_eq!(app_data.get_daemon_warnings_len(), 1);
+        assert_eq!(app_data.get_daemon_info(), Some(&info));
+
+        app_data.daemon_info = None;
+        assert!(!app_data.set_daemon_info(DaemonInfo::default()));
+        assert_eq!(app_data.get_daemon_warnings_len(), 0);
+    }
+
+    #[test]
+    /// Host defaults to local, latency cleared on a failed request
+    fn test_app_data_host_latency() {
+        let (_ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        assert_eq!(app_data.get_host(), "local");
+        assert!(app_data.get_latency().is_none());
+        app_data.set_latency(Some(Duration::from_millis(12)));
+        assert_eq!(app_data.get_latency(), Some(Duration::from_millis(12)));
+        app_data.set_latency(None);
+        assert!(app_data.get_latency().is_none());
+
+        app_data.args.host = Some("tcp://10.0.0.5:2375".to_owned());
+        assert_eq!(app_data.get_host(), "tcp://10.0.0.5:2375");
+    }
+
+    // **************** //
+    // Container groups //
+    // **************** //
+
+    /// Containers 1 & 3 share an image, so grouped into a single group, which is placed before the group of container 2
+    fn gen_grouped_appdata() -> AppData {
+        let (_ids, mut containers) = gen_containers();
+        containers[0].image.set("shared".to_owned());
+        containers[2].image.set("shared".to_owned());
+        for (index, (cpu, mem)) in [(10.0, 20), (5.0, 10), (2.5, 30)].into_iter().enumerate() {
+            containers[index].cpu_stats = VecDeque::from([CpuStats::new(cpu)]);
+            containers[index].mem_stats = VecDeque::from([ByteStats::new(mem)]);
+        }
+        containers[2].state = State::Exited;
+        let mut app_data = gen_appdata(&containers);
+        app_data.containers_start();
+        app_data.toggle_group_by();
+        app_data
+    }
+
+    #[test]
+    /// Rows are container indexes when not grouped, and group headers followed by their members when grouped
+    fn test_app_data_container_rows() {
+        let (_ids, containers) = gen_containers();
+        let app_data = gen_appdata(&containers);
+        assert_eq!(
+            app_data.get_container_rows(),
+            vec![
+                ContainerRow::Container(0),
+                ContainerRow::Container(1),
+                ContainerRow::Container(2)
+            ]
+        );
+
+        let app_data = gen_grouped_appdata();
+        assert_eq!(app_data.get_group_by(), Some(GroupBy::Image));
+        let result = app_data.get_container_rows();
+        assert_eq!(result.len(), 5);
+        assert_eq!(
+            result[0],
+            ContainerRow::Group(ContainerGroup {
+                key: "shared".to_owned(),
+                count: 2,
+                running: 1,
+                cpu: CpuStats::new(12.5),
+                mem: ByteStats::new(50),
+                expanded: true,
+            })
+        );
+        assert_eq!(result[1], ContainerRow::Container(0));
+        assert_eq!(result[2], ContainerRow::Container(2));
+        assert!(matches!(&result[3], ContainerRow::Group(i) if i.key == "image_2" && i.count == 1));
+        assert_eq!(result[4], ContainerRow::Container(1));
+    }
+
+    #[test]
+    /// Navigating when grouped moves through group headers, and with a header selected there is no selected container
+    fn test_app_data_container_groups_navigation() {
+        let mut app_data = gen_grouped_appdata();
+        assert_eq!(app_data.get_container_state().selected(), Some(1));
+
+        app_data.containers_previous();
+        assert_eq!(app_data.get_selected_group(), Some("shared"));
+        assert!(app_data.get_selected_container().is_none());
+        assert_eq!(app_data.get_container_state().selected(), Some(0));
+        assert_eq!(app_data.container_title(), " -/3");
+
+        app_data.containers_next();
+        app_data.containers_next();
+        assert_eq!(
+            app_data.get_selected_container_id(),
+            Some(ContainerId::from("3"))
+        );
+        app_data.containers_next();
+        assert_eq!(app_data.get_selected_group(), Some("image_2"));
+
+        app_data.containers_end();
+        assert_eq!(
+            app_data.get_selected_container_id(),
+            Some(ContainerId::from("2"))
+        );
+        assert_eq!(app_data.get_container_state().selected(), Some(4));
+        app_data.containers_next();
+        assert_eq!(app_data.get_container_state().selected(), Some(4));
+
+        app_data.containers_start();
+        assert_eq!(app_data.get_selected_group(), Some("shared"));
+
+        // Turning off grouping selects the previously selected container
+        app_data.toggle_group_by();
+        assert!(app_data.get_group_by().is_none());
+        assert_eq!(app_data.get_container_state().selected(), Some(1));
+        assert_eq!(
+            app_data.get_selected_container_id(),
+            Some(ContainerId::from("2"))
+        );
+    }
+
+    #[test]
+    /// Collapsing a group hides its members, collapsing from a member selects the group header
+    fn test_app_data_container_groups_expand() {
+        let mut app_data = gen_grouped_appdata();
+        app_data.containers_next();
+        assert_eq!(
+            app_data.get_selected_container_id(),
+            Some(ContainerId::from("3"))
+        );
+
+        app_data.set_group_expanded(Some(false));
+        assert_eq!(app_data.get_selected_group(), Some("shared"));
+        let result = app_data.get_container_rows();
+        assert_eq!(result.len(), 3);
+        assert!(matches!(&result[0], ContainerRow::Group(i) if !i.expanded));
+        assert!(matches!(&result[1], ContainerRow::Group(i) if i.key == "image_2"));
+
+        app_data.containers_next();
+        assert_eq!(app_data.get_selected_group(), Some("image_2"));
+        app_data.containers_previous();
+
+        // None toggles
+        app_data.set_group_expanded(None);
+        assert_eq!(app_data.get_container_rows().len(), 5);
+        app_data.set_group_expanded(None);
+        assert_eq!(app_data.get_container_rows().len(), 3);
+        app_data.set_group_expanded(Some(true));
+        assert_eq!(app_data.get_container_rows().len(), 5);
+    }
+
+    // **************** //
+    // Container state  //
+    // **************** //
+
+    #[test]
+    /// Pod grouping is skipped when no container has kubernetes labels, containers without labels are grouped together
+    fn test_app_data_container_groups_pod() {
+        let (_ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        app_data.toggle_group_by();
+        assert_eq!(app_data.get_group_by(), Some(GroupBy::Image));
+        app_data.toggle_group_by();
+        assert!(app_data.get_group_by().is_none());
+
+        let mut input = [
+            gen_container_summary(1, "running"),
+            gen_container_summary(2, "running"),
+            gen_container_summary(3, "running"),
+        ];
+        for i in &mut input[..2] {
+            i.labels = Some(std::collections::HashMap::from([
+                ("io.kubernetes.pod.name".to_owned(), "web".to_owned()),
+                (
+                    "io.kubernetes.pod.namespace".to_owned(),
+                    "default".to_owned(),
+                ),
+            ]));
+        }
+        app_data.update_containers(None, &mut input);
+        app_data.toggle_group_by();
+        app_data.toggle_group_by();
+        assert_eq!(app_data.get_group_by(), Some(GroupBy::Pod));
+        let result = app_data.get_container_rows();
+        assert_eq!(result.len(), 5);
+        assert!(
+            matches!(&result[0], ContainerRow::Group(i) if i.key == "default/web" && i.count == 2)
+        );
+        assert!(matches!(&result[3], ContainerRow::Group(i) if i.key == "no pod" && i.count == 1));
+        assert_eq!(
+            app_data.get_container_items()[0].display_name(Some(GroupBy::Pod)),
+            "POD"
+        );
+    }
+
+    #[test]
+    /// Grouping by label is only offered when a container has an oxker.group label, the oxker.url label replaces the published port url
+    fn test_app_data_container_groups_label() {
+        let (_ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        let mut input = [
+            gen_container_summary(1, "running"),
+            gen_container_summary(2, "running"),
+            gen_container_summary(3, "running"),
+        ];
+        input[1].labels = Some(std::collections::HashMap::from([
+            ("oxker.group".to_owned(), "backend".to_owned()),
+            ("oxker.url".to_owned(), "https://api.example.com".to_owned()),
+        ]));
+        app_data.update_containers(None, &mut input);
+        app_data.toggle_group_by();
+        app_data.toggle_group_by();
+        assert_eq!(app_data.get_group_by(), Some(GroupBy::Label));
+        let result = app_data.get_container_rows();
+        assert_eq!(result.len(), 5);
+        assert!(
+            matches!(&result[0], ContainerRow::Group(i) if i.key == "no group" && i.count == 2)
+        );
+        assert!(matches!(&result[3], ContainerRow::Group(i) if i.key == "backend" && i.count == 1));
+        app_data.toggle_group_by();
+        assert!(app_data.get_group_by().is_none());
+
+        app_data.containers_start();
+        app_data.containers_next();
+        assert_eq!(
+            app_data.get_selected_url().as_deref(),
+            Some("https://api.example.com")
+        );
+    }
+
+    #[test]
+    /// Grouping by compose project is only offered when there are compose containers, a selected project header has its own commands, sent to each container they apply to
+    fn test_app_data_container_groups_project() {
+        let (_ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        let mut input = [
+            gen_container_summary(1, "running"),
+            gen_container_summary(2, "exited"),
+            gen_container_summary(3, "running"),
+        ];
+        for i in &mut input[..2] {
+            i.labels = Some(std::collections::HashMap::from([(
+                PROJECT_LABEL.to_owned(),
+                "site".to_owned(),
+            )]));
+        }
+        app_data.update_containers(None, &mut input);
+        app_data.toggle_group_by();
+        app_data.toggle_group_by();
+        assert_eq!(app_data.get_group_by(), Some(GroupBy::Project));
+        let result = app_data.get_container_rows();
+        assert_eq!(result.len(), 5);
+        assert!(matches!(&result[0], ContainerRow::Group(i) if i.key == "site" && i.count == 2));
+        assert!(
+            matches!(&result[3], ContainerRow::Group(i) if i.key == "no project" && i.count == 1)
+        );
+
+        app_data.containers_start();
+        assert_eq!(app_data.get_selected_project(), Some("site"));
+        assert_eq!(
+            app_data.get_control_items().cloned(),
+            Some(vec![
+                DockerControls::Start,
+                DockerControls::Restart,
+                DockerControls::Stop
+            ])
+        );
+        app_data.docker_controls_end();
+        assert_eq!(
+            app_data.selected_docker_controls(),
+            Some(DockerControls::Stop)
+        );
+        assert_eq!(
+            app_data.get_project_ids("site", DockerControls::Stop),
+            [ContainerId::from("1")]
+        );
+        assert_eq!(
+            app_data.get_project_ids("site", DockerControls::Start),
+            [ContainerId::from("2")]
+        );
+        assert_eq!(
+            app_data
+                .get_project_ids("site", DockerControls::Restart)
+                .len(),
+            2
+        );
+
+        app_data.containers_end();
+        app_data.containers_previous();
+        assert_eq!(app_data.get_selected_group(), Some("no project"));
+        assert!(app_data.get_selected_project().is_none());
+        assert!(app_data.get_control_items().is_none());
+
+        app_data.toggle_group_by();
+        assert!(app_data.get_group_by().is_none());
+    }
+
+    #[test]
+    /// Once more containers are running than --lazy-stats, only the visible, & selected, containers want stats, unless sorted by a stats column, there's no gui, or the metrics are served
+    fn test_app_data_lazy_stats() {
+        let (ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        app_data.containers_start();
+        app_data.set_visible_containers(HashSet::from([ids[1].clone()]));
+        assert!(!app_data.is_lazy_stats());
+        assert!(app_data.wants_stats(&ids[2]));
+
+        app_data.args.lazy_stats = 2;
+        assert!(app_data.is_lazy_stats());
+        assert!(app_data.wants_stats(&ids[0]));
+        assert!(app_data.wants_stats(&ids[1]));
+        assert!(!app_data.wants_stats(&ids[2]));
+
+        app_data.set_sort_by_header(Header::Cpu);
+        assert!(!app_data.is_lazy_stats());
+        assert!(app_data.wants_stats(&ids[2]));
+        app_data.set_sort_by_header(Header::Name);
+        assert!(app_data.is_lazy_stats());
+
+        app_data.args.gui = false;
+        assert!(!app_data.is_lazy_stats());
+        assert!(app_data.wants_stats(&ids[2]));
+        app_data.args.gui = true;
+
+        app_data.args.metrics = Some(std::net::SocketAddr::from(([127, 0, 0, 1], 9_187)));
+        assert!(!app_data.is_lazy_stats());
+        app_data.args.metrics = None;
+
+        app_data.args.lazy_stats = 0;
+        assert!(!app_data.is_lazy_stats());
+    }
+
+    #[test]
+    /// Get len of current containers vec
+    fn test_app_data_get_container_len() {
+        let (_ids, containers) = gen_containers();
+        let app_data = gen_appdata(&containers);
+        assert_eq!(app_data.get_container_len(), 3);
+    }
+
+    #[test]
+    /// Select the first container
+    fn test_app_data_containers_start() {
+        let (_ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+
+        // No container selected
+        let result = app_data.get_container_state();
+        assert_eq!(result.selected(), None);
+        assert_eq!(result.offset(), 0);
+
+        // First container selected
+        app_data.containers_start();
+        let result = app_data.get_container_state();
+        assert_eq!(result.selected(), Some(0));
+        assert_eq!(result.offset(), 0);
+
+        let result = app_data.get_selected_container_id();
+        assert_eq!(result, Some(ContainerId::from("1")));
+        let result = app_data.get_selected_container_id_state_name();
+        assert_eq!(
+            result,
+            Some((
+                ContainerId::from("1"),
+                State::Running,
+                "container_1".to_owned()
+            ))
+        );
+
+        // Calling previous when at start has no effect
+        app_data.containers_previous();
+        let result = app_data.get_selected_container_id();
+        assert_eq!(result, Some(ContainerId::from("1")));
+        let result = app_data.get_selected_container_id_state_name();
+        assert_eq!(
+            result,
+            Some((
+                ContainerId::from("1"),
+                State::Running,
+                "container_1".to_owned()
+            ))
+        );
+    }
+
+    #[test]
+    /// advance container list state by one
+    /// get get_selected_container_id() & get_selected_container_id_state_name() return valid Some data
+    fn test_app_data_containers_next() {
+        let (_ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+
+        // Advance list state by 1
+        app_data.containers_start();
+        app_data.containers_next();
+
+        let result = app_data.get_container_state();
+        assert_eq!(result.selected(), Some(1));
+        assert_eq!(result.offset(), 0);
+
+        let result = app_data.get_selected_container_id();
+        assert_eq!(result, Some(ContainerId::from("2")));
+        let result = app_data.get_selected_container_id_state_name();
+        assert_eq!(
+            result,
+            Some((
+                ContainerId::from("2"),
+                State::Running,
+                "container_2".to_owned()
+            ))
+        );
+    }
+
+    #[test]
+    /// advance container list state to the end
+    /// get get_selected_container_id() & get_selected_container_id_state_name() return valid Some data
+    fn test_app_data_containers_end() {
+        let (_ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+
+        app_data.containers_end();
+        let result = app_data.get_container_state();
+        assert_eq!(result.selected(), Some(2));
+        assert_eq!(result.offset(), 0);
+
+        let result = app_data.get_selected_container_id();
+        assert_eq!(result, Some(ContainerId::from("3")));
+        let result = app_data.get_selected_container_id_state_name();
+        assert_eq!(
+            result,
+            Some((
+                ContainerId::from("3"),
+                State::Running,
+                "container_3".to_owned()
+            ))
+        );
+
+        // Calling previous when at end has no effect
+        app_data.containers_next();
+        let result = app_data.get_selected_container_id();
+        assert_eq!(result, Some(ContainerId::from("3")));
+        let result = app_data.get_selected_container_id_state_name();
+        assert_eq!(
+            result,
+            Some((
+                ContainerId::from("3"),
+                State::Running,
+                "container_3".to_owned()
+            ))
+        );
+    }
+
+    #[test]
+    /// go to previous container
+    fn test_app_data_containers_prev() {
+        let (_ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+
+        app_data.containers_end();
+        app_data.containers_previous();
+        let result = app_data.get_container_state();
+        assert_eq!(result.selected(), Some(1));
+        assert_eq!(result.offset(), 0);
+    }
+
+    #[test]
+    /// Get the currently selected container
+    fn test_app_data_get_selected_container() {
+        let (_ids, mut containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+
+        let result = app_data.get_selected_container();
+        assert_eq!(result, None);
+
+        app_data.containers.start();
+        app_data.containers.next();
+
+        let result = app_data.get_selected_container();
+        assert_eq!(result, Some(&containers[1]));
+
+        // As above, but now as mut
+        let result = app_data.get_mut_selected_container();
+        assert_eq!(result, Some(&mut containers[1]));
+    }
+
+    #[test]
+    /// Get mut container by id
+    fn test_app_data_get_container_by_id() {
+        let (_ids, mut containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+
+        let result = app_data.get_container_by_id(&ContainerId::from("2"));
+        assert_eq!(result, Some(&mut containers[1]));
+    }
+
+    #[test]
+    /// Get just the containers name by id
+    fn test_app_data_get_container_name_by_id() {
+        let (_ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+
+        let result = app_data.get_container_name_by_id(&ContainerId::from("2"));
+        assert_eq!(result, Some(ContainerName::from("container_2")));
+    }
+
+    #[test]
+    /// Get the id of the currently selected container
+    fn test_app_data_get_selected_container_id() {
+        let (_ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        app_data.containers_end();
+
+        let result = app_data.get_selected_container_id();
+        assert_eq!(result, Some(ContainerId::from("3")));
+    }
+
+    #[test]
+    fn test_app_data_get_selected_container_id_state_name() {
+        let (_ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        app_data.containers_end();
+
+        let result = app_data.get_selected_container_id_state_name();
+        assert_eq!(
+            result,
+            Some((
+                ContainerId::from("3"),
+                State::Running,
+                "container_3".to_owned()
+            ))
+        );
+    }
 
-    // ******* //
-    // Sort by //
-    // ******* //
+    // ************** //
+    // DockerControls //
+    // ************** //
 
     #[test]
-    /// Sort by header: name
-    fn test_app_data_set_sort_by_header_name() {
+    /// Docker commands returned correctly
+    fn test_app_data_selected_docker_command() {
         let (_ids, containers) = gen_containers();
-
         let mut app_data = gen_appdata(&containers);
 
-        let result = app_data.get_container_items();
-        assert_eq!(result, &containers);
+        // No commands when no container selected
+        let result = app_data.selected_docker_controls();
+        assert!(result.is_none());
 
-        // descending
-        app_data.set_sorted(Some((Header::Name, SortedOrder::Desc)));
-        let result = app_data.get_container_items();
-        let (a, b, c) = (&result[0], &result[1], &result[2]);
-        assert_eq!(a.id, ContainerId::from("3"));
-        assert_eq!(b.id, ContainerId::from("2"));
-        assert_eq!(c.id, ContainerId::from("1"));
+        // Correct commands returned
+        app_data.containers_start();
+        app_data.docker_controls_start();
 
-        // ascending
-        app_data.set_sorted(Some((Header::Name, SortedOrder::Asc)));
-        let result = app_data.get_container_items();
-        let (a, b, c) = (&result[0], &result[1], &result[2]);
-        assert_eq!(a.id, ContainerId::from("1"));
-        assert_eq!(b.id, ContainerId::from("2"));
-        assert_eq!(c.id, ContainerId::from("3"));
+        let result = app_data.selected_docker_controls();
+        assert_eq!(result, Some(DockerControls::Pause));
     }
 
     #[test]
-    /// Sort by header: state
-    fn test_app_data_set_sort_by_header_state() {
+    /// Docker command next works
+    fn test_app_data_selected_docker_command_next() {
         let (_ids, containers) = gen_containers();
-
         let mut app_data = gen_appdata(&containers);
+        app_data.containers_start();
+        app_data.docker_controls_start();
+        app_data.docker_controls_next();
 
-        let result = app_data.get_container_items();
-        assert_eq!(result, &containers);
-
-        if let Some(i) = app_data.get_container_by_id(&ContainerId::from("1")) {
-            i.state = State::Exited;
-        }
-        if let Some(i) = app_data.get_container_by_id(&ContainerId::from("2")) {
-            i.state = State::Running;
-        }
-        if let Some(i) = app_data.get_container_by_id(&ContainerId::from("3")) {
-            i.state = State::Paused;
-        }
-
-        // descending
-        app_data.set_sorted(Some((Header::State, SortedOrder::Desc)));
-        let result = app_data.get_container_items();
-        let (a, b, c) = (&result[0], &result[1], &result[2]);
-        assert_eq!(a.id, ContainerId::from("1"));
-        assert_eq!(b.id, ContainerId::from("3"));
-        assert_eq!(c.id, ContainerId::from("2"));
-
-        // ascending
-        app_data.set_sorted(Some((Header::State, SortedOrder::Asc)));
-        let result = app_data.get_container_items();
-        let (a, b, c) = (&result[0], &result[1], &result[2]);
-        assert_eq!(a.id, ContainerId::from("2"));
-        assert_eq!(b.id, ContainerId::from("3"));
-        assert_eq!(c.id, ContainerId::from("1"));
+        let result = app_data.selected_docker_controls();
+        assert_eq!(result, Some(DockerControls::Restart));
     }
 
     #[test]
-    /// Sort by header: status
-    fn test_app_data_set_sort_by_header_status() {
+    /// Dockercommand end works, and next has no effect when at end
+    fn test_app_data_selected_docker_command_end() {
         let (_ids, containers) = gen_containers();
-
         let mut app_data = gen_appdata(&containers);
+        app_data.containers_start();
+        app_data.docker_controls_end();
 
-        let result = app_data.get_container_items();
-        assert_eq!(result, &containers);
-
-        if let Some(i) = app_data.get_container_by_id(&ContainerId::from("2")) {
-            i.status = "Exited (0) 10 minutes ago".to_owned();
-        }
-
-        if let Some(i) = app_data.get_container_by_id(&ContainerId::from("3")) {
-            i.status = "Up 2 hours (Paused)".to_owned();
-        }
-
-        // Sort by status
-        // descending
-        app_data.set_sorted(Some((Header::Status, SortedOrder::Desc)));
-        let result = app_data.get_container_items();
-        let (a, b, c) = (&result[0], &result[1], &result[2]);
-        assert_eq!(a.id, ContainerId::from("3"));
-        assert_eq!(b.id, ContainerId::from("1"));
-        assert_eq!(c.id, ContainerId::from("2"));
+        let result = app_data.selected_docker_controls();
+        assert_eq!(result, Some(DockerControls::Delete));
 
-        // ascending
-        app_data.set_sorted(Some((Header::Status, SortedOrder::Asc)));
-        let result = app_data.get_container_items();
-        let (a, b, c) = (&result[0], &result[1], &result[2]);
-        assert_eq!(a.id, ContainerId::from("2"));
-        assert_eq!(b.id, ContainerId::from("1"));
-        assert_eq!(c.id, ContainerId::from("3"));
+        // Next has no effect when at end
+        app_data.docker_controls_next();
+        let result = app_data.selected_docker_controls();
+        assert_eq!(result, Some(DockerControls::Delete));
     }
 
     #[test]
-    /// Sort by header: cpu
-    fn test_app_data_set_sort_by_header_cpu() {
+    /// Docker commands previous works, and has no effect when at start
+    fn test_app_data_selected_docker_command_previous() {
         let (_ids, containers) = gen_containers();
-
         let mut app_data = gen_appdata(&containers);
+        app_data.containers_start();
+        app_data.docker_controls_end();
+        app_data.docker_controls_previous();
 
-        let result = app_data.get_container_items();
-        assert_eq!(result, &containers);
+        let result = app_data.selected_docker_controls();
+        assert_eq!(result, Some(DockerControls::Rename));
 
-        if let Some(i) = app_data.get_container_by_id(&ContainerId::from("1")) {
-            i.cpu_stats = VecDeque::from([CpuStats::new(10.1)]);
-        }
-        if let Some(i) = app_data.get_container_by_id(&ContainerId::from("2")) {
-            i.cpu_stats = VecDeque::from([CpuStats::new(8.1)]);
-        }
-        if let Some(i) = app_data.get_container_by_id(&ContainerId::from("3")) {
-            i.cpu_stats = VecDeque::from([CpuStats::new(20.3)]);
-        }
+        // previous has no effect when at start
+        app_data.docker_controls_start();
+        app_data.docker_controls_previous();
+        let result = app_data.selected_docker_controls();
+        assert_eq!(result, Some(DockerControls::Pause));
+    }
 
-        // descending
-        app_data.set_sorted(Some((Header::Cpu, SortedOrder::Desc)));
-        let result = app_data.get_container_items();
-        let (a, b, c) = (&result[0], &result[1], &result[2]);
-        assert_eq!(a.id, ContainerId::from("3"));
-        assert_eq!(b.id, ContainerId::from("1"));
-        assert_eq!(c.id, ContainerId::from("2"));
+    #[test]
+    /// DockerCommands get correct controls dependant on container state
+    fn test_app_data_get_control_items() {
+        let test_state = |state: State, expected: &mut Vec<DockerControls>| {
+            let gen_item_state = |state: State| {
+                ContainerItem::new(
+                    1,
+                    ContainerId::from("1"),
+                    "image_1".to_owned(),
+                    false,
+                    "container_1".to_owned(),
+                    vec![],
+                    state,
+                    "Up 1 hour".to_owned(),
+                )
+            };
+            let mut app_data = gen_appdata(&[gen_item_state(state)]);
+            app_data.containers_start();
+            app_data.docker_controls_start();
 
-        // ascending
-        app_data.set_sorted(Some((Header::Cpu, SortedOrder::Asc)));
-        let result = app_data.get_container_items();
-        let (a, b, c) = (&result[0], &result[1], &result[2]);
-        assert_eq!(a.id, ContainerId::from("2"));
-        assert_eq!(b.id, ContainerId::from("1"));
-        assert_eq!(c.id, ContainerId::from("3"));
+            let result = app_data.get_control_items();
+            assert_eq!(result, Some(expected));
+        };
+
+        test_state(
+            State::Dead,
+            &mut vec![
+                DockerControls::Start,
+                DockerControls::Restart,
+                DockerControls::Rename,
+                DockerControls::Delete,
+            ],
+        );
+        test_state(
+            State::Exited,
+            &mut vec![
+                DockerControls::Start,
+                DockerControls::Restart,
+                DockerControls::Rename,
+                DockerControls::Delete,
+            ],
+        );
+        test_state(
+            State::Paused,
+            &mut vec![
+                DockerControls::Resume,
+                DockerControls::Stop,
+                DockerControls::Rename,
+                DockerControls::Delete,
+            ],
+        );
+        test_state(State::Removing, &mut vec![DockerControls::Delete]);
+        test_state(
+            State::Restarting,
+            &mut vec![DockerControls::Stop, DockerControls::Delete],
+        );
+        test_state(
+            State::Running,
+            &mut vec![
+                DockerControls::Pause,
+                DockerControls::Restart,
+                DockerControls::Stop,
+                DockerControls::Kill(Signal::Term),
+                DockerControls::Rename,
+                DockerControls::Delete,
+            ],
+        );
+        test_state(State::Unknown, &mut vec![DockerControls::Delete]);
     }
 
+    // **** //
+    // Logs //
+    // **** //
+
     #[test]
-    /// Sort by header: memory
-    fn test_app_data_set_sort_by_header_mem() {
-        let (_ids, containers) = gen_containers();
+    /// log title string generated correctly
+    fn test_app_data_get_log_title() {
+        let (ids, containers) = gen_containers();
 
         let mut app_data = gen_appdata(&containers);
 
-        let result = app_data.get_container_items();
-        assert_eq!(result, &containers);
+        // No container selected select
+        let result = app_data.get_log_title();
+        assert_eq!(result, "");
 
-        if let Some(i) = app_data.get_container_by_id(&ContainerId::from("1")) {
-            i.mem_stats = VecDeque::from([ByteStats::new(40)]);
-        }
-        if let Some(i) = app_data.get_container_by_id(&ContainerId::from("2")) {
-            i.mem_stats = VecDeque::from([ByteStats::new(80)]);
-        }
-        if let Some(i) = app_data.get_container_by_id(&ContainerId::from("3")) {
-            i.mem_stats = VecDeque::from([ByteStats::new(2)]);
-        }
+        // No logs
+        app_data.containers.start();
+        let result = app_data.get_log_title();
+        assert_eq!(result, " - container_1");
 
-        // descending
-        app_data.set_sorted(Some((Header::Memory, SortedOrder::Desc)));
-        let result = app_data.get_container_items();
-        let (a, b, c) = (&result[0], &result[1], &result[2]);
-        assert_eq!(a.id, ContainerId::from("2"));
-        assert_eq!(b.id, ContainerId::from("1"));
-        assert_eq!(c.id, ContainerId::from("3"));
+        // On last line of logs
+        let logs = (1..=3).map(|i| format!("{i}")).collect::<Vec<_>>();
+        app_data.update_log_by_id(logs, &ids[0]);
+        let result = app_data.get_log_title();
+        assert_eq!(result, " 3/3 - container_1");
 
-        // ascending
-        app_data.set_sorted(Some((Header::Memory, SortedOrder::Asc)));
-        let result = app_data.get_container_items();
-        let (a, b, c) = (&result[0], &result[1], &result[2]);
-        assert_eq!(a.id, ContainerId::from("3"));
-        assert_eq!(b.id, ContainerId::from("1"));
-        assert_eq!(c.id, ContainerId::from("2"));
+        // Change log state to no longer be at the end
+        app_data.log_previous();
+        let result = app_data.get_log_title();
+        assert_eq!(result, " 2/3 - container_1");
     }
 
     #[test]
-    /// Sort by header: id
-    fn test_app_data_set_sort_by_header_id() {
-        let (_ids, containers) = gen_containers();
+    /// Windows containers, once inspected, show their isolation mode in the log title
+    fn test_app_data_isolation() {
+        let (ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        app_data.containers.start();
+        assert!(!app_data.is_windows());
+        assert_eq!(app_data.get_unknown_isolation(None).len(), 3);
+
+        app_data.set_isolation(&ids[0], Isolation::HyperV);
+        assert!(app_data.is_windows());
+        assert_eq!(app_data.get_unknown_isolation(None), &ids[1..]);
+        assert!(app_data.get_unknown_isolation(Some("remote")).is_empty());
+        assert_eq!(
+            app_data.get_log_title(),
+            " - container_1 - hyperv isolation"
+        );
+    }
 
+    #[test]
+    /// log title string generated correctly after container change
+    fn test_app_data_get_log_title_after_container_change() {
+        let (ids, containers) = gen_containers();
         let mut app_data = gen_appdata(&containers);
 
-        let result = app_data.get_container_items();
-        assert_eq!(result, &containers);
+        // No container selected select
+        let result = app_data.get_log_title();
+        assert_eq!(result, "");
 
-        // descending
-        app_data.set_sorted(Some((Header::Id, SortedOrder::Desc)));
-        let result = app_data.get_container_items();
-        let (a, b, c) = (&result[0], &result[1], &result[2]);
-        assert_eq!(a.id, ContainerId::from("3"));
-        assert_eq!(b.id, ContainerId::from("2"));
-        assert_eq!(c.id, ContainerId::from("1"));
+        app_data.containers_start();
 
-        // ascending
-        app_data.set_sorted(Some((Header::Id, SortedOrder::Asc)));
-        let result = app_data.get_container_items();
-        let (a, b, c) = (&result[0], &result[1], &result[2]);
-        assert_eq!(a.id, ContainerId::from("1"));
-        assert_eq!(b.id, ContainerId::from("2"));
-        assert_eq!(c.id, ContainerId::from("3"));
+        let result = app_data.get_log_title();
+        assert_eq!(result, " - container_1");
+
+        // change container
+        app_data.containers_next();
+        let result = app_data.get_log_title();
+        assert_eq!(result, " - container_2");
+
+        // On last line of logs
+        let logs = (1..=3).map(|i| format!("{i}")).collect::<Vec<_>>();
+        app_data.update_log_by_id(logs, &ids[1]);
+        let result = app_data.get_log_title();
+        assert_eq!(result, " 3/3 - container_2");
+
+        // Change log state to no longer be at the end
+        app_data.log_previous();
+        let result = app_data.get_log_title();
+        assert_eq!(result, " 2/3 - container_2");
     }
 
     #[test]
-    /// Sort by header: image
-    fn test_app_data_set_sort_by_header_image() {
-        let (_ids, containers) = gen_containers();
-
+    /// A locked logs panel keeps showing, & scrolling, the locked container's logs while another container is selected, & its logs are still updated
+    fn test_app_data_log_lock() {
+        let (ids, containers) = gen_containers();
         let mut app_data = gen_appdata(&containers);
+        app_data.containers_start();
+        let logs = (1..=3).map(|i| format!("{i}")).collect::<Vec<_>>();
+        app_data.update_log_by_id(logs, &ids[0]);
 
-        let result = app_data.get_container_items();
-        assert_eq!(result, &containers);
+        app_data.toggle_log_lock();
+        assert!(app_data.is_log_locked());
+        app_data.containers_next();
+        assert_eq!(app_data.get_log_title(), " 3/3 - container_1 - locked");
+        app_data.log_previous();
+        assert_eq!(app_data.get_log_title(), " 2/3 - container_1 - locked");
+        assert_eq!(app_data.get_logs().len(), 3);
+        assert_eq!(
+            app_data
+                .get_log_update_ids()
+                .iter()
+                .map(|i| i.0.clone())
+                .collect::<Vec<_>>(),
+            [ids[0].clone(), ids[1].clone()]
+        );
 
-        // descending
-        app_data.set_sorted(Some((Header::Image, SortedOrder::Desc)));
-        let result = app_data.get_container_items();
-        let (a, b, c) = (&result[0], &result[1], &result[2]);
-        assert_eq!(a.id, ContainerId::from("3"));
-        assert_eq!(b.id, ContainerId::from("2"));
-        assert_eq!(c.id, ContainerId::from("1"));
+        // The lock is ignored once the container is gone
+        app_data.log_lock = Some(ContainerId::from("removed"));
+        assert!(!app_data.is_log_locked());
+        assert_eq!(app_data.get_log_title(), " - container_2");
 
-        // ascending
-        app_data.set_sorted(Some((Header::Image, SortedOrder::Asc)));
-        let result = app_data.get_container_items();
-        let (a, b, c) = (&result[0], &result[1], &result[2]);
-        assert_eq!(a.id, ContainerId::from("1"));
-        assert_eq!(b.id, ContainerId::from("2"));
-        assert_eq!(c.id, ContainerId::from("3"));
+        app_data.log_lock = Some(ids[0].clone());
+        app_data.toggle_log_lock();
+        assert_eq!(app_data.log_lock, None);
+        assert_eq!(app_data.get_log_title(), " - container_2");
     }
 
     #[test]
-    /// Sort by header: rx
-    fn test_app_data_set_sort_by_header_rx() {
-        let (_ids, containers) = gen_containers();
-
+    /// Only the boosted container is boosted, until its deadline, & the logs panel title shows the time left
+    fn test_app_data_boost() {
+        let (ids, containers) = gen_containers();
         let mut app_data = gen_appdata(&containers);
+        app_data.containers_start();
+        assert!(!app_data.is_boosted(&ids[0]));
+
+        app_data.set_boost(Some((
+            ids[0].clone(),
+            Instant::now() + Duration::from_millis(90_500),
+        )));
+        assert!(app_data.is_boosted(&ids[0]));
+        assert!(!app_data.is_boosted(&ids[1]));
+        assert_eq!(
+            app_data.get_boost_update(&ids[0]),
+            Some((State::Running, 0))
+        );
+        assert_eq!(app_data.get_log_title(), " - container_1 - boosted 1m 30s");
+        app_data.containers_next();
+        assert_eq!(app_data.get_log_title(), " - container_2");
+        assert!(app_data.get_boost_update(&ids[1]).is_none());
 
-        let result = app_data.get_container_items();
-        assert_eq!(result, &containers);
-
-        if let Some(i) = app_data.get_container_by_id(&ContainerId::from("1")) {
-            i.rx = ByteStats::new(40);
-        }
-        if let Some(i) = app_data.get_container_by_id(&ContainerId::from("2")) {
-            i.rx = ByteStats::new(80);
-        }
-        if let Some(i) = app_data.get_container_by_id(&ContainerId::from("3")) {
-            i.rx = ByteStats::new(2);
-        }
-
-        // descending
-        app_data.set_sorted(Some((Header::Rx, SortedOrder::Desc)));
-        let result = app_data.get_container_items();
-        let (a, b, c) = (&result[0], &result[1], &result[2]);
-        assert_eq!(a.id, ContainerId::from("2"));
-        assert_eq!(b.id, ContainerId::from("1"));
-        assert_eq!(c.id, ContainerId::from("3"));
+        // An expired boost is no longer refreshed
+        app_data.set_boost(Some((ids[0].clone(), Instant::now())));
+        assert!(!app_data.is_boosted(&ids[0]));
+        assert!(app_data.get_boost_update(&ids[0]).is_none());
 
-        // ascending
-        app_data.set_sorted(Some((Header::Rx, SortedOrder::Asc)));
-        let result = app_data.get_container_items();
-        let (a, b, c) = (&result[0], &result[1], &result[2]);
-        assert_eq!(a.id, ContainerId::from("3"));
-        assert_eq!(b.id, ContainerId::from("1"));
-        assert_eq!(c.id, ContainerId::from("2"));
+        app_data.set_boost(None);
+        assert_eq!(app_data.get_boost(), None);
     }
 
     #[test]
-    /// Sort by header: tx
-    fn test_app_data_set_sort_by_header_tx() {
-        let (_ids, containers) = gen_containers();
-
+    /// The logs search is per container, new lines are matched as they arrive, and n/N jump between the lines with a match
+    fn test_app_data_log_search() {
+        let (ids, containers) = gen_containers();
         let mut app_data = gen_appdata(&containers);
+        app_data.containers_start();
+        let logs = [
+            "GET /a 200",
+            "GET /b 500 error",
+            "GET /c 200",
+            "GET /d 500 Error",
+        ]
+        .iter()
+        .enumerate()
+        .map(|(index, i)| format!("2023-01-14T19:13:3{index}.0Z {i}"))
+        .collect::<Vec<_>>();
+        app_data.update_log_by_id(logs, &ids[0]);
 
-        let result = app_data.get_container_items();
-        assert_eq!(result, &containers);
-
-        if let Some(i) = app_data.get_container_by_id(&ContainerId::from("1")) {
-            i.rx = ByteStats::new(400);
-        }
-        if let Some(i) = app_data.get_container_by_id(&ContainerId::from("2")) {
-            i.rx = ByteStats::new(80);
-        }
-        if let Some(i) = app_data.get_container_by_id(&ContainerId::from("3")) {
-            i.rx = ByteStats::new(83);
-        }
+        app_data.set_log_search(Some(LogSearch::new("error".to_owned(), false)));
+        assert_eq!(app_data.get_log_title(), " 4/4 - container_1 - /error 2/2");
+        app_data.log_search_jump(true);
+        assert_eq!(app_data.get_log_title(), " 2/4 - container_1 - /error 1/2");
+        app_data.log_search_jump(false);
+        assert_eq!(app_data.get_log_title(), " 4/4 - container_1 - /error 2/2");
 
-        // descending
-        app_data.set_sorted(Some((Header::Rx, SortedOrder::Desc)));
-        let result = app_data.get_container_items();
-        let (a, b, c) = (&result[0], &result[1], &result[2]);
-        assert_eq!(a.id, ContainerId::from("1"));
-        assert_eq!(b.id, ContainerId::from("3"));
-        assert_eq!(c.id, ContainerId::from("2"));
+        app_data.log_search_edit(|i| {
+            i.input = "\\d00".to_owned();
+            i.regex = true;
+        });
+        assert_eq!(
+            app_data.get_log_title(),
+            " 4/4 - container_1 - regex /\\d00 4/4"
+        );
+        app_data.update_log_by_id(
+            vec!["2023-01-14T19:13:40.0Z GET /e 404".to_owned()],
+            &ids[0],
+        );
+        assert_eq!(
+            app_data.get_log_title(),
+            " 5/5 - container_1 - regex /\\d00 -/4"
+        );
 
-        // ascending
-        app_data.set_sorted(Some((Header::Rx, SortedOrder::Asc)));
-        let result = app_data.get_container_items();
-        let (a, b, c) = (&result[0], &result[1], &result[2]);
-        assert_eq!(a.id, ContainerId::from("2"));
-        assert_eq!(b.id, ContainerId::from("3"));
-        assert_eq!(c.id, ContainerId::from("1"));
+        app_data.containers_next();
+        assert!(app_data.get_log_search().is_none());
+        app_data.containers_previous();
+        app_data.set_log_search(None);
+        assert_eq!(app_data.get_log_title(), " 5/5 - container_1");
     }
 
     #[test]
-    /// Sort by header when selected headers match
-    fn test_app_data_set_sort_by_header_match() {
-        let (_ids, containers) = gen_containers();
+    /// The logs filter is shown in the title, and new lines that it shows are followed when at the end
+    fn test_app_data_log_filter() {
+        let (ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        app_data.containers_start();
+        let gen_logs = |lines: &[&str], offset: usize| {
+            lines
+                .iter()
+                .enumerate()
+                .map(|(index, i)| format!("2023-01-14T19:13:{:02}.0Z {i}", index + offset))
+                .collect::<Vec<_>>()
+        };
+        app_data.update_log_by_id(gen_logs(&["a", "error: b", "c"], 0), &ids[0]);
+
+        app_data.set_log_filter(Some(LogFilter::new(String::new())));
+        app_data.log_filter_edit(Some('e'));
+        app_data.log_filter_edit(Some('r'));
+        assert_eq!(app_data.get_log_title(), " 1/1 - container_1 - filter &er");
+        app_data.update_log_by_id(gen_logs(&["d", "error: e"], 10), &ids[0]);
+        assert_eq!(app_data.get_log_title(), " 2/2 - container_1 - filter &er");
+        assert_eq!(app_data.get_logs().len(), 2);
+
+        app_data.log_filter_edit(None);
+        app_data.log_filter_edit(None);
+        assert_eq!(app_data.get_log_title(), " 5/5 - container_1 - filter &");
+        app_data.set_log_filter(None);
+        assert_eq!(app_data.get_log_title(), " 5/5 - container_1");
+    }
 
+    #[test]
+    /// update logs by id works
+    fn test_app_data_update_log_by_id() {
+        let (ids, containers) = gen_containers();
         let mut app_data = gen_appdata(&containers);
 
-        let result = app_data.get_container_items();
-        assert_eq!(result, &containers);
+        // No container selected select
+        let result = app_data.get_log_title();
+        assert_eq!(result, "");
 
-        // descending
-        app_data.set_sorted(Some((Header::Rx, SortedOrder::Desc)));
-        let result = app_data.get_container_items();
-        let (a, b, c) = (&result[0], &result[1], &result[2]);
-        assert_eq!(a.id, ContainerId::from("3"));
-        assert_eq!(b.id, ContainerId::from("2"));
-        assert_eq!(c.id, ContainerId::from("1"));
+        app_data.containers_start();
+        let logs = (1..=3).map(|i| format!("{i} {i}")).collect::<Vec<_>>();
 
-        // ascending
-        app_data.set_sorted(Some((Header::Rx, SortedOrder::Asc)));
-        let result = app_data.get_container_items();
-        let (a, b, c) = (&result[0], &result[1], &result[2]);
-        assert_eq!(a.id, ContainerId::from("1"));
-        assert_eq!(b.id, ContainerId::from("2"));
-        assert_eq!(c.id, ContainerId::from("3"));
+        app_data.update_log_by_id(logs, &ids[0]);
+        // app_data.log_start();
+
+        let result = app_data.get_log_state();
+        assert!(result.is_some());
+        assert_eq!(result.as_ref().unwrap().selected(), Some(2));
+        assert_eq!(result.unwrap().offset(), 0);
+
+        let result = app_data.get_logs();
+        assert_eq!(result.len(), 3);
+
+        let result = app_data.get_log_title();
+        assert_eq!(result, " 3/3 - container_1");
     }
 
     #[test]
-    /// reset sorted
-    fn test_app_data_reset_sorted() {
-        let (_ids, containers) = gen_containers();
-
+    /// A gap marker is inserted between lines logged at least --log-gap seconds apart, duplicate lines don't add a marker
+    fn test_app_data_update_log_by_id_gap() {
+        let (ids, containers) = gen_containers();
         let mut app_data = gen_appdata(&containers);
+        app_data.args.log_gap = Some(60);
+        app_data.containers_start();
 
-        let result = app_data.get_container_items();
-        assert_eq!(result, &containers);
-
-        if let Some(i) = app_data.get_container_by_id(&ContainerId::from("1")) {
-            i.rx = ByteStats::new(400);
-        }
-        if let Some(i) = app_data.get_container_by_id(&ContainerId::from("2")) {
-            i.rx = ByteStats::new(80);
-        }
-        if let Some(i) = app_data.get_container_by_id(&ContainerId::from("3")) {
-            i.rx = ByteStats::new(83);
-        }
+        let logs = [
+            "2024-05-01T12:00:00.000000000Z a",
+            "2024-05-01T12:00:30.000000000Z b",
+            "2024-05-01T14:13:30.000000000Z c",
+        ]
+        .map(String::from)
+        .to_vec();
+        app_data.update_log_by_id(logs.clone(), &ids[0]);
+        app_data.update_log_by_id(logs, &ids[0]);
 
-        app_data.set_sorted(Some((Header::Rx, SortedOrder::Asc)));
-        let result = app_data.get_container_items();
-        let (a, b, c) = (&result[0], &result[1], &result[2]);
-        assert_eq!(a.id, ContainerId::from("2"));
-        assert_eq!(b.id, ContainerId::from("3"));
-        assert_eq!(c.id, ContainerId::from("1"));
+        let result = app_data.get_logs();
+        assert_eq!(result.len(), 4);
+        assert_eq!(
+            result[2],
+            ListItem::new("— 2h 13m silence —")
+                .style(Style::default().add_modifier(Modifier::DIM | Modifier::ITALIC))
+        );
+    }
 
-        app_data.set_sorted(None);
-        let result = app_data.get_container_items();
-        let (a, b, c) = (&result[0], &result[1], &result[2]);
-        assert_eq!(a.id, ContainerId::from("1"));
-        assert_eq!(b.id, ContainerId::from("2"));
-        assert_eq!(c.id, ContainerId::from("3"));
+    #[test]
+    /// Images are listed newest first, the selected image stays selected when the images are updated
+    fn test_app_data_set_images() {
+        let (_, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        let gen_image = |id: &str, created: i64| ImageItem {
+            containers: vec![],
+            created,
+            id: id.to_owned(),
+            size: ByteStats::new(0),
+            tags: vec![],
+        };
+        assert!(app_data.get_selected_image().is_none());
+        app_data.set_images(vec![
+            gen_image("a", 1),
+            gen_image("b", 3),
+            gen_image("c", 2),
+        ]);
+        assert_eq!(
+            app_data
+                .get_images()
+                .iter()
+                .map(|i| i.id.as_str())
+                .collect::<Vec<_>>(),
+            ["b", "c", "a"]
+        );
+        assert_eq!(app_data.get_selected_image().unwrap().id, "b");
+        assert_eq!(app_data.get_images_title(), " 1/3");
+
+        app_data.images_next();
+        app_data.set_images(vec![gen_image("d", 4), gen_image("c", 2)]);
+        assert_eq!(app_data.get_selected_image().unwrap().id, "c");
+        app_data.set_images(vec![gen_image("d", 4)]);
+        assert_eq!(app_data.get_selected_image().unwrap().id, "d");
+        app_data.set_images(vec![]);
+        assert!(app_data.get_selected_image().is_none());
+
+        assert!(app_data.get_resources_host().is_none());
+        app_data.host_tab = Some(app_data.get_host().to_owned());
+        assert!(app_data.get_resources_host().is_none());
+        app_data.host_tab = Some("ssh://build".to_owned());
+        assert_eq!(app_data.get_resources_host(), Some("ssh://build"));
     }
 
-    // **************** //
-    // Container state  //
-    // **************** //
-
     #[test]
-    /// Get len of current containers vec
-    fn test_app_data_get_container_len() {
-        let (_ids, containers) = gen_containers();
-        let app_data = gen_appdata(&containers);
-        assert_eq!(app_data.get_container_len(), 3);
+    /// A change to the resources of a panel is only taken once, on the host it happened on
+    fn test_app_data_resources_changed() {
+        let (_, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        app_data.set_resources_changed(None, SelectablePanel::Images);
+        app_data.set_resources_changed(Some("ssh://build"), SelectablePanel::Networks);
+        assert!(!app_data.take_resources_changed(None, SelectablePanel::Volumes));
+        assert!(!app_data.take_resources_changed(None, SelectablePanel::Networks));
+        assert!(app_data.take_resources_changed(None, SelectablePanel::Images));
+        assert!(!app_data.take_resources_changed(None, SelectablePanel::Images));
+        assert!(app_data.take_resources_changed(Some("ssh://build"), SelectablePanel::Networks));
     }
 
     #[test]
-    /// Select the first container
-    fn test_app_data_containers_start() {
-        let (_ids, containers) = gen_containers();
+    /// Volumes are listed by name, a volume keeps its last known size until it's measured again
+    fn test_app_data_set_volumes() {
+        let (_, containers) = gen_containers();
         let mut app_data = gen_appdata(&containers);
+        let gen_volume = |name: &str, size: Option<u64>| VolumeItem {
+            containers: vec![],
+            driver: "local".to_owned(),
+            name: name.to_owned(),
+            scope: "local".to_owned(),
+            size: size.map(ByteStats::new),
+        };
+        app_data.set_volumes(vec![gen_volume("b", Some(20)), gen_volume("a", Some(10))]);
+        assert_eq!(app_data.get_selected_volume().unwrap().name, "a");
+        assert_eq!(app_data.get_volumes_title(), " 1/2");
+
+        app_data.volumes_next();
+        app_data.set_volumes(vec![
+            gen_volume("c", None),
+            gen_volume("b", None),
+            gen_volume("a", Some(30)),
+        ]);
+        let sizes = app_data
+            .get_volumes()
+            .iter()
+            .map(|i| i.size.map(ByteStats::get))
+            .collect::<Vec<_>>();
+        assert_eq!(sizes, [Some(30), Some(20), None]);
+        assert_eq!(app_data.get_selected_volume().unwrap().name, "b");
+        app_data.set_volumes(vec![]);
+        assert!(app_data.get_selected_volume().is_none());
+    }
 
-        // No container selected
-        let result = app_data.get_container_state();
-        assert_eq!(result.selected(), None);
-        assert_eq!(result.offset(), 0);
+    #[test]
+    /// Services are listed by stack, then by name, the selected service stays selected, & only a manager host is counted as a swarm
+    fn test_app_data_set_services() {
+        let (_, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        let gen_service = |id: &str, name: &str| ServiceItem {
+            desired: 1,
+            global: false,
+            id: id.to_owned(),
+            image: "nginx:1.27".to_owned(),
+            name: name.to_owned(),
+            running: 1,
+            stack: name.split_once('_').map(|(stack, _)| stack.to_owned()),
+            update: None,
+        };
+        app_data.set_services(vec![
+            gen_service("2", "site_web"),
+            gen_service("1", "site_api"),
+        ]);
+        assert_eq!(app_data.get_selected_service().unwrap().name, "site_api");
+        app_data.services_next();
+        app_data.set_services(vec![
+            gen_service("3", "site_db"),
+            gen_service("2", "site_web"),
+        ]);
+        assert_eq!(app_data.get_selected_service().unwrap().name, "site_web");
+        assert_eq!(app_data.get_services_title(), " 2/2");
+
+        app_data.set_services(vec![
+            gen_service("4", "proxy"),
+            gen_service("5", "auth_api"),
+            gen_service("3", "site_db"),
+            gen_service("2", "site_web"),
+        ]);
+        let names = app_data
+            .get_services()
+            .iter()
+            .map(|i| i.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["auth_api", "site_db", "site_web", "proxy"]);
+        assert_eq!(app_data.get_stack_services("site").len(), 2);
+        assert!(app_data.get_stack_services("proxy").is_empty());
+        app_data.set_services(vec![]);
+        assert!(app_data.get_selected_service().is_none());
+
+        assert!(!app_data.is_swarm(None));
+        app_data.set_swarm(None, true);
+        app_data.set_swarm(Some("ssh://build"), false);
+        assert!(app_data.is_swarm(None));
+        assert!(!app_data.is_swarm(Some("ssh://build")));
+        app_data.set_swarm(None, false);
+        assert!(!app_data.is_swarm(None));
+    }
 
-        // First container selected
-        app_data.containers_start();
-        let result = app_data.get_container_state();
-        assert_eq!(result.selected(), Some(0));
-        assert_eq!(result.offset(), 0);
+    #[test]
+    /// Log lines matching a watch are collected, but only new lines, after the first update, are alerted
+    fn test_app_data_update_log_by_id_watch() {
+        let (ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        app_data.args.watch = vec![
+            LogWatch::parse("container_1=(?i)error").unwrap(),
+            LogWatch::parse("container_2=x").unwrap(),
+        ];
 
-        let result = app_data.get_selected_container_id();
-        assert_eq!(result, Some(ContainerId::from("1")));
-        let result = app_data.get_selected_container_id_state_name();
+        // Nothing selected, so only the watched, running, containers have their logs updated
+        let result = app_data.get_log_update_ids();
         assert_eq!(
-            result,
-            Some((
-                ContainerId::from("1"),
-                State::Running,
-                "container_1".to_owned()
-            ))
+            result.iter().map(|i| i.0.clone()).collect::<Vec<_>>(),
+            [ids[0].clone(), ids[1].clone()]
         );
 
-        // Calling previous when at start has no effect
-        app_data.containers_previous();
-        let result = app_data.get_selected_container_id();
-        assert_eq!(result, Some(ContainerId::from("1")));
-        let result = app_data.get_selected_container_id_state_name();
-        assert_eq!(
-            result,
-            Some((
-                ContainerId::from("1"),
-                State::Running,
-                "container_1".to_owned()
-            ))
+        let result =
+            app_data.update_log_by_id(vec!["1 an error".to_owned(), "2 ok".to_owned()], &ids[0]);
+        assert!(result.is_none());
+        assert_eq!(app_data.get_log_matches().len(), 1);
+        assert_eq!(app_data.get_unseen_matches(), 0);
+
+        let result = app_data.update_log_by_id(
+            ["1 an error", "3 \x1b[31mERROR\x1b[0m here", "4 fine"]
+                .map(String::from)
+                .to_vec(),
+            &ids[0],
         );
+        let result = result.unwrap();
+        assert_eq!(result.line, "ERROR here");
+        assert_eq!(result.name, "container_1");
+        assert_eq!(result.pattern, "(?i)error");
+        assert_eq!(result.index, Some(2));
+        assert_eq!(app_data.get_log_matches().len(), 2);
+        assert_eq!(app_data.get_unseen_matches(), 1);
+
+        let (lines, selected) = app_data.get_log_context(&result, 1).unwrap();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(selected, 1);
+        let (lines, selected) = app_data.get_log_context(&result, 5).unwrap();
+        assert_eq!(lines.len(), 4);
+        assert_eq!(selected, 2);
+        let (lines, selected) = app_data
+            .get_log_context(&app_data.get_log_matches()[0], 0)
+            .unwrap();
+        assert_eq!(lines.len(), 1);
+        assert_eq!(selected, 0);
+
+        app_data.clear_unseen_matches();
+        assert_eq!(app_data.get_unseen_matches(), 0);
+
+        // Matches follow their lines as the oldest are dropped, a match of a dropped line has no context
+        app_data.args.max_log_lines = 3;
+        let result = app_data.update_log_by_id(vec!["5 error again".to_owned()], &ids[0]);
+        assert_eq!(result.unwrap().index, Some(2));
+        let indices = app_data
+            .get_log_matches()
+            .iter()
+            .map(|i| i.index)
+            .collect::<Vec<_>>();
+        assert_eq!(indices, [None, Some(0), Some(2)]);
+        assert!(app_data
+            .get_log_context(&app_data.get_log_matches()[0], 1)
+            .is_none());
     }
 
     #[test]
-    /// advance container list state by one
-    /// get get_selected_container_id() & get_selected_container_id_state_name() return valid Some data
-    fn test_app_data_containers_next() {
-        let (_ids, containers) = gen_containers();
+    /// Only the most recent events are kept, the oldest are dropped first
+    fn test_app_data_push_event() {
+        let (_, containers) = gen_containers();
         let mut app_data = gen_appdata(&containers);
+        for time in 0..250 {
+            app_data.push_event(DockerEvent {
+                time,
+                host: None,
+                id: None,
+                name: "nginx:latest".to_owned(),
+                kind: EventKind::Pull,
+            });
+        }
+        let events = app_data.get_events();
+        assert_eq!(events.len(), 200);
+        assert_eq!(events.front().map(|i| i.time), Some(50));
+        assert_eq!(events.back().map(|i| i.time), Some(249));
+    }
 
-        // Advance list state by 1
+    #[test]
+    /// A recent control, from elsewhere, is concurrent, unless it's too old, it's not a control, or one of oxker's own controls caused it
+    fn test_app_data_concurrent_event() {
+        let (ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        let now = AppData::get_systemtime();
+        let event = |ago: u64, kind| DockerEvent {
+            time: i64::try_from(now - ago).unwrap(),
+            host: None,
+            id: Some(ids[0].clone()),
+            name: "container_1".to_owned(),
+            kind: EventKind::Container(kind),
+        };
+        app_data.push_event(event(120, TimelineKind::Restart));
+        app_data.push_event(event(5, TimelineKind::Start));
+        assert!(app_data.concurrent_event(&ids[0]).is_none());
+
+        app_data.push_event(event(10, TimelineKind::Stop));
+        let (found, ago) = app_data.concurrent_event(&ids[0]).unwrap();
+        assert_eq!(found.to_string(), "container_1 stopped");
+        assert!((10..=11).contains(&ago));
+        assert!(app_data.concurrent_event(&ids[1]).is_none());
+
+        // The daemon is an hour ahead, so the stop was an hour ago
+        app_data.set_clock_skew(Some(3600));
+        assert!(app_data.concurrent_event(&ids[0]).is_none());
+        app_data.set_clock_skew(None);
+
+        app_data.push_attempt(&ids[0], None, DockerControls::Stop, None, now - 8);
+        assert!(app_data.concurrent_event(&ids[0]).is_none());
+    }
+
+    #[test]
+    /// Long log lines are truncated, with the full text available for the selected line, carriage returns collapsed
+    fn test_app_data_update_log_by_id_truncated() {
+        let (ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        app_data.args.max_line_length = 10;
         app_data.containers_start();
-        app_data.containers_next();
 
-        let result = app_data.get_container_state();
-        assert_eq!(result.selected(), Some(1));
-        assert_eq!(result.offset(), 0);
+        let long = "x".repeat(20);
+        let logs = vec![format!("1 {long}"), "2 10%\r50%\r100%\n".to_owned()];
+        app_data.update_log_by_id(logs, &ids[0]);
+        assert_eq!(app_data.get_logs().len(), 2);
+        assert_eq!(app_data.get_log_state().unwrap().selected(), Some(1));
+        assert!(app_data.get_selected_log_detail().is_none());
 
-        let result = app_data.get_selected_container_id();
-        assert_eq!(result, Some(ContainerId::from("2")));
-        let result = app_data.get_selected_container_id_state_name();
+        app_data.log_start();
         assert_eq!(
-            result,
-            Some((
-                ContainerId::from("2"),
-                State::Running,
-                "container_2".to_owned()
-            ))
+            app_data.get_selected_log_detail(),
+            Some(LogDetail::Truncated(long))
         );
+
+        let lines = app_data.containers.items[0].logs.to_vec(None, |_| None);
+        assert_eq!(lines[1], ListItem::new(log_sanitizer::remove_ansi("100%")));
     }
 
     #[test]
-    /// advance container list state to the end
-    /// get get_selected_container_id() & get_selected_container_id_state_name() return valid Some data
-    fn test_app_data_containers_end() {
-        let (_ids, containers) = gen_containers();
+    /// Binary output shown as a placeholder, with the bytes, minus the timestamp, available for the hex view
+    fn test_app_data_update_log_by_id_binary() {
+        let (ids, containers) = gen_containers();
         let mut app_data = gen_appdata(&containers);
+        app_data.containers_start();
 
-        app_data.containers_end();
-        let result = app_data.get_container_state();
-        assert_eq!(result.selected(), Some(2));
-        assert_eq!(result.offset(), 0);
-
-        let result = app_data.get_selected_container_id();
-        assert_eq!(result, Some(ContainerId::from("3")));
-        let result = app_data.get_selected_container_id_state_name();
+        app_data.update_log_by_id(vec![LogLine::Binary(b"1 \x00\x01abc".to_vec())], &ids[0]);
+        let lines = app_data.containers.items[0].logs.to_vec(None, |_| None);
         assert_eq!(
-            result,
-            Some((
-                ContainerId::from("3"),
-                State::Running,
-                "container_3".to_owned()
+            lines[0],
+            ListItem::new(log_sanitizer::raw(
+                "[binary output, 5 bytes, enter to view as hex]"
             ))
+            .style(Style::default().add_modifier(Modifier::DIM))
         );
-
-        // Calling previous when at end has no effect
-        app_data.containers_next();
-        let result = app_data.get_selected_container_id();
-        assert_eq!(result, Some(ContainerId::from("3")));
-        let result = app_data.get_selected_container_id_state_name();
         assert_eq!(
-            result,
-            Some((
-                ContainerId::from("3"),
-                State::Running,
-                "container_3".to_owned()
-            ))
+            app_data.get_selected_log_detail(),
+            Some(LogDetail::Binary(b"\x00\x01abc".to_vec()))
         );
     }
 
     #[test]
-    /// go to previous container
-    fn test_app_data_containers_prev() {
-        let (_ids, containers) = gen_containers();
+    /// logs state reset to start
+    fn test_app_data_logs_start() {
+        let (ids, containers) = gen_containers();
         let mut app_data = gen_appdata(&containers);
+        let logs = (1..=3).map(|i| format!("{i} {i}")).collect::<Vec<_>>();
+        app_data.containers_start();
+        app_data.update_log_by_id(logs, &ids[0]);
 
-        app_data.containers_end();
-        app_data.containers_previous();
-        let result = app_data.get_container_state();
-        assert_eq!(result.selected(), Some(1));
-        assert_eq!(result.offset(), 0);
+        app_data.log_start();
+
+        let result = app_data.get_log_state();
+        assert!(result.is_some());
+        assert_eq!(result.as_ref().unwrap().selected(), Some(0));
+        assert_eq!(result.unwrap().offset(), 0);
+
+        let result = app_data.get_log_title();
+        assert_eq!(result, " 1/3 - container_1");
     }
 
     #[test]
-    /// Get the currently selected container
-    fn test_app_data_get_selected_container() {
-        let (_ids, mut containers) = gen_containers();
+    /// logs state end goes to the end of the logs list
+    fn test_app_data_logs_end() {
+        let (ids, containers) = gen_containers();
         let mut app_data = gen_appdata(&containers);
+        let logs = (1..=3).map(|i| format!("{i} {i}")).collect::<Vec<_>>();
+        app_data.containers_start();
+        app_data.update_log_by_id(logs, &ids[0]);
 
-        let result = app_data.get_selected_container();
-        assert_eq!(result, None);
+        app_data.log_start();
 
-        app_data.containers.start();
-        app_data.containers.next();
+        let result = app_data.get_log_state();
+        assert!(result.is_some());
+        assert_eq!(result.as_ref().unwrap().selected(), Some(0));
+        assert_eq!(result.unwrap().offset(), 0);
 
-        let result = app_data.get_selected_container();
-        assert_eq!(result, Some(&containers[1]));
+        let result = app_data.get_log_title();
+        assert_eq!(result, " 1/3 - container_1");
 
-        // As above, but now as mut
-        let result = app_data.get_mut_selected_container();
-        assert_eq!(result, Some(&mut containers[1]));
+        app_data.log_end();
+        let result = app_data.get_log_state();
+        assert!(result.is_some());
+        assert_eq!(result.as_ref().unwrap().selected(), Some(2));
+        assert_eq!(result.unwrap().offset(), 0);
+
+        let result = app_data.get_log_title();
+        assert_eq!(result, " 3/3 - container_1");
     }
 
     #[test]
-    /// Get mut container by id
-    fn test_app_data_get_container_by_id() {
-        let (_ids, mut containers) = gen_containers();
+    /// logs state next works
+    /// At end has no effect
+    fn test_app_data_logs_next() {
+        let (ids, containers) = gen_containers();
         let mut app_data = gen_appdata(&containers);
+        let logs = (1..=3).map(|i| format!("{i} {i}")).collect::<Vec<_>>();
+        app_data.containers_start();
+        app_data.update_log_by_id(logs, &ids[0]);
 
-        let result = app_data.get_container_by_id(&ContainerId::from("2"));
-        assert_eq!(result, Some(&mut containers[1]));
+        app_data.log_start();
+
+        let result = app_data.get_log_state();
+        assert!(result.is_some());
+        assert_eq!(result.as_ref().unwrap().selected(), Some(0));
+        assert_eq!(result.unwrap().offset(), 0);
+
+        let result = app_data.get_log_title();
+        assert_eq!(result, " 1/3 - container_1");
+
+        app_data.log_next();
+
+        let result = app_data.get_log_state();
+        assert!(result.is_some());
+        assert_eq!(result.as_ref().unwrap().selected(), Some(1));
+        assert_eq!(result.unwrap().offset(), 0);
+
+        let result = app_data.get_log_title();
+        assert_eq!(result, " 2/3 - container_1");
+
+        app_data.log_next();
+        let result = app_data.get_log_state();
+        assert!(result.is_some());
+        assert_eq!(result.as_ref().unwrap().selected(), Some(2));
+        assert_eq!(result.unwrap().offset(), 0);
+
+        let result = app_data.get_log_title();
+        assert_eq!(result, " 3/3 - container_1");
+        app_data.log_next();
+
+        let result = app_data.get_log_state();
+        assert!(result.is_some());
+        assert_eq!(result.as_ref().unwrap().selected(), Some(2));
+        assert_eq!(result.unwrap().offset(), 0);
+
+        let result = app_data.get_log_title();
+        assert_eq!(result, " 3/3 - container_1");
     }
 
-    #[test]
-    /// Get just the containers name by id
-    fn test_app_data_get_container_name_by_id() {
-        let (_ids, containers) = gen_containers();
-        let mut app_data = gen_appdata(&containers);
+    #[test]
+    /// logs state previous works
+    /// previous at start has no effect
+    fn test_app_data_logs_previous() {
+        let (ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        let logs = (1..=3).map(|i| format!("{i} {i}")).collect::<Vec<_>>();
+        app_data.containers_start();
+        app_data.update_log_by_id(logs, &ids[0]);
+
+        app_data.log_end();
+
+        let result = app_data.get_log_state();
+        assert!(result.is_some());
+        assert_eq!(result.as_ref().unwrap().selected(), Some(2));
+        assert_eq!(result.unwrap().offset(), 0);
+
+        let result = app_data.get_log_title();
+        assert_eq!(result, " 3/3 - container_1");
+
+        app_data.log_previous();
+
+        let result = app_data.get_log_state();
+        assert!(result.is_some());
+        assert_eq!(result.as_ref().unwrap().selected(), Some(1));
+        assert_eq!(result.unwrap().offset(), 0);
+        let result = app_data.get_log_title();
+        assert_eq!(result, " 2/3 - container_1");
+
+        app_data.log_previous();
+        let result = app_data.get_log_state();
+        assert!(result.is_some());
+        assert_eq!(result.as_ref().unwrap().selected(), Some(0));
+        assert_eq!(result.unwrap().offset(), 0);
+        let result = app_data.get_log_title();
+        assert_eq!(result, " 1/3 - container_1");
 
-        let result = app_data.get_container_name_by_id(&ContainerId::from("2"));
-        assert_eq!(result, Some(ContainerName::from("container_2")));
+        app_data.log_previous();
+        let result = app_data.get_log_state();
+        assert!(result.is_some());
+        assert_eq!(result.as_ref().unwrap().selected(), Some(0));
+        assert_eq!(result.unwrap().offset(), 0);
+        let result = app_data.get_log_title();
+        assert_eq!(result, " 1/3 - container_1");
     }
 
+    // ********** //
+    // Chart data //
+    // ********** //
+
     #[test]
-    /// Get the id of the currently selected container
-    fn test_app_data_get_selected_container_id() {
+    /// Chart data returned correctly
+    fn test_app_data_get_chart_data() {
         let (_ids, containers) = gen_containers();
         let mut app_data = gen_appdata(&containers);
-        app_data.containers_end();
 
-        let result = app_data.get_selected_container_id();
-        assert_eq!(result, Some(ContainerId::from("3")));
-    }
+        let result = app_data.get_chart_data();
+        assert!(result.is_none());
 
-    #[test]
-    fn test_app_data_get_selected_container_id_state_name() {
-        let (_ids, containers) = gen_containers();
-        let mut app_data = gen_appdata(&containers);
-        app_data.containers_end();
+        app_data.containers_start();
 
-        let result = app_data.get_selected_container_id_state_name();
+        if let Some(item) = app_data.get_container_by_id(&ContainerId::from("1")) {
+            item.cpu_stats = VecDeque::from([CpuStats::new(1.1), CpuStats::new(1.2)]);
+            item.mem_stats = VecDeque::from([ByteStats::new(1), ByteStats::new(2)]);
+        }
+
+        let result = app_data.get_chart_data();
         assert_eq!(
             result,
             Some((
-                ContainerId::from("3"),
-                State::Running,
-                "container_3".to_owned()
+                (
+                    vec![(0.0, 1.1), (1.0, 1.2)],
+                    CpuStats::new(1.2),
+                    State::Running
+                ),
+                (
+                    vec![(0.0, 1.0), (1.0, 2.0)],
+                    ByteStats::new(2),
+                    State::Running
+                )
             ))
         );
     }
 
-    // ************** //
-    // DockerControls //
-    // ************** //
+    // ************* //
+    // Header Widths //
+    // ************* //
 
     #[test]
-    /// Docker commands returned correctly
-    fn test_app_data_selected_docker_command() {
+    /// Header widths return correctly
+    fn test_app_data_get_width() {
         let (_ids, containers) = gen_containers();
-        let mut app_data = gen_appdata(&containers);
-
-        // No commands when no container selected
-        let result = app_data.selected_docker_controls();
-        assert!(result.is_none());
-
-        // Correct commands returned
-        app_data.containers_start();
-        app_data.docker_controls_start();
+        let app_data = gen_appdata(&containers);
 
-        let result = app_data.selected_docker_controls();
-        assert_eq!(result, Some(DockerControls::Pause));
+        let result = app_data.get_width();
+        let expected = Columns {
+            name: (Header::Name, 11),
+            host: None,
+            state: (Header::State, 11),
+            status: (Header::Status, 16),
+            ready: None,
+            exit_code: None,
+            restarts: None,
+            cpu: (Header::Cpu, 7),
+            throttled: None,
+            mem: (Header::Memory, 7, 7),
+            id: (Header::Id, 8),
+            image: (Header::Image, 7),
+            net_rx: (Header::Rx, 7),
+            net_tx: (Header::Tx, 7),
+            net_io: None,
+            disk_io: None,
+            labels: vec![],
+        };
+        assert_eq!(result, expected);
     }
 
-    #[test]
-    /// Docker command next works
-    fn test_app_data_selected_docker_command_next() {
-        let (_ids, containers) = gen_containers();
-        let mut app_data = gen_appdata(&containers);
-        app_data.containers_start();
-        app_data.docker_controls_start();
-        app_data.docker_controls_next();
-
-        let result = app_data.selected_docker_controls();
-        assert_eq!(result, Some(DockerControls::Restart));
-    }
+    // ***** //
+    // Ports //
+    // ***** //
 
     #[test]
-    /// Dockercommand end works, and next has no effect when at end
-    fn test_app_data_selected_docker_command_end() {
+    /// Returns selected containers ports ordered by private ip
+    fn test_app_data_get_selected_ports() {
         let (_ids, containers) = gen_containers();
         let mut app_data = gen_appdata(&containers);
-        app_data.containers_start();
-        app_data.docker_controls_end();
 
-        let result = app_data.selected_docker_controls();
-        assert_eq!(result, Some(DockerControls::Delete));
+        app_data.containers.items[0].ports.push(ContainerPorts {
+            ip: None,
+            private: 10,
+            public: Some(1),
+        });
+        app_data.containers.items[0].ports.push(ContainerPorts {
+            ip: None,
+            private: 11,
+            public: Some(3),
+        });
+        app_data.containers.items[0].ports.push(ContainerPorts {
+            ip: None,
+            private: 4,
+            public: Some(2),
+        });
 
-        // Next has no effect when at end
-        app_data.docker_controls_next();
-        let result = app_data.selected_docker_controls();
-        assert_eq!(result, Some(DockerControls::Delete));
-    }
+        // No containers selected
+        let result = app_data.get_selected_ports();
+        assert!(result.is_none());
 
-    #[test]
-    /// Docker commands previous works, and has no effect when at start
-    fn test_app_data_selected_docker_command_previous() {
-        let (_ids, containers) = gen_containers();
-        let mut app_data = gen_appdata(&containers);
+        // Selected container & ports
         app_data.containers_start();
-        app_data.docker_controls_end();
-        app_data.docker_controls_previous();
-
-        let result = app_data.selected_docker_controls();
-        assert_eq!(result, Some(DockerControls::Stop));
-
-        // previous has no effect when at start
-        app_data.docker_controls_start();
-        app_data.docker_controls_previous();
-        let result = app_data.selected_docker_controls();
-        assert_eq!(result, Some(DockerControls::Pause));
-    }
+        let result = app_data.get_selected_ports();
 
-    #[test]
-    /// DockerCommands get correct controls dependant on container state
-    fn test_app_data_get_control_items() {
-        let test_state = |state: State, expected: &mut Vec<DockerControls>| {
-            let gen_item_state = |state: State| {
-                ContainerItem::new(
-                    1,
-                    ContainerId::from("1"),
-                    "image_1".to_owned(),
-                    false,
-                    "container_1".to_owned(),
-                    vec![],
-                    state,
-                    "Up 1 hour".to_owned(),
-                )
-            };
-            let mut app_data = gen_appdata(&[gen_item_state(state)]);
-            app_data.containers_start();
-            app_data.docker_controls_start();
+        assert_eq!(
+            result,
+            Some((
+                vec![
+                    ContainerPorts {
+                        ip: None,
+                        private: 4,
+                        public: Some(2)
+                    },
+                    ContainerPorts {
+                        ip: None,
+                        private: 10,
+                        public: Some(1)
+                    },
+                    ContainerPorts {
+                        ip: None,
+                        private: 11,
+                        public: Some(3)
+                    },
+                    ContainerPorts {
+                        ip: None,
+                        private: 8001,
+                        public: None
+                    }
+                ],
+                State::Running
+            ))
+        );
 
-            let result = app_data.get_control_items();
-            assert_eq!(result, Some(expected));
-        };
+        // Selected container & no ports
+        app_data.containers_start();
+        app_data.containers.items[0].ports = vec![];
+        let result = app_data.get_selected_ports();
 
-        test_state(
-            State::Dead,
-            &mut vec![
-                DockerControls::Start,
-                DockerControls::Restart,
-                DockerControls::Delete,
-            ],
-        );
-        test_state(
-            State::Exited,
-            &mut vec![
-                DockerControls::Start,
-                DockerControls::Restart,
-                DockerControls::Delete,
-            ],
-        );
-        test_state(
-            State::Paused,
-            &mut vec![
-                DockerControls::Resume,
-                DockerControls::Stop,
-                DockerControls::Delete,
-            ],
-        );
-        test_state(State::Removing, &mut vec![DockerControls::Delete]);
-        test_state(
-            State::Restarting,
-            &mut vec![DockerControls::Stop, DockerControls::Delete],
-        );
-        test_state(
-            State::Running,
-            &mut vec![
-                DockerControls::Pause,
-                DockerControls::Restart,
-                DockerControls::Stop,
-                DockerControls::Delete,
-            ],
-        );
-        test_state(State::Unknown, &mut vec![DockerControls::Delete]);
+        assert_eq!(result, Some((vec![], State::Running)));
     }
 
-    // **** //
-    // Logs //
-    // **** //
+    // ************** //
+    // Update mtehods //
+    // ************** //
 
     #[test]
-    /// log title string generated correctly
-    fn test_app_data_get_log_title() {
+    /// Update stats functioning
+    fn test_app_data_update_stats() {
         let (ids, containers) = gen_containers();
 
         let mut app_data = gen_appdata(&containers);
 
-        // No container selected select
-        let result = app_data.get_log_title();
-        assert_eq!(result, "");
-
-        // No logs
-        app_data.containers.start();
-        let result = app_data.get_log_title();
-        assert_eq!(result, " - container_1");
+        let result = app_data.get_container_items();
+        assert_eq!(result[0], containers[0]);
 
-        // On last line of logs
-        let logs = (1..=3).map(|i| format!("{i}")).collect::<Vec<_>>();
-        app_data.update_log_by_id(logs, &ids[0]);
-        let result = app_data.get_log_title();
-        assert_eq!(result, " 3/3 - container_1");
+        app_data.update_stats_by_id(&ids[0], Some(10.0), Some(10), 10, 10, 10);
 
-        // Change log state to no longer be at the end
-        app_data.log_previous();
-        let result = app_data.get_log_title();
-        assert_eq!(result, " 2/3 - container_1");
+        let result = app_data.get_container_items();
+        assert_ne!(result[0], containers[0]);
+        assert_eq!(result[0].cpu_stats, VecDeque::from([CpuStats::new(10.0)]));
+        assert_eq!(result[0].mem_stats, VecDeque::from([ByteStats::new(10)]));
+        assert_eq!(result[0].mem_limit, ByteStats::new(10));
+        assert_eq!(result[0].rx, ByteStats::new(10));
+        assert_eq!(result[0].tx, ByteStats::new(10));
     }
 
     #[test]
-    /// log title string generated correctly after container change
-    fn test_app_data_get_log_title_after_container_change() {
+    /// Stats of a paused container aren't recorded, the history is kept as it was when paused, even once full
+    fn test_app_data_update_stats_paused() {
         let (ids, containers) = gen_containers();
         let mut app_data = gen_appdata(&containers);
+        for i in 0..70 {
+            app_data.update_stats_by_id(&ids[0], Some(f64::from(i)), Some(10), 10, 10, 10);
+        }
+        app_data.containers.items[0].state = State::Paused;
+        app_data.update_stats_by_id(&ids[0], Some(0.0), Some(0), 10, 20, 20);
+
+        let result = &app_data.get_container_items()[0];
+        assert_eq!(result.cpu_stats.len(), 60);
+        assert_eq!(result.cpu_stats.back(), Some(&CpuStats::new(69.0)));
+        assert_eq!(result.mem_stats.back(), Some(&ByteStats::new(10)));
+        assert_eq!(result.rx, ByteStats::new(20));
+
+        // Missing samples no longer shrink the history
+        app_data.containers.items[0].state = State::Running;
+        app_data.update_stats_by_id(&ids[0], None, None, 10, 20, 20);
+        assert_eq!(app_data.get_container_items()[0].cpu_stats.len(), 60);
+    }
 
-        // No container selected select
-        let result = app_data.get_log_title();
-        assert_eq!(result, "");
+    #[test]
+    /// A zoomed in chart shows only the most recent samples, a frozen chart ignores samples taken since it was frozen, & the full history is exported
+    fn test_app_data_chart_view() {
+        let (ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        app_data.containers_start();
+        for i in 0..60 {
+            app_data.update_stats_by_id(&ids[0], Some(f64::from(i)), Some(10), 10, 10, 10);
+        }
+        assert_eq!(app_data.get_container_items()[0].stats_times.len(), 60);
+
+        app_data.chart_zoom(true);
+        assert_eq!(app_data.get_chart_window(), 30);
+        let (cpu, _) = app_data.get_chart_data().unwrap();
+        assert_eq!(cpu.0.len(), 30);
+        assert_eq!(cpu.0[0], (0.0, 30.0));
+        assert_eq!(cpu.1, CpuStats::new(59.0));
+
+        app_data.chart_view.toggle_freeze(1000);
+        let item = &mut app_data.containers.items[0];
+        item.stats_times = (0..60).map(|i| 960 + i).collect();
+        let (cpu, mem) = app_data.get_chart_data().unwrap();
+        assert_eq!(cpu.0.last(), Some(&(29.0, 40.0)));
+        assert_eq!(mem.0.len(), 30);
+        assert_eq!(app_data.get_chart_title(), " - last 30s - frozen");
+
+        app_data.chart_freeze();
+        assert!(!app_data.is_chart_frozen());
+        let (name, csv) = app_data.get_selected_stats_csv().unwrap();
+        assert_eq!(name, "container_1");
+        assert_eq!(csv.lines().count(), 61);
+        assert_eq!(csv.lines().nth(1), Some("1970-01-01T00:16:00Z,960,0.00,10"));
+    }
 
+    #[test]
+    /// Throughput is sampled from the counters, only shown as columns once toggled, & can be sorted by, a paused container isn't sampled
+    fn test_app_data_io_view() {
+        let (ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
         app_data.containers_start();
+        for (index, id) in ids.iter().enumerate() {
+            let bytes = u64::try_from(index + 1).unwrap() * 5000;
+            app_data.update_io_by_id(id, [0, 0, 0, 0], 0);
+            app_data.update_io_by_id(id, [bytes, bytes, 0, bytes * 2], 1_000_000_000);
+        }
+        assert_eq!(app_data.get_width().net_io, None);
+        assert_eq!(app_data.get_width().disk_io, None);
 
-        let result = app_data.get_log_title();
-        assert_eq!(result, " - container_1");
+        assert_eq!(app_data.cycle_io_view(), Some(IoView::Columns));
+        assert_eq!(app_data.get_width().net_io, Some((Header::NetIo, 8)));
+        assert_eq!(app_data.get_width().disk_io, Some((Header::DiskIo, 8)));
 
-        // change container
-        app_data.containers_next();
-        let result = app_data.get_log_title();
-        assert_eq!(result, " - container_2");
+        let (chart, _) = app_data.get_io_chart_data().unwrap();
+        assert_eq!(chart.rx, vec![(0.0, 5000.0)]);
+        assert_eq!(chart.disk_max, ByteStats::new(10000));
 
-        // On last line of logs
-        let logs = (1..=3).map(|i| format!("{i}")).collect::<Vec<_>>();
-        app_data.update_log_by_id(logs, &ids[1]);
-        let result = app_data.get_log_title();
-        assert_eq!(result, " 3/3 - container_2");
+        app_data.set_sorted(Some((Header::NetIo, SortedOrder::Desc)));
+        assert_eq!(app_data.get_container_items()[0].id, ids[2]);
 
-        // Change log state to no longer be at the end
-        app_data.log_previous();
-        let result = app_data.get_log_title();
-        assert_eq!(result, " 2/3 - container_2");
+        app_data.containers.items[0].state = State::Paused;
+        let id = app_data.containers.items[0].id.clone();
+        app_data.update_io_by_id(&id, [1, 1, 1, 1], 2_000_000_000);
+        assert_eq!(app_data.containers.items[0].io_stats.len(), 1);
+
+        assert_eq!(app_data.cycle_io_view(), Some(IoView::Charts));
+        assert_eq!(app_data.cycle_io_view(), None);
     }
 
     #[test]
-    /// update logs by id works
-    fn test_app_data_update_log_by_id() {
+    /// Limits are inspected once, shown beside the cpu usage, & inspected again once cleared
+    fn test_app_data_limits() {
         let (ids, containers) = gen_containers();
         let mut app_data = gen_appdata(&containers);
+        assert_eq!(app_data.get_unknown_limits(None), ids);
 
-        // No container selected select
-        let result = app_data.get_log_title();
-        assert_eq!(result, "");
+        let limits = Limits {
+            nano_cpus: Some(1_500_000_000),
+            memory: Some(1 << 30),
+        };
+        app_data.set_limits(&ids[0], Some(limits));
+        assert_eq!(app_data.get_unknown_limits(None), ids[1..]);
+        assert_eq!(app_data.containers.items[0].cpu_text(), "00.00% / 150%");
+        assert_eq!(app_data.containers.items[1].cpu_text(), "00.00%");
+        assert_eq!(app_data.get_width().cpu.1, 13);
+
+        app_data.set_limits(&ids[0], None);
+        assert_eq!(app_data.get_unknown_limits(None), ids);
+    }
 
-        app_data.containers_start();
-        let logs = (1..=3).map(|i| format!("{i} {i}")).collect::<Vec<_>>();
+    #[test]
+    /// Update stats functioning
+    fn test_app_data_update_containers() {
+        let (_ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        let result_pre = app_data.get_container_items().to_owned();
+        assert!(app_data.get_last_update().is_none());
+        assert_eq!(app_data.get_running_len(), 3);
+        let mut input = [
+            gen_container_summary(1, "paused"),
+            gen_container_summary(2, "dead"),
+        ];
 
-        app_data.update_log_by_id(logs, &ids[0]);
-        // app_data.log_start();
+        app_data.update_containers(None, &mut input);
+        let result_post = app_data.get_container_items().to_owned();
+        assert_ne!(result_pre, result_post);
+        assert_eq!(result_post[0].state, State::Paused);
+        assert_eq!(result_post[1].state, State::Dead);
+        assert!(app_data.get_last_update().is_some());
+        assert_eq!(app_data.get_running_len(), 0);
+    }
 
-        let result = app_data.get_log_state();
-        assert!(result.is_some());
-        assert_eq!(result.as_ref().unwrap().selected(), Some(2));
-        assert_eq!(result.unwrap().offset(), 0);
+    #[test]
+    /// Updating containers from one host leaves the containers of other hosts alone
+    fn test_app_data_update_containers_multi_host() {
+        let (_ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        app_data.args.extra_hosts = vec!["remote".to_owned()];
 
-        let result = app_data.get_logs();
-        assert_eq!(result.len(), 3);
+        app_data.update_containers(Some("remote"), &mut [gen_container_summary(9, "running")]);
+        assert_eq!(app_data.get_container_len(), 4);
+        assert_eq!(
+            app_data.get_container_items()[3].host.as_deref(),
+            Some("remote")
+        );
 
-        let result = app_data.get_log_title();
-        assert_eq!(result, " 3/3 - container_1");
+        app_data.update_containers(None, &mut [gen_container_summary(1, "running")]);
+        let result = app_data
+            .get_container_items()
+            .iter()
+            .map(|i| (i.id.get().to_owned(), i.host.clone()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            result,
+            vec![
+                ("1".to_owned(), None),
+                ("9".to_owned(), Some("remote".to_owned()))
+            ]
+        );
+        assert_eq!(
+            app_data.get_host_by_id(&ContainerId::from("9")).as_deref(),
+            Some("remote")
+        );
+        assert!(app_data.get_host_by_id(&ContainerId::from("1")).is_none());
     }
 
     #[test]
-    /// logs state reset to start
-    fn test_app_data_logs_start() {
+    /// A container recreated with the same name, under a temporary name first, as compose does, takes over the chart history, log lock, watch matches, & selection, of the removed container
+    fn test_app_data_update_containers_recreated() {
         let (ids, containers) = gen_containers();
         let mut app_data = gen_appdata(&containers);
-        let logs = (1..=3).map(|i| format!("{i} {i}")).collect::<Vec<_>>();
         app_data.containers_start();
-        app_data.update_log_by_id(logs, &ids[0]);
+        app_data.containers_next();
+        app_data.update_stats_by_id(&ids[1], Some(10.0), Some(100), 1000, 0, 0);
+        app_data.toggle_log_lock();
+        app_data.log_matches.push_back(LogMatch {
+            id: ids[1].clone(),
+            index: Some(0),
+            line: "panic".to_owned(),
+            name: "container_2".to_owned(),
+            pattern: "panic".to_owned(),
+        });
 
-        app_data.log_start();
+        let mut replacement = gen_container_summary(4, "running");
+        replacement.names = Some(vec!["/4f2a_container_2".to_owned()]);
+        let mut input = [
+            gen_container_summary(1, "running"),
+            gen_container_summary(3, "running"),
+            replacement,
+        ];
+        app_data.update_containers(None, &mut input);
+        assert_eq!(app_data.get_selected_container_id(), Some(ids[0].clone()));
+
+        input[2].names = Some(vec!["/container_2".to_owned()]);
+        app_data.update_containers(None, &mut input);
+        let id = ContainerId::from("4");
+        assert_eq!(app_data.get_selected_container_id(), Some(id.clone()));
+        assert_eq!(app_data.log_lock, Some(id.clone()));
+        assert_eq!(app_data.log_matches[0].id, id);
+        assert!(app_data.log_matches[0].index.is_none());
+        let item = app_data.get_container_by_id(&id).unwrap();
+        assert_eq!(item.cpu_stats, [CpuStats::new(10.0)]);
+        assert_eq!(item.mem_stats, [ByteStats::new(100)]);
+
+        // Only carried over once, & not to a container of another name
+        let mut input = [
+            input[0].clone(),
+            input[1].clone(),
+            input[2].clone(),
+            gen_container_summary(5, "running"),
+        ];
+        app_data.update_containers(None, &mut input);
+        assert!(app_data.recreated.take(None, "container_2").is_none());
+        assert!(app_data
+            .get_container_by_id(&ContainerId::from("5"))
+            .unwrap()
+            .cpu_stats
+            .is_empty());
+    }
 
-        let result = app_data.get_log_state();
-        assert!(result.is_some());
-        assert_eq!(result.as_ref().unwrap().selected(), Some(0));
-        assert_eq!(result.unwrap().offset(), 0);
+    #[test]
+    /// Cached containers are marked as stale, until the first update from the primary host replaces them
+    fn test_app_data_load_cached() {
+        let (_ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&[]);
+        app_data.load_cached(vec![]);
+        assert!(!app_data.is_stale());
 
-        let result = app_data.get_log_title();
-        assert_eq!(result, " 1/3 - container_1");
+        app_data.load_cached(containers);
+        assert!(app_data.is_stale());
+        assert_eq!(app_data.container_title(), " 1/3 - cached");
+
+        app_data.update_containers(Some("remote"), &mut [gen_container_summary(9, "running")]);
+        assert!(app_data.is_stale());
+
+        app_data.update_containers(None, &mut [gen_container_summary(2, "running")]);
+        assert!(!app_data.is_stale());
+        assert_eq!(app_data.get_container_len(), 2);
+        assert_eq!(app_data.container_title(), " 1/2");
     }
 
     #[test]
-    /// logs state end goes to the end of the logs list
-    fn test_app_data_logs_end() {
-        let (ids, containers) = gen_containers();
+    /// Probe targets use the published port, an unpublished port is `NoPort`, & containers that aren't running are cleared
+    fn test_app_data_get_probe_targets() {
+        let (ids, mut containers) = gen_containers();
+        containers[0].ports = vec![ContainerPorts {
+            ip: Some("0.0.0.0".to_owned()),
+            private: 80,
+            public: Some(8080),
+        }];
+        containers[2].state = State::Exited;
+        containers[2].readiness = Some(Readiness::Ready);
         let mut app_data = gen_appdata(&containers);
-        let logs = (1..=3).map(|i| format!("{i} {i}")).collect::<Vec<_>>();
-        app_data.containers_start();
-        app_data.update_log_by_id(logs, &ids[0]);
+        app_data.args.probes = vec![
+            Probe::parse("container_1=http:80/health").unwrap(),
+            Probe::parse("*=tcp:5432").unwrap(),
+        ];
 
-        app_data.log_start();
+        let targets = app_data.get_probe_targets(None);
+        assert_eq!(targets.len(), 1);
+        assert_eq!(targets[0].id, ids[0]);
+        assert_eq!(targets[0].address, Some(("localhost".to_owned(), 8080)));
+        let readiness = |app_data: &AppData| {
+            app_data
+                .get_container_items()
+                .iter()
+                .map(|i| i.readiness)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            readiness(&app_data),
+            [Some(Readiness::Pending), Some(Readiness::NoPort), None]
+        );
 
-        let result = app_data.get_log_state();
-        assert!(result.is_some());
-        assert_eq!(result.as_ref().unwrap().selected(), Some(0));
-        assert_eq!(result.unwrap().offset(), 0);
+        app_data.set_readiness(&ids[0], Readiness::Ready);
+        app_data.set_readiness(&ids[2], Readiness::Ready);
+        assert_eq!(
+            readiness(&app_data),
+            [Some(Readiness::Ready), Some(Readiness::NoPort), None]
+        );
+        assert_eq!(app_data.get_width().ready, Some((Header::Ready, 7)));
+        assert!(app_data.get_probe_targets(Some("remote")).is_empty());
+    }
 
-        let result = app_data.get_log_title();
-        assert_eq!(result, " 1/3 - container_1");
+    #[test]
+    /// A compose service is selected by its path, the host is only needed when the service is on more than one host, & switches to the host's tab
+    fn test_app_data_select_container_by_target() {
+        let (_ids, mut containers) = gen_containers();
+        for (container, service) in containers.iter_mut().zip(["web", "web", "db"]) {
+            container.project = Some("shop".to_owned());
+            container.service = Some(service.to_owned());
+        }
+        let mut app_data = gen_appdata(&containers);
+        app_data.containers_start();
 
-        app_data.log_end();
-        let result = app_data.get_log_state();
-        assert!(result.is_some());
-        assert_eq!(result.as_ref().unwrap().selected(), Some(2));
-        assert_eq!(result.unwrap().offset(), 0);
+        // Replicas of a service, the first by name is selected
+        assert!(app_data.select_container_by_target("shop/web"));
+        assert_eq!(
+            app_data.get_selected_container_id(),
+            Some(ContainerId::from("1"))
+        );
+        assert!(app_data.select_container_by_target("local/shop/db"));
+        assert_eq!(
+            app_data.get_selected_container_id(),
+            Some(ContainerId::from("3"))
+        );
+        assert!(!app_data.select_container_by_target("remote/shop/db"));
+        assert!(!app_data.select_container_by_target("shop/cache"));
+        assert!(app_data.select_container_by_target("container_2"));
+
+        // The same service on two hosts is ambiguous without the host
+        app_data.containers.items[1].host = Some("ssh://deploy@build".to_owned());
+        app_data.args.extra_hosts = vec!["ssh://deploy@build".to_owned()];
+        assert!(app_data.set_host_tab(Some("local")));
+        assert!(!app_data.select_container_by_target("shop/web"));
+        assert!(app_data.select_container_by_target("ssh://deploy@build/shop/web"));
+        assert_eq!(
+            app_data.get_selected_container_id(),
+            Some(ContainerId::from("2"))
+        );
+        assert_eq!(app_data.get_host_tab(), Some("ssh://deploy@build"));
+        assert!(app_data.select_container_by_target("local/shop/web"));
+        assert_eq!(
+            app_data.get_selected_container_id(),
+            Some(ContainerId::from("1"))
+        );
+    }
 
-        let result = app_data.get_log_title();
-        assert_eq!(result, " 3/3 - container_1");
+    #[test]
+    /// With `--restarts`, uninspected, & changed, containers are inspected, the columns are shown, & can be sorted by restart count
+    fn test_app_data_exit_info() {
+        let (ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        assert!(app_data.get_width().restarts.is_none());
+        app_data.args.restarts = true;
+        assert_eq!(app_data.get_stale_exit_info(None), ids);
+        assert!(app_data.get_stale_exit_info(Some("remote")).is_empty());
+
+        app_data.set_exit_info(&ids[0], Some(0), 2, 10);
+        app_data.set_exit_info(&ids[1], Some(137), 120, 10);
+        app_data.set_exit_info(&ids[2], Some(1), 7, 10);
+        assert!(app_data.get_stale_exit_info(None).is_empty());
+        let columns = app_data.get_width();
+        assert_eq!(columns.exit_code, Some((Header::ExitCode, 4)));
+        assert_eq!(columns.restarts, Some((Header::Restarts, 8)));
+
+        app_data.set_sort_by_header(Header::Restarts);
+        let names = |app_data: &AppData| {
+            app_data
+                .get_container_items()
+                .iter()
+                .map(|i| i.name.get().to_owned())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            names(&app_data),
+            ["container_1", "container_3", "container_2"]
+        );
+
+        app_data.set_exit_info_stale(&ids[2], Some("remote"));
+        assert!(app_data.get_stale_exit_info(None).is_empty());
+        app_data.set_exit_info_stale(&ids[2], None);
+        assert_eq!(app_data.get_stale_exit_info(None), [ids[2].clone()]);
+
+        // A change of state also needs inspecting again
+        app_data.update_containers(
+            None,
+            &mut [
+                gen_container_summary(1, "exited"),
+                gen_container_summary(2, "running"),
+                gen_container_summary(3, "running"),
+            ],
+        );
+        assert_eq!(app_data.get_stale_exit_info(None).len(), 2);
     }
 
     #[test]
-    /// logs state next works
-    /// At end has no effect
-    fn test_app_data_logs_next() {
+    /// The throttled column is only shown once a container reports a cpu quota, & can be sorted by the recent throttled percentage
+    fn test_app_data_throttling() {
         let (ids, containers) = gen_containers();
         let mut app_data = gen_appdata(&containers);
-        let logs = (1..=3).map(|i| format!("{i} {i}")).collect::<Vec<_>>();
-        app_data.containers_start();
-        app_data.update_log_by_id(logs, &ids[0]);
+        let throttling = |periods: u64, throttled_periods: u64| ThrottlingData {
+            periods,
+            throttled_periods,
+            throttled_time: 0,
+        };
+        for id in &ids {
+            app_data.update_throttling_by_id(id, &throttling(0, 0));
+        }
+        assert!(app_data.get_width().throttled.is_none());
 
-        app_data.log_start();
+        app_data.update_throttling_by_id(&ids[0], &throttling(100, 0));
+        app_data.update_throttling_by_id(&ids[0], &throttling(200, 20));
+        app_data.update_throttling_by_id(&ids[2], &throttling(100, 0));
+        app_data.update_throttling_by_id(&ids[2], &throttling(200, 90));
+        assert_eq!(app_data.get_width().throttled, Some((Header::Throttled, 9)));
+        assert_eq!(app_data.containers.items[2].throttling.percent(), Some(90));
 
-        let result = app_data.get_log_state();
-        assert!(result.is_some());
-        assert_eq!(result.as_ref().unwrap().selected(), Some(0));
-        assert_eq!(result.unwrap().offset(), 0);
+        app_data.set_sort_by_header(Header::Throttled);
+        assert_eq!(
+            app_data
+                .get_container_items()
+                .iter()
+                .map(|i| i.name.get().to_owned())
+                .collect::<Vec<_>>(),
+            ["container_2", "container_1", "container_3"]
+        );
+    }
 
-        let result = app_data.get_log_title();
-        assert_eq!(result, " 1/3 - container_1");
+    #[test]
+    /// A container is selected by its name, or else by a prefix of its id, a prefix of more than one id selects nothing
+    fn test_app_data_select_container_by_name_or_id() {
+        let (_ids, mut containers) = gen_containers();
+        containers[0].id = ContainerId::from("3f9a1c");
+        containers[1].id = ContainerId::from("3f2b7d");
+        containers[2].id = ContainerId::from("9d3f6b");
+        let mut app_data = gen_appdata(&containers);
+        app_data.containers_start();
 
-        app_data.log_next();
+        assert!(app_data.select_container_by_name_or_id("container_2"));
+        assert_eq!(
+            app_data.get_selected_container_id(),
+            Some(ContainerId::from("3f2b7d"))
+        );
+        assert!(app_data.select_container_by_name_or_id("9d"));
+        assert_eq!(
+            app_data.get_selected_container_id(),
+            Some(ContainerId::from("9d3f6b"))
+        );
+        assert!(!app_data.select_container_by_name_or_id("3f"));
+        assert!(!app_data.select_container_by_name_or_id(""));
+        assert!(app_data.select_container_by_name_or_id("3f9a1c"));
+        assert_eq!(
+            app_data.get_selected_container_id(),
+            Some(ContainerId::from("3f9a1c"))
+        );
+    }
 
-        let result = app_data.get_log_state();
-        assert!(result.is_some());
-        assert_eq!(result.as_ref().unwrap().selected(), Some(1));
-        assert_eq!(result.unwrap().offset(), 0);
+    #[test]
+    /// Hosts, & containers, are chosen by name, only when they're known and shown
+    fn test_app_data_set_host_tab_select_by_name() {
+        let (_ids, mut containers) = gen_containers();
+        containers[1].host = Some("remote".to_owned());
+        let mut app_data = gen_appdata(&containers);
+        app_data.containers_start();
 
-        let result = app_data.get_log_title();
-        assert_eq!(result, " 2/3 - container_1");
+        // Single host, no tabs
+        assert!(app_data.set_host_tab(Some("local")));
+        assert!(!app_data.set_host_tab(Some("remote")));
+        assert!(app_data.get_host_tab().is_none());
 
-        app_data.log_next();
-        let result = app_data.get_log_state();
-        assert!(result.is_some());
-        assert_eq!(result.as_ref().unwrap().selected(), Some(2));
-        assert_eq!(result.unwrap().offset(), 0);
+        app_data.args.extra_hosts = vec!["remote".to_owned()];
+        assert!(app_data.select_container_by_name("container_3"));
+        assert_eq!(
+            app_data.get_selected_container_id(),
+            Some(ContainerId::from("3"))
+        );
+        assert!(!app_data.select_container_by_name("container_4"));
 
-        let result = app_data.get_log_title();
-        assert_eq!(result, " 3/3 - container_1");
-        app_data.log_next();
+        assert!(app_data.set_host_tab(Some("remote")));
+        assert_eq!(app_data.get_host_tab(), Some("remote"));
+        assert_eq!(
+            app_data.get_selected_container_id(),
+            Some(ContainerId::from("2"))
+        );
+        assert!(!app_data.select_container_by_name("container_3"));
+        assert!(!app_data.set_host_tab(Some("other")));
+        assert_eq!(app_data.get_host_tab(), Some("remote"));
 
-        let result = app_data.get_log_state();
-        assert!(result.is_some());
-        assert_eq!(result.as_ref().unwrap().selected(), Some(2));
-        assert_eq!(result.unwrap().offset(), 0);
+        assert!(app_data.set_host_tab(None));
+        assert!(app_data.select_container_by_name("container_3"));
+        assert_eq!(
+            app_data.get_selected_container_id(),
+            Some(ContainerId::from("3"))
+        );
+    }
 
-        let result = app_data.get_log_title();
-        assert_eq!(result, " 3/3 - container_1");
+    #[test]
+    /// A container hidden by the host tab, & the search, is revealed, the search is only cleared if still hidden
+    fn test_app_data_reveal_container() {
+        let (ids, mut containers) = gen_containers();
+        containers[1].host = Some("remote".to_owned());
+        let mut app_data = gen_appdata(&containers);
+        app_data.args.extra_hosts = vec!["remote".to_owned()];
+        app_data.containers_start();
+        assert!(!app_data.reveal_container(&ContainerId::from("4")));
+
+        app_data.set_host_tab(Some("remote"));
+        app_data.set_search(Some("container".to_owned()));
+        assert!(app_data.reveal_container(&ids[2]));
+        assert_eq!(app_data.get_host_tab(), Some("local"));
+        assert_eq!(app_data.get_search(), Some("container"));
+        assert_eq!(app_data.get_selected_container_id(), Some(ids[2].clone()));
+
+        app_data.set_search(Some("container_1".to_owned()));
+        assert!(app_data.reveal_container(&ids[2]));
+        assert!(app_data.get_search().is_none());
+        assert_eq!(app_data.get_selected_container_id(), Some(ids[2].clone()));
     }
 
     #[test]
-    /// logs state previous works
-    /// previous at start has no effect
-    fn test_app_data_logs_previous() {
-        let (ids, containers) = gen_containers();
+    /// Host tabs cycle through each host, filtering the rows, and the width includes a host column
+    fn test_app_data_host_tabs() {
+        let (_ids, mut containers) = gen_containers();
+        containers[1].host = Some("remote".to_owned());
         let mut app_data = gen_appdata(&containers);
-        let logs = (1..=3).map(|i| format!("{i} {i}")).collect::<Vec<_>>();
         app_data.containers_start();
-        app_data.update_log_by_id(logs, &ids[0]);
-
-        app_data.log_end();
-
-        let result = app_data.get_log_state();
-        assert!(result.is_some());
-        assert_eq!(result.as_ref().unwrap().selected(), Some(2));
-        assert_eq!(result.unwrap().offset(), 0);
+        // ( v ) then ( enter ), the tab after the current tab is preselected
+        let next_host_tab = |app_data: &mut AppData| {
+            if let Some(picker) = app_data.host_picker() {
+                let host = picker.chosen().unwrap().host.clone();
+                app_data.set_host_tab(host.as_deref());
+            }
+        };
 
-        let result = app_data.get_log_title();
-        assert_eq!(result, " 3/3 - container_1");
+        // Single host, no tabs
+        next_host_tab(&mut app_data);
+        assert!(app_data.get_host_tab().is_none());
+        assert!(app_data.get_width().host.is_none());
+        assert_eq!(app_data.container_title(), " 1/3");
 
-        app_data.log_previous();
+        app_data.args.extra_hosts = vec!["remote".to_owned()];
+        assert_eq!(app_data.get_width().host, Some((Header::Host, 6)));
+        assert_eq!(app_data.container_title(), " 1/3 - all hosts");
 
-        let result = app_data.get_log_state();
-        assert!(result.is_some());
-        assert_eq!(result.as_ref().unwrap().selected(), Some(1));
-        assert_eq!(result.unwrap().offset(), 0);
-        let result = app_data.get_log_title();
-        assert_eq!(result, " 2/3 - container_1");
+        next_host_tab(&mut app_data);
+        assert_eq!(app_data.get_host_tab(), Some("local"));
+        assert_eq!(
+            app_data.get_container_rows(),
+            vec![ContainerRow::Container(0), ContainerRow::Container(2)]
+        );
+        app_data.containers_next();
+        assert_eq!(app_data.container_title(), " 2/2 - local");
+        assert_eq!(
+            app_data.get_selected_container_id(),
+            Some(ContainerId::from("3"))
+        );
 
-        app_data.log_previous();
-        let result = app_data.get_log_state();
-        assert!(result.is_some());
-        assert_eq!(result.as_ref().unwrap().selected(), Some(0));
-        assert_eq!(result.unwrap().offset(), 0);
-        let result = app_data.get_log_title();
-        assert_eq!(result, " 1/3 - container_1");
+        // Selected container is hidden, so first visible row is selected
+        next_host_tab(&mut app_data);
+        assert_eq!(app_data.get_host_tab(), Some("remote"));
+        assert_eq!(
+            app_data.get_container_rows(),
+            vec![ContainerRow::Container(1)]
+        );
+        assert_eq!(app_data.container_title(), " 1/1 - remote");
+        assert_eq!(
+            app_data.get_selected_container_id(),
+            Some(ContainerId::from("2"))
+        );
 
-        app_data.log_previous();
-        let result = app_data.get_log_state();
-        assert!(result.is_some());
-        assert_eq!(result.as_ref().unwrap().selected(), Some(0));
-        assert_eq!(result.unwrap().offset(), 0);
-        let result = app_data.get_log_title();
-        assert_eq!(result, " 1/3 - container_1");
+        next_host_tab(&mut app_data);
+        assert!(app_data.get_host_tab().is_none());
+        assert_eq!(app_data.get_container_rows().len(), 3);
     }
 
-    // ********** //
-    // Chart data //
-    // ********** //
-
     #[test]
-    /// Chart data returned correctly
-    fn test_app_data_get_chart_data() {
-        let (_ids, containers) = gen_containers();
+    /// The host switcher lists all hosts, then each host, with its running & total containers, the tab after the current one is selected
+    fn test_app_data_host_picker() {
+        let (_ids, mut containers) = gen_containers();
+        containers[1].host = Some("remote".to_owned());
+        containers[2].state = State::Exited;
         let mut app_data = gen_appdata(&containers);
+        assert!(app_data.host_picker().is_none());
 
-        let result = app_data.get_chart_data();
-        assert!(result.is_none());
+        app_data.args.extra_hosts = vec!["remote".to_owned()];
+        let picker = app_data.host_picker().unwrap();
+        assert_eq!(
+            picker
+                .rows
+                .iter()
+                .map(|i| (i.name(), i.running, i.total))
+                .collect::<Vec<_>>(),
+            [("all hosts", 2, 3), ("local", 1, 2), ("remote", 1, 1)]
+        );
+        assert_eq!(picker.current, 0);
+        assert_eq!(picker.selected, 1);
 
-        app_data.containers_start();
+        assert!(app_data.set_host_tab(Some("remote")));
+        let picker = app_data.host_picker().unwrap();
+        assert_eq!(picker.current, 2);
+        assert_eq!(picker.selected, 0);
+    }
 
-        if let Some(item) = app_data.get_container_by_id(&ContainerId::from("1")) {
-            item.cpu_stats = VecDeque::from([CpuStats::new(1.1), CpuStats::new(1.2)]);
-            item.mem_stats = VecDeque::from([ByteStats::new(1), ByteStats::new(2)]);
-        }
+    #[test]
+    /// Due schedules are resolved to their container, on their host, a schedule whose container is gone fails
+    fn test_app_data_take_due_schedules() {
+        let (ids, mut containers) = gen_containers();
+        containers[1].host = Some("remote".to_owned());
+        let mut app_data = gen_appdata(&containers);
 
-        let result = app_data.get_chart_data();
+        app_data
+            .add_schedule("container_1", None, "restart in 1m", 0)
+            .unwrap();
+        app_data
+            .add_schedule("container_2", Some("remote"), "stop in 1m", 0)
+            .unwrap();
+        app_data
+            .add_schedule("missing", None, "save logs every 1m", 0)
+            .unwrap();
+        assert!(app_data
+            .add_schedule("container_1", None, "restart", 0)
+            .is_err());
+        assert_eq!(app_data.get_schedules().len(), 3);
+
+        assert!(app_data.take_due_schedules(None, 59).is_empty());
         assert_eq!(
-            result,
-            Some((
-                (
-                    vec![(0.0, 1.1), (1.0, 1.2)],
-                    CpuStats::new(1.2),
-                    State::Running
-                ),
-                (
-                    vec![(0.0, 1.0), (1.0, 2.0)],
-                    ByteStats::new(2),
-                    State::Running
-                )
-            ))
+            app_data.take_due_schedules(None, 60),
+            [ScheduleRun {
+                key: 0,
+                id: ids[0].clone(),
+                name: "container_1".to_owned(),
+                action: ScheduleAction::Restart,
+            }]
+        );
+        assert_eq!(
+            app_data.get_schedules()[2].last,
+            Some((60, Err("container not found".to_owned())))
         );
+        assert!(app_data.take_due_schedules(None, 61).is_empty());
+
+        let remote = app_data.take_due_schedules(Some("remote"), 60);
+        assert_eq!(remote[0].id, ids[1]);
+        app_data.remove_schedule(0);
+        app_data.set_schedule_outcome(1, 65, Ok(()));
+        assert_eq!(app_data.get_schedules()[0].last, Some((65, Ok(()))));
+        assert!(app_data.get_schedules()[0].next.is_none());
     }
 
-    // ************* //
-    // Header Widths //
-    // ************* //
-
     #[test]
-    /// Header widths return correctly
-    fn test_app_data_get_width() {
+    /// Each `--label-column` is as wide as its widest value, sorts by its value, & "label=value" only searches that label
+    fn test_app_data_label_columns() {
         let (_ids, containers) = gen_containers();
-        let app_data = gen_appdata(&containers);
+        let mut app_data = gen_appdata(&containers);
+        app_data.args.label_columns = vec!["team".to_owned(), "version".to_owned()];
+        let mut input = [
+            gen_container_summary(1, "running"),
+            gen_container_summary(2, "running"),
+            gen_container_summary(3, "running"),
+        ];
+        for (i, (team, version)) in input.iter_mut().zip([
+            ("payments", "1.2.0"),
+            ("search", "1.10.3"),
+            ("ops", "2.0.0"),
+        ]) {
+            i.labels = Some(HashMap::from([
+                ("team".to_owned(), team.to_owned()),
+                ("version".to_owned(), version.to_owned()),
+            ]));
+        }
+        input[2].labels.as_mut().unwrap().remove("version");
+        app_data.update_containers(None, &mut input);
+        assert_eq!(
+            app_data.get_container_items()[0].label_values,
+            [Some("payments".to_owned()), Some("1.2.0".to_owned())]
+        );
+        assert_eq!(app_data.get_container_items()[2].label_values[1], None);
+        assert_eq!(
+            app_data.get_width().labels,
+            [(Header::Label(0), 8), (Header::Label(1), 7)]
+        );
 
-        let result = app_data.get_width();
-        let expected = Columns {
-            name: (Header::Name, 11),
-            state: (Header::State, 11),
-            status: (Header::Status, 16),
-            cpu: (Header::Cpu, 7),
-            mem: (Header::Memory, 7, 7),
-            id: (Header::Id, 8),
-            image: (Header::Image, 7),
-            net_rx: (Header::Rx, 7),
-            net_tx: (Header::Tx, 7),
-        };
-        assert_eq!(result, expected);
-    }
+        app_data.set_sort_by_header(Header::Label(0));
+        assert_eq!(
+            app_data
+                .get_container_items()
+                .iter()
+                .map(|i| i.name.get())
+                .collect::<Vec<_>>(),
+            ["container_3", "container_1", "container_2"]
+        );
 
-    // ***** //
-    // Ports //
-    // ***** //
+        app_data.set_search(Some("TEAM=pay".to_owned()));
+        assert_eq!(app_data.get_container_rows().len(), 1);
+        app_data.set_search(Some("version=search".to_owned()));
+        assert!(app_data.get_container_rows().is_empty());
+        app_data.set_search(Some("1.10".to_owned()));
+        assert_eq!(app_data.get_container_rows().len(), 1);
+    }
 
     #[test]
-    /// Returns selected containers ports ordered by private ip
-    fn test_app_data_get_selected_ports() {
+    /// Filter rules hide containers from the rows, the counts, & the title, a label rule keeps the values of its label, & a rule turned off is ignored
+    fn test_app_data_filters() {
         let (_ids, containers) = gen_containers();
         let mut app_data = gen_appdata(&containers);
+        let mut input = [
+            gen_container_summary(1, "running"),
+            gen_container_summary(2, "exited"),
+            gen_container_summary(3, "running"),
+        ];
+        input[2].labels = Some(HashMap::from([(
+            "role".to_owned(),
+            "log-sidecar".to_owned(),
+        )]));
+        app_data.update_containers(None, &mut input);
+        app_data.containers_start();
 
-        app_data.containers.items[0].ports.push(ContainerPorts {
-            ip: None,
-            private: 10,
-            public: Some(1),
-        });
-        app_data.containers.items[0].ports.push(ContainerPorts {
-            ip: None,
-            private: 11,
-            public: Some(3),
-        });
-        app_data.containers.items[0].ports.push(ContainerPorts {
-            ip: None,
-            private: 4,
-            public: Some(2),
-        });
+        assert!(app_data.add_filter("state=up").is_err());
+        app_data.add_filter("status!=exited").unwrap();
+        assert_eq!(app_data.get_shown_len(), (2, 2));
+        assert_eq!(app_data.container_title(), " 1/2 - 1 filtered");
 
-        // No containers selected
-        let result = app_data.get_selected_ports();
-        assert!(result.is_none());
+        app_data.add_filter("label!=role=*-sidecar").unwrap();
+        assert!(app_data.get_container_items()[2].filter_labels.is_empty());
+        app_data.update_containers(None, &mut input);
+        assert_eq!(
+            app_data.get_container_items()[2].filter_labels["role"],
+            "log-sidecar"
+        );
+        assert_eq!(
+            app_data.get_container_rows(),
+            vec![ContainerRow::Container(0)]
+        );
 
-        // Selected container & ports
+        app_data.toggle_filter(0);
+        assert_eq!(app_data.get_shown_len(), (1, 2));
+        assert_eq!(app_data.get_filters()[0].to_string(), "status!=exited");
+        app_data.remove_filter(1);
+        assert_eq!(app_data.get_filters().len(), 1);
+        assert_eq!(app_data.get_shown_len(), (2, 3));
+        assert_eq!(app_data.container_title(), " 1/3");
+    }
+
+    #[test]
+    /// Notes are keyed by container name, an empty note removes it, & the search matches names, images, & notes
+    fn test_app_data_notes_search() {
+        let (_ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
         app_data.containers_start();
-        let result = app_data.get_selected_ports();
 
+        app_data
+            .set_note("container_3", " don't restart before 2am backup ")
+            .unwrap();
+        assert!(app_data.get_selected_note().is_none());
         assert_eq!(
-            result,
-            Some((
-                vec![
-                    ContainerPorts {
-                        ip: None,
-                        private: 4,
-                        public: Some(2)
-                    },
-                    ContainerPorts {
-                        ip: None,
-                        private: 10,
-                        public: Some(1)
-                    },
-                    ContainerPorts {
-                        ip: None,
-                        private: 11,
-                        public: Some(3)
-                    },
-                    ContainerPorts {
-                        ip: None,
-                        private: 8001,
-                        public: None
-                    }
-                ],
-                State::Running
-            ))
+            app_data.get_note("container_3"),
+            Some("don't restart before 2am backup")
         );
 
-        // Selected container & no ports
-        app_data.containers_start();
-        app_data.containers.items[0].ports = vec![];
-        let result = app_data.get_selected_ports();
+        app_data.search_push('B');
+        app_data.search_push('a');
+        assert_eq!(
+            app_data.get_container_rows(),
+            vec![ContainerRow::Container(2)]
+        );
+        assert_eq!(app_data.container_title(), " 1/1 - /Ba");
+        assert_eq!(
+            app_data.get_selected_note(),
+            Some("don't restart before 2am backup")
+        );
 
-        assert_eq!(result, Some((vec![], State::Running)));
+        app_data.search_pop();
+        app_data.search_pop();
+        assert_eq!(app_data.get_search(), Some(""));
+        assert_eq!(app_data.get_container_rows().len(), 3);
+        app_data.set_search(Some("image_2".to_owned()));
+        assert_eq!(
+            app_data.get_container_rows(),
+            vec![ContainerRow::Container(1)]
+        );
+
+        app_data.set_search(None);
+        app_data.set_note("container_3", "").unwrap();
+        assert!(app_data.get_note("container_3").is_none());
+        assert_eq!(app_data.container_title(), " 2/3");
     }
 
-    // ************** //
-    // Update mtehods //
-    // ************** //
+    #[test]
+    /// Host usage sums the latest stats of the primary host's running containers, cpu is shared between all of the host's cpus
+    fn test_app_data_get_host_usage() {
+        let (_ids, mut containers) = gen_containers();
+        containers[0].cpu_stats.push_back(CpuStats::new(150.0));
+        containers[0].cpu_stats.push_back(CpuStats::new(250.0));
+        containers[0]
+            .mem_stats
+            .push_back(ByteStats::new(2_000_000_000));
+        containers[1].cpu_stats.push_back(CpuStats::new(150.0));
+        containers[1]
+            .mem_stats
+            .push_back(ByteStats::new(2_000_000_000));
+        containers[2].cpu_stats.push_back(CpuStats::new(400.0));
+        containers[2].host = Some("remote".to_owned());
+        let mut app_data = gen_appdata(&containers);
+        assert!(app_data.get_host_usage().is_none());
+
+        app_data.set_host_resources(Some(HostResources {
+            cpus: 8,
+            memory: 16_000_000_000,
+        }));
+        let usage = app_data.get_host_usage().unwrap();
+        assert!((usage.cpu - 50.0).abs() < f64::EPSILON);
+        assert_eq!(usage.memory, ByteStats::new(4_000_000_000));
+        assert_eq!(usage.to_string(), "8 cpu 50%, 16.00 GB 25%");
+    }
 
     #[test]
-    /// Update stats functioning
-    fn test_app_data_update_stats() {
+    /// Only containers whose writable layer has grown past the disk alert since the last check are returned, & counted while they're over it
+    fn test_app_data_update_rw_sizes() {
         let (ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        let sizes =
+            |size: [u64; 2]| HashMap::from([(ids[0].clone(), size[0]), (ids[1].clone(), size[1])]);
+        assert!(app_data.update_rw_sizes(&sizes([2_000, 0])).is_empty());
+        assert_eq!(app_data.get_rw_over_len(), 0);
+
+        app_data.args.disk_alert = Some(1_000);
+        let over = app_data.update_rw_sizes(&sizes([2_000, 500]));
+        assert_eq!(over.len(), 1);
+        assert_eq!(over[0].0, containers[0].name);
+        assert_eq!(over[0].1, ByteStats::new(2_000));
+        assert_eq!(app_data.get_rw_over_len(), 1);
+
+        let over = app_data.update_rw_sizes(&sizes([3_000, 1_500]));
+        assert_eq!(over.len(), 1);
+        assert_eq!(over[0].0, containers[1].name);
+        assert_eq!(app_data.get_rw_over_len(), 2);
+
+        assert!(app_data.update_rw_sizes(&sizes([500, 1_500])).is_empty());
+        assert_eq!(app_data.update_rw_sizes(&sizes([1_001, 1_500])).len(), 1);
+    }
 
+    #[test]
+    /// Url uses the lowest published port, and the Docker host address when bound to all interfaces
+    fn test_app_data_get_selected_url() {
+        let (_ids, mut containers) = gen_containers();
+        containers[0].ports = vec![
+            ContainerPorts {
+                ip: Some("0.0.0.0".to_owned()),
+                private: 80,
+                public: Some(8080),
+            },
+            ContainerPorts {
+                ip: Some("0.0.0.0".to_owned()),
+                private: 443,
+                public: Some(8043),
+            },
+        ];
         let mut app_data = gen_appdata(&containers);
 
-        let result = app_data.get_container_items();
-        assert_eq!(result[0], containers[0]);
+        // No public port
+        app_data.containers_select(1);
+        assert!(app_data.get_selected_url().is_none());
 
-        app_data.update_stats_by_id(&ids[0], Some(10.0), Some(10), 10, 10, 10);
+        app_data.containers_select(0);
+        assert_eq!(
+            app_data.get_selected_url().as_deref(),
+            Some("http://localhost:8043")
+        );
 
-        let result = app_data.get_container_items();
-        assert_ne!(result[0], containers[0]);
-        assert_eq!(result[0].cpu_stats, VecDeque::from([CpuStats::new(10.0)]));
-        assert_eq!(result[0].mem_stats, VecDeque::from([ByteStats::new(10)]));
-        assert_eq!(result[0].mem_limit, ByteStats::new(10));
-        assert_eq!(result[0].rx, ByteStats::new(10));
-        assert_eq!(result[0].tx, ByteStats::new(10));
+        app_data.args.host = Some("ssh://user@example.com:22".to_owned());
+        assert_eq!(
+            app_data.get_selected_url().as_deref(),
+            Some("http://example.com:8043")
+        );
+
+        app_data.containers.items[0].ports[1].ip = Some("127.0.0.1".to_owned());
+        assert_eq!(
+            app_data.get_selected_url().as_deref(),
+            Some("http://127.0.0.1:8043")
+        );
+        app_data.containers.items[0].ports[1].ip = Some("::1".to_owned());
+        assert_eq!(
+            app_data.get_selected_url().as_deref(),
+            Some("http://[::1]:8043")
+        );
     }
 
     #[test]
-    /// Update stats functioning
-    fn test_app_data_update_containers() {
-        let (_ids, containers) = gen_containers();
-        let mut app_data = gen_appdata(&containers);
-        let result_pre = app_data.get_container_items().to_owned();
-        let mut input = [
-            gen_container_summary(1, "paused"),
-            gen_container_summary(2, "dead"),
+    /// Published ports are listed first, each with its url, a port published on both ipv4 & ipv6 is listed once, & exposed ports have no url
+    fn test_app_data_get_selected_port_view() {
+        let (ids, mut containers) = gen_containers();
+        let port = |ip: Option<&str>, private, public| ContainerPorts {
+            ip: ip.map(ToOwned::to_owned),
+            private,
+            public,
+        };
+        containers[0].ports = vec![
+            port(None, 9000, None),
+            port(Some("0.0.0.0"), 80, Some(8080)),
+            port(Some("::"), 80, Some(8080)),
+            port(Some("127.0.0.1"), 443, Some(8043)),
         ];
+        let mut app_data = gen_appdata(&containers);
+        app_data.containers_start();
 
-        app_data.update_containers(&mut input);
-        let result_post = app_data.get_container_items().to_owned();
-        assert_ne!(result_pre, result_post);
-        assert_eq!(result_post[0].state, State::Paused);
-        assert_eq!(result_post[1].state, State::Dead);
+        let view = app_data.get_selected_port_view().unwrap();
+        assert_eq!(view.id, ids[0]);
+        assert_eq!(view.name, "container_1");
+        assert_eq!(
+            view.rows
+                .iter()
+                .map(|i| (i.private, i.url.as_deref()))
+                .collect::<Vec<_>>(),
+            [
+                (80, Some("http://localhost:8080")),
+                (443, Some("http://127.0.0.1:8043")),
+                (9000, None)
+            ]
+        );
+        assert!(!view.rows[2].is_published());
+    }
+
+    #[test]
+    /// Sort by host, the primary host uses its display name
+    fn test_app_data_sort_by_host() {
+        let (_ids, mut containers) = gen_containers();
+        containers[0].host = Some("zeta".to_owned());
+        containers[2].host = Some("alpha".to_owned());
+        let mut app_data = gen_appdata(&containers);
+        app_data.args.extra_hosts = vec!["zeta".to_owned(), "alpha".to_owned()];
+
+        app_data.set_sort_by_header(Header::Host);
+        let result = app_data
+            .get_container_items()
+            .iter()
+            .map(|i| app_data.container_host(i).to_owned())
+            .collect::<Vec<_>>();
+        assert_eq!(result, ["alpha", "local", "zeta"]);
     }
 
     #[test]
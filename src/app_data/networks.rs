@@ -0,0 +1,73 @@
+use super::images::in_use_text;
+
+/// Networks created by the daemon, which can't be removed, `nat` being the default network of a Windows daemon
+const PREDEFINED: [&str; 4] = ["bridge", "host", "nat", "none"];
+
+/// Length of the short network id, as shown by `docker network ls`
+const SHORT_ID: usize = 12;
+
+/// A network, as listed in the networks panel
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NetworkItem {
+    /// Names of the containers, running or not, that are connected to the network
+    pub containers: Vec<String>,
+    pub driver: String,
+    pub id: String,
+    /// Containers on an internal network have no external connectivity
+    pub internal: bool,
+    pub name: String,
+    /// Either `local`, `global`, or `swarm`
+    pub scope: String,
+}
+
+impl NetworkItem {
+    /// A network created by the daemon, rather than by a user, or compose
+    pub fn is_predefined(&self) -> bool {
+        PREDEFINED.contains(&self.name.as_str())
+    }
+
+    /// The network id, truncated, as shown by `docker network ls`
+    pub fn short_id(&self) -> &str {
+        self.id.get(..SHORT_ID).unwrap_or(&self.id)
+    }
+
+    /// Whether the network is in use, and by how many containers, a predefined network can't be removed, so is never shown as unused
+    pub fn state_text(&self) -> String {
+        if self.containers.is_empty() && self.is_predefined() {
+            "predefined".to_owned()
+        } else {
+            in_use_text(self.containers.len())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::NetworkItem;
+
+    #[test]
+    /// Predefined networks are never shown as unused
+    fn test_network_item() {
+        let gen_network = |name: &str, containers: &[&str]| NetworkItem {
+            containers: containers.iter().map(|i| (*i).to_owned()).collect(),
+            driver: "bridge".to_owned(),
+            id: "3f1e2d4c5b6a7f8e9d0c1b2a".to_owned(),
+            internal: false,
+            name: name.to_owned(),
+            scope: "local".to_owned(),
+        };
+        let network = gen_network("bridge", &[]);
+        assert!(network.is_predefined());
+        assert_eq!(network.state_text(), "predefined");
+        assert_eq!(network.short_id(), "3f1e2d4c5b6a");
+        assert_eq!(gen_network("bridge", &["web"]).state_text(), "1 container");
+
+        let network = gen_network("site_default", &[]);
+        assert!(!network.is_predefined());
+        assert_eq!(network.state_text(), "unused");
+        assert_eq!(
+            gen_network("site_default", &["site-api-1", "site-db-1"]).state_text(),
+            "2 containers"
+        );
+    }
+}
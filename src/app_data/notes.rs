@@ -0,0 +1,93 @@
+use std::{
+    collections::HashMap,
+    fmt::Write as _,
+    path::{Path, PathBuf},
+};
+
+use super::cache::{escape, unescape};
+
+/// First line of the notes file, changed whenever the format changes
+const NOTES_HEADER: &str = "oxker-notes 1";
+
+/// Location of the notes file, in the state directory, or the local data directory on platforms without one
+/// Notes are keyed by container name, so that they survive a container being recreated
+pub fn path() -> Option<PathBuf> {
+    directories::BaseDirs::new().map(|i| {
+        i.state_dir()
+            .unwrap_or_else(|| i.data_local_dir())
+            .join(env!("CARGO_PKG_NAME"))
+            .join("notes")
+    })
+}
+
+/// Convert the notes into the file format, one note per line, name then note, sorted by name so that the file is stable
+fn to_notes(notes: &HashMap<String, String>) -> String {
+    let mut output = format!("{NOTES_HEADER}\n");
+    let mut names = notes.keys().collect::<Vec<_>>();
+    names.sort();
+    for name in names {
+        writeln!(output, "{}\t{}", escape(name), escape(&notes[name])).ok();
+    }
+    output
+}
+
+/// Parse the notes file format, None if the header doesn't match, invalid lines are skipped
+fn from_notes(input: &str) -> Option<HashMap<String, String>> {
+    let mut lines = input.lines();
+    if lines.next() != Some(NOTES_HEADER) {
+        return None;
+    }
+    Some(
+        lines
+            .filter_map(|line| {
+                let (name, note) = line.split_once('\t')?;
+                Some((unescape(name), unescape(note)))
+            })
+            .filter(|(name, note)| !name.is_empty() && !note.is_empty())
+            .collect(),
+    )
+}
+
+/// Load the notes, empty if there is no notes file, or it can't be read
+pub fn load(path: &Path) -> HashMap<String, String> {
+    std::fs::read_to_string(path)
+        .ok()
+        .and_then(|i| from_notes(&i))
+        .unwrap_or_default()
+}
+
+/// Save the notes, creating the state directory if needed
+pub fn save(path: &Path, notes: &HashMap<String, String>) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(path, to_notes(notes))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::{from_notes, to_notes, NOTES_HEADER};
+
+    #[test]
+    /// Notes survive a round trip through the file format, unknown headers, & invalid lines, are ignored
+    fn test_notes_round_trip() {
+        let notes = HashMap::from([
+            (
+                "backup".to_owned(),
+                "don't restart before 2am\tbackup finishes".to_owned(),
+            ),
+            ("api".to_owned(), "owned by\nthe web team".to_owned()),
+        ]);
+        let output = to_notes(&notes);
+        assert!(output.starts_with(&format!("{NOTES_HEADER}\napi\t")));
+        assert_eq!(from_notes(&output).unwrap(), notes);
+
+        assert!(from_notes("oxker-notes 0\napi\tnote").is_none());
+        let result = from_notes(&format!("{NOTES_HEADER}\nno_note\napi\tnote\n\tno name")).unwrap();
+        assert_eq!(result.len(), 1);
+        assert_eq!(result["api"], "note");
+    }
+}
@@ -0,0 +1,292 @@
+use std::fmt::Write as _;
+
+use clap::ValueEnum;
+use serde_json::json;
+
+use super::{ContainerItem, ContainerPorts, State, Stats};
+use crate::docker_data::format_rfc3339;
+
+/// How the containers are printed by `--output`, instead of drawing the gui
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// A single line of json each time, so that each print can be read by jq
+    Json,
+    /// Aligned columns, as `docker ps` prints them
+    Table,
+}
+
+/// How `oxker status` prints its summary, for a desktop status bar
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum StatusFormat {
+    /// A line of json, for a waybar custom module with `"return-type": "json"`, its class is `unhealthy` while any container is unhealthy, else `ok`
+    Waybar,
+    /// The full text, the short text, & a red color while any container is unhealthy, a line each, for an i3blocks block
+    I3blocks,
+}
+
+/// A published port as `docker ps` shows it, `ip:public->private`, or just the private port
+fn port(port: &ContainerPorts) -> String {
+    match (port.ip.as_deref(), port.public) {
+        (Some(ip), Some(public)) => format!("{ip}:{public}->{}", port.private),
+        (None, Some(public)) => format!("{public}->{}", port.private),
+        _ => port.private.to_string(),
+    }
+}
+
+/// The state of a container, without the icon that the gui shows
+fn state(item: &ContainerItem) -> String {
+    format!("{:?}", item.state).to_lowercase()
+}
+
+fn json(items: &[&ContainerItem], stats: bool, time: i64) -> String {
+    let containers = items
+        .iter()
+        .map(|i| {
+            let mut value = json!({
+                "id": i.id.get(),
+                "name": i.name.get(),
+                "image": i.image.get(),
+                "state": state(i),
+                "status": i.status,
+                "host": i.host,
+                "ports": i.ports.iter().map(port).collect::<Vec<_>>(),
+            });
+            if stats {
+                value["cpu_percent"] = json!(i.cpu_stats.back().map(Stats::get_value));
+                value["memory_bytes"] = json!(i.mem_stats.back().map(|i| i.get()));
+                value["memory_limit_bytes"] = json!(i.mem_limit.get());
+                value["rx_bytes"] = json!(i.rx.get());
+                value["tx_bytes"] = json!(i.tx.get());
+            }
+            value
+        })
+        .collect::<Vec<_>>();
+    json!({
+        "time": format_rfc3339(time),
+        "containers": containers,
+    })
+    .to_string()
+}
+
+fn table(items: &[&ContainerItem], stats: bool) -> String {
+    let hosts = items.iter().any(|i| i.host.is_some());
+    let mut rows = vec![["NAME", "STATE", "STATUS", "IMAGE", "PORTS"]
+        .into_iter()
+        .chain(if stats {
+            vec!["CPU", "MEMORY", "LIMIT", "RX", "TX"]
+        } else {
+            vec![]
+        })
+        .chain(hosts.then_some("HOST"))
+        .map(ToOwned::to_owned)
+        .collect::<Vec<_>>()];
+    for i in items {
+        let mut row = vec![
+            i.name.get().to_owned(),
+            state(i),
+            i.status.clone(),
+            i.image.get().to_owned(),
+            i.ports.iter().map(port).collect::<Vec<_>>().join(", "),
+        ];
+        if stats {
+            row.extend([
+                i.cpu_stats
+                    .back()
+                    .map_or_else(String::new, ToString::to_string),
+                i.mem_stats
+                    .back()
+                    .map_or_else(String::new, ToString::to_string),
+                i.mem_limit.to_string(),
+                i.rx.to_string(),
+                i.tx.to_string(),
+            ]);
+        }
+        if hosts {
+            row.push(i.host.clone().unwrap_or_else(|| "local".to_owned()));
+        }
+        rows.push(row);
+    }
+
+    let widths = (0..rows[0].len())
+        .map(|column| {
+            rows.iter()
+                .map(|i| i[column].chars().count())
+                .max()
+                .unwrap_or_default()
+        })
+        .collect::<Vec<_>>();
+    let mut output = String::new();
+    for row in rows {
+        let mut line = String::new();
+        for (value, width) in row.iter().zip(&widths) {
+            let _ = write!(line, "{value:<width$}   ");
+        }
+        output.push_str(line.trim_end());
+        output.push('\n');
+    }
+    output
+}
+
+/// A summary of the containers, how many are running, & unhealthy, & the container using the most cpu, for a desktop status bar
+pub fn status(format: StatusFormat, items: &[&ContainerItem]) -> String {
+    let running = items.iter().filter(|i| i.state == State::Running).count();
+    let unhealthy = items
+        .iter()
+        .filter(|i| i.is_unhealthy())
+        .map(|i| i.name.get())
+        .collect::<Vec<_>>();
+    let top = items
+        .iter()
+        .filter_map(|i| i.cpu_stats.back().map(|cpu| (i, cpu.get_value())))
+        .max_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(i, cpu)| format!("{} {cpu:.1}%", i.name.get()));
+
+    let short = format!("{running}/{}", items.len());
+    let mut text = format!("{short} running");
+    if !unhealthy.is_empty() {
+        let _ = write!(text, ", {} unhealthy", unhealthy.len());
+    }
+    if let Some(top) = top.as_ref() {
+        let _ = write!(text, ", {top}");
+    }
+    match format {
+        StatusFormat::Waybar => {
+            let tooltip = [
+                Some(format!("{running} of {} containers running", items.len())),
+                (!unhealthy.is_empty()).then(|| format!("unhealthy: {}", unhealthy.join(", "))),
+                top.map(|i| format!("top cpu: {i}")),
+            ]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>()
+            .join("\n");
+            json!({
+                "text": text,
+                "tooltip": tooltip,
+                "class": if unhealthy.is_empty() { "ok" } else { "unhealthy" },
+            })
+            .to_string()
+        }
+        StatusFormat::I3blocks => {
+            let mut output = format!("{text}\n{short}");
+            if !unhealthy.is_empty() {
+                output.push_str("\n#FF0000");
+            }
+            output
+        }
+    }
+}
+
+/// Print the containers, with their latest stats if `stats`, at the unix timestamp `time`, json is a single line, so that each print on an interval is a line of its own
+pub fn render(format: OutputFormat, items: &[&ContainerItem], stats: bool, time: i64) -> String {
+    match format {
+        OutputFormat::Json => json(items, stats, time),
+        OutputFormat::Table => table(items, stats),
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use std::collections::VecDeque;
+
+    use super::{render, status, OutputFormat, StatusFormat};
+    use crate::app_data::{ByteStats, ContainerId, ContainerItem, ContainerPorts, CpuStats, State};
+
+    fn gen_items() -> Vec<ContainerItem> {
+        let mut web = ContainerItem::new(
+            1,
+            ContainerId::from("abc"),
+            "nginx:1.25".to_owned(),
+            false,
+            "web".to_owned(),
+            vec![ContainerPorts {
+                ip: Some("0.0.0.0".to_owned()),
+                private: 80,
+                public: Some(8080),
+            }],
+            State::Running,
+            "Up 2 hours".to_owned(),
+        );
+        web.cpu_stats = VecDeque::from([CpuStats::new(1.5)]);
+        web.mem_stats = VecDeque::from([ByteStats::new(2_000_000)]);
+        let db = ContainerItem::new(
+            2,
+            ContainerId::from("def"),
+            "postgres:16".to_owned(),
+            false,
+            "database".to_owned(),
+            vec![],
+            State::Exited,
+            "Exited (0) 5 minutes ago".to_owned(),
+        );
+        vec![web, db]
+    }
+
+    #[test]
+    /// Json is a single line, stats are only included when asked for, & are null when there are none yet
+    fn test_output_json() {
+        let items = gen_items();
+        let items = items.iter().collect::<Vec<_>>();
+        let output = render(OutputFormat::Json, &items, false, 0);
+        assert!(!output.contains('\n'));
+        let value = serde_json::from_str::<serde_json::Value>(&output).unwrap();
+        assert_eq!(value["time"], "1970-01-01T00:00:00Z");
+        assert_eq!(value["containers"][0]["name"], "web");
+        assert_eq!(value["containers"][0]["state"], "running");
+        assert_eq!(value["containers"][0]["ports"][0], "0.0.0.0:8080->80");
+        assert!(value["containers"][0].get("cpu_percent").is_none());
+
+        let output = render(OutputFormat::Json, &items, true, 0);
+        let value = serde_json::from_str::<serde_json::Value>(&output).unwrap();
+        assert_eq!(value["containers"][0]["cpu_percent"], 1.5);
+        assert_eq!(value["containers"][0]["memory_bytes"], 2_000_000);
+        assert!(value["containers"][1]["cpu_percent"].is_null());
+    }
+
+    #[test]
+    /// Columns are aligned to their widest value, the stats columns are only included when asked for
+    fn test_output_table() {
+        let items = gen_items();
+        let items = items.iter().collect::<Vec<_>>();
+        assert_eq!(
+            render(OutputFormat::Table, &items, false, 0),
+            "NAME       STATE     STATUS                     IMAGE         PORTS\nweb        running   Up 2 hours                 nginx:1.25    0.0.0.0:8080->80\ndatabase   exited    Exited (0) 5 minutes ago   postgres:16\n"
+        );
+        let output = render(OutputFormat::Table, &items, true, 0);
+        assert!(output
+            .lines()
+            .next()
+            .unwrap()
+            .ends_with("CPU      MEMORY    LIMIT     RX        TX"));
+        assert!(output.contains("01.50%   2.00 MB   0.00 kB   0.00 kB   0.00 kB"));
+    }
+    #[test]
+    /// The summary counts the running, & unhealthy, containers, & names the container using the most cpu, the unhealthy class, & color, are only set while any container is unhealthy
+    fn test_output_status() {
+        let mut items = gen_items();
+        let items_ref = items.iter().collect::<Vec<_>>();
+        let value =
+            serde_json::from_str::<serde_json::Value>(&status(StatusFormat::Waybar, &items_ref))
+                .unwrap();
+        assert_eq!(value["text"], "1/2 running, web 1.5%");
+        assert_eq!(
+            value["tooltip"],
+            "1 of 2 containers running\ntop cpu: web 1.5%"
+        );
+        assert_eq!(value["class"], "ok");
+
+        items[0].status = "Up 2 hours (unhealthy)".to_owned();
+        let items_ref = items.iter().collect::<Vec<_>>();
+        let value =
+            serde_json::from_str::<serde_json::Value>(&status(StatusFormat::Waybar, &items_ref))
+                .unwrap();
+        assert_eq!(value["text"], "1/2 running, 1 unhealthy, web 1.5%");
+        assert_eq!(value["class"], "unhealthy");
+        assert_eq!(
+            status(StatusFormat::I3blocks, &items_ref),
+            "1/2 running, 1 unhealthy, web 1.5%\n1/2\n#FF0000"
+        );
+        assert_eq!(status(StatusFormat::I3blocks, &[]), "0/0 running\n0/0");
+    }
+}
@@ -0,0 +1,224 @@
+use std::{collections::HashMap, fmt};
+
+use bollard::models::{Port, PortBinding};
+
+use super::{ContainerItem, State};
+
+/// A host port that a container publishes to, or would publish to once started
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HostPort {
+    /// Empty when bound to every interface
+    ip: String,
+    port: u16,
+    protocol: String,
+}
+
+impl HostPort {
+    /// A port of a running container, as listed, None if it isn't published
+    pub fn from_summary(port: &Port) -> Option<Self> {
+        Some(Self {
+            ip: port.ip.clone().unwrap_or_default(),
+            port: port.public_port?,
+            protocol: port.typ.map_or_else(|| "tcp".to_owned(), |i| i.to_string()),
+        })
+    }
+
+    /// The port bindings of an inspected container, keyed by `private/protocol`, a binding to a random host port is skipped, as its port isn't known until it's started
+    pub fn from_bindings(bindings: &HashMap<String, Option<Vec<PortBinding>>>) -> Vec<Self> {
+        let mut output = bindings
+            .iter()
+            .flat_map(|(key, bindings)| {
+                let protocol = key.split_once('/').map_or("tcp", |i| i.1);
+                bindings.iter().flatten().filter_map(move |binding| {
+                    Some(Self {
+                        ip: binding.host_ip.clone().unwrap_or_default(),
+                        port: binding
+                            .host_port
+                            .as_deref()?
+                            .parse::<u16>()
+                            .ok()
+                            .filter(|i| *i > 0)?,
+                        protocol: protocol.to_owned(),
+                    })
+                })
+            })
+            .collect::<Vec<_>>();
+        output.sort_by(|a, b| (a.port, &a.ip).cmp(&(b.port, &b.ip)));
+        output
+    }
+
+    /// A wildcard address of `0.0.0.0` overlaps every IPv4 address, `::`, by default, is dual stack, so overlaps every address
+    fn overlaps(&self, other: &Self) -> bool {
+        let any =
+            |a: &str, b: &str| a.is_empty() || a == "::" || a == "0.0.0.0" && !b.contains(':');
+        self.port == other.port
+            && self.protocol == other.protocol
+            && (self.ip == other.ip || any(&self.ip, &other.ip) || any(&other.ip, &self.ip))
+    }
+}
+
+/// A host port of a container that another container, on the same host, also publishes to
+/// If the other container is running, this container can't be started, or was started first & the other can't be, otherwise the two can't both be running
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PortConflict {
+    pub port: u16,
+    pub protocol: String,
+    pub with: String,
+    pub with_running: bool,
+}
+
+impl fmt::Display for PortConflict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}/{} {}", self.port, self.protocol, self.with)
+    }
+}
+
+/// The host ports of a container, as listed while it's running, or paused, otherwise as bound in its config, if it's been inspected
+fn host_ports(container: &ContainerItem) -> &[HostPort] {
+    match container.state {
+        State::Running | State::Paused => &container.published,
+        _ => container.bindings.as_deref().unwrap_or_default(),
+    }
+}
+
+/// Find the conflicts of each container, in the same order as the containers, only containers on the same host are compared
+pub fn find(containers: &[ContainerItem]) -> Vec<Vec<PortConflict>> {
+    containers
+        .iter()
+        .map(|container| {
+            let mut conflicts = vec![];
+            for other in containers
+                .iter()
+                .filter(|i| i.host == container.host && i.id != container.id)
+            {
+                for port in host_ports(container) {
+                    let conflict = host_ports(other).iter().any(|i| port.overlaps(i));
+                    if conflict
+                        && !conflicts.iter().any(|i: &PortConflict| {
+                            i.port == port.port
+                                && i.protocol == port.protocol
+                                && i.with == other.name.get()
+                        })
+                    {
+                        conflicts.push(PortConflict {
+                            port: port.port,
+                            protocol: port.protocol.clone(),
+                            with: other.name.get().to_owned(),
+                            with_running: matches!(other.state, State::Running | State::Paused),
+                        });
+                    }
+                }
+            }
+            conflicts.sort_by(|a, b| (a.port, &a.with).cmp(&(b.port, &b.with)));
+            conflicts
+        })
+        .collect()
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use std::collections::HashMap;
+
+    use bollard::models::{Port, PortBinding, PortTypeEnum};
+
+    use super::{find, HostPort, PortConflict};
+    use crate::app_data::{ContainerId, ContainerItem, State};
+
+    fn binding(ip: &str, port: &str) -> PortBinding {
+        PortBinding {
+            host_ip: Some(ip.to_owned()),
+            host_port: Some(port.to_owned()),
+        }
+    }
+
+    #[test]
+    /// Random host ports are skipped, the protocol is taken from the key
+    fn test_port_conflict_from_bindings() {
+        let bindings = HashMap::from([
+            (
+                "80/tcp".to_owned(),
+                Some(vec![binding("", "8080"), binding("", "")]),
+            ),
+            ("53/udp".to_owned(), Some(vec![binding("127.0.0.1", "53")])),
+            ("443/tcp".to_owned(), None),
+        ]);
+        let ports = HostPort::from_bindings(&bindings);
+        assert_eq!(ports.len(), 2);
+        assert_eq!(ports[0].port, 53);
+        assert_eq!(ports[0].protocol, "udp");
+        assert_eq!(ports[1].ip, "");
+
+        let port = Port {
+            ip: Some("0.0.0.0".to_owned()),
+            private_port: 80,
+            public_port: None,
+            typ: Some(PortTypeEnum::TCP),
+        };
+        assert!(HostPort::from_summary(&port).is_none());
+    }
+
+    #[test]
+    /// Wildcard addresses overlap, specific addresses only overlap themselves, & the protocol must match
+    fn test_port_conflict_overlaps() {
+        let port = |ip: &str, protocol: &str| HostPort {
+            ip: ip.to_owned(),
+            port: 80,
+            protocol: protocol.to_owned(),
+        };
+        assert!(port("0.0.0.0", "tcp").overlaps(&port("127.0.0.1", "tcp")));
+        assert!(port("::", "tcp").overlaps(&port("127.0.0.1", "tcp")));
+        assert!(port("10.0.0.1", "tcp").overlaps(&port("", "tcp")));
+        assert!(!port("0.0.0.0", "tcp").overlaps(&port("::1", "tcp")));
+        assert!(!port("127.0.0.1", "tcp").overlaps(&port("10.0.0.1", "tcp")));
+        assert!(!port("0.0.0.0", "tcp").overlaps(&port("0.0.0.0", "udp")));
+    }
+
+    #[test]
+    /// A stopped container conflicts with a running one, & with another stopped one, but not with one on another host
+    fn test_port_conflict_find() {
+        let container = |id: &str, state: State, port: u16| {
+            let mut item = ContainerItem::new(
+                0,
+                ContainerId::from(id),
+                "image".to_owned(),
+                false,
+                id.to_owned(),
+                vec![],
+                state,
+                String::new(),
+            );
+            let host_port = HostPort {
+                ip: "0.0.0.0".to_owned(),
+                port,
+                protocol: "tcp".to_owned(),
+            };
+            item.published = vec![host_port.clone()];
+            item.bindings = Some(vec![host_port]);
+            item
+        };
+        let mut remote = container("remote", State::Running, 80);
+        remote.host = Some("ssh://server".to_owned());
+        let containers = [
+            container("web", State::Running, 80),
+            container("old_web", State::Exited, 80),
+            container("db", State::Exited, 5432),
+            container("db_copy", State::Dead, 5432),
+            remote,
+        ];
+        let conflicts = find(&containers);
+        assert_eq!(
+            conflicts[0],
+            [PortConflict {
+                port: 80,
+                protocol: "tcp".to_owned(),
+                with: "old_web".to_owned(),
+                with_running: false,
+            }]
+        );
+        assert!(conflicts[1][0].with_running);
+        assert_eq!(conflicts[2][0].to_string(), "5432/tcp db_copy");
+        assert_eq!(conflicts[3][0].with, "db");
+        assert!(conflicts[4].is_empty());
+    }
+}
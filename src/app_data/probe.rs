@@ -0,0 +1,158 @@
+use std::fmt;
+
+use super::ContainerId;
+
+/// What a readiness probe checks
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProbeCheck {
+    /// An HTTP GET of the path, on the host port that the container port is published to, a 2xx or 3xx response is ready
+    Http { port: u16, path: String },
+    /// A TCP connection to the host port that the container port is published to
+    Tcp(u16),
+    /// A command exec'd in the container, exiting with 0 is ready
+    Exec(Vec<String>),
+}
+
+/// A readiness probe, `--probe [container]=[check]`, a container of `*` probes every container
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Probe {
+    container: Option<String>,
+    pub check: ProbeCheck,
+}
+
+impl Probe {
+    fn parse_port(input: &str) -> Result<u16, String> {
+        input
+            .parse::<u16>()
+            .ok()
+            .filter(|i| *i > 0)
+            .ok_or_else(|| format!("{input}: expected a port number"))
+    }
+
+    /// Parse a probe, `http:port[/path]`, `tcp:port`, or `exec:command`, the command is split on whitespace
+    pub fn parse(input: &str) -> Result<Self, String> {
+        let (container, check) = input
+            .split_once('=')
+            .ok_or_else(|| format!("{input}: expected [container]=[check]"))?;
+        let (kind, target) = check.split_once(':').ok_or_else(|| {
+            format!("{input}: expected http:port[/path], tcp:port, or exec:command")
+        })?;
+        let check = match kind.trim() {
+            "http" => {
+                let (port, path) = target
+                    .find('/')
+                    .map_or((target, "/"), |i| (&target[..i], &target[i..]));
+                ProbeCheck::Http {
+                    port: Self::parse_port(port)?,
+                    path: path.to_owned(),
+                }
+            }
+            "tcp" => ProbeCheck::Tcp(Self::parse_port(target)?),
+            "exec" => {
+                let command = target
+                    .split_whitespace()
+                    .map(ToOwned::to_owned)
+                    .collect::<Vec<_>>();
+                if command.is_empty() {
+                    return Err(format!("{input}: expected a command"));
+                }
+                ProbeCheck::Exec(command)
+            }
+            i => return Err(format!("{i}: expected http, tcp, or exec")),
+        };
+        Ok(Self {
+            container: Some(container.trim().to_owned()).filter(|i| !i.is_empty() && i != "*"),
+            check,
+        })
+    }
+
+    /// Find the probe for the named container, a probe for the container by name beats a `*` probe
+    pub fn find<'a>(probes: &'a [Self], name: &str) -> Option<&'a Self> {
+        probes
+            .iter()
+            .find(|i| i.container.as_deref() == Some(name))
+            .or_else(|| probes.iter().find(|i| i.container.is_none()))
+    }
+}
+
+/// A probe to run against a container, http & tcp checks connect to the published `address`, as (host, port)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProbeTarget {
+    pub address: Option<(String, u16)>,
+    pub check: ProbeCheck,
+    pub id: ContainerId,
+}
+
+/// The result of a container's readiness probe, for the readiness column
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Readiness {
+    /// The probe checks a container port that isn't published to the host
+    NoPort,
+    NotReady,
+    /// The probe hasn't finished yet
+    Pending,
+    Ready,
+}
+
+impl fmt::Display for Readiness {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let disp = match self {
+            Self::NoPort => "no port",
+            Self::NotReady => "not ready",
+            Self::Pending => "…",
+            Self::Ready => "ready",
+        };
+        write!(f, "{disp}")
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::{Probe, ProbeCheck};
+
+    #[test]
+    /// Each check kind is parsed, a probe for a container by name beats a `*` probe
+    fn test_probe_parse() {
+        let probes = [
+            Probe::parse("*=tcp:5432").unwrap(),
+            Probe::parse("api=http:8080").unwrap(),
+            Probe::parse("web=http:80/healthz?full=1").unwrap(),
+            Probe::parse("worker=exec: test -f  /tmp/ready").unwrap(),
+        ];
+        assert_eq!(
+            Probe::find(&probes, "api").unwrap().check,
+            ProbeCheck::Http {
+                port: 8080,
+                path: "/".to_owned()
+            }
+        );
+        assert_eq!(
+            Probe::find(&probes, "web").unwrap().check,
+            ProbeCheck::Http {
+                port: 80,
+                path: "/healthz?full=1".to_owned()
+            }
+        );
+        assert_eq!(
+            Probe::find(&probes, "worker").unwrap().check,
+            ProbeCheck::Exec(vec![
+                "test".to_owned(),
+                "-f".to_owned(),
+                "/tmp/ready".to_owned()
+            ])
+        );
+        assert_eq!(
+            Probe::find(&probes, "db").unwrap().check,
+            ProbeCheck::Tcp(5432)
+        );
+        assert!(Probe::find(&probes[1..], "db").is_none());
+
+        assert!(Probe::parse("api").is_err());
+        assert!(Probe::parse("api=8080").is_err());
+        assert!(Probe::parse("api=udp:53").is_err());
+        assert!(Probe::parse("api=tcp:0").is_err());
+        assert!(Probe::parse("api=http:port/health").is_err());
+        assert!(Probe::parse("api=exec:  ").is_err());
+    }
+}
@@ -0,0 +1,102 @@
+use std::{
+    collections::VecDeque,
+    time::{Duration, Instant},
+};
+
+use super::ContainerId;
+
+/// A container only counts as viewed once it has been selected for this long, so that scrolling past a container doesn't add it
+const DWELL: Duration = Duration::from_secs(1);
+
+/// Number of recently viewed containers kept
+const RECENT_LEN: usize = 10;
+
+/// The containers that were recently viewed, most recent first, not including the container that is being viewed now
+#[derive(Debug, Clone, Default)]
+pub struct Recent {
+    ids: VecDeque<ContainerId>,
+    /// The selected container, & when it was selected
+    viewing: Option<(ContainerId, Instant)>,
+}
+
+impl Recent {
+    fn push(&mut self, id: ContainerId) {
+        self.ids.retain(|i| i != &id);
+        self.ids.push_front(id);
+        self.ids.truncate(RECENT_LEN);
+    }
+
+    /// Track the selected container, the container that was being viewed is added, if it was viewed for long enough
+    pub fn update(&mut self, selected: Option<&ContainerId>, now: Instant) {
+        if self.viewing.as_ref().map(|i| &i.0) == selected {
+            return;
+        }
+        if let Some((id, since)) = self.viewing.take() {
+            if now.saturating_duration_since(since) >= DWELL {
+                self.push(id);
+            }
+        }
+        if let Some(selected) = selected {
+            self.ids.retain(|i| i != selected);
+            self.viewing = Some((selected.clone(), now));
+        }
+    }
+
+    /// Jump to a recent container, the container being viewed is always added, however briefly it was viewed, so that jumping again flips back to it
+    pub fn jump(&mut self, to: &ContainerId, now: Instant) {
+        if let Some((id, _)) = self.viewing.take() {
+            if &id != to {
+                self.push(id);
+            }
+        }
+        self.ids.retain(|i| i != to);
+        self.viewing = Some((to.clone(), now));
+    }
+
+    /// The recently viewed containers, most recent first
+    pub fn ids(&self) -> impl Iterator<Item = &ContainerId> {
+        self.ids.iter()
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use super::Recent;
+    use crate::app_data::ContainerId;
+
+    #[test]
+    /// Containers are only added once viewed for long enough, most recent first, the container being viewed isn't listed, & a jump always adds it
+    fn test_recent() {
+        let (a, b, c) = (
+            ContainerId::from("a"),
+            ContainerId::from("b"),
+            ContainerId::from("c"),
+        );
+        let ids = |recent: &Recent| recent.ids().cloned().collect::<Vec<_>>();
+        let start = Instant::now();
+        let secs = |i| start + Duration::from_secs(i);
+        let mut recent = Recent::default();
+
+        recent.update(Some(&a), start);
+        recent.update(Some(&a), secs(5));
+        // Scrolled past b
+        recent.update(Some(&b), secs(5));
+        recent.update(Some(&c), secs(5));
+        assert_eq!(ids(&recent), vec![a.clone()]);
+
+        recent.update(Some(&a), secs(10));
+        assert_eq!(ids(&recent), vec![c.clone()]);
+
+        recent.jump(&c, secs(10));
+        assert_eq!(ids(&recent), vec![a.clone()]);
+        recent.jump(&a, secs(10));
+        assert_eq!(ids(&recent), vec![c.clone()]);
+
+        recent.update(Some(&b), secs(20));
+        recent.update(None, secs(30));
+        assert_eq!(ids(&recent), vec![b, a, c]);
+    }
+}
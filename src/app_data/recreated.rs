@@ -0,0 +1,74 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    time::{Duration, Instant},
+};
+
+use super::{throughput::IoRates, ByteStats, ContainerId, ContainerItem, CpuStats};
+
+/// How long the state of a removed container is kept for its replacement, compose removes the old container, then renames the new container, which can be a few updates apart
+const CARRY_OVER: Duration = Duration::from_mins(1);
+
+/// The state of a removed container, carried over to the next container with the same name, on the same host, as when recreated by `docker compose up`
+#[derive(Debug, Clone)]
+pub struct Carried {
+    cpu_stats: VecDeque<CpuStats>,
+    /// The container selected once this container was removed, the selection only moves to the replacement if it's still selected
+    pub fallback: Option<ContainerId>,
+    pub id: ContainerId,
+    io_stats: VecDeque<IoRates>,
+    mem_stats: VecDeque<ByteStats>,
+    removed: Instant,
+    pub selected: bool,
+    stats_times: VecDeque<u64>,
+}
+
+impl Carried {
+    /// Move the chart history onto the new container, ahead of any samples that it already has, keeping at most `--chart-history` samples
+    pub fn apply(self, item: &mut ContainerItem, history: usize) {
+        fn prepend<T>(mut old: VecDeque<T>, new: &mut VecDeque<T>, history: usize) {
+            old.append(new);
+            old.drain(..old.len().saturating_sub(history));
+            *new = old;
+        }
+        prepend(self.cpu_stats, &mut item.cpu_stats, history);
+        prepend(self.io_stats, &mut item.io_stats, history);
+        prepend(self.mem_stats, &mut item.mem_stats, history);
+        prepend(self.stats_times, &mut item.stats_times, history);
+    }
+}
+
+/// Removed containers, by host & name, None is the primary host
+#[derive(Debug, Clone, Default)]
+pub struct Recreated {
+    removed: HashMap<(Option<String>, String), Carried>,
+}
+
+impl Recreated {
+    /// Keep the state of a removed container, along with the container selected in its place, expired containers are dropped
+    pub fn insert(&mut self, item: ContainerItem, selected: bool, fallback: Option<ContainerId>) {
+        self.removed.retain(|_, i| i.removed.elapsed() < CARRY_OVER);
+        self.removed.insert(
+            (item.host, item.name.get().to_owned()),
+            Carried {
+                cpu_stats: item.cpu_stats,
+                fallback,
+                id: item.id,
+                io_stats: item.io_stats,
+                mem_stats: item.mem_stats,
+                removed: Instant::now(),
+                selected,
+                stats_times: item.stats_times,
+            },
+        );
+    }
+
+    /// Take the state of a removed container, with the same host & name, unless it was removed too long ago
+    pub fn take(&mut self, host: Option<&str>, name: &str) -> Option<Carried> {
+        if self.removed.is_empty() {
+            return None;
+        }
+        self.removed
+            .remove(&(host.map(ToOwned::to_owned), name.to_owned()))
+            .filter(|i| i.removed.elapsed() < CARRY_OVER)
+    }
+}
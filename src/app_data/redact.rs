@@ -0,0 +1,96 @@
+use super::log_watch::Pattern;
+
+/// Built in rules, used by name, `--redact email`, as name, pattern, replacement
+const PRESETS: [(&str, &str, &str); 4] = [
+    ("email", r"[\w.+-]+@[\w-]+\.[\w.-]+", "<email>"),
+    ("ipv4", r"\d+\.\d+\.\d+\.\d+", "<ip>"),
+    ("jwt", r"eyJ[\w-]+\.[\w-]+\.[\w-]+", "<jwt>"),
+    ("bearer", r"(?i)bearer [\w.~+/-]+", "Bearer <token>"),
+];
+
+/// A redaction rule, `--redact [pattern]=[replacement]`, or the name of a preset, each match of the pattern is replaced in exported logs
+/// The replacement is after the last `=`, so that a pattern can contain a `=`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Redact {
+    pattern: Pattern,
+    replacement: String,
+}
+
+impl Redact {
+    pub fn parse(input: &str) -> Result<Self, String> {
+        if let Some((_, pattern, replacement)) = PRESETS.iter().find(|i| i.0 == input.trim()) {
+            return Ok(Self {
+                pattern: Pattern::new(pattern)?,
+                replacement: (*replacement).to_owned(),
+            });
+        }
+        let (pattern, replacement) = input.rsplit_once('=').ok_or_else(|| {
+            let names = PRESETS.iter().map(|i| i.0).collect::<Vec<_>>().join(", ");
+            format!("{input}: expected [pattern]=[replacement], or one of {names}")
+        })?;
+        Ok(Self {
+            pattern: Pattern::new(pattern).map_err(|e| format!("{input}: {e}"))?,
+            replacement: replacement.to_owned(),
+        })
+    }
+
+    /// Replace each match in the line
+    fn apply(&self, line: &str) -> String {
+        let mut output = String::with_capacity(line.len());
+        let mut last = 0;
+        for (from, to) in self.pattern.find_all(line) {
+            output.push_str(&line[last..from]);
+            output.push_str(&self.replacement);
+            last = to;
+        }
+        output.push_str(&line[last..]);
+        output
+    }
+}
+
+/// Apply each rule to a line, in the order given, so a later rule sees the replacements of the earlier rules
+pub fn redact(rules: &[Redact], line: &str) -> String {
+    rules
+        .iter()
+        .fold(line.to_owned(), |line, rule| rule.apply(&line))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::{redact, Redact};
+
+    #[test]
+    /// Presets by name, or a pattern with its replacement, a pattern can contain a `=`
+    fn test_redact_parse() {
+        assert!(Redact::parse("email").is_ok());
+        assert!(Redact::parse(" ipv4 ").is_ok());
+        let rule = Redact::parse("token=\\w+=<token>").unwrap();
+        assert_eq!(redact(&[rule], "login token=abc123 ok"), "login <token> ok");
+        assert!(Redact::parse("phone")
+            .unwrap_err()
+            .ends_with("or one of email, ipv4, jwt, bearer"));
+        assert!(Redact::parse("(a)=x").is_err());
+    }
+
+    #[test]
+    /// Every match is replaced, by each rule in turn
+    fn test_redact_redact() {
+        let rules = ["email", "ipv4", "jwt", "bearer", "secret-\\d+=***"]
+            .map(|i| Redact::parse(i).unwrap());
+        assert_eq!(
+            redact(
+                &rules,
+                "user a.b+c@mail.example.com from 10.0.0.12, and 192.168.1.1"
+            ),
+            "user <email> from <ip>, and <ip>"
+        );
+        assert_eq!(
+            redact(&rules, "Authorization: bearer abc.DEF-123/x secret-42"),
+            "Authorization: Bearer <token> ***"
+        );
+        assert_eq!(redact(&rules, "jwt eyJhbGc.eyJzdWI.SflKx"), "jwt <jwt>");
+        assert_eq!(redact(&rules, "nothing to hide"), "nothing to hide");
+        assert_eq!(redact(&[], "a@b.co"), "a@b.co");
+    }
+}
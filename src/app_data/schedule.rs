@@ -0,0 +1,282 @@
+use std::fmt;
+
+use super::ContainerId;
+
+/// Seconds in a day, a daily time, or a one-shot time that's already passed today, is moved on by this
+const DAY: u64 = 86_400;
+
+/// Shortest interval of a recurring schedule, so that a typo can't restart a container every second
+const MIN_EVERY: u64 = 60;
+
+/// What a schedule does to its container when it's due
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScheduleAction {
+    Restart,
+    SaveLogs,
+    Start,
+    Stop,
+}
+
+impl fmt::Display for ScheduleAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let disp = match self {
+            Self::Restart => "restart",
+            Self::SaveLogs => "save logs",
+            Self::Start => "start",
+            Self::Stop => "stop",
+        };
+        write!(f, "{disp}")
+    }
+}
+
+/// When a schedule is due, all times are UTC, as are the timestamps shown elsewhere
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScheduleWhen {
+    /// Run once, at this unix timestamp
+    Once(u64),
+    /// Run every this many seconds
+    Every(u64),
+    /// Run every day, at this many seconds after midnight
+    Daily(u64),
+}
+
+/// A duration, as one or more numbers with a unit, e.g. `90s`, `15m`, `2h30m`, or `1d`
+fn parse_duration(input: &str) -> Result<u64, String> {
+    let mut total = 0_u64;
+    let mut number = String::new();
+    for c in input.chars() {
+        if c.is_ascii_digit() {
+            number.push(c);
+            continue;
+        }
+        let unit = match c {
+            's' => 1,
+            'm' => 60,
+            'h' => 3600,
+            'd' => DAY,
+            _ => return Err(format!("{input}: expected a duration, e.g. 30m or 2h30m")),
+        };
+        let value = number
+            .parse::<u64>()
+            .map_err(|_| format!("{input}: expected a number before {c}"))?;
+        total = total.saturating_add(value.saturating_mul(unit));
+        number.clear();
+    }
+    if !number.is_empty() || total == 0 {
+        return Err(format!("{input}: expected a duration, e.g. 30m or 2h30m"));
+    }
+    Ok(total)
+}
+
+/// A time of day, `hh:mm`, as seconds after midnight
+fn parse_time(input: &str) -> Result<u64, String> {
+    let err = || format!("{input}: expected a time, e.g. 03:00");
+    let (hours, minutes) = input.split_once(':').ok_or_else(err)?;
+    let hours = hours.parse::<u64>().ok().filter(|i| *i < 24);
+    let minutes = minutes
+        .parse::<u64>()
+        .ok()
+        .filter(|i| *i < 60)
+        .filter(|_| minutes.len() == 2);
+    match (hours, minutes) {
+        (Some(hours), Some(minutes)) => Ok(hours * 3600 + minutes * 60),
+        _ => Err(err()),
+    }
+}
+
+/// The next unix timestamp, after now, that's the given number of seconds after midnight
+const fn next_time_of_day(now: u64, time: u64) -> u64 {
+    let next = now - now % DAY + time;
+    if next <= now {
+        next + DAY
+    } else {
+        next
+    }
+}
+
+/// A schedule that's due, resolved to the container it runs on
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScheduleRun {
+    pub key: usize,
+    pub id: ContainerId,
+    pub name: String,
+    pub action: ScheduleAction,
+}
+
+/// An action, run on a container, once or on repeat, while oxker is running, e.g. `restart at 03:00`, or `save logs every 1h`
+/// The container is found by name, so a schedule still applies after the container is recreated
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Schedule {
+    /// Unique for the session, so the outcome of a run can be set after the list has changed
+    pub key: usize,
+    pub name: String,
+    /// The extra host that the container is on, None for the primary host
+    pub host: Option<String>,
+    pub action: ScheduleAction,
+    pub when: ScheduleWhen,
+    /// As typed, shown in the schedules panel
+    pub input: String,
+    /// Unix timestamp of the next run, None once a one-shot schedule has run
+    pub next: Option<u64>,
+    /// Unix timestamp of the last run, & its outcome
+    pub last: Option<(u64, Result<(), String>)>,
+}
+
+impl Schedule {
+    /// Parse a schedule, `[action] at hh:mm`, `[action] in [duration]`, `[action] every [duration]`, or `[action] daily at hh:mm`
+    /// The actions are `restart`, `start`, `stop`, & `save logs`
+    pub fn parse(input: &str, now: u64) -> Result<(ScheduleAction, ScheduleWhen), String> {
+        let lower = input.trim().to_lowercase();
+        let words = lower.split_whitespace().collect::<Vec<_>>();
+        let (action, rest) = match words.as_slice() {
+            ["restart", rest @ ..] => (ScheduleAction::Restart, rest),
+            ["start", rest @ ..] => (ScheduleAction::Start, rest),
+            ["stop", rest @ ..] => (ScheduleAction::Stop, rest),
+            ["save", "logs", rest @ ..] => (ScheduleAction::SaveLogs, rest),
+            _ => {
+                return Err(format!(
+                    "{input}: expected restart, start, stop, or save logs"
+                ))
+            }
+        };
+        let when = match rest {
+            ["at", time] => ScheduleWhen::Once(next_time_of_day(now, parse_time(time)?)),
+            ["in", duration] => ScheduleWhen::Once(now.saturating_add(parse_duration(duration)?)),
+            ["every", duration] => {
+                let every = parse_duration(duration)?;
+                if every < MIN_EVERY {
+                    return Err(format!("{input}: expected at least every 1m"));
+                }
+                ScheduleWhen::Every(every)
+            }
+            ["daily", "at", time] => ScheduleWhen::Daily(parse_time(time)?),
+            _ => {
+                return Err(format!(
+                    "{input}: expected at hh:mm, in [duration], every [duration], or daily at hh:mm"
+                ))
+            }
+        };
+        Ok((action, when))
+    }
+
+    pub fn new(
+        key: usize,
+        name: String,
+        host: Option<String>,
+        input: &str,
+        now: u64,
+    ) -> Result<Self, String> {
+        let (action, when) = Self::parse(input, now)?;
+        let next = match when {
+            ScheduleWhen::Once(at) => at,
+            ScheduleWhen::Every(every) => now.saturating_add(every),
+            ScheduleWhen::Daily(time) => next_time_of_day(now, time),
+        };
+        Ok(Self {
+            key,
+            name,
+            host,
+            action,
+            when,
+            input: input.trim().to_owned(),
+            next: Some(next),
+            last: None,
+        })
+    }
+
+    /// Check if the schedule is due, and if so, move on to the next run, runs that were missed, e.g. while oxker was suspended, are skipped, rather than run back to back
+    pub fn take_due(&mut self, now: u64) -> bool {
+        if self.next.is_none_or(|next| next > now) {
+            return false;
+        }
+        self.next = match self.when {
+            ScheduleWhen::Once(_) => None,
+            ScheduleWhen::Every(every) => Some(now.saturating_add(every)),
+            ScheduleWhen::Daily(time) => Some(next_time_of_day(now, time)),
+        };
+        true
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::{Schedule, ScheduleAction, ScheduleWhen, DAY};
+
+    /// 2024-05-01T12:00:00Z
+    const NOW: u64 = 1_714_564_800;
+
+    #[test]
+    /// Each action, & when, is parsed, a time that's already passed today is tomorrow
+    fn test_schedule_parse() {
+        let midnight = NOW - NOW % DAY;
+        assert_eq!(
+            Schedule::parse("restart at 03:00", NOW),
+            Ok((
+                ScheduleAction::Restart,
+                ScheduleWhen::Once(midnight + DAY + 3 * 3600)
+            ))
+        );
+        assert_eq!(
+            Schedule::parse("  Stop at 18:30 ", NOW),
+            Ok((
+                ScheduleAction::Stop,
+                ScheduleWhen::Once(midnight + 18 * 3600 + 1800)
+            ))
+        );
+        assert_eq!(
+            Schedule::parse("start in 2h30m", NOW),
+            Ok((ScheduleAction::Start, ScheduleWhen::Once(NOW + 9000)))
+        );
+        assert_eq!(
+            Schedule::parse("save logs every 1h", NOW),
+            Ok((ScheduleAction::SaveLogs, ScheduleWhen::Every(3600)))
+        );
+        assert_eq!(
+            Schedule::parse("restart daily at 00:15", NOW),
+            Ok((ScheduleAction::Restart, ScheduleWhen::Daily(900)))
+        );
+    }
+
+    #[test]
+    /// Unknown actions, bad times & durations, and too short an interval, are errors
+    fn test_schedule_parse_err() {
+        for input in [
+            "",
+            "delete at 03:00",
+            "restart",
+            "restart at 3",
+            "restart at 24:00",
+            "restart at 03:0",
+            "restart in 5",
+            "restart in 5x",
+            "restart in 0m",
+            "save logs every 30s",
+            "restart every",
+        ] {
+            assert!(Schedule::parse(input, NOW).is_err(), "{input}");
+        }
+    }
+
+    #[test]
+    /// A one-shot schedule runs once, a recurring schedule moves on from now, skipping any missed runs
+    fn test_schedule_take_due() {
+        let mut once = Schedule::new(0, "api".to_owned(), None, "stop in 10m", NOW).unwrap();
+        assert!(!once.take_due(NOW + 599));
+        assert!(once.take_due(NOW + 600));
+        assert!(once.next.is_none());
+        assert!(!once.take_due(NOW + 1200));
+
+        let mut every =
+            Schedule::new(1, "api".to_owned(), None, "save logs every 1h", NOW).unwrap();
+        assert_eq!(every.next, Some(NOW + 3600));
+        assert!(every.take_due(NOW + 3 * 3600 + 5));
+        assert_eq!(every.next, Some(NOW + 4 * 3600 + 5));
+
+        let mut daily =
+            Schedule::new(2, "api".to_owned(), None, "restart daily at 12:00", NOW).unwrap();
+        assert_eq!(daily.next, Some(NOW + DAY));
+        assert!(daily.take_due(NOW + DAY + 60));
+        assert_eq!(daily.next, Some(NOW + 2 * DAY));
+    }
+}
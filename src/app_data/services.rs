@@ -0,0 +1,69 @@
+/// Length of the short service id, as shown by `docker service ls`
+const SHORT_ID: usize = 12;
+
+/// A swarm service, as listed in the services panel
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ServiceItem {
+    /// Tasks that should be running, the replicas of a replicated service, or a task per node of a global service
+    pub desired: u64,
+    /// A global service runs a task on every node, so can't be scaled
+    pub global: bool,
+    pub id: String,
+    /// The image of the service's tasks, without its digest
+    pub image: String,
+    pub name: String,
+    /// Tasks that are running
+    pub running: u64,
+    /// The stack that deployed the service, from its `com.docker.stack.namespace` label, None if it wasn't deployed with `docker stack deploy`
+    pub stack: Option<String>,
+    /// The state of the latest update, e.g. `updating`, or `rollback completed`, None if the service has never been updated
+    pub update: Option<String>,
+}
+
+impl ServiceItem {
+    /// The service id, truncated, as shown by `docker service ls`
+    pub fn short_id(&self) -> &str {
+        self.id.get(..SHORT_ID).unwrap_or(&self.id)
+    }
+
+    /// The running, & desired, tasks, as shown by `docker service ls`, e.g. `2/3`
+    pub fn replicas_text(&self) -> String {
+        format!("{}/{}", self.running, self.desired)
+    }
+
+    /// The armed removal of the service's stack, prefixed, so that it can't be taken for the armed force update of the service, None if it isn't in a stack
+    pub fn stack_armed_key(&self) -> Option<String> {
+        self.stack.as_ref().map(|i| format!("stack {i}"))
+    }
+
+    /// Fewer tasks are running than should be, e.g. while an update is rolling out, or a task keeps failing
+    pub const fn is_degraded(&self) -> bool {
+        self.running < self.desired
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ServiceItem;
+
+    #[test]
+    /// Replicas are shown as running of desired, fewer running than desired is degraded
+    fn test_service_item() {
+        let mut service = ServiceItem {
+            desired: 3,
+            global: false,
+            id: "q8k2xv1n4m7b9c3d5f6g".to_owned(),
+            image: "nginx:1.27".to_owned(),
+            name: "site_web".to_owned(),
+            running: 2,
+            stack: Some("site".to_owned()),
+            update: None,
+        };
+        assert_eq!(service.short_id(), "q8k2xv1n4m7b");
+        assert_eq!(service.replicas_text(), "2/3");
+        assert!(service.is_degraded());
+
+        service.running = 3;
+        assert!(!service.is_degraded());
+    }
+}
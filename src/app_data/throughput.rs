@@ -0,0 +1,181 @@
+use super::ByteStats;
+
+/// Network & disk throughput of a container, in bytes per second, between two samples of the daemon's counters
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct IoRates {
+    pub rx: ByteStats,
+    pub tx: ByteStats,
+    pub read: ByteStats,
+    pub write: ByteStats,
+}
+
+impl IoRates {
+    /// Combined network throughput, received & transmitted
+    pub const fn net(self) -> ByteStats {
+        ByteStats::new(self.rx.get() + self.tx.get())
+    }
+
+    /// Combined disk throughput, read & written
+    pub const fn disk(self) -> ByteStats {
+        ByteStats::new(self.read.get() + self.write.get())
+    }
+}
+
+/// The last network & block io counters from the daemon, each new sample is compared against these to get the throughput
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct IoCounters {
+    /// Nanosecond timestamp of the sample, & its rx, tx, read, & write, counters
+    last: Option<(i128, [u64; 4])>,
+}
+
+impl IoCounters {
+    /// Count a sample of the daemon's counters, the first sample only sets the start point, so has no rates
+    pub fn rates(&mut self, counters: [u64; 4], nanos: i128) -> Option<IoRates> {
+        let rates = self.last.and_then(|(last_nanos, last)| {
+            let elapsed = u128::try_from(nanos - last_nanos).ok().filter(|i| *i > 0)?;
+            // A counter lower than the last means the container has restarted, so everything in the new counter is new
+            let rate = |index: usize| {
+                let delta = counters[index]
+                    .checked_sub(last[index])
+                    .unwrap_or(counters[index]);
+                ByteStats::new(
+                    u64::try_from(u128::from(delta) * 1_000_000_000 / elapsed).unwrap_or(u64::MAX),
+                )
+            };
+            Some(IoRates {
+                rx: rate(0),
+                tx: rate(1),
+                read: rate(2),
+                write: rate(3),
+            })
+        });
+        self.last = Some((nanos, counters));
+        rates
+    }
+}
+
+/// Where the throughput is shown, cycled through off -> columns -> columns & charts -> off
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IoView {
+    Columns,
+    Charts,
+}
+
+impl IoView {
+    pub const fn next(current: Option<Self>) -> Option<Self> {
+        match current {
+            None => Some(Self::Columns),
+            Some(Self::Columns) => Some(Self::Charts),
+            Some(Self::Charts) => None,
+        }
+    }
+}
+
+/// The network & disk chart data, each dataset alongside the max shown value of the chart
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct IoChart {
+    pub rx: Vec<(f64, f64)>,
+    pub tx: Vec<(f64, f64)>,
+    pub net_max: ByteStats,
+    pub read: Vec<(f64, f64)>,
+    pub write: Vec<(f64, f64)>,
+    pub disk_max: ByteStats,
+    pub latest: IoRates,
+}
+
+impl From<&[IoRates]> for IoChart {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(samples: &[IoRates]) -> Self {
+        let dataset = |value: fn(&IoRates) -> ByteStats| {
+            samples
+                .iter()
+                .enumerate()
+                .map(|(index, i)| (index as f64, value(i).get() as f64))
+                .collect::<Vec<_>>()
+        };
+        let max = |value: fn(&IoRates) -> ByteStats, other: fn(&IoRates) -> ByteStats| {
+            samples
+                .iter()
+                .map(|i| value(i).max(other(i)))
+                .max()
+                .unwrap_or_default()
+        };
+        Self {
+            rx: dataset(|i| i.rx),
+            tx: dataset(|i| i.tx),
+            net_max: max(|i| i.rx, |i| i.tx),
+            read: dataset(|i| i.read),
+            write: dataset(|i| i.write),
+            disk_max: max(|i| i.read, |i| i.write),
+            latest: samples.last().copied().unwrap_or_default(),
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::{ByteStats, IoChart, IoCounters, IoRates, IoView};
+
+    #[test]
+    /// The first sample has no rates, later samples are per second, over the time between the samples, & a restart counts the whole new counter
+    fn test_io_counters_rates() {
+        let mut counters = IoCounters::default();
+        assert!(counters.rates([100, 100, 100, 100], 0).is_none());
+
+        let rates = counters
+            .rates([2100, 600, 100, 4100], 2_000_000_000)
+            .unwrap();
+        assert_eq!(rates.rx, ByteStats::new(1000));
+        assert_eq!(rates.tx, ByteStats::new(250));
+        assert_eq!(rates.read, ByteStats::new(0));
+        assert_eq!(rates.write, ByteStats::new(2000));
+        assert_eq!(rates.net(), ByteStats::new(1250));
+        assert_eq!(rates.disk(), ByteStats::new(2000));
+
+        // The same sample again has no time between, so no rates
+        assert!(counters
+            .rates([2100, 600, 100, 4100], 2_000_000_000)
+            .is_none());
+
+        let rates = counters.rates([50, 600, 100, 4100], 2_500_000_000).unwrap();
+        assert_eq!(rates.rx, ByteStats::new(100));
+        assert_eq!(rates.tx, ByteStats::new(0));
+    }
+
+    #[test]
+    /// The charts data has a dataset for each counter, & the max of both datasets of each chart
+    fn test_io_chart_from() {
+        let rates = [
+            IoRates {
+                rx: ByteStats::new(10),
+                tx: ByteStats::new(30),
+                read: ByteStats::new(5),
+                write: ByteStats::new(0),
+            },
+            IoRates {
+                rx: ByteStats::new(20),
+                tx: ByteStats::new(0),
+                read: ByteStats::new(0),
+                write: ByteStats::new(1),
+            },
+        ];
+        let chart = IoChart::from(&rates[..]);
+        assert_eq!(chart.rx, vec![(0.0, 10.0), (1.0, 20.0)]);
+        assert_eq!(chart.tx, vec![(0.0, 30.0), (1.0, 0.0)]);
+        assert_eq!(chart.net_max, ByteStats::new(30));
+        assert_eq!(chart.disk_max, ByteStats::new(5));
+        assert_eq!(chart.latest, rates[1]);
+
+        assert_eq!(IoChart::from(&[][..]), IoChart::default());
+    }
+
+    #[test]
+    fn test_io_view_next() {
+        let view = IoView::next(None);
+        assert_eq!(view, Some(IoView::Columns));
+        let view = IoView::next(view);
+        assert_eq!(view, Some(IoView::Charts));
+        assert_eq!(IoView::next(view), None);
+    }
+}
@@ -0,0 +1,70 @@
+use super::{images::in_use_text, ByteStats};
+
+/// Length of an anonymous volume's name, a random 64 character hex string
+const ANONYMOUS_LEN: usize = 64;
+
+/// Length of the truncated name of an anonymous volume
+const SHORT_NAME: usize = 12;
+
+/// A volume, as listed in the volumes panel
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VolumeItem {
+    /// Names of the containers, running or not, that mount the volume
+    pub containers: Vec<String>,
+    pub driver: String,
+    pub name: String,
+    /// Either `local` or `global`
+    pub scope: String,
+    /// Only known once the daemon's disk usage has been checked, and not reported by every volume driver
+    pub size: Option<ByteStats>,
+}
+
+impl VolumeItem {
+    /// An anonymous volume, e.g. from a `VOLUME` in an image, is named by a random hex string
+    pub fn is_anonymous(&self) -> bool {
+        self.name.len() == ANONYMOUS_LEN && self.name.chars().all(|i| i.is_ascii_hexdigit())
+    }
+
+    /// The name as shown in the volumes panel, an anonymous volume's name is truncated
+    pub fn short_name(&self) -> &str {
+        if self.is_anonymous() {
+            self.name.get(..SHORT_NAME).unwrap_or(&self.name)
+        } else {
+            &self.name
+        }
+    }
+
+    /// Whether the volume is in use, and by how many containers
+    pub fn state_text(&self) -> String {
+        in_use_text(self.containers.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::VolumeItem;
+
+    #[test]
+    /// Anonymous volumes are shown by a truncated name, named volumes are shown in full
+    fn test_volume_item() {
+        let gen_volume = |name: &str, containers: &[&str]| VolumeItem {
+            containers: containers.iter().map(|i| (*i).to_owned()).collect(),
+            driver: "local".to_owned(),
+            name: name.to_owned(),
+            scope: "local".to_owned(),
+            size: None,
+        };
+        let volume = gen_volume("site_db-data", &["site-db-1"]);
+        assert!(!volume.is_anonymous());
+        assert_eq!(volume.short_name(), "site_db-data");
+        assert_eq!(volume.state_text(), "1 container");
+
+        let volume = gen_volume(
+            "9f2c4b1a7e3d5c6b8a9f0e1d2c3b4a5f6e7d8c9b0a1f2e3d4c5b6a7f8e9d0c1b",
+            &[],
+        );
+        assert!(volume.is_anonymous());
+        assert_eq!(volume.short_name(), "9f2c4b1a7e3d");
+        assert_eq!(volume.state_text(), "unused");
+    }
+}
@@ -5,31 +5,88 @@ use std::fmt;
 #[allow(unused)]
 #[derive(Debug, Clone, Copy)]
 pub enum AppError {
+    BuildCache,
+    BuildCacheRemove,
+    Cleanup,
+    DockerAttach,
     DockerCommand(DockerControls),
     DockerExec,
     DockerLogs,
     DockerConnect,
     DockerInterval,
+    Events,
+    ExecLog,
+    Export,
+    ExportProject,
+    ImageInspect,
+    ImagePull,
+    ImageRemove,
     InputPoll,
+    Inspect,
     MouseCapture(bool),
+    NetworkRemove,
+    Prune,
+    SecretScan,
+    ServiceUpdate,
+    Snapshot,
+    SnapshotHost,
+    StackRemove,
+    Stats,
     Terminal,
+    Timeline,
+    VolumeRemove,
 }
 
 /// Convert errors into strings to display
 impl fmt::Display for AppError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
+            Self::BuildCache => write!(f, "Unable to get build cache"),
+            Self::BuildCacheRemove => {
+                write!(f, "Unable to remove build cache, requires the docker cli")
+            }
+            Self::Cleanup => write!(f, "Unable to get disk usage"),
+            Self::DockerAttach => write!(f, "Unable to attach to container"),
             Self::DockerCommand(s) => write!(f, "Unable to {s} container"),
             Self::DockerExec => write!(f, "Unable to exec into container"),
             Self::DockerLogs => write!(f, "Unable to save logs"),
             Self::DockerConnect => write!(f, "Unable to access docker daemon"),
             Self::DockerInterval => write!(f, "Docker update interval needs to be greater than 0"),
+            Self::Events => write!(f, "Unable to save events"),
+            Self::ExecLog => write!(f, "Unable to create the exec session log"),
+            Self::Export => write!(f, "Unable to export container"),
+            Self::ExportProject => write!(f, "Unable to export compose project logs"),
+            Self::ImageInspect => write!(f, "Unable to inspect image"),
+            Self::ImagePull => write!(f, "Unable to pull image"),
+            Self::ImageRemove => write!(f, "Unable to remove image, it may be used by a container"),
             Self::InputPoll => write!(f, "Unable to poll user input"),
+            Self::Inspect => write!(f, "Unable to inspect container"),
             Self::MouseCapture(x) => {
                 let reason = if *x { "en" } else { "dis" };
                 write!(f, "Unable to {reason}able mouse capture")
             }
+            Self::NetworkRemove => {
+                write!(f, "Unable to remove network, it may be used by a container")
+            }
+            Self::Prune => write!(f, "Unable to list images"),
+            Self::SecretScan => write!(f, "Unable to scan container environments"),
+            Self::ServiceUpdate => {
+                write!(
+                    f,
+                    "Unable to update service, it may have changed since it was listed"
+                )
+            }
+            Self::Snapshot => write!(f, "Unable to save snapshot"),
+            Self::SnapshotHost => write!(f, "Unable to save host snapshot"),
+            Self::StackRemove => {
+                write!(f, "Unable to remove stack, some of it may still be in use")
+            }
+            Self::Stats => write!(f, "Unable to save stats"),
             Self::Terminal => write!(f, "Unable to fully render to terminal"),
+            Self::Timeline => write!(f, "Unable to get container events"),
+            Self::VolumeRemove => {
+                write!(f, "Unable to remove volume, it may be used by a container")
+            }
         }
     }
 }
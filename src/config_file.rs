@@ -0,0 +1,1031 @@
+use std::{
+    ffi::OsString,
+    fmt,
+    path::{Path, PathBuf},
+};
+
+use clap::{
+    error::{ContextKind, ContextValue, ErrorKind},
+    parser::ValueSource,
+    ArgAction, ArgMatches, Command,
+};
+
+use crate::ui::Theme;
+
+/// Id of the argument that selects a profile
+const PROFILE: &str = "profile";
+
+/// A value in the config file, a subset of TOML, the only tables are profiles, & the ui colors, nested arrays aren't needed for any option
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Value {
+    Bool(bool),
+    Integer(i64),
+    String(String),
+    Array(Vec<Self>),
+}
+
+/// Why the config file can't be used, & where, so that the mistake can be shown as it is written in the file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigError {
+    /// None when there is no config file
+    pub path: Option<PathBuf>,
+    /// Number of the line, from 1, & the line as written
+    pub line: Option<(usize, String)>,
+    pub message: String,
+    /// The values that are expected, or the option that was probably meant
+    pub hint: Option<String>,
+}
+
+impl ConfigError {
+    fn new(message: impl Into<String>) -> Self {
+        Self {
+            path: None,
+            line: None,
+            message: message.into(),
+            hint: None,
+        }
+    }
+
+    /// The line is only a number until the error is given the file, with `in_file`
+    fn at(mut self, line: usize) -> Self {
+        self.line = Some((line, String::new()));
+        self
+    }
+
+    fn hint(mut self, hint: impl Into<String>) -> Self {
+        self.hint = Some(hint.into());
+        self
+    }
+
+    fn in_file(mut self, path: &Path, input: &str) -> Self {
+        self.path = Some(path.to_owned());
+        if let Some((line, text)) = self.line.as_mut() {
+            input
+                .lines()
+                .nth(line.saturating_sub(1))
+                .unwrap_or_default()
+                .trim_end()
+                .clone_into(text);
+        }
+        self
+    }
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(path) = &self.path {
+            write!(f, "{}: ", path.display())?;
+        }
+        if let Some((line, _)) = &self.line {
+            write!(f, "line {line}: ")?;
+        }
+        write!(f, "{}", self.message)?;
+        if let Some(hint) = &self.hint {
+            write!(f, ", {hint}")?;
+        }
+        Ok(())
+    }
+}
+
+/// An option of the config file, & the line that it's set on
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Entry {
+    key: String,
+    value: Value,
+    line: usize,
+}
+
+/// Default location of the config file, `$XDG_CONFIG_HOME/oxker/config.toml` on Linux
+pub fn path() -> Option<PathBuf> {
+    directories::BaseDirs::new().map(|i| {
+        i.config_dir()
+            .join(env!("CARGO_PKG_NAME"))
+            .join("config.toml")
+    })
+}
+
+/// Remove a trailing comment, a `#` inside a string isn't a comment
+fn strip_comment(line: &str) -> &str {
+    let mut quote = None;
+    let mut escaped = false;
+    for (index, c) in line.char_indices() {
+        match (quote, c) {
+            (Some('"'), '\\') if !escaped => {
+                escaped = true;
+                continue;
+            }
+            (Some(q), c) if c == q && !escaped => quote = None,
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '#') => return &line[..index],
+            _ => (),
+        }
+        escaped = false;
+    }
+    line
+}
+
+/// Parse a basic, `"..."`, or literal, `'...'`, string, returning the string, and the rest of the input
+fn parse_string(input: &str) -> Result<(String, &str), String> {
+    let mut chars = input.char_indices();
+    let quote = chars.next().map(|(_, c)| c).unwrap_or_default();
+    let mut output = String::new();
+    while let Some((index, c)) = chars.next() {
+        match c {
+            c if c == quote => return Ok((output, &input[index + 1..])),
+            '\\' if quote == '"' => match chars.next().map(|(_, c)| c) {
+                Some('n') => output.push('\n'),
+                Some('t') => output.push('\t'),
+                Some(c @ ('"' | '\\')) => output.push(c),
+                Some('u') => {
+                    let hex = chars.by_ref().take(4).map(|(_, c)| c).collect::<String>();
+                    let c = u32::from_str_radix(&hex, 16)
+                        .ok()
+                        .and_then(char::from_u32)
+                        .ok_or_else(|| format!("invalid escape \\u{hex}"))?;
+                    output.push(c);
+                }
+                Some(c) => return Err(format!("invalid escape \\{c}")),
+                None => break,
+            },
+            c => output.push(c),
+        }
+    }
+    Err("unterminated string".to_owned())
+}
+
+/// Parse a single value, returning the value, and the rest of the input
+fn parse_value(input: &str) -> Result<(Value, &str), String> {
+    let input = input.trim_start();
+    if input.starts_with(['"', '\'']) {
+        let (text, rest) = parse_string(input)?;
+        return Ok((Value::String(text), rest));
+    }
+    if let Some(mut rest) = input.strip_prefix('[') {
+        let mut items = vec![];
+        loop {
+            rest = rest.trim_start();
+            if let Some(rest) = rest.strip_prefix(']') {
+                return Ok((Value::Array(items), rest));
+            }
+            let (item, after) = parse_value(rest)?;
+            if matches!(item, Value::Array(_)) {
+                return Err("nested arrays aren't supported".to_owned());
+            }
+            items.push(item);
+            rest = after.trim_start();
+            if let Some(after) = rest.strip_prefix(',') {
+                rest = after;
+            } else if !rest.starts_with(']') {
+                return Err("expected , or ] in array".to_owned());
+            }
+        }
+    }
+    let end = input
+        .find(|c: char| c.is_whitespace() || c == ',' || c == ']')
+        .unwrap_or(input.len());
+    let (word, rest) = input.split_at(end);
+    let value = match word {
+        "true" => Value::Bool(true),
+        "false" => Value::Bool(false),
+        "" => return Err("expected a value".to_owned()),
+        x => Value::Integer(
+            x.replace('_', "")
+                .parse::<i64>()
+                .map_err(|_| format!("\"{x}\" is not a string, integer, boolean, or array"))?,
+        ),
+    };
+    Ok((value, rest))
+}
+
+/// Whether an array is still open at the end of the input, so that the value continues on the next line
+fn is_open_array(input: &str) -> bool {
+    let mut depth = 0_i32;
+    let mut quote = None;
+    let mut escaped = false;
+    for c in input.chars() {
+        match (quote, c) {
+            (Some('"'), '\\') if !escaped => {
+                escaped = true;
+                continue;
+            }
+            (Some(q), c) if c == q && !escaped => quote = None,
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '[') => depth += 1,
+            (None, ']') => depth -= 1,
+            _ => (),
+        }
+        escaped = false;
+    }
+    depth > 0
+}
+
+/// Id of the argument that the `[colors]` table is given to, as `element=color`
+const UI_COLOR: &str = "ui_color";
+
+/// The config file's options, the ui colors of the `[colors]` table, & the options of each `[profile.<name>]` table, in file order
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct Config {
+    colors: Vec<(String, String)>,
+    /// Line of the `[colors]` header
+    colors_line: usize,
+    entries: Vec<Entry>,
+    profiles: Vec<(String, Vec<Entry>)>,
+}
+
+/// Parse a `[profile.<name>]` table header, the name can be quoted, no other tables are supported
+fn parse_profile(line: &str) -> Result<String, String> {
+    let name = line
+        .strip_prefix('[')
+        .and_then(|i| i.strip_suffix(']'))
+        .map(str::trim)
+        .and_then(|i| i.strip_prefix("profile."))
+        .map(str::trim)
+        .ok_or_else(|| "only [colors], & [profile.<name>], tables are supported".to_owned())?;
+    let name = if name.starts_with(['"', '\'']) {
+        match parse_string(name)? {
+            (name, "") => name,
+            _ => return Err("invalid profile name".to_owned()),
+        }
+    } else {
+        name.to_owned()
+    };
+    if name.is_empty() || name.contains(char::is_whitespace) {
+        return Err("invalid profile name".to_owned());
+    }
+    Ok(name)
+}
+
+/// Parse the config file into its `key = value` pairs, in file order, an array can be split over multiple lines
+/// Pairs after a `[profile.<name>]` header belong to that profile, & pairs after the `[colors]` header are ui colors, until the next header
+fn parse(input: &str) -> Result<Config, ConfigError> {
+    let mut output = Config::default();
+    // If the pairs belong to the `[colors]` table, & if that table has been seen
+    let (mut in_colors, mut seen_colors) = (false, false);
+    let mut lines = input.lines().enumerate();
+    while let Some((index, line)) = lines.next() {
+        let line_number = index + 1;
+        let error = |e: String| ConfigError::new(e).at(line_number);
+        let mut line = strip_comment(line).trim().to_owned();
+        if line.is_empty() {
+            continue;
+        }
+        if line.starts_with('[') {
+            if line
+                .strip_prefix('[')
+                .and_then(|i| i.strip_suffix(']'))
+                .map(str::trim)
+                == Some("colors")
+            {
+                if seen_colors {
+                    return Err(error("colors is set more than once".to_owned()));
+                }
+                (in_colors, seen_colors) = (true, true);
+                output.colors_line = line_number;
+                continue;
+            }
+            in_colors = false;
+            let name = parse_profile(&line).map_err(error)?;
+            if output.profiles.iter().any(|(i, _)| i == &name) {
+                return Err(error(format!("profile {name} is set more than once")));
+            }
+            output.profiles.push((name, vec![]));
+            continue;
+        }
+        while is_open_array(&line) {
+            let Some((_, next)) = lines.next() else {
+                return Err(error("unterminated array".to_owned()));
+            };
+            line.push(' ');
+            line.push_str(strip_comment(next).trim());
+        }
+        let (key, value) = line
+            .split_once('=')
+            .ok_or_else(|| error("expected key = value".to_owned()))?;
+        let key = key.trim();
+        let key = if key.starts_with(['"', '\'']) {
+            match parse_string(key).map_err(error)? {
+                (key, "") => key,
+                _ => return Err(error("invalid key".to_owned())),
+            }
+        } else {
+            key.to_owned()
+        };
+        if key.is_empty() {
+            return Err(error("expected key = value".to_owned()));
+        }
+        let (value, rest) = parse_value(value).map_err(error)?;
+        if !rest.trim().is_empty() {
+            return Err(error(format!("unexpected \"{}\"", rest.trim())));
+        }
+        if in_colors {
+            let Value::String(color) = value else {
+                return Err(error(format!("{key} expects a color string")));
+            };
+            if output.colors.iter().any(|(i, _)| i == &key) {
+                return Err(error(format!("{key} is set more than once")));
+            }
+            // Checked here, rather than as a `--ui-color` argument, so that the line can be shown
+            Theme::default()
+                .set(&format!("{key}={color}"))
+                .map_err(error)?;
+            output.colors.push((key, color));
+            continue;
+        }
+        let entries = match output.profiles.last_mut() {
+            Some((_, entries)) => {
+                if key.replace('-', "_") == PROFILE {
+                    return Err(error("a profile can't select a profile".to_owned()));
+                }
+                entries
+            }
+            None => &mut output.entries,
+        };
+        if entries.iter().any(|i| i.key == key) {
+            return Err(error(format!("{key} is set more than once")));
+        }
+        entries.push(Entry {
+            key,
+            value,
+            line: line_number,
+        });
+    }
+    Ok(output)
+}
+
+/// The options, with those of the selected profile in place of the same options outside of the profile, & the `[colors]` table as a `ui_color` option
+/// The profile is the one given with `--profile`, else the `profile` option of the config file, if set
+fn select(mut config: Config, cli_profile: Option<&str>) -> Result<Vec<Entry>, ConfigError> {
+    let colors = Some(config.colors)
+        .filter(|i| !i.is_empty())
+        .map(|colors| Entry {
+            key: UI_COLOR.to_owned(),
+            value: Value::Array(
+                colors
+                    .into_iter()
+                    .map(|(element, color)| Value::String(format!("{element}={color}")))
+                    .collect(),
+            ),
+            line: config.colors_line,
+        });
+    let default_profile = config.entries.iter().find_map(|i| match &i.value {
+        Value::String(name) if i.key.replace('-', "_") == PROFILE => Some((name.clone(), i.line)),
+        _ => None,
+    });
+    let Some((name, line)) = cli_profile
+        .map(|i| (i.to_owned(), None))
+        .or_else(|| default_profile.map(|(name, line)| (name, Some(line))))
+    else {
+        return Ok(config.entries.into_iter().chain(colors).collect());
+    };
+    let Some(profile) = config
+        .profiles
+        .iter()
+        .position(|(i, _)| i == &name)
+        .map(|i| config.profiles.swap_remove(i).1)
+    else {
+        let mut error = ConfigError::new(format!("profile \"{name}\" not found"));
+        if let Some(line) = line {
+            error = error.at(line);
+        }
+        if !config.profiles.is_empty() {
+            let names = config
+                .profiles
+                .iter()
+                .map(|(i, _)| i.as_str())
+                .collect::<Vec<_>>();
+            error = error.hint(format!("expected one of {}", names.join(", ")));
+        }
+        return Err(error);
+    };
+    let keys = profile
+        .iter()
+        .map(|i| i.key.replace('-', "_"))
+        .collect::<Vec<_>>();
+    Ok(config
+        .entries
+        .into_iter()
+        .filter(|i| !keys.contains(&i.key.replace('-', "_")))
+        .chain(profile)
+        .chain(colors)
+        .collect())
+}
+
+/// Number of single character edits, insertions, deletions, or substitutions, between two keys
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut previous = (0..=b.len()).collect::<Vec<_>>();
+    for (i, x) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, y) in b.iter().enumerate() {
+            current.push(
+                (previous[j] + usize::from(x != *y))
+                    .min(previous[j + 1] + 1)
+                    .min(current[j] + 1),
+            );
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+/// The option that a misspelled key was probably meant to be, if one is within a couple of edits of it
+fn closest<'a>(key: &str, ids: impl Iterator<Item = &'a str>) -> Option<&'a str> {
+    ids.map(|i| (edit_distance(key, i), i))
+        .filter(|(distance, _)| *distance <= 2)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, i)| i)
+}
+
+/// Check a value by parsing the argument alone, as the command line would, so that the line of an invalid value can be shown, with the possible values, if there is a fixed set of them
+/// Only an invalid value is an error here, an option that needs another option is left to the full command line
+fn validate(
+    command: &mut Command,
+    key: &str,
+    argument: &str,
+    text: &str,
+) -> Result<(), ConfigError> {
+    let Err(e) = command.try_get_matches_from_mut([env!("CARGO_PKG_NAME"), argument]) else {
+        return Ok(());
+    };
+    if !matches!(
+        e.kind(),
+        ErrorKind::InvalidValue | ErrorKind::ValueValidation
+    ) {
+        return Ok(());
+    }
+    let reason = std::error::Error::source(&e)
+        .map(|i| format!(", {i}"))
+        .unwrap_or_default();
+    let error = ConfigError::new(format!("\"{text}\" is not a valid {key}{reason}"));
+    Err(match e.get(ContextKind::ValidValue) {
+        Some(ContextValue::Strings(values)) => {
+            error.hint(format!("expected one of {}", values.join(", ")))
+        }
+        _ => error,
+    })
+}
+
+/// Convert the config file's options into command line arguments, keys are the long argument names, with `-` or `_`
+/// An option that was also given on the command line, or that conflicts with one that was, is skipped, so that the command line takes precedence
+fn to_args(
+    command: &Command,
+    cli: &ArgMatches,
+    entries: Vec<Entry>,
+) -> Result<Vec<OsString>, ConfigError> {
+    let mut output = vec![];
+    let mut parser = command.clone();
+    let ids = || {
+        command
+            .get_arguments()
+            .map(|i| i.get_id().as_str())
+            .filter(|i| !["config", "help", "version"].contains(i))
+    };
+    for Entry { key, value, line } in entries {
+        let error = |e: String| ConfigError::new(e).at(line);
+        let id = key.replace('-', "_");
+        let Some(arg) = command
+            .get_arguments()
+            .find(|i| i.get_id() == id.as_str())
+            .filter(|_| ids().any(|i| i == id))
+        else {
+            let error = error(format!("{key} is not a valid option"));
+            return Err(match closest(&id, ids()) {
+                Some(i) => error.hint(format!("did you mean {i}")),
+                None => error,
+            });
+        };
+        let from_cli = |id: &str| cli.value_source(id) == Some(ValueSource::CommandLine);
+        if from_cli(&id)
+            || command
+                .get_arg_conflicts_with(arg)
+                .iter()
+                .any(|i| from_cli(i.get_id().as_str()))
+        {
+            continue;
+        }
+        let flag = arg.get_long().map_or_else(
+            || format!("-{}", arg.get_short().unwrap_or_default()),
+            |long| format!("--{long}"),
+        );
+        let takes_values = arg.get_action().takes_values();
+        let optional_value = arg.get_num_args().is_some_and(|i| i.min_values() == 0);
+        let mut scalar = |value: Value| {
+            let text = match value {
+                Value::String(text) => text,
+                Value::Integer(number) => number.to_string(),
+                Value::Bool(_) | Value::Array(_) => {
+                    return Err(error(format!("{key} expects a string or integer")))
+                }
+            };
+            let argument = format!("{flag}={text}");
+            validate(&mut parser, &key, &argument, &text).map_err(|e| e.at(line))?;
+            Ok(argument)
+        };
+        match value {
+            Value::Bool(true) if !takes_values || optional_value => output.push(flag.clone()),
+            Value::Bool(false) if !takes_values || optional_value => (),
+            _ if !takes_values => return Err(error(format!("{key} expects true or false"))),
+            Value::Array(items) if matches!(arg.get_action(), ArgAction::Append) => {
+                for item in items {
+                    output.push(scalar(item)?);
+                }
+            }
+            Value::Array(_) => return Err(error(format!("{key} expects a single value"))),
+            value => output.push(scalar(value)?),
+        }
+    }
+    Ok(output.into_iter().map(OsString::from).collect())
+}
+
+/// Arguments from the config file, the file given with `--config`, or the default file, if it exists
+/// A profile given with `--profile` must exist, even if the default file doesn't
+pub fn args(command: &Command, cli: &ArgMatches) -> Result<Vec<OsString>, ConfigError> {
+    let cli_profile = cli.get_one::<String>(PROFILE).map(String::as_str);
+    let (path, required) = cli.get_one::<String>("config").map_or_else(
+        || (path(), cli_profile.is_some()),
+        |i| (Some(PathBuf::from(i)), true),
+    );
+    let Some(path) = path else {
+        return cli_profile.map_or(Ok(vec![]), |name| {
+            Err(ConfigError::new(format!(
+                "profile \"{name}\" not found, no config file"
+            )))
+        });
+    };
+    let input = match std::fs::read_to_string(&path) {
+        Ok(input) => input,
+        Err(e) if !required && e.kind() == std::io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(e) => return Err(ConfigError::new(e.to_string()).in_file(&path, "")),
+    };
+    parse(&input)
+        .and_then(|config| select(config, cli_profile))
+        .and_then(|entries| to_args(command, cli, entries))
+        .map_err(|e| e.in_file(&path, &input))
+}
+
+/// Set, or with None remove, options outside of any table, keeping the rest of the file as written, a new option is added after the last one outside of a table
+/// The values are written as given, so a string must already be quoted
+fn set_options(input: &str, options: &[(&str, Option<String>)]) -> String {
+    let lines = input.lines().collect::<Vec<_>>();
+    // Each option outside of a table, with the lines of an array that continues over more than one line, options after the first header belong to that table
+    let mut entries = vec![];
+    let mut top = 0;
+    while top < lines.len() && !strip_comment(lines[top]).trim().starts_with('[') {
+        let mut end = top + 1;
+        let mut joined = strip_comment(lines[top]).trim().to_owned();
+        while end < lines.len() && is_open_array(&joined) {
+            joined.push(' ');
+            joined.push_str(strip_comment(lines[end]).trim());
+            end += 1;
+        }
+        entries.push(&lines[top..end]);
+        top = end;
+    }
+    let key = |line: &str| {
+        strip_comment(line)
+            .split_once('=')
+            .map(|(key, _)| key.trim().trim_matches(['"', '\'']).replace('-', "_"))
+    };
+    let line = |name: &str, value: &str| format!("{name} = {value}");
+    let mut written = vec![];
+    let mut head = vec![];
+    for entry in entries {
+        match options
+            .iter()
+            .find(|(name, _)| key(entry[0]).as_deref() == Some(*name))
+        {
+            Some((name, Some(value))) if !written.contains(name) => {
+                head.push(line(name, value));
+                written.push(*name);
+            }
+            // Removed, or set more than once
+            Some(_) => (),
+            None => head.extend(entry.iter().map(|i| (*i).to_owned())),
+        }
+    }
+    // New options go after the last option, ahead of the blank lines before the first table
+    let end = head
+        .iter()
+        .rposition(|i| !i.trim().is_empty())
+        .map_or(0, |i| i + 1);
+    let blank = head.split_off(end);
+    head.extend(options.iter().filter_map(|(name, value)| {
+        value
+            .as_ref()
+            .filter(|_| !written.contains(name))
+            .map(|value| line(name, value))
+    }));
+    if blank.is_empty() && !head.is_empty() && top < lines.len() {
+        head.push(String::new());
+    }
+    head.extend(blank);
+    head.extend(lines[top..].iter().map(|i| (*i).to_owned()));
+    let mut output = head.join("\n");
+    output.push('\n');
+    output
+}
+
+/// Save options into the config file, as `set_options`, the file, & its directory, are created if they don't exist
+/// A selected profile that sets the same options still takes precedence over them
+pub fn save(path: &Path, options: &[(&str, Option<String>)]) -> std::io::Result<()> {
+    let input = match std::fs::read_to_string(path) {
+        Ok(input) => input,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(e),
+    };
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(path, set_options(&input, options))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use clap::{CommandFactory, Parser};
+
+    use super::{parse, select, set_options, to_args, ConfigError, Value};
+    use crate::parse_args::Args;
+
+    #[test]
+    /// Strings, integers, booleans, & arrays, which can span lines, are parsed, comments are ignored
+    fn test_config_file_parse() {
+        let input = r#"
+# oxker config
+docker_interval = 2_000
+color = true # trailing comment
+save-dir = "/tmp/logs # not a comment"
+status_bar = '{host} \ {alerts}'
+"state-color" = "running=\"blue\""
+host = [
+    "unix:///var/run/docker.sock",
+    'ssh://user@remote', # the remote
+]
+only = []
+"#;
+        let result = parse(input).unwrap();
+        assert!(result.profiles.is_empty());
+        assert_eq!(
+            result
+                .entries
+                .into_iter()
+                .map(|i| (i.key, i.value))
+                .collect::<Vec<_>>(),
+            [
+                ("docker_interval".to_owned(), Value::Integer(2000)),
+                ("color".to_owned(), Value::Bool(true)),
+                (
+                    "save-dir".to_owned(),
+                    Value::String("/tmp/logs # not a comment".to_owned())
+                ),
+                (
+                    "status_bar".to_owned(),
+                    Value::String(r"{host} \ {alerts}".to_owned())
+                ),
+                (
+                    "state-color".to_owned(),
+                    Value::String("running=\"blue\"".to_owned())
+                ),
+                (
+                    "host".to_owned(),
+                    Value::Array(vec![
+                        Value::String("unix:///var/run/docker.sock".to_owned()),
+                        Value::String("ssh://user@remote".to_owned())
+                    ])
+                ),
+                ("only".to_owned(), Value::Array(vec![])),
+            ]
+        );
+
+        assert!(parse("[keys]")
+            .unwrap_err()
+            .to_string()
+            .starts_with("line 1:"));
+        assert!(parse("[profile.a b]").is_err());
+        assert!(parse("[profile.a]\n[profile.a]").is_err());
+        assert!(parse("[profile.a]\nprofile = \"b\"").is_err());
+        assert!(parse("color = true\ncolor = false").is_err());
+        assert!(parse("host = [\"a\"").is_err());
+        assert!(parse("save_dir = \"/tmp").is_err());
+        assert!(parse("save_dir = /tmp").is_err());
+        assert!(parse("host = [[\"a\"]]").is_err());
+        assert!(parse("color true").is_err());
+    }
+
+    #[test]
+    /// Options become arguments, those given on the command line, or that conflict with the command line, are skipped
+    fn test_config_file_to_args() {
+        let mut command = Args::command();
+        command.build();
+        let cli = Args::command().get_matches_from(["oxker", "-d", "500", "-r"]);
+        let entries = parse(
+            r#"
+docker_interval = 2000
+color = true
+use-cli = false
+host = ["one", "two"]
+theme = "protanopia"
+status_bar = true
+"#,
+        )
+        .unwrap()
+        .entries;
+        let result = to_args(&command, &cli, entries).unwrap();
+        assert_eq!(
+            result,
+            [
+                "--host=one",
+                "--host=two",
+                "--theme=protanopia",
+                "--status-bar"
+            ]
+        );
+        let args = Args::parse_from(std::iter::once("oxker".into()).chain(result).chain([
+            "-d".into(),
+            "500".into(),
+            "-r".into(),
+        ]));
+        assert_eq!(args.docker_interval, 500);
+        assert_eq!(args.host, ["one", "two"]);
+        assert!(args.raw);
+        assert!(!args.color);
+
+        let check = |input: &str| to_args(&command, &cli, parse(input).unwrap().entries);
+        assert_eq!(
+            check("d = 1").unwrap_err().to_string(),
+            "line 1: d is not a valid option"
+        );
+        assert!(check("config = \"other.toml\"").is_err());
+        assert!(check("command = \"wait\"").is_err());
+        assert!(check("use_cli = \"yes\"").is_err());
+        assert!(check("theme = [\"default\"]").is_err());
+        assert!(check("save_dir = true").is_err());
+        assert_eq!(
+            check("save_dir = \"/tmp\"\nmax_line_length = 80").unwrap(),
+            ["--save-dir=/tmp", "--max-line-length=80"]
+        );
+        assert_eq!(check("timestamp = true").unwrap(), ["-t"]);
+
+        let cli = Args::command().get_matches_from(["oxker"]);
+        let result = to_args(
+            &command,
+            &cli,
+            parse("docker_interval = 2000").unwrap().entries,
+        )
+        .unwrap();
+        assert_eq!(result, ["-d=2000"]);
+        let args = Args::parse_from(std::iter::once("oxker".into()).chain(result));
+        assert_eq!(args.docker_interval, 2000);
+    }
+
+    #[test]
+    /// The selected profile's options take the place of the same options outside of the profile, the profile is from the command line, else the config file
+    fn test_config_file_select() {
+        let input = r#"
+profile = "dev"
+host = "unix:///var/run/docker.sock"
+show-hidden = true
+
+[profile.dev]
+accent = "green"
+
+[profile."prod"]
+host = "ssh://deploy@prod" # the prod host
+read_only = true
+accent = "red"
+"#;
+        let config = parse(input).unwrap();
+        assert_eq!(config.profiles.len(), 2);
+        let keys = |name: Option<&str>| {
+            select(config.clone(), name)
+                .unwrap()
+                .into_iter()
+                .map(|i| match i.value {
+                    Value::String(text) => format!("{}={text}", i.key),
+                    value => format!("{}={value:?}", i.key),
+                })
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            keys(None),
+            [
+                "profile=dev",
+                "host=unix:///var/run/docker.sock",
+                "show-hidden=Bool(true)",
+                "accent=green"
+            ]
+        );
+        assert_eq!(
+            keys(Some("prod")),
+            [
+                "profile=dev",
+                "show-hidden=Bool(true)",
+                "host=ssh://deploy@prod",
+                "read_only=Bool(true)",
+                "accent=red"
+            ]
+        );
+        assert_eq!(
+            select(config, Some("staging")).unwrap_err().to_string(),
+            "profile \"staging\" not found, expected one of dev, prod"
+        );
+        let config = parse("color = true\n[profile.prod]\nread_only = true").unwrap();
+        assert_eq!(select(config.clone(), None).unwrap().len(), 1);
+
+        let mut command = Args::command();
+        command.build();
+        let cli = Args::command().get_matches_from(["oxker", "--profile", "prod"]);
+        let result = to_args(&command, &cli, select(config, Some("prod")).unwrap()).unwrap();
+        assert_eq!(result, ["-c", "--read-only"]);
+        let args = Args::parse_from(std::iter::once("oxker".into()).chain(result));
+        assert!(args.read_only);
+    }
+
+    #[test]
+    /// The `[colors]` table becomes `--ui-color` arguments, after the selected profile, its values must be strings
+    fn test_config_file_colors() {
+        let input = r##"
+theme = "light"
+
+[colors]
+border = "blue"
+popup-bg = "#eeeeee"
+
+[profile.prod]
+accent = "red"
+"##;
+        let config = parse(input).unwrap();
+        assert_eq!(config.colors.len(), 2);
+        assert_eq!(config.profiles[0].1.len(), 1);
+
+        let mut command = Args::command();
+        command.build();
+        let cli = Args::command().get_matches_from(["oxker"]);
+        let result = to_args(&command, &cli, select(config, Some("prod")).unwrap()).unwrap();
+        assert_eq!(
+            result,
+            [
+                "--theme=light",
+                "--accent=red",
+                "--ui-color=border=blue",
+                "--ui-color=popup-bg=#eeeeee"
+            ]
+        );
+        let args = Args::parse_from(std::iter::once("oxker".into()).chain(result));
+        assert_eq!(args.ui_color, ["border=blue", "popup-bg=#eeeeee"]);
+
+        assert!(parse(
+            "[colors]
+border = 4"
+        )
+        .is_err());
+        assert_eq!(
+            parse("[colors]\nborders = \"red\"")
+                .unwrap_err()
+                .to_string(),
+            "line 2: \"borders\" is not a valid element, expected one of accent, alert, border, chart_cpu, chart_memory, chart_read, chart_rx, chart_tx, chart_write, error, header_fg, header_highlight, highlight, log_text, ok, popup_bg, popup_fg, selected, text, warning"
+        );
+        assert!(parse(
+            "[colors]
+border = \"red\"
+border = \"blue\""
+        )
+        .is_err());
+        assert!(parse(
+            "[colors]
+[profile.a]
+[colors]"
+        )
+        .is_err());
+    }
+
+    #[test]
+    /// Errors point to the line of the option, with the line as written, values are checked as the command line would, & the expected values, or the option that was probably meant, are hinted
+    fn test_config_file_errors() {
+        let mut command = Args::command();
+        command.build();
+        let cli = Args::command().get_matches_from(["oxker"]);
+        let check = |input: &str| {
+            parse(input)
+                .and_then(|config| select(config, None))
+                .and_then(|entries| to_args(&command, &cli, entries))
+                .map_err(|e| e.in_file(std::path::Path::new("config.toml"), input))
+                .unwrap_err()
+        };
+
+        let error = check("color = true\n\ndocker_intervl = 2000 # typo");
+        assert_eq!(
+            error,
+            ConfigError {
+                path: Some("config.toml".into()),
+                line: Some((3, "docker_intervl = 2000 # typo".to_owned())),
+                message: "docker_intervl is not a valid option".to_owned(),
+                hint: Some("did you mean docker_interval".to_owned()),
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "config.toml: line 3: docker_intervl is not a valid option, did you mean docker_interval"
+        );
+        assert!(check("zzzzzz = 1").hint.is_none());
+
+        let error = check("theme = \"purple\"");
+        assert_eq!(error.message, "\"purple\" is not a valid theme");
+        assert!(error
+            .hint
+            .unwrap()
+            .starts_with("expected one of default, light, solarized"));
+
+        let error = check("color = true\ndocker_interval = -1");
+        assert_eq!(error.line.unwrap().0, 2);
+        assert!(error
+            .message
+            .starts_with("\"-1\" is not a valid docker_interval, "));
+
+        let error = check("profile = \"stage\"\n[profile.staging]");
+        assert_eq!(error.line.unwrap(), (1, "profile = \"stage\"".to_owned()));
+        assert_eq!(error.hint.unwrap(), "expected one of staging");
+    }
+
+    #[test]
+    /// Options outside of a table are replaced, or removed, in place, & new options are added before the first table
+    fn test_config_file_set_options() {
+        let input = "# oxker config
+theme = \"light\"
+charts-height = 40 # taller charts
+hide_charts = true
+
+[profile.prod]
+containers_height = 6
+";
+        let options = [
+            ("containers_height", Some("12".to_owned())),
+            ("charts_height", Some("50".to_owned())),
+            ("hide_charts", None),
+        ];
+        let output = set_options(input, &options);
+        assert_eq!(
+            output,
+            "# oxker config
+theme = \"light\"
+charts_height = 50
+containers_height = 12
+
+[profile.prod]
+containers_height = 6
+"
+        );
+        let config = parse(&output).unwrap();
+        assert_eq!(config.entries.len(), 3);
+        assert_eq!(config.profiles[0].1.len(), 1);
+
+        assert_eq!(set_options(&output, &options), output);
+        assert_eq!(
+            set_options("", &options),
+            "containers_height = 12\ncharts_height = 50\n"
+        );
+        assert_eq!(
+            set_options("[colors]\nborder = \"red\"\n", &options[2..]),
+            "[colors]\nborder = \"red\"\n"
+        );
+    }
+
+    #[test]
+    /// An array over more than one line is replaced, or removed, as a whole, its lines aren't taken for a table header, or other options
+    fn test_config_file_set_options_multi_line_array() {
+        let input = "watch = [
+  \"*=panic\",
+  [\"api=timeout\"], # nested
+]
+theme = \"light\"
+only = [
+  \"api\",
+]
+
+[profile.prod]
+only = [\"web\"]
+";
+        let output = set_options(
+            input,
+            &[("watch", Some("[\"*=oom\"]".to_owned())), ("only", None)],
+        );
+        assert_eq!(
+            output,
+            "watch = [\"*=oom\"]
+theme = \"light\"
+
+[profile.prod]
+only = [\"web\"]
+"
+        );
+        let config = parse(&output).unwrap();
+        assert_eq!(config.entries.len(), 2);
+        assert_eq!(config.profiles[0].1.len(), 1);
+
+        // Untouched, the array is kept as written
+        assert_eq!(
+            set_options(input, &[("theme", None)]),
+            input.replace("theme = \"light\"\n", "")
+        );
+    }
+}
@@ -0,0 +1,93 @@
+use bollard::service::SystemDataUsageResponse;
+use tokio::process::Command;
+
+use super::parse_rfc3339;
+use crate::{exec::command::DOCKER, ui::CacheRecord};
+
+/// The build cache records of the daemon's disk usage, empty if buildkit has never been used
+pub fn records(usage: &SystemDataUsageResponse) -> Vec<CacheRecord> {
+    usage
+        .build_cache
+        .iter()
+        .flatten()
+        .filter_map(|i| {
+            Some(CacheRecord {
+                description: i.description.clone().unwrap_or_default(),
+                id: i.id.clone()?,
+                in_use: i.in_use.unwrap_or_default(),
+                last_used: i.last_used_at.as_deref().and_then(parse_rfc3339),
+                shared: i.shared.unwrap_or_default(),
+                size: i
+                    .size
+                    .and_then(|i| u64::try_from(i).ok())
+                    .unwrap_or_default(),
+                usage_count: i.usage_count.unwrap_or_default(),
+            })
+        })
+        .collect()
+}
+
+/// The `DOCKER_HOST` for the docker cli, a bare socket path is given the `unix://` scheme that the cli expects
+pub fn cli_host(host: &str) -> String {
+    if host.starts_with('/') {
+        format!("unix://{host}")
+    } else {
+        host.to_owned()
+    }
+}
+
+/// Remove build cache records, the API version that bollard supports has no build prune endpoint, so the docker cli is used
+/// Returns true if the cli exited successfully
+pub async fn remove(host: Option<&str>, ids: &[String]) -> bool {
+    let mut command = Command::new(DOCKER);
+    command.args(["builder", "prune", "--force"]);
+    for id in ids {
+        command.arg("--filter").arg(format!("id={id}"));
+    }
+    if let Some(host) = host {
+        command.env("DOCKER_HOST", cli_host(host));
+    }
+    command
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .status()
+        .await
+        .is_ok_and(|i| i.success())
+}
+
+#[cfg(test)]
+mod tests {
+    use bollard::service::{BuildCache, SystemDataUsageResponse};
+
+    use super::{cli_host, records};
+
+    #[test]
+    /// Records without an id are skipped, the last used time is parsed, & socket paths are given a scheme for the cli
+    fn test_build_cache_records() {
+        let usage = SystemDataUsageResponse {
+            build_cache: Some(vec![
+                BuildCache {
+                    id: Some("abc".to_owned()),
+                    description: Some("mount / from exec /bin/sh -c apt-get update".to_owned()),
+                    size: Some(4096),
+                    last_used_at: Some("2024-05-01T12:34:56.123456789Z".to_owned()),
+                    usage_count: Some(3),
+                    ..Default::default()
+                },
+                BuildCache::default(),
+            ]),
+            ..Default::default()
+        };
+        let result = records(&usage);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].size, 4096);
+        assert_eq!(result[0].last_used, Some(1_714_566_896));
+        assert_eq!(result[0].usage_count, 3);
+
+        assert_eq!(
+            cli_host("/var/run/docker.sock"),
+            "unix:///var/run/docker.sock"
+        );
+        assert_eq!(cli_host("ssh://user@host"), "ssh://user@host");
+    }
+}
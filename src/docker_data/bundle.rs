@@ -0,0 +1,144 @@
+use std::{
+    fmt::Write as _,
+    path::{Path, PathBuf},
+};
+
+use super::parse_rfc3339_nanos;
+
+/// Name of the merged log file, in the bundle directory
+const TIMELINE_FILE: &str = "timeline.log";
+
+/// The logs of a single container of a compose project, each line starts with its timestamp, without any ansi formatting
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContainerLogs {
+    pub name: String,
+    pub lines: Vec<String>,
+}
+
+/// Container, and project, names as file names, anything that isn't safe in a file name is replaced with `_`
+pub fn file_name(name: &str) -> String {
+    name.chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || "._-".contains(c) {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+/// Every line of every container, oldest first, with the name of the container it was logged by, e.g. `2024-05-01T12:34:56.123456789Z api | listening`
+/// Lines logged at the same time are kept in container, then log, order, lines without a timestamp are first
+pub fn to_timeline(containers: &[ContainerLogs]) -> String {
+    let width = containers
+        .iter()
+        .map(|i| i.name.chars().count())
+        .max()
+        .unwrap_or_default();
+    let mut lines = containers
+        .iter()
+        .flat_map(|container| {
+            container.lines.iter().map(|line| {
+                let (timestamp, text) = line.split_once(' ').unwrap_or(("", line));
+                (
+                    parse_rfc3339_nanos(timestamp),
+                    timestamp,
+                    &container.name,
+                    text,
+                )
+            })
+        })
+        .collect::<Vec<_>>();
+    lines.sort_by_key(|i| i.0);
+    lines
+        .into_iter()
+        .fold(String::new(), |mut output, (_, timestamp, name, text)| {
+            writeln!(output, "{timestamp} {name:<width$} | {text}").ok();
+            output
+        })
+}
+
+/// Save the logs of each container, as `[container_name].log`, and every container's logs merged into a single `timeline.log`
+/// The files are saved into a new `[project]_[timestamp]` directory, which is returned
+pub fn save(
+    dir: &Path,
+    project: &str,
+    timestamp: u64,
+    containers: &[ContainerLogs],
+) -> std::io::Result<PathBuf> {
+    let dir = dir.join(format!("{}_{timestamp}", file_name(project)));
+    std::fs::create_dir_all(&dir)?;
+    for container in containers {
+        let mut output = container.lines.join("\n");
+        output.push('\n');
+        std::fs::write(
+            dir.join(format!("{}.log", file_name(&container.name))),
+            output,
+        )?;
+    }
+    std::fs::write(dir.join(TIMELINE_FILE), to_timeline(containers))?;
+    Ok(dir)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::{file_name, save, to_timeline, ContainerLogs, TIMELINE_FILE};
+
+    fn gen_logs() -> Vec<ContainerLogs> {
+        vec![
+            ContainerLogs {
+                name: "site-api-1".to_owned(),
+                lines: vec![
+                    "2024-05-01T12:34:56.5Z connecting".to_owned(),
+                    "2024-05-01T12:34:58.000000001Z connected".to_owned(),
+                ],
+            },
+            ContainerLogs {
+                name: "site-db-1".to_owned(),
+                lines: vec![
+                    "2024-05-01T12:34:56.123456789Z starting".to_owned(),
+                    "2024-05-01T12:34:58Z ready".to_owned(),
+                ],
+            },
+        ]
+    }
+
+    #[test]
+    /// Lines are merged in timestamp order, including fractional seconds of differing lengths
+    fn test_bundle_timeline() {
+        assert_eq!(
+            to_timeline(&gen_logs()),
+            [
+                "2024-05-01T12:34:56.123456789Z site-db-1  | starting",
+                "2024-05-01T12:34:56.5Z site-api-1 | connecting",
+                "2024-05-01T12:34:58Z site-db-1  | ready",
+                "2024-05-01T12:34:58.000000001Z site-api-1 | connected",
+                "",
+            ]
+            .join("\n")
+        );
+        assert_eq!(file_name("my project/web:1"), "my_project_web_1");
+    }
+
+    #[test]
+    /// Each container's logs, and the timeline, are saved into a timestamped project directory
+    fn test_bundle_save() {
+        let dir = std::env::temp_dir().join(format!("oxker-bundle-{}", uuid::Uuid::new_v4()));
+        let path = save(&dir, "site", 1_714_566_896, &gen_logs()).unwrap();
+        assert_eq!(path, dir.join("site_1714566896"));
+        assert_eq!(
+            std::fs::read_to_string(path.join("site-db-1.log")).unwrap(),
+            "2024-05-01T12:34:56.123456789Z starting\n2024-05-01T12:34:58Z ready\n"
+        );
+        assert_eq!(
+            std::fs::read_to_string(path.join(TIMELINE_FILE))
+                .unwrap()
+                .lines()
+                .count(),
+            4
+        );
+        std::fs::remove_dir_all(dir).ok();
+    }
+}
@@ -0,0 +1,435 @@
+use std::{
+    io::Write,
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr},
+    path::Path,
+    sync::Arc,
+};
+
+use bollard::{
+    container::{
+        AttachContainerOptions, Config, CreateContainerOptions, LogOutput, RemoveContainerOptions,
+        StartContainerOptions,
+    },
+    service::HostConfig,
+    Docker,
+};
+use futures_util::StreamExt;
+use parking_lot::Mutex;
+use tokio::sync::Notify;
+
+use crate::{
+    app_data::ContainerId,
+    ui::{Capture, GuiState},
+};
+
+/// The image of the capture container, it has tcpdump, & runs in the network namespace of the captured container, as `nsenter --net` would
+pub const CAPTURE_IMAGE: &str = "nicolaka/netshoot:latest";
+
+/// A record larger than tcpdump's default snapshot length can only be a stream that isn't pcap
+const MAX_RECORD: usize = 262_144;
+
+/// tcpdump writes the pcap to its stdout, a packet at a time, on every interface of the namespace
+fn cmd(filter: &str) -> Vec<String> {
+    ["tcpdump", "-i", "any", "-n", "-U", "-w", "-"]
+        .into_iter()
+        .map(str::to_owned)
+        .chain(filter.split_whitespace().map(str::to_owned))
+        .collect()
+}
+
+/// The capture container joins the network namespace of the container, & is removed once it's stopped
+pub fn config(id: &ContainerId, filter: &str) -> Config<String> {
+    Config {
+        image: Some(CAPTURE_IMAGE.to_owned()),
+        cmd: Some(cmd(filter)),
+        attach_stdout: Some(true),
+        attach_stderr: Some(true),
+        tty: Some(false),
+        host_config: Some(HostConfig {
+            auto_remove: Some(true),
+            network_mode: Some(format!("container:{}", id.get())),
+            cap_add: Some(vec!["NET_ADMIN".to_owned(), "NET_RAW".to_owned()]),
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
+/// A single captured packet, summarised, & its length on the wire
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Packet {
+    pub summary: String,
+    pub length: u32,
+}
+
+/// Reads the pcap that tcpdump writes, in whatever chunks the daemon sends it, a record split across chunks is kept until the rest of it arrives
+#[derive(Debug, Default)]
+pub struct Pcap {
+    buffer: Vec<u8>,
+    /// If the stream is big endian, & its link type, from the global header
+    header: Option<(bool, u32)>,
+}
+
+fn read_u32(big: bool, bytes: &[u8]) -> u32 {
+    let bytes = [bytes[0], bytes[1], bytes[2], bytes[3]];
+    if big {
+        u32::from_be_bytes(bytes)
+    } else {
+        u32::from_le_bytes(bytes)
+    }
+}
+
+/// A big endian u16, as every network header is
+fn be16(data: &[u8], offset: usize) -> Option<u16> {
+    Some(u16::from_be_bytes([
+        *data.get(offset)?,
+        *data.get(offset + 1)?,
+    ]))
+}
+
+impl Pcap {
+    /// The packets completed by this chunk, a stream that isn't pcap is dropped
+    pub fn push(&mut self, bytes: &[u8]) -> Vec<Packet> {
+        self.buffer.extend_from_slice(bytes);
+        let mut packets = vec![];
+        let mut offset = 0;
+        let (big, link) = if let Some(header) = self.header {
+            header
+        } else {
+            let Some(global) = self.buffer.get(..24) else {
+                return packets;
+            };
+            // Both the microsecond, & the nanosecond, magic numbers
+            let big = match global[..4] {
+                [0xa1, 0xb2, 0xc3, 0xd4] | [0xa1, 0xb2, 0x3c, 0x4d] => true,
+                [0xd4, 0xc3, 0xb2, 0xa1] | [0x4d, 0x3c, 0xb2, 0xa1] => false,
+                _ => {
+                    self.buffer.clear();
+                    return packets;
+                }
+            };
+            let header = (big, read_u32(big, &global[20..24]));
+            self.header = Some(header);
+            offset = 24;
+            header
+        };
+        while let Some(record) = self.buffer.get(offset..offset + 16) {
+            let included = usize::try_from(read_u32(big, &record[8..12])).unwrap_or(usize::MAX);
+            let length = read_u32(big, &record[12..16]);
+            if included > MAX_RECORD {
+                self.buffer.clear();
+                return packets;
+            }
+            let Some(data) = self.buffer.get(offset + 16..offset + 16 + included) else {
+                break;
+            };
+            packets.push(Packet {
+                summary: summary(link, data),
+                length,
+            });
+            offset += 16 + included;
+        }
+        self.buffer.drain(..offset);
+        packets
+    }
+}
+
+/// The ethertype, & the network layer, of a frame, `-i any` captures as Linux cooked frames, SLL, or SLL2 on newer versions of tcpdump
+fn network(link: u32, data: &[u8]) -> Option<(u16, &[u8])> {
+    match link {
+        // Ethernet, with any VLAN tags skipped
+        1 => {
+            let mut offset = 12;
+            let mut ethertype = be16(data, offset)?;
+            while ethertype == 0x8100 || ethertype == 0x88a8 {
+                offset += 4;
+                ethertype = be16(data, offset)?;
+            }
+            Some((ethertype, data.get(offset + 2..)?))
+        }
+        113 => Some((be16(data, 14)?, data.get(16..)?)),
+        276 => Some((be16(data, 0)?, data.get(20..)?)),
+        // Raw IP
+        12 | 101 => match data.first()? >> 4 {
+            4 => Some((0x0800, data)),
+            6 => Some((0x86dd, data)),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Flags of a TCP segment, as tcpdump shows them, e.g. `[S.]` for a SYN-ACK
+fn tcp_flags(flags: u8) -> String {
+    let mut output = [(0x02, 'S'), (0x01, 'F'), (0x04, 'R'), (0x08, 'P')]
+        .into_iter()
+        .filter(|(bit, _)| flags & bit != 0)
+        .map(|(_, c)| c)
+        .collect::<String>();
+    if flags & 0x10 != 0 {
+        output.push('.');
+    }
+    format!("[{output}]")
+}
+
+/// The protocol, & addresses, of a packet, with its ports for TCP & UDP
+fn transport(protocol: u8, src: IpAddr, dst: IpAddr, payload: &[u8]) -> String {
+    let ports = be16(payload, 0).zip(be16(payload, 2));
+    match (protocol, ports) {
+        (6, Some((from, to))) => format!(
+            "TCP {} > {} {}",
+            SocketAddr::new(src, from),
+            SocketAddr::new(dst, to),
+            payload.get(13).map_or_else(String::new, |i| tcp_flags(*i))
+        ),
+        (17, Some((from, to))) => format!(
+            "UDP {} > {}",
+            SocketAddr::new(src, from),
+            SocketAddr::new(dst, to)
+        ),
+        (1 | 58, _) => format!("ICMP {src} > {dst}"),
+        _ => format!("IP {src} > {dst} protocol {protocol}"),
+    }
+}
+
+fn ipv4(ip: &[u8]) -> Option<String> {
+    let header = usize::from(ip.first()? & 0x0f) * 4;
+    let src = Ipv4Addr::from(<[u8; 4]>::try_from(ip.get(12..16)?).ok()?);
+    let dst = Ipv4Addr::from(<[u8; 4]>::try_from(ip.get(16..20)?).ok()?);
+    Some(transport(
+        *ip.get(9)?,
+        IpAddr::V4(src),
+        IpAddr::V4(dst),
+        ip.get(header..).unwrap_or_default(),
+    ))
+}
+
+/// Extension headers aren't followed, so the protocol of a packet that has them is the first extension header
+fn ipv6(ip: &[u8]) -> Option<String> {
+    let src = Ipv6Addr::from(<[u8; 16]>::try_from(ip.get(8..24)?).ok()?);
+    let dst = Ipv6Addr::from(<[u8; 16]>::try_from(ip.get(24..40)?).ok()?);
+    Some(transport(
+        *ip.get(6)?,
+        IpAddr::V6(src),
+        IpAddr::V6(dst),
+        ip.get(40..).unwrap_or_default(),
+    ))
+}
+
+/// A single line summary of a packet, e.g. `TCP 172.18.0.2:5432 > 172.18.0.3:41234 [P.]`
+fn summary(link: u32, data: &[u8]) -> String {
+    let Some((ethertype, ip)) = network(link, data) else {
+        return format!("link type {link}");
+    };
+    match ethertype {
+        0x0800 => ipv4(ip),
+        0x86dd => ipv6(ip),
+        0x0806 => Some("ARP".to_owned()),
+        _ => None,
+    }
+    .unwrap_or_else(|| format!("ethertype {ethertype:#06x}"))
+}
+
+/// Update the capture pane, only if it's still showing this capture, a capture that's been closed isn't reopened
+fn update(gui_state: &Arc<Mutex<GuiState>>, stop: &Arc<Notify>, f: impl FnOnce(&mut Capture)) {
+    if let Some(capture) = gui_state.lock().get_capture().filter(|i| i.is(stop)) {
+        f(capture);
+    }
+}
+
+/// Stream the pcap from the capture container into the file, & its packets into the capture pane, until stopped, or tcpdump exits
+async fn stream(
+    docker: &Docker,
+    gui_state: &Arc<Mutex<GuiState>>,
+    container: &str,
+    path: &Path,
+    stop: &Arc<Notify>,
+) -> Result<String, String> {
+    let options = AttachContainerOptions::<String> {
+        stdout: Some(true),
+        stderr: Some(true),
+        stream: Some(true),
+        logs: Some(true),
+        ..Default::default()
+    };
+    let mut attached = docker
+        .attach_container(container, Some(options))
+        .await
+        .map_err(|e| format!("unable to capture: {e}"))?;
+    docker
+        .start_container(container, None::<StartContainerOptions<String>>)
+        .await
+        .map_err(|e| format!("unable to capture: {e}"))?;
+    let mut file = std::fs::File::create(path)
+        .map_err(|e| format!("unable to save {}: {e}", path.display()))?;
+    let mut pcap = Pcap::default();
+    let mut packets = 0;
+    // The latest line that tcpdump wrote to stderr, which is why it exited, if it exits on its own
+    let mut status = None;
+    let stopped = loop {
+        tokio::select! {
+            () = stop.notified() => break true,
+            output = attached.output.next() => match output {
+                Some(Ok(LogOutput::StdOut { message })) => {
+                    file.write_all(&message)
+                        .map_err(|e| format!("unable to save {}: {e}", path.display()))?;
+                    let captured = pcap.push(&message);
+                    packets += captured.len();
+                    update(gui_state, stop, |capture| {
+                        for packet in captured {
+                            capture.push(&packet.summary, packet.length);
+                        }
+                    });
+                }
+                Some(Ok(LogOutput::StdErr { message })) => {
+                    let message = String::from_utf8_lossy(&message);
+                    if let Some(line) = message.lines().map(str::trim).rfind(|i| !i.is_empty()) {
+                        update(gui_state, stop, |capture| capture.status = Some(line.to_owned()));
+                        status = Some(line.to_owned());
+                    }
+                }
+                Some(Ok(_)) => (),
+                Some(Err(e)) => return Err(format!("capture failed: {e}")),
+                None => break false,
+            }
+        }
+    };
+    file.flush()
+        .map_err(|e| format!("unable to save {}: {e}", path.display()))?;
+    if packets > 0 {
+        return Ok(format!("saved {packets} packets to {}", path.display()));
+    }
+    std::fs::remove_file(path).ok();
+    if stopped {
+        Ok("no packets captured".to_owned())
+    } else {
+        Err(status.unwrap_or_else(|| "capture ended, no packets captured".to_owned()))
+    }
+}
+
+/// Capture the packets of a container, with a tcpdump container in its network namespace, saved as a pcap to the path
+/// The capture container is removed however the capture ends
+pub async fn run(
+    docker: &Docker,
+    gui_state: &Arc<Mutex<GuiState>>,
+    (id, filter): (&ContainerId, &str),
+    path: &Path,
+    stop: &Arc<Notify>,
+) -> Result<String, String> {
+    let created = docker
+        .create_container(None::<CreateContainerOptions<String>>, config(id, filter))
+        .await
+        .map_err(|e| format!("unable to capture: {e}"))?;
+    let captured = stream(docker, gui_state, &created.id, path, stop).await;
+    let remove = Some(RemoveContainerOptions {
+        v: false,
+        force: true,
+        link: false,
+    });
+    docker.remove_container(&created.id, remove).await.ok();
+    captured
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::{config, Packet, Pcap};
+    use crate::app_data::ContainerId;
+
+    /// A pcap global header, little endian, with the link type
+    fn global(link: u32) -> Vec<u8> {
+        let mut output = vec![0xd4, 0xc3, 0xb2, 0xa1, 2, 0, 4, 0];
+        output.extend_from_slice(&[0; 8]);
+        output.extend_from_slice(&262_144u32.to_le_bytes());
+        output.extend_from_slice(&link.to_le_bytes());
+        output
+    }
+
+    fn record(data: &[u8], length: u32) -> Vec<u8> {
+        let mut output = vec![0; 8];
+        output.extend_from_slice(&u32::try_from(data.len()).unwrap().to_le_bytes());
+        output.extend_from_slice(&length.to_le_bytes());
+        output.extend_from_slice(data);
+        output
+    }
+
+    /// An IPv4 header, without options, followed by the first bytes of a TCP or UDP header
+    fn ipv4(protocol: u8, ports: (u16, u16), flags: u8) -> Vec<u8> {
+        let mut output = vec![0x45, 0, 0, 0, 0, 0, 0, 0, 64, protocol, 0, 0];
+        output.extend_from_slice(&[172, 18, 0, 2, 172, 18, 0, 3]);
+        output.extend_from_slice(&ports.0.to_be_bytes());
+        output.extend_from_slice(&ports.1.to_be_bytes());
+        output.extend_from_slice(&[0; 9]);
+        output.push(flags);
+        output
+    }
+
+    #[test]
+    /// The capture container joins the network namespace of the container, with the filter split into tcpdump's arguments
+    fn test_capture_config() {
+        let config = config(&ContainerId::from("abc"), " port 5432 ");
+        assert_eq!(
+            config.cmd.unwrap(),
+            ["tcpdump", "-i", "any", "-n", "-U", "-w", "-", "port", "5432"]
+        );
+        let host_config = config.host_config.unwrap();
+        assert_eq!(host_config.auto_remove, Some(true));
+        assert_eq!(host_config.network_mode.as_deref(), Some("container:abc"));
+        assert_eq!(
+            host_config.cap_add.unwrap(),
+            ["NET_ADMIN".to_owned(), "NET_RAW".to_owned()]
+        );
+    }
+
+    #[test]
+    /// Records split across chunks are kept until complete, & Linux cooked, & Ethernet, frames are summarised
+    fn test_capture_pcap() {
+        let mut sll = vec![0; 14];
+        sll.extend_from_slice(&[0x08, 0x00]);
+        sll.extend_from_slice(&ipv4(6, (5432, 41234), 0x18));
+        let mut stream = global(113);
+        stream.extend_from_slice(&record(&sll, 66));
+        let mut pcap = Pcap::default();
+        assert!(pcap.push(&stream[..30]).is_empty());
+        assert_eq!(
+            pcap.push(&stream[30..]),
+            vec![Packet {
+                summary: "TCP 172.18.0.2:5432 > 172.18.0.3:41234 [P.]".to_owned(),
+                length: 66,
+            }]
+        );
+
+        let frame = |ethertype: [u8; 2], payload: &[u8]| {
+            let mut output = vec![0; 12];
+            output.extend_from_slice(&ethertype);
+            output.extend_from_slice(payload);
+            output
+        };
+        let mut stream = global(1);
+        stream.extend_from_slice(&record(&frame([0x08, 0x00], &ipv4(17, (53, 40000), 0)), 80));
+        stream.extend_from_slice(&record(&frame([0x08, 0x06], &[0; 28]), 42));
+        stream.extend_from_slice(&record(
+            &frame([0x08, 0x00], &ipv4(6, (80, 50000), 0x02)),
+            60,
+        ));
+        let summaries = Pcap::default()
+            .push(&stream)
+            .into_iter()
+            .map(|i| i.summary)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            summaries,
+            [
+                "UDP 172.18.0.2:53 > 172.18.0.3:40000",
+                "ARP",
+                "TCP 172.18.0.2:80 > 172.18.0.3:50000 [S]"
+            ]
+        );
+
+        let mut pcap = Pcap::default();
+        assert!(pcap
+            .push(b"not a pcap stream, not a pcap stream")
+            .is_empty());
+        assert!(pcap.push(&global(1)).is_empty());
+    }
+}
@@ -0,0 +1,361 @@
+use std::collections::HashMap;
+
+use bollard::{
+    container::PruneContainersOptions,
+    errors::Error,
+    image::PruneImagesOptions,
+    network::PruneNetworksOptions,
+    service::{BuildCache, ContainerSummary, ImageSummary, SystemDataUsageResponse, Volume},
+    volume::PruneVolumesOptions,
+    Docker,
+};
+
+use super::prune::NONE_TAG;
+use crate::{
+    app_data::{ByteStats, NetworkItem},
+    ui::{CleanupKind, CleanupRow, DiskUsage},
+};
+
+/// Set by the daemon on volumes created without a name, only these are removed by a volume prune
+const ANONYMOUS_LABEL: &str = "com.docker.volume.anonymous";
+
+/// Container states that a container prune removes
+const STOPPED: [&str; 3] = ["created", "dead", "exited"];
+
+/// A daemon reports -1 for a size, or count, that it hasn't calculated
+fn positive(value: i64) -> u64 {
+    u64::try_from(value).unwrap_or_default()
+}
+
+/// The size of an image, without the layers it shares with other images, which aren't reclaimed by removing it
+fn unique_size(image: &ImageSummary) -> u64 {
+    positive(image.size).saturating_sub(positive(image.shared_size))
+}
+
+const fn is_unused(image: &ImageSummary) -> bool {
+    image.containers <= 0
+}
+
+fn is_dangling(image: &ImageSummary) -> bool {
+    image.repo_tags.iter().all(|i| i == NONE_TAG)
+}
+
+fn is_stopped(state: Option<&str>) -> bool {
+    state.is_some_and(|i| STOPPED.contains(&i))
+}
+
+fn volume_size(volume: &Volume) -> u64 {
+    volume.usage_data.as_ref().map_or(0, |i| positive(i.size))
+}
+
+fn volume_unused(volume: &Volume) -> bool {
+    volume.usage_data.as_ref().is_some_and(|i| i.ref_count == 0)
+}
+
+/// The disk usage of the images, containers, volumes, & build cache, with how much of each isn't used, as with `docker system df`
+pub fn disk_usage(usage: &SystemDataUsageResponse) -> Vec<DiskUsage> {
+    let images = usage.images.as_deref().unwrap_or_default();
+    let containers = usage.containers.as_deref().unwrap_or_default();
+    let volumes = usage.volumes.as_deref().unwrap_or_default();
+    let cache = usage.build_cache.as_deref().unwrap_or_default();
+    let container_size = |i: &ContainerSummary| i.size_rw.map_or(0, positive);
+    let cache_size = |i: &BuildCache| i.size.map_or(0, positive);
+    vec![
+        DiskUsage {
+            active: images.iter().filter(|i| !is_unused(i)).count(),
+            label: "images",
+            reclaimable: ByteStats::new(
+                images
+                    .iter()
+                    .filter(|i| is_unused(i))
+                    .map(unique_size)
+                    .sum(),
+            ),
+            size: ByteStats::new(usage.layers_size.map_or(0, positive)),
+            total: images.len(),
+        },
+        DiskUsage {
+            active: containers
+                .iter()
+                .filter(|i| i.state.as_deref() == Some("running"))
+                .count(),
+            label: "containers",
+            reclaimable: ByteStats::new(
+                containers
+                    .iter()
+                    .filter(|i| is_stopped(i.state.as_deref()))
+                    .map(container_size)
+                    .sum(),
+            ),
+            size: ByteStats::new(containers.iter().map(container_size).sum()),
+            total: containers.len(),
+        },
+        DiskUsage {
+            active: volumes.iter().filter(|i| !volume_unused(i)).count(),
+            label: "volumes",
+            reclaimable: ByteStats::new(
+                volumes
+                    .iter()
+                    .filter(|i| volume_unused(i))
+                    .map(volume_size)
+                    .sum(),
+            ),
+            size: ByteStats::new(volumes.iter().map(volume_size).sum()),
+            total: volumes.len(),
+        },
+        DiskUsage {
+            active: cache.iter().filter(|i| i.in_use == Some(true)).count(),
+            label: "build cache",
+            reclaimable: ByteStats::new(
+                cache
+                    .iter()
+                    .filter(|i| i.in_use != Some(true) && i.shared != Some(true))
+                    .map(cache_size)
+                    .sum(),
+            ),
+            size: ByteStats::new(cache.iter().map(cache_size).sum()),
+            total: cache.len(),
+        },
+    ]
+}
+
+/// What each prune would remove, image sizes leave out the layers shared with images that are kept, so are an estimate
+pub fn rows(usage: &SystemDataUsageResponse, networks: &[NetworkItem]) -> Vec<CleanupRow> {
+    let images = usage.images.as_deref().unwrap_or_default();
+    let containers = usage.containers.as_deref().unwrap_or_default();
+    let volumes = usage.volumes.as_deref().unwrap_or_default();
+    let row = |kind: CleanupKind, sizes: Vec<u64>| CleanupRow {
+        count: sizes.len(),
+        kind,
+        size: Some(ByteStats::new(sizes.iter().sum())),
+    };
+    CleanupKind::ALL
+        .into_iter()
+        .map(|kind| match kind {
+            CleanupKind::Containers => row(
+                kind,
+                containers
+                    .iter()
+                    .filter(|i| is_stopped(i.state.as_deref()))
+                    .map(|i| i.size_rw.map_or(0, positive))
+                    .collect(),
+            ),
+            CleanupKind::DanglingImages => row(
+                kind,
+                images
+                    .iter()
+                    .filter(|i| is_unused(i) && is_dangling(i))
+                    .map(unique_size)
+                    .collect(),
+            ),
+            CleanupKind::AllImages => row(
+                kind,
+                images
+                    .iter()
+                    .filter(|i| is_unused(i))
+                    .map(unique_size)
+                    .collect(),
+            ),
+            CleanupKind::Volumes => row(
+                kind,
+                volumes
+                    .iter()
+                    .filter(|i| volume_unused(i) && i.labels.contains_key(ANONYMOUS_LABEL))
+                    .map(volume_size)
+                    .collect(),
+            ),
+            CleanupKind::Networks => CleanupRow {
+                count: networks
+                    .iter()
+                    .filter(|i| i.containers.is_empty() && !i.is_predefined())
+                    .count(),
+                kind,
+                size: None,
+            },
+        })
+        .collect()
+}
+
+/// Run a prune, returns the number of items removed, & the space reclaimed
+pub async fn prune(docker: &Docker, kind: CleanupKind) -> Result<(usize, ByteStats), Error> {
+    let reclaimed = |i: Option<i64>| ByteStats::new(i.map_or(0, positive));
+    match kind {
+        CleanupKind::Containers => docker
+            .prune_containers(None::<PruneContainersOptions<String>>)
+            .await
+            .map(|i| {
+                (
+                    i.containers_deleted.map_or(0, |i| i.len()),
+                    reclaimed(i.space_reclaimed),
+                )
+            }),
+        CleanupKind::DanglingImages | CleanupKind::AllImages => {
+            // By default only dangling images are pruned
+            let filters = if kind == CleanupKind::AllImages {
+                HashMap::from([("dangling", vec!["false"])])
+            } else {
+                HashMap::new()
+            };
+            docker
+                .prune_images(Some(PruneImagesOptions { filters }))
+                .await
+                .map(|i| {
+                    (
+                        i.images_deleted.map_or(0, |i| i.len()),
+                        reclaimed(i.space_reclaimed),
+                    )
+                })
+        }
+        CleanupKind::Volumes => docker
+            .prune_volumes(None::<PruneVolumesOptions<String>>)
+            .await
+            .map(|i| {
+                (
+                    i.volumes_deleted.map_or(0, |i| i.len()),
+                    reclaimed(i.space_reclaimed),
+                )
+            }),
+        CleanupKind::Networks => docker
+            .prune_networks(None::<PruneNetworksOptions<String>>)
+            .await
+            .map(|i| {
+                (
+                    i.networks_deleted.map_or(0, |i| i.len()),
+                    ByteStats::default(),
+                )
+            }),
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use std::collections::HashMap;
+
+    use bollard::service::{
+        BuildCache, ContainerSummary, ImageSummary, SystemDataUsageResponse, Volume,
+        VolumeUsageData,
+    };
+
+    use super::{disk_usage, rows, ANONYMOUS_LABEL};
+    use crate::{
+        app_data::{ByteStats, NetworkItem},
+        ui::CleanupKind,
+    };
+
+    fn gen_usage() -> SystemDataUsageResponse {
+        let gen_image =
+            |tags: &[&str], containers: i64, size: i64, shared_size: i64| ImageSummary {
+                repo_tags: tags.iter().map(|i| (*i).to_owned()).collect(),
+                containers,
+                size,
+                shared_size,
+                ..Default::default()
+            };
+        let gen_container = |state: &str, size_rw: i64| ContainerSummary {
+            state: Some(state.to_owned()),
+            size_rw: Some(size_rw),
+            ..Default::default()
+        };
+        let gen_volume = |anonymous: bool, ref_count: i64, size: i64| Volume {
+            labels: if anonymous {
+                HashMap::from([(ANONYMOUS_LABEL.to_owned(), String::new())])
+            } else {
+                HashMap::new()
+            },
+            usage_data: Some(VolumeUsageData { size, ref_count }),
+            ..Default::default()
+        };
+        SystemDataUsageResponse {
+            layers_size: Some(1000),
+            images: Some(vec![
+                gen_image(&["nginx:latest"], 2, 500, 100),
+                gen_image(&["redis:7"], 0, 300, 100),
+                gen_image(&["<none>:<none>"], 0, 200, -1),
+                gen_image(&[], 1, 50, 0),
+            ]),
+            containers: Some(vec![
+                gen_container("running", 10),
+                gen_container("exited", 20),
+                gen_container("created", 5),
+                gen_container("paused", 1),
+            ]),
+            volumes: Some(vec![
+                gen_volume(true, 0, 40),
+                gen_volume(false, 0, 60),
+                gen_volume(true, 1, 80),
+            ]),
+            build_cache: Some(vec![
+                BuildCache {
+                    size: Some(70),
+                    in_use: Some(true),
+                    ..Default::default()
+                },
+                BuildCache {
+                    size: Some(30),
+                    ..Default::default()
+                },
+            ]),
+        }
+    }
+
+    #[test]
+    /// The disk usage counts what's in use, unused images are reclaimable without their shared layers
+    fn test_cleanup_disk_usage() {
+        let result = disk_usage(&gen_usage())
+            .into_iter()
+            .map(|i| {
+                (
+                    i.label,
+                    i.total,
+                    i.active,
+                    i.size.get(),
+                    i.reclaimable.get(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            result,
+            [
+                ("images", 4, 2, 1000, 400),
+                ("containers", 4, 1, 36, 25),
+                ("volumes", 3, 1, 180, 100),
+                ("build cache", 2, 1, 100, 30),
+            ]
+        );
+    }
+
+    #[test]
+    /// Each prune only counts what the daemon would remove, anonymous volumes, & networks that aren't predefined
+    fn test_cleanup_rows() {
+        let gen_network = |name: &str, containers: &[&str]| NetworkItem {
+            containers: containers.iter().map(|i| (*i).to_owned()).collect(),
+            driver: "bridge".to_owned(),
+            id: name.to_owned(),
+            internal: false,
+            name: name.to_owned(),
+            scope: "local".to_owned(),
+        };
+        let result = rows(
+            &gen_usage(),
+            &[
+                gen_network("bridge", &[]),
+                gen_network("backend", &["api"]),
+                gen_network("old", &[]),
+            ],
+        )
+        .into_iter()
+        .map(|i| (i.kind, i.count, i.size.map(ByteStats::get)))
+        .collect::<Vec<_>>();
+        assert_eq!(
+            result,
+            [
+                (CleanupKind::Containers, 2, Some(25)),
+                (CleanupKind::DanglingImages, 1, Some(200)),
+                (CleanupKind::AllImages, 2, Some(400)),
+                (CleanupKind::Volumes, 1, Some(40)),
+                (CleanupKind::Networks, 1, None),
+            ]
+        );
+    }
+}
@@ -0,0 +1,274 @@
+use std::collections::BTreeMap;
+
+use bollard::service::{ContainerInspectResponse, HostConfig, MountPointTypeEnum};
+
+use crate::{
+    app_data::ByteStats,
+    ui::{DiffRow, DiffSection, InspectDiff},
+};
+
+use super::export;
+
+/// The name of an inspected container, without the leading `/`
+fn name(inspect: &ContainerInspectResponse) -> String {
+    inspect
+        .name
+        .as_deref()
+        .unwrap_or_default()
+        .trim_start_matches('/')
+        .to_owned()
+}
+
+/// The cpu, memory, & pids, limits, & the restart policy, a limit of 0 isn't set
+#[allow(clippy::cast_precision_loss, clippy::cast_sign_loss)]
+fn limits(host_config: &HostConfig) -> [(&'static str, Option<String>); 10] {
+    let bytes = |i: Option<i64>| {
+        i.filter(|i| *i > 0)
+            .map(|i| ByteStats::new(i as u64).to_string())
+    };
+    let count = |i: Option<i64>| i.filter(|i| *i > 0).map(|i| i.to_string());
+    [
+        ("memory", bytes(host_config.memory)),
+        ("memory reservation", bytes(host_config.memory_reservation)),
+        ("memory swap", bytes(host_config.memory_swap)),
+        (
+            "cpus",
+            host_config
+                .nano_cpus
+                .filter(|i| *i > 0)
+                .map(|i| format!("{}", i as f64 / 1_000_000_000.0)),
+        ),
+        ("cpu shares", count(host_config.cpu_shares)),
+        ("cpu quota", count(host_config.cpu_quota)),
+        ("cpu period", count(host_config.cpu_period)),
+        ("cpuset", host_config.cpuset_cpus.clone()),
+        ("pids", count(host_config.pids_limit)),
+        (
+            "restart",
+            host_config
+                .restart_policy
+                .as_ref()
+                .and_then(export::restart),
+        ),
+    ]
+}
+
+/// The compared settings of a container, by section & key, settings that aren't set, or are empty, are left out
+fn settings(inspect: &ContainerInspectResponse) -> BTreeMap<(DiffSection, String), String> {
+    let config = inspect.config.clone().unwrap_or_default();
+    let host_config = inspect.host_config.clone().unwrap_or_default();
+    let mut output = BTreeMap::new();
+    let mut push = |section: DiffSection, key: &str, value: Option<String>| {
+        if let Some(value) = value.filter(|i| !i.is_empty()) {
+            output.insert((section, key.to_owned()), value);
+        }
+    };
+
+    push(DiffSection::Image, "image", config.image);
+    push(
+        DiffSection::Image,
+        "image id",
+        inspect
+            .image
+            .as_deref()
+            .map(|i| i.trim_start_matches("sha256:").chars().take(12).collect()),
+    );
+    push(
+        DiffSection::Image,
+        "entrypoint",
+        config.entrypoint.map(|i| i.join(" ")),
+    );
+    push(
+        DiffSection::Image,
+        "command",
+        config.cmd.map(|i| i.join(" ")),
+    );
+    push(DiffSection::Image, "user", config.user);
+    push(DiffSection::Image, "working dir", config.working_dir);
+
+    for (key, value) in config
+        .env
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|i| i.split_once('='))
+    {
+        push(DiffSection::Env, key, Some(value.to_owned()));
+    }
+
+    for mount in inspect.mounts.iter().flatten() {
+        let Some(destination) = mount.destination.as_deref() else {
+            continue;
+        };
+        let source = match mount.typ {
+            Some(MountPointTypeEnum::VOLUME) => mount.name.as_deref(),
+            _ => mount.source.as_deref(),
+        };
+        let kind = mount.typ.map_or_else(String::new, |i| i.to_string());
+        let read_only = if mount.rw == Some(false) { " ro" } else { "" };
+        push(
+            DiffSection::Mounts,
+            destination,
+            Some(format!("{kind} {}{read_only}", source.unwrap_or_default())),
+        );
+    }
+
+    for (key, value) in limits(&host_config) {
+        push(DiffSection::Limits, key, value);
+    }
+
+    push(
+        DiffSection::Networks,
+        "network mode",
+        host_config.network_mode,
+    );
+    for network in inspect
+        .network_settings
+        .as_ref()
+        .and_then(|i| i.networks.as_ref())
+        .into_iter()
+        .flat_map(|i| i.keys())
+    {
+        push(DiffSection::Networks, network, Some("connected".to_owned()));
+    }
+    output
+}
+
+/// Compare the image, env, mounts, limits, & networks, of two inspected containers, every setting set on either container is a row
+pub fn diff(left: &ContainerInspectResponse, right: &ContainerInspectResponse) -> InspectDiff {
+    let mut left_settings = settings(left);
+    let mut rows = settings(right)
+        .into_iter()
+        .map(|((section, key), value)| DiffRow {
+            left: left_settings.remove(&(section, key.clone())),
+            section,
+            key,
+            right: Some(value),
+        })
+        .collect::<Vec<_>>();
+    rows.extend(
+        left_settings
+            .into_iter()
+            .map(|((section, key), value)| DiffRow {
+                section,
+                key,
+                left: Some(value),
+                right: None,
+            }),
+    );
+    InspectDiff::new(name(left), name(right), rows)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use std::collections::HashMap;
+
+    use bollard::service::{
+        ContainerConfig, ContainerInspectResponse, EndpointSettings, HostConfig, MountPoint,
+        MountPointTypeEnum, NetworkSettings, RestartPolicy, RestartPolicyNameEnum,
+    };
+
+    use super::diff;
+    use crate::ui::{DiffLine, DiffSection};
+
+    fn gen_inspect(
+        name: &str,
+        env: &[&str],
+        memory: i64,
+        network: &str,
+    ) -> ContainerInspectResponse {
+        ContainerInspectResponse {
+            name: Some(format!("/{name}")),
+            image: Some("sha256:0123456789abcdef".to_owned()),
+            config: Some(ContainerConfig {
+                image: Some("api:2.1".to_owned()),
+                cmd: Some(vec![
+                    "serve".to_owned(),
+                    "--port".to_owned(),
+                    "80".to_owned(),
+                ]),
+                env: Some(env.iter().map(|i| (*i).to_owned()).collect()),
+                ..Default::default()
+            }),
+            host_config: Some(HostConfig {
+                memory: Some(memory),
+                nano_cpus: Some(500_000_000),
+                restart_policy: Some(RestartPolicy {
+                    name: Some(RestartPolicyNameEnum::ALWAYS),
+                    maximum_retry_count: None,
+                }),
+                ..Default::default()
+            }),
+            mounts: Some(vec![MountPoint {
+                typ: Some(MountPointTypeEnum::BIND),
+                source: Some("/srv/api".to_owned()),
+                destination: Some("/config".to_owned()),
+                rw: Some(false),
+                ..Default::default()
+            }]),
+            network_settings: Some(NetworkSettings {
+                networks: Some(HashMap::from([(
+                    network.to_owned(),
+                    EndpointSettings::default(),
+                )])),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    /// Settings of either container are compared by section & key, a setting missing from one container is None
+    fn test_compare_diff() {
+        let diff = diff(
+            &gen_inspect("api-staging", &["LOG=debug", "TZ=UTC"], 0, "staging"),
+            &gen_inspect("api-prod", &["LOG=info", "TZ=UTC"], 500_000_000, "prod"),
+        );
+        assert_eq!(diff.left, "api-staging");
+        assert_eq!(diff.right, "api-prod");
+        assert_eq!(diff.changed(), 4);
+
+        let row = |section, key: &str| {
+            diff.rows
+                .iter()
+                .find(|i| i.section == section && i.key == key)
+                .map(|i| (i.left.as_deref(), i.right.as_deref()))
+                .unwrap()
+        };
+        assert_eq!(
+            row(DiffSection::Image, "image id"),
+            (Some("0123456789ab"), Some("0123456789ab"))
+        );
+        assert_eq!(
+            row(DiffSection::Image, "command"),
+            (Some("serve --port 80"), Some("serve --port 80"))
+        );
+        assert_eq!(row(DiffSection::Env, "LOG"), (Some("debug"), Some("info")));
+        assert_eq!(row(DiffSection::Env, "TZ"), (Some("UTC"), Some("UTC")));
+        assert_eq!(
+            row(DiffSection::Mounts, "/config"),
+            (Some("bind /srv/api ro"), Some("bind /srv/api ro"))
+        );
+        assert_eq!(
+            row(DiffSection::Limits, "memory"),
+            (None, Some("500.00 MB"))
+        );
+        assert_eq!(row(DiffSection::Limits, "cpus"), (Some("0.5"), Some("0.5")));
+        assert_eq!(
+            row(DiffSection::Limits, "restart"),
+            (Some("always"), Some("always"))
+        );
+        assert_eq!(
+            row(DiffSection::Networks, "prod"),
+            (None, Some("connected"))
+        );
+        assert_eq!(
+            row(DiffSection::Networks, "staging"),
+            (Some("connected"), None)
+        );
+
+        let lines = diff.lines();
+        assert_eq!(lines[0], DiffLine::Section(DiffSection::Image));
+        assert!(matches!(lines[1], DiffLine::Row(i) if i.key == "command"));
+    }
+}
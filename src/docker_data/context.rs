@@ -0,0 +1,156 @@
+use std::path::{Path, PathBuf};
+
+use serde_json::Value;
+
+use super::TlsFiles;
+
+/// Name of the context that the docker CLI uses when no other is set, the `$DOCKER_HOST` env, or the socket defaults, it isn't stored in the contexts directory
+pub const DEFAULT_CONTEXT: &str = "default";
+
+/// A docker CLI context, as created by `docker context create`, read from `~/.docker/contexts`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DockerContext {
+    pub name: String,
+    /// None for the default context
+    pub host: Option<String>,
+    /// The certificates stored alongside the context, if it has any
+    pub tls: Option<TlsFiles>,
+}
+
+impl DockerContext {
+    pub fn default_context() -> Self {
+        Self {
+            name: DEFAULT_CONTEXT.to_owned(),
+            host: None,
+            tls: None,
+        }
+    }
+
+    /// The docker CLI config directory, `$DOCKER_CONFIG`, or `~/.docker`
+    pub fn config_dir() -> Option<PathBuf> {
+        std::env::var_os("DOCKER_CONFIG")
+            .map(PathBuf::from)
+            .or_else(|| {
+                directories::BaseDirs::new().map(|base_dirs| base_dirs.home_dir().join(".docker"))
+            })
+    }
+
+    /// Parse a context's `meta.json`, the certificates are in the matching directory of `contexts/tls`, which is named by a hash of the context name
+    fn parse_meta(text: &str, tls_dir: &Path) -> Option<Self> {
+        let value = serde_json::from_str::<Value>(text).ok()?;
+        let name = value.get("Name")?.as_str()?.to_owned();
+        let host = value
+            .pointer("/Endpoints/docker/Host")
+            .and_then(Value::as_str)
+            .filter(|i| !i.is_empty())
+            .map(ToOwned::to_owned);
+        let tls = tls_dir.join("ca.pem").exists().then(|| TlsFiles {
+            ca: tls_dir.join("ca.pem"),
+            cert: tls_dir.join("cert.pem"),
+            key: tls_dir.join("key.pem"),
+        });
+        Some(Self { name, host, tls })
+    }
+
+    /// The current context, `$DOCKER_CONTEXT`, or the `currentContext` of the docker CLI's `config.json`
+    pub fn current(dir: &Path) -> Option<String> {
+        std::env::var("DOCKER_CONTEXT")
+            .ok()
+            .filter(|i| !i.is_empty())
+            .or_else(|| {
+                let text = std::fs::read_to_string(dir.join("config.json")).ok()?;
+                Self::parse_current(&text)
+            })
+    }
+
+    fn parse_current(text: &str) -> Option<String> {
+        serde_json::from_str::<Value>(text)
+            .ok()?
+            .get("currentContext")?
+            .as_str()
+            .filter(|i| !i.is_empty())
+            .map(ToOwned::to_owned)
+    }
+
+    /// Every stored context, sorted by name, the default context isn't included
+    pub fn list(dir: &Path) -> Vec<Self> {
+        let Ok(entries) = std::fs::read_dir(dir.join("contexts").join("meta")) else {
+            return vec![];
+        };
+        let mut output = entries
+            .filter_map(Result::ok)
+            .filter_map(|entry| {
+                let text = std::fs::read_to_string(entry.path().join("meta.json")).ok()?;
+                let tls_dir = dir
+                    .join("contexts")
+                    .join("tls")
+                    .join(entry.file_name())
+                    .join("docker");
+                Self::parse_meta(&text, &tls_dir)
+            })
+            .collect::<Vec<_>>();
+        output.sort_by(|a, b| a.name.cmp(&b.name));
+        output
+    }
+
+    /// Find a context by name, `default` is always found
+    pub fn find(dir: &Path, name: &str) -> Result<Self, String> {
+        if name == DEFAULT_CONTEXT {
+            return Ok(Self::default_context());
+        }
+        Self::list(dir)
+            .into_iter()
+            .find(|i| i.name == name)
+            .ok_or_else(|| format!("context \"{name}\" not found in {}", dir.display()))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use std::path::Path;
+
+    use super::DockerContext;
+
+    #[test]
+    /// The name & docker endpoint are read from a context's meta.json, a missing endpoint is the socket defaults
+    fn test_docker_context_parse_meta() {
+        let text = r#"{"Name":"build","Metadata":{"Description":"ci"},"Endpoints":{"docker":{"Host":"ssh://ci@build","SkipTLSVerify":false}}}"#;
+        let result = DockerContext::parse_meta(text, Path::new("/nonexistent")).unwrap();
+        assert_eq!(
+            result,
+            DockerContext {
+                name: "build".to_owned(),
+                host: Some("ssh://ci@build".to_owned()),
+                tls: None,
+            }
+        );
+        let result =
+            DockerContext::parse_meta(r#"{"Name":"empty","Endpoints":{}}"#, Path::new("/"))
+                .unwrap();
+        assert!(result.host.is_none());
+        assert!(DockerContext::parse_meta(r#"{"Endpoints":{}}"#, Path::new("/")).is_none());
+        assert!(DockerContext::parse_meta("not json", Path::new("/")).is_none());
+    }
+
+    #[test]
+    /// The current context is read from config.json, an empty current context is none
+    fn test_docker_context_parse_current() {
+        assert_eq!(
+            DockerContext::parse_current(r#"{"auths":{},"currentContext":"build"}"#),
+            Some("build".to_owned())
+        );
+        assert!(DockerContext::parse_current(r#"{"currentContext":""}"#).is_none());
+        assert!(DockerContext::parse_current(r#"{"auths":{}}"#).is_none());
+    }
+
+    #[test]
+    /// The default context is always found, other contexts only if stored
+    fn test_docker_context_find() {
+        assert_eq!(
+            DockerContext::find(Path::new("/nonexistent"), "default"),
+            Ok(DockerContext::default_context())
+        );
+        assert!(DockerContext::find(Path::new("/nonexistent"), "build").is_err());
+    }
+}
@@ -0,0 +1,564 @@
+use std::{
+    collections::HashMap,
+    sync::{atomic::AtomicBool, Arc},
+};
+
+use bollard::{
+    container::{ListContainersOptions, LogOutput, LogsOptions, Stats},
+    service::{ContainerInspectResponse, ContainerSummary, Port, PortTypeEnum},
+};
+use futures_util::{
+    future::BoxFuture,
+    stream::{self, BoxStream, StreamExt},
+};
+use parking_lot::Mutex;
+use tokio::sync::mpsc::{Receiver, Sender};
+
+use super::{
+    format_rfc3339, runtime, DockerData, DockerMessage, ExecOutput, Runtime, READ_ONLY_TEXT,
+};
+use crate::{
+    app_data::{AppData, ContainerId, DaemonInfo, DockerControls, Signal, PROJECT_LABEL},
+    ui::GuiState,
+};
+
+/// The demo clock starts at 2024-05-01T12:00:00Z, and advances one second per update, so that the timestamps are the same in every run
+const DEMO_EPOCH: i64 = 1_714_564_800;
+
+/// Number of log lines each running container starts with
+const DEMO_HISTORY: u64 = 24;
+
+/// Shown for anything that the demo doesn't fake
+const DEMO_UNAVAILABLE: &str = "not available in demo mode";
+
+/// Memory limit of every demo container, 2GiB
+const DEMO_MEM_LIMIT: u64 = 2 * 1024 * 1024 * 1024;
+
+/// A fake container, its cpu & memory use wander around a base value
+#[derive(Debug, Clone, PartialEq)]
+struct DemoContainer {
+    id: &'static str,
+    name: &'static str,
+    image: &'static str,
+    project: Option<&'static str>,
+    port: Option<u16>,
+    state: &'static str,
+    cpu: f64,
+    mem: u64,
+    /// Log lines, one is picked for each update
+    logs: &'static [&'static str],
+}
+
+/// The starting containers, a compose project, and a few standalone containers, in every state that can be produced by the controls
+#[allow(clippy::too_many_lines)]
+fn demo_containers() -> Vec<DemoContainer> {
+    vec![
+        DemoContainer {
+            id: "3f9a1c7e2b4d",
+            name: "shop-proxy-1",
+            image: "nginx:1.25-alpine",
+            project: Some("shop"),
+            port: Some(80),
+            state: "running",
+            cpu: 0.8,
+            mem: 12 * 1024 * 1024,
+            logs: &[
+                "172.18.0.1 - - \"GET / HTTP/1.1\" 200 615",
+                "172.18.0.1 - - \"GET /api/products HTTP/1.1\" 200 4821",
+                "172.18.0.1 - - \"POST /api/cart HTTP/1.1\" 201 88",
+                "172.18.0.1 - - \"GET /favicon.ico HTTP/1.1\" 404 153",
+            ],
+        },
+        DemoContainer {
+            id: "8b2e6d4f1a9c",
+            name: "shop-api-1",
+            image: "node:20-alpine",
+            project: Some("shop"),
+            port: Some(3000),
+            state: "running",
+            cpu: 6.5,
+            mem: 180 * 1024 * 1024,
+            logs: &[
+                "INFO  GET /products 200 12ms",
+                "INFO  POST /cart 201 31ms",
+                "WARN  slow query, 412ms, SELECT * FROM orders",
+                "ERROR payment provider timed out, retrying",
+                "DEBUG cache hit, products:page:1",
+            ],
+        },
+        DemoContainer {
+            id: "c4d7a2e9f3b1",
+            name: "shop-postgres-1",
+            image: "postgres:16",
+            project: Some("shop"),
+            port: Some(5432),
+            state: "running",
+            cpu: 2.1,
+            mem: 96 * 1024 * 1024,
+            logs: &[
+                "LOG:  checkpoint starting: time",
+                "LOG:  checkpoint complete: wrote 42 buffers (0.3%)",
+                "LOG:  automatic vacuum of table \"shop.public.orders\"",
+            ],
+        },
+        DemoContainer {
+            id: "e1b5f8c3d6a2",
+            name: "shop-redis-1",
+            image: "redis:7",
+            project: Some("shop"),
+            port: Some(6379),
+            state: "running",
+            cpu: 0.4,
+            mem: 8 * 1024 * 1024,
+            logs: &[
+                "1:M * 100 changes in 300 seconds. Saving...",
+                "1:M * Background saving started",
+                "1:M * Background saving terminated with success",
+            ],
+        },
+        DemoContainer {
+            id: "5a8c2f7b9e4d",
+            name: "grafana",
+            image: "grafana/grafana:10.4.2",
+            project: None,
+            port: Some(3001),
+            state: "running",
+            cpu: 1.2,
+            mem: 64 * 1024 * 1024,
+            logs: &[
+                "logger=context userId=1 t=demo level=info msg=\"Request Completed\" status=200",
+                "logger=cleanup t=demo level=info msg=\"Completed cleanup jobs\"",
+            ],
+        },
+        DemoContainer {
+            id: "9d3f6b1e8c5a",
+            name: "worker",
+            image: "python:3.12-slim",
+            project: None,
+            port: None,
+            state: "paused",
+            cpu: 12.0,
+            mem: 140 * 1024 * 1024,
+            logs: &[
+                "processing batch",
+                "batch complete, 250 items",
+                "Traceback (most recent call last): ValueError: invalid row",
+            ],
+        },
+        DemoContainer {
+            id: "7e4a9c2d5f8b",
+            name: "backup",
+            image: "alpine:3.19",
+            project: None,
+            port: None,
+            state: "exited",
+            cpu: 0.0,
+            mem: 0,
+            logs: &["backup complete"],
+        },
+    ]
+}
+
+/// A stable pseudo random number, from splitmix64, so that the same tick always gives the same stats & logs
+const fn noise(seed: u64) -> u64 {
+    let mut z = seed.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+/// The state of the demo, advanced one tick per update, everything is derived from the tick, so every run is identical
+#[derive(Debug, Clone, PartialEq)]
+pub struct DemoState {
+    containers: Vec<DemoContainer>,
+    tick: u64,
+    /// Ticks each container has been running since, for the status column
+    started: HashMap<&'static str, u64>,
+}
+
+/// The stats of a running container, for a single tick
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DemoStats {
+    pub cpu: f64,
+    pub mem: u64,
+    pub rx: u64,
+    pub tx: u64,
+}
+
+impl DemoState {
+    pub fn new() -> Self {
+        Self {
+            containers: demo_containers(),
+            tick: 0,
+            started: HashMap::new(),
+        }
+    }
+
+    fn find(&mut self, id: &ContainerId) -> Result<&mut DemoContainer, String> {
+        self.containers
+            .iter_mut()
+            .find(|i| i.id == id.get())
+            .ok_or_else(|| format!("no such container: {}", id.get()))
+    }
+
+    /// Change the state of a container, as the Docker daemon would, a control that doesn't apply to the current state is an error
+    fn control(&mut self, control: DockerControls, id: &ContainerId) -> Result<(), String> {
+        let tick = self.tick;
+        let container = self.find(id)?;
+        let state = match (control, container.state) {
+            (DockerControls::Pause, "running") => "paused",
+            // A hangup is a reload, the process keeps running
+            (DockerControls::Kill(Signal::Hup), "running")
+            | (DockerControls::Resume, "paused")
+            | (DockerControls::Start, "exited")
+            | (DockerControls::Restart, _) => "running",
+            (DockerControls::Stop, "running" | "paused") | (DockerControls::Kill(_), "running") => {
+                "exited"
+            }
+            (DockerControls::Delete, _) => {
+                self.containers.retain(|i| i.id != id.get());
+                return Ok(());
+            }
+            (control, state) => return Err(format!("unable to {control} a {state} container")),
+        };
+        container.state = state;
+        let name = container.id;
+        self.started.insert(name, tick);
+        Ok(())
+    }
+
+    /// Containers, as listed by the Docker daemon
+    pub fn summaries(&self) -> Vec<ContainerSummary> {
+        self.containers
+            .iter()
+            .enumerate()
+            .map(|(index, i)| {
+                let since = self.tick - self.started.get(i.id).copied().unwrap_or_default();
+                let status = match i.state {
+                    "running" => format!("Up {} minutes", 42 + since / 60),
+                    "paused" => "Up 3 hours (Paused)".to_owned(),
+                    _ => "Exited (0) 2 hours ago".to_owned(),
+                };
+                ContainerSummary {
+                    id: Some(i.id.to_owned()),
+                    names: Some(vec![format!("/{}", i.name)]),
+                    image: Some(i.image.to_owned()),
+                    image_id: Some(format!("sha256:{}", i.id)),
+                    command: None,
+                    created: Some(
+                        DEMO_EPOCH - 86400 + i64::try_from(index).unwrap_or_default() * 3600,
+                    ),
+                    ports: Some(
+                        i.port
+                            .map(|port| Port {
+                                ip: Some("0.0.0.0".to_owned()),
+                                private_port: port,
+                                public_port: Some(port),
+                                typ: Some(PortTypeEnum::TCP),
+                            })
+                            .into_iter()
+                            .collect(),
+                    ),
+                    size_rw: None,
+                    size_root_fs: None,
+                    labels: i.project.map(|project| {
+                        HashMap::from([(PROJECT_LABEL.to_owned(), project.to_owned())])
+                    }),
+                    state: Some(i.state.to_owned()),
+                    status: Some(status),
+                    host_config: None,
+                    network_settings: None,
+                    mounts: None,
+                }
+            })
+            .collect()
+    }
+
+    /// Stats of the running containers, at the current tick
+    #[allow(clippy::cast_precision_loss)]
+    pub fn stats(&self) -> Vec<(ContainerId, DemoStats)> {
+        self.containers
+            .iter()
+            .enumerate()
+            .filter(|(_, i)| i.state == "running")
+            .map(|(index, i)| {
+                let seed = noise(self.tick * 64 + index as u64);
+                // Within 50% either side of the base value
+                let wander = (seed % 1000) as f64 / 1000.0 + 0.5;
+                let stats = DemoStats {
+                    cpu: i.cpu * wander,
+                    mem: i.mem + i.mem / 4 * (seed % 100) / 100,
+                    rx: self.tick * (index as u64 + 1) * 1536,
+                    tx: self.tick * (index as u64 + 1) * 512,
+                };
+                (ContainerId::from(i.id), stats)
+            })
+            .collect()
+    }
+
+    /// Log lines of a container, for a range of ticks, with the timestamps that Docker adds
+    fn logs(container: &DemoContainer, index: usize, ticks: std::ops::Range<u64>) -> Vec<String> {
+        ticks
+            .filter_map(|tick| {
+                let seed = noise(tick * 64 + index as u64 + 32);
+                // Roughly one line every other tick
+                seed.is_multiple_of(2).then(|| {
+                    let line = container.logs
+                        [usize::try_from(seed / 2).unwrap_or_default() % container.logs.len()];
+                    let secs = i64::try_from(tick).unwrap_or_default();
+                    format!("{} {line}", format_rfc3339(DEMO_EPOCH + secs))
+                })
+            })
+            .collect()
+    }
+
+    /// Advance a tick, returns the new log lines of each running container, the first tick includes some history
+    pub fn advance(&mut self) -> Vec<(ContainerId, Vec<String>)> {
+        let ticks = if self.tick == 0 {
+            self.tick = DEMO_HISTORY;
+            0..DEMO_HISTORY
+        } else {
+            self.tick += 1;
+            self.tick - 1..self.tick
+        };
+        self.containers
+            .iter()
+            .enumerate()
+            .filter(|(_, i)| i.state == "running")
+            .map(|(index, i)| (ContainerId::from(i.id), Self::logs(i, index, ticks.clone())))
+            .collect()
+    }
+
+    /// Advance a tick, and send the containers, stats, & logs into app_data, as the Docker data handler would
+    pub fn update(&mut self, app_data: &Mutex<AppData>) {
+        let logs = self.advance();
+        let mut summaries = self.summaries();
+        let mut app_data = app_data.lock();
+        app_data.update_containers(None, &mut summaries);
+        for (id, stats) in self.stats() {
+            app_data.update_stats_by_id(
+                &id,
+                Some(stats.cpu),
+                Some(stats.mem),
+                DEMO_MEM_LIMIT,
+                stats.rx,
+                stats.tx,
+            );
+        }
+        for (id, lines) in logs {
+            app_data.update_log_by_id(lines, &id);
+        }
+        app_data.sort_containers();
+    }
+}
+
+/// A synthetic runtime, for `--demo`, the controls change the state of the fake containers
+#[derive(Debug)]
+pub struct DemoRuntime(Mutex<DemoState>);
+
+impl Runtime for DemoRuntime {
+    fn control<'a>(
+        &'a self,
+        control: DockerControls,
+        id: &'a ContainerId,
+    ) -> BoxFuture<'a, Result<(), String>> {
+        Box::pin(async move { self.0.lock().control(control, id) })
+    }
+
+    fn list(
+        &self,
+        _options: ListContainersOptions<String>,
+    ) -> BoxFuture<'_, Result<Vec<ContainerSummary>, String>> {
+        Box::pin(async move { Ok(self.0.lock().summaries()) })
+    }
+
+    fn inspect<'a>(
+        &'a self,
+        _id: &'a ContainerId,
+        _size: bool,
+    ) -> BoxFuture<'a, Result<ContainerInspectResponse, String>> {
+        Box::pin(async move { Err(DEMO_UNAVAILABLE.to_owned()) })
+    }
+
+    fn stats<'a>(
+        &'a self,
+        _id: &'a ContainerId,
+        _one_shot: bool,
+    ) -> BoxFuture<'a, Result<Stats, String>> {
+        Box::pin(async move { Err(DEMO_UNAVAILABLE.to_owned()) })
+    }
+
+    /// The demo logs are sent into app_data on each update, rather than read
+    fn logs<'a>(
+        &'a self,
+        _id: &'a ContainerId,
+        _options: LogsOptions<String>,
+    ) -> BoxStream<'a, Result<LogOutput, String>> {
+        stream::empty().boxed()
+    }
+
+    fn exec<'a>(
+        &'a self,
+        _id: &'a ContainerId,
+        _cmd: Vec<String>,
+    ) -> BoxFuture<'a, Result<ExecOutput, String>> {
+        Box::pin(async move { Err(DEMO_UNAVAILABLE.to_owned()) })
+    }
+}
+
+impl DemoRuntime {
+    /// Run in place of the Docker data handler, without a Docker daemon, only the container controls are handled, everything else is shown as unavailable
+    pub async fn init(
+        app_data: Arc<Mutex<AppData>>,
+        mut docker_rx: Receiver<DockerMessage>,
+        docker_tx: Sender<DockerMessage>,
+        gui_state: Arc<Mutex<GuiState>>,
+        is_running: Arc<AtomicBool>,
+    ) {
+        let args = app_data.lock().args.clone();
+        let runtime = Self(Mutex::new(DemoState::new()));
+        app_data.lock().set_daemon_info(DaemonInfo {
+            cgroup: Some("v2".to_owned()),
+            kernel: Some("6.8.0-demo".to_owned()),
+            name: Some("demo".to_owned()),
+            os: Some("oxker demo".to_owned()),
+            storage_driver: Some("overlay2".to_owned()),
+            version: Some("26.1.0".to_owned()),
+            warnings: vec![],
+        });
+        runtime.0.lock().update(&app_data);
+        DockerData::select_on_start(&app_data, &gui_state);
+        DockerData::scheduler(&args, docker_tx);
+
+        while let Some(message) = docker_rx.recv().await {
+            if args.read_only && message.is_write() {
+                gui_state.lock().set_info_box(READ_ONLY_TEXT);
+                continue;
+            }
+            let control = match message {
+                DockerMessage::Update => None,
+                DockerMessage::Kill(id, signal) => Some((DockerControls::Kill(signal), id)),
+                DockerMessage::Pause(id) => Some((DockerControls::Pause, id)),
+                DockerMessage::Restart(id) => Some((DockerControls::Restart, id)),
+                DockerMessage::Resume(id) => Some((DockerControls::Resume, id)),
+                DockerMessage::Start(id) => Some((DockerControls::Start, id)),
+                DockerMessage::Stop(id) => Some((DockerControls::Stop, id)),
+                DockerMessage::Delete(id) => {
+                    gui_state.lock().set_confirm(None);
+                    Some((DockerControls::Delete, id))
+                }
+                DockerMessage::Confirm(control, id) => {
+                    gui_state.lock().set_confirm(Some((control, id)));
+                    continue;
+                }
+                DockerMessage::ReadLogs(id, options, sx) => {
+                    let output =
+                        DockerData::read_log_output(&runtime, &id, options, None, None).await;
+                    sx.send(output).ok();
+                    continue;
+                }
+                // The demo containers have no size, so the dry run preview is left as unknown
+                DockerMessage::BatchPreview(_) => continue,
+                DockerMessage::ProjectControl(control, ids) => {
+                    let status = |text: &str| gui_state.lock().set_info_box(text);
+                    if !runtime::project_control(&runtime, control, &ids, &status).await {
+                        gui_state
+                            .lock()
+                            .set_info_box(&format!("unable to {control} every container"));
+                    }
+                    None
+                }
+                DockerMessage::BatchControl(control, ids) => {
+                    if !runtime::batch_control(&runtime, control, &ids).await {
+                        gui_state
+                            .lock()
+                            .set_info_box(&format!("unable to {control} every container"));
+                    }
+                    None
+                }
+                DockerMessage::Quit => {
+                    is_running.store(false, std::sync::atomic::Ordering::SeqCst);
+                    continue;
+                }
+                _ => {
+                    gui_state.lock().set_info_box(DEMO_UNAVAILABLE);
+                    continue;
+                }
+            };
+            if let Some((control, id)) = control {
+                if let Err(e) = runtime.control(control, &id).await {
+                    gui_state.lock().set_info_box(&e);
+                }
+            }
+            runtime.0.lock().update(&app_data);
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use parking_lot::Mutex;
+
+    use super::DemoState;
+    use crate::app_data::{AppData, ContainerId, DockerControls, Signal, State};
+
+    #[test]
+    /// Every run of the demo is identical, the first tick includes some log history
+    fn test_demo_state_deterministic() {
+        let mut first = DemoState::new();
+        let mut second = DemoState::new();
+        let logs = first.advance();
+        assert_eq!(logs, second.advance());
+        assert_eq!(logs.len(), 5);
+        assert!(logs.iter().all(|(_, lines)| !lines.is_empty()));
+        assert!(logs[0].1[0].starts_with("2024-05-01T12:00:"));
+        for _ in 0..10 {
+            assert_eq!(first.advance(), second.advance());
+        }
+        assert_eq!(first.stats(), second.stats());
+        assert_eq!(first.summaries(), second.summaries());
+    }
+
+    #[test]
+    /// The controls change the state of a container, a control that doesn't apply to its state is an error
+    fn test_demo_state_control() {
+        let mut state = DemoState::new();
+        let worker = ContainerId::from("9d3f6b1e8c5a");
+        assert!(state.control(DockerControls::Start, &worker).is_err());
+        state.control(DockerControls::Resume, &worker).unwrap();
+        assert_eq!(state.stats().len(), 6);
+        // A hangup is a reload, any other signal stops it
+        state
+            .control(DockerControls::Kill(Signal::Hup), &worker)
+            .unwrap();
+        assert_eq!(state.stats().len(), 6);
+        state
+            .control(DockerControls::Kill(Signal::Term), &worker)
+            .unwrap();
+        assert_eq!(state.stats().len(), 5);
+        assert!(state
+            .control(DockerControls::Kill(Signal::Kill), &worker)
+            .is_err());
+        state.control(DockerControls::Start, &worker).unwrap();
+        state.control(DockerControls::Stop, &worker).unwrap();
+        assert_eq!(state.stats().len(), 5);
+        state.control(DockerControls::Delete, &worker).unwrap();
+        assert_eq!(state.summaries().len(), 6);
+        assert!(state.control(DockerControls::Restart, &worker).is_err());
+    }
+
+    #[test]
+    /// An update fills app_data with the containers, their stats, & logs
+    fn test_demo_state_update() {
+        let app_data = Mutex::new(AppData::default(crate::tests::gen_args()));
+        let mut state = DemoState::new();
+        state.update(&app_data);
+        let items = app_data.lock().get_container_items().to_vec();
+        assert_eq!(items.len(), 7);
+        let api = items.iter().find(|i| i.name.get() == "shop-api-1").unwrap();
+        assert_eq!(api.state, State::Running);
+        assert!(api.logs.len() > 0);
+        assert!(!api.cpu_stats.is_empty());
+    }
+}
@@ -0,0 +1,348 @@
+use std::{path::Path, process::Stdio};
+
+use bollard::auth::DockerCredentials;
+use serde_json::Value;
+use tokio::io::AsyncWriteExt;
+
+use super::DockerContext;
+
+/// The key of Docker Hub in config.json, as written by `docker login`, & passed to a credential helper
+const DOCKER_HUB: &str = "https://index.docker.io/v1/";
+
+/// Hosts that are all Docker Hub
+const DOCKER_HUB_HOSTS: [&str; 3] = ["index.docker.io", "docker.io", "registry-1.docker.io"];
+
+/// The username that a credential helper returns with an identity token, rather than a password
+const TOKEN_USERNAME: &str = "<token>";
+
+/// The registry of an image, the first part of its name if that has a `.`, or a `:`, or is `localhost`, as docker finds it, otherwise Docker Hub
+pub fn registry(image: &str) -> &str {
+    match image.split_once('/') {
+        Some((first, _)) if first.contains(['.', ':']) || first == "localhost" => {
+            if DOCKER_HUB_HOSTS.contains(&first) {
+                DOCKER_HUB
+            } else {
+                first
+            }
+        }
+        _ => DOCKER_HUB,
+    }
+}
+
+/// The host of a registry, a key of config.json can be a url, e.g. `https://index.docker.io/v1/`, or only the host, & each Docker Hub host is the same registry
+fn registry_host(key: &str) -> &str {
+    let key = key.split_once("://").map_or(key, |(_, i)| i);
+    let host = key.split('/').next().unwrap_or(key);
+    if DOCKER_HUB_HOSTS.contains(&host) {
+        DOCKER_HUB_HOSTS[0]
+    } else {
+        host
+    }
+}
+
+/// The settings of a `proxies` entry, & the env var that each is given to a new container as
+const PROXY_ENV: [(&str, &str); 5] = [
+    ("httpProxy", "HTTP_PROXY"),
+    ("httpsProxy", "HTTPS_PROXY"),
+    ("noProxy", "NO_PROXY"),
+    ("ftpProxy", "FTP_PROXY"),
+    ("allProxy", "ALL_PROXY"),
+];
+
+/// Decode standard base64, as used for the `auth` of config.json, padding is optional
+fn decode_base64(input: &str) -> Option<Vec<u8>> {
+    let mut output = Vec::with_capacity(input.len() * 3 / 4);
+    let mut buffer = 0u32;
+    let mut bits = 0;
+    for c in input.trim_end_matches('=').bytes() {
+        let value = match c {
+            b'A'..=b'Z' => c - b'A',
+            b'a'..=b'z' => c - b'a' + 26,
+            b'0'..=b'9' => c - b'0' + 52,
+            b'+' => 62,
+            b'/' => 63,
+            _ => return None,
+        };
+        buffer = (buffer << 6) | u32::from(value);
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            output.push(u8::try_from((buffer >> bits) & 0xff).ok()?);
+        }
+    }
+    Some(output)
+}
+
+/// The registry credentials, & proxies, of the docker CLI's `config.json`, so that private images are pulled as they are with `docker pull`, & new containers are given the proxies as with `docker run`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DockerConfig {
+    value: Value,
+}
+
+impl DockerConfig {
+    /// Read `config.json` from the docker CLI config directory, a missing, or invalid, file has no credentials
+    /// It's read for each pull, so that a `docker login` while oxker is open is used straight away
+    pub fn load() -> Self {
+        DockerContext::config_dir()
+            .and_then(|dir| Self::read(&dir.join("config.json")))
+            .unwrap_or_default()
+    }
+
+    fn read(path: &Path) -> Option<Self> {
+        Self::parse(&std::fs::read_to_string(path).ok()?)
+    }
+
+    fn parse(text: &str) -> Option<Self> {
+        serde_json::from_str::<Value>(text)
+            .ok()
+            .filter(Value::is_object)
+            .map(|value| Self { value })
+    }
+
+    /// The credential helper for a registry, its own from `credHelpers`, or else the `credsStore` for every registry
+    fn helper(&self, registry: &str) -> Option<&str> {
+        let host = registry_host(registry);
+        self.value
+            .get("credHelpers")
+            .and_then(Value::as_object)
+            .and_then(|i| i.iter().find(|(key, _)| registry_host(key) == host))
+            .and_then(|(_, helper)| helper.as_str())
+            .or_else(|| self.value.get("credsStore").and_then(Value::as_str))
+            .filter(|i| !i.is_empty())
+    }
+
+    /// The credentials stored in `auths`, either the base64 `user:password` of `auth`, or an `identitytoken`
+    fn auths(&self, registry: &str) -> Option<DockerCredentials> {
+        let host = registry_host(registry);
+        let (_, entry) = self
+            .value
+            .get("auths")
+            .and_then(Value::as_object)?
+            .iter()
+            .find(|(key, _)| registry_host(key) == host)?;
+        let field = |key: &str| {
+            entry
+                .get(key)
+                .and_then(Value::as_str)
+                .filter(|i| !i.is_empty())
+                .map(ToOwned::to_owned)
+        };
+        let (username, password) = field("auth")
+            .and_then(|i| String::from_utf8(decode_base64(&i)?).ok())
+            .and_then(|i| {
+                i.split_once(':')
+                    .map(|(user, password)| (user.to_owned(), password.to_owned()))
+            })
+            .map_or((None, None), |(user, password)| {
+                (Some(user), Some(password))
+            });
+        let identitytoken = field("identitytoken");
+        (username.is_some() || identitytoken.is_some()).then(|| DockerCredentials {
+            username,
+            password,
+            identitytoken,
+            serveraddress: Some(registry.to_owned()),
+            ..Default::default()
+        })
+    }
+
+    /// The credentials to pull an image with, from the registry's credential helper, else from `auths`, None if there aren't any, so the image is pulled anonymously
+    pub async fn credentials(&self, image: &str) -> Option<DockerCredentials> {
+        let registry = registry(image);
+        let helper = match self.helper(registry) {
+            Some(helper) => helper_credentials(helper, registry).await,
+            None => None,
+        };
+        helper.or_else(|| self.auths(registry))
+    }
+    /// Add the proxies of `proxies` to the env of a new container, the entry of its daemon host, else the `default` entry, as the docker CLI does
+    /// Each is set in both upper, & lower, case, unless the env already sets that name
+    pub fn with_proxies(&self, host: Option<&str>, mut env: Vec<String>) -> Vec<String> {
+        let Some(proxies) = self.value.get("proxies").and_then(Value::as_object) else {
+            return env;
+        };
+        let Some(proxy) = host
+            .and_then(|host| proxies.get(host))
+            .or_else(|| proxies.get("default"))
+        else {
+            return env;
+        };
+        for (key, name) in PROXY_ENV {
+            let Some(value) = proxy
+                .get(key)
+                .and_then(Value::as_str)
+                .filter(|i| !i.is_empty())
+            else {
+                continue;
+            };
+            for name in [name.to_owned(), name.to_lowercase()] {
+                let prefix = format!("{name}=");
+                if !env.iter().any(|i| i == &name || i.starts_with(&prefix)) {
+                    env.push(format!("{prefix}{value}"));
+                }
+            }
+        }
+        env
+    }
+}
+
+/// Parse the output of a credential helper's `get`, `{"ServerURL": "", "Username": "", "Secret": ""}`
+fn parse_helper(text: &str, registry: &str) -> Option<DockerCredentials> {
+    let value = serde_json::from_str::<Value>(text).ok()?;
+    let username = value.get("Username")?.as_str()?;
+    let secret = value.get("Secret")?.as_str()?.to_owned();
+    let mut credentials = DockerCredentials {
+        serveraddress: Some(registry.to_owned()),
+        ..Default::default()
+    };
+    if username == TOKEN_USERNAME {
+        credentials.identitytoken = Some(secret);
+    } else {
+        credentials.username = Some(username.to_owned());
+        credentials.password = Some(secret);
+    }
+    Some(credentials)
+}
+
+/// Ask a credential helper, `docker-credential-[helper] get`, for the credentials of a registry, the registry is written to its stdin, as the docker CLI does
+async fn helper_credentials(helper: &str, registry: &str) -> Option<DockerCredentials> {
+    let mut child = tokio::process::Command::new(format!("docker-credential-{helper}"))
+        .arg("get")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .kill_on_drop(true)
+        .spawn()
+        .ok()?;
+    let mut stdin = child.stdin.take()?;
+    stdin.write_all(registry.as_bytes()).await.ok()?;
+    drop(stdin);
+    let output = child.wait_with_output().await.ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    parse_helper(&String::from_utf8_lossy(&output.stdout), registry)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::{decode_base64, parse_helper, registry, DockerConfig, DOCKER_HUB};
+
+    #[test]
+    /// Images without a registry host are from Docker Hub, as are the Docker Hub hosts themselves
+    fn test_docker_config_registry() {
+        assert_eq!(registry("nginx:1.27"), DOCKER_HUB);
+        assert_eq!(registry("library/redis"), DOCKER_HUB);
+        assert_eq!(registry("docker.io/library/redis"), DOCKER_HUB);
+        assert_eq!(registry("ghcr.io/owner/app:1"), "ghcr.io");
+        assert_eq!(registry("localhost/app"), "localhost");
+        assert_eq!(registry("registry.local:5000/app"), "registry.local:5000");
+    }
+
+    #[test]
+    /// Base64, with, or without, padding, anything else is invalid
+    fn test_docker_config_decode_base64() {
+        assert_eq!(decode_base64("dXNlcjpwYXNz").unwrap(), b"user:pass");
+        assert_eq!(decode_base64("YQ==").unwrap(), b"a");
+        assert_eq!(decode_base64("YQ").unwrap(), b"a");
+        assert!(decode_base64("a b").is_none());
+    }
+
+    #[tokio::test]
+    /// Credentials are found by registry host, from the `auth` or `identitytoken` of `auths`, a registry without an entry is pulled anonymously
+    async fn test_docker_config_auths() {
+        let config = DockerConfig::parse(
+            r#"{"auths": {
+                "https://index.docker.io/v1/": {"auth": "dXNlcjpwYXNz"},
+                "ghcr.io": {"identitytoken": "token"},
+                "quay.io": {}
+            }}"#,
+        )
+        .unwrap();
+        let hub = config.credentials("nginx").await.unwrap();
+        assert_eq!(hub.username.as_deref(), Some("user"));
+        assert_eq!(hub.password.as_deref(), Some("pass"));
+        assert_eq!(hub.serveraddress.as_deref(), Some(DOCKER_HUB));
+        assert_eq!(
+            config.credentials("docker.io/library/nginx").await,
+            Some(hub)
+        );
+
+        let ghcr = config.credentials("ghcr.io/owner/app").await.unwrap();
+        assert_eq!(ghcr.identitytoken.as_deref(), Some("token"));
+        assert_eq!(ghcr.username, None);
+
+        assert!(config.credentials("quay.io/owner/app").await.is_none());
+        assert!(config.credentials("registry.local/app").await.is_none());
+        assert!(DockerConfig::parse("[]").is_none());
+    }
+
+    #[test]
+    /// A registry's own credential helper is used before the credential store, either only if set
+    fn test_docker_config_helper() {
+        let config = DockerConfig::parse(
+            r#"{"credsStore": "desktop", "credHelpers": {"123.dkr.ecr.eu-west-1.amazonaws.com": "ecr-login"}}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            config.helper("123.dkr.ecr.eu-west-1.amazonaws.com"),
+            Some("ecr-login")
+        );
+        assert_eq!(config.helper(DOCKER_HUB), Some("desktop"));
+        assert_eq!(DockerConfig::default().helper(DOCKER_HUB), None);
+
+        let credentials = parse_helper(
+            r#"{"ServerURL": "ghcr.io", "Username": "user", "Secret": "pass"}"#,
+            "ghcr.io",
+        )
+        .unwrap();
+        assert_eq!(credentials.username.as_deref(), Some("user"));
+        assert_eq!(credentials.password.as_deref(), Some("pass"));
+        let credentials = parse_helper(
+            r#"{"ServerURL": "ghcr.io", "Username": "<token>", "Secret": "token"}"#,
+            "ghcr.io",
+        )
+        .unwrap();
+        assert_eq!(credentials.identitytoken.as_deref(), Some("token"));
+        assert_eq!(credentials.username, None);
+        assert!(parse_helper("not json", "ghcr.io").is_none());
+    }
+
+    #[test]
+    /// The daemon host's proxies are used before the default proxies, & a name already in the env isn't replaced
+    fn test_docker_config_with_proxies() {
+        let config = DockerConfig::parse(
+            r#"{"proxies": {
+                "default": {"httpProxy": "http://proxy:3128", "noProxy": "localhost", "ftpProxy": ""},
+                "tcp://remote:2375": {"httpsProxy": "http://remote:3128"}
+            }}"#,
+        )
+        .unwrap();
+        let env = config.with_proxies(None, vec!["A=1".to_owned(), "no_proxy=*.local".to_owned()]);
+        assert_eq!(
+            env,
+            [
+                "A=1",
+                "no_proxy=*.local",
+                "HTTP_PROXY=http://proxy:3128",
+                "http_proxy=http://proxy:3128",
+                "NO_PROXY=localhost",
+            ]
+        );
+        assert_eq!(
+            config.with_proxies(Some("tcp://remote:2375"), vec![]),
+            [
+                "HTTPS_PROXY=http://remote:3128",
+                "https_proxy=http://remote:3128"
+            ]
+        );
+        assert_eq!(
+            config.with_proxies(Some("tcp://other:2375"), vec![]).len(),
+            4
+        );
+        assert!(DockerConfig::default()
+            .with_proxies(None, vec![])
+            .is_empty());
+    }
+}
@@ -0,0 +1,373 @@
+use std::{
+    collections::HashMap,
+    sync::Arc,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use bollard::{
+    service::{EventMessage, EventMessageTypeEnum},
+    system::EventsOptions,
+    Docker,
+};
+use futures_util::StreamExt;
+use parking_lot::Mutex;
+
+use crate::{
+    app_data::{AppData, ContainerId, DockerEvent, EventKind},
+    ui::{GuiState, NotifyEvent, SelectablePanel, TimelineKind},
+};
+
+/// How long to wait before subscribing to the events again, after the stream has ended, or failed
+const RESUBSCRIBE_DELAY: Duration = Duration::from_secs(5);
+
+/// Convert a Docker event, only container lifecycle events, & image pulls, are kept
+pub fn event(message: &EventMessage, host: Option<&str>) -> Option<DockerEvent> {
+    let actor = message.actor.as_ref()?;
+    let attribute = |key: &str| actor.attributes.as_ref().and_then(|i| i.get(key));
+    let action = message.action.as_deref()?;
+    // The id of an image pull is the image that was pulled, its name attribute is missing the tag
+    let (kind, id, name) = match message.typ? {
+        EventMessageTypeEnum::CONTAINER => {
+            let exit_code = attribute("exitCode").and_then(|i| i.parse().ok());
+            (
+                EventKind::Container(TimelineKind::from_action(action, exit_code)?),
+                actor.id.as_deref().map(ContainerId::from),
+                attribute("name").or(actor.id.as_ref())?,
+            )
+        }
+        EventMessageTypeEnum::IMAGE if action == "pull" => {
+            (EventKind::Pull, None, actor.id.as_ref()?)
+        }
+        _ => return None,
+    };
+    Some(DockerEvent {
+        time: message.time?,
+        host: host.map(ToOwned::to_owned),
+        id,
+        name: name.clone(),
+        kind,
+    })
+}
+
+/// The images, volumes, networks, & services panels, whose listing an event changes, a container being created, or destroyed, changes which containers use each of them
+/// A service's replicas only change as its tasks are scheduled, which isn't an event of its own, so they're left to the regular listing
+pub fn changed_panels(message: &EventMessage) -> &'static [SelectablePanel] {
+    let action = message.action.as_deref().unwrap_or_default();
+    match (message.typ, action) {
+        (Some(EventMessageTypeEnum::CONTAINER), "create" | "destroy") => &[
+            SelectablePanel::Images,
+            SelectablePanel::Volumes,
+            SelectablePanel::Networks,
+        ],
+        (
+            Some(EventMessageTypeEnum::IMAGE),
+            "delete" | "import" | "load" | "prune" | "pull" | "tag" | "untag",
+        ) => &[SelectablePanel::Images],
+        (Some(EventMessageTypeEnum::VOLUME), "create" | "destroy" | "prune") => {
+            &[SelectablePanel::Volumes]
+        }
+        (
+            Some(EventMessageTypeEnum::NETWORK),
+            "connect" | "create" | "destroy" | "disconnect" | "prune",
+        ) => &[SelectablePanel::Networks],
+        (Some(EventMessageTypeEnum::SERVICE), "create" | "remove" | "update") => {
+            &[SelectablePanel::Services]
+        }
+        _ => &[],
+    }
+}
+
+/// A container exiting with a non-zero code is shown in the info box, unless it's the selected container, whose logs are already being looked at
+/// The bell is rung for any container, as well as for one that's killed for running out of memory, or turns unhealthy, & a desktop notification is sent for each type of event given to `--notify`, even for the selected container, as oxker may not be in view
+fn notify(app_data: &Mutex<AppData>, gui_state: &Mutex<GuiState>, event: &DockerEvent) {
+    if event.is_alert() {
+        gui_state.lock().ring_bell();
+    }
+    let host = event
+        .host
+        .as_ref()
+        .map_or_else(String::new, |i| format!("{i}: "));
+    let notified = NotifyEvent::of(event).is_some_and(|i| app_data.lock().args.notify.contains(&i));
+    if notified {
+        gui_state.lock().notify(format!("{host}{event}"));
+    }
+    let Some(code) = event.failed_exit() else {
+        return;
+    };
+    let selected = app_data
+        .lock()
+        .get_selected_container()
+        .is_some_and(|i| Some(&i.id) == event.id.as_ref() && i.host == event.host);
+    if !selected {
+        gui_state
+            .lock()
+            .set_info_box(&format!("{host}{} exited with code {code}", event.name));
+    }
+}
+
+/// A restart that makes a restart storm, a container crash looping, is shown in the info box, & rings the bell, even for the selected container, as a restart quicker than the update interval isn't otherwise seen
+fn alert_storm(
+    app_data: &Mutex<AppData>,
+    gui_state: &Mutex<GuiState>,
+    event: &DockerEvent,
+    now: u64,
+) {
+    let Some(text) = app_data.lock().record_restart(event, now) else {
+        return;
+    };
+    let host = event
+        .host
+        .as_ref()
+        .map_or_else(String::new, |i| format!("{i}: "));
+    let mut gui_state = gui_state.lock();
+    gui_state.ring_bell();
+    gui_state.set_info_box(&format!("{host}{text}"));
+    drop(gui_state);
+}
+
+/// Follow the daemon's events, adding each to the events pane, & marking the resources that they change, the stream is subscribed to again if it ends
+/// Events since the last one received are replayed on subscribing again, so that none are missed
+pub async fn subscribe(
+    docker: Arc<Docker>,
+    app_data: Arc<Mutex<AppData>>,
+    gui_state: Arc<Mutex<GuiState>>,
+    host: Option<String>,
+) {
+    let mut last = None::<i64>;
+    loop {
+        let options = EventsOptions {
+            since: last.map(|i| (i / 1_000_000_000).to_string()),
+            until: None,
+            filters: HashMap::from([(
+                "type",
+                vec!["container", "image", "network", "service", "volume"],
+            )]),
+        };
+        let mut stream = docker.events(Some(options));
+        while let Some(Ok(message)) = stream.next().await {
+            let time = message
+                .time_nano
+                .or_else(|| message.time.map(|i| i * 1_000_000_000));
+            if last.is_some_and(|last| time.is_some_and(|time| time <= last)) {
+                continue;
+            }
+            last = time.or(last);
+            let panels = changed_panels(&message);
+            if !panels.is_empty() {
+                let mut app_data = app_data.lock();
+                for panel in panels {
+                    app_data.set_resources_changed(host.as_deref(), *panel);
+                }
+            }
+            if let Some(event) = event(&message, host.as_deref()) {
+                notify(&app_data, &gui_state, &event);
+                let now = SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .map_or(0, |i| i.as_secs());
+                alert_storm(&app_data, &gui_state, &event, now);
+                let mut app_data = app_data.lock();
+                // A restart can be quicker than an update, so it wouldn't be seen as a change of state
+                if let (
+                    Some(id),
+                    EventKind::Container(
+                        TimelineKind::Die(_) | TimelineKind::Restart | TimelineKind::Start,
+                    ),
+                ) = (event.id.as_ref(), event.kind)
+                {
+                    app_data.set_exit_info_stale(id, event.host.as_deref());
+                }
+                app_data.push_event(event);
+            }
+        }
+        tokio::time::sleep(RESUBSCRIBE_DELAY).await;
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use std::collections::HashMap;
+
+    use bollard::service::{EventActor, EventMessage, EventMessageTypeEnum};
+    use parking_lot::Mutex;
+
+    use super::{alert_storm, changed_panels, event, notify};
+    use crate::{
+        app_data::{ContainerId, DockerEvent, EventKind},
+        tests::{gen_appdata, gen_containers},
+        ui::{GuiState, NotifyEvent, SelectablePanel, TimelineKind},
+    };
+
+    fn gen_message(
+        typ: EventMessageTypeEnum,
+        action: &str,
+        id: &str,
+        attributes: &[(&str, &str)],
+    ) -> EventMessage {
+        EventMessage {
+            typ: Some(typ),
+            action: Some(action.to_owned()),
+            actor: Some(EventActor {
+                id: Some(id.to_owned()),
+                attributes: Some(
+                    attributes
+                        .iter()
+                        .map(|(k, v)| ((*k).to_owned(), (*v).to_owned()))
+                        .collect::<HashMap<_, _>>(),
+                ),
+            }),
+            time: Some(10),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    /// Container lifecycle events, & image pulls, are kept, with the container name, & exit code, from the attributes
+    fn test_events_event() {
+        let message = gen_message(
+            EventMessageTypeEnum::CONTAINER,
+            "die",
+            "abc",
+            &[("exitCode", "137"), ("name", "api")],
+        );
+        assert_eq!(
+            event(&message, Some("build")),
+            Some(DockerEvent {
+                time: 10,
+                host: Some("build".to_owned()),
+                id: Some(ContainerId::from("abc")),
+                name: "api".to_owned(),
+                kind: EventKind::Container(TimelineKind::Die(Some(137))),
+            })
+        );
+
+        let message = gen_message(
+            EventMessageTypeEnum::IMAGE,
+            "pull",
+            "nginx:latest",
+            &[("name", "nginx")],
+        );
+        let result = event(&message, None).unwrap();
+        assert_eq!(result.kind, EventKind::Pull);
+        assert_eq!(result.name, "nginx:latest");
+        assert!(result.id.is_none());
+
+        for (typ, action) in [
+            (EventMessageTypeEnum::CONTAINER, "exec_create: sh"),
+            (EventMessageTypeEnum::IMAGE, "tag"),
+            (EventMessageTypeEnum::NETWORK, "connect"),
+        ] {
+            assert!(event(&gen_message(typ, action, "abc", &[]), None).is_none());
+        }
+    }
+
+    #[test]
+    /// Only the types of event given to `--notify` send a notification, with the host, even for the selected container
+    fn test_events_notify() {
+        let (_ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        app_data.args.notify = vec![NotifyEvent::Oom, NotifyEvent::Exit];
+        let app_data = Mutex::new(app_data);
+        let gui_state = Mutex::new(GuiState::default());
+        let event = |kind| DockerEvent {
+            time: 10,
+            host: Some("build".to_owned()),
+            id: Some(ContainerId::from("1")),
+            name: "container_1".to_owned(),
+            kind: EventKind::Container(kind),
+        };
+
+        for kind in [
+            TimelineKind::Die(Some(1)),
+            TimelineKind::Die(Some(0)),
+            TimelineKind::Unhealthy,
+            TimelineKind::Oom,
+        ] {
+            notify(&app_data, &gui_state, &event(kind));
+        }
+        assert_eq!(
+            gui_state.lock().take_notifications(),
+            [
+                "build: container_1 died, exit code 1",
+                "build: container_1 out of memory"
+            ]
+        );
+        assert!(gui_state.lock().take_notifications().is_empty());
+    }
+
+    #[test]
+    /// The third restart within the window alerts the storm, with the host, & rings the bell, a start without a die first isn't a restart
+    fn test_events_alert_storm() {
+        let (_ids, mut containers) = gen_containers();
+        containers[0].host = Some("build".to_owned());
+        let app_data = Mutex::new(gen_appdata(&containers));
+        let gui_state = Mutex::new(GuiState::default());
+        let event = |kind| DockerEvent {
+            time: 10,
+            host: Some("build".to_owned()),
+            id: Some(ContainerId::from("1")),
+            name: "container_1".to_owned(),
+            kind: EventKind::Container(kind),
+        };
+
+        alert_storm(&app_data, &gui_state, &event(TimelineKind::Start), 100);
+        for now in [110, 120] {
+            alert_storm(
+                &app_data,
+                &gui_state,
+                &event(TimelineKind::Die(Some(1))),
+                now,
+            );
+            alert_storm(&app_data, &gui_state, &event(TimelineKind::Start), now);
+        }
+        assert!(!gui_state.lock().take_bell());
+        assert_eq!(app_data.lock().get_restart_storms_len(), 0);
+
+        alert_storm(
+            &app_data,
+            &gui_state,
+            &event(TimelineKind::Die(Some(1))),
+            130,
+        );
+        alert_storm(&app_data, &gui_state, &event(TimelineKind::Start), 130);
+        assert!(gui_state.lock().take_bell());
+        assert_eq!(
+            gui_state
+                .lock()
+                .info_box_text
+                .as_ref()
+                .map(|i| i.0.as_str()),
+            Some("build: container_1 restarted 3 times in the last 10 minutes")
+        );
+        assert_eq!(app_data.lock().get_restart_storms_len(), 1);
+        assert_eq!(
+            app_data.lock().get_container_items()[0].status_text(),
+            "restarted 3x in 10m"
+        );
+    }
+
+    #[test]
+    /// Resource events change their own panel, a container being created, or destroyed, changes every panel
+    fn test_events_changed_panels() {
+        let panels = |typ, action| changed_panels(&gen_message(typ, action, "abc", &[]));
+        assert_eq!(
+            panels(EventMessageTypeEnum::IMAGE, "delete"),
+            [SelectablePanel::Images]
+        );
+        assert_eq!(
+            panels(EventMessageTypeEnum::VOLUME, "create"),
+            [SelectablePanel::Volumes]
+        );
+        assert_eq!(
+            panels(EventMessageTypeEnum::NETWORK, "connect"),
+            [SelectablePanel::Networks]
+        );
+        assert_eq!(
+            panels(EventMessageTypeEnum::SERVICE, "update"),
+            [SelectablePanel::Services]
+        );
+        assert_eq!(panels(EventMessageTypeEnum::CONTAINER, "destroy").len(), 3);
+        assert!(panels(EventMessageTypeEnum::CONTAINER, "start").is_empty());
+        assert!(panels(EventMessageTypeEnum::VOLUME, "mount").is_empty());
+    }
+}
@@ -0,0 +1,590 @@
+use std::{
+    collections::BTreeSet,
+    fmt::Write as _,
+    path::{Path, PathBuf},
+};
+
+use bollard::service::{
+    ContainerConfig, ContainerInspectResponse, MountPointTypeEnum, RestartPolicy,
+    RestartPolicyNameEnum,
+};
+
+use super::recreate;
+
+/// Labels set by compose itself, which would conflict with the project the exported service is added to
+const COMPOSE_LABEL: &str = "com.docker.compose.";
+
+/// The settings of a container that differ from the defaults of its image, or of the Docker daemon, everything else is left out of the export
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+struct Definition {
+    cap_add: Vec<String>,
+    cap_drop: Vec<String>,
+    command: Vec<String>,
+    cpus: Option<String>,
+    entrypoint: Option<Vec<String>>,
+    env: Vec<String>,
+    extra_hosts: Vec<String>,
+    hostname: Option<String>,
+    image: String,
+    interactive: bool,
+    labels: Vec<String>,
+    memory: Option<i64>,
+    mounts: Vec<String>,
+    name: String,
+    network_mode: Option<String>,
+    networks: Vec<String>,
+    ports: Vec<String>,
+    privileged: bool,
+    restart: Option<String>,
+    tty: bool,
+    user: Option<String>,
+    volumes: Vec<String>,
+    working_dir: Option<String>,
+}
+
+/// Some only when the container value is set, and isn't the same as the image value
+fn changed(container: Option<&String>, image: Option<&String>) -> Option<String> {
+    container
+        .filter(|i| !i.is_empty() && Some(*i) != image)
+        .cloned()
+}
+
+/// The restart policy as a `--restart` value, None for the default `no` policy
+pub fn restart(policy: &RestartPolicy) -> Option<String> {
+    let name = policy.name?;
+    match name {
+        RestartPolicyNameEnum::EMPTY | RestartPolicyNameEnum::NO => None,
+        RestartPolicyNameEnum::ON_FAILURE => Some(
+            policy
+                .maximum_retry_count
+                .filter(|i| *i > 0)
+                .map_or_else(|| name.to_string(), |count| format!("{name}:{count}")),
+        ),
+        _ => Some(name.to_string()),
+    }
+}
+
+impl Definition {
+    #[allow(clippy::cast_precision_loss)]
+    fn new(inspect: &ContainerInspectResponse, image: Option<&ContainerConfig>) -> Self {
+        let config = inspect.config.clone().unwrap_or_default();
+        let host_config = inspect.host_config.clone().unwrap_or_default();
+        let image_env = image.and_then(|i| i.env.clone()).unwrap_or_default();
+        let image_labels = image.and_then(|i| i.labels.clone()).unwrap_or_default();
+
+        let mut labels = config
+            .labels
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|(k, v)| !k.starts_with(COMPOSE_LABEL) && image_labels.get(k) != Some(v))
+            .map(|(k, v)| format!("{k}={v}"))
+            .collect::<Vec<_>>();
+        labels.sort();
+
+        let id = inspect.id.clone().unwrap_or_default();
+        let mut networks = inspect
+            .network_settings
+            .as_ref()
+            .and_then(|i| i.networks.as_ref())
+            .map_or_else(Vec::new, |i| {
+                i.keys()
+                    .filter(|i| !["bridge", "host", "none"].contains(&i.as_str()))
+                    .cloned()
+                    .collect()
+            });
+        networks.sort();
+        // A custom network mode is the primary network of the container, so it's listed first, only host, none, & container:[id] are kept as a mode
+        let network_mode = host_config
+            .network_mode
+            .filter(|i| !["", "default", "bridge"].contains(&i.as_str()));
+        let primary = network_mode
+            .as_ref()
+            .and_then(|mode| networks.iter().position(|i| i == mode));
+        if let Some(index) = primary {
+            networks[..=index].rotate_right(1);
+        }
+        let network_mode = network_mode.filter(|_| primary.is_none());
+
+        let image_cmd = image.and_then(|i| i.cmd.clone());
+        let image_entrypoint = image.and_then(|i| i.entrypoint.clone());
+        let entrypoint = config.entrypoint.unwrap_or_default();
+        // A new entrypoint resets the image command, so it must always be given alongside it
+        let entrypoint_changed = Some(&entrypoint) != image_entrypoint.as_ref().or(Some(&vec![]));
+        let command = config.cmd.unwrap_or_default();
+        let command = if entrypoint_changed || Some(&command) != image_cmd.as_ref() {
+            command
+        } else {
+            vec![]
+        };
+
+        Self {
+            cap_add: host_config.cap_add.unwrap_or_default(),
+            cap_drop: host_config.cap_drop.unwrap_or_default(),
+            command,
+            cpus: host_config
+                .nano_cpus
+                .filter(|i| *i > 0)
+                .map(|i| format!("{}", i as f64 / 1_000_000_000.0)),
+            entrypoint: entrypoint_changed.then_some(entrypoint),
+            env: config
+                .env
+                .unwrap_or_default()
+                .into_iter()
+                .filter(|i| !image_env.contains(i))
+                .collect(),
+            extra_hosts: host_config.extra_hosts.unwrap_or_default(),
+            hostname: config
+                .hostname
+                .filter(|i| network_mode.is_none() && !id.starts_with(i.as_str())),
+            image: config.image.unwrap_or_default(),
+            interactive: config.open_stdin.unwrap_or_default(),
+            labels,
+            memory: host_config.memory.filter(|i| *i > 0),
+            mounts: recreate::mounts(inspect),
+            name: inspect
+                .name
+                .as_deref()
+                .map_or_else(String::new, |i| i.trim_start_matches('/').to_owned()),
+            network_mode,
+            networks,
+            ports: recreate::ports(inspect),
+            privileged: host_config.privileged.unwrap_or_default(),
+            restart: host_config.restart_policy.as_ref().and_then(restart),
+            tty: config.tty.unwrap_or_default(),
+            user: changed(config.user.as_ref(), image.and_then(|i| i.user.as_ref())),
+            volumes: inspect.mounts.as_ref().map_or_else(Vec::new, |i| {
+                i.iter()
+                    .filter(|i| i.typ == Some(MountPointTypeEnum::VOLUME))
+                    .filter_map(|i| i.name.clone())
+                    .collect::<BTreeSet<_>>()
+                    .into_iter()
+                    .collect()
+            }),
+            working_dir: changed(
+                config.working_dir.as_ref(),
+                image.and_then(|i| i.working_dir.as_ref()),
+            ),
+        }
+    }
+
+    /// An equivalent `docker run` command, one option per line
+    fn to_run(&self) -> String {
+        let mut args = vec![
+            "docker run -d".to_owned(),
+            format!("--name {}", shell_quote(&self.name)),
+        ];
+        let mut push =
+            |flag: &str, value: &str| args.push(format!("{flag} {}", shell_quote(value)));
+
+        if let Some(hostname) = &self.hostname {
+            push("--hostname", hostname);
+        }
+        if let Some(restart) = &self.restart {
+            push("--restart", restart);
+        }
+        if let Some(network) = self.network_mode.as_ref().or_else(|| self.networks.first()) {
+            push("--network", network);
+        }
+        for port in &self.ports {
+            push("-p", port);
+        }
+        for mount in &self.mounts {
+            push("-v", mount);
+        }
+        for env in &self.env {
+            push("-e", env);
+        }
+        for label in &self.labels {
+            push("--label", label);
+        }
+        for host in &self.extra_hosts {
+            push("--add-host", host);
+        }
+        for cap in &self.cap_add {
+            push("--cap-add", cap);
+        }
+        for cap in &self.cap_drop {
+            push("--cap-drop", cap);
+        }
+        if let Some(user) = &self.user {
+            push("--user", user);
+        }
+        if let Some(working_dir) = &self.working_dir {
+            push("--workdir", working_dir);
+        }
+        if let Some(memory) = self.memory {
+            push("--memory", &format!("{memory}b"));
+        }
+        if let Some(cpus) = &self.cpus {
+            push("--cpus", cpus);
+        }
+        // docker run only takes a single entrypoint value, any further parts are passed as the start of the command
+        let mut command = self.command.clone();
+        if let Some(entrypoint) = &self.entrypoint {
+            let mut parts = entrypoint.iter();
+            push("--entrypoint", parts.next().map_or("", String::as_str));
+            command.splice(0..0, parts.cloned());
+        }
+        if self.privileged {
+            args.push("--privileged".to_owned());
+        }
+        if self.interactive {
+            args.push("-i".to_owned());
+        }
+        if self.tty {
+            args.push("-t".to_owned());
+        }
+        let mut image = shell_quote(&self.image);
+        for i in &command {
+            write!(image, " {}", shell_quote(i)).ok();
+        }
+        args.push(image);
+
+        let mut output = args.join(" \\\n  ");
+        // Only the first custom network can be given to docker run, the others are connected after it's created
+        for network in self
+            .networks
+            .iter()
+            .skip(usize::from(self.network_mode.is_none()))
+        {
+            write!(
+                output,
+                "\ndocker network connect {} {}",
+                shell_quote(network),
+                shell_quote(&self.name)
+            )
+            .ok();
+        }
+        output.push('\n');
+        output
+    }
+
+    /// A compose file with a single service, networks & volumes are declared as external, as they already exist
+    fn to_compose(&self) -> String {
+        let mut output = format!(
+            "services:\n  {}:\n    image: {}\n    container_name: {}\n",
+            yaml_quote(&self.name),
+            yaml_quote(&self.image),
+            yaml_quote(&self.name)
+        );
+        let mut scalar = |key: &str, value: &str| {
+            writeln!(output, "    {key}: {}", yaml_quote(value)).ok();
+        };
+        if let Some(hostname) = &self.hostname {
+            scalar("hostname", hostname);
+        }
+        if let Some(restart) = &self.restart {
+            scalar("restart", restart);
+        }
+        if let Some(network_mode) = &self.network_mode {
+            scalar("network_mode", network_mode);
+        }
+        if let Some(user) = &self.user {
+            scalar("user", user);
+        }
+        if let Some(working_dir) = &self.working_dir {
+            scalar("working_dir", working_dir);
+        }
+        if let Some(memory) = self.memory {
+            scalar("mem_limit", &format!("{memory}b"));
+        }
+        if let Some(cpus) = &self.cpus {
+            scalar("cpus", cpus);
+        }
+        for (key, value) in [
+            ("privileged", self.privileged),
+            ("stdin_open", self.interactive),
+            ("tty", self.tty),
+        ] {
+            if value {
+                writeln!(output, "    {key}: true").ok();
+            }
+        }
+        if let Some(entrypoint) = &self.entrypoint {
+            writeln!(output, "    entrypoint: {}", yaml_flow(entrypoint)).ok();
+        }
+        if !self.command.is_empty() {
+            writeln!(output, "    command: {}", yaml_flow(&self.command)).ok();
+        }
+        for (key, values) in [
+            ("ports", &self.ports),
+            ("volumes", &self.mounts),
+            ("environment", &self.env),
+            ("labels", &self.labels),
+            ("extra_hosts", &self.extra_hosts),
+            ("cap_add", &self.cap_add),
+            ("cap_drop", &self.cap_drop),
+            ("networks", &self.networks),
+        ] {
+            yaml_list(&mut output, "    ", key, values);
+        }
+        for (key, values) in [("networks", &self.networks), ("volumes", &self.volumes)] {
+            if !values.is_empty() {
+                writeln!(output, "{key}:").ok();
+                for i in values {
+                    writeln!(output, "  {}:\n    external: true", yaml_quote(i)).ok();
+                }
+            }
+        }
+        output
+    }
+}
+
+/// Quote a shell argument, only when it contains chars that the shell would interpret
+fn shell_quote(input: &str) -> String {
+    let safe = !input.is_empty()
+        && input
+            .chars()
+            .all(|i| i.is_ascii_alphanumeric() || "-_./:=@%+,".contains(i));
+    if safe {
+        input.to_owned()
+    } else {
+        format!("'{}'", input.replace('\'', r"'\''"))
+    }
+}
+
+/// Double quote a yaml scalar, so that values such as `yes`, `8080:80`, or `1.0` are never converted into another type
+fn yaml_quote(input: &str) -> String {
+    let mut output = String::with_capacity(input.len() + 2);
+    output.push('"');
+    for c in input.chars() {
+        match c {
+            '"' => output.push_str("\\\""),
+            '\\' => output.push_str("\\\\"),
+            '\n' => output.push_str("\\n"),
+            '\t' => output.push_str("\\t"),
+            c => output.push(c),
+        }
+    }
+    output.push('"');
+    output
+}
+
+/// A yaml flow sequence, `["a", "b"]`
+fn yaml_flow(values: &[String]) -> String {
+    format!(
+        "[{}]",
+        values
+            .iter()
+            .map(|i| yaml_quote(i))
+            .collect::<Vec<_>>()
+            .join(", ")
+    )
+}
+
+fn yaml_list(output: &mut String, indent: &str, key: &str, values: &[String]) {
+    if !values.is_empty() {
+        writeln!(output, "{indent}{key}:").ok();
+        for i in values {
+            writeln!(output, "{indent}  - {}", yaml_quote(i)).ok();
+        }
+    }
+}
+
+/// Save the inspected container as a `docker run` script, and as a compose file, `[container_name]_[timestamp].sh` & `.yml`, returns the script path
+/// The image config is used to leave out the env, labels, command etc. that the container inherited from its image
+pub fn save(
+    inspect: &ContainerInspectResponse,
+    image: Option<&ContainerConfig>,
+    dir: &Path,
+    timestamp: u64,
+) -> std::io::Result<PathBuf> {
+    let definition = Definition::new(inspect, image);
+    let name = format!("{}_{timestamp}", definition.name);
+    let run_path = dir.join(format!("{name}.sh"));
+    std::fs::write(&run_path, format!("#!/bin/sh\n{}", definition.to_run()))?;
+    std::fs::write(dir.join(format!("{name}.yml")), definition.to_compose())?;
+    Ok(run_path)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use bollard::service::{
+        ContainerConfig, ContainerInspectResponse, EndpointSettings, HostConfig, MountPoint,
+        MountPointTypeEnum, NetworkSettings, PortBinding, RestartPolicy, RestartPolicyNameEnum,
+    };
+
+    use super::{shell_quote, yaml_quote, Definition};
+
+    fn gen_image() -> ContainerConfig {
+        ContainerConfig {
+            env: Some(vec!["PATH=/usr/bin".to_owned()]),
+            cmd: Some(vec![
+                "nginx".to_owned(),
+                "-g".to_owned(),
+                "daemon off;".to_owned(),
+            ]),
+            entrypoint: Some(vec!["/docker-entrypoint.sh".to_owned()]),
+            labels: Some(HashMap::from([(
+                "maintainer".to_owned(),
+                "nginx".to_owned(),
+            )])),
+            working_dir: Some("/".to_owned()),
+            ..Default::default()
+        }
+    }
+
+    fn gen_inspect() -> ContainerInspectResponse {
+        let image = gen_image();
+        ContainerInspectResponse {
+            id: Some("abcdef123456789".to_owned()),
+            name: Some("/web".to_owned()),
+            config: Some(ContainerConfig {
+                hostname: Some("abcdef123456".to_owned()),
+                image: Some("nginx:1.25".to_owned()),
+                env: Some(vec![
+                    "PATH=/usr/bin".to_owned(),
+                    "MODE=it's live".to_owned(),
+                ]),
+                labels: Some(HashMap::from([
+                    ("maintainer".to_owned(), "nginx".to_owned()),
+                    ("com.docker.compose.project".to_owned(), "site".to_owned()),
+                    ("tier".to_owned(), "front".to_owned()),
+                ])),
+                tty: Some(true),
+                ..image
+            }),
+            host_config: Some(HostConfig {
+                port_bindings: Some(HashMap::from([(
+                    "80/tcp".to_owned(),
+                    Some(vec![PortBinding {
+                        host_ip: Some("0.0.0.0".to_owned()),
+                        host_port: Some("8080".to_owned()),
+                    }]),
+                )])),
+                restart_policy: Some(RestartPolicy {
+                    name: Some(RestartPolicyNameEnum::ON_FAILURE),
+                    maximum_retry_count: Some(3),
+                }),
+                network_mode: Some("metrics".to_owned()),
+                memory: Some(0),
+                nano_cpus: Some(1_500_000_000),
+                ..Default::default()
+            }),
+            mounts: Some(vec![MountPoint {
+                typ: Some(MountPointTypeEnum::VOLUME),
+                name: Some("data".to_owned()),
+                destination: Some("/data".to_owned()),
+                rw: Some(true),
+                ..Default::default()
+            }]),
+            network_settings: Some(NetworkSettings {
+                networks: Some(HashMap::from([
+                    ("backend".to_owned(), EndpointSettings::default()),
+                    ("metrics".to_owned(), EndpointSettings::default()),
+                ])),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    /// Only settings that differ from the image, and the daemon defaults, are kept
+    fn test_export_definition() {
+        let image = gen_image();
+        let result = Definition::new(&gen_inspect(), Some(&image));
+        assert_eq!(result.name, "web");
+        assert_eq!(result.env, ["MODE=it's live"]);
+        assert_eq!(result.labels, ["tier=front"]);
+        assert!(result.command.is_empty());
+        assert!(result.entrypoint.is_none());
+        assert!(result.hostname.is_none());
+        assert!(result.working_dir.is_none());
+        assert_eq!(result.restart.as_deref(), Some("on-failure:3"));
+        assert!(result.network_mode.is_none());
+        assert_eq!(result.networks, ["metrics", "backend"]);
+        assert_eq!(result.cpus.as_deref(), Some("1.5"));
+        assert!(result.memory.is_none());
+        assert_eq!(result.volumes, ["data"]);
+
+        // Without the image config, everything the container has is exported
+        let result = Definition::new(&gen_inspect(), None);
+        assert_eq!(result.env.len(), 2);
+        assert_eq!(
+            result.entrypoint,
+            Some(vec!["/docker-entrypoint.sh".to_owned()])
+        );
+        assert_eq!(result.command.len(), 3);
+    }
+
+    #[test]
+    /// docker run command, with quoted arguments, and further networks connected afterwards
+    fn test_export_run() {
+        let image = gen_image();
+        let mut definition = Definition::new(&gen_inspect(), Some(&image));
+        definition.entrypoint = Some(vec!["sh".to_owned(), "-c".to_owned()]);
+        definition.command = vec!["echo hi".to_owned()];
+        assert_eq!(
+            definition.to_run(),
+            [
+                "docker run -d \\",
+                "  --name web \\",
+                "  --restart on-failure:3 \\",
+                "  --network metrics \\",
+                "  -p 8080:80 \\",
+                "  -v data:/data \\",
+                "  -e 'MODE=it'\\''s live' \\",
+                "  --label tier=front \\",
+                "  --cpus 1.5 \\",
+                "  --entrypoint sh \\",
+                "  -t \\",
+                "  nginx:1.25 -c 'echo hi'",
+                "docker network connect backend web",
+                ""
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    /// Compose service, with the existing networks & volumes as external
+    fn test_export_compose() {
+        let image = gen_image();
+        let definition = Definition::new(&gen_inspect(), Some(&image));
+        assert_eq!(
+            definition.to_compose(),
+            [
+                "services:",
+                "  \"web\":",
+                "    image: \"nginx:1.25\"",
+                "    container_name: \"web\"",
+                "    restart: \"on-failure:3\"",
+                "    cpus: \"1.5\"",
+                "    tty: true",
+                "    ports:",
+                "      - \"8080:80\"",
+                "    volumes:",
+                "      - \"data:/data\"",
+                "    environment:",
+                "      - \"MODE=it's live\"",
+                "    labels:",
+                "      - \"tier=front\"",
+                "    networks:",
+                "      - \"metrics\"",
+                "      - \"backend\"",
+                "networks:",
+                "  \"metrics\":",
+                "    external: true",
+                "  \"backend\":",
+                "    external: true",
+                "volumes:",
+                "  \"data\":",
+                "    external: true",
+                ""
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn test_export_quote() {
+        assert_eq!(shell_quote("nginx:1.25"), "nginx:1.25");
+        assert_eq!(shell_quote(""), "''");
+        assert_eq!(shell_quote("a b"), "'a b'");
+        assert_eq!(shell_quote("$HOME"), "'$HOME'");
+        assert_eq!(yaml_quote("say \"hi\"\\"), r#""say \"hi\"\\""#);
+    }
+}
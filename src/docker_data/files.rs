@@ -0,0 +1,224 @@
+use std::path::{Path, PathBuf};
+
+use bollard::{container::DownloadFromContainerOptions, Docker};
+use futures_util::StreamExt;
+
+use super::{bundle::file_name, host_snapshot::BLOCK, Runtime};
+use crate::{app_data::ContainerId, ui::FileEntry};
+
+/// The entries of a directory, one per line, dotfiles included, with a trailing `/` on directories, & symlinks followed, so a linked directory is marked as one
+/// These flags are shared by coreutils, & busybox, so work in most images, a distroless image has no ls, so can't be browsed
+fn ls_command(path: &str) -> Vec<String> {
+    ["ls", "-1ApL", "--", path]
+        .into_iter()
+        .map(ToOwned::to_owned)
+        .collect()
+}
+
+/// Parse the output of ls_command
+fn entries(output: &str) -> Vec<FileEntry> {
+    output
+        .lines()
+        .filter(|i| !i.is_empty())
+        .map(|i| {
+            i.strip_suffix('/').map_or_else(
+                || FileEntry {
+                    is_dir: false,
+                    name: i.to_owned(),
+                },
+                |name| FileEntry {
+                    is_dir: true,
+                    name: name.to_owned(),
+                },
+            )
+        })
+        .collect()
+}
+
+/// List a directory of a running container, with an exec of ls, a broken symlink doesn't stop the rest of the directory from being listed
+pub async fn list(
+    runtime: &dyn Runtime,
+    id: &ContainerId,
+    path: &str,
+) -> Result<Vec<FileEntry>, String> {
+    let output = runtime
+        .exec(id, ls_command(path))
+        .await
+        .map_err(|e| format!("unable to list {path}: {e}"))?;
+    match output.exit_code {
+        Some(0) => Ok(entries(&output.stdout)),
+        Some(_) if !output.stdout.is_empty() => Ok(entries(&output.stdout)),
+        Some(126 | 127) => Err("unable to list files, the container doesn't have ls".to_owned()),
+        _ => Err(format!(
+            "unable to list {path}: {}",
+            output.stderr.lines().next().unwrap_or("no output")
+        )),
+    }
+}
+
+/// Read a number from a tar header field, as octal, or as base-256 if the high bit of the first byte is set, as used for large files
+fn tar_number(field: &[u8]) -> Option<u64> {
+    match field.split_first() {
+        Some((first, rest)) if first & 0x80 != 0 => {
+            rest.iter().try_fold(u64::from(first & 0x7f), |acc, i| {
+                acc.checked_mul(256)?.checked_add(u64::from(*i))
+            })
+        }
+        _ => {
+            let text = String::from_utf8_lossy(field);
+            let text = text.trim_matches(|c: char| c == '\0' || c == ' ');
+            if text.is_empty() {
+                Some(0)
+            } else {
+                u64::from_str_radix(text, 8).ok()
+            }
+        }
+    }
+}
+
+/// The data of the file, if the archive is of a single regular file, pax, & gnu long name, headers before it are skipped
+/// None for a directory, or a link, which are saved as the archive itself
+fn single_file(archive: &[u8]) -> Option<&[u8]> {
+    let mut offset = 0;
+    while let Some(header) = archive.get(offset..offset + BLOCK) {
+        let size = usize::try_from(tar_number(&header[124..136])?).ok()?;
+        let data = offset + BLOCK;
+        match header[156] {
+            b'x' | b'g' | b'L' | b'K' => offset = data + size.div_ceil(BLOCK) * BLOCK,
+            b'0' | 0 if header.iter().any(|i| *i != 0) => return archive.get(data..data + size),
+            _ => return None,
+        }
+    }
+    None
+}
+
+/// Copy a file, or directory, out of a container, into the save_dir, as `docker cp` would, the daemon sends every copy as a tar archive
+/// A single file is extracted, as `[container]_[file]`, anything else is saved as the archive, `[container]_[directory].tar`
+pub async fn download(
+    docker: &Docker,
+    id: &ContainerId,
+    path: &str,
+    container: &str,
+    dir: &Path,
+) -> Result<PathBuf, String> {
+    let mut stream =
+        docker.download_from_container(id.get(), Some(DownloadFromContainerOptions { path }));
+    let mut archive = vec![];
+    while let Some(chunk) = stream.next().await {
+        archive.extend_from_slice(&chunk.map_err(|e| format!("unable to copy {path}: {e}"))?);
+    }
+    let base = path
+        .trim_end_matches('/')
+        .rsplit('/')
+        .next()
+        .unwrap_or_default();
+    let name = file_name(&format!("{container}_{base}"));
+    let (file, data) = single_file(&archive).map_or_else(
+        || (dir.join(format!("{name}.tar")), archive.as_slice()),
+        |data| (dir.join(&name), data),
+    );
+    std::fs::write(&file, data).map_err(|e| format!("unable to save {}: {e}", file.display()))?;
+    Ok(file)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::{entries, list, single_file, tar_number};
+    use crate::{
+        app_data::ContainerId,
+        docker_data::{
+            host_snapshot::{tar_entry, BLOCK},
+            runtime::{ExecOutput, MockRuntime},
+        },
+    };
+
+    #[test]
+    /// Directories are marked with a trailing `/`, which isn't part of the name
+    fn test_files_entries() {
+        let output = entries(".env\nbin/\nnginx.conf\n\n");
+        assert_eq!(output.len(), 3);
+        assert_eq!(output[0].name, ".env");
+        assert!(!output[0].is_dir);
+        assert_eq!(output[1].name, "bin");
+        assert!(output[1].is_dir);
+        assert!(!output[2].is_dir);
+    }
+
+    #[test]
+    fn test_files_tar_number() {
+        assert_eq!(tar_number(b"00000000017\0"), Some(15));
+        assert_eq!(tar_number(b"     17 \0"), Some(15));
+        assert_eq!(tar_number(&[0x80, 0, 0, 0, 0, 0, 0, 1, 0]), Some(256));
+        assert_eq!(tar_number(b"\0\0\0"), Some(0));
+        assert_eq!(tar_number(b"9x"), None);
+    }
+
+    #[test]
+    /// A single file is extracted, after any pax header, a directory isn't
+    fn test_files_single_file() {
+        let mut archive = vec![];
+        tar_entry(&mut archive, "hosts", b"127.0.0.1 localhost\n", 0);
+        archive.resize(archive.len() + BLOCK * 2, 0);
+        assert_eq!(single_file(&archive).unwrap(), b"127.0.0.1 localhost\n");
+
+        let mut pax = vec![];
+        tar_entry(
+            &mut pax,
+            "PaxHeaders/hosts",
+            b"30 path=a-very-long-file-name\n",
+            0,
+        );
+        pax[156] = b'x';
+        pax.extend_from_slice(&archive);
+        assert_eq!(single_file(&pax).unwrap(), b"127.0.0.1 localhost\n");
+
+        let mut directory = vec![];
+        tar_entry(&mut directory, "nginx/", b"", 0);
+        directory[156] = b'5';
+        tar_entry(&mut directory, "nginx/nginx.conf", b"events {}", 0);
+        assert!(single_file(&directory).is_none());
+        assert!(single_file(&[0; BLOCK * 2]).is_none());
+    }
+
+    #[tokio::test]
+    /// The ls output is listed, even when ls exits with an error part way through, a container without ls, or that can't exec, is an error
+    async fn test_files_list() {
+        let id = ContainerId::from("1");
+        let runtime = |stdout: &str, stderr: &str, exit_code| MockRuntime {
+            exec: Some(ExecOutput {
+                stdout: stdout.to_owned(),
+                stderr: stderr.to_owned(),
+                exit_code: Some(exit_code),
+            }),
+            ..Default::default()
+        };
+        let listed = list(&runtime("bin/\nhosts\n", "", 0), &id, "/etc")
+            .await
+            .unwrap();
+        assert_eq!(listed.len(), 2);
+        let listed = list(
+            &runtime("hosts\n", "ls: broken: No such file", 1),
+            &id,
+            "/etc",
+        )
+        .await;
+        assert_eq!(listed.unwrap().len(), 1);
+        assert_eq!(
+            list(&runtime("", "", 127), &id, "/etc").await.unwrap_err(),
+            "unable to list files, the container doesn't have ls"
+        );
+        assert_eq!(
+            list(&runtime("", "ls: /nope: No such file", 1), &id, "/nope")
+                .await
+                .unwrap_err(),
+            "unable to list /nope: ls: /nope: No such file"
+        );
+        assert_eq!(
+            list(&MockRuntime::default(), &id, "/etc")
+                .await
+                .unwrap_err(),
+            "unable to list /etc: no such container: 1"
+        );
+    }
+}
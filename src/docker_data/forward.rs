@@ -0,0 +1,214 @@
+use std::collections::HashMap;
+
+use bollard::{
+    container::{Config, CreateContainerOptions, RemoveContainerOptions, StartContainerOptions},
+    errors::Error,
+    service::{ContainerInspectResponse, HostConfig},
+    Docker,
+};
+
+use super::recreate;
+
+/// The image of the forward container, socat relays a port of the host to the exposed port of the container
+pub const FORWARD_IMAGE: &str = "alpine/socat:latest";
+
+/// The forwarded port is only published on the loopback address, so an unpublished port isn't opened to the rest of the network
+const FORWARD_IP: &str = "127.0.0.1";
+
+/// A forward of an exposed port, relayed over the network that the container is attached to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Forward {
+    pub ip: String,
+    pub name: String,
+    pub network: String,
+    pub port: u16,
+}
+
+impl Forward {
+    /// The network, & address, of the container, None when it isn't on a network that another container can join, e.g. `host`, or `container:<id>`
+    /// When attached to more than one network the first, by name, is used
+    pub fn new(inspect: &ContainerInspectResponse, port: u16) -> Option<Self> {
+        let mode = inspect
+            .host_config
+            .as_ref()
+            .and_then(|i| i.network_mode.as_deref())
+            .unwrap_or_default();
+        if mode == "host" || mode == "none" || mode.starts_with("container:") {
+            return None;
+        }
+        let mut networks = inspect
+            .network_settings
+            .as_ref()?
+            .networks
+            .as_ref()?
+            .iter()
+            .filter_map(|(network, endpoint)| {
+                endpoint
+                    .ip_address
+                    .as_deref()
+                    .filter(|i| !i.is_empty())
+                    .map(|ip| (network.clone(), ip.to_owned()))
+            })
+            .collect::<Vec<_>>();
+        networks.sort();
+        let (network, ip) = networks.into_iter().next()?;
+        let name = inspect
+            .name
+            .as_deref()
+            .unwrap_or_default()
+            .trim_start_matches('/');
+        Some(Self {
+            ip,
+            name: format!("{name}-forward-{port}"),
+            network,
+            port,
+        })
+    }
+
+    fn cmd(&self) -> Vec<String> {
+        vec![
+            format!("TCP-LISTEN:{},fork,reuseaddr", self.port),
+            format!("TCP:{}:{}", self.ip, self.port),
+        ]
+    }
+
+    /// The address that the forwarded port is reached at, on the Docker host
+    pub fn address(&self) -> String {
+        format!("{FORWARD_IP}:{}", self.port)
+    }
+
+    /// The same forward, as a docker run command
+    pub fn command(&self) -> String {
+        format!(
+            "docker run -d --rm --name {} --network {} -p {}:{} {FORWARD_IMAGE} {}",
+            self.name,
+            self.network,
+            self.address(),
+            self.port,
+            self.cmd().join(" ")
+        )
+    }
+
+    /// The forward container is removed once it's stopped
+    pub fn config(&self) -> Config<String> {
+        let mut exposed = HashMap::new();
+        let port_bindings =
+            recreate::port_bindings(&[format!("{}:{}", self.address(), self.port)], &mut exposed);
+        Config {
+            image: Some(FORWARD_IMAGE.to_owned()),
+            cmd: Some(self.cmd()),
+            exposed_ports: Some(exposed),
+            host_config: Some(HostConfig {
+                auto_remove: Some(true),
+                network_mode: Some(self.network.clone()),
+                port_bindings: Some(port_bindings),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    /// Create, and start, the forward container, if it can't be started, e.g. the port is already in use on the host, it's removed
+    pub async fn start(&self, docker: &Docker) -> Result<(), Error> {
+        let options = Some(CreateContainerOptions {
+            name: self.name.clone(),
+            platform: None,
+        });
+        let created = docker.create_container(options, self.config()).await?;
+        if let Err(e) = docker
+            .start_container(&created.id, None::<StartContainerOptions<String>>)
+            .await
+        {
+            let remove = Some(RemoveContainerOptions {
+                v: false,
+                force: true,
+                link: false,
+            });
+            docker.remove_container(&created.id, remove).await.ok();
+            return Err(e);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use std::collections::HashMap;
+
+    use bollard::service::{
+        ContainerInspectResponse, EndpointSettings, HostConfig, NetworkSettings, PortBinding,
+    };
+
+    use super::Forward;
+
+    fn inspect(mode: &str, networks: &[(&str, &str)]) -> ContainerInspectResponse {
+        ContainerInspectResponse {
+            name: Some("/api".to_owned()),
+            host_config: Some(HostConfig {
+                network_mode: Some(mode.to_owned()),
+                ..Default::default()
+            }),
+            network_settings: Some(NetworkSettings {
+                networks: Some(
+                    networks
+                        .iter()
+                        .map(|(network, ip)| {
+                            (
+                                (*network).to_owned(),
+                                EndpointSettings {
+                                    ip_address: Some((*ip).to_owned()),
+                                    ..Default::default()
+                                },
+                            )
+                        })
+                        .collect::<HashMap<_, _>>(),
+                ),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    /// The first network, by name, with an address is used, & a container sharing the host's, or another container's, network can't be forwarded
+    fn test_forward_new() {
+        let forward = Forward::new(
+            &inspect(
+                "app",
+                &[("web", "172.19.0.3"), ("app", "172.18.0.2"), ("db", "")],
+            ),
+            9000,
+        )
+        .unwrap();
+        assert_eq!(forward.network, "app");
+        assert_eq!(forward.ip, "172.18.0.2");
+        assert_eq!(forward.name, "api-forward-9000");
+        assert_eq!(
+            forward.command(),
+            "docker run -d --rm --name api-forward-9000 --network app -p 127.0.0.1:9000:9000 alpine/socat:latest TCP-LISTEN:9000,fork,reuseaddr TCP:172.18.0.2:9000"
+        );
+
+        assert!(Forward::new(&inspect("host", &[("host", "")]), 9000).is_none());
+        assert!(Forward::new(&inspect("container:abc", &[]), 9000).is_none());
+        assert!(Forward::new(&inspect("bridge", &[("bridge", "")]), 9000).is_none());
+    }
+
+    #[test]
+    /// The port is only published on the loopback address, & the container removes itself once stopped
+    fn test_forward_config() {
+        let forward = Forward::new(&inspect("bridge", &[("bridge", "172.17.0.2")]), 5432).unwrap();
+        let config = forward.config();
+        assert!(config.exposed_ports.unwrap().contains_key("5432/tcp"));
+        let host_config = config.host_config.unwrap();
+        assert_eq!(host_config.auto_remove, Some(true));
+        assert_eq!(host_config.network_mode.as_deref(), Some("bridge"));
+        assert_eq!(
+            host_config.port_bindings.unwrap().get("5432/tcp"),
+            Some(&Some(vec![PortBinding {
+                host_ip: Some("127.0.0.1".to_owned()),
+                host_port: Some("5432".to_owned()),
+            }]))
+        );
+    }
+}
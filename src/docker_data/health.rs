@@ -0,0 +1,155 @@
+use bollard::service::{ContainerInspectResponse, HealthcheckResult};
+
+use super::parse_rfc3339_nanos;
+use crate::ui::{HealthProbe, HealthView};
+
+/// The health check durations are in nanoseconds, shown in whole seconds
+const NANOS: i64 = 1_000_000_000;
+
+/// The command of a health check test, `CMD-SHELL` runs a single shell command, `CMD` an exec form command, & `NONE` disables an inherited check
+fn test_command(test: &[String]) -> Option<String> {
+    match test.split_first() {
+        Some((kind, rest)) if kind == "CMD" || kind == "CMD-SHELL" => Some(rest.join(" ")),
+        _ => None,
+    }
+}
+
+fn probe(result: &HealthcheckResult) -> HealthProbe {
+    let start = result.start.as_deref().and_then(parse_rfc3339_nanos);
+    let end = result.end.as_deref().and_then(parse_rfc3339_nanos);
+    let duration = start
+        .zip(end)
+        .and_then(|(start, end)| u64::try_from((end - start) / 1_000_000).ok());
+    HealthProbe {
+        duration,
+        exit_code: result.exit_code,
+        output: result
+            .output
+            .as_deref()
+            .unwrap_or_default()
+            .trim()
+            .to_owned(),
+        start: start.and_then(|i| u64::try_from(i / 1_000_000_000).ok()),
+    }
+}
+
+/// The health check of a container, None if it doesn't have one, the daemon keeps the last few probes, oldest first, so they are reversed
+pub fn view(inspect: &ContainerInspectResponse, now: u64) -> Option<HealthView> {
+    let config = inspect.config.as_ref()?.healthcheck.as_ref()?;
+    let test = test_command(config.test.as_deref().unwrap_or_default())?;
+    let health = inspect.state.as_ref().and_then(|i| i.health.as_ref());
+    let seconds = |i: Option<i64>| {
+        i.filter(|i| *i > 0)
+            .and_then(|i| u64::try_from(i / NANOS).ok())
+    };
+    Some(HealthView {
+        failing_streak: health.and_then(|i| i.failing_streak).unwrap_or_default(),
+        interval: seconds(config.interval),
+        name: inspect
+            .name
+            .as_deref()
+            .unwrap_or_default()
+            .trim_start_matches('/')
+            .to_owned(),
+        now,
+        probes: health
+            .and_then(|i| i.log.as_ref())
+            .map(|i| i.iter().rev().map(probe).collect())
+            .unwrap_or_default(),
+        retries: config.retries.filter(|i| *i > 0),
+        selected: 0,
+        status: health
+            .and_then(|i| i.status)
+            .map_or_else(|| "starting".to_owned(), |i| i.to_string()),
+        test,
+        timeout: seconds(config.timeout),
+    })
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use bollard::service::{
+        ContainerConfig, ContainerInspectResponse, ContainerState, Health, HealthConfig,
+        HealthStatusEnum, HealthcheckResult,
+    };
+
+    use super::{test_command, view};
+
+    #[test]
+    fn test_health_test_command() {
+        let test =
+            |i: &[&str]| test_command(&i.iter().map(|i| (*i).to_owned()).collect::<Vec<_>>());
+        assert_eq!(
+            test(&["CMD-SHELL", "curl -f localhost || exit 1"]).unwrap(),
+            "curl -f localhost || exit 1"
+        );
+        assert_eq!(
+            test(&["CMD", "pg_isready", "-U", "app"]).unwrap(),
+            "pg_isready -U app"
+        );
+        assert!(test(&["NONE"]).is_none());
+        assert!(test(&[]).is_none());
+    }
+
+    #[test]
+    /// The probes are newest first, with their duration, & a container without a health check has no view
+    fn test_health_view() {
+        let result = |start: &str, end: &str, exit_code: i64, output: &str| HealthcheckResult {
+            start: Some(start.to_owned()),
+            end: Some(end.to_owned()),
+            exit_code: Some(exit_code),
+            output: Some(output.to_owned()),
+        };
+        let mut inspect = ContainerInspectResponse {
+            name: Some("/web".to_owned()),
+            config: Some(ContainerConfig {
+                healthcheck: Some(HealthConfig {
+                    test: Some(vec!["CMD-SHELL".to_owned(), "curl -f localhost".to_owned()]),
+                    interval: Some(30_000_000_000),
+                    timeout: Some(5_000_000_000),
+                    retries: Some(3),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            state: Some(ContainerState {
+                health: Some(Health {
+                    status: Some(HealthStatusEnum::UNHEALTHY),
+                    failing_streak: Some(1),
+                    log: Some(vec![
+                        result(
+                            "2024-01-01T00:00:00.100000000Z",
+                            "2024-01-01T00:00:00.150000000Z",
+                            0,
+                            "ok\n",
+                        ),
+                        result(
+                            "2024-01-01T00:00:30.000000000Z",
+                            "2024-01-01T00:00:35.000000000Z",
+                            1,
+                            "timed out",
+                        ),
+                    ]),
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let health = view(&inspect, 1_704_067_260).unwrap();
+        assert_eq!(health.name, "web");
+        assert_eq!(health.status, "unhealthy");
+        assert_eq!(health.test, "curl -f localhost");
+        assert_eq!((health.interval, health.timeout), (Some(30), Some(5)));
+        assert_eq!(health.retries, Some(3));
+        assert_eq!(health.probes.len(), 2);
+        assert_eq!(health.probes[0].output, "timed out");
+        assert_eq!(health.probes[0].duration, Some(5000));
+        assert_eq!(health.probes[0].start, Some(1_704_067_230));
+        assert_eq!(health.probes[1].output, "ok");
+        assert_eq!(health.probes[1].duration, Some(50));
+
+        inspect.config = Some(ContainerConfig::default());
+        assert!(view(&inspect, 0).is_none());
+    }
+}
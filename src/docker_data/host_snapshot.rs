@@ -0,0 +1,331 @@
+use std::{
+    fmt::Write as _,
+    path::{Path, PathBuf},
+};
+
+use serde_json::json;
+
+use super::{bundle::file_name, format_rfc3339};
+use crate::{
+    app_data::{stats_csv, ContainerItem, DockerEvent, EventKind},
+    ui::csv_quote,
+};
+
+/// The most recent lines of each container's logs included in a snapshot
+pub const LOG_TAIL: usize = 500;
+
+/// Size of a tar header, & of each block of file data
+pub const BLOCK: usize = 512;
+
+/// A single container, as listed, with its inspect output, stats history, & the tail of its logs
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContainerSnapshot {
+    pub id: String,
+    pub name: String,
+    pub image: String,
+    pub state: String,
+    pub status: String,
+    /// Pretty printed json, None if the container couldn't be inspected
+    pub inspect: Option<String>,
+    /// The time, cpu percentage, & memory bytes, of each update, oldest first
+    pub stats: Vec<(u64, f64, u64)>,
+    /// Each line starts with its timestamp, without any ansi formatting
+    pub logs: Vec<String>,
+}
+
+impl From<&ContainerItem> for ContainerSnapshot {
+    fn from(item: &ContainerItem) -> Self {
+        Self {
+            id: item.id.get().to_owned(),
+            name: item.name.get().to_owned(),
+            image: item.image.get().to_owned(),
+            state: format!("{:?}", item.state).to_lowercase(),
+            status: item.status.clone(),
+            inspect: None,
+            stats: item.stats_history(),
+            logs: vec![],
+        }
+    }
+}
+
+/// Everything known about a host, at a single moment, to hand to someone else
+#[derive(Debug, Clone, PartialEq)]
+pub struct HostSnapshot {
+    /// None for the primary host
+    pub host: Option<String>,
+    /// Unix timestamp, in seconds
+    pub created: i64,
+    pub containers: Vec<ContainerSnapshot>,
+    pub events: Vec<DockerEvent>,
+}
+
+impl HostSnapshot {
+    /// The archive, & the directory inside it, `oxker_host_[timestamp]`, or `oxker_host_[host]_[timestamp]` for another host
+    fn name(&self) -> String {
+        self.host.as_ref().map_or_else(
+            || format!("oxker_host_{}", self.created),
+            |host| format!("oxker_host_{}_{}", file_name(host), self.created),
+        )
+    }
+
+    /// The host, the time of the snapshot, & the containers as listed
+    fn summary(&self) -> String {
+        let containers = self
+            .containers
+            .iter()
+            .map(|i| {
+                json!({
+                    "id": i.id,
+                    "name": i.name,
+                    "image": i.image,
+                    "state": i.state,
+                    "status": i.status,
+                })
+            })
+            .collect::<Vec<_>>();
+        let mut output = serde_json::to_string_pretty(&json!({
+            "host": self.host,
+            "created": format_rfc3339(self.created),
+            "containers": containers,
+        }))
+        .unwrap_or_default();
+        output.push('\n');
+        output
+    }
+
+    /// The recent events, oldest first, as csv rows
+    fn events_csv(&self) -> String {
+        let mut output = "time,timestamp,name,event,exit_code\n".to_owned();
+        for event in &self.events {
+            let (action, exit_code) = match event.kind {
+                EventKind::Container(kind) => (kind.action(), kind.exit_code()),
+                EventKind::Pull => ("pull", None),
+            };
+            writeln!(
+                output,
+                "{},{},{},{},{}",
+                format_rfc3339(event.time),
+                event.time,
+                csv_quote(&event.name),
+                csv_quote(action),
+                exit_code.map_or_else(String::new, |i| i.to_string())
+            )
+            .ok();
+        }
+        output
+    }
+
+    /// Each file of the archive, & its contents, inspects, stats, & logs, are in a directory each, one file per container
+    fn files(&self) -> Vec<(String, String)> {
+        let dir = self.name();
+        let mut output = vec![
+            (format!("{dir}/snapshot.json"), self.summary()),
+            (format!("{dir}/events.csv"), self.events_csv()),
+        ];
+        for container in &self.containers {
+            let name = file_name(&container.name);
+            if let Some(inspect) = &container.inspect {
+                output.push((format!("{dir}/inspect/{name}.json"), inspect.clone()));
+            }
+            output.push((
+                format!("{dir}/stats/{name}.csv"),
+                stats_csv(&container.stats),
+            ));
+            let mut logs = container.logs.join("\n");
+            if !logs.is_empty() {
+                logs.push('\n');
+            }
+            output.push((format!("{dir}/logs/{name}.log"), logs));
+        }
+        output
+    }
+
+    /// Save the snapshot, as a single uncompressed tar archive, into dir, the path of the archive is returned
+    pub fn save(&self, dir: &Path) -> std::io::Result<PathBuf> {
+        let mut archive = vec![];
+        for (path, contents) in self.files() {
+            tar_entry(&mut archive, &path, contents.as_bytes(), self.created);
+        }
+        // The end of the archive is marked by two empty blocks
+        archive.resize(archive.len() + BLOCK * 2, 0);
+        let path = dir.join(format!("{}.tar", self.name()));
+        std::fs::write(&path, archive)?;
+        Ok(path)
+    }
+}
+
+/// Write a number, as zero padded octal, followed by a NUL, into a header field
+fn octal(field: &mut [u8], value: u64) {
+    let len = field.len() - 1;
+    let text = format!("{value:0len$o}");
+    field[..len].copy_from_slice(&text.as_bytes()[text.len() - len..]);
+    field[len] = 0;
+}
+
+/// Append a single file, a ustar header, followed by the data padded to a whole block
+/// A path longer than the 100 bytes of the name field is split at a `/`, with the directories in the 155 byte prefix field
+pub fn tar_entry(archive: &mut Vec<u8>, path: &str, data: &[u8], mtime: i64) {
+    let mut header = [0u8; BLOCK];
+    let (prefix, name) = if path.len() > 100 {
+        path.rsplit_once('/').unwrap_or(("", path))
+    } else {
+        ("", path)
+    };
+    let name = &name.as_bytes()[..name.len().min(100)];
+    let prefix = &prefix.as_bytes()[..prefix.len().min(155)];
+    header[..name.len()].copy_from_slice(name);
+    octal(&mut header[100..108], 0o644);
+    octal(&mut header[108..116], 0);
+    octal(&mut header[116..124], 0);
+    octal(&mut header[124..136], data.len() as u64);
+    octal(
+        &mut header[136..148],
+        u64::try_from(mtime).unwrap_or_default(),
+    );
+    header[156] = b'0';
+    header[257..263].copy_from_slice(b"ustar\0");
+    header[263..265].copy_from_slice(b"00");
+    header[345..345 + prefix.len()].copy_from_slice(prefix);
+    // The checksum is calculated with its own field as spaces, & written as six octal digits, a NUL, & a space
+    header[148..156].fill(b' ');
+    let checksum = header.iter().map(|i| u64::from(*i)).sum::<u64>();
+    octal(&mut header[148..155], checksum);
+    header[155] = b' ';
+
+    archive.extend_from_slice(&header);
+    archive.extend_from_slice(data);
+    let padding = (BLOCK - data.len() % BLOCK) % BLOCK;
+    archive.resize(archive.len() + padding, 0);
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::{tar_entry, ContainerSnapshot, HostSnapshot, BLOCK};
+    use crate::{
+        app_data::{ContainerId, DockerEvent, EventKind},
+        ui::TimelineKind,
+    };
+
+    /// The path & contents of each file of a tar archive
+    fn read_tar(archive: &[u8]) -> Vec<(String, String)> {
+        let text = |i: &[u8]| String::from_utf8_lossy(i).trim_end_matches('\0').to_owned();
+        let mut output = vec![];
+        let mut offset = 0;
+        while archive.get(offset).is_some_and(|i| *i != 0) {
+            let header = &archive[offset..offset + BLOCK];
+            let size = usize::from_str_radix(&text(&header[124..135]), 8).unwrap();
+            let prefix = text(&header[345..500]);
+            let name = text(&header[..100]);
+            let path = if prefix.is_empty() {
+                name
+            } else {
+                format!("{prefix}/{name}")
+            };
+            let data = &archive[offset + BLOCK..offset + BLOCK + size];
+            output.push((path, String::from_utf8_lossy(data).into_owned()));
+            offset += BLOCK + size.div_ceil(BLOCK) * BLOCK;
+        }
+        output
+    }
+
+    fn gen_snapshot() -> HostSnapshot {
+        HostSnapshot {
+            host: None,
+            created: 1_714_566_896,
+            containers: vec![ContainerSnapshot {
+                id: "abc".to_owned(),
+                name: "web/1".to_owned(),
+                image: "nginx".to_owned(),
+                state: "running".to_owned(),
+                status: "Up 2 hours".to_owned(),
+                inspect: Some("{}\n".to_owned()),
+                stats: vec![(1_714_566_890, 1.5, 100), (1_714_566_891, 12.346, 200)],
+                logs: vec!["2024-05-01T12:34:56Z listening".to_owned()],
+            }],
+            events: vec![
+                DockerEvent {
+                    time: 1_714_566_800,
+                    host: None,
+                    id: Some(ContainerId::from("abc")),
+                    name: "web/1".to_owned(),
+                    kind: EventKind::Container(TimelineKind::Die(Some(137))),
+                },
+                DockerEvent {
+                    time: 1_714_566_801,
+                    host: None,
+                    id: None,
+                    name: "nginx, latest".to_owned(),
+                    kind: EventKind::Pull,
+                },
+            ],
+        }
+    }
+
+    #[test]
+    /// Each header has a valid checksum, the data is padded to a whole block, & a long path is split into the prefix
+    fn test_host_snapshot_tar_entry() {
+        let mut archive = vec![];
+        tar_entry(&mut archive, "dir/file.txt", b"hello", 0);
+        assert_eq!(archive.len(), BLOCK * 2);
+        assert_eq!(&archive[BLOCK..BLOCK + 5], b"hello");
+        let checksum = archive[..BLOCK]
+            .iter()
+            .enumerate()
+            .map(|(index, i)| {
+                if (148..156).contains(&index) {
+                    u64::from(b' ')
+                } else {
+                    u64::from(*i)
+                }
+            })
+            .sum::<u64>();
+        assert_eq!(&archive[148..156], format!("{checksum:06o}\0 ").as_bytes());
+
+        let long = format!("oxker_host_1/logs/{}.log", "a".repeat(120));
+        tar_entry(&mut archive, &long, &[b'x'; BLOCK + 1], 0);
+        assert_eq!(archive.len(), BLOCK * 5);
+        let files = read_tar(&archive);
+        assert_eq!(files[1].0, format!("oxker_host_1/logs/{}", "a".repeat(100)));
+        assert_eq!(files[1].1.len(), BLOCK + 1);
+    }
+
+    #[test]
+    /// The archive holds the summary, events, & each container's inspect, stats, & logs, in a timestamped directory
+    fn test_host_snapshot_save() {
+        let dir = std::env::temp_dir().join(format!("oxker-snapshot-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = gen_snapshot().save(&dir).unwrap();
+        assert_eq!(path, dir.join("oxker_host_1714566896.tar"));
+        let archive = std::fs::read(&path).unwrap();
+        assert_eq!(archive.len() % BLOCK, 0);
+        let files = read_tar(&archive);
+        assert_eq!(
+            files.iter().map(|i| i.0.as_str()).collect::<Vec<_>>(),
+            [
+                "oxker_host_1714566896/snapshot.json",
+                "oxker_host_1714566896/events.csv",
+                "oxker_host_1714566896/inspect/web_1.json",
+                "oxker_host_1714566896/stats/web_1.csv",
+                "oxker_host_1714566896/logs/web_1.log",
+            ]
+        );
+        let summary = serde_json::from_str::<serde_json::Value>(&files[0].1).unwrap();
+        assert_eq!(summary["created"], "2024-05-01T12:34:56Z");
+        assert_eq!(summary["containers"][0]["state"], "running");
+        assert_eq!(
+            files[1].1,
+            "time,timestamp,name,event,exit_code\n2024-05-01T12:33:20Z,1714566800,web/1,die,137\n2024-05-01T12:33:21Z,1714566801,\"nginx, latest\",pull,\n"
+        );
+        assert_eq!(
+            files[3].1,
+            "time,timestamp,cpu_percent,memory_bytes\n2024-05-01T12:34:50Z,1714566890,1.50,100\n2024-05-01T12:34:51Z,1714566891,12.35,200\n"
+        );
+        assert_eq!(files[4].1, "2024-05-01T12:34:56Z listening\n");
+
+        let mut remote = gen_snapshot();
+        remote.host = Some("ssh://server".to_owned());
+        assert_eq!(remote.name(), "oxker_host_ssh___server_1714566896");
+        std::fs::remove_dir_all(dir).ok();
+    }
+}
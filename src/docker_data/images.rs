@@ -0,0 +1,242 @@
+use std::{collections::HashMap, sync::Arc, time::Instant};
+
+use bollard::{
+    image::{CreateImageOptions, RemoveImageOptions},
+    service::{ContainerSummary, ImageInspect, ImageSummary},
+    Docker,
+};
+use futures_util::StreamExt;
+use parking_lot::Mutex;
+
+use super::{docker_config::DockerConfig, prune::NONE_TAG};
+use crate::{
+    app_data::{ByteStats, ImageItem},
+    ui::{GuiState, ImageDetail, PullProgress},
+};
+
+/// Convert the local images into image items, with the name of each container, running or not, that was created from it
+pub fn images(images: &[ImageSummary], containers: &[ContainerSummary]) -> Vec<ImageItem> {
+    let mut in_use = HashMap::<&str, Vec<String>>::new();
+    for container in containers {
+        if let (Some(image_id), Some(name)) = (
+            container.image_id.as_deref(),
+            container.names.as_ref().and_then(|i| i.first()),
+        ) {
+            in_use
+                .entry(image_id)
+                .or_default()
+                .push(name.trim_start_matches('/').to_owned());
+        }
+    }
+    images
+        .iter()
+        .map(|i| ImageItem {
+            containers: in_use.remove(i.id.as_str()).unwrap_or_default(),
+            created: i.created,
+            id: i.id.clone(),
+            size: ByteStats::new(u64::try_from(i.size).unwrap_or_default()),
+            tags: i
+                .repo_tags
+                .iter()
+                .filter(|i| *i != NONE_TAG)
+                .cloned()
+                .collect(),
+        })
+        .collect()
+}
+
+/// The details of an inspected image, the config of the image, rather than of any container created from it
+pub fn detail(inspect: ImageInspect, image: ImageItem, host: Option<String>) -> ImageDetail {
+    let config = inspect.config.unwrap_or_default();
+    let mut exposed_ports = config
+        .exposed_ports
+        .map(|i| i.into_keys().collect::<Vec<_>>())
+        .unwrap_or_default();
+    exposed_ports.sort();
+    let non_empty = |i: Option<String>| i.filter(|i| !i.is_empty());
+    ImageDetail {
+        armed: false,
+        platform: match (inspect.os, inspect.architecture) {
+            (Some(os), Some(architecture)) => Some(format!("{os}/{architecture}")),
+            (os, architecture) => os.or(architecture),
+        },
+        cmd: config.cmd.unwrap_or_default(),
+        entrypoint: config.entrypoint.unwrap_or_default(),
+        env: config.env.unwrap_or_default(),
+        exposed_ports,
+        host,
+        image,
+        layers: inspect
+            .root_fs
+            .and_then(|i| i.layers)
+            .map_or(0, |i| i.len()),
+        user: non_empty(config.user),
+        working_dir: non_empty(config.working_dir),
+    }
+}
+
+/// The outcome of pulling an image
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Pulled {
+    /// The daemon's final status, e.g. `Image is up to date for redis:7`
+    Done(String),
+    Cancelled,
+    /// The daemon's error
+    Failed(String),
+}
+
+/// Pull the latest image of a tag, the progress of each layer is shown until the pull completes, fails, or is cancelled
+/// A cancelled pull drops the stream, so the daemon stops the pull, any layers already downloaded are kept by the daemon
+/// The registry's credentials are read from the docker CLI's config.json, so that a private image pulls as it would with `docker pull`
+pub async fn pull(
+    docker: &Docker,
+    gui_state: &Arc<Mutex<GuiState>>,
+    image: &str,
+    host: Option<String>,
+) -> Pulled {
+    let (repository, tag) = match image.rsplit_once(':') {
+        Some((repository, tag)) if !tag.contains('/') => (repository, tag),
+        _ => (image, "latest"),
+    };
+    let options = Some(CreateImageOptions {
+        from_image: repository,
+        tag,
+        ..Default::default()
+    });
+    let progress = PullProgress::new(image, host, Instant::now());
+    let cancelled = progress.cancelled();
+    gui_state.lock().set_pull_progress(Some(progress));
+
+    let credentials = DockerConfig::load().credentials(image).await;
+    let mut stream = docker.create_image(options, None, credentials);
+    let mut status = None;
+    let pulled = loop {
+        tokio::select! {
+            () = cancelled.notified() => break Pulled::Cancelled,
+            info = stream.next() => match info {
+                Some(Ok(info)) if info.error.is_none() => {
+                    let Some(text) = info.status else {
+                        continue;
+                    };
+                    let detail = info.progress_detail.and_then(|i| {
+                        Some((
+                            u64::try_from(i.current?).ok()?,
+                            u64::try_from(i.total?).ok()?,
+                        ))
+                    });
+                    if let Some(progress) = gui_state.lock().get_pull_progress() {
+                        progress.update(info.id.as_deref(), &text, detail, Instant::now());
+                    }
+                    status = Some(text.strip_prefix("Status: ").unwrap_or(&text).to_owned());
+                }
+                Some(Ok(info)) => break Pulled::Failed(info.error.unwrap_or_default()),
+                Some(Err(e)) => break Pulled::Failed(e.to_string()),
+                None => break Pulled::Done(status.unwrap_or_else(|| format!("pulled {image}"))),
+            }
+        }
+    };
+    gui_state.lock().set_pull_progress(None);
+    pulled
+}
+
+/// Remove an image, a tagged image is removed by untagging each of its tags, so that the daemon still refuses to remove an image that has since been used
+pub async fn remove(docker: &Docker, id: &str, tags: &[String]) -> bool {
+    let options = Some(RemoveImageOptions {
+        force: false,
+        noprune: false,
+    });
+    if tags.is_empty() {
+        return docker.remove_image(id, options, None).await.is_ok();
+    }
+    let mut removed = true;
+    for tag in tags {
+        removed &= docker.remove_image(tag, options, None).await.is_ok();
+    }
+    removed
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use bollard::service::{
+        ContainerConfig, ContainerSummary, ImageInspect, ImageInspectRootFs, ImageSummary,
+    };
+
+    use super::{detail, images};
+
+    #[test]
+    /// Each image lists the containers created from it, dangling images have no tags
+    fn test_images() {
+        let gen_image = |id: &str, tags: &[&str]| ImageSummary {
+            id: id.to_owned(),
+            repo_tags: tags.iter().map(|i| (*i).to_owned()).collect(),
+            size: 1024,
+            created: 1,
+            ..Default::default()
+        };
+        let gen_container = |name: &str, image_id: &str| ContainerSummary {
+            names: Some(vec![format!("/{name}")]),
+            image_id: Some(image_id.to_owned()),
+            ..Default::default()
+        };
+        let result = images(
+            &[
+                gen_image("sha256:1", &["redis:7", "redis:latest"]),
+                gen_image("sha256:2", &["<none>:<none>"]),
+            ],
+            &[
+                gen_container("cache", "sha256:1"),
+                gen_container("cache_old", "sha256:1"),
+            ],
+        );
+        assert_eq!(result[0].tags, ["redis:7", "redis:latest"]);
+        assert_eq!(result[0].containers, ["cache", "cache_old"]);
+        assert_eq!(result[0].size.get(), 1024);
+        assert!(result[1].is_dangling());
+        assert!(result[1].containers.is_empty());
+    }
+
+    #[test]
+    #[allow(clippy::zero_sized_map_values)]
+    /// The image config is summarised, empty values are left out
+    fn test_images_detail() {
+        let image = images(
+            &[ImageSummary {
+                id: "sha256:1".to_owned(),
+                repo_tags: vec!["nginx:1.25".to_owned()],
+                ..Default::default()
+            }],
+            &[],
+        )
+        .remove(0);
+        let inspect = ImageInspect {
+            architecture: Some("arm64".to_owned()),
+            os: Some("linux".to_owned()),
+            config: Some(ContainerConfig {
+                cmd: Some(vec!["nginx".to_owned(), "-g".to_owned()]),
+                exposed_ports: Some(HashMap::from([
+                    ("80/tcp".to_owned(), HashMap::new()),
+                    ("443/tcp".to_owned(), HashMap::new()),
+                ])),
+                user: Some(String::new()),
+                working_dir: Some("/usr/share/nginx".to_owned()),
+                ..Default::default()
+            }),
+            root_fs: Some(ImageInspectRootFs {
+                typ: "layers".to_owned(),
+                layers: Some(vec!["a".to_owned(), "b".to_owned(), "c".to_owned()]),
+            }),
+            ..Default::default()
+        };
+        let result = detail(inspect, image, None);
+        assert_eq!(result.platform.as_deref(), Some("linux/arm64"));
+        assert_eq!(result.cmd, ["nginx", "-g"]);
+        assert_eq!(result.exposed_ports, ["443/tcp", "80/tcp"]);
+        assert_eq!(result.layers, 3);
+        assert!(result.user.is_none());
+        assert_eq!(result.working_dir.as_deref(), Some("/usr/share/nginx"));
+        assert_eq!(result.pull_tag(), Some("nginx:1.25"));
+        assert!(!result.armed);
+    }
+}
@@ -0,0 +1,153 @@
+use clap::ValueEnum;
+use tokio::process::Command;
+
+use super::{build_cache::cli_host, format_rfc3339};
+use crate::{app_data::ContainerId, exec::command::DOCKER};
+
+/// Where the logs of a container are read from when the daemon can't return them, as with the `syslog`, `fluentd`, or `gelf` logging drivers, & dual logging disabled
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum LogFallback {
+    /// `docker logs`, through the docker cli, with the same host
+    Cli,
+    /// `journalctl`, the journal of the local machine, so only for a local daemon using the `journald` driver
+    Journald,
+}
+
+impl LogFallback {
+    /// The program, & its args, to read the logs since a unix timestamp, in seconds, 0 reads the tail of the logs
+    fn command(self, id: &ContainerId, since: u64, tail: &str) -> (&'static str, Vec<String>) {
+        let mut args = vec![];
+        match self {
+            Self::Cli => {
+                args.extend(["logs", "--timestamps"].map(ToOwned::to_owned));
+                if since > 0 {
+                    args.extend(["--since".to_owned(), since.to_string()]);
+                } else {
+                    args.extend(["--tail".to_owned(), tail.to_owned()]);
+                }
+                args.push(id.get().to_owned());
+                (DOCKER, args)
+            }
+            Self::Journald => {
+                args.extend([
+                    format!("CONTAINER_ID_FULL={}", id.get()),
+                    "--no-pager".to_owned(),
+                    "--output=json".to_owned(),
+                    "--output-fields=MESSAGE".to_owned(),
+                ]);
+                if since > 0 {
+                    args.push(format!("--since=@{since}"));
+                } else if tail != "all" {
+                    args.push(format!("--lines={tail}"));
+                }
+                ("journalctl", args)
+            }
+        }
+    }
+
+    /// Read the logs, each line starts with its timestamp, as the daemon returns them, None if the command can't be run, or fails
+    pub async fn read(
+        self,
+        host: Option<&str>,
+        id: &ContainerId,
+        since: u64,
+        tail: &str,
+    ) -> Option<Vec<String>> {
+        let (program, args) = self.command(id, since, tail);
+        let mut command = Command::new(program);
+        command.args(args);
+        if let (Self::Cli, Some(host)) = (self, host) {
+            command.env("DOCKER_HOST", cli_host(host));
+        }
+        let output = command
+            .stdin(std::process::Stdio::null())
+            .stderr(std::process::Stdio::null())
+            .output()
+            .await
+            .ok()
+            .filter(|i| i.status.success())?;
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        Some(match self {
+            Self::Cli => stdout.lines().map(|i| format!("{i}\n")).collect(),
+            Self::Journald => stdout.lines().filter_map(journald_line).collect(),
+        })
+    }
+}
+
+/// Convert a line of `journalctl --output=json` into a log line, the timestamp is in microseconds, a message that isn't valid UTF-8 is an array of bytes
+fn journald_line(line: &str) -> Option<String> {
+    let value = serde_json::from_str::<serde_json::Value>(line).ok()?;
+    let micros = value
+        .get("__REALTIME_TIMESTAMP")?
+        .as_str()?
+        .parse::<i64>()
+        .ok()?;
+    let message = match value.get("MESSAGE")? {
+        serde_json::Value::String(text) => text.clone(),
+        serde_json::Value::Array(bytes) => String::from_utf8_lossy(
+            &bytes
+                .iter()
+                .filter_map(|i| i.as_u64().and_then(|i| u8::try_from(i).ok()))
+                .collect::<Vec<_>>(),
+        )
+        .into_owned(),
+        _ => return None,
+    };
+    Some(format!(
+        "{} {message}\n",
+        format_rfc3339(micros / 1_000_000)
+    ))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::{journald_line, LogFallback};
+    use crate::app_data::ContainerId;
+
+    #[test]
+    /// The tail is only used when the logs are first read, later reads are since the last update
+    fn test_log_fallback_command() {
+        let id = ContainerId::from("abc");
+        assert_eq!(
+            LogFallback::Cli.command(&id, 0, "100"),
+            (
+                "docker",
+                ["logs", "--timestamps", "--tail", "100", "abc"]
+                    .map(ToOwned::to_owned)
+                    .to_vec()
+            )
+        );
+        assert_eq!(
+            LogFallback::Cli.command(&id, 50, "100").1[2..4],
+            ["--since", "50"]
+        );
+        let (program, args) = LogFallback::Journald.command(&id, 0, "all");
+        assert_eq!(program, "journalctl");
+        assert_eq!(args[0], "CONTAINER_ID_FULL=abc");
+        assert_eq!(args.len(), 4);
+        assert_eq!(
+            LogFallback::Journald.command(&id, 0, "20").1[4],
+            "--lines=20"
+        );
+        assert_eq!(
+            LogFallback::Journald.command(&id, 50, "20").1[4],
+            "--since=@50"
+        );
+    }
+
+    #[test]
+    /// Journal entries are given the timestamp prefix of the daemon's logs, a message of bytes is decoded
+    fn test_log_fallback_journald_line() {
+        assert_eq!(
+            journald_line(r#"{"__REALTIME_TIMESTAMP":"1714566896123456","MESSAGE":"started"}"#),
+            Some("2024-05-01T12:34:56Z started\n".to_owned())
+        );
+        assert_eq!(
+            journald_line(r#"{"__REALTIME_TIMESTAMP":"1000000","MESSAGE":[104,105]}"#),
+            Some("1970-01-01T00:00:01Z hi\n".to_owned())
+        );
+        assert!(journald_line(r#"{"MESSAGE":"no time"}"#).is_none());
+        assert!(journald_line("not json").is_none());
+    }
+}
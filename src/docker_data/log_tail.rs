@@ -0,0 +1,62 @@
+use std::collections::HashMap;
+
+/// Number of historical log lines fetched when a container's logs are first loaded, set with `--tail`, None fetches every line
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct LogTail {
+    containers: HashMap<String, Option<usize>>,
+    default: Option<usize>,
+}
+
+impl LogTail {
+    fn parse_lines(input: &str) -> Result<Option<usize>, String> {
+        match input.trim() {
+            "all" => Ok(None),
+            i => i
+                .parse::<usize>()
+                .map(Some)
+                .map_err(|_| format!("{i}: expected a number of lines, or all")),
+        }
+    }
+
+    /// Set either the default, `[lines]`, or the value for a single container, `[container]=[lines]`, lines can be `all`
+    pub fn set(&mut self, input: &str) -> Result<(), String> {
+        match input.split_once('=') {
+            Some((container, lines)) => {
+                self.containers
+                    .insert(container.trim().to_owned(), Self::parse_lines(lines)?);
+            }
+            None => self.default = Self::parse_lines(input)?,
+        }
+        Ok(())
+    }
+
+    /// The `tail` value for the Docker logs request of a named container
+    pub fn get(&self, name: &str) -> String {
+        self.containers
+            .get(name)
+            .copied()
+            .unwrap_or(self.default)
+            .map_or_else(|| "all".to_owned(), |i| i.to_string())
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::LogTail;
+
+    #[test]
+    /// Per container values override the default, which is every line when not set
+    fn test_log_tail() {
+        let mut tail = LogTail::default();
+        assert_eq!(tail.get("api"), "all");
+        tail.set("500").unwrap();
+        tail.set("api=all").unwrap();
+        tail.set("db = 20").unwrap();
+        assert_eq!(tail.get("web"), "500");
+        assert_eq!(tail.get("api"), "all");
+        assert_eq!(tail.get("db"), "20");
+        assert!(tail.set("-1").is_err());
+        assert!(tail.set("api=lots").is_err());
+    }
+}
@@ -1,19 +1,219 @@
 use std::sync::Arc;
 
-use crate::app_data::ContainerId;
-use bollard::Docker;
-use tokio::sync::oneshot::Sender;
+use crate::{
+    app_data::{
+        ContainerId, DockerControls, ImageItem, NetworkItem, ServiceItem, Signal, VolumeItem,
+    },
+    ui::{CleanupKind, PruneImage, ReachTarget, RecreateForm, RunForm},
+};
+use bollard::{
+    container::{LogOutput, LogsOptions},
+    Docker,
+};
+use tokio::sync::{oneshot::Sender, Notify};
 
 #[derive(Debug)]
 pub enum DockerMessage {
-    ConfirmDelete(ContainerId),
+    /// Refresh the container's stats & logs every BOOST_INTERVAL, for BOOST_DURATION
+    Boost(ContainerId),
+    /// A single refresh of a boosted container
+    BoostTick(ContainerId),
+    BuildCache(Option<String>),
+    /// Capture the packets of the container, with the tcpdump filter, until notified, saving the pcap to the save_dir
+    Capture(ContainerId, String, Arc<Notify>),
+    BuildCacheRemove(Option<String>, Vec<String>),
+    /// Show the cleanup menu, with the disk usage of the host
+    Cleanup(Option<String>),
+    CleanupPrune(Option<String>, CleanupKind),
+    Confirm(DockerControls, ContainerId),
+    /// Copy a file, or directory, of the container into the save_dir
+    CopyFrom(ContainerId, String),
     Delete(ContainerId),
+    /// Inspect two containers, of the same host, & compare their settings
+    Compare(ContainerId, ContainerId),
     Exec(Sender<Arc<Docker>>),
+    Export(ContainerId),
+    ExportProject(ContainerId),
+    /// List a directory of the container, for the file browser
+    Files(ContainerId, String),
+    /// Forward an exposed, but unpublished, port of the container to the Docker host, with a socat container
+    Forward(ContainerId, u16),
+    ImageDetail(Option<String>, ImageItem),
+    ImagePull(Option<String>, String),
+    ImageRemove(Option<String>, ImageItem),
+    /// Inspect the container for its health check, & its recent probes
+    Health(ContainerId),
+    Inspect(ContainerId),
+    /// Send the signal to the main process of the container
+    Kill(ContainerId, Signal),
+    /// Inspect the container for its current cpu & memory limits, to fill in the limits form
+    LimitsForm(ContainerId),
+    NetworkRemove(Option<String>, NetworkItem),
+    /// Load the lines logged before the oldest line of the container's logs
+    OlderLogs(ContainerId),
     Pause(ContainerId),
+    ProjectControl(DockerControls, Vec<ContainerId>),
+    /// A control sent to every marked container, once confirmed
+    BatchControl(DockerControls, Vec<ContainerId>),
+    /// Inspect the marked containers, for the dry run preview of deleting them
+    BatchPreview(Vec<ContainerId>),
+    /// Pull the latest image of the container, & recreate it, if the image has changed
+    PullRecreate(ContainerId),
+    Prune(Option<String>, Vec<PruneImage>),
+    PrunePreview(Option<String>),
     Quit,
+    /// Read the logs of the container, with the stream of each line, to save them, from the log driver fallback if the daemon can't read them
+    ReadLogs(ContainerId, LogsOptions<String>, Sender<Vec<LogOutput>>),
+    /// The source container, its name, & the target to check
+    Reach(ContainerId, String, ReachTarget),
+    ReachPicker(ContainerId),
+    Recreate(Box<RecreateForm>),
+    RecreateForm(ContainerId),
+    /// Give the container a new name
+    Rename(ContainerId, String),
+    /// Open the rename form, pre-filled with the current name of the container
+    RenameForm(ContainerId),
     Restart(ContainerId),
+    /// Create, and start, a new container, on the host
+    Run(Option<String>, Box<RunForm>),
+    /// Send a failed control again, once the backoff of its failures in a row has passed
+    Retry(DockerControls, ContainerId),
+    /// Scan the environment of every container of the host, for values that look like plaintext credentials
+    SecretScan(Option<String>),
+    /// Replace every task of the swarm service, even though its spec is unchanged
+    ServiceForceUpdate(Option<String>, ServiceItem),
+    /// Scale the replicated swarm service to the replicas
+    ServiceScale(Option<String>, ServiceItem, u64),
+    /// Save every container of the host, its inspect, stats, & recent logs, with the recent events, as a single archive
+    SnapshotHost(Option<String>),
+    /// Remove every service, network, & secret, of the stack, as `docker stack rm` does
+    StackRemove(Option<String>, String),
+    /// Scale every replicated service of the stack to the replicas
+    StackScale(Option<String>, String, u64),
     Start(ContainerId),
+    /// A line to send to the stdin of the container
+    Stdin(ContainerId, String),
+    StdinForm(ContainerId),
     Stop(ContainerId),
+    SwitchTag(ContainerId, String),
+    TagPicker(ContainerId),
+    /// Merge the logs of the tasks of the swarm service that run on the host
+    TaskLogs(Option<String>, ServiceItem),
+    Timeline(ContainerId),
     Resume(ContainerId),
     Update,
+    /// Update the cpu, as nano cpus, & memory, as bytes, limits of a running container, None leaves a limit unchanged
+    UpdateLimits(ContainerId, Option<u64>, Option<u64>),
+    VolumeRemove(Option<String>, VolumeItem),
+}
+
+/// Shown in the info box when a change is refused with `--read-only`
+pub const READ_ONLY_TEXT: &str = "read-only, changes aren't allowed";
+
+impl DockerMessage {
+    /// Whether the message changes a container, image, volume, network, swarm service, or the build cache, runs a process in a container, or copies files out of one, all of which are refused with `--read-only`
+    pub const fn is_write(&self) -> bool {
+        matches!(
+            self,
+            Self::BuildCacheRemove(..)
+                | Self::Capture(..)
+                | Self::CleanupPrune(..)
+                | Self::Confirm(..)
+                | Self::CopyFrom(..)
+                | Self::Delete(_)
+                | Self::Files(..)
+                | Self::Forward(..)
+                | Self::ImagePull(..)
+                | Self::ImageRemove(..)
+                | Self::Kill(..)
+                | Self::LimitsForm(_)
+                | Self::NetworkRemove(..)
+                | Self::Pause(_)
+                | Self::ProjectControl(..)
+                | Self::BatchControl(..)
+                | Self::BatchPreview(_)
+                | Self::PullRecreate(_)
+                | Self::Run(..)
+                | Self::Retry(..)
+                | Self::Prune(..)
+                | Self::Reach(..)
+                | Self::Recreate(_)
+                | Self::RecreateForm(_)
+                | Self::Rename(..)
+                | Self::RenameForm(_)
+                | Self::Restart(_)
+                | Self::Resume(_)
+                | Self::ServiceForceUpdate(..)
+                | Self::ServiceScale(..)
+                | Self::StackRemove(..)
+                | Self::StackScale(..)
+                | Self::Start(_)
+                | Self::Stdin(..)
+                | Self::StdinForm(_)
+                | Self::Stop(_)
+                | Self::SwitchTag(..)
+                | Self::TagPicker(_)
+                | Self::UpdateLimits(..)
+                | Self::VolumeRemove(..)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DockerMessage;
+    use crate::{
+        app_data::{ContainerId, DockerControls, ServiceItem},
+        ui::ReachTarget,
+    };
+
+    #[test]
+    /// Controls, & removals, are writes, updates, & views of a container, aren't
+    fn test_docker_message_is_write() {
+        let id = ContainerId::from("1");
+        let service = ServiceItem {
+            desired: 3,
+            global: false,
+            id: "q8k2xv1n4m7b".to_owned(),
+            image: "nginx:1.27".to_owned(),
+            name: "site_web".to_owned(),
+            running: 3,
+            stack: Some("site".to_owned()),
+            update: None,
+        };
+        for message in [
+            DockerMessage::Stop(id.clone()),
+            DockerMessage::Confirm(DockerControls::Delete, id.clone()),
+            DockerMessage::ImagePull(None, "nginx".to_owned()),
+            DockerMessage::Stdin(id.clone(), "say hello".to_owned()),
+            DockerMessage::ProjectControl(DockerControls::Restart, vec![id.clone()]),
+            DockerMessage::BatchControl(DockerControls::Delete, vec![id.clone()]),
+            DockerMessage::ServiceScale(None, service.clone(), 5),
+            DockerMessage::ServiceForceUpdate(None, service.clone()),
+            DockerMessage::StackScale(None, "site".to_owned(), 2),
+            DockerMessage::StackRemove(None, "site".to_owned()),
+        ] {
+            assert!(message.is_write());
+        }
+
+        // The connectivity check, & the file browser, exec in the container, & copying a file out of it reads its filesystem
+        let target = ReachTarget {
+            name: "db".to_owned(),
+            ip: "172.18.0.3".to_owned(),
+            port: Some(5432),
+            shared: true,
+        };
+        assert!(DockerMessage::Reach(id.clone(), "web".to_owned(), target).is_write());
+        assert!(DockerMessage::Files(id.clone(), "/app".to_owned()).is_write());
+        assert!(DockerMessage::CopyFrom(id.clone(), "/app/.env".to_owned()).is_write());
+        for message in [
+            DockerMessage::Update,
+            DockerMessage::Inspect(id.clone()),
+            DockerMessage::Export(id.clone()),
+            DockerMessage::Timeline(id),
+            DockerMessage::TaskLogs(None, service),
+        ] {
+            assert!(!message.is_write());
+        }
+    }
 }
@@ -1,40 +1,309 @@
 use bollard::{
     container::{
-        ListContainersOptions, LogsOptions, MemoryStatsStats, RemoveContainerOptions,
-        StartContainerOptions, Stats, StatsOptions,
+        CPUStats, ListContainersOptions, LogOutput, LogsOptions, RenameContainerOptions, Stats,
+        UpdateContainerOptions,
     },
-    service::ContainerSummary,
+    image::ListImagesOptions,
+    network::ListNetworksOptions,
+    secret::ListSecretsOptions,
+    service::{
+        ContainerInspectResponse, ContainerSummary, ListServicesOptions, MountPointTypeEnum,
+        ServiceSpec, SystemInfo, UpdateServiceOptions,
+    },
+    volume::{ListVolumesOptions, RemoveVolumeOptions},
     Docker,
 };
 use futures_util::StreamExt;
 use parking_lot::Mutex;
 use std::{
     collections::HashMap,
+    path::{Path, PathBuf},
     sync::{
         atomic::{AtomicBool, AtomicUsize},
         Arc,
     },
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
 };
 use tokio::{
-    sync::mpsc::{Receiver, Sender},
+    sync::{
+        mpsc::{Receiver, Sender},
+        Notify,
+    },
     task::JoinHandle,
 };
 use uuid::Uuid;
 
 use crate::{
-    app_data::{AppData, ContainerId, DockerControls, State},
+    app_data::{
+        compose_project, redact, AppData, ByteStats, ContainerId, CpuCores, DaemonInfo,
+        DockerControls, HostPort, HostResources, Isolation, LabelHints, Limits, LogLine,
+        MemoryBreakdown, Redact, ScheduleAction, State, BOOST_DURATION, BOOST_INTERVAL,
+        PODMAN_PROJECT_LABEL, PROJECT_LABEL,
+    },
     app_error::AppError,
     parse_args::CliArgs,
-    ui::{GuiState, Status},
+    ui::{
+        log_sanitizer::{self, InvalidUtf8},
+        BuildCache, Cleanup, DeletePreview, FailedControl, FileBrowser, GuiState, InspectView,
+        LimitsForm, PrunePreview, RenameForm, SecretScan, SelectablePanel, Status, StdinForm,
+        TaskLogs,
+    },
     ENTRY_POINT,
 };
+mod build_cache;
+mod bundle;
+mod capture;
+mod cleanup;
+mod compare;
+mod context;
+mod demo;
+mod docker_config;
+mod events;
+mod export;
+mod files;
+mod forward;
+mod health;
+mod host_snapshot;
+mod images;
+mod log_fallback;
+mod log_tail;
 mod message;
-pub use message::DockerMessage;
+mod networks;
+mod probe;
+mod prune;
+mod rate_limit;
+mod reach;
+mod recreate;
+mod router;
+mod run;
+mod runtime;
+mod schedule;
+mod secret_scan;
+mod services;
+mod ssh_tunnel;
+mod stdin;
+mod timeline;
+mod tls;
+mod volumes;
+mod wait;
+use capture::CAPTURE_IMAGE;
+pub use context::DockerContext;
+pub use demo::DemoRuntime;
+use forward::{Forward, FORWARD_IMAGE};
+use host_snapshot::{ContainerSnapshot, HostSnapshot};
+use images::Pulled;
+pub use log_fallback::LogFallback;
+pub use log_tail::LogTail;
+pub use message::{DockerMessage, READ_ONLY_TEXT};
+use rate_limit::RateLimit;
+pub use router::DockerRouter;
+pub use runtime::RuntimeKind;
+use runtime::{ExecOutput, Runtime};
+pub use ssh_tunnel::SshTunnel;
+pub use tls::TlsFiles;
+pub use wait::wait;
+
+/// How often, in seconds, to compare the Docker daemon's clock against the local clock
+const CLOCK_CHECK_INTERVAL: u64 = 60;
+
+/// How often, in seconds, to update while in standby
+const STANDBY_INTERVAL: u64 = 30;
+
+/// How often the sizes of the volumes are checked, while the volumes panel is shown
+const VOLUME_SIZE_INTERVAL: Duration = Duration::from_secs(30);
+
+/// How often the sizes of the containers' writable layers are checked, with `--disk-alert`
+const DISK_CHECK_INTERVAL: Duration = Duration::from_secs(30);
+
+/// The images, volumes, networks, or services panel is listed again when an event changes it, and otherwise this often, in case an event was missed
+const RESOURCES_INTERVAL: Duration = Duration::from_mins(1);
+
+/// Number of older log lines loaded at a time, when the container's `--tail` is every line
+const OLDER_LOGS: usize = 500;
+
+/// Convert a RFC 3339 timestamp, as returned by the Docker daemon, e.g. `2024-05-01T12:34:56.123456789Z` or `2024-05-01T13:34:56+01:00`, into a unix timestamp
+/// Fractional seconds are ignored
+pub fn parse_rfc3339(input: &str) -> Option<i64> {
+    let (date, time) = input.split_once(['T', 't', ' '])?;
+    let mut date = date.splitn(3, '-').map(str::parse::<i64>);
+    let (year, month, day) = (date.next()?.ok()?, date.next()?.ok()?, date.next()?.ok()?);
+
+    // Split off the timezone offset, Z or +hh:mm / -hh:mm
+    let (time, offset) = if let Some(time) = time.strip_suffix(['Z', 'z']) {
+        (time, 0)
+    } else {
+        let index = time.rfind(['+', '-'])?;
+        let (time, offset) = time.split_at(index);
+        let sign = if offset.starts_with('-') { -1 } else { 1 };
+        let (hours, minutes) = offset.get(1..)?.split_once(':')?;
+        (
+            time,
+            sign * (hours.parse::<i64>().ok()? * 3600 + minutes.parse::<i64>().ok()? * 60),
+        )
+    };
+    let time = time.split('.').next()?;
+    let mut time = time.splitn(3, ':').map(str::parse::<i64>);
+    let (hour, minute, second) = (time.next()?.ok()?, time.next()?.ok()?, time.next()?.ok()?);
+
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+
+    Some(days_from_civil(year, month, day) * 86400 + hour * 3600 + minute * 60 + second - offset)
+}
+
+/// Days since the unix epoch of a date, from Howard Hinnant's days_from_civil algorithm
+pub const fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// The year, month, & day, of a number of days since the unix epoch, from Howard Hinnant's civil_from_days algorithm
+pub fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    (year_of_era + era * 400 + i64::from(month <= 2), month, day)
+}
+
+/// Convert a unix timestamp into a UTC RFC 3339 timestamp, e.g. `2024-05-01T12:34:56Z`
+pub fn format_rfc3339(timestamp: i64) -> String {
+    let (days, secs) = (timestamp.div_euclid(86400), timestamp.rem_euclid(86400));
+    let (year, month, day) = civil_from_days(days);
+
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z",
+        secs / 3600,
+        secs % 3600 / 60,
+        secs % 60
+    )
+}
+
+/// Convert a RFC 3339 timestamp into nanoseconds since the unix epoch, keeping the fractional seconds, used to time Windows stats samples
+pub fn parse_rfc3339_nanos(input: &str) -> Option<i128> {
+    let seconds = parse_rfc3339(input)?;
+    let fraction = input.split_once('.').map_or("", |(_, rest)| {
+        rest.find(|c: char| !c.is_ascii_digit())
+            .map_or(rest, |i| &rest[..i])
+    });
+    let nanos = format!("{fraction:0<9}")
+        .get(..9)
+        .and_then(|i| i.parse::<i128>().ok())
+        .unwrap_or_default();
+    Some(i128::from(seconds) * 1_000_000_000 + nanos)
+}
+
+/// Storage drivers that Docker only suggests as a fallback, as they are slow, & use a lot of disk space, the daemon itself doesn't warn about them
+const FALLBACK_STORAGE_DRIVERS: [&str; 1] = ["vfs"];
+
+/// The daemon's details, & its warnings, without the `WARNING: ` prefix, a fallback storage driver is added as a warning
+fn daemon_info(info: &SystemInfo) -> DaemonInfo {
+    let mut warnings = info
+        .warnings
+        .iter()
+        .flatten()
+        .map(|i| i.trim().trim_start_matches("WARNING:").trim().to_owned())
+        .filter(|i| !i.is_empty())
+        .collect::<Vec<_>>();
+    if let Some(driver) = info
+        .driver
+        .as_deref()
+        .filter(|i| FALLBACK_STORAGE_DRIVERS.contains(i))
+    {
+        warnings.push(format!(
+            "the {driver} storage driver is slow, & uses a lot of disk space, overlay2 is recommended"
+        ));
+    }
+    let non_empty = |i: String| Some(i).filter(|i| !i.is_empty());
+    let version = info
+        .cgroup_version
+        .map(|i| i.to_string())
+        .and_then(non_empty);
+    let driver = info
+        .cgroup_driver
+        .map(|i| i.to_string())
+        .and_then(non_empty);
+    let cgroup = match (version, driver) {
+        (Some(version), Some(driver)) => Some(format!("v{version} ({driver})")),
+        (Some(version), None) => Some(format!("v{version}")),
+        (None, driver) => driver,
+    };
+    DaemonInfo {
+        cgroup,
+        kernel: info.kernel_version.clone(),
+        name: info.name.clone(),
+        os: info.operating_system.clone(),
+        storage_driver: info.driver.clone(),
+        version: info.server_version.clone(),
+        warnings,
+    }
+}
+
+/// The lines read from the log driver fallback, as stdout, each starts with its timestamp, which is removed if timestamps weren't asked for
+fn fallback_output(lines: Vec<String>, timestamps: bool) -> Vec<LogOutput> {
+    lines
+        .into_iter()
+        .filter(|i| !i.trim().is_empty())
+        .map(|line| {
+            let message = if timestamps {
+                line
+            } else {
+                line.split_once(' ')
+                    .map_or_else(|| line.clone(), |(_, text)| text.to_owned())
+            };
+            LogOutput::StdOut {
+                message: message.into(),
+            }
+        })
+        .collect()
+}
+
+/// Container list filters for `--only`, the Docker name filter is a regex, and includes the leading slash, so each name is escaped & anchored to only match exactly
+fn only_filters(names: &[String]) -> HashMap<String, Vec<String>> {
+    if names.is_empty() {
+        return HashMap::new();
+    }
+    let names = names
+        .iter()
+        .map(|name| {
+            let escaped = name.chars().fold(String::new(), |mut output, c| {
+                if "\\.+*?()|[]{}^$".contains(c) {
+                    output.push('\\');
+                }
+                output.push(c);
+                output
+            });
+            format!("^/{escaped}$")
+        })
+        .collect();
+    HashMap::from([("name".to_owned(), names)])
+}
 
 #[derive(Debug, Clone, Eq, Hash, PartialEq)]
 enum SpawnId {
+    /// The Docker events stream, followed for as long as oxker is running
+    Events,
     Stats((ContainerId, Binate)),
+    /// The images, volumes, networks, or services, of the panel shown beside the logs
+    Resources,
+    /// The sizes of the containers' writable layers, with `--disk-alert`
+    Disk,
     Log(ContainerId),
+    Probe(ContainerId),
 }
 
 /// Cpu & Mem stats take twice as long as the update interval to get a value, so will have two being executed at the same time
@@ -60,14 +329,34 @@ pub struct DockerData {
     app_data: Arc<Mutex<AppData>>,
     args: CliArgs,
     binate: Binate,
+    clock_checked: Option<Instant>,
+    disk_checked: Option<Instant>,
     docker: Arc<Docker>,
+    /// This host's own channel, so that a boosted container's refreshes reach this DockerData directly
+    docker_tx: Sender<DockerMessage>,
     gui_state: Arc<Mutex<GuiState>>,
+    host: Option<String>,
     is_running: Arc<AtomicBool>,
     init: Option<Arc<AtomicUsize>>,
+    probes_checked: Option<Instant>,
+    /// The images, volumes, networks, or services panel that was last listed, & when, None once it's no longer shown
+    resources_listed: Option<(SelectablePanel, Instant)>,
+    /// Volume sizes are checked less often than the volumes are listed, as the daemon has to measure every volume
+    volume_sizes_checked: Option<Instant>,
+    standby_updated: Option<Instant>,
+    rate_limit: RateLimit,
     receiver: Receiver<DockerMessage>,
+    /// Some on a Podman daemon, the last cpu sample of each container, its one-shot stats have no precpu stats to measure the usage against
+    podman: Option<CpuSamples>,
+    /// The same connection as docker, the containers are controlled, listed, inspected, & exec'd into, & their stats & logs read, through it
+    runtime: Arc<dyn Runtime>,
     spawns: Arc<Mutex<HashMap<SpawnId, JoinHandle<()>>>>,
+    windows: Option<Isolation>,
 }
 
+/// The last cpu sample of each container
+type CpuSamples = Arc<Mutex<HashMap<ContainerId, CPUStats>>>;
+
 impl DockerData {
     /// Use docker stats to calculate current cpu usage
     #[allow(clippy::cast_precision_loss)]
@@ -93,16 +382,62 @@ impl DockerData {
             if system_delta > 0.0 && cpu_delta > 0.0 {
                 cpu_percentage = (cpu_delta / system_delta) * online_cpus * 100.0;
             }
+        } else if let (Some(read), Some(preread), true) = (
+            parse_rfc3339_nanos(&stats.read),
+            parse_rfc3339_nanos(&stats.preread),
+            stats.num_procs > 0,
+        ) {
+            // Windows daemons don't report system cpu usage, cpu usage is in 100ns intervals, so compare against the time between the two samples, for each processor
+            let intervals = (read - preread) as f64 / 100.0 * f64::from(stats.num_procs);
+            if intervals > 0.0 && cpu_delta > 0.0 {
+                cpu_percentage = cpu_delta / intervals * 100.0;
+            }
         }
         cpu_percentage
     }
 
+    /// Bytes read from, & written to, block devices, since the container started, summed over every device
+    /// Windows daemons don't report block io, so use the storage stats instead
+    fn block_io(stats: &Stats) -> (u64, u64) {
+        stats
+            .blkio_stats
+            .io_service_bytes_recursive
+            .as_ref()
+            .map_or_else(
+                || {
+                    (
+                        stats.storage_stats.read_size_bytes.unwrap_or_default(),
+                        stats.storage_stats.write_size_bytes.unwrap_or_default(),
+                    )
+                },
+                |entries| {
+                    let sum = |op: &str| {
+                        entries
+                            .iter()
+                            .filter(|i| i.op.eq_ignore_ascii_case(op))
+                            .map(|i| i.value)
+                            .sum()
+                    };
+                    (sum("read"), sum("write"))
+                },
+            )
+    }
+
+    /// Measure a one-shot sample against the last sample of the container, in place of its empty precpu stats, the first sample measures no usage
+    fn previous_sample(samples: &CpuSamples, id: &ContainerId, stats: &mut Stats) {
+        stats.precpu_stats = samples
+            .lock()
+            .insert(id.clone(), stats.cpu_stats.clone())
+            .unwrap_or_else(|| stats.cpu_stats.clone());
+    }
+
     /// Get a single docker stat in order to update mem and cpu usage
     /// don't take &self, so that can tokio::spawn into it's own thread
     /// remove if from spawns hashmap when complete
+    /// Podman's stats are taken one-shot, & measured against the last sample, as its precpu stats are empty without a stream
     async fn update_container_stat(
         app_data: Arc<Mutex<AppData>>,
-        docker: Arc<Docker>,
+        (runtime, podman): (Arc<dyn Runtime>, Option<CpuSamples>),
         id: ContainerId,
         init: Option<(Arc<AtomicUsize>, usize)>,
         state: State,
@@ -110,59 +445,53 @@ impl DockerData {
         spawns: Arc<Mutex<HashMap<SpawnId, JoinHandle<()>>>>,
     ) {
         if state.is_alive() || init.is_some() {
-            let mut stream = docker
-                .stats(
-                    id.get(),
-                    Some(StatsOptions {
-                        stream: false,
-                        one_shot: false,
-                    }),
-                )
-                .take(1);
-
-            while let Some(Ok(stats)) = stream.next().await {
+            if let Ok(mut stats) = runtime.stats(&id, podman.is_some()).await {
+                if let Some(samples) = podman.as_ref() {
+                    Self::previous_sample(samples, &id, &mut stats);
+                }
                 // Memory stats are only collected if the container is alive - is this the behaviour we want?
+                let memory = MemoryBreakdown::new(&stats.memory_stats).filter(|_| state.is_alive());
                 let mem_stat = if state.is_alive() {
-                    let mem_cache = stats.memory_stats.stats.map_or(0, |i| match i {
-                        MemoryStatsStats::V1(x) => x.inactive_file,
-                        MemoryStatsStats::V2(x) => x.inactive_file,
-                    });
-                    Some(
-                        stats
-                            .memory_stats
-                            .usage
-                            .unwrap_or_default()
-                            .saturating_sub(mem_cache),
-                    )
+                    let mem_cache = memory.map_or(0, |i| i.inactive_file.get());
+                    // Windows containers have no cgroup usage, so use the private working set instead
+                    Some(stats.memory_stats.usage.map_or_else(
+                        || stats.memory_stats.privateworkingset.unwrap_or_default(),
+                        |i| i.saturating_sub(mem_cache),
+                    ))
                 } else {
                     None
                 };
 
                 let mem_limit = stats.memory_stats.limit.unwrap_or_default();
 
-                let op_key = stats
-                    .networks
-                    .as_ref()
-                    .and_then(|networks| networks.keys().next().cloned());
-
                 let cpu_stats = if state.is_alive() {
                     Some(Self::calculate_usage(&stats))
                 } else {
                     None
                 };
-                let (rx, tx) = if let Some(key) = op_key {
-                    stats
-                        .networks
-                        .unwrap_or_default()
-                        .get(&key)
-                        .map_or((0, 0), |f| (f.rx_bytes, f.tx_bytes))
-                } else {
-                    (0, 0)
-                };
+                // Summed over every network that the container is attached to, these are counted since the container started
+                let (rx, tx) = stats.networks.as_ref().map_or((0, 0), |networks| {
+                    networks
+                        .values()
+                        .fold((0, 0), |acc, i| (acc.0 + i.rx_bytes, acc.1 + i.tx_bytes))
+                });
 
-                app_data
-                    .lock()
-                    .update_stats_by_id(&id, cpu_stats, mem_stat, mem_limit, rx, tx);
+                let mut app_data = app_data.lock();
+                // Counted before the stats are updated, so that a sort by throughput is of the latest sample
+                if let (true, Some(nanos)) = (state.is_alive(), parse_rfc3339_nanos(&stats.read)) {
+                    let (read, write) = Self::block_io(&stats);
+                    app_data.update_io_by_id(&id, [rx, tx, read, write], nanos);
+                }
+                app_data.update_stats_by_id(&id, cpu_stats, mem_stat, mem_limit, rx, tx);
+                app_data.update_memory_by_id(&id, memory);
+                app_data.update_cores_by_id(
+                    &id,
+                    CpuCores::new(&stats.cpu_stats, &stats.precpu_stats)
+                        .filter(|_| state.is_alive()),
+                );
+                if state.is_alive() {
+                    app_data.update_throttling_by_id(&id, &stats.cpu_stats.throttling_data);
+                }
             }
         }
         spawns.lock().remove(&spawn_id);
@@ -172,54 +501,97 @@ impl DockerData {
     }
 
     /// Update all stats, spawn each container into own tokio::spawn thread
-    fn update_all_container_stats(&mut self, all_ids: &[(State, ContainerId)]) {
-        for (state, id) in all_ids {
-            let docker = Arc::clone(&self.docker);
+    /// Once initialised, with `--lazy-stats`, only the visible, and selected, containers are updated, returns the number of running containers that are updated
+    fn update_all_container_stats(&mut self, all_ids: &[(State, ContainerId)]) -> usize {
+        let ids = {
+            let mut app_data = self.app_data.lock();
+            let ids = all_ids
+                .iter()
+                .filter(|(_, id)| self.init.is_some() || app_data.wants_stats(id))
+                .collect::<Vec<_>>();
+            for (_, id) in ids.iter().filter(|(state, _)| state.is_alive()) {
+                app_data.wait_for_stats(id);
+            }
+            ids
+        };
+        let alive = ids.iter().filter(|(state, _)| state.is_alive()).count();
+        if let Some(samples) = self.podman.as_ref() {
+            samples
+                .lock()
+                .retain(|id, _| all_ids.iter().any(|(_, i)| i == id));
+        }
+        for (state, id) in ids {
+            let runtime = (Arc::clone(&self.runtime), self.podman.clone());
             let app_data = Arc::clone(&self.app_data);
             let spawns = Arc::clone(&self.spawns);
             let spawn_id = SpawnId::Stats((id.clone(), self.binate));
 
             let init = self.init.as_ref().map(|i| (Arc::clone(i), all_ids.len()));
+            let rate_limit = self.rate_limit.clone();
             self.spawns
                 .lock()
                 .entry(spawn_id.clone())
                 .or_insert_with(|| {
-                    tokio::spawn(Self::update_container_stat(
-                        app_data,
-                        docker,
-                        id.clone(),
-                        init,
-                        *state,
-                        spawn_id,
-                        spawns,
-                    ))
+                    let id = id.clone();
+                    let state = *state;
+                    tokio::spawn(async move {
+                        let _permit = rate_limit.acquire().await;
+                        Self::update_container_stat(
+                            app_data, runtime, id, init, state, spawn_id, spawns,
+                        )
+                        .await;
+                    })
                 });
         }
         self.binate = self.binate.toggle();
+        alive
     }
 
     /// Get all current containers, handle into ContainerItem in the app_data struct rather than here
     /// Just make sure that items sent are guaranteed to have an id
     /// If in a containerised runtime, will ignore any container that uses the `/app/oxker` as an entry point, unless the `-s` flag is set
+    /// Containers with an `oxker.hide` label are also ignored, unless `--show-hidden` is set
     pub async fn update_all_containers(&mut self) -> Vec<(State, ContainerId)> {
+        let permit = self.rate_limit.acquire().await;
+        let start = Instant::now();
         let containers = self
-            .docker
-            .list_containers(Some(ListContainersOptions::<String> {
+            .runtime
+            .list(ListContainersOptions::<String> {
                 all: true,
+                filters: only_filters(&self.args.only),
                 ..Default::default()
-            }))
-            .await
-            .unwrap_or_default();
+            })
+            .await;
+        drop(permit);
+        // Latency, like the clock skew, is only reported for the primary host
+        if self.host.is_none() {
+            self.app_data
+                .lock()
+                .set_latency(containers.as_ref().ok().map(|_| start.elapsed()));
+        }
+        let containers = containers.unwrap_or_default();
 
         let mut output = containers
             .into_iter()
             .filter_map(|f| match f.id {
                 Some(_) => {
-                    if self.args.in_container
-                        && f.command
-                            .as_ref()
-                            .map_or(false, |c| c.starts_with(ENTRY_POINT))
-                        && self.args.show_self
+                    // The name filter is applied by the daemon, but check again, in case an older daemon treats it as a substring match
+                    let not_only = !self.args.only.is_empty()
+                        && !f.names.as_ref().is_some_and(|names| {
+                            names.iter().any(|n| {
+                                self.args
+                                    .only
+                                    .iter()
+                                    .any(|o| n.trim_start_matches('/') == o)
+                            })
+                        });
+                    if not_only
+                        || !self.args.show_hidden && LabelHints::is_hidden(f.labels.as_ref())
+                        || self.args.in_container
+                            && f.command
+                                .as_ref()
+                                .map_or(false, |c| c.starts_with(ENTRY_POINT))
+                            && self.args.show_self
                     {
                         None
                     } else {
@@ -230,7 +602,20 @@ impl DockerData {
             })
             .collect::<Vec<ContainerSummary>>();
 
-        self.app_data.lock().update_containers(&mut output);
+        self.app_data
+            .lock()
+            .update_containers(self.host.as_deref(), &mut output);
+
+        if let Some(default) = self.windows {
+            self.update_isolation(default).await;
+        }
+
+        if self.args.restarts {
+            self.update_exit_info().await;
+        }
+
+        self.update_bindings().await;
+        self.update_limits().await;
 
         // Just get the containers that are currently running, or being restarted, no point updating info on paused or dead containers
         output
@@ -241,68 +626,892 @@ impl DockerData {
             .collect::<Vec<_>>()
     }
 
+    /// Windows containers use either process or Hyper-V isolation, which is only reported by inspect, so inspect each new container once
+    async fn update_isolation(&self, default: Isolation) {
+        let ids = self
+            .app_data
+            .lock()
+            .get_unknown_isolation(self.host.as_deref());
+        for id in ids {
+            let permit = self.rate_limit.acquire().await;
+            let isolation = self
+                .runtime
+                .inspect(&id, false)
+                .await
+                .ok()
+                .and_then(|i| i.host_config)
+                .and_then(|i| i.isolation)
+                .map_or(default, |i| Isolation::from_api(i.as_ref(), default));
+            drop(permit);
+            self.app_data.lock().set_isolation(&id, isolation);
+        }
+    }
+
+    /// A container that isn't running doesn't list its ports, so inspect each one once for its port bindings, the bindings of a container can't be changed
+    async fn update_bindings(&self) {
+        let ids = self
+            .app_data
+            .lock()
+            .get_unknown_bindings(self.host.as_deref());
+        for id in ids {
+            let permit = self.rate_limit.acquire().await;
+            let inspect = self.runtime.inspect(&id, false).await;
+            drop(permit);
+            if let Ok(inspect) = inspect {
+                let bindings = inspect
+                    .host_config
+                    .and_then(|i| i.port_bindings)
+                    .map_or_else(Vec::new, |i| HostPort::from_bindings(&i));
+                self.app_data.lock().set_bindings(&id, bindings);
+            }
+        }
+    }
+
+    /// The cpu & memory limits are only reported by inspect, so inspect each new container once, & again after its limits have been updated
+    async fn update_limits(&self) {
+        let ids = self
+            .app_data
+            .lock()
+            .get_unknown_limits(self.host.as_deref());
+        for id in ids {
+            let permit = self.rate_limit.acquire().await;
+            let inspect = self.runtime.inspect(&id, false).await;
+            drop(permit);
+            if let Ok(inspect) = inspect {
+                let limits = inspect
+                    .host_config
+                    .map_or_else(Limits::default, |i| Limits::from(&i));
+                self.app_data.lock().set_limits(&id, Some(limits));
+            }
+        }
+    }
+
+    /// For `--restarts`, inspect each container that is new, or has changed since it was last inspected, for its exit code, & restart count
+    async fn update_exit_info(&self) {
+        let ids = self
+            .app_data
+            .lock()
+            .get_stale_exit_info(self.host.as_deref());
+        for id in ids {
+            let permit = self.rate_limit.acquire().await;
+            let inspect = self.runtime.inspect(&id, false).await;
+            drop(permit);
+            if let Ok(inspect) = inspect {
+                let now = SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .map_or(0, |i| i.as_secs());
+                self.app_data.lock().set_exit_info(
+                    &id,
+                    inspect.state.and_then(|i| i.exit_code),
+                    inspect.restart_count.unwrap_or_default(),
+                    now,
+                );
+            }
+        }
+    }
+
+    /// Read the log lines of a container, & whether the stream ended with an error, rather than after the last line
+    async fn read_logs(
+        runtime: &dyn Runtime,
+        id: &ContainerId,
+        options: LogsOptions<String>,
+        invalid_utf8: InvalidUtf8,
+    ) -> (Vec<LogLine>, bool) {
+        let mut logs = runtime.logs(id, options);
+        let mut output = vec![];
+
+        while let Some(value) = logs.next().await {
+            let Ok(value) = value else {
+                return (output, true);
+            };
+            let bytes = value.into_bytes();
+            if log_sanitizer::is_binary(&bytes) {
+                output.push(LogLine::Binary(bytes.to_vec()));
+            } else {
+                let data = log_sanitizer::decode(&bytes, invalid_utf8);
+                if !data.trim().is_empty() {
+                    output.push(LogLine::Text(data));
+                }
+            }
+        }
+        (output, false)
+    }
+
+    /// Load a page of the lines logged before the oldest line of a container, the page is the `--tail` of the container, or OLDER_LOGS lines
+    async fn older_logs(
+        app_data: Arc<Mutex<AppData>>,
+        runtime: Arc<dyn Runtime>,
+        id: ContainerId,
+    ) -> Option<usize> {
+        let (invalid_utf8, until, page) = {
+            let mut app_data = app_data.lock();
+            let until = app_data.get_oldest_log(&id)?;
+            let page = app_data
+                .get_container_name_by_id(&id)
+                .map(|name| app_data.args.tail.get(name.get()))
+                .filter(|i| i != "all")
+                .unwrap_or_else(|| OLDER_LOGS.to_string());
+            (app_data.args.invalid_utf8, until, page)
+        };
+        // Until is inclusive, so lines logged in the same second as the oldest line are returned again, & skipped as duplicates
+        let options = LogsOptions::<String> {
+            stdout: true,
+            timestamps: true,
+            until,
+            tail: page,
+            ..Default::default()
+        };
+        let (output, _) = Self::read_logs(runtime.as_ref(), &id, options, invalid_utf8).await;
+        Some(app_data.lock().prepend_log_by_id(output, &id))
+    }
+
     /// Update single container logs
     /// remove it from spawns hashmap when complete
+    /// A log line that matches a `--watch` is shown in the info box
     async fn update_log(
         app_data: Arc<Mutex<AppData>>,
-        docker: Arc<Docker>,
+        gui_state: Arc<Mutex<GuiState>>,
+        runtime: Arc<dyn Runtime>,
         id: ContainerId,
         since: u64,
         spawns: Arc<Mutex<HashMap<SpawnId, JoinHandle<()>>>>,
     ) {
-        let options = Some(LogsOptions::<String> {
+        let (invalid_utf8, tail, fallback, host) = {
+            let mut app_data = app_data.lock();
+            let tail = app_data.get_container_name_by_id(&id).map_or_else(
+                || "all".to_owned(),
+                |name| app_data.args.tail.get(name.get()),
+            );
+            // The primary host is the resolved host, from the cli args or DOCKER_HOST
+            let host = app_data
+                .get_host_by_id(&id)
+                .or_else(|| app_data.args.host.clone());
+            (
+                app_data.args.invalid_utf8,
+                tail,
+                app_data.get_log_fallback(&id),
+                host,
+            )
+        };
+        // The tail only limits the history fetched when the logs are first loaded, later updates fetch every line since the last update
+        let options = LogsOptions::<String> {
             stdout: true,
             timestamps: true,
             since: i64::try_from(since).unwrap_or_default(),
+            tail: if since == 0 {
+                tail.clone()
+            } else {
+                "all".to_owned()
+            },
             ..Default::default()
-        });
-
-        let mut logs = docker.logs(id.get(), options);
-        let mut output = vec![];
+        };
 
-        while let Some(Ok(value)) = logs.next().await {
-            let data = value.to_string();
-            if !data.trim().is_empty() {
-                output.push(data);
+        let (mut output, failed) =
+            Self::read_logs(runtime.as_ref(), &id, options, invalid_utf8).await;
+        // The daemon can't read the logs of some logging drivers, so they're read from the fallback instead
+        if let Some(fallback) = fallback.filter(|_| failed && output.is_empty()) {
+            if let Some(lines) = fallback.read(host.as_deref(), &id, since, &tail).await {
+                output.extend(
+                    lines
+                        .into_iter()
+                        .filter(|i| !i.trim().is_empty())
+                        .map(LogLine::Text),
+                );
             }
         }
         spawns.lock().remove(&SpawnId::Log(id.clone()));
-        app_data.lock().update_log_by_id(output, &id);
+        let found = app_data.lock().update_log_by_id(output, &id);
+        if let Some(found) = found {
+            let mut gui_state = gui_state.lock();
+            gui_state.set_info_box(&format!("{}: {}", found.name, found.line));
+            gui_state.ring_bell();
+        }
+    }
+
+    /// Read the logs of a container, to save them, with the stream of each line
+    /// The daemon can't read the logs of some logging drivers, so they're read from the fallback instead, as stdout
+    async fn read_log_output(
+        runtime: &dyn Runtime,
+        id: &ContainerId,
+        options: LogsOptions<String>,
+        fallback: Option<LogFallback>,
+        host: Option<&str>,
+    ) -> Vec<LogOutput> {
+        let since = u64::try_from(options.since).unwrap_or_default();
+        let timestamps = options.timestamps;
+        let mut output = vec![];
+        let mut failed = false;
+        let mut logs = runtime.logs(id, options);
+        while let Some(value) = logs.next().await {
+            if let Ok(value) = value {
+                output.push(value);
+            } else {
+                failed = true;
+                break;
+            }
+        }
+        drop(logs);
+        if let Some(fallback) = fallback.filter(|_| failed && output.is_empty()) {
+            if let Some(lines) = fallback.read(host, id, since, "all").await {
+                output.extend(fallback_output(lines, timestamps));
+            }
+        }
+        output
     }
 
     /// Update all logs, spawn each container into own tokio::spawn thread
     fn init_all_logs(&mut self, all_ids: &[(State, ContainerId)]) {
         for (_, id) in all_ids {
-            let docker = Arc::clone(&self.docker);
+            let runtime = Arc::clone(&self.runtime);
             let app_data = Arc::clone(&self.app_data);
+            let gui_state = Arc::clone(&self.gui_state);
             let spawns = Arc::clone(&self.spawns);
             let key = SpawnId::Log(id.clone());
+            let rate_limit = self.rate_limit.clone();
+            let id = id.clone();
             self.spawns.lock().insert(
                 key,
-                tokio::spawn(Self::update_log(app_data, docker, id.clone(), 0, spawns)),
+                tokio::spawn(async move {
+                    let _permit = rate_limit.acquire().await;
+                    Self::update_log(app_data, gui_state, runtime, id, 0, spawns).await;
+                }),
+            );
+        }
+    }
+
+    /// Compare the daemon's reported time against the local time, the local time is taken as the midpoint of the request, to account for latency
+    /// Also records the host's cpu count, & total memory, to give container usage some context, & the daemon's warnings, which are announced once
+    /// Every host records whether it's a swarm manager, the rest is only recorded for the primary host
+    async fn update_daemon_info(
+        app_data: Arc<Mutex<AppData>>,
+        docker: Arc<Docker>,
+        gui_state: Arc<Mutex<GuiState>>,
+        host: Option<String>,
+    ) {
+        let as_secs = |time: SystemTime| {
+            time.duration_since(UNIX_EPOCH)
+                .map_or(0, |i| i64::try_from(i.as_secs()).unwrap_or_default())
+        };
+        let start = SystemTime::now();
+        if let Ok(info) = docker.info().await {
+            app_data
+                .lock()
+                .set_swarm(host.as_deref(), services::is_manager(&info));
+            if host.is_some() {
+                return;
+            }
+            let local = as_secs(start + start.elapsed().unwrap_or_default() / 2);
+            let resources = info.ncpu.zip(info.mem_total).and_then(|(cpus, memory)| {
+                Some(HostResources {
+                    cpus: u64::try_from(cpus).ok()?,
+                    memory: u64::try_from(memory).ok()?,
+                })
+            });
+            let mut app_data = app_data.lock();
+            app_data.set_host_resources(resources);
+            if let Some(daemon) = info.system_time.as_deref().and_then(parse_rfc3339) {
+                app_data.set_clock_skew(Some(daemon - local));
+            }
+            if app_data.set_daemon_info(daemon_info(&info)) {
+                let warnings = app_data.get_daemon_warnings_len();
+                drop(app_data);
+                gui_state.lock().set_info_box(&format!(
+                    "docker daemon has {warnings} warning{}, ( d ) to view",
+                    if warnings == 1 { "" } else { "s" }
+                ));
+            }
+        }
+    }
+
+    /// Run the readiness probes of this host's running containers, a probe that is still running from the last interval isn't run again
+    fn update_probes(&self) {
+        let targets = self.app_data.lock().get_probe_targets(self.host.as_deref());
+        for target in targets {
+            self.spawns
+                .lock()
+                .entry(SpawnId::Probe(target.id.clone()))
+                .or_insert_with(|| {
+                    let app_data = Arc::clone(&self.app_data);
+                    let runtime = Arc::clone(&self.runtime);
+                    let spawns = Arc::clone(&self.spawns);
+                    tokio::spawn(async move {
+                        let readiness = probe::run(runtime.as_ref(), &target).await;
+                        spawns.lock().remove(&SpawnId::Probe(target.id.clone()));
+                        app_data.lock().set_readiness(&target.id, readiness);
+                    })
+                });
+        }
+    }
+
+    /// List the containers with their sizes, as the daemon has to measure every writable layer this is slow, so a single check runs at a time
+    /// Each container whose layer has grown past the `--disk-alert` is shown in the info box, & rings the bell
+    fn update_disk(&self) {
+        self.spawns.lock().entry(SpawnId::Disk).or_insert_with(|| {
+            let app_data = Arc::clone(&self.app_data);
+            let runtime = Arc::clone(&self.runtime);
+            let gui_state = Arc::clone(&self.gui_state);
+            let spawns = Arc::clone(&self.spawns);
+            let rate_limit = self.rate_limit.clone();
+            let host = self
+                .host
+                .as_ref()
+                .map_or_else(String::new, |i| format!("{i}: "));
+            tokio::spawn(async move {
+                let _permit = rate_limit.acquire().await;
+                let containers = runtime
+                    .list(ListContainersOptions::<String> {
+                        all: true,
+                        size: true,
+                        ..Default::default()
+                    })
+                    .await;
+                spawns.lock().remove(&SpawnId::Disk);
+                let Ok(containers) = containers else {
+                    return;
+                };
+                let sizes = containers
+                    .iter()
+                    .filter_map(|i| {
+                        Some((
+                            ContainerId::from(i.id.as_deref()?),
+                            u64::try_from(i.size_rw?).ok()?,
+                        ))
+                    })
+                    .collect::<HashMap<_, _>>();
+                let (over, limit) = {
+                    let mut app_data = app_data.lock();
+                    (app_data.update_rw_sizes(&sizes), app_data.args.disk_alert)
+                };
+                let limit = ByteStats::new(limit.unwrap_or_default());
+                let text = match over.as_slice() {
+                    [] => return,
+                    [(name, size)] => {
+                        format!("{host}{name} writable layer is {size}, over {limit}")
+                    }
+                    over => format!(
+                        "{host}{} writable layers are over {limit}",
+                        over.iter()
+                            .map(|(name, _)| name.to_string())
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ),
+                };
+                let mut gui_state = gui_state.lock();
+                gui_state.set_info_box(&text);
+                gui_state.ring_bell();
+            })
+        });
+    }
+
+    /// Run the schedules, of this host's containers, that are due, the outcome of each is shown in the info box, & the schedules panel
+    fn update_schedules(&self) {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |i| i.as_secs());
+        let due = self
+            .app_data
+            .lock()
+            .take_due_schedules(self.host.as_deref(), now);
+        for run in due {
+            let app_data = Arc::clone(&self.app_data);
+            let docker = Arc::clone(&self.docker);
+            let gui_state = Arc::clone(&self.gui_state);
+            let save_dir = self.args.save_dir.clone();
+            let timestamps = self.args.timestamp;
+            let invalid_utf8 = self.args.invalid_utf8;
+            let rules = self.args.redact.clone();
+            let refused = self.args.read_only && run.action != ScheduleAction::SaveLogs;
+            tokio::spawn(async move {
+                let outcome = if refused {
+                    Err(READ_ONLY_TEXT.to_owned())
+                } else {
+                    schedule::run(
+                        &docker,
+                        &run,
+                        save_dir.as_deref(),
+                        timestamps,
+                        invalid_utf8,
+                        &rules,
+                        now,
+                    )
+                    .await
+                };
+                let text = match &outcome {
+                    Ok(text) => text.clone(),
+                    Err(e) => format!("scheduled {} of {} failed: {e}", run.action, run.name),
+                };
+                app_data
+                    .lock()
+                    .set_schedule_outcome(run.key, now, outcome.map(|_| ()));
+                gui_state.lock().set_info_box(&text);
+            });
+        }
+    }
+
+    /// In standby, updates are skipped, other than one every STANDBY_INTERVAL seconds
+    fn skip_standby_update(&mut self) -> bool {
+        if !self.gui_state.lock().status_contains(&[Status::Standby]) {
+            self.standby_updated = None;
+            return false;
+        }
+        if matches!(self.standby_updated, Some(i) if i.elapsed() < Duration::from_secs(STANDBY_INTERVAL))
+        {
+            return true;
+        }
+        self.standby_updated = Some(Instant::now());
+        false
+    }
+
+    /// Refresh a boosted container's logs, & stats, either is skipped while its previous request is still running
+    /// A stats request takes as long as the daemon takes to sample, about a second, so it's spawned into whichever of the two stats spawns is free
+    fn update_boosted(&self, id: &ContainerId) {
+        let Some((state, last_updated)) = self.app_data.lock().get_boost_update(id) else {
+            return;
+        };
+        let (log_free, stats_free) = {
+            let spawns = self.spawns.lock();
+            (
+                !spawns.contains_key(&SpawnId::Log(id.clone())),
+                [Binate::One, Binate::Two]
+                    .into_iter()
+                    .map(|i| SpawnId::Stats((id.clone(), i)))
+                    .find(|i| !spawns.contains_key(i))
+                    .filter(|_| state.is_alive()),
+            )
+        };
+        if log_free {
+            self.app_data.lock().wait_for_logs(id);
+            let app_data = Arc::clone(&self.app_data);
+            let gui_state = Arc::clone(&self.gui_state);
+            let runtime = Arc::clone(&self.runtime);
+            let spawns = Arc::clone(&self.spawns);
+            let rate_limit = self.rate_limit.clone();
+            let id = id.clone();
+            self.spawns.lock().insert(
+                SpawnId::Log(id.clone()),
+                tokio::spawn(async move {
+                    let _permit = rate_limit.acquire().await;
+                    Self::update_log(app_data, gui_state, runtime, id, last_updated, spawns).await;
+                }),
+            );
+        }
+        if let Some(spawn_id) = stats_free {
+            self.app_data.lock().wait_for_stats(id);
+            let app_data = Arc::clone(&self.app_data);
+            let runtime = (Arc::clone(&self.runtime), self.podman.clone());
+            let spawns = Arc::clone(&self.spawns);
+            let rate_limit = self.rate_limit.clone();
+            let id = id.clone();
+            self.spawns.lock().insert(
+                spawn_id.clone(),
+                tokio::spawn(async move {
+                    let _permit = rate_limit.acquire().await;
+                    Self::update_container_stat(
+                        app_data, runtime, id, None, state, spawn_id, spawns,
+                    )
+                    .await;
+                }),
             );
         }
     }
 
+    /// Boost a container for BOOST_DURATION, a refresh is sent every BOOST_INTERVAL, until the boost expires, is cancelled, or another container is boosted
+    fn boost(&self, id: ContainerId) {
+        let deadline = Instant::now() + BOOST_DURATION;
+        self.app_data.lock().set_boost(Some((id.clone(), deadline)));
+        let app_data = Arc::clone(&self.app_data);
+        let gui_state = Arc::clone(&self.gui_state);
+        let docker_tx = self.docker_tx.clone();
+        let interval = self.args.docker_interval;
+        tokio::spawn(async move {
+            loop {
+                tokio::time::sleep(BOOST_INTERVAL).await;
+                if app_data.lock().get_boost() != Some((id.clone(), deadline)) {
+                    break;
+                }
+                if Instant::now() >= deadline {
+                    let name = {
+                        let mut app_data = app_data.lock();
+                        app_data.set_boost(None);
+                        app_data.get_container_name_by_id(&id)
+                    };
+                    if let Some(name) = name {
+                        gui_state.lock().set_info_box(&format!(
+                            "refresh of {} back to every {interval}ms",
+                            name.get()
+                        ));
+                    }
+                    break;
+                }
+                if docker_tx
+                    .send(DockerMessage::BoostTick(id.clone()))
+                    .await
+                    .is_err()
+                {
+                    break;
+                }
+            }
+        });
+    }
+
     /// Update all cpu_mem, and selected container log (if a log update join_handle isn't currently being executed)
     async fn update_everything(&mut self) {
+        if !matches!(self.clock_checked, Some(i) if i.elapsed() < Duration::from_secs(CLOCK_CHECK_INTERVAL))
+        {
+            self.clock_checked = Some(Instant::now());
+            tokio::spawn(Self::update_daemon_info(
+                Arc::clone(&self.app_data),
+                Arc::clone(&self.docker),
+                Arc::clone(&self.gui_state),
+                self.host.clone(),
+            ));
+        }
         let all_ids = self.update_all_containers().await;
-        if let Some(container) = self.app_data.lock().get_selected_container() {
-            let last_updated = container.last_updated;
+        if !self.args.probes.is_empty()
+            && !matches!(self.probes_checked, Some(i) if i.elapsed() < Duration::from_secs(self.args.probe_interval))
+        {
+            self.probes_checked = Some(Instant::now());
+            self.update_probes();
+        }
+        if self.args.disk_alert.is_some()
+            && !matches!(self.disk_checked, Some(i) if i.elapsed() < DISK_CHECK_INTERVAL)
+        {
+            self.disk_checked = Some(Instant::now());
+            self.update_disk();
+        }
+        self.update_schedules();
+        // The selected container's logs, and the logs of any watched containers, are updated
+        let log_ids = {
+            let mut app_data = self.app_data.lock();
+            let log_ids = app_data.get_log_update_ids();
+            for (id, _) in &log_ids {
+                app_data.wait_for_logs(id);
+            }
+            log_ids
+        };
+        for (id, last_updated) in &log_ids {
             self.spawns
                 .lock()
-                .entry(SpawnId::Log(container.id.clone()))
+                .entry(SpawnId::Log(id.clone()))
                 .or_insert_with(|| {
                     // MAYBE make a struct that can create this data?
                     let app_data = Arc::clone(&self.app_data);
-                    let docker = Arc::clone(&self.docker);
-                    let id = container.id.clone();
+                    let gui_state = Arc::clone(&self.gui_state);
+                    let runtime = Arc::clone(&self.runtime);
+                    let id = id.clone();
+                    let last_updated = *last_updated;
                     let spawns = Arc::clone(&self.spawns);
-                    tokio::spawn(Self::update_log(app_data, docker, id, last_updated, spawns))
+                    let rate_limit = self.rate_limit.clone();
+                    tokio::spawn(async move {
+                        let _permit = rate_limit.acquire().await;
+                        Self::update_log(app_data, gui_state, runtime, id, last_updated, spawns)
+                            .await;
+                    })
                 });
-        };
-        self.update_all_container_stats(&all_ids);
+        }
+        let resources = self
+            .resources_shown()
+            .filter(|panel| self.resources_due(*panel));
+        if let Some(panel) = resources {
+            if self.update_resources(panel) {
+                self.resources_listed = Some((panel, Instant::now()));
+            } else {
+                // Still listing from an earlier update, so the change is listed on a later update
+                self.app_data
+                    .lock()
+                    .set_resources_changed(self.host.as_deref(), panel);
+            }
+        }
+        let stats = self.update_all_container_stats(&all_ids);
         self.app_data.lock().sort_containers();
+
+        // The list request, a stats request for each updated alive container, the log requests, and the two list requests of the images, volumes, or networks panel
+        let cost = stats + 1 + log_ids.len() + if resources.is_some() { 2 } else { 0 };
+        self.rate_limit.set_cost(cost);
+        if self.host.is_none() {
+            let stretch = u32::try_from(self.rate_limit.stretch()).unwrap_or(u32::MAX);
+            self.app_data
+                .lock()
+                .set_refresh_stretch((stretch > 1).then(|| {
+                    Duration::from_millis(u64::from(self.args.docker_interval)) * stretch
+                }));
+        }
+    }
+
+    /// The images, volumes, networks, or services panel that's shown, if it's showing the resources of this host
+    /// The services panel is hidden while this host isn't a swarm manager
+    fn resources_shown(&mut self) -> Option<SelectablePanel> {
+        let (this_host, swarm) = {
+            let app_data = self.app_data.lock();
+            (
+                app_data.get_resources_host() == self.host.as_deref(),
+                app_data.is_swarm(self.host.as_deref()),
+            )
+        };
+        let shown = this_host
+            .then(|| {
+                let mut gui_state = self.gui_state.lock();
+                gui_state.set_swarm(swarm);
+                gui_state.get_resources_panel()
+            })
+            .flatten();
+        if shown.is_none() {
+            self.resources_listed = None;
+        }
+        shown
+    }
+
+    /// The shown panel is listed when it's first shown, when an event has changed it, &, as a fallback, every RESOURCES_INTERVAL
+    /// Volume sizes change without an event, so the volumes are also listed whenever their sizes are due to be checked
+    fn resources_due(&self, panel: SelectablePanel) -> bool {
+        let changed = self
+            .app_data
+            .lock()
+            .take_resources_changed(self.host.as_deref(), panel);
+        let listed = self
+            .resources_listed
+            .filter(|(listed, _)| *listed == panel)
+            .map(|(_, at)| at.elapsed());
+        let sizes_due = panel == SelectablePanel::Volumes
+            && !matches!(self.volume_sizes_checked, Some(i) if i.elapsed() < VOLUME_SIZE_INTERVAL);
+        changed || sizes_due || listed.is_none_or(|i| i >= RESOURCES_INTERVAL)
+    }
+
+    /// Every container, running or not, to find which containers use each image, volume, or network
+    async fn list_all_containers(docker: &Docker) -> Option<Vec<ContainerSummary>> {
+        docker
+            .list_containers(Some(ListContainersOptions::<String> {
+                all: true,
+                ..Default::default()
+            }))
+            .await
+            .ok()
+    }
+
+    /// List the local images, and which containers were created from each of them
+    async fn list_images(docker: &Docker, app_data: &Arc<Mutex<AppData>>) {
+        let images = docker
+            .list_images(Some(ListImagesOptions::<String>::default()))
+            .await;
+        let containers = Self::list_all_containers(docker).await;
+        if let (Ok(images), Some(containers)) = (images, containers) {
+            app_data
+                .lock()
+                .set_images(images::images(&images, &containers));
+        }
+    }
+
+    /// List the volumes, and which containers mount each of them, the size of each volume is only checked if `with_sizes`
+    async fn list_volumes(docker: &Docker, app_data: &Arc<Mutex<AppData>>, with_sizes: bool) {
+        let list = docker
+            .list_volumes(None::<ListVolumesOptions<String>>)
+            .await;
+        let containers = Self::list_all_containers(docker).await;
+        let sizes = if with_sizes {
+            docker
+                .df()
+                .await
+                .ok()
+                .and_then(|i| i.volumes)
+                .map(|i| volumes::sizes(&i))
+                .unwrap_or_default()
+        } else {
+            HashMap::new()
+        };
+        if let (Ok(list), Some(containers)) = (list, containers) {
+            app_data.lock().set_volumes(volumes::volumes(
+                list.volumes.as_deref().unwrap_or_default(),
+                &containers,
+                &sizes,
+            ));
+        }
+    }
+
+    /// List the networks, and which containers are connected to each of them
+    async fn list_networks(docker: &Docker, app_data: &Arc<Mutex<AppData>>) {
+        let list = docker
+            .list_networks(None::<ListNetworksOptions<String>>)
+            .await;
+        let containers = Self::list_all_containers(docker).await;
+        if let (Ok(list), Some(containers)) = (list, containers) {
+            app_data
+                .lock()
+                .set_networks(networks::networks(&list, &containers));
+        }
+    }
+
+    /// List the swarm services, with the running, & desired, tasks of each
+    async fn list_services(docker: &Docker, app_data: &Arc<Mutex<AppData>>) {
+        let options = ListServicesOptions::<String> {
+            status: true,
+            ..Default::default()
+        };
+        if let Ok(list) = docker.list_services(Some(options)).await {
+            app_data.lock().set_services(services::services(&list));
+        }
+    }
+
+    /// Inspect the service for its current spec, & version, & update it with the changed spec, returns false if the change doesn't apply, or the update fails
+    /// The version is checked by the daemon, so a service that has changed since it was inspected isn't overwritten
+    async fn update_service(
+        docker: &Docker,
+        id: &str,
+        change: impl FnOnce(ServiceSpec) -> Option<ServiceSpec>,
+    ) -> bool {
+        let Ok(service) = docker.inspect_service(id, None).await else {
+            return false;
+        };
+        let (Some(spec), Some(version)) = (
+            service.spec.and_then(change),
+            service.version.and_then(|i| i.index),
+        ) else {
+            return false;
+        };
+        let options = UpdateServiceOptions {
+            version,
+            ..Default::default()
+        };
+        docker.update_service(id, spec, options, None).await.is_ok()
+    }
+
+    /// Scale every replicated service of the stack, returns the services that were scaled, & those that couldn't be, None if the stack's services can't be listed
+    async fn scale_stack(docker: &Docker, stack: &str, replicas: u64) -> Option<(usize, usize)> {
+        let options = ListServicesOptions {
+            filters: services::stack_filters(stack),
+            ..Default::default()
+        };
+        let list = docker.list_services(Some(options)).await.ok()?;
+        let (mut scaled, mut failed) = (0, 0);
+        for service in services::services(&list).into_iter().filter(|i| !i.global) {
+            if Self::update_service(docker, &service.id, |spec| services::scaled(spec, replicas))
+                .await
+            {
+                scaled += 1;
+            } else {
+                failed += 1;
+            }
+        }
+        Some((scaled, failed))
+    }
+
+    /// Remove the services of the stack, then its networks, & secrets, as `docker stack rm` does, each is found by the stack's label
+    /// Returns the services removed, & whether everything was removed, None if the stack's services can't be listed
+    /// The configs of the stack are left, as oxker's docker client has no configs endpoints
+    async fn remove_stack(docker: &Docker, stack: &str) -> Option<(usize, bool)> {
+        let options = ListServicesOptions {
+            filters: services::stack_filters(stack),
+            ..Default::default()
+        };
+        let list = docker.list_services(Some(options)).await.ok()?;
+        let mut removed = 0;
+        let mut complete = true;
+        for id in list.into_iter().filter_map(|i| i.id) {
+            if docker.delete_service(&id).await.is_ok() {
+                removed += 1;
+            } else {
+                complete = false;
+            }
+        }
+        let networks = docker
+            .list_networks(Some(ListNetworksOptions {
+                filters: services::stack_filters(stack),
+            }))
+            .await;
+        for id in networks.iter().flatten().filter_map(|i| i.id.as_ref()) {
+            complete &= docker.remove_network(id).await.is_ok();
+        }
+        let secrets = docker
+            .list_secrets(Some(ListSecretsOptions {
+                filters: services::stack_filters(stack),
+            }))
+            .await;
+        for id in secrets.iter().flatten().filter_map(|i| i.id.as_ref()) {
+            complete &= docker.delete_secret(id).await.is_ok();
+        }
+        Some((removed, complete && networks.is_ok() && secrets.is_ok()))
+    }
+
+    /// The logs of each task of the service on this host, running or not, merged oldest first
+    /// Returns the number of tasks, & the lines, as the daemon's service logs aren't available, the tasks on other nodes aren't included
+    async fn task_logs(
+        runtime: &dyn Runtime,
+        id: &str,
+        invalid_utf8: InvalidUtf8,
+        rules: &[Redact],
+    ) -> Option<(usize, Vec<String>)> {
+        let filters = HashMap::from([(
+            "label".to_owned(),
+            vec![format!("{}={id}", services::SERVICE_ID_LABEL)],
+        )]);
+        let containers = runtime
+            .list(ListContainersOptions::<String> {
+                all: true,
+                filters,
+                ..Default::default()
+            })
+            .await
+            .ok()?;
+        let mut output = vec![];
+        for container in containers {
+            let (Some(id), Some(name)) = (container.id, container.names) else {
+                continue;
+            };
+            let name = name
+                .first()
+                .map_or_else(|| id.clone(), |i| i.trim_start_matches('/').to_owned());
+            let lines = Self::plain_logs(
+                runtime,
+                &ContainerId::from(id.as_str()),
+                Some(services::TASK_LOG_LINES),
+                invalid_utf8,
+                rules,
+            )
+            .await;
+            output.push(bundle::ContainerLogs { name, lines });
+        }
+        output.sort_by(|a, b| a.name.cmp(&b.name));
+        let lines = bundle::to_timeline(&output)
+            .lines()
+            .map(ToOwned::to_owned)
+            .collect();
+        Some((output.len(), lines))
+    }
+
+    /// Update the images, volumes, networks, or services, of the shown panel, a single update at a time, returns false if an update is already running
+    fn update_resources(&mut self, panel: SelectablePanel) -> bool {
+        let with_sizes = panel == SelectablePanel::Volumes
+            && !matches!(self.volume_sizes_checked, Some(i) if i.elapsed() < VOLUME_SIZE_INTERVAL);
+        let mut started = false;
+        self.spawns
+            .lock()
+            .entry(SpawnId::Resources)
+            .or_insert_with(|| {
+                started = true;
+                let app_data = Arc::clone(&self.app_data);
+                let docker = Arc::clone(&self.docker);
+                let spawns = Arc::clone(&self.spawns);
+                let rate_limit = self.rate_limit.clone();
+                tokio::spawn(async move {
+                    let _permit = rate_limit.acquire().await;
+                    match panel {
+                        SelectablePanel::Volumes => {
+                            Self::list_volumes(&docker, &app_data, with_sizes).await;
+                        }
+                        SelectablePanel::Networks => {
+                            Self::list_networks(&docker, &app_data).await;
+                        }
+                        SelectablePanel::Services => {
+                            Self::list_services(&docker, &app_data).await;
+                        }
+                        _ => Self::list_images(&docker, &app_data).await,
+                    }
+                    spawns.lock().remove(&SpawnId::Resources);
+                })
+            });
+        if started && with_sizes {
+            self.volume_sizes_checked = Some(Instant::now());
+        }
+        started
     }
 
     /// Initialize docker container data, before any messages are received
@@ -340,100 +1549,1431 @@ impl DockerData {
             .set_error(AppError::DockerCommand(error), gui_state, Status::Error);
     }
 
+    /// Show the outcome of a pull, a failed pull is shown as an error
+    /// Forward an exposed port of a container to the Docker host, with a socat container, its image is pulled first, if it isn't available locally
+    /// Returns the text for the info box, None if the pull failed, or was cancelled, as that's already shown
+    async fn forward_port(
+        app_data: &Arc<Mutex<AppData>>,
+        docker: &Docker,
+        gui_state: &Arc<Mutex<GuiState>>,
+        id: &ContainerId,
+        port: u16,
+        host: Option<String>,
+    ) -> Option<String> {
+        let inspect = match docker.inspect_container(id.get(), None).await {
+            Ok(inspect) => inspect,
+            Err(e) => return Some(format!("unable to inspect container: {e}")),
+        };
+        let Some(forward) = Forward::new(&inspect, port) else {
+            return Some(format!(
+                "unable to forward {port}, the container isn't on a network that can be joined"
+            ));
+        };
+        if docker.inspect_image(FORWARD_IMAGE).await.is_err() {
+            match images::pull(docker, gui_state, FORWARD_IMAGE, host).await {
+                pulled @ (Pulled::Cancelled | Pulled::Failed(_)) => {
+                    Self::set_pulled(app_data, gui_state, FORWARD_IMAGE, pulled);
+                    return None;
+                }
+                Pulled::Done(_) => Self::list_images(docker, app_data).await,
+            }
+        }
+        Some(match forward.start(docker).await {
+            Ok(()) => format!(
+                "forwarding {} to port {port}, stop {} to close it",
+                forward.address(),
+                forward.name
+            ),
+            Err(e) => format!(
+                "unable to forward {port}: {e}, to forward it manually: {}",
+                forward.command()
+            ),
+        })
+    }
+
+    /// Capture the packets of a container, with a tcpdump container in its network namespace, its image is pulled first, if it isn't available locally
+    /// Returns the text for the info box, None if the pull failed, or was cancelled, as that's already shown
+    async fn capture(
+        app_data: &Arc<Mutex<AppData>>,
+        docker: &Docker,
+        gui_state: &Arc<Mutex<GuiState>>,
+        (id, filter): (&ContainerId, &str),
+        (save_dir, host): (Option<PathBuf>, Option<String>),
+        stop: &Arc<Notify>,
+    ) -> Option<String> {
+        let Some(dir) = save_dir else {
+            return Some("no save directory".to_owned());
+        };
+        if docker.inspect_image(CAPTURE_IMAGE).await.is_err() {
+            match images::pull(docker, gui_state, CAPTURE_IMAGE, host).await {
+                pulled @ (Pulled::Cancelled | Pulled::Failed(_)) => {
+                    Self::set_pulled(app_data, gui_state, CAPTURE_IMAGE, pulled);
+                    return None;
+                }
+                Pulled::Done(_) => Self::list_images(docker, app_data).await,
+            }
+        }
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |i| i.as_secs());
+        let name = bundle::file_name(&Self::container_name(app_data, id));
+        let path = dir.join(format!("{name}_{now}.pcap"));
+        if let Some(capture) = gui_state.lock().get_capture().filter(|i| i.is(stop)) {
+            capture.path = Some(path.clone());
+        }
+        Some(
+            capture::run(docker, gui_state, (id, filter), &path, stop)
+                .await
+                .unwrap_or_else(|e| e),
+        )
+    }
+
+    fn set_pulled(
+        app_data: &Arc<Mutex<AppData>>,
+        gui_state: &Arc<Mutex<GuiState>>,
+        image: &str,
+        pulled: Pulled,
+    ) {
+        match pulled {
+            Pulled::Done(status) => gui_state.lock().set_info_box(&status),
+            Pulled::Cancelled => gui_state
+                .lock()
+                .set_info_box(&format!("pull of {image} cancelled")),
+            Pulled::Failed(_) => {
+                app_data
+                    .lock()
+                    .set_error(AppError::ImagePull, gui_state, Status::Error);
+            }
+        }
+    }
+
+    /// Record the outcome of a control sent to a container, a failure is shown with the daemon's error, & a retry is offered
+    /// A success closes the failure of the same control, if it's still shown
+    fn set_attempt(
+        app_data: &Arc<Mutex<AppData>>,
+        gui_state: &Arc<Mutex<GuiState>>,
+        control: DockerControls,
+        id: &ContainerId,
+        host: Option<&str>,
+        result: Result<(), String>,
+    ) {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |i| i.as_secs());
+        let failed = result.is_err();
+        app_data
+            .lock()
+            .push_attempt(id, host, control, result.err(), now);
+        let retried = !failed
+            && gui_state
+                .lock()
+                .get_failed_ref()
+                .is_some_and(|i| &i.id == id && i.control == control);
+        if failed {
+            gui_state.lock().set_failed(Some(FailedControl {
+                control,
+                id: id.clone(),
+                host: host.map(ToOwned::to_owned),
+                retry_at: None,
+            }));
+        } else if retried {
+            gui_state.lock().set_failed(None);
+            gui_state
+                .lock()
+                .set_info_box(&format!("{control} succeeded"));
+        }
+    }
+
+    /// Send a control to a container, via the runtime, in its own thread, after a delay, the backoff of a retry
+    async fn control(&mut self, control: DockerControls, id: ContainerId, delay: Duration) {
+        let runtime = Arc::clone(&self.runtime);
+        let gui_state = Arc::clone(&self.gui_state);
+        let app_data = Arc::clone(&self.app_data);
+        let host = self.host.clone();
+        let uuid = Uuid::new_v4();
+        tokio::spawn(async move {
+            tokio::time::sleep(delay).await;
+            let handle = GuiState::start_loading_animation(&gui_state, uuid);
+            let result = runtime.control(control, &id).await;
+            gui_state.lock().stop_loading_animation(&handle, uuid);
+            Self::set_attempt(&app_data, &gui_state, control, &id, host.as_deref(), result);
+        });
+        self.update_everything().await;
+    }
+
+    /// Send a control to several containers, in its own thread, every container of a compose project, in the order of its `depends_on`, with each step shown in the info box, or else every marked container
+    async fn batch_control(
+        &mut self,
+        control: DockerControls,
+        ids: Vec<ContainerId>,
+        project: bool,
+    ) {
+        let runtime = Arc::clone(&self.runtime);
+        let gui_state = Arc::clone(&self.gui_state);
+        let app_data = Arc::clone(&self.app_data);
+        let uuid = Uuid::new_v4();
+        tokio::spawn(async move {
+            let handle = GuiState::start_loading_animation(&gui_state, uuid);
+            let sent = if project {
+                let status = |text: &str| gui_state.lock().set_info_box(text);
+                runtime::project_control(runtime.as_ref(), control, &ids, &status).await
+            } else {
+                runtime::batch_control(runtime.as_ref(), control, &ids).await
+            };
+            if !sent {
+                Self::set_error(&app_data, control, &gui_state);
+            }
+            gui_state.lock().stop_loading_animation(&handle, uuid);
+        });
+        self.update_everything().await;
+    }
+
+    /// Pull the latest image of a container, & recreate the container with it, in its own thread, after a delay, the backoff of a retry
+    fn update_container(&self, id: ContainerId, delay: Duration) {
+        let docker = Arc::clone(&self.docker);
+        let gui_state = Arc::clone(&self.gui_state);
+        let app_data = Arc::clone(&self.app_data);
+        let host = self.host.clone();
+        let uuid = Uuid::new_v4();
+        tokio::spawn(async move {
+            tokio::time::sleep(delay).await;
+            let handle = GuiState::start_loading_animation(&gui_state, uuid);
+            let result = match docker.inspect_container(id.get(), None).await {
+                Ok(inspect) => {
+                    Self::pull_recreate(&docker, &gui_state, id.clone(), inspect, host.clone())
+                        .await
+                }
+                Err(e) => Some(Err(e.to_string())),
+            };
+            gui_state.lock().stop_loading_animation(&handle, uuid);
+            // A cancelled pull isn't an attempt
+            if let Some(result) = result {
+                if let Ok(text) = &result {
+                    gui_state.lock().set_info_box(text);
+                }
+                Self::set_attempt(
+                    &app_data,
+                    &gui_state,
+                    DockerControls::Update,
+                    &id,
+                    host.as_deref(),
+                    result.map(|_| ()),
+                );
+            }
+        });
+    }
+
+    /// Pull the latest image of the container's tag, with the progress shown, and recreate the container with it, keeping the rest of its config
+    /// If the tag still points to the container's image, the container is left as it is, None if the pull was cancelled
+    async fn pull_recreate(
+        docker: &Docker,
+        gui_state: &Arc<Mutex<GuiState>>,
+        id: ContainerId,
+        inspect: ContainerInspectResponse,
+        host: Option<String>,
+    ) -> Option<Result<String, String>> {
+        let form = recreate::form(id, &inspect, None);
+        let image = form.image();
+        match images::pull(docker, gui_state, &image, host).await {
+            Pulled::Done(_) => (),
+            Pulled::Cancelled => {
+                gui_state
+                    .lock()
+                    .set_info_box(&format!("pull of {image} cancelled"));
+                return None;
+            }
+            Pulled::Failed(e) => return Some(Err(e)),
+        }
+        let up_to_date = docker
+            .inspect_image(&image)
+            .await
+            .is_ok_and(|i| recreate::is_up_to_date(&inspect, &i));
+        if up_to_date {
+            return Some(Ok(format!("{} is up to date, {image}", form.name)));
+        }
+        Some(
+            recreate::recreate(docker, &form)
+                .await
+                .map(|()| format!("{} recreated with the latest {image}", form.name))
+                .map_err(|e| e.to_string()),
+        )
+    }
+
+    /// Inspect a container, with size, to find out how much space deleting it will reclaim, and how many volumes will be left behind
+    /// If the inspect fails, the size is unknown, and the preview still shown
+    async fn delete_preview(runtime: &dyn Runtime, id: &ContainerId) -> DeletePreview {
+        let inspect = runtime.inspect(id, true).await.ok();
+        DeletePreview {
+            confirmed: false,
+            size: inspect
+                .as_ref()
+                .and_then(|i| i.size_rw)
+                .map(|i| ByteStats::new(u64::try_from(i).unwrap_or_default())),
+            volumes: inspect.and_then(|i| i.mounts).map_or(0, |mounts| {
+                mounts
+                    .iter()
+                    .filter(|i| i.typ == Some(MountPointTypeEnum::VOLUME))
+                    .count()
+            }),
+        }
+    }
+
+    /// The dry run preview of deleting every marked container of the host, the total size of their writable layers, & of the volumes left behind, the size is unknown only if every inspect fails
+    async fn batch_preview(runtime: &dyn Runtime, ids: &[ContainerId]) -> DeletePreview {
+        let mut preview = DeletePreview {
+            confirmed: false,
+            size: None,
+            volumes: 0,
+        };
+        for id in ids {
+            preview.add(Self::delete_preview(runtime, id).await);
+        }
+        preview
+    }
+
+    /// Save the container as a docker run script & compose file, once its config, and its image's config, have been inspected
+    async fn export(docker: &Docker, id: &ContainerId, dir: Option<PathBuf>) -> Option<PathBuf> {
+        let dir = dir?;
+        let inspect = docker.inspect_container(id.get(), None).await.ok()?;
+        let image = match inspect.image.as_deref() {
+            Some(image) => docker
+                .inspect_image(image)
+                .await
+                .ok()
+                .and_then(|i| i.config),
+            None => None,
+        };
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |i| i.as_secs());
+        export::save(&inspect, image.as_ref(), &dir, now).ok()
+    }
+
+    /// The name of a container, or its id if it's no longer listed
+    fn container_name(app_data: &Arc<Mutex<AppData>>, id: &ContainerId) -> String {
+        let name = app_data.lock().get_container_name_by_id(id);
+        name.map_or_else(|| id.get().to_owned(), |i| i.get().to_owned())
+    }
+
+    /// The compose project that a container is part of, from its labels
+    async fn project(runtime: &dyn Runtime, id: &ContainerId) -> Option<String> {
+        let config = runtime.inspect(id, false).await.ok()?.config?;
+        compose_project(config.labels.as_ref())
+    }
+
+    /// The logs of a container, each line starts with its timestamp, without any ansi formatting, & with the redact rules applied, only the last tail lines if set
+    async fn plain_logs(
+        runtime: &dyn Runtime,
+        id: &ContainerId,
+        tail: Option<usize>,
+        invalid_utf8: InvalidUtf8,
+        rules: &[Redact],
+    ) -> Vec<String> {
+        let options = LogsOptions::<String> {
+            stdout: true,
+            timestamps: true,
+            tail: tail.map_or_else(|| "all".to_owned(), |i| i.to_string()),
+            ..Default::default()
+        };
+        let mut logs = runtime.logs(id, options);
+        let mut lines = vec![];
+        while let Some(Ok(value)) = logs.next().await {
+            let data = log_sanitizer::decode(&value.into_bytes(), invalid_utf8);
+            let line = log_sanitizer::plain(log_sanitizer::carriage_return(data.trim_end()));
+            if !line.trim().is_empty() {
+                lines.push(redact(rules, &line));
+            }
+        }
+        lines
+    }
+
+    /// Save the full logs of every container in a compose project, stopped containers included, as a bundle directory in dir
+    async fn export_project(
+        runtime: &dyn Runtime,
+        project: &str,
+        dir: &Path,
+        invalid_utf8: InvalidUtf8,
+        rules: &[Redact],
+    ) -> Option<PathBuf> {
+        // Label filters are all matched, so a project only labelled by podman-compose is listed by its own label
+        let mut containers = vec![];
+        for label in [PROJECT_LABEL, PODMAN_PROJECT_LABEL] {
+            let filters = HashMap::from([("label".to_owned(), vec![format!("{label}={project}")])]);
+            containers = runtime
+                .list(ListContainersOptions::<String> {
+                    all: true,
+                    filters,
+                    ..Default::default()
+                })
+                .await
+                .ok()?;
+            if !containers.is_empty() {
+                break;
+            }
+        }
+
+        let mut output = vec![];
+        for container in containers {
+            let (Some(id), Some(name)) = (container.id, container.names) else {
+                continue;
+            };
+            let name = name
+                .first()
+                .map_or_else(|| id.clone(), |i| i.trim_start_matches('/').to_owned());
+            let id = ContainerId::from(id.as_str());
+            let lines = Self::plain_logs(runtime, &id, None, invalid_utf8, rules).await;
+            output.push(bundle::ContainerLogs { name, lines });
+        }
+        output.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |i| i.as_secs());
+        bundle::save(dir, project, now, &output).ok()
+    }
+
+    /// Fill in the inspect output, & the tail of the logs, of each container of a snapshot, then save it as an archive in dir
+    /// The redact rules are applied to the inspect output, as well as the logs, as env vars often hold secrets
+    async fn snapshot_host(
+        runtime: &dyn Runtime,
+        mut snapshot: HostSnapshot,
+        dir: &Path,
+        invalid_utf8: InvalidUtf8,
+        rules: &[Redact],
+    ) -> Option<PathBuf> {
+        for container in &mut snapshot.containers {
+            let id = ContainerId::from(container.id.as_str());
+            container.inspect = runtime
+                .inspect(&id, false)
+                .await
+                .ok()
+                .and_then(|i| serde_json::to_string_pretty(&i).ok())
+                .map(|i| format!("{}\n", redact(rules, &i)));
+            container.logs = Self::plain_logs(
+                runtime,
+                &id,
+                Some(host_snapshot::LOG_TAIL),
+                invalid_utf8,
+                rules,
+            )
+            .await;
+        }
+        snapshot.save(dir).ok()
+    }
+
     /// Handle incoming messages, container controls & all container information update
     /// Spawn Docker commands off into own thread
     #[allow(clippy::too_many_lines)]
     async fn message_handler(&mut self) {
         while let Some(message) = self.receiver.recv().await {
             let docker = Arc::clone(&self.docker);
+            let runtime = Arc::clone(&self.runtime);
             let gui_state = Arc::clone(&self.gui_state);
             let app_data = Arc::clone(&self.app_data);
             let uuid = Uuid::new_v4();
+            if self.args.read_only && message.is_write() {
+                gui_state.lock().set_info_box(READ_ONLY_TEXT);
+                continue;
+            }
             // TODO need to refactor these
             match message {
+                DockerMessage::Boost(id) => self.boost(id),
+                DockerMessage::BoostTick(id) => self.update_boosted(&id),
+                DockerMessage::Capture(id, filter, stop) => {
+                    let dirs = (self.args.save_dir.clone(), self.host.clone());
+                    tokio::spawn(async move {
+                        let text = Self::capture(
+                            &app_data,
+                            &docker,
+                            &gui_state,
+                            (&id, &filter),
+                            dirs,
+                            &stop,
+                        )
+                        .await;
+                        let mut gui_state = gui_state.lock();
+                        if gui_state.get_capture_ref().is_some_and(|i| i.is(&stop)) {
+                            gui_state.status_del(Status::Capture);
+                        }
+                        if let Some(text) = text {
+                            gui_state.set_info_box(&text);
+                        }
+                    });
+                }
+                DockerMessage::CopyFrom(id, path) => {
+                    let save_dir = self.args.save_dir.clone();
+                    tokio::spawn(async move {
+                        let Some(dir) = save_dir else {
+                            gui_state.lock().set_info_box("no save directory");
+                            return;
+                        };
+                        let name = Self::container_name(&app_data, &id);
+                        let handle = GuiState::start_loading_animation(&gui_state, uuid);
+                        let saved = files::download(&docker, &id, &path, &name, &dir).await;
+                        gui_state.lock().stop_loading_animation(&handle, uuid);
+                        let text =
+                            saved.map_or_else(|e| e, |i| format!("saved to {}", i.display()));
+                        gui_state.lock().set_info_box(&text);
+                    });
+                }
                 DockerMessage::Exec(docker_tx) => {
                     docker_tx.send(Arc::clone(&self.docker)).ok();
                 }
-                DockerMessage::Pause(id) => {
+                DockerMessage::Export(id) => {
+                    let save_dir = self.args.save_dir.clone();
+                    tokio::spawn(async move {
+                        let handle = GuiState::start_loading_animation(&gui_state, uuid);
+                        let path = Self::export(&docker, &id, save_dir).await;
+                        gui_state.lock().stop_loading_animation(&handle, uuid);
+                        if let Some(path) = path {
+                            gui_state
+                                .lock()
+                                .set_info_box(&format!("saved to {} & .yml", path.display()));
+                        } else {
+                            app_data
+                                .lock()
+                                .set_error(AppError::Export, &gui_state, Status::Error);
+                        }
+                    });
+                }
+                DockerMessage::ExportProject(id) => {
+                    let save_dir = self.args.save_dir.clone();
+                    let invalid_utf8 = self.args.invalid_utf8;
+                    let rules = self.args.redact.clone();
+                    tokio::spawn(async move {
+                        let handle = GuiState::start_loading_animation(&gui_state, uuid);
+                        let project = Self::project(runtime.as_ref(), &id).await;
+                        let path = match (&project, save_dir) {
+                            (Some(project), Some(dir)) => {
+                                Self::export_project(
+                                    runtime.as_ref(),
+                                    project,
+                                    &dir,
+                                    invalid_utf8,
+                                    &rules,
+                                )
+                                .await
+                            }
+                            _ => None,
+                        };
+                        gui_state.lock().stop_loading_animation(&handle, uuid);
+                        match (project, path) {
+                            (None, _) => gui_state
+                                .lock()
+                                .set_info_box("container isn't part of a compose project"),
+                            (Some(_), Some(path)) => gui_state
+                                .lock()
+                                .set_info_box(&format!("saved to {}", path.display())),
+                            (Some(_), None) => app_data.lock().set_error(
+                                AppError::ExportProject,
+                                &gui_state,
+                                Status::Error,
+                            ),
+                        }
+                    });
+                }
+                DockerMessage::Files(id, path) => {
+                    tokio::spawn(async move {
+                        let handle = GuiState::start_loading_animation(&gui_state, uuid);
+                        let listed = files::list(runtime.as_ref(), &id, &path).await;
+                        gui_state.lock().stop_loading_animation(&handle, uuid);
+                        match listed {
+                            Ok(entries) => {
+                                let name = Self::container_name(&app_data, &id);
+                                gui_state.lock().set_file_browser(Some(FileBrowser::new(
+                                    id, name, path, entries,
+                                )));
+                            }
+                            Err(e) => gui_state.lock().set_info_box(&e),
+                        }
+                    });
+                }
+                DockerMessage::ImageDetail(host, image) => {
+                    tokio::spawn(async move {
+                        let handle = GuiState::start_loading_animation(&gui_state, uuid);
+                        let inspect = docker.inspect_image(&image.id).await;
+                        gui_state.lock().stop_loading_animation(&handle, uuid);
+                        match inspect {
+                            Ok(inspect) => gui_state
+                                .lock()
+                                .set_image_detail(Some(images::detail(inspect, image, host))),
+                            Err(_) => app_data.lock().set_error(
+                                AppError::ImageInspect,
+                                &gui_state,
+                                Status::Error,
+                            ),
+                        }
+                    });
+                }
+                DockerMessage::Forward(id, port) => {
+                    let host = self.host.clone();
+                    tokio::spawn(async move {
+                        let handle = GuiState::start_loading_animation(&gui_state, uuid);
+                        let text =
+                            Self::forward_port(&app_data, &docker, &gui_state, &id, port, host)
+                                .await;
+                        gui_state.lock().stop_loading_animation(&handle, uuid);
+                        if let Some(text) = text {
+                            gui_state.lock().set_info_box(&text);
+                        }
+                    });
+                }
+                DockerMessage::Health(id) => {
+                    tokio::spawn(async move {
+                        let inspect = runtime.inspect(&id, false).await;
+                        let now = SystemTime::now()
+                            .duration_since(UNIX_EPOCH)
+                            .map_or(0, |i| i.as_secs());
+                        match inspect {
+                            Ok(inspect) => match health::view(&inspect, now) {
+                                Some(view) => gui_state.lock().set_health_view(Some(view)),
+                                None => gui_state
+                                    .lock()
+                                    .set_info_box("the container doesn't have a health check"),
+                            },
+                            Err(e) => gui_state
+                                .lock()
+                                .set_info_box(&format!("unable to inspect container: {e}")),
+                        }
+                    });
+                }
+                DockerMessage::Kill(id, signal) => {
+                    self.control(DockerControls::Kill(signal), id, Duration::ZERO)
+                        .await;
+                }
+                DockerMessage::Inspect(id) => {
+                    tokio::spawn(async move {
+                        let handle = GuiState::start_loading_animation(&gui_state, uuid);
+                        let inspect = runtime.inspect(&id, false).await;
+                        gui_state.lock().stop_loading_animation(&handle, uuid);
+                        let view = inspect.ok().and_then(|inspect| {
+                            let name = inspect.name.as_deref().unwrap_or_default();
+                            let name = name.trim_start_matches('/').to_owned();
+                            serde_json::to_value(inspect)
+                                .ok()
+                                .map(|value| InspectView::new(name, value))
+                        });
+                        if view.is_some() {
+                            gui_state.lock().set_inspect_view(view);
+                        } else {
+                            app_data
+                                .lock()
+                                .set_error(AppError::Inspect, &gui_state, Status::Error);
+                        }
+                    });
+                }
+                DockerMessage::Compare(left, right) => {
+                    tokio::spawn(async move {
+                        let handle = GuiState::start_loading_animation(&gui_state, uuid);
+                        let inspects = tokio::try_join!(
+                            runtime.inspect(&left, false),
+                            runtime.inspect(&right, false)
+                        );
+                        gui_state.lock().stop_loading_animation(&handle, uuid);
+                        match inspects {
+                            Ok((left, right)) => gui_state
+                                .lock()
+                                .set_inspect_diff(Some(compare::diff(&left, &right))),
+                            Err(e) => gui_state
+                                .lock()
+                                .set_info_box(&format!("unable to inspect container: {e}")),
+                        }
+                    });
+                }
+                DockerMessage::LimitsForm(id) => {
+                    tokio::spawn(async move {
+                        match runtime.inspect(&id, false).await {
+                            Ok(inspect) => {
+                                let name = inspect.name.as_deref().map_or_else(
+                                    || id.get().to_owned(),
+                                    |i| i.trim_start_matches('/').to_owned(),
+                                );
+                                let limits = inspect
+                                    .host_config
+                                    .map_or_else(Limits::default, |i| Limits::from(&i));
+                                gui_state
+                                    .lock()
+                                    .set_limits_form(Some(LimitsForm::new(id, name, limits)));
+                            }
+                            Err(e) => gui_state
+                                .lock()
+                                .set_info_box(&format!("unable to inspect container: {e}")),
+                        }
+                    });
+                }
+                DockerMessage::ImagePull(_, image) => {
+                    let host = self.host.clone();
+                    tokio::spawn(async move {
+                        let handle = GuiState::start_loading_animation(&gui_state, uuid);
+                        let pulled = images::pull(&docker, &gui_state, &image, host).await;
+                        if matches!(pulled, Pulled::Done(_)) {
+                            Self::list_images(&docker, &app_data).await;
+                        }
+                        gui_state.lock().stop_loading_animation(&handle, uuid);
+                        Self::set_pulled(&app_data, &gui_state, &image, pulled);
+                    });
+                }
+                DockerMessage::ImageRemove(_, image) => {
                     tokio::spawn(async move {
                         let handle = GuiState::start_loading_animation(&gui_state, uuid);
-                        if docker.pause_container(id.get()).await.is_err() {
-                            Self::set_error(&app_data, DockerControls::Pause, &gui_state);
+                        let removed = images::remove(&docker, &image.id, &image.tags).await;
+                        Self::list_images(&docker, &app_data).await;
+                        gui_state.lock().stop_loading_animation(&handle, uuid);
+                        if removed {
+                            gui_state
+                                .lock()
+                                .set_info_box(&format!("removed {}", image.name()));
+                        } else {
+                            app_data.lock().set_error(
+                                AppError::ImageRemove,
+                                &gui_state,
+                                Status::Error,
+                            );
                         }
+                    });
+                }
+                DockerMessage::VolumeRemove(_, volume) => {
+                    tokio::spawn(async move {
+                        let handle = GuiState::start_loading_animation(&gui_state, uuid);
+                        let removed = docker
+                            .remove_volume(&volume.name, None::<RemoveVolumeOptions>)
+                            .await
+                            .is_ok();
+                        Self::list_volumes(&docker, &app_data, false).await;
                         gui_state.lock().stop_loading_animation(&handle, uuid);
+                        if removed {
+                            gui_state
+                                .lock()
+                                .set_info_box(&format!("removed {}", volume.short_name()));
+                        } else {
+                            app_data.lock().set_error(
+                                AppError::VolumeRemove,
+                                &gui_state,
+                                Status::Error,
+                            );
+                        }
                     });
-                    self.update_everything().await;
                 }
-                DockerMessage::Restart(id) => {
+                DockerMessage::NetworkRemove(_, network) => {
+                    tokio::spawn(async move {
+                        let handle = GuiState::start_loading_animation(&gui_state, uuid);
+                        let removed = docker.remove_network(&network.id).await.is_ok();
+                        Self::list_networks(&docker, &app_data).await;
+                        gui_state.lock().stop_loading_animation(&handle, uuid);
+                        if removed {
+                            gui_state
+                                .lock()
+                                .set_info_box(&format!("removed {}", network.name));
+                        } else {
+                            app_data.lock().set_error(
+                                AppError::NetworkRemove,
+                                &gui_state,
+                                Status::Error,
+                            );
+                        }
+                    });
+                }
+                DockerMessage::ServiceScale(_, service, replicas) => {
                     tokio::spawn(async move {
                         let handle = GuiState::start_loading_animation(&gui_state, uuid);
-                        if docker.restart_container(id.get(), None).await.is_err() {
-                            Self::set_error(&app_data, DockerControls::Restart, &gui_state);
+                        let updated = Self::update_service(&docker, &service.id, |spec| {
+                            services::scaled(spec, replicas)
+                        })
+                        .await;
+                        Self::list_services(&docker, &app_data).await;
+                        gui_state.lock().stop_loading_animation(&handle, uuid);
+                        if updated {
+                            gui_state.lock().set_info_box(&format!(
+                                "scaled {} to {replicas} replicas",
+                                service.name
+                            ));
+                        } else {
+                            app_data.lock().set_error(
+                                AppError::ServiceUpdate,
+                                &gui_state,
+                                Status::Error,
+                            );
                         }
+                    });
+                }
+                DockerMessage::ServiceForceUpdate(_, service) => {
+                    tokio::spawn(async move {
+                        let handle = GuiState::start_loading_animation(&gui_state, uuid);
+                        let updated =
+                            Self::update_service(&docker, &service.id, services::force_updated)
+                                .await;
+                        Self::list_services(&docker, &app_data).await;
                         gui_state.lock().stop_loading_animation(&handle, uuid);
+                        if updated {
+                            gui_state
+                                .lock()
+                                .set_info_box(&format!("force updating {}", service.name));
+                        } else {
+                            app_data.lock().set_error(
+                                AppError::ServiceUpdate,
+                                &gui_state,
+                                Status::Error,
+                            );
+                        }
                     });
-                    self.update_everything().await;
                 }
-                DockerMessage::Start(id) => {
+                DockerMessage::StackScale(_, stack, replicas) => {
                     tokio::spawn(async move {
                         let handle = GuiState::start_loading_animation(&gui_state, uuid);
-                        if docker
-                            .start_container(id.get(), None::<StartContainerOptions<String>>)
-                            .await
-                            .is_err()
-                        {
-                            Self::set_error(&app_data, DockerControls::Start, &gui_state);
+                        let scaled = Self::scale_stack(&docker, &stack, replicas).await;
+                        Self::list_services(&docker, &app_data).await;
+                        gui_state.lock().stop_loading_animation(&handle, uuid);
+                        match scaled {
+                            Some((scaled, 0)) => gui_state.lock().set_info_box(&format!(
+                                "scaled {scaled} service{} of {stack} to {replicas} replicas",
+                                if scaled == 1 { "" } else { "s" }
+                            )),
+                            _ => app_data.lock().set_error(
+                                AppError::ServiceUpdate,
+                                &gui_state,
+                                Status::Error,
+                            ),
+                        }
+                    });
+                }
+                DockerMessage::StackRemove(_, stack) => {
+                    tokio::spawn(async move {
+                        let handle = GuiState::start_loading_animation(&gui_state, uuid);
+                        let removed = Self::remove_stack(&docker, &stack).await;
+                        Self::list_services(&docker, &app_data).await;
+                        gui_state.lock().stop_loading_animation(&handle, uuid);
+                        match removed {
+                            Some((removed, true)) => gui_state.lock().set_info_box(&format!(
+                                "removed stack {stack}, {removed} service{}",
+                                if removed == 1 { "" } else { "s" }
+                            )),
+                            _ => app_data.lock().set_error(
+                                AppError::StackRemove,
+                                &gui_state,
+                                Status::Error,
+                            ),
+                        }
+                    });
+                }
+                DockerMessage::TaskLogs(_, service) => {
+                    let invalid_utf8 = self.args.invalid_utf8;
+                    let rules = self.args.redact.clone();
+                    tokio::spawn(async move {
+                        let handle = GuiState::start_loading_animation(&gui_state, uuid);
+                        let logs =
+                            Self::task_logs(runtime.as_ref(), &service.id, invalid_utf8, &rules)
+                                .await;
+                        gui_state.lock().stop_loading_animation(&handle, uuid);
+                        match logs {
+                            Some((0, _)) => gui_state.lock().set_info_box(&format!(
+                                "no tasks of {} run on this host",
+                                service.name
+                            )),
+                            Some((tasks, lines)) => gui_state
+                                .lock()
+                                .set_task_logs(Some(TaskLogs::new(service.name, tasks, lines))),
+                            None => app_data.lock().set_error(
+                                AppError::DockerLogs,
+                                &gui_state,
+                                Status::Error,
+                            ),
                         }
+                    });
+                }
+                DockerMessage::Pause(id) => {
+                    self.control(DockerControls::Pause, id, Duration::ZERO)
+                        .await;
+                }
+                DockerMessage::RecreateForm(id) => {
+                    tokio::spawn(async move {
+                        let handle = GuiState::start_loading_animation(&gui_state, uuid);
+                        let inspect = docker.inspect_container(id.get(), None).await;
+                        // The image is inspected by id, as the tag may since have been moved to a different image
+                        let image = match inspect.as_ref().ok().and_then(|i| i.image.as_deref()) {
+                            Some(image) => docker.inspect_image(image).await.ok(),
+                            None => None,
+                        };
                         gui_state.lock().stop_loading_animation(&handle, uuid);
+                        match inspect {
+                            Ok(inspect) => gui_state.lock().set_recreate_form(Some(
+                                recreate::form(id, &inspect, image.as_ref()),
+                            )),
+                            Err(_) => {
+                                Self::set_error(&app_data, DockerControls::Recreate, &gui_state);
+                            }
+                        }
+                    });
+                }
+                DockerMessage::RenameForm(id) => {
+                    let name = Self::container_name(&app_data, &id);
+                    gui_state
+                        .lock()
+                        .set_rename_form(Some(RenameForm::new(id, name)));
+                }
+                DockerMessage::Rename(id, name) => {
+                    let current = Self::container_name(&app_data, &id);
+                    tokio::spawn(async move {
+                        let options = RenameContainerOptions { name: name.clone() };
+                        let text = match docker.rename_container(id.get(), options).await {
+                            Ok(()) => format!("renamed {current} to {name}"),
+                            Err(e) => format!("unable to rename {current}: {e}"),
+                        };
+                        gui_state.lock().set_info_box(&text);
                     });
                     self.update_everything().await;
                 }
-                DockerMessage::Stop(id) => {
+                DockerMessage::StdinForm(id) => {
+                    tokio::spawn(async move {
+                        let inspect = runtime.inspect(&id, false).await;
+                        match inspect {
+                            Ok(inspect) if stdin::is_open(&inspect) => {
+                                let name = inspect.name.as_deref().map_or_else(
+                                    || id.get().to_owned(),
+                                    |i| i.trim_start_matches('/').to_owned(),
+                                );
+                                gui_state.lock().set_stdin_form(Some(StdinForm {
+                                    id,
+                                    name,
+                                    text: String::new(),
+                                    sent: None,
+                                }));
+                            }
+                            Ok(_) => gui_state.lock().set_info_box(
+                                "stdin isn't open, the container needs to be started with -i",
+                            ),
+                            Err(e) => gui_state
+                                .lock()
+                                .set_info_box(&format!("unable to inspect container: {e}")),
+                        }
+                    });
+                }
+                DockerMessage::Stdin(id, line) => {
+                    tokio::spawn(async move {
+                        if let Err(e) = stdin::send(&docker, &id, &line).await {
+                            gui_state
+                                .lock()
+                                .set_info_box(&format!("unable to send to stdin: {e}"));
+                        }
+                    });
+                }
+                DockerMessage::Recreate(form) => {
+                    let host = self.host.clone();
                     tokio::spawn(async move {
                         let handle = GuiState::start_loading_animation(&gui_state, uuid);
-                        if docker.stop_container(id.get(), None).await.is_err() {
-                            Self::set_error(&app_data, DockerControls::Stop, &gui_state);
+                        // An image that isn't available locally, e.g. a newly edited tag, is pulled first, a failed, or cancelled, pull leaves the container as it was
+                        let image = form.image();
+                        let pulled = if docker.inspect_image(&image).await.is_ok() {
+                            None
+                        } else {
+                            Some(images::pull(&docker, &gui_state, &image, host).await)
+                        };
+                        match pulled {
+                            Some(pulled @ (Pulled::Cancelled | Pulled::Failed(_))) => {
+                                Self::set_pulled(&app_data, &gui_state, &image, pulled);
+                            }
+                            _ => {
+                                if recreate::recreate(&docker, &form).await.is_err() {
+                                    Self::set_error(
+                                        &app_data,
+                                        DockerControls::Recreate,
+                                        &gui_state,
+                                    );
+                                }
+                            }
                         }
                         gui_state.lock().stop_loading_animation(&handle, uuid);
                     });
                     self.update_everything().await;
                 }
-                DockerMessage::Resume(id) => {
+                DockerMessage::Run(_, form) => {
+                    let host = self.host.clone();
+                    let address = host.clone().or_else(|| self.args.host.clone());
                     tokio::spawn(async move {
                         let handle = GuiState::start_loading_animation(&gui_state, uuid);
-                        if docker.unpause_container(id.get()).await.is_err() {
-                            Self::set_error(&app_data, DockerControls::Resume, &gui_state);
+                        // An image that isn't available locally is pulled first, as docker run does
+                        let image = form.image();
+                        let pulled = if docker.inspect_image(&image).await.is_ok() {
+                            None
+                        } else {
+                            Some(images::pull(&docker, &gui_state, &image, host).await)
+                        };
+                        match pulled {
+                            Some(pulled @ (Pulled::Cancelled | Pulled::Failed(_))) => {
+                                Self::set_pulled(&app_data, &gui_state, &image, pulled);
+                            }
+                            pulled => {
+                                if pulled.is_some() {
+                                    Self::list_images(&docker, &app_data).await;
+                                }
+                                let text = match run::run(&docker, &form, address.as_deref()).await
+                                {
+                                    Ok(id) => format!(
+                                        "{} started",
+                                        form.name()
+                                            .unwrap_or_else(|| id.chars().take(12).collect())
+                                    ),
+                                    Err(e) => format!("unable to run {image}: {e}"),
+                                };
+                                gui_state.lock().set_info_box(&text);
+                            }
                         }
                         gui_state.lock().stop_loading_animation(&handle, uuid);
                     });
                     self.update_everything().await;
                 }
-                DockerMessage::Delete(id) => {
+                DockerMessage::PullRecreate(id) => {
+                    self.update_container(id, Duration::ZERO);
+                    self.update_everything().await;
+                }
+                DockerMessage::Retry(control, id) => {
+                    let backoff = app_data
+                        .lock()
+                        .get_attempts(&id, self.host.as_deref())
+                        .map_or(0, |(_, attempts)| attempts.backoff(control));
+                    let delay = Duration::from_secs(backoff);
+                    if let Some(failed) = gui_state.lock().get_failed() {
+                        failed.retry_at = Some(Instant::now() + delay);
+                    }
+                    if control == DockerControls::Update {
+                        self.update_container(id, delay);
+                    } else {
+                        self.control(control, id, delay).await;
+                    }
+                }
+                DockerMessage::TagPicker(id) => {
                     tokio::spawn(async move {
                         let handle = GuiState::start_loading_animation(&gui_state, uuid);
-                        if docker
-                            .remove_container(
-                                id.get(),
-                                Some(RemoveContainerOptions {
-                                    v: false,
-                                    force: true,
-                                    link: false,
-                                }),
-                            )
-                            .await
-                            .is_err()
-                        {
-                            Self::set_error(&app_data, DockerControls::Stop, &gui_state);
+                        let inspect = docker.inspect_container(id.get(), None).await;
+                        let images = docker
+                            .list_images(Some(ListImagesOptions::<String>::default()))
+                            .await;
+                        gui_state.lock().stop_loading_animation(&handle, uuid);
+                        match (inspect, images) {
+                            (Ok(inspect), Ok(images)) => gui_state
+                                .lock()
+                                .set_tag_picker(Some(recreate::tag_picker(id, &inspect, &images))),
+                            _ => {
+                                Self::set_error(&app_data, DockerControls::Recreate, &gui_state);
+                            }
+                        }
+                    });
+                }
+                DockerMessage::BuildCache(host) => {
+                    let now = SystemTime::now()
+                        .duration_since(UNIX_EPOCH)
+                        .map_or(0, |i| i64::try_from(i.as_secs()).unwrap_or_default());
+                    tokio::spawn(async move {
+                        let handle = GuiState::start_loading_animation(&gui_state, uuid);
+                        let usage = docker.df().await;
+                        gui_state.lock().stop_loading_animation(&handle, uuid);
+                        if let Ok(usage) = usage {
+                            gui_state.lock().set_build_cache(Some(BuildCache::new(
+                                build_cache::records(&usage),
+                                now,
+                                host,
+                            )));
+                        } else {
+                            app_data.lock().set_error(
+                                AppError::BuildCache,
+                                &gui_state,
+                                Status::Error,
+                            );
+                        }
+                    });
+                }
+                DockerMessage::BuildCacheRemove(_, ids) => {
+                    // The primary host is the resolved host, from the cli args or DOCKER_HOST
+                    let host = self.host.clone().or_else(|| self.args.host.clone());
+                    tokio::spawn(async move {
+                        let handle = GuiState::start_loading_animation(&gui_state, uuid);
+                        let removed = build_cache::remove(host.as_deref(), &ids).await;
+                        gui_state.lock().stop_loading_animation(&handle, uuid);
+                        if removed {
+                            gui_state.lock().set_info_box(&format!(
+                                "removed {} build cache records",
+                                ids.len()
+                            ));
+                        } else {
+                            app_data.lock().set_error(
+                                AppError::BuildCacheRemove,
+                                &gui_state,
+                                Status::Error,
+                            );
+                        }
+                    });
+                }
+                DockerMessage::Cleanup(host) => {
+                    tokio::spawn(async move {
+                        let handle = GuiState::start_loading_animation(&gui_state, uuid);
+                        let usage = docker.df().await;
+                        let list = docker
+                            .list_networks(None::<ListNetworksOptions<String>>)
+                            .await;
+                        let containers = Self::list_all_containers(&docker).await;
+                        gui_state.lock().stop_loading_animation(&handle, uuid);
+                        match (usage, list, containers) {
+                            (Ok(usage), Ok(list), Some(containers)) => {
+                                let networks = networks::networks(&list, &containers);
+                                gui_state.lock().set_cleanup(Some(Cleanup::new(
+                                    cleanup::disk_usage(&usage),
+                                    cleanup::rows(&usage, &networks),
+                                    host,
+                                )));
+                            }
+                            _ => app_data.lock().set_error(
+                                AppError::Cleanup,
+                                &gui_state,
+                                Status::Error,
+                            ),
+                        }
+                    });
+                }
+                DockerMessage::CleanupPrune(_, kind) => {
+                    tokio::spawn(async move {
+                        let handle = GuiState::start_loading_animation(&gui_state, uuid);
+                        let result = cleanup::prune(&docker, kind).await;
+                        let mut gui_state = gui_state.lock();
+                        gui_state.stop_loading_animation(&handle, uuid);
+                        match result {
+                            Ok((removed, reclaimed)) => gui_state.set_info_box(&format!(
+                                "pruned {removed} {}, reclaimed {}",
+                                kind.label(),
+                                reclaimed.to_string().trim()
+                            )),
+                            Err(e) => gui_state
+                                .set_info_box(&format!("unable to prune {}: {e}", kind.label())),
+                        }
+                        drop(gui_state);
+                    });
+                }
+                DockerMessage::PrunePreview(host) => {
+                    let skew = if self.host.is_none() {
+                        self.app_data.lock().get_clock_skew().unwrap_or_default()
+                    } else {
+                        0
+                    };
+                    tokio::spawn(async move {
+                        let handle = GuiState::start_loading_animation(&gui_state, uuid);
+                        let images = docker
+                            .list_images(Some(ListImagesOptions::<String>::default()))
+                            .await;
+                        let containers = docker
+                            .list_containers(Some(ListContainersOptions::<String> {
+                                all: true,
+                                ..Default::default()
+                            }))
+                            .await;
+                        gui_state.lock().stop_loading_animation(&handle, uuid);
+                        let now = SystemTime::now()
+                            .duration_since(UNIX_EPOCH)
+                            .map_or(0, |i| i64::try_from(i.as_secs()).unwrap_or_default())
+                            + skew;
+                        match (images, containers) {
+                            (Ok(images), Ok(containers)) => {
+                                gui_state.lock().set_prune_preview(Some(PrunePreview::new(
+                                    prune::images(&images, &containers),
+                                    now,
+                                    host,
+                                )));
+                            }
+                            _ => app_data.lock().set_error(
+                                AppError::Prune,
+                                &gui_state,
+                                Status::Error,
+                            ),
+                        }
+                    });
+                }
+                DockerMessage::Prune(_, images) => {
+                    tokio::spawn(async move {
+                        let handle = GuiState::start_loading_animation(&gui_state, uuid);
+                        let removed = prune::prune(&docker, &images).await;
+                        let mut gui_state = gui_state.lock();
+                        gui_state.stop_loading_animation(&handle, uuid);
+                        gui_state
+                            .set_info_box(&format!("removed {removed}/{} images", images.len()));
+                        drop(gui_state);
+                    });
+                }
+                DockerMessage::ReachPicker(id) => {
+                    tokio::spawn(async move {
+                        let handle = GuiState::start_loading_animation(&gui_state, uuid);
+                        let containers = Self::list_all_containers(&docker).await;
+                        gui_state.lock().stop_loading_animation(&handle, uuid);
+                        let picker = containers.and_then(|i| reach::picker(id, &i));
+                        let mut gui_state = gui_state.lock();
+                        match picker {
+                            Some(picker) if picker.targets.is_empty() => gui_state.set_info_box(
+                                "no other running containers with an ip address to check",
+                            ),
+                            Some(picker) => gui_state.set_reach_picker(Some(picker)),
+                            None => gui_state.set_info_box("unable to list containers"),
+                        }
+                    });
+                }
+                DockerMessage::ReadLogs(id, options, sx) => {
+                    let (fallback, host) = {
+                        let app_data = app_data.lock();
+                        // The primary host is the resolved host, from the cli args or DOCKER_HOST
+                        let host = app_data
+                            .get_host_by_id(&id)
+                            .or_else(|| app_data.args.host.clone());
+                        (app_data.get_log_fallback(&id), host)
+                    };
+                    tokio::spawn(async move {
+                        let output = Self::read_log_output(
+                            runtime.as_ref(),
+                            &id,
+                            options,
+                            fallback,
+                            host.as_deref(),
+                        )
+                        .await;
+                        sx.send(output).ok();
+                    });
+                }
+                DockerMessage::Reach(id, source, target) => {
+                    tokio::spawn(async move {
+                        let handle = GuiState::start_loading_animation(&gui_state, uuid);
+                        let reached = reach::check(runtime.as_ref(), &id, &target).await;
+                        let mut gui_state = gui_state.lock();
+                        gui_state.stop_loading_animation(&handle, uuid);
+                        gui_state.set_info_box(&reached.text(&source, &target));
+                    });
+                }
+                DockerMessage::OlderLogs(id) => {
+                    tokio::spawn(async move {
+                        let handle = GuiState::start_loading_animation(&gui_state, uuid);
+                        let text = match Self::older_logs(app_data, runtime, id).await {
+                            Some(0) | None => "no older log lines".to_owned(),
+                            Some(1) => "1 older log line loaded".to_owned(),
+                            Some(count) => format!("{count} older log lines loaded"),
+                        };
+                        gui_state.lock().stop_loading_animation(&handle, uuid);
+                        gui_state.lock().set_info_box(&text);
+                    });
+                }
+                DockerMessage::Timeline(id) => {
+                    // The daemon's clock is used, so that events near the end of the timeline aren't cut off by clock skew
+                    let skew = if self.host.is_none() {
+                        self.app_data.lock().get_clock_skew().unwrap_or_default()
+                    } else {
+                        0
+                    };
+                    tokio::spawn(async move {
+                        let handle = GuiState::start_loading_animation(&gui_state, uuid);
+                        let end = SystemTime::now()
+                            .duration_since(UNIX_EPOCH)
+                            .map_or(0, |i| i64::try_from(i.as_secs()).unwrap_or_default())
+                            + skew;
+                        let timeline = timeline::timeline(&docker, &id, end).await;
+                        gui_state.lock().stop_loading_animation(&handle, uuid);
+                        match timeline {
+                            Ok(timeline) => gui_state.lock().set_timeline(Some(timeline)),
+                            Err(_) => app_data.lock().set_error(
+                                AppError::Timeline,
+                                &gui_state,
+                                Status::Error,
+                            ),
+                        }
+                    });
+                }
+                DockerMessage::SwitchTag(id, image) => {
+                    tokio::spawn(async move {
+                        let handle = GuiState::start_loading_animation(&gui_state, uuid);
+                        let result = match docker.inspect_container(id.get(), None).await {
+                            Ok(inspect) => {
+                                let mut form = recreate::form(id, &inspect, None);
+                                form.set_image(&image);
+                                recreate::recreate(&docker, &form).await
+                            }
+                            Err(e) => Err(e),
+                        };
+                        if result.is_err() {
+                            Self::set_error(&app_data, DockerControls::Recreate, &gui_state);
                         }
                         gui_state.lock().stop_loading_animation(&handle, uuid);
                     });
                     self.update_everything().await;
-                    self.gui_state.lock().set_delete_container(None);
                 }
-                DockerMessage::ConfirmDelete(id) => {
-                    self.gui_state.lock().set_delete_container(Some(id));
+                DockerMessage::SecretScan(host) => {
+                    let containers = app_data
+                        .lock()
+                        .get_container_items()
+                        .iter()
+                        .filter(|i| i.host == self.host)
+                        .map(|i| (i.id.clone(), i.name.get().to_owned()))
+                        .collect::<Vec<_>>();
+                    tokio::spawn(async move {
+                        let handle = GuiState::start_loading_animation(&gui_state, uuid);
+                        let inspects = futures_util::future::join_all(
+                            containers.iter().map(|(id, _)| runtime.inspect(id, false)),
+                        )
+                        .await;
+                        gui_state.lock().stop_loading_animation(&handle, uuid);
+                        let mut scanned = 0;
+                        let mut findings = vec![];
+                        for ((_, name), inspect) in containers.iter().zip(inspects) {
+                            if let Ok(inspect) = inspect {
+                                scanned += 1;
+                                let env = inspect.config.and_then(|i| i.env).unwrap_or_default();
+                                findings.extend(secret_scan::scan(name, &env));
+                            }
+                        }
+                        if scanned == 0 && !containers.is_empty() {
+                            app_data.lock().set_error(
+                                AppError::SecretScan,
+                                &gui_state,
+                                Status::Error,
+                            );
+                        } else {
+                            gui_state
+                                .lock()
+                                .set_secret_scan(Some(SecretScan::new(findings, scanned, host)));
+                        }
+                    });
+                }
+                DockerMessage::SnapshotHost(_) => {
+                    let save_dir = self.args.save_dir.clone();
+                    let invalid_utf8 = self.args.invalid_utf8;
+                    let rules = self.args.redact.clone();
+                    let snapshot = {
+                        let app_data = app_data.lock();
+                        HostSnapshot {
+                            host: self.host.clone(),
+                            created: SystemTime::now()
+                                .duration_since(UNIX_EPOCH)
+                                .map_or(0, |i| i64::try_from(i.as_secs()).unwrap_or_default()),
+                            containers: app_data
+                                .get_container_items()
+                                .iter()
+                                .filter(|i| i.host == self.host)
+                                .map(ContainerSnapshot::from)
+                                .collect(),
+                            events: app_data
+                                .get_events()
+                                .iter()
+                                .filter(|i| i.host == self.host)
+                                .cloned()
+                                .collect(),
+                        }
+                    };
+                    tokio::spawn(async move {
+                        let handle = GuiState::start_loading_animation(&gui_state, uuid);
+                        let path = match save_dir {
+                            Some(dir) => {
+                                Self::snapshot_host(
+                                    runtime.as_ref(),
+                                    snapshot,
+                                    &dir,
+                                    invalid_utf8,
+                                    &rules,
+                                )
+                                .await
+                            }
+                            None => None,
+                        };
+                        gui_state.lock().stop_loading_animation(&handle, uuid);
+                        if let Some(path) = path {
+                            gui_state
+                                .lock()
+                                .set_info_box(&format!("saved to {}", path.display()));
+                        } else {
+                            app_data.lock().set_error(
+                                AppError::SnapshotHost,
+                                &gui_state,
+                                Status::Error,
+                            );
+                        }
+                    });
+                }
+                DockerMessage::Restart(id) => {
+                    self.control(DockerControls::Restart, id, Duration::ZERO)
+                        .await;
+                }
+                DockerMessage::Start(id) => {
+                    self.control(DockerControls::Start, id, Duration::ZERO)
+                        .await;
+                }
+                DockerMessage::Stop(id) => {
+                    self.control(DockerControls::Stop, id, Duration::ZERO).await;
+                }
+                DockerMessage::ProjectControl(control, ids) => {
+                    self.batch_control(control, ids, true).await;
+                }
+                DockerMessage::BatchControl(control, ids) => {
+                    self.batch_control(control, ids, false).await;
+                }
+                DockerMessage::BatchPreview(ids) => {
+                    tokio::spawn(async move {
+                        let handle = GuiState::start_loading_animation(&gui_state, uuid);
+                        let preview = Self::batch_preview(runtime.as_ref(), &ids).await;
+                        let mut gui_state = gui_state.lock();
+                        gui_state.stop_loading_animation(&handle, uuid);
+                        gui_state.add_delete_preview(preview);
+                    });
+                }
+                DockerMessage::Resume(id) => {
+                    self.control(DockerControls::Resume, id, Duration::ZERO)
+                        .await;
+                }
+                DockerMessage::Delete(id) => {
+                    self.control(DockerControls::Delete, id, Duration::ZERO)
+                        .await;
+                    self.gui_state.lock().set_confirm(None);
+                }
+                DockerMessage::Confirm(control, id) => {
+                    // Only deleting has a dry run preview
+                    if self.args.dry_run && control == DockerControls::Delete {
+                        tokio::spawn(async move {
+                            let handle = GuiState::start_loading_animation(&gui_state, uuid);
+                            let preview = Self::delete_preview(runtime.as_ref(), &id).await;
+                            let mut gui_state = gui_state.lock();
+                            gui_state.stop_loading_animation(&handle, uuid);
+                            gui_state.set_confirm(Some((control, id)));
+                            gui_state.set_delete_preview(Some(preview));
+                        });
+                    } else {
+                        self.gui_state.lock().set_confirm(Some((control, id)));
+                    }
+                }
+                DockerMessage::UpdateLimits(id, nano_cpus, memory) => {
+                    tokio::spawn(async move {
+                        let handle = GuiState::start_loading_animation(&gui_state, uuid);
+                        let options = UpdateContainerOptions::<String> {
+                            nano_cp_us: nano_cpus.and_then(|i| i64::try_from(i).ok()),
+                            memory: memory.and_then(|i| i64::try_from(i).ok()),
+                            // Mirrors docker run, a swap of twice the memory, otherwise raising the memory over the current swap limit is refused
+                            memory_swap: memory
+                                .and_then(|i| i64::try_from(i.saturating_mul(2)).ok()),
+                            ..Default::default()
+                        };
+                        let updated = docker.update_container(id.get(), options).await;
+                        gui_state.lock().stop_loading_animation(&handle, uuid);
+                        match updated {
+                            Ok(()) => {
+                                let name = {
+                                    let mut app_data = app_data.lock();
+                                    app_data.set_limits(&id, None);
+                                    app_data.get_container_name_by_id(&id)
+                                };
+                                let name = name
+                                    .map_or_else(|| id.get().to_owned(), |i| i.get().to_owned());
+                                gui_state
+                                    .lock()
+                                    .set_info_box(&format!("updated limits of {name}"));
+                            }
+                            Err(e) => gui_state
+                                .lock()
+                                .set_info_box(&format!("unable to update limits: {e}")),
+                        }
+                    });
+                }
+                DockerMessage::Update => {
+                    if !self.skip_standby_update() && self.rate_limit.should_update() {
+                        self.update_everything().await;
+                    }
                 }
-                DockerMessage::Update => self.update_everything().await,
                 DockerMessage::Quit => {
                     self.spawns
                         .lock()
@@ -446,6 +2986,29 @@ impl DockerData {
         }
     }
 
+    /// Select the `--select` container, once the containers have first been listed, with `--follow-logs` its logs are focused, at the newest line
+    /// With more than one host, it's only shown as missing once every host has listed its containers without it
+    fn select_on_start(app_data: &Arc<Mutex<AppData>>, gui_state: &Arc<Mutex<GuiState>>) {
+        let mut app_data = app_data.lock();
+        let Some(target) = app_data.args.select.clone() else {
+            return;
+        };
+        match app_data.select_on_start(&target) {
+            Some(true) if app_data.args.follow_logs => {
+                app_data.log_end();
+                drop(app_data);
+                gui_state.lock().set_selected_panel(SelectablePanel::Logs);
+            }
+            Some(false) => {
+                drop(app_data);
+                gui_state
+                    .lock()
+                    .set_info_box(&format!("no container {target}"));
+            }
+            _ => (),
+        }
+    }
+
     /// Send an update message every x ms, where x is the args.docker_interval
     fn scheduler(args: &CliArgs, docker_tx: Sender<DockerMessage>) {
         let update_duration = std::time::Duration::from_millis(u64::from(args.docker_interval));
@@ -461,28 +3024,67 @@ impl DockerData {
     }
 
     /// Initialise self, and start the message receiving loop
+    /// host is None for the primary host, else the name of an extra host, which its containers are tagged with
     pub async fn init(
         app_data: Arc<Mutex<AppData>>,
         docker: Docker,
         docker_rx: Receiver<DockerMessage>,
         docker_tx: Sender<DockerMessage>,
         gui_state: Arc<Mutex<GuiState>>,
+        host: Option<String>,
         is_running: Arc<AtomicBool>,
     ) {
         let args = app_data.lock().args.clone();
         if app_data.lock().get_error().is_none() {
+            // Windows daemons, with their different stats, isolation modes, and lack of sh, are detected once, on connection
+            let windows = docker
+                .info()
+                .await
+                .ok()
+                .filter(|i| i.os_type.as_deref() == Some("windows"))
+                .map(|i| {
+                    i.isolation.map_or(Isolation::Process, |i| {
+                        Isolation::from_api(i.as_ref(), Isolation::Process)
+                    })
+                });
+            // Podman is detected from its engine component, else taken from `--runtime`
+            let podman = RuntimeKind::detect(args.runtime, docker.version().await.ok().as_ref())
+                == RuntimeKind::Podman;
+            let docker = Arc::new(docker);
             let mut inner = Self {
                 app_data,
                 args: args.clone(),
                 binate: Binate::One,
-                docker: Arc::new(docker),
+                clock_checked: None,
+                docker: Arc::clone(&docker),
+                docker_tx: docker_tx.clone(),
                 gui_state,
+                host,
                 init: Some(Arc::new(AtomicUsize::new(0))),
                 is_running,
+                podman: podman.then(|| Arc::new(Mutex::new(HashMap::new()))),
+                probes_checked: None,
+                resources_listed: None,
+                volume_sizes_checked: None,
+                disk_checked: None,
+                standby_updated: None,
+                rate_limit: RateLimit::new(args.api_concurrency, args.api_limit),
                 receiver: docker_rx,
+                runtime: docker,
                 spawns: Arc::new(Mutex::new(HashMap::new())),
+                windows,
             };
             inner.initialise_container_data().await;
+            Self::select_on_start(&inner.app_data, &inner.gui_state);
+            inner.spawns.lock().insert(
+                SpawnId::Events,
+                tokio::spawn(events::subscribe(
+                    Arc::clone(&inner.docker),
+                    Arc::clone(&inner.app_data),
+                    Arc::clone(&inner.gui_state),
+                    inner.host.clone(),
+                )),
+            );
             Self::scheduler(&args, docker_tx);
             inner.message_handler().await;
         }
@@ -493,7 +3095,8 @@ impl DockerData {
 #[cfg(test)]
 mod tests {
     use bollard::container::{
-        BlkioStats, CPUStats, CPUUsage, MemoryStats, PidsStats, StorageStats, ThrottlingData,
+        BlkioStats, BlkioStatsEntry, CPUStats, CPUUsage, MemoryStats, PidsStats, StorageStats,
+        ThrottlingData,
     };
 
     use super::*;
@@ -631,4 +3234,382 @@ mod tests {
         let result = DockerData::calculate_usage(&stats);
         assert_eq!(result, 20.00);
     }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    /// A Podman one-shot sample is measured against the last sample of the same container, the first measures no usage
+    fn test_calculate_usage_previous_sample() {
+        let samples = Arc::new(Mutex::new(HashMap::new()));
+        let id = ContainerId::from("1");
+        let mut first = gen_stats(1_000_000_000, 900_000_000);
+        DockerData::previous_sample(&samples, &id, &mut first);
+        assert_eq!(DockerData::calculate_usage(&first), 0.0);
+
+        let mut second = gen_stats(1_000_000_000, 900_000_000);
+        second.cpu_stats.cpu_usage.total_usage += 100_000_000;
+        second.cpu_stats.system_cpu_usage =
+            second.cpu_stats.system_cpu_usage.map(|i| i + 200_000_000);
+        DockerData::previous_sample(&samples, &id, &mut second);
+        assert_eq!(second.precpu_stats, first.cpu_stats);
+        assert!(DockerData::calculate_usage(&second) > 0.0);
+
+        let mut other = gen_stats(1_000_000_000, 900_000_000);
+        DockerData::previous_sample(&samples, &ContainerId::from("2"), &mut other);
+        assert_eq!(DockerData::calculate_usage(&other), 0.0);
+        assert_eq!(samples.lock().len(), 2);
+    }
+
+    #[test]
+    /// Each name anchored, with regex characters escaped, no filter when no names given
+    fn test_only_filters() {
+        assert!(only_filters(&[]).is_empty());
+        assert_eq!(
+            only_filters(&["redis".to_owned(), "web.1".to_owned()]),
+            HashMap::from([(
+                "name".to_owned(),
+                vec!["^/redis$".to_owned(), "^/web\\.1$".to_owned()]
+            )])
+        );
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    /// Windows stats have no system cpu usage, so usage is compared against the time between the two samples
+    fn test_calculate_usage_windows() {
+        let mut stats = gen_stats(1_000_000_000, 900_000_000);
+        stats.cpu_stats.system_cpu_usage = None;
+        stats.precpu_stats.system_cpu_usage = None;
+        stats.cpu_stats.cpu_usage.total_usage = 15_000_000;
+        stats.precpu_stats.cpu_usage.total_usage = 10_000_000;
+        stats.num_procs = 2;
+        stats.read = "2024-05-01T12:00:01.5Z".to_owned();
+        stats.preread = "2024-05-01T12:00:01Z".to_owned();
+        // 5_000_000 100ns intervals used, out of 0.5s * 2 processors
+        assert_eq!(DockerData::calculate_usage(&stats), 50.0);
+
+        stats.preread = String::new();
+        assert_eq!(DockerData::calculate_usage(&stats), 0.0);
+    }
+
+    #[test]
+    /// Block io is summed over every device, by op, & Windows falls back to the storage stats
+    fn test_block_io() {
+        let mut stats = gen_stats(1_000_000_000, 900_000_000);
+        assert_eq!(DockerData::block_io(&stats), (0, 0));
+
+        stats.storage_stats.read_size_bytes = Some(10);
+        stats.storage_stats.write_size_bytes = Some(20);
+        assert_eq!(DockerData::block_io(&stats), (10, 20));
+
+        let entry = |op: &str, value: u64| BlkioStatsEntry {
+            major: 8,
+            minor: 0,
+            op: op.to_owned(),
+            value,
+        };
+        stats.blkio_stats.io_service_bytes_recursive = Some(vec![
+            entry("Read", 100),
+            entry("Write", 300),
+            entry("read", 50),
+            entry("write", 1),
+            entry("Sync", 1000),
+            entry("Total", 451),
+        ]);
+        assert_eq!(DockerData::block_io(&stats), (150, 301));
+    }
+
+    #[test]
+    /// Fractional seconds kept, to the nanosecond
+    fn test_parse_rfc3339_nanos() {
+        assert_eq!(
+            parse_rfc3339_nanos("1970-01-01T00:00:01Z"),
+            Some(1_000_000_000)
+        );
+        assert_eq!(
+            parse_rfc3339_nanos("1970-01-01T00:00:01.25Z"),
+            Some(1_250_000_000)
+        );
+        assert_eq!(
+            parse_rfc3339_nanos("1970-01-01T01:00:00.1234567891+01:00"),
+            Some(123_456_789)
+        );
+        assert!(parse_rfc3339_nanos("").is_none());
+    }
+
+    #[test]
+    /// RFC 3339 timestamps, with either a Z or an offset, converted into unix timestamps
+    fn test_parse_rfc3339() {
+        assert_eq!(parse_rfc3339("1970-01-01T00:00:00Z"), Some(0));
+        assert_eq!(
+            parse_rfc3339("2024-05-01T12:34:56.123456789Z"),
+            Some(1_714_566_896)
+        );
+        assert_eq!(
+            parse_rfc3339("2024-05-01T13:34:56.123456789+01:00"),
+            Some(1_714_566_896)
+        );
+        assert_eq!(
+            parse_rfc3339("2024-05-01T07:04:56-05:30"),
+            Some(1_714_566_896)
+        );
+        assert_eq!(parse_rfc3339("2000-02-29T00:00:00Z"), Some(951_782_400));
+        assert!(parse_rfc3339("").is_none());
+        assert!(parse_rfc3339("2024-13-01T00:00:00Z").is_none());
+        assert!(parse_rfc3339("not a timestamp").is_none());
+    }
+
+    #[test]
+    /// The `--select` container is selected on start, with `--follow-logs` its logs are focused, an unknown container is shown in the info box, once every host has listed its containers
+    fn test_select_on_start() {
+        let (ids, containers) = crate::tests::gen_containers();
+        let app_data = Arc::new(Mutex::new(crate::tests::gen_appdata(&containers)));
+        let gui_state = Arc::new(Mutex::new(GuiState::default()));
+        app_data.lock().args.select = Some("container_2".to_owned());
+        DockerData::select_on_start(&app_data, &gui_state);
+        assert_eq!(
+            app_data.lock().get_selected_container_id(),
+            Some(ids[1].clone())
+        );
+        assert_eq!(
+            gui_state.lock().get_selected_panel(),
+            SelectablePanel::Containers
+        );
+
+        // Already selected, so a later host doesn't change the selection
+        app_data.lock().args.select = Some("3".to_owned());
+        DockerData::select_on_start(&app_data, &gui_state);
+        assert_eq!(
+            app_data.lock().get_selected_container_id(),
+            Some(ids[1].clone())
+        );
+
+        app_data.lock().set_select_hosts(1);
+        app_data.lock().args.follow_logs = true;
+        DockerData::select_on_start(&app_data, &gui_state);
+        assert_eq!(
+            app_data.lock().get_selected_container_id(),
+            Some(ids[2].clone())
+        );
+        assert_eq!(gui_state.lock().get_selected_panel(), SelectablePanel::Logs);
+
+        app_data.lock().set_select_hosts(2);
+        app_data.lock().args.select = Some("api".to_owned());
+        DockerData::select_on_start(&app_data, &gui_state);
+        assert!(gui_state.lock().info_box_text.is_none());
+        DockerData::select_on_start(&app_data, &gui_state);
+        assert_eq!(
+            gui_state
+                .lock()
+                .info_box_text
+                .as_ref()
+                .map(|i| i.0.as_str()),
+            Some("no container api")
+        );
+    }
+
+    #[test]
+    /// Unix timestamps formatted as UTC RFC 3339 timestamps, which parse back into the same timestamp
+    fn test_format_rfc3339() {
+        assert_eq!(format_rfc3339(0), "1970-01-01T00:00:00Z");
+        assert_eq!(format_rfc3339(1_714_566_896), "2024-05-01T12:34:56Z");
+        assert_eq!(format_rfc3339(951_782_400), "2000-02-29T00:00:00Z");
+        assert_eq!(format_rfc3339(-1), "1969-12-31T23:59:59Z");
+        for timestamp in [1, 86_399, 1_000_000_000, 4_102_444_800] {
+            assert_eq!(parse_rfc3339(&format_rfc3339(timestamp)), Some(timestamp));
+        }
+    }
+
+    #[test]
+    /// Warnings lose their prefix, a fallback storage driver is warned about, & the cgroup combines the version & driver
+    fn test_daemon_info() {
+        use bollard::service::{SystemInfoCgroupDriverEnum, SystemInfoCgroupVersionEnum};
+        let info = SystemInfo {
+            cgroup_driver: Some(SystemInfoCgroupDriverEnum::SYSTEMD),
+            cgroup_version: Some(SystemInfoCgroupVersionEnum::_2),
+            driver: Some("vfs".to_owned()),
+            server_version: Some("26.1.3".to_owned()),
+            warnings: Some(vec![
+                "WARNING: No swap limit support".to_owned(),
+                " ".to_owned(),
+            ]),
+            ..SystemInfo::default()
+        };
+        let result = daemon_info(&info);
+        assert_eq!(result.cgroup.as_deref(), Some("v2 (systemd)"));
+        assert_eq!(result.storage_driver.as_deref(), Some("vfs"));
+        assert_eq!(result.version.as_deref(), Some("26.1.3"));
+        assert_eq!(result.warnings.len(), 2);
+        assert_eq!(result.warnings[0], "No swap limit support");
+        assert!(result.warnings[1].starts_with("the vfs storage driver is slow"));
+
+        let result = daemon_info(&SystemInfo {
+            driver: Some("overlay2".to_owned()),
+            ..SystemInfo::default()
+        });
+        assert!(result.cgroup.is_none());
+        assert!(result.warnings.is_empty());
+    }
+
+    #[tokio::test]
+    /// Stats are read through the runtime, a container that the runtime has no stats for is left without a sample
+    async fn test_update_container_stat_runtime() {
+        let (ids, containers) = crate::tests::gen_containers();
+        let app_data = Arc::new(Mutex::new(crate::tests::gen_appdata(&containers)));
+        let runtime = runtime::MockRuntime {
+            stats: HashMap::from([("1".to_owned(), gen_stats(1_000_000_000, 900_000_000))]),
+            ..Default::default()
+        };
+        let runtime: Arc<dyn Runtime> = Arc::new(runtime);
+        let spawns = Arc::new(Mutex::new(HashMap::new()));
+        for id in &ids[..2] {
+            DockerData::update_container_stat(
+                Arc::clone(&app_data),
+                (Arc::clone(&runtime), None),
+                id.clone(),
+                None,
+                State::Running,
+                SpawnId::Stats((id.clone(), Binate::One)),
+                Arc::clone(&spawns),
+            )
+            .await;
+        }
+        let app_data = app_data.lock();
+        let items = app_data.get_container_items();
+        assert_eq!(items[0].cpu_stats.len(), 1);
+        assert_eq!(items[0].cpu_stats[0], crate::app_data::CpuStats::new(50.0));
+        assert!(items[1].cpu_stats.is_empty());
+        drop(app_data);
+    }
+
+    #[tokio::test]
+    /// The dry run preview of deleting the marked containers totals their writable layers, & volumes, without sending any control
+    async fn test_batch_preview_dry_run() {
+        let inspect = |size: i64, volumes: usize| bollard::models::ContainerInspectResponse {
+            size_rw: Some(size),
+            mounts: Some(
+                (0..volumes)
+                    .map(|_| bollard::models::MountPoint {
+                        typ: Some(MountPointTypeEnum::VOLUME),
+                        ..Default::default()
+                    })
+                    .collect(),
+            ),
+            ..Default::default()
+        };
+        let runtime = runtime::MockRuntime {
+            inspects: HashMap::from([
+                ("1".to_owned(), inspect(12_000, 2)),
+                ("2".to_owned(), inspect(3_000, 1)),
+            ]),
+            ..Default::default()
+        };
+        let ids = ["1", "2", "3"].map(ContainerId::from);
+        let preview = DockerData::batch_preview(&runtime, &ids).await;
+        assert_eq!(
+            preview,
+            DeletePreview {
+                confirmed: false,
+                size: Some(ByteStats::new(15_000)),
+                volumes: 3,
+            }
+        );
+        assert!(runtime.sent.lock().is_empty());
+
+        let preview = DockerData::batch_preview(&runtime, &ids[2..]).await;
+        assert!(preview.size.is_none());
+        assert!(runtime.sent.lock().is_empty());
+    }
+
+    #[tokio::test]
+    /// Logs are saved from the runtime, a container whose logs can't be read, & has no fallback, has nothing to save
+    async fn test_read_log_output_runtime() {
+        let runtime = runtime::MockRuntime {
+            logs: HashMap::from([(
+                "1".to_owned(),
+                vec![
+                    "2024-05-01T12:00:00Z started".to_owned(),
+                    "2024-05-01T12:00:01Z ready".to_owned(),
+                ],
+            )]),
+            ..Default::default()
+        };
+        let output = DockerData::read_log_output(
+            &runtime,
+            &ContainerId::from("1"),
+            LogsOptions::default(),
+            None,
+            None,
+        )
+        .await;
+        assert_eq!(output.len(), 2);
+        assert_eq!(
+            output[1].clone().into_bytes(),
+            b"2024-05-01T12:00:01Z ready".as_slice()
+        );
+
+        let output = DockerData::read_log_output(
+            &runtime,
+            &ContainerId::from("2"),
+            LogsOptions::default(),
+            None,
+            None,
+        )
+        .await;
+        assert!(output.is_empty());
+    }
+
+    #[test]
+    /// The fallback's lines are saved as stdout, without their timestamp, unless timestamps are wanted, blank lines are skipped
+    fn test_fallback_output() {
+        let lines = vec![
+            "2024-05-01T12:00:00.000000001Z started\n".to_owned(),
+            " \n".to_owned(),
+            "2024-05-01T12:00:01.000000001Z ready\n".to_owned(),
+        ];
+        let output = fallback_output(lines.clone(), false);
+        assert_eq!(output.len(), 2);
+        assert!(matches!(output[0], LogOutput::StdOut { .. }));
+        assert_eq!(output[1].clone().into_bytes(), b"ready\n".as_slice());
+
+        let output = fallback_output(lines, true);
+        assert_eq!(
+            output[0].clone().into_bytes(),
+            b"2024-05-01T12:00:00.000000001Z started\n".as_slice()
+        );
+    }
+
+    #[tokio::test]
+    /// Logs are read through the runtime, blank lines are skipped, & a stream that ends with an error is reported as failed
+    async fn test_read_logs_runtime() {
+        let runtime = runtime::MockRuntime {
+            logs: HashMap::from([(
+                "1".to_owned(),
+                vec![
+                    "2024-05-01T12:00:00Z started".to_owned(),
+                    "  ".to_owned(),
+                    "2024-05-01T12:00:01Z ready".to_owned(),
+                ],
+            )]),
+            ..Default::default()
+        };
+        let (lines, failed) = DockerData::read_logs(
+            &runtime,
+            &ContainerId::from("1"),
+            LogsOptions::default(),
+            InvalidUtf8::Lossy,
+        )
+        .await;
+        assert_eq!(lines.len(), 2);
+        assert!(!failed);
+
+        let (lines, failed) = DockerData::read_logs(
+            &runtime,
+            &ContainerId::from("2"),
+            LogsOptions::default(),
+            InvalidUtf8::Lossy,
+        )
+        .await;
+        assert!(lines.is_empty());
+        assert!(failed);
+    }
 }
@@ -0,0 +1,103 @@
+use std::collections::HashMap;
+
+use bollard::service::{ContainerSummary, Network};
+
+use crate::app_data::NetworkItem;
+
+/// Convert the networks into network items, with the name of each container, running or not, that's connected to it
+/// The daemon doesn't list the containers of each network, so they're found from the networks of each container
+pub fn networks(networks: &[Network], containers: &[ContainerSummary]) -> Vec<NetworkItem> {
+    let mut in_use = HashMap::<&str, Vec<String>>::new();
+    for container in containers {
+        let Some(name) = container.names.as_ref().and_then(|i| i.first()) else {
+            continue;
+        };
+        let endpoints = container
+            .network_settings
+            .as_ref()
+            .and_then(|i| i.networks.as_ref());
+        for network_id in endpoints
+            .into_iter()
+            .flat_map(HashMap::values)
+            .filter_map(|i| i.network_id.as_deref())
+        {
+            in_use
+                .entry(network_id)
+                .or_default()
+                .push(name.trim_start_matches('/').to_owned());
+        }
+    }
+    networks
+        .iter()
+        .filter_map(|i| {
+            let id = i.id.clone()?;
+            Some(NetworkItem {
+                containers: in_use.remove(id.as_str()).unwrap_or_default(),
+                driver: i.driver.clone().unwrap_or_default(),
+                id,
+                internal: i.internal.unwrap_or_default(),
+                name: i.name.clone().unwrap_or_default(),
+                scope: i.scope.clone().unwrap_or_default(),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use bollard::service::{
+        ContainerSummary, ContainerSummaryNetworkSettings, EndpointSettings, Network,
+    };
+
+    use super::networks;
+
+    #[test]
+    /// Each network lists the containers connected to it, matched by network id
+    fn test_networks() {
+        let gen_network = |id: &str, name: &str| Network {
+            id: Some(id.to_owned()),
+            name: Some(name.to_owned()),
+            driver: Some("bridge".to_owned()),
+            scope: Some("local".to_owned()),
+            internal: Some(name == "backend"),
+            ..Default::default()
+        };
+        let gen_container = |name: &str, network_ids: &[&str]| ContainerSummary {
+            names: Some(vec![format!("/{name}")]),
+            network_settings: Some(ContainerSummaryNetworkSettings {
+                networks: Some(
+                    network_ids
+                        .iter()
+                        .map(|id| {
+                            (
+                                format!("net-{id}"),
+                                EndpointSettings {
+                                    network_id: Some((*id).to_owned()),
+                                    ..Default::default()
+                                },
+                            )
+                        })
+                        .collect::<HashMap<_, _>>(),
+                ),
+            }),
+            ..Default::default()
+        };
+        let result = networks(
+            &[
+                gen_network("n1", "site_default"),
+                gen_network("n2", "backend"),
+            ],
+            &[
+                gen_container("site-api-1", &["n1", "n2"]),
+                gen_container("site-db-1", &["n2"]),
+            ],
+        );
+        assert_eq!(result[0].containers, ["site-api-1"]);
+        assert!(!result[0].internal);
+        assert_eq!(result[1].containers, ["site-api-1", "site-db-1"]);
+        assert!(result[1].internal);
+        assert_eq!(result[1].driver, "bridge");
+    }
+}
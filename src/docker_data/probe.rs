@@ -0,0 +1,94 @@
+use std::time::Duration;
+
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpStream,
+};
+
+use super::Runtime;
+use crate::app_data::{ContainerId, ProbeCheck, ProbeTarget, Readiness};
+
+/// Seconds that a probe can take before the container is treated as not ready
+const PROBE_TIMEOUT: u64 = 5;
+
+/// Check the status line of an HTTP response, `HTTP/1.1 204 No Content`, a 2xx or 3xx status is ready
+fn is_ready_status(response: &str) -> bool {
+    response
+        .lines()
+        .next()
+        .and_then(|i| i.split_whitespace().nth(1))
+        .and_then(|i| i.parse::<u16>().ok())
+        .is_some_and(|i| (200..400).contains(&i))
+}
+
+/// Send an HTTP/1.0 GET, so that the server closes the connection once the response has been sent, only the status line is read
+async fn http(address: &str, port: u16, path: &str) -> bool {
+    let Ok(mut stream) = TcpStream::connect((address, port)).await else {
+        return false;
+    };
+    let request =
+        format!("GET {path} HTTP/1.0\r\nHost: {address}:{port}\r\nUser-Agent: oxker\r\n\r\n");
+    if stream.write_all(request.as_bytes()).await.is_err() {
+        return false;
+    }
+    let mut buffer = [0; 64];
+    let mut read = 0;
+    while read < buffer.len() {
+        match stream.read(&mut buffer[read..]).await {
+            Ok(0) | Err(_) => break,
+            Ok(count) => read += count,
+        }
+        if buffer[..read].contains(&b'\n') {
+            break;
+        }
+    }
+    is_ready_status(&String::from_utf8_lossy(&buffer[..read]))
+}
+
+/// Exec the command in the container, output is discarded, and an exit code of 0 is ready
+async fn exec(runtime: &dyn Runtime, id: &ContainerId, command: &[String]) -> bool {
+    runtime
+        .exec(id, command.to_vec())
+        .await
+        .is_ok_and(|i| i.exit_code == Some(0))
+}
+
+/// Run a readiness probe, a probe that times out is not ready
+pub async fn run(runtime: &dyn Runtime, target: &ProbeTarget) -> Readiness {
+    let check = async {
+        let ready = match (&target.check, &target.address) {
+            (ProbeCheck::Http { path, .. }, Some((address, port))) => {
+                http(address, *port, path).await
+            }
+            (ProbeCheck::Tcp(_), Some((address, port))) => {
+                TcpStream::connect((address.as_str(), *port)).await.is_ok()
+            }
+            (ProbeCheck::Exec(command), _) => exec(runtime, &target.id, command).await,
+            _ => return Readiness::NoPort,
+        };
+        if ready {
+            Readiness::Ready
+        } else {
+            Readiness::NotReady
+        }
+    };
+    tokio::time::timeout(Duration::from_secs(PROBE_TIMEOUT), check)
+        .await
+        .unwrap_or(Readiness::NotReady)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::is_ready_status;
+
+    #[test]
+    /// Only 2xx & 3xx statuses are ready
+    fn test_probe_ready_status() {
+        assert!(is_ready_status("HTTP/1.1 200 OK\r\nContent-Length: 2"));
+        assert!(is_ready_status("HTTP/1.0 302 Found\r\n"));
+        assert!(!is_ready_status("HTTP/1.1 503 Service Unavailable\r\n"));
+        assert!(!is_ready_status("HTTP/1.1 404"));
+        assert!(!is_ready_status("SSH-2.0-OpenSSH_9.6\r\n"));
+        assert!(!is_ready_status(""));
+    }
+}
@@ -0,0 +1,85 @@
+use std::collections::HashSet;
+
+use bollard::{
+    service::{ContainerSummary, ImageSummary},
+    Docker,
+};
+
+use super::recreate::repository;
+use crate::ui::PruneImage;
+
+/// The `<none>:<none>` tag that dangling images are listed with
+pub const NONE_TAG: &str = "<none>:<none>";
+
+/// Convert the local images into prune images, an image is in use if any container, running or not, was created from it
+pub fn images(images: &[ImageSummary], containers: &[ContainerSummary]) -> Vec<PruneImage> {
+    let in_use = containers
+        .iter()
+        .filter_map(|i| i.image_id.as_deref())
+        .collect::<HashSet<_>>();
+    images
+        .iter()
+        .map(|i| {
+            let tags = i
+                .repo_tags
+                .iter()
+                .filter(|i| *i != NONE_TAG)
+                .cloned()
+                .collect::<Vec<_>>();
+            PruneImage {
+                id: i.id.clone(),
+                created: i.created,
+                in_use: in_use.contains(i.id.as_str()) || i.containers > 0,
+                repositories: tags.iter().map(|i| repository(i).to_owned()).collect(),
+                size: u64::try_from(i.size).unwrap_or_default(),
+                tags,
+            }
+        })
+        .collect()
+}
+
+/// Remove each image, returns the number of images that were removed
+pub async fn prune(docker: &Docker, images: &[PruneImage]) -> usize {
+    let mut removed = 0;
+    for image in images {
+        if super::images::remove(docker, &image.id, &image.tags).await {
+            removed += 1;
+        }
+    }
+    removed
+}
+
+#[cfg(test)]
+mod tests {
+    use bollard::service::{ContainerSummary, ImageSummary};
+
+    use super::images;
+
+    #[test]
+    /// Dangling images have no tags, an image is in use once any container uses it
+    fn test_prune_images() {
+        let gen_image = |id: &str, tags: &[&str]| ImageSummary {
+            id: id.to_owned(),
+            repo_tags: tags.iter().map(|i| (*i).to_owned()).collect(),
+            created: 10,
+            size: 2048,
+            containers: -1,
+            ..Default::default()
+        };
+        let result = images(
+            &[
+                gen_image("sha256:a", &["localhost:5000/app:1", "app:latest"]),
+                gen_image("sha256:b", &["<none>:<none>"]),
+            ],
+            &[ContainerSummary {
+                image_id: Some("sha256:b".to_owned()),
+                ..Default::default()
+            }],
+        );
+        assert_eq!(result[0].repositories, ["localhost:5000/app", "app"]);
+        assert!(!result[0].in_use);
+        assert!(result[1].tags.is_empty());
+        assert!(result[1].in_use);
+        assert_eq!(result[1].size, 2048);
+    }
+}
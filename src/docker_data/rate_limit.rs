@@ -0,0 +1,99 @@
+use std::sync::Arc;
+
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+/// Limits on the Docker API requests made to a single host, set with `--api-concurrency` & `--api-limit`
+/// When a full update would make more requests than the limit allows, scheduled updates are skipped, stretching the effective refresh interval
+#[derive(Debug, Clone)]
+pub struct RateLimit {
+    concurrency: Option<Arc<Semaphore>>,
+    cost: usize,
+    limit: Option<usize>,
+    skipped: usize,
+}
+
+impl RateLimit {
+    pub fn new(concurrency: Option<usize>, limit: Option<usize>) -> Self {
+        Self {
+            concurrency: concurrency.map(|i| Arc::new(Semaphore::new(i.max(1)))),
+            cost: 0,
+            limit: limit.map(|i| i.max(1)),
+            skipped: 0,
+        }
+    }
+
+    /// Wait for a free request slot, the permit should be held for the duration of the request, None if concurrency isn't limited
+    pub async fn acquire(&self) -> Option<OwnedSemaphorePermit> {
+        match self.concurrency.as_ref() {
+            Some(semaphore) => Arc::clone(semaphore).acquire_owned().await.ok(),
+            None => None,
+        }
+    }
+
+    /// Set the number of requests that a full update makes, the list request, the stats requests, and the log request
+    pub const fn set_cost(&mut self, cost: usize) {
+        self.cost = cost;
+    }
+
+    /// How many update intervals each full update needs to be spread over, to stay under the limit
+    pub fn stretch(&self) -> usize {
+        self.limit
+            .map_or(1, |limit| self.cost.div_ceil(limit).max(1))
+    }
+
+    /// Check if a scheduled update should run, or be skipped as the limit has been reached
+    pub fn should_update(&mut self) -> bool {
+        if self.skipped + 1 >= self.stretch() {
+            self.skipped = 0;
+            true
+        } else {
+            self.skipped += 1;
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RateLimit;
+
+    #[test]
+    /// Updates skipped so that each interval makes, on average, no more requests than the limit
+    fn test_rate_limit_stretch() {
+        let mut rate_limit = RateLimit::new(None, Some(10));
+        rate_limit.set_cost(10);
+        assert_eq!(rate_limit.stretch(), 1);
+        assert!(rate_limit.should_update());
+        assert!(rate_limit.should_update());
+
+        rate_limit.set_cost(25);
+        assert_eq!(rate_limit.stretch(), 3);
+        assert_eq!(
+            (0..6)
+                .map(|_| rate_limit.should_update())
+                .collect::<Vec<_>>(),
+            [false, false, true, false, false, true]
+        );
+
+        let mut rate_limit = RateLimit::new(None, None);
+        rate_limit.set_cost(500);
+        assert_eq!(rate_limit.stretch(), 1);
+        assert!(rate_limit.should_update());
+    }
+
+    #[tokio::test]
+    /// Only the set number of permits can be held at once
+    async fn test_rate_limit_concurrency() {
+        let rate_limit = RateLimit::new(Some(1), None);
+        let permit = rate_limit.acquire().await;
+        assert!(permit.is_some());
+        assert!(
+            tokio::time::timeout(std::time::Duration::from_millis(10), rate_limit.acquire())
+                .await
+                .is_err()
+        );
+        drop(permit);
+        assert!(rate_limit.acquire().await.is_some());
+        assert!(RateLimit::new(None, None).acquire().await.is_none());
+    }
+}
@@ -0,0 +1,293 @@
+use std::{
+    collections::{HashMap, HashSet},
+    time::{Duration, Instant},
+};
+
+use bollard::service::{ContainerSummary, EndpointSettings, PortTypeEnum};
+
+use super::Runtime;
+use crate::{
+    app_data::ContainerId,
+    ui::{ReachPicker, ReachTarget},
+};
+
+/// Seconds that the check can take, inside the source container, before the target is unreachable
+const REACH_TIMEOUT: u64 = 3;
+
+/// Exit code of a shell when a command can't be found, the checks also exit with it when the container has none of their tools
+const NOT_FOUND: i64 = 127;
+
+/// Exit code when a command can't be executed, e.g. the container has no shell
+const NOT_EXECUTABLE: i64 = 126;
+
+/// A tcp connect, with nc, or else bash's `/dev/tcp`, `$0` is the ip, `$1` the port, & `$2` the timeout
+const TCP_CHECK: &str = r#"if command -v nc >/dev/null 2>&1; then exec nc -z -w "$2" "$0" "$1"; fi
+if command -v bash >/dev/null 2>&1; then
+  if command -v timeout >/dev/null 2>&1; then exec timeout "$2" bash -c 'exec 3<>"/dev/tcp/$0/$1"' "$0" "$1"; fi
+  exec bash -c 'exec 3<>"/dev/tcp/$0/$1"' "$0" "$1"
+fi
+exit 127"#;
+
+/// A single ping, `$0` is the ip, & `$1` the timeout
+const PING_CHECK: &str = r#"if command -v ping >/dev/null 2>&1; then exec ping -c 1 -W "$1" "$0"; fi
+exit 127"#;
+
+/// The outcome of a connectivity check
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Reached {
+    /// The round trip time reported by ping, otherwise the time taken by the exec, which includes the exec's own overhead
+    Yes(Duration),
+    No,
+    /// The source container has none of the tools that the check uses
+    NoTool,
+    /// Unable to exec in the source container, e.g. it has stopped
+    Failed,
+}
+
+impl Reached {
+    /// The outcome, as shown in the info box
+    pub fn text(self, source: &str, target: &ReachTarget) -> String {
+        let to = format!("{} ({})", target.name, target.address());
+        match self {
+            Self::Yes(took) => format!(
+                "{source} can reach {to}, {:.1} ms",
+                took.as_secs_f64() * 1000.0
+            ),
+            Self::No if target.shared => format!("{source} can't reach {to}"),
+            Self::No => format!("{source} can't reach {to}, they share no network"),
+            Self::NoTool if target.port.is_some() => {
+                format!("{source} has no nc, or bash, to check with")
+            }
+            Self::NoTool => format!("{source} has no ping to check with"),
+            Self::Failed => format!("unable to exec in {source}"),
+        }
+    }
+}
+
+/// Name of a container, without the leading `/`
+fn name(container: &ContainerSummary) -> String {
+    container
+        .names
+        .as_ref()
+        .and_then(|i| i.first())
+        .map_or_else(String::new, |i| i.trim_start_matches('/').to_owned())
+}
+
+/// Every network endpoint of a container
+fn endpoints(container: &ContainerSummary) -> impl Iterator<Item = &EndpointSettings> {
+    container
+        .network_settings
+        .as_ref()
+        .and_then(|i| i.networks.as_ref())
+        .into_iter()
+        .flat_map(HashMap::values)
+}
+
+/// The other running containers, with an ip, each tcp port is a separate target, a container without any tcp ports is pinged instead
+/// The ip on a network shared with the source container is used, targets on a shared network are listed first
+pub fn picker(id: ContainerId, containers: &[ContainerSummary]) -> Option<ReachPicker> {
+    let source = containers
+        .iter()
+        .find(|i| i.id.as_deref() == Some(id.get()))?;
+    let networks = endpoints(source)
+        .filter_map(|i| i.network_id.as_deref())
+        .collect::<HashSet<_>>();
+
+    let mut targets = vec![];
+    for container in containers
+        .iter()
+        .filter(|i| i.id != source.id && i.state.as_deref() == Some("running"))
+    {
+        let with_ip = endpoints(container)
+            .filter(|i| i.ip_address.as_deref().is_some_and(|ip| !ip.is_empty()))
+            .collect::<Vec<_>>();
+        let shared = with_ip.iter().find(|i| {
+            i.network_id
+                .as_deref()
+                .is_some_and(|network| networks.contains(network))
+        });
+        let Some(ip) = shared
+            .or_else(|| with_ip.first())
+            .and_then(|i| i.ip_address.clone())
+        else {
+            continue;
+        };
+        let mut ports = container
+            .ports
+            .iter()
+            .flatten()
+            .filter(|i| i.typ != Some(PortTypeEnum::UDP))
+            .map(|i| Some(i.private_port))
+            .collect::<Vec<_>>();
+        ports.sort_unstable();
+        ports.dedup();
+        if ports.is_empty() {
+            ports.push(None);
+        }
+        let name = name(container);
+        targets.extend(ports.into_iter().map(|port| ReachTarget {
+            name: name.clone(),
+            ip: ip.clone(),
+            port,
+            shared: shared.is_some(),
+        }));
+    }
+    targets.sort_by(|a, b| {
+        b.shared
+            .cmp(&a.shared)
+            .then_with(|| a.name.cmp(&b.name))
+            .then_with(|| a.port.cmp(&b.port))
+    });
+    Some(ReachPicker {
+        name: name(source),
+        id,
+        selected: 0,
+        targets,
+    })
+}
+
+/// The round trip time from the output of a ping, e.g. `64 bytes from 172.18.0.3: seq=0 ttl=64 time=0.104 ms`
+fn ping_time(output: &str) -> Option<Duration> {
+    let (_, time) = output.split_once("time=")?;
+    let millis = time.split_whitespace().next()?.parse::<f64>().ok()?;
+    (millis.is_finite() && millis >= 0.0).then(|| Duration::from_secs_f64(millis / 1000.0))
+}
+
+/// Exec the check, in the source container, against the target
+async fn exec(runtime: &dyn Runtime, id: &ContainerId, target: &ReachTarget) -> Reached {
+    let timeout = REACH_TIMEOUT.to_string();
+    let mut cmd = vec!["sh".to_owned(), "-c".to_owned()];
+    match target.port {
+        Some(port) => cmd.extend([TCP_CHECK.to_owned(), target.ip.clone(), port.to_string()]),
+        None => cmd.extend([PING_CHECK.to_owned(), target.ip.clone()]),
+    }
+    cmd.push(timeout);
+
+    let start = Instant::now();
+    let Ok(output) = runtime.exec(id, cmd).await else {
+        return Reached::Failed;
+    };
+    let took = start.elapsed();
+    match output.exit_code {
+        Some(0) => Reached::Yes(ping_time(&output.stdout).unwrap_or(took)),
+        Some(NOT_FOUND | NOT_EXECUTABLE) => Reached::NoTool,
+        Some(_) => Reached::No,
+        None => Reached::Failed,
+    }
+}
+
+/// Check if the source container can reach the target, a check that doesn't finish, shortly after its own timeout, is unreachable
+pub async fn check(runtime: &dyn Runtime, id: &ContainerId, target: &ReachTarget) -> Reached {
+    tokio::time::timeout(
+        Duration::from_secs(REACH_TIMEOUT + 2),
+        exec(runtime, id, target),
+    )
+    .await
+    .unwrap_or(Reached::No)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use std::{collections::HashMap, time::Duration};
+
+    use bollard::service::{
+        ContainerSummary, ContainerSummaryNetworkSettings, EndpointSettings, Port, PortTypeEnum,
+    };
+
+    use super::{picker, ping_time, Reached};
+    use crate::app_data::ContainerId;
+
+    fn gen_container(
+        id: &str,
+        state: &str,
+        networks: &[(&str, &str)],
+        ports: &[(u16, PortTypeEnum)],
+    ) -> ContainerSummary {
+        ContainerSummary {
+            id: Some(id.to_owned()),
+            names: Some(vec![format!("/{id}")]),
+            state: Some(state.to_owned()),
+            ports: Some(
+                ports
+                    .iter()
+                    .map(|(port, typ)| Port {
+                        private_port: *port,
+                        typ: Some(*typ),
+                        ..Default::default()
+                    })
+                    .collect(),
+            ),
+            network_settings: Some(ContainerSummaryNetworkSettings {
+                networks: Some(
+                    networks
+                        .iter()
+                        .map(|(network, ip)| {
+                            (
+                                (*network).to_owned(),
+                                EndpointSettings {
+                                    network_id: Some((*network).to_owned()),
+                                    ip_address: Some((*ip).to_owned()),
+                                    ..Default::default()
+                                },
+                            )
+                        })
+                        .collect::<HashMap<_, _>>(),
+                ),
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    /// Each tcp port of every other running container is a target, the ip of a shared network is used, and shared targets are first
+    fn test_reach_picker() {
+        let containers = [
+            gen_container("api", "running", &[("site", "172.18.0.2")], &[]),
+            gen_container(
+                "db",
+                "running",
+                &[("site", "172.18.0.3"), ("bridge", "172.17.0.3")],
+                &[
+                    (5432, PortTypeEnum::TCP),
+                    (5432, PortTypeEnum::TCP),
+                    (53, PortTypeEnum::UDP),
+                ],
+            ),
+            gen_container("cache", "running", &[("bridge", "172.17.0.4")], &[]),
+            gen_container("old", "exited", &[("site", "172.18.0.5")], &[]),
+            gen_container("host", "running", &[("host", "")], &[]),
+        ];
+        let result = picker(ContainerId::from("api"), &containers).unwrap();
+        assert_eq!(result.name, "api");
+        assert_eq!(result.targets.len(), 2);
+        assert_eq!(result.targets[0].name, "db");
+        assert_eq!(result.targets[0].address(), "172.18.0.3:5432");
+        assert!(result.targets[0].shared);
+        assert_eq!(result.targets[1].address(), "172.17.0.4");
+        assert!(!result.targets[1].shared);
+        assert_eq!(
+            Reached::No.text("api", &result.targets[1]),
+            "api can't reach cache (172.17.0.4), they share no network"
+        );
+        assert_eq!(
+            Reached::Yes(Duration::from_micros(1300)).text("api", &result.targets[0]),
+            "api can reach db (172.18.0.3:5432), 1.3 ms"
+        );
+        assert!(picker(ContainerId::from("missing"), &containers).is_none());
+    }
+
+    #[test]
+    /// The round trip time is read from ping's output, both busybox & iputils formats
+    fn test_reach_ping_time() {
+        assert_eq!(
+            ping_time("64 bytes from 172.18.0.3: seq=0 ttl=64 time=0.104 ms"),
+            Some(Duration::from_micros(104))
+        );
+        assert_eq!(
+            ping_time("64 bytes from 172.18.0.3: icmp_seq=1 ttl=64 time=12.5 ms"),
+            Some(Duration::from_micros(12_500))
+        );
+        assert!(ping_time("1 packets transmitted, 0 received").is_none());
+    }
+}
@@ -0,0 +1,512 @@
+use std::collections::HashMap;
+
+use bollard::{
+    container::{
+        Config, CreateContainerOptions, NetworkingConfig, RemoveContainerOptions,
+        RenameContainerOptions, StartContainerOptions,
+    },
+    errors::Error,
+    service::{
+        ContainerInspectResponse, EndpointSettings, ImageInspect, ImageSummary, MountPoint,
+        MountPointTypeEnum, MountTypeEnum, PortBinding,
+    },
+    Docker,
+};
+
+use crate::{
+    app_data::ContainerId,
+    ui::{FieldKind, PortSpec, RecreateForm, TagPicker},
+};
+
+/// Suffix of the name given to the new container, until the old one has been removed
+const TEMP_SUFFIX: &str = "-oxker-recreate";
+
+/// Convert a bind, or volume, mount point into a mount field, `source:target[:ro]`, other mount types aren't editable
+fn format_mount(mount: &MountPoint) -> Option<String> {
+    let source = match mount.typ {
+        Some(MountPointTypeEnum::BIND) => mount.source.as_ref()?,
+        Some(MountPointTypeEnum::VOLUME) => mount.name.as_ref()?,
+        _ => return None,
+    };
+    let target = mount.destination.as_ref()?;
+    let read_only = if mount.rw == Some(false) { ":ro" } else { "" };
+    Some(format!("{source}:{target}{read_only}"))
+}
+
+/// The published ports of the inspected container, as port fields, `[ip:]host_port:container_port[/protocol]`
+pub fn ports(inspect: &ContainerInspectResponse) -> Vec<String> {
+    let mut ports = inspect
+        .host_config
+        .as_ref()
+        .and_then(|i| i.port_bindings.as_ref())
+        .map_or_else(Vec::new, |bindings| {
+            bindings
+                .iter()
+                .flat_map(|(container, bindings)| {
+                    bindings.iter().flatten().map(move |binding| {
+                        PortSpec::format(
+                            container,
+                            binding.host_ip.as_deref().unwrap_or_default(),
+                            binding.host_port.as_deref().unwrap_or_default(),
+                        )
+                    })
+                })
+                .collect::<Vec<_>>()
+        });
+    // Docker reports a binding for both the IPv4 & IPv6 all interfaces address, which are the same field
+    ports.sort();
+    ports.dedup();
+    ports
+}
+
+/// The bind & volume mounts of the inspected container, as mount fields
+pub fn mounts(inspect: &ContainerInspectResponse) -> Vec<String> {
+    inspect
+        .mounts
+        .as_ref()
+        .map_or_else(Vec::new, |i| i.iter().filter_map(format_mount).collect())
+}
+
+/// The default env of an inspected image, as KEY, value
+pub fn image_env(image: &ImageInspect) -> HashMap<String, String> {
+    image
+        .config
+        .as_ref()
+        .and_then(|i| i.env.as_ref())
+        .map_or_else(HashMap::new, |env| {
+            env.iter()
+                .filter_map(|i| i.split_once('='))
+                .map(|(key, value)| (key.to_owned(), value.to_owned()))
+                .collect()
+        })
+}
+
+/// Pre-fill the recreate form with the image, env, published ports, and mounts of the inspected container
+/// The inspected image, if given, is used to mark which env vars differ from the image defaults
+pub fn form(
+    id: ContainerId,
+    inspect: &ContainerInspectResponse,
+    image: Option<&ImageInspect>,
+) -> RecreateForm {
+    let name = inspect
+        .name
+        .as_deref()
+        .map_or_else(String::new, |i| i.trim_start_matches('/').to_owned());
+    let image_name = inspect
+        .config
+        .as_ref()
+        .and_then(|i| i.image.clone())
+        .unwrap_or_default();
+    let env = inspect
+        .config
+        .as_ref()
+        .and_then(|i| i.env.clone())
+        .unwrap_or_default();
+
+    let mut form = RecreateForm::new(id, name, image_name, env, ports(inspect), mounts(inspect));
+    form.image_env = image.map(image_env);
+    form
+}
+
+/// The repository of an image reference, without its tag, or digest, a registry port, e.g. `localhost:5000/app`, isn't mistaken for a tag
+pub fn repository(image: &str) -> &str {
+    let image = image.split_once('@').map_or(image, |i| i.0);
+    match image.rsplit_once(':') {
+        Some((repository, tag)) if !tag.contains('/') => repository,
+        _ => image,
+    }
+}
+
+/// The local tags of the image's repository, as full image references, newest image first
+/// An image without a tag is the `latest` tag
+pub fn local_tags(image: &str, images: &[ImageSummary]) -> Vec<String> {
+    let repo = repository(image);
+    let mut images = images.iter().collect::<Vec<_>>();
+    images.sort_by_key(|i| std::cmp::Reverse(i.created));
+    images
+        .into_iter()
+        .flat_map(|i| i.repo_tags.iter())
+        .filter(|i| repository(i) == repo)
+        .cloned()
+        .collect()
+}
+
+/// The tag picker of the inspected container, the current image is always included, even when it's no longer tagged locally
+pub fn tag_picker(
+    id: ContainerId,
+    inspect: &ContainerInspectResponse,
+    images: &[ImageSummary],
+) -> TagPicker {
+    let form = form(id, inspect, None);
+    let current = match form.image() {
+        i if i == repository(&i) => format!("{i}:latest"),
+        i => i,
+    };
+    let mut tags = local_tags(&current, images);
+    if !tags.contains(&current) {
+        tags.insert(0, current.clone());
+    }
+    TagPicker::new(form.id, form.name, current, tags)
+}
+
+/// The container already uses the image that its tag points to, e.g. after a pull, so recreating it wouldn't change anything
+pub fn is_up_to_date(inspect: &ContainerInspectResponse, image: &ImageInspect) -> bool {
+    inspect.image.is_some() && inspect.image == image.id
+}
+
+/// The host bindings of port fields, each port is also added to the exposed ports, as a port that's only exposed has no binding
+#[allow(clippy::zero_sized_map_values)]
+pub fn port_bindings(
+    ports: &[String],
+    exposed: &mut HashMap<String, HashMap<(), ()>>,
+) -> HashMap<String, Option<Vec<PortBinding>>> {
+    let mut bindings: HashMap<String, Option<Vec<PortBinding>>> = HashMap::new();
+    for port in ports.iter().filter_map(|i| PortSpec::parse(i)) {
+        exposed.insert(port.container.clone(), HashMap::new());
+        if let Some(host_port) = port.host_port {
+            bindings
+                .entry(port.container)
+                .or_insert_with(|| Some(vec![]))
+                .get_or_insert_with(Vec::new)
+                .push(PortBinding {
+                    host_ip: port.host_ip,
+                    host_port: Some(host_port),
+                });
+        }
+    }
+    bindings
+}
+
+/// Build the config of the new container, from the inspected config of the old container, with the fields of the form applied
+/// Bind & volume mounts are replaced by the mount fields, as binds, other mounts, such as tmpfs, are kept as they were
+#[allow(clippy::zero_sized_map_values)]
+pub fn config(form: &RecreateForm, inspect: ContainerInspectResponse) -> Config<String> {
+    let mut config = inspect.config.map(Config::from).unwrap_or_default();
+    let mut host_config = inspect.host_config.unwrap_or_default();
+
+    config.image = Some(form.image());
+    config.env = Some(form.values(FieldKind::Env));
+
+    let mut exposed = config.exposed_ports.take().unwrap_or_default();
+    host_config.port_bindings = Some(port_bindings(&form.values(FieldKind::Port), &mut exposed));
+    config.exposed_ports = Some(exposed);
+
+    host_config.binds = Some(form.values(FieldKind::Mount));
+    host_config.mounts = host_config.mounts.map(|mounts| {
+        mounts
+            .into_iter()
+            .filter(|i| !matches!(i.typ, Some(MountTypeEnum::BIND | MountTypeEnum::VOLUME)))
+            .collect()
+    });
+    config.host_config = Some(host_config);
+
+    // Keep the networks, and their aliases, the generated short id alias of the old container isn't kept
+    let old_id = inspect.id.unwrap_or_default();
+    config.networking_config = inspect
+        .network_settings
+        .and_then(|i| i.networks)
+        .map(|networks| NetworkingConfig {
+            endpoints_config: networks
+                .into_iter()
+                .map(|(network, endpoint)| {
+                    let aliases = endpoint.aliases.map(|aliases| {
+                        aliases
+                            .into_iter()
+                            .filter(|i| !old_id.starts_with(i.as_str()))
+                            .collect()
+                    });
+                    (
+                        network,
+                        EndpointSettings {
+                            aliases,
+                            links: endpoint.links,
+                            ..Default::default()
+                        },
+                    )
+                })
+                .collect(),
+        });
+    config
+}
+
+/// Recreate a container with the edited form, the new container is created, and started, before the old one is removed
+/// If the new container can't be started, e.g. a port is already in use, it's removed, and the old container is started again
+pub async fn recreate(docker: &Docker, form: &RecreateForm) -> Result<(), Error> {
+    let inspect = docker.inspect_container(form.id.get(), None).await?;
+    let was_running = inspect
+        .state
+        .as_ref()
+        .and_then(|i| i.running)
+        .unwrap_or_default();
+    let config = config(form, inspect);
+
+    let new = docker
+        .create_container(
+            Some(CreateContainerOptions {
+                name: format!("{}{TEMP_SUFFIX}", form.name),
+                platform: None,
+            }),
+            config,
+        )
+        .await?;
+    let remove = Some(RemoveContainerOptions {
+        v: false,
+        force: true,
+        link: false,
+    });
+
+    if was_running {
+        docker.stop_container(form.id.get(), None).await?;
+        if let Err(e) = docker
+            .start_container(&new.id, None::<StartContainerOptions<String>>)
+            .await
+        {
+            docker.remove_container(&new.id, remove).await.ok();
+            docker
+                .start_container(form.id.get(), None::<StartContainerOptions<String>>)
+                .await
+                .ok();
+            return Err(e);
+        }
+    }
+    docker.remove_container(form.id.get(), remove).await?;
+    docker
+        .rename_container(
+            &new.id,
+            RenameContainerOptions {
+                name: form.name.clone(),
+            },
+        )
+        .await
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use std::collections::HashMap;
+
+    use bollard::service::{
+        ContainerConfig, ContainerInspectResponse, EndpointSettings, HostConfig, ImageInspect,
+        ImageSummary, Mount, MountPoint, MountPointTypeEnum, MountTypeEnum, NetworkSettings,
+        PortBinding,
+    };
+
+    use super::{config, form, is_up_to_date, local_tags, repository, tag_picker};
+    use crate::{
+        app_data::ContainerId,
+        ui::{EnvOrigin, FieldKind},
+    };
+
+    fn gen_inspect() -> ContainerInspectResponse {
+        ContainerInspectResponse {
+            id: Some("abcdef123456789".to_owned()),
+            name: Some("/web".to_owned()),
+            config: Some(ContainerConfig {
+                image: Some("nginx:1.25".to_owned()),
+                env: Some(vec!["A=1".to_owned(), "B=2".to_owned()]),
+                cmd: Some(vec!["nginx".to_owned()]),
+                ..Default::default()
+            }),
+            host_config: Some(HostConfig {
+                port_bindings: Some(HashMap::from([(
+                    "80/tcp".to_owned(),
+                    Some(vec![
+                        PortBinding {
+                            host_ip: Some("0.0.0.0".to_owned()),
+                            host_port: Some("8080".to_owned()),
+                        },
+                        PortBinding {
+                            host_ip: Some("::".to_owned()),
+                            host_port: Some("8080".to_owned()),
+                        },
+                    ]),
+                )])),
+                mounts: Some(vec![
+                    Mount {
+                        typ: Some(MountTypeEnum::TMPFS),
+                        target: Some("/tmp".to_owned()),
+                        ..Default::default()
+                    },
+                    Mount {
+                        typ: Some(MountTypeEnum::VOLUME),
+                        target: Some("/data".to_owned()),
+                        ..Default::default()
+                    },
+                ]),
+                ..Default::default()
+            }),
+            mounts: Some(vec![
+                MountPoint {
+                    typ: Some(MountPointTypeEnum::BIND),
+                    source: Some("/srv/html".to_owned()),
+                    destination: Some("/usr/share/nginx/html".to_owned()),
+                    rw: Some(false),
+                    ..Default::default()
+                },
+                MountPoint {
+                    typ: Some(MountPointTypeEnum::VOLUME),
+                    name: Some("data".to_owned()),
+                    destination: Some("/data".to_owned()),
+                    rw: Some(true),
+                    ..Default::default()
+                },
+                MountPoint {
+                    typ: Some(MountPointTypeEnum::TMPFS),
+                    destination: Some("/tmp".to_owned()),
+                    ..Default::default()
+                },
+            ]),
+            network_settings: Some(NetworkSettings {
+                networks: Some(HashMap::from([(
+                    "backend".to_owned(),
+                    EndpointSettings {
+                        aliases: Some(vec!["web".to_owned(), "abcdef123456".to_owned()]),
+                        ip_address: Some("172.18.0.2".to_owned()),
+                        ..Default::default()
+                    },
+                )])),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    /// Form pre-filled with the image, env, de-duplicated ports, and bind & volume mounts
+    fn test_recreate_form_from_inspect() {
+        let result = form(ContainerId::from("abcdef123456789"), &gen_inspect(), None);
+        assert_eq!(result.name, "web");
+        assert_eq!(result.image(), "nginx:1.25");
+        assert_eq!(result.values(FieldKind::Env), ["A=1", "B=2"]);
+        assert_eq!(result.values(FieldKind::Port), ["8080:80"]);
+        assert_eq!(
+            result.values(FieldKind::Mount),
+            ["/srv/html:/usr/share/nginx/html:ro", "data:/data"]
+        );
+    }
+
+    #[test]
+    /// Env vars compared against the env of the inspected image
+    fn test_recreate_form_image_env() {
+        let image = ImageInspect {
+            config: Some(ContainerConfig {
+                env: Some(vec![
+                    "A=1".to_owned(),
+                    "B=0".to_owned(),
+                    "PATH=/usr/bin".to_owned(),
+                ]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let result = form(
+            ContainerId::from("abcdef123456789"),
+            &gen_inspect(),
+            Some(&image),
+        );
+        assert_eq!(result.env_origin("A=1"), Some(EnvOrigin::Image));
+        assert_eq!(result.env_origin("B=2"), Some(EnvOrigin::Changed));
+        assert_eq!(result.env_origin("C=3"), Some(EnvOrigin::Runtime));
+    }
+
+    #[test]
+    /// Edited fields applied to the config, everything else is kept from the old container
+    fn test_recreate_config() {
+        let mut recreate = form(ContainerId::from("abcdef123456789"), &gen_inspect(), None);
+        recreate.fields[0].value = "nginx:1.26".to_owned();
+        recreate.fields[2].value = "B=3".to_owned();
+        recreate.fields[5].value = "127.0.0.1:9090:90/udp".to_owned();
+
+        let result = config(&recreate, gen_inspect());
+        assert_eq!(result.image.as_deref(), Some("nginx:1.26"));
+        assert_eq!(result.cmd, Some(vec!["nginx".to_owned()]));
+        assert_eq!(result.env, Some(vec!["A=1".to_owned(), "B=3".to_owned()]));
+        let exposed = result.exposed_ports.unwrap();
+        assert!(exposed.contains_key("80/tcp") && exposed.contains_key("90/udp"));
+
+        let host_config = result.host_config.unwrap();
+        let bindings = host_config.port_bindings.unwrap();
+        assert_eq!(bindings.len(), 2);
+        assert_eq!(
+            bindings.get("90/udp"),
+            Some(&Some(vec![PortBinding {
+                host_ip: Some("127.0.0.1".to_owned()),
+                host_port: Some("9090".to_owned()),
+            }]))
+        );
+        assert_eq!(
+            host_config.binds.unwrap(),
+            ["/srv/html:/usr/share/nginx/html:ro", "data:/data"]
+        );
+        let mounts = host_config.mounts.unwrap();
+        assert_eq!(mounts.len(), 1);
+        assert_eq!(mounts[0].typ, Some(MountTypeEnum::TMPFS));
+
+        let endpoint = result
+            .networking_config
+            .unwrap()
+            .endpoints_config
+            .remove("backend")
+            .unwrap();
+        assert_eq!(endpoint.aliases, Some(vec!["web".to_owned()]));
+        assert!(endpoint.ip_address.is_none());
+    }
+
+    fn gen_image(created: i64, tags: &[&str]) -> ImageSummary {
+        ImageSummary {
+            created,
+            repo_tags: tags.iter().map(|i| (*i).to_owned()).collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    /// Tags, digests, & registry ports, are handled when finding the repository of an image
+    fn test_recreate_repository() {
+        assert_eq!(repository("nginx:1.25"), "nginx");
+        assert_eq!(repository("nginx"), "nginx");
+        assert_eq!(repository("localhost:5000/app:v2"), "localhost:5000/app");
+        assert_eq!(repository("localhost:5000/app"), "localhost:5000/app");
+        assert_eq!(repository("nginx:1.25@sha256:abc"), "nginx");
+    }
+
+    #[test]
+    /// Only tags of the same repository are listed, newest image first, & the current image is always included
+    fn test_recreate_tag_picker() {
+        let images = [
+            gen_image(1, &["nginx:1.24", "nginx:stable"]),
+            gen_image(3, &["nginx:1.26"]),
+            gen_image(2, &["nginx-proxy:1.25", "redis:7"]),
+        ];
+        assert_eq!(
+            local_tags("nginx:1.25", &images),
+            ["nginx:1.26", "nginx:1.24", "nginx:stable"]
+        );
+
+        let mut picker = tag_picker(ContainerId::from("1"), &gen_inspect(), &images);
+        assert_eq!(picker.name, "web");
+        assert_eq!(
+            picker.tags,
+            ["nginx:1.25", "nginx:1.26", "nginx:1.24", "nginx:stable"]
+        );
+        assert_eq!(picker.current, "nginx:1.25");
+        assert!(picker.chosen().is_none());
+        picker.next();
+        assert_eq!(picker.chosen(), Some("nginx:1.26"));
+    }
+
+    #[test]
+    /// Only an image with the same id as the container's image is up to date
+    fn test_recreate_is_up_to_date() {
+        let mut inspect = gen_inspect();
+        inspect.image = Some("sha256:aaa".to_owned());
+        let image = |id: &str| ImageInspect {
+            id: Some(id.to_owned()),
+            ..Default::default()
+        };
+        assert!(is_up_to_date(&inspect, &image("sha256:aaa")));
+        assert!(!is_up_to_date(&inspect, &image("sha256:bbb")));
+        inspect.image = None;
+        assert!(!is_up_to_date(&inspect, &ImageInspect::default()));
+    }
+}
@@ -0,0 +1,218 @@
+use parking_lot::Mutex;
+use std::sync::Arc;
+use tokio::sync::mpsc::{Receiver, Sender};
+
+use crate::app_data::{AppData, ContainerId};
+
+use super::DockerMessage;
+
+/// When monitoring multiple hosts, each host has its own DockerData, the router forwards each message to the DockerData of the host that the container is running on
+pub struct DockerRouter {
+    app_data: Arc<Mutex<AppData>>,
+    receiver: Receiver<DockerMessage>,
+    /// The primary host is None
+    routes: Vec<(Option<String>, Sender<DockerMessage>)>,
+}
+
+impl DockerRouter {
+    /// Find the sender for a given host, defaults to the primary host
+    fn get_sender(&self, host: Option<&str>) -> Option<&Sender<DockerMessage>> {
+        self.routes
+            .iter()
+            .find(|i| i.0.as_deref() == host)
+            .or_else(|| self.routes.first())
+            .map(|i| &i.1)
+    }
+
+    /// Containers of the same project name, or the marked containers, may be on more than one host, so each host is sent only its own containers
+    async fn send_by_host(
+        &self,
+        ids: &[ContainerId],
+        message: impl Fn(Vec<ContainerId>) -> DockerMessage,
+    ) {
+        let with_host = {
+            let app_data = self.app_data.lock();
+            ids.iter()
+                .map(|id| (app_data.get_host_by_id(id), id.clone()))
+                .collect::<Vec<_>>()
+        };
+        let mut hosts: Vec<(Option<String>, Vec<ContainerId>)> = vec![];
+        for (host, id) in with_host {
+            match hosts.iter_mut().find(|i| i.0 == host) {
+                Some(i) => i.1.push(id),
+                None => hosts.push((host, vec![id])),
+            }
+        }
+        for (host, ids) in hosts {
+            if let Some(sender) = self.get_sender(host.as_deref()) {
+                sender.send(message(ids)).await.ok();
+            }
+        }
+    }
+
+    /// Forward messages until a Quit message is received, which is sent to every host
+    #[allow(clippy::too_many_lines)]
+    async fn message_handler(&mut self) {
+        while let Some(message) = self.receiver.recv().await {
+            let host = match &message {
+                DockerMessage::Boost(id)
+                | DockerMessage::BoostTick(id)
+                | DockerMessage::Capture(id, ..)
+                | DockerMessage::Compare(id, _)
+                | DockerMessage::Confirm(_, id)
+                | DockerMessage::CopyFrom(id, _)
+                | DockerMessage::Delete(id)
+                | DockerMessage::Export(id)
+                | DockerMessage::ExportProject(id)
+                | DockerMessage::Files(id, _)
+                | DockerMessage::Forward(id, _)
+                | DockerMessage::Health(id)
+                | DockerMessage::Inspect(id)
+                | DockerMessage::Kill(id, _)
+                | DockerMessage::LimitsForm(id)
+                | DockerMessage::OlderLogs(id)
+                | DockerMessage::Pause(id)
+                | DockerMessage::PullRecreate(id)
+                | DockerMessage::Reach(id, _, _)
+                | DockerMessage::ReadLogs(id, ..)
+                | DockerMessage::ReachPicker(id)
+                | DockerMessage::RecreateForm(id)
+                | DockerMessage::Rename(id, _)
+                | DockerMessage::RenameForm(id)
+                | DockerMessage::Restart(id)
+                | DockerMessage::Resume(id)
+                | DockerMessage::Retry(_, id)
+                | DockerMessage::Start(id)
+                | DockerMessage::Stdin(id, _)
+                | DockerMessage::StdinForm(id)
+                | DockerMessage::Stop(id)
+                | DockerMessage::SwitchTag(id, _)
+                | DockerMessage::TagPicker(id)
+                | DockerMessage::Timeline(id)
+                | DockerMessage::UpdateLimits(id, ..) => self.app_data.lock().get_host_by_id(id),
+                DockerMessage::Recreate(form) => self.app_data.lock().get_host_by_id(&form.id),
+                DockerMessage::BuildCache(host)
+                | DockerMessage::BuildCacheRemove(host, _)
+                | DockerMessage::Cleanup(host)
+                | DockerMessage::CleanupPrune(host, _)
+                | DockerMessage::ImageDetail(host, _)
+                | DockerMessage::ImagePull(host, _)
+                | DockerMessage::ImageRemove(host, _)
+                | DockerMessage::NetworkRemove(host, _)
+                | DockerMessage::Prune(host, _)
+                | DockerMessage::PrunePreview(host)
+                | DockerMessage::Run(host, _)
+                | DockerMessage::SecretScan(host)
+                | DockerMessage::ServiceForceUpdate(host, _)
+                | DockerMessage::ServiceScale(host, ..)
+                | DockerMessage::SnapshotHost(host)
+                | DockerMessage::StackRemove(host, _)
+                | DockerMessage::StackScale(host, ..)
+                | DockerMessage::TaskLogs(host, _)
+                | DockerMessage::VolumeRemove(host, _) => host.clone(),
+                DockerMessage::ProjectControl(control, ids) => {
+                    let control = *control;
+                    self.send_by_host(ids, |ids| DockerMessage::ProjectControl(control, ids))
+                        .await;
+                    continue;
+                }
+                DockerMessage::BatchControl(control, ids) => {
+                    let control = *control;
+                    self.send_by_host(ids, |ids| DockerMessage::BatchControl(control, ids))
+                        .await;
+                    continue;
+                }
+                DockerMessage::BatchPreview(ids) => {
+                    self.send_by_host(ids, DockerMessage::BatchPreview).await;
+                    continue;
+                }
+                DockerMessage::Exec(_) => {
+                    let app_data = self.app_data.lock();
+                    app_data
+                        .get_selected_container_id()
+                        .and_then(|id| app_data.get_host_by_id(&id))
+                }
+                DockerMessage::Quit | DockerMessage::Update => {
+                    let quit = matches!(message, DockerMessage::Quit);
+                    for (_, sender) in &self.routes {
+                        let message = if quit {
+                            DockerMessage::Quit
+                        } else {
+                            DockerMessage::Update
+                        };
+                        sender.send(message).await.ok();
+                    }
+                    if quit {
+                        break;
+                    }
+                    continue;
+                }
+            };
+            if let Some(sender) = self.get_sender(host.as_deref()) {
+                sender.send(message).await.ok();
+            }
+        }
+    }
+
+    /// Initialise self, and start forwarding messages
+    pub async fn init(
+        app_data: Arc<Mutex<AppData>>,
+        docker_rx: Receiver<DockerMessage>,
+        routes: Vec<(Option<String>, Sender<DockerMessage>)>,
+    ) {
+        let mut inner = Self {
+            app_data,
+            receiver: docker_rx,
+            routes,
+        };
+        inner.message_handler().await;
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use parking_lot::Mutex;
+    use std::sync::Arc;
+
+    use super::DockerRouter;
+    use crate::{
+        app_data::ContainerId,
+        docker_data::DockerMessage,
+        tests::{gen_appdata, gen_containers},
+    };
+
+    #[tokio::test]
+    /// Messages are sent to the host of the container, Quit is sent to every host
+    async fn test_router_routes() {
+        let (ids, mut containers) = gen_containers();
+        containers[1].host = Some("remote".to_owned());
+        let app_data = Arc::new(Mutex::new(gen_appdata(&containers)));
+
+        let (tx, rx) = tokio::sync::mpsc::channel(8);
+        let (primary_tx, mut primary_rx) = tokio::sync::mpsc::channel(8);
+        let (remote_tx, mut remote_rx) = tokio::sync::mpsc::channel(8);
+        let handle = tokio::spawn(DockerRouter::init(
+            app_data,
+            rx,
+            vec![(None, primary_tx), (Some("remote".to_owned()), remote_tx)],
+        ));
+
+        tx.send(DockerMessage::Stop(ids[1].clone())).await.unwrap();
+        tx.send(DockerMessage::Pause(ids[0].clone())).await.unwrap();
+        tx.send(DockerMessage::Start(ContainerId::from("unknown")))
+            .await
+            .unwrap();
+        tx.send(DockerMessage::Quit).await.unwrap();
+        handle.await.unwrap();
+
+        assert!(matches!(remote_rx.recv().await, Some(DockerMessage::Stop(id)) if id == ids[1]));
+        assert!(matches!(remote_rx.recv().await, Some(DockerMessage::Quit)));
+        assert!(matches!(primary_rx.recv().await, Some(DockerMessage::Pause(id)) if id == ids[0]));
+        assert!(matches!(
+            primary_rx.recv().await,
+            Some(DockerMessage::Start(_))
+        ));
+        assert!(matches!(primary_rx.recv().await, Some(DockerMessage::Quit)));
+    }
+}
@@ -0,0 +1,112 @@
+use std::collections::HashMap;
+
+use bollard::{
+    container::{Config, CreateContainerOptions, RemoveContainerOptions, StartContainerOptions},
+    errors::Error,
+    service::{HostConfig, RestartPolicy, RestartPolicyNameEnum},
+    Docker,
+};
+
+use super::{docker_config::DockerConfig, recreate};
+use crate::ui::{FieldKind, Restart, RunForm};
+
+/// The restart policy of the new container's host config
+pub const fn restart_policy(restart: Restart) -> RestartPolicy {
+    let (name, maximum_retry_count) = match restart {
+        Restart::No => (RestartPolicyNameEnum::NO, None),
+        Restart::Always => (RestartPolicyNameEnum::ALWAYS, None),
+        Restart::UnlessStopped => (RestartPolicyNameEnum::UNLESS_STOPPED, None),
+        Restart::OnFailure(count) => (RestartPolicyNameEnum::ON_FAILURE, count),
+    };
+    RestartPolicy {
+        name: Some(name),
+        maximum_retry_count,
+    }
+}
+
+/// Build the config of the new container from the fields of the run form, mounts are given as binds, as `docker run -v` does
+pub fn config(form: &RunForm) -> Config<String> {
+    let mut exposed = HashMap::new();
+    let port_bindings = recreate::port_bindings(&form.values(FieldKind::Port), &mut exposed);
+    Config {
+        image: Some(form.image()),
+        env: Some(form.values(FieldKind::Env)),
+        exposed_ports: Some(exposed),
+        host_config: Some(HostConfig {
+            binds: Some(form.values(FieldKind::Mount)),
+            port_bindings: Some(port_bindings),
+            restart_policy: form.restart().map(restart_policy),
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
+/// Create, and start, the container of the run form, returning its id
+/// The proxies of the docker CLI's `config.json`, for the daemon host, are added to its env, as `docker run` does
+/// If the container can't be started, e.g. a port is already in use, it's removed, so that its name is free to run it again
+pub async fn run(docker: &Docker, form: &RunForm, host: Option<&str>) -> Result<String, Error> {
+    let options = form.name().map(|name| CreateContainerOptions {
+        name,
+        platform: None,
+    });
+    let mut config = config(form);
+    config.env = config
+        .env
+        .map(|env| DockerConfig::load().with_proxies(host, env));
+    let created = docker.create_container(options, config).await?;
+    if let Err(e) = docker
+        .start_container(&created.id, None::<StartContainerOptions<String>>)
+        .await
+    {
+        let remove = Some(RemoveContainerOptions {
+            v: false,
+            force: true,
+            link: false,
+        });
+        docker.remove_container(&created.id, remove).await.ok();
+        return Err(e);
+    }
+    Ok(created.id)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use bollard::service::{PortBinding, RestartPolicyNameEnum};
+
+    use super::config;
+    use crate::ui::RunForm;
+
+    #[test]
+    /// The fields of the run form are given to the daemon as docker run would, empty fields ignored
+    fn test_run_config() {
+        let mut form = RunForm::new("redis:7".to_owned(), vec![], None);
+        for (index, value) in [
+            (1, "cache"),
+            (2, "A=1"),
+            (3, "127.0.0.1:6380:6379"),
+            (4, "data:/data"),
+            (5, "on-failure:5"),
+        ] {
+            value.clone_into(&mut form.fields[index].value);
+        }
+        let config = config(&form);
+        assert_eq!(config.image.as_deref(), Some("redis:7"));
+        assert_eq!(config.env, Some(vec!["A=1".to_owned()]));
+        assert!(config.exposed_ports.unwrap().contains_key("6379/tcp"));
+
+        let host_config = config.host_config.unwrap();
+        assert_eq!(host_config.binds, Some(vec!["data:/data".to_owned()]));
+        assert_eq!(
+            host_config.port_bindings.unwrap().get("6379/tcp"),
+            Some(&Some(vec![PortBinding {
+                host_ip: Some("127.0.0.1".to_owned()),
+                host_port: Some("6380".to_owned()),
+            }]))
+        );
+        let restart = host_config.restart_policy.unwrap();
+        assert_eq!(restart.name, Some(RestartPolicyNameEnum::ON_FAILURE));
+        assert_eq!(restart.maximum_retry_count, Some(5));
+    }
+}
@@ -0,0 +1,649 @@
+use std::{fmt, path::PathBuf};
+
+use bollard::{
+    container::{
+        InspectContainerOptions, KillContainerOptions, ListContainersOptions, LogOutput,
+        LogsOptions, RemoveContainerOptions, StartContainerOptions, Stats, StatsOptions,
+    },
+    exec::{CreateExecOptions, StartExecResults},
+    service::{ContainerInspectResponse, ContainerSummary},
+    system::Version,
+    Docker,
+};
+use clap::ValueEnum;
+use futures_util::{
+    future::BoxFuture,
+    stream::{BoxStream, StreamExt},
+};
+
+use crate::app_data::{ContainerId, DockerControls, DEPENDS_ON_LABEL, SERVICE_LABEL};
+
+/// Socket of a rootful Podman service
+const PODMAN_SOCKET: &str = "/run/podman/podman.sock";
+
+/// The version component that Podman reports itself as
+const PODMAN_ENGINE: &str = "Podman Engine";
+
+/// The container runtime being connected to, Podman serves the Docker Engine API from `podman system service`, so is connected to in the same way
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum RuntimeKind {
+    #[default]
+    Docker,
+    Podman,
+}
+
+impl RuntimeKind {
+    /// The host used when none is given, the `$CONTAINER_HOST` env for Podman, else its rootless socket, if it exists, else its rootful socket
+    /// None for Docker, as its host is resolved from the docker contexts, & the socket defaults
+    pub fn default_host(self) -> Option<String> {
+        match self {
+            Self::Docker => None,
+            Self::Podman => std::env::var("CONTAINER_HOST").ok().or_else(|| {
+                let rootless = std::env::var_os("XDG_RUNTIME_DIR")
+                    .map(|i| PathBuf::from(i).join("podman").join("podman.sock"))
+                    .filter(|i| i.exists());
+                Some(rootless.map_or_else(
+                    || format!("unix://{PODMAN_SOCKET}"),
+                    |i| format!("unix://{}", i.display()),
+                ))
+            }),
+        }
+    }
+
+    /// The runtime that the daemon is, a daemon whose version has the Podman engine component is Podman, whatever `--runtime` is set to, else the given runtime
+    pub fn detect(self, version: Option<&Version>) -> Self {
+        let podman = version
+            .and_then(|i| i.components.as_ref())
+            .is_some_and(|i| i.iter().any(|i| i.name == PODMAN_ENGINE));
+        if podman {
+            Self::Podman
+        } else {
+            self
+        }
+    }
+}
+
+impl fmt::Display for RuntimeKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let disp = match self {
+            Self::Docker => "docker",
+            Self::Podman => "podman",
+        };
+        write!(f, "{disp}")
+    }
+}
+
+/// The output of a command exec'd in a container, without a tty, & its exit code, None if it couldn't be inspected
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ExecOutput {
+    pub stdout: String,
+    pub stderr: String,
+    pub exit_code: Option<i64>,
+}
+
+/// The containers, behind a trait, so that a runtime that doesn't speak the Docker Engine API, or a mock, can be used in place of bollard
+/// The controls, listing, inspecting, stats, logs, & a command exec'd without a tty, go through the runtime
+/// Creating, recreating, & attaching to, containers, & the images, volumes, networks, & services, still go through bollard directly
+pub trait Runtime: Send + Sync {
+    /// Send a single control to a container, recreate isn't a single call, & rename needs the new name, so both are an error
+    fn control<'a>(
+        &'a self,
+        control: DockerControls,
+        id: &'a ContainerId,
+    ) -> BoxFuture<'a, Result<(), String>>;
+
+    /// List the containers that match the options
+    fn list(
+        &self,
+        options: ListContainersOptions<String>,
+    ) -> BoxFuture<'_, Result<Vec<ContainerSummary>, String>>;
+
+    /// Inspect a container, with the size of its writable layer if size is set
+    fn inspect<'a>(
+        &'a self,
+        id: &'a ContainerId,
+        size: bool,
+    ) -> BoxFuture<'a, Result<ContainerInspectResponse, String>>;
+
+    /// A single stats sample of a container, a one-shot sample doesn't wait for the precpu stats
+    fn stats<'a>(
+        &'a self,
+        id: &'a ContainerId,
+        one_shot: bool,
+    ) -> BoxFuture<'a, Result<Stats, String>>;
+
+    /// The logs of a container, the stream ends with an error if the logs can't be read
+    fn logs<'a>(
+        &'a self,
+        id: &'a ContainerId,
+        options: LogsOptions<String>,
+    ) -> BoxStream<'a, Result<LogOutput, String>>;
+
+    /// Exec a command in a container, without a tty, until it exits
+    fn exec<'a>(
+        &'a self,
+        id: &'a ContainerId,
+        cmd: Vec<String>,
+    ) -> BoxFuture<'a, Result<ExecOutput, String>>;
+}
+
+impl Runtime for Docker {
+    fn control<'a>(
+        &'a self,
+        control: DockerControls,
+        id: &'a ContainerId,
+    ) -> BoxFuture<'a, Result<(), String>> {
+        Box::pin(async move {
+            let id = id.get();
+            match control {
+                DockerControls::Kill(signal) => {
+                    self.kill_container(
+                        id,
+                        Some(KillContainerOptions {
+                            signal: signal.to_string(),
+                        }),
+                    )
+                    .await
+                }
+                DockerControls::Pause => self.pause_container(id).await,
+                DockerControls::Restart => self.restart_container(id, None).await,
+                DockerControls::Resume => self.unpause_container(id).await,
+                DockerControls::Start => {
+                    self.start_container(id, None::<StartContainerOptions<String>>)
+                        .await
+                }
+                DockerControls::Stop => self.stop_container(id, None).await,
+                DockerControls::Delete => {
+                    self.remove_container(
+                        id,
+                        Some(RemoveContainerOptions {
+                            v: false,
+                            force: true,
+                            link: false,
+                        }),
+                    )
+                    .await
+                }
+                DockerControls::Recreate | DockerControls::Rename | DockerControls::Update => {
+                    return Err(format!("unable to {control} {id}"))
+                }
+            }
+            .map_err(|e| e.to_string())
+        })
+    }
+
+    fn list(
+        &self,
+        options: ListContainersOptions<String>,
+    ) -> BoxFuture<'_, Result<Vec<ContainerSummary>, String>> {
+        Box::pin(async move {
+            self.list_containers(Some(options))
+                .await
+                .map_err(|e| e.to_string())
+        })
+    }
+
+    fn inspect<'a>(
+        &'a self,
+        id: &'a ContainerId,
+        size: bool,
+    ) -> BoxFuture<'a, Result<ContainerInspectResponse, String>> {
+        Box::pin(async move {
+            self.inspect_container(id.get(), Some(InspectContainerOptions { size }))
+                .await
+                .map_err(|e| e.to_string())
+        })
+    }
+
+    fn stats<'a>(
+        &'a self,
+        id: &'a ContainerId,
+        one_shot: bool,
+    ) -> BoxFuture<'a, Result<Stats, String>> {
+        Box::pin(async move {
+            let options = StatsOptions {
+                stream: false,
+                one_shot,
+            };
+            // The inherent bollard methods, which take priority over those of the trait
+            self.stats(id.get(), Some(options))
+                .next()
+                .await
+                .map_or_else(
+                    || Err(format!("no stats for {}", id.get())),
+                    |stats| stats.map_err(|e| e.to_string()),
+                )
+        })
+    }
+
+    fn logs<'a>(
+        &'a self,
+        id: &'a ContainerId,
+        options: LogsOptions<String>,
+    ) -> BoxStream<'a, Result<LogOutput, String>> {
+        self.logs(id.get(), Some(options))
+            .map(|i| i.map_err(|e| e.to_string()))
+            .boxed()
+    }
+
+    fn exec<'a>(
+        &'a self,
+        id: &'a ContainerId,
+        cmd: Vec<String>,
+    ) -> BoxFuture<'a, Result<ExecOutput, String>> {
+        Box::pin(async move {
+            let exec = self
+                .create_exec(
+                    id.get(),
+                    CreateExecOptions {
+                        attach_stdout: Some(true),
+                        attach_stderr: Some(true),
+                        cmd: Some(cmd),
+                        ..Default::default()
+                    },
+                )
+                .await
+                .map_err(|e| e.to_string())?;
+            let mut output = ExecOutput::default();
+            if let Ok(StartExecResults::Attached {
+                output: mut stream, ..
+            }) = self.start_exec(&exec.id, None).await
+            {
+                while let Some(Ok(i)) = stream.next().await {
+                    match i {
+                        LogOutput::StdErr { message } => {
+                            output.stderr.push_str(&String::from_utf8_lossy(&message));
+                        }
+                        i => output
+                            .stdout
+                            .push_str(&String::from_utf8_lossy(&i.into_bytes())),
+                    }
+                }
+            }
+            output.exit_code = self
+                .inspect_exec(&exec.id)
+                .await
+                .ok()
+                .and_then(|i| i.exit_code);
+            Ok(output)
+        })
+    }
+}
+
+/// A container of a compose project, its service, the services it depends on, & whether it's running, from its inspect
+#[derive(Debug)]
+struct ProjectMember {
+    depends_on: Vec<String>,
+    id: ContainerId,
+    running: bool,
+    service: String,
+}
+
+/// Inspect each container of a project, & order them so that each comes after the services it depends on
+/// A container that can't be inspected depends on nothing, & a dependency cycle keeps the given order
+async fn dependency_order(runtime: &dyn Runtime, ids: &[ContainerId]) -> Vec<ProjectMember> {
+    let mut remaining = vec![];
+    for id in ids {
+        let inspect = runtime.inspect(id, false).await.ok();
+        let labels = inspect
+            .as_ref()
+            .and_then(|i| i.config.as_ref())
+            .and_then(|i| i.labels.as_ref());
+        remaining.push(ProjectMember {
+            depends_on: labels
+                .and_then(|i| i.get(DEPENDS_ON_LABEL))
+                .map(|i| {
+                    i.split(',')
+                        .filter_map(|i| i.split(':').next())
+                        .map(str::trim)
+                        .filter(|i| !i.is_empty())
+                        .map(ToOwned::to_owned)
+                        .collect()
+                })
+                .unwrap_or_default(),
+            id: id.clone(),
+            running: inspect
+                .as_ref()
+                .and_then(|i| i.state.as_ref())
+                .and_then(|i| i.running)
+                .unwrap_or(true),
+            service: labels
+                .and_then(|i| i.get(SERVICE_LABEL))
+                .cloned()
+                .unwrap_or_else(|| id.get().to_owned()),
+        });
+    }
+    let mut ordered = Vec::with_capacity(remaining.len());
+    while !remaining.is_empty() {
+        let index = remaining
+            .iter()
+            .position(|member| {
+                member.depends_on.iter().all(|service| {
+                    *service == member.service || !remaining.iter().any(|i| i.service == *service)
+                })
+            })
+            .unwrap_or(0);
+        ordered.push(remaining.remove(index));
+    }
+    ordered
+}
+
+/// Send a control to every container of a compose project, only start, stop, & restart are sent to a whole project
+/// The `depends_on` of the compose labels is respected, a service is started after the services it depends on, & stopped before them
+/// A restart of a project with dependencies stops the running containers, in reverse order, & then starts every container, in order, as compose does
+/// Each step is given to status, every container is sent the control, even after one has failed, returns false if any failed
+pub async fn project_control(
+    runtime: &dyn Runtime,
+    control: DockerControls,
+    ids: &[ContainerId],
+    status: &(dyn Fn(&str) + Sync),
+) -> bool {
+    if !DockerControls::gen_project_vec().contains(&control) {
+        return false;
+    }
+    let members = dependency_order(runtime, ids).await;
+    let mut steps = vec![];
+    match control {
+        DockerControls::Restart if members.iter().any(|i| !i.depends_on.is_empty()) => {
+            steps.extend(
+                members
+                    .iter()
+                    .rev()
+                    .filter(|i| i.running)
+                    .map(|i| (DockerControls::Stop, i)),
+            );
+            steps.extend(members.iter().map(|i| (DockerControls::Start, i)));
+        }
+        DockerControls::Stop => steps.extend(members.iter().rev().map(|i| (control, i))),
+        _ => steps.extend(members.iter().map(|i| (control, i))),
+    }
+    let mut sent = true;
+    for (index, (control, member)) in steps.iter().enumerate() {
+        status(&format!(
+            "{control} {} {}/{}",
+            member.service,
+            index + 1,
+            steps.len()
+        ));
+        sent &= runtime.control(*control, &member.id).await.is_ok();
+    }
+    sent
+}
+
+/// Send a control to every marked container, one after another, a failure doesn't stop the control being sent to the rest, false if any failed
+pub async fn batch_control(
+    runtime: &dyn Runtime,
+    control: DockerControls,
+    ids: &[ContainerId],
+) -> bool {
+    if !DockerControls::gen_batch_vec().contains(&control) {
+        return false;
+    }
+    let mut sent = true;
+    for id in ids {
+        sent &= runtime.control(control, id).await.is_ok();
+    }
+    sent
+}
+
+/// A runtime without a daemon, for the tests, records each control, & fails for the container with id "fail"
+/// The containers, inspects, stats, logs, & exec output, are whatever the test has set, a container without any is an error
+#[cfg(test)]
+#[derive(Debug, Default)]
+pub struct MockRuntime {
+    pub sent: parking_lot::Mutex<Vec<(DockerControls, String)>>,
+    pub containers: Vec<ContainerSummary>,
+    pub inspects: std::collections::HashMap<String, ContainerInspectResponse>,
+    pub stats: std::collections::HashMap<String, Stats>,
+    pub logs: std::collections::HashMap<String, Vec<String>>,
+    pub exec: Option<ExecOutput>,
+}
+
+#[cfg(test)]
+impl Runtime for MockRuntime {
+    fn control<'a>(
+        &'a self,
+        control: DockerControls,
+        id: &'a ContainerId,
+    ) -> BoxFuture<'a, Result<(), String>> {
+        Box::pin(async move {
+            self.sent.lock().push((control, id.get().to_owned()));
+            if id.get() == "fail" {
+                Err("failed".to_owned())
+            } else {
+                Ok(())
+            }
+        })
+    }
+
+    fn list(
+        &self,
+        _options: ListContainersOptions<String>,
+    ) -> BoxFuture<'_, Result<Vec<ContainerSummary>, String>> {
+        Box::pin(async move { Ok(self.containers.clone()) })
+    }
+
+    fn inspect<'a>(
+        &'a self,
+        id: &'a ContainerId,
+        _size: bool,
+    ) -> BoxFuture<'a, Result<ContainerInspectResponse, String>> {
+        Box::pin(async move {
+            self.inspects
+                .get(id.get())
+                .cloned()
+                .ok_or_else(|| format!("no such container: {}", id.get()))
+        })
+    }
+
+    fn stats<'a>(
+        &'a self,
+        id: &'a ContainerId,
+        _one_shot: bool,
+    ) -> BoxFuture<'a, Result<Stats, String>> {
+        Box::pin(async move {
+            self.stats
+                .get(id.get())
+                .cloned()
+                .ok_or_else(|| format!("no such container: {}", id.get()))
+        })
+    }
+
+    fn logs<'a>(
+        &'a self,
+        id: &'a ContainerId,
+        _options: LogsOptions<String>,
+    ) -> BoxStream<'a, Result<LogOutput, String>> {
+        let logs = self.logs.get(id.get()).map_or_else(
+            || vec![Err(format!("no such container: {}", id.get()))],
+            |lines| {
+                lines
+                    .iter()
+                    .map(|i| {
+                        Ok(LogOutput::StdOut {
+                            message: i.clone().into_bytes().into(),
+                        })
+                    })
+                    .collect()
+            },
+        );
+        futures_util::stream::iter(logs).boxed()
+    }
+
+    fn exec<'a>(
+        &'a self,
+        id: &'a ContainerId,
+        _cmd: Vec<String>,
+    ) -> BoxFuture<'a, Result<ExecOutput, String>> {
+        Box::pin(async move {
+            self.exec
+                .clone()
+                .ok_or_else(|| format!("no such container: {}", id.get()))
+        })
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use std::collections::HashMap;
+
+    use bollard::{
+        service::{ContainerConfig, ContainerInspectResponse, ContainerState},
+        system::{Version, VersionComponents},
+    };
+    use parking_lot::Mutex;
+
+    use super::{batch_control, project_control, MockRuntime, RuntimeKind};
+    use crate::app_data::{ContainerId, DockerControls, DEPENDS_ON_LABEL, SERVICE_LABEL};
+
+    #[test]
+    /// A daemon with the Podman engine component is Podman, else the runtime is kept, even without a version
+    fn test_runtime_detect() {
+        let version = |name: &str| Version {
+            components: Some(vec![VersionComponents {
+                name: name.to_owned(),
+                version: "5.0.0".to_owned(),
+                details: None,
+            }]),
+            ..Default::default()
+        };
+        let podman = version("Podman Engine");
+        let docker = version("Engine");
+        assert_eq!(
+            RuntimeKind::Docker.detect(Some(&podman)),
+            RuntimeKind::Podman
+        );
+        assert_eq!(
+            RuntimeKind::Docker.detect(Some(&docker)),
+            RuntimeKind::Docker
+        );
+        assert_eq!(RuntimeKind::Podman.detect(None), RuntimeKind::Podman);
+        assert_eq!(RuntimeKind::Docker.detect(None), RuntimeKind::Docker);
+    }
+
+    #[tokio::test]
+    /// Every container of a project is sent the control, even after one fails, only project controls are sent, each step is given to the status
+    async fn test_runtime_project_control() {
+        let runtime = MockRuntime::default();
+        let ids = ["a", "fail", "b"].map(ContainerId::from);
+        let status = Mutex::new(vec![]);
+        let push = |text: &str| status.lock().push(text.to_owned());
+        assert!(!project_control(&runtime, DockerControls::Restart, &ids, &push).await);
+        assert_eq!(
+            *runtime.sent.lock(),
+            [
+                (DockerControls::Restart, "a".to_owned()),
+                (DockerControls::Restart, "fail".to_owned()),
+                (DockerControls::Restart, "b".to_owned()),
+            ]
+        );
+        assert_eq!(
+            *status.lock(),
+            ["restart a 1/3", "restart fail 2/3", "restart b 3/3"]
+        );
+
+        let runtime = MockRuntime::default();
+        assert!(project_control(&runtime, DockerControls::Stop, &ids[..1], &push).await);
+        assert!(!project_control(&runtime, DockerControls::Delete, &ids[..1], &push).await);
+        assert_eq!(runtime.sent.lock().len(), 1);
+    }
+
+    #[tokio::test]
+    /// A project is started in the order of its `depends_on`, stopped in reverse, & a restart stops the running containers before starting them all, a cycle keeps the given order
+    async fn test_runtime_project_control_depends_on() {
+        let inspect = |service: &str, depends_on: &str, running: bool| ContainerInspectResponse {
+            config: Some(ContainerConfig {
+                labels: Some(HashMap::from([
+                    (SERVICE_LABEL.to_owned(), service.to_owned()),
+                    (DEPENDS_ON_LABEL.to_owned(), depends_on.to_owned()),
+                ])),
+                ..Default::default()
+            }),
+            state: Some(ContainerState {
+                running: Some(running),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let runtime = MockRuntime {
+            inspects: HashMap::from([
+                (
+                    "1".to_owned(),
+                    inspect(
+                        "web",
+                        "api:service_started:false,cache:service_healthy:true",
+                        true,
+                    ),
+                ),
+                (
+                    "2".to_owned(),
+                    inspect("api", "db:service_healthy:false", true),
+                ),
+                ("3".to_owned(), inspect("db", "", false)),
+                ("4".to_owned(), inspect("cache", "", true)),
+            ]),
+            ..Default::default()
+        };
+        let ids = ["1", "2", "3", "4"].map(ContainerId::from);
+        let sent = |control: DockerControls, order: &[&str]| {
+            order
+                .iter()
+                .map(|i| (control, (*i).to_owned()))
+                .collect::<Vec<_>>()
+        };
+        let status = Mutex::new(vec![]);
+        let push = |text: &str| status.lock().push(text.to_owned());
+
+        assert!(project_control(&runtime, DockerControls::Start, &ids, &push).await);
+        assert_eq!(
+            std::mem::take(&mut *runtime.sent.lock()),
+            sent(DockerControls::Start, &["3", "2", "4", "1"])
+        );
+        assert!(project_control(&runtime, DockerControls::Stop, &ids, &push).await);
+        assert_eq!(
+            std::mem::take(&mut *runtime.sent.lock()),
+            sent(DockerControls::Stop, &["1", "4", "2", "3"])
+        );
+
+        // The stopped db isn't stopped again
+        status.lock().clear();
+        assert!(project_control(&runtime, DockerControls::Restart, &ids, &push).await);
+        let mut expected = sent(DockerControls::Stop, &["1", "4", "2"]);
+        expected.extend(sent(DockerControls::Start, &["3", "2", "4", "1"]));
+        assert_eq!(*runtime.sent.lock(), expected);
+        assert_eq!(status.lock()[0], "stop web 1/7");
+        assert_eq!(status.lock()[6], "start web 7/7");
+
+        let runtime = MockRuntime {
+            inspects: HashMap::from([
+                ("1".to_owned(), inspect("a", "b", true)),
+                ("2".to_owned(), inspect("b", "a", true)),
+            ]),
+            ..Default::default()
+        };
+        assert!(project_control(&runtime, DockerControls::Start, &ids[..2], &push).await);
+        assert_eq!(
+            *runtime.sent.lock(),
+            sent(DockerControls::Start, &["1", "2"])
+        );
+    }
+
+    #[tokio::test]
+    /// Every marked container is sent the control, deleting included, recreating isn't a batch control
+    async fn test_runtime_batch_control() {
+        let runtime = MockRuntime::default();
+        let ids = ["a", "b"].map(ContainerId::from);
+        assert!(batch_control(&runtime, DockerControls::Delete, &ids).await);
+        assert!(batch_control(&runtime, DockerControls::Pause, &ids[..1]).await);
+        assert!(!batch_control(&runtime, DockerControls::Recreate, &ids).await);
+        assert_eq!(
+            *runtime.sent.lock(),
+            [
+                (DockerControls::Delete, "a".to_owned()),
+                (DockerControls::Delete, "b".to_owned()),
+                (DockerControls::Pause, "a".to_owned()),
+            ]
+        );
+    }
+}
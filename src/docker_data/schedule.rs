@@ -0,0 +1,72 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use bollard::{container::LogsOptions, Docker};
+use futures_util::StreamExt;
+
+use super::Runtime;
+use crate::{
+    app_data::{redact, DockerControls, Redact, ScheduleAction, ScheduleRun},
+    ui::log_sanitizer::{self, InvalidUtf8},
+};
+
+/// Save every log line of the container, as plain text, into `[name]_[now].log` in the save_dir, as ( s ) does
+async fn save_logs(
+    docker: &Docker,
+    run: &ScheduleRun,
+    dir: &Path,
+    timestamps: bool,
+    invalid_utf8: InvalidUtf8,
+    rules: &[Redact],
+    now: u64,
+) -> Result<PathBuf, String> {
+    let options = Some(LogsOptions::<String> {
+        stdout: true,
+        timestamps,
+        ..Default::default()
+    });
+    let mut logs = docker.logs(run.id.get(), options);
+    let mut output = String::new();
+    while let Some(value) = logs.next().await {
+        let value = value.map_err(|e| e.to_string())?;
+        let data = log_sanitizer::decode(&value.into_bytes(), invalid_utf8);
+        let line = log_sanitizer::plain(log_sanitizer::carriage_return(data.trim_end()));
+        if !line.trim().is_empty() {
+            output.push_str(&redact(rules, &line));
+            output.push('\n');
+        }
+    }
+    let path = dir.join(format!("{}_{now}.log", run.name));
+    fs::write(&path, output).map_err(|e| e.to_string())?;
+    Ok(path)
+}
+
+/// Run a schedule that's due, returns a description of what was done, for the info box
+pub async fn run(
+    docker: &Docker,
+    run: &ScheduleRun,
+    save_dir: Option<&Path>,
+    timestamps: bool,
+    invalid_utf8: InvalidUtf8,
+    rules: &[Redact],
+    now: u64,
+) -> Result<String, String> {
+    let control = match run.action {
+        ScheduleAction::Restart => DockerControls::Restart,
+        ScheduleAction::Start => DockerControls::Start,
+        ScheduleAction::Stop => DockerControls::Stop,
+        ScheduleAction::SaveLogs => {
+            let dir = save_dir.ok_or_else(|| "no save directory".to_owned())?;
+            let path = save_logs(docker, run, dir, timestamps, invalid_utf8, rules, now).await?;
+            return Ok(format!(
+                "scheduled save of {} logs to {}",
+                run.name,
+                path.display()
+            ));
+        }
+    };
+    docker.control(control, &run.id).await?;
+    Ok(format!("scheduled {} of {}", run.action, run.name))
+}
@@ -0,0 +1,209 @@
+use std::collections::HashMap;
+
+use bollard::service::{
+    LocalNodeState, Service, ServiceSpec, ServiceSpecModeReplicated, ServiceUpdateStatusStateEnum,
+    SystemInfo,
+};
+
+use crate::app_data::ServiceItem;
+
+/// Label of a swarm task's container, the id of the service that the task belongs to
+pub const SERVICE_ID_LABEL: &str = "com.docker.swarm.service.id";
+
+/// Label of a service, network, or secret, deployed by `docker stack deploy`, the name of its stack
+pub const STACK_LABEL: &str = "com.docker.stack.namespace";
+
+/// Lines of each task's logs that are merged into the task logs
+pub const TASK_LOG_LINES: usize = 200;
+
+/// The host is an active swarm node that can manage the swarm, a worker can't list the services
+pub fn is_manager(info: &SystemInfo) -> bool {
+    info.swarm.as_ref().is_some_and(|i| {
+        i.local_node_state == Some(LocalNodeState::ACTIVE) && i.control_available == Some(true)
+    })
+}
+
+/// The state of the latest update, as shown in the services panel, None if the service has never been updated
+const fn update_text(state: ServiceUpdateStatusStateEnum) -> Option<&'static str> {
+    match state {
+        ServiceUpdateStatusStateEnum::EMPTY => None,
+        ServiceUpdateStatusStateEnum::UPDATING => Some("updating"),
+        ServiceUpdateStatusStateEnum::PAUSED => Some("update paused"),
+        ServiceUpdateStatusStateEnum::COMPLETED => Some("updated"),
+        ServiceUpdateStatusStateEnum::ROLLBACK_STARTED => Some("rolling back"),
+        ServiceUpdateStatusStateEnum::ROLLBACK_PAUSED => Some("rollback paused"),
+        ServiceUpdateStatusStateEnum::ROLLBACK_COMPLETED => Some("rolled back"),
+    }
+}
+
+/// Convert the services, listed with their status, into service items, the image digest that the daemon pins each task to is left out
+pub fn services(services: &[Service]) -> Vec<ServiceItem> {
+    services
+        .iter()
+        .filter_map(|i| {
+            let spec = i.spec.as_ref();
+            let status = i.service_status.as_ref();
+            Some(ServiceItem {
+                desired: status.and_then(|i| i.desired_tasks).unwrap_or_default(),
+                global: spec
+                    .and_then(|i| i.mode.as_ref())
+                    .is_some_and(|i| i.global.is_some()),
+                id: i.id.clone()?,
+                image: spec
+                    .and_then(|i| i.task_template.as_ref())
+                    .and_then(|i| i.container_spec.as_ref())
+                    .and_then(|i| i.image.as_deref())
+                    .and_then(|i| i.split('@').next())
+                    .unwrap_or_default()
+                    .to_owned(),
+                name: spec.and_then(|i| i.name.clone()).unwrap_or_default(),
+                running: status.and_then(|i| i.running_tasks).unwrap_or_default(),
+                stack: spec
+                    .and_then(|i| i.labels.as_ref())
+                    .and_then(|i| i.get(STACK_LABEL))
+                    .filter(|i| !i.is_empty())
+                    .cloned(),
+                update: i
+                    .update_status
+                    .as_ref()
+                    .and_then(|i| i.state)
+                    .and_then(update_text)
+                    .map(ToOwned::to_owned),
+            })
+        })
+        .collect()
+}
+
+/// The filters to list the services, networks, or secrets, of a stack, by its label, as `docker stack` does
+pub fn stack_filters(stack: &str) -> HashMap<String, Vec<String>> {
+    HashMap::from([("label".to_owned(), vec![format!("{STACK_LABEL}={stack}")])])
+}
+
+/// The spec, scaled to the replicas, None if the service isn't replicated, as only a replicated service can be scaled
+pub fn scaled(mut spec: ServiceSpec, replicas: u64) -> Option<ServiceSpec> {
+    let replicated = spec.mode.as_mut()?.replicated.as_mut()?;
+    *replicated = ServiceSpecModeReplicated {
+        replicas: Some(i64::try_from(replicas).ok()?),
+    };
+    Some(spec)
+}
+
+/// The spec, with its force update counter incremented, the daemon replaces every task when the counter changes, even though nothing else has
+pub fn force_updated(mut spec: ServiceSpec) -> Option<ServiceSpec> {
+    let template = spec.task_template.as_mut()?;
+    template.force_update = Some(template.force_update.unwrap_or_default().wrapping_add(1));
+    Some(spec)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use std::collections::HashMap;
+
+    use bollard::service::{
+        LocalNodeState, Service, ServiceServiceStatus, ServiceSpec, ServiceSpecMode,
+        ServiceSpecModeReplicated, ServiceUpdateStatus, ServiceUpdateStatusStateEnum, SwarmInfo,
+        SystemInfo, TaskSpec, TaskSpecContainerSpec,
+    };
+
+    use super::{force_updated, is_manager, scaled, services, stack_filters, STACK_LABEL};
+
+    #[allow(clippy::zero_sized_map_values)]
+    fn gen_spec(name: &str, global: bool) -> ServiceSpec {
+        ServiceSpec {
+            name: Some(name.to_owned()),
+            mode: Some(if global {
+                ServiceSpecMode {
+                    global: Some(HashMap::new()),
+                    ..Default::default()
+                }
+            } else {
+                ServiceSpecMode {
+                    replicated: Some(ServiceSpecModeReplicated { replicas: Some(3) }),
+                    ..Default::default()
+                }
+            }),
+            task_template: Some(TaskSpec {
+                container_spec: Some(TaskSpecContainerSpec {
+                    image: Some("nginx:1.27@sha256:0d17b565c37b".to_owned()),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    /// Only an active manager counts as a swarm host
+    fn test_services_is_manager() {
+        let gen_info = |state, control| SystemInfo {
+            swarm: Some(SwarmInfo {
+                local_node_state: Some(state),
+                control_available: Some(control),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        assert!(is_manager(&gen_info(LocalNodeState::ACTIVE, true)));
+        assert!(!is_manager(&gen_info(LocalNodeState::ACTIVE, false)));
+        assert!(!is_manager(&gen_info(LocalNodeState::INACTIVE, false)));
+        assert!(!is_manager(&SystemInfo::default()));
+    }
+
+    #[test]
+    /// Services show their running of desired tasks, their image without its digest, & the state of the latest update
+    fn test_services() {
+        let result = services(&[
+            Service {
+                id: Some("q8k2xv1n4m7b".to_owned()),
+                spec: Some(gen_spec("site_web", false)),
+                service_status: Some(ServiceServiceStatus {
+                    running_tasks: Some(2),
+                    desired_tasks: Some(3),
+                    completed_tasks: None,
+                }),
+                update_status: Some(ServiceUpdateStatus {
+                    state: Some(ServiceUpdateStatusStateEnum::ROLLBACK_STARTED),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+            Service {
+                id: Some("r9l3yw2o5n8c".to_owned()),
+                spec: Some(ServiceSpec {
+                    labels: Some(HashMap::from([(STACK_LABEL.to_owned(), "site".to_owned())])),
+                    ..gen_spec("site_agent", true)
+                }),
+                ..Default::default()
+            },
+            Service::default(),
+        ]);
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].name, "site_web");
+        assert_eq!(result[0].image, "nginx:1.27");
+        assert_eq!(result[0].replicas_text(), "2/3");
+        assert_eq!(result[0].update.as_deref(), Some("rolling back"));
+        assert!(!result[0].global);
+        assert!(result[1].global);
+        assert_eq!(result[1].update, None);
+        assert_eq!(result[0].stack, None);
+        assert_eq!(result[1].stack.as_deref(), Some("site"));
+        assert_eq!(
+            stack_filters("site").get("label"),
+            Some(&vec!["com.docker.stack.namespace=site".to_owned()])
+        );
+    }
+
+    #[test]
+    /// Only a replicated service is scaled, & each force update increments the counter
+    fn test_services_spec_changes() {
+        let spec = scaled(gen_spec("site_web", false), 5).unwrap();
+        assert_eq!(spec.mode.unwrap().replicated.unwrap().replicas, Some(5));
+        assert!(scaled(gen_spec("site_agent", true), 5).is_none());
+
+        let spec = force_updated(gen_spec("site_web", false)).unwrap();
+        let spec = force_updated(spec).unwrap();
+        assert_eq!(spec.task_template.unwrap().force_update, Some(2));
+    }
+}
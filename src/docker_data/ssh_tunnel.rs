@@ -0,0 +1,202 @@
+use std::{
+    path::{Path, PathBuf},
+    process::{Child, Command, Stdio},
+    time::{Duration, Instant},
+};
+
+use tracing::error;
+use uuid::Uuid;
+
+/// How long to wait for ssh to create the forwarded socket
+const TUNNEL_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// Default socket location on the remote host
+const REMOTE_SOCKET: &str = "/var/run/docker.sock";
+
+/// The parts of a `ssh://[user@]host[:port][/path/to/docker.sock]` url
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct SshTarget {
+    destination: String,
+    port: Option<u16>,
+    socket: String,
+}
+
+impl SshTarget {
+    /// Parse a ssh url, None if it isn't a ssh url, or is missing a host
+    /// A user, or host, starting with a `-` would be read by ssh as an option, e.g. `ssh://-oProxyCommand=...`, so is refused, hosts can come from a docker context, or the control socket, as well as the command line
+    fn parse(host: &str) -> Option<Self> {
+        let rest = host.strip_prefix("ssh://")?;
+        let (authority, socket) = rest
+            .find('/')
+            .map_or((rest, REMOTE_SOCKET), |i| (&rest[..i], &rest[i..]));
+        let (destination, port) = match authority.rsplit_once(':') {
+            Some((destination, port)) => (destination, Some(port.parse::<u16>().ok()?)),
+            None => (authority, None),
+        };
+        if destination.is_empty() || destination.ends_with('@') {
+            return None;
+        }
+        let (user, host) = destination
+            .rsplit_once('@')
+            .map_or((None, destination), |(user, host)| (Some(user), host));
+        if host.starts_with('-') || user.is_some_and(|i| i.is_empty() || i.starts_with('-')) {
+            return None;
+        }
+        Some(Self {
+            destination: destination.to_owned(),
+            port,
+            socket: socket.to_owned(),
+        })
+    }
+
+    /// Arguments for the ssh command, forwarding a local socket to the remote docker socket
+    /// As the system ssh binary is used, `~/.ssh/config` is honoured, so ProxyJump, identities, & ports set there all apply
+    /// The destination follows a `--`, so that ssh never reads it as an option
+    fn args(&self, local: &Path, jump: Option<&str>) -> Vec<String> {
+        let mut args = vec![
+            "-nNT".to_owned(),
+            "-o".to_owned(),
+            "ExitOnForwardFailure=yes".to_owned(),
+            "-L".to_owned(),
+            format!("{}:{}", local.display(), self.socket),
+        ];
+        if let Some(jump) = jump {
+            args.push("-J".to_owned());
+            args.push(jump.to_owned());
+        }
+        if let Some(port) = self.port {
+            args.push("-p".to_owned());
+            args.push(port.to_string());
+        }
+        args.push("--".to_owned());
+        args.push(self.destination.clone());
+        args
+    }
+}
+
+/// A ssh process forwarding a local unix socket to the docker socket of a remote host, the process is killed when dropped
+#[derive(Debug)]
+pub struct SshTunnel {
+    child: Child,
+    socket: PathBuf,
+}
+
+impl SshTunnel {
+    /// Check if a host should be connected to via ssh
+    pub fn is_ssh(host: &str) -> bool {
+        host.starts_with("ssh://")
+    }
+
+    /// Start ssh, and wait for the forwarded socket to be created
+    pub async fn open(host: &str, jump: Option<&str>) -> Option<Self> {
+        let Some(target) = SshTarget::parse(host) else {
+            error!("invalid ssh host: {host}");
+            return None;
+        };
+        let socket = std::env::temp_dir().join(format!("oxker-{}.sock", Uuid::new_v4()));
+        let child = Command::new("ssh")
+            .args(target.args(&socket, jump))
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn();
+        let mut tunnel = match child {
+            Ok(child) => Self { child, socket },
+            Err(e) => {
+                error!("unable to start ssh: {e}");
+                return None;
+            }
+        };
+
+        let start = Instant::now();
+        while start.elapsed() < TUNNEL_TIMEOUT {
+            if tunnel.socket.exists() {
+                return Some(tunnel);
+            }
+            // ssh has exited, so the connection, or the forward, has failed
+            if matches!(tunnel.child.try_wait(), Ok(Some(_))) {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+        error!("unable to open ssh tunnel to {host}");
+        None
+    }
+
+    /// Path of the local socket, to connect to with Docker::connect_with_socket
+    pub fn socket(&self) -> String {
+        format!("unix://{}", self.socket.display())
+    }
+}
+
+impl Drop for SshTunnel {
+    fn drop(&mut self) {
+        self.child.kill().ok();
+        self.child.wait().ok();
+        std::fs::remove_file(&self.socket).ok();
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use std::path::Path;
+
+    use super::{SshTarget, REMOTE_SOCKET};
+
+    #[test]
+    /// Ssh urls parsed into destination, port, and remote socket
+    fn test_ssh_target_parse() {
+        assert_eq!(
+            SshTarget::parse("ssh://user@host"),
+            Some(SshTarget {
+                destination: "user@host".to_owned(),
+                port: None,
+                socket: REMOTE_SOCKET.to_owned(),
+            })
+        );
+        assert_eq!(
+            SshTarget::parse("ssh://host:2222/run/user/1000/docker.sock"),
+            Some(SshTarget {
+                destination: "host".to_owned(),
+                port: Some(2222),
+                socket: "/run/user/1000/docker.sock".to_owned(),
+            })
+        );
+        assert!(SshTarget::parse("tcp://host:2375").is_none());
+        assert!(SshTarget::parse("ssh://").is_none());
+        assert!(SshTarget::parse("ssh://user@").is_none());
+        assert!(SshTarget::parse("ssh://host:port").is_none());
+        assert!(SshTarget::parse("ssh://-oProxyCommand=touch%20x").is_none());
+        assert!(SshTarget::parse("ssh://-oProxyCommand=x@host").is_none());
+        assert!(SshTarget::parse("ssh://user@-oProxyCommand=x").is_none());
+        assert!(SshTarget::parse("ssh://@host").is_none());
+    }
+
+    #[test]
+    /// Jump host and port passed to ssh, destination is always last, after a `--`
+    fn test_ssh_target_args() {
+        let target = SshTarget::parse("ssh://user@host:2222").unwrap();
+        assert_eq!(
+            target.args(Path::new("/tmp/a.sock"), Some("bastion")),
+            [
+                "-nNT",
+                "-o",
+                "ExitOnForwardFailure=yes",
+                "-L",
+                "/tmp/a.sock:/var/run/docker.sock",
+                "-J",
+                "bastion",
+                "-p",
+                "2222",
+                "--",
+                "user@host"
+            ]
+        );
+        let target = SshTarget::parse("ssh://host").unwrap();
+        assert_eq!(
+            target.args(Path::new("/tmp/a.sock"), None).last().unwrap(),
+            "host"
+        );
+    }
+}
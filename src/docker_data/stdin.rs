@@ -0,0 +1,77 @@
+use bollard::{container::AttachContainerOptions, service::ContainerInspectResponse, Docker};
+use tokio::io::AsyncWriteExt;
+
+use crate::app_data::ContainerId;
+
+/// Whether the container was started with stdin open, e.g. `docker run -i`, only then does it read what is sent to its stdin
+pub fn is_open(inspect: &ContainerInspectResponse) -> bool {
+    inspect
+        .config
+        .as_ref()
+        .and_then(|i| i.open_stdin)
+        .unwrap_or_default()
+}
+
+/// The bytes written for a line, a tty, `docker run -t`, expects a carriage return, as if enter had been pressed
+pub fn line_bytes(line: &str, tty: bool) -> Vec<u8> {
+    let end = if tty { '\r' } else { '\n' };
+    format!("{line}{end}").into_bytes()
+}
+
+/// Send a single line to the stdin of a container, by attaching to its stdin only, so none of its output is streamed
+/// Detaching only closes the container's stdin if it was started with stdin once, as `docker run -i` in the foreground is
+pub async fn send(docker: &Docker, id: &ContainerId, line: &str) -> Result<(), String> {
+    let inspect = docker
+        .inspect_container(id.get(), None)
+        .await
+        .map_err(|e| e.to_string())?;
+    if !is_open(&inspect) {
+        return Err("stdin isn't open".to_owned());
+    }
+    let tty = inspect
+        .config
+        .as_ref()
+        .and_then(|i| i.tty)
+        .unwrap_or_default();
+    let options = AttachContainerOptions::<String> {
+        stdin: Some(true),
+        stream: Some(true),
+        ..Default::default()
+    };
+    let mut attached = docker
+        .attach_container(id.get(), Some(options))
+        .await
+        .map_err(|e| e.to_string())?;
+    attached
+        .input
+        .write_all(&line_bytes(line, tty))
+        .await
+        .map_err(|e| e.to_string())?;
+    attached.input.flush().await.map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use bollard::service::{ContainerConfig, ContainerInspectResponse};
+
+    use super::{is_open, line_bytes};
+
+    #[test]
+    /// Only a container with open stdin can be sent lines, a tty gets a carriage return in place of a newline
+    fn test_stdin_is_open_line_bytes() {
+        let inspect = |open_stdin| ContainerInspectResponse {
+            config: Some(ContainerConfig {
+                open_stdin,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        assert!(is_open(&inspect(Some(true))));
+        assert!(!is_open(&inspect(Some(false))));
+        assert!(!is_open(&inspect(None)));
+        assert!(!is_open(&ContainerInspectResponse::default()));
+
+        assert_eq!(line_bytes("say hi", false), b"say hi\n");
+        assert_eq!(line_bytes("say hi", true), b"say hi\r");
+    }
+}
@@ -0,0 +1,130 @@
+use std::collections::HashMap;
+
+use bollard::{
+    errors::Error,
+    service::{EventMessage, EventMessageTypeEnum},
+    system::EventsOptions,
+    Docker,
+};
+use futures_util::StreamExt;
+
+use crate::{
+    app_data::ContainerId,
+    ui::{Timeline, TimelineEvent, TimelineKind, TIMELINE_SPAN},
+};
+
+/// Convert Docker events into lifecycle events, events of other object types, without a time, or that aren't part of the lifecycle, are skipped
+pub fn events(messages: &[EventMessage]) -> Vec<TimelineEvent> {
+    messages
+        .iter()
+        .filter(|i| matches!(i.typ, None | Some(EventMessageTypeEnum::CONTAINER)))
+        .filter_map(|i| {
+            let exit_code = i
+                .actor
+                .as_ref()
+                .and_then(|i| i.attributes.as_ref())
+                .and_then(|i| i.get("exitCode"))
+                .and_then(|i| i.parse().ok());
+            Some(TimelineEvent {
+                time: i.time?,
+                kind: TimelineKind::from_action(i.action.as_deref()?, exit_code)?,
+            })
+        })
+        .collect()
+}
+
+/// Build the lifecycle timeline of a container, from the daemon's events over the last day, `end` is the daemon's current time
+/// The daemon only keeps a limited number of events, so on a busy host the timeline may not reach back a full day
+pub async fn timeline(docker: &Docker, id: &ContainerId, end: i64) -> Result<Timeline, Error> {
+    let inspect = docker.inspect_container(id.get(), None).await?;
+    let options = EventsOptions {
+        since: Some((end - TIMELINE_SPAN).to_string()),
+        until: Some(end.to_string()),
+        filters: HashMap::from([("container", vec![id.get()]), ("type", vec!["container"])]),
+    };
+    let mut stream = docker.events(Some(options));
+    let mut messages = vec![];
+    while let Some(message) = stream.next().await {
+        messages.push(message?);
+    }
+    Ok(Timeline::new(
+        inspect
+            .name
+            .map_or_else(String::new, |i| i.trim_start_matches('/').to_owned()),
+        end,
+        inspect.state.and_then(|i| i.running).unwrap_or_default(),
+        events(&messages),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use bollard::service::{EventActor, EventMessage, EventMessageTypeEnum};
+
+    use super::events;
+    use crate::ui::{TimelineEvent, TimelineKind};
+
+    fn gen_message(
+        typ: EventMessageTypeEnum,
+        action: &str,
+        time: i64,
+        attributes: &[(&str, &str)],
+    ) -> EventMessage {
+        EventMessage {
+            typ: Some(typ),
+            action: Some(action.to_owned()),
+            actor: Some(EventActor {
+                id: Some("1".to_owned()),
+                attributes: Some(
+                    attributes
+                        .iter()
+                        .map(|(k, v)| ((*k).to_owned(), (*v).to_owned()))
+                        .collect::<HashMap<_, _>>(),
+                ),
+            }),
+            time: Some(time),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    /// Only container lifecycle events are kept, the exit code of a die event is parsed from its attributes
+    fn test_timeline_events() {
+        let messages = [
+            gen_message(EventMessageTypeEnum::CONTAINER, "start", 10, &[]),
+            gen_message(
+                EventMessageTypeEnum::CONTAINER,
+                "die",
+                20,
+                &[("exitCode", "137"), ("name", "container_1")],
+            ),
+            gen_message(EventMessageTypeEnum::CONTAINER, "exec_create: sh", 25, &[]),
+            gen_message(EventMessageTypeEnum::NETWORK, "disconnect", 30, &[]),
+            gen_message(
+                EventMessageTypeEnum::CONTAINER,
+                "health_status: unhealthy",
+                40,
+                &[],
+            ),
+        ];
+        assert_eq!(
+            events(&messages),
+            vec![
+                TimelineEvent {
+                    time: 10,
+                    kind: TimelineKind::Start
+                },
+                TimelineEvent {
+                    time: 20,
+                    kind: TimelineKind::Die(Some(137))
+                },
+                TimelineEvent {
+                    time: 40,
+                    kind: TimelineKind::Unhealthy
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,133 @@
+use std::path::{Path, PathBuf};
+
+use bollard::{Docker, API_DEFAULT_VERSION};
+
+/// Default port of a Docker daemon that's listening with TLS
+const TLS_PORT: u16 = 2376;
+
+/// The client certificates used to connect to a `tcp://` host with TLS, as for `docker --tlsverify`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TlsFiles {
+    pub ca: PathBuf,
+    pub cert: PathBuf,
+    pub key: PathBuf,
+}
+
+impl TlsFiles {
+    /// Resolve the certificates, TLS is used if any are given, if `--tlsverify` is set, or if `$DOCKER_TLS_VERIFY` is set
+    /// Any that aren't given are read from `$DOCKER_CERT_PATH`, or `~/.docker`, as `ca.pem`, `cert.pem`, & `key.pem`
+    pub fn new(
+        verify: bool,
+        ca: Option<String>,
+        cert: Option<String>,
+        key: Option<String>,
+    ) -> Option<Self> {
+        let verify = verify
+            || std::env::var("DOCKER_TLS_VERIFY").is_ok_and(|i| !i.is_empty())
+            || ca.is_some()
+            || cert.is_some()
+            || key.is_some();
+        if !verify {
+            return None;
+        }
+        let dir = std::env::var_os("DOCKER_CERT_PATH")
+            .map(PathBuf::from)
+            .or_else(|| {
+                directories::BaseDirs::new().map(|base_dirs| base_dirs.home_dir().join(".docker"))
+            })
+            .unwrap_or_default();
+        Some(Self::with_dir(&dir, ca, cert, key))
+    }
+
+    fn with_dir(dir: &Path, ca: Option<String>, cert: Option<String>, key: Option<String>) -> Self {
+        let file =
+            |given: Option<String>, name: &str| given.map_or_else(|| dir.join(name), PathBuf::from);
+        Self {
+            ca: file(ca, "ca.pem"),
+            cert: file(cert, "cert.pem"),
+            key: file(key, "key.pem"),
+        }
+    }
+
+    /// Connect to a `tcp://` host with bollard's TLS, the daemon's certificate has to be signed by the ca, & match the host, & the client certificate is sent
+    pub fn connect(&self, host: &str) -> Result<Docker, bollard::errors::Error> {
+        let address = address(host).unwrap_or_else(|| host.to_owned());
+        Docker::connect_with_ssl(
+            &address,
+            &self.key,
+            &self.cert,
+            &self.ca,
+            120,
+            API_DEFAULT_VERSION,
+        )
+    }
+
+    /// Check if a host should be connected to via TLS, when the TLS certificates are set
+    pub fn is_tls(host: &str) -> bool {
+        host.starts_with("tcp://")
+    }
+}
+
+/// The `tcp://host:port` of a tcp url, with the port of a TLS daemon, 2376, if it has none, as bollard would otherwise connect to the https port
+/// None if it isn't a tcp url, or is missing a host
+fn address(host: &str) -> Option<String> {
+    let rest = host.strip_prefix("tcp://")?;
+    let authority = rest.split('/').next().unwrap_or_default();
+    let (name, port) = match authority.rsplit_once(':') {
+        Some((name, port)) if !port.contains(']') => (name, port.parse::<u16>().ok()?),
+        _ => (authority, TLS_PORT),
+    };
+    let name = name.trim_start_matches('[').trim_end_matches(']');
+    if name.is_empty() {
+        return None;
+    }
+    Some(if name.contains(':') {
+        format!("tcp://[{name}]:{port}")
+    } else {
+        format!("tcp://{name}:{port}")
+    })
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use std::path::{Path, PathBuf};
+
+    use super::{address, TlsFiles};
+
+    #[test]
+    /// Certificates that aren't given are read from the cert directory
+    fn test_tls_files_with_dir() {
+        let files = TlsFiles::with_dir(
+            Path::new("/certs"),
+            None,
+            Some("/home/user/client.pem".to_owned()),
+            None,
+        );
+        assert_eq!(
+            files,
+            TlsFiles {
+                ca: PathBuf::from("/certs/ca.pem"),
+                cert: PathBuf::from("/home/user/client.pem"),
+                key: PathBuf::from("/certs/key.pem"),
+            }
+        );
+    }
+
+    #[test]
+    /// Tcp urls get the TLS port if they have none, ipv6 hosts are bracketed
+    fn test_tls_address() {
+        assert_eq!(
+            address("tcp://docker.example.com").as_deref(),
+            Some("tcp://docker.example.com:2376")
+        );
+        assert_eq!(
+            address("tcp://10.0.0.2:2377/").as_deref(),
+            Some("tcp://10.0.0.2:2377")
+        );
+        assert_eq!(address("tcp://[::1]").as_deref(), Some("tcp://[::1]:2376"));
+        assert!(address("ssh://host").is_none());
+        assert!(address("tcp://").is_none());
+        assert!(address("tcp://host:port").is_none());
+    }
+}
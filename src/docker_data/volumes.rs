@@ -0,0 +1,98 @@
+use std::collections::HashMap;
+
+use bollard::service::{ContainerSummary, MountPointTypeEnum, Volume};
+
+use crate::app_data::{ByteStats, VolumeItem};
+
+/// The size of each volume, as reported by the daemon's disk usage, a driver that doesn't report sizes reports `-1`
+pub fn sizes(volumes: &[Volume]) -> HashMap<String, ByteStats> {
+    volumes
+        .iter()
+        .filter_map(|i| {
+            let size = u64::try_from(i.usage_data.as_ref()?.size).ok()?;
+            Some((i.name.clone(), ByteStats::new(size)))
+        })
+        .collect()
+}
+
+/// Convert the volumes into volume items, with the name of each container, running or not, that mounts it
+pub fn volumes(
+    volumes: &[Volume],
+    containers: &[ContainerSummary],
+    sizes: &HashMap<String, ByteStats>,
+) -> Vec<VolumeItem> {
+    let mut in_use = HashMap::<&str, Vec<String>>::new();
+    for container in containers {
+        let Some(name) = container.names.as_ref().and_then(|i| i.first()) else {
+            continue;
+        };
+        for mount in container.mounts.iter().flatten() {
+            if let (Some(MountPointTypeEnum::VOLUME), Some(volume)) =
+                (mount.typ, mount.name.as_deref())
+            {
+                in_use
+                    .entry(volume)
+                    .or_default()
+                    .push(name.trim_start_matches('/').to_owned());
+            }
+        }
+    }
+    volumes
+        .iter()
+        .map(|i| VolumeItem {
+            containers: in_use.remove(i.name.as_str()).unwrap_or_default(),
+            driver: i.driver.clone(),
+            name: i.name.clone(),
+            scope: i.scope.map(|i| i.to_string()).unwrap_or_default(),
+            size: sizes.get(&i.name).copied(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use bollard::service::{
+        ContainerSummary, MountPoint, MountPointTypeEnum, Volume, VolumeScopeEnum, VolumeUsageData,
+    };
+
+    use super::{sizes, volumes};
+    use crate::app_data::ByteStats;
+
+    #[test]
+    /// Each volume lists the containers that mount it, bind mounts aren't volumes, sizes are only known if reported
+    fn test_volumes() {
+        let gen_volume = |name: &str, size: i64| Volume {
+            name: name.to_owned(),
+            driver: "local".to_owned(),
+            scope: Some(VolumeScopeEnum::LOCAL),
+            usage_data: Some(VolumeUsageData { size, ref_count: 1 }),
+            ..Default::default()
+        };
+        let gen_mount = |typ: MountPointTypeEnum, name: &str| MountPoint {
+            typ: Some(typ),
+            name: Some(name.to_owned()),
+            ..Default::default()
+        };
+        let container = ContainerSummary {
+            names: Some(vec!["/site-db-1".to_owned()]),
+            mounts: Some(vec![
+                gen_mount(MountPointTypeEnum::VOLUME, "db-data"),
+                gen_mount(MountPointTypeEnum::BIND, "cache"),
+            ]),
+            ..Default::default()
+        };
+        let all = [gen_volume("db-data", 2048), gen_volume("cache", -1)];
+        let sizes = sizes(&all);
+        assert_eq!(sizes.len(), 1);
+
+        let result = volumes(&all, &[container], &sizes);
+        assert_eq!(result[0].containers, ["site-db-1"]);
+        assert_eq!(result[0].scope, "local");
+        assert_eq!(result[0].size.map(ByteStats::get), Some(2048));
+        assert!(result[1].containers.is_empty());
+        assert!(result[1].size.is_none());
+        assert!(volumes(&all, &[], &HashMap::new())[0].size.is_none());
+    }
+}
@@ -0,0 +1,165 @@
+use std::time::{Duration, Instant};
+
+use bollard::{
+    errors::Error,
+    service::{ContainerInspectResponse, ContainerStateStatusEnum, HealthStatusEnum},
+    Docker,
+};
+
+/// How often the container is inspected, whilst waiting for it
+const WAIT_INTERVAL: Duration = Duration::from_millis(500);
+
+/// The state of a container that is being waited for, by `oxker wait`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WaitStatus {
+    /// Healthy, or running when it has no health check
+    Ready(String),
+    /// Not ready yet, but could still become ready, e.g. created, restarting, or the health check is starting
+    Waiting(String),
+    /// Can't become ready without outside intervention, e.g. exited, or dead
+    Failed(String),
+}
+
+impl WaitStatus {
+    /// Work out the wait status from an inspected container, a container with a health check is only ready once healthy
+    pub fn from_inspect(inspect: &ContainerInspectResponse) -> Self {
+        let Some(state) = inspect.state.as_ref() else {
+            return Self::Waiting("unknown".to_owned());
+        };
+        let health = state.health.as_ref().and_then(|i| i.status);
+        match (state.status, health) {
+            (Some(ContainerStateStatusEnum::RUNNING), Some(HealthStatusEnum::HEALTHY)) => {
+                Self::Ready("healthy".to_owned())
+            }
+            (
+                Some(ContainerStateStatusEnum::RUNNING),
+                None | Some(HealthStatusEnum::NONE | HealthStatusEnum::EMPTY),
+            ) => Self::Ready("running".to_owned()),
+            (Some(ContainerStateStatusEnum::RUNNING), Some(health)) => {
+                Self::Waiting(format!("running, {health}"))
+            }
+            (
+                Some(
+                    status @ (ContainerStateStatusEnum::EXITED
+                    | ContainerStateStatusEnum::DEAD
+                    | ContainerStateStatusEnum::REMOVING),
+                ),
+                _,
+            ) => Self::Failed(state.exit_code.map_or_else(
+                || status.to_string(),
+                |code| format!("{status}, exit code {code}"),
+            )),
+            (Some(status), _) => Self::Waiting(status.to_string()),
+            (None, _) => Self::Waiting("unknown".to_owned()),
+        }
+    }
+
+    fn text(&self) -> &str {
+        match self {
+            Self::Ready(i) | Self::Waiting(i) | Self::Failed(i) => i,
+        }
+    }
+}
+
+/// Block until the named container is ready, printing each change of its state, returns the process exit code
+/// 0 once ready, 1 if it has failed, times out, or can't be inspected
+pub async fn wait(docker: &Docker, name: &str, timeout: Option<Duration>) -> i32 {
+    let start = Instant::now();
+    let mut previous = None;
+    loop {
+        let elapsed = start.elapsed().as_secs();
+        let status = match docker.inspect_container(name, None).await {
+            Ok(inspect) => WaitStatus::from_inspect(&inspect),
+            Err(Error::DockerResponseServerError {
+                status_code: 404, ..
+            }) => WaitStatus::Waiting("not found".to_owned()),
+            Err(e) => {
+                eprintln!("{name}: unable to inspect, {e}");
+                return 1;
+            }
+        };
+        if previous.as_ref() != Some(&status) {
+            println!("{name}: {} ({elapsed}s)", status.text());
+        }
+        match status {
+            WaitStatus::Ready(_) => return 0,
+            WaitStatus::Failed(_) => return 1,
+            WaitStatus::Waiting(_) => (),
+        }
+        if timeout.is_some_and(|i| start.elapsed() >= i) {
+            eprintln!("{name}: timed out after {elapsed}s");
+            return 1;
+        }
+        previous = Some(status);
+        tokio::time::sleep(WAIT_INTERVAL).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bollard::service::{
+        ContainerInspectResponse, ContainerState, ContainerStateStatusEnum, Health,
+        HealthStatusEnum,
+    };
+
+    use super::WaitStatus;
+
+    fn gen_inspect(
+        status: ContainerStateStatusEnum,
+        health: Option<HealthStatusEnum>,
+        exit_code: Option<i64>,
+    ) -> ContainerInspectResponse {
+        ContainerInspectResponse {
+            state: Some(ContainerState {
+                status: Some(status),
+                health: health.map(|status| Health {
+                    status: Some(status),
+                    ..Default::default()
+                }),
+                exit_code,
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    /// A container with a health check is only ready once healthy, without one, running is ready
+    fn test_wait_status() {
+        let status = |status, health, exit_code| {
+            WaitStatus::from_inspect(&gen_inspect(status, health, exit_code))
+        };
+        assert_eq!(
+            status(ContainerStateStatusEnum::RUNNING, None, None),
+            WaitStatus::Ready("running".to_owned())
+        );
+        assert_eq!(
+            status(
+                ContainerStateStatusEnum::RUNNING,
+                Some(HealthStatusEnum::HEALTHY),
+                None
+            ),
+            WaitStatus::Ready("healthy".to_owned())
+        );
+        assert_eq!(
+            status(
+                ContainerStateStatusEnum::RUNNING,
+                Some(HealthStatusEnum::STARTING),
+                None
+            ),
+            WaitStatus::Waiting("running, starting".to_owned())
+        );
+        assert_eq!(
+            status(ContainerStateStatusEnum::RESTARTING, None, Some(1)),
+            WaitStatus::Waiting("restarting".to_owned())
+        );
+        assert_eq!(
+            status(ContainerStateStatusEnum::EXITED, None, Some(137)),
+            WaitStatus::Failed("exited, exit code 137".to_owned())
+        );
+        assert_eq!(
+            WaitStatus::from_inspect(&ContainerInspectResponse::default()),
+            WaitStatus::Waiting("unknown".to_owned())
+        );
+    }
+}
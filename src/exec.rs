@@ -1,26 +1,42 @@
 use std::{
-    io::{Read, Stdout, Write},
+    fs::File,
+    io::{BufWriter, Read, Stdout, Write},
+    path::{Path, PathBuf},
+    pin::Pin,
     sync::{atomic::AtomicBool, Arc},
+    time::{Instant, SystemTime},
 };
 
 use bollard::{
+    container::{AttachContainerOptions, LogOutput, ResizeContainerTtyOptions},
     exec::{CreateExecOptions, ResizeExecOptions, StartExecOptions, StartExecResults},
     Docker,
 };
 use crossterm::terminal::enable_raw_mode;
-use futures_util::StreamExt;
+use futures_util::{Stream, StreamExt};
 use parking_lot::Mutex;
 use ratatui::{backend::CrosstermBackend, Terminal};
-use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use serde_json::json;
+use tokio::io::{AsyncReadExt, AsyncWrite, AsyncWriteExt};
 
 use crate::{
-    app_data::{AppData, ContainerId, State},
+    app_data::{AppData, ContainerId, ExecEntry, State},
     app_error::AppError,
+    ui::cast_time,
 };
 
 /// TTY location
 const TTY: &str = "/dev/tty";
 
+/// Max bytes read from the tty at once
+const TTY_BUFFER: usize = 1024;
+
+/// How often to check if the terminal has been resized during an exec session
+const RESIZE_INTERVAL: std::time::Duration = std::time::Duration::from_millis(250);
+
+/// TERM used inside the container if it isn't set on the host
+const DEFAULT_TERM: &str = "xterm-256color";
+
 /// This will be the start of a docker exec message if one is unable to actually exec into the container
 const OCI_ERROR: &str = "OCI runtime exec failed";
 
@@ -31,12 +47,92 @@ const CURSOR_POS: &str = "\x1B[J\x1B[H";
 /// see https://sw.kovidgoyal.net/kitty/keyboard-protocol/#progressive-enhancement
 const KEYBOARD_PROTO: &str = "\x1B[?u\x1B[c";
 
-mod command {
+pub mod command {
     pub const PWD: &str = "pwd";
     pub const DOCKER: &str = "docker";
     pub const EXEC: &str = "exec";
+    pub const ATTACH: &str = "attach";
+    pub const INSPECT: &str = "inspect";
     pub const SH: &str = "sh";
+    pub const CMD: &str = "cmd.exe";
     pub const IT: &str = "-it";
+    pub const DETACH_KEYS: &str = "--detach-keys";
+    /// Signals, e.g. ctrl-c, aren't sent to the main process of an attached container, so it can't be stopped by mistake
+    pub const NO_SIG_PROXY: &str = "--sig-proxy=false";
+
+    /// Windows containers have neither sh nor pwd, so use cmd.exe instead
+    pub const fn shell(windows: bool) -> &'static str {
+        if windows {
+            CMD
+        } else {
+            SH
+        }
+    }
+
+    /// Command to exec, the shell, or a command run by the shell, which then waits for enter, so that the output can be read before returning to oxker
+    pub fn exec(windows: bool, command: Option<&str>) -> Vec<String> {
+        let shell = shell(windows).to_owned();
+        match command {
+            None => vec![shell],
+            Some(command) if windows => vec![shell, "/c".to_owned(), format!("{command} & pause")],
+            Some(command) => vec![
+                shell,
+                "-c".to_owned(),
+                format!("{command}\nprintf '\\n[exit code %s] press enter to return to oxker' \"$?\"\nread _"),
+            ],
+        }
+    }
+
+    /// Split a command into its arguments, as a shell would, with single, & double, quotes, & backslash escapes, so it can be exec'd without a shell
+    /// None if a quote isn't closed, or it ends with a lone backslash
+    pub fn split(input: &str) -> Option<Vec<String>> {
+        let mut output = vec![];
+        let mut word: Option<String> = None;
+        let mut chars = input.chars();
+        while let Some(c) = chars.next() {
+            match c {
+                c if c.is_whitespace() => output.extend(word.take()),
+                '\'' => {
+                    let word = word.get_or_insert_with(String::new);
+                    loop {
+                        match chars.next()? {
+                            '\'' => break,
+                            c => word.push(c),
+                        }
+                    }
+                }
+                '"' => {
+                    let word = word.get_or_insert_with(String::new);
+                    loop {
+                        match chars.next()? {
+                            '"' => break,
+                            '\\' => match chars.next()? {
+                                c @ ('"' | '\\' | '$' | '`') => word.push(c),
+                                c => {
+                                    word.push('\\');
+                                    word.push(c);
+                                }
+                            },
+                            c => word.push(c),
+                        }
+                    }
+                }
+                '\\' => word.get_or_insert_with(String::new).push(chars.next()?),
+                c => word.get_or_insert_with(String::new).push(c),
+            }
+        }
+        output.extend(word);
+        Some(output)
+    }
+
+    /// Command used to check that a container can be exec'd into
+    pub fn probe(windows: bool) -> Vec<&'static str> {
+        if windows {
+            vec![CMD, "/c", "cd"]
+        } else {
+            vec![PWD]
+        }
+    }
 }
 
 /// Currently known byte output after writing KEYBOARD_PROTO to stdout
@@ -81,22 +177,22 @@ pub fn tty_readable() -> bool {
 }
 
 /// Async tty reading, spawned into its own tokio thread
+/// Input is read in chunks, rather than byte by byte, so that escape sequences, e.g. arrow keys, function keys, & alt combinations, are forwarded whole
+/// else applications such as vim can mistake a split sequence for a lone escape key press
 fn tty(run: Arc<AtomicBool>) -> Option<AsyncTTY> {
     if tty_readable() {
         let (tx, rx) = std::sync::mpsc::channel();
         tokio::spawn(async move {
             if let Ok(mut f) = tokio::fs::File::open(TTY).await {
+                let mut buf = [0; TTY_BUFFER];
                 while run.load(std::sync::atomic::Ordering::SeqCst) {
-                    let mut buf = [0];
-                    if tokio::time::timeout(
-                        std::time::Duration::from_millis(10),
-                        f.read_exact(&mut buf),
-                    )
-                    .await
-                    .is_ok()
-                        && tx.send(buf[0]).is_err()
+                    if let Ok(Ok(len)) =
+                        tokio::time::timeout(std::time::Duration::from_millis(10), f.read(&mut buf))
+                            .await
                     {
-                        run.store(false, std::sync::atomic::Ordering::SeqCst);
+                        if len == 0 || tx.send(buf[..len].to_vec()).is_err() {
+                            run.store(false, std::sync::atomic::Ordering::SeqCst);
+                        }
                     }
                 }
             }
@@ -108,11 +204,333 @@ fn tty(run: Arc<AtomicBool>) -> Option<AsyncTTY> {
 }
 
 struct AsyncTTY {
-    rx: std::sync::mpsc::Receiver<u8>,
+    rx: std::sync::mpsc::Receiver<Vec<u8>>,
+}
+
+/// Key sequence that detaches from an exec, or attach, session, set with `--detach-keys`, in the same format as docker, e.g. "ctrl-p,ctrl-q"
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DetachKeys {
+    bytes: Vec<u8>,
+    display: String,
+}
+
+impl Default for DetachKeys {
+    fn default() -> Self {
+        Self {
+            bytes: vec![0x10, 0x11],
+            display: "ctrl-p ctrl-q".to_owned(),
+        }
+    }
+}
+
+impl std::str::FromStr for DetachKeys {
+    type Err = String;
+
+    /// Each comma separated key is either a single character, or `ctrl-` followed by a letter, or one of `@[\\]^_`
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut bytes = vec![];
+        for key in input.split(',').map(str::trim) {
+            let byte = match key.strip_prefix("ctrl-") {
+                Some(c) if c.len() == 1 => {
+                    let c = c.as_bytes()[0].to_ascii_uppercase();
+                    if !matches!(c, b'@'..=b'_') {
+                        return Err(format!("\"{key}\" is not a valid ctrl key"));
+                    }
+                    c & 0x1f
+                }
+                None if key.len() == 1 && key.is_ascii() => key.as_bytes()[0],
+                _ => return Err(format!("\"{key}\" is not a valid key")),
+            };
+            bytes.push(byte);
+        }
+        Ok(Self {
+            bytes,
+            display: input
+                .split(',')
+                .map(str::trim)
+                .collect::<Vec<_>>()
+                .join(" "),
+        })
+    }
+}
+
+impl std::fmt::Display for DetachKeys {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.display)
+    }
+}
+
+impl DetachKeys {
+    /// In the format used by the docker cli `--detach-keys` argument
+    fn to_docker(&self) -> String {
+        self.display.replace(' ', ",")
+    }
+}
+
+/// Watch the input of an exec session for the detach sequence
+/// Bytes that could be the start of the sequence are held back, and only forwarded once it's known that the sequence isn't being entered
+#[derive(Debug)]
+struct DetachMatcher<'a> {
+    keys: &'a [u8],
+    matched: usize,
+}
+
+impl<'a> DetachMatcher<'a> {
+    const fn new(keys: &'a DetachKeys) -> Self {
+        Self {
+            keys: keys.bytes.as_slice(),
+            matched: 0,
+        }
+    }
+
+    /// Check a chunk of input, returns the bytes that should be forwarded, and true if the detach sequence has been entered
+    fn feed(&mut self, input: &[u8]) -> (Vec<u8>, bool) {
+        let mut output = Vec::with_capacity(input.len());
+        for byte in input {
+            if self.keys.get(self.matched) != Some(byte) {
+                output.extend_from_slice(&self.keys[..self.matched]);
+                self.matched = 0;
+            }
+            if self.keys.get(self.matched) == Some(byte) {
+                self.matched += 1;
+                if self.matched == self.keys.len() {
+                    return (output, true);
+                }
+            } else {
+                output.push(*byte);
+            }
+        }
+        (output, false)
+    }
+}
+
+/// Header written at the top of the screen when entering an exec, or attach, session, a recorded session says so
+fn header(session: &Session, name: &str, detach_keys: &DetachKeys, recorded: bool) -> String {
+    format!(
+        "\x1B[7m oxker {}: {name} - detach with {detach_keys}{} \x1B[0m\r\n",
+        session.label(),
+        if recorded { " - recorded" } else { "" }
+    )
+}
+
+/// Shown once a command run without the shell has exited, as the shell would, so that the output can be read before returning to oxker
+fn exit_message(exit_code: Option<impl std::fmt::Display>) -> String {
+    exit_code.map_or_else(
+        || "\r\n[exited] press enter to return to oxker".to_owned(),
+        |i| format!("\r\n[exit code {i}] press enter to return to oxker"),
+    )
 }
 
+/// Wait for enter to be pressed, in raw mode, after showing the exit code of the command
+fn wait_for_enter(exit_code: Option<i64>) {
+    let mut stdout = std::io::stdout();
+    stdout.write_all(exit_message(exit_code).as_bytes()).ok();
+    stdout.flush().ok();
+    if let Ok(mut f) = std::fs::File::open(TTY) {
+        let mut buf = [0; TTY_BUFFER];
+        while let Ok(len) = f.read(&mut buf) {
+            if len == 0 || buf[..len].iter().any(|i| *i == b'\r' || *i == b'\n') {
+                break;
+            }
+        }
+    }
+}
+
+/// Without a tty the output only has line feeds, which, as the terminal is in raw mode, need a carriage return too
+fn crlf(output: &[u8]) -> Vec<u8> {
+    let mut crlf = Vec::with_capacity(output.len());
+    for (index, byte) in output.iter().enumerate() {
+        if *byte == b'\n' && (index == 0 || output[index - 1] != b'\r') {
+            crlf.push(b'\r');
+        }
+        crlf.push(*byte);
+    }
+    crlf
+}
+
+/// The process exec'd into a container, & the options it's run with, as set in the command form
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ExecOptions {
+    cmd: Vec<String>,
+    env: Vec<String>,
+    user: Option<String>,
+    /// A command run without the shell can't wait for enter itself, so oxker waits once it exits, so that the output can be read
+    wait: bool,
+    working_dir: Option<String>,
+}
+
+impl ExecOptions {
+    /// The shell, a command run by the shell, or, without the shell, the command split into its arguments
+    pub fn new(windows: bool, entry: Option<&ExecEntry>) -> Result<Self, String> {
+        let Some(entry) = entry else {
+            return Ok(Self {
+                cmd: command::exec(windows, None),
+                ..Self::default()
+            });
+        };
+        let cmd = if entry.shell {
+            command::exec(windows, Some(&entry.command))
+        } else {
+            command::split(&entry.command)
+                .filter(|i| !i.is_empty())
+                .ok_or_else(|| "unclosed quote in the command".to_owned())?
+        };
+        let option = |i: &str| Some(i.trim().to_owned()).filter(|i| !i.is_empty());
+        Ok(Self {
+            cmd,
+            env: entry.env_pairs()?,
+            user: option(&entry.user),
+            wait: !entry.shell,
+            working_dir: option(&entry.working_dir),
+        })
+    }
+
+    /// The options as `docker exec` arguments, placed before the container id
+    fn docker_args(&self) -> Vec<&str> {
+        let mut args = vec![];
+        if let Some(user) = self.user.as_deref() {
+            args.extend(["-u", user]);
+        }
+        if let Some(working_dir) = self.working_dir.as_deref() {
+            args.extend(["-w", working_dir]);
+        }
+        for env in &self.env {
+            args.extend(["-e", env]);
+        }
+        args
+    }
+}
+
+/// What a session runs in the container
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Session {
+    /// Attach to the main process of the container, as `docker attach`, tty is if the container was created with one
+    Attach { tty: bool },
+    /// Exec a new process, the shell, or a command, with the options of the command form
+    Exec(ExecOptions),
+}
+
+impl Session {
+    const fn label(&self) -> &'static str {
+        match self {
+            Self::Attach { .. } => "attach",
+            Self::Exec(_) => "exec",
+        }
+    }
+}
+
+/// Take the text from the start of the pending bytes, an incomplete character at the end is kept for the next chunk, invalid bytes are replaced
+fn take_utf8(pending: &mut Vec<u8>) -> String {
+    let split = match std::str::from_utf8(pending) {
+        Err(e) if e.error_len().is_none() => e.valid_up_to(),
+        _ => pending.len(),
+    };
+    let rest = pending.split_off(split);
+    let text = String::from_utf8_lossy(pending).into_owned();
+    *pending = rest;
+    text
+}
+
+/// With `--exec-log`, the input, & output, of a session, with timings, recorded as an asciinema v2 cast, so it can be replayed, or read, for an audit
+/// Input is recorded after the detach keys are removed, the same bytes that are sent to the container
+pub struct SessionLog<W: Write> {
+    input: Vec<u8>,
+    output: Vec<u8>,
+    start: Instant,
+    writer: W,
+}
+
+impl SessionLog<BufWriter<File>> {
+    /// Create `[name]_[exec|attach]_[now].cast` in the directory
+    fn create(
+        dir: &Path,
+        name: &str,
+        session: &Session,
+        size: Option<TerminalSize>,
+    ) -> std::io::Result<Self> {
+        let now = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .map_or(0, |i| i.as_secs());
+        let path = dir.join(format!("{name}_{}_{now}.cast", session.label()));
+        Self::new(
+            BufWriter::new(File::create(path)?),
+            size,
+            &format!("oxker {}: {name}", session.label()),
+            now,
+        )
+    }
+}
+
+impl<W: Write> SessionLog<W> {
+    /// Write the header, the terminal size defaults to 80x24 if unknown
+    fn new(
+        mut writer: W,
+        size: Option<TerminalSize>,
+        title: &str,
+        timestamp: u64,
+    ) -> std::io::Result<Self> {
+        let size = size.unwrap_or(TerminalSize {
+            width: 80,
+            height: 24,
+        });
+        let header = json!({
+            "version": 2,
+            "width": size.width,
+            "height": size.height,
+            "timestamp": timestamp,
+            "title": title,
+        });
+        writeln!(writer, "{header}")?;
+        writer.flush()?;
+        Ok(Self {
+            input: vec![],
+            output: vec![],
+            start: Instant::now(),
+            writer,
+        })
+    }
+
+    /// Write a single event line, `[time, code, data]`, each is flushed, so that the log is complete even if oxker is killed
+    fn event(&mut self, code: &str, data: &str) {
+        if !data.is_empty() {
+            writeln!(
+                self.writer,
+                "{}",
+                json!([cast_time(self.start.elapsed()), code, data])
+            )
+            .ok();
+            self.writer.flush().ok();
+        }
+    }
+
+    fn input(&mut self, bytes: &[u8]) {
+        self.input.extend_from_slice(bytes);
+        let text = take_utf8(&mut self.input);
+        self.event("i", &text);
+    }
+
+    fn output(&mut self, bytes: &[u8]) {
+        self.output.extend_from_slice(bytes);
+        let text = take_utf8(&mut self.output);
+        self.event("o", &text);
+    }
+}
+
+/// The tty that follows the size of the terminal, an exec's own tty, or, when attached, the container's
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum ResizeTarget {
+    Container { id: ContainerId, tty: bool },
+    Exec(String),
+}
+
+/// The output, & input, streams of an exec, or attach, session
+type Attached = (
+    Pin<Box<dyn Stream<Item = Result<LogOutput, bollard::errors::Error>> + Send>>,
+    Pin<Box<dyn AsyncWrite + Send>>,
+);
+
 /// This is used to set the terminal size when exec via the Internal method
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct TerminalSize {
     width: u16,
     height: u16,
@@ -127,97 +545,304 @@ impl TerminalSize {
             })
         })
     }
+
+    /// Get the current size of the terminal, used to track resizes whilst the exec session is running
+    fn current() -> Option<Self> {
+        crossterm::terminal::size()
+            .ok()
+            .map(|(width, height)| Self { width, height })
+    }
+
+    /// Resize the tty of an exec session, or of an attached container, to match, false if the daemon refused
+    /// Podman refuses to resize an exec session until it's running, so a failed resize is only retried, never an error
+    async fn resize(self, docker: &Docker, target: &ResizeTarget) -> bool {
+        match target {
+            ResizeTarget::Exec(exec_id) => docker
+                .resize_exec(
+                    exec_id,
+                    ResizeExecOptions {
+                        height: self.height,
+                        width: self.width,
+                    },
+                )
+                .await
+                .ok(),
+            ResizeTarget::Container { id, tty } if *tty => docker
+                .resize_container_tty(
+                    id.get(),
+                    ResizeContainerTtyOptions {
+                        height: self.height,
+                        width: self.width,
+                    },
+                )
+                .await
+                .ok(),
+            ResizeTarget::Container { .. } => Some(()),
+        }
+        .is_some()
+    }
+}
+
+/// Resize the session tty whenever the terminal is resized, until the session has ended
+fn resize_watcher(
+    run: Arc<AtomicBool>,
+    docker: Arc<Docker>,
+    target: ResizeTarget,
+    mut size: Option<TerminalSize>,
+) {
+    tokio::spawn(async move {
+        while run.load(std::sync::atomic::Ordering::SeqCst) {
+            tokio::time::sleep(RESIZE_INTERVAL).await;
+            let current = TerminalSize::current();
+            if current != size {
+                if let Some(current) = current {
+                    if !current.resize(&docker, &target).await {
+                        continue;
+                    }
+                }
+                size = current;
+            }
+        }
+    });
 }
 
 #[derive(Debug, Clone)]
 pub enum ExecMode {
     // use Bollard Rust library
-    Internal((ContainerId, Arc<Docker>)),
+    Internal {
+        detach_keys: DetachKeys,
+        docker: Arc<Docker>,
+        id: ContainerId,
+        /// The directory that the session is recorded into, with `--exec-log`
+        log_dir: Option<PathBuf>,
+        name: String,
+        session: Session,
+    },
     // use the external `docker-cli`
-    External(ContainerId),
+    External {
+        detach_keys: DetachKeys,
+        id: ContainerId,
+        name: String,
+        session: Session,
+    },
 }
 
 impl ExecMode {
+    /// Check that the shell of a container can be exec'd into, via the Bollard library
+    async fn probe_internal(docker: &Docker, id: &ContainerId, windows: bool) -> bool {
+        let Ok(exec) = docker
+            .create_exec(
+                id.get(),
+                CreateExecOptions {
+                    attach_stdout: Some(true),
+                    attach_stderr: Some(true),
+                    cmd: Some(command::probe(windows)),
+                    ..Default::default()
+                },
+            )
+            .await
+        else {
+            return false;
+        };
+        if let Ok(StartExecResults::Attached { mut output, .. }) =
+            docker.start_exec(&exec.id, None).await
+        {
+            if let Some(Ok(msg)) = output.next().await {
+                return !msg.to_string().starts_with(OCI_ERROR);
+            }
+        }
+        false
+    }
+
+    /// Check that the shell of a container can be exec'd into, via the docker cli, a command run without the shell only needs the container to exist
+    fn probe_external(id: &ContainerId, windows: bool, shell: bool) -> bool {
+        if !shell {
+            return std::process::Command::new(command::DOCKER)
+                .args([command::INSPECT, id.get()])
+                .output()
+                .is_ok_and(|i| i.status.success());
+        }
+        std::process::Command::new(command::DOCKER)
+            .arg(command::EXEC)
+            .arg(id.get())
+            .args(command::probe(windows))
+            .output()
+            .ok()
+            .and_then(|i| String::from_utf8(i.stdout).ok())
+            .is_some_and(|i| !i.starts_with(OCI_ERROR))
+    }
+
     /// Test if we can exec into the selected container, first via the Internal methods, then by the External
+    /// The container's shell is exec'd, or, if given, a command, run by the shell, or exec'd as is, in which case the shell isn't probed, as a distroless container has none
     /// If the container is oxker, it will always return None
-    pub async fn new(app_data: &Arc<Mutex<AppData>>, docker: &Arc<Docker>) -> Option<Self> {
-        let is_oxker = app_data.lock().is_oxker();
-        if is_oxker {
+    pub async fn new(
+        app_data: &Arc<Mutex<AppData>>,
+        docker: &Arc<Docker>,
+        entry: Option<&ExecEntry>,
+    ) -> Option<Self> {
+        let (use_cli, log_dir, detach_keys, container, windows) = {
+            let app_data = app_data.lock();
+            if app_data.is_oxker() {
+                return None;
+            }
+            (
+                app_data.args.use_cli,
+                app_data.args.exec_log.clone(),
+                app_data.args.detach_keys.clone(),
+                app_data.get_selected_container_id_state_name(),
+                app_data.is_windows(),
+            )
+        };
+        let (id, state, name) = container?;
+        if state != State::Running {
             return None;
         }
+        let session = Session::Exec(ExecOptions::new(windows, entry).ok()?);
+        let shell = entry.is_none_or(|i| i.shell);
 
-        let use_cli = app_data.lock().args.use_cli;
-        let container = app_data.lock().get_selected_container_id_state_name();
-
-        if let Some((id, state, _)) = container {
-            if state == State::Running {
-                if tty_readable() && !use_cli {
-                    if let Ok(exec) = docker
-                        .create_exec(
-                            id.get(),
-                            CreateExecOptions {
-                                attach_stdout: Some(true),
-                                attach_stderr: Some(true),
-                                cmd: Some(vec![command::PWD]),
-                                ..Default::default()
-                            },
-                        )
-                        .await
-                    {
-                        if let Ok(StartExecResults::Attached { mut output, .. }) =
-                            docker.start_exec(&exec.id, None).await
-                        {
-                            if let Some(Ok(msg)) = output.next().await {
-                                if !msg.to_string().starts_with(OCI_ERROR) {
-                                    return Some(Self::Internal((id.clone(), Arc::clone(docker))));
-                                }
-                            }
-                        }
-                    }
-                }
+        if tty_readable()
+            && !use_cli
+            && (!shell || Self::probe_internal(docker, &id, windows).await)
+        {
+            return Some(Self::Internal {
+                detach_keys,
+                docker: Arc::clone(docker),
+                id,
+                log_dir,
+                name,
+                session,
+            });
+        }
 
-                if let Ok(output) = std::process::Command::new(command::DOCKER)
-                    .args([command::EXEC, id.get(), command::PWD])
-                    .output()
-                {
-                    if let Ok(output) = String::from_utf8(output.stdout) {
-                        if !output.starts_with(OCI_ERROR) {
-                            return Some(Self::External(id.clone()));
-                        }
-                    }
-                }
+        // A session of the docker cli can't be recorded
+        if log_dir.is_some() {
+            return None;
+        }
+        Self::probe_external(&id, windows, shell).then_some(Self::External {
+            detach_keys,
+            id,
+            name,
+            session,
+        })
+    }
+
+    /// Test if we can attach to the selected container, via the Internal method, else by the External, which needs the docker cli
+    /// If the container is oxker, or isn't running, it will return None
+    pub async fn attach(app_data: &Arc<Mutex<AppData>>, docker: &Arc<Docker>) -> Option<Self> {
+        let (use_cli, log_dir, detach_keys, container) = {
+            let app_data = app_data.lock();
+            if app_data.is_oxker() {
+                return None;
             }
+            (
+                app_data.args.use_cli,
+                app_data.args.exec_log.clone(),
+                app_data.args.detach_keys.clone(),
+                app_data.get_selected_container_id_state_name(),
+            )
+        };
+        let (id, state, name) = container?;
+        if state != State::Running {
+            return None;
         }
-        None
+        let tty = docker
+            .inspect_container(id.get(), None)
+            .await
+            .ok()?
+            .config
+            .and_then(|i| i.tty)
+            .unwrap_or_default();
+        let session = Session::Attach { tty };
+        if tty_readable() && !use_cli {
+            return Some(Self::Internal {
+                detach_keys,
+                docker: Arc::clone(docker),
+                id,
+                log_dir,
+                name,
+                session,
+            });
+        }
+        if log_dir.is_some() {
+            return None;
+        }
+        std::process::Command::new(command::DOCKER)
+            .args([command::INSPECT, id.get()])
+            .output()
+            .is_ok_and(|i| i.status.success())
+            .then_some(Self::External {
+                detach_keys,
+                id,
+                name,
+                session,
+            })
     }
 
-    /// exec into the container using the external docker cli, the result it just piped into oxker
-    fn exec_external(id: &ContainerId) {
+    /// exec into, or attach to, the container using the external docker cli, the result it just piped into oxker
+    fn external(id: &ContainerId, name: &str, detach_keys: &DetachKeys, session: &Session) {
         let mut stdout = std::io::stdout();
         stdout.write_all(CURSOR_POS.as_bytes()).ok();
+        stdout
+            .write_all(header(session, name, detach_keys, false).as_bytes())
+            .ok();
+        stdout.flush().ok();
+        let detach_keys = detach_keys.to_docker();
+        let args = match session {
+            Session::Attach { .. } => vec![
+                command::ATTACH,
+                command::NO_SIG_PROXY,
+                command::DETACH_KEYS,
+                &detach_keys,
+                id.get(),
+            ],
+            Session::Exec(options) => [
+                command::EXEC,
+                command::IT,
+                command::DETACH_KEYS,
+                &detach_keys,
+            ]
+            .into_iter()
+            .chain(options.docker_args())
+            .chain([id.get()])
+            .chain(options.cmd.iter().map(String::as_str))
+            .collect(),
+        };
         if let Ok(mut child) = std::process::Command::new(command::DOCKER)
-            .args([command::EXEC, command::IT, id.get(), command::SH])
+            .args(args)
             .stdin(std::process::Stdio::inherit())
             .stdout(std::process::Stdio::inherit())
             .stderr(std::process::Stdio::inherit())
             .spawn()
         {
-            child.wait().ok();
+            let status = child.wait().ok();
             if child.kill().is_err() {
                 std::process::exit(1)
             }
+            if matches!(session, Session::Exec(ExecOptions { wait: true, .. })) {
+                stdout
+                    .write_all(exit_message(status.and_then(|i| i.code())).as_bytes())
+                    .ok();
+                stdout.flush().ok();
+                std::io::stdin().read_line(&mut String::new()).ok();
+            }
         }
     }
 
-    /// Exec into the container via the Bollard library, stdout & stdin on different threads
-    /// Have to deal with strange output once dropped, hence the use of internal_cleanup() method
+    /// Exec into the container via the Bollard library
     async fn exec_internal(
         &self,
-        id: &ContainerId,
-        docker: &Arc<Docker>,
+        (id, docker, options): (&ContainerId, &Arc<Docker>, &ExecOptions),
         terminal_size: Option<TerminalSize>,
+        log: Option<SessionLog<BufWriter<File>>>,
     ) -> Result<(), AppError> {
-        let run = Arc::new(AtomicBool::new(true));
+        // Pass TERM through, so that terminfo based applications, e.g. vim & htop, know which escape sequences are supported
+        let term = format!(
+            "TERM={}",
+            std::env::var("TERM").unwrap_or_else(|_| DEFAULT_TERM.to_owned())
+        );
+        let env = std::iter::once(term.as_str())
+            .chain(options.env.iter().map(String::as_str))
+            .collect();
 
         if let Ok(exec_result) = docker
             .create_exec(
@@ -227,64 +852,174 @@ impl ExecMode {
                     attach_stderr: Some(false),
                     attach_stdin: Some(true),
                     tty: Some(true),
-                    cmd: Some(vec![command::SH]),
+                    cmd: Some(options.cmd.iter().map(String::as_str).collect()),
+                    env: Some(env),
+                    user: options.user.as_deref(),
+                    working_dir: options.working_dir.as_deref(),
                     ..Default::default()
                 },
             )
             .await
         {
-            if let Ok(StartExecResults::Attached {
-                mut output,
-                mut input,
-            }) = docker
+            if let Ok(StartExecResults::Attached { output, input }) = docker
                 .start_exec(
                     &exec_result.id,
+                    // The tty is set on the start, as well as the create, as the docker cli does, which Podman expects
                     Some(StartExecOptions {
                         detach: false,
+                        tty: true,
                         ..Default::default()
                     }),
                 )
                 .await
             {
-                if let Some(async_tty) = tty(Arc::clone(&run)) {
-                    let run_thread = Arc::clone(&run);
-                    tokio::spawn(async move {
-                        enable_raw_mode().ok();
-                        let mut stdout = std::io::stdout();
-                        stdout.write_all(CURSOR_POS.as_bytes()).ok();
-                        stdout.flush().ok();
+                let target = ResizeTarget::Exec(exec_result.id);
+                self.stream((output, input), docker, target, terminal_size, log)
+                    .await?;
+            } else {
+                return Err(AppError::Terminal);
+            }
+        }
+        Ok(())
+    }
 
-                        while run_thread.load(std::sync::atomic::Ordering::SeqCst) {
-                            while let Some(Ok(x)) = output.next().await {
-                                stdout.write_all(&x.into_bytes()).ok();
-                                stdout.flush().ok();
-                            }
-                            run_thread.store(false, std::sync::atomic::Ordering::SeqCst);
+    /// Attach to the main process of the container via the Bollard library, only output from now on is shown, as with `docker attach`
+    async fn attach_internal(
+        &self,
+        (id, docker, tty): (&ContainerId, &Arc<Docker>, bool),
+        terminal_size: Option<TerminalSize>,
+        log: Option<SessionLog<BufWriter<File>>>,
+    ) -> Result<(), AppError> {
+        let attached = docker
+            .attach_container(
+                id.get(),
+                Some(AttachContainerOptions::<String> {
+                    stdin: Some(true),
+                    stdout: Some(true),
+                    stderr: Some(true),
+                    stream: Some(true),
+                    logs: Some(false),
+                    detach_keys: None,
+                }),
+            )
+            .await
+            .map_err(|_| AppError::Terminal)?;
+        let target = ResizeTarget::Container {
+            id: id.clone(),
+            tty,
+        };
+        self.stream(
+            (attached.output, attached.input),
+            docker,
+            target,
+            terminal_size,
+            log,
+        )
+        .await
+    }
+
+    /// Forward the tty to the input of the session, & its output to stdout, on different threads, until the detach keys are entered, or the session ends
+    /// Have to deal with strange output once dropped, hence the use of internal_cleanup() method
+    async fn stream(
+        &self,
+        (mut output, mut input): Attached,
+        docker: &Arc<Docker>,
+        target: ResizeTarget,
+        terminal_size: Option<TerminalSize>,
+        log: Option<SessionLog<BufWriter<File>>>,
+    ) -> Result<(), AppError> {
+        let Self::Internal {
+            detach_keys,
+            name,
+            session,
+            ..
+        } = self
+        else {
+            return Ok(());
+        };
+        let header = header(session, name, detach_keys, log.is_some());
+        let log = log.map(|i| Arc::new(Mutex::new(i)));
+        let run = Arc::new(AtomicBool::new(true));
+        if let Some(async_tty) = tty(Arc::clone(&run)) {
+            let run_thread = Arc::clone(&run);
+            let line_feeds = matches!(target, ResizeTarget::Container { tty: false, .. });
+            let output_log = log.clone();
+            tokio::spawn(async move {
+                enable_raw_mode().ok();
+                let mut stdout = std::io::stdout();
+                stdout.write_all(CURSOR_POS.as_bytes()).ok();
+                stdout.write_all(header.as_bytes()).ok();
+                stdout.flush().ok();
+
+                while run_thread.load(std::sync::atomic::Ordering::SeqCst) {
+                    while let Some(Ok(x)) = output.next().await {
+                        let x = x.into_bytes();
+                        if let Some(log) = output_log.as_ref() {
+                            log.lock().output(&x);
                         }
-                    });
-
-                    if let Some(terminal_size) = terminal_size {
-                        docker
-                            .resize_exec(
-                                &exec_result.id,
-                                ResizeExecOptions {
-                                    height: terminal_size.height,
-                                    width: terminal_size.width,
-                                },
-                            )
-                            .await
-                            .ok();
+                        if line_feeds {
+                            stdout.write_all(&crlf(&x)).ok();
+                        } else {
+                            stdout.write_all(&x).ok();
+                        }
+                        stdout.flush().ok();
                     }
+                    run_thread.store(false, std::sync::atomic::Ordering::SeqCst);
+                }
+            });
 
-                    while let Ok(x) = async_tty.rx.recv() {
-                        input.write_all(&[x]).await.ok();
+            // A size that the daemon refused is sent again by the watcher, as though the terminal had been resized
+            let resized = if let Some(terminal_size) = terminal_size {
+                // An application already running in an attached container only redraws once resized, so it's resized twice, as docker attach does
+                if matches!(target, ResizeTarget::Container { .. }) {
+                    TerminalSize {
+                        width: terminal_size.width + 1,
+                        height: terminal_size.height + 1,
                     }
-
-                    self.internal_cleanup()?;
+                    .resize(docker, &target)
+                    .await;
                 }
+                terminal_size.resize(docker, &target).await
             } else {
-                return Err(AppError::Terminal);
+                false
+            };
+            resize_watcher(
+                Arc::clone(&run),
+                Arc::clone(docker),
+                target.clone(),
+                terminal_size.filter(|_| resized),
+            );
+
+            let mut matcher = DetachMatcher::new(detach_keys);
+            let mut detached = false;
+            while let Ok(x) = async_tty.rx.recv() {
+                let (x, detach) = matcher.feed(&x);
+                if let Some(log) = log.as_ref() {
+                    log.lock().input(&x);
+                }
+                input.write_all(&x).await.ok();
+                input.flush().await.ok();
+                if detach {
+                    run.store(false, std::sync::atomic::Ordering::SeqCst);
+                    detached = true;
+                    break;
+                }
+            }
+
+            if let (Session::Exec(ExecOptions { wait: true, .. }), ResizeTarget::Exec(exec_id)) =
+                (session, &target)
+            {
+                if !detached {
+                    let exit_code = docker
+                        .inspect_exec(exec_id)
+                        .await
+                        .ok()
+                        .and_then(|i| i.exit_code);
+                    wait_for_enter(exit_code);
+                }
             }
+
+            self.internal_cleanup()?;
         }
         Ok(())
     }
@@ -294,8 +1029,8 @@ impl ExecMode {
     // afterwhich it's assumes that we're completely done with TTY
     fn internal_cleanup(&self) -> Result<(), AppError> {
         match self {
-            Self::External(_) => Ok(()),
-            Self::Internal(_) => {
+            Self::External { .. } => Ok(()),
+            Self::Internal { .. } => {
                 let waiting = Arc::new(AtomicBool::new(true));
                 let waiting_thread = Arc::clone(&waiting);
 
@@ -334,12 +1069,187 @@ impl ExecMode {
 
     pub async fn run(&self, tty_size: Option<TerminalSize>) -> Result<(), AppError> {
         match self {
-            Self::External(id) => {
-                Self::exec_external(id);
+            Self::External {
+                detach_keys,
+                id,
+                name,
+                session,
+            } => {
+                Self::external(id, name, detach_keys, session);
                 Ok(())
             }
 
-            Self::Internal((id, docker)) => self.exec_internal(id, docker, tty_size).await,
+            Self::Internal {
+                docker,
+                id,
+                log_dir,
+                name,
+                session,
+                ..
+            } => {
+                // When recording, a session that can't be recorded isn't started
+                let log = match log_dir {
+                    Some(dir) => match SessionLog::create(dir, name, session, tty_size) {
+                        Ok(log) => Some(log),
+                        Err(_) => return Err(AppError::ExecLog),
+                    },
+                    None => None,
+                };
+                match session {
+                    Session::Exec(options) => {
+                        self.exec_internal((id, docker, options), tty_size, log)
+                            .await
+                    }
+                    Session::Attach { tty } => {
+                        self.attach_internal((id, docker, *tty), tty_size, log)
+                            .await
+                    }
+                }
+            }
         }
     }
 }
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::{
+        command::split, crlf, exit_message, header, take_utf8, DetachKeys, DetachMatcher,
+        ExecOptions, Session, SessionLog, TerminalSize,
+    };
+    use crate::app_data::ExecEntry;
+
+    #[test]
+    /// Detach keys parsed from the docker format, invalid keys return an error
+    fn test_exec_detach_keys_parse() {
+        let keys = "ctrl-p,ctrl-q".parse::<DetachKeys>().unwrap();
+        assert_eq!(keys, DetachKeys::default());
+        assert_eq!(keys.to_docker(), "ctrl-p,ctrl-q");
+
+        let keys = "ctrl-@, ctrl-], x".parse::<DetachKeys>().unwrap();
+        assert_eq!(keys.bytes, [0x00, 0x1d, b'x']);
+        assert_eq!(keys.to_string(), "ctrl-@ ctrl-] x");
+
+        assert!("ctrl-1".parse::<DetachKeys>().is_err());
+        assert!("ctrl-pq".parse::<DetachKeys>().is_err());
+        assert!("xy".parse::<DetachKeys>().is_err());
+        assert!("".parse::<DetachKeys>().is_err());
+    }
+
+    #[test]
+    /// Partial sequences held back, and forwarded once broken, the full sequence detaches, even when split across reads
+    fn test_exec_detach_matcher() {
+        let keys = DetachKeys::default();
+        let mut matcher = DetachMatcher::new(&keys);
+        assert_eq!(matcher.feed(b"ls\r"), (b"ls\r".to_vec(), false));
+        assert_eq!(matcher.feed(&[0x10]), (vec![], false));
+        assert_eq!(matcher.feed(b"a"), (vec![0x10, b'a'], false));
+        assert_eq!(matcher.feed(&[0x10, 0x10]), (vec![0x10], false));
+        assert_eq!(matcher.feed(&[0x11, b'b']), (vec![], true));
+    }
+
+    #[test]
+    /// The header names the kind of session
+    fn test_exec_header() {
+        let keys = DetachKeys::default();
+        assert_eq!(
+            header(&Session::Attach { tty: true }, "web", &keys, false),
+            "\x1B[7m oxker attach: web - detach with ctrl-p ctrl-q \x1B[0m\r\n"
+        );
+        assert!(
+            header(&Session::Exec(ExecOptions::default()), "web", &keys, true)
+                .contains(" oxker exec: web - detach with ctrl-p ctrl-q - recorded ")
+        );
+    }
+
+    #[test]
+    /// Bare line feeds get a carriage return, those that already have one are unchanged
+    fn test_exec_crlf() {
+        assert_eq!(crlf(b"a\nb\r\n\n"), b"a\r\nb\r\n\r\n");
+        assert_eq!(crlf(b"\nx"), b"\r\nx");
+    }
+
+    #[test]
+    /// A character split across chunks is kept until it's complete, invalid bytes are replaced
+    fn test_exec_take_utf8() {
+        let mut pending = "aé".as_bytes()[..2].to_vec();
+        assert_eq!(take_utf8(&mut pending), "a");
+        pending.push("é".as_bytes()[1]);
+        assert_eq!(take_utf8(&mut pending), "é");
+        assert!(pending.is_empty());
+        let mut pending = vec![b'x', 0xff, b'y'];
+        assert_eq!(take_utf8(&mut pending), "x\u{fffd}y");
+    }
+
+    #[test]
+    /// The header has the terminal size, & title, input, & output, events are written as each is received
+    fn test_exec_session_log() {
+        let size = TerminalSize {
+            width: 120,
+            height: 40,
+        };
+        let mut log =
+            SessionLog::new(vec![], Some(size), "oxker exec: web", 1_700_000_000).unwrap();
+        log.input(b"ls\r");
+        log.output(b"bin\r\n\x1b[0m");
+        log.output(&"é".as_bytes()[..1]);
+
+        let output = String::from_utf8(log.writer).unwrap();
+        let lines = output.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(
+            lines[0],
+            r#"{"height":40,"timestamp":1700000000,"title":"oxker exec: web","version":2,"width":120}"#
+        );
+        assert!(lines[1].ends_with(r#","i","ls\r"]"#));
+        assert!(lines[2].ends_with(r#","o","bin\r\n\u001b[0m"]"#));
+    }
+
+    #[test]
+    /// Arguments are split as a shell would, an unclosed quote is an error
+    fn test_exec_split() {
+        assert_eq!(
+            split(r#"/app/server --name 'a b' "c \"d\" \e" f\ g ''"#).unwrap(),
+            ["/app/server", "--name", "a b", r#"c "d" \e"#, "f g", ""]
+        );
+        assert_eq!(split("  ").unwrap(), Vec::<String>::new());
+        assert!(split("echo 'a").is_none());
+        assert!(split("echo \"a").is_none());
+        assert!(split("echo \\").is_none());
+    }
+
+    #[test]
+    /// A command without the shell is split, & waited for, the options become docker exec flags
+    fn test_exec_options() {
+        let entry = ExecEntry {
+            command: "/busybox/ls -la".to_owned(),
+            env: "A=1 B=2".to_owned(),
+            shell: false,
+            user: " 65532 ".to_owned(),
+            working_dir: String::new(),
+        };
+        let options = ExecOptions::new(false, Some(&entry)).unwrap();
+        assert_eq!(options.cmd, ["/busybox/ls", "-la"]);
+        assert!(options.wait);
+        assert_eq!(
+            options.docker_args(),
+            ["-u", "65532", "-e", "A=1", "-e", "B=2"]
+        );
+
+        let options = ExecOptions::new(false, Some(&ExecEntry::shell("df -h"))).unwrap();
+        assert_eq!(options.cmd[..2], ["sh", "-c"]);
+        assert!(!options.wait);
+        assert!(options.docker_args().is_empty());
+        assert_eq!(ExecOptions::new(false, None).unwrap().cmd, ["sh"]);
+
+        let entry = ExecEntry {
+            env: "=1".to_owned(),
+            ..ExecEntry::shell("ls")
+        };
+        assert!(ExecOptions::new(false, Some(&entry)).is_err());
+        assert_eq!(
+            exit_message(Some(3)),
+            "\r\n[exit code 3] press enter to return to oxker"
+        );
+    }
+}
@@ -0,0 +1,54 @@
+use std::time::{Duration, Instant};
+
+/// Maximum time between two clicks, on the same cell, for them to count as a double click
+const DOUBLE_CLICK: Duration = Duration::from_millis(400);
+
+/// Action to take when a container row is clicked, set with `--click`, `--double-click`, & `--middle-click`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum ClickAction {
+    /// Open the lowest published port of the container in a web browser
+    Browser,
+    /// Select the container, and focus the logs panel
+    Logs,
+    /// Select the container, and focus the docker commands panel
+    Menu,
+    /// Do nothing
+    None,
+    /// Select the container
+    Select,
+}
+
+/// Crossterm doesn't report double clicks, so keep track of the previous click
+#[derive(Debug, Default)]
+pub struct ClickTracker {
+    last: Option<(Instant, u16, u16)>,
+}
+
+impl ClickTracker {
+    /// Register a left click, returns true if it completes a double click, a triple click is a double click followed by a single click
+    pub fn click(&mut self, column: u16, row: u16) -> bool {
+        let double = matches!(self.last, Some((time, x, y)) if x == column && y == row && time.elapsed() <= DOUBLE_CLICK);
+        self.last = if double {
+            None
+        } else {
+            Some((Instant::now(), column, row))
+        };
+        double
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ClickTracker;
+
+    #[test]
+    /// Two clicks on the same cell is a double click, a click elsewhere resets it
+    fn test_click_tracker() {
+        let mut tracker = ClickTracker::default();
+        assert!(!tracker.click(1, 1));
+        assert!(tracker.click(1, 1));
+        assert!(!tracker.click(1, 1));
+        assert!(!tracker.click(2, 1));
+        assert!(tracker.click(2, 1));
+    }
+}
@@ -0,0 +1,149 @@
+#[cfg(unix)]
+use std::path::Path;
+
+#[cfg(unix)]
+use tokio::{
+    io::{AsyncBufReadExt, AsyncWriteExt, BufReader},
+    net::{UnixListener, UnixStream},
+    sync::mpsc::Sender,
+};
+
+#[cfg(unix)]
+use super::InputMessages;
+
+/// A command sent to the `--control-socket`, one per line
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ControlCommand {
+    /// Switch the host tab, None is the merged view of all hosts
+    Host(Option<String>),
+    /// Save the logs of the given container, or of the selected container, as with ( s )
+    SaveLogs(Option<String>),
+    /// Select a container by name, id, or `host/project/service` path, as with `--select`
+    Select(String),
+}
+
+impl ControlCommand {
+    /// Parse a line, `host name|all`, `select container`, or `save-logs [container]`
+    pub fn parse(line: &str) -> Result<Self, String> {
+        let line = line.trim();
+        let (command, value) = line
+            .split_once(char::is_whitespace)
+            .map_or((line, ""), |(command, value)| (command, value.trim()));
+        let value = Some(value.to_owned()).filter(|i| !i.is_empty());
+        match (command, value) {
+            ("host", Some(host)) if host == "all" => Ok(Self::Host(None)),
+            ("host", Some(host)) => Ok(Self::Host(Some(host))),
+            ("select", Some(name)) => Ok(Self::Select(name)),
+            ("save-logs", name) => Ok(Self::SaveLogs(name)),
+            ("host" | "select", None) => Err(format!("{command}: expected a name")),
+            _ => Err(format!("{command}: expected host, select, or save-logs")),
+        }
+    }
+}
+
+/// Listen on the control socket, a stale socket left by an earlier oxker is replaced, but a socket that is still listened on, or any other file, is left as is
+#[cfg(unix)]
+pub fn bind(path: &Path) -> std::io::Result<UnixListener> {
+    use std::os::unix::fs::{FileTypeExt, PermissionsExt};
+    if std::fs::symlink_metadata(path).is_ok_and(|i| i.file_type().is_socket()) {
+        if std::os::unix::net::UnixStream::connect(path).is_ok() {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::AddrInUse,
+                "already in use",
+            ));
+        }
+        std::fs::remove_file(path)?;
+    }
+    let listener = UnixListener::bind(path)?;
+    // Any local user that can connect could switch hosts, or save logs, so only the owner can
+    std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o600))?;
+    Ok(listener)
+}
+
+/// Read the commands of a single connection, each line is answered with `ok`, or `error: reason`, once the command has been applied
+#[cfg(unix)]
+async fn connection(stream: UnixStream, input_tx: Sender<InputMessages>) {
+    let (reader, mut writer) = stream.into_split();
+    let mut lines = BufReader::new(reader).lines();
+    while let Ok(Some(line)) = lines.next_line().await {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let reply = match ControlCommand::parse(&line) {
+            Ok(command) => {
+                let (sx, rx) = tokio::sync::oneshot::channel();
+                if input_tx
+                    .send(InputMessages::Control(command, sx))
+                    .await
+                    .is_err()
+                {
+                    return;
+                }
+                match rx.await {
+                    Ok(Ok(())) => "ok\n".to_owned(),
+                    Ok(Err(e)) => format!("error: {e}\n"),
+                    Err(_) => return,
+                }
+            }
+            Err(e) => format!("error: {e}\n"),
+        };
+        if writer.write_all(reply.as_bytes()).await.is_err() {
+            return;
+        }
+    }
+}
+
+/// Accept connections to the control socket, until oxker closes
+#[cfg(unix)]
+pub async fn serve(listener: UnixListener, input_tx: Sender<InputMessages>) {
+    while let Ok((stream, _)) = listener.accept().await {
+        tokio::spawn(connection(stream, input_tx.clone()));
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::ControlCommand;
+
+    #[test]
+    /// Commands are parsed, with `all` as the merged host view, and the container of save-logs is optional
+    fn test_control_parse() {
+        assert_eq!(
+            ControlCommand::parse("host all"),
+            Ok(ControlCommand::Host(None))
+        );
+        assert_eq!(
+            ControlCommand::parse(" host  ssh://user@remote \n"),
+            Ok(ControlCommand::Host(Some("ssh://user@remote".to_owned())))
+        );
+        assert_eq!(
+            ControlCommand::parse("select web"),
+            Ok(ControlCommand::Select("web".to_owned()))
+        );
+        assert_eq!(
+            ControlCommand::parse("save-logs"),
+            Ok(ControlCommand::SaveLogs(None))
+        );
+        assert_eq!(
+            ControlCommand::parse("save-logs db"),
+            Ok(ControlCommand::SaveLogs(Some("db".to_owned())))
+        );
+        assert!(ControlCommand::parse("select").is_err());
+        assert!(ControlCommand::parse("quit").is_err());
+        assert!(ControlCommand::parse("").is_err());
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    /// Only the owner can connect to the socket
+    async fn test_control_bind_permissions() {
+        use std::os::unix::fs::PermissionsExt;
+        let path = std::env::temp_dir().join(format!("oxker-test-{}.sock", uuid::Uuid::new_v4()));
+        let listener = super::bind(&path).unwrap();
+        let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+        drop(listener);
+        std::fs::remove_file(&path).ok();
+    }
+}
@@ -1,7 +1,14 @@
 use crossterm::event::{KeyCode, KeyModifiers, MouseEvent};
+use tokio::sync::oneshot::Sender;
 
-#[derive(Debug, Clone, Copy)]
+use super::ControlCommand;
+
+#[derive(Debug)]
 pub enum InputMessages {
     ButtonPress((KeyCode, KeyModifiers)),
+    /// A command from the `--control-socket`, & where to send the result, once the command has been applied
+    Control(ControlCommand, Sender<Result<(), String>>),
+    /// The terminal gained, true, or lost, false, focus, only sent by terminals that support focus events
+    Focus(bool),
     MouseEvent(MouseEvent),
 }
@@ -8,142 +8,2260 @@ use std::{
     time::SystemTime,
 };
 
-use bollard::{container::LogsOptions, Docker};
+use bollard::{
+    container::{LogOutput, LogsOptions},
+    Docker,
+};
 use cansi::v3::categorise_text;
 use crossterm::{
     event::{DisableMouseCapture, KeyCode, KeyModifiers, MouseButton, MouseEvent, MouseEventKind},
     execute,
 };
-use futures_util::StreamExt;
 use parking_lot::Mutex;
 use ratatui::layout::Rect;
 use tokio::sync::mpsc::{Receiver, Sender};
 use uuid::Uuid;
 
-mod message;
-use crate::{
-    app_data::{AppData, DockerControls, Header},
-    app_error::AppError,
-    docker_data::DockerMessage,
-    exec::{tty_readable, ExecMode},
-    ui::{DeleteButton, GuiState, SelectablePanel, Status, Ui},
-};
-pub use message::InputMessages;
+mod click;
+mod control;
+mod message;
+use crate::{
+    app_data::{
+        redact, AppData, ContainerId, DockerControls, ExecEntry, Header, IoView, JsonView,
+        LogFilter, LogSearch, LogTime, TopMode,
+    },
+    app_error::AppError,
+    config_file,
+    docker_data::{DockerMessage, READ_ONLY_TEXT},
+    exec::{tty_readable, ExecMode},
+    ui::{
+        log_sanitizer, Capture, CommandForm, DeleteButton, DeletePreview, ExportFormat, FilterForm,
+        Finder, FinderRow, GuiState, KillPicker, LogExport, NoteForm, Pane, PaneLayout, RunForm,
+        ScaleForm, ScheduleForm, SelectablePanel, Status, Ui,
+    },
+};
+pub use click::ClickAction;
+use click::ClickTracker;
+pub use control::ControlCommand;
+#[cfg(unix)]
+pub use control::{bind as control_bind, serve as control_serve};
+pub use message::InputMessages;
+
+/// Handle all input events
+#[derive(Debug)]
+pub struct InputHandler {
+    app_data: Arc<Mutex<AppData>>,
+    clicks: ClickTracker,
+    /// The border between the containers, & the logs, is being dragged
+    dragging: bool,
+    docker_tx: Sender<DockerMessage>,
+    gui_state: Arc<Mutex<GuiState>>,
+    is_running: Arc<AtomicBool>,
+    mouse_capture: bool,
+    rec: Receiver<InputMessages>,
+}
+
+impl InputHandler {
+    /// Initialize self, and running the message handling loop
+    pub async fn init(
+        app_data: Arc<Mutex<AppData>>,
+        rec: Receiver<InputMessages>,
+        docker_tx: Sender<DockerMessage>,
+        gui_state: Arc<Mutex<GuiState>>,
+        is_running: Arc<AtomicBool>,
+    ) {
+        let mouse_capture = app_data.lock().args.mouse;
+        let mut inner = Self {
+            app_data,
+            clicks: ClickTracker::default(),
+            dragging: false,
+            docker_tx,
+            gui_state,
+            is_running,
+            rec,
+            mouse_capture,
+        };
+        inner.start().await;
+    }
+
+    /// check for incoming messages
+    async fn start(&mut self) {
+        while let Some(message) = self.rec.recv().await {
+            match message {
+                InputMessages::ButtonPress(key) => {
+                    if !self.resume().await {
+                        self.button_press(key.0, key.1).await;
+                    }
+                }
+                InputMessages::Control(command, sx) => {
+                    let result = self.control(command).await;
+                    if let Err(e) = result.as_ref() {
+                        self.gui_state.lock().set_info_box(e);
+                    }
+                    sx.send(result).ok();
+                }
+                InputMessages::Focus(true) => {
+                    self.resume().await;
+                }
+                InputMessages::Focus(false) => {
+                    if self.app_data.lock().args.auto_standby {
+                        self.gui_state.lock().status_push(Status::Standby);
+                    }
+                }
+                InputMessages::MouseEvent(_) if self.resume().await => (),
+                InputMessages::MouseEvent(mouse_event) => {
+                    if !self.gui_state.lock().status_contains(&[
+                        Status::Error,
+                        Status::Help,
+                        Status::Confirm,
+                    ]) {
+                        self.mouse_press(mouse_event);
+                    }
+                    let contains_confirm =
+                        self.gui_state.lock().status_contains(&[Status::Confirm]);
+                    if contains_confirm {
+                        self.button_intersect(mouse_event).await;
+                    }
+                }
+            }
+            self.app_data.lock().update_recent();
+            if !self.is_running.load(Ordering::SeqCst) {
+                break;
+            }
+        }
+    }
+
+    /// Leave standby, updating straight away, rather than waiting for the next standby update
+    /// Returns true if was in standby, so that the key, or click, that resumed isn't also handled
+    async fn resume(&self) -> bool {
+        let standby = {
+            let mut gui_state = self.gui_state.lock();
+            let standby = gui_state.status_contains(&[Status::Standby]);
+            gui_state.status_del(Status::Standby);
+            standby
+        };
+        if standby {
+            self.docker_tx.send(DockerMessage::Update).await.ok();
+        }
+        standby
+    }
+
+    /// Handle a command from the control socket, problems are shown in the info box
+    async fn control(&mut self, command: ControlCommand) -> Result<(), String> {
+        match command {
+            ControlCommand::Host(host) => {
+                if self.app_data.lock().set_host_tab(host.as_deref()) {
+                    self.gui_state
+                        .lock()
+                        .set_info_box(&format!("host: {}", host.as_deref().unwrap_or("all hosts")));
+                    Ok(())
+                } else {
+                    Err(format!("unknown host: {}", host.unwrap_or_default()))
+                }
+            }
+            ControlCommand::Select(target) => {
+                if self.app_data.lock().select_container_by_target(&target) {
+                    Ok(())
+                } else {
+                    Err(format!("no container {target}"))
+                }
+            }
+            ControlCommand::SaveLogs(Some(target))
+                if !self.app_data.lock().select_container_by_target(&target) =>
+            {
+                Err(format!("no container {target}"))
+            }
+            ControlCommand::SaveLogs(_) => {
+                // Saved straight away, as every line of plain text, there's no one to choose in the popup
+                let export = self
+                    .log_export()
+                    .ok_or_else(|| "no save dir, or no container selected".to_owned())?;
+                self.save_logs(&export).await
+            }
+        }
+    }
+
+    /// Sort the containers by a given header
+    fn sort(&self, selected_header: Header) {
+        self.app_data.lock().set_sort_by_header(selected_header);
+    }
+
+    /// Cycle the top-like sorting mode, and display the new mode in the info box
+    fn t_key(&self) {
+        let text = {
+            let mut app_data = self.app_data.lock();
+            app_data.toggle_top_mode();
+            match app_data.get_top_mode() {
+                Some(TopMode::Cpu) => "top mode: cpu",
+                Some(TopMode::Memory) => "top mode: memory",
+                None => "top mode: off",
+            }
+        };
+        self.gui_state.lock().set_info_box(text);
+    }
+
+    /// Cycle how the timestamps of log lines are shown, and display the new mode in the info box
+    fn shift_t_key(&self) {
+        let text = {
+            let mut app_data = self.app_data.lock();
+            app_data.cycle_log_time();
+            match app_data.get_log_time() {
+                LogTime::Off => "timestamps: off",
+                LogTime::Utc => "timestamps: utc",
+                LogTime::Local => "timestamps: local",
+            }
+        };
+        self.gui_state.lock().set_info_box(text);
+    }
+
+    /// Cycle the group layout of the containers panel
+    fn g_key(&self) {
+        let text = {
+            let mut app_data = self.app_data.lock();
+            app_data.toggle_group_by();
+            app_data
+                .get_group_by()
+                .map_or_else(|| "group by: off".to_owned(), |i| format!("group by: {i}"))
+        };
+        self.gui_state.lock().set_info_box(&text);
+    }
+
+    /// Show the host switcher, only when monitoring multiple hosts
+    fn v_key(&self) {
+        let picker = self.app_data.lock().host_picker();
+        if picker.is_some() {
+            self.gui_state.lock().set_host_picker(picker);
+        }
+    }
+
+    /// Select a host tab in the host switcher, ( v ) cycles the selection, ( enter ) switches to it
+    fn hosts_key(&self, key_code: KeyCode) {
+        let mut gui_state = self.gui_state.lock();
+        let Some(picker) = gui_state.get_host_picker() else {
+            return;
+        };
+        match key_code {
+            KeyCode::Up | KeyCode::Char('k' | 'K') => picker.previous(),
+            KeyCode::Down | KeyCode::Char('j' | 'J') => picker.next(),
+            KeyCode::Char('v' | 'V') => picker.cycle(),
+            KeyCode::Esc => gui_state.status_del(Status::Hosts),
+            KeyCode::Enter => {
+                let host = picker.chosen().map(|i| i.host.clone());
+                gui_state.status_del(Status::Hosts);
+                drop(gui_state);
+                if let Some(host) = host {
+                    self.app_data.lock().set_host_tab(host.as_deref());
+                    let text = format!("host: {}", host.as_deref().unwrap_or("all hosts"));
+                    self.gui_state.lock().set_info_box(&text);
+                }
+            }
+            _ => (),
+        }
+    }
+
+    /// Jump back to the most recently viewed container, pressing it again flips back, as alt-tab would
+    fn backspace_key(&self) {
+        let picker = self.app_data.lock().recent_picker();
+        match picker.as_ref().and_then(|i| i.chosen()) {
+            Some(row) => self.select_recent(&row.id),
+            None => self
+                .gui_state
+                .lock()
+                .set_info_box("no recently viewed containers"),
+        }
+    }
+
+    /// Show the recently viewed containers
+    fn backslash_key(&self) {
+        let picker = self.app_data.lock().recent_picker();
+        if picker.is_some() {
+            self.gui_state.lock().set_recent_picker(picker);
+        } else {
+            self.gui_state
+                .lock()
+                .set_info_box("no recently viewed containers");
+        }
+    }
+
+    /// Select a recently viewed container, ( \ ) cycles the selection, ( enter ) jumps to it
+    fn recent_key(&self, key_code: KeyCode) {
+        let mut gui_state = self.gui_state.lock();
+        let Some(picker) = gui_state.get_recent_picker() else {
+            return;
+        };
+        match key_code {
+            KeyCode::Up | KeyCode::Char('k' | 'K') => picker.previous(),
+            KeyCode::Down | KeyCode::Char('j' | 'J') => picker.next(),
+            KeyCode::Char('\\') => picker.cycle(),
+            KeyCode::Esc => gui_state.status_del(Status::Recent),
+            KeyCode::Enter => {
+                let id = picker.chosen().map(|i| i.id.clone());
+                gui_state.status_del(Status::Recent);
+                drop(gui_state);
+                if let Some(id) = id {
+                    self.select_recent(&id);
+                }
+            }
+            _ => (),
+        }
+    }
+
+    /// Jump to a recently viewed container, the containers panel is selected, so that its control keys apply
+    fn select_recent(&self, id: &ContainerId) {
+        let name = self.app_data.lock().select_recent(id);
+        let mut gui_state = self.gui_state.lock();
+        match name {
+            Some(name) => {
+                gui_state.set_selected_panel(SelectablePanel::Containers);
+                gui_state.set_info_box(&format!("viewing: {name}"));
+            }
+            None => gui_state.set_info_box("container no longer exists"),
+        }
+    }
+
+    /// Pick a docker context at startup, ( esc ) connects to the default context
+    fn contexts_key(&self, key_code: KeyCode) {
+        let mut gui_state = self.gui_state.lock();
+        let Some(picker) = gui_state.get_context_picker() else {
+            return;
+        };
+        match key_code {
+            KeyCode::Up | KeyCode::Char('k' | 'K') => picker.previous(),
+            KeyCode::Down | KeyCode::Char('j' | 'J') => picker.next(),
+            KeyCode::Esc => gui_state.status_del(Status::Contexts),
+            KeyCode::Enter => {
+                let context = picker.chosen().cloned();
+                gui_state.status_del(Status::Contexts);
+                drop(gui_state);
+                if let Some(context) = context {
+                    self.app_data.lock().args.set_docker_context(&context);
+                    let text = format!("context: {}", context.name);
+                    self.gui_state.lock().set_info_box(&text);
+                }
+            }
+            _ => (),
+        }
+    }
+
+    /// Expand, or collapse, the selected group in the containers panel
+    fn group_expand(&self, expand: Option<bool>) {
+        if self.gui_state.lock().get_selected_panel() == SelectablePanel::Containers {
+            self.app_data.lock().set_group_expanded(expand);
+        }
+    }
+
+    /// Send a quit message to docker, to abort all spawns, if an error is returned, set is_running to false here instead
+    /// If gui_status is Error or Init, then just set the is_running to false immediately, for a quicker exit
+    async fn quit(&self) {
+        let error_init = self
+            .gui_state
+            .lock()
+            .status_contains(&[Status::Error, Status::Init]);
+        if error_init || self.docker_tx.send(DockerMessage::Quit).await.is_err() {
+            self.is_running
+                .store(false, std::sync::atomic::Ordering::SeqCst);
+        }
+    }
+
+    /// This is executed from the Confirm dialog, and will send an internal message to actually send the control to the given container
+    /// The dialog is closed once a delete has completed, every other control closes it straight away, as does a control sent to the marked containers, which are unmarked
+    async fn confirm(&self) {
+        if !self.gui_state.lock().delete_confirm_ready() {
+            return;
+        }
+        let batch = self.gui_state.lock().get_batch_confirm();
+        if let Some((control, ids)) = batch {
+            self.gui_state.lock().set_batch_confirm(None);
+            self.app_data.lock().clear_marked();
+            self.docker_tx
+                .send(DockerMessage::BatchControl(control, ids))
+                .await
+                .ok();
+            return;
+        }
+        let confirm = self.gui_state.lock().get_confirm();
+        if let Some((control, id)) = confirm {
+            if control != DockerControls::Delete {
+                self.gui_state.lock().set_confirm(None);
+            }
+            self.send_control(control, id).await;
+        }
+    }
+
+    /// This is executed from the Confirm dialog, and will clear the confirm information (removes id and closes panel)
+    fn clear_confirm(&self) {
+        let mut gui_state = self.gui_state.lock();
+        gui_state.set_confirm(None);
+        gui_state.set_batch_confirm(None);
+    }
+
+    /// Mark, or unmark, the selected container, a control is then sent to every marked container
+    fn space_key(&self) {
+        if self.gui_state.lock().get_selected_panel() == SelectablePanel::Containers {
+            self.app_data.lock().toggle_marked();
+        }
+    }
+
+    /// Confirm a control for every marked container that it can be sent to, in their current state, every batch control is confirmed
+    /// With `--dry-run`, deleting them is previewed, & needs a second confirmation, as when deleting a single container
+    async fn marked_control(&self, control: DockerControls) {
+        let marked = self.app_data.lock().get_marked(control);
+        if marked.is_empty() {
+            self.gui_state
+                .lock()
+                .set_info_box(&format!("no marked containers to {control}"));
+            return;
+        }
+        let ids = marked.into_iter().map(|(id, _)| id).collect::<Vec<_>>();
+        let preview = self.app_data.lock().args.dry_run && control == DockerControls::Delete;
+        {
+            let mut gui_state = self.gui_state.lock();
+            gui_state.set_batch_confirm(Some((control, ids.clone())));
+            if preview {
+                gui_state.set_delete_preview(Some(DeletePreview {
+                    confirmed: false,
+                    size: None,
+                    volumes: 0,
+                }));
+            }
+        }
+        if preview {
+            self.docker_tx
+                .send(DockerMessage::BatchPreview(ids))
+                .await
+                .ok();
+        }
+    }
+
+    /// Show the log lines that matched a `--watch`, which marks them as seen
+    fn a_key(&self) {
+        self.app_data.lock().clear_unseen_matches();
+        self.gui_state.lock().status_push(Status::LogMatches);
+    }
+
+    /// Show the primary daemon's details, & its warnings
+    fn d_key(&self) {
+        self.gui_state.lock().status_push(Status::Daemon);
+    }
+
+    /// Show the network traffic totals of every container
+    fn u_key(&self) {
+        self.gui_state.lock().status_push(Status::Traffic);
+    }
+
+    /// Show the memory breakdown of the selected container
+    fn asterisk_key(&self) {
+        self.gui_state.lock().status_push(Status::Memory);
+    }
+
+    /// Show the cpu usage, on each core, of the selected container
+    fn close_brace_key(&self) {
+        self.gui_state.lock().status_push(Status::Cores);
+    }
+
+    /// Show the sizes of the panes, to resize, or hide, them
+    fn backtick_key(&self) {
+        self.gui_state
+            .lock()
+            .set_layout_pane(Some(Pane::Containers));
+    }
+
+    /// Validate that one can exec into a Docker container
+    async fn e_key(&self) {
+        self.exec(None).await;
+    }
+
+    /// Exec into the selected container, running its shell, or, if given, a command, with the options of the command form
+    async fn exec(&self, entry: Option<&ExecEntry>) {
+        self.session(false, entry).await;
+    }
+
+    /// Attach to the main process of the selected container, as with `docker attach`
+    async fn attach(&self) {
+        self.session(true, None).await;
+    }
+
+    /// Start an exec session, with its command, or attach to the container
+    async fn session(&self, attach: bool, entry: Option<&ExecEntry>) {
+        if self.app_data.lock().args.read_only {
+            self.gui_state.lock().set_info_box(READ_ONLY_TEXT);
+            return;
+        }
+        let is_oxker = self.app_data.lock().is_oxker();
+        if !is_oxker && tty_readable() {
+            let uuid = Uuid::new_v4();
+            let handle = GuiState::start_loading_animation(&self.gui_state, uuid);
+            let (sx, rx) = tokio::sync::oneshot::channel::<Arc<Docker>>();
+            self.docker_tx.send(DockerMessage::Exec(sx)).await.ok();
+
+            if let Ok(docker) = rx.await {
+                let (mode, error) = if attach {
+                    (
+                        ExecMode::attach(&self.app_data, &docker).await,
+                        AppError::DockerAttach,
+                    )
+                } else {
+                    (
+                        ExecMode::new(&self.app_data, &docker, entry).await,
+                        AppError::DockerExec,
+                    )
+                };
+                mode.map_or_else(
+                    || {
+                        self.app_data
+                            .lock()
+                            .set_error(error, &self.gui_state, Status::Error);
+                    },
+                    |mode| {
+                        self.gui_state.lock().set_exec_mode(mode);
+                    },
+                );
+            }
+            self.gui_state.lock().stop_loading_animation(&handle, uuid);
+        }
+    }
+
+    /// Open the command form for the selected container, with the commands run in containers of its image before
+    fn o_key(&self) {
+        let app_data = self.app_data.lock();
+        if app_data.is_oxker() || !tty_readable() {
+            return;
+        }
+        let form = app_data.get_selected_container().map(|i| {
+            CommandForm::new(
+                i.name.get().to_owned(),
+                i.image.get().to_owned(),
+                app_data.get_exec_history(i.image.get()).to_vec(),
+            )
+        });
+        drop(app_data);
+        if form.is_some() {
+            self.gui_state.lock().set_command_form(form);
+        }
+    }
+
+    /// Edit the command form, chars are typed into the selected field, so none of the usual keys, including q, apply
+    /// ( enter ) records the command in the image's history, and then runs it, invalid options keep the form open, showing the error
+    async fn command_key(&self, key_code: KeyCode) {
+        let submit = {
+            let mut gui_state = self.gui_state.lock();
+            let Some(form) = gui_state.get_command_form() else {
+                return;
+            };
+            let mut submit = None;
+            match key_code {
+                KeyCode::Char(c) => form.push(c),
+                KeyCode::Backspace => form.pop(),
+                KeyCode::Up => form.older(),
+                KeyCode::Down => form.newer(),
+                KeyCode::Tab => form.next_field(),
+                KeyCode::BackTab => form.previous_field(),
+                KeyCode::Esc => gui_state.status_del(Status::Command),
+                KeyCode::Enter => {
+                    submit = form.submit().map(|i| (form.image.clone(), i));
+                    if submit.is_some() {
+                        gui_state.status_del(Status::Command);
+                    }
+                }
+                _ => (),
+            }
+            drop(gui_state);
+            submit
+        };
+        if let Some((image, entry)) = submit {
+            let saved = self.app_data.lock().record_exec(&image, &entry);
+            if let Err(e) = saved {
+                self.gui_state
+                    .lock()
+                    .set_info_box(&format!("unable to save exec history: {e}"));
+            }
+            self.exec(Some(&entry)).await;
+        }
+    }
+
+    /// Open the recreate with changes form for the selected container, the form is pre-filled once the container has been inspected
+    async fn r_key(&self) {
+        if self.gui_state.lock().get_selected_panel() == SelectablePanel::Services {
+            self.force_update_service().await;
+            return;
+        }
+        let is_oxker =
+            self.app_data.lock().is_oxker() || self.app_data.lock().is_oxker_in_container();
+        let option_id = self.app_data.lock().get_selected_container_id();
+        if let (false, Some(id)) = (is_oxker, option_id) {
+            self.docker_tx
+                .send(DockerMessage::RecreateForm(id))
+                .await
+                .ok();
+        }
+    }
+
+    /// List the local tags of the selected container's image, to recreate the container on a different tag
+    async fn i_key(&self) {
+        let is_oxker =
+            self.app_data.lock().is_oxker() || self.app_data.lock().is_oxker_in_container();
+        let option_id = self.app_data.lock().get_selected_container_id();
+        if let (false, Some(id)) = (is_oxker, option_id) {
+            self.docker_tx.send(DockerMessage::TagPicker(id)).await.ok();
+        }
+    }
+
+    /// Show the full docker inspect output of the selected container, as a tree
+    async fn question_key(&self) {
+        let option_id = self.app_data.lock().get_selected_container_id();
+        if let Some(id) = option_id {
+            self.docker_tx.send(DockerMessage::Inspect(id)).await.ok();
+        }
+    }
+
+    /// Show, or hide, the events pane
+    fn exclamation_key(&self) {
+        let mut gui_state = self.gui_state.lock();
+        if gui_state.status_contains(&[Status::Events]) {
+            gui_state.status_del(Status::Events);
+        } else {
+            gui_state.status_push(Status::Events);
+        }
+    }
+
+    /// Move around the inspect tree, ( enter ) expands, or collapses, the selected node
+    fn inspect_key(&self, key_code: KeyCode) {
+        let mut gui_state = self.gui_state.lock();
+        let Some(view) = gui_state.get_inspect_view() else {
+            return;
+        };
+        match key_code {
+            KeyCode::Up | KeyCode::Char('k' | 'K') => view.previous(1),
+            KeyCode::Down | KeyCode::Char('j' | 'J') => view.next(1),
+            KeyCode::PageUp => view.previous(7),
+            KeyCode::PageDown => view.next(7),
+            KeyCode::Home => view.previous(usize::MAX),
+            KeyCode::End => view.end(),
+            KeyCode::Left | KeyCode::Char('h' | 'H') => view.expand(Some(false)),
+            KeyCode::Right | KeyCode::Char('l' | 'L') => view.expand(Some(true)),
+            KeyCode::Enter | KeyCode::Char(' ') => view.expand(None),
+            KeyCode::Esc | KeyCode::Char('?') => gui_state.status_del(Status::Inspect),
+            _ => (),
+        }
+    }
+
+    /// Pick another container, & port, to check if the selected container can reach it
+    async fn c_key(&self) {
+        let option_id = self.app_data.lock().get_selected_container_id();
+        if let Some(id) = option_id {
+            self.docker_tx
+                .send(DockerMessage::ReachPicker(id))
+                .await
+                .ok();
+        }
+    }
+
+    /// Select a target in the connectivity check picker, ( enter ) runs the check
+    async fn reach_key(&self, key_code: KeyCode) {
+        let submit = {
+            let mut gui_state = self.gui_state.lock();
+            let Some(picker) = gui_state.get_reach_picker() else {
+                return;
+            };
+            let mut submit = None;
+            match key_code {
+                KeyCode::Up | KeyCode::Char('k' | 'K') => picker.previous(),
+                KeyCode::Down | KeyCode::Char('j' | 'J') => picker.next(),
+                KeyCode::Esc | KeyCode::Char('c' | 'C') => gui_state.status_del(Status::Reach),
+                KeyCode::Enter => {
+                    submit = picker.chosen().map(|target| {
+                        DockerMessage::Reach(picker.id.clone(), picker.name.clone(), target.clone())
+                    });
+                    gui_state.status_del(Status::Reach);
+                }
+                _ => (),
+            }
+            drop(gui_state);
+            submit
+        };
+        if let Some(message) = submit {
+            self.docker_tx.send(message).await.ok();
+        }
+    }
+
+    /// Show the lifecycle timeline of the selected container, over the last day
+    async fn l_key(&self) {
+        let option_id = self.app_data.lock().get_selected_container_id();
+        if let Some(id) = option_id {
+            self.docker_tx.send(DockerMessage::Timeline(id)).await.ok();
+        }
+    }
+
+    /// Preview which images the prune rules would remove, on the current host tab, or the primary host
+    async fn b_key(&self) {
+        let host = self.app_data.lock().get_host_tab().map(ToOwned::to_owned);
+        self.docker_tx
+            .send(DockerMessage::BuildCache(host))
+            .await
+            .ok();
+    }
+
+    /// Mark build cache records, ( enter ) removes the marked records, or the selected record if none are marked, with `--dry-run` a second ( enter ) is needed
+    async fn build_cache_key(&self, key_code: KeyCode) {
+        let dry_run = self.app_data.lock().args.dry_run;
+        let submit = {
+            let mut gui_state = self.gui_state.lock();
+            let Some(cache) = gui_state.get_build_cache() else {
+                return;
+            };
+            let mut submit = None;
+            match key_code {
+                KeyCode::Up | KeyCode::Char('k' | 'K') => cache.previous(),
+                KeyCode::Down | KeyCode::Char('j' | 'J') => cache.next(),
+                KeyCode::Char(' ') => cache.toggle(),
+                KeyCode::Esc | KeyCode::Char('b' | 'B') => gui_state.status_del(Status::BuildCache),
+                KeyCode::Enter => {
+                    let ids = cache.chosen();
+                    if dry_run && !cache.armed && !ids.is_empty() {
+                        cache.armed = true;
+                    } else {
+                        if !ids.is_empty() {
+                            submit = Some((cache.host.clone(), ids));
+                        }
+                        gui_state.status_del(Status::BuildCache);
+                    }
+                }
+                _ => (),
+            }
+            drop(gui_state);
+            submit
+        };
+        if let Some((host, ids)) = submit {
+            self.docker_tx
+                .send(DockerMessage::BuildCacheRemove(host, ids))
+                .await
+                .ok();
+        }
+    }
+
+    /// Show the cleanup menu, the disk usage of the current host tab, or the primary host, and what each prune would remove
+    async fn percent_key(&self) {
+        let host = self.app_data.lock().get_host_tab().map(ToOwned::to_owned);
+        self.docker_tx.send(DockerMessage::Cleanup(host)).await.ok();
+    }
+
+    /// Select a prune, ( enter ) shows its confirmation, in which ( y ) prunes, & ( n ) goes back to the menu, with `--dry-run` a second ( y ) is needed
+    async fn cleanup_key(&self, key_code: KeyCode) {
+        let dry_run = self.app_data.lock().args.dry_run;
+        let submit = {
+            let mut gui_state = self.gui_state.lock();
+            let Some(cleanup) = gui_state.get_cleanup() else {
+                return;
+            };
+            let mut submit = None;
+            if cleanup.confirm {
+                match key_code {
+                    KeyCode::Char('y' | 'Y') if dry_run && !cleanup.armed => cleanup.armed = true,
+                    KeyCode::Char('y' | 'Y') => {
+                        submit = cleanup.chosen().map(|i| (cleanup.host.clone(), i.kind));
+                        gui_state.status_del(Status::Cleanup);
+                    }
+                    KeyCode::Char('n' | 'N') | KeyCode::Esc => {
+                        cleanup.armed = false;
+                        cleanup.confirm = false;
+                    }
+                    _ => (),
+                }
+            } else {
+                match key_code {
+                    KeyCode::Up | KeyCode::Char('k' | 'K') => cleanup.previous(),
+                    KeyCode::Down | KeyCode::Char('j' | 'J') => cleanup.next(),
+                    KeyCode::Enter if !cleanup.ask() => gui_state.set_info_box("nothing to prune"),
+                    KeyCode::Esc | KeyCode::Char('%') => gui_state.status_del(Status::Cleanup),
+                    _ => (),
+                }
+            }
+            drop(gui_state);
+            submit
+        };
+        if let Some((host, kind)) = submit {
+            self.docker_tx
+                .send(DockerMessage::CleanupPrune(host, kind))
+                .await
+                .ok();
+        }
+    }
+
+    async fn p_key(&self) {
+        let host = self.app_data.lock().get_host_tab().map(ToOwned::to_owned);
+        self.docker_tx
+            .send(DockerMessage::PrunePreview(host))
+            .await
+            .ok();
+    }
+
+    /// Edit the prune rules, ( enter ) removes the matching images, with `--dry-run` a second ( enter ) is needed
+    async fn prune_key(&self, key_code: KeyCode) {
+        let dry_run = self.app_data.lock().args.dry_run;
+        let submit = {
+            let mut gui_state = self.gui_state.lock();
+            let Some(preview) = gui_state.get_prune_preview() else {
+                return;
+            };
+            let mut submit = None;
+            match key_code {
+                KeyCode::Up | KeyCode::Down | KeyCode::Char('j' | 'J' | 'k' | 'K') => {
+                    preview.next();
+                }
+                KeyCode::Left => preview.change(-1),
+                KeyCode::Right => preview.change(1),
+                KeyCode::PageDown => preview.change(-10),
+                KeyCode::PageUp => preview.change(10),
+                KeyCode::Esc | KeyCode::Char('p' | 'P') => gui_state.status_del(Status::Prune),
+                KeyCode::Enter => {
+                    if dry_run && !preview.armed {
+                        preview.armed = true;
+                    } else {
+                        let images = preview.matches().into_iter().cloned().collect::<Vec<_>>();
+                        if !images.is_empty() {
+                            submit = Some((preview.host.clone(), images));
+                        }
+                        gui_state.status_del(Status::Prune);
+                    }
+                }
+                _ => (),
+            }
+            drop(gui_state);
+            submit
+        };
+        if let Some((host, images)) = submit {
+            self.docker_tx
+                .send(DockerMessage::Prune(host, images))
+                .await
+                .ok();
+        }
+    }
+
+    /// ( r ) resets the traffic counted for the selected container, ( a ) resets every container
+    fn traffic_key(&self, key_code: KeyCode) {
+        match key_code {
+            KeyCode::Char('r' | 'R') => {
+                let mut app_data = self.app_data.lock();
+                let id = app_data.get_selected_container_id();
+                if let Some(id) = id {
+                    app_data.reset_traffic(Some(&id));
+                }
+            }
+            KeyCode::Char('a' | 'A') => self.app_data.lock().reset_traffic(None),
+            KeyCode::Esc | KeyCode::Char('u' | 'U') => {
+                self.gui_state.lock().status_del(Status::Traffic);
+            }
+            _ => (),
+        }
+    }
+
+    /// Select a pane, ( ← → ) resizes it, ( space ) hides the charts, or sizes the containers panel to the containers again, ( s ) saves the layout to the config file
+    fn layout_key(&self, key_code: KeyCode) {
+        let mut gui_state = self.gui_state.lock();
+        let Some(pane) = gui_state.get_layout_pane() else {
+            return;
+        };
+        let mut layout = gui_state.get_layout();
+        match key_code {
+            KeyCode::Up | KeyCode::Down | KeyCode::Char('j' | 'J' | 'k' | 'K') => {
+                gui_state.set_layout_pane(Some(match pane {
+                    Pane::Containers => Pane::Charts,
+                    Pane::Charts => Pane::Containers,
+                }));
+            }
+            KeyCode::Left | KeyCode::Right => {
+                let drawn = gui_state
+                    .get_panel_area(SelectablePanel::Containers)
+                    .map_or(0, |i| i.height);
+                layout.resize(pane, key_code == KeyCode::Right, drawn);
+            }
+            KeyCode::Char(' ') => layout.toggle(pane),
+            KeyCode::Char('r' | 'R') => layout = PaneLayout::default(),
+            KeyCode::Char('s' | 'S') | KeyCode::Enter => {
+                let path = self.app_data.lock().args.config.clone();
+                let text = path.map_or_else(
+                    || "unable to save the layout: no config file location".to_owned(),
+                    |path| match config_file::save(&path, &layout.config()) {
+                        Ok(()) => format!("layout saved to {}", path.display()),
+                        Err(e) => format!("unable to save the layout: {e}"),
+                    },
+                );
+                gui_state.set_info_box(&text);
+            }
+            KeyCode::Esc | KeyCode::Char('`') => gui_state.status_del(Status::Layout),
+            _ => (),
+        }
+        gui_state.set_layout(layout);
+    }
+
+    /// Select a match, ( enter ) shows the lines logged around the selected match, ( + - ) changes how many lines are shown
+    fn matches_key(&self, key_code: KeyCode) {
+        let len = self.app_data.lock().get_log_matches().len();
+        let mut gui_state = self.gui_state.lock();
+        let view = gui_state.get_match_view_mut();
+        match key_code {
+            KeyCode::Up | KeyCode::Char('k' | 'K') => view.previous(len),
+            KeyCode::Down | KeyCode::Char('j' | 'J') => view.next(len),
+            KeyCode::Enter => view.toggle_context(),
+            KeyCode::Char('+' | '=') => view.change_context(true),
+            KeyCode::Char('-') => view.change_context(false),
+            KeyCode::Char('a' | 'A') | KeyCode::Esc => gui_state.status_del(Status::LogMatches),
+            _ => (),
+        }
+    }
+
+    /// Change the span of the lifecycle timeline, ( s ) saves the events within the span to save_dir, as csv & json
+    fn timeline_key(&self, key_code: KeyCode) {
+        let save_dir = self.app_data.lock().args.save_dir.clone();
+        let mut gui_state = self.gui_state.lock();
+        let Some(timeline) = gui_state.get_timeline_mut() else {
+            return;
+        };
+        match key_code {
+            KeyCode::Left => timeline.narrower(),
+            KeyCode::Right => timeline.wider(),
+            KeyCode::Char('s' | 'S') => {
+                if let Some(Ok(path)) = save_dir.map(|dir| timeline.save(&dir)) {
+                    gui_state.set_info_box(&format!("saved to {} & .json", path.display()));
+                } else {
+                    drop(gui_state);
+                    self.app_data.lock().set_error(
+                        AppError::Events,
+                        &self.gui_state,
+                        Status::Error,
+                    );
+                }
+            }
+            KeyCode::Esc | KeyCode::Char('l' | 'L') => gui_state.status_del(Status::Timeline),
+            _ => (),
+        }
+    }
+
+    /// Select a tag in the tag picker, ( enter ) recreates the container on the selected tag
+    async fn tags_key(&self, key_code: KeyCode) {
+        let submit = {
+            let mut gui_state = self.gui_state.lock();
+            let Some(picker) = gui_state.get_tag_picker() else {
+                return;
+            };
+            let mut submit = None;
+            match key_code {
+                KeyCode::Up | KeyCode::Char('k' | 'K') => picker.previous(),
+                KeyCode::Down | KeyCode::Char('j' | 'J') => picker.next(),
+                KeyCode::Esc | KeyCode::Char('i' | 'I') => gui_state.status_del(Status::Tags),
+                KeyCode::Enter => {
+                    submit = picker
+                        .chosen()
+                        .map(|tag| (picker.id.clone(), tag.to_owned()));
+                    gui_state.status_del(Status::Tags);
+                }
+                _ => (),
+            }
+            drop(gui_state);
+            submit
+        };
+        if let Some((id, tag)) = submit {
+            self.docker_tx
+                .send(DockerMessage::SwitchTag(id, tag))
+                .await
+                .ok();
+        }
+    }
+
+    /// Export the selected container as a docker run script & compose file, into the save_dir
+    async fn w_key(&self) {
+        let option_id = self.app_data.lock().get_selected_container_id();
+        if let Some(id) = option_id {
+            self.docker_tx.send(DockerMessage::Export(id)).await.ok();
+        }
+    }
+
+    /// Save the logs of every container in the selected container's compose project, & a merged timeline, into the save_dir
+    async fn f_key(&self) {
+        let option_id = self.app_data.lock().get_selected_container_id();
+        if let Some(id) = option_id {
+            self.docker_tx
+                .send(DockerMessage::ExportProject(id))
+                .await
+                .ok();
+        }
+    }
+
+    /// Save a snapshot of the current host, every container's inspect, stats, & recent logs, with the recent events, as an archive into the save_dir
+    async fn hash_key(&self) {
+        let host = self
+            .app_data
+            .lock()
+            .get_resources_host()
+            .map(ToOwned::to_owned);
+        self.docker_tx
+            .send(DockerMessage::SnapshotHost(host))
+            .await
+            .ok();
+    }
+
+    /// Scan the environment of every container on the current host, for values that look like plaintext credentials
+    async fn semicolon_key(&self) {
+        let host = self
+            .app_data
+            .lock()
+            .get_resources_host()
+            .map(ToOwned::to_owned);
+        self.docker_tx
+            .send(DockerMessage::SecretScan(host))
+            .await
+            .ok();
+    }
+
+    /// Scroll the secret scan findings
+    fn secret_scan_key(&self, key_code: KeyCode) {
+        let mut gui_state = self.gui_state.lock();
+        let Some(scan) = gui_state.get_secret_scan() else {
+            return;
+        };
+        match key_code {
+            KeyCode::Up | KeyCode::Char('k' | 'K') => scan.previous(),
+            KeyCode::Down | KeyCode::Char('j' | 'J') => scan.next(),
+            KeyCode::Esc | KeyCode::Char(';') => gui_state.status_del(Status::SecretScan),
+            _ => (),
+        }
+    }
+
+    /// Lock the logs panel to the selected container, or unlock it, and display the container it's locked to in the info box
+    fn equals_key(&self) {
+        let text = {
+            let mut app_data = self.app_data.lock();
+            app_data.toggle_log_lock();
+            match app_data.get_log_container() {
+                Some(i) if app_data.is_log_locked() => format!("logs locked to {}", i.name.get()),
+                _ => "logs follow the selection".to_owned(),
+            }
+        };
+        self.gui_state.lock().set_info_box(&text);
+    }
+
+    /// Freeze the charts, or unfreeze them, stats are still collected while frozen
+    fn pipe_key(&self) {
+        let text = {
+            let mut app_data = self.app_data.lock();
+            app_data.chart_freeze();
+            if app_data.is_chart_frozen() {
+                "charts: frozen"
+            } else {
+                "charts: live"
+            }
+        };
+        self.gui_state.lock().set_info_box(text);
+    }
+
+    /// Cycle where the network & disk throughput is shown, off -> columns -> columns & charts -> off
+    fn tilde_key(&self) {
+        let view = self.app_data.lock().cycle_io_view();
+        let text = match view {
+            None => "throughput: off",
+            Some(IoView::Columns) => "throughput: columns",
+            Some(IoView::Charts) => "throughput: columns & charts",
+        };
+        self.gui_state.lock().set_info_box(text);
+    }
+
+    /// Save the full stats history of the selected container, as csv, into the save_dir
+    fn dollar_key(&self) {
+        let (save_dir, stats) = {
+            let app_data = self.app_data.lock();
+            (
+                app_data.args.save_dir.clone(),
+                app_data.get_selected_stats_csv(),
+            )
+        };
+        let Some((name, csv)) = stats else {
+            return;
+        };
+        let now = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .map_or(0, |i| i.as_secs());
+        let path = save_dir.map(|dir| dir.join(format!("{name}_stats_{now}.csv")));
+        if let Some(Ok(path)) = path.map(|path| std::fs::write(&path, csv).map(|()| path)) {
+            self.gui_state
+                .lock()
+                .set_info_box(&format!("saved to {}", path.display()));
+        } else {
+            self.app_data
+                .lock()
+                .set_error(AppError::Stats, &self.gui_state, Status::Error);
+        }
+    }
+
+    /// Open the limits form, for the selected container, filled in with its current cpu & memory limits
+    async fn colon_key(&self) {
+        let option_id = self.app_data.lock().get_selected_container_id();
+        if let Some(id) = option_id {
+            self.docker_tx
+                .send(DockerMessage::LimitsForm(id))
+                .await
+                .ok();
+        }
+    }
+
+    /// Edit the limits form, chars are typed into the selected field, so none of the usual keys, including q, apply
+    /// ( enter ) updates the limits, & closes the form, unless a field can't be parsed
+    async fn limits_key(&self, key_code: KeyCode) {
+        let submit = {
+            let mut gui_state = self.gui_state.lock();
+            let Some(form) = gui_state.get_limits_form() else {
+                return;
+            };
+            let mut submit = None;
+            match key_code {
+                KeyCode::Char(c) => form.push(c),
+                KeyCode::Backspace => form.pop(),
+                KeyCode::Tab | KeyCode::BackTab | KeyCode::Up | KeyCode::Down => form.toggle(),
+                KeyCode::Esc => gui_state.status_del(Status::Limits),
+                KeyCode::Enter => {
+                    if let Some((nano_cpus, memory)) = form.submit() {
+                        submit = Some((form.id.clone(), nano_cpus, memory));
+                        gui_state.status_del(Status::Limits);
+                    }
+                }
+                _ => (),
+            }
+            drop(gui_state);
+            submit
+        };
+        if let Some((id, nano_cpus, memory)) = submit {
+            self.docker_tx
+                .send(DockerMessage::UpdateLimits(id, nano_cpus, memory))
+                .await
+                .ok();
+        }
+    }
+
+    /// Show the health check of the selected container, & its recent probes
+    async fn apostrophe_key(&self) {
+        let option_id = self.app_data.lock().get_selected_container_id();
+        if let Some(id) = option_id {
+            self.docker_tx.send(DockerMessage::Health(id)).await.ok();
+        }
+    }
+
+    /// Show the ports of the selected container
+    fn comma_key(&self) {
+        let view = self.app_data.lock().get_selected_port_view();
+        let mut gui_state = self.gui_state.lock();
+        match view {
+            Some(view) if !view.rows.is_empty() => gui_state.set_port_view(Some(view)),
+            Some(_) => gui_state.set_info_box("the container has no ports"),
+            None => (),
+        }
+    }
+
+    /// Select a port, a published port is opened in the browser, & an exposed port is forwarded to the host
+    async fn ports_key(&self, key_code: KeyCode) {
+        let selected = {
+            let mut gui_state = self.gui_state.lock();
+            let Some(view) = gui_state.get_port_view() else {
+                return;
+            };
+            match key_code {
+                KeyCode::Up | KeyCode::Char('k' | 'K') => {
+                    view.previous();
+                    None
+                }
+                KeyCode::Down | KeyCode::Char('j' | 'J') => {
+                    view.next();
+                    None
+                }
+                KeyCode::Enter => {
+                    let selected = view.selected_row().cloned().map(|i| (view.id.clone(), i));
+                    gui_state.status_del(Status::Ports);
+                    selected
+                }
+                KeyCode::Esc | KeyCode::Char(',') => {
+                    gui_state.status_del(Status::Ports);
+                    None
+                }
+                _ => None,
+            }
+        };
+        if let Some((id, row)) = selected {
+            if let Some(url) = row.url {
+                self.open_browser(&url);
+            } else {
+                self.docker_tx
+                    .send(DockerMessage::Forward(id, row.private))
+                    .await
+                    .ok();
+            }
+        }
+    }
+
+    /// Browse the filesystem of the selected container, from the root
+    async fn underscore_key(&self) {
+        let option_id = self.app_data.lock().get_selected_container_id();
+        if let Some(id) = option_id {
+            self.docker_tx
+                .send(DockerMessage::Files(id, "/".to_owned()))
+                .await
+                .ok();
+        }
+    }
+
+    /// Move around the file browser, a directory is opened, & a file, or with ( s ) a directory, is copied into the save_dir
+    async fn files_key(&self, key_code: KeyCode) {
+        let message = {
+            let mut gui_state = self.gui_state.lock();
+            let Some(browser) = gui_state.get_file_browser() else {
+                return;
+            };
+            let id = browser.id.clone();
+            match key_code {
+                KeyCode::Up | KeyCode::Char('k' | 'K') => {
+                    browser.previous();
+                    None
+                }
+                KeyCode::Down | KeyCode::Char('j' | 'J') => {
+                    browser.next();
+                    None
+                }
+                KeyCode::Enter => match (browser.selected_entry(), browser.selected_path()) {
+                    (Some(entry), Some(path)) if entry.is_dir => {
+                        Some(DockerMessage::Files(id, path))
+                    }
+                    (_, path) => path.map(|path| DockerMessage::CopyFrom(id, path)),
+                },
+                KeyCode::Char('s' | 'S') => browser
+                    .selected_path()
+                    .map(|path| DockerMessage::CopyFrom(id, path)),
+                KeyCode::Left | KeyCode::Backspace | KeyCode::Char('h' | 'H') => browser
+                    .parent_path()
+                    .map(|path| DockerMessage::Files(id, path)),
+                KeyCode::Esc | KeyCode::Char('_') => {
+                    gui_state.status_del(Status::Files);
+                    None
+                }
+                _ => None,
+            }
+        };
+        if let Some(message) = message {
+            self.docker_tx.send(message).await.ok();
+        }
+    }
+
+    /// Boost the refresh of the selected container, or cancel its boost, only a single container is boosted at once
+    async fn period_key(&self) {
+        let (option_id, boosted, interval) = {
+            let app_data = self.app_data.lock();
+            let id = app_data.get_selected_container_id();
+            let boosted = id.as_ref().is_some_and(|i| app_data.is_boosted(i));
+            (id, boosted, app_data.args.docker_interval)
+        };
+        if let Some(id) = option_id {
+            if boosted {
+                self.app_data.lock().set_boost(None);
+                self.gui_state
+                    .lock()
+                    .set_info_box(&format!("refresh back to every {interval}ms"));
+            } else {
+                self.docker_tx.send(DockerMessage::Boost(id)).await.ok();
+            }
+        }
+    }
+
+    /// Select a health check probe, to show its full output
+    fn health_key(&self, key_code: KeyCode) {
+        let mut gui_state = self.gui_state.lock();
+        let Some(view) = gui_state.get_health_view() else {
+            return;
+        };
+        match key_code {
+            KeyCode::Up | KeyCode::Char('k' | 'K') => view.previous(),
+            KeyCode::Down | KeyCode::Char('j' | 'J') => view.next(),
+            KeyCode::Esc | KeyCode::Char('\'') => gui_state.status_del(Status::Health),
+            _ => (),
+        }
+    }
+
+    /// Cycle the images, volumes, & networks panels, an update is requested so that the newly shown panel is listed straight away
+    async fn y_key(&self) {
+        self.gui_state.lock().cycle_resources();
+        self.docker_tx.send(DockerMessage::Update).await.ok();
+    }
+
+    /// The first ( enter ) arms the removal of the selected volume, or network, & the second removes it, one that's in use, or a predefined network, can't be removed
+    async fn remove_resource(&self, panel: SelectablePanel) {
+        let selected = {
+            let app_data = self.app_data.lock();
+            let host = app_data.get_resources_host().map(ToOwned::to_owned);
+            if panel == SelectablePanel::Volumes {
+                app_data.get_selected_volume().map(|i| {
+                    let blocked = (!i.containers.is_empty())
+                        .then(|| format!("{} is used by {}", i.short_name(), i.state_text()));
+                    (
+                        i.name.clone(),
+                        blocked,
+                        DockerMessage::VolumeRemove(host, i.clone()),
+                    )
+                })
+            } else {
+                app_data.get_selected_network().map(|i| {
+                    let blocked = if i.is_predefined() {
+                        Some(format!(
+                            "{} is a predefined network, so can't be removed",
+                            i.name
+                        ))
+                    } else {
+                        (!i.containers.is_empty())
+                            .then(|| format!("{} is used by {}", i.name, i.state_text()))
+                    };
+                    (
+                        i.id.clone(),
+                        blocked,
+                        DockerMessage::NetworkRemove(host, i.clone()),
+                    )
+                })
+            }
+        };
+        let Some((key, blocked, message)) = selected else {
+            return;
+        };
+        let message = {
+            let mut gui_state = self.gui_state.lock();
+            if let Some(blocked) = blocked {
+                gui_state.set_info_box(&blocked);
+                None
+            } else if gui_state.get_remove_armed() == Some(key.as_str()) {
+                gui_state.set_remove_armed(None);
+                Some(message)
+            } else {
+                gui_state.set_remove_armed(Some(key));
+                None
+            }
+        };
+        if let Some(message) = message {
+            self.docker_tx.send(message).await.ok();
+        }
+    }
+
+    /// Open the scale form of the selected service, a global service runs a task on every node, so can't be scaled
+    fn scale_service(&self) {
+        let service = {
+            let app_data = self.app_data.lock();
+            app_data.get_selected_service().cloned().map(|i| {
+                let stack = i
+                    .stack
+                    .as_deref()
+                    .map(|stack| app_data.get_stack_services(stack))
+                    .unwrap_or_default();
+                (
+                    app_data.get_resources_host().map(ToOwned::to_owned),
+                    i,
+                    stack,
+                )
+            })
+        };
+        let Some((host, service, stack)) = service else {
+            return;
+        };
+        let mut gui_state = self.gui_state.lock();
+        if service.global {
+            gui_state.set_info_box(&format!(
+                "{} is a global service, so can't be scaled",
+                service.name
+            ));
+        } else {
+            gui_state.set_scale_form(Some(ScaleForm::new(host, service, stack)));
+        }
+    }
+
+    /// Edit the scale form, digits are typed into the replicas, so none of the usual keys, including q, apply
+    /// ( tab ) switches between the service, & its whole stack, ( enter ) scales them, unchanged replicas keep the form open, with the reason shown
+    async fn scale_key(&self, key_code: KeyCode) {
+        let submit = {
+            let mut gui_state = self.gui_state.lock();
+            let Some(form) = gui_state.get_scale_form() else {
+                return;
+            };
+            let mut submit = None;
+            match key_code {
+                KeyCode::Char(c) => form.push(c),
+                KeyCode::Backspace => {
+                    form.text.pop();
+                }
+                KeyCode::Esc => gui_state.status_del(Status::Scale),
+                KeyCode::Tab => form.toggle_stack(),
+                KeyCode::Enter => {
+                    if let Some(replicas) = form.submit() {
+                        submit = Some(form.scaled_stack().map_or_else(
+                            || {
+                                DockerMessage::ServiceScale(
+                                    form.host.clone(),
+                                    form.service.clone(),
+                                    replicas,
+                                )
+                            },
+                            |stack| {
+                                DockerMessage::StackScale(
+                                    form.host.clone(),
+                                    stack.to_owned(),
+                                    replicas,
+                                )
+                            },
+                        ));
+                        gui_state.status_del(Status::Scale);
+                    }
+                }
+                _ => (),
+            }
+            drop(gui_state);
+            submit
+        };
+        if let Some(message) = submit {
+            self.docker_tx.send(message).await.ok();
+        }
+    }
+
+    /// The first ( r ) arms the force update of the selected service, & the second replaces every one of its tasks
+    async fn force_update_service(&self) {
+        if self.app_data.lock().args.read_only {
+            self.gui_state.lock().set_info_box(READ_ONLY_TEXT);
+            return;
+        }
+        let service = {
+            let app_data = self.app_data.lock();
+            app_data
+                .get_selected_service()
+                .cloned()
+                .map(|i| (app_data.get_resources_host().map(ToOwned::to_owned), i))
+        };
+        let Some((host, service)) = service else {
+            return;
+        };
+        let armed = {
+            let mut gui_state = self.gui_state.lock();
+            if gui_state.get_remove_armed() == Some(service.id.as_str()) {
+                gui_state.set_remove_armed(None);
+                true
+            } else {
+                gui_state.set_remove_armed(Some(service.id.clone()));
+                false
+            }
+        };
+        if armed {
+            self.docker_tx
+                .send(DockerMessage::ServiceForceUpdate(host, service))
+                .await
+                .ok();
+        }
+    }
+
+    /// The first ( delete ) arms the removal of the selected service's stack, & the second removes every service, network, & secret, of the stack
+    async fn remove_stack(&self) {
+        if self.app_data.lock().args.read_only {
+            self.gui_state.lock().set_info_box(READ_ONLY_TEXT);
+            return;
+        }
+        let service = {
+            let app_data = self.app_data.lock();
+            app_data
+                .get_selected_service()
+                .cloned()
+                .map(|i| (app_data.get_resources_host().map(ToOwned::to_owned), i))
+        };
+        let Some((host, service)) = service else {
+            return;
+        };
+        let (Some(stack), Some(key)) = (service.stack.clone(), service.stack_armed_key()) else {
+            self.gui_state
+                .lock()
+                .set_info_box(&format!("{} isn't in a stack", service.name));
+            return;
+        };
+        let armed = {
+            let mut gui_state = self.gui_state.lock();
+            if gui_state.get_remove_armed() == Some(key.as_str()) {
+                gui_state.set_remove_armed(None);
+                true
+            } else {
+                gui_state.set_remove_armed(Some(key));
+                false
+            }
+        };
+        if armed {
+            self.docker_tx
+                .send(DockerMessage::StackRemove(host, stack))
+                .await
+                .ok();
+        }
+    }
+
+    /// Move the scroll of the merged task logs
+    fn task_logs_key(&self, key_code: KeyCode) {
+        let mut gui_state = self.gui_state.lock();
+        let Some(logs) = gui_state.get_task_logs() else {
+            return;
+        };
+        match key_code {
+            KeyCode::Up | KeyCode::Char('k' | 'K') => logs.scroll(true, 1),
+            KeyCode::Down | KeyCode::Char('j' | 'J') => logs.scroll(false, 1),
+            KeyCode::PageUp => logs.scroll(true, 7),
+            KeyCode::PageDown => logs.scroll(false, 7),
+            KeyCode::Home => logs.scroll(true, usize::MAX),
+            KeyCode::End => logs.scroll(false, usize::MAX),
+            KeyCode::Esc => gui_state.status_del(Status::TaskLogs),
+            _ => (),
+        }
+    }
+
+    /// Cancel an armed removal, otherwise clear the search
+    fn esc_key(&self) {
+        let armed = {
+            let mut gui_state = self.gui_state.lock();
+            let armed = gui_state.get_remove_armed().is_some();
+            gui_state.set_remove_armed(None);
+            armed
+        };
+        if !armed {
+            let logs = self.gui_state.lock().get_selected_panel() == SelectablePanel::Logs;
+            let mut app_data = self.app_data.lock();
+            if logs && app_data.get_log_search().is_some() {
+                app_data.set_log_search(None);
+            } else if logs && app_data.get_log_filter().is_some() {
+                app_data.set_log_filter(None);
+            } else if !logs && app_data.get_marked_len() > 0 {
+                app_data.clear_marked();
+            } else {
+                app_data.set_search(None);
+            }
+            drop(app_data);
+        }
+    }
+
+    /// ( p ) pulls the latest image of the inspected image's tag, the first ( r ) arms the removal of the image, & the second removes it
+    async fn image_detail_key(&self, key_code: KeyCode) {
+        let message = {
+            let mut gui_state = self.gui_state.lock();
+            let Some(detail) = gui_state.get_image_detail() else {
+                return;
+            };
+            let mut message = None;
+            match key_code {
+                KeyCode::Char('p' | 'P') => {
+                    if let Some(tag) = detail.pull_tag() {
+                        message = Some(DockerMessage::ImagePull(
+                            detail.host.clone(),
+                            tag.to_owned(),
+                        ));
+                    }
+                }
+                KeyCode::Char('r' | 'R') => {
+                    if detail.armed {
+                        message = Some(DockerMessage::ImageRemove(
+                            detail.host.clone(),
+                            detail.image.clone(),
+                        ));
+                    } else {
+                        detail.armed = true;
+                    }
+                }
+                KeyCode::Esc | KeyCode::Enter => {
+                    if detail.armed {
+                        detail.armed = false;
+                    } else {
+                        gui_state.status_del(Status::ImageDetail);
+                    }
+                }
+                _ => (),
+            }
+            if message.is_some() {
+                gui_state.status_del(Status::ImageDetail);
+            }
+            drop(gui_state);
+            message
+        };
+        if let Some(message) = message {
+            self.docker_tx.send(message).await.ok();
+        }
+    }
+
+    /// Edit the recreate form, chars are typed into the selected field, so none of the usual keys, including q, apply
+    async fn recreate_key(&self, key_code: KeyCode) {
+        let submit = {
+            let mut gui_state = self.gui_state.lock();
+            let Some(form) = gui_state.get_recreate_form() else {
+                return;
+            };
+            let mut submit = None;
+            match key_code {
+                KeyCode::Char(c) => form.push(c),
+                KeyCode::Backspace => form.pop(),
+                KeyCode::Up | KeyCode::BackTab => form.previous(),
+                KeyCode::Down | KeyCode::Tab => form.next(),
+                KeyCode::Esc => gui_state.status_del(Status::Recreate),
+                KeyCode::Enter => {
+                    if let Some(message) = form.invalid() {
+                        gui_state.set_info_box(&message);
+                    } else {
+                        let form = form.clone();
+                        gui_state.status_del(Status::Recreate);
+                        submit = Some(form);
+                    }
+                }
+                _ => (),
+            }
+            drop(gui_state);
+            submit
+        };
+        if let Some(form) = submit {
+            self.docker_tx
+                .send(DockerMessage::Recreate(Box::new(form)))
+                .await
+                .ok();
+        }
+    }
+
+    /// Open the run form, on the host of the images panel, pre-filled with the selected image, if the images panel is selected
+    /// With the services panel selected, open the scale form of the selected service instead
+    fn plus_key(&self) {
+        if self.app_data.lock().args.read_only {
+            self.gui_state.lock().set_info_box(READ_ONLY_TEXT);
+            return;
+        }
+        if self.gui_state.lock().get_selected_panel() == SelectablePanel::Services {
+            self.scale_service();
+            return;
+        }
+        let images_panel = self.gui_state.lock().get_selected_panel() == SelectablePanel::Images;
+        let app_data = self.app_data.lock();
+        let image = app_data
+            .get_selected_image()
+            .filter(|_| images_panel)
+            .and_then(|i| i.tags.first().cloned())
+            .unwrap_or_default();
+        let images = app_data
+            .get_images()
+            .iter()
+            .flat_map(|i| i.tags.iter().cloned())
+            .collect::<Vec<_>>();
+        let host = app_data.get_resources_host().map(ToOwned::to_owned);
+        drop(app_data);
+        self.gui_state
+            .lock()
+            .set_run_form(Some(RunForm::new(image, images, host)));
+    }
+
+    /// Edit the run form, chars are typed into the selected field, so none of the usual keys, including q, apply
+    async fn run_key(&self, key_code: KeyCode) {
+        let submit = {
+            let mut gui_state = self.gui_state.lock();
+            let Some(form) = gui_state.get_run_form() else {
+                return;
+            };
+            let mut submit = None;
+            match key_code {
+                KeyCode::Char(c) => form.push(c),
+                KeyCode::Backspace => form.pop(),
+                KeyCode::Up | KeyCode::BackTab => form.previous(),
+                KeyCode::Down | KeyCode::Tab => form.next(),
+                KeyCode::Left => form.cycle(false),
+                KeyCode::Right => form.cycle(true),
+                KeyCode::Esc => gui_state.status_del(Status::Run),
+                KeyCode::Enter => {
+                    if let Some(message) = form.invalid() {
+                        gui_state.set_info_box(&message);
+                    } else {
+                        let form = form.clone();
+                        gui_state.status_del(Status::Run);
+                        submit = Some(form);
+                    }
+                }
+                _ => (),
+            }
+            drop(gui_state);
+            submit
+        };
+        if let Some(form) = submit {
+            self.docker_tx
+                .send(DockerMessage::Run(form.host.clone(), Box::new(form)))
+                .await
+                .ok();
+        }
+    }
+
+    /// Open the note form for the selected container, pre-filled with its current note
+    fn n_key(&self) {
+        let app_data = self.app_data.lock();
+        let form = app_data.get_selected_container().map(|i| NoteForm {
+            name: i.name.get().to_owned(),
+            text: app_data.get_selected_note().unwrap_or_default().to_owned(),
+        });
+        drop(app_data);
+        if form.is_some() {
+            self.gui_state.lock().set_note_form(form);
+        }
+    }
+
+    /// Edit the note form, chars are typed into the note, so none of the usual keys, including q, apply
+    fn note_key(&self, key_code: KeyCode) {
+        let submit = {
+            let mut gui_state = self.gui_state.lock();
+            let Some(form) = gui_state.get_note_form() else {
+                return;
+            };
+            let mut submit = None;
+            match key_code {
+                KeyCode::Char(c) => form.text.push(c),
+                KeyCode::Backspace => {
+                    form.text.pop();
+                }
+                KeyCode::Esc => gui_state.status_del(Status::Note),
+                KeyCode::Enter => {
+                    submit = Some(form.clone());
+                    gui_state.status_del(Status::Note);
+                }
+                _ => (),
+            }
+            drop(gui_state);
+            submit
+        };
+        if let Some(form) = submit {
+            let saved = self.app_data.lock().set_note(&form.name, &form.text);
+            if let Err(e) = saved {
+                self.gui_state
+                    .lock()
+                    .set_info_box(&format!("unable to save note: {e}"));
+            }
+        }
+    }
+
+    /// Open the stdin form, for the selected container, if it was started with stdin open
+    async fn greater_than_key(&self) {
+        let option_id = self.app_data.lock().get_selected_container_id();
+        if let Some(id) = option_id {
+            self.docker_tx.send(DockerMessage::StdinForm(id)).await.ok();
+        }
+    }
+
+    /// Load the lines logged before the oldest line in the logs panel, of the container whose logs are shown
+    async fn less_than_key(&self) {
+        let option_id = self
+            .app_data
+            .lock()
+            .get_log_container()
+            .map(|i| i.id.clone());
+        if let Some(id) = option_id {
+            self.docker_tx.send(DockerMessage::OlderLogs(id)).await.ok();
+        }
+    }
+
+    /// Cycle how log lines of a JSON object are shown, and display the new view in the info box
+    fn brace_key(&self) {
+        let text = {
+            let mut app_data = self.app_data.lock();
+            app_data.cycle_log_json();
+            match app_data.get_log_json() {
+                Some(JsonView::Pretty) => "json logs: pretty",
+                Some(JsonView::Flat) => "json logs: key=value",
+                None => "json logs: off",
+            }
+        };
+        self.gui_state.lock().set_info_box(text);
+    }
+
+    /// Mark the selected container, the next ( ( ), on another container of the same host, compares the inspect output of the two, on the same container unmarks it
+    async fn parenthesis_key(&self) {
+        let marked = self.gui_state.lock().get_compare_marked().cloned();
+        let (marked, selected, name, same_host) = {
+            let mut app_data = self.app_data.lock();
+            let Some(selected) = app_data.get_selected_container_id() else {
+                return;
+            };
+            let name = app_data
+                .get_container_name_by_id(&selected)
+                .map(|i| i.get().to_owned())
+                .unwrap_or_default();
+            // A marked container that's since been removed is forgotten
+            let marked = marked.filter(|id| app_data.get_container_name_by_id(id).is_some());
+            let same_host = marked
+                .as_ref()
+                .is_none_or(|id| app_data.get_host_by_id(id) == app_data.get_host_by_id(&selected));
+            drop(app_data);
+            (marked, selected, name, same_host)
+        };
+
+        let message = {
+            let mut gui_state = self.gui_state.lock();
+            match marked {
+                Some(id) if id == selected => {
+                    gui_state.set_compare_marked(None);
+                    gui_state.set_info_box(&format!("{name} unmarked"));
+                    None
+                }
+                Some(_) if !same_host => {
+                    gui_state.set_info_box("only containers of the same host can be compared");
+                    None
+                }
+                Some(id) => {
+                    gui_state.set_compare_marked(None);
+                    Some(DockerMessage::Compare(id, selected))
+                }
+                None => {
+                    gui_state.set_info_box(&format!(
+                        "{name} marked, press ( ( ) on another container to compare"
+                    ));
+                    gui_state.set_compare_marked(Some(selected));
+                    None
+                }
+            }
+        };
+        if let Some(message) = message {
+            self.docker_tx.send(message).await.ok();
+        }
+    }
+
+    /// Scroll the comparison of two containers, ( space ) hides the settings that are the same
+    fn compare_key(&self, key_code: KeyCode) {
+        let mut gui_state = self.gui_state.lock();
+        let Some(diff) = gui_state.get_inspect_diff() else {
+            return;
+        };
+        match key_code {
+            KeyCode::Up | KeyCode::Char('k' | 'K') => diff.previous(1),
+            KeyCode::Down | KeyCode::Char('j' | 'J') => diff.next(1),
+            KeyCode::PageUp => diff.previous(7),
+            KeyCode::PageDown => diff.next(7),
+            KeyCode::Home => diff.previous(usize::MAX),
+            KeyCode::End => diff.end(),
+            KeyCode::Char(' ') => diff.toggle_changed(),
+            KeyCode::Esc | KeyCode::Char('(') => gui_state.status_del(Status::Compare),
+            _ => (),
+        }
+    }
+
+    /// Pull the latest image of the selected container, & recreate it if the image has changed, confirmed first unless --confirm says otherwise
+    async fn caret_key(&self) {
+        if self.app_data.lock().is_oxker_in_container() {
+            return;
+        }
+        let option_id = self.app_data.lock().get_selected_container_id();
+        if let Some(id) = option_id {
+            if self
+                .app_data
+                .lock()
+                .requires_confirm(DockerControls::Update)
+            {
+                self.docker_tx
+                    .send(DockerMessage::Confirm(DockerControls::Update, id))
+                    .await
+                    .ok();
+            } else {
+                self.send_control(DockerControls::Update, id).await;
+            }
+        }
+    }
+
+    /// Retry the failed control, which waits for its backoff, the failure stays open, counting down, until the control is sent again
+    async fn failed_key(&self, key_code: KeyCode) {
+        match key_code {
+            KeyCode::Char('r' | 'R') => {
+                let retry = self
+                    .gui_state
+                    .lock()
+                    .get_failed_ref()
+                    .filter(|i| i.retry_at.is_none())
+                    .map(|i| (i.control, i.id.clone()));
+                if let Some((control, id)) = retry {
+                    self.docker_tx
+                        .send(DockerMessage::Retry(control, id))
+                        .await
+                        .ok();
+                }
+            }
+            KeyCode::Esc => self.gui_state.lock().status_del(Status::Failed),
+            _ => (),
+        }
+    }
+
+    /// Open the capture pane, to type a filter for a packet capture of the selected container, only a running container has a network namespace to capture
+    fn minus_key(&self) {
+        if self.app_data.lock().args.read_only {
+            self.gui_state.lock().set_info_box(READ_ONLY_TEXT);
+            return;
+        }
+        let selected = self
+            .app_data
+            .lock()
+            .get_selected_container()
+            .map(|i| (i.id.clone(), i.name.get().to_owned(), i.state.is_alive()));
+        match selected {
+            Some((id, name, true)) => self
+                .gui_state
+                .lock()
+                .set_capture(Some(Capture::new(id, name))),
+            Some((_, _, false)) => self
+                .gui_state
+                .lock()
+                .set_info_box("only a running container can be captured"),
+            None => (),
+        }
+    }
+
+    /// Edit the capture filter, chars are typed into the filter, so none of the usual keys, including q, apply
+    /// ( enter ) starts the capture, once started only ( esc ) applies, which stops it, & closes the pane
+    async fn capture_key(&self, key_code: KeyCode) {
+        let start = {
+            let mut gui_state = self.gui_state.lock();
+            let Some(capture) = gui_state.get_capture() else {
+                return;
+            };
+            let mut start = None;
+            match key_code {
+                KeyCode::Esc => gui_state.status_del(Status::Capture),
+                _ if capture.is_started() => (),
+                KeyCode::Char(c) => capture.filter.push(c),
+                KeyCode::Backspace => {
+                    capture.filter.pop();
+                }
+                KeyCode::Enter => {
+                    let stop = capture.start();
+                    start = Some((capture.id.clone(), capture.filter.trim().to_owned(), stop));
+                }
+                _ => (),
+            }
+            drop(gui_state);
+            start
+        };
+        if let Some((id, filter, stop)) = start {
+            self.docker_tx
+                .send(DockerMessage::Capture(id, filter, stop))
+                .await
+                .ok();
+        }
+    }
+
+    /// Edit the stdin form, chars are typed into the line, so none of the usual keys, including q, apply
+    /// ( enter ) sends the line, & clears it, the form stays open until ( esc )
+    async fn stdin_key(&self, key_code: KeyCode) {
+        let submit = {
+            let mut gui_state = self.gui_state.lock();
+            let Some(form) = gui_state.get_stdin_form() else {
+                return;
+            };
+            let mut submit = None;
+            match key_code {
+                KeyCode::Char(c) => form.text.push(c),
+                KeyCode::Backspace => {
+                    form.text.pop();
+                }
+                KeyCode::Esc => gui_state.status_del(Status::Stdin),
+                KeyCode::Enter => {
+                    let line = std::mem::take(&mut form.text);
+                    form.sent = Some(line.clone());
+                    submit = Some((form.id.clone(), line));
+                }
+                _ => (),
+            }
+            drop(gui_state);
+            submit
+        };
+        if let Some((id, line)) = submit {
+            self.docker_tx
+                .send(DockerMessage::Stdin(id, line))
+                .await
+                .ok();
+        }
+    }
+
+    /// Start typing into the logs search, when the logs panel is selected, or else the containers search, continuing an existing search
+    fn slash_key(&self) {
+        if self.gui_state.lock().get_selected_panel() == SelectablePanel::Logs {
+            let mut app_data = self.app_data.lock();
+            if app_data.get_selected_container().is_none() {
+                return;
+            }
+            let search = app_data
+                .get_log_search()
+                .cloned()
+                .unwrap_or_else(|| LogSearch::new(String::new(), false));
+            app_data.set_log_search(Some(search));
+            drop(app_data);
+            self.gui_state.lock().status_push(Status::LogSearch);
+            return;
+        }
+        let search = self
+            .app_data
+            .lock()
+            .get_search()
+            .unwrap_or_default()
+            .to_owned();
+        self.app_data.lock().set_search(Some(search));
+        self.gui_state.lock().status_push(Status::Search);
+    }
+
+    /// Edit the containers search, the containers are filtered as each char is typed, ( enter ) keeps the search, ( esc ) clears it
+    fn search_key(&self, key_code: KeyCode) {
+        let mut app_data = self.app_data.lock();
+        match key_code {
+            KeyCode::Char(c) => app_data.search_push(c),
+            KeyCode::Backspace => app_data.search_pop(),
+            KeyCode::Enter => {
+                if app_data.get_search().is_some_and(str::is_empty) {
+                    app_data.set_search(None);
+                }
+                self.gui_state.lock().status_del(Status::Search);
+            }
+            KeyCode::Esc => {
+                app_data.set_search(None);
+                self.gui_state.lock().status_del(Status::Search);
+            }
+            _ => (),
+        }
+        drop(app_data);
+    }
 
-/// Handle all input events
-#[derive(Debug)]
-pub struct InputHandler {
-    app_data: Arc<Mutex<AppData>>,
-    docker_tx: Sender<DockerMessage>,
-    gui_state: Arc<Mutex<GuiState>>,
-    is_running: Arc<AtomicBool>,
-    mouse_capture: bool,
-    rec: Receiver<InputMessages>,
-}
+    /// Open the container finder, with every container, of every host, that isn't hidden by a filter rule
+    fn quote_key(&self) {
+        let app_data = self.app_data.lock();
+        let rows = app_data
+            .get_container_items()
+            .iter()
+            .filter(|i| app_data.is_filter_shown(i))
+            .map(|i| FinderRow {
+                id: i.id.clone(),
+                image: i.image.get().to_owned(),
+                name: i.name.get().to_owned(),
+                project: i.project.clone(),
+            })
+            .collect::<Vec<_>>();
+        drop(app_data);
+        if !rows.is_empty() {
+            self.gui_state.lock().set_finder(Some(Finder::new(rows)));
+        }
+    }
 
-impl InputHandler {
-    /// Initialize self, and running the message handling loop
-    pub async fn init(
-        app_data: Arc<Mutex<AppData>>,
-        rec: Receiver<InputMessages>,
-        docker_tx: Sender<DockerMessage>,
-        gui_state: Arc<Mutex<GuiState>>,
-        is_running: Arc<AtomicBool>,
-    ) {
-        let mut inner = Self {
-            app_data,
-            docker_tx,
-            gui_state,
-            is_running,
-            rec,
-            mouse_capture: true,
+    /// Type into the container finder, chars are typed into the input, so none of the usual keys, including q, apply
+    /// ( enter ) selects the chosen container, even if it's hidden by the search, its group, or the host tab
+    fn finder_key(&self, key_code: KeyCode) {
+        let mut gui_state = self.gui_state.lock();
+        let Some(finder) = gui_state.get_finder() else {
+            return;
         };
-        inner.start().await;
+        match key_code {
+            KeyCode::Char(c) => finder.push(c),
+            KeyCode::Backspace => finder.pop(),
+            KeyCode::Up => finder.previous(),
+            KeyCode::Down => finder.next(),
+            KeyCode::Enter => {
+                let id = finder.selected_row().map(|i| i.id.clone());
+                gui_state.status_del(Status::Finder);
+                drop(gui_state);
+                if let Some(id) = id {
+                    self.app_data.lock().reveal_container(&id);
+                    self.gui_state
+                        .lock()
+                        .set_selected_panel(SelectablePanel::Containers);
+                }
+            }
+            KeyCode::Esc => gui_state.status_del(Status::Finder),
+            _ => (),
+        }
     }
 
-    /// check for incoming messages
-    async fn start(&mut self) {
-        while let Some(message) = self.rec.recv().await {
-            match message {
-                InputMessages::ButtonPress(key) => self.button_press(key.0, key.1).await,
-                InputMessages::MouseEvent(mouse_event) => {
-                    if !self.gui_state.lock().status_contains(&[
-                        Status::Error,
-                        Status::Help,
-                        Status::DeleteConfirm,
-                    ]) {
-                        self.mouse_press(mouse_event);
-                    }
-                    let delete_confirm = self
-                        .gui_state
-                        .lock()
-                        .status_contains(&[Status::DeleteConfirm]);
-                    if delete_confirm {
-                        self.button_intersect(mouse_event).await;
-                    }
+    /// Edit the logs search, the matches are highlighted as each char is typed, ( tab ) switches between plain text & regex
+    /// ( enter ) keeps the search, & selects the closest match above the selected line, ( esc ) clears the search
+    fn log_search_key(&self, key_code: KeyCode) {
+        let mut app_data = self.app_data.lock();
+        match key_code {
+            KeyCode::Char(c) => app_data.log_search_edit(|i| i.input.push(c)),
+            KeyCode::Backspace => app_data.log_search_edit(|i| {
+                i.input.pop();
+            }),
+            KeyCode::Tab => app_data.log_search_edit(|i| i.regex = !i.regex),
+            KeyCode::Enter => {
+                if app_data
+                    .get_log_search()
+                    .is_some_and(|i| i.input.is_empty())
+                {
+                    app_data.set_log_search(None);
+                } else {
+                    app_data.log_search_jump(false);
                 }
+                self.gui_state.lock().status_del(Status::LogSearch);
             }
-            if !self.is_running.load(Ordering::SeqCst) {
-                break;
+            KeyCode::Esc => {
+                app_data.set_log_search(None);
+                self.gui_state.lock().status_del(Status::LogSearch);
             }
+            _ => (),
         }
+        drop(app_data);
     }
 
-    /// Sort the containers by a given header
-    fn sort(&self, selected_header: Header) {
-        self.app_data.lock().set_sort_by_header(selected_header);
+    /// Open the schedules panel, new schedules are for the selected container
+    fn at_key(&self) {
+        let container = self
+            .app_data
+            .lock()
+            .get_selected_container()
+            .map(|i| (i.name.get().to_owned(), i.host.clone()));
+        self.gui_state.lock().set_schedule_form(Some(ScheduleForm {
+            container,
+            text: String::new(),
+            error: None,
+            selected: 0,
+        }));
     }
 
-    /// Send a quit message to docker, to abort all spawns, if an error is returned, set is_running to false here instead
-    /// If gui_status is Error or Init, then just set the is_running to false immediately, for a quicker exit
-    async fn quit(&self) {
-        let error_init = self
-            .gui_state
-            .lock()
-            .status_contains(&[Status::Error, Status::Init]);
-        if error_init || self.docker_tx.send(DockerMessage::Quit).await.is_err() {
-            self.is_running
-                .store(false, std::sync::atomic::Ordering::SeqCst);
+    /// Edit the schedules panel, chars are typed into the new schedule, so none of the usual keys, including q, apply
+    fn schedules_key(&self, key_code: KeyCode) {
+        let len = self.app_data.lock().get_schedules().len();
+        let mut gui_state = self.gui_state.lock();
+        let Some(form) = gui_state.get_schedule_form() else {
+            return;
+        };
+        match key_code {
+            KeyCode::Char(c) => form.text.push(c),
+            KeyCode::Backspace => {
+                form.text.pop();
+            }
+            KeyCode::Up => form.selected = form.selected.saturating_sub(1),
+            KeyCode::Down => form.selected = (form.selected + 1).min(len.saturating_sub(1)),
+            KeyCode::Delete => {
+                let selected = form.selected;
+                form.selected = selected.min(len.saturating_sub(2));
+                drop(gui_state);
+                self.app_data.lock().remove_schedule(selected);
+            }
+            KeyCode::Esc => gui_state.status_del(Status::Schedules),
+            KeyCode::Enter => {
+                let Some((name, host)) = form.container.clone() else {
+                    return;
+                };
+                let text = form.text.clone();
+                drop(gui_state);
+                let now = SystemTime::now()
+                    .duration_since(SystemTime::UNIX_EPOCH)
+                    .map_or(0, |i| i.as_secs());
+                let added = self
+                    .app_data
+                    .lock()
+                    .add_schedule(&name, host.as_deref(), &text, now);
+                if let Some(form) = self.gui_state.lock().get_schedule_form() {
+                    match added {
+                        Ok(()) => {
+                            form.text.clear();
+                            form.error = None;
+                            form.selected = len;
+                        }
+                        Err(e) => form.error = Some(e),
+                    }
+                }
+            }
+            _ => (),
         }
     }
 
-    /// This is executed from the Delete Confirm dialog, and will send an internal message to actually remove the given container
-    async fn confirm_delete(&self) {
-        let id = self.gui_state.lock().get_delete_container();
-        if let Some(id) = id {
-            self.docker_tx.send(DockerMessage::Delete(id)).await.ok();
-        }
+    /// Open the filters panel, with the current rules
+    fn close_parenthesis_key(&self) {
+        self.gui_state
+            .lock()
+            .set_filter_form(Some(FilterForm::default()));
     }
 
-    /// This is executed from the Delete Confirm dialog, and will clear the delete_container information (removes id and closes panel)
-    fn clear_delete(&self) {
-        self.gui_state.lock().set_delete_container(None);
+    /// Edit the filters panel, chars are typed into the new rule, so none of the usual keys, including q, apply
+    /// ( tab ) turns the selected rule off, or back on, without removing it
+    fn filters_key(&self, key_code: KeyCode) {
+        let len = self.app_data.lock().get_filters().len();
+        let mut gui_state = self.gui_state.lock();
+        let Some(form) = gui_state.get_filter_form() else {
+            return;
+        };
+        match key_code {
+            KeyCode::Char(c) => form.text.push(c),
+            KeyCode::Backspace => {
+                form.text.pop();
+            }
+            KeyCode::Up => form.selected = form.selected.saturating_sub(1),
+            KeyCode::Down => form.selected = (form.selected + 1).min(len.saturating_sub(1)),
+            KeyCode::Tab => {
+                let selected = form.selected;
+                drop(gui_state);
+                self.app_data.lock().toggle_filter(selected);
+            }
+            KeyCode::Delete => {
+                let selected = form.selected;
+                form.selected = selected.min(len.saturating_sub(2));
+                drop(gui_state);
+                self.app_data.lock().remove_filter(selected);
+            }
+            KeyCode::Esc => gui_state.status_del(Status::Filters),
+            KeyCode::Enter => {
+                let text = form.text.clone();
+                drop(gui_state);
+                let added = self.app_data.lock().add_filter(&text);
+                if let Some(form) = self.gui_state.lock().get_filter_form() {
+                    match added {
+                        Ok(()) => {
+                            form.text.clear();
+                            form.error = None;
+                            form.selected = len;
+                        }
+                        Err(e) => form.error = Some(e),
+                    }
+                }
+            }
+            _ => (),
+        }
     }
 
-    /// Validate that one can exec into a Docker container
-    async fn e_key(&self) {
-        let is_oxker = self.app_data.lock().is_oxker();
-        if !is_oxker && tty_readable() {
-            let uuid = Uuid::new_v4();
-            let handle = GuiState::start_loading_animation(&self.gui_state, uuid);
-            let (sx, rx) = tokio::sync::oneshot::channel::<Arc<Docker>>();
-            self.docker_tx.send(DockerMessage::Exec(sx)).await.ok();
+    /// Start typing into the logs filter of the selected container, when the logs panel is selected, continuing an existing filter
+    fn ampersand_key(&self) {
+        if self.gui_state.lock().get_selected_panel() != SelectablePanel::Logs {
+            return;
+        }
+        let mut app_data = self.app_data.lock();
+        if app_data.get_selected_container().is_none() {
+            return;
+        }
+        let filter = app_data
+            .get_log_filter()
+            .cloned()
+            .unwrap_or_else(|| LogFilter::new(String::new()));
+        app_data.set_log_filter(Some(filter));
+        drop(app_data);
+        self.gui_state.lock().status_push(Status::LogFilter);
+    }
 
-            if let Ok(docker) = rx.await {
-                (ExecMode::new(&self.app_data, &docker).await).map_or_else(
-                    || {
-                        self.app_data.lock().set_error(
-                            AppError::DockerExec,
-                            &self.gui_state,
-                            Status::Error,
-                        );
-                    },
-                    |mode| {
-                        self.gui_state.lock().set_exec_mode(mode);
-                    },
-                );
+    /// Edit the logs filter, the lines are filtered as each char is typed, ( enter ) keeps the filter, ( esc ) clears it
+    fn log_filter_key(&self, key_code: KeyCode) {
+        let mut app_data = self.app_data.lock();
+        match key_code {
+            KeyCode::Char(c) => app_data.log_filter_edit(Some(c)),
+            KeyCode::Backspace => app_data.log_filter_edit(None),
+            KeyCode::Enter => {
+                if app_data
+                    .get_log_filter()
+                    .is_some_and(|i| i.input.trim().is_empty())
+                {
+                    app_data.set_log_filter(None);
+                }
+                self.gui_state.lock().status_del(Status::LogFilter);
             }
-            self.gui_state.lock().stop_loading_animation(&handle, uuid);
+            KeyCode::Esc => {
+                app_data.set_log_filter(None);
+                self.gui_state.lock().status_del(Status::LogFilter);
+            }
+            _ => (),
+        }
+        drop(app_data);
+    }
+
+    /// ( n ) & ( N ) jump to the next, or previous, match of the logs search, when the logs panel is selected, or else ( n ) edits the note of the selected container
+    fn n_or_jump(&self, forward: bool) {
+        let is_logs = self.gui_state.lock().get_selected_panel() == SelectablePanel::Logs;
+        if is_logs && self.app_data.lock().get_log_search().is_some() {
+            self.app_data.lock().log_search_jump(forward);
+        } else {
+            self.n_key();
         }
     }
 
@@ -177,66 +2295,136 @@ impl InputHandler {
     }
 
     /// Save the currently selected containers logs into a `[container_name]_[timestamp].log` file
-    async fn s_key(&mut self) {
+    /// The save logs popup, of the selected container, with the filter & search of its logs, only when there's a save directory
+    fn log_export(&self) -> Option<LogExport> {
+        let app_data = self.app_data.lock();
+        app_data.args.save_dir.as_ref()?;
+        app_data.get_log_container().map(|i| {
+            LogExport::new(
+                i.id.clone(),
+                i.name.get().to_owned(),
+                i.logs.get_filter().cloned(),
+                i.logs.get_search().cloned(),
+            )
+        })
+    }
+
+    /// Show the save logs popup, to choose the format, lines, & time range, of the logs to save
+    fn s_key(&self) {
+        let export = self.log_export();
+        if export.is_some() {
+            self.gui_state.lock().set_log_export(export);
+        }
+    }
+
+    /// Change the options of the save logs popup, ( enter ) saves the logs
+    async fn log_export_key(&self, key_code: KeyCode) {
+        let submit = {
+            let mut gui_state = self.gui_state.lock();
+            let Some(export) = gui_state.get_log_export() else {
+                return;
+            };
+            let mut submit = None;
+            match key_code {
+                KeyCode::Up | KeyCode::Char('k' | 'K') => export.previous(),
+                KeyCode::Down | KeyCode::Char('j' | 'J') => export.next(),
+                KeyCode::Left | KeyCode::Char('h' | 'H') => export.change(false),
+                KeyCode::Right | KeyCode::Char('l' | 'L') => export.change(true),
+                KeyCode::Enter => {
+                    submit = Some(export.clone());
+                    gui_state.status_del(Status::LogExport);
+                }
+                KeyCode::Esc | KeyCode::Char('s' | 'S') => gui_state.status_del(Status::LogExport),
+                _ => (),
+            }
+            drop(gui_state);
+            submit
+        };
+        if let Some(export) = submit {
+            self.save_logs(&export).await.ok();
+        }
+    }
+
+    /// Save the logs, the error is also shown in the gui, an error popup if the logs couldn't be read, or written
+    async fn save_logs(&self, export: &LogExport) -> Result<(), String> {
         /// This is the inner workings, *inlined* here to return a Result
         async fn save_logs(
             app_data: &Arc<Mutex<AppData>>,
             gui_state: &Arc<Mutex<GuiState>>,
             docker_tx: &Sender<DockerMessage>,
+            export: &LogExport,
         ) -> Result<(), Box<dyn std::error::Error>> {
             let args = app_data.lock().args.clone();
-            let container = app_data.lock().get_selected_container_id_state_name();
-            if let Some((id, _, name)) = container {
-                if let Some(log_path) = args.save_dir {
-                    let (sx, rx) = tokio::sync::oneshot::channel::<Arc<Docker>>();
-                    docker_tx.send(DockerMessage::Exec(sx)).await?;
-
-                    let now = SystemTime::now()
-                        .duration_since(SystemTime::UNIX_EPOCH)
-                        .map_or(0, |i| i.as_secs());
-
-                    let path = log_path.join(format!("{name}_{now}.log"));
-
-                    let docker = rx.await?;
-                    let options = Some(LogsOptions::<String> {
-                        stdout: true,
-                        timestamps: args.timestamp,
-                        since: 0,
-                        ..Default::default()
-                    });
-                    let mut logs = docker.logs(id.get(), options);
-                    let mut output = vec![];
-
-                    while let Some(Ok(value)) = logs.next().await {
-                        let data = value.to_string();
-                        if !data.trim().is_empty() {
-                            output.push(
-                                categorise_text(&data)
-                                    .into_iter()
-                                    .map(|i| i.text)
-                                    .collect::<String>(),
-                            );
+            if let Some(log_path) = args.save_dir {
+                let now = SystemTime::now()
+                    .duration_since(SystemTime::UNIX_EPOCH)
+                    .map_or(0, |i| i.as_secs());
+
+                let path = log_path.join(export.file_name(now));
+
+                // Timestamps are always needed for the json & csv fields, plain text only has them with `--timestamp`
+                let timestamps = export.format != ExportFormat::Text || args.timestamp;
+                let options = LogsOptions::<String> {
+                    stdout: true,
+                    stderr: true,
+                    timestamps,
+                    since: i64::try_from(export.range.since(now)).unwrap_or_default(),
+                    ..Default::default()
+                };
+                let (sx, rx) = tokio::sync::oneshot::channel::<Vec<LogOutput>>();
+                docker_tx
+                    .send(DockerMessage::ReadLogs(export.id.clone(), options, sx))
+                    .await?;
+                let mut output = vec![];
+
+                for value in rx.await? {
+                    let stream = if matches!(value, LogOutput::StdErr { .. }) {
+                        "stderr"
+                    } else {
+                        "stdout"
+                    };
+                    let data = log_sanitizer::decode(&value.into_bytes(), args.invalid_utf8);
+                    if !data.trim().is_empty() {
+                        let line = categorise_text(&data)
+                            .into_iter()
+                            .map(|i| i.text)
+                            .collect::<String>();
+                        let line = line.trim_end_matches(['\n', '\r']);
+                        let (timestamp, text) = if timestamps {
+                            line.split_once(' ')
+                                .map_or((None, line), |(x, y)| (Some(x), y))
+                        } else {
+                            (None, line)
+                        };
+                        if export.keep(text) {
+                            let text = redact(&args.redact, text);
+                            output.push(export.format_line(timestamp, stream, &text));
                         }
                     }
-                    if !output.is_empty() {
-                        let mut stream = BufWriter::new(
-                            OpenOptions::new()
-                                .read(true)
-                                .write(true)
-                                .create(true)
-                                .truncate(true)
-                                .open(&path)?,
-                        );
-
-                        for line in &output {
-                            stream.write_all(line.as_bytes())?;
-                        }
-                        stream.flush()?;
+                }
+                if output.is_empty() {
+                    gui_state.lock().set_info_box("no logs to save");
+                } else {
+                    let mut stream = BufWriter::new(
+                        OpenOptions::new()
+                            .read(true)
+                            .write(true)
+                            .create(true)
+                            .truncate(true)
+                            .open(&path)?,
+                    );
 
-                        gui_state
-                            .lock()
-                            .set_info_box(&format!("saved to {}", path.display()));
+                    if let Some(header) = export.header() {
+                        stream.write_all(header.as_bytes())?;
+                    }
+                    for line in &output {
+                        stream.write_all(line.as_bytes())?;
                     }
+                    stream.flush()?;
+
+                    gui_state
+                        .lock()
+                        .set_info_box(&format!("saved to {}", path.display()));
                 }
             }
             Ok(())
@@ -244,31 +2432,104 @@ impl InputHandler {
 
         let log_status = Status::Logs;
         let status = self.gui_state.lock().status_contains(&[log_status]);
-        if !status {
-            self.gui_state.lock().status_push(log_status);
+        if status {
+            return Err("logs are already being saved".to_owned());
+        }
+        self.gui_state.lock().status_push(log_status);
 
-            let uuid = Uuid::new_v4();
-            let handle = GuiState::start_loading_animation(&self.gui_state, uuid);
-            if save_logs(&self.app_data, &self.gui_state, &self.docker_tx)
-                .await
-                .is_err()
-            {
+        let uuid = Uuid::new_v4();
+        let handle = GuiState::start_loading_animation(&self.gui_state, uuid);
+        let result = save_logs(&self.app_data, &self.gui_state, &self.docker_tx, export)
+            .await
+            .map_err(|e| {
                 self.app_data.lock().set_error(
                     AppError::DockerLogs,
                     &self.gui_state,
                     Status::Error,
                 );
-            }
-            self.gui_state.lock().status_del(log_status);
-            self.gui_state.lock().stop_loading_animation(&handle, uuid);
+                format!("unable to save logs: {e}")
+            });
+        self.gui_state.lock().status_del(log_status);
+        self.gui_state.lock().stop_loading_animation(&handle, uuid);
+        result
+    }
+
+    /// Send docker command, if the Commands panel is selected, or expand the selected log line, if it was truncated, or is binary
+    /// Send a docker control, for a container, to the docker data handler
+    async fn send_control(&self, control: DockerControls, id: ContainerId) {
+        let message = match control {
+            DockerControls::Delete => DockerMessage::Delete(id),
+            DockerControls::Kill(signal) => DockerMessage::Kill(id, signal),
+            DockerControls::Pause => DockerMessage::Pause(id),
+            DockerControls::Recreate => DockerMessage::RecreateForm(id),
+            DockerControls::Rename => DockerMessage::RenameForm(id),
+            DockerControls::Resume => DockerMessage::Resume(id),
+            DockerControls::Start => DockerMessage::Start(id),
+            DockerControls::Stop => DockerMessage::Stop(id),
+            DockerControls::Restart => DockerMessage::Restart(id),
+            DockerControls::Update => DockerMessage::PullRecreate(id),
+        };
+        self.docker_tx.send(message).await.ok();
+    }
+
+    /// Send a control to every container of a compose project that it applies to, e.g. stop is only sent to the running containers
+    async fn project_control(&self, control: DockerControls, project: &str) {
+        let ids = self.app_data.lock().get_project_ids(project, control);
+        if ids.is_empty() {
+            self.gui_state
+                .lock()
+                .set_info_box(&format!("no containers of {project} to {control}"));
+        } else {
+            self.docker_tx
+                .send(DockerMessage::ProjectControl(control, ids))
+                .await
+                .ok();
         }
     }
 
-    /// Send docker command, if the Commands panel is selected
     async fn enter_key(&mut self) {
         // This isn't great, just means you can't send docker commands before full initialization of the program
         let panel = self.gui_state.lock().get_selected_panel();
-        if panel == SelectablePanel::Commands {
+        if panel == SelectablePanel::Containers
+            && self.app_data.lock().get_selected_group().is_some()
+        {
+            self.group_expand(None);
+        } else if panel == SelectablePanel::Images {
+            let image = {
+                let app_data = self.app_data.lock();
+                app_data
+                    .get_selected_image()
+                    .cloned()
+                    .map(|i| (app_data.get_resources_host().map(ToOwned::to_owned), i))
+            };
+            if let Some((host, image)) = image {
+                self.docker_tx
+                    .send(DockerMessage::ImageDetail(host, image))
+                    .await
+                    .ok();
+            }
+        } else if matches!(panel, SelectablePanel::Volumes | SelectablePanel::Networks) {
+            self.remove_resource(panel).await;
+        } else if panel == SelectablePanel::Services {
+            let service = {
+                let app_data = self.app_data.lock();
+                app_data
+                    .get_selected_service()
+                    .cloned()
+                    .map(|i| (app_data.get_resources_host().map(ToOwned::to_owned), i))
+            };
+            if let Some((host, service)) = service {
+                self.docker_tx
+                    .send(DockerMessage::TaskLogs(host, service))
+                    .await
+                    .ok();
+            }
+        } else if panel == SelectablePanel::Logs {
+            let detail = self.app_data.lock().get_selected_log_detail();
+            if detail.is_some() {
+                self.gui_state.lock().set_expanded_log(detail);
+            }
+        } else if panel == SelectablePanel::Commands {
             let option_command = self.app_data.lock().selected_docker_controls();
 
             if let Some(command) = option_command {
@@ -276,32 +2537,110 @@ impl InputHandler {
                 if self.app_data.lock().is_oxker_in_container() {
                     return;
                 };
-                let option_id = self.app_data.lock().get_selected_container_id();
-                if let Some(id) = option_id {
-                    match command {
-                        DockerControls::Delete => self
-                            .docker_tx
-                            .send(DockerMessage::ConfirmDelete(id))
-                            .await
-                            .ok(),
-                        DockerControls::Pause => {
-                            self.docker_tx.send(DockerMessage::Pause(id)).await.ok()
-                        }
-                        DockerControls::Resume => {
-                            self.docker_tx.send(DockerMessage::Resume(id)).await.ok()
-                        }
-                        DockerControls::Start => {
-                            self.docker_tx.send(DockerMessage::Start(id)).await.ok()
-                        }
-                        DockerControls::Stop => {
-                            self.docker_tx.send(DockerMessage::Stop(id)).await.ok()
-                        }
-                        DockerControls::Restart => {
-                            self.docker_tx.send(DockerMessage::Restart(id)).await.ok()
-                        }
-                    };
+                if self.app_data.lock().get_marked_len() > 0 {
+                    self.marked_control(command).await;
+                    return;
+                }
+                let project = self
+                    .app_data
+                    .lock()
+                    .get_selected_project()
+                    .map(ToOwned::to_owned);
+                if let Some(project) = project {
+                    self.project_control(command, &project).await;
+                    return;
+                }
+                let selected = self
+                    .app_data
+                    .lock()
+                    .get_selected_container()
+                    .map(|i| (i.id.clone(), i.name.get().to_owned()));
+                match (command, selected) {
+                    // The signal is picked first, then confirmed, if kill requires confirmation
+                    (DockerControls::Kill(_), Some((id, name))) => self
+                        .gui_state
+                        .lock()
+                        .set_kill_picker(Some(KillPicker::new(id, name))),
+                    (_, Some((id, _))) => self.confirm_control(command, id).await,
+                    (_, None) => (),
+                }
+            }
+        }
+    }
+
+    /// Send a control to a container, or first ask for confirmation, if the control requires it, or if the container was just controlled from elsewhere
+    async fn confirm_control(&self, command: DockerControls, id: ContainerId) {
+        // The recreate, & rename, forms are their own confirmation
+        if !matches!(command, DockerControls::Recreate | DockerControls::Rename) && {
+            let app_data = self.app_data.lock();
+            app_data.requires_confirm(command) || app_data.concurrent_event(&id).is_some()
+        } {
+            self.docker_tx
+                .send(DockerMessage::Confirm(command, id))
+                .await
+                .ok();
+        } else {
+            self.send_control(command, id).await;
+        }
+    }
+
+    /// Pick the signal to kill a container with, ( enter ) kills it, once confirmed, if kill requires confirmation
+    async fn kill_key(&self, key_code: KeyCode) {
+        let kill = {
+            let mut gui_state = self.gui_state.lock();
+            let Some(picker) = gui_state.get_kill_picker() else {
+                return;
+            };
+            let mut kill = None;
+            match key_code {
+                KeyCode::Up | KeyCode::Char('k' | 'K') => picker.previous(),
+                KeyCode::Down | KeyCode::Char('j' | 'J') => picker.next(),
+                KeyCode::Esc => gui_state.status_del(Status::Kill),
+                KeyCode::Enter => {
+                    kill = Some((picker.chosen(), picker.id.clone()));
+                    gui_state.status_del(Status::Kill);
+                }
+                _ => (),
+            }
+            drop(gui_state);
+            kill
+        };
+        if let Some((signal, id)) = kill {
+            self.confirm_control(DockerControls::Kill(signal), id).await;
+        }
+    }
+
+    /// Edit the rename form, chars are typed into the name, so none of the usual keys, including q, apply
+    /// ( enter ) renames the container, an invalid name keeps the form open, with the reason shown
+    async fn rename_key(&self, key_code: KeyCode) {
+        let submit = {
+            let mut gui_state = self.gui_state.lock();
+            let Some(form) = gui_state.get_rename_form() else {
+                return;
+            };
+            let mut submit = None;
+            match key_code {
+                KeyCode::Char(c) => form.text.push(c),
+                KeyCode::Backspace => {
+                    form.text.pop();
+                }
+                KeyCode::Esc => gui_state.status_del(Status::Rename),
+                KeyCode::Enter => {
+                    if let Some(name) = form.submit() {
+                        submit = Some((form.id.clone(), name));
+                        gui_state.status_del(Status::Rename);
+                    }
                 }
+                _ => (),
             }
+            drop(gui_state);
+            submit
+        };
+        if let Some((id, name)) = submit {
+            self.docker_tx
+                .send(DockerMessage::Rename(id, name))
+                .await
+                .ok();
         }
     }
 
@@ -334,39 +2673,127 @@ impl InputHandler {
 
     fn home_key(&mut self) {
         let mut locked_data = self.app_data.lock();
-        let selected_panel = self.gui_state.lock().get_selected_panel();
+        let selected_panel = {
+            let mut gui_state = self.gui_state.lock();
+            gui_state.set_remove_armed(None);
+            gui_state.get_selected_panel()
+        };
         match selected_panel {
             SelectablePanel::Containers => locked_data.containers_start(),
             SelectablePanel::Logs => locked_data.log_start(),
             SelectablePanel::Commands => locked_data.docker_controls_start(),
+            SelectablePanel::Images => locked_data.images_start(),
+            SelectablePanel::Volumes => locked_data.volumes_start(),
+            SelectablePanel::Networks => locked_data.networks_start(),
+            SelectablePanel::Services => locked_data.services_start(),
         }
     }
 
     /// Go to end of the list of the currently selected panel
     fn end_key(&mut self) {
         let mut locked_data = self.app_data.lock();
-        let selected_panel = self.gui_state.lock().get_selected_panel();
+        let selected_panel = {
+            let mut gui_state = self.gui_state.lock();
+            gui_state.set_remove_armed(None);
+            gui_state.get_selected_panel()
+        };
         match selected_panel {
             SelectablePanel::Containers => locked_data.containers_end(),
             SelectablePanel::Logs => locked_data.log_end(),
             SelectablePanel::Commands => locked_data.docker_controls_end(),
+            SelectablePanel::Images => locked_data.images_end(),
+            SelectablePanel::Volumes => locked_data.volumes_end(),
+            SelectablePanel::Networks => locked_data.networks_end(),
+            SelectablePanel::Services => locked_data.services_end(),
         }
     }
 
     /// Handle keyboard button events
+    #[allow(clippy::too_many_lines)]
     async fn button_press(&mut self, key_code: KeyCode, key_modififer: KeyModifiers) {
-        let contains_delete = self
-            .gui_state
-            .lock()
-            .status_contains(&[Status::DeleteConfirm]);
+        let contains_confirm = self.gui_state.lock().status_contains(&[Status::Confirm]);
 
         let contains = |s: Status| self.gui_state.lock().status_contains(&[s]);
 
         let contains_error = contains(Status::Error);
         let contains_help = contains(Status::Help);
         let contains_exec = contains(Status::Exec);
+        let contains_expanded = contains(Status::ExpandedLog);
+        let contains_recreate = contains(Status::Recreate);
+        let contains_run = contains(Status::Run);
+        let contains_matches = contains(Status::LogMatches);
+        let contains_note = contains(Status::Note);
+        let contains_stdin = contains(Status::Stdin);
+        let contains_rename = contains(Status::Rename);
+        let contains_scale = contains(Status::Scale);
+        let contains_capture = contains(Status::Capture);
+        let contains_limits = contains(Status::Limits);
+        let contains_schedules = contains(Status::Schedules);
+        let contains_filters = contains(Status::Filters);
+        let contains_command = contains(Status::Command);
+        let contains_search = contains(Status::Search);
+        let contains_finder = contains(Status::Finder);
+        let contains_log_search = contains(Status::LogSearch);
+        let contains_log_filter = contains(Status::LogFilter);
+        let contains_tags = contains(Status::Tags);
+        let contains_reach = contains(Status::Reach);
+        let contains_hosts = contains(Status::Hosts);
+        let contains_recent = contains(Status::Recent);
+        let contains_kill = contains(Status::Kill);
+        let contains_contexts = contains(Status::Contexts);
+        let contains_timeline = contains(Status::Timeline);
+        let contains_prune = contains(Status::Prune);
+        let contains_build_cache = contains(Status::BuildCache);
+        let contains_cleanup = contains(Status::Cleanup);
+        let contains_secret_scan = contains(Status::SecretScan);
+        let contains_health = contains(Status::Health);
+        let contains_task_logs = contains(Status::TaskLogs);
+        let contains_ports = contains(Status::Ports);
+        let contains_files = contains(Status::Files);
+        let contains_log_export = contains(Status::LogExport);
+        let contains_traffic = contains(Status::Traffic);
+        let contains_memory = contains(Status::Memory);
+        let contains_cores = contains(Status::Cores);
+        let contains_layout = contains(Status::Layout);
+        let contains_daemon = contains(Status::Daemon);
+        let contains_image_detail = contains(Status::ImageDetail);
+        let contains_inspect = contains(Status::Inspect);
+        let contains_compare = contains(Status::Compare);
+        let contains_pull = contains(Status::Pull);
+        let contains_failed = contains(Status::Failed);
 
-        if !contains_exec {
+        if contains_recreate {
+            self.recreate_key(key_code).await;
+        } else if contains_run {
+            self.run_key(key_code).await;
+        } else if contains_note {
+            self.note_key(key_code);
+        } else if contains_stdin {
+            self.stdin_key(key_code).await;
+        } else if contains_rename {
+            self.rename_key(key_code).await;
+        } else if contains_scale {
+            self.scale_key(key_code).await;
+        } else if contains_capture && !contains(Status::Pull) {
+            // The pull of the capture image takes ( esc ) first, to cancel it
+            self.capture_key(key_code).await;
+        } else if contains_limits {
+            self.limits_key(key_code).await;
+        } else if contains_schedules {
+            self.schedules_key(key_code);
+        } else if contains_filters {
+            self.filters_key(key_code);
+        } else if contains_command {
+            self.command_key(key_code).await;
+        } else if contains_search {
+            self.search_key(key_code);
+        } else if contains_finder {
+            self.finder_key(key_code);
+        } else if contains_log_search {
+            self.log_search_key(key_code);
+        } else if contains_log_filter {
+            self.log_filter_key(key_code);
+        } else if !contains_exec {
             // Always just quit on Ctrl + c/C or q/Q
             let is_c = || key_code == KeyCode::Char('c') || key_code == KeyCode::Char('C');
             let is_q = || key_code == KeyCode::Char('q') || key_code == KeyCode::Char('Q');
@@ -379,16 +2806,97 @@ impl InputHandler {
                     self.app_data.lock().remove_error();
                     self.gui_state.lock().status_del(Status::Error);
                 }
+            } else if contains_pull {
+                // The pull clears its own progress, once it's stopped
+                if key_code == KeyCode::Esc {
+                    if let Some(progress) = self.gui_state.lock().get_pull_progress_ref() {
+                        progress.cancel();
+                    }
+                }
+            } else if contains_failed {
+                self.failed_key(key_code).await;
             } else if contains_help {
                 match key_code {
                     KeyCode::Char('h' | 'H') => self.gui_state.lock().status_del(Status::Help),
                     KeyCode::Char('m' | 'M') => self.m_key(),
                     _ => (),
                 }
-            } else if contains_delete {
+            } else if contains_expanded {
+                let mut gui_state = self.gui_state.lock();
+                match key_code {
+                    KeyCode::Enter | KeyCode::Esc => gui_state.status_del(Status::ExpandedLog),
+                    KeyCode::Up | KeyCode::Char('k' | 'K') => {
+                        gui_state.scroll_expanded_log(true, 1);
+                    }
+                    KeyCode::Down | KeyCode::Char('j' | 'J') => {
+                        gui_state.scroll_expanded_log(false, 1);
+                    }
+                    KeyCode::PageUp => gui_state.scroll_expanded_log(true, 7),
+                    KeyCode::PageDown => gui_state.scroll_expanded_log(false, 7),
+                    KeyCode::Home => gui_state.scroll_expanded_log(true, usize::MAX),
+                    KeyCode::End => gui_state.scroll_expanded_log(false, usize::MAX),
+                    _ => (),
+                }
+            } else if contains_tags {
+                self.tags_key(key_code).await;
+            } else if contains_reach {
+                self.reach_key(key_code).await;
+            } else if contains_contexts {
+                self.contexts_key(key_code);
+            } else if contains_hosts {
+                self.hosts_key(key_code);
+            } else if contains_recent {
+                self.recent_key(key_code);
+            } else if contains_kill {
+                self.kill_key(key_code).await;
+            } else if contains_timeline {
+                self.timeline_key(key_code);
+            } else if contains_prune {
+                self.prune_key(key_code).await;
+            } else if contains_build_cache {
+                self.build_cache_key(key_code).await;
+            } else if contains_secret_scan {
+                self.secret_scan_key(key_code);
+            } else if contains_health {
+                self.health_key(key_code);
+            } else if contains_task_logs {
+                self.task_logs_key(key_code);
+            } else if contains_ports {
+                self.ports_key(key_code).await;
+            } else if contains_files {
+                self.files_key(key_code).await;
+            } else if contains_cleanup {
+                self.cleanup_key(key_code).await;
+            } else if contains_log_export {
+                self.log_export_key(key_code).await;
+            } else if contains_traffic {
+                self.traffic_key(key_code);
+            } else if contains_memory {
+                if let KeyCode::Char('*') | KeyCode::Esc = key_code {
+                    self.gui_state.lock().status_del(Status::Memory);
+                }
+            } else if contains_cores {
+                if let KeyCode::Char('}') | KeyCode::Esc = key_code {
+                    self.gui_state.lock().status_del(Status::Cores);
+                }
+            } else if contains_layout {
+                self.layout_key(key_code);
+            } else if contains_image_detail {
+                self.image_detail_key(key_code).await;
+            } else if contains_inspect {
+                self.inspect_key(key_code);
+            } else if contains_compare {
+                self.compare_key(key_code);
+            } else if contains_daemon {
+                if let KeyCode::Char('d' | 'D') | KeyCode::Esc = key_code {
+                    self.gui_state.lock().status_del(Status::Daemon);
+                }
+            } else if contains_matches {
+                self.matches_key(key_code);
+            } else if contains_confirm {
                 match key_code {
-                    KeyCode::Char('y' | 'Y') => self.confirm_delete().await,
-                    KeyCode::Char('n' | 'N') => self.clear_delete(),
+                    KeyCode::Char('y' | 'Y') => self.confirm().await,
+                    KeyCode::Char('n' | 'N') => self.clear_confirm(),
                     _ => (),
                 }
             } else {
@@ -403,10 +2911,67 @@ impl InputHandler {
                     KeyCode::Char('7') => self.sort(Header::Image),
                     KeyCode::Char('8') => self.sort(Header::Rx),
                     KeyCode::Char('9') => self.sort(Header::Tx),
-                    KeyCode::Char('e' | 'E') => self.e_key().await,
+                    KeyCode::Char('a' | 'A') => self.a_key(),
+                    KeyCode::Char('b' | 'B') => self.b_key().await,
+                    KeyCode::Char('c' | 'C') => self.c_key().await,
+                    KeyCode::Char('d' | 'D') => self.d_key(),
+                    KeyCode::Char('e') => self.e_key().await,
+                    KeyCode::Char('E') => self.attach().await,
+                    KeyCode::Char('g' | 'G') => self.g_key(),
                     KeyCode::Char('h' | 'H') => self.gui_state.lock().status_push(Status::Help),
+                    KeyCode::Char('i' | 'I') => self.i_key().await,
+                    KeyCode::Char('l' | 'L') => self.l_key().await,
                     KeyCode::Char('m' | 'M') => self.m_key(),
-                    KeyCode::Char('s' | 'S') => self.s_key().await,
+                    KeyCode::Char('n') => self.n_or_jump(true),
+                    KeyCode::Char('N') => self.n_or_jump(false),
+                    KeyCode::Char('o' | 'O') => self.o_key(),
+                    KeyCode::Char('p' | 'P') => self.p_key().await,
+                    KeyCode::Char('r' | 'R') => self.r_key().await,
+                    KeyCode::Char('s' | 'S') => self.s_key(),
+                    KeyCode::Char('t') => self.t_key(),
+                    KeyCode::Char('T') => self.shift_t_key(),
+                    KeyCode::Char('u' | 'U') => self.u_key(),
+                    KeyCode::Char('v' | 'V') => self.v_key(),
+                    KeyCode::Char('w' | 'W') => self.w_key().await,
+                    KeyCode::Char('f' | 'F') => self.f_key().await,
+                    KeyCode::Char('x' | 'X') => self.gui_state.lock().request_snapshot(),
+                    KeyCode::Char('y' | 'Y') => self.y_key().await,
+                    KeyCode::Char('z' | 'Z') => self.gui_state.lock().status_push(Status::Standby),
+                    KeyCode::Char('/') => self.slash_key(),
+                    KeyCode::Char('&') => self.ampersand_key(),
+                    KeyCode::Char('@') => self.at_key(),
+                    KeyCode::Char('?') => self.question_key().await,
+                    KeyCode::Char('!') => self.exclamation_key(),
+                    KeyCode::Char('>') => self.greater_than_key().await,
+                    KeyCode::Char('<') => self.less_than_key().await,
+                    KeyCode::Char('{') => self.brace_key(),
+                    KeyCode::Char('^') => self.caret_key().await,
+                    KeyCode::Char('+') => self.plus_key(),
+                    KeyCode::Char('-') => self.minus_key(),
+                    KeyCode::Char('*') => self.asterisk_key(),
+                    KeyCode::Char('}') => self.close_brace_key(),
+                    KeyCode::Char('`') => self.backtick_key(),
+                    KeyCode::Char(' ') => self.space_key(),
+                    KeyCode::Char('%') => self.percent_key().await,
+                    KeyCode::Char('#') => self.hash_key().await,
+                    KeyCode::Char(']') => self.app_data.lock().chart_zoom(true),
+                    KeyCode::Char('[') => self.app_data.lock().chart_zoom(false),
+                    KeyCode::Char('|') => self.pipe_key(),
+                    KeyCode::Char('=') => self.equals_key(),
+                    KeyCode::Char('~') => self.tilde_key(),
+                    KeyCode::Char(';') => self.semicolon_key().await,
+                    KeyCode::Char('$') => self.dollar_key(),
+                    KeyCode::Char(':') => self.colon_key().await,
+                    KeyCode::Char('\'') => self.apostrophe_key().await,
+                    KeyCode::Char(',') => self.comma_key(),
+                    KeyCode::Char('.') => self.period_key().await,
+                    KeyCode::Char('_') => self.underscore_key().await,
+                    KeyCode::Char('"') => self.quote_key(),
+                    KeyCode::Char('\\') => self.backslash_key(),
+                    KeyCode::Backspace => self.backspace_key(),
+                    KeyCode::Char('(') => self.parenthesis_key().await,
+                    KeyCode::Char(')') => self.close_parenthesis_key(),
+                    KeyCode::Esc => self.esc_key(),
                     KeyCode::Tab => self.tab_key(),
                     KeyCode::BackTab => self.back_tab_key(),
                     KeyCode::Home => self.home_key(),
@@ -423,7 +2988,15 @@ impl InputHandler {
                             self.next();
                         }
                     }
+                    KeyCode::Left => self.group_expand(Some(false)),
+                    KeyCode::Right => self.group_expand(Some(true)),
                     KeyCode::Enter => self.enter_key().await,
+                    KeyCode::Delete
+                        if self.gui_state.lock().get_selected_panel()
+                            == SelectablePanel::Services =>
+                    {
+                        self.remove_stack().await;
+                    }
                     _ => (),
                 }
             }
@@ -442,34 +3015,146 @@ impl InputHandler {
 
             if let Some(button) = intersect {
                 match button {
-                    DeleteButton::Yes => self.confirm_delete().await,
-                    DeleteButton::No => self.clear_delete(),
+                    DeleteButton::Yes => self.confirm().await,
+                    DeleteButton::No => self.clear_confirm(),
+                }
+            }
+        }
+    }
+
+    /// Find the index of the row, in the containers panel, at a given screen position
+    fn container_row_at(&self, column: u16, row: u16) -> Option<usize> {
+        let area = self
+            .gui_state
+            .lock()
+            .get_panel_area(SelectablePanel::Containers)?;
+        // Ignore the borders
+        if column <= area.left()
+            || column >= area.right().saturating_sub(1)
+            || row <= area.top()
+            || row >= area.bottom().saturating_sub(1)
+        {
+            return None;
+        }
+        let mut app_data = self.app_data.lock();
+        let index = app_data.get_container_state().offset() + usize::from(row - area.top() - 1);
+        (index < app_data.get_container_rows().len()).then_some(index)
+    }
+
+    /// Open a url in the default web browser
+    fn open_browser(&self, url: &str) {
+        #[cfg(target_os = "macos")]
+        let command = std::process::Command::new("open").arg(url).spawn();
+        #[cfg(target_os = "windows")]
+        let command = std::process::Command::new("cmd")
+            .args(["/C", "start", "", url])
+            .spawn();
+        #[cfg(not(any(target_os = "macos", target_os = "windows")))]
+        let command = std::process::Command::new("xdg-open")
+            .arg(url)
+            .stdout(std::process::Stdio::null())
+            .stderr(std::process::Stdio::null())
+            .spawn();
+        let text = if command.is_ok() {
+            format!("opening {url}")
+        } else {
+            format!("unable to open {url}")
+        };
+        self.gui_state.lock().set_info_box(&text);
+    }
+
+    /// Run the configured action on a clicked row of the containers panel
+    fn click_action(&self, action: ClickAction, index: usize) {
+        if action == ClickAction::None {
+            return;
+        }
+        self.app_data.lock().containers_select(index);
+        match action {
+            ClickAction::Browser => {
+                let url = self.app_data.lock().get_selected_url();
+                if let Some(url) = url {
+                    self.open_browser(&url);
+                } else {
+                    self.gui_state.lock().set_info_box("no published port");
                 }
             }
+            ClickAction::Logs => self
+                .gui_state
+                .lock()
+                .set_selected_panel(SelectablePanel::Logs),
+            ClickAction::Menu => self
+                .gui_state
+                .lock()
+                .set_selected_panel(SelectablePanel::Commands),
+            ClickAction::None | ClickAction::Select => (),
+        }
+    }
+
+    /// If a screen row is the bottom border of the containers, & commands, panels, which can be dragged to resize them
+    fn on_split(&self, row: u16) -> bool {
+        self.gui_state
+            .lock()
+            .get_panel_area(SelectablePanel::Containers)
+            .is_some_and(|area| row + 1 == area.bottom())
+    }
+
+    /// Resize the containers panel, so that its bottom border is on the given row
+    fn drag_split(&self, row: u16) {
+        let mut gui_state = self.gui_state.lock();
+        if let Some(area) = gui_state.get_panel_area(SelectablePanel::Containers) {
+            gui_state.set_containers_height(Some(row.saturating_sub(area.top()) + 1));
         }
     }
 
-    /// Handle mouse button events
+    /// Handle mouse button events, the wheel scrolls the panel under the pointer
     fn mouse_press(&mut self, mouse_event: MouseEvent) {
+        let pointer = Rect::new(mouse_event.column, mouse_event.row, 1, 1);
         match mouse_event.kind {
-            MouseEventKind::ScrollUp => self.previous(),
-            MouseEventKind::ScrollDown => self.next(),
+            MouseEventKind::ScrollUp => {
+                self.gui_state.lock().panel_intersect(pointer);
+                self.previous();
+            }
+            MouseEventKind::ScrollDown => {
+                self.gui_state.lock().panel_intersect(pointer);
+                self.next();
+            }
+            MouseEventKind::Drag(MouseButton::Left) if self.dragging => {
+                self.drag_split(mouse_event.row);
+            }
+            MouseEventKind::Up(MouseButton::Left) => self.dragging = false,
+            MouseEventKind::Down(MouseButton::Left) if self.on_split(mouse_event.row) => {
+                // A double click on the border sizes the panel to the containers again
+                if self.clicks.click(mouse_event.column, mouse_event.row) {
+                    self.gui_state.lock().set_containers_height(None);
+                } else {
+                    self.dragging = true;
+                }
+            }
             MouseEventKind::Down(MouseButton::Left) => {
-                if let Some(header) = self.gui_state.lock().header_intersect(Rect::new(
-                    mouse_event.column,
-                    mouse_event.row,
-                    1,
-                    1,
-                )) {
+                if let Some(header) = self.gui_state.lock().header_intersect(pointer) {
                     self.sort(header);
                 }
 
-                self.gui_state.lock().panel_intersect(Rect::new(
-                    mouse_event.column,
-                    mouse_event.row,
-                    1,
-                    1,
-                ));
+                self.gui_state.lock().panel_intersect(pointer);
+
+                let double = self.clicks.click(mouse_event.column, mouse_event.row);
+                if let Some(index) = self.container_row_at(mouse_event.column, mouse_event.row) {
+                    let action = {
+                        let args = &self.app_data.lock().args;
+                        if double {
+                            args.double_click
+                        } else {
+                            args.click
+                        }
+                    };
+                    self.click_action(action, index);
+                }
+            }
+            MouseEventKind::Down(MouseButton::Middle) => {
+                if let Some(index) = self.container_row_at(mouse_event.column, mouse_event.row) {
+                    let action = self.app_data.lock().args.middle_click;
+                    self.click_action(action, index);
+                }
             }
             _ => (),
         }
@@ -478,22 +3163,38 @@ impl InputHandler {
     /// Change state to next, depending which panel is currently in focus
     fn next(&mut self) {
         let mut locked_data = self.app_data.lock();
-        let selected_panel = self.gui_state.lock().get_selected_panel();
+        let selected_panel = {
+            let mut gui_state = self.gui_state.lock();
+            gui_state.set_remove_armed(None);
+            gui_state.get_selected_panel()
+        };
         match selected_panel {
             SelectablePanel::Containers => locked_data.containers_next(),
             SelectablePanel::Logs => locked_data.log_next(),
             SelectablePanel::Commands => locked_data.docker_controls_next(),
+            SelectablePanel::Images => locked_data.images_next(),
+            SelectablePanel::Volumes => locked_data.volumes_next(),
+            SelectablePanel::Networks => locked_data.networks_next(),
+            SelectablePanel::Services => locked_data.services_next(),
         };
     }
 
     /// Change state to previous, depending which panel is currently in focus
     fn previous(&mut self) {
         let mut locked_data = self.app_data.lock();
-        let selected_panel = self.gui_state.lock().get_selected_panel();
+        let selected_panel = {
+            let mut gui_state = self.gui_state.lock();
+            gui_state.set_remove_armed(None);
+            gui_state.get_selected_panel()
+        };
         match selected_panel {
             SelectablePanel::Containers => locked_data.containers_previous(),
             SelectablePanel::Logs => locked_data.log_previous(),
             SelectablePanel::Commands => locked_data.docker_controls_previous(),
+            SelectablePanel::Images => locked_data.images_previous(),
+            SelectablePanel::Volumes => locked_data.volumes_previous(),
+            SelectablePanel::Networks => locked_data.networks_previous(),
+            SelectablePanel::Services => locked_data.services_previous(),
         }
     }
 }
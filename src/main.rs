@@ -4,10 +4,12 @@
 use app_data::AppData;
 use app_error::AppError;
 use bollard::{Docker, API_DEFAULT_VERSION};
-use docker_data::DockerData;
+use docker_data::{
+    DemoRuntime, DockerContext, DockerData, DockerRouter, RuntimeKind, SshTunnel, TlsFiles,
+};
 use input_handler::InputMessages;
 use parking_lot::Mutex;
-use parse_args::CliArgs;
+use parse_args::{CliArgs, Command};
 use std::{
     process,
     sync::{
@@ -20,13 +22,17 @@ use tracing::{error, info, Level};
 
 mod app_data;
 mod app_error;
+mod config_file;
 mod docker_data;
 mod exec;
 mod input_handler;
+mod metrics;
 mod parse_args;
+mod setup;
 mod ui;
+mod update_check;
 
-use ui::{GuiState, Status, Ui};
+use ui::{ContextPicker, GuiState, Status, Ui};
 
 use crate::docker_data::DockerMessage;
 
@@ -38,49 +44,169 @@ const DOCKER_HOST: &str = "DOCKER_HOST";
 
 /// Enable tracing, only really used in debug mode, for now
 /// write to file if `-g` is set?
+/// Written to stderr, so that stdout is only ever the `--output`
 fn setup_tracing() {
-    tracing_subscriber::fmt().with_max_level(Level::INFO).init();
+    tracing_subscriber::fmt()
+        .with_writer(std::io::stderr)
+        .with_max_level(Level::INFO)
+        .init();
+}
+
+/// Resolve the optional docker_host path, as the docker CLI does, the cli args take priority, then the `--context` arg, then the DOCKER_HOST env, then the current docker context
+/// When none of these are set, the stored docker contexts are returned, so that one can be picked at startup
+/// The Podman runtime has no docker contexts, so its own default host is used
+fn read_docker_host(args: &mut CliArgs) -> Vec<DockerContext> {
+    if args.host.is_some() {
+        return vec![];
+    }
+    // Docker contexts are only used by the Docker CLI
+    if args.runtime == RuntimeKind::Podman {
+        args.host = args.runtime.default_host();
+        return vec![];
+    }
+    let dir = DockerContext::config_dir().unwrap_or_default();
+    let name = if let Some(name) = args.context.clone() {
+        name
+    } else if let Ok(host) = std::env::var(DOCKER_HOST) {
+        args.host = Some(host);
+        return vec![];
+    } else if let Some(name) = DockerContext::current(&dir) {
+        name
+    } else {
+        return DockerContext::list(&dir);
+    };
+    match DockerContext::find(&dir, &name) {
+        Ok(context) => args.set_docker_context(&context),
+        Err(e) => {
+            error!("\"--context\" {e}");
+            process::exit(1)
+        }
+    }
+    vec![]
 }
 
-/// Read the optional docker_host path, the cli args take priority over the DOCKER_HOST env
-fn read_docker_host(args: &CliArgs) -> Option<String> {
-    args.host
-        .as_ref()
-        .map_or_else(|| std::env::var(DOCKER_HOST).ok(), |x| Some(x.to_string()))
+/// Connect to a docker daemon, either the given host, or the socket defaults
+/// A `ssh://` host is connected to via a ssh tunnel, which needs to be kept alive for as long as the connection is used, and a `tcp://` host, when TLS certificates are set, via TLS
+async fn docker_connect(
+    host: Option<&str>,
+    ssh_jump: Option<&str>,
+    tls: Option<&TlsFiles>,
+) -> Option<(Docker, Option<SshTunnel>)> {
+    let tunnel = match host {
+        Some(host) if SshTunnel::is_ssh(host) => Some(SshTunnel::open(host, ssh_jump).await?),
+        _ => None,
+    };
+    let socket = tunnel.as_ref().map(SshTunnel::socket);
+    let connection = match (socket.as_deref().or(host), tls) {
+        (Some(host), Some(tls)) if TlsFiles::is_tls(host) => tls.connect(host),
+        (Some(host), _) if host.starts_with("tcp://") || host.starts_with("http://") => {
+            Docker::connect_with_http(host, 120, API_DEFAULT_VERSION)
+        }
+        (Some(host), _) => Docker::connect_with_socket(host, 120, API_DEFAULT_VERSION),
+        (None, _) => Docker::connect_with_socket_defaults(),
+    };
+    match connection {
+        Ok(docker) if docker.ping().await.is_ok() => Some((docker, tunnel)),
+        _ => None,
+    }
 }
 
 /// Create docker daemon handler, and only spawn up the docker data handler if a ping returns non-error
+/// When extra hosts are given, each gets its own docker data handler, and a router forwards messages to the correct one, an extra host that can't be connected to is skipped
+/// Returns any ssh tunnels that have been opened, they are closed when dropped
 async fn docker_init(
     app_data: &Arc<Mutex<AppData>>,
     docker_rx: Receiver<DockerMessage>,
     docker_tx: Sender<DockerMessage>,
     gui_state: &Arc<Mutex<GuiState>>,
     is_running: &Arc<AtomicBool>,
-    host: Option<String>,
-) {
-    let connection = host.map_or_else(Docker::connect_with_socket_defaults, |host| {
-        Docker::connect_with_socket(&host, 120, API_DEFAULT_VERSION)
-    });
+) -> Vec<SshTunnel> {
+    // Wait for a docker context to be picked, when the picker is shown at startup
+    while gui_state.lock().status_contains(&[Status::Contexts]) {
+        if !is_running.load(Ordering::SeqCst) {
+            return vec![];
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+    }
+    if app_data.lock().args.demo {
+        tokio::spawn(DemoRuntime::init(
+            Arc::clone(app_data),
+            docker_rx,
+            docker_tx,
+            Arc::clone(gui_state),
+            Arc::clone(is_running),
+        ));
+        return vec![];
+    }
+    let (host, extra_hosts, ssh_jump, tls) = {
+        let args = &app_data.lock().args;
+        (
+            args.host.clone(),
+            args.extra_hosts.clone(),
+            args.ssh_jump.clone(),
+            args.tls.clone(),
+        )
+    };
+    let Some((docker, tunnel)) =
+        docker_connect(host.as_deref(), ssh_jump.as_deref(), tls.as_ref()).await
+    else {
+        app_data
+            .lock()
+            .set_error(AppError::DockerConnect, gui_state, Status::DockerConnect);
+        return vec![];
+    };
+    let mut tunnels = tunnel.into_iter().collect::<Vec<_>>();
 
-    if let Ok(docker) = connection {
-        if docker.ping().await.is_ok() {
-            let app_data = Arc::clone(app_data);
-            let gui_state = Arc::clone(gui_state);
-            let is_running = Arc::clone(is_running);
+    if extra_hosts.is_empty() {
+        tokio::spawn(DockerData::init(
+            Arc::clone(app_data),
+            docker,
+            docker_rx,
+            docker_tx,
+            Arc::clone(gui_state),
+            None,
+            Arc::clone(is_running),
+        ));
+        return tunnels;
+    }
 
+    let mut routes = vec![];
+    let connections = std::iter::once((None, Some((docker, None))))
+        .chain(
+            futures_util::future::join_all(extra_hosts.into_iter().map(|host| async {
+                let connection =
+                    docker_connect(Some(&host), ssh_jump.as_deref(), tls.as_ref()).await;
+                (Some(host), connection)
+            }))
+            .await,
+        )
+        .collect::<Vec<_>>();
+    // Each host looks for the `--select` container once it has listed its containers, it's only missing if none of them have it
+    app_data
+        .lock()
+        .set_select_hosts(connections.iter().filter(|(_, i)| i.is_some()).count());
+    for (host, connection) in connections {
+        if let Some((docker, tunnel)) = connection {
+            tunnels.extend(tunnel);
+            let (tx, rx) = tokio::sync::mpsc::channel(32);
+            routes.push((host.clone(), tx.clone()));
             tokio::spawn(DockerData::init(
-                app_data, docker, docker_rx, docker_tx, gui_state, is_running,
+                Arc::clone(app_data),
+                docker,
+                rx,
+                tx,
+                Arc::clone(gui_state),
+                host,
+                Arc::clone(is_running),
             ));
-        } else {
-            app_data
+        } else if let Some(host) = host {
+            gui_state
                 .lock()
-                .set_error(AppError::DockerConnect, gui_state, Status::DockerConnect);
+                .set_info_box(&format!("unable to connect to {host}"));
         }
-    } else {
-        app_data
-            .lock()
-            .set_error(AppError::DockerConnect, gui_state, Status::DockerConnect);
     }
+    tokio::spawn(DockerRouter::init(Arc::clone(app_data), docker_rx, routes));
+    tunnels
 }
 
 /// Create data for, and then spawn a tokio thread, for the input handler
@@ -103,46 +229,101 @@ fn handler_init(
     ));
 }
 
-#[tokio::main]
-async fn main() {
-    setup_tracing();
-
-    let args = CliArgs::new();
+/// Listen on the control socket, if one was given, a socket that can't be listened on is shown in the info box, rather than stopping oxker
+/// Returns the path of the socket, so that it can be removed when oxker closes
+#[cfg(unix)]
+fn control_init(
+    path: Option<&std::path::Path>,
+    gui_state: &Arc<Mutex<GuiState>>,
+    input_tx: &Sender<InputMessages>,
+) -> Option<std::path::PathBuf> {
+    let path = path?;
+    match input_handler::control_bind(path) {
+        Ok(listener) => {
+            tokio::spawn(input_handler::control_serve(listener, input_tx.clone()));
+            Some(path.to_owned())
+        }
+        Err(e) => {
+            gui_state
+                .lock()
+                .set_info_box(&format!("unable to listen on {}: {e}", path.display()));
+            None
+        }
+    }
+}
 
-    // If running via Docker image, need to sleep else program will just quit straight away, no real idea why
-    // So just sleep for small while
-    if args.in_container {
-        std::thread::sleep(std::time::Duration::from_millis(250));
+/// Serve the Prometheus metrics, if a port was given, an address that can't be listened on is shown in the info box, or logged without the gui, rather than stopping oxker
+async fn metrics_init(app_data: &Arc<Mutex<AppData>>, gui_state: &Arc<Mutex<GuiState>>) {
+    let (address, gui) = {
+        let args = &app_data.lock().args;
+        (args.metrics, args.gui)
+    };
+    let Some(address) = address else {
+        return;
+    };
+    match tokio::net::TcpListener::bind(address).await {
+        Ok(listener) => {
+            tokio::spawn(metrics::serve(listener, Arc::clone(app_data)));
+        }
+        Err(e) if gui => {
+            gui_state
+                .lock()
+                .set_info_box(&format!("unable to serve metrics on {address}: {e}"));
+        }
+        Err(e) => error!("unable to serve metrics on {address}: {e}"),
     }
-    let host = read_docker_host(&args);
+}
 
-    let app_data = Arc::new(Mutex::new(AppData::default(args.clone())));
-    let gui_state = Arc::new(Mutex::new(GuiState::default()));
-    let is_running = Arc::new(AtomicBool::new(true));
-    let (docker_tx, docker_rx) = tokio::sync::mpsc::channel(32);
+/// Save the primary host's containers to the cache, nothing is saved if they were never updated by the Docker daemon
+fn save_cache(app_data: &Arc<Mutex<AppData>>, path: Option<&std::path::Path>) {
+    let Some(path) = path else {
+        return;
+    };
+    let app_data = app_data.lock();
+    if app_data.is_stale() {
+        return;
+    }
+    let items = app_data
+        .get_container_items()
+        .iter()
+        .filter(|i| i.host.is_none())
+        .cloned()
+        .collect::<Vec<_>>();
+    drop(app_data);
+    if let Err(e) = app_data::cache::save(path, &items) {
+        error!("Unable to save cache to {}: {e}", path.display());
+    }
+}
 
-    docker_init(
-        &app_data,
-        docker_rx,
-        docker_tx.clone(),
-        &gui_state,
-        &is_running,
-        host,
-    )
-    .await;
+/// Exit with the error, if there is one, as there's no gui to show it in
+fn exit_on_error(app_data: &Arc<Mutex<AppData>>) {
+    let err = app_data.lock().get_error();
+    if let Some(err) = err {
+        error!("{}", err);
+        process::exit(1);
+    }
+}
 
-    if args.gui {
-        let (input_tx, input_rx) = tokio::sync::mpsc::channel(32);
-        handler_init(&app_data, &docker_tx, &gui_state, input_rx, &is_running);
-        Ui::create(app_data, gui_state, input_tx, is_running).await;
-    } else {
+/// Run without the gui, printing the containers as given by `--output`, or the summary of `oxker status`, or, without either, logging each container every update
+async fn no_gui(
+    app_data: &Arc<Mutex<AppData>>,
+    docker_rx: Receiver<DockerMessage>,
+    docker_tx: Sender<DockerMessage>,
+    gui_state: &Arc<Mutex<GuiState>>,
+    is_running: &Arc<AtomicBool>,
+) {
+    // Kept until the end, so that any ssh tunnels stay open
+    let _tunnels = docker_init(app_data, docker_rx, docker_tx, gui_state, is_running).await;
+    let args = app_data.lock().args.clone();
+    let status = match args.command {
+        Some(Command::Status { format }) => Some(format),
+        _ => None,
+    };
+    if args.output.is_none() && status.is_none() {
         info!("in debug mode\n");
         // Debug mode for testing, less pointless now, will display some basic information
         while is_running.load(Ordering::SeqCst) {
-            if let Some(err) = app_data.lock().get_error() {
-                error!("{}", err);
-                process::exit(1);
-            }
+            exit_on_error(app_data);
             tokio::time::sleep(std::time::Duration::from_millis(u64::from(
                 args.docker_interval,
             )))
@@ -161,6 +342,123 @@ async fn main() {
                 println!();
             }
         }
+        return;
+    }
+
+    // The first stats of each container are taken whilst initialising, so wait until that's done
+    while !app_data.lock().is_updated() || gui_state.lock().status_contains(&[Status::Init]) {
+        exit_on_error(app_data);
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+    }
+    if let Some(format) = status {
+        let output = app_data.lock().render_status(format);
+        println!("{output}");
+        process::exit(0)
+    }
+    let Some(format) = args.output else {
+        return;
+    };
+    loop {
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |i| i64::try_from(i.as_secs()).unwrap_or_default());
+        let output = app_data
+            .lock()
+            .render_output(format, args.output_stats, now);
+        println!("{output}");
+        let Some(interval) = args.output_interval else {
+            process::exit(0)
+        };
+        tokio::time::sleep(std::time::Duration::from_secs(interval)).await;
+        exit_on_error(app_data);
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    setup_tracing();
+
+    let mut args = CliArgs::new();
+
+    // If running via Docker image, need to sleep else program will just quit straight away, no real idea why
+    // So just sleep for small while
+    if args.in_container {
+        std::thread::sleep(std::time::Duration::from_millis(250));
+    }
+    // Store the resolved host, so that it can be displayed in the status bar
+    // The demo doesn't connect to a Docker daemon, so there's no host to resolve
+    let contexts = if args.demo {
+        vec![]
+    } else {
+        read_docker_host(&mut args)
+    };
+    let host = args.host.clone();
+
+    if let Some(Command::Wait { name, timeout }) = args.command.as_ref() {
+        // Kept until the wait is over, so that any ssh tunnel stays open
+        let Some((docker, _tunnel)) =
+            docker_connect(host.as_deref(), args.ssh_jump.as_deref(), args.tls.as_ref()).await
+        else {
+            error!("{}", AppError::DockerConnect);
+            process::exit(1)
+        };
+        let code =
+            docker_data::wait(&docker, name, timeout.map(std::time::Duration::from_secs)).await;
+        process::exit(code)
+    }
+
+    let app_data = Arc::new(Mutex::new(AppData::default(args.clone())));
+    let gui_state = Arc::new(Mutex::new(GuiState::default()));
+    let is_running = Arc::new(AtomicBool::new(true));
+    let (docker_tx, docker_rx) = tokio::sync::mpsc::channel(32);
+    metrics_init(&app_data, &gui_state).await;
+
+    // Until a context is picked the host isn't known, so the cache isn't used, nor is it for the demo's fake containers
+    let picker = (args.gui && !contexts.is_empty()).then(|| ContextPicker::new(contexts));
+    let cache_path = (args.cache && !args.demo)
+        .then(|| app_data::cache::path(app_data.lock().get_host()))
+        .flatten()
+        .filter(|_| picker.is_none());
+
+    if args.gui {
+        gui_state.lock().set_layout(args.layout);
+        gui_state.lock().set_context_picker(picker);
+        if let Some(path) = cache_path.as_ref() {
+            app_data.lock().load_cached(app_data::cache::load(path));
+        }
+        app_data.lock().load_notes(app_data::notes::path());
+        app_data
+            .lock()
+            .load_exec_history(app_data::exec_history::path());
+        // Connect in the background, so that the cached containers are drawn straight away, even when the host is slow to respond
+        // The handle is kept until the end of main, so that any ssh tunnels stay open
+        let _tunnels = tokio::spawn({
+            let (app_data, gui_state, is_running) = (
+                Arc::clone(&app_data),
+                Arc::clone(&gui_state),
+                Arc::clone(&is_running),
+            );
+            let docker_tx = docker_tx.clone();
+            async move { docker_init(&app_data, docker_rx, docker_tx, &gui_state, &is_running).await }
+        });
+        let (input_tx, input_rx) = tokio::sync::mpsc::channel(32);
+        #[cfg(unix)]
+        let control_socket = control_init(args.control_socket.as_deref(), &gui_state, &input_tx);
+        handler_init(&app_data, &docker_tx, &gui_state, input_rx, &is_running);
+        if args.update_check {
+            tokio::spawn(update_check::check(
+                Arc::clone(&gui_state),
+                update_check::path(),
+            ));
+        }
+        Ui::create(Arc::clone(&app_data), gui_state, input_tx, is_running).await;
+        #[cfg(unix)]
+        if let Some(path) = control_socket {
+            std::fs::remove_file(path).ok();
+        }
+        save_cache(&app_data, cache_path.as_deref());
+    } else {
+        no_gui(&app_data, docker_rx, docker_tx, &gui_state, &is_running).await;
     }
 }
 
@@ -168,24 +466,92 @@ async fn main() {
 #[allow(clippy::unwrap_used, clippy::many_single_char_names, unused)]
 mod tests {
     use bollard::service::{ContainerSummary, Port};
+    use ratatui::style::Color;
 
     use crate::{
-        app_data::{AppData, ContainerId, ContainerItem, ContainerPorts, State, StatefulList},
+        app_data::{
+            AppData, ConfirmPolicy, ContainerId, ContainerItem, ContainerPorts, LogTime, State,
+            StatefulList, Zone,
+        },
+        docker_data::{LogTail, RuntimeKind},
+        exec::DetachKeys,
+        input_handler::ClickAction,
         parse_args::CliArgs,
+        ui::{log_sanitizer::InvalidUtf8, NotifyOsc, PaneLayout, StateColors, Theme},
     };
 
-    pub const fn gen_args() -> CliArgs {
+    pub fn gen_args() -> CliArgs {
         CliArgs {
+            api_concurrency: None,
+            api_limit: None,
+            auto_standby: true,
+            bell: None,
+            cache: false,
+            chart_history: 60,
+            click: ClickAction::Select,
             color: false,
+            command: None,
+            config: None,
+            confirm: ConfirmPolicy::default(),
+            context: None,
+            control_socket: None,
+            demo: false,
+            detach_keys: DetachKeys::default(),
+            disk_alert: None,
             docker_interval: 1000,
+            double_click: ClickAction::Menu,
+            dry_run: false,
+            exec_log: None,
+            extra_hosts: vec![],
+            filters: vec![],
+            follow_logs: false,
             gui: true,
             host: None,
             in_container: false,
+            invalid_utf8: InvalidUtf8::Lossy,
+            label_columns: vec![],
+            lazy_stats: 100,
+            log_driver_fallback: None,
+            log_gap: None,
+            log_time: LogTime::Off,
+            max_line_length: 1000,
+            max_log_lines: 0,
+            metrics: None,
+            middle_click: ClickAction::Browser,
+            layout: PaneLayout::default(),
+            mouse: true,
+            notify: vec![],
+            notify_osc: NotifyOsc::Osc9,
+            only: vec![],
+            order: vec![],
+            order_label: None,
+            output: None,
+            output_interval: None,
+            output_stats: false,
+            probe_interval: 10,
+            probes: vec![],
             save_dir: None,
             raw: false,
+            read_only: false,
+            record: None,
+            redact: vec![],
+            restarts: false,
+            runtime: RuntimeKind::Docker,
+            select: None,
+            show_hidden: false,
             show_self: false,
+            ssh_jump: None,
+            state_colors: StateColors::new(),
+            status_bar: None,
+            tail: LogTail::default(),
+            theme: Theme::default(),
             timestamp: false,
+            timestamp_format: None,
+            timezone: Zone::utc(),
+            tls: None,
+            update_check: false,
             use_cli: false,
+            watch: vec![],
         }
     }
 
@@ -209,9 +575,7 @@ mod tests {
     pub fn gen_appdata(containers: &[ContainerItem]) -> AppData {
         AppData {
             containers: StatefulList::new(containers.to_vec()),
-            error: None,
-            sorted_by: None,
-            args: gen_args(),
+            ..AppData::default(gen_args())
         }
     }
 
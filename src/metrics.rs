@@ -0,0 +1,184 @@
+use std::{fmt::Write as _, sync::Arc, time::Duration};
+
+use parking_lot::Mutex;
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{TcpListener, TcpStream},
+};
+
+use crate::app_data::{AppData, ContainerItem, State, Stats};
+
+/// How long a scrape is given to send its request, so that an idle connection isn't kept open
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Escape a label value, as the text exposition format requires
+fn escape(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+/// A metric, its help, its type, & its value for each container, None leaves a container out, e.g. a stopped container has no cpu usage
+struct Metric {
+    name: &'static str,
+    help: &'static str,
+    kind: &'static str,
+    value: fn(&ContainerItem) -> Option<f64>,
+}
+
+#[allow(clippy::cast_precision_loss)]
+const METRICS: [Metric; 6] = [
+    Metric {
+        name: "oxker_container_running",
+        help: "1 if the container is running, else 0",
+        kind: "gauge",
+        value: |i| Some(if i.state == State::Running { 1.0 } else { 0.0 }),
+    },
+    Metric {
+        name: "oxker_container_cpu_percent",
+        help: "Latest cpu usage of the container, as a percentage of a single core",
+        kind: "gauge",
+        value: |i| i.cpu_stats.back().map(Stats::get_value),
+    },
+    Metric {
+        name: "oxker_container_memory_bytes",
+        help: "Latest memory usage of the container",
+        kind: "gauge",
+        value: |i| i.mem_stats.back().map(Stats::get_value),
+    },
+    Metric {
+        name: "oxker_container_memory_limit_bytes",
+        help: "Memory limit of the container, the memory of the host if it has no limit",
+        kind: "gauge",
+        value: |i| (!i.mem_stats.is_empty()).then(|| i.mem_limit.get() as f64),
+    },
+    Metric {
+        name: "oxker_container_network_receive_bytes_total",
+        help: "Bytes received by the container, across every network interface",
+        kind: "counter",
+        value: |i| (!i.mem_stats.is_empty()).then(|| i.rx.get() as f64),
+    },
+    Metric {
+        name: "oxker_container_network_transmit_bytes_total",
+        help: "Bytes sent by the container, across every network interface",
+        kind: "counter",
+        value: |i| (!i.mem_stats.is_empty()).then(|| i.tx.get() as f64),
+    },
+];
+
+/// The latest stats of every container, in the Prometheus text format, each labelled with the container's id, name, image, & host
+pub fn render(app_data: &AppData) -> String {
+    let mut output = String::new();
+    for metric in &METRICS {
+        let _ = writeln!(output, "# HELP {} {}", metric.name, metric.help);
+        let _ = writeln!(output, "# TYPE {} {}", metric.name, metric.kind);
+        for item in app_data.get_container_items() {
+            if let Some(value) = (metric.value)(item) {
+                let _ = writeln!(
+                    output,
+                    "{}{{id=\"{}\",name=\"{}\",image=\"{}\",host=\"{}\"}} {value}",
+                    metric.name,
+                    escape(item.id.get()),
+                    escape(item.name.get()),
+                    escape(item.image.get()),
+                    escape(app_data.container_host(item))
+                );
+            }
+        }
+    }
+    output
+}
+
+/// Answer a single scrape, only `GET /metrics` is served, the connection is closed after the response
+async fn connection(mut stream: TcpStream, app_data: Arc<Mutex<AppData>>) {
+    let mut buf = [0; 1024];
+    let Ok(Ok(count)) = tokio::time::timeout(REQUEST_TIMEOUT, stream.read(&mut buf)).await else {
+        return;
+    };
+    let request = String::from_utf8_lossy(&buf[..count]);
+    let mut parts = request.split_whitespace();
+    let (status, body) = match (parts.next(), parts.next()) {
+        (Some("GET"), Some("/metrics")) => ("200 OK", render(&app_data.lock())),
+        (Some("GET"), _) => ("404 Not Found", "only /metrics is served\n".to_owned()),
+        _ => ("405 Method Not Allowed", "only GET is served\n".to_owned()),
+    };
+    let response = format!(
+        "HTTP/1.1 {status}\r\nContent-Type: text/plain; version=0.0.4; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    );
+    stream.write_all(response.as_bytes()).await.ok();
+    stream.shutdown().await.ok();
+}
+
+/// Answer scrapes, until oxker closes
+pub async fn serve(listener: TcpListener, app_data: Arc<Mutex<AppData>>) {
+    while let Ok((stream, _)) = listener.accept().await {
+        tokio::spawn(connection(stream, Arc::clone(&app_data)));
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use std::{collections::VecDeque, sync::Arc};
+
+    use parking_lot::Mutex;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    use super::{render, serve};
+    use crate::{
+        app_data::{ByteStats, CpuStats, State},
+        tests::{gen_appdata, gen_containers},
+    };
+
+    #[test]
+    /// Every container has a running gauge, only containers with stats have the stats metrics, & label values are escaped
+    fn test_metrics_render() {
+        let (_ids, mut containers) = gen_containers();
+        containers[0].cpu_stats = VecDeque::from([CpuStats::new(12.5)]);
+        containers[0].mem_stats = VecDeque::from([ByteStats::new(2048)]);
+        containers[0].rx = ByteStats::new(100);
+        containers[0].name = "web \"1\"".into();
+        containers[1].state = State::Exited;
+        let output = render(&gen_appdata(&containers));
+
+        assert!(output.contains("# TYPE oxker_container_running gauge\n"));
+        assert!(output.contains(
+            "oxker_container_running{id=\"1\",name=\"web \\\"1\\\"\",image=\"image_1\",host=\"local\"} 1\n"
+        ));
+        assert!(output.contains("oxker_container_running{id=\"2\",name=\"container_2\",image=\"image_2\",host=\"local\"} 0\n"));
+        assert!(output.contains("oxker_container_cpu_percent{id=\"1\",name=\"web \\\"1\\\"\",image=\"image_1\",host=\"local\"} 12.5\n"));
+        assert!(output.contains("oxker_container_memory_bytes{id=\"1\",name=\"web \\\"1\\\"\",image=\"image_1\",host=\"local\"} 2048\n"));
+        assert!(output.contains("# TYPE oxker_container_network_receive_bytes_total counter\n"));
+        assert!(output.contains("oxker_container_network_receive_bytes_total{id=\"1\",name=\"web \\\"1\\\"\",image=\"image_1\",host=\"local\"} 100\n"));
+        assert_eq!(output.matches("oxker_container_cpu_percent{").count(), 1);
+    }
+
+    #[tokio::test]
+    /// Scrapes of /metrics are answered with the metrics, any other path isn't found
+    async fn test_metrics_serve() {
+        let (_ids, containers) = gen_containers();
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(serve(
+            listener,
+            Arc::new(Mutex::new(gen_appdata(&containers))),
+        ));
+
+        let scrape = |path: &'static str| async move {
+            let mut stream = tokio::net::TcpStream::connect(address).await.unwrap();
+            stream
+                .write_all(format!("GET {path} HTTP/1.1\r\nHost: localhost\r\n\r\n").as_bytes())
+                .await
+                .unwrap();
+            let mut response = String::new();
+            stream.read_to_string(&mut response).await.unwrap();
+            response
+        };
+        let response = scrape("/metrics").await;
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert!(response.contains("oxker_container_running{id=\"3\""));
+        assert!(scrape("/").await.starts_with("HTTP/1.1 404 Not Found\r\n"));
+    }
+}
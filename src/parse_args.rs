@@ -1,22 +1,74 @@
-use std::{path::PathBuf, process};
+use std::{
+    io::IsTerminal,
+    net::{IpAddr, SocketAddr},
+    path::PathBuf,
+    process,
+    str::FromStr,
+};
 
-use clap::Parser;
+use clap::{CommandFactory, Parser, Subcommand};
+use ratatui::style::Color;
 use tracing::error;
 
-use crate::{ENV_KEY, ENV_VALUE};
+use crate::{
+    app_data::{
+        parse_memory, ConfirmPolicy, ContainerFilter, LogTime, LogWatch, OutputFormat, Probe,
+        Redact, StatusFormat, Zone,
+    },
+    config_file,
+    docker_data::{DockerContext, LogFallback, LogTail, RuntimeKind, TlsFiles},
+    exec::DetachKeys,
+    input_handler::ClickAction,
+    setup,
+    ui::{
+        log_sanitizer::InvalidUtf8, Bell, NotifyEvent, NotifyOsc, Palette, PaneLayout, StateColors,
+        Theme, ThemePreset, Ui, CHARTS_HEIGHT, DEFAULT_STATUS_BAR,
+    },
+    update_check, ENV_KEY, ENV_VALUE,
+};
+
+/// Commands that run without the gui, and then exit
+#[derive(Subcommand, Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    /// Wait until a container is healthy, or running if it has no health check, exits with 1 if it has stopped, or on timeout
+    Wait {
+        /// Name, or id, of the container
+        name: String,
+
+        /// Stop waiting after this many seconds
+        #[clap(long, value_name = "seconds")]
+        timeout: Option<u64>,
+    },
+    /// Print a summary of the containers, how many are running, & unhealthy, & the container using the most cpu, once the first stats are in, for a desktop status bar, the "--filter" rules are applied
+    Status {
+        #[clap(long, value_enum, value_name = "format", default_value_t = StatusFormat::Waybar)]
+        format: StatusFormat,
+    },
+}
 
 #[derive(Parser, Debug, Clone)]
 #[allow(clippy::struct_excessive_bools)]
 #[command(version, about)]
 pub struct Args {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
     /// Docker update interval in ms, minimum effectively 1000
     #[clap(short = 'd', value_name = "ms", default_value_t = 1000)]
     pub docker_interval: u32,
 
-    /// Remove timestamps from Docker logs
+    /// Remove timestamps from Docker logs, they can be shown again with ( T )
     #[clap(short = 't')]
     pub timestamp: bool,
 
+    /// Timezone that ( T ) shows the timestamps of log lines in, a tz database name, e.g. "Europe/London", an offset, e.g. "+05:30", a POSIX TZ string, or "local", the default, the timezone of the system, timestamps are shown in it from the start
+    #[clap(long, short = None, value_name = "zone")]
+    pub timezone: Option<String>,
+
+    /// Format of the timestamps of log lines, with strftime specifiers, e.g. "%H:%M:%S.%3f", by default timestamps are as logged, in UTC, or RFC 3339 in local time
+    #[clap(long="timestamp-format", short = None, value_name = "format")]
+    pub timestamp_format: Option<String>,
+
     /// Attempt to colorize the logs, conflicts with "-r"
     #[clap(short = 'c', conflicts_with = "raw")]
     pub color: bool,
@@ -25,43 +77,391 @@ pub struct Args {
     #[clap(short = 'r', conflicts_with = "color")]
     pub raw: bool,
 
+    /// Show the containers that have an "oxker.hide=true" label
+    #[clap(long="show-hidden", short = None)]
+    pub show_hidden: bool,
+
     /// Show self when running as a docker container
     #[clap(short = 's')]
     pub show_self: bool,
 
-    /// Don't draw gui - for debugging - mostly pointless
+    /// Don't draw gui - for debugging - "--output" prints the containers for scripts
     #[clap(short = 'g')]
     pub gui: bool,
 
-    /// Docker host, defaults to `/var/run/docker.sock`
+    /// Print the containers to stdout, as json, or a table, once the first stats are in, & then exit, instead of drawing the gui, the "--filter" rules are applied
+    #[clap(long, short = None, value_enum, value_name = "format")]
+    pub output: Option<OutputFormat>,
+
+    /// Include the latest cpu, memory, & network, stats of each container in the "--output"
+    #[clap(long="output-stats", short = None, requires = "output")]
+    pub output_stats: bool,
+
+    /// Print the "--output" every this many seconds, until interrupted, rather than once
+    #[clap(long="output-interval", short = None, value_name = "seconds", requires = "output")]
+    pub output_interval: Option<u64>,
+
+    /// Docker host, defaults to `/var/run/docker.sock`, can be given multiple times to monitor several hosts
     #[clap(long, short = None)]
-    pub host: Option<String>,
+    pub host: Vec<String>,
 
     /// Force use of docker cli when execing into containers
     #[clap(long="use-cli", short = None)]
     pub use_cli: bool,
 
+    /// Key sequence to detach from an exec, or attach, session, comma separated, each key is a single character, or ctrl-<value>
+    #[clap(long="detach-keys", short = None, value_name = "keys", default_value = "ctrl-p,ctrl-q")]
+    pub detach_keys: DetachKeys,
+
+    /// Record each exec, & attach, session, its input & output, as an asciinema v2 cast into the save directory, one file per session, sessions always use the docker api, as the docker cli can't be recorded
+    #[clap(long="exec-log", short = None, conflicts_with = "use_cli")]
+    pub exec_log: bool,
+
     /// Directory for saving exported logs, defaults to `$HOME`
     #[clap(long="save-dir", short = None)]
     pub save_dir: Option<String>,
+
+    /// Set the color of a container state, "state=color", comma separated, e.g. "running=blue,exited=#ff8800"
+    #[clap(long="state-color", short = None, value_name = "state=color", value_delimiter = ',')]
+    pub state_color: Vec<String>,
+
+    /// Preset ui, & state, colors, the color-blind presets also add text cues to state information that is otherwise only shown by color, "--ui-color", & "--state-color", are applied on top
+    #[clap(long, short = None, value_enum, default_value_t = ThemePreset::Default)]
+    pub theme: ThemePreset,
+
+    /// Don't query the terminal for its colors, which the default theme is otherwise built from, for a terminal that doesn't reply to the query
+    #[clap(long="no-palette", short = None)]
+    pub no_palette: bool,
+
+    /// Set the color of a ui element, "element=color", comma separated, e.g. "border=blue,popup_bg=#eeeeee", also set by the "[colors]" table of the config file
+    #[clap(long="ui-color", short = None, value_name = "element=color", value_delimiter = ',')]
+    pub ui_color: Vec<String>,
+
+    /// Show the exit code, & restart count, of each container, containers that are crash looping are highlighted, each container is inspected whenever its state changes
+    #[clap(long, short = None)]
+    pub restarts: bool,
+
+    /// Record the session, as an asciinema v2 cast, into the given file
+    #[clap(long, short = None, value_name = "file")]
+    pub record: Option<String>,
+
+    /// Preview what destructive actions will remove, and require a second confirmation before executing them
+    #[clap(long="dry-run", short = None)]
+    pub dry_run: bool,
+
+    /// Set which docker controls require confirmation, "[container:]control=always|never", control can be "*", can be given multiple times, by default only delete is confirmed
+    #[clap(long, short = None, value_name = "[container:]control=policy")]
+    pub confirm: Vec<String>,
+
+    /// Show a status bar at the bottom of the screen, an optional template can be given, segments are {host}, {latency}, {total}, {running}, {stopped}, {refresh}, {resources}, {alerts}, and {keys}
+    #[clap(long="status-bar", short = None, value_name = "template", num_args = 0..=1, default_missing_value = DEFAULT_STATUS_BAR)]
+    pub status_bar: Option<String>,
+
+    /// Connect to the endpoint of this docker CLI context, as listed by `docker context ls`, ignored if "--host" is given
+    #[clap(long, short = None, value_name = "name")]
+    pub context: Option<String>,
+
+    /// Jump host, passed to `ssh -J`, used when connecting to `ssh://` hosts, jump hosts set in `~/.ssh/config` are used without this
+    #[clap(long="ssh-jump", short = None, value_name = "[user@]host[:port]")]
+    pub ssh_jump: Option<String>,
+
+    /// Connect to `tcp://` hosts with TLS, verifying the daemon's certificate, the certificates default to `$DOCKER_CERT_PATH`, or `~/.docker`, implied by any of the other TLS arguments, or by `$DOCKER_TLS_VERIFY`
+    #[clap(long, short = None)]
+    pub tlsverify: bool,
+
+    /// CA certificate that the daemon's certificate is signed by, used when connecting to `tcp://` hosts
+    #[clap(long, short = None, value_name = "file")]
+    pub tlscacert: Option<String>,
+
+    /// Client certificate, used when connecting to `tcp://` hosts
+    #[clap(long, short = None, value_name = "file")]
+    pub tlscert: Option<String>,
+
+    /// Client certificate key, used when connecting to `tcp://` hosts
+    #[clap(long, short = None, value_name = "file")]
+    pub tlskey: Option<String>,
+
+    /// Container runtime, `podman` connects to "$CONTAINER_HOST", or the Podman socket, when no host is given
+    #[clap(long, short = None, value_enum, default_value_t = RuntimeKind::Docker)]
+    pub runtime: RuntimeKind,
+
+    /// Ring the terminal bell, or flash the screen, when an alert fires, a container exits with a non-zero code, is killed for running out of memory, turns unhealthy, a log line matches a --watch, or a writable layer grows past the --disk-alert
+    #[clap(long, short = None, value_enum, value_name = "mode")]
+    pub bell: Option<Bell>,
+
+    /// Send a desktop notification, through the terminal, & ring the terminal bell, when a container exits with a non-zero code, is killed for running out of memory, or turns unhealthy, comma separated, e.g. "--notify exit,oom"
+    #[clap(long, short = None, value_enum, value_delimiter = ',', value_name = "event")]
+    pub notify: Vec<NotifyEvent>,
+
+    /// The escape sequence that the "--notify" notifications are sent with, 9 for iTerm2, kitty, WezTerm, Ghostty, & Windows Terminal, 777 for VTE based terminals, foot, & urxvt
+    #[clap(long, short = None, value_enum, value_name = "osc", default_value_t = NotifyOsc::Osc9)]
+    pub notify_osc: NotifyOsc,
+
+    /// Show fake containers, stats, & logs, without connecting to a Docker daemon, every run is identical
+    #[clap(long, short = None)]
+    pub demo: bool,
+
+    /// How to display log output that isn't valid UTF-8
+    #[clap(long="invalid-utf8", short = None, value_enum, default_value_t = InvalidUtf8::Lossy)]
+    pub invalid_utf8: InvalidUtf8,
+
+    /// Read the logs through the docker cli, or journald, when the daemon can't return them, as with some logging drivers, the `oxker.logs` label has precedence
+    #[clap(long="log-driver-fallback", short = None, value_enum)]
+    pub log_driver_fallback: Option<LogFallback>,
+
+    /// Truncate log lines longer than this many chars, the full line can be viewed with enter, 0 to disable
+    #[clap(long="max-line-length", short = None, value_name = "chars", default_value_t = 1000)]
+    pub max_line_length: usize,
+
+    /// Keep at most this many log lines of each container in memory, the oldest are dropped first, & can be loaded again with ( < ), 0 keeps every line
+    #[clap(long="max-log-lines", short = None, value_name = "lines", default_value_t = 0)]
+    pub max_log_lines: usize,
+
+    /// Rows of the containers, & commands, panels, including their borders, by default sized to the containers, also set with ( ` ), or by dragging the border below the containers panel
+    #[clap(long="containers-height", short = None, value_name = "rows", value_parser = clap::value_parser!(u16).range(3..))]
+    pub containers_height: Option<u16>,
+
+    /// Percentage of the space below the containers that the charts take, the logs take the rest
+    #[clap(long="charts-height", short = None, value_name = "percent", default_value_t = CHARTS_HEIGHT, value_parser = clap::value_parser!(u16).range(10..=90))]
+    pub charts_height: u16,
+
+    /// Hide the charts, & ports, panels, so that the logs take their space, shown again with ( ` )
+    #[clap(long="hide-charts", short = None)]
+    pub hide_charts: bool,
+
+    /// Seconds of cpu & memory stats kept for the charts, & ( $ ) export, of each container, by default the last 60 updates
+    #[clap(long="chart-history", short = None, value_name = "seconds")]
+    pub chart_history: Option<u64>,
+
+    /// Max number of concurrent Docker API requests, per host
+    #[clap(long="api-concurrency", short = None, value_name = "requests")]
+    pub api_concurrency: Option<usize>,
+
+    /// Max number of Docker API requests per update interval, per host, the refresh interval is stretched when a full update would exceed it
+    #[clap(long="api-limit", short = None, value_name = "requests")]
+    pub api_limit: Option<usize>,
+
+    /// Once more than this many containers are running, only request the stats of the containers shown in the containers panel, and the selected container, 0 to always request every container's stats
+    #[clap(long="lazy-stats", short = None, value_name = "containers", default_value_t = 100)]
+    pub lazy_stats: usize,
+
+    /// Only monitor the container with this name, can be given multiple times, other containers are never listed
+    #[clap(long, short = None, value_name = "name")]
+    pub only: Vec<String>,
+
+    /// Don't load, or save, the cached container list, used to show containers straight away on startup
+    #[clap(long="no-cache", short = None)]
+    pub no_cache: bool,
+
+    /// Check GitHub for a newer release of oxker at startup, a newer version is shown once, in the info box, needs curl
+    #[clap(long="update-check", short = None)]
+    pub update_check: bool,
+
+    /// Never check for a newer release, even when the config file sets "update_check"
+    #[clap(long="no-update-check", short = None)]
+    pub no_update_check: bool,
+
+    /// Don't enter standby when the terminal loses focus, standby can still be entered with ( z )
+    #[clap(long="no-auto-standby", short = None)]
+    pub no_auto_standby: bool,
+
+    /// Insert a marker into the logs when consecutive lines are logged at least this many seconds apart
+    #[clap(long="log-gap", short = None, value_name = "seconds", value_parser = clap::value_parser!(u64).range(1..))]
+    pub log_gap: Option<u64>,
+
+    /// Alert when a container's writable layer grows past this size, e.g. 1g, the sizes are checked every 30 seconds, as the daemon has to measure each container's layer
+    #[clap(long="disk-alert", short = None, value_name = "size")]
+    pub disk_alert: Option<String>,
+
+    /// Number of historical log lines to fetch when a container's logs are first loaded, "lines" or "container=lines", lines can be "all", can be given multiple times
+    #[clap(long, short = None, value_name = "[container=]lines")]
+    pub tail: Vec<String>,
+
+    /// Watch a container's logs for a pattern, "container=pattern", "*" watches every container, can be given multiple times, matching lines are alerted & collected
+    #[clap(long, short = None, value_name = "container=pattern")]
+    pub watch: Vec<String>,
+
+    /// Redact logs when they're saved, or exported, "pattern=replacement", or a preset of "email", "ipv4", "jwt", or "bearer", can be given multiple times, applied in order
+    #[clap(long, short = None, value_name = "pattern=replacement")]
+    pub redact: Vec<String>,
+
+    /// Show, or hide, containers, "key=value" shows only the matching containers, "key!=value" hides them, a key of "name", "label", or "status", names & label values can use * globs, comma separated, can be given multiple times
+    #[clap(long, short = None, value_name = "key=value", value_delimiter = ',')]
+    pub filter: Vec<String>,
+
+    /// Preferred order of containers, by name, used when no column is sorted, comma separated, can be given multiple times, named containers are listed first, in the order given
+    #[clap(long, short = None, value_name = "name", value_delimiter = ',')]
+    pub order: Vec<String>,
+
+    /// Label holding an integer priority, used when no column is sorted, containers are listed lowest priority first, after any "--order" containers
+    #[clap(long="order-label", short = None, value_name = "label")]
+    pub order_label: Option<String>,
+
+    /// Select a container on start, by its name, or its id, or a prefix of its id, or its compose service, "project/service", or on a single host, "host/project/service"
+    #[clap(long, short = None, value_name = "name|id|path")]
+    pub select: Option<String>,
+
+    /// Focus the logs of the "--select" container on start, at the newest line, so that new lines are followed
+    #[clap(long="follow-logs", short = None, requires = "select")]
+    pub follow_logs: bool,
+
+    /// Show a label as its own column of the containers panel, sortable by clicking its header, & matched by the containers search, "label=value" only matches that label, comma separated, can be given multiple times
+    #[clap(long="label-column", short = None, value_name = "label", value_delimiter = ',')]
+    pub label_column: Vec<String>,
+
+    /// Readiness probe of a container, "container=check", check is "http:port[/path]", "tcp:port", or "exec:command", the port is the container's port, "*" probes every container, can be given multiple times, results are shown in a ready column
+    #[clap(long, short = None, value_name = "container=check")]
+    pub probe: Vec<String>,
+
+    /// Seconds between each run of the readiness probes
+    #[clap(long="probe-interval", short = None, value_name = "seconds", default_value_t = 10, value_parser = clap::value_parser!(u64).range(1..))]
+    pub probe_interval: u64,
+
+    /// Listen on a unix socket for control commands, one per line, "host name|all", "select container", or "save-logs [container]", so that scripts & hotkeys can drive a running oxker
+    #[clap(long="control-socket", short = None, value_name = "path")]
+    pub control_socket: Option<String>,
+
+    /// Serve the latest stats of every container, in the Prometheus text format, at "/metrics" on this port, so that oxker's polling can be scraped instead of running cAdvisor
+    #[clap(long="metrics-port", short = None, value_name = "port")]
+    pub metrics_port: Option<u16>,
+
+    /// Address that the "--metrics-port" listens on, "0.0.0.0" to be scraped from other machines
+    #[clap(long="metrics-address", short = None, value_name = "ip", default_value = "127.0.0.1", requires = "metrics_port")]
+    pub metrics_address: IpAddr,
+
+    /// Action when a container is clicked
+    #[clap(long, short = None, value_enum, default_value_t = ClickAction::Select)]
+    pub click: ClickAction,
+
+    /// Action when a container is double clicked
+    #[clap(long="double-click", short = None, value_enum, default_value_t = ClickAction::Menu)]
+    pub double_click: ClickAction,
+
+    /// Action when a container is middle clicked
+    #[clap(long="middle-click", short = None, value_enum, default_value_t = ClickAction::Browser)]
+    pub middle_click: ClickAction,
+
+    /// Start without mouse capture, so that text on screen can be selected, ( m ) still enables it, everything can be done from the keyboard
+    #[clap(long="no-mouse", short = None)]
+    pub no_mouse: bool,
+
+    /// Read options from this TOML file, instead of "$XDG_CONFIG_HOME/oxker/config.toml", keys are the argument names, options given on the command line take precedence
+    #[clap(long, short = None, value_name = "file")]
+    pub config: Option<String>,
+
+    /// Run the interactive setup, choosing the host, theme, click action, & save directory, which writes the config file, as is offered on the first run, when there's no config file
+    #[clap(long, short = None)]
+    pub setup: bool,
+
+    /// Apply a "[profile.<name>]" table of the config file, its options take precedence over the rest of the file, e.g. a "prod" profile with its own host, "read_only", & "accent"
+    #[clap(long, short = None, value_name = "name")]
+    pub profile: Option<String>,
+
+    /// Don't allow any changes, container controls, exec, image pulls, & removals, are refused, only viewing, & saving logs, is possible
+    #[clap(long="read-only", short = None)]
+    pub read_only: bool,
+
+    /// Color of the borders, & the selected header, a name, "#rrggbb", or an ansi index, defaults to the accent of the theme
+    #[clap(long, short = None, value_name = "color")]
+    pub accent: Option<String>,
 }
 
 #[derive(Debug, Clone)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct CliArgs {
+    pub api_concurrency: Option<usize>,
+    pub api_limit: Option<usize>,
+    pub auto_standby: bool,
+    pub bell: Option<Bell>,
+    pub cache: bool,
+    /// Number of stats samples kept, of each container, one is taken each update
+    pub chart_history: usize,
+    pub click: ClickAction,
     pub color: bool,
+    pub command: Option<Command>,
+    /// The config file, given with `--config`, or the default file, the layout set with ( ` ) is saved into it
+    pub config: Option<PathBuf>,
+    pub confirm: ConfirmPolicy,
+    pub context: Option<String>,
+    pub control_socket: Option<PathBuf>,
+    pub demo: bool,
+    pub detach_keys: DetachKeys,
+    /// Bytes of a container's writable layer at which an alert fires
+    pub disk_alert: Option<u64>,
     pub docker_interval: u32,
+    pub double_click: ClickAction,
+    pub dry_run: bool,
+    /// The directory that exec, & attach, sessions are recorded into, with `--exec-log`
+    pub exec_log: Option<PathBuf>,
+    pub extra_hosts: Vec<String>,
+    /// The `--filter` rules, in the order given
+    pub filters: Vec<ContainerFilter>,
+    pub follow_logs: bool,
     pub gui: bool,
     pub host: Option<String>,
     pub in_container: bool,
+    pub invalid_utf8: InvalidUtf8,
+    /// The labels shown as columns, in the order given, without duplicates
+    pub label_columns: Vec<String>,
+    pub layout: PaneLayout,
+    pub lazy_stats: usize,
+    pub log_driver_fallback: Option<LogFallback>,
+    pub log_gap: Option<u64>,
+    /// How the timestamps of log lines are shown at first, off with `-t`, local time with `--timezone`, otherwise UTC
+    pub log_time: LogTime,
+    pub max_line_length: usize,
+    pub max_log_lines: usize,
+    /// Where the Prometheus metrics are served
+    pub metrics: Option<SocketAddr>,
+    pub middle_click: ClickAction,
+    pub mouse: bool,
+    /// The events that a desktop notification is sent for
+    pub notify: Vec<NotifyEvent>,
+    pub notify_osc: NotifyOsc,
+    pub only: Vec<String>,
+    pub order: Vec<String>,
+    pub order_label: Option<String>,
+    /// Print the containers, instead of drawing the gui
+    pub output: Option<OutputFormat>,
+    pub output_interval: Option<u64>,
+    pub output_stats: bool,
+    pub probe_interval: u64,
+    pub probes: Vec<Probe>,
     pub save_dir: Option<PathBuf>,
     pub raw: bool,
+    pub read_only: bool,
+    pub record: Option<PathBuf>,
+    pub redact: Vec<Redact>,
+    pub restarts: bool,
+    pub runtime: RuntimeKind,
+    /// The container selected on start, by name, or id
+    pub select: Option<String>,
+    pub show_hidden: bool,
     pub show_self: bool,
+    pub ssh_jump: Option<String>,
+    pub state_colors: StateColors,
+    pub status_bar: Option<String>,
+    pub tail: LogTail,
+    pub theme: Theme,
     pub timestamp: bool,
+    pub timestamp_format: Option<String>,
+    pub timezone: Zone,
+    pub tls: Option<TlsFiles>,
+    /// Only with `--update-check`, & never in a package built with the check disabled
+    pub update_check: bool,
     pub use_cli: bool,
+    pub watch: Vec<LogWatch>,
 }
 
 impl CliArgs {
+    /// Connect to the endpoint of a docker context, its certificates are used in place of any TLS arguments
+    pub fn set_docker_context(&mut self, context: &DockerContext) {
+        self.host.clone_from(&context.host);
+        if context.tls.is_some() {
+            self.tls.clone_from(&context.tls);
+        }
+    }
+
     /// An ENV is set in the ./containerised/Dockerfile, if this is ENV found, then sleep for 250ms, else the container, for as yet unknown reasons, will close immediately
     /// returns a bool, so that the `update_all_containers()` won't bother to check the entry point unless running via a container
     fn check_if_in_container() -> bool {
@@ -73,32 +473,282 @@ impl CliArgs {
         false
     }
 
+    /// Parse the command line, the config file's options are inserted before the command line arguments, so that the command line takes precedence
+    fn args() -> Args {
+        let cli = std::env::args_os().collect::<Vec<_>>();
+        let mut command = Args::command();
+        command.build();
+        let matches = Args::command().get_matches_from(&cli);
+        setup::run(&matches, cli.len() == 1 && !Self::check_if_in_container());
+        let config = config_file::args(&command, &matches).unwrap_or_else(|e| {
+            // Shown on a screen of its own, as the message would otherwise be lost when the gui would have started
+            if !matches.get_flag("gui")
+                && matches.get_one::<OutputFormat>("output").is_none()
+                && matches.subcommand().is_none()
+                && std::io::stdout().is_terminal()
+            {
+                Ui::config_error(&e).ok();
+            }
+            error!("config file {e}");
+            process::exit(1)
+        });
+        Args::parse_from(
+            cli.iter()
+                .take(1)
+                .cloned()
+                .chain(config)
+                .chain(cli.iter().skip(1).cloned()),
+        )
+    }
+
     /// Parse cli arguments
+    #[allow(clippy::too_many_lines)]
     pub fn new() -> Self {
-        let args = Args::parse();
+        let args = Self::args();
 
         let logs_dir = args.save_dir.map_or_else(
             || directories::BaseDirs::new().map(|base_dirs| base_dirs.home_dir().to_owned()),
             |logs_dir| Some(std::path::Path::new(&logs_dir).to_owned()),
         );
 
+        if args.exec_log && logs_dir.is_none() {
+            error!("\"--exec-log\" needs a save directory, set with \"--save-dir\"");
+            process::exit(1)
+        }
+
         // Quit the program if the docker update argument is 0
         // Should maybe change it to check if less than 100
         if args.docker_interval == 0 {
             error!("\"-d\" argument needs to be greater than 0");
             process::exit(1)
         }
+
+        if args.output_interval == Some(0) {
+            error!("\"--output-interval\" argument needs to be greater than 0");
+            process::exit(1)
+        }
+
+        let chart_history = match args.chart_history {
+            Some(0) => {
+                error!("\"--chart-history\" argument needs to be greater than 0");
+                process::exit(1)
+            }
+            Some(secs) => {
+                usize::try_from(secs.saturating_mul(1000) / u64::from(args.docker_interval))
+                    .unwrap_or(usize::MAX)
+                    .max(2)
+            }
+            None => 60,
+        };
+
+        let gui = !args.gui && args.output.is_none() && args.command.is_none();
+
+        // Only the default theme adapts to the terminal, the other presets have their own palette
+        let palette = (args.theme == ThemePreset::Default && gui && !args.no_palette)
+            .then(Palette::query)
+            .flatten();
+
+        let mut state_colors = StateColors::preset(args.theme);
+        if let Some(palette) = palette.as_ref() {
+            state_colors = state_colors.with_palette(palette);
+        }
+        for i in &args.state_color {
+            if let Err(e) = state_colors.set(i) {
+                error!("\"--state-color\" {e}");
+                process::exit(1)
+            }
+        }
+
+        let mut theme = Theme::preset(args.theme);
+        if let Some(palette) = palette.as_ref() {
+            theme = theme.with_palette(palette);
+        }
+        for i in &args.ui_color {
+            if let Err(e) = theme.set(i) {
+                error!("\"--ui-color\" {e}");
+                process::exit(1)
+            }
+        }
+        if let Some(accent) = args.accent.as_deref().map(str::trim) {
+            theme.accent = Color::from_str(accent).unwrap_or_else(|_| {
+                error!("\"--accent\" \"{accent}\" is not a valid color");
+                process::exit(1)
+            });
+        }
+
+        let mut confirm = ConfirmPolicy::default();
+        for i in &args.confirm {
+            if let Err(e) = confirm.set(i) {
+                error!("\"--confirm\" {e}");
+                process::exit(1)
+            }
+        }
+
+        let mut tail = LogTail::default();
+        for i in &args.tail {
+            if let Err(e) = tail.set(i) {
+                error!("\"--tail\" {e}");
+                process::exit(1)
+            }
+        }
+
+        let mut watch = vec![];
+        for i in &args.watch {
+            match LogWatch::parse(i) {
+                Ok(i) => watch.push(i),
+                Err(e) => {
+                    error!("\"--watch\" {e}");
+                    process::exit(1)
+                }
+            }
+        }
+
+        let mut filters = vec![];
+        for i in &args.filter {
+            match ContainerFilter::parse(i) {
+                Ok(i) => filters.push(i),
+                Err(e) => {
+                    error!("\"--filter\" {e}");
+                    process::exit(1)
+                }
+            }
+        }
+
+        let mut redact = vec![];
+        for i in &args.redact {
+            match Redact::parse(i) {
+                Ok(i) => redact.push(i),
+                Err(e) => {
+                    error!("\"--redact\" {e}");
+                    process::exit(1)
+                }
+            }
+        }
+
+        let mut label_columns: Vec<String> = vec![];
+        for i in args
+            .label_column
+            .iter()
+            .map(|i| i.trim())
+            .filter(|i| !i.is_empty())
+        {
+            if !label_columns.iter().any(|x| x == i) {
+                label_columns.push(i.to_owned());
+            }
+        }
+
+        let mut probes = vec![];
+        for i in &args.probe {
+            match Probe::parse(i) {
+                Ok(i) => probes.push(i),
+                Err(e) => {
+                    error!("\"--probe\" {e}");
+                    process::exit(1)
+                }
+            }
+        }
+        let disk_alert = args.disk_alert.as_deref().map(|i| {
+            parse_memory(i).ok().flatten().unwrap_or_else(|| {
+                error!("\"--disk-alert\" {i}: expected a size, e.g. 1g");
+                process::exit(1)
+            })
+        });
+
+        let timezone = args
+            .timezone
+            .as_deref()
+            .map_or_else(|| Ok(Zone::local()), Zone::parse)
+            .unwrap_or_else(|e| {
+                error!("\"--timezone\" {e}");
+                process::exit(1)
+            });
+        let log_time = match (args.timestamp, args.timezone.is_some()) {
+            (true, _) => LogTime::Off,
+            (false, true) => LogTime::Local,
+            (false, false) => LogTime::Utc,
+        };
+
         Self {
+            api_concurrency: args.api_concurrency,
+            api_limit: args.api_limit,
+            auto_standby: !args.no_auto_standby,
+            bell: args.bell,
+            cache: !args.no_cache,
+            click: args.click,
             color: args.color,
+            command: args.command,
+            config: args
+                .config
+                .as_deref()
+                .map(PathBuf::from)
+                .or_else(config_file::path),
+            confirm,
+            context: args.context,
+            chart_history,
+            control_socket: args.control_socket.map(PathBuf::from),
+            demo: args.demo,
+            detach_keys: args.detach_keys,
+            disk_alert,
             docker_interval: args.docker_interval,
+            double_click: args.double_click,
+            dry_run: args.dry_run,
+            exec_log: logs_dir.clone().filter(|_| args.exec_log),
             use_cli: args.use_cli,
-            gui: !args.gui,
-            host: args.host,
+            extra_hosts: args.host.iter().skip(1).cloned().collect(),
+            filters,
+            follow_logs: args.follow_logs,
+            gui,
+            host: args.host.first().cloned(),
             in_container: Self::check_if_in_container(),
+            invalid_utf8: args.invalid_utf8,
+            label_columns,
+            layout: PaneLayout {
+                containers: args.containers_height,
+                charts: args.charts_height,
+                charts_hidden: args.hide_charts,
+            },
+            lazy_stats: args.lazy_stats,
+            log_driver_fallback: args.log_driver_fallback,
+            log_gap: args.log_gap,
+            log_time,
+            max_line_length: args.max_line_length,
+            max_log_lines: args.max_log_lines,
+            metrics: args
+                .metrics_port
+                .map(|port| SocketAddr::new(args.metrics_address, port)),
+            middle_click: args.middle_click,
+            mouse: !args.no_mouse,
+            notify: args.notify,
+            notify_osc: args.notify_osc,
+            only: args.only,
+            order: args.order,
+            order_label: args.order_label,
+            output: args.output,
+            output_interval: args.output_interval,
+            output_stats: args.output_stats,
+            probe_interval: args.probe_interval,
+            probes,
             save_dir: logs_dir,
             raw: args.raw,
+            read_only: args.read_only,
+            record: args.record.map(PathBuf::from),
+            redact,
+            restarts: args.restarts,
+            runtime: args.runtime,
+            select: args.select,
+            show_hidden: args.show_hidden,
             show_self: !args.show_self,
+            ssh_jump: args.ssh_jump,
+            state_colors,
+            status_bar: args.status_bar,
+            tail,
+            theme,
             timestamp: !args.timestamp,
+            timestamp_format: args.timestamp_format,
+            timezone,
+            tls: TlsFiles::new(args.tlsverify, args.tlscacert, args.tlscert, args.tlskey),
+            update_check: args.update_check && !args.no_update_check && !update_check::DISABLED,
+            watch,
         }
     }
 }
@@ -0,0 +1,337 @@
+use std::{
+    io::{self, BufRead, IsTerminal, Write},
+    path::{Path, PathBuf},
+};
+
+use clap::{ArgMatches, ValueEnum};
+
+use crate::{config_file, input_handler::ClickAction, ui::ThemePreset};
+
+/// Id of the argument that runs the setup, even when there's already a config file
+const SETUP: &str = "setup";
+
+/// The first lines of a config file written by the setup, also written alone when the setup is declined, so that it isn't offered again
+const HEADER: &str = concat!(
+    "# oxker config file, each key is an argument name, without the leading --, e.g. tail = 500\n",
+    "# Options given on the command line take precedence, run `oxker --setup` to answer the setup again\n",
+);
+
+/// Sockets that a local daemon listens on, checked for in order, `~` is the home dir, `$XDG_RUNTIME_DIR` the runtime dir
+const SOCKETS: [&str; 6] = [
+    "/var/run/docker.sock",
+    "$XDG_RUNTIME_DIR/docker.sock",
+    "~/.docker/run/docker.sock",
+    "~/.colima/default/docker.sock",
+    "$XDG_RUNTIME_DIR/podman/podman.sock",
+    "/run/podman/podman.sock",
+];
+
+/// The answers to the setup, None keeps the default, which is written as a comment
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct Answers {
+    click: Option<ClickAction>,
+    host: Option<String>,
+    save_dir: Option<String>,
+    theme: Option<ThemePreset>,
+}
+
+/// The `unix://` host of each socket that exists
+fn sockets() -> Vec<String> {
+    let home = directories::BaseDirs::new().map(|i| i.home_dir().to_owned());
+    let runtime = std::env::var_os("XDG_RUNTIME_DIR").map(PathBuf::from);
+    SOCKETS
+        .iter()
+        .filter_map(
+            |i| match (i.strip_prefix("~/"), i.strip_prefix("$XDG_RUNTIME_DIR/")) {
+                (Some(rest), _) => home.as_ref().map(|home| home.join(rest)),
+                (_, Some(rest)) => runtime.as_ref().map(|runtime| runtime.join(rest)),
+                _ => Some(PathBuf::from(i)),
+            },
+        )
+        .filter(|i| i.exists())
+        .map(|i| format!("unix://{}", i.display()))
+        .collect()
+}
+
+/// Ask a question, returns the trimmed answer, an empty answer at the end of the input
+fn ask<R: BufRead, W: Write>(input: &mut R, output: &mut W, question: &str) -> io::Result<String> {
+    write!(output, "{question}: ")?;
+    output.flush()?;
+    let mut answer = String::new();
+    input.read_line(&mut answer)?;
+    Ok(answer.trim().to_owned())
+}
+
+/// A yes or no question, yes when the answer is empty
+fn confirm<R: BufRead, W: Write>(
+    input: &mut R,
+    output: &mut W,
+    question: &str,
+) -> io::Result<bool> {
+    let answer = ask(input, output, &format!("{question} [Y/n]"))?;
+    Ok(!answer.to_lowercase().starts_with('n'))
+}
+
+/// Choose one of the values of an argument, by its number, or name, the first value is the default, chosen by an empty answer
+/// Asked again until the answer is valid
+fn choose<R: BufRead, W: Write, T: ValueEnum + PartialEq>(
+    input: &mut R,
+    output: &mut W,
+    question: &str,
+    default: &T,
+) -> io::Result<Option<T>> {
+    let values = T::value_variants();
+    writeln!(output, "\n{question}")?;
+    for (index, value) in values.iter().enumerate() {
+        let marker = if value == default { " (default)" } else { "" };
+        if let Some(value) = value.to_possible_value() {
+            let help = value
+                .get_help()
+                .map(|i| format!(" - {i}"))
+                .unwrap_or_default();
+            writeln!(
+                output,
+                "  {}) {}{marker}{help}",
+                index + 1,
+                value.get_name()
+            )?;
+        }
+    }
+    loop {
+        let answer = ask(input, output, "Choice, enter for the default")?;
+        if answer.is_empty() {
+            return Ok(None);
+        }
+        let chosen = answer
+            .parse::<usize>()
+            .ok()
+            .and_then(|i| i.checked_sub(1))
+            .and_then(|i| values.get(i))
+            .cloned()
+            .or_else(|| T::from_str(&answer, true).ok());
+        if let Some(chosen) = chosen {
+            return Ok(Some(chosen).filter(|i| i != default));
+        }
+        writeln!(output, "{answer} isn't one of the choices")?;
+    }
+}
+
+/// Ask each question of the setup, the sockets that exist are offered as the host, or any host can be typed
+fn questions<R: BufRead, W: Write>(
+    input: &mut R,
+    output: &mut W,
+    sockets: &[String],
+) -> io::Result<Answers> {
+    writeln!(
+        output,
+        "\nDocker host, by default $DOCKER_HOST, the current docker context, or the default socket"
+    )?;
+    for (index, socket) in sockets.iter().enumerate() {
+        writeln!(output, "  {}) {socket}", index + 1)?;
+    }
+    let answer = ask(
+        input,
+        output,
+        "Choice, a host, e.g. ssh://user@server, or enter for the default",
+    )?;
+    let host = answer
+        .parse::<usize>()
+        .ok()
+        .and_then(|i| i.checked_sub(1))
+        .and_then(|i| sockets.get(i))
+        .cloned()
+        .or_else(|| Some(answer).filter(|i| !i.is_empty()));
+
+    let theme = choose(
+        input,
+        output,
+        "Theme, the colors of the ui, & of each container state",
+        &ThemePreset::Default,
+    )?;
+    let click = choose(
+        input,
+        output,
+        "Action when a container is clicked",
+        &ClickAction::Select,
+    )?;
+
+    writeln!(output, "\nDirectory that logs are saved to")?;
+    let save_dir = Some(ask(input, output, "Path, or enter for the home directory")?)
+        .filter(|i| !i.is_empty());
+
+    Ok(Answers {
+        click,
+        host,
+        save_dir,
+        theme,
+    })
+}
+
+/// A basic string of the config file
+fn quote(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// The quoted name of a value of an argument
+fn value_name<T: ValueEnum>(value: &T) -> String {
+    value
+        .to_possible_value()
+        .map_or_else(String::new, |i| quote(i.get_name()))
+}
+
+/// The config file, each option that was left as the default is written as a comment, so that it can be found later
+fn render(answers: &Answers) -> String {
+    let line = |key: &str, value: Option<String>, default: String| {
+        value.map_or_else(
+            || format!("# {key} = {default}\n"),
+            |value| format!("{key} = {value}\n"),
+        )
+    };
+    [
+        HEADER.to_owned(),
+        line(
+            "host",
+            answers.host.as_deref().map(quote),
+            quote("unix:///var/run/docker.sock"),
+        ),
+        line(
+            "theme",
+            answers.theme.as_ref().map(value_name),
+            value_name(&ThemePreset::Default),
+        ),
+        line(
+            "click",
+            answers.click.as_ref().map(value_name),
+            value_name(&ClickAction::Select),
+        ),
+        line(
+            "save_dir",
+            answers.save_dir.as_deref().map(quote),
+            quote("/path/to/logs"),
+        ),
+    ]
+    .concat()
+}
+
+/// Write the config file, & the directory it's in
+fn write(path: &Path, content: &str) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(path, content)
+}
+
+/// Run the setup, with `--setup`, or on the first run, when oxker is started without any arguments, in a terminal, & there's no config file
+/// The config file is written before it's read, so the answers are used straight away
+pub fn run(matches: &ArgMatches, first_run: bool) {
+    let requested = matches.get_flag(SETUP);
+    let Some(path) = matches
+        .get_one::<String>("config")
+        .map(PathBuf::from)
+        .or_else(config_file::path)
+    else {
+        return;
+    };
+    let offered =
+        first_run && !path.exists() && io::stdin().is_terminal() && io::stdout().is_terminal();
+    if !requested && !offered {
+        return;
+    }
+    let (mut input, mut output) = (io::stdin().lock(), io::stdout().lock());
+    let result = (|| {
+        if !requested
+            && !confirm(
+                &mut input,
+                &mut output,
+                "No config file found, run the setup",
+            )?
+        {
+            write(&path, HEADER)?;
+            writeln!(
+                output,
+                "Not asked again, {} can be edited instead",
+                path.display()
+            )?;
+            return Ok(());
+        }
+        let content = render(&questions(&mut input, &mut output, &sockets())?);
+        writeln!(output, "\n{content}")?;
+        if confirm(
+            &mut input,
+            &mut output,
+            &format!("Write to {}", path.display()),
+        )? {
+            write(&path, &content)?;
+        }
+        Ok::<(), io::Error>(())
+    })();
+    if let Err(e) = result {
+        tracing::error!("setup {}: {e}", path.display());
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::{questions, render, Answers};
+    use crate::{input_handler::ClickAction, ui::ThemePreset};
+
+    #[test]
+    /// A socket is chosen by number, a value by number, or name, & an invalid answer is asked again
+    fn test_setup_questions() {
+        let sockets = ["unix:///var/run/docker.sock".to_owned()];
+        let mut output = vec![];
+        let answers = questions(
+            &mut Cursor::new("1\n9\nProtanopia\n\n/tmp/logs\n"),
+            &mut output,
+            &sockets,
+        )
+        .unwrap();
+        assert_eq!(
+            answers,
+            Answers {
+                click: None,
+                host: Some("unix:///var/run/docker.sock".to_owned()),
+                save_dir: Some("/tmp/logs".to_owned()),
+                theme: Some(ThemePreset::Protanopia),
+            }
+        );
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains("9 isn't one of the choices"));
+        assert!(output.contains("  1) default (default)"));
+
+        let answers = questions(
+            &mut Cursor::new("ssh://me@server\n1\n3\n"),
+            &mut vec![],
+            &[],
+        )
+        .unwrap();
+        assert_eq!(answers.host.as_deref(), Some("ssh://me@server"));
+        assert_eq!(answers.theme, None);
+        assert_eq!(answers.click, Some(ClickAction::Menu));
+        assert_eq!(answers.save_dir, None);
+    }
+
+    #[test]
+    /// Defaults are written as comments, strings are escaped
+    fn test_setup_render() {
+        let config = render(&Answers {
+            click: Some(ClickAction::Logs),
+            host: None,
+            save_dir: Some(r#"C:\logs "old""#.to_owned()),
+            theme: None,
+        });
+        let lines = config.lines().skip(2).collect::<Vec<_>>();
+        assert_eq!(
+            lines,
+            [
+                "# host = \"unix:///var/run/docker.sock\"",
+                "# theme = \"default\"",
+                "click = \"logs\"",
+                r#"save_dir = "C:\\logs \"old\"""#,
+            ]
+        );
+    }
+}
@@ -0,0 +1,63 @@
+use std::time::Duration;
+
+use clap::ValueEnum;
+use ratatui::{
+    buffer::Buffer,
+    style::{Modifier, Style},
+};
+
+/// How long the screen is flashed for, by the visual bell
+pub const FLASH: Duration = Duration::from_millis(150);
+
+/// The terminal bell character, a terminal multiplexer marks the window it rang in
+pub const BEL: &[u8] = b"\x07";
+
+/// How an alert, a container exiting with a non-zero code, killed for running out of memory, turning unhealthy, a `--watch` match, or a writable layer growing past the `--disk-alert`, is signalled
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Bell {
+    /// Ring the terminal bell
+    Audible,
+    /// Flash the screen, by reversing the colours for a moment
+    Visual,
+    /// Ring the terminal bell, & flash the screen
+    Both,
+}
+
+impl Bell {
+    pub const fn audible(self) -> bool {
+        matches!(self, Self::Audible | Self::Both)
+    }
+
+    pub const fn visual(self) -> bool {
+        matches!(self, Self::Visual | Self::Both)
+    }
+}
+
+/// Reverse the colours of every cell of a drawn frame
+pub fn flash(buffer: &mut Buffer) {
+    let area = buffer.area;
+    buffer.set_style(area, Style::default().add_modifier(Modifier::REVERSED));
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use ratatui::{buffer::Buffer, layout::Rect, style::Modifier};
+
+    use super::{flash, Bell};
+
+    #[test]
+    /// Both rings the bell, & flashes, every cell is reversed by a flash
+    fn test_bell() {
+        assert!(Bell::Audible.audible() && !Bell::Audible.visual());
+        assert!(!Bell::Visual.audible() && Bell::Visual.visual());
+        assert!(Bell::Both.audible() && Bell::Both.visual());
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 2));
+        flash(&mut buffer);
+        assert!(buffer
+            .content
+            .iter()
+            .all(|i| i.modifier.contains(Modifier::REVERSED)));
+    }
+}
@@ -0,0 +1,143 @@
+use std::collections::HashSet;
+
+use crate::app_data::ByteStats;
+
+/// A single buildkit build cache record, as reported by the daemon's disk usage
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CacheRecord {
+    pub description: String,
+    pub id: String,
+    /// Used by a build that is currently running, so can't be removed
+    pub in_use: bool,
+    /// Unix timestamp, in seconds
+    pub last_used: Option<i64>,
+    pub shared: bool,
+    pub size: u64,
+    pub usage_count: i64,
+}
+
+/// The build cache view, records are sorted largest first, and can be marked for removal
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BuildCache {
+    /// With `--dry-run`, the first ( enter ) arms the removal, and the second removes
+    pub armed: bool,
+    /// The host that the build cache is on, the primary host is None
+    pub host: Option<String>,
+    pub marked: HashSet<String>,
+    /// Unix timestamp, in seconds, that last used is measured from
+    pub now: i64,
+    pub records: Vec<CacheRecord>,
+    pub selected: usize,
+}
+
+impl BuildCache {
+    pub fn new(mut records: Vec<CacheRecord>, now: i64, host: Option<String>) -> Self {
+        records.sort_by(|a, b| b.size.cmp(&a.size).then_with(|| a.id.cmp(&b.id)));
+        Self {
+            armed: false,
+            host,
+            marked: HashSet::new(),
+            now,
+            records,
+            selected: 0,
+        }
+    }
+
+    pub fn next(&mut self) {
+        self.selected = (self.selected + 1).min(self.records.len().saturating_sub(1));
+    }
+
+    pub const fn previous(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    /// Mark, or unmark, the selected record, records that are in use can't be marked
+    pub fn toggle(&mut self) {
+        self.armed = false;
+        if let Some(record) = self.records.get(self.selected).filter(|i| !i.in_use) {
+            if !self.marked.remove(&record.id) {
+                self.marked.insert(record.id.clone());
+            }
+        }
+    }
+
+    /// The ids to remove, the marked records, or the selected record if none are marked
+    pub fn chosen(&self) -> Vec<String> {
+        if self.marked.is_empty() {
+            self.records
+                .get(self.selected)
+                .filter(|i| !i.in_use)
+                .map(|i| vec![i.id.clone()])
+                .unwrap_or_default()
+        } else {
+            self.records
+                .iter()
+                .filter(|i| self.marked.contains(&i.id))
+                .map(|i| i.id.clone())
+                .collect()
+        }
+    }
+
+    pub fn total(&self) -> ByteStats {
+        ByteStats::new(self.records.iter().map(|i| i.size).sum())
+    }
+
+    /// Size of the records that would be removed, shared records may still be referenced, so this is an upper bound
+    pub fn chosen_size(&self) -> ByteStats {
+        let chosen = self.chosen();
+        ByteStats::new(
+            self.records
+                .iter()
+                .filter(|i| chosen.contains(&i.id))
+                .map(|i| i.size)
+                .sum(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BuildCache, CacheRecord};
+
+    fn gen_record(id: &str, size: u64, in_use: bool) -> CacheRecord {
+        CacheRecord {
+            description: format!("[stage-0 {id}] RUN make"),
+            id: id.to_owned(),
+            in_use,
+            last_used: Some(100),
+            shared: false,
+            size,
+            usage_count: 1,
+        }
+    }
+
+    #[test]
+    /// Records are sorted largest first, the selected record is chosen until some are marked, in use records can't be chosen
+    fn test_build_cache_chosen() {
+        let mut cache = BuildCache::new(
+            vec![
+                gen_record("a", 10, false),
+                gen_record("b", 300, true),
+                gen_record("c", 200, false),
+            ],
+            1000,
+            None,
+        );
+        assert_eq!(cache.total().get(), 510);
+        assert!(cache.chosen().is_empty());
+        cache.toggle();
+        assert!(cache.marked.is_empty());
+
+        cache.next();
+        assert_eq!(cache.chosen(), ["c"]);
+        cache.next();
+        cache.next();
+        cache.toggle();
+        cache.previous();
+        cache.toggle();
+        assert_eq!(cache.chosen(), ["c", "a"]);
+        assert_eq!(cache.chosen_size().get(), 210);
+        cache.toggle();
+        assert_eq!(cache.chosen(), ["a"]);
+    }
+}
@@ -0,0 +1,101 @@
+use std::{collections::VecDeque, path::PathBuf, sync::Arc};
+
+use tokio::sync::Notify;
+
+use crate::app_data::ContainerId;
+
+/// Number of packets summarised in the capture pane, every packet is in the saved pcap
+const CAPTURE_LINES: usize = 200;
+
+/// A packet capture of a container, the tcpdump filter is typed first, then its packets are summarised as they're captured, until it's stopped
+#[derive(Debug, Clone)]
+pub struct Capture {
+    pub id: ContainerId,
+    pub name: String,
+    pub filter: String,
+    /// The pcap file, once the capture has started
+    pub path: Option<PathBuf>,
+    /// Summaries of the latest packets, oldest first
+    pub lines: VecDeque<String>,
+    pub packets: u64,
+    pub bytes: u64,
+    /// The latest line that tcpdump wrote to stderr, e.g. `listening on any, link-type LINUX_SLL2`
+    pub status: Option<String>,
+    /// Notified to stop the capture, None until it's started
+    stop: Option<Arc<Notify>>,
+}
+
+impl Capture {
+    pub const fn new(id: ContainerId, name: String) -> Self {
+        Self {
+            id,
+            name,
+            filter: String::new(),
+            path: None,
+            lines: VecDeque::new(),
+            packets: 0,
+            bytes: 0,
+            status: None,
+            stop: None,
+        }
+    }
+
+    /// Start the capture, the filter can't be changed once started, returns what the capture awaits to be stopped
+    pub fn start(&mut self) -> Arc<Notify> {
+        let stop = Arc::new(Notify::new());
+        self.stop = Some(Arc::clone(&stop));
+        stop
+    }
+
+    pub const fn is_started(&self) -> bool {
+        self.stop.is_some()
+    }
+
+    /// Stop the capture, it's notified even if it's not currently waiting on the daemon
+    pub fn stop(&self) {
+        if let Some(stop) = &self.stop {
+            stop.notify_one();
+        }
+    }
+
+    /// If this is the capture that's stopped by this notify, so that a closed capture doesn't update its replacement
+    pub fn is(&self, stop: &Arc<Notify>) -> bool {
+        self.stop.as_ref().is_some_and(|i| Arc::ptr_eq(i, stop))
+    }
+
+    /// Add a captured packet, its summary, & its length on the wire
+    pub fn push(&mut self, summary: &str, length: u32) {
+        self.packets += 1;
+        self.bytes += u64::from(length);
+        self.lines.push_back(format!("{summary} length {length}"));
+        if self.lines.len() > CAPTURE_LINES {
+            self.lines.pop_front();
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::{Capture, CAPTURE_LINES};
+    use crate::app_data::ContainerId;
+
+    #[test]
+    /// Only the latest packets are summarised, but every packet is counted, & a capture is only its own stop
+    fn test_capture() {
+        let mut capture = Capture::new(ContainerId::from("1"), "postgres".to_owned());
+        assert!(!capture.is_started());
+        let stop = capture.start();
+        assert!(capture.is_started());
+        assert!(capture.is(&stop));
+        assert!(!Capture::new(ContainerId::from("1"), "postgres".to_owned()).is(&stop));
+
+        for i in 0..=CAPTURE_LINES {
+            capture.push(&format!("UDP {i}"), 10);
+        }
+        assert_eq!(capture.lines.len(), CAPTURE_LINES);
+        assert_eq!(capture.lines.front().unwrap(), "UDP 1 length 10");
+        assert_eq!(capture.packets, 201);
+        assert_eq!(capture.bytes, 2010);
+    }
+}
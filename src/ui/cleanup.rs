@@ -0,0 +1,147 @@
+use crate::app_data::ByteStats;
+
+/// A prune of the cleanup menu, each the equivalent of a docker cli prune
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CleanupKind {
+    Containers,
+    DanglingImages,
+    AllImages,
+    Volumes,
+    Networks,
+}
+
+impl CleanupKind {
+    pub const ALL: [Self; 5] = [
+        Self::Containers,
+        Self::DanglingImages,
+        Self::AllImages,
+        Self::Volumes,
+        Self::Networks,
+    ];
+
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Containers => "stopped containers",
+            Self::DanglingImages => "dangling images",
+            Self::AllImages => "unused images",
+            Self::Volumes => "unused anonymous volumes",
+            Self::Networks => "unused networks",
+        }
+    }
+
+    /// The docker cli command that the prune is the equivalent of
+    pub const fn command(self) -> &'static str {
+        match self {
+            Self::Containers => "docker container prune",
+            Self::DanglingImages => "docker image prune",
+            Self::AllImages => "docker image prune -a",
+            Self::Volumes => "docker volume prune",
+            Self::Networks => "docker network prune",
+        }
+    }
+}
+
+/// What a prune would remove, networks have no size
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CleanupRow {
+    pub count: usize,
+    pub kind: CleanupKind,
+    pub size: Option<ByteStats>,
+}
+
+/// A row of the disk usage, as with `docker system df`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DiskUsage {
+    pub active: usize,
+    pub label: &'static str,
+    pub reclaimable: ByteStats,
+    pub size: ByteStats,
+    pub total: usize,
+}
+
+/// The cleanup menu, the disk usage of the host, and each prune, with what it would remove
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cleanup {
+    /// With `--dry-run`, the confirmed prune needs a second ( y )
+    pub armed: bool,
+    /// The confirmation of the selected prune is shown
+    pub confirm: bool,
+    /// The host that is cleaned up, the primary host is None
+    pub host: Option<String>,
+    pub rows: Vec<CleanupRow>,
+    pub selected: usize,
+    pub usage: Vec<DiskUsage>,
+}
+
+impl Cleanup {
+    pub const fn new(usage: Vec<DiskUsage>, rows: Vec<CleanupRow>, host: Option<String>) -> Self {
+        Self {
+            armed: false,
+            confirm: false,
+            host,
+            rows,
+            selected: 0,
+            usage,
+        }
+    }
+
+    pub fn next(&mut self) {
+        self.selected = (self.selected + 1).min(self.rows.len().saturating_sub(1));
+    }
+
+    pub const fn previous(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    /// The selected prune, None if it wouldn't remove anything
+    pub fn chosen(&self) -> Option<CleanupRow> {
+        self.rows
+            .get(self.selected)
+            .filter(|i| i.count > 0)
+            .copied()
+    }
+
+    /// Show the confirmation of the selected prune, returns false if there's nothing to prune
+    pub fn ask(&mut self) -> bool {
+        self.confirm = self.chosen().is_some();
+        self.confirm
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::{Cleanup, CleanupKind, CleanupRow};
+    use crate::app_data::ByteStats;
+
+    #[test]
+    /// Only a prune that would remove something can be confirmed
+    fn test_cleanup_ask() {
+        let mut cleanup = Cleanup::new(
+            vec![],
+            CleanupKind::ALL
+                .into_iter()
+                .enumerate()
+                .map(|(count, kind)| CleanupRow {
+                    count,
+                    kind,
+                    size: Some(ByteStats::new(0)),
+                })
+                .collect(),
+            None,
+        );
+        assert!(!cleanup.ask());
+        assert!(cleanup.chosen().is_none());
+
+        cleanup.next();
+        assert!(cleanup.ask());
+        assert_eq!(cleanup.chosen().unwrap().kind, CleanupKind::DanglingImages);
+
+        for _ in 0..10 {
+            cleanup.next();
+        }
+        assert_eq!(cleanup.chosen().unwrap().kind, CleanupKind::Networks);
+        cleanup.previous();
+        assert_eq!(cleanup.chosen().unwrap().count, 3);
+    }
+}
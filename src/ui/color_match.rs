@@ -1,4 +1,5 @@
 pub mod log_sanitizer {
+    use std::fmt::Write as _;
 
     use cansi::{v3::categorise_text, Color as CansiColor, Intensity};
     use ratatui::{
@@ -6,6 +7,114 @@ pub mod log_sanitizer {
         text::{Line, Span},
     };
 
+    /// How to handle log output that isn't valid UTF-8, set with `--invalid-utf8`
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+    pub enum InvalidUtf8 {
+        /// Escape each invalid byte, e.g. `\xff`
+        Escape,
+        /// Replace invalid sequences with `�`
+        Lossy,
+        /// Decode each invalid byte as latin-1, so that legacy 8-bit text is kept, intended to be used with "-r"
+        Raw,
+    }
+
+    /// Convert a log line into a String, valid UTF-8 is left as is, invalid bytes are handled by the given strategy
+    pub fn decode(input: &[u8], strategy: InvalidUtf8) -> String {
+        if strategy == InvalidUtf8::Lossy {
+            return String::from_utf8_lossy(input).into_owned();
+        }
+        let mut output = String::with_capacity(input.len());
+        let mut rest = input;
+        loop {
+            match std::str::from_utf8(rest) {
+                Ok(valid) => {
+                    output.push_str(valid);
+                    return output;
+                }
+                Err(e) => {
+                    let (valid, invalid) = rest.split_at(e.valid_up_to());
+                    output.push_str(std::str::from_utf8(valid).unwrap_or_default());
+                    // An incomplete sequence at the end of the input has no error_len
+                    let len = e.error_len().unwrap_or(invalid.len());
+                    for byte in &invalid[..len] {
+                        if strategy == InvalidUtf8::Escape {
+                            write!(output, "\\x{byte:02x}").ok();
+                        } else {
+                            output.push(char::from(*byte));
+                        }
+                    }
+                    rest = &invalid[len..];
+                }
+            }
+        }
+    }
+
+    /// Bytes per row of a hexdump
+    const HEX_WIDTH: usize = 16;
+
+    /// Check if output looks to be binary, rather than text, it is if it contains a NUL byte, or more than 10% of it is control characters
+    /// Tabs, newlines, carriage returns, & escape (for ansi codes), are not counted
+    pub fn is_binary(input: &[u8]) -> bool {
+        let control = input
+            .iter()
+            .filter(|i| (**i < 0x20 && !matches!(**i, b'\t' | b'\n' | b'\r' | 0x1b)) || **i == 0x7f)
+            .count();
+        input.contains(&0) || control * 10 > input.len()
+    }
+
+    /// Format bytes as `hexdump -C` style rows, offset, hex bytes, & printable ascii
+    pub fn hexdump(input: &[u8]) -> Vec<String> {
+        input
+            .chunks(HEX_WIDTH)
+            .enumerate()
+            .map(|(index, chunk)| {
+                let mut row = format!("{:08x} ", index * HEX_WIDTH);
+                for i in 0..HEX_WIDTH {
+                    if i % 8 == 0 {
+                        row.push(' ');
+                    }
+                    match chunk.get(i) {
+                        Some(byte) => write!(row, "{byte:02x} ").ok(),
+                        None => write!(row, "   ").ok(),
+                    };
+                }
+                row.push_str(" |");
+                row.extend(chunk.iter().map(|i| {
+                    if i.is_ascii_graphic() || *i == b' ' {
+                        char::from(*i)
+                    } else {
+                        '.'
+                    }
+                }));
+                row.push('|');
+                row
+            })
+            .collect()
+    }
+
+    /// Progress bars redraw themselves by writing a carriage return, and then the updated text, so only keep the text after the last carriage return
+    /// Trailing carriage returns & newlines, e.g. from CRLF line endings, are removed first
+    pub fn carriage_return(input: &str) -> &str {
+        input
+            .trim_end_matches(['\r', '\n'])
+            .rsplit('\r')
+            .next()
+            .unwrap_or_default()
+    }
+
+    /// Truncate a line to at most max chars, with a suffix showing how many chars were removed, None if the line doesn't need truncating, or max is 0
+    pub fn truncate(input: &str, max: usize) -> Option<String> {
+        if max == 0 {
+            return None;
+        }
+        let (index, _) = input.char_indices().nth(max)?;
+        let removed = input[index..].chars().count();
+        Some(format!(
+            "{}… (+{removed} chars, enter to expand)",
+            &input[..index]
+        ))
+    }
+
     /// Attempt to colorize the given string to ratatui standards
     pub fn colorize_logs<'a>(input: &str) -> Vec<Line<'a>> {
         vec![Line::from(
@@ -41,10 +150,15 @@ pub mod log_sanitizer {
 
     /// Remove all ansi formatting from a given string and create ratatui Lines
     pub fn remove_ansi<'a>(input: &str) -> Vec<Line<'a>> {
-        raw(&categorise_text(input)
+        raw(&plain(input))
+    }
+
+    /// The text of a line, with all ansi formatting removed
+    pub fn plain(input: &str) -> String {
+        categorise_text(input)
             .into_iter()
             .map(|i| i.text)
-            .collect::<String>())
+            .collect::<String>()
     }
 
     /// create ratatui Lines that exactly match the given strings
@@ -80,11 +194,60 @@ mod tests {
         text::{Line, Span},
     };
 
-    use super::log_sanitizer;
+    use super::log_sanitizer::{self, InvalidUtf8};
 
     // This spells out "oxker", with each char having a foreground and background colour
     const INPUT: &str = "\x1b[31;47mo\x1b[32;40mx\x1b[33;41mk\x1b[34;42me\x1b[35;43mr\x1b[0m";
 
+    #[test]
+    /// NUL bytes, or a high ratio of control characters, is binary, ansi escape codes are not
+    fn color_match_is_binary() {
+        assert!(!log_sanitizer::is_binary(INPUT.as_bytes()));
+        assert!(!log_sanitizer::is_binary(b"tab\tseparated\r\n"));
+        assert!(log_sanitizer::is_binary(b"a\0b"));
+        assert!(log_sanitizer::is_binary(b"\x08\x12\x05protobuf"));
+        assert!(!log_sanitizer::is_binary(b""));
+    }
+
+    #[test]
+    /// Hexdump rows contain offset, hex, and ascii, the last row is padded
+    fn color_match_hexdump() {
+        let result = log_sanitizer::hexdump(b"Hello, world!\n\x00\x01\xffabc");
+        assert_eq!(
+            result,
+            [
+                "00000000  48 65 6c 6c 6f 2c 20 77  6f 72 6c 64 21 0a 00 01  |Hello, world!...|",
+                "00000010  ff 61 62 63                                       |.abc|",
+            ]
+        );
+        assert!(log_sanitizer::hexdump(b"").is_empty());
+    }
+
+    #[test]
+    /// Only the text after the last carriage return is kept, trailing line endings ignored
+    fn color_match_carriage_return() {
+        assert_eq!(log_sanitizer::carriage_return("10%\r50%\r100%"), "100%");
+        assert_eq!(log_sanitizer::carriage_return("50%\rdone\r\n"), "done");
+        assert_eq!(log_sanitizer::carriage_return("done\r"), "done");
+        assert_eq!(log_sanitizer::carriage_return("no return"), "no return");
+        assert_eq!(log_sanitizer::carriage_return("\r"), "");
+    }
+
+    #[test]
+    /// Lines longer than max are truncated, with a count of removed chars, 0 disables truncation
+    fn color_match_truncate() {
+        assert_eq!(log_sanitizer::truncate("abcdef", 6), None);
+        assert_eq!(
+            log_sanitizer::truncate("abcdef", 2),
+            Some("ab… (+4 chars, enter to expand)".to_owned())
+        );
+        assert_eq!(
+            log_sanitizer::truncate("ééééé", 3),
+            Some("ééé… (+2 chars, enter to expand)".to_owned())
+        );
+        assert_eq!(log_sanitizer::truncate("abcdef", 0), None);
+    }
+
     #[test]
     /// Return test raw, as in show escape codes
     fn color_match_raw() {
@@ -136,6 +299,30 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    /// Invalid UTF-8 handled by each strategy, valid UTF-8 is always untouched
+    fn color_match_decode() {
+        let input = b"caf\xe9 \xf0\x9f\x92\xa9 ok\xff";
+        assert_eq!(
+            log_sanitizer::decode(input, InvalidUtf8::Lossy),
+            "caf\u{fffd} 💩 ok\u{fffd}"
+        );
+        assert_eq!(
+            log_sanitizer::decode(input, InvalidUtf8::Escape),
+            "caf\\xe9 💩 ok\\xff"
+        );
+        assert_eq!(
+            log_sanitizer::decode(input, InvalidUtf8::Raw),
+            "café 💩 okÿ"
+        );
+        // Truncated multi-byte sequence at the end
+        assert_eq!(
+            log_sanitizer::decode(b"ab\xf0\x9f", InvalidUtf8::Escape),
+            "ab\\xf0\\x9f"
+        );
+        assert_eq!(log_sanitizer::decode(b"oxker", InvalidUtf8::Raw), "oxker");
+    }
+
     #[test]
     /// Remove all escape ansi codes from given input
     fn color_match_remove_ansi() {
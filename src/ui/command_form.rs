@@ -0,0 +1,275 @@
+use crate::{app_data::ExecEntry, exec::command::split};
+
+/// The fields of the command form, moved between with ( tab )
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommandField {
+    Command,
+    User,
+    WorkingDir,
+    Env,
+    Shell,
+}
+
+impl CommandField {
+    pub const ALL: [Self; 5] = [
+        Self::Command,
+        Self::User,
+        Self::WorkingDir,
+        Self::Env,
+        Self::Shell,
+    ];
+
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Command => "command",
+            Self::User => "user",
+            Self::WorkingDir => "directory",
+            Self::Env => "env",
+            Self::Shell => "shell",
+        }
+    }
+
+    const fn next(self) -> Self {
+        match self {
+            Self::Command => Self::User,
+            Self::User => Self::WorkingDir,
+            Self::WorkingDir => Self::Env,
+            Self::Env => Self::Shell,
+            Self::Shell => Self::Command,
+        }
+    }
+
+    const fn previous(self) -> Self {
+        match self {
+            Self::Command => Self::Shell,
+            Self::User => Self::Command,
+            Self::WorkingDir => Self::User,
+            Self::Env => Self::WorkingDir,
+            Self::Shell => Self::Env,
+        }
+    }
+}
+
+/// A command to run in a container, with the user, working directory, & env, of the exec, earlier commands run in containers of the image are recalled with ( ↑ ↓ ), as in a shell
+/// Without the shell the command is exec'd as is, split into arguments as a shell would, for distroless images, which have no `/bin/sh`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommandForm {
+    pub entry: ExecEntry,
+    /// Set when the command can't be run, e.g. an invalid variable
+    pub error: Option<String>,
+    pub field: CommandField,
+    /// Commands run in containers of the image, most recent first
+    pub history: Vec<ExecEntry>,
+    pub image: String,
+    pub name: String,
+    /// Index of the recalled command, None when typing a new command
+    pub recalled: Option<usize>,
+}
+
+impl CommandForm {
+    pub fn new(name: String, image: String, history: Vec<ExecEntry>) -> Self {
+        Self {
+            entry: ExecEntry::shell(""),
+            error: None,
+            field: CommandField::Command,
+            history,
+            image,
+            name,
+            recalled: None,
+        }
+    }
+
+    pub const fn next_field(&mut self) {
+        self.field = self.field.next();
+    }
+
+    pub const fn previous_field(&mut self) {
+        self.field = self.field.previous();
+    }
+
+    /// Recall the next oldest command, along with its options
+    pub fn older(&mut self) {
+        let next = self.recalled.map_or(0, |i| i + 1);
+        if let Some(entry) = self.history.get(next) {
+            self.entry.clone_from(entry);
+            self.recalled = Some(next);
+            self.error = None;
+        }
+    }
+
+    /// Recall the next newest command, past the newest is an empty command
+    pub fn newer(&mut self) {
+        match self.recalled {
+            Some(0) => {
+                self.entry = ExecEntry::shell("");
+                self.recalled = None;
+            }
+            Some(i) => {
+                self.entry.clone_from(&self.history[i - 1]);
+                self.recalled = Some(i - 1);
+            }
+            None => (),
+        }
+        self.error = None;
+    }
+
+    /// The text of the selected field, None for the shell toggle
+    const fn text(&mut self) -> Option<&mut String> {
+        match self.field {
+            CommandField::Command => Some(&mut self.entry.command),
+            CommandField::User => Some(&mut self.entry.user),
+            CommandField::WorkingDir => Some(&mut self.entry.working_dir),
+            CommandField::Env => Some(&mut self.entry.env),
+            CommandField::Shell => None,
+        }
+    }
+
+    /// Typing edits the selected field, so it's no longer the recalled command, ( space ) toggles the shell
+    pub fn push(&mut self, c: char) {
+        match self.text() {
+            Some(text) => text.push(c),
+            None if c == ' ' => self.entry.shell = !self.entry.shell,
+            None => return,
+        }
+        self.recalled = None;
+        self.error = None;
+    }
+
+    pub fn pop(&mut self) {
+        if let Some(text) = self.text() {
+            text.pop();
+            self.recalled = None;
+            self.error = None;
+        }
+    }
+
+    /// The command to run, None if it's empty
+    pub fn command(&self) -> Option<&str> {
+        Some(self.entry.command.trim()).filter(|i| !i.is_empty())
+    }
+
+    /// The command, & its options, trimmed, ready to run, else None, with the error set, if the variables, or, without the shell, the quoting, are invalid
+    pub fn submit(&mut self) -> Option<ExecEntry> {
+        let command = self.command()?.to_owned();
+        let check = self.entry.env_pairs().map(|_| ()).and_then(|()| {
+            if self.entry.shell || split(&command).is_some() {
+                Ok(())
+            } else {
+                Err("unclosed quote in the command".to_owned())
+            }
+        });
+        if let Err(e) = check {
+            self.error = Some(e);
+            return None;
+        }
+        Some(ExecEntry {
+            command,
+            env: self
+                .entry
+                .env
+                .split_whitespace()
+                .collect::<Vec<_>>()
+                .join(" "),
+            shell: self.entry.shell,
+            user: self.entry.user.trim().to_owned(),
+            working_dir: self.entry.working_dir.trim().to_owned(),
+        })
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::{CommandField, CommandForm};
+    use crate::app_data::ExecEntry;
+
+    #[test]
+    /// Commands are recalled oldest with up, newest with down, & typing a new command ends the recall
+    fn test_command_form_recall() {
+        let mut form = CommandForm::new(
+            "db".to_owned(),
+            "postgres:16".to_owned(),
+            vec![
+                ExecEntry {
+                    user: "postgres".to_owned(),
+                    ..ExecEntry::shell("psql")
+                },
+                ExecEntry::shell("df -h"),
+            ],
+        );
+        assert!(form.command().is_none());
+        form.newer();
+        assert!(form.recalled.is_none());
+
+        form.older();
+        assert_eq!(form.command(), Some("psql"));
+        assert_eq!(form.entry.user, "postgres");
+        form.older();
+        form.older();
+        assert_eq!(form.command(), Some("df -h"));
+        assert!(form.entry.user.is_empty());
+        assert_eq!(form.recalled, Some(1));
+        form.newer();
+        assert_eq!(form.command(), Some("psql"));
+        form.newer();
+        assert!(form.command().is_none());
+        assert!(form.recalled.is_none());
+
+        form.older();
+        form.push(' ');
+        form.push('-');
+        form.push('l');
+        assert_eq!(form.command(), Some("psql -l"));
+        assert!(form.recalled.is_none());
+        form.pop();
+        form.pop();
+        assert_eq!(form.command(), Some("psql"));
+    }
+
+    #[test]
+    /// Tab moves between the fields, typing edits the selected field, space toggles the shell, & invalid options aren't submitted
+    fn test_command_form_fields() {
+        let mut form = CommandForm::new("api".to_owned(), "distroless".to_owned(), vec![]);
+        assert!(form.submit().is_none());
+        for c in "/app/server --check 'a b".chars() {
+            form.push(c);
+        }
+        form.previous_field();
+        assert_eq!(form.field, CommandField::Shell);
+        form.push('x');
+        assert!(form.entry.shell);
+        form.push(' ');
+        assert!(!form.entry.shell);
+        assert_eq!(form.submit(), None);
+        assert_eq!(form.error.as_deref(), Some("unclosed quote in the command"));
+
+        form.next_field();
+        form.push('\'');
+        assert!(form.error.is_none());
+        form.next_field();
+        for c in " 1000 ".chars() {
+            form.push(c);
+        }
+        form.next_field();
+        form.next_field();
+        assert_eq!(form.field, CommandField::Env);
+        for c in "RUST_LOG=debug  =x".chars() {
+            form.push(c);
+        }
+        assert!(form.submit().is_none());
+        assert!(form.error.is_some());
+        form.pop();
+        form.pop();
+        form.pop();
+        assert_eq!(
+            form.submit().unwrap(),
+            ExecEntry {
+                command: "/app/server --check 'a b'".to_owned(),
+                env: "RUST_LOG=debug".to_owned(),
+                shell: false,
+                user: "1000".to_owned(),
+                working_dir: String::new(),
+            }
+        );
+    }
+}
@@ -0,0 +1,55 @@
+use crate::docker_data::DockerContext;
+
+/// The docker contexts, the default context first, to pick from at startup
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContextPicker {
+    pub rows: Vec<DockerContext>,
+    pub selected: usize,
+}
+
+impl ContextPicker {
+    pub fn new(contexts: Vec<DockerContext>) -> Self {
+        Self {
+            rows: std::iter::once(DockerContext::default_context())
+                .chain(contexts)
+                .collect(),
+            selected: 0,
+        }
+    }
+
+    pub fn next(&mut self) {
+        self.selected = (self.selected + 1).min(self.rows.len().saturating_sub(1));
+    }
+
+    pub const fn previous(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    pub fn chosen(&self) -> Option<&DockerContext> {
+        self.rows.get(self.selected)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::ContextPicker;
+    use crate::docker_data::DockerContext;
+
+    #[test]
+    /// The default context is always the first row, next & previous don't wrap around
+    fn test_context_picker() {
+        let build = DockerContext {
+            name: "build".to_owned(),
+            host: Some("tcp://build:2376".to_owned()),
+            tls: None,
+        };
+        let mut picker = ContextPicker::new(vec![build.clone()]);
+        assert_eq!(picker.chosen().unwrap().name, "default");
+        picker.next();
+        picker.next();
+        assert_eq!(picker.chosen(), Some(&build));
+        picker.previous();
+        assert!(picker.chosen().unwrap().host.is_none());
+    }
+}
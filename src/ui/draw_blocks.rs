@@ -6,24 +6,37 @@ use ratatui::{
     text::{Line, Span},
     widgets::{
         Axis, Block, BorderType, Borders, Chart, Clear, Dataset, GraphType, List, ListItem,
-        Paragraph,
+        ListState, Padding, Paragraph, Wrap,
     },
     Frame,
 };
-use std::{default::Default, time::Instant};
+use std::{
+    collections::HashSet,
+    default::Default,
+    time::{Instant, SystemTime, UNIX_EPOCH},
+};
 use std::{fmt::Display, sync::Arc};
 
-use crate::app_data::{ContainerItem, ContainerName, Header, SortedOrder};
+use crate::app_data::{
+    ContainerGroup, ContainerItem, ContainerName, ContainerRow, DockerControls, EventKind, Header,
+    Health, ImageItem, Readiness, ServiceItem, Signal, SortedOrder, TopMode, VolumeItem,
+};
 use crate::{
     app_data::{AppData, ByteStats, Columns, CpuStats, State, Stats},
     app_error::AppError,
+    config_file::ConfigError,
 };
 
 use super::{
-    gui_state::{BoxLocation, DeleteButton, Region},
+    gui_state::{BoxLocation, DeleteButton, ExpandedContent, Region},
+    prune::PruneRule,
+    timeline::{format_span, TIMELINE_RECENT},
     FrameData,
 };
-use super::{GuiState, SelectablePanel};
+use super::{
+    CommandField, CommandForm, DiffLine, DiffRow, EnvOrigin, FieldKind, FinderField, GuiState,
+    HealthProbe, MatchView, Pane, PaneLayout, PortRow, PullLayer, SelectablePanel, Theme,
+};
 
 const NAME_TEXT: &str = r#"
                           88                               
@@ -41,8 +54,20 @@ const REPO: &str = env!("CARGO_PKG_REPOSITORY");
 const DESCRIPTION: &str = env!("CARGO_PKG_DESCRIPTION");
 const ORANGE: Color = Color::Rgb(255, 178, 36);
 const MARGIN: &str = "   ";
+/// Number of marked containers named in the confirm dialog, the rest are counted
+const BATCH_NAMES: usize = 8;
+/// Round-trip latency, in ms, at which the host header turns yellow
+const LATENCY_WARN: u128 = 500;
+/// Percentage of the recent cpu periods throttled at which the throttled column turns yellow
+const THROTTLED_WARN: u64 = 10;
+/// Percentage of the recent cpu periods throttled at which the throttled column is highlighted
+const THROTTLED_HIGH: u64 = 50;
 const RIGHT_ARROW: &str = "▶ ";
 const CIRCLE: &str = "⚪ ";
+/// Overlay drawn on the charts of a paused container
+const PAUSED: &str = " paused ";
+/// Marks stats, or logs, that haven't been updated for a few intervals
+const STALE: &str = "⧗";
 
 const CONSTRAINT_50_50: [Constraint; 2] = [Constraint::Percentage(50), Constraint::Percentage(50)];
 const CONSTRAINT_100: [Constraint; 1] = [Constraint::Percentage(100)];
@@ -87,9 +112,48 @@ fn generate_block<'a>(
             format!("{}{}", panel.title(), app_data.lock().container_title())
         }
         SelectablePanel::Logs => {
-            format!("{}{}", panel.title(), app_data.lock().get_log_title())
+            let (log_title, stale) = {
+                let app_data = app_data.lock();
+                (app_data.get_log_title(), app_data.get_selected_stale().1)
+            };
+            format!("{}{log_title}{}", panel.title(), stale_text(stale))
+        }
+        SelectablePanel::Commands => {
+            let app_data = app_data.lock();
+            match app_data.get_marked_len() {
+                0 => app_data
+                    .get_selected_project()
+                    .map_or_else(String::new, ToOwned::to_owned),
+                marked => format!("{marked} marked"),
+            }
+        }
+        SelectablePanel::Images => {
+            let (images_title, host) = {
+                let app_data = app_data.lock();
+                (
+                    app_data.get_images_title(),
+                    app_data.get_resources_host().map(ToOwned::to_owned),
+                )
+            };
+            let host = host.map_or_else(String::new, |i| format!(" - {i}"));
+            format!("{}{images_title}{host}", panel.title())
+        }
+        SelectablePanel::Volumes | SelectablePanel::Networks | SelectablePanel::Services => {
+            let (list_title, host) = {
+                let app_data = app_data.lock();
+                let list_title = match panel {
+                    SelectablePanel::Volumes => app_data.get_volumes_title(),
+                    SelectablePanel::Networks => app_data.get_networks_title(),
+                    _ => app_data.get_services_title(),
+                };
+                (
+                    list_title,
+                    app_data.get_resources_host().map(ToOwned::to_owned),
+                )
+            };
+            let host = host.map_or_else(String::new, |i| format!(" - {i}"));
+            format!("{}{list_title}{host}", panel.title())
         }
-        SelectablePanel::Commands => String::new(),
     };
     if !title.is_empty() {
         title = format!(" {title} ");
@@ -99,11 +163,26 @@ fn generate_block<'a>(
         .border_type(BorderType::Rounded)
         .title(title);
     if fd.selected_panel == panel {
-        block = block.border_style(Style::default().fg(Color::LightCyan));
+        block = block.border_style(Style::default().fg(fd.theme.border));
+    }
+    if panel == SelectablePanel::Logs {
+        if let Some(note) = app_data.lock().get_selected_note() {
+            block = block.title_bottom(Span::styled(
+                format!(" note: {note} "),
+                Style::default().fg(fd.theme.warning),
+            ));
+        }
     }
     block
 }
 
+/// Style of the selected row of a panel, bold, on the selected background of the theme
+fn selected_style(theme: &Theme) -> Style {
+    Style::default()
+        .bg(theme.selected)
+        .add_modifier(Modifier::BOLD)
+}
+
 /// Draw the command panel
 pub fn commands(
     app_data: &Arc<Mutex<AppData>>,
@@ -128,7 +207,7 @@ pub fn commands(
     if let Some(i) = app_data.lock().get_control_state() {
         let items = List::new(items)
             .block(block)
-            .highlight_style(Style::default().add_modifier(Modifier::BOLD))
+            .highlight_style(selected_style(&fd.theme))
             .highlight_symbol(RIGHT_ARROW);
         f.render_stateful_widget(items, area, i);
     } else {
@@ -137,25 +216,112 @@ pub fn commands(
     }
 }
 
+/// When in top mode, replace the last char of the margin with an arrow showing the direction of the most recent change, rising values in the alert color, falling in the ok color
+fn top_mode_margin(
+    delta: Option<std::cmp::Ordering>,
+    state_style: Style,
+    theme: &Theme,
+) -> (String, Style) {
+    match delta {
+        Some(std::cmp::Ordering::Greater) => (
+            "  ↑".to_owned(),
+            Style::default()
+                .fg(theme.alert)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Some(std::cmp::Ordering::Less) => (
+            "  ↓".to_owned(),
+            Style::default().fg(theme.ok).add_modifier(Modifier::BOLD),
+        ),
+        _ => (MARGIN.to_owned(), state_style),
+    }
+}
+
 /// Format the container data to display nicely on the screen
-fn format_containers<'a>(i: &ContainerItem, widths: &Columns) -> Line<'a> {
-    let state_style = Style::default().fg(i.state.get_color());
-    let blue = Style::default().fg(Color::Blue);
+#[allow(clippy::too_many_lines)]
+fn format_containers<'a>(i: &ContainerItem, fd: &FrameData) -> Line<'a> {
+    let (widths, top_mode, group_by) = (&fd.columns, fd.top_mode, fd.group_by);
+    let state_style = Style::default().fg(fd.state_colors.get(i.state, i.is_unhealthy()));
+    let blue = Style::default().fg(fd.theme.highlight);
+    // A restart storm, from the events stream, or with `--restarts`, a crash loop from inspect, has the container's name, restart count, & status, highlighted
+    let crash_loop = i.restart_storm.storm().is_some()
+        || widths.restarts.is_some()
+            && i.exit_info
+                .is_crash_loop(i.state == State::Restarting, fd.now);
+    let crash_style = Style::default()
+        .fg(fd.theme.alert)
+        .add_modifier(Modifier::BOLD);
+
+    // Stale stats are dimmed, & marked in place of the top mode arrow, as the direction of a stale change isn't current
+    let stats_stale = i.state.is_alive() && i.stats_waiting.stale(fd.now, fd.stale_limit).is_some();
+    let usage_style = if stats_stale {
+        state_style.add_modifier(Modifier::DIM)
+    } else {
+        state_style
+    };
+    let (cpu_margin, cpu_style) = if stats_stale {
+        (format!("  {STALE}"), usage_style)
+    } else if top_mode == Some(TopMode::Cpu) {
+        top_mode_margin(i.cpu_delta(), state_style, &fd.theme)
+    } else {
+        (MARGIN.to_owned(), state_style)
+    };
+    let (mem_margin, mem_style) = if stats_stale {
+        (MARGIN.to_owned(), usage_style)
+    } else if top_mode == Some(TopMode::Memory) {
+        top_mode_margin(i.mem_delta(), state_style, &fd.theme)
+    } else {
+        (MARGIN.to_owned(), state_style)
+    };
+
+    // The last char of the margin before the state marks the health of a running container with a health check
+    let health_margin = i
+        .health()
+        .filter(|_| i.state == State::Running)
+        .map_or_else(
+            || Span::styled(MARGIN, state_style),
+            |health| {
+                // Unhealthy matches the unhealthy state color, so that it follows the theme
+                let color = if health == Health::Unhealthy {
+                    fd.state_colors.unhealthy
+                } else {
+                    health.get_color()
+                };
+                Span::styled(format!("  {}", health.icon()), Style::default().fg(color))
+            },
+        );
 
     // Truncate?
-    Line::from(vec![
+    let mut spans = vec![
         Span::styled(
             format!(
                 "{:>width$}",
-                i.name.to_string(),
+                i.display_name(group_by),
                 width = widths.name.1.into()
             ),
+            if crash_loop {
+                crash_style
+            } else {
+                i.hints
+                    .color
+                    .map_or(blue, |color| Style::default().fg(color))
+            },
+        ),
+        Span::styled(
+            widths.host.map_or_else(String::new, |width| {
+                format!(
+                    "{MARGIN}{:>width$}",
+                    i.host.as_deref().unwrap_or(&fd.host),
+                    width = width.1.into()
+                )
+            }),
             blue,
         ),
+        health_margin,
         Span::styled(
             format!(
-                "{MARGIN}{:<width$}",
-                i.state.to_string(),
+                "{:<width$}",
+                fd.state_colors.label(i.state, i.is_unhealthy()),
                 width = widths.state.1.into()
             ),
             state_style,
@@ -163,29 +329,101 @@ fn format_containers<'a>(i: &ContainerItem, widths: &Columns) -> Line<'a> {
         Span::styled(
             format!(
                 "{MARGIN}{:>width$}",
-                i.status,
+                i.status_text(),
                 width = &widths.status.1.into()
             ),
-            state_style,
+            if i.restart_storm.storm().is_some() {
+                crash_style
+            } else {
+                state_style
+            },
+        ),
+        widths.ready.map_or_else(
+            || Span::from(""),
+            |width| {
+                let style = match i.readiness {
+                    Some(Readiness::Ready) => Style::default().fg(fd.theme.ok),
+                    Some(Readiness::NotReady) => Style::default().fg(fd.theme.error),
+                    _ => state_style,
+                };
+                Span::styled(
+                    format!(
+                        "{MARGIN}{:>width$}",
+                        i.readiness.map_or_else(String::new, |i| i.to_string()),
+                        width = width.1.into()
+                    ),
+                    style,
+                )
+            },
+        ),
+        widths.exit_code.map_or_else(
+            || Span::from(""),
+            |width| {
+                let style = match i.exit_info.exit_code {
+                    Some(0) | None => state_style,
+                    Some(_) => Style::default().fg(fd.theme.error),
+                };
+                Span::styled(
+                    format!(
+                        "{MARGIN}{:>width$}",
+                        i.exit_info
+                            .exit_code
+                            .map_or_else(String::new, |i| i.to_string()),
+                        width = width.1.into()
+                    ),
+                    style,
+                )
+            },
         ),
+        widths.restarts.map_or_else(
+            || Span::from(""),
+            |width| {
+                Span::styled(
+                    format!(
+                        "{MARGIN}{:>width$}",
+                        i.exit_info
+                            .restart_count
+                            .map_or_else(String::new, |i| i.to_string()),
+                        width = width.1.into()
+                    ),
+                    if crash_loop { crash_style } else { state_style },
+                )
+            },
+        ),
+        Span::styled(cpu_margin, cpu_style),
         Span::styled(
-            format!(
-                "{}{:>width$}",
-                MARGIN,
-                i.cpu_stats.back().unwrap_or(&CpuStats::default()),
-                width = &widths.cpu.1.into()
-            ),
-            state_style,
+            format!("{:>width$}", i.cpu_text(), width = &widths.cpu.1.into()),
+            usage_style,
+        ),
+        widths.throttled.map_or_else(
+            || Span::from(""),
+            |width| {
+                // Throttled in most of the recent periods, the container is held at its cpu quota
+                let style = match i.throttling.percent() {
+                    Some(x) if x >= THROTTLED_HIGH => crash_style,
+                    Some(x) if x >= THROTTLED_WARN => Style::default().fg(fd.theme.warning),
+                    _ => usage_style,
+                };
+                Span::styled(
+                    format!(
+                        "{MARGIN}{:>width$}",
+                        i.throttling.to_string(),
+                        width = width.1.into()
+                    ),
+                    style,
+                )
+            },
         ),
+        Span::styled(mem_margin, mem_style),
         Span::styled(
             format!(
-                "{MARGIN}{:>width_current$} / {:>width_limit$}",
+                "{:>width_current$} / {:>width_limit$}",
                 i.mem_stats.back().unwrap_or(&ByteStats::default()),
                 i.mem_limit,
                 width_current = &widths.mem.1.into(),
                 width_limit = &widths.mem.2.into()
             ),
-            state_style,
+            usage_style,
         ),
         Span::styled(
             format!(
@@ -206,13 +444,81 @@ fn format_containers<'a>(i: &ContainerItem, widths: &Columns) -> Line<'a> {
         ),
         Span::styled(
             format!("{MARGIN}{:>width$}", i.rx, width = widths.net_rx.1.into()),
-            Style::default().fg(Color::Rgb(255, 233, 193)),
+            Style::default().fg(fd.theme.chart_rx),
         ),
         Span::styled(
             format!("{MARGIN}{:>width$}", i.tx, width = widths.net_tx.1.into()),
-            Style::default().fg(Color::Rgb(205, 140, 140)),
+            Style::default().fg(fd.theme.chart_tx),
+        ),
+        Span::styled(
+            widths.net_io.map_or_else(String::new, |width| {
+                format!(
+                    "{MARGIN}{:>width$}",
+                    i.io_rates().net(),
+                    width = width.1.into()
+                )
+            }),
+            Style::default().fg(fd.theme.chart_rx),
+        ),
+        Span::styled(
+            widths.disk_io.map_or_else(String::new, |width| {
+                format!(
+                    "{MARGIN}{:>width$}",
+                    i.io_rates().disk(),
+                    width = width.1.into()
+                )
+            }),
+            Style::default().fg(fd.theme.chart_tx),
+        ),
+    ];
+    spans.extend(widths.labels.iter().enumerate().map(|(index, width)| {
+        Span::styled(
+            format!(
+                "{MARGIN}{:>width$}",
+                i.label_values
+                    .get(index)
+                    .and_then(Option::as_deref)
+                    .unwrap_or_default(),
+                width = width.1.into()
+            ),
+            blue,
+        )
+    }));
+    Line::from(spans)
+}
+
+/// Format a group header row, showing the running count, and the combined cpu & memory usage of all the containers in the group
+fn format_group<'a>(group: &ContainerGroup, widths: &Columns, theme: &Theme) -> Line<'a> {
+    let style = Style::default().fg(theme.text).add_modifier(Modifier::BOLD);
+    let host = widths.host.map_or_else(String::new, |i| {
+        format!("{MARGIN}{:>width$}", "", width = i.1.into())
+    });
+    let blank = |column: Option<(Header, u8)>| {
+        column.map_or_else(String::new, |i| {
+            format!("{MARGIN}{:>width$}", "", width = i.1.into())
+        })
+    };
+    let ready = [widths.ready, widths.exit_code, widths.restarts]
+        .into_iter()
+        .map(blank)
+        .collect::<String>();
+    let throttled = blank(widths.throttled);
+    Line::from(vec![Span::styled(
+        format!(
+            "{:>name$}{host}{MARGIN}{:<state$}{MARGIN}{:>status$}{ready}{MARGIN}{:>cpu$}{throttled}{MARGIN}{:>mem$}",
+            group.label(),
+            group.count_text(),
+            "",
+            group.cpu,
+            group.mem,
+            name = widths.name.1.into(),
+            state = widths.state.1.into(),
+            status = widths.status.1.into(),
+            cpu = widths.cpu.1.into(),
+            mem = widths.mem.1.into(),
         ),
-    ])
+        style,
+    )])
 }
 
 /// Draw the containers panel
@@ -225,12 +531,52 @@ pub fn containers(
 ) {
     let block = generate_block(app_data, area, fd, gui_state, SelectablePanel::Containers);
 
-    let items = app_data
-        .lock()
-        .get_container_items()
-        .iter()
-        .map(|i| ListItem::new(format_containers(i, &fd.columns)))
-        .collect::<Vec<_>>();
+    // Only the rows that fit in the panel are formatted, so that thousands of containers can be drawn, & scrolled, without formatting every row on every frame
+    let (items, mut state) = {
+        let mut app_data = app_data.lock();
+        let rows = app_data.get_container_rows();
+        let height = usize::from(area.height.saturating_sub(2));
+        let state = app_data.get_container_state();
+        let selected = state
+            .selected()
+            .map(|i| i.min(rows.len().saturating_sub(1)));
+        let start = visible_start(state.offset(), selected, height, rows.len());
+        *state.offset_mut() = start;
+        let window = ListState::default().with_selected(selected.map(|i| i - start));
+        let rows = rows
+            .get(start..rows.len().min(start + height))
+            .unwrap_or_default();
+
+        let containers = app_data.get_container_items();
+        // Containers loaded from the cache are dimmed, until the Docker daemon has responded
+        let style = if app_data.is_stale() {
+            Style::default().add_modifier(Modifier::DIM)
+        } else {
+            Style::default()
+        };
+        let mut visible = HashSet::new();
+        let items = rows
+            .iter()
+            .filter_map(|row| match row {
+                ContainerRow::Group(group) => {
+                    Some(ListItem::new(format_group(group, &fd.columns, &fd.theme)))
+                }
+                ContainerRow::Container(index) => containers.get(*index).map(|i| {
+                    visible.insert(i.id.clone());
+                    let mut line = format_containers(i, fd);
+                    // A marked container has its name reversed
+                    if let Some(name) = line.spans.first_mut().filter(|_| app_data.is_marked(&i.id))
+                    {
+                        name.style = name.style.add_modifier(Modifier::REVERSED);
+                    }
+                    ListItem::new(line).style(style)
+                }),
+            })
+            .collect::<Vec<_>>();
+        app_data.set_visible_containers(visible);
+        drop(app_data);
+        (items, window)
+    };
 
     if items.is_empty() {
         let paragraph = Paragraph::new("no containers running")
@@ -240,9 +586,481 @@ pub fn containers(
     } else {
         let items = List::new(items)
             .block(block)
-            .highlight_style(Style::default().add_modifier(Modifier::BOLD))
+            .highlight_style(selected_style(&fd.theme))
+            .highlight_symbol(CIRCLE);
+        f.render_stateful_widget(items, area, &mut state);
+    }
+}
+
+/// The first row shown in a list of single line rows, the previous first row is kept, unless that would hide the selected row, in the same way as a ratatui List
+fn visible_start(offset: usize, selected: Option<usize>, height: usize, len: usize) -> usize {
+    let start = offset.min(len.saturating_sub(1));
+    match selected {
+        Some(selected) if selected >= start + height => selected + 1 - height.max(1),
+        Some(selected) if selected < start => selected,
+        _ => start,
+    }
+}
+
+/// Width of the repository column of the images panel, longer repositories are truncated
+const IMAGE_REPOSITORY_WIDTH: usize = 32;
+
+/// Width of the tag column of the images panel, longer tags are truncated
+const IMAGE_TAG_WIDTH: usize = 16;
+
+/// Width of the name, & driver, columns of the volumes, & networks, panels, longer names are truncated
+const RESOURCE_NAME_WIDTH: usize = 32;
+
+/// Truncate text to a width, marking the truncation with `…`
+fn truncate_text(text: &str, width: usize) -> String {
+    if text.chars().count() > width {
+        format!(
+            "{}…",
+            text.chars()
+                .take(width.saturating_sub(1))
+                .collect::<String>()
+        )
+    } else {
+        text.to_owned()
+    }
+}
+
+/// Draw the images panel, the local images of the selected host, newest first, dangling images are dimmed
+pub fn images(
+    app_data: &Arc<Mutex<AppData>>,
+    area: Rect,
+    f: &mut Frame,
+    fd: &FrameData,
+    gui_state: &Arc<Mutex<GuiState>>,
+) {
+    let block = generate_block(app_data, area, fd, gui_state, SelectablePanel::Images);
+
+    let images = app_data.lock().get_images().to_vec();
+    let items = {
+        let width = |f: fn(&ImageItem) -> usize, max: usize| {
+            images.iter().map(f).max().unwrap_or_default().min(max)
+        };
+        let repository_width = width(
+            |i| i.repository_tag().0.chars().count(),
+            IMAGE_REPOSITORY_WIDTH,
+        );
+        let tag_width = width(|i| i.repository_tag().1.chars().count(), IMAGE_TAG_WIDTH);
+        images
+            .iter()
+            .map(|i| {
+                let (repository, tag) = i.repository_tag();
+                let style = if i.is_dangling() {
+                    Style::default().add_modifier(Modifier::DIM)
+                } else {
+                    Style::default()
+                };
+                ListItem::new(Line::from(vec![
+                    Span::styled(
+                        format!(
+                            "{:<repository_width$}  {:<tag_width$}  ",
+                            truncate_text(repository, repository_width),
+                            truncate_text(tag, tag_width)
+                        ),
+                        Style::default().add_modifier(Modifier::BOLD),
+                    ),
+                    Span::styled(
+                        format!("{}  ", i.short_id()),
+                        Style::default().fg(fd.theme.highlight),
+                    ),
+                    Span::raw(format!("{:>9}  ", i.size.to_string())),
+                    Span::raw(i.state_text()),
+                ]))
+                .style(style)
+            })
+            .collect::<Vec<_>>()
+    };
+
+    resource_list(
+        f,
+        area,
+        block,
+        items,
+        "no images",
+        app_data.lock().get_images_state(),
+        &fd.theme,
+    );
+}
+
+/// Draw the list of an images, volumes, or networks panel, or the placeholder text if there's nothing to list
+fn resource_list(
+    f: &mut Frame,
+    area: Rect,
+    block: Block,
+    items: Vec<ListItem>,
+    empty: &str,
+    state: &mut ListState,
+    theme: &Theme,
+) {
+    if items.is_empty() {
+        let paragraph = Paragraph::new(empty)
+            .block(block)
+            .alignment(Alignment::Center);
+        f.render_widget(paragraph, area);
+    } else {
+        let items = List::new(items)
+            .block(block)
+            .highlight_style(selected_style(theme))
             .highlight_symbol(CIRCLE);
-        f.render_stateful_widget(items, area, app_data.lock().get_container_state());
+        f.render_stateful_widget(items, area, state);
+    }
+}
+
+/// The bottom title of the volumes, or networks, panel, the armed removal of the selected row, or the containers that use it
+fn resource_hint<'a>(
+    armed: bool,
+    name: &str,
+    containers: &[String],
+    theme: &Theme,
+) -> Option<Span<'a>> {
+    if armed {
+        Some(Span::styled(
+            format!(" ( enter ) again to remove {name} ( esc ) cancel "),
+            Style::default()
+                .fg(theme.error)
+                .add_modifier(Modifier::BOLD),
+        ))
+    } else if containers.is_empty() {
+        None
+    } else {
+        Some(Span::styled(
+            format!(" used by {} ", containers.join(", ")),
+            Style::default().add_modifier(Modifier::DIM),
+        ))
+    }
+}
+
+/// Width of a column of the volumes, or networks, panel, the widest value, up to a max
+fn column_width<T>(items: &[T], max: usize, f: impl Fn(&T) -> &str) -> usize {
+    items
+        .iter()
+        .map(|i| f(i).chars().count())
+        .max()
+        .unwrap_or_default()
+        .min(max)
+}
+
+/// Draw the volumes panel, the volumes of the selected host, by name, the containers that use the selected volume are listed in the bottom border
+pub fn volumes(
+    app_data: &Arc<Mutex<AppData>>,
+    area: Rect,
+    f: &mut Frame,
+    fd: &FrameData,
+    gui_state: &Arc<Mutex<GuiState>>,
+) {
+    let mut block = generate_block(app_data, area, fd, gui_state, SelectablePanel::Volumes);
+    let armed = gui_state.lock().get_remove_armed().map(ToOwned::to_owned);
+    let (volumes, selected) = {
+        let app_data = app_data.lock();
+        (
+            app_data.get_volumes().to_vec(),
+            app_data.get_selected_volume().cloned(),
+        )
+    };
+    if let Some(hint) = selected.and_then(|i| {
+        resource_hint(
+            armed.as_deref() == Some(i.name.as_str()),
+            i.short_name(),
+            &i.containers,
+            &fd.theme,
+        )
+    }) {
+        block = block.title_bottom(hint);
+    }
+    let name_width = column_width(&volumes, RESOURCE_NAME_WIDTH, VolumeItem::short_name);
+    let driver_width = column_width(&volumes, RESOURCE_NAME_WIDTH, |i| i.driver.as_str());
+    let items = volumes
+        .iter()
+        .map(|i| {
+            let style = if armed.as_deref() == Some(i.name.as_str()) {
+                Style::default().fg(fd.theme.error)
+            } else {
+                Style::default()
+            };
+            ListItem::new(Line::from(vec![
+                Span::styled(
+                    format!(
+                        "{:<name_width$}  ",
+                        truncate_text(i.short_name(), name_width)
+                    ),
+                    Style::default().add_modifier(Modifier::BOLD),
+                ),
+                Span::raw(format!(
+                    "{:<driver_width$}  {:<6}  ",
+                    truncate_text(&i.driver, driver_width),
+                    i.scope
+                )),
+                Span::raw(format!(
+                    "{:>9}  ",
+                    i.size.map_or_else(|| "-".to_owned(), |i| i.to_string())
+                )),
+                Span::raw(i.state_text()),
+            ]))
+            .style(style)
+        })
+        .collect::<Vec<_>>();
+    resource_list(
+        f,
+        area,
+        block,
+        items,
+        "no volumes",
+        app_data.lock().get_volumes_state(),
+        &fd.theme,
+    );
+}
+
+/// Draw the networks panel, the networks of the selected host, by name, predefined networks, which can't be removed, are dimmed
+pub fn networks(
+    app_data: &Arc<Mutex<AppData>>,
+    area: Rect,
+    f: &mut Frame,
+    fd: &FrameData,
+    gui_state: &Arc<Mutex<GuiState>>,
+) {
+    let mut block = generate_block(app_data, area, fd, gui_state, SelectablePanel::Networks);
+    let armed = gui_state.lock().get_remove_armed().map(ToOwned::to_owned);
+    let (networks, selected) = {
+        let app_data = app_data.lock();
+        (
+            app_data.get_networks().to_vec(),
+            app_data.get_selected_network().cloned(),
+        )
+    };
+    if let Some(hint) = selected.and_then(|i| {
+        resource_hint(
+            armed.as_deref() == Some(i.id.as_str()),
+            &i.name,
+            &i.containers,
+            &fd.theme,
+        )
+    }) {
+        block = block.title_bottom(hint);
+    }
+    let name_width = column_width(&networks, RESOURCE_NAME_WIDTH, |i| i.name.as_str());
+    let driver_width = column_width(&networks, RESOURCE_NAME_WIDTH, |i| i.driver.as_str());
+    let items = networks
+        .iter()
+        .map(|i| {
+            let style = if armed.as_deref() == Some(i.id.as_str()) {
+                Style::default().fg(fd.theme.error)
+            } else if i.is_predefined() {
+                Style::default().add_modifier(Modifier::DIM)
+            } else {
+                Style::default()
+            };
+            let mut spans = vec![
+                Span::styled(
+                    format!("{:<name_width$}  ", truncate_text(&i.name, name_width)),
+                    Style::default().add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    format!("{}  ", i.short_id()),
+                    Style::default().fg(fd.theme.highlight),
+                ),
+                Span::raw(format!(
+                    "{:<driver_width$}  {:<6}  ",
+                    truncate_text(&i.driver, driver_width),
+                    i.scope
+                )),
+                Span::raw(i.state_text()),
+            ];
+            if i.internal {
+                spans.push(Span::styled(
+                    ", internal",
+                    Style::default().add_modifier(Modifier::DIM),
+                ));
+            }
+            ListItem::new(Line::from(spans)).style(style)
+        })
+        .collect::<Vec<_>>();
+    resource_list(
+        f,
+        area,
+        block,
+        items,
+        "no networks",
+        app_data.lock().get_networks_state(),
+        &fd.theme,
+    );
+}
+
+/// The actions of the selected service, or its armed force update, or stack removal, in the error color
+fn service_hint(service: &ServiceItem, armed: Option<&str>, theme: &Theme) -> Span<'static> {
+    let armed_text = if armed == Some(service.id.as_str()) {
+        Some(format!(
+            " ( r ) again to force update {} ( esc ) cancel ",
+            service.name
+        ))
+    } else if service
+        .stack_armed_key()
+        .is_some_and(|i| armed == Some(i.as_str()))
+    {
+        Some(format!(
+            " ( delete ) again to remove stack {} ( esc ) cancel ",
+            service.stack.as_deref().unwrap_or_default()
+        ))
+    } else {
+        None
+    };
+    armed_text.map_or_else(
+        || {
+            Span::styled(
+                if service.stack.is_some() {
+                    " ( enter ) task logs ( + ) scale ( r ) force update ( delete ) remove stack "
+                } else {
+                    " ( enter ) task logs ( + ) scale ( r ) force update "
+                },
+                Style::default().add_modifier(Modifier::DIM),
+            )
+        },
+        |i| {
+            Span::styled(
+                i,
+                Style::default()
+                    .fg(theme.error)
+                    .add_modifier(Modifier::BOLD),
+            )
+        },
+    )
+}
+
+/// Draw the services panel, the swarm services of the selected host, grouped by stack, with their running of desired tasks, degraded services in the error color
+/// The stack is only shown on the first service of each stack, the actions of the selected service are listed in the bottom border, or its armed force update, or stack removal
+pub fn services(
+    app_data: &Arc<Mutex<AppData>>,
+    area: Rect,
+    f: &mut Frame,
+    fd: &FrameData,
+    gui_state: &Arc<Mutex<GuiState>>,
+) {
+    let mut block = generate_block(app_data, area, fd, gui_state, SelectablePanel::Services);
+    let armed = gui_state.lock().get_remove_armed().map(ToOwned::to_owned);
+    let (services, selected) = {
+        let app_data = app_data.lock();
+        (
+            app_data.get_services().to_vec(),
+            app_data.get_selected_service().cloned(),
+        )
+    };
+    if let Some(service) = selected {
+        block = block.title_bottom(service_hint(&service, armed.as_deref(), &fd.theme));
+    }
+    let stack_width = column_width(&services, RESOURCE_NAME_WIDTH, |i| {
+        i.stack.as_deref().unwrap_or("-")
+    });
+    let name_width = column_width(&services, RESOURCE_NAME_WIDTH, |i| i.name.as_str());
+    let image_width = column_width(&services, RESOURCE_NAME_WIDTH, |i| i.image.as_str());
+    let items = services
+        .iter()
+        .enumerate()
+        .map(|(index, i)| {
+            let replicas = if i.is_degraded() {
+                Style::default().fg(fd.theme.error)
+            } else {
+                Style::default()
+            };
+            let mut spans = vec![];
+            if services.iter().any(|i| i.stack.is_some()) {
+                let first = index == 0 || services[index - 1].stack != i.stack;
+                let (stack, style) = match (first, i.stack.as_deref()) {
+                    (false, _) => ("", Style::default()),
+                    (true, Some(stack)) => (stack, Style::default().fg(fd.theme.accent)),
+                    (true, None) => ("-", Style::default().add_modifier(Modifier::DIM)),
+                };
+                spans.push(Span::styled(
+                    format!("{:<stack_width$}  ", truncate_text(stack, stack_width)),
+                    style,
+                ));
+            }
+            spans.extend([
+                Span::styled(
+                    format!("{:<name_width$}  ", truncate_text(&i.name, name_width)),
+                    Style::default().add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    format!("{}  ", i.short_id()),
+                    Style::default().fg(fd.theme.highlight),
+                ),
+                Span::styled(format!("{:<7}", i.replicas_text()), replicas),
+                Span::raw(format!(
+                    "  {:<image_width$}  ",
+                    truncate_text(&i.image, image_width)
+                )),
+            ]);
+            if i.global {
+                spans.push(Span::styled(
+                    "global ",
+                    Style::default().add_modifier(Modifier::DIM),
+                ));
+            }
+            if let Some(update) = i.update.as_ref() {
+                spans.push(Span::styled(
+                    update.clone(),
+                    Style::default().fg(fd.theme.warning),
+                ));
+            }
+            ListItem::new(Line::from(spans))
+        })
+        .collect::<Vec<_>>();
+    resource_list(
+        f,
+        area,
+        block,
+        items,
+        "no services",
+        app_data.lock().get_services_state(),
+        &fd.theme,
+    );
+}
+
+/// Draw the events pane, the most recent events from the Docker events stream, newest first
+pub fn events(f: &mut Frame, area: Rect, app_data: &Arc<Mutex<AppData>>, now: u64) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(" Events ");
+    let now = i64::try_from(now).unwrap_or_default();
+    let items = app_data
+        .lock()
+        .get_events()
+        .iter()
+        .rev()
+        .take(usize::from(area.height))
+        .map(|i| {
+            let (symbol, color) = match i.kind {
+                EventKind::Container(kind) => (kind.symbol(), kind.color()),
+                EventKind::Pull => ('↓', Color::Cyan),
+            };
+            let ago = format!(
+                "{} ago",
+                format_duration(u64::try_from(now - i.time).unwrap_or_default())
+            );
+            let host = i
+                .host
+                .as_ref()
+                .map_or_else(String::new, |i| format!("{i}: "));
+            ListItem::new(Line::from(vec![
+                Span::styled(
+                    format!("{ago:<12}"),
+                    Style::default().add_modifier(Modifier::DIM),
+                ),
+                Span::styled(symbol.to_string(), Style::default().fg(color)),
+                Span::from(format!(" {host}{i}")),
+            ]))
+        })
+        .collect::<Vec<_>>();
+    if items.is_empty() {
+        let paragraph = Paragraph::new("no events yet")
+            .block(block)
+            .alignment(Alignment::Center);
+        f.render_widget(paragraph, area);
+    } else {
+        f.render_widget(List::new(items).block(block), area);
     }
 }
 
@@ -262,18 +1080,27 @@ pub fn logs(
             .alignment(Alignment::Center);
         f.render_widget(paragraph, area);
     } else {
-        let logs = app_data.lock().get_logs();
+        let (logs, filtered) = {
+            let app_data = app_data.lock();
+            (app_data.get_logs(), app_data.get_log_filter().is_some())
+        };
 
         if logs.is_empty() {
-            let paragraph = Paragraph::new("no logs found")
+            let text = if filtered {
+                "no logs match the filter"
+            } else {
+                "no logs found"
+            };
+            let paragraph = Paragraph::new(text)
                 .block(block)
                 .alignment(Alignment::Center);
             f.render_widget(paragraph, area);
         } else {
             let items = List::new(logs)
                 .block(block)
+                .style(Style::default().fg(fd.theme.log_text))
                 .highlight_symbol(RIGHT_ARROW)
-                .highlight_style(Style::default().add_modifier(Modifier::BOLD));
+                .highlight_style(selected_style(&fd.theme));
             // This should always return Some, as logs is not empty
             if let Some(log_state) = app_data.lock().get_log_state() {
                 f.render_stateful_widget(items, area, log_state);
@@ -289,21 +1116,38 @@ pub fn ports(
     app_data: &Arc<Mutex<AppData>>,
     max_lens: (usize, usize, usize),
 ) {
+    let (state_colors, theme) = {
+        let app_data = app_data.lock();
+        (app_data.args.state_colors, app_data.args.theme)
+    };
+    let conflicts = app_data.lock().get_selected_port_conflicts();
+    // Red when the other container is running, so this one can't be started, yellow when the two can't both be running
+    let conflict_lines = conflicts.iter().map(|i| {
+        let fg = if i.with_running {
+            theme.error
+        } else {
+            theme.warning
+        };
+        Line::from(Span::from(format!("! {i}")).fg(fg))
+    });
     if let Some(ports) = app_data.lock().get_selected_ports() {
         let block = Block::default()
             .borders(Borders::ALL)
             .border_type(BorderType::Rounded)
             .title_alignment(Alignment::Center)
             .title(Span::styled(
-                " ports ",
+                format!(" ports{} ", state_colors.cue(ports.1)),
                 Style::default()
-                    .fg(ports.1.get_color())
+                    .fg(state_colors.get(ports.1, false))
                     .add_modifier(Modifier::BOLD),
             ));
 
         let (ip, private, public) = max_lens;
 
-        if ports.0.is_empty() {
+        if ports.0.is_empty() && !conflicts.is_empty() {
+            let paragraph = Paragraph::new(conflict_lines.collect::<Vec<_>>()).block(block);
+            f.render_widget(paragraph, area);
+        } else if ports.0.is_empty() {
             let text = match ports.1 {
                 State::Running | State::Paused | State::Restarting => "no ports",
                 _ => "",
@@ -318,10 +1162,14 @@ pub fn ports(
                     "{:>ip$}{:>private$}{:>public$}",
                     "ip", "private", "public"
                 ))
-                .fg(Color::Yellow),
+                .fg(theme.warning),
             )];
             for item in &ports.0 {
-                let fg = Color::White;
+                let fg = if conflicts.iter().any(|i| Some(i.port) == item.public) {
+                    theme.error
+                } else {
+                    theme.text
+                };
                 let strings = item.print();
 
                 let line = vec![
@@ -331,6 +1179,7 @@ pub fn ports(
                 ];
                 output.push(Line::from(line));
             }
+            output.extend(conflict_lines);
             let paragraph = Paragraph::new(output).block(block);
             f.render_widget(paragraph, area);
         }
@@ -339,6 +1188,15 @@ pub fn ports(
 
 /// Draw the cpu + mem charts
 pub fn chart(f: &mut Frame, area: Rect, app_data: &Arc<Mutex<AppData>>) {
+    let (state_colors, theme) = {
+        let app_data = app_data.lock();
+        (app_data.args.state_colors, app_data.args.theme)
+    };
+    let stale = app_data.lock().get_selected_stale().0;
+    let (window, view_title) = {
+        let app_data = app_data.lock();
+        (app_data.get_chart_window(), app_data.get_chart_title())
+    };
     if let Some((cpu, mem)) = app_data.lock().get_chart_data() {
         let area = Layout::default()
             .direction(Direction::Horizontal)
@@ -347,45 +1205,88 @@ pub fn chart(f: &mut Frame, area: Rect, app_data: &Arc<Mutex<AppData>>) {
 
         let cpu_dataset = vec![Dataset::default()
             .marker(symbols::Marker::Dot)
-            .style(Style::default().fg(Color::Magenta))
+            .style(Style::default().fg(theme.chart_cpu))
             .graph_type(GraphType::Line)
             .data(&cpu.0)];
         let mem_dataset = vec![Dataset::default()
             .marker(symbols::Marker::Dot)
-            .style(Style::default().fg(Color::Cyan))
+            .style(Style::default().fg(theme.chart_memory))
             .graph_type(GraphType::Line)
             .data(&mem.0)];
 
         let cpu_stats = CpuStats::new(cpu.0.last().map_or(0.00, |f| f.1));
         #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
         let mem_stats = ByteStats::new(mem.0.last().map_or(0, |f| f.1 as u64));
-        let cpu_chart = make_chart(cpu.2, "cpu", cpu_dataset, &cpu_stats, &cpu.1);
-        let mem_chart = make_chart(mem.2, "memory", mem_dataset, &mem_stats, &mem.1);
+        let color = state_colors.get(cpu.2, false);
+        let cue = format!(
+            "{}{}{view_title}",
+            state_colors.cue(cpu.2),
+            stale_text(stale)
+        );
+        let cpu_chart = make_chart(
+            (cpu.2, window),
+            color,
+            "cpu",
+            &cue,
+            cpu_dataset,
+            &cpu_stats,
+            &cpu.1,
+        );
+        let mem_chart = make_chart(
+            (mem.2, window),
+            color,
+            "memory",
+            &cue,
+            mem_dataset,
+            &mem_stats,
+            &mem.1,
+        );
 
         f.render_widget(cpu_chart, area[0]);
         f.render_widget(mem_chart, area[1]);
+
+        // Charts are frozen while paused, so make it clear that the last values aren't current
+        if cpu.2 == State::Paused {
+            let width = u16::try_from(PAUSED.chars().count()).unwrap_or_default();
+            for area in area.iter() {
+                let overlay = Rect {
+                    x: area.right().saturating_sub(width + 1).max(area.x),
+                    y: area.y + 1,
+                    width: width.min(area.width),
+                    height: 1.min(area.height.saturating_sub(1)),
+                };
+                f.render_widget(Clear, overlay);
+                f.render_widget(
+                    Paragraph::new(PAUSED)
+                        .style(Style::default().fg(color).add_modifier(Modifier::BOLD)),
+                    overlay,
+                );
+            }
+        }
     }
 }
 
-/// Create charts
+/// Create charts, the x axis spans the number of samples shown
+#[allow(clippy::cast_precision_loss)]
 fn make_chart<'a, T: Stats + Display>(
-    state: State,
+    (state, window): (State, usize),
+    title_color: Color,
     name: &'a str,
+    cue: &str,
     dataset: Vec<Dataset<'a>>,
-    current: &'a T,
+    current: &dyn Display,
     max: &'a T,
 ) -> Chart<'a> {
-    let title_color = state.get_color();
     let label_color = match state {
         State::Running => ORANGE,
-        _ => state.get_color(),
+        _ => title_color,
     };
     Chart::new(dataset)
         .block(
             Block::default()
                 .title_alignment(Alignment::Center)
                 .title(Span::styled(
-                    format!(" {name} {current} "),
+                    format!(" {name} {current}{cue} "),
                     Style::default()
                         .fg(title_color)
                         .add_modifier(Modifier::BOLD),
@@ -396,7 +1297,7 @@ fn make_chart<'a, T: Stats + Display>(
         .x_axis(
             Axis::default()
                 .style(Style::default().fg(title_color))
-                .bounds([0.00, 60.0]),
+                .bounds([0.00, window as f64]),
         )
         .y_axis(
             Axis::default()
@@ -414,6 +1315,67 @@ fn make_chart<'a, T: Stats + Display>(
         )
 }
 
+/// A line dataset of one direction of the throughput
+fn io_dataset(data: &[(f64, f64)], color: Color) -> Dataset<'_> {
+    Dataset::default()
+        .marker(symbols::Marker::Dot)
+        .style(Style::default().fg(color))
+        .graph_type(GraphType::Line)
+        .data(data)
+}
+
+/// Draw the network & disk throughput charts, of the selected container, each with a dataset for both directions
+pub fn io_chart(f: &mut Frame, area: Rect, app_data: &Arc<Mutex<AppData>>) {
+    let (data, state_colors, theme, window, view_title) = {
+        let app_data = app_data.lock();
+        (
+            app_data.get_io_chart_data(),
+            app_data.args.state_colors,
+            app_data.args.theme,
+            app_data.get_chart_window(),
+            app_data.get_chart_title(),
+        )
+    };
+    if let Some((io, state)) = data {
+        let area = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(CONSTRAINT_50_50)
+            .split(area);
+        let net_dataset = vec![
+            io_dataset(&io.rx, theme.chart_rx),
+            io_dataset(&io.tx, theme.chart_tx),
+        ];
+        let disk_dataset = vec![
+            io_dataset(&io.read, theme.chart_read),
+            io_dataset(&io.write, theme.chart_write),
+        ];
+        let color = state_colors.get(state, false);
+        let cue = format!("{}{view_title}", state_colors.cue(state));
+        let net_current = format!("↓ {} ↑ {}", io.latest.rx, io.latest.tx);
+        let disk_current = format!("r {} w {}", io.latest.read, io.latest.write);
+        let net_chart = make_chart(
+            (state, window),
+            color,
+            "net/s",
+            &cue,
+            net_dataset,
+            &net_current,
+            &io.net_max,
+        );
+        let disk_chart = make_chart(
+            (state, window),
+            color,
+            "disk/s",
+            &cue,
+            disk_dataset,
+            &disk_current,
+            &io.disk_max,
+        );
+        f.render_widget(net_chart, area[0]);
+        f.render_widget(disk_chart, area[1]);
+    }
+}
+
 /// Draw heading bar at top of program, always visible
 /// TODO Should separate into loading icon/headers/help functions
 #[allow(clippy::too_many_lines)]
@@ -423,13 +1385,13 @@ pub fn heading_bar(
     data: &FrameData,
     gui_state: &Arc<Mutex<GuiState>>,
 ) {
-    let block = |fg: Color| Block::default().style(Style::default().bg(Color::Magenta).fg(fg));
+    let block = |fg: Color| Block::default().style(Style::default().bg(data.theme.accent).fg(fg));
 
-    frame.render_widget(block(Color::Black), area);
+    frame.render_widget(block(data.theme.header_fg), area);
 
-    // Generate a block for the header, if the header is currently being used to sort a column, then highlight it white
+    // Generate a block for the header, if the header is currently being used to sort a column, then highlight it
     let header_block = |x: &Header| {
-        let mut color = Color::Black;
+        let mut color = data.theme.header_fg;
         let mut prefix = "";
         let mut prefix_margin = 0;
         if let Some((a, b)) = &data.sorted_by {
@@ -439,11 +1401,11 @@ pub fn heading_bar(
                     SortedOrder::Desc => prefix = "▼ ",
                 }
                 prefix_margin = 2;
-                color = Color::White;
+                color = data.theme.header_highlight;
             };
         };
         (
-            Block::default().style(Style::default().bg(Color::Magenta).fg(color)),
+            Block::default().style(Style::default().bg(data.theme.accent).fg(color)),
             prefix,
             prefix_margin,
         )
@@ -471,6 +1433,18 @@ pub fn heading_bar(
                 x = format!("{ic}{header}", ic = block.1),
                 width = width
             ),
+            Header::Label(index) => format!(
+                "{}{x:>width$}",
+                MARGIN,
+                x = format!(
+                    "{ic}{}",
+                    data.label_columns
+                        .get(*index)
+                        .map_or("label", String::as_str),
+                    ic = block.1
+                ),
+                width = width
+            ),
             _ => format!(
                 "{}{x:>width$}",
                 MARGIN,
@@ -487,19 +1461,28 @@ pub fn heading_bar(
 
     // Meta data to iterate over to create blocks with correct widths
     let header_meta = [
-        (Header::Name, data.columns.name.1),
-        (Header::State, data.columns.state.1),
-        (Header::Status, data.columns.status.1),
-        (Header::Cpu, data.columns.cpu.1),
-        (Header::Memory, data.columns.mem.1 + data.columns.mem.2 + 3),
-        (Header::Id, data.columns.id.1),
-        (Header::Image, data.columns.image.1),
-        (Header::Rx, data.columns.net_rx.1),
-        (Header::Tx, data.columns.net_tx.1),
+        Some((Header::Name, data.columns.name.1)),
+        data.columns.host,
+        Some((Header::State, data.columns.state.1)),
+        Some((Header::Status, data.columns.status.1)),
+        data.columns.ready,
+        data.columns.exit_code,
+        data.columns.restarts,
+        Some((Header::Cpu, data.columns.cpu.1)),
+        data.columns.throttled,
+        Some((Header::Memory, data.columns.mem.1 + data.columns.mem.2 + 3)),
+        Some((Header::Id, data.columns.id.1)),
+        Some((Header::Image, data.columns.image.1)),
+        Some((Header::Rx, data.columns.net_rx.1)),
+        Some((Header::Tx, data.columns.net_tx.1)),
+        data.columns.net_io,
+        data.columns.disk_io,
     ];
 
     let header_data = header_meta
         .iter()
+        .flatten()
+        .chain(data.columns.labels.iter())
         .map(|i| {
             let header_block = gen_header(&i.0, i.1.into());
             (header_block.0, i.0, Constraint::Max(header_block.1))
@@ -508,7 +1491,35 @@ pub fn heading_bar(
 
     let suffix = if data.help_visible { "exit" } else { "show" };
     let info_text = format!("( h ) {suffix} help {MARGIN}",);
-    let info_width = info_text.chars().count();
+    let skew_text = data.clock_skew.map_or_else(String::new, |i| {
+        format!("⚠ daemon clock {} {MARGIN}", format_skew(i))
+    });
+    let latency_text = data.latency.map_or_else(String::new, |i| {
+        let stretch = data
+            .refresh_stretch
+            .map_or_else(String::new, |i| format!(", every {}s", i.as_secs()));
+        format!("{} {}ms{stretch} {MARGIN}", data.host, i.as_millis())
+    });
+    let read_only_text = if data.read_only {
+        format!("read-only {MARGIN}")
+    } else {
+        String::new()
+    };
+    let usage_text = data
+        .host_usage
+        .as_ref()
+        .map_or_else(String::new, |i| format!("{i} {MARGIN}"));
+    let latency_color = match data.latency {
+        Some(i) if i.as_millis() >= LATENCY_WARN || data.refresh_stretch.is_some() => {
+            data.theme.warning
+        }
+        _ => color_help(data.help_visible, &data.theme),
+    };
+    let info_width = info_text.chars().count()
+        + read_only_text.chars().count()
+        + skew_text.chars().count()
+        + latency_text.chars().count()
+        + usage_text.chars().count();
 
     let column_width = usize::from(area.width).saturating_sub(info_width);
     let column_width = if column_width > 0 { column_width } else { 1 };
@@ -529,7 +1540,7 @@ pub fn heading_bar(
     if data.has_containers {
         // Draw loading icon, or not, and a prefix with a single space
         let loading_paragraph = Paragraph::new(format!("{:>2}", data.loading_icon))
-            .block(block(Color::White))
+            .block(block(data.theme.header_highlight))
             .alignment(Alignment::Center);
         frame.render_widget(loading_paragraph, split_bar[0]);
 
@@ -550,20 +1561,69 @@ pub fn heading_bar(
     }
 
     // show/hide help
-    let color = if data.help_visible {
-        Color::Black
-    } else {
-        Color::White
-    };
-    let help_paragraph = Paragraph::new(info_text)
-        .block(block(color))
-        .alignment(Alignment::Right);
+    let color = color_help(data.help_visible, &data.theme);
+    let help_paragraph = Paragraph::new(Line::from(vec![
+        Span::styled(
+            read_only_text,
+            Style::default().fg(color).add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(usage_text, Style::default().fg(color)),
+        Span::styled(latency_text, Style::default().fg(latency_color)),
+        Span::styled(
+            skew_text,
+            Style::default()
+                .fg(data.theme.warning)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::from(info_text),
+    ]))
+    .block(block(color))
+    .alignment(Alignment::Right);
 
     // If no containers, don't display the headers, could maybe do this first?
     let help_index = if data.has_containers { 2 } else { 0 };
     frame.render_widget(help_paragraph, split_bar[help_index]);
 }
 
+/// Color of the help text in the heading bar, the header text color when the help panel is visible, else its highlight
+const fn color_help(help_visible: bool, theme: &Theme) -> Color {
+    if help_visible {
+        theme.header_fg
+    } else {
+        theme.header_highlight
+    }
+}
+
+/// Draw the optional status bar at the bottom of the program
+pub fn status_bar(area: Rect, frame: &mut Frame, text: &str, theme: &Theme) {
+    let paragraph = Paragraph::new(format!(" {text}"))
+        .block(Block::default().style(Style::default().bg(theme.accent).fg(theme.header_fg)))
+        .alignment(Alignment::Left);
+    frame.render_widget(paragraph, area);
+}
+
+/// How long stats, or logs, have been stale, appended to a title, e.g. ` - ⧗ stale 2m 5s`
+fn stale_text(stale: Option<u64>) -> String {
+    stale.map_or_else(String::new, |i| {
+        format!(" - {STALE} stale {}", format_duration(i))
+    })
+}
+
+/// Format a clock skew, in seconds, as a signed duration, e.g. `+45s`, `-3m 20s`, `+2h 5m`
+pub fn format_skew(skew: i64) -> String {
+    let sign = if skew < 0 { "-" } else { "+" };
+    format!("{sign}{}", format_duration(skew.unsigned_abs()))
+}
+
+/// Format a number of seconds as the two largest units, e.g. `2h 13m`, `4m 10s`, or `9s`
+pub fn format_duration(secs: u64) -> String {
+    match secs {
+        x if x >= 3600 => format!("{}h {}m", x / 3600, x % 3600 / 60),
+        x if x >= 60 => format!("{}m {}s", x / 60, x % 60),
+        x => format!("{x}s"),
+    }
+}
+
 /// Help popup box needs these three pieces of information
 struct HelpInfo {
     lines: Vec<Line<'static>>,
@@ -592,21 +1652,21 @@ impl HelpInfo {
         Span::styled(input.to_owned(), Style::default().fg(color))
     }
 
-    /// &str to black text span
-    fn black_span<'a>(input: &str) -> Span<'a> {
-        Self::span(input, Color::Black)
+    /// &str to a span in the text color of the accent
+    fn text_span<'a>(input: &str, theme: &Theme) -> Span<'a> {
+        Self::span(input, theme.header_fg)
     }
 
-    /// &str to white text span
-    fn white_span<'a>(input: &str) -> Span<'a> {
-        Self::span(input, Color::White)
+    /// &str to a span in the highlight color of the accent
+    fn highlight_span<'a>(input: &str, theme: &Theme) -> Span<'a> {
+        Self::span(input, theme.header_highlight)
     }
 
     /// Generate the `oxker` name span + metadata
-    fn gen_name() -> Self {
+    fn gen_name(theme: &Theme) -> Self {
         let mut lines = NAME_TEXT
             .lines()
-            .map(|i| Line::from(Self::white_span(i)))
+            .map(|i| Line::from(Self::highlight_span(i, theme)))
             .collect::<Vec<_>>();
         lines.insert(0, Self::empty_span());
         let width = Self::calc_width(&lines);
@@ -619,10 +1679,10 @@ impl HelpInfo {
     }
 
     /// Generate the description span + metadata
-    fn gen_description() -> Self {
+    fn gen_description(theme: &Theme) -> Self {
         let lines = [
             Self::empty_span(),
-            Line::from(Self::white_span(DESCRIPTION)),
+            Line::from(Self::highlight_span(DESCRIPTION, theme)),
             Self::empty_span(),
         ];
         let width = Self::calc_width(&lines);
@@ -635,9 +1695,10 @@ impl HelpInfo {
     }
 
     /// Generate the button information span + metadata
-    fn gen_button() -> Self {
-        let button_item = |x: &str| Self::white_span(&format!(" ( {x} ) "));
-        let button_desc = |x: &str| Self::black_span(x);
+    #[allow(clippy::too_many_lines)]
+    fn gen_button(theme: &Theme) -> Self {
+        let button_item = |x: &str| Self::highlight_span(&format!(" ( {x} ) "), theme);
+        let button_desc = |x: &str| Self::text_span(x, theme);
         let or = || button_desc("or");
         let space = || button_desc(" ");
 
@@ -663,13 +1724,147 @@ impl HelpInfo {
             Line::from(vec![
                 space(),
                 button_item("enter"),
-                button_desc("send docker container command"),
+                button_desc("send docker container command, or expand a truncated log line"),
             ]),
             Line::from(vec![
                 space(),
                 button_item("e"),
                 button_desc("exec into a container"),
             ]),
+            Line::from(vec![
+                space(),
+                button_item("E"),
+                button_desc("attach to the main process of a container, as docker attach"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("o"),
+                button_desc(
+                    "run a command in a container, as a user, with env, or without a shell",
+                ),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("r"),
+                button_desc(
+                    "recreate a container, with changes to its image, env, ports, & mounts",
+                ),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("i"),
+                button_desc(
+                    "switch the image tag of a container, from the local tags, & recreate it",
+                ),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("l"),
+                button_desc("show the lifecycle timeline of a container, over the last day"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("?"),
+                button_desc("inspect a container, as a tree that can be expanded & collapsed"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("!"),
+                button_desc(
+                    "toggle the events pane, container exits, health changes, & image pulls",
+                ),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item(">"),
+                button_desc("send lines to the stdin of a container started with -i"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("^"),
+                button_desc(
+                    "pull the latest image of a container, & recreate it if it has changed",
+                ),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("+"),
+                button_desc(
+                    "run a new container, with a name, env, ports, mounts, & restart policy",
+                ),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("-"),
+                button_desc("capture the packets of a container, with tcpdump, & save the pcap"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("*"),
+                button_desc("memory breakdown, anonymous vs page cache, of the selected container"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("}"),
+                button_desc("cpu usage of each core, of the selected container"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("`"),
+                button_desc("resize, or hide, the panes, & save the layout"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("space"),
+                button_desc("mark the selected container, to send a control to every marked one"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("backspace"),
+                button_desc("jump back to the previously viewed container"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("\\"),
+                button_desc("show the recently viewed containers"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("%"),
+                button_desc(
+                    "cleanup, disk usage, & prune containers, images, volumes, or networks",
+                ),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("p"),
+                button_desc("prune images, by age, keeping the newest tags of each repository"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("b"),
+                button_desc("view the build cache, & remove selected records"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("u"),
+                button_desc("view the network traffic of each container, since oxker started"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("c"),
+                button_desc("check if a container can reach another container's port"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("d"),
+                button_desc("view the docker daemon's details, & any warnings it reports"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("z"),
+                button_desc("standby, pause drawing & slow updates, until a key is pressed"),
+            ]),
             Line::from(vec![
                 space(),
                 button_item("h"),
@@ -678,7 +1873,165 @@ impl HelpInfo {
             Line::from(vec![
                 space(),
                 button_item("s"),
-                button_desc("save logs to file"),
+                button_desc("save logs to file, as text, json lines, or csv"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("<"),
+                button_desc(
+                    "load older log lines, from before the --tail, or dropped by --max-log-lines",
+                ),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("{"),
+                button_desc("cycle json log lines, as logged, pretty-printed, or as key=value"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("T"),
+                button_desc("cycle log timestamps, off, in utc, or in local time"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("="),
+                button_desc("lock the logs panel to a container, while selecting others"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("~"),
+                button_desc("show network & disk throughput, as columns, then as charts"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item(";"),
+                button_desc("scan container environments for plaintext credentials"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item(":"),
+                button_desc("update the cpu & memory limits of the selected container"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("'"),
+                button_desc("show the health check of the selected container, & its recent probes"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("."),
+                button_desc("refresh the selected container every 250ms for 2 minutes, or cancel"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item(","),
+                button_desc("show the ports of the selected container, to open, or forward"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("_"),
+                button_desc("browse the files of the selected container, & copy them to file"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("a"),
+                button_desc("view log lines that matched a --watch pattern"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("w"),
+                button_desc(
+                    "export a container, as a docker run command & compose service, to file",
+                ),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("f"),
+                button_desc("save the logs of a container's compose project, & a merged timeline"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("#"),
+                button_desc(
+                    "save a snapshot of the host, containers, inspects, events, stats, & logs",
+                ),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("[ ]"),
+                button_desc("zoom the cpu & memory charts out, or in, to the most recent stats"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("|"),
+                button_desc("freeze the charts, or unfreeze them, stats are still collected"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("$"),
+                button_desc("save the cpu & memory history of a container, as csv, to file"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("y"),
+                button_desc(
+                    "cycle the images, volumes, networks, & services panels, to manage them",
+                ),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("x"),
+                button_desc("save a snapshot of the screen, as text & html, to file"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("n"),
+                button_desc("edit the note of a container, shown under its logs"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("/"),
+                button_desc(
+                    "search containers by name, image, or note, or the logs panel - esc to clear",
+                ),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("\""),
+                button_desc("fuzzy find a container by name, image, or project, & select it"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("("),
+                button_desc("mark a container, then compare its settings with another container"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item(")"),
+                button_desc("show, or hide, containers by name, label, or status"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("n"),
+                or(),
+                button_item("N"),
+                button_desc("jump between logs search matches -"),
+                button_item("tab"),
+                button_desc("toggles regex"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("&"),
+                button_desc(
+                    "filter the logs panel, hiding lines, e.g. error !healthcheck - esc to clear",
+                ),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("@"),
+                button_desc(
+                    "schedule a restart, start, stop, or save logs, e.g. restart daily at 03:00",
+                ),
             ]),
             Line::from(vec![
                 space(),
@@ -693,6 +2046,30 @@ impl HelpInfo {
                 button_item("1 - 9"),
                 button_desc("sort by header - or click header"),
             ]),
+            Line::from(vec![
+                space(),
+                button_item("t"),
+                button_desc("cycle top mode, continuously sort by cpu or memory"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("g"),
+                button_desc(
+                    "cycle grouping, list containers under their image, pod, project, or label",
+                ),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("← →"),
+                or(),
+                button_item("enter"),
+                button_desc("collapse or expand selected group"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("v"),
+                button_desc("switch host tab, when monitoring multiple hosts"),
+            ]),
             Line::from(vec![
                 space(),
                 button_item("q"),
@@ -710,16 +2087,17 @@ impl HelpInfo {
     }
 
     /// Generate the final lines, GitHub link etc, + metadata
-    fn gen_final() -> Self {
+    fn gen_final(theme: &Theme) -> Self {
         let lines = [
             Self::empty_span(),
-            Line::from(vec![Self::black_span(
+            Line::from(vec![Self::text_span(
                 "currently an early work in progress, all and any input appreciated",
+                theme,
             )]),
             Line::from(vec![Span::styled(
                 REPO.to_owned(),
                 Style::default()
-                    .fg(Color::White)
+                    .fg(theme.header_highlight)
                     .add_modifier(Modifier::UNDERLINED),
             )]),
         ];
@@ -734,13 +2112,13 @@ impl HelpInfo {
 }
 
 /// Draw the help box in the centre of the screen
-pub fn help_box(f: &mut Frame) {
+pub fn help_box(f: &mut Frame, theme: &Theme) {
     let title = format!(" {VERSION} ");
 
-    let name_info = HelpInfo::gen_name();
-    let description_info = HelpInfo::gen_description();
-    let button_info = HelpInfo::gen_button();
-    let final_info = HelpInfo::gen_final();
+    let name_info = HelpInfo::gen_name(theme);
+    let description_info = HelpInfo::gen_description(theme);
+    let button_info = HelpInfo::gen_button(theme);
+    let final_info = HelpInfo::gen_final(theme);
 
     // have to add 10, but shouldn't need to, is an error somewhere
     let max_line_width = [
@@ -774,22 +2152,22 @@ pub fn help_box(f: &mut Frame) {
         .split(area);
 
     let name_paragraph = Paragraph::new(name_info.lines)
-        .style(Style::default().bg(Color::Magenta).fg(Color::White))
+        .style(Style::default().bg(theme.accent).fg(theme.header_highlight))
         .block(Block::default())
         .alignment(Alignment::Center);
 
     let description_paragraph = Paragraph::new(description_info.lines)
-        .style(Style::default().bg(Color::Magenta).fg(Color::Black))
+        .style(Style::default().bg(theme.accent).fg(theme.popup_fg))
         .block(Block::default())
         .alignment(Alignment::Center);
 
     let help_paragraph = Paragraph::new(button_info.lines)
-        .style(Style::default().bg(Color::Magenta).fg(Color::Black))
+        .style(Style::default().bg(theme.accent).fg(theme.popup_fg))
         .block(Block::default())
         .alignment(Alignment::Left);
 
     let final_paragraph = Paragraph::new(final_info.lines)
-        .style(Style::default().bg(Color::Magenta).fg(Color::Black))
+        .style(Style::default().bg(theme.accent).fg(theme.popup_fg))
         .block(Block::default())
         .alignment(Alignment::Center);
 
@@ -797,7 +2175,7 @@ pub fn help_box(f: &mut Frame) {
         .title(title)
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
-        .border_style(Style::default().fg(Color::Black).bg(Color::Magenta));
+        .border_style(Style::default().fg(theme.popup_fg).bg(theme.accent));
 
     // Order is important here
     f.render_widget(Clear, area);
@@ -808,33 +2186,122 @@ pub fn help_box(f: &mut Frame) {
     f.render_widget(block, area);
 }
 
-/// Draw the delete confirm box in the centre of the screen
-/// take in container id and container name here?
-pub fn delete_confirm(f: &mut Frame, gui_state: &Arc<Mutex<GuiState>>, name: &ContainerName) {
+/// The question of the confirm box, naming the container, or each marked container
+fn confirm_names<'a>(control: &str, names: &'a [ContainerName], theme: &Theme) -> Vec<Line<'a>> {
+    let name_style = Style::default()
+        .fg(theme.error)
+        .bg(theme.popup_bg)
+        .add_modifier(Modifier::BOLD);
+    if let [name] = names {
+        vec![Line::from(vec![
+            Span::from(format!("Are you sure you want to {control} container: ")),
+            Span::styled(name.get(), name_style),
+        ])]
+    } else {
+        // The marked containers, one per line, those that don't fit are counted
+        let mut lines = vec![Line::from(format!(
+            "Are you sure you want to {control} {} containers:",
+            names.len()
+        ))];
+        lines.extend(
+            names
+                .iter()
+                .take(BATCH_NAMES)
+                .map(|i| Line::from(Span::styled(i.get(), name_style))),
+        );
+        if names.len() > BATCH_NAMES {
+            lines.push(Line::from(format!(
+                "and {} more",
+                names.len() - BATCH_NAMES
+            )));
+        }
+        lines
+    }
+}
+
+/// Draw the confirm box, of a docker control, in the centre of the screen
+#[allow(clippy::too_many_lines)]
+pub fn confirm(
+    f: &mut Frame,
+    gui_state: &Arc<Mutex<GuiState>>,
+    control: DockerControls,
+    names: &[ContainerName],
+    concurrent: &[String],
+    theme: &Theme,
+) {
+    let update = control == DockerControls::Update;
+    let signal = match control {
+        DockerControls::Kill(signal) => Some(signal),
+        _ => None,
+    };
+    let control = control.to_string();
+    let title = control
+        .chars()
+        .take(1)
+        .flat_map(char::to_uppercase)
+        .chain(control.chars().skip(1))
+        .collect::<String>();
     let block = Block::default()
-        .title(" Confirm Delete ")
+        .title(format!(" Confirm {title} "))
         .border_type(BorderType::Rounded)
-        .style(Style::default().bg(Color::White).fg(Color::Black))
+        .style(theme.popup())
         .title_alignment(Alignment::Center)
         .borders(Borders::ALL);
 
-    let confirm = Line::from(vec![
-        Span::from("Are you sure you want to delete container: "),
-        Span::styled(
-            name.get(),
+    let mut confirm = confirm_names(&control, names, theme);
+
+    if update {
+        confirm.push(Line::from(
+            "the latest image is pulled, the container is recreated if it has changed",
+        ));
+    }
+
+    if let Some(signal) = signal {
+        confirm.push(Line::from(format!(
+            "sends {signal}, to {}",
+            signal.description()
+        )));
+    }
+
+    // Another user, or the docker cli, may be working on the same container
+    for line in concurrent {
+        confirm.push(Line::from(Span::styled(
+            line.as_str(),
             Style::default()
-                .fg(Color::Red)
-                .bg(Color::White)
+                .fg(theme.error)
                 .add_modifier(Modifier::BOLD),
-        ),
-    ]);
+        )));
+    }
+
+    // When running with --dry-run, list what will be removed, and what will be kept
+    let preview = gui_state.lock().get_delete_preview();
+    if let Some(preview) = preview {
+        let size = preview
+            .size
+            .map_or_else(|| "unknown".to_owned(), |i| i.to_string().trim().to_owned());
+        confirm.push(Line::from(format!("writable layer to reclaim: {size}")));
+        confirm.push(Line::from(format!(
+            "volumes kept: {}, image kept",
+            preview.volumes
+        )));
+        confirm.push(Line::from(Span::styled(
+            if preview.confirmed {
+                "confirm again to delete"
+            } else {
+                "dry run - nothing deleted yet, a second confirmation is required"
+            },
+            Style::default().add_modifier(Modifier::BOLD),
+        )));
+    }
 
     let yes_text = " (Y)es ";
     let no_text = " (N)o ";
 
     // Find the maximum line width & height, and add some padding
-    let max_line_width = u16::try_from(confirm.width()).unwrap_or(64) + 12;
-    let lines = 8;
+    let max_line_width =
+        u16::try_from(confirm.iter().map(Line::width).max().unwrap_or(64)).unwrap_or(64) + 12;
+    let text_lines = u16::try_from(confirm.len()).unwrap_or(1);
+    let lines = 7 + confirm.len();
 
     let confirm_para = Paragraph::new(confirm).alignment(Alignment::Center);
 
@@ -842,7 +2309,7 @@ pub fn delete_confirm(f: &mut Frame, gui_state: &Arc<Mutex<GuiState>>, name: &Co
         Block::default()
             .border_type(BorderType::Rounded)
             .borders(Borders::ALL)
-            .style(Style::default().bg(Color::White))
+            .style(Style::default().bg(theme.popup_bg))
     };
 
     let yes_para = Paragraph::new(yes_text)
@@ -860,9 +2327,11 @@ pub fn delete_confirm(f: &mut Frame, gui_state: &Arc<Mutex<GuiState>>, name: &Co
         BoxLocation::MiddleCentre,
     );
 
+    let mut constraints = CONSTRAINT_POPUP;
+    constraints[1] = Constraint::Max(text_lines);
     let split_popup = Layout::default()
         .direction(Direction::Vertical)
-        .constraints(CONSTRAINT_POPUP)
+        .constraints(constraints)
         .split(area);
 
     let split_buttons = Layout::default()
@@ -888,378 +2357,6704 @@ pub fn delete_confirm(f: &mut Frame, gui_state: &Arc<Mutex<GuiState>>, name: &Co
         .update_region_map(Region::Delete(DeleteButton::Yes), yes_area);
 }
 
-/// Draw an error popup over whole screen
-pub fn error(f: &mut Frame, error: AppError, seconds: Option<u8>) {
-    let block = Block::default()
-        .title(" Error ")
-        .border_type(BorderType::Rounded)
-        .title_alignment(Alignment::Center)
-        .borders(Borders::ALL);
-
-    let to_push = match error {
-        AppError::DockerConnect => {
-            format!(
-                "\n\n {}::v{} closing in {:02} seconds",
-                NAME,
-                VERSION,
-                seconds.unwrap_or(5)
-            )
+/// Draw the full text of a truncated log line, hard wrapped, or a hexdump of binary output, over most of the screen
+/// Only the visible rows are built, so that a multi-megabyte line can't slow down drawing
+pub fn expanded_log(f: &mut Frame, gui_state: &Arc<Mutex<GuiState>>, theme: &Theme) {
+    let mut gui_state = gui_state.lock();
+    let Some(log) = gui_state.get_expanded_log() else {
+        return;
+    };
+    let size = f.size();
+    let area = popup(
+        usize::from(size.height.saturating_sub(4)),
+        usize::from(size.width.saturating_sub(8)),
+        size,
+        BoxLocation::MiddleCentre,
+    );
+    let width = usize::from(area.width.saturating_sub(2)).max(1);
+    let height = usize::from(area.height.saturating_sub(2)).max(1);
+
+    let (lines, total, description) = match &log.content {
+        ExpandedContent::Rows(rows) => {
+            log.scroll = log.scroll.min(rows.len().saturating_sub(height));
+            let lines = rows
+                .iter()
+                .skip(log.scroll)
+                .take(height)
+                .map(|i| Line::from(i.clone()))
+                .collect::<Vec<_>>();
+            (lines, rows.len().max(1), "hex".to_owned())
+        }
+        ExpandedContent::Text { chars, text } => {
+            let total = chars.div_ceil(width).max(1);
+            log.scroll = log.scroll.min(total.saturating_sub(height));
+            let start = text
+                .char_indices()
+                .nth(log.scroll * width)
+                .map_or(text.len(), |(i, _)| i);
+            let chars_visible = text[start..]
+                .chars()
+                .take(width * height)
+                .collect::<Vec<_>>();
+            let lines = chars_visible
+                .chunks(width)
+                .map(|i| Line::from(i.iter().collect::<String>()))
+                .collect::<Vec<_>>();
+            (lines, total, format!("{chars} chars"))
         }
-        _ => String::from("\n\n ( c ) clear error\n ( q ) quit oxker "),
     };
 
-    let mut text = format!("\n{error}");
-
-    text.push_str(to_push.as_str());
-
-    // Find the maximum line width & height
-    let mut max_line_width = max_line_width(&text);
-    let mut lines = text.lines().count();
-
-    // Add some horizontal & vertical margins
-    max_line_width += 8;
-    lines += 3;
+    let title = format!(" {description}, row {}/{total} ", log.scroll + 1);
+    drop(gui_state);
 
-    let paragraph = Paragraph::new(text)
-        .style(Style::default().bg(Color::Red).fg(Color::White))
-        .block(block)
-        .alignment(Alignment::Center);
+    let block = Block::default()
+        .title(title)
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(theme.popup_fg).bg(theme.accent));
 
-    let area = popup(lines, max_line_width, f.size(), BoxLocation::MiddleCentre);
+    let paragraph = Paragraph::new(lines)
+        .style(Style::default().bg(theme.accent).fg(theme.popup_fg))
+        .block(block);
 
-    // let (paragraph, area) = gen_error(f, error, seconds);
     f.render_widget(Clear, area);
     f.render_widget(paragraph, area);
 }
 
-/// Draw info box in one of the 9 BoxLocations
-// TODO is this broken?
-pub fn info(f: &mut Frame, text: &str, instant: Instant, gui_state: &Arc<Mutex<GuiState>>) {
+/// Draw the recreate with changes form, one field per row, scrolled so that the selected field is always visible
+pub fn recreate_form(f: &mut Frame, gui_state: &Arc<Mutex<GuiState>>, theme: &Theme) {
+    let gui_state = gui_state.lock();
+    let Some(form) = gui_state.get_recreate_form_ref() else {
+        return;
+    };
+    // Only shown when the image env is known, to explain the env origin markers
+    let legend = usize::from(form.image_env.is_some());
+    let size = f.size();
+    let area = popup(
+        (form.fields.len() + 4 + legend).min(usize::from(size.height.saturating_sub(4))),
+        usize::from(size.width.saturating_sub(8)),
+        size,
+        BoxLocation::MiddleCentre,
+    );
+    // Borders, and the blank, legend & key hint lines
+    let rows = usize::from(area.height.saturating_sub(4 + u16::from(legend > 0))).max(1);
+    let start = form.selected.saturating_sub(rows - 1);
+
+    let mut lines = form
+        .fields
+        .iter()
+        .enumerate()
+        .skip(start)
+        .take(rows)
+        .map(|(index, field)| {
+            let origin = if field.kind == FieldKind::Env {
+                form.env_origin(&field.value)
+            } else {
+                None
+            };
+            let marker = origin.map_or_else(
+                || Span::from(" "),
+                |origin| {
+                    let style = match origin {
+                        EnvOrigin::Image => Style::default().add_modifier(Modifier::DIM),
+                        EnvOrigin::Changed => Style::default().fg(theme.accent),
+                        EnvOrigin::Runtime => Style::default().fg(theme.highlight),
+                    };
+                    Span::styled(origin.symbol(), style)
+                },
+            );
+            if index == form.selected {
+                let bold = Style::default().add_modifier(Modifier::BOLD);
+                Line::from(vec![
+                    Span::styled(format!("{RIGHT_ARROW}{:>5} ", field.kind), bold),
+                    marker,
+                    Span::styled(format!(" {}█", field.value), bold),
+                ])
+            } else {
+                Line::from(vec![
+                    Span::from(format!("  {:>5} ", field.kind)),
+                    marker,
+                    Span::from(format!(" {}", field.value)),
+                ])
+            }
+        })
+        .collect::<Vec<_>>();
+    lines.push(Line::from(""));
+    if legend > 0 {
+        lines.push(Line::from(Span::styled(
+            "= image default ~ overrides the image + set at run time",
+            Style::default().add_modifier(Modifier::DIM),
+        )));
+    }
+    lines.push(Line::from(Span::styled(
+        "( ↑ ↓ ) select field, empty fields are removed ( enter ) recreate ( esc ) cancel",
+        Style::default().add_modifier(Modifier::DIM),
+    )));
+
+    let title = format!(
+        " recreate {}, field {}/{} ",
+        form.name,
+        form.selected + 1,
+        form.fields.len()
+    );
+    drop(gui_state);
+
     let block = Block::default()
-        .title("")
+        .title(title)
         .title_alignment(Alignment::Center)
-        .borders(Borders::NONE);
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .style(theme.popup());
 
-    let mut max_line_width = max_line_width(text);
-    let mut lines = text.lines().count();
+    f.render_widget(Clear, area);
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
 
-    // Add some horizontal & vertical margins
-    max_line_width += 8;
-    lines += 2;
+/// Draw the run form, one field per row, scrolled so that the selected field is always visible
+pub fn run_form(f: &mut Frame, gui_state: &Arc<Mutex<GuiState>>, theme: &Theme) {
+    let gui_state = gui_state.lock();
+    let Some(form) = gui_state.get_run_form_ref() else {
+        return;
+    };
+    let size = f.size();
+    let area = popup(
+        (form.fields.len() + 4).min(usize::from(size.height.saturating_sub(4))),
+        usize::from(size.width.saturating_sub(8)),
+        size,
+        BoxLocation::MiddleCentre,
+    );
+    // Borders, and the blank & key hint lines
+    let rows = usize::from(area.height.saturating_sub(4)).max(1);
+    let start = form.selected.saturating_sub(rows - 1);
 
-    let paragraph = Paragraph::new(text)
-        .style(Style::default().bg(Color::Blue).fg(Color::White))
-        .block(block)
-        .alignment(Alignment::Center);
+    let mut lines = form
+        .fields
+        .iter()
+        .enumerate()
+        .skip(start)
+        .take(rows)
+        .map(|(index, field)| {
+            if index == form.selected {
+                Line::from(Span::styled(
+                    format!("{RIGHT_ARROW}{:>7}  {}█", field.kind, field.value),
+                    Style::default().add_modifier(Modifier::BOLD),
+                ))
+            } else {
+                Line::from(format!("  {:>7}  {}", field.kind, field.value))
+            }
+        })
+        .collect::<Vec<_>>();
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        "( ↑ ↓ ) select field ( ← → ) choose a local image, or restart policy ( enter ) run ( esc ) cancel",
+        Style::default().add_modifier(Modifier::DIM),
+    )));
+
+    let host = form
+        .host
+        .as_ref()
+        .map_or_else(String::new, |i| format!(" on {i}"));
+    let title = format!(
+        " run a new container{host}, field {}/{} ",
+        form.selected + 1,
+        form.fields.len()
+    );
+    drop(gui_state);
+
+    let block = Block::default()
+        .title(title)
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .style(theme.popup());
 
-    let area = popup(lines, max_line_width, f.size(), BoxLocation::BottomRight);
     f.render_widget(Clear, area);
-    f.render_widget(paragraph, area);
-    if instant.elapsed().as_millis() > 4000 {
-        gui_state.lock().reset_info_box();
-    }
+    f.render_widget(Paragraph::new(lines).block(block), area);
 }
 
-/// draw a box in the one of the BoxLocations, based on max line width + number of lines
-fn popup(text_lines: usize, text_width: usize, r: Rect, box_location: BoxLocation) -> Rect {
-    // Make sure blank_space can't be an negative, as will crash
-    let calc = |x: u16, y: usize| usize::from(x).saturating_sub(y).saturating_div(2);
-
-    let blank_vertical = calc(r.height, text_lines);
-    let blank_horizontal = calc(r.width, text_width);
-
-    let (h_constraints, v_constraints) = box_location.get_constraints(
-        blank_horizontal.try_into().unwrap_or_default(),
-        blank_vertical.try_into().unwrap_or_default(),
-        text_lines.try_into().unwrap_or_default(),
-        text_width.try_into().unwrap_or_default(),
+/// Draw the tag picker, one tag per row, scrolled so that the selected tag is always visible
+pub fn tag_picker(f: &mut Frame, gui_state: &Arc<Mutex<GuiState>>, theme: &Theme) {
+    let gui_state = gui_state.lock();
+    let Some(picker) = gui_state.get_tag_picker_ref() else {
+        return;
+    };
+    let hint = "( ↑ ↓ ) select tag ( enter ) recreate on the selected tag ( esc ) cancel";
+    let size = f.size();
+    let width = picker
+        .tags
+        .iter()
+        .map(|i| i.chars().count() + 14)
+        .chain(std::iter::once(hint.chars().count() + 4))
+        .max()
+        .unwrap_or_default()
+        .min(usize::from(size.width.saturating_sub(8)));
+    let area = popup(
+        (picker.tags.len() + 4).min(usize::from(size.height.saturating_sub(4))),
+        width,
+        size,
+        BoxLocation::MiddleCentre,
     );
+    // Borders, and the blank & key hint lines
+    let rows = usize::from(area.height.saturating_sub(4)).max(1);
+    let start = picker.selected.saturating_sub(rows - 1);
 
-    let indexes = box_location.get_indexes();
+    let mut lines = picker
+        .tags
+        .iter()
+        .enumerate()
+        .skip(start)
+        .take(rows)
+        .map(|(index, tag)| {
+            let current = if tag == &picker.current {
+                " (current)"
+            } else {
+                ""
+            };
+            if index == picker.selected {
+                Line::from(Span::styled(
+                    format!("{RIGHT_ARROW}{tag}{current}"),
+                    Style::default().add_modifier(Modifier::BOLD),
+                ))
+            } else {
+                Line::from(format!("  {tag}{current}"))
+            }
+        })
+        .collect::<Vec<_>>();
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        hint,
+        Style::default().add_modifier(Modifier::DIM),
+    )));
+    let title = format!(" switch tag of {} ", picker.name);
+    drop(gui_state);
 
-    let popup_layout = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints(v_constraints)
-        .split(r);
+    let block = Block::default()
+        .title(title)
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .style(theme.popup());
 
-    Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints(h_constraints)
-        .split(popup_layout[indexes.0])[indexes.1]
+    f.render_widget(Clear, area);
+    f.render_widget(Paragraph::new(lines).block(block), area);
 }
 
-#[cfg(test)]
-#[allow(clippy::unwrap_used, clippy::many_single_char_names)]
-mod tests {
+/// Draw the connectivity check target picker, one container & port per row, scrolled so that the selected target is always visible
+pub fn reach_picker(f: &mut Frame, gui_state: &Arc<Mutex<GuiState>>, theme: &Theme) {
+    let gui_state = gui_state.lock();
+    let Some(picker) = gui_state.get_reach_picker_ref() else {
+        return;
+    };
+    let hint = "( ↑ ↓ ) select target ( enter ) check ( esc ) cancel";
+    let name_width = picker
+        .targets
+        .iter()
+        .map(|i| i.name.chars().count())
+        .max()
+        .unwrap_or_default();
+    let rows = picker
+        .targets
+        .iter()
+        .map(|i| {
+            let check = if i.port.is_some() { "tcp" } else { "ping" };
+            let shared = if i.shared { "" } else { " no shared network" };
+            (
+                format!(
+                    "{:<name_width$}  {:<21} {check:<4}{shared}",
+                    i.name,
+                    i.address()
+                ),
+                i.shared,
+            )
+        })
+        .collect::<Vec<_>>();
+    let size = f.size();
+    let width = rows
+        .iter()
+        .map(|i| i.0.chars().count() + 6)
+        .chain(std::iter::once(hint.chars().count() + 4))
+        .max()
+        .unwrap_or_default()
+        .min(usize::from(size.width.saturating_sub(8)));
+    let area = popup(
+        (rows.len() + 4).min(usize::from(size.height.saturating_sub(4))),
+        width,
+        size,
+        BoxLocation::MiddleCentre,
+    );
+    // Borders, and the blank & key hint lines
+    let height = usize::from(area.height.saturating_sub(4)).max(1);
+    let start = picker.selected.saturating_sub(height - 1);
+
+    let mut lines = rows
+        .into_iter()
+        .enumerate()
+        .skip(start)
+        .take(height)
+        .map(|(index, (text, shared))| {
+            let style = if shared {
+                Style::default()
+            } else {
+                Style::default().add_modifier(Modifier::DIM)
+            };
+            if index == picker.selected {
+                Line::from(Span::styled(
+                    format!("{RIGHT_ARROW}{text}"),
+                    style.add_modifier(Modifier::BOLD),
+                ))
+            } else {
+                Line::from(Span::styled(format!("  {text}"), style))
+            }
+        })
+        .collect::<Vec<_>>();
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        hint,
+        Style::default().add_modifier(Modifier::DIM),
+    )));
+    let title = format!(" can {} reach ", picker.name);
+    drop(gui_state);
 
-    use std::{ops::RangeInclusive, sync::Arc};
+    let block = Block::default()
+        .title(title)
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .style(theme.popup());
 
-    use parking_lot::Mutex;
-    use ratatui::{
-        backend::TestBackend,
-        layout::Rect,
-        style::{Color, Modifier},
-        Terminal,
-    };
-    use uuid::Uuid;
+    f.render_widget(Clear, area);
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
 
-    use crate::{
-        app_data::{
-            AppData, ContainerId, ContainerImage, ContainerName, ContainerPorts, Header,
-            SortedOrder, State, StatefulList,
-        },
-        app_error::AppError,
-        tests::{gen_appdata, gen_container_summary, gen_containers},
-        ui::{draw_frame, GuiState},
+/// Draw the host switcher, one host tab per row, with how many of its containers are running, the current tab is marked
+pub fn host_picker(f: &mut Frame, gui_state: &Arc<Mutex<GuiState>>, theme: &Theme) {
+    let gui_state = gui_state.lock();
+    let Some(picker) = gui_state.get_host_picker_ref() else {
+        return;
     };
+    let hint = "( ↑ ↓ ) select host ( enter ) switch ( esc ) cancel";
+    let name_width = picker
+        .rows
+        .iter()
+        .map(|i| i.name().chars().count())
+        .max()
+        .unwrap_or_default();
+    let rows = picker
+        .rows
+        .iter()
+        .enumerate()
+        .map(|(index, i)| {
+            let current = if index == picker.current {
+                " current"
+            } else {
+                ""
+            };
+            format!(
+                "{:<name_width$}  {}/{} running{current}",
+                i.name(),
+                i.running,
+                i.total
+            )
+        })
+        .collect::<Vec<_>>();
+    let size = f.size();
+    let width = rows
+        .iter()
+        .map(|i| i.chars().count() + 6)
+        .chain(std::iter::once(hint.chars().count() + 4))
+        .max()
+        .unwrap_or_default()
+        .min(usize::from(size.width.saturating_sub(8)));
+    let area = popup(
+        (rows.len() + 4).min(usize::from(size.height.saturating_sub(4))),
+        width,
+        size,
+        BoxLocation::MiddleCentre,
+    );
+    // Borders, and the blank & key hint lines
+    let height = usize::from(area.height.saturating_sub(4)).max(1);
+    let start = picker.selected.saturating_sub(height - 1);
+
+    let mut lines = rows
+        .into_iter()
+        .enumerate()
+        .skip(start)
+        .take(height)
+        .map(|(index, text)| {
+            if index == picker.selected {
+                Line::from(Span::styled(
+                    format!("{RIGHT_ARROW}{text}"),
+                    Style::default().add_modifier(Modifier::BOLD),
+                ))
+            } else {
+                Line::from(format!("  {text}"))
+            }
+        })
+        .collect::<Vec<_>>();
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        hint,
+        Style::default().add_modifier(Modifier::DIM),
+    )));
+    drop(gui_state);
 
-    use super::{FrameData, ORANGE, VERSION};
-
-    struct TuiTestSetup {
-        app_data: Arc<Mutex<AppData>>,
-        gui_state: Arc<Mutex<GuiState>>,
-        fd: FrameData,
-        area: Rect,
-        terminal: Terminal<TestBackend>,
-        ids: Vec<ContainerId>,
-    }
+    let block = Block::default()
+        .title(" hosts ")
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .style(theme.popup());
 
-    const BORDER_CHARS: [&str; 6] = ["╭", "╮", "─", "│", "╰", "╯"];
+    f.render_widget(Clear, area);
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
 
-    /// Generate state to be used in *most* gui tests
-    fn test_setup(w: u16, h: u16, control_start: bool, container_start: bool) -> TuiTestSetup {
-        let backend = TestBackend::new(w, h);
-        let terminal = Terminal::new(backend).unwrap();
+/// Draw the recently viewed containers, most recent first, with their state
+pub fn recent_picker(f: &mut Frame, gui_state: &Arc<Mutex<GuiState>>, theme: &Theme) {
+    let gui_state = gui_state.lock();
+    let Some(picker) = gui_state.get_recent_picker_ref() else {
+        return;
+    };
+    let hint = "( ↑ ↓ \\ ) select container ( enter ) jump ( esc ) cancel";
+    let name_width = picker
+        .rows
+        .iter()
+        .map(|i| i.name.get().chars().count())
+        .max()
+        .unwrap_or_default();
+    let rows = picker
+        .rows
+        .iter()
+        .map(|i| format!("{:<name_width$}  {}", i.name.get(), i.state))
+        .collect::<Vec<_>>();
+    let size = f.size();
+    let width = rows
+        .iter()
+        .map(|i| i.chars().count() + 6)
+        .chain(std::iter::once(hint.chars().count() + 4))
+        .max()
+        .unwrap_or_default()
+        .min(usize::from(size.width.saturating_sub(8)));
+    let area = popup(
+        (rows.len() + 4).min(usize::from(size.height.saturating_sub(4))),
+        width,
+        size,
+        BoxLocation::MiddleCentre,
+    );
+    // Borders, and the blank & key hint lines
+    let height = usize::from(area.height.saturating_sub(4)).max(1);
+    let start = picker.selected.saturating_sub(height - 1);
+
+    let mut lines = rows
+        .into_iter()
+        .enumerate()
+        .skip(start)
+        .take(height)
+        .map(|(index, text)| {
+            if index == picker.selected {
+                Line::from(Span::styled(
+                    format!("{RIGHT_ARROW}{text}"),
+                    Style::default().add_modifier(Modifier::BOLD),
+                ))
+            } else {
+                Line::from(format!("  {text}"))
+            }
+        })
+        .collect::<Vec<_>>();
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        hint,
+        Style::default().add_modifier(Modifier::DIM),
+    )));
+    drop(gui_state);
+
+    let block = Block::default()
+        .title(" recent ")
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .style(theme.popup());
+
+    f.render_widget(Clear, area);
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+/// Draw the docker context picker, one context per row, with its docker endpoint
+pub fn context_picker(f: &mut Frame, gui_state: &Arc<Mutex<GuiState>>, theme: &Theme) {
+    let gui_state = gui_state.lock();
+    let Some(picker) = gui_state.get_context_picker_ref() else {
+        return;
+    };
+    let hint = "( ↑ ↓ ) select context ( enter ) connect ( esc ) default";
+    let name_width = picker
+        .rows
+        .iter()
+        .map(|i| i.name.chars().count())
+        .max()
+        .unwrap_or_default();
+    let rows = picker
+        .rows
+        .iter()
+        .map(|i| {
+            let tls = if i.tls.is_some() { " tls" } else { "" };
+            format!(
+                "{:<name_width$}  {}{tls}",
+                i.name,
+                i.host.as_deref().unwrap_or("default socket")
+            )
+        })
+        .collect::<Vec<_>>();
+    let size = f.size();
+    let width = rows
+        .iter()
+        .map(|i| i.chars().count() + 6)
+        .chain(std::iter::once(hint.chars().count() + 4))
+        .max()
+        .unwrap_or_default()
+        .min(usize::from(size.width.saturating_sub(8)));
+    let area = popup(
+        (rows.len() + 4).min(usize::from(size.height.saturating_sub(4))),
+        width,
+        size,
+        BoxLocation::MiddleCentre,
+    );
+    // Borders, and the blank & key hint lines
+    let height = usize::from(area.height.saturating_sub(4)).max(1);
+    let start = picker.selected.saturating_sub(height - 1);
+
+    let mut lines = rows
+        .into_iter()
+        .enumerate()
+        .skip(start)
+        .take(height)
+        .map(|(index, text)| {
+            if index == picker.selected {
+                Line::from(Span::styled(
+                    format!("{RIGHT_ARROW}{text}"),
+                    Style::default().add_modifier(Modifier::BOLD),
+                ))
+            } else {
+                Line::from(format!("  {text}"))
+            }
+        })
+        .collect::<Vec<_>>();
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        hint,
+        Style::default().add_modifier(Modifier::DIM),
+    )));
+    drop(gui_state);
+
+    let block = Block::default()
+        .title(" docker contexts ")
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .style(theme.popup());
+
+    f.render_widget(Clear, area);
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+/// Draw the schedules panel, one schedule per row, with when it next runs, & the outcome of its last run, above the schedule being typed
+#[allow(clippy::too_many_lines)]
+pub fn schedules(
+    f: &mut Frame,
+    app_data: &Arc<Mutex<AppData>>,
+    gui_state: &Arc<Mutex<GuiState>>,
+    now: u64,
+    theme: &Theme,
+) {
+    let gui_state = gui_state.lock();
+    let Some(form) = gui_state.get_schedule_form_ref() else {
+        return;
+    };
+    let hint = "( ↑ ↓ ) select ( enter ) add ( delete ) remove ( esc ) close";
+    let input = form.container.as_ref().map_or_else(
+        || "no container selected".to_owned(),
+        |(name, _)| format!("{name} @ {}█", form.text),
+    );
+    let error = form.error.clone();
+    let selected = form.selected;
+    drop(gui_state);
+
+    let app_data = app_data.lock();
+    let schedules = app_data.get_schedules();
+    let name_width = schedules
+        .iter()
+        .map(|i| i.name.chars().count())
+        .max()
+        .unwrap_or_default();
+    let input_width = schedules
+        .iter()
+        .map(|i| i.input.chars().count())
+        .max()
+        .unwrap_or_default();
+    let rows = schedules
+        .iter()
+        .map(|i| {
+            let next = i.next.map_or_else(
+                || "done".to_owned(),
+                |next| format!("in {}", format_duration(next.saturating_sub(now))),
+            );
+            let last = match &i.last {
+                None => String::new(),
+                Some((at, Ok(()))) => {
+                    format!("  ok {} ago", format_duration(now.saturating_sub(*at)))
+                }
+                Some((at, Err(e))) => format!(
+                    "  failed {} ago: {e}",
+                    format_duration(now.saturating_sub(*at))
+                ),
+            };
+            format!(
+                "{:<name_width$}  {:<input_width$}  {next:<11}{last}",
+                i.name, i.input
+            )
+            .trim_end()
+            .to_owned()
+        })
+        .collect::<Vec<_>>();
+    drop(app_data);
+
+    let size = f.size();
+    let width = rows
+        .iter()
+        .map(|i| i.chars().count() + 6)
+        .chain(
+            [&input, &hint.to_owned()]
+                .iter()
+                .map(|i| i.chars().count() + 4),
+        )
+        .chain(error.iter().map(|i| i.chars().count() + 4))
+        .max()
+        .unwrap_or_default()
+        .min(usize::from(size.width.saturating_sub(8)));
+    let fixed = 6 + usize::from(error.is_some());
+    let area = popup(
+        (rows.len().max(1) + fixed).min(usize::from(size.height.saturating_sub(4))),
+        width,
+        size,
+        BoxLocation::MiddleCentre,
+    );
+    // Borders, the blank lines, the input, the error, & the key hint
+    let height = usize::from(
+        area.height
+            .saturating_sub(u16::try_from(fixed).unwrap_or(u16::MAX)),
+    )
+    .max(1);
+    let start = selected.saturating_sub(height - 1);
+
+    let mut lines = if rows.is_empty() {
+        vec![Line::from(Span::styled(
+            "  no schedules, e.g. restart at 03:00, save logs every 1h",
+            Style::default().add_modifier(Modifier::DIM),
+        ))]
+    } else {
+        rows.into_iter()
+            .enumerate()
+            .skip(start)
+            .take(height)
+            .map(|(index, text)| {
+                if index == selected {
+                    Line::from(Span::styled(
+                        format!("{RIGHT_ARROW}{text}"),
+                        Style::default().add_modifier(Modifier::BOLD),
+                    ))
+                } else {
+                    Line::from(format!("  {text}"))
+                }
+            })
+            .collect::<Vec<_>>()
+    };
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        format!(" {input}"),
+        Style::default().add_modifier(Modifier::BOLD),
+    )));
+    if let Some(error) = error {
+        lines.push(Line::from(Span::styled(
+            format!(" {error}"),
+            Style::default().fg(theme.error),
+        )));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        hint,
+        Style::default().add_modifier(Modifier::DIM),
+    )));
+
+    let block = Block::default()
+        .title(" schedules, times are UTC ")
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .style(theme.popup());
+
+    f.render_widget(Clear, area);
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+/// Draw the filters panel, one rule per row, checked if it's on, above the rule being typed
+pub fn filters(
+    f: &mut Frame,
+    app_data: &Arc<Mutex<AppData>>,
+    gui_state: &Arc<Mutex<GuiState>>,
+    theme: &Theme,
+) {
+    let gui_state = gui_state.lock();
+    let Some(form) = gui_state.get_filter_form_ref() else {
+        return;
+    };
+    let hint = "( ↑ ↓ ) select ( tab ) on/off ( enter ) add ( delete ) remove ( esc ) close";
+    let input = format!("{}█", form.text);
+    let error = form.error.clone();
+    let selected = form.selected;
+    drop(gui_state);
+
+    let app_data = app_data.lock();
+    let rows = app_data
+        .get_filters()
+        .iter()
+        .map(|i| {
+            (
+                i.enabled,
+                format!("[{}] {i}", if i.enabled { "x" } else { " " }),
+            )
+        })
+        .collect::<Vec<_>>();
+    let (_, shown) = app_data.get_shown_len();
+    let title = format!(" filters - {shown}/{} shown ", app_data.get_container_len());
+    drop(app_data);
+
+    let size = f.size();
+    let width = rows
+        .iter()
+        .map(|(_, i)| i.chars().count() + 6)
+        .chain(
+            [&input, &hint.to_owned(), &title]
+                .iter()
+                .map(|i| i.chars().count() + 4),
+        )
+        .chain(error.iter().map(|i| i.chars().count() + 4))
+        .max()
+        .unwrap_or_default()
+        .min(usize::from(size.width.saturating_sub(8)));
+    let fixed = 6 + usize::from(error.is_some());
+    let area = popup(
+        (rows.len().max(1) + fixed).min(usize::from(size.height.saturating_sub(4))),
+        width,
+        size,
+        BoxLocation::MiddleCentre,
+    );
+    // Borders, the blank lines, the input, the error, & the key hint
+    let height = usize::from(
+        area.height
+            .saturating_sub(u16::try_from(fixed).unwrap_or(u16::MAX)),
+    )
+    .max(1);
+    let start = selected.saturating_sub(height - 1);
+
+    let mut lines = if rows.is_empty() {
+        vec![Line::from(Span::styled(
+            "  no filters, e.g. status!=exited, name!=*-sidecar",
+            Style::default().add_modifier(Modifier::DIM),
+        ))]
+    } else {
+        rows.into_iter()
+            .enumerate()
+            .skip(start)
+            .take(height)
+            .map(|(index, (enabled, text))| {
+                let style = if enabled {
+                    Style::default()
+                } else {
+                    Style::default().add_modifier(Modifier::DIM)
+                };
+                if index == selected {
+                    Line::from(Span::styled(
+                        format!("{RIGHT_ARROW}{text}"),
+                        style.add_modifier(Modifier::BOLD),
+                    ))
+                } else {
+                    Line::from(Span::styled(format!("  {text}"), style))
+                }
+            })
+            .collect::<Vec<_>>()
+    };
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        format!(" {input}"),
+        Style::default().add_modifier(Modifier::BOLD),
+    )));
+    if let Some(error) = error {
+        lines.push(Line::from(Span::styled(
+            format!(" {error}"),
+            Style::default().fg(theme.error),
+        )));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        hint,
+        Style::default().add_modifier(Modifier::DIM),
+    )));
+
+    let block = Block::default()
+        .title(title)
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .style(theme.popup());
+
+    f.render_widget(Clear, area);
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+/// Draw the lifecycle timeline, a row of event markers, and a row of the running state, over the selected span, with the most recent events listed below
+#[allow(clippy::too_many_lines)]
+pub fn timeline(f: &mut Frame, gui_state: &Arc<Mutex<GuiState>>, theme: &Theme) {
+    let gui_state = gui_state.lock();
+    let Some(timeline) = gui_state.get_timeline() else {
+        return;
+    };
+    let label_width = 9;
+    let size = f.size();
+    let width = usize::from(size.width.saturating_sub(8)).min(104);
+    // Borders, and a single space of padding either side
+    let axis_width = width.saturating_sub(label_width + 4).max(1);
+    let label = |text: &str| Span::from(format!(" {text:<width$}", width = label_width - 1));
+
+    let markers = timeline.markers(axis_width).into_iter().map(|i| {
+        i.map_or_else(
+            || Span::styled("·", Style::default().add_modifier(Modifier::DIM)),
+            |kind| {
+                Span::styled(
+                    kind.symbol().to_string(),
+                    Style::default()
+                        .fg(kind.color())
+                        .add_modifier(Modifier::BOLD),
+                )
+            },
+        )
+    });
+    let states = timeline.states(axis_width).into_iter().map(|running| {
+        if running {
+            Span::styled("━", Style::default().fg(theme.ok))
+        } else {
+            Span::styled("─", Style::default().add_modifier(Modifier::DIM))
+        }
+    });
+    let start_label = format!("{} ago", format_span(timeline.span));
+    let middle_label = format!("{} ago", format_span(timeline.span / 2));
+    let end_label = "now";
+    let middle = axis_width.saturating_sub(middle_label.len()) / 2;
+    let axis_labels = format!(
+        "{start_label:<middle$}{middle_label}{end_label:>rest$}",
+        rest = axis_width.saturating_sub(middle.max(start_label.len()) + middle_label.len())
+    );
+
+    let mut lines = vec![
+        Line::from(
+            std::iter::once(label("events"))
+                .chain(markers)
+                .collect::<Vec<_>>(),
+        ),
+        Line::from(
+            std::iter::once(label("running"))
+                .chain(states)
+                .collect::<Vec<_>>(),
+        ),
+        Line::from(vec![label(""), Span::from(axis_labels)]),
+        Line::from(""),
+        Line::from(format!(" {}", timeline.summary())),
+        Line::from(""),
+    ];
+    if timeline.visible().is_empty() {
+        lines.push(Line::from(format!(
+            " no events in the last {}",
+            format_span(timeline.span)
+        )));
+    } else {
+        lines.extend(
+            timeline
+                .visible()
+                .iter()
+                .rev()
+                .take(TIMELINE_RECENT)
+                .map(|i| {
+                    let ago = format!(
+                        "{} ago",
+                        format_duration(u64::try_from(timeline.end - i.time).unwrap_or_default())
+                    );
+                    Line::from(vec![
+                        Span::from(format!(" {ago:<14}")),
+                        Span::styled(
+                            i.kind.symbol().to_string(),
+                            Style::default().fg(i.kind.color()),
+                        ),
+                        Span::from(format!(" {}", i.kind)),
+                    ])
+                }),
+        );
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        " ( ← → ) change span ( s ) save as csv & json ( esc ) close",
+        Style::default().add_modifier(Modifier::DIM),
+    )));
+    let title = format!(
+        " timeline of {}, last {} ",
+        timeline.name,
+        format_span(timeline.span)
+    );
+    drop(gui_state);
+
+    let area = popup(lines.len() + 2, width, size, BoxLocation::MiddleCentre);
+    let block = Block::default()
+        .title(title)
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .style(theme.popup());
+
+    f.render_widget(Clear, area);
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+/// Draw the image prune preview, the two rules, and the images that they would remove, oldest first
+#[allow(clippy::too_many_lines)]
+pub fn prune(f: &mut Frame, gui_state: &Arc<Mutex<GuiState>>, theme: &Theme) {
+    let gui_state = gui_state.lock();
+    let Some(preview) = gui_state.get_prune_preview_ref() else {
+        return;
+    };
+    let size = f.size();
+    let width = usize::from(size.width.saturating_sub(8)).min(84);
+    let rule = |selected: bool, text: String| {
+        if selected {
+            Line::from(Span::styled(
+                format!("{RIGHT_ARROW}{text}"),
+                Style::default().add_modifier(Modifier::BOLD),
+            ))
+        } else {
+            Line::from(format!("  {text}"))
+        }
+    };
+    let plural = |count: u16| if count == 1 { "" } else { "s" };
+    let matches = preview.matches();
+    let mut lines = vec![
+        rule(
+            preview.selected == PruneRule::Age,
+            format!(
+                "remove unused images older than {} day{}",
+                preview.days,
+                plural(preview.days)
+            ),
+        ),
+        rule(
+            preview.selected == PruneRule::Keep,
+            format!(
+                "keep the newest {} tag{} per repository",
+                preview.keep,
+                plural(preview.keep)
+            ),
+        ),
+        Line::from(""),
+        Line::from(format!(
+            "  {} of {} images match, up to {} reclaimed",
+            matches.len(),
+            preview.images.len(),
+            preview.reclaimed().to_string().trim()
+        )),
+        Line::from(""),
+    ];
+
+    // Borders, the lines above, and the blank & key hint lines below
+    let rows = usize::from(size.height.saturating_sub(4)).saturating_sub(lines.len() + 4);
+    // Borders, indent, & the age & size columns
+    let name_width = width.saturating_sub(31);
+    let shown = if matches.len() > rows {
+        rows.saturating_sub(1)
+    } else {
+        matches.len()
+    };
+    lines.extend(matches.iter().take(shown).map(|i| {
+        let name = i.tags.first().map_or_else(
+            || {
+                format!(
+                    "<none> {}",
+                    i.id.trim_start_matches("sha256:")
+                        .get(..12)
+                        .unwrap_or_default()
+                )
+            },
+            |tag| {
+                let more = i.tags.len() - 1;
+                if more > 0 {
+                    format!("{tag} (+{more})")
+                } else {
+                    tag.clone()
+                }
+            },
+        );
+        let name = if name.chars().count() > name_width {
+            format!(
+                "{}…",
+                name.chars()
+                    .take(name_width.saturating_sub(1))
+                    .collect::<String>()
+            )
+        } else {
+            name
+        };
+        let days = (preview.now - i.created).max(0) / (60 * 60 * 24);
+        Line::from(format!(
+            "  {name:<name_width$} {:>8} days {:>10}",
+            days,
+            ByteStats::new(i.size).to_string().trim()
+        ))
+    }));
+    if shown < matches.len() {
+        lines.push(Line::from(format!(
+            "  … and {} more",
+            matches.len() - shown
+        )));
+    }
+    lines.push(Line::from(""));
+    let hint = if preview.armed {
+        Span::styled(
+            format!(
+                "  ( enter ) again to remove {} images ( esc ) cancel",
+                matches.len()
+            ),
+            Style::default()
+                .fg(theme.error)
+                .add_modifier(Modifier::BOLD),
+        )
+    } else {
+        Span::styled(
+            "  ( ↑ ↓ ) select rule ( ← → ) change ( enter ) remove ( esc ) cancel",
+            Style::default().add_modifier(Modifier::DIM),
+        )
+    };
+    lines.push(Line::from(hint));
+    let title = preview.host.as_ref().map_or_else(
+        || " prune images ".to_owned(),
+        |host| format!(" prune images on {host} "),
+    );
+    drop(gui_state);
+
+    let area = popup(lines.len() + 2, width, size, BoxLocation::MiddleCentre);
+    let block = Block::default()
+        .title(title)
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .style(theme.popup());
+
+    f.render_widget(Clear, area);
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+/// Draw the build cache view, one record per row, largest first, scrolled so that the selected record is always visible
+#[allow(clippy::too_many_lines)]
+pub fn build_cache(f: &mut Frame, gui_state: &Arc<Mutex<GuiState>>, theme: &Theme) {
+    let gui_state = gui_state.lock();
+    let Some(cache) = gui_state.get_build_cache_ref() else {
+        return;
+    };
+    let size = f.size();
+    let width = usize::from(size.width.saturating_sub(8)).min(96);
+    let height = (cache.records.len().max(1) + 6).min(usize::from(size.height.saturating_sub(4)));
+    let area = popup(height, width, size, BoxLocation::MiddleCentre);
+    // Borders, the summary & blank lines above, and the blank & key hint lines below
+    let rows = usize::from(area.height.saturating_sub(6)).max(1);
+    let start = cache.selected.saturating_sub(rows - 1);
+    // Borders, indent, & the mark, size, & last used columns
+    let description_width = width.saturating_sub(33);
+
+    let mut lines = vec![
+        Line::from(format!(
+            "  {} records, {} total, up to {} reclaimed",
+            cache.records.len(),
+            cache.total().to_string().trim(),
+            cache.chosen_size().to_string().trim()
+        )),
+        Line::from(""),
+    ];
+    if cache.records.is_empty() {
+        lines.push(Line::from("  no build cache"));
+    }
+    lines.extend(
+        cache
+            .records
+            .iter()
+            .enumerate()
+            .skip(start)
+            .take(rows)
+            .map(|(index, record)| {
+                let mark = if record.in_use {
+                    "[-]"
+                } else if cache.marked.contains(&record.id) {
+                    "[x]"
+                } else {
+                    "[ ]"
+                };
+                let last_used = record.last_used.map_or_else(
+                    || "never".to_owned(),
+                    |i| {
+                        format!(
+                            "{} ago",
+                            format_duration(u64::try_from(cache.now - i).unwrap_or_default())
+                        )
+                    },
+                );
+                let description = if record.description.chars().count() > description_width {
+                    format!(
+                        "{}…",
+                        record
+                            .description
+                            .chars()
+                            .take(description_width.saturating_sub(1))
+                            .collect::<String>()
+                    )
+                } else {
+                    record.description.clone()
+                };
+                let text = format!(
+                    "{mark} {:>9} {last_used:>12}  {description}",
+                    ByteStats::new(record.size).to_string().trim()
+                );
+                if index == cache.selected {
+                    Line::from(Span::styled(
+                        format!("{RIGHT_ARROW}{text}"),
+                        Style::default().add_modifier(Modifier::BOLD),
+                    ))
+                } else {
+                    Line::from(format!("  {text}"))
+                }
+            }),
+    );
+    lines.push(Line::from(""));
+    let hint = if cache.armed {
+        Span::styled(
+            format!(
+                "  ( enter ) again to remove {} records ( esc ) cancel",
+                cache.chosen().len()
+            ),
+            Style::default()
+                .fg(theme.error)
+                .add_modifier(Modifier::BOLD),
+        )
+    } else {
+        Span::styled(
+            "  ( ↑ ↓ ) select ( space ) mark ( enter ) remove ( esc ) close",
+            Style::default().add_modifier(Modifier::DIM),
+        )
+    };
+    lines.push(Line::from(hint));
+    let title = cache.host.as_ref().map_or_else(
+        || " build cache ".to_owned(),
+        |host| format!(" build cache on {host} "),
+    );
+    drop(gui_state);
+
+    let block = Block::default()
+        .title(title)
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .style(theme.popup());
+
+    f.render_widget(Clear, area);
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+/// Draw the secret scan findings, one variable per row, grouped by container, scrolled so that the selected finding is always visible
+pub fn secret_scan(f: &mut Frame, gui_state: &Arc<Mutex<GuiState>>, theme: &Theme) {
+    let gui_state = gui_state.lock();
+    let Some(scan) = gui_state.get_secret_scan_ref() else {
+        return;
+    };
+    let size = f.size();
+    let width = usize::from(size.width.saturating_sub(8)).min(96);
+    let height = (scan.findings.len().max(1) + 6).min(usize::from(size.height.saturating_sub(4)));
+    let area = popup(height, width, size, BoxLocation::MiddleCentre);
+    // Borders, the summary & blank lines above, and the blank & key hint lines below
+    let rows = usize::from(area.height.saturating_sub(6)).max(1);
+    let start = scan.selected.saturating_sub(rows - 1);
+    let container_width = scan
+        .findings
+        .iter()
+        .map(|i| i.container.chars().count())
+        .max()
+        .unwrap_or_default();
+    let key_width = scan
+        .findings
+        .iter()
+        .map(|i| i.key.chars().count())
+        .max()
+        .unwrap_or_default();
+
+    let mut lines = vec![
+        Line::from(format!(
+            "  {} findings, in {} of {} containers",
+            scan.findings.len(),
+            scan.flagged(),
+            scan.scanned
+        )),
+        Line::from(""),
+    ];
+    if scan.findings.is_empty() {
+        lines.push(Line::from("  no plaintext credentials found"));
+    }
+    lines.extend(scan.findings.iter().enumerate().skip(start).take(rows).map(
+        |(index, finding)| {
+            let text = format!(
+                "{:<container_width$}  {:<key_width$}  {:<17}  {}",
+                finding.container,
+                finding.key,
+                finding.kind.to_string(),
+                finding.preview
+            );
+            if index == scan.selected {
+                Line::from(Span::styled(
+                    format!("{RIGHT_ARROW}{text}"),
+                    Style::default().add_modifier(Modifier::BOLD),
+                ))
+            } else {
+                Line::from(format!("  {text}"))
+            }
+        },
+    ));
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        "  ( ↑ ↓ ) select ( esc ) close",
+        Style::default().add_modifier(Modifier::DIM),
+    )));
+    let title = scan.host.as_ref().map_or_else(
+        || " secret scan ".to_owned(),
+        |host| format!(" secret scan on {host} "),
+    );
+    drop(gui_state);
+
+    let block = Block::default()
+        .title(title)
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .style(theme.popup());
+
+    f.render_widget(Clear, area);
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+/// A health check probe, as its age, exit code, duration, & the first line of its output, passes in the ok color, & failures in the error color
+fn health_probe(
+    probe: &HealthProbe,
+    now: u64,
+    output_width: usize,
+    selected: bool,
+    theme: &Theme,
+) -> Line<'static> {
+    let age = probe.start.map_or_else(
+        || "unknown".to_owned(),
+        |i| format!("{} ago", format_duration(now.saturating_sub(i))),
+    );
+    let exit = probe
+        .exit_code
+        .map_or_else(|| "exit ?".to_owned(), |i| format!("exit {i}"));
+    let duration = probe
+        .duration
+        .map_or_else(String::new, |i| format!("{i}ms"));
+    let first = probe.output.lines().next().unwrap_or_default();
+    let first = if first.chars().count() > output_width {
+        format!(
+            "{}…",
+            first
+                .chars()
+                .take(output_width.saturating_sub(1))
+                .collect::<String>()
+        )
+    } else {
+        first.to_owned()
+    };
+    let text = format!("{age:>12}  {exit:<7} {duration:>8}  {first}");
+    let color = if probe.passed() {
+        theme.ok
+    } else {
+        theme.error
+    };
+    if selected {
+        Line::from(Span::styled(
+            format!("{RIGHT_ARROW}{text}"),
+            Style::default().fg(color).add_modifier(Modifier::BOLD),
+        ))
+    } else {
+        Line::from(Span::styled(
+            format!("  {text}"),
+            Style::default().fg(color),
+        ))
+    }
+}
+
+/// The most output lines of the selected probe that are shown
+const HEALTH_OUTPUT: usize = 8;
+
+/// Draw the health check of a container, its test, & the probes that the daemon keeps, newest first, with the full output of the selected probe
+pub fn health(f: &mut Frame, gui_state: &Arc<Mutex<GuiState>>, theme: &Theme) {
+    let gui_state = gui_state.lock();
+    let Some(view) = gui_state.get_health_view_ref() else {
+        return;
+    };
+    let size = f.size();
+    let width = usize::from(size.width.saturating_sub(8)).min(96);
+    // Borders, indent, & the age, exit code, & duration columns
+    let output_width = width.saturating_sub(36);
+    let output = view
+        .selected_probe()
+        .map(|i| {
+            i.output
+                .lines()
+                .take(HEALTH_OUTPUT)
+                .map(|i| format!("    {i}"))
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+
+    let status = if view.failing_streak > 0 {
+        format!(
+            "  {}, failing {} in a row",
+            view.status, view.failing_streak
+        )
+    } else {
+        format!("  {}", view.status)
+    };
+    let mut schedule = vec![];
+    if let Some(interval) = view.interval {
+        schedule.push(format!("every {}", format_duration(interval)));
+    }
+    if let Some(timeout) = view.timeout {
+        schedule.push(format!("timeout {}", format_duration(timeout)));
+    }
+    if let Some(retries) = view.retries {
+        schedule.push(format!("unhealthy after {retries} failures"));
+    }
+    let mut lines = vec![
+        Line::from(Span::styled(
+            status,
+            Style::default().add_modifier(Modifier::BOLD),
+        )),
+        Line::from(format!("  test: {}", view.test)),
+    ];
+    if !schedule.is_empty() {
+        lines.push(Line::from(format!("  {}", schedule.join(", "))));
+    }
+    lines.push(Line::from(""));
+    if view.probes.is_empty() {
+        lines.push(Line::from("  no probes yet"));
+    }
+    lines.extend(view.probes.iter().enumerate().map(|(index, probe)| {
+        health_probe(probe, view.now, output_width, index == view.selected, theme)
+    }));
+    if !output.is_empty() {
+        lines.push(Line::from(""));
+        lines.extend(output.into_iter().map(Line::from));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        "  ( ↑ ↓ ) select ( esc ) close",
+        Style::default().add_modifier(Modifier::DIM),
+    )));
+    let title = format!(" health of {} ", view.name);
+    drop(gui_state);
+
+    let height = (lines.len() + 2).min(usize::from(size.height.saturating_sub(4)));
+    let area = popup(height, width, size, BoxLocation::MiddleCentre);
+    let block = Block::default()
+        .title(title)
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .style(theme.popup());
+
+    f.render_widget(Clear, area);
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+/// Draw the published, & exposed, ports of a container, a published port shows the url it's opened at
+pub fn port_mappings(f: &mut Frame, gui_state: &Arc<Mutex<GuiState>>, theme: &Theme) {
+    let gui_state = gui_state.lock();
+    let Some(view) = gui_state.get_port_view_ref() else {
+        return;
+    };
+    let size = f.size();
+    let width = usize::from(size.width.saturating_sub(8)).min(72);
+    let dim = Style::default().add_modifier(Modifier::DIM);
+    let mut lines = vec![Line::from(Span::styled(
+        format!("  {:<24}{:<12}url", "published", "container"),
+        dim,
+    ))];
+    lines.extend(view.rows.iter().enumerate().map(|(index, row)| {
+        let (published, url) = match (row.public, row.url.as_deref()) {
+            (Some(public), Some(url)) => (
+                format!("{}:{public}", row.ip.as_deref().unwrap_or_default()),
+                url.to_owned(),
+            ),
+            _ => ("exposed".to_owned(), String::new()),
+        };
+        let text = format!("{published:<24}{:<12}{url}", row.private);
+        if index == view.selected {
+            Line::from(Span::styled(
+                format!("{RIGHT_ARROW}{text}"),
+                Style::default().add_modifier(Modifier::BOLD),
+            ))
+        } else if row.is_published() {
+            Line::from(format!("  {text}"))
+        } else {
+            Line::from(Span::styled(format!("  {text}"), dim))
+        }
+    }));
+    lines.push(Line::from(""));
+    let enter = if view.selected_row().is_some_and(PortRow::is_published) {
+        "open in browser"
+    } else {
+        "forward with socat"
+    };
+    lines.push(Line::from(Span::styled(
+        format!("  ( enter ) {enter} ( ↑ ↓ ) select ( esc ) close"),
+        dim,
+    )));
+    let title = format!(" ports of {} ", view.name);
+    drop(gui_state);
+
+    let height = (lines.len() + 2).min(usize::from(size.height.saturating_sub(4)));
+    let area = popup(height, width, size, BoxLocation::MiddleCentre);
+    let block = Block::default()
+        .title(title)
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .style(theme.popup());
+
+    f.render_widget(Clear, area);
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+/// Draw a directory of a container's filesystem, one entry per row, directories first, scrolled so that the selected entry is always visible
+pub fn file_browser(f: &mut Frame, gui_state: &Arc<Mutex<GuiState>>, theme: &Theme) {
+    let gui_state = gui_state.lock();
+    let Some(browser) = gui_state.get_file_browser_ref() else {
+        return;
+    };
+    let size = f.size();
+    let width = usize::from(size.width.saturating_sub(8)).min(72);
+    let height = (browser.entries.len().max(1) + 4).min(usize::from(size.height.saturating_sub(4)));
+    let area = popup(height, width, size, BoxLocation::MiddleCentre);
+    // Borders, and the blank & key hint lines below
+    let rows = usize::from(area.height.saturating_sub(4)).max(1);
+    let start = browser.selected.saturating_sub(rows - 1);
+    let dim = Style::default().add_modifier(Modifier::DIM);
+
+    let mut lines = vec![];
+    if browser.entries.is_empty() {
+        lines.push(Line::from(Span::styled("  empty directory", dim)));
+    }
+    lines.extend(
+        browser
+            .entries
+            .iter()
+            .enumerate()
+            .skip(start)
+            .take(rows)
+            .map(|(index, entry)| {
+                let text = if entry.is_dir {
+                    format!("{}/", entry.name)
+                } else {
+                    entry.name.clone()
+                };
+                if index == browser.selected {
+                    Line::from(Span::styled(
+                        format!("{RIGHT_ARROW}{text}"),
+                        Style::default().add_modifier(Modifier::BOLD),
+                    ))
+                } else if entry.is_dir {
+                    Line::from(Span::styled(
+                        format!("  {text}"),
+                        Style::default().fg(theme.highlight),
+                    ))
+                } else {
+                    Line::from(format!("  {text}"))
+                }
+            }),
+    );
+    lines.push(Line::from(""));
+    let enter = if browser.selected_entry().is_some_and(|i| i.is_dir) {
+        "open ( s ) save"
+    } else {
+        "save"
+    };
+    lines.push(Line::from(Span::styled(
+        format!("  ( enter ) {enter} ( ← ) up ( esc ) close"),
+        dim,
+    )));
+    let title = format!(" {}:{} ", browser.name, browser.path);
+    drop(gui_state);
+
+    let block = Block::default()
+        .title(title)
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .style(theme.popup());
+
+    f.render_widget(Clear, area);
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+/// The chars of a text, with those at the matched positions of the finder underlined
+fn finder_spans(
+    text: &str,
+    positions: &[usize],
+    style: Style,
+    theme: &Theme,
+) -> Vec<Span<'static>> {
+    let matched = style.fg(theme.highlight).add_modifier(Modifier::UNDERLINED);
+    text.chars()
+        .enumerate()
+        .map(|(index, c)| {
+            let style = if positions.contains(&index) {
+                matched
+            } else {
+                style
+            };
+            Span::styled(c.to_string(), style)
+        })
+        .collect()
+}
+
+/// Draw the container finder, the input, above the containers that match it, best match first, with the matched chars underlined
+pub fn finder(f: &mut Frame, gui_state: &Arc<Mutex<GuiState>>, theme: &Theme) {
+    let gui_state = gui_state.lock();
+    let Some(finder) = gui_state.get_finder_ref() else {
+        return;
+    };
+    let size = f.size();
+    let width = usize::from(size.width.saturating_sub(8)).min(96);
+    let height = (finder.matches.len().max(1) + 6).min(usize::from(size.height.saturating_sub(4)));
+    let area = popup(height, width, size, BoxLocation::MiddleCentre);
+    // Borders, the input & blank lines above, and the blank & key hint lines below
+    let rows = usize::from(area.height.saturating_sub(6)).max(1);
+    let start = finder.selected.saturating_sub(rows - 1);
+    let bold = Style::default().add_modifier(Modifier::BOLD);
+    let dim = Style::default().add_modifier(Modifier::DIM);
+    let name_width = finder
+        .matches
+        .iter()
+        .skip(start)
+        .take(rows)
+        .map(|i| finder.rows[i.row].name.chars().count())
+        .max()
+        .unwrap_or_default();
+
+    let mut lines = vec![
+        Line::from(Span::styled(format!(" > {}█", finder.input), bold)),
+        Line::from(""),
+    ];
+    if finder.matches.is_empty() {
+        lines.push(Line::from(Span::styled("   no matching containers", dim)));
+    }
+    lines.extend(
+        finder
+            .matches
+            .iter()
+            .enumerate()
+            .skip(start)
+            .take(rows)
+            .map(|(index, found)| {
+                let row = &finder.rows[found.row];
+                let selected = index == finder.selected;
+                let style = if selected { bold } else { Style::default() };
+                let positions = |field| {
+                    if found.field == field {
+                        found.positions.as_slice()
+                    } else {
+                        &[]
+                    }
+                };
+                let mut spans = vec![Span::styled(
+                    if selected {
+                        format!(" {RIGHT_ARROW}")
+                    } else {
+                        "   ".to_owned()
+                    },
+                    style,
+                )];
+                spans.extend(finder_spans(
+                    &row.name,
+                    positions(FinderField::Name),
+                    style,
+                    theme,
+                ));
+                spans.push(Span::from(
+                    " ".repeat(name_width - row.name.chars().count() + 2),
+                ));
+                spans.extend(finder_spans(
+                    &row.image,
+                    positions(FinderField::Image),
+                    dim,
+                    theme,
+                ));
+                if let Some(project) = row.project.as_ref() {
+                    spans.push(Span::styled("  ", dim));
+                    spans.extend(finder_spans(
+                        project,
+                        positions(FinderField::Project),
+                        dim,
+                        theme,
+                    ));
+                }
+                Line::from(spans)
+            }),
+    );
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        " ( ↑ ↓ ) choose a container ( enter ) select ( esc ) close",
+        dim,
+    )));
+    let title = format!(
+        " find a container - {}/{} ",
+        finder.matches.len(),
+        finder.rows.len()
+    );
+    drop(gui_state);
+
+    let block = Block::default()
+        .title(title)
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .style(theme.popup());
+
+    f.render_widget(Clear, area);
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+/// Draw the cleanup menu, the disk usage of the host, as with `docker system df`, and each prune, with what it would remove
+/// Once a prune is chosen, its confirmation is drawn over the menu
+#[allow(clippy::too_many_lines)]
+pub fn cleanup(f: &mut Frame, gui_state: &Arc<Mutex<GuiState>>, theme: &Theme) {
+    let gui_state = gui_state.lock();
+    let Some(cleanup) = gui_state.get_cleanup_ref() else {
+        return;
+    };
+    let dim = Style::default().add_modifier(Modifier::DIM);
+    let mut lines = vec![Line::from(Span::styled(
+        format!(
+            "  {:<28}{:>6} {:>7} {:>11} {:>12}",
+            "disk usage", "total", "active", "size", "reclaimable"
+        ),
+        dim,
+    ))];
+    lines.extend(cleanup.usage.iter().map(|i| {
+        Line::from(format!(
+            "  {:<28}{:>6} {:>7} {:>11} {:>12}",
+            i.label,
+            i.total,
+            i.active,
+            i.size.to_string().trim(),
+            i.reclaimable.to_string().trim()
+        ))
+    }));
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        format!(
+            "  {:<28}{:<24}{:>7} {:>9}",
+            "prune", "equivalent to", "removes", "reclaims"
+        ),
+        dim,
+    )));
+    lines.extend(cleanup.rows.iter().enumerate().map(|(index, row)| {
+        let text = format!(
+            "{:<28}{:<24}{:>7} {:>9}",
+            row.kind.label(),
+            row.kind.command(),
+            row.count,
+            row.size
+                .map_or_else(|| "-".to_owned(), |i| i.to_string().trim().to_owned())
+        );
+        if index == cleanup.selected {
+            Line::from(Span::styled(
+                format!("{RIGHT_ARROW}{text}"),
+                Style::default().add_modifier(Modifier::BOLD),
+            ))
+        } else if row.count == 0 {
+            Line::from(Span::styled(format!("  {text}"), dim))
+        } else {
+            Line::from(format!("  {text}"))
+        }
+    }));
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        "  ( ↑ ↓ ) select ( enter ) prune ( esc ) close",
+        dim,
+    )));
+    let title = cleanup.host.as_ref().map_or_else(
+        || " cleanup ".to_owned(),
+        |host| format!(" cleanup on {host} "),
+    );
+    let confirm = cleanup.chosen().filter(|_| cleanup.confirm);
+    let armed = cleanup.armed;
+    drop(gui_state);
+
+    let size = f.size();
+    let width = lines.iter().map(Line::width).max().unwrap_or_default() + 4;
+    let area = popup(
+        (lines.len() + 2).min(usize::from(size.height.saturating_sub(4))),
+        width.min(usize::from(size.width.saturating_sub(8))),
+        size,
+        BoxLocation::MiddleCentre,
+    );
+    let block = Block::default()
+        .title(title)
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .style(theme.popup());
+    f.render_widget(Clear, area);
+    f.render_widget(Paragraph::new(lines).block(block), area);
+
+    if let Some(row) = confirm {
+        let reclaims = row.size.map_or_else(String::new, |i| {
+            format!(", reclaiming up to {}", i.to_string().trim())
+        });
+        let lines = vec![
+            Line::from(vec![
+                Span::from("Are you sure you want to prune: "),
+                Span::styled(
+                    format!("{} {}", row.count, row.kind.label()),
+                    Style::default()
+                        .fg(theme.error)
+                        .add_modifier(Modifier::BOLD),
+                ),
+            ]),
+            Line::from(format!("as with {}{reclaims}", row.kind.command())),
+            Line::from(""),
+            if armed {
+                Line::from(Span::styled(
+                    "( y ) again to prune ( n ) no",
+                    Style::default()
+                        .fg(theme.error)
+                        .add_modifier(Modifier::BOLD),
+                ))
+            } else {
+                Line::from(Span::styled("( y ) yes ( n ) no", dim))
+            },
+        ];
+        let width = lines.iter().map(Line::width).max().unwrap_or_default() + 8;
+        let area = popup(
+            lines.len() + 2,
+            width.min(usize::from(size.width.saturating_sub(8))),
+            size,
+            BoxLocation::MiddleCentre,
+        );
+        let block = Block::default()
+            .title(" Confirm Prune ")
+            .title_alignment(Alignment::Center)
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .style(theme.popup());
+        f.render_widget(Clear, area);
+        f.render_widget(
+            Paragraph::new(lines)
+                .alignment(Alignment::Center)
+                .block(block),
+            area,
+        );
+    }
+}
+
+/// Draw the save logs popup, the format, lines, & time range, of the logs to save
+pub fn log_export(f: &mut Frame, gui_state: &Arc<Mutex<GuiState>>, theme: &Theme) {
+    let gui_state = gui_state.lock();
+    let Some(export) = gui_state.get_log_export_ref() else {
+        return;
+    };
+    let dim = Style::default().add_modifier(Modifier::DIM);
+    let options = [
+        ("format", export.format.label().to_owned()),
+        ("lines", export.lines.label().to_owned()),
+        ("range", export.range.label()),
+    ];
+    let mut lines = options
+        .into_iter()
+        .enumerate()
+        .map(|(index, (label, value))| {
+            let mut spans = if index == export.selected {
+                vec![
+                    Span::styled(
+                        format!("{RIGHT_ARROW}{label:<8}"),
+                        Style::default().add_modifier(Modifier::BOLD),
+                    ),
+                    Span::styled(
+                        format!("◀ {value} ▶"),
+                        Style::default().add_modifier(Modifier::BOLD),
+                    ),
+                ]
+            } else {
+                vec![
+                    Span::from(format!("  {label:<8}")),
+                    Span::from(format!("  {value}  ")),
+                ]
+            };
+            if index == 1 && !export.has_shown() {
+                spans.push(Span::styled(" no filter, or search, is set", dim));
+            }
+            Line::from(spans)
+        })
+        .collect::<Vec<_>>();
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        format!(
+            "  saved as {}",
+            export.file_name(0).replace("_0.", "_[timestamp].")
+        ),
+        dim,
+    )));
+    lines.push(Line::from(Span::styled(
+        "  ( ↑ ↓ ) select ( ← → ) change ( enter ) save ( esc ) cancel",
+        dim,
+    )));
+    let title = format!(" save logs of {} ", export.name);
+    drop(gui_state);
+
+    let size = f.size();
+    let width = lines.iter().map(Line::width).max().unwrap_or_default() + 4;
+    let area = popup(
+        (lines.len() + 2).min(usize::from(size.height.saturating_sub(4))),
+        width.min(usize::from(size.width.saturating_sub(8))),
+        size,
+        BoxLocation::MiddleCentre,
+    );
+    let block = Block::default()
+        .title(title)
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .style(theme.popup());
+    f.render_widget(Clear, area);
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+/// Draw the standby notice, drawing is paused once it has been drawn, so it's the last frame shown until standby ends
+pub fn standby(f: &mut Frame, theme: &Theme) {
+    let lines = [
+        "drawing paused, & updates slowed",
+        "press any key, or focus the terminal, to resume",
+    ];
+    let width = lines
+        .iter()
+        .map(|i| i.chars().count())
+        .max()
+        .unwrap_or_default()
+        + 6;
+    let area = popup(lines.len() + 4, width, f.size(), BoxLocation::MiddleCentre);
+    let block = Block::default()
+        .title(" standby ")
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .style(theme.popup());
+    let paragraph = Paragraph::new(
+        std::iter::once(Line::from(""))
+            .chain(lines.into_iter().map(Line::from))
+            .collect::<Vec<_>>(),
+    )
+    .block(block)
+    .alignment(Alignment::Center);
+
+    f.render_widget(Clear, area);
+    f.render_widget(paragraph, area);
+}
+
+/// Draw the note form, a single line of text, for the note of a container
+pub fn note_form(f: &mut Frame, gui_state: &Arc<Mutex<GuiState>>, theme: &Theme) {
+    let gui_state = gui_state.lock();
+    let Some(form) = gui_state.get_note_form_ref() else {
+        return;
+    };
+    let hint = "empty removes the note ( enter ) save ( esc ) cancel";
+    let text = format!("{}█", form.text);
+    let title = format!(" note for {} ", form.name);
+    drop(gui_state);
+
+    let size = f.size();
+    // Borders, with a char of padding either side
+    let width = [hint, &text, &title]
+        .iter()
+        .map(|i| i.chars().count() + 4)
+        .max()
+        .unwrap_or_default()
+        .min(usize::from(size.width.saturating_sub(8)));
+    let area = popup(5, width, size, BoxLocation::MiddleCentre);
+    let lines = vec![
+        Line::from(Span::styled(
+            format!(" {text}"),
+            Style::default().add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+        Line::from(Span::styled(
+            format!(" {hint}"),
+            Style::default().add_modifier(Modifier::DIM),
+        )),
+    ];
+
+    let block = Block::default()
+        .title(title)
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .style(theme.popup());
+
+    f.render_widget(Clear, area);
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+/// Draw the stdin form, the line being typed, above the last line that was sent
+pub fn stdin_form(f: &mut Frame, gui_state: &Arc<Mutex<GuiState>>, theme: &Theme) {
+    let gui_state = gui_state.lock();
+    let Some(form) = gui_state.get_stdin_form_ref() else {
+        return;
+    };
+    let hint = "( enter ) send the line ( esc ) close";
+    let text = format!("{}█", form.text);
+    let sent = form
+        .sent
+        .as_ref()
+        .map_or_else(String::new, |i| format!("sent: {i}"));
+    let title = format!(" stdin of {} ", form.name);
+    drop(gui_state);
+
+    let size = f.size();
+    // Borders, with a char of padding either side
+    let width = [hint, &text, &sent, &title]
+        .iter()
+        .map(|i| i.chars().count() + 4)
+        .max()
+        .unwrap_or_default()
+        .min(usize::from(size.width.saturating_sub(8)));
+    let area = popup(5, width, size, BoxLocation::MiddleCentre);
+    let lines = vec![
+        Line::from(Span::styled(
+            format!(" {text}"),
+            Style::default().add_modifier(Modifier::BOLD),
+        )),
+        Line::from(format!(" {sent}")),
+        Line::from(Span::styled(
+            format!(" {hint}"),
+            Style::default().add_modifier(Modifier::DIM),
+        )),
+    ];
+
+    let block = Block::default()
+        .title(title)
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .style(theme.popup());
+
+    f.render_widget(Clear, area);
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+/// Draw the rename form, the name being typed, & why it can't be used, once submitted
+pub fn rename_form(f: &mut Frame, gui_state: &Arc<Mutex<GuiState>>, theme: &Theme) {
+    let gui_state = gui_state.lock();
+    let Some(form) = gui_state.get_rename_form_ref() else {
+        return;
+    };
+    let hint = "( enter ) rename ( esc ) cancel";
+    let text = format!("{}█", form.text);
+    let error = form.error.clone().unwrap_or_default();
+    let title = format!(" rename {} ", form.name);
+    drop(gui_state);
+
+    let size = f.size();
+    // Borders, with a char of padding either side
+    let width = [hint, &text, &error, &title]
+        .iter()
+        .map(|i| i.chars().count() + 4)
+        .max()
+        .unwrap_or_default()
+        .min(usize::from(size.width.saturating_sub(8)));
+    let area = popup(5, width, size, BoxLocation::MiddleCentre);
+    let lines = vec![
+        Line::from(Span::styled(
+            format!(" {text}"),
+            Style::default().add_modifier(Modifier::BOLD),
+        )),
+        Line::from(Span::styled(
+            format!(" {error}"),
+            Style::default().fg(theme.error),
+        )),
+        Line::from(Span::styled(
+            format!(" {hint}"),
+            Style::default().add_modifier(Modifier::DIM),
+        )),
+    ];
+
+    let block = Block::default()
+        .title(title)
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .style(theme.popup());
+
+    f.render_widget(Clear, area);
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+/// Draw the scale form, the replicas being typed, & why they can't be used, once submitted
+/// The title is the service, or the whole stack, that's scaled, ( tab ) is only hinted if the service is in a stack
+pub fn scale_form(f: &mut Frame, gui_state: &Arc<Mutex<GuiState>>, theme: &Theme) {
+    let gui_state = gui_state.lock();
+    let Some(form) = gui_state.get_scale_form_ref() else {
+        return;
+    };
+    let hint = match (form.service.stack.is_some(), form.whole_stack) {
+        (false, _) => "( enter ) scale ( esc ) cancel",
+        (true, false) => "( tab ) whole stack ( enter ) scale ( esc ) cancel",
+        (true, true) => "( tab ) only the service ( enter ) scale ( esc ) cancel",
+    };
+    let text = format!("replicas {}█", form.text);
+    let error = form.error.clone().unwrap_or_default();
+    let title = form.scaled_stack().map_or_else(
+        || {
+            format!(
+                " scale {}, {} running ",
+                form.service.name, form.service.running
+            )
+        },
+        |stack| {
+            format!(
+                " scale stack {stack}, {} replicated service{} ",
+                form.stack.len(),
+                if form.stack.len() == 1 { "" } else { "s" }
+            )
+        },
+    );
+    drop(gui_state);
+
+    let size = f.size();
+    // Borders, with a char of padding either side
+    let width = [hint, &text, &error, &title]
+        .iter()
+        .map(|i| i.chars().count() + 4)
+        .max()
+        .unwrap_or_default()
+        .min(usize::from(size.width.saturating_sub(8)));
+    let area = popup(5, width, size, BoxLocation::MiddleCentre);
+    let lines = vec![
+        Line::from(Span::styled(
+            format!(" {text}"),
+            Style::default().add_modifier(Modifier::BOLD),
+        )),
+        Line::from(Span::styled(
+            format!(" {error}"),
+            Style::default().fg(theme.error),
+        )),
+        Line::from(Span::styled(
+            format!(" {hint}"),
+            Style::default().add_modifier(Modifier::DIM),
+        )),
+    ];
+
+    let block = Block::default()
+        .title(title)
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .style(theme.popup());
+
+    f.render_widget(Clear, area);
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+/// Draw the merged logs of a service's tasks on the host, the newest lines to begin with, scrolled by row
+pub fn task_logs(f: &mut Frame, gui_state: &Arc<Mutex<GuiState>>, theme: &Theme) {
+    let mut gui_state = gui_state.lock();
+    let Some(logs) = gui_state.get_task_logs() else {
+        return;
+    };
+    let size = f.size();
+    let area = popup(
+        usize::from(size.height.saturating_sub(4)),
+        usize::from(size.width.saturating_sub(8)),
+        size,
+        BoxLocation::MiddleCentre,
+    );
+    // Borders, & the hint
+    let height = usize::from(area.height.saturating_sub(3)).max(1);
+    let tasks = format!(
+        "{} task{} on this host",
+        logs.tasks,
+        if logs.tasks == 1 { "" } else { "s" }
+    );
+    let title = format!(" logs of {}, {tasks} ", logs.name);
+    let mut lines = if logs.lines.is_empty() {
+        vec![Line::from(" no logs")]
+    } else {
+        logs.visible(height)
+            .iter()
+            .map(|i| Line::from(format!(" {i}")))
+            .collect::<Vec<_>>()
+    };
+    drop(gui_state);
+    // The hint is kept on the bottom row
+    lines.resize(height, Line::from(""));
+    lines.push(Line::from(Span::styled(
+        " ( ↑ ↓ ) scroll ( esc ) close",
+        Style::default().add_modifier(Modifier::DIM),
+    )));
+
+    let block = Block::default()
+        .title(title)
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .style(theme.popup());
+
+    f.render_widget(Clear, area);
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+/// Draw the kill signal picker, one signal per row, with what it usually does
+pub fn kill_picker(f: &mut Frame, gui_state: &Arc<Mutex<GuiState>>, theme: &Theme) {
+    let Some(picker) = gui_state.lock().get_kill_picker_ref().cloned() else {
+        return;
+    };
+    let hint = "( ↑ ↓ ) select signal ( enter ) kill ( esc ) cancel";
+    let mut lines = Signal::ALL
+        .iter()
+        .enumerate()
+        .map(|(index, signal)| {
+            let text = format!("{:<8} {}", signal.to_string(), signal.description());
+            if index == picker.selected {
+                Line::from(Span::styled(
+                    format!("{RIGHT_ARROW}{text}"),
+                    Style::default().add_modifier(Modifier::BOLD),
+                ))
+            } else {
+                Line::from(format!("  {text}"))
+            }
+        })
+        .collect::<Vec<_>>();
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        format!("  {hint}"),
+        Style::default().add_modifier(Modifier::DIM),
+    )));
+    let title = format!(" kill {} ", picker.name);
+
+    let size = f.size();
+    let width = lines
+        .iter()
+        .map(|i| i.width() + 4)
+        .chain([title.chars().count() + 4])
+        .max()
+        .unwrap_or_default()
+        .min(usize::from(size.width.saturating_sub(8)));
+    let area = popup(
+        (lines.len() + 2).min(usize::from(size.height.saturating_sub(4))),
+        width,
+        size,
+        BoxLocation::MiddleCentre,
+    );
+    let block = Block::default()
+        .title(title)
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .style(theme.popup());
+
+    f.render_widget(Clear, area);
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+/// Draw the capture pane, the filter being typed, or once started, the latest packets, below the pcap file & the totals
+pub fn capture(f: &mut Frame, gui_state: &Arc<Mutex<GuiState>>, theme: &Theme) {
+    let Some(capture) = gui_state.lock().get_capture_ref().cloned() else {
+        return;
+    };
+    let dim = Style::default().add_modifier(Modifier::DIM);
+    let bold = Style::default().add_modifier(Modifier::BOLD);
+    let size = f.size();
+    let title = format!(" capture of {} ", capture.name);
+    let mut lines = vec![];
+    if capture.is_started() {
+        let filter = if capture.filter.is_empty() {
+            "every packet"
+        } else {
+            &capture.filter
+        };
+        lines.push(Line::from(vec![
+            Span::styled(" filter ", dim),
+            Span::from(filter.to_owned()),
+        ]));
+        if let Some(path) = &capture.path {
+            lines.push(Line::from(vec![
+                Span::styled(" saving ", dim),
+                Span::from(path.display().to_string()),
+            ]));
+        }
+        lines.push(Line::from(Span::styled(
+            format!(
+                " {} packets, {}",
+                capture.packets,
+                ByteStats::new(capture.bytes)
+            ),
+            bold,
+        )));
+        lines.push(Line::from(Span::styled(
+            format!(
+                " {}",
+                capture.status.as_deref().unwrap_or("starting tcpdump")
+            ),
+            dim,
+        )));
+        // Borders, the lines above, & the hint
+        let rows = usize::from(size.height.saturating_sub(4)).saturating_sub(lines.len() + 3);
+        let skip = capture.lines.len().saturating_sub(rows);
+        lines.extend(
+            capture
+                .lines
+                .iter()
+                .skip(skip)
+                .map(|i| Line::from(format!(" {i}"))),
+        );
+        lines.push(Line::from(Span::styled(
+            " ( esc ) stop, & save the pcap",
+            dim,
+        )));
+    } else {
+        lines.push(Line::from(Span::styled(
+            format!(" {}█", capture.filter),
+            bold,
+        )));
+        lines.push(Line::from(
+            " a tcpdump filter, e.g. port 5432, empty captures every packet",
+        ));
+        lines.push(Line::from(Span::styled(
+            " ( enter ) start ( esc ) close",
+            dim,
+        )));
+    }
+    let width = lines
+        .iter()
+        .map(Line::width)
+        .chain([title.chars().count()])
+        .max()
+        .unwrap_or_default()
+        + 3;
+    let area = popup(
+        (lines.len() + 2).min(usize::from(size.height.saturating_sub(4))),
+        width.min(usize::from(size.width.saturating_sub(8))),
+        size,
+        BoxLocation::MiddleCentre,
+    );
+    let block = Block::default()
+        .title(title)
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .style(theme.popup());
+
+    f.render_widget(Clear, area);
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+/// Draw the limits form, the cpus & memory fields, with a cursor in the selected field, & why the limits couldn't be parsed
+pub fn limits_form(f: &mut Frame, gui_state: &Arc<Mutex<GuiState>>, theme: &Theme) {
+    let gui_state = gui_state.lock();
+    let Some(form) = gui_state.get_limits_form_ref() else {
+        return;
+    };
+    let hint = "( tab ) switch field ( enter ) update ( esc ) cancel";
+    let field = |label: &str, value: &str, selected: bool| {
+        let cursor = if selected { "█" } else { "" };
+        format!(" {label:<7}{value}{cursor}")
+    };
+    let cpus = field("cpus", &form.cpus, !form.memory_selected);
+    let memory = field("memory", &form.memory, form.memory_selected);
+    let note = form
+        .error
+        .as_ref()
+        .map_or(("an empty field is left unchanged", theme.popup_fg), |i| {
+            (i.as_str(), theme.error)
+        });
+    let note = (format!(" {}", note.0), note.1);
+    let title = format!(" limits of {} ", form.name);
+    let memory_selected = form.memory_selected;
+    drop(gui_state);
+
+    let size = f.size();
+    // Borders, with a char of padding either side
+    let width = [hint, &cpus, &memory, &note.0, &title]
+        .iter()
+        .map(|i| i.chars().count() + 4)
+        .max()
+        .unwrap_or_default()
+        .min(usize::from(size.width.saturating_sub(8)));
+    let area = popup(6, width, size, BoxLocation::MiddleCentre);
+    let style = |selected: bool| {
+        if selected {
+            Style::default().add_modifier(Modifier::BOLD)
+        } else {
+            Style::default()
+        }
+    };
+    let lines = vec![
+        Line::from(Span::styled(cpus, style(!memory_selected))),
+        Line::from(Span::styled(memory, style(memory_selected))),
+        Line::from(Span::styled(note.0, Style::default().fg(note.1))),
+        Line::from(Span::styled(
+            format!(" {hint}"),
+            Style::default().add_modifier(Modifier::DIM),
+        )),
+    ];
+
+    let block = Block::default()
+        .title(title)
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .style(theme.popup());
+
+    f.render_widget(Clear, area);
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+/// Draw the failure of a control, the daemon's error, above the container's recent attempts, newest first, & when it can be retried
+pub fn failed(
+    f: &mut Frame,
+    app_data: &Arc<Mutex<AppData>>,
+    gui_state: &Arc<Mutex<GuiState>>,
+    now: u64,
+    theme: &Theme,
+) {
+    let Some(failed) = gui_state.lock().get_failed_ref().cloned() else {
+        return;
+    };
+    let Some((name, attempts)) = app_data
+        .lock()
+        .get_attempts(&failed.id, failed.host.as_deref())
+    else {
+        return;
+    };
+    let error = attempts
+        .last_failure()
+        .and_then(|i| i.error.clone())
+        .unwrap_or_default();
+    // The backoff is only known before the retry, after which it's counting down to the retry
+    let hint = failed.retry_at.map_or_else(
+        || {
+            format!(
+                "( r ) retry, after {}s ( esc ) close",
+                attempts.backoff(failed.control)
+            )
+        },
+        |at| {
+            format!(
+                "retrying in {}s ( esc ) close",
+                at.saturating_duration_since(Instant::now()).as_secs()
+            )
+        },
+    );
+    let history = attempts
+        .iter()
+        .rev()
+        .map(|i| {
+            let ago = format!("{} ago", format_duration(now.saturating_sub(i.time)));
+            let outcome = i
+                .error
+                .as_ref()
+                .map_or_else(|| "succeeded".to_owned(), |e| format!("failed: {e}"));
+            (format!("{ago:<12}"), format!("{} {outcome}", i.control))
+        })
+        .collect::<Vec<_>>();
+    let title = format!(" {} {} failed ", failed.control, name.get());
+
+    let size = f.size();
+    // Borders, with a char of padding either side
+    let width = history
+        .iter()
+        .map(|(ago, text)| ago.chars().count() + text.chars().count())
+        .chain([&error, &hint, &title].iter().map(|i| i.chars().count()))
+        .max()
+        .unwrap_or_default()
+        .saturating_add(4)
+        .min(usize::from(size.width.saturating_sub(8)));
+    let area = popup(history.len() + 6, width, size, BoxLocation::MiddleCentre);
+
+    let mut lines = vec![
+        Line::from(Span::styled(
+            format!(" {error}"),
+            Style::default()
+                .fg(theme.error)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+    ];
+    lines.extend(history.into_iter().map(|(ago, text)| {
+        Line::from(vec![
+            Span::styled(
+                format!(" {ago}"),
+                Style::default().add_modifier(Modifier::DIM),
+            ),
+            Span::from(text),
+        ])
+    }));
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        format!(" {hint}"),
+        Style::default().add_modifier(Modifier::DIM),
+    )));
+
+    let block = Block::default()
+        .title(title)
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .style(theme.popup());
+
+    f.render_widget(Clear, area);
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+/// A field of the command form, the command as a prompt, the options with their labels, the selected field, with its cursor, in bold
+fn command_field(form: &CommandForm, field: CommandField) -> Line<'static> {
+    let selected = form.field == field;
+    let cursor = match (selected, field) {
+        (false, _) => "",
+        (true, CommandField::Shell) => "  ( space ) toggle",
+        (true, _) => "█",
+    };
+    let value = match field {
+        CommandField::Command => format!(" $ {}{cursor}", form.entry.command),
+        CommandField::User => form.entry.user.clone(),
+        CommandField::WorkingDir => form.entry.working_dir.clone(),
+        CommandField::Env => form.entry.env.clone(),
+        CommandField::Shell => {
+            if form.entry.shell {
+                "yes".to_owned()
+            } else {
+                "no, exec'd as is".to_owned()
+            }
+        }
+    };
+    if field == CommandField::Command {
+        return Line::from(Span::styled(
+            value,
+            Style::default().add_modifier(Modifier::BOLD),
+        ));
+    }
+    let marker = if selected { RIGHT_ARROW } else { "  " };
+    let text = format!(" {marker}{:<10}{value}", field.label());
+    if selected {
+        Line::from(Span::styled(
+            format!("{text}{cursor}"),
+            Style::default().add_modifier(Modifier::BOLD),
+        ))
+    } else {
+        Line::from(text)
+    }
+}
+
+/// Draw the command form, the command being typed, & the options of the exec, above the image's earlier commands, most recent first, with the selected field, & the recalled command, marked
+pub fn command_form(f: &mut Frame, gui_state: &Arc<Mutex<GuiState>>, theme: &Theme) {
+    let gui_state = gui_state.lock();
+    let Some(form) = gui_state.get_command_form_ref() else {
+        return;
+    };
+    let size = f.size();
+    let error = usize::from(form.error.is_some());
+    let height = if form.history.is_empty() {
+        9 + error
+    } else {
+        form.history.len() + 11 + error
+    };
+    let area = popup(
+        height.min(usize::from(size.height.saturating_sub(4))),
+        usize::from(size.width.saturating_sub(8)).min(96),
+        size,
+        BoxLocation::MiddleCentre,
+    );
+    // Borders, the command, options & blank lines above, the heading, and the blank & key hint lines below
+    let rows = usize::from(
+        area.height
+            .saturating_sub(11 + u16::from(form.error.is_some())),
+    )
+    .max(1);
+    let start = form.recalled.unwrap_or_default().saturating_sub(rows - 1);
+
+    let bold = Style::default().add_modifier(Modifier::BOLD);
+    let dim = Style::default().add_modifier(Modifier::DIM);
+    let mut lines = CommandField::ALL
+        .into_iter()
+        .map(|field| command_field(form, field))
+        .collect::<Vec<_>>();
+    if let Some(error) = form.error.as_ref() {
+        lines.push(Line::from(Span::styled(
+            format!(" {error}"),
+            Style::default().fg(theme.error),
+        )));
+    }
+    lines.push(Line::from(""));
+    if !form.history.is_empty() {
+        lines.push(Line::from(Span::styled(" earlier commands", dim)));
+        lines.extend(form.history.iter().enumerate().skip(start).take(rows).map(
+            |(index, entry)| {
+                let flags = entry.flags();
+                let flags = if flags.is_empty() {
+                    String::new()
+                } else {
+                    format!("  [{flags}]")
+                };
+                if form.recalled == Some(index) {
+                    Line::from(Span::styled(
+                        format!(" {RIGHT_ARROW}{}{flags}", entry.command),
+                        bold,
+                    ))
+                } else {
+                    Line::from(vec![
+                        Span::from(format!("   {}", entry.command)),
+                        Span::styled(flags, dim),
+                    ])
+                }
+            },
+        ));
+        lines.push(Line::from(""));
+    }
+    lines.push(Line::from(Span::styled(
+        " ( ↑ ↓ ) recall a command ( tab ) next option ( enter ) run ( esc ) cancel",
+        dim,
+    )));
+    let title = format!(" run a command in {} ", form.name);
+    drop(gui_state);
+
+    let block = Block::default()
+        .title(title)
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .style(theme.popup());
+
+    f.render_widget(Clear, area);
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+/// Draw the log lines that matched a `--watch`, newest at the bottom, scrolled so that the selected match is shown
+/// The lines logged around the selected match can be shown below the matches, the match itself highlighted
+pub fn log_matches(f: &mut Frame, app_data: &Arc<Mutex<AppData>>, view: MatchView, theme: &Theme) {
+    let size = f.size();
+    let max_height = usize::from(size.height.saturating_sub(4));
+    let app_data = app_data.lock();
+    let matches = app_data.get_log_matches();
+    let selected = view.selected(matches.len());
+    let context = view.context.and_then(|lines| {
+        selected.and_then(|i| {
+            let found = &matches[i];
+            app_data
+                .get_log_context(found, lines)
+                .zip(found.index)
+                .map(|((items, index), line)| {
+                    (
+                        format!(" {} - line {}, ±{lines} lines ", found.name, line + 1),
+                        items,
+                        index,
+                    )
+                })
+        })
+    });
+
+    let height = if context.is_some() {
+        max_height
+    } else {
+        (matches.len() + 4).min(max_height)
+    };
+    let area = popup(
+        height,
+        usize::from(size.width.saturating_sub(8)),
+        size,
+        BoxLocation::MiddleCentre,
+    );
+    // Borders, and the blank & key hint lines, a third of what's left is given to the matches when the context is shown
+    let inner = usize::from(area.height.saturating_sub(4)).max(1);
+    let rows = if context.is_some() {
+        (inner / 3).clamp(1, matches.len().max(1))
+    } else {
+        inner
+    };
+    let skip = matches
+        .len()
+        .saturating_sub(rows)
+        .min(selected.unwrap_or_default());
+
+    let mut lines = matches
+        .iter()
+        .enumerate()
+        .skip(skip)
+        .take(rows)
+        .map(|(index, i)| {
+            let line = Line::from(vec![
+                Span::styled(
+                    format!("{} ", i.name),
+                    Style::default().add_modifier(Modifier::BOLD),
+                ),
+                Span::raw(i.line.clone()),
+            ]);
+            if Some(index) == selected {
+                line.patch_style(Style::default().add_modifier(Modifier::REVERSED))
+            } else {
+                line
+            }
+        })
+        .collect::<Vec<_>>();
+    if lines.is_empty() {
+        lines.push(Line::from("no matches"));
+    }
+    let title = format!(" log matches, {} ", matches.len());
+    drop(app_data);
+
+    let hint = if context.is_some() {
+        "( + - ) lines ( enter ) hide ( a ) close"
+    } else {
+        "( ↑ ↓ ) select ( enter ) context ( a ) close"
+    };
+    let block = Block::default()
+        .title(title)
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .style(Style::default().bg(theme.warning).fg(theme.popup_fg));
+
+    let split = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(u16::try_from(lines.len()).unwrap_or_default()),
+            Constraint::Min(0),
+            Constraint::Length(1),
+        ])
+        .split(block.inner(area));
+
+    f.render_widget(Clear, area);
+    f.render_widget(block, area);
+    f.render_widget(Paragraph::new(lines), split[0]);
+    f.render_widget(
+        Paragraph::new(hint).style(Style::default().add_modifier(Modifier::DIM)),
+        split[2],
+    );
+
+    if let Some((title, items, index)) = context {
+        log_context(f, split[1], title, items, index);
+    }
+}
+
+/// Draw the lines logged around a match, leaving a blank line below, the match is kept in the middle when all the lines don't fit
+fn log_context(
+    f: &mut Frame,
+    area: Rect,
+    title: String,
+    items: Vec<ListItem<'static>>,
+    index: usize,
+) {
+    let area = Rect {
+        height: area.height.saturating_sub(1),
+        ..area
+    };
+    let block = Block::default()
+        .title(title)
+        .borders(Borders::TOP)
+        .style(Style::default().bg(Color::Reset).fg(Color::Reset));
+    let height = usize::from(block.inner(area).height);
+    let mut state = ListState::default()
+        .with_selected(Some(index))
+        .with_offset(index.saturating_sub(height / 2));
+    let items = List::new(items)
+        .block(block)
+        .highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+    f.render_widget(Clear, area);
+    f.render_stateful_widget(items, area, &mut state);
+}
+
+/// Draw the primary daemon's details, & the warnings it reports, which explain why some container limits are ignored
+pub fn daemon(f: &mut Frame, app_data: &Arc<Mutex<AppData>>, theme: &Theme) {
+    let info = app_data.lock().get_daemon_info().cloned();
+    let mut lines = vec![];
+    if let Some(info) = info {
+        let os = match (&info.os, &info.kernel) {
+            (Some(os), Some(kernel)) => Some(format!("{os}, kernel {kernel}")),
+            (Some(os), None) => Some(os.clone()),
+            (None, Some(kernel)) => Some(format!("kernel {kernel}")),
+            (None, None) => None,
+        };
+        for (label, value) in [
+            ("name", info.name),
+            ("version", info.version),
+            ("os", os),
+            ("storage", info.storage_driver),
+            ("cgroup", info.cgroup),
+        ] {
+            if let Some(value) = value {
+                lines.push(Line::from(vec![
+                    Span::styled(
+                        format!("  {label:<9}"),
+                        Style::default().add_modifier(Modifier::DIM),
+                    ),
+                    Span::from(value),
+                ]));
+            }
+        }
+        lines.push(Line::from(""));
+        if info.warnings.is_empty() {
+            lines.push(Line::from("  no warnings"));
+        } else {
+            lines.push(Line::from(Span::styled(
+                match info.warnings.len() {
+                    1 => "  1 warning".to_owned(),
+                    x => format!("  {x} warnings"),
+                },
+                Style::default().add_modifier(Modifier::BOLD),
+            )));
+            for warning in info.warnings {
+                lines.push(Line::from(vec![
+                    Span::styled("  ! ", Style::default().fg(theme.error)),
+                    Span::from(warning),
+                ]));
+            }
+        }
+    } else {
+        lines.push(Line::from("  waiting for the daemon's details"));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        "  ( esc ) close",
+        Style::default().add_modifier(Modifier::DIM),
+    )));
+
+    let size = f.size();
+    let width = lines.iter().map(Line::width).max().unwrap_or_default() + 4;
+    let area = popup(
+        (lines.len() + 2).min(usize::from(size.height.saturating_sub(4))),
+        width.min(usize::from(size.width.saturating_sub(8))),
+        size,
+        BoxLocation::MiddleCentre,
+    );
+
+    let block = Block::default()
+        .title(" docker daemon ")
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .style(theme.popup());
+
+    f.render_widget(Clear, area);
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+/// Draw the details of an inspected image, from which it can be pulled, or removed
+pub fn image_detail(f: &mut Frame, gui_state: &Arc<Mutex<GuiState>>, theme: &Theme) {
+    let Some(detail) = gui_state.lock().get_image_detail_ref().cloned() else {
+        return;
+    };
+    let join = |values: &[String]| Some(values.join(" ")).filter(|i| !i.is_empty());
+    let tags = detail.image.tags.join(", ");
+    let containers = detail.image.containers.join(", ");
+    let mut lines = vec![];
+    for (label, value) in [
+        ("id", Some(detail.image.short_id().to_owned())),
+        ("tags", Some(tags).filter(|i| !i.is_empty())),
+        ("size", Some(detail.image.size.to_string())),
+        ("platform", detail.platform.clone()),
+        ("layers", Some(detail.layers.to_string())),
+        ("entrypoint", join(&detail.entrypoint)),
+        ("cmd", join(&detail.cmd)),
+        ("workdir", detail.working_dir.clone()),
+        ("user", detail.user.clone()),
+        (
+            "ports",
+            Some(detail.exposed_ports.join(", ")).filter(|i| !i.is_empty()),
+        ),
+        (
+            "env",
+            Some(detail.env.len().to_string()).filter(|_| !detail.env.is_empty()),
+        ),
+        ("used by", Some(containers).filter(|i| !i.is_empty())),
+    ] {
+        if let Some(value) = value {
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!("  {label:<12}"),
+                    Style::default().add_modifier(Modifier::DIM),
+                ),
+                Span::from(value),
+            ]));
+        }
+    }
+    if detail.image.is_dangling() {
+        lines.push(Line::from(vec![
+            Span::styled("  ! ", Style::default().fg(theme.error)),
+            Span::from("dangling, no tag refers to this image"),
+        ]));
+    }
+    lines.push(Line::from(""));
+    let hint = if detail.armed {
+        format!(
+            "  ( r ) again to remove {} ( esc ) cancel",
+            detail.image.name()
+        )
+    } else if let Some(tag) = detail.pull_tag() {
+        format!("  ( p ) pull {tag} ( r ) remove ( esc ) close")
+    } else {
+        "  ( r ) remove ( esc ) close".to_owned()
+    };
+    lines.push(Line::from(Span::styled(
+        hint,
+        if detail.armed {
+            Style::default()
+                .fg(theme.error)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().add_modifier(Modifier::DIM)
+        },
+    )));
+
+    let size = f.size();
+    let width = lines.iter().map(Line::width).max().unwrap_or_default() + 4;
+    let area = popup(
+        (lines.len() + 2).min(usize::from(size.height.saturating_sub(4))),
+        width.min(usize::from(size.width.saturating_sub(8))),
+        size,
+        BoxLocation::MiddleCentre,
+    );
+
+    let block = Block::default()
+        .title(format!(" image {} ", detail.image.name()))
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .style(theme.popup());
+
+    f.render_widget(Clear, area);
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+/// Draw the docker inspect tree of a container, objects & arrays show their size, and can be expanded
+pub fn inspect_view(f: &mut Frame, gui_state: &Arc<Mutex<GuiState>>, theme: &Theme) {
+    let gui_state = gui_state.lock();
+    let Some(view) = gui_state.get_inspect_view_ref() else {
+        return;
+    };
+    let size = f.size();
+    let area = popup(
+        usize::from(size.height.saturating_sub(4)),
+        usize::from(size.width.saturating_sub(8)),
+        size,
+        BoxLocation::MiddleCentre,
+    );
+    // Borders, and the blank & key hint lines
+    let height = usize::from(area.height.saturating_sub(4)).max(1);
+    let start = view.selected.saturating_sub(height - 1);
+    let dim = Style::default().add_modifier(Modifier::DIM);
+
+    let mut lines = view
+        .rows
+        .iter()
+        .enumerate()
+        .skip(start)
+        .take(height)
+        .map(|(index, row)| {
+            let marker = match row.expanded {
+                Some(true) => "▾ ",
+                Some(false) => "▸ ",
+                None => "  ",
+            };
+            let line = Line::from(vec![
+                Span::from(format!("{}{marker}", "  ".repeat(row.depth))),
+                Span::styled(
+                    format!("{}: ", row.key),
+                    Style::default().add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    row.value.clone(),
+                    if row.expanded.is_some() {
+                        dim
+                    } else {
+                        Style::default()
+                    },
+                ),
+            ]);
+            if index == view.selected {
+                line.patch_style(Style::default().add_modifier(Modifier::REVERSED))
+            } else {
+                line
+            }
+        })
+        .collect::<Vec<_>>();
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        "( ↑ ↓ ) move ( ← → ) collapse, expand ( esc ) close",
+        dim,
+    )));
+
+    let block = Block::default()
+        .title(format!(" inspect {} ", view.name))
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .style(theme.popup());
+    drop(gui_state);
+
+    f.render_widget(Clear, area);
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+/// A row of the comparison, the key, then the value of each container, padded to the width of its column, a value that isn't set is shown as `-`
+fn diff_row(row: &DiffRow, key_width: usize, value_width: usize, theme: &Theme) -> Line<'static> {
+    let dim = Style::default().add_modifier(Modifier::DIM);
+    let changed = row.changed();
+    let value = |value: Option<&String>| {
+        let text = format!(
+            "{:<value_width$}",
+            truncate_text(value.map_or("-", String::as_str), value_width)
+        );
+        match (value, changed) {
+            (None, _) => Span::styled(text, dim),
+            (Some(_), true) => Span::styled(text, Style::default().add_modifier(Modifier::BOLD)),
+            (Some(_), false) => Span::from(text),
+        }
+    };
+    let line = Line::from(vec![
+        Span::from(if changed { " ≠ " } else { "   " }),
+        Span::from(format!(
+            "{:<key_width$}  ",
+            truncate_text(&row.key, key_width)
+        )),
+        value(row.left.as_ref()),
+        Span::from("  "),
+        value(row.right.as_ref()),
+    ]);
+    if changed {
+        line.patch_style(Style::default().fg(theme.highlight))
+    } else {
+        line
+    }
+}
+
+/// Draw the comparison of two containers, each setting, of both containers, side by side, the settings that differ are highlighted
+pub fn inspect_diff(f: &mut Frame, gui_state: &Arc<Mutex<GuiState>>, theme: &Theme) {
+    let gui_state = gui_state.lock();
+    let Some(diff) = gui_state.get_inspect_diff_ref() else {
+        return;
+    };
+    let size = f.size();
+    let area = popup(
+        usize::from(size.height.saturating_sub(4)),
+        usize::from(size.width.saturating_sub(8)),
+        size,
+        BoxLocation::MiddleCentre,
+    );
+    let dim = Style::default().add_modifier(Modifier::DIM);
+    let bold = Style::default().add_modifier(Modifier::BOLD);
+    // Borders, the column headings, and the blank & key hint lines
+    let height = usize::from(area.height.saturating_sub(5)).max(1);
+    let width = usize::from(area.width.saturating_sub(2));
+    let key_width = diff
+        .rows
+        .iter()
+        .map(|i| i.key.chars().count())
+        .max()
+        .unwrap_or_default()
+        .clamp(8, 24);
+    // The marker, & the gaps between the columns
+    let value_width = width.saturating_sub(key_width + 7) / 2;
+
+    let rows = diff.lines();
+    let start = diff.offset.min(rows.len().saturating_sub(height));
+    let mut lines = vec![Line::from(Span::styled(
+        format!(
+            "   {:<key_width$}  {:<value_width$}  {}",
+            "",
+            truncate_text(&diff.left, value_width),
+            truncate_text(&diff.right, value_width)
+        ),
+        bold,
+    ))];
+    lines.extend(rows.iter().skip(start).take(height).map(|i| match i {
+        DiffLine::Section(section) => {
+            Line::from(Span::styled(format!(" {}", section.label()), bold))
+        }
+        DiffLine::Row(row) => diff_row(row, key_width, value_width, theme),
+    }));
+    if rows.is_empty() {
+        lines.push(Line::from("   every setting is the same"));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        if diff.only_changed {
+            "   ( ↑ ↓ ) scroll ( space ) show every setting ( esc ) close"
+        } else {
+            "   ( ↑ ↓ ) scroll ( space ) only show differences ( esc ) close"
+        },
+        dim,
+    )));
+
+    let block = Block::default()
+        .title(format!(
+            " compare {} & {} - {}/{} settings differ ",
+            diff.left,
+            diff.right,
+            diff.changed(),
+            diff.rows.len()
+        ))
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .style(theme.popup());
+    drop(gui_state);
+
+    f.render_widget(Clear, area);
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+/// Width of each layer's progress bar, in the pull progress popup
+const PULL_BAR_WIDTH: usize = 20;
+
+/// A layer's progress bar, as the done, & not done, parts of the bar, a layer without progress is either complete, or not yet started
+fn pull_bar(layer: &PullLayer) -> (String, String) {
+    let done = match layer.progress {
+        Some((current, total)) => {
+            usize::try_from(current.min(total).saturating_mul(PULL_BAR_WIDTH as u64) / total.max(1))
+                .unwrap_or_default()
+        }
+        None if layer.status.ends_with("complete") || layer.status == "Already exists" => {
+            PULL_BAR_WIDTH
+        }
+        None => 0,
+    };
+    (
+        "━".repeat(done),
+        "─".repeat(PULL_BAR_WIDTH.saturating_sub(done)),
+    )
+}
+
+/// Draw the progress of the image being pulled, each layer's progress & speed, and the combined progress, speed, & eta
+pub fn pull_progress(f: &mut Frame, gui_state: &Arc<Mutex<GuiState>>, now: Instant, theme: &Theme) {
+    let Some(progress) = gui_state.lock().get_pull_progress_ref().cloned() else {
+        return;
+    };
+    let dim = Style::default().add_modifier(Modifier::DIM);
+    let mut lines = vec![Line::from(Span::styled(
+        format!(
+            "  {}",
+            progress
+                .status
+                .as_deref()
+                .unwrap_or("waiting for the daemon")
+        ),
+        dim,
+    ))];
+    for layer in &progress.layers {
+        let (done, not_done) = pull_bar(layer);
+        let color = if layer.is_downloading() {
+            theme.ok
+        } else {
+            theme.highlight
+        };
+        let mut spans = vec![
+            Span::from(format!("  {:<12} {:<18} ", layer.id, layer.status)),
+            Span::styled(done, Style::default().fg(color)),
+            Span::styled(not_done, dim),
+        ];
+        if let Some((current, total)) = layer.progress {
+            spans.push(Span::from(format!(
+                " {} / {}",
+                ByteStats::new(current),
+                ByteStats::new(total)
+            )));
+        }
+        if let Some(speed) = layer.speed() {
+            spans.push(Span::styled(format!("  {}/s", ByteStats::new(speed)), dim));
+        }
+        lines.push(Line::from(spans));
+    }
+    lines.push(Line::from(""));
+    let (downloaded, total) = progress.downloaded();
+    let mut summary = vec![format!(
+        "  {} / {} downloaded",
+        ByteStats::new(downloaded),
+        ByteStats::new(total)
+    )];
+    if let Some(speed) = progress.speed(now) {
+        summary.push(format!("{}/s", ByteStats::new(speed)));
+    }
+    if let Some(eta) = progress.eta(now) {
+        summary.push(format!("eta {}", format_duration(eta.as_secs())));
+    }
+    summary.push(format!(
+        "{} elapsed",
+        format_duration(progress.elapsed(now).as_secs())
+    ));
+    lines.push(Line::from(summary.join(", ")));
+    lines.push(Line::from(Span::styled("  ( esc ) cancel", dim)));
+
+    let size = f.size();
+    let width = lines.iter().map(Line::width).max().unwrap_or_default() + 4;
+    let area = popup(
+        (lines.len() + 2).min(usize::from(size.height.saturating_sub(4))),
+        width.min(usize::from(size.width.saturating_sub(8))),
+        size,
+        BoxLocation::MiddleCentre,
+    );
+    let title = progress.host.as_ref().map_or_else(
+        || format!(" pulling {} ", progress.image),
+        |host| format!(" pulling {} - {host} ", progress.image),
+    );
+    let block = Block::default()
+        .title(title)
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .style(theme.popup());
+
+    f.render_widget(Clear, area);
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+/// Draw the memory of the selected container broken down by type, each with its share of the usage
+/// The page cache is counted in the usage, so the anonymous memory is what's actually held by the processes
+#[allow(clippy::too_many_lines)]
+pub fn memory(f: &mut Frame, app_data: &Arc<Mutex<AppData>>, theme: &Theme) {
+    let (name, memory) = app_data
+        .lock()
+        .get_selected_container()
+        .map(|i| (i.name.to_string(), i.memory))
+        .unzip();
+    let memory = memory.flatten();
+    let dim = Style::default().add_modifier(Modifier::DIM);
+
+    let mut lines = vec![];
+    if let Some(memory) = memory {
+        let usage = memory.usage.get();
+        let row = |label: &str, value: ByteStats, desc: &str| {
+            let share = value
+                .get()
+                .saturating_mul(100)
+                .checked_div(usage)
+                .unwrap_or_default();
+            Line::from(vec![
+                Span::from(format!(
+                    "  {label:<16}{:>10} {share:>3}%  ",
+                    value.to_string().trim()
+                )),
+                Span::styled(desc.to_owned(), dim),
+            ])
+        };
+        let limit = if memory.limit.get() == 0 {
+            String::new()
+        } else {
+            format!("of {} limit", memory.limit.to_string().trim())
+        };
+        lines.push(row("usage", memory.usage, &limit));
+        lines.push(row(
+            "working set",
+            memory.working_set(),
+            "without the inactive file cache, as in the memory column",
+        ));
+        lines.push(Line::from(""));
+        let (anon, file) = if memory.v2 {
+            ("anonymous", "file")
+        } else {
+            ("anonymous (rss)", "file (cache)")
+        };
+        lines.push(row(
+            anon,
+            memory.anon,
+            "heaps & stacks, only freed by the processes, or swapped",
+        ));
+        lines.push(row(
+            file,
+            memory.file,
+            "page cache, reclaimed when memory is needed",
+        ));
+        lines.push(row(
+            "  active",
+            memory.active_file,
+            "recently used, reclaimed after the inactive",
+        ));
+        lines.push(row("  inactive", memory.inactive_file, "reclaimed first"));
+        lines.push(row(
+            "  mapped",
+            memory.mapped_file,
+            "memory mapped files, such as shared libraries",
+        ));
+        lines.push(row(
+            "  shared",
+            memory.shmem,
+            "tmpfs & shared memory, can't be reclaimed",
+        ));
+        if let Some(kernel) = memory.kernel {
+            lines.push(row(
+                "kernel",
+                kernel,
+                "kernel stacks, slab, & socket buffers",
+            ));
+        }
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            format!("  cgroup {}", if memory.v2 { "v2" } else { "v1" }),
+            dim,
+        )));
+    } else {
+        lines.push(Line::from(
+            "  no memory stats, the container isn't running, or has no cgroup memory stats",
+        ));
+    }
+    lines.push(Line::from(Span::styled("  ( esc ) close", dim)));
+
+    let size = f.size();
+    let width = lines.iter().map(Line::width).max().unwrap_or_default() + 4;
+    let area = popup(
+        (lines.len() + 2).min(usize::from(size.height.saturating_sub(4))),
+        width.min(usize::from(size.width.saturating_sub(8))),
+        size,
+        BoxLocation::MiddleCentre,
+    );
+
+    let block = Block::default()
+        .title(name.map_or_else(|| " memory ".to_owned(), |i| format!(" memory {i} ")))
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .style(theme.popup());
+
+    f.render_widget(Clear, area);
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+/// Width of the bar of each core
+const CORE_BAR: usize = 10;
+
+/// Draw the cpu usage of the selected container on each core of the host, as a bar each, in columns, so that a single saturated core stands out
+pub fn cores(f: &mut Frame, app_data: &Arc<Mutex<AppData>>, theme: &Theme) {
+    let (name, cores) = app_data
+        .lock()
+        .get_selected_container()
+        .map(|i| (i.name.to_string(), i.cores.clone()))
+        .unzip();
+    let cores = cores.flatten().filter(|i| !i.cores.is_empty());
+    let dim = Style::default().add_modifier(Modifier::DIM);
+    let size = f.size();
+
+    let mut lines = vec![];
+    if let Some(cores) = cores {
+        let label_width = (cores.cores.len() - 1).to_string().len();
+        // Each cell is `cpu n`, the bar, & the usage, as many columns as fit, filled top to bottom
+        let cell_width = label_width + CORE_BAR + 15;
+        let columns = (usize::from(size.width.saturating_sub(12)) / cell_width).clamp(1, 4);
+        let rows = cores.cores.len().div_ceil(columns);
+        for row in 0..rows {
+            let mut spans = vec![];
+            for (index, usage) in cores.cores.iter().enumerate().skip(row).step_by(rows) {
+                #[allow(
+                    clippy::cast_possible_truncation,
+                    clippy::cast_precision_loss,
+                    clippy::cast_sign_loss
+                )]
+                let filled =
+                    ((usage.get_value() / 100.0 * CORE_BAR as f64).round() as usize).min(CORE_BAR);
+                spans.push(Span::from(format!("  cpu {index:<label_width$} ")));
+                spans.push(Span::styled(
+                    "█".repeat(filled),
+                    Style::default().fg(theme.chart_cpu),
+                ));
+                spans.push(Span::styled("·".repeat(CORE_BAR - filled), dim));
+                spans.push(Span::from(format!(" {usage:>7}")));
+            }
+            lines.push(Line::from(spans));
+        }
+        lines.push(Line::from(""));
+        if let Some((index, busiest)) = cores.busiest() {
+            let total = cores.total();
+            lines.push(Line::from(format!(
+                "  busiest cpu {index} at {}, {} in total, over {} cores",
+                busiest.to_string().trim(),
+                total.to_string().trim(),
+                cores.cores.len()
+            )));
+            // The rest of the cores together are less than half of a core, so the load is held by a single thread
+            if busiest.get_value() >= 90.0 && total.get_value() - busiest.get_value() < 50.0 {
+                lines.push(Line::from(Span::styled(
+                    "  one core is saturated, the rest are idle, likely a single threaded bottleneck",
+                    Style::default().fg(theme.error),
+                )));
+            }
+        }
+    } else {
+        lines.push(Line::from(
+            "  no per core usage, the container isn't running, or the daemon doesn't report it, as with cgroup v2",
+        ));
+    }
+    lines.push(Line::from(Span::styled("  ( esc ) close", dim)));
+
+    let width = lines.iter().map(Line::width).max().unwrap_or_default() + 4;
+    let area = popup(
+        (lines.len() + 2).min(usize::from(size.height.saturating_sub(4))),
+        width.min(usize::from(size.width.saturating_sub(8))),
+        size,
+        BoxLocation::MiddleCentre,
+    );
+
+    let block = Block::default()
+        .title(name.map_or_else(|| " cpu cores ".to_owned(), |i| format!(" cpu cores {i} ")))
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .style(theme.popup());
+
+    f.render_widget(Clear, area);
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+/// Draw the sizes of the panes, the selected pane is resized, or hidden, & the layout can be saved to the config file
+pub fn layout(f: &mut Frame, layout: PaneLayout, pane: Pane, theme: &Theme) {
+    let rows = [
+        (
+            Pane::Containers,
+            layout.containers.map_or_else(
+                || "containers  sized to the containers".to_owned(),
+                |i| format!("containers  {i} rows"),
+            ),
+        ),
+        (
+            Pane::Charts,
+            if layout.charts_hidden {
+                "charts      hidden".to_owned()
+            } else {
+                format!("charts      {}%", layout.charts)
+            },
+        ),
+    ];
+    let hint =
+        "  ( ↑ ↓ ) select  ( ← → ) resize  ( space ) hide  ( r ) reset  ( s ) save  ( esc ) close";
+
+    let mut lines = rows
+        .into_iter()
+        .map(|(row, text)| {
+            if row == pane {
+                Line::from(Span::styled(
+                    format!("{RIGHT_ARROW}{text}"),
+                    Style::default().add_modifier(Modifier::BOLD),
+                ))
+            } else {
+                Line::from(format!("  {text}"))
+            }
+        })
+        .collect::<Vec<_>>();
+    lines.push(Line::from(Span::styled(
+        "  the logs take the rest",
+        Style::default().add_modifier(Modifier::DIM),
+    )));
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        hint,
+        Style::default().add_modifier(Modifier::DIM),
+    )));
+
+    let size = f.size();
+    let width = lines.iter().map(Line::width).max().unwrap_or_default() + 4;
+    let area = popup(
+        (lines.len() + 2).min(usize::from(size.height.saturating_sub(4))),
+        width.min(usize::from(size.width.saturating_sub(8))),
+        size,
+        BoxLocation::MiddleCentre,
+    );
+
+    let block = Block::default()
+        .title(" layout ")
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .style(theme.popup());
+
+    f.render_widget(Clear, area);
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+/// Draw the network traffic of every container, counted by oxker since it started, or since the count was reset, most traffic first
+/// The daemon's totals, since each container started, are shown alongside
+pub fn traffic(f: &mut Frame, app_data: &Arc<Mutex<AppData>>, theme: &Theme) {
+    let app_data = app_data.lock();
+    let selected = app_data.get_selected_container_id();
+    let mut containers = app_data.get_container_items().iter().collect::<Vec<_>>();
+    containers.sort_by(|a, b| {
+        b.traffic
+            .total()
+            .cmp(&a.traffic.total())
+            .then_with(|| a.name.get().cmp(b.name.get()))
+    });
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |i| i.as_secs());
+    let name_width = containers
+        .iter()
+        .map(|i| i.name.to_string().chars().count())
+        .max()
+        .unwrap_or_default()
+        .max(4);
+
+    let size = f.size();
+    let area = popup(
+        (containers.len() + 7).min(usize::from(size.height.saturating_sub(4))),
+        (name_width + 72).min(usize::from(size.width.saturating_sub(8))),
+        size,
+        BoxLocation::MiddleCentre,
+    );
+    // Borders, the summary, blank, & header lines above, and the blank & key hint lines below
+    let rows = usize::from(area.height.saturating_sub(7)).max(1);
+
+    let (rx, tx) = containers.iter().fold((0, 0), |acc, i| {
+        (acc.0 + i.traffic.rx.get(), acc.1 + i.traffic.tx.get())
+    });
+    let mut lines = vec![
+        Line::from(format!(
+            "  {} containers, ↓ {} ↑ {} counted by oxker",
+            containers.len(),
+            ByteStats::new(rx).to_string().trim(),
+            ByteStats::new(tx).to_string().trim()
+        )),
+        Line::from(""),
+        Line::from(Span::styled(
+            format!(
+                "  {:<name_width$} {:>10} {:>10} {:>12} {:>14} {:>14}",
+                "name", "↓ rx", "↑ tx", "counted for", "↓ since start", "↑ since start"
+            ),
+            Style::default().add_modifier(Modifier::DIM),
+        )),
+    ];
+    let mut body = containers
+        .iter()
+        .map(|i| {
+            let text = format!(
+                "{:<name_width$} {:>10} {:>10} {:>12} {:>14} {:>14}",
+                i.name.to_string(),
+                i.traffic.rx.to_string().trim(),
+                i.traffic.tx.to_string().trim(),
+                format_duration(now.saturating_sub(i.traffic.since)),
+                i.rx.to_string().trim(),
+                i.tx.to_string().trim()
+            );
+            if selected.as_ref() == Some(&i.id) {
+                Line::from(Span::styled(
+                    format!("{RIGHT_ARROW}{text}"),
+                    Style::default().add_modifier(Modifier::BOLD),
+                ))
+            } else {
+                Line::from(format!("  {text}"))
+            }
+        })
+        .take(rows)
+        .collect::<Vec<_>>();
+    if body.is_empty() {
+        body.push(Line::from("  no containers"));
+    }
+    lines.append(&mut body);
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        "  ( r ) reset the selected container ( a ) reset all ( esc ) close",
+        Style::default().add_modifier(Modifier::DIM),
+    )));
+    drop(app_data);
+
+    let block = Block::default()
+        .title(" network traffic ")
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .style(theme.popup());
+
+    f.render_widget(Clear, area);
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+/// Draw an error popup over whole screen
+pub fn error(f: &mut Frame, error: AppError, seconds: Option<u8>, theme: &Theme) {
+    let block = Block::default()
+        .title(" Error ")
+        .border_type(BorderType::Rounded)
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL);
+
+    let to_push = match error {
+        AppError::DockerConnect => {
+            format!(
+                "\n\n {}::v{} closing in {:02} seconds",
+                NAME,
+                VERSION,
+                seconds.unwrap_or(5)
+            )
+        }
+        _ => String::from("\n\n ( c ) clear error\n ( q ) quit oxker "),
+    };
+
+    let mut text = format!("\n{error}");
+
+    text.push_str(to_push.as_str());
+
+    // Find the maximum line width & height
+    let mut max_line_width = max_line_width(&text);
+    let mut lines = text.lines().count();
+
+    // Add some horizontal & vertical margins
+    max_line_width += 8;
+    lines += 3;
+
+    let paragraph = Paragraph::new(text)
+        .style(Style::default().bg(theme.error).fg(theme.popup_bg))
+        .block(block)
+        .alignment(Alignment::Center);
+
+    let area = popup(lines, max_line_width, f.size(), BoxLocation::MiddleCentre);
+
+    // let (paragraph, area) = gen_error(f, error, seconds);
+    f.render_widget(Clear, area);
+    f.render_widget(paragraph, area);
+}
+
+/// Draw a config file error over the whole screen, the line is shown as it is written in the file, with what is wrong with it, & the values that are expected
+pub fn config_error(f: &mut Frame, error: &ConfigError, theme: &Theme) {
+    let block = Block::default()
+        .title(" config file error ")
+        .border_type(BorderType::Rounded)
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .padding(Padding::horizontal(1));
+
+    let mut lines = vec![Line::from("")];
+    if let Some(path) = &error.path {
+        lines.push(Line::from(path.display().to_string()));
+        lines.push(Line::from(""));
+    }
+    if let Some((number, text)) = &error.line {
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("{number} │ "),
+                Style::default().add_modifier(Modifier::DIM),
+            ),
+            Span::styled(text.as_str(), Style::default().add_modifier(Modifier::BOLD)),
+        ]));
+        lines.push(Line::from(""));
+    }
+    lines.push(Line::from(error.message.as_str()));
+    if let Some(hint) = &error.hint {
+        lines.push(Line::from(hint.as_str()));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from("( any key ) quit oxker"));
+
+    let paragraph = Paragraph::new(lines)
+        .style(Style::default().bg(theme.error).fg(theme.popup_bg))
+        .block(block)
+        .wrap(Wrap { trim: false });
+    f.render_widget(Clear, f.size());
+    f.render_widget(paragraph, f.size());
+}
+
+/// Draw info box in one of the 9 BoxLocations
+// TODO is this broken?
+pub fn info(
+    f: &mut Frame,
+    text: &str,
+    instant: Instant,
+    gui_state: &Arc<Mutex<GuiState>>,
+    theme: &Theme,
+) {
+    let block = Block::default()
+        .title("")
+        .title_alignment(Alignment::Center)
+        .borders(Borders::NONE);
+
+    let mut max_line_width = max_line_width(text);
+    let mut lines = text.lines().count();
+
+    // Add some horizontal & vertical margins
+    max_line_width += 8;
+    lines += 2;
+
+    let paragraph = Paragraph::new(text)
+        .style(Style::default().bg(theme.highlight).fg(theme.popup_bg))
+        .block(block)
+        .alignment(Alignment::Center);
+
+    let area = popup(lines, max_line_width, f.size(), BoxLocation::BottomRight);
+    f.render_widget(Clear, area);
+    f.render_widget(paragraph, area);
+    if instant.elapsed().as_millis() > 4000 {
+        gui_state.lock().reset_info_box();
+    }
+}
+
+/// draw a box in the one of the BoxLocations, based on max line width + number of lines
+fn popup(text_lines: usize, text_width: usize, r: Rect, box_location: BoxLocation) -> Rect {
+    // Make sure blank_space can't be an negative, as will crash
+    let calc = |x: u16, y: usize| usize::from(x).saturating_sub(y).saturating_div(2);
+
+    let blank_vertical = calc(r.height, text_lines);
+    let blank_horizontal = calc(r.width, text_width);
+
+    let (h_constraints, v_constraints) = box_location.get_constraints(
+        blank_horizontal.try_into().unwrap_or_default(),
+        blank_vertical.try_into().unwrap_or_default(),
+        text_lines.try_into().unwrap_or_default(),
+        text_width.try_into().unwrap_or_default(),
+    );
+
+    let indexes = box_location.get_indexes();
+
+    let popup_layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(v_constraints)
+        .split(r);
+
+    Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(h_constraints)
+        .split(popup_layout[indexes.0])[indexes.1]
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::many_single_char_names)]
+mod tests {
+
+    use std::{collections::HashSet, ops::RangeInclusive, path::PathBuf, sync::Arc, time::Instant};
+
+    use parking_lot::Mutex;
+    use ratatui::{
+        backend::TestBackend,
+        layout::Rect,
+        style::{Color, Modifier},
+        Terminal,
+    };
+    use uuid::Uuid;
+
+    use crate::{
+        app_data::{
+            AppData, ByteStats, ContainerId, ContainerImage, ContainerName, ContainerPorts,
+            CpuCores, CpuStats, DaemonInfo, DockerControls, DockerEvent, EventKind, ExecEntry,
+            Header, ImageItem, Limits, LogDetail, LogMatch, MemoryBreakdown, NetworkItem,
+            PortConflict, Probe, Readiness, ServiceItem, SortedOrder, State, StatefulList,
+            VolumeItem,
+        },
+        app_error::AppError,
+        config_file::ConfigError,
+        docker_data::{DockerContext, TlsFiles},
+        tests::{gen_appdata, gen_container_summary, gen_containers},
+        ui::{
+            draw_frame, BuildCache, CacheRecord, Capture, Cleanup, CleanupKind, CleanupRow,
+            CommandForm, ContextPicker, DeletePreview, DiffRow, DiffSection, DiskUsage,
+            FailedControl, FileBrowser, FileEntry, FilterForm, Finder, FinderRow, GuiState,
+            HealthProbe, HealthView, HostPicker, HostRow, ImageDetail, InspectDiff, InspectView,
+            KillPicker, LimitsForm, LogExport, MatchView, Pane, PaneLayout, PortRow, PortView,
+            PruneImage, PrunePreview, PullProgress, ReachPicker, ReachTarget, RecentPicker,
+            RecentRow, RenameForm, RunForm, ScaleForm, ScheduleForm, SecretFinding, SecretKind,
+            SecretScan, SelectablePanel, StdinForm, TagPicker, TaskLogs, Theme, ThemePreset,
+            Timeline, TimelineEvent, TimelineKind,
+        },
+    };
+
+    use super::{FrameData, ORANGE, VERSION};
+
+    struct TuiTestSetup {
+        app_data: Arc<Mutex<AppData>>,
+        gui_state: Arc<Mutex<GuiState>>,
+        fd: FrameData,
+        area: Rect,
+        terminal: Terminal<TestBackend>,
+        ids: Vec<ContainerId>,
+    }
+
+    const BORDER_CHARS: [&str; 6] = ["╭", "╮", "─", "│", "╰", "╯"];
+
+    /// Generate state to be used in *most* gui tests
+    fn test_setup(w: u16, h: u16, control_start: bool, container_start: bool) -> TuiTestSetup {
+        let backend = TestBackend::new(w, h);
+        let terminal = Terminal::new(backend).unwrap();
 
         let (ids, containers) = gen_containers();
         let mut app_data = gen_appdata(&containers);
         if control_start {
             app_data.docker_controls_start();
         }
-        if container_start {
-            app_data.containers_start();
+        if container_start {
+            app_data.containers_start();
+        }
+
+        let gui_state = GuiState::default();
+
+        let app_data = Arc::new(Mutex::new(app_data));
+        let gui_state = Arc::new(Mutex::new(gui_state));
+
+        let fd = FrameData::from((app_data.lock(), gui_state.lock()));
+        let area = Rect::new(0, 0, w, h);
+        TuiTestSetup {
+            app_data,
+            gui_state,
+            fd,
+            area,
+            terminal,
+            ids,
+        }
+    }
+
+    /// Insert some logs into the first container
+    fn insert_logs(setup: &TuiTestSetup) {
+        let logs = (1..=3).map(|i| format!("{i} line {i}")).collect::<Vec<_>>();
+        setup.app_data.lock().update_log_by_id(logs, &setup.ids[0]);
+    }
+
+    // ******************** //
+    // DockerControls panel //
+    // ******************** //
+
+    #[test]
+    /// Test that when DockerCommands are available, they are drawn correctly, dependant on container state
+    fn test_draw_blocks_commands_none() {
+        let (w, h) = (12, 6);
+        let mut setup = test_setup(w, h, false, false);
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::commands(&setup.app_data, setup.area, f, &setup.fd, &setup.gui_state);
+            })
+            .unwrap();
+
+        let expected = [
+            "╭──────────╮",
+            "│          │",
+            "│          │",
+            "│          │",
+            "│          │",
+            "╰──────────╯",
+        ];
+
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                let result_cell = &result[index];
+
+                assert_eq!(result_cell.symbol(), expected_char.to_string());
+                assert_eq!(result_cell.fg, Color::Reset);
+            }
+        }
+    }
+
+    #[test]
+    #[allow(clippy::too_many_lines)]
+    // Test that when DockerCommands are available, they are drawn correctly, dependant on container state
+    fn test_draw_blocks_commands_some() {
+        let (w, h) = (12, 8);
+        let mut setup = test_setup(w, h, true, true);
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::commands(&setup.app_data, setup.area, f, &setup.fd, &setup.gui_state);
+            })
+            .unwrap();
+
+        let expected = [
+            "╭──────────╮",
+            "│▶ pause   │",
+            "│  restart │",
+            "│  stop    │",
+            "│  kill    │",
+            "│  rename  │",
+            "│  delete  │",
+            "╰──────────╯",
+        ];
+        let result = &setup.terminal.backend().buffer().content;
+
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                let result_cell = &result[index];
+
+                assert_eq!(result_cell.symbol(), expected_char.to_string());
+
+                // Check the text color is correct
+                match index {
+                    // pause
+                    15..=19 => {
+                        assert_eq!(result_cell.fg, Color::Yellow);
+                    }
+                    // restart
+                    27..=33 => {
+                        assert_eq!(result_cell.fg, Color::Magenta);
+                    }
+                    // stop
+                    39..=42 => {
+                        assert_eq!(result_cell.fg, Color::Red);
+                    }
+                    // kill
+                    51..=54 => {
+                        assert_eq!(result_cell.fg, Color::LightRed);
+                    }
+                    // rename
+                    63..=68 => {
+                        assert_eq!(result_cell.fg, Color::White);
+                    }
+                    // delete
+                    75..=80 => {
+                        assert_eq!(result_cell.fg, Color::Gray);
+                    }
+                    // no text
+                    _ => {
+                        assert_eq!(result_cell.fg, Color::Reset);
+                    }
+                }
+                if result_cell.symbol().starts_with('▶') {
+                    assert_eq!(result_cell.fg, Color::Reset);
+                }
+            }
+        }
+
+        // Change the controls state
+        setup
+            .app_data
+            .lock()
+            .update_containers(None, &mut vec![gen_container_summary(1, "paused")]);
+        setup.app_data.lock().docker_controls_next();
+
+        let expected = [
+            "╭──────────╮",
+            "│  resume  │",
+            "│▶ stop    │",
+            "│  rename  │",
+            "│  delete  │",
+            "│          │",
+            "│          │",
+            "╰──────────╯",
+        ];
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::commands(&setup.app_data, setup.area, f, &setup.fd, &setup.gui_state);
+            })
+            .unwrap();
+
+        let result = &setup.terminal.backend().buffer().content;
+
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                let result_cell = &result[index];
+
+                assert_eq!(result_cell.symbol(), expected_char.to_string());
+
+                // Chceck the text color is correct
+                match index {
+                    // resume
+                    15..=20 => {
+                        assert_eq!(result_cell.fg, Color::Blue);
+                    }
+                    // stop
+                    27..=30 => {
+                        assert_eq!(result_cell.fg, Color::Red);
+                    }
+                    // rename
+                    39..=44 => {
+                        assert_eq!(result_cell.fg, Color::White);
+                    }
+                    // delete
+                    51..=56 => {
+                        assert_eq!(result_cell.fg, Color::Gray);
+                    }
+                    // no text
+                    _ => {
+                        assert_eq!(result_cell.fg, Color::Reset);
+                    }
+                }
+                if result_cell.symbol().starts_with('▶') {
+                    assert_eq!(result_cell.fg, Color::Reset);
+                }
+            }
+        }
+    }
+
+    #[test]
+    /// When control panel is selected, the border is blue, if not then white, selected text is highlighted
+    fn test_draw_blocks_commands_panel_selected_color() {
+        let (w, h) = (12, 8);
+        let mut setup = test_setup(w, h, true, true);
+        let expected = [
+            "╭──────────╮",
+            "│▶ pause   │",
+            "│  restart │",
+            "│  stop    │",
+            "│  kill    │",
+            "│  rename  │",
+            "│  delete  │",
+            "╰──────────╯",
+        ];
+
+        // Unselected, has a grey border
+        setup
+            .terminal
+            .draw(|f| {
+                super::commands(&setup.app_data, setup.area, f, &setup.fd, &setup.gui_state);
+            })
+            .unwrap();
+
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                let result_cell = &result[index];
+
+                assert_eq!(result_cell.symbol(), expected_char.to_string());
+                if BORDER_CHARS.contains(&result_cell.symbol()) {
+                    assert_eq!(result_cell.fg, Color::Reset);
+                }
+            }
+        }
+
+        // Control panel now selected, should have a blue border
+        setup.gui_state.lock().next_panel();
+        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+        setup
+            .terminal
+            .draw(|f| {
+                super::commands(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
+
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                let result_cell = &result[index];
+
+                assert_eq!(result_cell.symbol(), expected_char.to_string());
+                if BORDER_CHARS.contains(&result_cell.symbol()) {
+                    assert_eq!(result_cell.fg, Color::LightCyan);
+                }
+                // Make sure that the selected line has bold text
+                match index {
+                    // pause
+                    13..=22 => {
+                        assert_eq!(result_cell.modifier, Modifier::BOLD);
+                    }
+                    _ => {
+                        assert!(result_cell.modifier.is_empty());
+                    }
+                }
+            }
+        }
+    }
+
+    // *********************** //
+    // Container summary panel //
+    // *********************** //
+
+    // Check that the correct solor is applied to the state/status/cpu/memory section
+    fn check_expected(expected: [&str; 6], w: u16, _h: u16, setup: &TuiTestSetup, color: Color) {
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                let result_cell = &result[index];
+
+                assert_eq!(result_cell.symbol(), expected_char.to_string());
+                if (145..=207).contains(&index) {
+                    assert_eq!(result_cell.fg, color);
+                    assert_eq!(result_cell.modifier, Modifier::BOLD);
+                }
+            }
+        }
+    }
+
+    #[test]
+    /// No containers, panel unselected, then selected, border color changes correctly
+    fn test_draw_blocks_containers_none() {
+        let (w, h) = (25, 6);
+        let mut setup = test_setup(w, h, true, true);
+        setup.app_data.lock().containers = StatefulList::new(vec![]);
+
+        let expected = [
+            "╭ Containers ───────────╮",
+            "│ no containers running │",
+            "│                       │",
+            "│                       │",
+            "│                       │",
+            "╰───────────────────────╯",
+        ];
+
+        setup.gui_state.lock().next_panel();
+        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::containers(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
+
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                let result_cell = &result[index];
+
+                assert_eq!(result_cell.symbol(), expected_char.to_string());
+                assert_eq!(result_cell.fg, Color::Reset);
+            }
+        }
+
+        setup.gui_state.lock().previous_panel();
+        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::containers(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
+
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                let result_cell = &result[index];
+
+                assert_eq!(result_cell.symbol(), expected_char.to_string());
+                if BORDER_CHARS.contains(&result_cell.symbol()) {
+                    assert_eq!(result_cell.fg, Color::LightCyan);
+                }
+            }
+        }
+    }
+
+    #[test]
+    /// Containers panel drawn, selected line is bold, border is blue
+    fn test_draw_blocks_containers_some() {
+        let (w, h) = (130, 6);
+        let mut setup = test_setup(w, h, true, true);
+
+        let expected = [
+        "╭ Containers 1/3 ────────────────────────────────────────────────────────────────────────────────────────────────────────────────╮",
+        "│⚪  container_1   ✓ running            Up 1 hour    00.00%   0.00 kB / 0.00 kB          1   image_1   0.00 kB   0.00 kB          │",
+        "│   container_2   ✓ running            Up 2 hour    00.00%   0.00 kB / 0.00 kB          2   image_2   0.00 kB   0.00 kB          │",
+        "│   container_3   ✓ running            Up 3 hour    00.00%   0.00 kB / 0.00 kB          3   image_3   0.00 kB   0.00 kB          │",
+        "│                                                                                                                                │",
+        "╰────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────╯",
+    ];
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::containers(&setup.app_data, setup.area, f, &setup.fd, &setup.gui_state);
+            })
+            .unwrap();
+
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                let result_cell = &result[index];
+
+                // result matches expected
+                assert_eq!(result_cell.symbol(), expected_char.to_string());
+
+                // Selected container is bold
+                match index {
+                    131 | 133..=258 => assert_eq!(result_cell.modifier, Modifier::BOLD),
+                    _ => {
+                        assert!(result_cell.modifier.is_empty());
+                    }
+                }
+
+                // Border is blue
+                if BORDER_CHARS.contains(&result_cell.symbol()) {
+                    assert_eq!(result_cell.fg, Color::LightCyan);
+                }
+            }
+        }
+
+        // Change selected panel, border is now no longer blue
+        setup.gui_state.lock().next_panel();
+        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+        setup
+            .terminal
+            .draw(|f| {
+                super::containers(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
+
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                let result_cell = &result[index];
+
+                assert_eq!(result_cell.symbol(), expected_char.to_string());
+
+                // Border is gray
+                if BORDER_CHARS.contains(&result_cell.symbol()) {
+                    assert_eq!(result_cell.fg, Color::Reset);
+                }
+            }
+        }
+    }
+
+    #[test]
+    /// ALl columns on all rows are coloured correctly
+    fn test_draw_blocks_containers_colors() {
+        let (w, h) = (130, 6);
+        let mut setup = test_setup(w, h, true, true);
+
+        let expected = [
+            "╭ Containers 1/3 ────────────────────────────────────────────────────────────────────────────────────────────────────────────────╮",
+            "│⚪  container_1   ✓ running            Up 1 hour    00.00%   0.00 kB / 0.00 kB          1   image_1   0.00 kB   0.00 kB          │",
+            "│   container_2   ✓ running            Up 2 hour    00.00%   0.00 kB / 0.00 kB          2   image_2   0.00 kB   0.00 kB          │",
+            "│   container_3   ✓ running            Up 3 hour    00.00%   0.00 kB / 0.00 kB          3   image_3   0.00 kB   0.00 kB          │",
+            "│                                                                                                                                │",
+            "╰────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────╯",
+        ];
+        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::containers(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
+
+        let index_blue = [
+            134, 135, 136, 137, 138, 139, 140, 141, 142, 143, 144, 208, 209, 210, 211, 212, 213,
+            214, 215, 216, 217, 218, 219, 220, 221, 222, 223, 224, 225, 226, 227, 228,
+        ];
+        let index_blue = index_blue
+            .iter()
+            .flat_map(|&x| vec![x, x + 130, x + 260])
+            .collect::<Vec<_>>();
+        let index_green = [
+            145, 146, 147, 148, 149, 150, 151, 152, 153, 154, 155, 156, 157, 158, 159, 160, 161,
+            162, 163, 164, 165, 166, 167, 168, 169, 170, 171, 172, 173, 174, 175, 176, 177, 178,
+            179, 180, 181, 182, 183, 184, 185, 186, 187, 188, 189, 190, 191, 192, 193, 194, 195,
+            196, 197, 198, 199, 200, 201, 202, 203, 204, 205, 206, 207,
+        ];
+        let index_green = index_green
+            .iter()
+            .flat_map(|&x| vec![x, x + 130, x + 260])
+            .collect::<Vec<_>>();
+
+        let index_rx = [229, 230, 231, 232, 233, 234, 235, 236, 237, 238];
+        let index_rx = index_rx
+            .iter()
+            .flat_map(|&x| vec![x, x + 130, x + 260])
+            .collect::<Vec<_>>();
+
+        let index_tx = [239, 240, 241, 242, 243, 244, 245, 246, 247, 248];
+        let index_tx = index_tx
+            .iter()
+            .flat_map(|&x| vec![x, x + 130, x + 260])
+            .collect::<Vec<_>>();
+
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+
+                let result_cell = &result[index];
+                assert_eq!(result_cell.symbol(), expected_char.to_string());
+
+                match index {
+                    _x if index_blue.contains(&index) => {
+                        assert_eq!(result_cell.fg, Color::Blue);
+                    }
+                    _x if index_green.contains(&index) => {
+                        assert_eq!(result_cell.fg, Color::Green);
+                    }
+                    _x if index_rx.contains(&index) => {
+                        assert_eq!(result_cell.fg, Color::Rgb(255, 233, 193));
+                    }
+                    _x if index_tx.contains(&index) => {
+                        assert_eq!(result_cell.fg, Color::Rgb(205, 140, 140));
+                    }
+                    (0..=130) | (259..=260) | (389..=390) | (519..=520) | (649..=779) => {
+                        assert_eq!(result_cell.fg, Color::LightCyan);
+                    }
+                    _ => {
+                        assert_eq!(result_cell.fg, Color::Reset);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    /// When monitoring multiple hosts, a host column is shown, and the title shows the current host tab
+    fn test_draw_blocks_containers_multi_host() {
+        let (w, h) = (140, 6);
+        let mut setup = test_setup(w, h, true, true);
+        setup.app_data.lock().args.extra_hosts = vec!["remote".to_owned()];
+        setup.app_data.lock().containers.items[1].host = Some("remote".to_owned());
+
+        let expected = [
+        "╭ Containers 1/3 - all hosts ──────────────────────────────────────────────────────────────────────────────────────────────────────────────╮",
+        "│⚪  container_1    local   ✓ running            Up 1 hour    00.00%   0.00 kB / 0.00 kB          1   image_1   0.00 kB   0.00 kB           │",
+        "│   container_2   remote   ✓ running            Up 2 hour    00.00%   0.00 kB / 0.00 kB          2   image_2   0.00 kB   0.00 kB           │",
+        "│   container_3    local   ✓ running            Up 3 hour    00.00%   0.00 kB / 0.00 kB          3   image_3   0.00 kB   0.00 kB           │",
+        "│                                                                                                                                          │",
+        "╰──────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────╯",
+        ];
+        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::containers(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
+
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                assert_eq!(result[index].symbol(), expected_char.to_string());
+            }
+        }
+    }
+
+    #[test]
+    /// When a probe is set, a ready column is shown, containers without a probe are blank
+    fn test_draw_blocks_containers_ready() {
+        let (w, h) = (140, 6);
+        let mut setup = test_setup(w, h, true, true);
+        setup.app_data.lock().args.probes = vec![Probe::parse("x=tcp:80").unwrap()];
+        setup.app_data.lock().containers.items[0].readiness = Some(Readiness::Ready);
+        setup.app_data.lock().containers.items[1].readiness = Some(Readiness::NotReady);
+
+        let expected = [
+        "╭ Containers 1/3 ──────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────╮",
+        "│⚪  container_1   ✓ running            Up 1 hour       ready    00.00%   0.00 kB / 0.00 kB          1   image_1   0.00 kB   0.00 kB        │",
+        "│   container_2   ✓ running            Up 2 hour   not ready    00.00%   0.00 kB / 0.00 kB          2   image_2   0.00 kB   0.00 kB        │",
+        "│   container_3   ✓ running            Up 3 hour                00.00%   0.00 kB / 0.00 kB          3   image_3   0.00 kB   0.00 kB        │",
+        "│                                                                                                                                          │",
+        "╰──────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────╯",
+        ];
+        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::containers(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
+
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                assert_eq!(result[index].symbol(), expected_char.to_string());
+            }
+        }
+        assert_eq!(result[usize::from(w) + 62].fg, Color::Green);
+        assert_eq!(result[usize::from(w) * 2 + 58].fg, Color::Red);
+    }
+
+    #[test]
+    /// With `--restarts`, the exit code, & restart count, are shown, a crash looping container is highlighted
+    fn test_draw_blocks_containers_restarts() {
+        let (w, h) = (140, 6);
+        let mut setup = test_setup(w, h, true, true);
+        {
+            let mut app_data = setup.app_data.lock();
+            app_data.args.restarts = true;
+            app_data.containers.items[0].exit_info.set(Some(0), 0, 10);
+            app_data.containers.items[1]
+                .exit_info
+                .set(Some(137), 12, 10);
+            app_data.containers.items[1].state = State::Restarting;
+        }
+
+        let expected = [
+            "╭ Containers 1/3 ──────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────╮",
+            "│⚪  container_1   ✓ running             Up 1 hour      0          0    00.00%   0.00 kB / 0.00 kB          1   image_1   0.00 kB   0.00 kB │",
+            "│   container_2   ↻ restarting          Up 2 hour    137         12    00.00%   0.00 kB / 0.00 kB          2   image_2   0.00 kB   0.00 kB │",
+            "│   container_3   ✓ running             Up 3 hour                      00.00%   0.00 kB / 0.00 kB          3   image_3   0.00 kB   0.00 kB │",
+            "│                                                                                                                                          │",
+            "╰──────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────╯",
+        ];
+        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::containers(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
+
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                assert_eq!(result[index].symbol(), expected_char.to_string());
+            }
+        }
+        // container_2 is highlighted, as is its restart count, & its non-zero exit code is red
+        assert_eq!(result[usize::from(w) * 2 + 5].fg, Color::LightRed);
+        assert_eq!(result[usize::from(w) * 2 + 53].fg, Color::Red);
+        assert_eq!(result[usize::from(w) * 2 + 65].fg, Color::LightRed);
+        assert_eq!(result[usize::from(w) * 3 + 5].fg, Color::Blue);
+    }
+
+    #[test]
+    /// With a cpu quota, the throttled column is shown, a mostly throttled container is highlighted
+    fn test_draw_blocks_containers_throttled() {
+        let (w, h) = (140, 6);
+        let mut setup = test_setup(w, h, true, true);
+        {
+            let mut app_data = setup.app_data.lock();
+            app_data.containers.items[0].throttling.count(100, 0, 0);
+            app_data.containers.items[0]
+                .throttling
+                .count(200, 20, 4_000_000_000);
+            app_data.containers.items[1].throttling.count(100, 0, 0);
+            app_data.containers.items[1]
+                .throttling
+                .count(200, 90, 75_000_000_000);
+        }
+
+        let expected = [
+            "╭ Containers 1/3 ──────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────╮",
+            "│⚪  container_1   ✓ running            Up 1 hour    00.00%       20% 4s   0.00 kB / 0.00 kB          1   image_1   0.00 kB   0.00 kB       │",
+            "│   container_2   ✓ running            Up 2 hour    00.00%   90% 1m 15s   0.00 kB / 0.00 kB          2   image_2   0.00 kB   0.00 kB       │",
+            "│   container_3   ✓ running            Up 3 hour    00.00%                0.00 kB / 0.00 kB          3   image_3   0.00 kB   0.00 kB       │",
+            "│                                                                                                                                          │",
+            "╰──────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────╯",
+        ];
+        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::containers(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
+
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                assert_eq!(result[index].symbol(), expected_char.to_string());
+            }
+        }
+        // container_1 is yellow, container_2 highlighted
+        assert_eq!(result[usize::from(w) + 65].fg, Color::Yellow);
+        assert_eq!(result[usize::from(w) * 2 + 61].fg, Color::LightRed);
+    }
+
+    #[test]
+    /// When long container/image name, it is truncated correctly
+    fn test_draw_blocks_containers_long_name_image() {
+        let (w, h) = (170, 6);
+        let mut setup = test_setup(w, h, true, true);
+        setup.app_data.lock().containers.items[0].name =
+            ContainerName::from("a_long_container_name_for_the_purposes_of_this_test");
+        setup.app_data.lock().containers.items[0].image =
+            ContainerImage::from("a_long_image_name_for_the_purposes_of_this_test");
+
+        let expected = [
+        "╭ Containers 1/3 ────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────╮",
+        "│⚪  a_long_container_name_for_the…   ॥ paused             Up 1 hour    00.00%   0.00 kB / 0.00 kB          1   a_long_image_name_for_the_pur…   0.00 kB   0.00 kB        │",
+        "│                      container_2   ✓ running            Up 2 hour    00.00%   0.00 kB / 0.00 kB          2                          image_2   0.00 kB   0.00 kB        │",
+        "│                      container_3   ✓ running            Up 3 hour    00.00%   0.00 kB / 0.00 kB          3                          image_3   0.00 kB   0.00 kB        │",
+        "│                                                                                                                                                                        │",
+        "╰────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────╯",
+        ];
+        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+        setup.app_data.lock().containers.items[0].state = State::Paused;
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::containers(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
+
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                let result_cell = &result[index];
+
+                assert_eq!(result_cell.symbol(), expected_char.to_string());
+            }
+        }
+
+        // THis char: …
+    }
+
+    #[test]
+    /// When container is paused, correct colors displayed
+    fn test_draw_blocks_containers_paused() {
+        let (w, h) = (130, 6);
+        let mut setup = test_setup(w, h, true, true);
+
+        let expected = [
+            "╭ Containers 1/3 ────────────────────────────────────────────────────────────────────────────────────────────────────────────────╮",
+        "│⚪  container_1   ॥ paused             Up 1 hour    00.00%   0.00 kB / 0.00 kB          1   image_1   0.00 kB   0.00 kB          │",
+        "│   container_2   ✓ running            Up 2 hour    00.00%   0.00 kB / 0.00 kB          2   image_2   0.00 kB   0.00 kB          │",
+        "│   container_3   ✓ running            Up 3 hour    00.00%   0.00 kB / 0.00 kB          3   image_3   0.00 kB   0.00 kB          │",
+        "│                                                                                                                                │",
+        "╰────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────╯",
+        ];
+        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+        setup.app_data.lock().containers.items[0].state = State::Paused;
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::containers(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
+
+        check_expected(expected, w, h, &setup, Color::Yellow);
+    }
+
+    #[test]
+    /// Stats that haven't been updated for a few intervals are dimmed, & marked in place of the cpu margin
+    fn test_draw_blocks_containers_stale() {
+        let (w, h) = (130, 6);
+        let mut setup = test_setup(w, h, true, true);
+        setup.app_data.lock().containers.items[1]
+            .stats_waiting
+            .start(1);
+        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+
+        let expected = [
+            "╭ Containers 1/3 ────────────────────────────────────────────────────────────────────────────────────────────────────────────────╮",
+            "│⚪  container_1   ✓ running            Up 1 hour    00.00%   0.00 kB / 0.00 kB          1   image_1   0.00 kB   0.00 kB          │",
+            "│   container_2   ✓ running            Up 2 hour  ⧗ 00.00%   0.00 kB / 0.00 kB          2   image_2   0.00 kB   0.00 kB          │",
+            "│   container_3   ✓ running            Up 3 hour    00.00%   0.00 kB / 0.00 kB          3   image_3   0.00 kB   0.00 kB          │",
+            "│                                                                                                                                │",
+            "╰────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────╯",
+        ];
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::containers(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
+
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                assert_eq!(expected_char.to_string(), result[index].symbol());
+                let dim = result[index].modifier.contains(Modifier::DIM);
+                assert_eq!(dim, row_index == 2 && (48..78).contains(&char_index));
+            }
+        }
+    }
+
+    #[test]
+    /// A running container with a health check has its health marked, in its color, in the margin before the state
+    fn test_draw_blocks_containers_health() {
+        let (w, h) = (130, 6);
+        let mut setup = test_setup(w, h, true, true);
+        setup.app_data.lock().containers.items[1].status = "Up 2 hour (healthy)".to_owned();
+        setup.app_data.lock().containers.items[2].status = "Up 3 hour (unhealthy)".to_owned();
+        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::containers(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
+
+        let result = &setup.terminal.backend().buffer().content;
+        let cell = |row: usize| &result[row * usize::from(w) + 17];
+        assert_eq!(cell(1).symbol(), " ");
+        assert_eq!(cell(2).symbol(), "♥");
+        assert_eq!(cell(2).fg, Color::Green);
+        assert_eq!(cell(3).symbol(), "!");
+        assert_eq!(cell(3).fg, Color::LightRed);
+        assert_eq!(result[3 * usize::from(w) + 18].symbol(), "✓");
+    }
+
+    #[test]
+    /// A `--label-column` is drawn after the other columns, with its label as the header, blank for a container without the label
+    fn test_draw_blocks_containers_label_columns() {
+        let (w, h) = (150, 5);
+        let mut setup = test_setup(w, h, true, true);
+        {
+            let mut app_data = setup.app_data.lock();
+            app_data.args.label_columns = vec!["team".to_owned()];
+            app_data.containers.items[0].label_values = vec![Some("payments".to_owned())];
+            app_data.containers.items[1].label_values = vec![Some("ops".to_owned())];
+        }
+        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::containers(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
+
+        let expected = [
+            "╭ Containers 1/3 ────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────╮",
+            "│⚪  container_1   ✓ running            Up 1 hour    00.00%   0.00 kB / 0.00 kB          1   image_1   0.00 kB   0.00 kB   payments                   │",
+            "│   container_2   ✓ running            Up 2 hour    00.00%   0.00 kB / 0.00 kB          2   image_2   0.00 kB   0.00 kB        ops                   │",
+            "│   container_3   ✓ running            Up 3 hour    00.00%   0.00 kB / 0.00 kB          3   image_3   0.00 kB   0.00 kB                              │",
+            "╰────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────╯",
+        ];
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                assert_eq!(result[index].symbol(), expected_char.to_string());
+            }
+        }
+
+        let (w, h) = (150, 1);
+        let mut setup = test_setup(w, h, true, true);
+        setup.app_data.lock().args.label_columns = vec!["team".to_owned()];
+        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+        setup
+            .terminal
+            .draw(|f| {
+                super::heading_bar(setup.area, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
+        let result = &setup.terminal.backend().buffer().content;
+        let expected = "           name       state               status       cpu        memory/limit         id     image      ↓ rx      ↑ tx   team       ( h ) show help  ";
+        for (index, expected_char) in expected.chars().enumerate() {
+            assert_eq!(result[index].symbol(), expected_char.to_string());
+        }
+    }
+
+    #[test]
+    /// Only the rows that fit in the panel are drawn, scrolled to keep the selected container shown, & only those containers are visible for stats
+    fn test_draw_blocks_containers_virtualized() {
+        let (w, h) = (130, 4);
+        let mut setup = test_setup(w, h, true, true);
+        setup.app_data.lock().containers_end();
+        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::containers(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
+
+        let expected = [
+            "╭ Containers 3/3 ────────────────────────────────────────────────────────────────────────────────────────────────────────────────╮",
+            "│   container_2   ✓ running            Up 2 hour    00.00%   0.00 kB / 0.00 kB          2   image_2   0.00 kB   0.00 kB          │",
+            "│⚪  container_3   ✓ running            Up 3 hour    00.00%   0.00 kB / 0.00 kB          3   image_3   0.00 kB   0.00 kB          │",
+            "╰────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────╯",
+        ];
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                assert_eq!(result[index].symbol(), expected_char.to_string());
+            }
+        }
+        assert_eq!(setup.app_data.lock().get_container_state().offset(), 1);
+        assert_eq!(
+            setup.app_data.lock().visible_containers,
+            HashSet::from([setup.ids[1].clone(), setup.ids[2].clone()])
+        );
+    }
+
+    #[test]
+    /// The first row is kept, unless the selected row would be hidden
+    fn test_draw_blocks_visible_start() {
+        assert_eq!(super::visible_start(0, Some(1), 2, 3), 0);
+        assert_eq!(super::visible_start(0, Some(2), 2, 3), 1);
+        assert_eq!(super::visible_start(1, Some(0), 2, 3), 0);
+        assert_eq!(super::visible_start(5, None, 2, 3), 2);
+        assert_eq!(super::visible_start(0, Some(999), 40, 1000), 960);
+        assert_eq!(super::visible_start(0, None, 2, 0), 0);
+    }
+
+    /// Images for the images panel & image detail tests
+    fn gen_images() -> Vec<ImageItem> {
+        let gen_image = |id: &str, created: i64, tags: &[&str], containers: &[&str]| ImageItem {
+            containers: containers.iter().map(|i| (*i).to_owned()).collect(),
+            created,
+            id: format!("sha256:{id}"),
+            size: ByteStats::new(u64::try_from(created).unwrap_or_default() * 1_000_000),
+            tags: tags.iter().map(|i| (*i).to_owned()).collect(),
+        };
+        vec![
+            gen_image("8a3e4f5b6c7d8e9f", 40, &["redis:7"], &["cache"]),
+            gen_image(
+                "1b2c3d4e5f6a7b8c",
+                120,
+                &["localhost:5000/team/api:v2"],
+                &["api-1", "api-2"],
+            ),
+            gen_image("9f8e7d6c5b4a3f2e", 30, &[], &[]),
+        ]
+    }
+
+    #[test]
+    /// Images panel lists the newest image first, dangling images are dimmed
+    fn test_draw_blocks_images() {
+        let (w, h) = (80, 6);
+        let mut setup = test_setup(w, h, true, true);
+        setup.app_data.lock().set_images(gen_images());
+        setup.gui_state.lock().cycle_resources();
+        setup
+            .gui_state
+            .lock()
+            .set_selected_panel(SelectablePanel::Images);
+        setup.app_data.lock().images_next();
+        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+
+        let expected = [
+            "╭ Images 2/3 ──────────────────────────────────────────────────────────────────╮",
+            "│   localhost:5000/team/api  v2      1b2c3d4e5f6a  120.00 MB  2 containers     │",
+            "│⚪  redis                    7       8a3e4f5b6c7d   40.00 MB  1 container      │",
+            "│   <none>                   <none>  9f8e7d6c5b4a   30.00 MB  dangling         │",
+            "│                                                                              │",
+            "╰──────────────────────────────────────────────────────────────────────────────╯",
+        ];
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::images(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
+
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                assert_eq!(expected_char.to_string(), result[index].symbol());
+                assert_eq!(
+                    result[index].fg == Color::LightCyan,
+                    row_index == 0 || row_index == 5 || char_index == 0 || char_index == 79,
+                );
+            }
+        }
+        assert!(!result[usize::from(w) * 2 + 4]
+            .modifier
+            .contains(Modifier::DIM));
+        assert!(result[usize::from(w) * 2 + 4]
+            .modifier
+            .contains(Modifier::BOLD));
+        assert!(result[usize::from(w) * 3 + 4]
+            .modifier
+            .contains(Modifier::DIM));
+    }
+
+    #[test]
+    /// Volumes panel lists volumes by name, with the containers of the selected volume in the bottom border, an armed removal is red
+    fn test_draw_blocks_volumes() {
+        let (w, h) = (80, 6);
+        let mut setup = test_setup(w, h, true, true);
+        let gen_volume = |name: &str, size: Option<u64>, containers: &[&str]| VolumeItem {
+            containers: containers.iter().map(|i| (*i).to_owned()).collect(),
+            driver: "local".to_owned(),
+            name: name.to_owned(),
+            scope: "local".to_owned(),
+            size: size.map(ByteStats::new),
+        };
+        setup.app_data.lock().set_volumes(vec![
+            gen_volume("site_db-data", Some(250_000_000), &["site-db-1"]),
+            gen_volume(
+                "9f2c4b1a7e3d5c6b8a9f0e1d2c3b4a5f6e7d8c9b0a1f2e3d4c5b6a7f8e9d0c1b",
+                None,
+                &[],
+            ),
+        ]);
+        setup.gui_state.lock().cycle_resources();
+        setup.gui_state.lock().cycle_resources();
+        setup
+            .gui_state
+            .lock()
+            .set_selected_panel(SelectablePanel::Volumes);
+        setup.app_data.lock().volumes_next();
+        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+
+        let expected = [
+            "╭ Volumes 2/2 ─────────────────────────────────────────────────────────────────╮",
+            "│   9f2c4b1a7e3d  local  local           -  unused                             │",
+            "│⚪  site_db-data  local  local   250.00 MB  1 container                        │",
+            "│                                                                              │",
+            "│                                                                              │",
+            "╰ used by site-db-1 ───────────────────────────────────────────────────────────╯",
+        ];
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::volumes(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
+
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                assert_eq!(expected_char.to_string(), result[index].symbol());
+            }
+        }
+
+        setup
+            .gui_state
+            .lock()
+            .set_remove_armed(Some("site_db-data".to_owned()));
+        setup
+            .terminal
+            .draw(|f| {
+                super::volumes(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
+        let result = &setup.terminal.backend().buffer().content;
+        let row = (0..usize::from(w))
+            .map(|i| result[usize::from(w) * 5 + i].symbol())
+            .collect::<String>();
+        assert!(row.contains(" ( enter ) again to remove site_db-data ( esc ) cancel "));
+        assert_eq!(result[usize::from(w) * 2 + 4].fg, Color::Red);
+    }
+
+    #[test]
+    /// Networks panel lists networks by name, predefined networks are dimmed
+    fn test_draw_blocks_networks() {
+        let (w, h) = (80, 6);
+        let mut setup = test_setup(w, h, true, true);
+        let gen_network = |id: &str, name: &str, internal: bool, containers: &[&str]| NetworkItem {
+            containers: containers.iter().map(|i| (*i).to_owned()).collect(),
+            driver: "bridge".to_owned(),
+            id: id.to_owned(),
+            internal,
+            name: name.to_owned(),
+            scope: "local".to_owned(),
+        };
+        setup.app_data.lock().set_networks(vec![
+            gen_network("5c6d7e8f9a0b1c2d", "site_default", false, &["site-api-1"]),
+            gen_network("1a2b3c4d5e6f7a8b", "bridge", false, &[]),
+            gen_network("9e8d7c6b5a4f3e2d", "site_backend", true, &[]),
+        ]);
+        for _ in 0..3 {
+            setup.gui_state.lock().cycle_resources();
+        }
+        setup
+            .gui_state
+            .lock()
+            .set_selected_panel(SelectablePanel::Networks);
+        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+
+        let expected = [
+            "╭ Networks 1/3 ────────────────────────────────────────────────────────────────╮",
+            "│⚪  bridge        1a2b3c4d5e6f  bridge  local   predefined                     │",
+            "│   site_backend  9e8d7c6b5a4f  bridge  local   unused, internal               │",
+            "│   site_default  5c6d7e8f9a0b  bridge  local   1 container                    │",
+            "│                                                                              │",
+            "╰──────────────────────────────────────────────────────────────────────────────╯",
+        ];
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::networks(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
+
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                assert_eq!(expected_char.to_string(), result[index].symbol());
+            }
+        }
+        assert!(result[usize::from(w) + 4].modifier.contains(Modifier::DIM));
+        assert!(!result[usize::from(w) * 3 + 4]
+            .modifier
+            .contains(Modifier::DIM));
+    }
+
+    fn gen_service(id: &str, name: &str, running: u64, desired: u64, global: bool) -> ServiceItem {
+        ServiceItem {
+            desired,
+            global,
+            id: id.to_owned(),
+            image: "nginx:1.27".to_owned(),
+            name: name.to_owned(),
+            running,
+            stack: None,
+            update: None,
+        }
+    }
+
+    #[test]
+    /// Services panel is only cycled to on a swarm manager, it lists services grouped by stack, with too few running replicas in red, & the armed removal of a stack
+    fn test_draw_blocks_services() {
+        let (w, h) = (80, 6);
+        let mut setup = test_setup(w, h, true, true);
+        for _ in 0..4 {
+            setup.gui_state.lock().cycle_resources();
+        }
+        assert_eq!(setup.gui_state.lock().get_resources_panel(), None);
+
+        let mut web = gen_service("q8k2xv1n4m7b9c3d", "site_web", 2, 3, false);
+        web.update = Some("updating".to_owned());
+        web.stack = Some("site".to_owned());
+        let mut agent = gen_service("r9l3yw2o5n8c", "site_agent", 1, 1, true);
+        agent.stack = Some("site".to_owned());
+        setup.app_data.lock().set_services(vec![
+            web,
+            agent,
+            gen_service("s0m4zx3p6o9d", "proxy", 1, 1, false),
+        ]);
+        setup.gui_state.lock().set_swarm(true);
+        for _ in 0..4 {
+            setup.gui_state.lock().cycle_resources();
+        }
+        setup
+            .gui_state
+            .lock()
+            .set_selected_panel(SelectablePanel::Services);
+        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+
+        let expected = [
+            "╭ Services 1/3 ────────────────────────────────────────────────────────────────╮",
+            "│⚪  site  site_agent  r9l3yw2o5n8c  1/1      nginx:1.27  global                │",
+            "│         site_web    q8k2xv1n4m7b  2/3      nginx:1.27  updating              │",
+            "│   -     proxy       s0m4zx3p6o9d  1/1      nginx:1.27                        │",
+            "│                                                                              │",
+            "╰ ( enter ) task logs ( + ) scale ( r ) force update ( delete ) remove stack ──╯",
+        ];
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::services(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
+
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                assert_eq!(expected_char.to_string(), result[index].symbol());
+            }
+        }
+        assert_eq!(result[usize::from(w) + 36].fg, Color::Reset);
+        assert_eq!(result[usize::from(w) * 2 + 36].fg, Color::Red);
+
+        setup
+            .gui_state
+            .lock()
+            .set_remove_armed(Some("stack site".to_owned()));
+        setup
+            .terminal
+            .draw(|f| {
+                super::services(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
+        let result = &setup.terminal.backend().buffer().content;
+        let bottom = result[usize::from(w) * 5..]
+            .iter()
+            .map(ratatui::buffer::Cell::symbol)
+            .collect::<String>();
+        assert!(bottom.starts_with("╰ ( delete ) again to remove stack site ( esc ) cancel ─"));
+
+        setup.gui_state.lock().set_swarm(false);
+        assert_eq!(setup.gui_state.lock().get_resources_panel(), None);
+        assert_eq!(
+            setup.gui_state.lock().get_selected_panel(),
+            SelectablePanel::Containers
+        );
+    }
+
+    #[test]
+    /// Image detail popup lists the image's config, the removal is armed before the image is removed
+    fn test_draw_blocks_image_detail() {
+        let (w, h) = (80, 19);
+        let mut setup = test_setup(w, h, true, true);
+        let image = gen_images().remove(1);
+        setup.gui_state.lock().set_image_detail(Some(ImageDetail {
+            armed: false,
+            platform: Some("linux/amd64".to_owned()),
+            cmd: vec!["./api".to_owned(), "--port".to_owned(), "8080".to_owned()],
+            entrypoint: vec![],
+            env: vec!["PATH=/usr/bin".to_owned(), "RUST_LOG=info".to_owned()],
+            exposed_ports: vec!["8080/tcp".to_owned()],
+            host: None,
+            image,
+            layers: 7,
+            user: Some("api".to_owned()),
+            working_dir: Some("/app".to_owned()),
+        }));
+
+        let expected = [
+            "                                                                                ",
+            "                                                                                ",
+            "     ╭───────────────── image localhost:5000/team/api:v2 ─────────────────╮     ",
+            "     │  id          1b2c3d4e5f6a                                          │     ",
+            "     │  tags        localhost:5000/team/api:v2                            │     ",
+            "     │  size        120.00 MB                                             │     ",
+            "     │  platform    linux/amd64                                           │     ",
+            "     │  layers      7                                                     │     ",
+            "     │  cmd         ./api --port 8080                                     │     ",
+            "     │  workdir     /app                                                  │     ",
+            "     │  user        api                                                   │     ",
+            "     │  ports       8080/tcp                                              │     ",
+            "     │  env         2                                                     │     ",
+            "     │  used by     api-1, api-2                                          │     ",
+            "     │                                                                    │     ",
+            "     │  ( p ) pull localhost:5000/team/api:v2 ( r ) remove ( esc ) close  │     ",
+            "     ╰────────────────────────────────────────────────────────────────────╯     ",
+            "                                                                                ",
+            "                                                                                ",
+        ];
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::image_detail(f, &setup.gui_state, &Theme::default());
+            })
+            .unwrap();
+
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                assert_eq!(expected_char.to_string(), result[index].symbol());
+            }
+        }
+
+        setup.gui_state.lock().get_image_detail().unwrap().armed = true;
+        setup
+            .terminal
+            .draw(|f| {
+                super::image_detail(f, &setup.gui_state, &Theme::default());
+            })
+            .unwrap();
+        let result = &setup.terminal.backend().buffer().content;
+        let row = (0..usize::from(w))
+            .map(|i| result[usize::from(w) * 15 + i].symbol())
+            .collect::<String>();
+        assert!(row.contains("│  ( r ) again to remove localhost:5000/team/api:v2 ( esc ) cancel "));
+        assert_eq!(result[usize::from(w) * 15 + 7].fg, Color::Red);
+    }
+
+    #[test]
+    /// Pull progress popup shows each layer's bar & speed, and the combined progress, speed, & eta
+    fn test_draw_blocks_pull_progress() {
+        let (w, h) = (100, 13);
+        let mut setup = test_setup(w, h, true, true);
+        let start = Instant::now();
+        let at = |secs: u64| start + std::time::Duration::from_secs(secs);
+        let mut progress = PullProgress::new("redis:7", None, start);
+        progress.update(Some("7"), "Pulling from library/redis", None, start);
+        progress.update(Some("a1b2c3d4e5f6"), "Already exists", None, start);
+        progress.update(Some("b2c3d4e5f6a1"), "Waiting", None, start);
+        progress.update(
+            Some("c3d4e5f6a1b2"),
+            "Downloading",
+            Some((1000, 20_000)),
+            at(1),
+        );
+        progress.update(
+            Some("c3d4e5f6a1b2"),
+            "Downloading",
+            Some((5000, 20_000)),
+            at(3),
+        );
+        setup.gui_state.lock().set_pull_progress(Some(progress));
+
+        let expected = [
+            "                                                                                                    ",
+            "                                                                                                    ",
+            "      ╭────────────────────────────────── pulling redis:7 ───────────────────────────────────╮      ",
+            "      │  Pulling from library/redis                                                          │      ",
+            "      │  a1b2c3d4e5f6 Already exists     ━━━━━━━━━━━━━━━━━━━━                                │      ",
+            "      │  b2c3d4e5f6a1 Waiting            ────────────────────                                │      ",
+            "      │  c3d4e5f6a1b2 Downloading        ━━━━━─────────────── 5.00 kB / 20.00 kB  2.50 kB/s  │      ",
+            "      │                                                                                      │      ",
+            "      │  5.00 kB / 20.00 kB downloaded, 2.50 kB/s, eta 6s, 3s elapsed                        │      ",
+            "      │  ( esc ) cancel                                                                      │      ",
+            "      ╰──────────────────────────────────────────────────────────────────────────────────────╯      ",
+            "                                                                                                    ",
+            "                                                                                                    ",
+        ];
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::pull_progress(f, &setup.gui_state, at(3), &Theme::default());
+            })
+            .unwrap();
+
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                assert_eq!(expected_char.to_string(), result[index].symbol());
+            }
+        }
+        assert_eq!(result[usize::from(w) * 6 + 41].fg, Color::Green);
+        assert_eq!(result[usize::from(w) * 4 + 41].fg, Color::Blue);
+    }
+
+    #[test]
+    /// When container is dead, correct colors displayed
+    fn test_draw_blocks_containers_dead() {
+        let (w, h) = (130, 6);
+        let mut setup = test_setup(w, h, true, true);
+
+        let expected = [
+            "╭ Containers 1/3 ────────────────────────────────────────────────────────────────────────────────────────────────────────────────╮",
+            "│⚪  container_1   ✖ dead               Up 1 hour    00.00%   0.00 kB / 0.00 kB          1   image_1   0.00 kB   0.00 kB          │",
+            "│   container_2   ✓ running            Up 2 hour    00.00%   0.00 kB / 0.00 kB          2   image_2   0.00 kB   0.00 kB          │",
+            "│   container_3   ✓ running            Up 3 hour    00.00%   0.00 kB / 0.00 kB          3   image_3   0.00 kB   0.00 kB          │",
+            "│                                                                                                                                │",
+            "╰────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────╯",
+        ];
+        setup.app_data.lock().containers.items[0].state = State::Dead;
+        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::containers(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
+        check_expected(expected, w, h, &setup, Color::Red);
+    }
+
+    #[test]
+    /// When container is exited, correct colors displayed
+    fn test_draw_blocks_containers_exited() {
+        let (w, h) = (130, 6);
+        let mut setup = test_setup(w, h, true, true);
+
+        let expected = [
+            "╭ Containers 1/3 ────────────────────────────────────────────────────────────────────────────────────────────────────────────────╮",
+            "│⚪  container_1   ✖ exited             Up 1 hour    00.00%   0.00 kB / 0.00 kB          1   image_1   0.00 kB   0.00 kB          │",
+            "│   container_2   ✓ running            Up 2 hour    00.00%   0.00 kB / 0.00 kB          2   image_2   0.00 kB   0.00 kB          │",
+            "│   container_3   ✓ running            Up 3 hour    00.00%   0.00 kB / 0.00 kB          3   image_3   0.00 kB   0.00 kB          │",
+            "│                                                                                                                                │",
+            "╰────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────╯",
+        ];
+        setup.app_data.lock().containers.items[0].state = State::Exited;
+        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::containers(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
+
+        check_expected(expected, w, h, &setup, Color::Red);
+    }
+    #[test]
+    /// When container is paused, correct colors displayed
+    fn test_draw_blocks_containers_removing() {
+        let (w, h) = (130, 6);
+        let mut setup = test_setup(w, h, true, true);
+
+        let expected = [
+            "╭ Containers 1/3 ────────────────────────────────────────────────────────────────────────────────────────────────────────────────╮",
+            "│⚪  container_1   removing             Up 1 hour    00.00%   0.00 kB / 0.00 kB          1   image_1   0.00 kB   0.00 kB          │",
+            "│   container_2   ✓ running            Up 2 hour    00.00%   0.00 kB / 0.00 kB          2   image_2   0.00 kB   0.00 kB          │",
+            "│   container_3   ✓ running            Up 3 hour    00.00%   0.00 kB / 0.00 kB          3   image_3   0.00 kB   0.00 kB          │",
+            "│                                                                                                                                │",
+            "╰────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────╯",
+        ];
+        setup.app_data.lock().containers.items[0].state = State::Removing;
+        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::containers(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
+
+        check_expected(expected, w, h, &setup, Color::LightRed);
+    }
+    #[test]
+    /// When container state is restarting, correct colors displayed
+    fn test_draw_blocks_containers_restarting() {
+        let (w, h) = (130, 6);
+        let mut setup = test_setup(w, h, true, true);
+
+        let expected = [
+            "╭ Containers 1/3 ────────────────────────────────────────────────────────────────────────────────────────────────────────────────╮",
+            "│⚪  container_1   ↻ restarting          Up 1 hour    00.00%   0.00 kB / 0.00 kB          1   image_1   0.00 kB   0.00 kB         │",
+            "│   container_2   ✓ running             Up 2 hour    00.00%   0.00 kB / 0.00 kB          2   image_2   0.00 kB   0.00 kB         │",
+            "│   container_3   ✓ running             Up 3 hour    00.00%   0.00 kB / 0.00 kB          3   image_3   0.00 kB   0.00 kB         │",
+            "│                                                                                                                                │",
+            "╰────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────╯",
+        ];
+        setup.app_data.lock().containers.items[0].state = State::Restarting;
+        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::containers(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
+
+        check_expected(expected, w, h, &setup, Color::LightGreen);
+    }
+    #[test]
+    /// When container state is unknown, correct colors displayed
+    fn test_draw_blocks_containers_unknown() {
+        let (w, h) = (130, 6);
+        let mut setup = test_setup(w, h, true, true);
+
+        let expected = [
+            "╭ Containers 1/3 ────────────────────────────────────────────────────────────────────────────────────────────────────────────────╮",
+            "│⚪  container_1   ? unknown            Up 1 hour    00.00%   0.00 kB / 0.00 kB          1   image_1   0.00 kB   0.00 kB          │",
+            "│   container_2   ✓ running            Up 2 hour    00.00%   0.00 kB / 0.00 kB          2   image_2   0.00 kB   0.00 kB          │",
+            "│   container_3   ✓ running            Up 3 hour    00.00%   0.00 kB / 0.00 kB          3   image_3   0.00 kB   0.00 kB          │",
+            "│                                                                                                                                │",
+            "╰────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────╯",
+        ];
+        setup.app_data.lock().containers.items[0].state = State::Unknown;
+        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::containers(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
+        check_expected(expected, w, h, &setup, Color::Red);
+    }
+    // ********** //
+    // Logs panel //
+    // ********** //
+
+    #[test]
+    /// No logs, panel unselected, then selected, border color changes correctly
+    fn test_draw_blocks_logs_none() {
+        let (w, h) = (25, 6);
+        let mut setup = test_setup(w, h, true, true);
+        setup.app_data.lock().containers = StatefulList::new(vec![]);
+
+        let expected = [
+            "╭ Logs ─────────────────╮",
+            "│     no logs found     │",
+            "│                       │",
+            "│                       │",
+            "│                       │",
+            "╰───────────────────────╯",
+        ];
+
+        let _fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::logs(&setup.app_data, setup.area, f, &setup.fd, &setup.gui_state);
+            })
+            .unwrap();
+
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                let result_cell = &result[index];
+
+                assert_eq!(result_cell.symbol(), expected_char.to_string());
+                assert_eq!(result_cell.fg, Color::Reset);
+            }
+        }
+
+        setup.gui_state.lock().next_panel();
+        setup.gui_state.lock().next_panel();
+        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+
+        // When selected, has a blue border
+        setup
+            .terminal
+            .draw(|f| {
+                super::logs(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
+
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                let result_cell = &result[index];
+
+                assert_eq!(result_cell.symbol(), expected_char.to_string());
+                if BORDER_CHARS.contains(&result_cell.symbol()) {
+                    assert_eq!(result_cell.fg, Color::LightCyan);
+                }
+            }
+        }
+    }
+
+    #[test]
+    /// Parsing logs, spinner visible, and then animates by one frame
+    fn test_draw_blocks_logs_parsing() {
+        let (w, h) = (25, 6);
+        let mut setup = test_setup(w, h, true, true);
+        let uuid = Uuid::new_v4();
+        setup.gui_state.lock().next_loading(uuid);
+
+        let expected = [
+            "╭ Logs - container_1 ───╮",
+            "│    parsing logs ⠙     │",
+            "│                       │",
+            "│                       │",
+            "│                       │",
+            "╰───────────────────────╯",
+        ];
+
+        let mut fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+        fd.init = true;
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::logs(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
+
+        let test = |terminal: &Terminal<TestBackend>, expected: [&str; 6]| {
+            let result = &terminal.backend().buffer().content;
+            for (row_index, row) in expected.iter().enumerate() {
+                for (char_index, expected_char) in row.chars().enumerate() {
+                    let index = row_index * usize::from(w) + char_index;
+                    let result_cell = &result[index];
+
+                    assert_eq!(result_cell.symbol(), expected_char.to_string());
+                    assert_eq!(result_cell.fg, Color::Reset);
+                }
+            }
+        };
+
+        test(&setup.terminal, expected);
+
+        // animation moved by one frame
+        setup.gui_state.lock().next_loading(uuid);
+
+        let expected = [
+            "╭ Logs - container_1 ───╮",
+            "│    parsing logs ⠹     │",
+            "│                       │",
+            "│                       │",
+            "│                       │",
+            "╰───────────────────────╯",
+        ];
+
+        let mut fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+        fd.init = true;
+        setup
+            .terminal
+            .draw(|f| {
+                super::logs(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
+
+        test(&setup.terminal, expected);
+    }
+
+    #[test]
+    /// Logs correct displayed, changing log state also draws correctly
+    fn test_draw_blocks_logs_some() {
+        let (w, h) = (25, 6);
+        let mut setup = test_setup(w, h, true, true);
+
+        insert_logs(&setup);
+
+        let test = |terminal: &Terminal<TestBackend>,
+                    expected: [&str; 6],
+                    range: RangeInclusive<usize>| {
+            let result = &terminal.backend().buffer().content;
+
+            for (row_index, row) in expected.iter().enumerate() {
+                for (char_index, expected_char) in row.chars().enumerate() {
+                    let index = row_index * usize::from(w) + char_index;
+                    let result_cell = &result[index];
+
+                    assert_eq!(result_cell.symbol(), expected_char.to_string());
+                    assert_eq!(result_cell.fg, Color::Reset);
+
+                    if range.contains(&index) {
+                        assert_eq!(result_cell.modifier, Modifier::BOLD);
+                    } else {
+                        assert!(result_cell.modifier.is_empty());
+                    }
+                }
+            }
+        };
+
+        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+        setup
+            .terminal
+            .draw(|f| {
+                super::logs(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
+        let expected = [
+            "╭ Logs 3/3 - container_1╮",
+            "│  line 1               │",
+            "│  line 2               │",
+            "│▶ line 3               │",
+            "│                       │",
+            "╰───────────────────────╯",
+        ];
+        test(&setup.terminal, expected, 76..=98);
+
+        // Change selected log line
+        setup.app_data.lock().log_previous();
+        let _fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::logs(&setup.app_data, setup.area, f, &setup.fd, &setup.gui_state);
+            })
+            .unwrap();
+
+        let expected = [
+            "╭ Logs 2/3 - container_1╮",
+            "│  line 1               │",
+            "│▶ line 2               │",
+            "│  line 3               │",
+            "│                       │",
+            "╰───────────────────────╯",
+        ];
+        test(&setup.terminal, expected, 51..=73);
+    }
+
+    #[test]
+    /// Full (long) name displayed in logs border
+    fn test_draw_blocks_logs_long_name() {
+        let (w, h) = (80, 6);
+        let mut setup = test_setup(w, h, true, true);
+        setup.app_data.lock().containers.items[0].name =
+            ContainerName::from("a_long_container_name_for_the_purposes_of_this_test");
+        setup.app_data.lock().containers.items[0].image =
+            ContainerImage::from("a_long_image_name_for_the_purposes_of_this_test");
+
+        insert_logs(&setup);
+
+        let expected = [
+            "╭ Logs 3/3 - a_long_container_name_for_the_purposes_of_this_test ──────────────╮",
+            "│  line 1                                                                      │",
+            "│  line 2                                                                      │",
+            "│▶ line 3                                                                      │",
+            "│                                                                              │",
+            "╰──────────────────────────────────────────────────────────────────────────────╯",
+        ];
+
+        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+        setup
+            .terminal
+            .draw(|f| {
+                super::logs(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
+
+        let result = &setup.terminal.backend().buffer().content;
+
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                let result_cell = &result[index];
+
+                assert_eq!(result_cell.symbol(), expected_char.to_string());
+            }
+        }
+    }
+
+    // ************ //
+    // Charts panel //
+    // ************ //
+
+    const EXPECTED: [&str; 10] = [
+        "╭───────────── cpu 03.00% ─────────────╮╭────────── memory 30.00 kB ───────────╮",
+        "│10.00%│    •                          ││100.00 kB│   ••                       │",
+        "│      │   ••                          ││         │   ••                       │",
+        "│      │  •••                          ││         │  • •                       │",
+        "│      │  • •                          ││         │ •  •                       │",
+        "│      │ •   ••                        ││         │••  ••                      │",
+        "│      │•    •                         ││         │•   •                       │",
+        "│      │•    •                         ││         │•   •                       │",
+        "│      │                               ││         │                            │",
+        "╰──────────────────────────────────────╯╰──────────────────────────────────────╯",
+    ];
+    const MEMORY_INDEX: [usize; 16] = [
+        134, 135, 214, 215, 293, 295, 372, 375, 451, 452, 455, 456, 531, 535, 611, 615,
+    ];
+
+    const CPU_INDEX: [usize; 15] = [
+        92, 171, 172, 250, 251, 252, 330, 332, 409, 413, 414, 488, 493, 568, 573,
+    ];
+
+    #[allow(clippy::cast_precision_loss)]
+    // Add fixed data to the cpu & mem vecdeques, that match the above data
+    fn insert_chart_data(setup: &TuiTestSetup) {
+        for i in 1..=10 {
+            setup.app_data.lock().update_stats_by_id(
+                &setup.ids[0],
+                Some(i as f64),
+                Some(i * 10000),
+                i * 10000,
+                i,
+                i,
+            );
+        }
+        for i in 1..=3 {
+            setup.app_data.lock().update_stats_by_id(
+                &setup.ids[0],
+                Some(i as f64),
+                Some(i * 10000),
+                i * 10000,
+                i,
+                i,
+            );
+        }
+    }
+    #[test]
+    /// When status is Running, but not data, charts drawn without dots etc
+    fn test_draw_blocks_charts_running_none() {
+        let (w, h) = (80, 10);
+        let mut setup = test_setup(w, h, true, true);
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::chart(f, setup.area, &setup.app_data);
+            })
+            .unwrap();
+
+        let expected = [
+            "╭───────────── cpu 00.00% ─────────────╮╭─────────── memory 0.00 kB ───────────╮",
+            "│00.00%│                               ││0.00 kB│                              │",
+            "│      │                               ││       │                              │",
+            "│      │                               ││       │                              │",
+            "│      │                               ││       │                              │",
+            "│      │                               ││       │                              │",
+            "│      │                               ││       │                              │",
+            "│      │                               ││       │                              │",
+            "│      │                               ││       │                              │",
+            "╰──────────────────────────────────────╯╰──────────────────────────────────────╯",
+        ];
+
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                let result_cell = &result[index];
+
+                assert_eq!(result_cell.symbol(), expected_char.to_string());
+
+                match index {
+                    // chart tiles - cpu 03.00% && memory 30.00 kB - are green
+                    14..=25 | 52..=67 => {
+                        assert_eq!(result_cell.fg, Color::Green);
+                        assert_eq!(result_cell.modifier, Modifier::BOLD);
+                    }
+                    // Cpu & Memory max are orange and bold
+                    81..=86 | 121..=127 => {
+                        assert_eq!(result_cell.fg, ORANGE);
+                        assert_eq!(result_cell.modifier, Modifier::BOLD);
+                    }
+                    // All others
+                    _ => {
+                        assert_eq!(result_cell.fg, Color::Reset);
+                        assert!(result_cell.modifier.is_empty());
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    /// When status is Running, charts correctly drawn
+    fn test_draw_blocks_charts_running_some() {
+        let (w, h) = (80, 10);
+        let mut setup = test_setup(w, h, true, true);
+
+        insert_chart_data(&setup);
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::chart(f, setup.area, &setup.app_data);
+            })
+            .unwrap();
+
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in EXPECTED.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                let result_cell = &result[index];
+
+                assert_eq!(result_cell.symbol(), expected_char.to_string());
+                match index {
+                    // chart tiles - cpu 03.00% && memory 30.00 kB - are green
+                    14..=25 | 51..=67 => {
+                        assert_eq!(result_cell.fg, Color::Green);
+                        assert_eq!(result_cell.modifier, Modifier::BOLD);
+                    }
+                    // Cpu & Memory max are orange and bold
+                    81..=86 | 121..=129 => {
+                        assert_eq!(result_cell.fg, ORANGE);
+                        assert_eq!(result_cell.modifier, Modifier::BOLD);
+                    }
+                    // cpu dots are magenta
+                    _x if CPU_INDEX.contains(&index) => {
+                        assert_eq!(result_cell.fg, Color::Magenta);
+                        assert!(result_cell.modifier.is_empty());
+                    }
+                    // memory dots are cyan
+                    _x if MEMORY_INDEX.contains(&index) => {
+                        assert_eq!(result_cell.fg, Color::Cyan);
+                        assert!(result_cell.modifier.is_empty());
+                    }
+                    // All others
+                    _ => {
+                        assert_eq!(result_cell.fg, Color::Reset);
+                        assert!(result_cell.modifier.is_empty());
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    /// Whens status paused, some text is now Yellow, and a paused overlay is drawn on each chart
+    fn test_draw_blocks_charts_paused() {
+        let (w, h) = (80, 10);
+        let mut setup = test_setup(w, h, true, true);
+
+        insert_chart_data(&setup);
+        setup.app_data.lock().containers.items[0].state = State::Paused;
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::chart(f, setup.area, &setup.app_data);
+            })
+            .unwrap();
+
+        let expected = [
+            "╭───────────── cpu 03.00% ─────────────╮╭────────── memory 30.00 kB ───────────╮",
+            "│10.00%│    •                   paused ││100.00 kB│   ••                paused │",
+            "│      │   ••                          ││         │   ••                       │",
+            "│      │  •••                          ││         │  • •                       │",
+            "│      │  • •                          ││         │ •  •                       │",
+            "│      │ •   ••                        ││         │••  ••                      │",
+            "│      │•    •                         ││         │•   •                       │",
+            "│      │•    •                         ││         │•   •                       │",
+            "│      │                               ││         │                            │",
+            "╰──────────────────────────────────────╯╰──────────────────────────────────────╯",
+        ];
+
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                let result_cell = &result[index];
+
+                assert_eq!(result_cell.symbol(), expected_char.to_string());
+                match index {
+                    // Titles, y axis, and paused overlays are yellow
+                    14..=25 | 51..=67 | 81..=86 | 111..=118 | 121..=129 | 151..=158 => {
+                        assert_eq!(result_cell.fg, Color::Yellow);
+                        assert_eq!(result_cell.modifier, Modifier::BOLD);
+                    }
+                    _x if CPU_INDEX.contains(&index) => {
+                        assert_eq!(result_cell.fg, Color::Magenta);
+                        assert!(result_cell.modifier.is_empty());
+                    }
+                    // memory dots are cyan
+                    _x if MEMORY_INDEX.contains(&index) => {
+                        assert_eq!(result_cell.fg, Color::Cyan);
+                        assert!(result_cell.modifier.is_empty());
+                    }
+                    // All others
+                    _ => {
+                        assert_eq!(result_cell.fg, Color::Reset);
+                        assert!(result_cell.modifier.is_empty());
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    /// When dead, text is read
+    fn test_draw_blocks_charts_dead() {
+        let (w, h) = (80, 10);
+        let mut setup = test_setup(w, h, true, true);
+        insert_chart_data(&setup);
+        setup.app_data.lock().containers.items[0].state = State::Dead;
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::chart(f, setup.area, &setup.app_data);
+            })
+            .unwrap();
+
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in EXPECTED.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                let result_cell = &result[index];
+
+                assert_eq!(result_cell.symbol(), expected_char.to_string());
+                match index {
+                    // Titles and y axis are red
+                    14..=25 | 51..=67 | 81..=86 | 121..=129 => {
+                        assert_eq!(result_cell.fg, Color::Red);
+                        assert_eq!(result_cell.modifier, Modifier::BOLD);
+                    }
+                    // cpu dots are magenta
+                    _x if CPU_INDEX.contains(&index) => {
+                        assert_eq!(result_cell.fg, Color::Magenta);
+                        assert!(result_cell.modifier.is_empty());
+                    }
+                    // memory dots are cyan
+                    _x if MEMORY_INDEX.contains(&index) => {
+                        assert_eq!(result_cell.fg, Color::Cyan);
+                        assert!(result_cell.modifier.is_empty());
+                    }
+                    // All others
+                    _ => {
+                        assert_eq!(result_cell.fg, Color::Reset);
+                        assert!(result_cell.modifier.is_empty());
+                    }
+                }
+            }
+        }
+    }
+
+    // ******* //
+    // Headers //
+    // ******* //
+
+    #[test]
+    /// Heading back only has show/exit help when no containers, correctly coloured
+    fn test_draw_blocks_headers_no_containers() {
+        let (w, h) = (140, 1);
+        let mut setup = test_setup(w, h, true, true);
+        setup.app_data.lock().containers = StatefulList::new(vec![]);
+
+        let mut fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+
+        let expected =  "                                                                                                                         ( h ) show help    ";
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::heading_bar(setup.area, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
+
+        let result = &setup.terminal.backend().buffer().content;
+        for (index, expected_char) in expected.chars().enumerate() {
+            let result_cell = &result[index];
+
+            assert_eq!(result_cell.symbol(), expected_char.to_string());
+            assert_eq!(result_cell.bg, Color::Magenta);
+            assert_eq!(result_cell.fg, Color::White);
+        }
+
+        fd.help_visible = true;
+        let expected =  "                                                                                                                         ( h ) exit help    ";
+        setup
+            .terminal
+            .draw(|f| {
+                super::heading_bar(setup.area, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
+
+        let result = &setup.terminal.backend().buffer().content;
+        for (index, expected_char) in expected.chars().enumerate() {
+            let result_cell = &result[index];
+
+            assert_eq!(result_cell.symbol(), expected_char.to_string());
+            assert_eq!(result_cell.bg, Color::Magenta);
+            assert_eq!(result_cell.fg, Color::Black);
+        }
+    }
+
+    #[test]
+    /// Show a clock skew warning, in yellow, before the help text
+    fn test_draw_blocks_headers_clock_skew() {
+        let (w, h) = (140, 1);
+        let mut setup = test_setup(w, h, true, true);
+        setup.app_data.lock().containers = StatefulList::new(vec![]);
+        setup.app_data.lock().set_clock_skew(Some(-200));
+
+        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+
+        let expected =  "                                                                                               ⚠ daemon clock -3m 20s    ( h ) show help    ";
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::heading_bar(setup.area, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
+
+        let result = &setup.terminal.backend().buffer().content;
+        for (index, expected_char) in expected.chars().enumerate() {
+            let result_cell = &result[index];
+
+            assert_eq!(result_cell.symbol(), expected_char.to_string());
+            assert_eq!(result_cell.bg, Color::Magenta);
+            if (95..=120).contains(&index) {
+                assert_eq!(result_cell.fg, Color::Yellow);
+            } else {
+                assert_eq!(result_cell.fg, Color::White);
+            }
+        }
+    }
+
+    #[test]
+    /// Show the host and latency before the help text, in yellow when the latency is high
+    fn test_draw_blocks_headers_latency() {
+        let (w, h) = (140, 1);
+        let mut setup = test_setup(w, h, true, true);
+        setup.app_data.lock().containers = StatefulList::new(vec![]);
+        setup
+            .app_data
+            .lock()
+            .set_latency(Some(std::time::Duration::from_millis(12)));
+
+        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+        let expected = format!("{}local 12ms    ( h ) show help    ", " ".repeat(107));
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::heading_bar(setup.area, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
+
+        let result = &setup.terminal.backend().buffer().content;
+        for (index, expected_char) in expected.chars().enumerate() {
+            let result_cell = &result[index];
+            assert_eq!(result_cell.symbol(), expected_char.to_string());
+            assert_eq!(result_cell.bg, Color::Magenta);
+            assert_eq!(result_cell.fg, Color::White);
+        }
+
+        setup
+            .app_data
+            .lock()
+            .set_latency(Some(std::time::Duration::from_millis(1500)));
+        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+        setup
+            .terminal
+            .draw(|f| {
+                super::heading_bar(setup.area, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
+        let result = &setup.terminal.backend().buffer().content;
+        assert_eq!(result[105].symbol(), "l");
+        assert_eq!(result[105].fg, Color::Yellow);
+        assert_eq!(result[125].fg, Color::White);
+
+        // A stretched refresh interval is shown after the latency, also in yellow
+        setup
+            .app_data
+            .lock()
+            .set_latency(Some(std::time::Duration::from_millis(12)));
+        setup
+            .app_data
+            .lock()
+            .set_refresh_stretch(Some(std::time::Duration::from_secs(3)));
+        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+        setup
+            .terminal
+            .draw(|f| {
+                super::heading_bar(setup.area, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
+        let expected = format!(
+            "{}local 12ms, every 3s    ( h ) show help    ",
+            " ".repeat(97)
+        );
+        let result = &setup.terminal.backend().buffer().content;
+        for (index, expected_char) in expected.chars().enumerate() {
+            assert_eq!(result[index].symbol(), expected_char.to_string());
+        }
+        assert_eq!(result[97].fg, Color::Yellow);
+    }
+
+    #[test]
+    /// Status bar template rendered, with alerts, on a magenta background
+    fn test_draw_blocks_status_bar() {
+        let (w, h) = (90, 1);
+        let mut setup = test_setup(w, h, true, true);
+        setup.app_data.lock().args.status_bar =
+            Some("{host} {running}/{total} {alerts} {refresh}".to_owned());
+        setup.app_data.lock().set_clock_skew(Some(45));
+
+        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+
+        let expected = " local 3/3 daemon clock +45s -                                                            ";
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::status_bar(
+                    setup.area,
+                    f,
+                    fd.status_bar.as_deref().unwrap_or_default(),
+                    &fd.theme,
+                );
+            })
+            .unwrap();
+
+        let result = &setup.terminal.backend().buffer().content;
+        for (index, expected_char) in expected.chars().enumerate() {
+            let result_cell = &result[index];
+
+            assert_eq!(result_cell.symbol(), expected_char.to_string());
+            assert_eq!(result_cell.bg, Color::Magenta);
+            assert_eq!(result_cell.fg, Color::Black);
+        }
+
+        // The text is drawn in the theme's header color
+        let mut theme = fd.theme;
+        theme.set("header-fg=blue").unwrap();
+        setup
+            .terminal
+            .draw(|f| {
+                super::status_bar(
+                    setup.area,
+                    f,
+                    fd.status_bar.as_deref().unwrap_or_default(),
+                    &theme,
+                );
+            })
+            .unwrap();
+        let result = &setup.terminal.backend().buffer().content;
+        assert_eq!(result[1].fg, Color::Blue);
+    }
+
+    #[test]
+    /// Show all headings when containers present, colors valid
+    fn test_draw_blocks_headers_some_containers() {
+        let (w, h) = (140, 1);
+        let mut setup = test_setup(w, h, true, true);
+        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+
+        let expected =   "           name       state               status       cpu        memory/limit         id     image      ↓ rx      ↑ tx    ( h ) show help  ";
+        setup
+            .terminal
+            .draw(|f| {
+                super::heading_bar(setup.area, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
+
+        let result = &setup.terminal.backend().buffer().content;
+        for (index, expected_char) in expected.chars().enumerate() {
+            let result_cell = &result[index];
+
+            assert_eq!(result_cell.symbol(), expected_char.to_string());
+            assert_eq!(result_cell.bg, Color::Magenta);
+            assert_eq!(
+                result_cell.fg,
+                match index {
+                    (2..=122) => Color::Black,
+                    _ => Color::White,
+                }
+            );
+        }
+    }
+
+    #[test]
+    /// Test all combination of headers & sort by
+    fn test_draw_blocks_headers_sort_containers() {
+        let (w, h) = (140, 1);
+        let mut setup = test_setup(w, h, true, true);
+        let mut fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+        let mut test = |expected: &str, range: RangeInclusive<usize>, x: (Header, SortedOrder)| {
+            fd.sorted_by = Some(x);
+
+            setup
+                .terminal
+                .draw(|f| {
+                    super::heading_bar(setup.area, f, &fd, &setup.gui_state);
+                })
+                .unwrap();
+
+            let result = &setup.terminal.backend().buffer().content;
+            for (index, expected_char) in expected.chars().enumerate() {
+                let result_cell = &result[index];
+                assert_eq!(result_cell.symbol(), expected_char.to_string());
+                assert_eq!(result_cell.bg, Color::Magenta);
+                assert_eq!(
+                    result_cell.fg,
+                    match index {
+                        0 | 1 => Color::White,
+                        // given range | help section
+                        x if range.contains(&x) || (123..=139).contains(&x) => Color::White,
+                        _ => Color::Black,
+                    }
+                );
+            }
+        };
+
+        // Name
+        test("         ▲ name       state               status       cpu        memory/limit         id     image      ↓ rx      ↑ tx    ( h ) show help  ", 1..=14, (Header::Name, SortedOrder::Asc));
+        test("         ▼ name       state               status       cpu        memory/limit         id     image      ↓ rx      ↑ tx    ( h ) show help  ", 1..=14, (Header::Name, SortedOrder::Desc));
+
+        // state
+        test("           name     ▲ state               status       cpu        memory/limit         id     image      ↓ rx      ↑ tx    ( h ) show help  ", 15..=26, (Header::State, SortedOrder::Asc));
+        test("           name     ▼ state               status       cpu        memory/limit         id     image      ↓ rx      ↑ tx    ( h ) show help  ", 15..=26, (Header::State, SortedOrder::Desc));
+
+        // status
+        test("           name       state             ▲ status       cpu        memory/limit         id     image      ↓ rx      ↑ tx    ( h ) show help  ", 27..=47, (Header::Status, SortedOrder::Asc));
+        test("           name       state             ▼ status       cpu        memory/limit         id     image      ↓ rx      ↑ tx    ( h ) show help  ", 27..=47, (Header::Status, SortedOrder::Desc));
+
+        // cpu
+        test("           name       state               status     ▲ cpu        memory/limit         id     image      ↓ rx      ↑ tx    ( h ) show help  ", 48..=57, (Header::Cpu, SortedOrder::Asc));
+        test("           name       state               status     ▼ cpu        memory/limit         id     image      ↓ rx      ↑ tx    ( h ) show help  ", 48..=57, (Header::Cpu, SortedOrder::Desc));
+
+        // mem
+        test("           name       state               status       cpu      ▲ memory/limit         id     image      ↓ rx      ↑ tx    ( h ) show help  ", 58..=77, (Header::Memory, SortedOrder::Asc));
+        test("           name       state               status       cpu      ▼ memory/limit         id     image      ↓ rx      ↑ tx    ( h ) show help  ", 58..=77, (Header::Memory, SortedOrder::Desc));
+
+        // id
+        test("           name       state               status       cpu        memory/limit       ▲ id     image      ↓ rx      ↑ tx    ( h ) show help  ", 78..=88, (Header::Id, SortedOrder::Asc));
+        test("           name       state               status       cpu        memory/limit       ▼ id     image      ↓ rx      ↑ tx    ( h ) show help  ", 78..=88, (Header::Id, SortedOrder::Desc));
+
+        // image
+        test("           name       state               status       cpu        memory/limit         id   ▲ image      ↓ rx      ↑ tx    ( h ) show help  ", 89..=98, (Header::Image, SortedOrder::Asc));
+        test("           name       state               status       cpu        memory/limit         id   ▼ image      ↓ rx      ↑ tx    ( h ) show help  ", 89..=98, (Header::Image, SortedOrder::Desc));
+
+        // rx
+        test("           name       state               status       cpu        memory/limit         id     image    ▲ ↓ rx      ↑ tx    ( h ) show help  ", 99..=108, (Header::Rx, SortedOrder::Asc));
+        test("           name       state               status       cpu        memory/limit         id     image    ▼ ↓ rx      ↑ tx    ( h ) show help  ", 99..=108, (Header::Rx, SortedOrder::Desc));
+
+        // tx
+        test("           name       state               status       cpu        memory/limit         id     image      ↓ rx    ▲ ↑ tx    ( h ) show help  ", 109..=118, (Header::Tx, SortedOrder::Asc));
+        test("           name       state               status       cpu        memory/limit         id     image      ↓ rx    ▼ ↑ tx    ( h ) show help  ", 109..=118, (Header::Tx, SortedOrder::Desc));
+    }
+
+    #[test]
+    /// Show animation
+    fn test_draw_blocks_headers_animation() {
+        let (w, h) = (140, 1);
+        let mut setup = test_setup(w, h, true, true);
+        let uuid = Uuid::new_v4();
+        setup.gui_state.lock().next_loading(uuid);
+        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::heading_bar(setup.area, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
+
+        let expected =   " ⠙         name       state               status       cpu        memory/limit         id     image      ↓ rx      ↑ tx    ( h ) show help  ";
+
+        let result = &setup.terminal.backend().buffer().content;
+        for (index, expected_char) in expected.chars().enumerate() {
+            let result_cell = &result[index];
+
+            assert_eq!(result_cell.symbol(), expected_char.to_string());
+            assert_eq!(result_cell.bg, Color::Magenta);
+            assert_eq!(
+                result_cell.fg,
+                match index {
+                    (2..=122) => Color::Black,
+                    _ => Color::White,
+                }
+            );
+        }
+    }
+
+    // ********** //
+    // Help popup //
+    #[test]
+    /// The daemon's details are listed, followed by its warnings
+    fn test_draw_blocks_daemon() {
+        let (w, h) = (70, 17);
+        let mut setup = test_setup(w, h, true, true);
+        setup.app_data.lock().set_daemon_info(DaemonInfo {
+            cgroup: Some("v2 (systemd)".to_owned()),
+            kernel: Some("6.8.0".to_owned()),
+            name: Some("docker-host".to_owned()),
+            os: Some("Ubuntu 24.04".to_owned()),
+            storage_driver: Some("overlay2".to_owned()),
+            version: Some("26.1.3".to_owned()),
+            warnings: vec![
+                "No swap limit support".to_owned(),
+                "bridge-nf-call-iptables is disabled".to_owned(),
+            ],
+        });
+
+        let expected = [
+            "                                                                      ",
+            "                                                                      ",
+            "             ╭───────────── docker daemon ──────────────╮             ",
+            "             │  name     docker-host                    │             ",
+            "             │  version  26.1.3                         │             ",
+            "             │  os       Ubuntu 24.04, kernel 6.8.0     │             ",
+            "             │  storage  overlay2                       │             ",
+            "             │  cgroup   v2 (systemd)                   │             ",
+            "             │                                          │             ",
+            "             │  2 warnings                              │             ",
+            "             │  ! No swap limit support                 │             ",
+            "             │  ! bridge-nf-call-iptables is disabled   │             ",
+            "             │                                          │             ",
+            "             │  ( esc ) close                           │             ",
+            "             ╰──────────────────────────────────────────╯             ",
+            "                                                                      ",
+            "                                                                      ",
+        ];
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::daemon(f, &setup.app_data, &Theme::default());
+            })
+            .unwrap();
+
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                assert_eq!(expected_char.to_string(), result[index].symbol());
+            }
         }
+    }
+    // ********** //
+    #[test]
+    #[allow(clippy::too_many_lines)]
+    /// This will cause issues once the version has more than the current 5 chars (0.5.0)
+    // Help  popup is drawn correctly
+    fn test_draw_blocks_help() {
+        let (w, h) = (87, 86);
+        let mut setup = test_setup(w, h, true, true);
 
-        let gui_state = GuiState::default();
+        setup
+            .terminal
+            .draw(|f| {
+                super::help_box(f, &Theme::default());
+            })
+            .unwrap();
 
-        let app_data = Arc::new(Mutex::new(app_data));
-        let gui_state = Arc::new(Mutex::new(gui_state));
+        let expected = [
+                "                                                                                       ".to_owned(),
+                format!(" ╭ {VERSION} ────────────────────────────────────────────────────────────────────────────╮ "),
+                " │                                                                                   │ ".to_owned(),
+                " │                                      88                                           │ ".to_owned(),
+                " │                                      88                                           │ ".to_owned(),
+                " │                                      88                                           │ ".to_owned(),
+                " │             ,adPPYba,   8b,     ,d8  88   ,d8    ,adPPYba,  8b,dPPYba,            │ ".to_owned(),
+                r#" │            a8"     "8a   `Y8, ,8P'   88 ,a8"    a8P_____88  88P'   "Y8            │ "#.to_owned(),
+                r#" │            8b       d8     )888(     8888[      8PP"""""""  88                    │ "#.to_owned(),
+                r#" │            "8a,   ,a8"   ,d8" "8b,   88`"Yba,   "8b,   ,aa  88                    │ "#.to_owned(),
+                r#" │             `"YbbdP"'   8P'     `Y8  88   `Y8a   `"Ybbd8"'  88                    │ "#.to_owned(),
+                " │                                                                                   │ ".to_owned(),
+                " │                 A simple tui to view & control docker containers                  │ ".to_owned(),
+                " │                                                                                   │ ".to_owned(),
+                " │ ( tab ) or ( shift+tab ) change panels                                            │ ".to_owned(),
+                " │ ( ↑ ↓ ) or ( j k ) or ( PgUp PgDown ) or ( Home End ) change selected line        │ ".to_owned(),
+                " │ ( enter ) send docker container command, or expand a truncated log line           │ ".to_owned(),
+                " │ ( e ) exec into a container                                                       │ ".to_owned(),
+                " │ ( E ) attach to the main process of a container, as docker attach                 │ ".to_owned(),
+                " │ ( o ) run a command in a container, as a user, with env, or without a shell       │ ".to_owned(),
+                " │ ( r ) recreate a container, with changes to its image, env, ports, & mounts       │ ".to_owned(),
+                " │ ( i ) switch the image tag of a container, from the local tags, & recreate it     │ ".to_owned(),
+                " │ ( l ) show the lifecycle timeline of a container, over the last day               │ ".to_owned(),
+                " │ ( ? ) inspect a container, as a tree that can be expanded & collapsed             │ ".to_owned(),
+                " │ ( ! ) toggle the events pane, container exits, health changes, & image pulls      │ ".to_owned(),
+                " │ ( > ) send lines to the stdin of a container started with -i                      │ ".to_owned(),
+                " │ ( ^ ) pull the latest image of a container, & recreate it if it has changed       │ ".to_owned(),
+                " │ ( + ) run a new container, with a name, env, ports, mounts, & restart policy      │ ".to_owned(),
+                " │ ( - ) capture the packets of a container, with tcpdump, & save the pcap           │ ".to_owned(),
+                " │ ( * ) memory breakdown, anonymous vs page cache, of the selected container        │ ".to_owned(),
+                " │ ( } ) cpu usage of each core, of the selected container                           │ ".to_owned(),
+                " │ ( ` ) resize, or hide, the panes, & save the layout                               │ ".to_owned(),
+            " │ ( space ) mark the selected container, to send a control to every marked one      │ ".to_owned(),
+            " │ ( backspace ) jump back to the previously viewed container                        │ ".to_owned(),
+            " │ ( \\ ) show the recently viewed containers                                         │ ".to_owned(),
+                " │ ( % ) cleanup, disk usage, & prune containers, images, volumes, or networks       │ ".to_owned(),
+                " │ ( p ) prune images, by age, keeping the newest tags of each repository            │ ".to_owned(),
+                " │ ( b ) view the build cache, & remove selected records                             │ ".to_owned(),
+                " │ ( u ) view the network traffic of each container, since oxker started             │ ".to_owned(),
+                " │ ( c ) check if a container can reach another container's port                     │ ".to_owned(),
+                " │ ( d ) view the docker daemon's details, & any warnings it reports                 │ ".to_owned(),
+                " │ ( z ) standby, pause drawing & slow updates, until a key is pressed               │ ".to_owned(),
+                " │ ( h ) toggle this help information                                                │ ".to_owned(),
+                " │ ( s ) save logs to file, as text, json lines, or csv                              │ ".to_owned(),
+                " │ ( < ) load older log lines, from before the --tail, or dropped by --max-log-lines │ ".to_owned(),
+                " │ ( { ) cycle json log lines, as logged, pretty-printed, or as key=value            │ ".to_owned(),
+                " │ ( T ) cycle log timestamps, off, in utc, or in local time                         │ ".to_owned(),
+                " │ ( = ) lock the logs panel to a container, while selecting others                  │ ".to_owned(),
+                " │ ( ~ ) show network & disk throughput, as columns, then as charts                  │ ".to_owned(),
+                " │ ( ; ) scan container environments for plaintext credentials                       │ ".to_owned(),
+                " │ ( : ) update the cpu & memory limits of the selected container                    │ ".to_owned(),
+                " │ ( ' ) show the health check of the selected container, & its recent probes        │ ".to_owned(),
+                " │ ( . ) refresh the selected container every 250ms for 2 minutes, or cancel         │ ".to_owned(),
+                " │ ( , ) show the ports of the selected container, to open, or forward               │ ".to_owned(),
+                " │ ( _ ) browse the files of the selected container, & copy them to file             │ ".to_owned(),
+                " │ ( a ) view log lines that matched a --watch pattern                               │ ".to_owned(),
+                " │ ( w ) export a container, as a docker run command & compose service, to file      │ ".to_owned(),
+                " │ ( f ) save the logs of a container's compose project, & a merged timeline         │ ".to_owned(),
+                " │ ( # ) save a snapshot of the host, containers, inspects, events, stats, & logs    │ ".to_owned(),
+                " │ ( [ ] ) zoom the cpu & memory charts out, or in, to the most recent stats         │ ".to_owned(),
+                " │ ( | ) freeze the charts, or unfreeze them, stats are still collected              │ ".to_owned(),
+                " │ ( $ ) save the cpu & memory history of a container, as csv, to file               │ ".to_owned(),
+                " │ ( y ) cycle the images, volumes, networks, & services panels, to manage them      │ ".to_owned(),
+                " │ ( x ) save a snapshot of the screen, as text & html, to file                      │ ".to_owned(),
+                " │ ( n ) edit the note of a container, shown under its logs                          │ ".to_owned(),
+                " │ ( / ) search containers by name, image, or note, or the logs panel - esc to clear │ ".to_owned(),
+                " │ ( \" ) fuzzy find a container by name, image, or project, & select it              │ ".to_owned(),
+                " │ ( ( ) mark a container, then compare its settings with another container          │ ".to_owned(),
+                " │ ( ) ) show, or hide, containers by name, label, or status                         │ ".to_owned(),
+                " │ ( n ) or ( N ) jump between logs search matches - ( tab ) toggles regex           │ ".to_owned(),
+                " │ ( & ) filter the logs panel, hiding lines, e.g. error !healthcheck - esc to clear │ ".to_owned(),
+                " │ ( @ ) schedule a restart, start, stop, or save logs, e.g. restart daily at 03:00  │ ".to_owned(),
+                " │ ( m ) toggle mouse capture - if disabled, text on screen can be selected & copied │ ".to_owned(),
+                " │ ( 0 ) stop sort                                                                   │ ".to_owned(),
+                " │ ( 1 - 9 ) sort by header - or click header                                        │ ".to_owned(),
+                " │ ( t ) cycle top mode, continuously sort by cpu or memory                          │ ".to_owned(),
+                " │ ( g ) cycle grouping, list containers under their image, pod, project, or label   │ ".to_owned(),
+                " │ ( ← → ) or ( enter ) collapse or expand selected group                            │ ".to_owned(),
+                " │ ( v ) switch host tab, when monitoring multiple hosts                             │ ".to_owned(),
+                " │ ( q ) quit at any time                                                            │ ".to_owned(),
+                " │                                                                                   │ ".to_owned(),
+                " │        currently an early work in progress, all and any input appreciated         │ ".to_owned(),
+                " │                       https://github.com/mrjackwills/oxker                        │ ".to_owned(),
+                " │                                                                                   │ ".to_owned(),
+                " ╰───────────────────────────────────────────────────────────────────────────────────╯ ".to_owned(),
+                "                                                                                       ".to_owned(),
+            ];
 
-        let fd = FrameData::from((app_data.lock(), gui_state.lock()));
-        let area = Rect::new(0, 0, w, h);
-        TuiTestSetup {
-            app_data,
-            gui_state,
-            fd,
-            area,
-            terminal,
-            ids,
+        for (row_index, row) in expected.iter().enumerate() {
+            let mut bracket_key = vec![];
+            let mut push_bracket_key = false;
+
+            let result = &setup.terminal.backend().buffer().content;
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                let result_cell = &result[index];
+                let result_str = result_cell.symbol();
+
+                assert_eq!(result_cell.symbol(), expected_char.to_string());
+
+                // First and last row, and first char and last char in each row, is empty
+                if row_index == 0
+                    || row_index == usize::from(h - 1)
+                    || char_index == 0
+                    || char_index == usize::from(w - 1)
+                {
+                    assert_eq!(result_cell.fg, Color::Reset);
+                    assert_eq!(result_cell.bg, Color::Reset);
+                // Borders
+                } else if BORDER_CHARS.contains(&result_str) {
+                    assert_eq!(result_cell.fg, Color::Black);
+                    assert_eq!(result_cell.bg, Color::Magenta);
+                // everything else has a magenta background
+                } else {
+                    assert_eq!(result_cell.bg, Color::Magenta);
+                }
+
+                // check that ( [key] ) is white
+                if result_str == "(" {
+                    push_bracket_key = true;
+                    bracket_key.push(result_cell);
+                }
+                if push_bracket_key {
+                    bracket_key.push(result_cell);
+                    if result_str == ")" {
+                        push_bracket_key = false;
+                        for i in &bracket_key {
+                            assert_eq!(i.fg, Color::White);
+                        }
+                        bracket_key.clear();
+                    }
+                }
+                // TODO should really be testing every color of every str here
+            }
         }
     }
 
-    /// Insert some logs into the first container
-    fn insert_logs(setup: &TuiTestSetup) {
-        let logs = (1..=3).map(|i| format!("{i} line {i}")).collect::<Vec<_>>();
-        setup.app_data.lock().update_log_by_id(logs, &setup.ids[0]);
+    // ************ //
+    // Delete popup //
+    // ************ //
+
+    #[test]
+    /// Delete container popup is drawn correctly
+    fn test_draw_blocks_delete() {
+        let (w, h) = (82, 10);
+        let mut setup = test_setup(w, h, true, true);
+
+        let expected = [
+            "                                                                                  ",
+            "        ╭──────────────────────── Confirm Delete ────────────────────────╮        ",
+            "        │                                                                │        ",
+            "        │     Are you sure you want to delete container: container_1     │        ",
+            "        │                                                                │        ",
+            "        │      ╭─────────────────────╮      ╭─────────────────────╮      │        ",
+            "        │      │        (N)o         │      │        (Y)es        │      │        ",
+            "        │      ╰─────────────────────╯      ╰─────────────────────╯      │        ",
+            "        ╰────────────────────────────────────────────────────────────────╯        ",
+            "                                                                                  ",
+        ];
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::confirm(
+                    f,
+                    &setup.gui_state,
+                    DockerControls::Delete,
+                    &[ContainerName::from("container_1")],
+                    &[],
+                    &Theme::default(),
+                );
+            })
+            .unwrap();
+
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                let result_cell = &result[index];
+
+                assert_eq!(result_cell.symbol(), expected_char.to_string());
+
+                if row_index == 0
+                    || row_index == usize::from(h - 1)
+                    || char_index < 8
+                    || char_index > usize::from(w - 9)
+                {
+                    assert_eq!(result_cell.fg, Color::Reset);
+                    assert_eq!(result_cell.bg, Color::Reset);
+                } else {
+                    assert_eq!(result_cell.bg, Color::White);
+                }
+
+                // Borders are black
+                if BORDER_CHARS.contains(&result_cell.symbol()) {
+                    assert_eq!(result_cell.fg, Color::Black);
+                    // Container name is red
+                } else if row_index == 3 && (57..=67).contains(&char_index) {
+                    assert_eq!(result_cell.fg, Color::Red);
+                    // All other text is black
+                } else if !row_index == 0
+                    && !row_index == usize::from(h - 1)
+                    && !char_index < 8
+                    && !char_index > usize::from(w - 9)
+                {
+                    assert_eq!(result_cell.fg, Color::Black);
+                }
+            }
+        }
     }
 
-    // ******************** //
-    // DockerControls panel //
-    // ******************** //
+    #[test]
+    /// Confirm popup of any other docker control, as set by --confirm, uses the control's name
+    fn test_draw_blocks_confirm_restart() {
+        let (w, h) = (84, 10);
+        let mut setup = test_setup(w, h, true, true);
+
+        let expected = [
+            "                                                                                    ",
+            "        ╭──────────────────────── Confirm Restart ─────────────────────────╮        ",
+            "        │                                                                  │        ",
+            "        │      Are you sure you want to restart container: container_1     │        ",
+            "        │                                                                  │        ",
+            "        │      ╭──────────────────────╮      ╭──────────────────────╮      │        ",
+            "        │      │         (N)o         │      │         (Y)es        │      │        ",
+            "        │      ╰──────────────────────╯      ╰──────────────────────╯      │        ",
+            "        ╰──────────────────────────────────────────────────────────────────╯        ",
+            "                                                                                    ",
+        ];
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::confirm(
+                    f,
+                    &setup.gui_state,
+                    DockerControls::Restart,
+                    &[ContainerName::from("container_1")],
+                    &[],
+                    &Theme::default(),
+                );
+            })
+            .unwrap();
+
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                assert_eq!(result[index].symbol(), expected_char.to_string());
+            }
+        }
+    }
 
     #[test]
-    /// Test that when DockerCommands are available, they are drawn correctly, dependant on container state
-    fn test_draw_blocks_commands_none() {
-        let (w, h) = (12, 6);
-        let mut setup = test_setup(w, h, false, false);
+    /// A recent control from elsewhere is shown, in the error color, below the container
+    fn test_draw_blocks_confirm_concurrent() {
+        let (w, h) = (84, 11);
+        let mut setup = test_setup(w, h, true, true);
+
+        let expected = [
+            "                                                                                    ",
+            "        ╭──────────────────────── Confirm Restart ─────────────────────────╮        ",
+            "        │                                                                  │        ",
+            "        │      Are you sure you want to restart container: container_1     │        ",
+            "        │         container_1 restarted 12s ago, not by this oxker         │        ",
+            "        │                                                                  │        ",
+            "        │      ╭──────────────────────╮      ╭──────────────────────╮      │        ",
+            "        │      │         (N)o         │      │         (Y)es        │      │        ",
+            "        │      ╰──────────────────────╯      ╰──────────────────────╯      │        ",
+            "        ╰──────────────────────────────────────────────────────────────────╯        ",
+            "                                                                                    ",
+        ];
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::confirm(
+                    f,
+                    &setup.gui_state,
+                    DockerControls::Restart,
+                    &[ContainerName::from("container_1")],
+                    &["container_1 restarted 12s ago, not by this oxker".to_owned()],
+                    &Theme::default(),
+                );
+            })
+            .unwrap();
+
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                let result_cell = &result[index];
+                assert_eq!(result_cell.symbol(), expected_char.to_string());
+                if row_index == 4 && expected_char.is_alphanumeric() {
+                    assert_eq!(result_cell.fg, Color::Red);
+                }
+            }
+        }
+    }
+
+    #[test]
+    /// A control sent to the marked containers lists every container that it's sent to
+    fn test_draw_blocks_confirm_marked() {
+        let (w, h) = (82, 13);
+        let mut setup = test_setup(w, h, true, true);
+        let names = ["container_1", "container_2", "container_3"].map(ContainerName::from);
+
+        let expected = [
+            "                                                                                  ",
+            "             ╭──────────────────── Confirm Stop ────────────────────╮             ",
+            "             │                                                      │             ",
+            "             │      Are you sure you want to stop 3 containers:     │             ",
+            "             │                      container_1                     │             ",
+            "             │                      container_2                     │             ",
+            "             │                      container_3                     │             ",
+            "             │                                                      │             ",
+            "             │     ╭─────────────────╮      ╭─────────────────╮     │             ",
+            "             │     │      (N)o       │      │      (Y)es      │     │             ",
+            "             │     ╰─────────────────╯      ╰─────────────────╯     │             ",
+            "             ╰──────────────────────────────────────────────────────╯             ",
+            "                                                                                  ",
+        ];
 
         setup
             .terminal
             .draw(|f| {
-                super::commands(&setup.app_data, setup.area, f, &setup.fd, &setup.gui_state);
+                super::confirm(
+                    f,
+                    &setup.gui_state,
+                    DockerControls::Stop,
+                    &names,
+                    &[],
+                    &Theme::default(),
+                );
             })
             .unwrap();
 
-        let expected = [
-            "╭──────────╮",
-            "│          │",
-            "│          │",
-            "│          │",
-            "│          │",
-            "╰──────────╯",
-        ];
-
         let result = &setup.terminal.backend().buffer().content;
         for (row_index, row) in expected.iter().enumerate() {
             for (char_index, expected_char) in row.chars().enumerate() {
                 let index = row_index * usize::from(w) + char_index;
-                let result_cell = &result[index];
-
-                assert_eq!(result_cell.symbol(), expected_char.to_string());
-                assert_eq!(result_cell.fg, Color::Reset);
+                assert_eq!(expected_char.to_string(), result[index].symbol());
             }
         }
     }
 
     #[test]
-    // Test that when DockerCommands are available, they are drawn correctly, dependant on container state
-    fn test_draw_blocks_commands_some() {
-        let (w, h) = (12, 6);
+    /// Delete container popup is drawn correctly
+    fn test_draw_blocks_delete_long_name() {
+        let (w, h) = (106, 10);
         let mut setup = test_setup(w, h, true, true);
+        let name = ContainerName::from("container_1_container_1_container_1");
+        setup.app_data.lock().containers.items[0].name = name.clone();
+
+        let expected = [
+            "                                                                                                          ",
+            "        ╭──────────────────────────────────── Confirm Delete ────────────────────────────────────╮        ",
+            "        │                                                                                        │        ",
+            "        │     Are you sure you want to delete container: container_1_container_1_container_1     │        ",
+            "        │                                                                                        │        ",
+            "        │        ╭──────────────────────────────╮         ╭─────────────────────────────╮        │        ",
+            "        │        │             (N)o             │         │            (Y)es            │        │        ",
+            "        │        ╰──────────────────────────────╯         ╰─────────────────────────────╯        │        ",
+            "        ╰────────────────────────────────────────────────────────────────────────────────────────╯        ",
+            "                                                                                                          ",
+        ];
 
         setup
             .terminal
             .draw(|f| {
-                super::commands(&setup.app_data, setup.area, f, &setup.fd, &setup.gui_state);
+                super::confirm(
+                    f,
+                    &setup.gui_state,
+                    DockerControls::Delete,
+                    &[name],
+                    &[],
+                    &Theme::default(),
+                );
             })
             .unwrap();
 
-        let expected = [
-            "╭──────────╮",
-            "│▶ pause   │",
-            "│  restart │",
-            "│  stop    │",
-            "│  delete  │",
-            "╰──────────╯",
-        ];
         let result = &setup.terminal.backend().buffer().content;
-
         for (row_index, row) in expected.iter().enumerate() {
             for (char_index, expected_char) in row.chars().enumerate() {
                 let index = row_index * usize::from(w) + char_index;
                 let result_cell = &result[index];
-
                 assert_eq!(result_cell.symbol(), expected_char.to_string());
 
-                // Check the text color is correct
-                match index {
-                    // pause
-                    15..=19 => {
-                        assert_eq!(result_cell.fg, Color::Yellow);
-                    }
-                    // restart
-                    27..=33 => {
-                        assert_eq!(result_cell.fg, Color::Magenta);
-                    }
-                    // stop
-                    39..=42 => {
-                        assert_eq!(result_cell.fg, Color::Red);
-                    }
-                    // delete
-                    51..=56 => {
-                        assert_eq!(result_cell.fg, Color::Gray);
-                    }
-                    // no text
-                    _ => {
-                        assert_eq!(result_cell.fg, Color::Reset);
-                    }
-                }
-                if result_cell.symbol().starts_with('▶') {
+                if row_index == 0
+                    || row_index == usize::from(h - 1)
+                    || char_index < 8
+                    || char_index > usize::from(w - 9)
+                {
                     assert_eq!(result_cell.fg, Color::Reset);
+                    assert_eq!(result_cell.bg, Color::Reset);
+                } else {
+                    assert_eq!(result_cell.bg, Color::White);
+                }
+
+                // Borders are black
+                if BORDER_CHARS.contains(&result_cell.symbol()) {
+                    assert_eq!(result_cell.fg, Color::Black);
+                // Container name is red
+                } else if row_index == 3 && (57..=82).contains(&char_index) {
+                    assert_eq!(result_cell.fg, Color::Red);
+                // All other text is black
+                } else if !row_index == 0
+                    && !row_index == usize::from(h - 1)
+                    && !char_index < 8
+                    && !char_index > usize::from(w - 9)
+                {
+                    assert_eq!(result_cell.fg, Color::Black);
                 }
             }
         }
+    }
 
-        // Change the controls state
+    #[test]
+    /// Delete container popup, when running with --dry-run, shows what will be removed, and changes once confirmed once
+    fn test_draw_blocks_delete_dry_run() {
+        let (w, h) = (86, 13);
+        let mut setup = test_setup(w, h, true, true);
         setup
-            .app_data
+            .gui_state
             .lock()
-            .update_containers(&mut vec![gen_container_summary(1, "paused")]);
-        setup.app_data.lock().docker_controls_next();
+            .set_delete_preview(Some(DeletePreview {
+                confirmed: false,
+                size: Some(ByteStats::new(12000)),
+                volumes: 2,
+            }));
 
         let expected = [
-            "╭──────────╮",
-            "│  resume  │",
-            "│▶ stop    │",
-            "│  delete  │",
-            "│          │",
-            "╰──────────╯",
+            "                                                                                      ",
+            "     ╭───────────────────────────── Confirm Delete ─────────────────────────────╮     ",
+            "     │                                                                          │     ",
+            "     │          Are you sure you want to delete container: container_1          │     ",
+            "     │                    writable layer to reclaim: 12.00 kB                   │     ",
+            "     │                        volumes kept: 2, image kept                       │     ",
+            "     │     dry run - nothing deleted yet, a second confirmation is required     │     ",
+            "     │                                                                          │     ",
+            "     │       ╭────────────────────────╮        ╭────────────────────────╮       │     ",
+            "     │       │          (N)o          │        │          (Y)es         │       │     ",
+            "     │       ╰────────────────────────╯        ╰────────────────────────╯       │     ",
+            "     ╰──────────────────────────────────────────────────────────────────────────╯     ",
+            "                                                                                      ",
         ];
 
         setup
             .terminal
             .draw(|f| {
-                super::commands(&setup.app_data, setup.area, f, &setup.fd, &setup.gui_state);
+                super::confirm(
+                    f,
+                    &setup.gui_state,
+                    DockerControls::Delete,
+                    &[ContainerName::from("container_1")],
+                    &[],
+                    &Theme::default(),
+                );
             })
             .unwrap();
 
         let result = &setup.terminal.backend().buffer().content;
-
         for (row_index, row) in expected.iter().enumerate() {
             for (char_index, expected_char) in row.chars().enumerate() {
                 let index = row_index * usize::from(w) + char_index;
-                let result_cell = &result[index];
-
-                assert_eq!(result_cell.symbol(), expected_char.to_string());
-
-                // Chceck the text color is correct
-                match index {
-                    // resume
-                    15..=20 => {
-                        assert_eq!(result_cell.fg, Color::Blue);
-                    }
-                    // stop
-                    27..=30 => {
-                        assert_eq!(result_cell.fg, Color::Red);
-                    }
-                    // delete
-                    39..=44 => {
-                        assert_eq!(result_cell.fg, Color::Gray);
-                    }
-                    // no text
-                    _ => {
-                        assert_eq!(result_cell.fg, Color::Reset);
-                    }
-                }
-                if result_cell.symbol().starts_with('▶') {
-                    assert_eq!(result_cell.fg, Color::Reset);
-                }
+                assert_eq!(result[index].symbol(), expected_char.to_string());
             }
         }
+
+        assert!(!setup.gui_state.lock().delete_confirm_ready());
+        assert!(setup.gui_state.lock().delete_confirm_ready());
+        setup
+            .terminal
+            .draw(|f| {
+                super::confirm(
+                    f,
+                    &setup.gui_state,
+                    DockerControls::Delete,
+                    &[ContainerName::from("container_1")],
+                    &[],
+                    &Theme::default(),
+                );
+            })
+            .unwrap();
+        let result = &setup.terminal.backend().buffer().content;
+        let row = (0..w)
+            .map(|i| result[usize::from(w) * 6 + usize::from(i)].symbol())
+            .collect::<String>();
+        assert_eq!(
+            row.trim(),
+            "│                     confirm again to delete                    │"
+        );
     }
 
+    // ***** //
+    // popup //
+    // ***** //
+
     #[test]
-    /// When control panel is selected, the border is blue, if not then white, selected text is highlighted
-    fn test_draw_blocks_commands_panel_selected_color() {
-        let (w, h) = (12, 6);
+    /// Info box drawn in bottom right
+    fn test_draw_blocks_info() {
+        let (w, h) = (45, 9);
         let mut setup = test_setup(w, h, true, true);
+
         let expected = [
-            "╭──────────╮",
-            "│▶ pause   │",
-            "│  restart │",
-            "│  stop    │",
-            "│  delete  │",
-            "╰──────────╯",
+            "                                             ",
+            "                                             ",
+            "                                             ",
+            "                                             ",
+            "                                             ",
+            "                                             ",
+            "                                             ",
+            "                                    test     ",
+            "                                             ",
         ];
 
-        // Unselected, has a grey border
         setup
             .terminal
             .draw(|f| {
-                super::commands(&setup.app_data, setup.area, f, &setup.fd, &setup.gui_state);
+                super::info(
+                    f,
+                    "test",
+                    std::time::Instant::now(),
+                    &setup.gui_state,
+                    &Theme::default(),
+                );
             })
             .unwrap();
 
         let result = &setup.terminal.backend().buffer().content;
+
         for (row_index, row) in expected.iter().enumerate() {
             for (char_index, expected_char) in row.chars().enumerate() {
                 let index = row_index * usize::from(w) + char_index;
                 let result_cell = &result[index];
 
-                assert_eq!(result_cell.symbol(), expected_char.to_string());
-                if BORDER_CHARS.contains(&result_cell.symbol()) {
-                    assert_eq!(result_cell.fg, Color::Reset);
-                }
+                assert_eq!(expected_char.to_string(), result_cell.symbol());
+                let (fg, bg) = if row_index >= 6 && char_index >= 32 {
+                    (Color::White, Color::Blue)
+                } else {
+                    (Color::Reset, Color::Reset)
+                };
+
+                assert_eq!(result_cell.fg, fg);
+                assert_eq!(result_cell.bg, bg);
             }
         }
+    }
+
+    #[test]
+    /// Log matches popup, only the newest matches that fit are shown, the newest is selected
+    fn test_draw_blocks_log_matches() {
+        let (w, h) = (60, 10);
+        let mut setup = test_setup(w, h, true, true);
+        let id = setup.ids[0].clone();
+        setup.app_data.lock().log_matches = (1..=4)
+            .map(|i| LogMatch {
+                id: id.clone(),
+                index: Some(i),
+                line: format!("error {i}"),
+                name: "c1".to_owned(),
+                pattern: "error".to_owned(),
+            })
+            .collect();
+
+        let expected = [
+            "                                                            ",
+            "                                                            ",
+            "    ╭───────────────── log matches, 4 ─────────────────╮    ",
+            "    │c1 error 3                                        │    ",
+            "    │c1 error 4                                        │    ",
+            "    │                                                  │    ",
+            "    │( ↑ ↓ ) select ( enter ) context ( a ) close      │    ",
+            "    ╰──────────────────────────────────────────────────╯    ",
+            "                                                            ",
+            "                                                            ",
+        ];
 
-        // Control panel now selected, should have a blue border
-        setup.gui_state.lock().next_panel();
-        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
         setup
             .terminal
             .draw(|f| {
-                super::commands(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+                super::log_matches(f, &setup.app_data, MatchView::default(), &Theme::default());
             })
             .unwrap();
 
@@ -1267,70 +9062,141 @@ mod tests {
         for (row_index, row) in expected.iter().enumerate() {
             for (char_index, expected_char) in row.chars().enumerate() {
                 let index = row_index * usize::from(w) + char_index;
-                let result_cell = &result[index];
-
-                assert_eq!(result_cell.symbol(), expected_char.to_string());
-                if BORDER_CHARS.contains(&result_cell.symbol()) {
-                    assert_eq!(result_cell.fg, Color::LightCyan);
-                }
-                // Make sure that the selected line has bold text
-                match index {
-                    // pause
-                    13..=22 => {
-                        assert_eq!(result_cell.modifier, Modifier::BOLD);
-                    }
-                    _ => {
-                        assert!(result_cell.modifier.is_empty());
-                    }
-                }
+                assert_eq!(expected_char.to_string(), result[index].symbol());
             }
         }
+        assert_eq!(result[usize::from(w) * 3 + 5].fg, Color::Black);
+        assert_eq!(result[usize::from(w) * 3 + 5].bg, Color::Yellow);
+        assert!(!result[usize::from(w) * 3 + 5]
+            .modifier
+            .contains(Modifier::REVERSED));
+        assert!(result[usize::from(w) * 4 + 5]
+            .modifier
+            .contains(Modifier::REVERSED));
     }
 
-    // *********************** //
-    // Container summary panel //
-    // *********************** //
+    #[test]
+    /// The lines logged around the selected match are shown below the matches, with the match highlighted
+    fn test_draw_blocks_log_matches_context() {
+        let (w, h) = (60, 20);
+        let mut setup = test_setup(w, h, true, true);
+        let id = setup.ids[0].clone();
+        let logs = (1..=9)
+            .map(|i| format!("2023-01-14T19:13:3{i}.783138328Z line {i}"))
+            .collect::<Vec<_>>();
+        setup.app_data.lock().update_log_by_id(logs, &id);
+        setup.app_data.lock().log_matches = [2, 4, 6]
+            .into_iter()
+            .map(|i| LogMatch {
+                id: id.clone(),
+                index: Some(i),
+                line: format!("line {}", i + 1),
+                name: "container_1".to_owned(),
+                pattern: "line".to_owned(),
+            })
+            .collect();
+        let mut view = MatchView::default();
+        view.previous(3);
+        view.toggle_context();
+        view.change_context(false);
+        view.change_context(false);
+        view.change_context(false);
+
+        let expected = [
+            "                                                            ",
+            "                                                            ",
+            "    ╭───────────────── log matches, 3 ─────────────────╮    ",
+            "    │container_1 line 3                                │    ",
+            "    │container_1 line 5                                │    ",
+            "    │container_1 line 7                                │    ",
+            "    │ container_1 - line 5, ±2 lines ──────────────────│    ",
+            "    │line 3                                            │    ",
+            "    │line 4                                            │    ",
+            "    │line 5                                            │    ",
+            "    │line 6                                            │    ",
+            "    │line 7                                            │    ",
+            "    │                                                  │    ",
+            "    │                                                  │    ",
+            "    │                                                  │    ",
+            "    │                                                  │    ",
+            "    │( + - ) lines ( enter ) hide ( a ) close          │    ",
+            "    ╰──────────────────────────────────────────────────╯    ",
+            "                                                            ",
+            "                                                            ",
+        ];
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::log_matches(f, &setup.app_data, view, &Theme::default());
+            })
+            .unwrap();
 
-    // Check that the correct solor is applied to the state/status/cpu/memory section
-    fn check_expected(expected: [&str; 6], w: u16, _h: u16, setup: &TuiTestSetup, color: Color) {
         let result = &setup.terminal.backend().buffer().content;
         for (row_index, row) in expected.iter().enumerate() {
             for (char_index, expected_char) in row.chars().enumerate() {
                 let index = row_index * usize::from(w) + char_index;
-                let result_cell = &result[index];
-
-                assert_eq!(result_cell.symbol(), expected_char.to_string());
-                if (145..=207).contains(&index) {
-                    assert_eq!(result_cell.fg, color);
-                    assert_eq!(result_cell.modifier, Modifier::BOLD);
-                }
+                assert_eq!(expected_char.to_string(), result[index].symbol());
             }
         }
+        assert!(result[usize::from(w) * 4 + 5]
+            .modifier
+            .contains(Modifier::REVERSED));
+        assert!(result[usize::from(w) * 9 + 5]
+            .modifier
+            .contains(Modifier::REVERSED));
+        assert!(!result[usize::from(w) * 10 + 5]
+            .modifier
+            .contains(Modifier::REVERSED));
+        assert_eq!(result[usize::from(w) * 9 + 5].bg, Color::Reset);
     }
 
     #[test]
-    /// No containers, panel unselected, then selected, border color changes correctly
-    fn test_draw_blocks_containers_none() {
-        let (w, h) = (25, 6);
+    /// Command form shows the command being typed, above the earlier commands, with the recalled command marked
+    fn test_draw_blocks_command_form() {
+        let (w, h) = (90, 18);
         let mut setup = test_setup(w, h, true, true);
-        setup.app_data.lock().containers = StatefulList::new(vec![]);
+        let mut form = CommandForm::new(
+            "db".to_owned(),
+            "postgres:16".to_owned(),
+            vec![
+                ExecEntry {
+                    env: "PAGER=".to_owned(),
+                    user: "postgres".to_owned(),
+                    ..ExecEntry::shell("psql")
+                },
+                ExecEntry::shell("df -h"),
+            ],
+        );
+        form.older();
+        form.next_field();
+        setup.gui_state.lock().set_command_form(Some(form));
 
         let expected = [
-            "╭ Containers ───────────╮",
-            "│ no containers running │",
-            "│                       │",
-            "│                       │",
-            "│                       │",
-            "╰───────────────────────╯",
+            "                                                                                          ",
+            "                                                                                          ",
+            "    ╭───────────────────────────── run a command in db ──────────────────────────────╮    ",
+            "    │ $ psql                                                                         │    ",
+            "    │ ▶ user      postgres█                                                          │    ",
+            "    │   directory                                                                    │    ",
+            "    │   env       PAGER=                                                             │    ",
+            "    │   shell     yes                                                                │    ",
+            "    │                                                                                │    ",
+            "    │ earlier commands                                                               │    ",
+            "    │ ▶ psql  [-u postgres -e PAGER=]                                                │    ",
+            "    │   df -h                                                                        │    ",
+            "    │                                                                                │    ",
+            "    │ ( ↑ ↓ ) recall a command ( tab ) next option ( enter ) run ( esc ) cancel      │    ",
+            "    │                                                                                │    ",
+            "    ╰────────────────────────────────────────────────────────────────────────────────╯    ",
+            "                                                                                          ",
+            "                                                                                          ",
         ];
 
-        setup.gui_state.lock().next_panel();
-        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
-
         setup
             .terminal
             .draw(|f| {
-                super::containers(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+                super::command_form(f, &setup.gui_state, &Theme::default());
             })
             .unwrap();
 
@@ -1338,20 +9204,37 @@ mod tests {
         for (row_index, row) in expected.iter().enumerate() {
             for (char_index, expected_char) in row.chars().enumerate() {
                 let index = row_index * usize::from(w) + char_index;
-                let result_cell = &result[index];
-
-                assert_eq!(result_cell.symbol(), expected_char.to_string());
-                assert_eq!(result_cell.fg, Color::Reset);
+                assert_eq!(expected_char.to_string(), result[index].symbol());
             }
         }
+    }
 
-        setup.gui_state.lock().previous_panel();
-        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+    #[test]
+    /// Stdin form shows the line being typed, above the last line sent
+    fn test_draw_blocks_stdin_form() {
+        let (w, h) = (50, 7);
+        let mut setup = test_setup(w, h, true, true);
+        setup.gui_state.lock().set_stdin_form(Some(StdinForm {
+            id: ContainerId::from("1"),
+            name: "minecraft".to_owned(),
+            text: "say hi".to_owned(),
+            sent: Some("list".to_owned()),
+        }));
+
+        let expected = [
+            "                                                  ",
+            "    ╭────────── stdin of minecraft ──────────╮    ",
+            "    │ say hi█                                │    ",
+            "    │ sent: list                             │    ",
+            "    │ ( enter ) send the line ( esc ) close  │    ",
+            "    ╰────────────────────────────────────────╯    ",
+            "                                                  ",
+        ];
 
         setup
             .terminal
             .draw(|f| {
-                super::containers(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+                super::stdin_form(f, &setup.gui_state, &Theme::default());
             })
             .unwrap();
 
@@ -1359,35 +9242,35 @@ mod tests {
         for (row_index, row) in expected.iter().enumerate() {
             for (char_index, expected_char) in row.chars().enumerate() {
                 let index = row_index * usize::from(w) + char_index;
-                let result_cell = &result[index];
-
-                assert_eq!(result_cell.symbol(), expected_char.to_string());
-                if BORDER_CHARS.contains(&result_cell.symbol()) {
-                    assert_eq!(result_cell.fg, Color::LightCyan);
-                }
+                assert_eq!(expected_char.to_string(), result[index].symbol());
             }
         }
     }
 
     #[test]
-    /// Containers panel drawn, selected line is bold, border is blue
-    fn test_draw_blocks_containers_some() {
-        let (w, h) = (130, 6);
+    /// Rename form shows the name being typed, & why it can't be used
+    fn test_draw_blocks_rename_form() {
+        let (w, h) = (80, 7);
         let mut setup = test_setup(w, h, true, true);
+        let mut form = RenameForm::new(ContainerId::from("1"), "container_1".to_owned());
+        form.text = "-web".to_owned();
+        form.submit();
+        setup.gui_state.lock().set_rename_form(Some(form));
 
         let expected = [
-        "╭ Containers 1/3 ────────────────────────────────────────────────────────────────────────────────────────────────────────────────╮",
-        "│⚪  container_1   ✓ running            Up 1 hour    00.00%   0.00 kB / 0.00 kB          1   image_1   0.00 kB   0.00 kB          │",
-        "│   container_2   ✓ running            Up 2 hour    00.00%   0.00 kB / 0.00 kB          2   image_2   0.00 kB   0.00 kB          │",
-        "│   container_3   ✓ running            Up 3 hour    00.00%   0.00 kB / 0.00 kB          3   image_3   0.00 kB   0.00 kB          │",
-        "│                                                                                                                                │",
-        "╰────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────╯",
-    ];
+            "                                                                                ",
+            "       ╭────────────────────── rename container_1 ──────────────────────╮       ",
+            "       │ -web█                                                          │       ",
+            "       │ letters, digits, & _ . - only, starting with a letter or digit │       ",
+            "       │ ( enter ) rename ( esc ) cancel                                │       ",
+            "       ╰────────────────────────────────────────────────────────────────╯       ",
+            "                                                                                ",
+        ];
 
         setup
             .terminal
             .draw(|f| {
-                super::containers(&setup.app_data, setup.area, f, &setup.fd, &setup.gui_state);
+                super::rename_form(f, &setup.gui_state, &Theme::default());
             })
             .unwrap();
 
@@ -1395,33 +9278,38 @@ mod tests {
         for (row_index, row) in expected.iter().enumerate() {
             for (char_index, expected_char) in row.chars().enumerate() {
                 let index = row_index * usize::from(w) + char_index;
-                let result_cell = &result[index];
-
-                // result matches expected
-                assert_eq!(result_cell.symbol(), expected_char.to_string());
-
-                // Selected container is bold
-                match index {
-                    131 | 133..=258 => assert_eq!(result_cell.modifier, Modifier::BOLD),
-                    _ => {
-                        assert!(result_cell.modifier.is_empty());
-                    }
-                }
-
-                // Border is blue
-                if BORDER_CHARS.contains(&result_cell.symbol()) {
-                    assert_eq!(result_cell.fg, Color::LightCyan);
-                }
+                assert_eq!(expected_char.to_string(), result[index].symbol());
             }
         }
+    }
+
+    #[test]
+    /// Scale form shows the replicas being typed, & why they can't be used, or the whole stack that's scaled
+    fn test_draw_blocks_scale_form() {
+        let (w, h) = (80, 7);
+        let mut setup = test_setup(w, h, true, true);
+        let mut form = ScaleForm::new(
+            None,
+            gen_service("q8k2xv1n4m7b", "site_web", 2, 3, false),
+            vec![],
+        );
+        form.submit();
+        setup.gui_state.lock().set_scale_form(Some(form));
+
+        let expected = [
+            "                                                                                ",
+            "                       ╭── scale site_web, 2 running ───╮                       ",
+            "                       │ replicas 3█                    │                       ",
+            "                       │ the replicas are unchanged     │                       ",
+            "                       │ ( enter ) scale ( esc ) cancel │                       ",
+            "                       ╰────────────────────────────────╯                       ",
+            "                                                                                ",
+        ];
 
-        // Change selected panel, border is now no longer blue
-        setup.gui_state.lock().next_panel();
-        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
         setup
             .terminal
             .draw(|f| {
-                super::containers(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+                super::scale_form(f, &setup.gui_state, &Theme::default());
             })
             .unwrap();
 
@@ -1429,129 +9317,105 @@ mod tests {
         for (row_index, row) in expected.iter().enumerate() {
             for (char_index, expected_char) in row.chars().enumerate() {
                 let index = row_index * usize::from(w) + char_index;
-                let result_cell = &result[index];
-
-                assert_eq!(result_cell.symbol(), expected_char.to_string());
-
-                // Border is gray
-                if BORDER_CHARS.contains(&result_cell.symbol()) {
-                    assert_eq!(result_cell.fg, Color::Reset);
-                }
+                assert_eq!(expected_char.to_string(), result[index].symbol());
             }
         }
+
+        let mut service = gen_service("q8k2xv1n4m7b", "site_web", 2, 3, false);
+        service.stack = Some("site".to_owned());
+        let mut api = gen_service("t1n5aw4q7p0e", "site_api", 2, 2, false);
+        api.stack = Some("site".to_owned());
+        let mut form = ScaleForm::new(None, service.clone(), vec![service, api]);
+        form.toggle_stack();
+        setup.gui_state.lock().set_scale_form(Some(form));
+        setup
+            .terminal
+            .draw(|f| {
+                super::scale_form(f, &setup.gui_state, &Theme::default());
+            })
+            .unwrap();
+        let result = &setup.terminal.backend().buffer().content;
+        let rows = result
+            .chunks(usize::from(w))
+            .map(|i| {
+                i.iter()
+                    .map(ratatui::buffer::Cell::symbol)
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>();
+        assert!(rows[1].contains(" scale stack site, 2 replicated services "));
+        assert!(rows[4].contains("( tab ) only the service ( enter ) scale ( esc ) cancel"));
     }
 
     #[test]
-    /// ALl columns on all rows are coloured correctly
-    fn test_draw_blocks_containers_colors() {
-        let (w, h) = (130, 6);
+    /// Task logs popup shows the newest merged lines first, with the number of tasks on the host
+    fn test_draw_blocks_task_logs() {
+        let (w, h) = (60, 10);
         let mut setup = test_setup(w, h, true, true);
+        let lines = (0..8)
+            .map(|i| format!("site_web.{}  request {i}", i % 2 + 1))
+            .collect();
+        setup
+            .gui_state
+            .lock()
+            .set_task_logs(Some(TaskLogs::new("site_web".to_owned(), 2, lines)));
 
         let expected = [
-            "╭ Containers 1/3 ────────────────────────────────────────────────────────────────────────────────────────────────────────────────╮",
-            "│⚪  container_1   ✓ running            Up 1 hour    00.00%   0.00 kB / 0.00 kB          1   image_1   0.00 kB   0.00 kB          │",
-            "│   container_2   ✓ running            Up 2 hour    00.00%   0.00 kB / 0.00 kB          2   image_2   0.00 kB   0.00 kB          │",
-            "│   container_3   ✓ running            Up 3 hour    00.00%   0.00 kB / 0.00 kB          3   image_3   0.00 kB   0.00 kB          │",
-            "│                                                                                                                                │",
-            "╰────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────╯",
+            "                                                            ",
+            "                                                            ",
+            "    ╭───── logs of site_web, 2 tasks on this host ─────╮    ",
+            "    │ site_web.2  request 5                            │    ",
+            "    │ site_web.1  request 6                            │    ",
+            "    │ site_web.2  request 7                            │    ",
+            "    │ ( ↑ ↓ ) scroll ( esc ) close                     │    ",
+            "    ╰──────────────────────────────────────────────────╯    ",
+            "                                                            ",
+            "                                                            ",
         ];
-        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
 
         setup
             .terminal
             .draw(|f| {
-                super::containers(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+                super::task_logs(f, &setup.gui_state, &Theme::default());
             })
             .unwrap();
 
-        let index_blue = [
-            134, 135, 136, 137, 138, 139, 140, 141, 142, 143, 144, 208, 209, 210, 211, 212, 213,
-            214, 215, 216, 217, 218, 219, 220, 221, 222, 223, 224, 225, 226, 227, 228,
-        ];
-        let index_blue = index_blue
-            .iter()
-            .flat_map(|&x| vec![x, x + 130, x + 260])
-            .collect::<Vec<_>>();
-        let index_green = [
-            145, 146, 147, 148, 149, 150, 151, 152, 153, 154, 155, 156, 157, 158, 159, 160, 161,
-            162, 163, 164, 165, 166, 167, 168, 169, 170, 171, 172, 173, 174, 175, 176, 177, 178,
-            179, 180, 181, 182, 183, 184, 185, 186, 187, 188, 189, 190, 191, 192, 193, 194, 195,
-            196, 197, 198, 199, 200, 201, 202, 203, 204, 205, 206, 207,
-        ];
-        let index_green = index_green
-            .iter()
-            .flat_map(|&x| vec![x, x + 130, x + 260])
-            .collect::<Vec<_>>();
-
-        let index_rx = [229, 230, 231, 232, 233, 234, 235, 236, 237, 238];
-        let index_rx = index_rx
-            .iter()
-            .flat_map(|&x| vec![x, x + 130, x + 260])
-            .collect::<Vec<_>>();
-
-        let index_tx = [239, 240, 241, 242, 243, 244, 245, 246, 247, 248];
-        let index_tx = index_tx
-            .iter()
-            .flat_map(|&x| vec![x, x + 130, x + 260])
-            .collect::<Vec<_>>();
-
         let result = &setup.terminal.backend().buffer().content;
         for (row_index, row) in expected.iter().enumerate() {
             for (char_index, expected_char) in row.chars().enumerate() {
                 let index = row_index * usize::from(w) + char_index;
-
-                let result_cell = &result[index];
-                assert_eq!(result_cell.symbol(), expected_char.to_string());
-
-                match index {
-                    _x if index_blue.contains(&index) => {
-                        assert_eq!(result_cell.fg, Color::Blue);
-                    }
-                    _x if index_green.contains(&index) => {
-                        assert_eq!(result_cell.fg, Color::Green);
-                    }
-                    _x if index_rx.contains(&index) => {
-                        assert_eq!(result_cell.fg, Color::Rgb(255, 233, 193));
-                    }
-                    _x if index_tx.contains(&index) => {
-                        assert_eq!(result_cell.fg, Color::Rgb(205, 140, 140));
-                    }
-                    (0..=130) | (259..=260) | (389..=390) | (519..=520) | (649..=779) => {
-                        assert_eq!(result_cell.fg, Color::LightCyan);
-                    }
-                    _ => {
-                        assert_eq!(result_cell.fg, Color::Reset);
-                    }
-                }
+                assert_eq!(expected_char.to_string(), result[index].symbol());
             }
         }
     }
 
     #[test]
-    /// When long container/image name, it is truncated correctly
-    fn test_draw_blocks_containers_long_name_image() {
-        let (w, h) = (170, 6);
+    /// Kill picker shows each signal, with what it does, the selected signal is bold
+    fn test_draw_blocks_kill_picker() {
+        let (w, h) = (74, 11);
         let mut setup = test_setup(w, h, true, true);
-        setup.app_data.lock().containers.items[0].name =
-            ContainerName::from("a_long_container_name_for_the_purposes_of_this_test");
-        setup.app_data.lock().containers.items[0].image =
-            ContainerImage::from("a_long_image_name_for_the_purposes_of_this_test");
+        let mut picker = KillPicker::new(ContainerId::from("1"), "container_1".to_owned());
+        picker.next();
+        setup.gui_state.lock().set_kill_picker(Some(picker));
 
         let expected = [
-        "╭ Containers 1/3 ────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────╮",
-        "│⚪  a_long_container_name_for_the…   ॥ paused             Up 1 hour    00.00%   0.00 kB / 0.00 kB          1   a_long_image_name_for_the_pur…   0.00 kB   0.00 kB        │",
-        "│                      container_2   ✓ running            Up 2 hour    00.00%   0.00 kB / 0.00 kB          2                          image_2   0.00 kB   0.00 kB        │",
-        "│                      container_3   ✓ running            Up 3 hour    00.00%   0.00 kB / 0.00 kB          3                          image_3   0.00 kB   0.00 kB        │",
-        "│                                                                                                                                                                        │",
-        "╰────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────╯",
+            "                                                                          ",
+            "                                                                          ",
+            "     ╭────────────────────── kill container_1 ──────────────────────╮     ",
+            "     │  SIGTERM  ask the process to exit                            │     ",
+            "     │▶ SIGKILL  end the process immediately                        │     ",
+            "     │  SIGHUP   reload the config, for processes that support it   │     ",
+            "     │                                                              │     ",
+            "     │  ( ↑ ↓ ) select signal ( enter ) kill ( esc ) cancel         │     ",
+            "     ╰──────────────────────────────────────────────────────────────╯     ",
+            "                                                                          ",
+            "                                                                          ",
         ];
-        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
-        setup.app_data.lock().containers.items[0].state = State::Paused;
 
         setup
             .terminal
             .draw(|f| {
-                super::containers(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+                super::kill_picker(f, &setup.gui_state, &Theme::default());
             })
             .unwrap();
 
@@ -1560,196 +9424,441 @@ mod tests {
             for (char_index, expected_char) in row.chars().enumerate() {
                 let index = row_index * usize::from(w) + char_index;
                 let result_cell = &result[index];
-
-                assert_eq!(result_cell.symbol(), expected_char.to_string());
+                assert_eq!(expected_char.to_string(), result_cell.symbol());
+                if row_index == 4 && (6..44).contains(&char_index) {
+                    assert_eq!(result_cell.modifier, Modifier::BOLD);
+                }
             }
         }
-
-        // THis char: …
     }
 
     #[test]
-    /// When container is paused, correct colors displayed
-    fn test_draw_blocks_containers_paused() {
-        let (w, h) = (130, 6);
+    /// The capture filter is typed first, once started the latest packets are shown below the totals
+    fn test_draw_blocks_capture() {
+        let (w, h) = (76, 16);
         let mut setup = test_setup(w, h, true, true);
+        let mut capture = Capture::new(ContainerId::from("1"), "postgres".to_owned());
+        capture.filter = "port 5432".to_owned();
+        setup.gui_state.lock().set_capture(Some(capture.clone()));
 
         let expected = [
-            "╭ Containers 1/3 ────────────────────────────────────────────────────────────────────────────────────────────────────────────────╮",
-        "│⚪  container_1   ॥ paused             Up 1 hour    00.00%   0.00 kB / 0.00 kB          1   image_1   0.00 kB   0.00 kB          │",
-        "│   container_2   ✓ running            Up 2 hour    00.00%   0.00 kB / 0.00 kB          2   image_2   0.00 kB   0.00 kB          │",
-        "│   container_3   ✓ running            Up 3 hour    00.00%   0.00 kB / 0.00 kB          3   image_3   0.00 kB   0.00 kB          │",
-        "│                                                                                                                                │",
-        "╰────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────╯",
+            "                                                                            ",
+            "                                                                            ",
+            "                                                                            ",
+            "                                                                            ",
+            "                                                                            ",
+            "     ╭───────────────────── capture of postgres ──────────────────────╮     ",
+            "     │ port 5432█                                                     │     ",
+            "     │ a tcpdump filter, e.g. port 5432, empty captures every packet  │     ",
+            "     │ ( enter ) start ( esc ) close                                  │     ",
+            "     │                                                                │     ",
+            "     ╰────────────────────────────────────────────────────────────────╯     ",
+            "                                                                            ",
+            "                                                                            ",
+            "                                                                            ",
+            "                                                                            ",
+            "                                                                            ",
         ];
-        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
-        setup.app_data.lock().containers.items[0].state = State::Paused;
-
         setup
             .terminal
             .draw(|f| {
-                super::containers(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+                super::capture(f, &setup.gui_state, &Theme::default());
             })
             .unwrap();
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                assert_eq!(expected_char.to_string(), result[index].symbol());
+            }
+        }
 
-        check_expected(expected, w, h, &setup, Color::Yellow);
+        capture.start();
+        capture.path = Some(PathBuf::from("/tmp/postgres_1.pcap"));
+        capture.status = Some("listening on any, link-type LINUX_SLL2".to_owned());
+        for i in 0..6 {
+            capture.push(
+                &format!("TCP 172.18.0.2:5432 > 172.18.0.3:4123{i} [P.]"),
+                66,
+            );
+        }
+        setup.gui_state.lock().set_capture(Some(capture));
+
+        let expected = [
+            "                                                                            ",
+            "                                                                            ",
+            "         ╭───────────────── capture of postgres ──────────────────╮         ",
+            "         │ filter port 5432                                       │         ",
+            "         │ saving /tmp/postgres_1.pcap                            │         ",
+            "         │ 6 packets, 0.40 kB                                     │         ",
+            "         │ listening on any, link-type LINUX_SLL2                 │         ",
+            "         │ TCP 172.18.0.2:5432 > 172.18.0.3:41231 [P.] length 66  │         ",
+            "         │ TCP 172.18.0.2:5432 > 172.18.0.3:41232 [P.] length 66  │         ",
+            "         │ TCP 172.18.0.2:5432 > 172.18.0.3:41233 [P.] length 66  │         ",
+            "         │ TCP 172.18.0.2:5432 > 172.18.0.3:41234 [P.] length 66  │         ",
+            "         │ TCP 172.18.0.2:5432 > 172.18.0.3:41235 [P.] length 66  │         ",
+            "         │ ( esc ) stop, & save the pcap                          │         ",
+            "         ╰────────────────────────────────────────────────────────╯         ",
+            "                                                                            ",
+            "                                                                            ",
+        ];
+        setup
+            .terminal
+            .draw(|f| {
+                super::capture(f, &setup.gui_state, &Theme::default());
+            })
+            .unwrap();
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                assert_eq!(expected_char.to_string(), result[index].symbol());
+            }
+        }
     }
 
     #[test]
-    /// When container is dead, correct colors displayed
-    fn test_draw_blocks_containers_dead() {
-        let (w, h) = (130, 6);
+    /// The limits form shows both fields, the cursor in the selected one, & the error once submitted
+    fn test_draw_blocks_limits_form() {
+        let (w, h) = (64, 8);
         let mut setup = test_setup(w, h, true, true);
+        let limits = Limits {
+            nano_cpus: Some(1_500_000_000),
+            memory: None,
+        };
+        setup.gui_state.lock().set_limits_form(Some(LimitsForm::new(
+            ContainerId::from("1"),
+            "web".to_owned(),
+            limits,
+        )));
 
         let expected = [
-            "╭ Containers 1/3 ────────────────────────────────────────────────────────────────────────────────────────────────────────────────╮",
-            "│⚪  container_1   ✖ dead               Up 1 hour    00.00%   0.00 kB / 0.00 kB          1   image_1   0.00 kB   0.00 kB          │",
-            "│   container_2   ✓ running            Up 2 hour    00.00%   0.00 kB / 0.00 kB          2   image_2   0.00 kB   0.00 kB          │",
-            "│   container_3   ✓ running            Up 3 hour    00.00%   0.00 kB / 0.00 kB          3   image_3   0.00 kB   0.00 kB          │",
-            "│                                                                                                                                │",
-            "╰────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────╯",
+            "                                                                ",
+            "    ╭─────────────────── limits of web ────────────────────╮    ",
+            "    │ cpus   1.5█                                          │    ",
+            "    │ memory                                               │    ",
+            "    │ an empty field is left unchanged                     │    ",
+            "    │ ( tab ) switch field ( enter ) update ( esc ) cancel │    ",
+            "    ╰──────────────────────────────────────────────────────╯    ",
+            "                                                                ",
         ];
-        setup.app_data.lock().containers.items[0].state = State::Dead;
-        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
 
         setup
             .terminal
             .draw(|f| {
-                super::containers(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+                super::limits_form(f, &setup.gui_state, &Theme::default());
             })
             .unwrap();
-        check_expected(expected, w, h, &setup, Color::Red);
+
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                assert_eq!(expected_char.to_string(), result[index].symbol());
+            }
+        }
+
+        if let Some(form) = setup.gui_state.lock().get_limits_form() {
+            form.toggle();
+            form.push('x');
+            form.submit();
+        }
+        setup
+            .terminal
+            .draw(|f| {
+                super::limits_form(f, &setup.gui_state, &Theme::default());
+            })
+            .unwrap();
+        let result = &setup.terminal.backend().buffer().content;
+        let row = |row_index: usize| {
+            (0..usize::from(w))
+                .map(|i| result[row_index * usize::from(w) + i].symbol())
+                .collect::<String>()
+        };
+        assert_eq!(
+            row(2),
+            "    │ cpus   1.5                                           │    "
+        );
+        assert_eq!(
+            row(3),
+            "    │ memory x█                                            │    "
+        );
+        assert_eq!(
+            row(4),
+            "    │ x: expected a size of memory, e.g. 512m              │    "
+        );
+        assert_eq!(result[4 * usize::from(w) + 6].fg, Color::Red);
     }
 
     #[test]
-    /// When container is exited, correct colors displayed
-    fn test_draw_blocks_containers_exited() {
-        let (w, h) = (130, 6);
+    /// A failed control shows the daemon's error, the container's attempts, newest first, & the backoff of the retry
+    fn test_draw_blocks_failed() {
+        let (w, h) = (70, 11);
         let mut setup = test_setup(w, h, true, true);
+        let id = ContainerId::from("1");
+        for (time, error) in [
+            (1_000, None),
+            (1_100, Some("port is already allocated")),
+            (1_160, Some("port is already allocated")),
+        ] {
+            setup.app_data.lock().push_attempt(
+                &id,
+                None,
+                DockerControls::Start,
+                error.map(ToOwned::to_owned),
+                time,
+            );
+        }
+        setup.gui_state.lock().set_failed(Some(FailedControl {
+            control: DockerControls::Start,
+            id,
+            host: None,
+            retry_at: None,
+        }));
 
         let expected = [
-            "╭ Containers 1/3 ────────────────────────────────────────────────────────────────────────────────────────────────────────────────╮",
-            "│⚪  container_1   ✖ exited             Up 1 hour    00.00%   0.00 kB / 0.00 kB          1   image_1   0.00 kB   0.00 kB          │",
-            "│   container_2   ✓ running            Up 2 hour    00.00%   0.00 kB / 0.00 kB          2   image_2   0.00 kB   0.00 kB          │",
-            "│   container_3   ✓ running            Up 3 hour    00.00%   0.00 kB / 0.00 kB          3   image_3   0.00 kB   0.00 kB          │",
-            "│                                                                                                                                │",
-            "╰────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────╯",
+            "                                                                      ",
+            "       ╭────────────── start container_1 failed ──────────────╮       ",
+            "       │ port is already allocated                            │       ",
+            "       │                                                      │       ",
+            "       │ 40s ago     start failed: port is already allocated  │       ",
+            "       │ 1m 40s ago  start failed: port is already allocated  │       ",
+            "       │ 3m 20s ago  start succeeded                          │       ",
+            "       │                                                      │       ",
+            "       │ ( r ) retry, after 2s ( esc ) close                  │       ",
+            "       ╰──────────────────────────────────────────────────────╯       ",
+            "                                                                      ",
         ];
-        setup.app_data.lock().containers.items[0].state = State::Exited;
-        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
 
         setup
             .terminal
             .draw(|f| {
-                super::containers(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+                super::failed(
+                    f,
+                    &setup.app_data,
+                    &setup.gui_state,
+                    1_200,
+                    &Theme::default(),
+                );
             })
             .unwrap();
 
-        check_expected(expected, w, h, &setup, Color::Red);
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                assert_eq!(expected_char.to_string(), result[index].symbol());
+            }
+        }
     }
+
     #[test]
-    /// When container is paused, correct colors displayed
-    fn test_draw_blocks_containers_removing() {
-        let (w, h) = (130, 6);
+    /// The run form has a field per section, the selected field marked, with a cursor
+    fn test_draw_blocks_run_form() {
+        let (w, h) = (110, 14);
         let mut setup = test_setup(w, h, true, true);
+        let mut form = RunForm::new("redis:7".to_owned(), vec![], Some("build".to_owned()));
+        form.next();
+        for c in "cache".chars() {
+            form.push(c);
+        }
+        setup.gui_state.lock().set_run_form(Some(form));
 
         let expected = [
-            "╭ Containers 1/3 ────────────────────────────────────────────────────────────────────────────────────────────────────────────────╮",
-            "│⚪  container_1   removing             Up 1 hour    00.00%   0.00 kB / 0.00 kB          1   image_1   0.00 kB   0.00 kB          │",
-            "│   container_2   ✓ running            Up 2 hour    00.00%   0.00 kB / 0.00 kB          2   image_2   0.00 kB   0.00 kB          │",
-            "│   container_3   ✓ running            Up 3 hour    00.00%   0.00 kB / 0.00 kB          3   image_3   0.00 kB   0.00 kB          │",
-            "│                                                                                                                                │",
-            "╰────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────╯",
+            "                                                                                                              ",
+            "                                                                                                              ",
+            "    ╭───────────────────────────── run a new container on build, field 2/6 ──────────────────────────────╮    ",
+            "    │    image  redis:7                                                                                  │    ",
+            "    │▶    name  cache█                                                                                   │    ",
+            "    │      env                                                                                           │    ",
+            "    │     port                                                                                           │    ",
+            "    │    mount                                                                                           │    ",
+            "    │  restart  no                                                                                       │    ",
+            "    │                                                                                                    │    ",
+            "    │( ↑ ↓ ) select field ( ← → ) choose a local image, or restart policy ( enter ) run ( esc ) cancel   │    ",
+            "    ╰────────────────────────────────────────────────────────────────────────────────────────────────────╯    ",
+            "                                                                                                              ",
+            "                                                                                                              ",
         ];
-        setup.app_data.lock().containers.items[0].state = State::Removing;
-        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
 
         setup
             .terminal
             .draw(|f| {
-                super::containers(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+                super::run_form(f, &setup.gui_state, &Theme::default());
             })
             .unwrap();
 
-        check_expected(expected, w, h, &setup, Color::LightRed);
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                assert_eq!(expected_char.to_string(), result[index].symbol());
+            }
+        }
     }
+
     #[test]
-    /// When container state is restarting, correct colors displayed
-    fn test_draw_blocks_containers_restarting() {
-        let (w, h) = (130, 6);
+    /// Traffic lists the most traffic first, with the selected container marked, and the daemon's totals alongside
+    fn test_draw_blocks_traffic() {
+        let (w, h) = (90, 14);
         let mut setup = test_setup(w, h, true, true);
+        {
+            let mut app_data = setup.app_data.lock();
+            // In the future, so that the counted for column is always 0s
+            let since = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_secs()
+                + 60;
+            for (index, item) in app_data.containers.items.iter_mut().enumerate() {
+                item.traffic.since = since;
+                item.rx = ByteStats::new(20_000);
+                item.tx = ByteStats::new(1_000);
+                item.traffic.count(0, 0, since);
+                item.traffic.count(1_000 * index as u64, 500, since);
+            }
+        }
 
         let expected = [
-            "╭ Containers 1/3 ────────────────────────────────────────────────────────────────────────────────────────────────────────────────╮",
-            "│⚪  container_1   ↻ restarting          Up 1 hour    00.00%   0.00 kB / 0.00 kB          1   image_1   0.00 kB   0.00 kB         │",
-            "│   container_2   ✓ running             Up 2 hour    00.00%   0.00 kB / 0.00 kB          2   image_2   0.00 kB   0.00 kB         │",
-            "│   container_3   ✓ running             Up 3 hour    00.00%   0.00 kB / 0.00 kB          3   image_3   0.00 kB   0.00 kB         │",
-            "│                                                                                                                                │",
-            "╰────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────╯",
+            "                                                                                          ",
+            "                                                                                          ",
+            "    ╭─────────────────────────────── network traffic ────────────────────────────────╮    ",
+            "    │  3 containers, ↓ 3.00 kB ↑ 1.50 kB counted by oxker                            │    ",
+            "    │                                                                                │    ",
+            "    │  name              ↓ rx       ↑ tx  counted for  ↓ since start  ↑ since start  │    ",
+            "    │  container_3    2.00 kB    0.50 kB           0s       20.00 kB        1.00 kB  │    ",
+            "    │  container_2    1.00 kB    0.50 kB           0s       20.00 kB        1.00 kB  │    ",
+            "    │▶ container_1    0.00 kB    0.50 kB           0s       20.00 kB        1.00 kB  │    ",
+            "    │                                                                                │    ",
+            "    │  ( r ) reset the selected container ( a ) reset all ( esc ) close              │    ",
+            "    ╰────────────────────────────────────────────────────────────────────────────────╯    ",
+            "                                                                                          ",
+            "                                                                                          ",
         ];
-        setup.app_data.lock().containers.items[0].state = State::Restarting;
-        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
 
         setup
             .terminal
             .draw(|f| {
-                super::containers(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+                super::traffic(f, &setup.app_data, &Theme::default());
             })
             .unwrap();
 
-        check_expected(expected, w, h, &setup, Color::LightGreen);
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                assert_eq!(expected_char.to_string(), result[index].symbol());
+            }
+        }
     }
+
     #[test]
-    /// When container state is unknown, correct colors displayed
-    fn test_draw_blocks_containers_unknown() {
-        let (w, h) = (130, 6);
+    /// Save logs popup shows each option, the selected option can be changed, & the file it's saved as
+    fn test_draw_blocks_log_export() {
+        let (w, h) = (90, 12);
         let mut setup = test_setup(w, h, true, true);
+        let mut export =
+            LogExport::new(ContainerId::from("1"), "container_1".to_owned(), None, None);
+        export.change(true);
+        export.next();
+        setup.gui_state.lock().set_log_export(Some(export));
 
         let expected = [
-            "╭ Containers 1/3 ────────────────────────────────────────────────────────────────────────────────────────────────────────────────╮",
-            "│⚪  container_1   ? unknown            Up 1 hour    00.00%   0.00 kB / 0.00 kB          1   image_1   0.00 kB   0.00 kB          │",
-            "│   container_2   ✓ running            Up 2 hour    00.00%   0.00 kB / 0.00 kB          2   image_2   0.00 kB   0.00 kB          │",
-            "│   container_3   ✓ running            Up 3 hour    00.00%   0.00 kB / 0.00 kB          3   image_3   0.00 kB   0.00 kB          │",
-            "│                                                                                                                                │",
-            "╰────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────╯",
+            "                                                                                          ",
+            "                                                                                          ",
+            "            ╭─────────────────── save logs of container_1 ───────────────────╮            ",
+            "            │  format    json lines                                          │            ",
+            "            │▶ lines   ◀ every line ▶ no filter, or search, is set           │            ",
+            "            │  range     all time                                            │            ",
+            "            │                                                                │            ",
+            "            │  saved as container_1_[timestamp].jsonl                        │            ",
+            "            │  ( ↑ ↓ ) select ( ← → ) change ( enter ) save ( esc ) cancel   │            ",
+            "            ╰────────────────────────────────────────────────────────────────╯            ",
+            "                                                                                          ",
+            "                                                                                          ",
         ];
-        setup.app_data.lock().containers.items[0].state = State::Unknown;
-        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
 
         setup
             .terminal
             .draw(|f| {
-                super::containers(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+                super::log_export(f, &setup.gui_state, &Theme::default());
             })
             .unwrap();
-        check_expected(expected, w, h, &setup, Color::Red);
+
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                assert_eq!(expected_char.to_string(), result[index].symbol());
+            }
+        }
     }
-    // ********** //
-    // Logs panel //
-    // ********** //
 
     #[test]
-    /// No logs, panel unselected, then selected, border color changes correctly
-    fn test_draw_blocks_logs_none() {
-        let (w, h) = (25, 6);
+    #[allow(clippy::too_many_lines)]
+    /// Cleanup menu lists the disk usage, & each prune, with the confirmation of the chosen prune drawn over it
+    fn test_draw_blocks_cleanup() {
+        let (w, h) = (100, 20);
         let mut setup = test_setup(w, h, true, true);
-        setup.app_data.lock().containers = StatefulList::new(vec![]);
+        let usage = |label: &'static str, total: usize, size: u64| DiskUsage {
+            active: total / 2,
+            label,
+            reclaimable: ByteStats::new(size / 4),
+            size: ByteStats::new(size),
+            total,
+        };
+        let row = |kind: CleanupKind, count: usize| CleanupRow {
+            count,
+            kind,
+            size: (kind != CleanupKind::Networks).then(|| ByteStats::new(count as u64 * 1_000_000)),
+        };
+        let mut cleanup = Cleanup::new(
+            vec![
+                usage("images", 8, 2_000_000_000),
+                usage("containers", 4, 20_000_000),
+                usage("volumes", 2, 100_000_000),
+                usage("build cache", 0, 0),
+            ],
+            vec![
+                row(CleanupKind::Containers, 2),
+                row(CleanupKind::DanglingImages, 0),
+                row(CleanupKind::AllImages, 4),
+                row(CleanupKind::Volumes, 1),
+                row(CleanupKind::Networks, 3),
+            ],
+            None,
+        );
+        cleanup.next();
+        cleanup.next();
+        setup.gui_state.lock().set_cleanup(Some(cleanup));
 
         let expected = [
-            "╭ Logs ─────────────────╮",
-            "│     no logs found     │",
-            "│                       │",
-            "│                       │",
-            "│                       │",
-            "╰───────────────────────╯",
+            "                                                                                                    ",
+            "                                                                                                    ",
+            "            ╭──────────────────────────────── cleanup ─────────────────────────────────╮            ",
+            "            │  disk usage                   total  active        size  reclaimable     │            ",
+            "            │  images                           8       4     2.00 GB    500.00 MB     │            ",
+            "            │  containers                       4       2    20.00 MB      5.00 MB     │            ",
+            "            │  volumes                          2       1   100.00 MB     25.00 MB     │            ",
+            "            │  build cache                      0       0     0.00 kB      0.00 kB     │            ",
+            "            │                                                                          │            ",
+            "            │  prune                       equivalent to           removes  reclaims   │            ",
+            "            │  stopped containers          docker container prune        2   2.00 MB   │            ",
+            "            │  dangling images             docker image prune            0   0.00 kB   │            ",
+            "            │▶ unused images               docker image prune -a         4   4.00 MB   │            ",
+            "            │  unused anonymous volumes    docker volume prune           1   1.00 MB   │            ",
+            "            │  unused networks             docker network prune          3         -   │            ",
+            "            │                                                                          │            ",
+            "            │  ( ↑ ↓ ) select ( enter ) prune ( esc ) close                            │            ",
+            "            ╰──────────────────────────────────────────────────────────────────────────╯            ",
+            "                                                                                                    ",
+            "                                                                                                    ",
         ];
 
-        let _fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
-
         setup
             .terminal
             .draw(|f| {
-                super::logs(&setup.app_data, setup.area, f, &setup.fd, &setup.gui_state);
+                super::cleanup(f, &setup.gui_state, &Theme::default());
             })
             .unwrap();
 
@@ -1757,22 +9866,38 @@ mod tests {
         for (row_index, row) in expected.iter().enumerate() {
             for (char_index, expected_char) in row.chars().enumerate() {
                 let index = row_index * usize::from(w) + char_index;
-                let result_cell = &result[index];
-
-                assert_eq!(result_cell.symbol(), expected_char.to_string());
-                assert_eq!(result_cell.fg, Color::Reset);
+                assert_eq!(expected_char.to_string(), result[index].symbol());
             }
         }
 
-        setup.gui_state.lock().next_panel();
-        setup.gui_state.lock().next_panel();
-        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+        setup.gui_state.lock().get_cleanup().unwrap().ask();
+        let expected = [
+            "                                                                                                    ",
+            "                                                                                                    ",
+            "            ╭──────────────────────────────── cleanup ─────────────────────────────────╮            ",
+            "            │  disk usage                   total  active        size  reclaimable     │            ",
+            "            │  images                           8       4     2.00 GB    500.00 MB     │            ",
+            "            │  containers                       4       2    20.00 MB      5.00 MB     │            ",
+            "            │  volumes                          2       1   100.00 MB     25.00 MB     │            ",
+            "            │  bui╭─────────────────────── Confirm Prune ────────────────────────╮     │            ",
+            "            │     │        Are you sure you want to prune: 4 unused images       │     │            ",
+            "            │  pru│    as with docker image prune -a, reclaiming up to 4.00 MB   │ms   │            ",
+            "            │  sto│                                                              │MB   │            ",
+            "            │  dan│                      ( y ) yes ( n ) no                      │kB   │            ",
+            "            │▶ unu╰──────────────────────────────────────────────────────────────╯MB   │            ",
+            "            │  unused anonymous volumes    docker volume prune           1   1.00 MB   │            ",
+            "            │  unused networks             docker network prune          3         -   │            ",
+            "            │                                                                          │            ",
+            "            │  ( ↑ ↓ ) select ( enter ) prune ( esc ) close                            │            ",
+            "            ╰──────────────────────────────────────────────────────────────────────────╯            ",
+            "                                                                                                    ",
+            "                                                                                                    ",
+        ];
 
-        // When selected, has a blue border
         setup
             .terminal
             .draw(|f| {
-                super::logs(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+                super::cleanup(f, &setup.gui_state, &Theme::default());
             })
             .unwrap();
 
@@ -1780,755 +9905,1065 @@ mod tests {
         for (row_index, row) in expected.iter().enumerate() {
             for (char_index, expected_char) in row.chars().enumerate() {
                 let index = row_index * usize::from(w) + char_index;
-                let result_cell = &result[index];
-
-                assert_eq!(result_cell.symbol(), expected_char.to_string());
-                if BORDER_CHARS.contains(&result_cell.symbol()) {
-                    assert_eq!(result_cell.fg, Color::LightCyan);
-                }
+                assert_eq!(expected_char.to_string(), result[index].symbol());
             }
         }
+
+        // With --dry-run, the first ( y ) only arms the prune
+        setup.gui_state.lock().get_cleanup().unwrap().armed = true;
+        setup
+            .terminal
+            .draw(|f| {
+                super::cleanup(f, &setup.gui_state, &Theme::default());
+            })
+            .unwrap();
+        let result = &setup.terminal.backend().buffer().content;
+        let row = (0..usize::from(w))
+            .map(|i| result[11 * usize::from(w) + i].symbol())
+            .collect::<String>();
+        assert!(row.contains("( y ) again to prune ( n ) no"));
     }
 
     #[test]
-    /// Parsing logs, spinner visible, and then animates by one frame
-    fn test_draw_blocks_logs_parsing() {
-        let (w, h) = (25, 6);
+    /// Memory breakdown of the selected container, each with its share of the usage
+    fn test_draw_blocks_memory() {
+        let (w, h) = (104, 19);
         let mut setup = test_setup(w, h, true, true);
-        let uuid = Uuid::new_v4();
-        setup.gui_state.lock().next_loading(uuid);
+        setup.app_data.lock().containers.items[0].memory = Some(MemoryBreakdown {
+            active_file: ByteStats::new(300_000_000),
+            anon: ByteStats::new(250_000_000),
+            file: ByteStats::new(700_000_000),
+            inactive_file: ByteStats::new(400_000_000),
+            kernel: Some(ByteStats::new(50_000_000)),
+            limit: ByteStats::new(2_000_000_000),
+            mapped_file: ByteStats::new(20_000_000),
+            shmem: ByteStats::new(10_000_000),
+            usage: ByteStats::new(1_000_000_000),
+            v2: true,
+        });
 
         let expected = [
-            "╭ Logs - container_1 ───╮",
-            "│    parsing logs ⠙     │",
-            "│                       │",
-            "│                       │",
-            "│                       │",
-            "╰───────────────────────╯",
+            "                                                                                                        ",
+            "                                                                                                        ",
+            "    ╭───────────────────────────────────── memory container_1 ─────────────────────────────────────╮    ",
+            "    │  usage              1.00 GB 100%  of 2.00 GB limit                                           │    ",
+            "    │  working set      600.00 MB  60%  without the inactive file cache, as in the memory column   │    ",
+            "    │                                                                                              │    ",
+            "    │  anonymous        250.00 MB  25%  heaps & stacks, only freed by the processes, or swapped    │    ",
+            "    │  file             700.00 MB  70%  page cache, reclaimed when memory is needed                │    ",
+            "    │    active         300.00 MB  30%  recently used, reclaimed after the inactive                │    ",
+            "    │    inactive       400.00 MB  40%  reclaimed first                                            │    ",
+            "    │    mapped          20.00 MB   2%  memory mapped files, such as shared libraries              │    ",
+            "    │    shared          10.00 MB   1%  tmpfs & shared memory, can't be reclaimed                  │    ",
+            "    │  kernel            50.00 MB   5%  kernel stacks, slab, & socket buffers                      │    ",
+            "    │                                                                                              │    ",
+            "    │  cgroup v2                                                                                   │    ",
+            "    │  ( esc ) close                                                                               │    ",
+            "    ╰──────────────────────────────────────────────────────────────────────────────────────────────╯    ",
+            "                                                                                                        ",
+            "                                                                                                        ",
         ];
 
-        let mut fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
-        fd.init = true;
-
         setup
             .terminal
             .draw(|f| {
-                super::logs(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+                super::memory(f, &setup.app_data, &Theme::default());
             })
             .unwrap();
 
-        let test = |terminal: &Terminal<TestBackend>, expected: [&str; 6]| {
-            let result = &terminal.backend().buffer().content;
-            for (row_index, row) in expected.iter().enumerate() {
-                for (char_index, expected_char) in row.chars().enumerate() {
-                    let index = row_index * usize::from(w) + char_index;
-                    let result_cell = &result[index];
-
-                    assert_eq!(result_cell.symbol(), expected_char.to_string());
-                    assert_eq!(result_cell.fg, Color::Reset);
-                }
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                assert_eq!(expected_char.to_string(), result[index].symbol());
             }
-        };
-
-        test(&setup.terminal, expected);
+        }
+    }
 
-        // animation moved by one frame
-        setup.gui_state.lock().next_loading(uuid);
+    #[test]
+    /// Each core is a bar, in columns, & a single saturated core is called out
+    fn test_draw_blocks_cores() {
+        let (w, h) = (100, 13);
+        let mut setup = test_setup(w, h, true, true);
+        setup.app_data.lock().containers.items[0].cores = Some(CpuCores {
+            cores: [98.0, 4.0, 0.0, 12.5, 0.0, 0.0, 1.0, 3.0]
+                .map(CpuStats::new)
+                .to_vec(),
+        });
 
         let expected = [
-            "╭ Logs - container_1 ───╮",
-            "│    parsing logs ⠹     │",
-            "│                       │",
-            "│                       │",
-            "│                       │",
-            "╰───────────────────────╯",
+            "                                                                                                    ",
+            "                                                                                                    ",
+            "        ╭───────────────────────────── cpu cores container_1 ──────────────────────────────╮        ",
+            "        │  cpu 0 ██████████  98.00%  cpu 3 █·········  12.50%  cpu 6 ··········  01.00%    │        ",
+            "        │  cpu 1 ··········  04.00%  cpu 4 ··········  00.00%  cpu 7 ··········  03.00%    │        ",
+            "        │  cpu 2 ··········  00.00%  cpu 5 ··········  00.00%                              │        ",
+            "        │                                                                                  │        ",
+            "        │  busiest cpu 0 at 98.00%, 118.50% in total, over 8 cores                         │        ",
+            "        │  one core is saturated, the rest are idle, likely a single threaded bottleneck   │        ",
+            "        │  ( esc ) close                                                                   │        ",
+            "        ╰──────────────────────────────────────────────────────────────────────────────────╯        ",
+            "                                                                                                    ",
+            "                                                                                                    ",
         ];
 
-        let mut fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
-        fd.init = true;
         setup
             .terminal
             .draw(|f| {
-                super::logs(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+                super::cores(f, &setup.app_data, &Theme::default());
             })
             .unwrap();
 
-        test(&setup.terminal, expected);
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                assert_eq!(expected_char.to_string(), result[index].symbol());
+            }
+        }
     }
 
     #[test]
-    /// Logs correct displayed, changing log state also draws correctly
-    fn test_draw_blocks_logs_some() {
-        let (w, h) = (25, 6);
+    /// The selected pane is marked, & hidden charts are shown as hidden
+    fn test_draw_blocks_layout() {
+        let (w, h) = (100, 11);
         let mut setup = test_setup(w, h, true, true);
+        let mut layout = PaneLayout::default();
+        layout.set_containers(Some(12));
+        layout.toggle(Pane::Charts);
 
-        insert_logs(&setup);
+        let expected = [
+            "                                                                                                    ",
+            "                                                                                                    ",
+            "    ╭───────────────────────────────────────── layout ─────────────────────────────────────────╮    ",
+            "    │  containers  12 rows                                                                     │    ",
+            "    │▶ charts      hidden                                                                      │    ",
+            "    │  the logs take the rest                                                                  │    ",
+            "    │                                                                                          │    ",
+            "    │  ( ↑ ↓ ) select  ( ← → ) resize  ( space ) hide  ( r ) reset  ( s ) save  ( esc ) close  │    ",
+            "    ╰──────────────────────────────────────────────────────────────────────────────────────────╯    ",
+            "                                                                                                    ",
+            "                                                                                                    ",
+        ];
 
-        let test = |terminal: &Terminal<TestBackend>,
-                    expected: [&str; 6],
-                    range: RangeInclusive<usize>| {
-            let result = &terminal.backend().buffer().content;
+        setup
+            .terminal
+            .draw(|f| {
+                super::layout(f, layout, Pane::Charts, &Theme::default());
+            })
+            .unwrap();
 
-            for (row_index, row) in expected.iter().enumerate() {
-                for (char_index, expected_char) in row.chars().enumerate() {
-                    let index = row_index * usize::from(w) + char_index;
-                    let result_cell = &result[index];
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                assert_eq!(expected_char.to_string(), result[index].symbol());
+            }
+        }
+    }
 
-                    assert_eq!(result_cell.symbol(), expected_char.to_string());
-                    assert_eq!(result_cell.fg, Color::Reset);
+    #[test]
+    /// Tag picker marks the current tag, and the selected tag
+    fn test_draw_blocks_tag_picker() {
+        let (w, h) = (84, 11);
+        let mut setup = test_setup(w, h, true, true);
+        let mut picker = TagPicker::new(
+            ContainerId::from("1"),
+            "web".to_owned(),
+            "nginx:1.25".to_owned(),
+            ["nginx:1.26", "nginx:1.25", "nginx:1.24"]
+                .map(String::from)
+                .to_vec(),
+        );
+        picker.next();
+        setup.gui_state.lock().set_tag_picker(Some(picker));
 
-                    if range.contains(&index) {
-                        assert_eq!(result_cell.modifier, Modifier::BOLD);
-                    } else {
-                        assert!(result_cell.modifier.is_empty());
-                    }
-                }
-            }
-        };
+        let expected = [
+            "                                                                                    ",
+            "                                                                                    ",
+            "    ╭─────────────────────────── switch tag of web ────────────────────────────╮    ",
+            "    │  nginx:1.26                                                              │    ",
+            "    │  nginx:1.25 (current)                                                    │    ",
+            "    │▶ nginx:1.24                                                              │    ",
+            "    │                                                                          │    ",
+            "    │( ↑ ↓ ) select tag ( enter ) recreate on the selected tag ( esc ) cancel  │    ",
+            "    ╰──────────────────────────────────────────────────────────────────────────╯    ",
+            "                                                                                    ",
+            "                                                                                    ",
+        ];
 
-        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
         setup
             .terminal
             .draw(|f| {
-                super::logs(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+                super::tag_picker(f, &setup.gui_state, &Theme::default());
             })
             .unwrap();
+
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                assert_eq!(expected_char.to_string(), result[index].symbol());
+            }
+        }
+    }
+
+    #[test]
+    /// Recently viewed containers are listed most recent first, with their state, the most recent is selected
+    fn test_draw_blocks_recent_picker() {
+        let (w, h) = (72, 11);
+        let mut setup = test_setup(w, h, true, true);
+        let rows = [("shop-api-1", State::Running), ("backup", State::Exited)]
+            .into_iter()
+            .map(|(name, state)| RecentRow {
+                id: ContainerId::from(name),
+                name: ContainerName::from(name),
+                state,
+            })
+            .collect::<Vec<_>>();
+        setup
+            .gui_state
+            .lock()
+            .set_recent_picker(Some(RecentPicker::new(rows)));
+
         let expected = [
-            "╭ Logs 3/3 - container_1╮",
-            "│  line 1               │",
-            "│  line 2               │",
-            "│▶ line 3               │",
-            "│                       │",
-            "╰───────────────────────╯",
+            "                                                                        ",
+            "                                                                        ",
+            "      ╭───────────────────────── recent ─────────────────────────╮      ",
+            "      │▶ shop-api-1  ✓ running                                   │      ",
+            "      │  backup      ✖ exited                                    │      ",
+            "      │                                                          │      ",
+            "      │( ↑ ↓ \\ ) select container ( enter ) jump ( esc ) cancel  │      ",
+            "      │                                                          │      ",
+            "      ╰──────────────────────────────────────────────────────────╯      ",
+            "                                                                        ",
+            "                                                                        ",
         ];
-        test(&setup.terminal, expected, 76..=98);
-
-        // Change selected log line
-        setup.app_data.lock().log_previous();
-        let _fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
 
         setup
             .terminal
             .draw(|f| {
-                super::logs(&setup.app_data, setup.area, f, &setup.fd, &setup.gui_state);
+                super::recent_picker(f, &setup.gui_state, &Theme::default());
             })
             .unwrap();
 
-        let expected = [
-            "╭ Logs 2/3 - container_1╮",
-            "│  line 1               │",
-            "│▶ line 2               │",
-            "│  line 3               │",
-            "│                       │",
-            "╰───────────────────────╯",
-        ];
-        test(&setup.terminal, expected, 51..=73);
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                assert_eq!(expected_char.to_string(), result[index].symbol());
+            }
+        }
     }
 
     #[test]
-    /// Full (long) name displayed in logs border
-    fn test_draw_blocks_logs_long_name() {
-        let (w, h) = (80, 6);
+    /// Host switcher lists each host tab, with its running & total containers, the current tab is marked
+    fn test_draw_blocks_host_picker() {
+        let (w, h) = (72, 11);
         let mut setup = test_setup(w, h, true, true);
-        setup.app_data.lock().containers.items[0].name =
-            ContainerName::from("a_long_container_name_for_the_purposes_of_this_test");
-        setup.app_data.lock().containers.items[0].image =
-            ContainerImage::from("a_long_image_name_for_the_purposes_of_this_test");
-
-        insert_logs(&setup);
+        let rows = [
+            (None, 2, 3),
+            (Some("local"), 1, 2),
+            (Some("ssh://build"), 1, 1),
+        ]
+        .into_iter()
+        .map(|(host, running, total)| HostRow {
+            host: host.map(ToOwned::to_owned),
+            running,
+            total,
+        })
+        .collect::<Vec<_>>();
+        setup
+            .gui_state
+            .lock()
+            .set_host_picker(Some(HostPicker::new(rows, 0)));
 
         let expected = [
-            "╭ Logs 3/3 - a_long_container_name_for_the_purposes_of_this_test ──────────────╮",
-            "│  line 1                                                                      │",
-            "│  line 2                                                                      │",
-            "│▶ line 3                                                                      │",
-            "│                                                                              │",
-            "╰──────────────────────────────────────────────────────────────────────────────╯",
+            "                                                                        ",
+            "                                                                        ",
+            "        ╭─────────────────────── hosts ────────────────────────╮        ",
+            "        │  all hosts    2/3 running current                    │        ",
+            "        │▶ local        1/2 running                            │        ",
+            "        │  ssh://build  1/1 running                            │        ",
+            "        │                                                      │        ",
+            "        │( ↑ ↓ ) select host ( enter ) switch ( esc ) cancel   │        ",
+            "        ╰──────────────────────────────────────────────────────╯        ",
+            "                                                                        ",
+            "                                                                        ",
         ];
 
-        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
         setup
             .terminal
             .draw(|f| {
-                super::logs(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+                super::host_picker(f, &setup.gui_state, &Theme::default());
             })
             .unwrap();
 
         let result = &setup.terminal.backend().buffer().content;
-
         for (row_index, row) in expected.iter().enumerate() {
             for (char_index, expected_char) in row.chars().enumerate() {
                 let index = row_index * usize::from(w) + char_index;
-                let result_cell = &result[index];
-
-                assert_eq!(result_cell.symbol(), expected_char.to_string());
+                assert_eq!(expected_char.to_string(), result[index].symbol());
             }
         }
     }
 
-    // ************ //
-    // Charts panel //
-    // ************ //
+    #[test]
+    /// Context picker lists the default context first, then each stored context, with its endpoint
+    fn test_draw_blocks_context_picker() {
+        let (w, h) = (72, 11);
+        let mut setup = test_setup(w, h, true, true);
+        let contexts = vec![
+            DockerContext {
+                name: "build".to_owned(),
+                host: Some("ssh://ci@build".to_owned()),
+                tls: None,
+            },
+            DockerContext {
+                name: "prod".to_owned(),
+                host: Some("tcp://prod:2376".to_owned()),
+                tls: Some(TlsFiles {
+                    ca: "ca.pem".into(),
+                    cert: "cert.pem".into(),
+                    key: "key.pem".into(),
+                }),
+            },
+        ];
+        setup
+            .gui_state
+            .lock()
+            .set_context_picker(Some(ContextPicker::new(contexts)));
 
-    const EXPECTED: [&str; 10] = [
-        "╭───────────── cpu 03.00% ─────────────╮╭────────── memory 30.00 kB ───────────╮",
-        "│10.00%│    •                          ││100.00 kB│   ••                       │",
-        "│      │   ••                          ││         │   ••                       │",
-        "│      │  •••                          ││         │  • •                       │",
-        "│      │  • •                          ││         │ •  •                       │",
-        "│      │ •   ••                        ││         │••  ••                      │",
-        "│      │•    •                         ││         │•   •                       │",
-        "│      │•    •                         ││         │•   •                       │",
-        "│      │                               ││         │                            │",
-        "╰──────────────────────────────────────╯╰──────────────────────────────────────╯",
-    ];
-    const MEMORY_INDEX: [usize; 16] = [
-        134, 135, 214, 215, 293, 295, 372, 375, 451, 452, 455, 456, 531, 535, 611, 615,
-    ];
+        let expected = [
+            "                                                                        ",
+            "                                                                        ",
+            "      ╭──────────────────── docker contexts ─────────────────────╮      ",
+            "      │▶ default  default socket                                 │      ",
+            "      │  build    ssh://ci@build                                 │      ",
+            "      │  prod     tcp://prod:2376 tls                            │      ",
+            "      │                                                          │      ",
+            "      │( ↑ ↓ ) select context ( enter ) connect ( esc ) default  │      ",
+            "      ╰──────────────────────────────────────────────────────────╯      ",
+            "                                                                        ",
+            "                                                                        ",
+        ];
 
-    const CPU_INDEX: [usize; 15] = [
-        92, 171, 172, 250, 251, 252, 330, 332, 409, 413, 414, 488, 493, 568, 573,
-    ];
+        setup
+            .terminal
+            .draw(|f| {
+                super::context_picker(f, &setup.gui_state, &Theme::default());
+            })
+            .unwrap();
 
-    #[allow(clippy::cast_precision_loss)]
-    // Add fixed data to the cpu & mem vecdeques, that match the above data
-    fn insert_chart_data(setup: &TuiTestSetup) {
-        for i in 1..=10 {
-            setup.app_data.lock().update_stats_by_id(
-                &setup.ids[0],
-                Some(i as f64),
-                Some(i * 10000),
-                i * 10000,
-                i,
-                i,
-            );
-        }
-        for i in 1..=3 {
-            setup.app_data.lock().update_stats_by_id(
-                &setup.ids[0],
-                Some(i as f64),
-                Some(i * 10000),
-                i * 10000,
-                i,
-                i,
-            );
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                assert_eq!(expected_char.to_string(), result[index].symbol());
+            }
         }
     }
+
     #[test]
-    /// When status is Running, but not data, charts drawn without dots etc
-    fn test_draw_blocks_charts_running_none() {
-        let (w, h) = (80, 10);
+    /// Inspect view shows the top level keys, an expanded node shows its children, indented
+    fn test_draw_blocks_inspect_view() {
+        let (w, h) = (64, 12);
         let mut setup = test_setup(w, h, true, true);
+        let value = serde_json::json!({
+            "Name": "/container_1",
+            "Config": {"Env": ["A=1", "B=2"]},
+            "RestartCount": 0,
+        });
+        let mut view = InspectView::new("container_1".to_owned(), value);
+        view.expand(Some(true));
+        view.next(1);
+        setup.gui_state.lock().set_inspect_view(Some(view));
+
+        let expected = [
+            "                                                                ",
+            "                                                                ",
+            "    ╭──────────────── inspect container_1 ─────────────────╮    ",
+            "    │▾ Config: {1}                                         │    ",
+            "    │  ▸ Env: [2]                                          │    ",
+            "    │  Name: \"/container_1\"                                │    ",
+            "    │  RestartCount: 0                                     │    ",
+            "    │                                                      │    ",
+            "    │( ↑ ↓ ) move ( ← → ) collapse, expand ( esc ) close   │    ",
+            "    ╰──────────────────────────────────────────────────────╯    ",
+            "                                                                ",
+            "                                                                ",
+        ];
 
         setup
             .terminal
             .draw(|f| {
-                super::chart(f, setup.area, &setup.app_data);
+                super::inspect_view(f, &setup.gui_state, &Theme::default());
             })
             .unwrap();
 
-        let expected = [
-            "╭───────────── cpu 00.00% ─────────────╮╭─────────── memory 0.00 kB ───────────╮",
-            "│00.00%│                               ││0.00 kB│                              │",
-            "│      │                               ││       │                              │",
-            "│      │                               ││       │                              │",
-            "│      │                               ││       │                              │",
-            "│      │                               ││       │                              │",
-            "│      │                               ││       │                              │",
-            "│      │                               ││       │                              │",
-            "│      │                               ││       │                              │",
-            "╰──────────────────────────────────────╯╰──────────────────────────────────────╯",
-        ];
-
         let result = &setup.terminal.backend().buffer().content;
         for (row_index, row) in expected.iter().enumerate() {
             for (char_index, expected_char) in row.chars().enumerate() {
                 let index = row_index * usize::from(w) + char_index;
-                let result_cell = &result[index];
-
-                assert_eq!(result_cell.symbol(), expected_char.to_string());
-
-                match index {
-                    // chart tiles - cpu 03.00% && memory 30.00 kB - are green
-                    14..=25 | 52..=67 => {
-                        assert_eq!(result_cell.fg, Color::Green);
-                        assert_eq!(result_cell.modifier, Modifier::BOLD);
-                    }
-                    // Cpu & Memory max are orange and bold
-                    81..=86 | 121..=127 => {
-                        assert_eq!(result_cell.fg, ORANGE);
-                        assert_eq!(result_cell.modifier, Modifier::BOLD);
-                    }
-                    // All others
-                    _ => {
-                        assert_eq!(result_cell.fg, Color::Reset);
-                        assert!(result_cell.modifier.is_empty());
-                    }
-                }
+                assert_eq!(expected_char.to_string(), result[index].symbol());
             }
         }
+        assert!(result[4 * usize::from(w) + 6]
+            .modifier
+            .contains(Modifier::REVERSED));
     }
 
     #[test]
-    /// When status is Running, charts correctly drawn
-    fn test_draw_blocks_charts_running_some() {
-        let (w, h) = (80, 10);
+    /// The settings of both containers are side by side, under their section, with the differences marked, & only they are shown once toggled
+    fn test_draw_blocks_inspect_diff() {
+        let (w, h) = (76, 14);
         let mut setup = test_setup(w, h, true, true);
+        let row = |section, key: &str, left: Option<&str>, right: Option<&str>| DiffRow {
+            section,
+            key: key.to_owned(),
+            left: left.map(ToOwned::to_owned),
+            right: right.map(ToOwned::to_owned),
+        };
+        let mut diff = InspectDiff::new(
+            "api-staging".to_owned(),
+            "api-prod".to_owned(),
+            vec![
+                row(
+                    DiffSection::Image,
+                    "image",
+                    Some("api:2.1"),
+                    Some("api:2.1"),
+                ),
+                row(DiffSection::Env, "LOG", Some("debug"), Some("info")),
+                row(DiffSection::Env, "TZ", Some("UTC"), Some("UTC")),
+                row(DiffSection::Limits, "memory", None, Some("512.00 MB")),
+            ],
+        );
+        setup.gui_state.lock().set_inspect_diff(Some(diff.clone()));
 
-        insert_chart_data(&setup);
+        let expected = [
+            "                                                                            ",
+            "                                                                            ",
+            "    ╭────── compare api-staging & api-prod - 2/4 settings differ ──────╮    ",
+            "    │             api-staging                api-prod                  │    ",
+            "    │ image                                                            │    ",
+            "    │   image     api:2.1                    api:2.1                   │    ",
+            "    │ env                                                              │    ",
+            "    │ ≠ LOG       debug                      info                      │    ",
+            "    │   TZ        UTC                        UTC                       │    ",
+            "    │                                                                  │    ",
+            "    │   ( ↑ ↓ ) scroll ( space ) only show differences ( esc ) close   │    ",
+            "    ╰──────────────────────────────────────────────────────────────────╯    ",
+            "                                                                            ",
+            "                                                                            ",
+        ];
 
         setup
             .terminal
             .draw(|f| {
-                super::chart(f, setup.area, &setup.app_data);
+                super::inspect_diff(f, &setup.gui_state, &Theme::default());
             })
             .unwrap();
 
         let result = &setup.terminal.backend().buffer().content;
-        for (row_index, row) in EXPECTED.iter().enumerate() {
+        for (row_index, row) in expected.iter().enumerate() {
             for (char_index, expected_char) in row.chars().enumerate() {
                 let index = row_index * usize::from(w) + char_index;
-                let result_cell = &result[index];
+                assert_eq!(expected_char.to_string(), result[index].symbol());
+            }
+        }
+        assert_eq!(result[7 * usize::from(w) + 18].fg, Color::Blue);
+        assert!(result[8 * usize::from(w) + 18].modifier.is_empty());
 
-                assert_eq!(result_cell.symbol(), expected_char.to_string());
-                match index {
-                    // chart tiles - cpu 03.00% && memory 30.00 kB - are green
-                    14..=25 | 51..=67 => {
-                        assert_eq!(result_cell.fg, Color::Green);
-                        assert_eq!(result_cell.modifier, Modifier::BOLD);
-                    }
-                    // Cpu & Memory max are orange and bold
-                    81..=86 | 121..=129 => {
-                        assert_eq!(result_cell.fg, ORANGE);
-                        assert_eq!(result_cell.modifier, Modifier::BOLD);
-                    }
-                    // cpu dots are magenta
-                    _x if CPU_INDEX.contains(&index) => {
-                        assert_eq!(result_cell.fg, Color::Magenta);
-                        assert!(result_cell.modifier.is_empty());
-                    }
-                    // memory dots are cyan
-                    _x if MEMORY_INDEX.contains(&index) => {
-                        assert_eq!(result_cell.fg, Color::Cyan);
-                        assert!(result_cell.modifier.is_empty());
-                    }
-                    // All others
-                    _ => {
-                        assert_eq!(result_cell.fg, Color::Reset);
-                        assert!(result_cell.modifier.is_empty());
-                    }
-                }
+        diff.toggle_changed();
+        setup.gui_state.lock().set_inspect_diff(Some(diff));
+        let expected = [
+            "                                                                            ",
+            "                                                                            ",
+            "    ╭────── compare api-staging & api-prod - 2/4 settings differ ──────╮    ",
+            "    │             api-staging                api-prod                  │    ",
+            "    │ env                                                              │    ",
+            "    │ ≠ LOG       debug                      info                      │    ",
+            "    │ limits                                                           │    ",
+            "    │ ≠ memory    -                          512.00 MB                 │    ",
+            "    │                                                                  │    ",
+            "    │   ( ↑ ↓ ) scroll ( space ) show every setting ( esc ) close      │    ",
+            "    │                                                                  │    ",
+            "    ╰──────────────────────────────────────────────────────────────────╯    ",
+            "                                                                            ",
+            "                                                                            ",
+        ];
+        setup
+            .terminal
+            .draw(|f| {
+                super::inspect_diff(f, &setup.gui_state, &Theme::default());
+            })
+            .unwrap();
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                assert_eq!(expected_char.to_string(), result[index].symbol());
             }
         }
     }
 
     #[test]
-    /// Whens status paused, some text is now Yellow
-    fn test_draw_blocks_charts_paused() {
-        let (w, h) = (80, 10);
+    /// Events pane lists the newest events first, with how long ago each happened, & the host of an extra host's event
+    fn test_draw_blocks_events() {
+        let (w, h) = (56, 6);
         let mut setup = test_setup(w, h, true, true);
+        let event = |time, host: Option<&str>, name: &str, kind| DockerEvent {
+            time,
+            host: host.map(ToOwned::to_owned),
+            id: None,
+            name: name.to_owned(),
+            kind,
+        };
+        setup
+            .app_data
+            .lock()
+            .push_event(event(40, None, "nginx:latest", EventKind::Pull));
+        setup.app_data.lock().push_event(event(
+            70,
+            Some("build"),
+            "api",
+            EventKind::Container(TimelineKind::Die(Some(137))),
+        ));
+        setup.app_data.lock().push_event(event(
+            95,
+            None,
+            "db",
+            EventKind::Container(TimelineKind::Healthy),
+        ));
 
-        insert_chart_data(&setup);
-        setup.app_data.lock().containers.items[0].state = State::Paused;
+        let expected = [
+            "╭ Events ──────────────────────────────────────────────╮",
+            "│5s ago      ♥ db healthy                              │",
+            "│30s ago     ✖ build: api died, exit code 137          │",
+            "│1m 0s ago   ↓ nginx:latest pulled                     │",
+            "│                                                      │",
+            "╰──────────────────────────────────────────────────────╯",
+        ];
 
         setup
             .terminal
             .draw(|f| {
-                super::chart(f, setup.area, &setup.app_data);
+                super::events(f, setup.area, &setup.app_data, 100);
             })
             .unwrap();
 
         let result = &setup.terminal.backend().buffer().content;
-        for (row_index, row) in EXPECTED.iter().enumerate() {
+        for (row_index, row) in expected.iter().enumerate() {
             for (char_index, expected_char) in row.chars().enumerate() {
                 let index = row_index * usize::from(w) + char_index;
-                let result_cell = &result[index];
-
-                assert_eq!(result_cell.symbol(), expected_char.to_string());
-                match index {
-                    // Titles and y axis are yellow
-                    14..=25 | 51..=67 | 81..=86 | 121..=129 => {
-                        assert_eq!(result_cell.fg, Color::Yellow);
-                        assert_eq!(result_cell.modifier, Modifier::BOLD);
-                    }
-                    _x if CPU_INDEX.contains(&index) => {
-                        assert_eq!(result_cell.fg, Color::Magenta);
-                        assert!(result_cell.modifier.is_empty());
-                    }
-                    // memory dots are cyan
-                    _x if MEMORY_INDEX.contains(&index) => {
-                        assert_eq!(result_cell.fg, Color::Cyan);
-                        assert!(result_cell.modifier.is_empty());
-                    }
-                    // All others
-                    _ => {
-                        assert_eq!(result_cell.fg, Color::Reset);
-                        assert!(result_cell.modifier.is_empty());
-                    }
-                }
+                assert_eq!(expected_char.to_string(), result[index].symbol());
             }
         }
+        assert_eq!(result[usize::from(w) * 2 + 13].fg, Color::Red);
     }
 
     #[test]
-    /// When dead, text is read
-    fn test_draw_blocks_charts_dead() {
-        let (w, h) = (80, 10);
+    /// Schedules panel lists each schedule, with when it next runs, & its last outcome, above the schedule being typed
+    fn test_draw_blocks_schedules() {
+        let (w, h) = (96, 13);
         let mut setup = test_setup(w, h, true, true);
-        insert_chart_data(&setup);
-        setup.app_data.lock().containers.items[0].state = State::Dead;
+        let now = 10_000;
+        {
+            let mut app_data = setup.app_data.lock();
+            app_data
+                .add_schedule("container_1", None, "restart in 1h", now - 60)
+                .unwrap();
+            app_data
+                .add_schedule("container_2", None, "save logs every 30m", now - 2100)
+                .unwrap();
+            app_data.take_due_schedules(None, now - 300);
+            app_data.set_schedule_outcome(1, now - 300, Err("no save directory".to_owned()));
+        }
+        setup.gui_state.lock().set_schedule_form(Some(ScheduleForm {
+            container: Some(("container_1".to_owned(), None)),
+            text: "stop at 3".to_owned(),
+            error: Some("3: expected a time, e.g. 03:00".to_owned()),
+            selected: 1,
+        }));
+
+        let expected = [
+            "                                                                                                ",
+            "                                                                                                ",
+            "    ╭────────────────────────────── schedules, times are UTC ──────────────────────────────╮    ",
+            "    │  container_1  restart in 1h        in 59m 0s                                         │    ",
+            "    │▶ container_2  save logs every 30m  in 25m 0s    failed 5m 0s ago: no save directory  │    ",
+            "    │                                                                                      │    ",
+            "    │ container_1 @ stop at 3█                                                             │    ",
+            "    │ 3: expected a time, e.g. 03:00                                                       │    ",
+            "    │                                                                                      │    ",
+            "    │( ↑ ↓ ) select ( enter ) add ( delete ) remove ( esc ) close                          │    ",
+            "    ╰──────────────────────────────────────────────────────────────────────────────────────╯    ",
+            "                                                                                                ",
+            "                                                                                                ",
+        ];
 
         setup
             .terminal
             .draw(|f| {
-                super::chart(f, setup.area, &setup.app_data);
+                super::schedules(f, &setup.app_data, &setup.gui_state, now, &Theme::default());
             })
             .unwrap();
 
         let result = &setup.terminal.backend().buffer().content;
-        for (row_index, row) in EXPECTED.iter().enumerate() {
+        for (row_index, row) in expected.iter().enumerate() {
             for (char_index, expected_char) in row.chars().enumerate() {
                 let index = row_index * usize::from(w) + char_index;
-                let result_cell = &result[index];
-
-                assert_eq!(result_cell.symbol(), expected_char.to_string());
-                match index {
-                    // Titles and y axis are red
-                    14..=25 | 51..=67 | 81..=86 | 121..=129 => {
-                        assert_eq!(result_cell.fg, Color::Red);
-                        assert_eq!(result_cell.modifier, Modifier::BOLD);
-                    }
-                    // cpu dots are magenta
-                    _x if CPU_INDEX.contains(&index) => {
-                        assert_eq!(result_cell.fg, Color::Magenta);
-                        assert!(result_cell.modifier.is_empty());
-                    }
-                    // memory dots are cyan
-                    _x if MEMORY_INDEX.contains(&index) => {
-                        assert_eq!(result_cell.fg, Color::Cyan);
-                        assert!(result_cell.modifier.is_empty());
-                    }
-                    // All others
-                    _ => {
-                        assert_eq!(result_cell.fg, Color::Reset);
-                        assert!(result_cell.modifier.is_empty());
-                    }
-                }
+                assert_eq!(expected_char.to_string(), result[index].symbol());
             }
         }
     }
 
-    // ******* //
-    // Headers //
-    // ******* //
-
     #[test]
-    /// Heading back only has show/exit help when no containers, correctly coloured
-    fn test_draw_blocks_headers_no_containers() {
-        let (w, h) = (140, 1);
-        let mut setup = test_setup(w, h, true, true);
-        setup.app_data.lock().containers = StatefulList::new(vec![]);
-
-        let mut fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+    /// Filters panel lists each rule, checked if it's on, with the count of the shown containers in the title
+    fn test_draw_blocks_filters() {
+        let (w, h) = (90, 14);
+        let mut setup = test_setup(w, h, true, true);
+        {
+            let mut app_data = setup.app_data.lock();
+            app_data.add_filter("name!=container_3").unwrap();
+            app_data.add_filter("status=paused").unwrap();
+            app_data.toggle_filter(1);
+        }
+        setup.gui_state.lock().set_filter_form(Some(FilterForm {
+            text: "state=up".to_owned(),
+            error: Some("state: expected a key of name, label, or status".to_owned()),
+            selected: 1,
+        }));
 
-        let expected =  "                                                                                                                         ( h ) show help    ";
+        let expected = [
+            "                                                                                          ",
+            "                                                                                          ",
+            "     ╭──────────────────────────── filters - 2/3 shown ─────────────────────────────╮     ",
+            "     │  [x] name!=container_3                                                       │     ",
+            "     │▶ [ ] status=paused                                                           │     ",
+            "     │                                                                              │     ",
+            "     │ state=up█                                                                    │     ",
+            "     │ state: expected a key of name, label, or status                              │     ",
+            "     │                                                                              │     ",
+            "     │( ↑ ↓ ) select ( tab ) on/off ( enter ) add ( delete ) remove ( esc ) close   │     ",
+            "     │                                                                              │     ",
+            "     ╰──────────────────────────────────────────────────────────────────────────────╯     ",
+            "                                                                                          ",
+            "                                                                                          ",
+        ];
 
         setup
             .terminal
             .draw(|f| {
-                super::heading_bar(setup.area, f, &fd, &setup.gui_state);
+                super::filters(f, &setup.app_data, &setup.gui_state, &Theme::default());
             })
             .unwrap();
 
         let result = &setup.terminal.backend().buffer().content;
-        for (index, expected_char) in expected.chars().enumerate() {
-            let result_cell = &result[index];
-
-            assert_eq!(result_cell.symbol(), expected_char.to_string());
-            assert_eq!(result_cell.bg, Color::Magenta);
-            assert_eq!(result_cell.fg, Color::White);
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                assert_eq!(expected_char.to_string(), result[index].symbol());
+            }
         }
+    }
+
+    #[test]
+    /// Reach picker lists each target, with its address & check, targets without a shared network are marked
+    fn test_draw_blocks_reach_picker() {
+        let (w, h) = (72, 11);
+        let mut setup = test_setup(w, h, true, true);
+        let target = |name: &str, ip: &str, port: Option<u16>, shared: bool| ReachTarget {
+            name: name.to_owned(),
+            ip: ip.to_owned(),
+            port,
+            shared,
+        };
+        let mut picker = ReachPicker {
+            id: ContainerId::from("1"),
+            name: "api".to_owned(),
+            selected: 0,
+            targets: vec![
+                target("db", "172.18.0.3", Some(5432), true),
+                target("redis", "172.18.0.4", Some(6379), true),
+                target("cache", "172.17.0.4", None, false),
+            ],
+        };
+        picker.next();
+        setup.gui_state.lock().set_reach_picker(Some(picker));
+
+        let expected = [
+            "                                                                        ",
+            "                                                                        ",
+            "       ╭──────────────────── can api reach ─────────────────────╮       ",
+            "       │  db     172.18.0.3:5432       tcp                      │       ",
+            "       │▶ redis  172.18.0.4:6379       tcp                      │       ",
+            "       │  cache  172.17.0.4            ping no shared network   │       ",
+            "       │                                                        │       ",
+            "       │( ↑ ↓ ) select target ( enter ) check ( esc ) cancel    │       ",
+            "       ╰────────────────────────────────────────────────────────╯       ",
+            "                                                                        ",
+            "                                                                        ",
+        ];
 
-        fd.help_visible = true;
-        let expected =  "                                                                                                                         ( h ) exit help    ";
         setup
             .terminal
             .draw(|f| {
-                super::heading_bar(setup.area, f, &fd, &setup.gui_state);
+                super::reach_picker(f, &setup.gui_state, &Theme::default());
             })
             .unwrap();
 
         let result = &setup.terminal.backend().buffer().content;
-        for (index, expected_char) in expected.chars().enumerate() {
-            let result_cell = &result[index];
-
-            assert_eq!(result_cell.symbol(), expected_char.to_string());
-            assert_eq!(result_cell.bg, Color::Magenta);
-            assert_eq!(result_cell.fg, Color::Black);
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                assert_eq!(expected_char.to_string(), result[index].symbol());
+            }
         }
     }
 
     #[test]
-    /// Show all headings when containers present, colors valid
-    fn test_draw_blocks_headers_some_containers() {
-        let (w, h) = (140, 1);
+    /// Timeline draws events, & the running state, on the axis, with the most recent events listed newest first
+    fn test_draw_blocks_timeline() {
+        let (w, h) = (72, 18);
         let mut setup = test_setup(w, h, true, true);
-        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+        let end = 1_000_000;
+        let event = |hours_ago: i64, kind| TimelineEvent {
+            time: end - hours_ago * 3600,
+            kind,
+        };
+        setup.gui_state.lock().set_timeline(Some(Timeline::new(
+            "web".to_owned(),
+            end,
+            true,
+            vec![
+                event(20, TimelineKind::Start),
+                event(12, TimelineKind::Oom),
+                event(12, TimelineKind::Die(Some(137))),
+                event(11, TimelineKind::Start),
+            ],
+        )));
+
+        let expected = [
+            "                                                                        ",
+            "                                                                        ",
+            "    ╭───────────────── timeline of web, last 24h ──────────────────╮    ",
+            "    │ events  ········▲················!·▲·······················  │    ",
+            "    │ running ────────━━━━━━━━━━━━━━━━━──━━━━━━━━━━━━━━━━━━━━━━━━  │    ",
+            "    │         24h ago               12h ago                   now  │    ",
+            "    │                                                              │    ",
+            "    │ 1 restart, 1 oom, 1 failed                                   │    ",
+            "    │                                                              │    ",
+            "    │ 11h 0m ago    ▲ started                                      │    ",
+            "    │ 12h 0m ago    ✖ died, exit code 137                          │    ",
+            "    │ 12h 0m ago    ! out of memory                                │    ",
+            "    │ 20h 0m ago    ▲ started                                      │    ",
+            "    │                                                              │    ",
+            "    │ ( ← → ) change span ( s ) save as csv & json ( esc ) close   │    ",
+            "    ╰──────────────────────────────────────────────────────────────╯    ",
+            "                                                                        ",
+            "                                                                        ",
+        ];
 
-        let expected =   "           name       state               status       cpu        memory/limit         id     image      ↓ rx      ↑ tx    ( h ) show help  ";
         setup
             .terminal
             .draw(|f| {
-                super::heading_bar(setup.area, f, &fd, &setup.gui_state);
+                super::timeline(f, &setup.gui_state, &Theme::default());
             })
             .unwrap();
 
         let result = &setup.terminal.backend().buffer().content;
-        for (index, expected_char) in expected.chars().enumerate() {
-            let result_cell = &result[index];
-
-            assert_eq!(result_cell.symbol(), expected_char.to_string());
-            assert_eq!(result_cell.bg, Color::Magenta);
-            assert_eq!(
-                result_cell.fg,
-                match index {
-                    (2..=122) => Color::Black,
-                    _ => Color::White,
-                }
-            );
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                assert_eq!(expected_char.to_string(), result[index].symbol());
+            }
         }
     }
 
     #[test]
-    /// Test all combination of headers & sort by
-    fn test_draw_blocks_headers_sort_containers() {
-        let (w, h) = (140, 1);
+    /// Prune preview shows the rules, the selected rule, & the matching images, oldest first
+    fn test_draw_blocks_prune() {
+        let (w, h) = (84, 16);
         let mut setup = test_setup(w, h, true, true);
-        let mut fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
-        let mut test = |expected: &str, range: RangeInclusive<usize>, x: (Header, SortedOrder)| {
-            fd.sorted_by = Some(x);
-
-            setup
-                .terminal
-                .draw(|f| {
-                    super::heading_bar(setup.area, f, &fd, &setup.gui_state);
-                })
-                .unwrap();
-
-            let result = &setup.terminal.backend().buffer().content;
-            for (index, expected_char) in expected.chars().enumerate() {
-                let result_cell = &result[index];
-                assert_eq!(result_cell.symbol(), expected_char.to_string());
-                assert_eq!(result_cell.bg, Color::Magenta);
-                assert_eq!(
-                    result_cell.fg,
-                    match index {
-                        0 | 1 => Color::White,
-                        // given range | help section
-                        x if range.contains(&x) || (123..=139).contains(&x) => Color::White,
-                        _ => Color::Black,
-                    }
-                );
-            }
+        let day = 60 * 60 * 24;
+        let image = |id: &str, days_old: i64, tags: &[&str]| PruneImage {
+            id: id.to_owned(),
+            created: 100 * day - days_old * day,
+            in_use: false,
+            repositories: tags
+                .iter()
+                .map(|i| i.split(':').next().unwrap().to_owned())
+                .collect(),
+            size: 250_000_000,
+            tags: tags.iter().map(|i| (*i).to_owned()).collect(),
         };
+        let mut preview = PrunePreview::new(
+            vec![
+                image("sha256:0123456789abcdef", 60, &[]),
+                image("sha256:1", 90, &["app:1", "registry.local/app:1"]),
+                image("sha256:2", 50, &["app:2"]),
+                image("sha256:3", 10, &["app:3"]),
+            ],
+            100 * day,
+            None,
+        );
+        preview.next();
+        preview.change(-1);
+        setup.gui_state.lock().set_prune_preview(Some(preview));
 
-        // Name
-        test("         ▲ name       state               status       cpu        memory/limit         id     image      ↓ rx      ↑ tx    ( h ) show help  ", 1..=14, (Header::Name, SortedOrder::Asc));
-        test("         ▼ name       state               status       cpu        memory/limit         id     image      ↓ rx      ↑ tx    ( h ) show help  ", 1..=14, (Header::Name, SortedOrder::Desc));
-
-        // state
-        test("           name     ▲ state               status       cpu        memory/limit         id     image      ↓ rx      ↑ tx    ( h ) show help  ", 15..=26, (Header::State, SortedOrder::Asc));
-        test("           name     ▼ state               status       cpu        memory/limit         id     image      ↓ rx      ↑ tx    ( h ) show help  ", 15..=26, (Header::State, SortedOrder::Desc));
-
-        // status
-        test("           name       state             ▲ status       cpu        memory/limit         id     image      ↓ rx      ↑ tx    ( h ) show help  ", 27..=47, (Header::Status, SortedOrder::Asc));
-        test("           name       state             ▼ status       cpu        memory/limit         id     image      ↓ rx      ↑ tx    ( h ) show help  ", 27..=47, (Header::Status, SortedOrder::Desc));
-
-        // cpu
-        test("           name       state               status     ▲ cpu        memory/limit         id     image      ↓ rx      ↑ tx    ( h ) show help  ", 48..=57, (Header::Cpu, SortedOrder::Asc));
-        test("           name       state               status     ▼ cpu        memory/limit         id     image      ↓ rx      ↑ tx    ( h ) show help  ", 48..=57, (Header::Cpu, SortedOrder::Desc));
-
-        // mem
-        test("           name       state               status       cpu      ▲ memory/limit         id     image      ↓ rx      ↑ tx    ( h ) show help  ", 58..=77, (Header::Memory, SortedOrder::Asc));
-        test("           name       state               status       cpu      ▼ memory/limit         id     image      ↓ rx      ↑ tx    ( h ) show help  ", 58..=77, (Header::Memory, SortedOrder::Desc));
-
-        // id
-        test("           name       state               status       cpu        memory/limit       ▲ id     image      ↓ rx      ↑ tx    ( h ) show help  ", 78..=88, (Header::Id, SortedOrder::Asc));
-        test("           name       state               status       cpu        memory/limit       ▼ id     image      ↓ rx      ↑ tx    ( h ) show help  ", 78..=88, (Header::Id, SortedOrder::Desc));
-
-        // image
-        test("           name       state               status       cpu        memory/limit         id   ▲ image      ↓ rx      ↑ tx    ( h ) show help  ", 89..=98, (Header::Image, SortedOrder::Asc));
-        test("           name       state               status       cpu        memory/limit         id   ▼ image      ↓ rx      ↑ tx    ( h ) show help  ", 89..=98, (Header::Image, SortedOrder::Desc));
+        let expected = [
+            "                                                                                    ",
+            "                                                                                    ",
+            "    ╭────────────────────────────── prune images ──────────────────────────────╮    ",
+            "    │  remove unused images older than 30 days                                 │    ",
+            "    │▶ keep the newest 1 tag per repository                                    │    ",
+            "    │                                                                          │    ",
+            "    │  2 of 4 images match, up to 500.00 MB reclaimed                          │    ",
+            "    │                                                                          │    ",
+            "    │  <none> 0123456789ab                                 60 days  250.00 MB  │    ",
+            "    │  app:2                                               50 days  250.00 MB  │    ",
+            "    │                                                                          │    ",
+            "    │  ( ↑ ↓ ) select rule ( ← → ) change ( enter ) remove ( esc ) cancel      │    ",
+            "    │                                                                          │    ",
+            "    ╰──────────────────────────────────────────────────────────────────────────╯    ",
+            "                                                                                    ",
+            "                                                                                    ",
+        ];
 
-        // rx
-        test("           name       state               status       cpu        memory/limit         id     image    ▲ ↓ rx      ↑ tx    ( h ) show help  ", 99..=108, (Header::Rx, SortedOrder::Asc));
-        test("           name       state               status       cpu        memory/limit         id     image    ▼ ↓ rx      ↑ tx    ( h ) show help  ", 99..=108, (Header::Rx, SortedOrder::Desc));
+        setup
+            .terminal
+            .draw(|f| {
+                super::prune(f, &setup.gui_state, &Theme::default());
+            })
+            .unwrap();
 
-        // tx
-        test("           name       state               status       cpu        memory/limit         id     image      ↓ rx    ▲ ↑ tx    ( h ) show help  ", 109..=118, (Header::Tx, SortedOrder::Asc));
-        test("           name       state               status       cpu        memory/limit         id     image      ↓ rx    ▼ ↑ tx    ( h ) show help  ", 109..=118, (Header::Tx, SortedOrder::Desc));
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                assert_eq!(expected_char.to_string(), result[index].symbol());
+            }
+        }
     }
 
     #[test]
-    /// Show animation
-    fn test_draw_blocks_headers_animation() {
-        let (w, h) = (140, 1);
+    /// Build cache records are listed largest first, with the mark, size, last used, & a truncated description
+    fn test_draw_blocks_build_cache() {
+        let (w, h) = (80, 14);
         let mut setup = test_setup(w, h, true, true);
-        let uuid = Uuid::new_v4();
-        setup.gui_state.lock().next_loading(uuid);
-        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+        let record = |id: &str, size: u64, in_use: bool, last_used: Option<i64>| CacheRecord {
+            description: format!(
+                "mount / from exec /bin/sh -c apt-get update && apt-get install {id}"
+            ),
+            id: id.to_owned(),
+            in_use,
+            last_used,
+            shared: false,
+            size,
+            usage_count: 1,
+        };
+        let mut cache = BuildCache::new(
+            vec![
+                record("curl", 20_000_000, false, Some(7200)),
+                record("git", 150_000_000, true, Some(9990)),
+                record("make", 5_000, false, None),
+            ],
+            10_000,
+            None,
+        );
+        cache.next();
+        cache.toggle();
+        cache.next();
+        setup.gui_state.lock().set_build_cache(Some(cache));
+
+        let expected = [
+            "                                                                                ",
+            "                                                                                ",
+            "    ╭──────────────────────────── build cache ─────────────────────────────╮    ",
+            "    │  3 records, 170.00 MB total, up to 20.00 MB reclaimed                │    ",
+            "    │                                                                      │    ",
+            "    │  [-] 150.00 MB      10s ago  mount / from exec /bin/sh -c apt-get u… │    ",
+            "    │  [x]  20.00 MB  46m 40s ago  mount / from exec /bin/sh -c apt-get u… │    ",
+            "    │▶ [ ]   5.00 kB        never  mount / from exec /bin/sh -c apt-get u… │    ",
+            "    │                                                                      │    ",
+            "    │  ( ↑ ↓ ) select ( space ) mark ( enter ) remove ( esc ) close        │    ",
+            "    │                                                                      │    ",
+            "    ╰──────────────────────────────────────────────────────────────────────╯    ",
+            "                                                                                ",
+            "                                                                                ",
+        ];
 
         setup
             .terminal
             .draw(|f| {
-                super::heading_bar(setup.area, f, &fd, &setup.gui_state);
+                super::build_cache(f, &setup.gui_state, &Theme::default());
             })
             .unwrap();
 
-        let expected =   " ⠙         name       state               status       cpu        memory/limit         id     image      ↓ rx      ↑ tx    ( h ) show help  ";
-
         let result = &setup.terminal.backend().buffer().content;
-        for (index, expected_char) in expected.chars().enumerate() {
-            let result_cell = &result[index];
-
-            assert_eq!(result_cell.symbol(), expected_char.to_string());
-            assert_eq!(result_cell.bg, Color::Magenta);
-            assert_eq!(
-                result_cell.fg,
-                match index {
-                    (2..=122) => Color::Black,
-                    _ => Color::White,
-                }
-            );
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                assert_eq!(expected_char.to_string(), result[index].symbol());
+            }
         }
     }
 
-    // ********** //
-    // Help popup //
-    // ********** //
     #[test]
-    /// This will cause issues once the version has more than the current 5 chars (0.5.0)
-    // Help  popup is drawn correctly
-    fn test_draw_blocks_help() {
-        let (w, h) = (87, 30);
+    /// The health check is shown above its probes, newest first, with the full output of the selected probe
+    fn test_draw_blocks_health() {
+        let (w, h) = (80, 17);
         let mut setup = test_setup(w, h, true, true);
+        let probe = |start: u64, exit_code: i64, output: &str| HealthProbe {
+            duration: Some(12),
+            exit_code: Some(exit_code),
+            output: output.to_owned(),
+            start: Some(start),
+        };
+        setup.gui_state.lock().set_health_view(Some(HealthView {
+            failing_streak: 2,
+            interval: Some(30),
+            name: "web".to_owned(),
+            now: 1_000,
+            probes: vec![
+                probe(970, 1, "curl: (7) failed to connect\nretrying"),
+                probe(940, 0, "ok"),
+            ],
+            retries: Some(3),
+            selected: 0,
+            status: "unhealthy".to_owned(),
+            test: "curl -f localhost/health".to_owned(),
+            timeout: None,
+        }));
+
+        let expected = [
+            "                                                                                ",
+            "                                                                                ",
+            "    ╭─────────────────────────── health of web ────────────────────────────╮    ",
+            "    │  unhealthy, failing 2 in a row                                       │    ",
+            "    │  test: curl -f localhost/health                                      │    ",
+            "    │  every 30s, unhealthy after 3 failures                               │    ",
+            "    │                                                                      │    ",
+            "    │▶      30s ago  exit 1      12ms  curl: (7) failed to connect         │    ",
+            "    │     1m 0s ago  exit 0      12ms  ok                                  │    ",
+            "    │                                                                      │    ",
+            "    │    curl: (7) failed to connect                                       │    ",
+            "    │    retrying                                                          │    ",
+            "    │                                                                      │    ",
+            "    │  ( ↑ ↓ ) select ( esc ) close                                        │    ",
+            "    ╰──────────────────────────────────────────────────────────────────────╯    ",
+            "                                                                                ",
+            "                                                                                ",
+        ];
 
         setup
             .terminal
             .draw(|f| {
-                super::help_box(f);
+                super::health(f, &setup.gui_state, &Theme::default());
             })
             .unwrap();
 
-        let expected = [
-                "                                                                                       ".to_owned(),
-                format!(" ╭ {VERSION} ────────────────────────────────────────────────────────────────────────────╮ "),
-                " │                                                                                   │ ".to_owned(),
-                " │                                      88                                           │ ".to_owned(),
-                " │                                      88                                           │ ".to_owned(),
-                " │                                      88                                           │ ".to_owned(),
-                " │             ,adPPYba,   8b,     ,d8  88   ,d8    ,adPPYba,  8b,dPPYba,            │ ".to_owned(),
-                r#" │            a8"     "8a   `Y8, ,8P'   88 ,a8"    a8P_____88  88P'   "Y8            │ "#.to_owned(),
-                r#" │            8b       d8     )888(     8888[      8PP"""""""  88                    │ "#.to_owned(),
-                r#" │            "8a,   ,a8"   ,d8" "8b,   88`"Yba,   "8b,   ,aa  88                    │ "#.to_owned(),
-                r#" │             `"YbbdP"'   8P'     `Y8  88   `Y8a   `"Ybbd8"'  88                    │ "#.to_owned(),
-                " │                                                                                   │ ".to_owned(),
-                " │                 A simple tui to view & control docker containers                  │ ".to_owned(),
-                " │                                                                                   │ ".to_owned(),
-                " │ ( tab ) or ( shift+tab ) change panels                                            │ ".to_owned(),
-                " │ ( ↑ ↓ ) or ( j k ) or ( PgUp PgDown ) or ( Home End ) change selected line        │ ".to_owned(),
-                " │ ( enter ) send docker container command                                           │ ".to_owned(),
-                " │ ( e ) exec into a container                                                       │ ".to_owned(),
-                " │ ( h ) toggle this help information                                                │ ".to_owned(),
-                " │ ( s ) save logs to file                                                           │ ".to_owned(),
-                " │ ( m ) toggle mouse capture - if disabled, text on screen can be selected & copied │ ".to_owned(),
-                " │ ( 0 ) stop sort                                                                   │ ".to_owned(),
-                " │ ( 1 - 9 ) sort by header - or click header                                        │ ".to_owned(),
-                " │ ( q ) quit at any time                                                            │ ".to_owned(),
-                " │                                                                                   │ ".to_owned(),
-                " │        currently an early work in progress, all and any input appreciated         │ ".to_owned(),
-                " │                       https://github.com/mrjackwills/oxker                        │ ".to_owned(),
-                " │                                                                                   │ ".to_owned(),
-                " ╰───────────────────────────────────────────────────────────────────────────────────╯ ".to_owned(),
-                "                                                                                       ".to_owned(),
-            ];
-
+        let result = &setup.terminal.backend().buffer().content;
         for (row_index, row) in expected.iter().enumerate() {
-            let mut bracket_key = vec![];
-            let mut push_bracket_key = false;
-
-            let result = &setup.terminal.backend().buffer().content;
             for (char_index, expected_char) in row.chars().enumerate() {
                 let index = row_index * usize::from(w) + char_index;
-                let result_cell = &result[index];
-                let result_str = result_cell.symbol();
-
-                assert_eq!(result_cell.symbol(), expected_char.to_string());
-
-                // First and last row, and first char and last char in each row, is empty
-                if row_index == 0
-                    || row_index == usize::from(h - 1)
-                    || char_index == 0
-                    || char_index == usize::from(w - 1)
-                {
-                    assert_eq!(result_cell.fg, Color::Reset);
-                    assert_eq!(result_cell.bg, Color::Reset);
-                // Borders
-                } else if BORDER_CHARS.contains(&result_str) {
-                    assert_eq!(result_cell.fg, Color::Black);
-                    assert_eq!(result_cell.bg, Color::Magenta);
-                // everything else has a magenta background
-                } else {
-                    assert_eq!(result_cell.bg, Color::Magenta);
-                }
+                assert_eq!(expected_char.to_string(), result[index].symbol());
+            }
+        }
+        assert_eq!(result[7 * usize::from(w) + 12].fg, Color::Red);
+        assert_eq!(result[8 * usize::from(w) + 12].fg, Color::Green);
+    }
 
-                // check that ( [key] ) is white
-                if result_str == "(" {
-                    push_bracket_key = true;
-                    bracket_key.push(result_cell);
-                }
-                if push_bracket_key {
-                    bracket_key.push(result_cell);
-                    if result_str == ")" {
-                        push_bracket_key = false;
-                        for i in &bracket_key {
-                            assert_eq!(i.fg, Color::White);
-                        }
-                        bracket_key.clear();
-                    }
-                }
-                // TODO should really be testing every color of every str here
+    #[test]
+    /// Published ports are shown with their url, exposed ports without, & the enter hint matches the selected port
+    fn test_draw_blocks_port_mappings() {
+        let (w, h) = (80, 12);
+        let mut setup = test_setup(w, h, true, true);
+        let row = |private, public: Option<u16>| PortRow {
+            ip: public.map(|_| "0.0.0.0".to_owned()),
+            private,
+            public,
+            url: public.map(|i| format!("http://localhost:{i}")),
+        };
+        setup.gui_state.lock().set_port_view(Some(PortView {
+            id: ContainerId::from("1"),
+            name: "web".to_owned(),
+            rows: vec![row(80, Some(8080)), row(9000, None)],
+            selected: 1,
+        }));
+
+        let expected = [
+            "                                                                                ",
+            "                                                                                ",
+            "    ╭──────────────────────────── ports of web ────────────────────────────╮    ",
+            "    │  published               container   url                             │    ",
+            "    │  0.0.0.0:8080            80          http://localhost:8080           │    ",
+            "    │▶ exposed                 9000                                        │    ",
+            "    │                                                                      │    ",
+            "    │  ( enter ) forward with socat ( ↑ ↓ ) select ( esc ) close           │    ",
+            "    │                                                                      │    ",
+            "    ╰──────────────────────────────────────────────────────────────────────╯    ",
+            "                                                                                ",
+            "                                                                                ",
+        ];
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::port_mappings(f, &setup.gui_state, &Theme::default());
+            })
+            .unwrap();
+
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                assert_eq!(expected_char.to_string(), result[index].symbol());
             }
         }
     }
 
-    // ************ //
-    // Delete popup //
-    // ************ //
-
     #[test]
-    /// Delete container popup is drawn correctly
-    fn test_draw_blocks_delete() {
-        let (w, h) = (82, 10);
+    /// The finder lists the matching containers, best first, the selected container in bold, & the matched chars underlined
+    fn test_draw_blocks_finder() {
+        let (w, h) = (70, 12);
         let mut setup = test_setup(w, h, true, true);
+        let row = |id: &str, name: &str, image: &str, project: Option<&str>| FinderRow {
+            id: ContainerId::from(id),
+            image: image.to_owned(),
+            name: name.to_owned(),
+            project: project.map(ToOwned::to_owned),
+        };
+        let mut finder = Finder::new(vec![
+            row("1", "shop-web-1", "nginx:1.25", Some("shop")),
+            row("2", "shop-db-1", "postgres:16", Some("shop")),
+            row("3", "grafana", "grafana/grafana", None),
+        ]);
+        finder.push('s');
+        finder.push('d');
+        setup.gui_state.lock().set_finder(Some(finder));
 
         let expected = [
-            "                                                                                  ",
-            "        ╭──────────────────────── Confirm Delete ────────────────────────╮        ",
-            "        │                                                                │        ",
-            "        │     Are you sure you want to delete container: container_1     │        ",
-            "        │                                                                │        ",
-            "        │      ╭─────────────────────╮      ╭─────────────────────╮      │        ",
-            "        │      │        (N)o         │      │        (Y)es        │      │        ",
-            "        │      ╰─────────────────────╯      ╰─────────────────────╯      │        ",
-            "        ╰────────────────────────────────────────────────────────────────╯        ",
-            "                                                                                  ",
+            "                                                                      ",
+            "                                                                      ",
+            "    ╭────────────────── find a container - 1/3 ──────────────────╮    ",
+            "    │ > sd█                                                      │    ",
+            "    │                                                            │    ",
+            "    │ ▶ shop-db-1  postgres:16  shop                             │    ",
+            "    │                                                            │    ",
+            "    │ ( ↑ ↓ ) choose a container ( enter ) select ( esc ) close  │    ",
+            "    │                                                            │    ",
+            "    ╰────────────────────────────────────────────────────────────╯    ",
+            "                                                                      ",
+            "                                                                      ",
         ];
 
         setup
             .terminal
             .draw(|f| {
-                super::delete_confirm(f, &setup.gui_state, &ContainerName::from("container_1"));
+                super::finder(f, &setup.gui_state, &Theme::default());
             })
             .unwrap();
 
@@ -2536,64 +10971,86 @@ mod tests {
         for (row_index, row) in expected.iter().enumerate() {
             for (char_index, expected_char) in row.chars().enumerate() {
                 let index = row_index * usize::from(w) + char_index;
-                let result_cell = &result[index];
+                assert_eq!(expected_char.to_string(), result[index].symbol());
+            }
+        }
+        // The matched chars of the name are underlined
+        assert!(result[usize::from(w) * 5 + 8]
+            .modifier
+            .contains(Modifier::UNDERLINED));
+    }
 
-                assert_eq!(result_cell.symbol(), expected_char.to_string());
+    #[test]
+    /// A popup is drawn in the colors of the theme, its background, text, & highlighted matches
+    fn test_draw_blocks_theme() {
+        let (w, h) = (70, 12);
+        let mut setup = test_setup(w, h, true, true);
+        let mut finder = Finder::new(vec![FinderRow {
+            id: ContainerId::from("1"),
+            image: "nginx:1.25".to_owned(),
+            name: "shop-web-1".to_owned(),
+            project: None,
+        }]);
+        finder.push('s');
+        setup.gui_state.lock().set_finder(Some(finder));
+        let mut theme = Theme::preset(ThemePreset::Dracula);
+        theme.set("highlight=#00ff00").unwrap();
 
-                if row_index == 0
-                    || row_index == usize::from(h - 1)
-                    || char_index < 8
-                    || char_index > usize::from(w - 9)
-                {
-                    assert_eq!(result_cell.fg, Color::Reset);
-                    assert_eq!(result_cell.bg, Color::Reset);
-                } else {
-                    assert_eq!(result_cell.bg, Color::White);
-                }
+        setup
+            .terminal
+            .draw(|f| {
+                super::finder(f, &setup.gui_state, &theme);
+            })
+            .unwrap();
 
-                // Borders are black
-                if BORDER_CHARS.contains(&result_cell.symbol()) {
-                    assert_eq!(result_cell.fg, Color::Black);
-                    // Container name is red
-                } else if row_index == 3 && (57..=67).contains(&char_index) {
-                    assert_eq!(result_cell.fg, Color::Red);
-                    // All other text is black
-                } else if !row_index == 0
-                    && !row_index == usize::from(h - 1)
-                    && !char_index < 8
-                    && !char_index > usize::from(w - 9)
-                {
-                    assert_eq!(result_cell.fg, Color::Black);
-                }
-            }
-        }
+        let result = &setup.terminal.backend().buffer().content;
+        let border = &result[usize::from(w) * 2 + 4];
+        assert_eq!(border.bg, theme.popup_bg);
+        assert_eq!(border.fg, Color::Rgb(248, 248, 242));
+        // The matched char of the name
+        assert_eq!(result[usize::from(w) * 5 + 8].fg, Color::Rgb(0, 255, 0));
     }
 
     #[test]
-    /// Delete container popup is drawn correctly
-    fn test_draw_blocks_delete_long_name() {
-        let (w, h) = (106, 10);
+    /// Directories are listed first, & the entries are scrolled so the selected file is shown
+    fn test_draw_blocks_file_browser() {
+        let (w, h) = (80, 10);
         let mut setup = test_setup(w, h, true, true);
-        let name = ContainerName::from("container_1_container_1_container_1");
-        setup.app_data.lock().containers.items[0].name = name.clone();
+        let entry = |name: &str, is_dir| FileEntry {
+            is_dir,
+            name: name.to_owned(),
+        };
+        let mut browser = FileBrowser::new(
+            ContainerId::from("1"),
+            "web".to_owned(),
+            "/app".to_owned(),
+            vec![
+                entry("hosts", false),
+                entry("usr", true),
+                entry(".env", false),
+                entry("etc", true),
+            ],
+        );
+        browser.selected = 3;
+        setup.gui_state.lock().set_file_browser(Some(browser));
 
         let expected = [
-            "                                                                                                          ",
-            "        ╭──────────────────────────────────── Confirm Delete ────────────────────────────────────╮        ",
-            "        │                                                                                        │        ",
-            "        │     Are you sure you want to delete container: container_1_container_1_container_1     │        ",
-            "        │                                                                                        │        ",
-            "        │        ╭──────────────────────────────╮         ╭─────────────────────────────╮        │        ",
-            "        │        │             (N)o             │         │            (Y)es            │        │        ",
-            "        │        ╰──────────────────────────────╯         ╰─────────────────────────────╯        │        ",
-            "        ╰────────────────────────────────────────────────────────────────────────────────────────╯        ",
-            "                                                                                                          ",
+            "                                                                                ",
+            "                                                                                ",
+            "    ╭────────────────────────────── web:/app ──────────────────────────────╮    ",
+            "    │  .env                                                                │    ",
+            "    │▶ hosts                                                               │    ",
+            "    │                                                                      │    ",
+            "    │  ( enter ) save ( ← ) up ( esc ) close                               │    ",
+            "    ╰──────────────────────────────────────────────────────────────────────╯    ",
+            "                                                                                ",
+            "                                                                                ",
         ];
 
         setup
             .terminal
             .draw(|f| {
-                super::delete_confirm(f, &setup.gui_state, &name);
+                super::file_browser(f, &setup.gui_state, &Theme::default());
             })
             .unwrap();
 
@@ -2601,85 +11058,145 @@ mod tests {
         for (row_index, row) in expected.iter().enumerate() {
             for (char_index, expected_char) in row.chars().enumerate() {
                 let index = row_index * usize::from(w) + char_index;
-                let result_cell = &result[index];
-                assert_eq!(result_cell.symbol(), expected_char.to_string());
+                assert_eq!(expected_char.to_string(), result[index].symbol());
+            }
+        }
+    }
 
-                if row_index == 0
-                    || row_index == usize::from(h - 1)
-                    || char_index < 8
-                    || char_index > usize::from(w - 9)
-                {
-                    assert_eq!(result_cell.fg, Color::Reset);
-                    assert_eq!(result_cell.bg, Color::Reset);
-                } else {
-                    assert_eq!(result_cell.bg, Color::White);
-                }
+    #[test]
+    /// Secret scan findings are listed by container, then variable, with why each was flagged, & only a masked preview of the value
+    fn test_draw_blocks_secret_scan() {
+        let (w, h) = (80, 12);
+        let mut setup = test_setup(w, h, true, true);
+        let finding = |container: &str, key: &str, kind: SecretKind, preview: &str| SecretFinding {
+            container: container.to_owned(),
+            key: key.to_owned(),
+            kind,
+            preview: preview.to_owned(),
+        };
+        let mut scan = SecretScan::new(
+            vec![
+                finding(
+                    "web",
+                    "AWS_KEY",
+                    SecretKind::Token("aws access key"),
+                    "AKIA… (20 chars)",
+                ),
+                finding("db", "POSTGRES_PASSWORD", SecretKind::Named, "e… (7 chars)"),
+            ],
+            3,
+            None,
+        );
+        scan.next();
+        setup.gui_state.lock().set_secret_scan(Some(scan));
 
-                // Borders are black
-                if BORDER_CHARS.contains(&result_cell.symbol()) {
-                    assert_eq!(result_cell.fg, Color::Black);
-                // Container name is red
-                } else if row_index == 3 && (57..=82).contains(&char_index) {
-                    assert_eq!(result_cell.fg, Color::Red);
-                // All other text is black
-                } else if !row_index == 0
-                    && !row_index == usize::from(h - 1)
-                    && !char_index < 8
-                    && !char_index > usize::from(w - 9)
-                {
-                    assert_eq!(result_cell.fg, Color::Black);
-                }
+        let expected = [
+            "                                                                                ",
+            "                                                                                ",
+            "    ╭──────────────────────────── secret scan ─────────────────────────────╮    ",
+            "    │  2 findings, in 2 of 3 containers                                    │    ",
+            "    │                                                                      │    ",
+            "    │  db   POSTGRES_PASSWORD  secret name        e… (7 chars)             │    ",
+            "    │▶ web  AWS_KEY            aws access key     AKIA… (20 chars)         │    ",
+            "    │                                                                      │    ",
+            "    │  ( ↑ ↓ ) select ( esc ) close                                        │    ",
+            "    ╰──────────────────────────────────────────────────────────────────────╯    ",
+            "                                                                                ",
+            "                                                                                ",
+        ];
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::secret_scan(f, &setup.gui_state, &Theme::default());
+            })
+            .unwrap();
+
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                assert_eq!(expected_char.to_string(), result[index].symbol());
             }
         }
     }
 
-    // ***** //
-    // popup //
-    // ***** //
-
     #[test]
-    /// Info box drawn in bottom right
-    fn test_draw_blocks_info() {
-        let (w, h) = (45, 9);
+    /// Standby notice is drawn in the middle of the screen
+    fn test_draw_blocks_standby() {
+        let (w, h) = (60, 10);
         let mut setup = test_setup(w, h, true, true);
 
         let expected = [
-            "                                             ",
-            "                                             ",
-            "                                             ",
-            "                                             ",
-            "                                             ",
-            "                                             ",
-            "                                             ",
-            "                                    test     ",
-            "                                             ",
+            "                                                            ",
+            "                                                            ",
+            "   ╭───────────────────── standby ──────────────────────╮   ",
+            "   │                                                    │   ",
+            "   │          drawing paused, & updates slowed          │   ",
+            "   │   press any key, or focus the terminal, to resume  │   ",
+            "   │                                                    │   ",
+            "   ╰────────────────────────────────────────────────────╯   ",
+            "                                                            ",
+            "                                                            ",
         ];
 
         setup
             .terminal
             .draw(|f| {
-                super::info(f, "test", std::time::Instant::now(), &setup.gui_state);
+                super::standby(f, &Theme::default());
             })
             .unwrap();
 
         let result = &setup.terminal.backend().buffer().content;
-
         for (row_index, row) in expected.iter().enumerate() {
             for (char_index, expected_char) in row.chars().enumerate() {
                 let index = row_index * usize::from(w) + char_index;
-                let result_cell = &result[index];
+                assert_eq!(expected_char.to_string(), result[index].symbol());
+            }
+        }
+    }
 
-                assert_eq!(expected_char.to_string(), result_cell.symbol());
-                let (fg, bg) = if row_index >= 6 && char_index >= 32 {
-                    (Color::White, Color::Blue)
-                } else {
-                    (Color::Reset, Color::Reset)
-                };
+    #[test]
+    /// Expanded log line is hard wrapped, scroll is clamped so that the last row is at the bottom of the popup
+    fn test_draw_blocks_expanded_log() {
+        let (w, h) = (30, 10);
+        let mut setup = test_setup(w, h, true, true);
+        setup
+            .gui_state
+            .lock()
+            .set_expanded_log(Some(LogDetail::Truncated(
+                (0..5).map(|i| i.to_string().repeat(20)).collect(),
+            )));
+        setup.gui_state.lock().scroll_expanded_log(false, 10);
 
-                assert_eq!(result_cell.fg, fg);
-                assert_eq!(result_cell.bg, bg);
+        let expected = [
+            "                              ",
+            "                              ",
+            "    ╭ 100 chars, row 2/5 ╮    ",
+            "    │11111111111111111111│    ",
+            "    │22222222222222222222│    ",
+            "    │33333333333333333333│    ",
+            "    │44444444444444444444│    ",
+            "    ╰────────────────────╯    ",
+            "                              ",
+            "                              ",
+        ];
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::expanded_log(f, &setup.gui_state, &Theme::default());
+            })
+            .unwrap();
+
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                assert_eq!(expected_char.to_string(), result[index].symbol());
             }
         }
+        assert_eq!(setup.gui_state.lock().get_expanded_log().unwrap().scroll, 1);
     }
 
     // *********** //
@@ -2695,7 +11212,7 @@ mod tests {
         setup
             .terminal
             .draw(|f| {
-                super::error(f, AppError::DockerConnect, Some(4));
+                super::error(f, AppError::DockerConnect, Some(4), &Theme::default());
             })
             .unwrap();
 
@@ -2746,7 +11263,7 @@ mod tests {
         setup
             .terminal
             .draw(|f| {
-                super::error(f, AppError::DockerExec, Some(4));
+                super::error(f, AppError::DockerExec, Some(4), &Theme::default());
             })
             .unwrap();
 
@@ -2789,6 +11306,53 @@ mod tests {
         }
     }
 
+    #[test]
+    /// The config file error covers the whole screen, with the file, the line as written, what is wrong with it, & the hint, wrapped to the width
+    fn test_draw_blocks_config_error() {
+        let (w, h) = (46, 13);
+        let mut setup = test_setup(w, h, true, true);
+        let error = ConfigError {
+            path: Some("/home/user/.config/oxker/config.toml".into()),
+            line: Some((3, "theme = \"purple\"".to_owned())),
+            message: "\"purple\" is not a valid theme".to_owned(),
+            hint: Some("expected one of default, light, solarized, dracula".to_owned()),
+        };
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::config_error(f, &error, &Theme::default());
+            })
+            .unwrap();
+
+        let expected = [
+            "╭──────────── config file error ─────────────╮",
+            "│                                            │",
+            "│ /home/user/.config/oxker/config.toml       │",
+            "│                                            │",
+            "│ 3 │ theme = \"purple\"                       │",
+            "│                                            │",
+            "│ \"purple\" is not a valid theme              │",
+            "│ expected one of default, light, solarized, │",
+            "│ dracula                                    │",
+            "│                                            │",
+            "│ ( any key ) quit oxker                     │",
+            "│                                            │",
+            "╰────────────────────────────────────────────╯",
+        ];
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let result_cell = &result[row_index * usize::from(w) + char_index];
+                assert_eq!(result_cell.symbol(), expected_char.to_string());
+                assert_eq!(result_cell.bg, Color::Red);
+            }
+        }
+        let line = &result[4 * usize::from(w) + 6];
+        assert_eq!(line.symbol(), "t");
+        assert!(line.modifier.contains(Modifier::BOLD));
+    }
+
     #[test]
     /// Port section when container has no ports
     fn test_draw_blocks_ports_no_ports() {
@@ -2939,6 +11503,76 @@ mod tests {
         }
     }
 
+    #[test]
+    /// Conflicting ports are red, each conflict is listed under the ports, or alone when a stopped container lists no ports
+    fn test_draw_blocks_ports_conflicts() {
+        let (w, h) = (32, 6);
+        let mut setup = test_setup(w, h, true, true);
+        setup.app_data.lock().containers.items[0].ports[0].public = Some(8001);
+        setup.app_data.lock().containers.items[0].conflicts = vec![
+            PortConflict {
+                port: 8001,
+                protocol: "tcp".to_owned(),
+                with: "container_2".to_owned(),
+                with_running: false,
+            },
+            PortConflict {
+                port: 8001,
+                protocol: "tcp".to_owned(),
+                with: "container_3".to_owned(),
+                with_running: true,
+            },
+        ];
+        let max_lens = setup.app_data.lock().get_longest_port();
+        setup
+            .terminal
+            .draw(|f| {
+                super::ports(f, setup.area, &setup.app_data, max_lens);
+            })
+            .unwrap();
+
+        let expected = [
+            "╭─────────── ports ────────────╮",
+            "│   ip   private   public      │",
+            "│           8001     8001      │",
+            "│! 8001/tcp container_2        │",
+            "│! 8001/tcp container_3        │",
+            "╰──────────────────────────────╯",
+        ];
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let result_cell = &result[row_index * usize::from(w) + char_index];
+                assert_eq!(expected_char.to_string(), result_cell.symbol());
+                if result_cell
+                    .symbol()
+                    .chars()
+                    .all(|i| i.is_ascii_alphanumeric())
+                {
+                    match row_index {
+                        2 | 4 => assert_eq!(result_cell.fg, Color::Red),
+                        3 => assert_eq!(result_cell.fg, Color::Yellow),
+                        _ => (),
+                    }
+                }
+            }
+        }
+
+        setup.app_data.lock().containers.items[0].ports = vec![];
+        setup.app_data.lock().containers.items[0].state = State::Exited;
+        let max_lens = setup.app_data.lock().get_longest_port();
+        setup
+            .terminal
+            .draw(|f| {
+                super::ports(f, setup.area, &setup.app_data, max_lens);
+            })
+            .unwrap();
+        let row = (1..w - 1)
+            .map(|i| setup.terminal.backend().buffer().get(i, 1).symbol())
+            .collect::<String>();
+        assert_eq!(row.trim_end(), "! 8001/tcp container_2");
+    }
+
     #[test]
     /// Port section title color correct dependant on state
     fn test_draw_blocks_ports_container_state() {
@@ -3053,9 +11687,9 @@ mod tests {
         "│⚪  container_1   ✓ running            Up 1 hour    03.00%   30.00 kB / 30.00 kB          1   image_1   0.00 kB   0.00 kB                      ││▶ pause       │",
         "│   container_2   ✓ running            Up 2 hour    00.00%    0.00 kB /  0.00 kB          2   image_2   0.00 kB   0.00 kB                      ││  restart     │",
         "│   container_3   ✓ running            Up 3 hour    00.00%    0.00 kB /  0.00 kB          3   image_3   0.00 kB   0.00 kB                      ││  stop        │",
+        "│                                                                                                                                              ││  kill        │",
+        "│                                                                                                                                              ││  rename      │",
         "│                                                                                                                                              ││  delete      │",
-        "│                                                                                                                                              ││              │",
-        "│                                                                                                                                              ││              │",
         "╰──────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────╯╰──────────────╯",
         "╭ Logs 3/3 - container_1 ──────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────╮",
         "│  line 1                                                                                                                                                      │",
@@ -3124,9 +11758,9 @@ mod tests {
         "│⚪  a_long_container_name_for_the…   ✓ running            Up 1 hour    03.00%   30.00 kB / 30.00 kB          1   a_long_image_name_for_the_pur…   0.00 kB   0.00 kB       ││▶ pause          │",
         "│                      container_2   ✓ running            Up 2 hour    00.00%    0.00 kB /  0.00 kB          2                          image_2   0.00 kB   0.00 kB       ││  restart        │",
         "│                      container_3   ✓ running            Up 3 hour    00.00%    0.00 kB /  0.00 kB          3                          image_3   0.00 kB   0.00 kB       ││  stop           │",
+        "│                                                                                                                                                                         ││  kill           │",
+        "│                                                                                                                                                                         ││  rename         │",
         "│                                                                                                                                                                         ││  delete         │",
-        "│                                                                                                                                                                         ││                 │",
-        "│                                                                                                                                                                         ││                 │",
         "╰─────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────╯╰─────────────────╯",
         "╭ Logs 3/3 - a_long_container_name_for_the_purposes_of_this_test ────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────╮",
         "│  line 1                                                                                                                                                                                    │",
@@ -3167,4 +11801,35 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    /// A dragged border sets the height of the containers panel, the logs take the rest, until it's sized to the containers again
+    fn test_draw_blocks_whole_layout_split() {
+        let (w, h) = (190, 30);
+        let mut setup = test_setup(w, h, true, true);
+        let mut draw = |height: Option<u16>| {
+            setup.gui_state.lock().set_containers_height(height);
+            setup
+                .terminal
+                .draw(|f| {
+                    draw_frame(f, &setup.app_data, &setup.gui_state);
+                })
+                .unwrap();
+            let gui_state = setup.gui_state.lock();
+            (
+                gui_state
+                    .get_panel_area(SelectablePanel::Containers)
+                    .unwrap()
+                    .height,
+                gui_state
+                    .get_panel_area(SelectablePanel::Logs)
+                    .unwrap()
+                    .top(),
+            )
+        };
+        assert_eq!(draw(None), (8, 9));
+        assert_eq!(draw(Some(14)), (14, 15));
+        assert_eq!(draw(Some(1)), (3, 4));
+        assert_eq!(draw(None), (8, 9));
+    }
 }
@@ -0,0 +1,118 @@
+use crate::app_data::ContainerId;
+
+/// A single entry of a directory in a container, a symlink to a directory is a directory
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileEntry {
+    pub is_dir: bool,
+    pub name: String,
+}
+
+/// A directory of a container's filesystem, directories are listed first, then files, each by name
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileBrowser {
+    pub entries: Vec<FileEntry>,
+    pub id: ContainerId,
+    pub name: String,
+    /// Absolute path of the directory, e.g. `/etc/nginx`
+    pub path: String,
+    pub selected: usize,
+}
+
+/// The path of an entry of a directory
+fn join(dir: &str, name: &str) -> String {
+    format!("{}/{name}", dir.trim_end_matches('/'))
+}
+
+/// The parent of a path, None for the root
+fn parent(path: &str) -> Option<String> {
+    let path = path.trim_end_matches('/');
+    if path.is_empty() {
+        return None;
+    }
+    let (parent, _) = path.rsplit_once('/')?;
+    Some(if parent.is_empty() {
+        "/".to_owned()
+    } else {
+        parent.to_owned()
+    })
+}
+
+impl FileBrowser {
+    pub fn new(id: ContainerId, name: String, path: String, mut entries: Vec<FileEntry>) -> Self {
+        entries.sort_by(|a, b| b.is_dir.cmp(&a.is_dir).then_with(|| a.name.cmp(&b.name)));
+        Self {
+            entries,
+            id,
+            name,
+            path,
+            selected: 0,
+        }
+    }
+
+    pub fn next(&mut self) {
+        self.selected = (self.selected + 1).min(self.entries.len().saturating_sub(1));
+    }
+
+    pub const fn previous(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    pub fn selected_entry(&self) -> Option<&FileEntry> {
+        self.entries.get(self.selected)
+    }
+
+    /// The directory above, None at the root
+    pub fn parent_path(&self) -> Option<String> {
+        parent(&self.path)
+    }
+
+    /// The absolute path of the selected entry
+    pub fn selected_path(&self) -> Option<String> {
+        self.selected_entry().map(|i| join(&self.path, &i.name))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::{join, parent, FileBrowser, FileEntry};
+    use crate::app_data::ContainerId;
+
+    #[test]
+    /// Paths are joined, & walked up, to the root
+    fn test_file_browser_paths() {
+        assert_eq!(join("/", "etc"), "/etc");
+        assert_eq!(join("/etc/", "hosts"), "/etc/hosts");
+        assert_eq!(parent("/etc/nginx").as_deref(), Some("/etc"));
+        assert_eq!(parent("/etc/").as_deref(), Some("/"));
+        assert_eq!(parent("/"), None);
+    }
+
+    #[test]
+    /// Directories are listed first, & the selection stays within the entries
+    fn test_file_browser_select() {
+        let entry = |name: &str, is_dir| FileEntry {
+            is_dir,
+            name: name.to_owned(),
+        };
+        let mut browser = FileBrowser::new(
+            ContainerId::from("1"),
+            "web".to_owned(),
+            "/etc".to_owned(),
+            vec![
+                entry("hosts", false),
+                entry("nginx", true),
+                entry("apk", true),
+            ],
+        );
+        assert_eq!(browser.selected_path().as_deref(), Some("/etc/apk"));
+        assert_eq!(browser.parent_path().as_deref(), Some("/"));
+        browser.previous();
+        assert_eq!(browser.selected, 0);
+        browser.next();
+        browser.next();
+        browser.next();
+        assert_eq!(browser.selected_path().as_deref(), Some("/etc/hosts"));
+        assert!(!browser.selected_entry().unwrap().is_dir);
+    }
+}
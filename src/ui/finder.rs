@@ -0,0 +1,212 @@
+use crate::app_data::ContainerId;
+
+/// A container that can be found, by its name, image, or compose project
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FinderRow {
+    pub id: ContainerId,
+    pub image: String,
+    pub name: String,
+    pub project: Option<String>,
+}
+
+/// Which text of a row the input matched, with the positions of the matched chars, so that they can be highlighted
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FinderField {
+    Name,
+    Image,
+    Project,
+}
+
+/// A row that matched the input, with its score, higher is a better match
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FinderMatch {
+    pub field: FinderField,
+    /// Char positions of the matched chars, in the matched field
+    pub positions: Vec<usize>,
+    pub row: usize,
+    score: i64,
+}
+
+/// Chars that start a new word, a match just after one scores higher
+const fn is_separator(c: char) -> bool {
+    matches!(c, '-' | '_' | '.' | '/' | ':' | '@' | ' ')
+}
+
+/// Fuzzy match the input against a text, as fzf does, every char of the input, ignoring case, must be found in order
+/// Consecutive chars, & chars at the start of a word, score higher, gaps between the chars score lower, None if there's no match
+fn fuzzy(input: &str, text: &str) -> Option<(i64, Vec<usize>)> {
+    let text = text.chars().collect::<Vec<_>>();
+    let mut positions = Vec::with_capacity(input.len());
+    let mut score = 0;
+    let mut next = 0;
+    for c in input.chars().filter(|c| !c.is_whitespace()) {
+        let position = text[next..]
+            .iter()
+            .position(|i| i.to_lowercase().eq(c.to_lowercase()))?
+            + next;
+        score += 1;
+        if position == 0 || is_separator(text[position - 1]) {
+            score += 8;
+        }
+        match positions.last() {
+            Some(last) if last + 1 == position => score += 5,
+            Some(last) => {
+                score -= i64::try_from(position - last - 1)
+                    .unwrap_or(i64::MAX)
+                    .min(5);
+            }
+            None => score -= i64::try_from(position).unwrap_or(i64::MAX).min(5),
+        }
+        positions.push(position);
+        next = position + 1;
+    }
+    // A shorter text is a closer match
+    score -= i64::try_from(text.len() - positions.len()).unwrap_or_default() / 8;
+    Some((score, positions))
+}
+
+/// Find a container, as the input is typed, the containers are fuzzy matched against their name, image, & project, best match first
+/// ( enter ) selects the chosen container in the containers panel
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Finder {
+    pub input: String,
+    pub matches: Vec<FinderMatch>,
+    pub rows: Vec<FinderRow>,
+    pub selected: usize,
+}
+
+impl Finder {
+    pub fn new(rows: Vec<FinderRow>) -> Self {
+        let mut finder = Self {
+            input: String::new(),
+            matches: vec![],
+            rows,
+            selected: 0,
+        };
+        finder.filter();
+        finder
+    }
+
+    /// Match every row against the input, the best match of a row's fields is used, an empty input matches every row, in order
+    fn filter(&mut self) {
+        self.matches = self
+            .rows
+            .iter()
+            .enumerate()
+            .filter_map(|(row, i)| {
+                [
+                    (FinderField::Name, Some(i.name.as_str())),
+                    (FinderField::Image, Some(i.image.as_str())),
+                    (FinderField::Project, i.project.as_deref()),
+                ]
+                .into_iter()
+                .filter_map(|(field, text)| {
+                    let (score, positions) = fuzzy(&self.input, text?)?;
+                    // A match of the name beats an equal match of the image, or project
+                    let score = score * 2 + i64::from(field == FinderField::Name);
+                    Some(FinderMatch {
+                        field,
+                        positions,
+                        row,
+                        score,
+                    })
+                })
+                .max_by_key(|i| i.score)
+            })
+            .collect();
+        if !self.input.is_empty() {
+            self.matches.sort_by(|a, b| {
+                b.score
+                    .cmp(&a.score)
+                    .then_with(|| self.rows[a.row].name.cmp(&self.rows[b.row].name))
+            });
+        }
+        self.selected = 0;
+    }
+
+    pub fn push(&mut self, c: char) {
+        self.input.push(c);
+        self.filter();
+    }
+
+    pub fn pop(&mut self) {
+        self.input.pop();
+        self.filter();
+    }
+
+    pub fn next(&mut self) {
+        self.selected = (self.selected + 1).min(self.matches.len().saturating_sub(1));
+    }
+
+    pub const fn previous(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    /// The chosen container
+    pub fn selected_row(&self) -> Option<&FinderRow> {
+        self.matches.get(self.selected).map(|i| &self.rows[i.row])
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::{fuzzy, Finder, FinderField, FinderRow};
+    use crate::app_data::ContainerId;
+
+    #[test]
+    /// Chars are matched in order, ignoring case, the start of a word, & consecutive chars, score higher
+    fn test_finder_fuzzy() {
+        assert_eq!(fuzzy("pgw", "postgres-worker").unwrap().1, [0, 4, 9]);
+        assert_eq!(fuzzy("API", "billing_api").unwrap().1, [8, 9, 10]);
+        assert!(fuzzy("wp", "postgres-worker").is_none());
+        assert_eq!(fuzzy("", "web").unwrap().1, Vec::<usize>::new());
+
+        let word = fuzzy("api", "billing_api").unwrap().0;
+        let scattered = fuzzy("api", "rapid_io").unwrap().0;
+        assert!(word > scattered);
+        assert!(fuzzy("web", "web").unwrap().0 > fuzzy("web", "web_frontend_v2").unwrap().0);
+    }
+
+    #[test]
+    /// Rows are matched by name, image, or project, best first, & the selection is reset as the input changes
+    fn test_finder_filter() {
+        let row = |id: &str, name: &str, image: &str, project: Option<&str>| FinderRow {
+            id: ContainerId::from(id),
+            image: image.to_owned(),
+            name: name.to_owned(),
+            project: project.map(ToOwned::to_owned),
+        };
+        let mut finder = Finder::new(vec![
+            row("1", "shop-web-1", "nginx:1.25", Some("shop")),
+            row("2", "shop-db-1", "postgres:16", Some("shop")),
+            row("3", "grafana", "grafana/grafana", None),
+        ]);
+        assert_eq!(finder.matches.len(), 3);
+        finder.next();
+        finder.next();
+        finder.next();
+        assert_eq!(finder.selected, 2);
+
+        for c in "pos".chars() {
+            finder.push(c);
+        }
+        assert_eq!(finder.selected, 0);
+        assert_eq!(finder.matches.len(), 1);
+        assert_eq!(finder.matches[0].field, FinderField::Image);
+        assert_eq!(finder.selected_row().unwrap().id, ContainerId::from("2"));
+
+        finder.pop();
+        finder.pop();
+        finder.pop();
+        for c in "shop".chars() {
+            finder.push(c);
+        }
+        assert_eq!(finder.matches.len(), 2);
+        assert_eq!(finder.matches[0].field, FinderField::Name);
+        assert_eq!(finder.selected_row().unwrap().name, "shop-db-1");
+
+        finder.push('x');
+        assert!(finder.selected_row().is_none());
+    }
+}
@@ -9,16 +9,55 @@ use tokio::task::JoinHandle;
 use uuid::Uuid;
 
 use crate::{
-    app_data::{ContainerId, Header},
+    app_data::{ByteStats, ContainerId, DockerControls, Header, LogDetail},
     exec::ExecMode,
 };
 
+use super::{
+    build_cache::BuildCache,
+    capture::Capture,
+    cleanup::Cleanup,
+    command_form::CommandForm,
+    context_picker::ContextPicker,
+    file_browser::FileBrowser,
+    finder::Finder,
+    health_view::HealthView,
+    host_picker::HostPicker,
+    image_detail::ImageDetail,
+    inspect_diff::InspectDiff,
+    inspect_view::InspectView,
+    kill_picker::KillPicker,
+    limits_form::LimitsForm,
+    log_export::LogExport,
+    log_sanitizer,
+    match_view::MatchView,
+    pane_layout::{Pane, PaneLayout},
+    port_view::PortView,
+    prune::PrunePreview,
+    pull_progress::PullProgress,
+    reach::ReachPicker,
+    recent_picker::RecentPicker,
+    recreate::{RecreateForm, TagPicker},
+    rename_form::RenameForm,
+    run_form::RunForm,
+    scale_form::ScaleForm,
+    secret_scan::SecretScan,
+    task_logs::TaskLogs,
+    timeline::Timeline,
+};
+
 #[derive(Debug, Default, Clone, Copy, Eq, Hash, PartialEq)]
 pub enum SelectablePanel {
     #[default]
     Containers,
     Commands,
     Logs,
+    /// The images, volumes, networks, & services panels, are only selectable while shown, at most one of them is shown at a time
+    Images,
+    Volumes,
+    Networks,
+    /// Only shown for a host that's a swarm manager
+    Services,
 }
 
 impl SelectablePanel {
@@ -27,20 +66,43 @@ impl SelectablePanel {
             Self::Containers => "Containers",
             Self::Logs => "Logs",
             Self::Commands => "",
+            Self::Images => "Images",
+            Self::Volumes => "Volumes",
+            Self::Networks => "Networks",
+            Self::Services => "Services",
         }
     }
     pub const fn next(self) -> Self {
         match self {
             Self::Containers => Self::Commands,
             Self::Commands => Self::Logs,
-            Self::Logs => Self::Containers,
+            Self::Logs => Self::Images,
+            Self::Images => Self::Volumes,
+            Self::Volumes => Self::Networks,
+            Self::Networks => Self::Services,
+            Self::Services => Self::Containers,
         }
     }
     pub const fn prev(self) -> Self {
         match self {
-            Self::Containers => Self::Logs,
+            Self::Containers => Self::Services,
             Self::Commands => Self::Containers,
             Self::Logs => Self::Commands,
+            Self::Images => Self::Logs,
+            Self::Volumes => Self::Images,
+            Self::Networks => Self::Volumes,
+            Self::Services => Self::Networks,
+        }
+    }
+
+    /// The status that shows the panel, the other panels are always shown
+    const fn status(self) -> Option<Status> {
+        match self {
+            Self::Images => Some(Status::Images),
+            Self::Volumes => Some(Status::Volumes),
+            Self::Networks => Some(Status::Networks),
+            Self::Services => Some(Status::Services),
+            Self::Containers | Self::Commands | Self::Logs => None,
         }
     }
 }
@@ -58,6 +120,26 @@ pub enum DeleteButton {
     No,
 }
 
+/// What deleting a container will remove, shown in the delete confirm dialog when running with `--dry-run`
+/// The first confirmation only acknowledges the preview, a second is needed to actually delete the container
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeletePreview {
+    pub confirmed: bool,
+    pub size: Option<ByteStats>,
+    pub volumes: usize,
+}
+
+impl DeletePreview {
+    /// Add the size, & volumes, of another container, the size is only unknown if it's unknown for both
+    pub fn add(&mut self, other: Self) {
+        self.size = match (self.size, other.size) {
+            (Some(a), Some(b)) => Some(ByteStats::new(a.get() + b.get())),
+            (a, b) => a.or(b),
+        };
+        self.volumes += other.volumes;
+    }
+}
+
 #[allow(unused)]
 #[derive(Debug, Clone, Copy)]
 pub enum BoxLocation {
@@ -159,26 +241,238 @@ const FRAMES_LEN: u8 = 9;
 /// Various functions (e.g input handler), operate differently depending upon current Status
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
 pub enum Status {
-    DeleteConfirm,
+    BuildCache,
+    /// The cleanup menu is shown
+    Cleanup,
+    /// The packets of a container are captured, or its capture filter is being typed
+    Capture,
+    Command,
+    /// The inspect output of two containers is compared
+    Compare,
+    Confirm,
+    /// The docker context picker is shown, at startup, before connecting
+    Contexts,
+    /// The cpu usage, on each core, of the selected container is shown
+    Cores,
+    Daemon,
     DockerConnect,
     Error,
+    /// The events pane is shown
+    Events,
     Exec,
+    ExpandedLog,
+    /// A control sent to a container has failed, its error, & recent attempts, are shown
+    Failed,
+    /// A directory of a container's filesystem is shown
+    Files,
+    /// The filters panel is shown, typing a new rule into it
+    Filters,
+    /// Typing into the container finder
+    Finder,
+    /// The health check of a container, & its recent probes, are shown
+    Health,
     Help,
+    /// The host switcher is shown
+    Hosts,
+    ImageDetail,
+    /// The images panel is shown
+    Images,
     Init,
+    /// The docker inspect tree of a container is shown
+    Inspect,
+    /// Picking the signal to kill a container with
+    Kill,
+    /// Typing the cpu & memory limits of a container
+    Limits,
+    /// The sizes of the panes are shown, to be changed, & saved
+    Layout,
+    /// Choosing the format, lines, & time range, of the logs to save
+    LogExport,
+    /// Typing into the logs filter
+    LogFilter,
+    LogMatches,
+    /// Typing into the logs search
+    LogSearch,
     Logs,
+    /// The memory breakdown of the selected container is shown
+    Memory,
+    /// The networks panel is shown
+    Networks,
+    Note,
+    /// The published, & exposed, ports of a container are shown
+    Ports,
+    Prune,
+    /// An image is being pulled, with its progress shown
+    Pull,
+    Reach,
+    /// The recently viewed containers are listed, to jump back to
+    Recent,
+    Recreate,
+    /// Typing the new name of a container
+    Rename,
+    /// Filling in the form to run a new container
+    Run,
+    /// Typing the replicas to scale a swarm service to
+    Scale,
+    /// The schedules panel is shown, typing into it
+    Schedules,
+    Search,
+    /// The findings of a scan of the container environments are shown
+    SecretScan,
+    /// The services panel is shown
+    Services,
+    Standby,
+    /// Typing a line to send to the stdin of a container
+    Stdin,
+    Tags,
+    /// The merged logs of the tasks of a swarm service are shown
+    TaskLogs,
+    Timeline,
+    Traffic,
+    /// The volumes panel is shown
+    Volumes,
+}
+
+/// The content of the expanded log popup
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExpandedContent {
+    /// Hexdump rows of binary output
+    Rows(Vec<String>),
+    /// Full text of a truncated line, hard wrapped to the width of the popup
+    Text { chars: usize, text: String },
+}
+
+/// The full content of a truncated, or binary, log line, shown in a popup, scrolled by row
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExpandedLog {
+    pub content: ExpandedContent,
+    pub scroll: usize,
+}
+
+impl From<LogDetail> for ExpandedLog {
+    fn from(value: LogDetail) -> Self {
+        let content = match value {
+            LogDetail::Binary(bytes) => ExpandedContent::Rows(log_sanitizer::hexdump(&bytes)),
+            LogDetail::Truncated(text) => ExpandedContent::Text {
+                chars: text.chars().count(),
+                text,
+            },
+        };
+        Self { content, scroll: 0 }
+    }
+}
+
+/// The note being edited, of a container, notes are keyed by container name
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoteForm {
+    pub name: String,
+    pub text: String,
+}
+
+/// The line being typed, to send to the stdin of a container that was started with stdin open
+/// The form stays open after each line is sent, so that several commands can be sent in a row
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StdinForm {
+    pub id: ContainerId,
+    pub name: String,
+    pub text: String,
+    /// The last line sent
+    pub sent: Option<String>,
+}
+
+/// A control that failed, shown with the daemon's error, & the container's recent attempts, until it's retried, or closed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FailedControl {
+    pub control: DockerControls,
+    pub id: ContainerId,
+    pub host: Option<String>,
+    /// When a retry, which waits for the backoff, is due, None if it hasn't been retried
+    pub retry_at: Option<Instant>,
+}
+
+/// The schedules panel, with the schedule being typed, for the container that was selected when the panel was opened
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScheduleForm {
+    /// The container, & its host, that a new schedule is for, None when no container was selected, so schedules can only be removed
+    pub container: Option<(String, Option<String>)>,
+    pub text: String,
+    /// Why the last schedule typed couldn't be parsed
+    pub error: Option<String>,
+    pub selected: usize,
+}
+
+/// The filters panel, with the rule being typed, the rules themselves are kept in app_data
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FilterForm {
+    pub text: String,
+    /// Why the last rule typed couldn't be parsed
+    pub error: Option<String>,
+    pub selected: usize,
 }
 
 /// Global gui_state, stored in an Arc<Mutex>
 #[derive(Debug, Default, Clone)]
 pub struct GuiState {
-    delete_container: Option<ContainerId>,
+    /// An alert has fired since the last frame was drawn, so the bell is rung, if set with `--bell`
+    bell_rung: bool,
+    build_cache: Option<BuildCache>,
+    cleanup: Option<Cleanup>,
+    capture: Option<Capture>,
+    command_form: Option<CommandForm>,
+    /// The container marked by the first ( ( ), the second ( ( ), on another container, compares the two
+    compare_marked: Option<ContainerId>,
+    confirm: Option<(DockerControls, ContainerId)>,
+    /// A control, & the marked containers that it's sent to, confirmed in the same dialog as a single container
+    batch_confirm: Option<(DockerControls, Vec<ContainerId>)>,
     delete_map: HashMap<DeleteButton, Rect>,
+    delete_preview: Option<DeletePreview>,
+    expanded_log: Option<ExpandedLog>,
+    failed: Option<FailedControl>,
     heading_map: HashMap<Header, Rect>,
+    file_browser: Option<FileBrowser>,
+    filter_form: Option<FilterForm>,
+    finder: Option<Finder>,
+    health_view: Option<HealthView>,
+    image_detail: Option<ImageDetail>,
+    inspect_diff: Option<InspectDiff>,
+    inspect_view: Option<InspectView>,
+    kill_picker: Option<KillPicker>,
     is_loading: HashSet<Uuid>,
+    layout: PaneLayout,
+    /// The pane selected in the layout popup
+    layout_pane: Option<Pane>,
+    limits_form: Option<LimitsForm>,
     loading_index: u8,
+    log_export: Option<LogExport>,
+    match_view: MatchView,
+    /// The `--notify` notifications since the last frame was drawn, each is sent to the terminal before the next frame
+    notifications: Vec<String>,
+    note_form: Option<NoteForm>,
+    schedule_form: Option<ScheduleForm>,
     panel_map: HashMap<SelectablePanel, Rect>,
+    port_view: Option<PortView>,
+    prune_preview: Option<PrunePreview>,
+    pull_progress: Option<PullProgress>,
+    host_picker: Option<HostPicker>,
+    context_picker: Option<ContextPicker>,
+    reach_picker: Option<ReachPicker>,
+    recent_picker: Option<RecentPicker>,
+    recreate_form: Option<RecreateForm>,
+    rename_form: Option<RenameForm>,
+    run_form: Option<RunForm>,
+    /// The volume, or network, whose removal has been armed, by the first ( enter ), the second ( enter ) removes it, or the service whose force update has been armed, by the first ( r ), or the stack whose removal has been armed, by the first ( delete )
+    remove_armed: Option<String>,
+    scale_form: Option<ScaleForm>,
+    secret_scan: Option<SecretScan>,
     selected_panel: SelectablePanel,
+    snapshot_requested: bool,
+    stdin_form: Option<StdinForm>,
     status: HashSet<Status>,
+    /// The host of the resources panels is a swarm manager, the services panel is only shown while it is
+    swarm: bool,
+    tag_picker: Option<TagPicker>,
+    task_logs: Option<TaskLogs>,
+    timeline: Option<Timeline>,
     exec_mode: Option<ExecMode>,
     pub info_box_text: Option<(String, Instant)>,
 }
@@ -193,6 +487,30 @@ impl GuiState {
         self.selected_panel
     }
 
+    /// Set the selected panel
+    pub const fn set_selected_panel(&mut self, panel: SelectablePanel) {
+        self.selected_panel = panel;
+    }
+
+    /// Get the area that a panel was last drawn in
+    pub fn get_panel_area(&self, panel: SelectablePanel) -> Option<Rect> {
+        self.panel_map.get(&panel).copied()
+    }
+
+    /// The sizes of the panes, as drawn
+    pub const fn get_layout(&self) -> PaneLayout {
+        self.layout
+    }
+
+    pub const fn set_layout(&mut self, layout: PaneLayout) {
+        self.layout = layout;
+    }
+
+    /// Set the height of the containers panel, None to size it to the containers again
+    pub fn set_containers_height(&mut self, rows: Option<u16>) {
+        self.layout.set_containers(rows);
+    }
+
     /// Check if a given Rect (a clicked area of 1x1), interacts with any known panels
     pub fn panel_intersect(&mut self, rect: Rect) {
         if let Some(data) = self
@@ -247,21 +565,840 @@ impl GuiState {
         };
     }
 
-    /// Check if an ContainerId is set in the delete_container field
-    pub fn get_delete_container(&self) -> Option<ContainerId> {
-        self.delete_container.clone()
+    /// Get the docker control, and container, waiting for confirmation in the confirm dialog
+    pub fn get_confirm(&self) -> Option<(DockerControls, ContainerId)> {
+        self.confirm.clone()
+    }
+
+    pub fn get_batch_confirm(&self) -> Option<(DockerControls, Vec<ContainerId>)> {
+        self.batch_confirm.clone()
     }
 
-    /// Set either a ContainerId, or None, to the delete_container field
-    /// If Some, will also insert the DeleteConfirm status into self.status
-    pub fn set_delete_container(&mut self, id: Option<ContainerId>) {
-        if id.is_some() {
-            self.status.insert(Status::DeleteConfirm);
+    /// Set a control, & the marked containers to send it to, or None, if Some, will also insert the Confirm status into self.status
+    pub fn set_batch_confirm(&mut self, confirm: Option<(DockerControls, Vec<ContainerId>)>) {
+        if confirm.is_some() {
+            self.status.insert(Status::Confirm);
         } else {
             self.delete_map.clear();
-            self.status.remove(&Status::DeleteConfirm);
+            self.delete_preview = None;
+            self.status.remove(&Status::Confirm);
+        }
+        self.batch_confirm = confirm;
+    }
+
+    /// Set either a docker control & ContainerId, or None, to the confirm field
+    /// If Some, will also insert the Confirm status into self.status
+    pub fn set_confirm(&mut self, confirm: Option<(DockerControls, ContainerId)>) {
+        if confirm.is_some() {
+            self.status.insert(Status::Confirm);
+        } else {
+            self.delete_map.clear();
+            self.delete_preview = None;
+            self.status.remove(&Status::Confirm);
+        }
+        self.confirm = confirm;
+    }
+
+    /// Set, or clear, the log line shown in the expanded log popup
+    /// If Some, will also insert the ExpandedLog status into self.status
+    pub fn set_expanded_log(&mut self, detail: Option<LogDetail>) {
+        if detail.is_some() {
+            self.status.insert(Status::ExpandedLog);
+        } else {
+            self.status.remove(&Status::ExpandedLog);
+        }
+        self.expanded_log = detail.map(ExpandedLog::from);
+    }
+
+    /// Get the expanded log, mutable so that the scroll can be clamped once the popup size is known
+    pub const fn get_expanded_log(&mut self) -> Option<&mut ExpandedLog> {
+        self.expanded_log.as_mut()
+    }
+
+    /// Scroll the expanded log popup by a number of rows
+    pub const fn scroll_expanded_log(&mut self, up: bool, rows: usize) {
+        if let Some(log) = self.expanded_log.as_mut() {
+            log.scroll = if up {
+                log.scroll.saturating_sub(rows)
+            } else {
+                log.scroll.saturating_add(rows)
+            };
+        }
+    }
+
+    /// Set, or clear, the recreate with changes form
+    /// If Some, will also insert the Recreate status into self.status
+    pub fn set_recreate_form(&mut self, form: Option<RecreateForm>) {
+        if form.is_some() {
+            self.status.insert(Status::Recreate);
+        } else {
+            self.status.remove(&Status::Recreate);
+        }
+        self.recreate_form = form;
+    }
+
+    pub const fn get_recreate_form(&mut self) -> Option<&mut RecreateForm> {
+        self.recreate_form.as_mut()
+    }
+
+    pub const fn get_recreate_form_ref(&self) -> Option<&RecreateForm> {
+        self.recreate_form.as_ref()
+    }
+
+    /// Set, or clear, the image tag picker
+    /// If Some, will also insert the Tags status into self.status
+    pub fn set_tag_picker(&mut self, picker: Option<TagPicker>) {
+        if picker.is_some() {
+            self.status.insert(Status::Tags);
+        } else {
+            self.status.remove(&Status::Tags);
+        }
+        self.tag_picker = picker;
+    }
+
+    pub const fn get_tag_picker(&mut self) -> Option<&mut TagPicker> {
+        self.tag_picker.as_mut()
+    }
+
+    pub const fn get_tag_picker_ref(&self) -> Option<&TagPicker> {
+        self.tag_picker.as_ref()
+    }
+
+    /// Set, or clear, the host switcher
+    /// If Some, will also insert the Hosts status into self.status
+    pub fn set_host_picker(&mut self, picker: Option<HostPicker>) {
+        if picker.is_some() {
+            self.status.insert(Status::Hosts);
+        } else {
+            self.status.remove(&Status::Hosts);
+        }
+        self.host_picker = picker;
+    }
+
+    pub const fn get_host_picker(&mut self) -> Option<&mut HostPicker> {
+        self.host_picker.as_mut()
+    }
+
+    pub const fn get_host_picker_ref(&self) -> Option<&HostPicker> {
+        self.host_picker.as_ref()
+    }
+
+    /// Set, or clear, the recently viewed containers
+    /// If Some, will also insert the Recent status into self.status
+    pub fn set_recent_picker(&mut self, picker: Option<RecentPicker>) {
+        if picker.is_some() {
+            self.status.insert(Status::Recent);
+        } else {
+            self.status.remove(&Status::Recent);
+        }
+        self.recent_picker = picker;
+    }
+
+    pub const fn get_recent_picker(&mut self) -> Option<&mut RecentPicker> {
+        self.recent_picker.as_mut()
+    }
+
+    pub const fn get_recent_picker_ref(&self) -> Option<&RecentPicker> {
+        self.recent_picker.as_ref()
+    }
+
+    /// Set, or clear, the docker context picker
+    /// If Some, will also insert the Contexts status into self.status
+    pub fn set_context_picker(&mut self, picker: Option<ContextPicker>) {
+        if picker.is_some() {
+            self.status.insert(Status::Contexts);
+        } else {
+            self.status.remove(&Status::Contexts);
+        }
+        self.context_picker = picker;
+    }
+
+    pub const fn get_context_picker(&mut self) -> Option<&mut ContextPicker> {
+        self.context_picker.as_mut()
+    }
+
+    pub const fn get_context_picker_ref(&self) -> Option<&ContextPicker> {
+        self.context_picker.as_ref()
+    }
+
+    /// Set, or clear, the connectivity check target picker
+    /// If Some, will also insert the Reach status into self.status
+    pub fn set_reach_picker(&mut self, picker: Option<ReachPicker>) {
+        if picker.is_some() {
+            self.status.insert(Status::Reach);
+        } else {
+            self.status.remove(&Status::Reach);
+        }
+        self.reach_picker = picker;
+    }
+
+    pub const fn get_reach_picker(&mut self) -> Option<&mut ReachPicker> {
+        self.reach_picker.as_mut()
+    }
+
+    pub const fn get_reach_picker_ref(&self) -> Option<&ReachPicker> {
+        self.reach_picker.as_ref()
+    }
+
+    /// Set, or clear, the build cache view
+    /// If Some, will also insert the BuildCache status into self.status
+    pub fn set_build_cache(&mut self, cache: Option<BuildCache>) {
+        if cache.is_some() {
+            self.status.insert(Status::BuildCache);
+        } else {
+            self.status.remove(&Status::BuildCache);
+        }
+        self.build_cache = cache;
+    }
+
+    pub const fn get_build_cache(&mut self) -> Option<&mut BuildCache> {
+        self.build_cache.as_mut()
+    }
+
+    pub const fn get_build_cache_ref(&self) -> Option<&BuildCache> {
+        self.build_cache.as_ref()
+    }
+
+    /// Set, or clear, the health check of a container
+    /// If Some, will also insert the Health status into self.status
+    pub fn set_health_view(&mut self, view: Option<HealthView>) {
+        if view.is_some() {
+            self.status.insert(Status::Health);
+        } else {
+            self.status.remove(&Status::Health);
+        }
+        self.health_view = view;
+    }
+
+    pub const fn get_health_view(&mut self) -> Option<&mut HealthView> {
+        self.health_view.as_mut()
+    }
+
+    pub const fn get_health_view_ref(&self) -> Option<&HealthView> {
+        self.health_view.as_ref()
+    }
+
+    /// Set, or clear, the file browser, each directory that is opened replaces it
+    /// If Some, will also insert the Files status into self.status
+    pub fn set_file_browser(&mut self, browser: Option<FileBrowser>) {
+        if browser.is_some() {
+            self.status.insert(Status::Files);
+        } else {
+            self.status.remove(&Status::Files);
+        }
+        self.file_browser = browser;
+    }
+
+    pub const fn get_file_browser(&mut self) -> Option<&mut FileBrowser> {
+        self.file_browser.as_mut()
+    }
+
+    pub const fn get_file_browser_ref(&self) -> Option<&FileBrowser> {
+        self.file_browser.as_ref()
+    }
+
+    /// Set, or clear, the container finder
+    /// If Some, will also insert the Finder status into self.status
+    pub fn set_finder(&mut self, finder: Option<Finder>) {
+        if finder.is_some() {
+            self.status.insert(Status::Finder);
+        } else {
+            self.status.remove(&Status::Finder);
+        }
+        self.finder = finder;
+    }
+
+    pub const fn get_finder(&mut self) -> Option<&mut Finder> {
+        self.finder.as_mut()
+    }
+
+    pub const fn get_finder_ref(&self) -> Option<&Finder> {
+        self.finder.as_ref()
+    }
+
+    /// Set, or clear, the ports of a container
+    /// If Some, will also insert the Ports status into self.status
+    pub fn set_port_view(&mut self, view: Option<PortView>) {
+        if view.is_some() {
+            self.status.insert(Status::Ports);
+        } else {
+            self.status.remove(&Status::Ports);
+        }
+        self.port_view = view;
+    }
+
+    pub const fn get_port_view(&mut self) -> Option<&mut PortView> {
+        self.port_view.as_mut()
+    }
+
+    pub const fn get_port_view_ref(&self) -> Option<&PortView> {
+        self.port_view.as_ref()
+    }
+
+    /// If Some, will also insert the SecretScan status into self.status
+    pub fn set_secret_scan(&mut self, scan: Option<SecretScan>) {
+        if scan.is_some() {
+            self.status.insert(Status::SecretScan);
+        } else {
+            self.status.remove(&Status::SecretScan);
+        }
+        self.secret_scan = scan;
+    }
+
+    pub const fn get_secret_scan(&mut self) -> Option<&mut SecretScan> {
+        self.secret_scan.as_mut()
+    }
+
+    pub const fn get_secret_scan_ref(&self) -> Option<&SecretScan> {
+        self.secret_scan.as_ref()
+    }
+
+    /// Set, or clear, the cleanup menu
+    /// If Some, will also insert the Cleanup status into self.status
+    pub fn set_cleanup(&mut self, cleanup: Option<Cleanup>) {
+        if cleanup.is_some() {
+            self.status.insert(Status::Cleanup);
+        } else {
+            self.status.remove(&Status::Cleanup);
+        }
+        self.cleanup = cleanup;
+    }
+
+    pub const fn get_cleanup(&mut self) -> Option<&mut Cleanup> {
+        self.cleanup.as_mut()
+    }
+
+    pub const fn get_cleanup_ref(&self) -> Option<&Cleanup> {
+        self.cleanup.as_ref()
+    }
+
+    /// Set, or clear, the save logs popup
+    /// If Some, will also insert the LogExport status into self.status
+    pub fn set_log_export(&mut self, export: Option<LogExport>) {
+        if export.is_some() {
+            self.status.insert(Status::LogExport);
+        } else {
+            self.status.remove(&Status::LogExport);
+        }
+        self.log_export = export;
+    }
+
+    pub const fn get_log_export(&mut self) -> Option<&mut LogExport> {
+        self.log_export.as_mut()
+    }
+
+    pub const fn get_log_export_ref(&self) -> Option<&LogExport> {
+        self.log_export.as_ref()
+    }
+
+    /// Set, or clear, the inspected image
+    /// If Some, will also insert the ImageDetail status into self.status
+    pub fn set_image_detail(&mut self, detail: Option<ImageDetail>) {
+        if detail.is_some() {
+            self.status.insert(Status::ImageDetail);
+        } else {
+            self.status.remove(&Status::ImageDetail);
+        }
+        self.image_detail = detail;
+    }
+
+    pub const fn get_image_detail(&mut self) -> Option<&mut ImageDetail> {
+        self.image_detail.as_mut()
+    }
+
+    pub const fn get_image_detail_ref(&self) -> Option<&ImageDetail> {
+        self.image_detail.as_ref()
+    }
+
+    /// Set, or clear, the comparison of two containers
+    /// If Some, will also insert the Compare status into self.status
+    pub fn set_inspect_diff(&mut self, diff: Option<InspectDiff>) {
+        if diff.is_some() {
+            self.status.insert(Status::Compare);
+        } else {
+            self.status.remove(&Status::Compare);
+        }
+        self.inspect_diff = diff;
+    }
+
+    pub const fn get_inspect_diff(&mut self) -> Option<&mut InspectDiff> {
+        self.inspect_diff.as_mut()
+    }
+
+    pub const fn get_inspect_diff_ref(&self) -> Option<&InspectDiff> {
+        self.inspect_diff.as_ref()
+    }
+
+    /// Mark, or unmark, the container to compare with
+    pub fn set_compare_marked(&mut self, id: Option<ContainerId>) {
+        self.compare_marked = id;
+    }
+
+    pub const fn get_compare_marked(&self) -> Option<&ContainerId> {
+        self.compare_marked.as_ref()
+    }
+
+    /// Set, or clear, the inspect tree of a container
+    /// If Some, will also insert the Inspect status into self.status
+    pub fn set_inspect_view(&mut self, view: Option<InspectView>) {
+        if view.is_some() {
+            self.status.insert(Status::Inspect);
+        } else {
+            self.status.remove(&Status::Inspect);
+        }
+        self.inspect_view = view;
+    }
+
+    pub const fn get_inspect_view(&mut self) -> Option<&mut InspectView> {
+        self.inspect_view.as_mut()
+    }
+
+    pub const fn get_inspect_view_ref(&self) -> Option<&InspectView> {
+        self.inspect_view.as_ref()
+    }
+
+    /// Set, or clear, the progress of the image being pulled
+    /// If Some, will also insert the Pull status into self.status
+    pub fn set_pull_progress(&mut self, progress: Option<PullProgress>) {
+        if progress.is_some() {
+            self.status.insert(Status::Pull);
+        } else {
+            self.status.remove(&Status::Pull);
+        }
+        self.pull_progress = progress;
+    }
+
+    pub const fn get_pull_progress(&mut self) -> Option<&mut PullProgress> {
+        self.pull_progress.as_mut()
+    }
+
+    pub const fn get_pull_progress_ref(&self) -> Option<&PullProgress> {
+        self.pull_progress.as_ref()
+    }
+
+    /// The images, volumes, networks, or services panel, whichever is shown
+    pub fn get_resources_panel(&self) -> Option<SelectablePanel> {
+        [
+            SelectablePanel::Images,
+            SelectablePanel::Volumes,
+            SelectablePanel::Networks,
+            SelectablePanel::Services,
+        ]
+        .into_iter()
+        .find(|i| self.is_shown(*i))
+    }
+
+    /// Cycle the panel shown beside the logs, none -> images -> volumes -> networks -> services -> none, the services panel is skipped if the host isn't a swarm manager
+    /// If the hidden panel was selected, the newly shown panel is selected, or the containers panel if none is shown
+    pub fn cycle_resources(&mut self) {
+        let shown = self.get_resources_panel();
+        let next = match shown {
+            None => Some(SelectablePanel::Images),
+            Some(SelectablePanel::Images) => Some(SelectablePanel::Volumes),
+            Some(SelectablePanel::Volumes) => Some(SelectablePanel::Networks),
+            Some(SelectablePanel::Networks) if self.swarm => Some(SelectablePanel::Services),
+            Some(_) => None,
+        };
+        for status in [shown, next]
+            .into_iter()
+            .flatten()
+            .filter_map(SelectablePanel::status)
+        {
+            if !self.status.remove(&status) {
+                self.status.insert(status);
+            }
+        }
+        self.remove_armed = None;
+        if shown.is_some() && shown == Some(self.selected_panel) {
+            self.selected_panel = next.unwrap_or(SelectablePanel::Containers);
+        }
+    }
+
+    /// The panel is always shown, or is the images, volumes, networks, or services panel, and is currently shown
+    fn is_shown(&self, panel: SelectablePanel) -> bool {
+        panel.status().is_none_or(|i| self.status.contains(&i))
+    }
+
+    /// Set whether the host of the resources panels is a swarm manager, the services panel is hidden once it isn't, & if it was selected, the containers panel is selected
+    pub fn set_swarm(&mut self, swarm: bool) {
+        self.swarm = swarm;
+        if !swarm && self.status.remove(&Status::Services) {
+            self.remove_armed = None;
+            if self.selected_panel == SelectablePanel::Services {
+                self.selected_panel = SelectablePanel::Containers;
+            }
+        }
+    }
+
+    /// Arm the removal of a volume, network, or stack, or the force update of a service, or clear the armed action
+    pub fn set_remove_armed(&mut self, armed: Option<String>) {
+        self.remove_armed = armed;
+    }
+
+    pub fn get_remove_armed(&self) -> Option<&str> {
+        self.remove_armed.as_deref()
+    }
+
+    /// Set, or clear, the image prune preview
+    /// If Some, will also insert the Prune status into self.status
+    pub fn set_prune_preview(&mut self, preview: Option<PrunePreview>) {
+        if preview.is_some() {
+            self.status.insert(Status::Prune);
+        } else {
+            self.status.remove(&Status::Prune);
+        }
+        self.prune_preview = preview;
+    }
+
+    pub const fn get_prune_preview(&mut self) -> Option<&mut PrunePreview> {
+        self.prune_preview.as_mut()
+    }
+
+    pub const fn get_prune_preview_ref(&self) -> Option<&PrunePreview> {
+        self.prune_preview.as_ref()
+    }
+
+    /// Set, or clear, the lifecycle timeline
+    /// If Some, will also insert the Timeline status into self.status
+    pub fn set_timeline(&mut self, timeline: Option<Timeline>) {
+        if timeline.is_some() {
+            self.status.insert(Status::Timeline);
+        } else {
+            self.status.remove(&Status::Timeline);
+        }
+        self.timeline = timeline;
+    }
+
+    pub const fn get_timeline(&self) -> Option<&Timeline> {
+        self.timeline.as_ref()
+    }
+
+    pub const fn get_timeline_mut(&mut self) -> Option<&mut Timeline> {
+        self.timeline.as_mut()
+    }
+
+    /// Set, or clear, the note form
+    /// If Some, will also insert the Note status into self.status
+    pub fn set_note_form(&mut self, form: Option<NoteForm>) {
+        if form.is_some() {
+            self.status.insert(Status::Note);
+        } else {
+            self.status.remove(&Status::Note);
+        }
+        self.note_form = form;
+    }
+
+    pub const fn get_note_form(&mut self) -> Option<&mut NoteForm> {
+        self.note_form.as_mut()
+    }
+
+    pub const fn get_note_form_ref(&self) -> Option<&NoteForm> {
+        self.note_form.as_ref()
+    }
+
+    /// Set, or clear, the failed control
+    /// If Some, will also insert the Failed status into self.status
+    pub fn set_failed(&mut self, failed: Option<FailedControl>) {
+        if failed.is_some() {
+            self.status.insert(Status::Failed);
+        } else {
+            self.status.remove(&Status::Failed);
+        }
+        self.failed = failed;
+    }
+
+    pub const fn get_failed(&mut self) -> Option<&mut FailedControl> {
+        self.failed.as_mut()
+    }
+
+    pub const fn get_failed_ref(&self) -> Option<&FailedControl> {
+        self.failed.as_ref()
+    }
+
+    /// Set, or clear, the run form
+    /// If Some, will also insert the Run status into self.status
+    pub fn set_run_form(&mut self, form: Option<RunForm>) {
+        if form.is_some() {
+            self.status.insert(Status::Run);
+        } else {
+            self.status.remove(&Status::Run);
+        }
+        self.run_form = form;
+    }
+
+    pub const fn get_run_form(&mut self) -> Option<&mut RunForm> {
+        self.run_form.as_mut()
+    }
+
+    pub const fn get_run_form_ref(&self) -> Option<&RunForm> {
+        self.run_form.as_ref()
+    }
+
+    /// Set, or clear, the capture pane
+    /// If Some, will also insert the Capture status into self.status
+    pub fn set_capture(&mut self, capture: Option<Capture>) {
+        if capture.is_some() {
+            self.status.insert(Status::Capture);
+        } else {
+            self.status.remove(&Status::Capture);
+        }
+        self.capture = capture;
+    }
+
+    pub const fn get_capture(&mut self) -> Option<&mut Capture> {
+        self.capture.as_mut()
+    }
+
+    pub const fn get_capture_ref(&self) -> Option<&Capture> {
+        self.capture.as_ref()
+    }
+
+    /// Set, or clear, the rename form
+    /// If Some, will also insert the Rename status into self.status
+    pub fn set_rename_form(&mut self, form: Option<RenameForm>) {
+        if form.is_some() {
+            self.status.insert(Status::Rename);
+        } else {
+            self.status.remove(&Status::Rename);
+        }
+        self.rename_form = form;
+    }
+
+    pub const fn get_rename_form(&mut self) -> Option<&mut RenameForm> {
+        self.rename_form.as_mut()
+    }
+
+    pub const fn get_rename_form_ref(&self) -> Option<&RenameForm> {
+        self.rename_form.as_ref()
+    }
+
+    /// Set, or clear, the scale form
+    /// If Some, will also insert the Scale status into self.status
+    pub fn set_scale_form(&mut self, form: Option<ScaleForm>) {
+        if form.is_some() {
+            self.status.insert(Status::Scale);
+        } else {
+            self.status.remove(&Status::Scale);
+        }
+        self.scale_form = form;
+    }
+
+    pub const fn get_scale_form(&mut self) -> Option<&mut ScaleForm> {
+        self.scale_form.as_mut()
+    }
+
+    pub const fn get_scale_form_ref(&self) -> Option<&ScaleForm> {
+        self.scale_form.as_ref()
+    }
+
+    /// Set, or clear, the merged logs of a service's tasks
+    /// If Some, will also insert the TaskLogs status into self.status
+    pub fn set_task_logs(&mut self, logs: Option<TaskLogs>) {
+        if logs.is_some() {
+            self.status.insert(Status::TaskLogs);
+        } else {
+            self.status.remove(&Status::TaskLogs);
+        }
+        self.task_logs = logs;
+    }
+
+    pub const fn get_task_logs(&mut self) -> Option<&mut TaskLogs> {
+        self.task_logs.as_mut()
+    }
+
+    /// Set, or clear, the kill signal picker
+    /// If Some, will also insert the Kill status into self.status
+    pub fn set_kill_picker(&mut self, picker: Option<KillPicker>) {
+        if picker.is_some() {
+            self.status.insert(Status::Kill);
+        } else {
+            self.status.remove(&Status::Kill);
+        }
+        self.kill_picker = picker;
+    }
+
+    pub const fn get_kill_picker(&mut self) -> Option<&mut KillPicker> {
+        self.kill_picker.as_mut()
+    }
+
+    pub const fn get_kill_picker_ref(&self) -> Option<&KillPicker> {
+        self.kill_picker.as_ref()
+    }
+
+    /// Set, or clear, the stdin form
+    /// If Some, will also insert the Stdin status into self.status
+    pub fn set_stdin_form(&mut self, form: Option<StdinForm>) {
+        if form.is_some() {
+            self.status.insert(Status::Stdin);
+        } else {
+            self.status.remove(&Status::Stdin);
+        }
+        self.stdin_form = form;
+    }
+
+    pub const fn get_stdin_form(&mut self) -> Option<&mut StdinForm> {
+        self.stdin_form.as_mut()
+    }
+
+    pub const fn get_stdin_form_ref(&self) -> Option<&StdinForm> {
+        self.stdin_form.as_ref()
+    }
+
+    /// Set, or clear, the limits form
+    /// If Some, will also insert the Limits status into self.status
+    pub fn set_limits_form(&mut self, form: Option<LimitsForm>) {
+        if form.is_some() {
+            self.status.insert(Status::Limits);
+        } else {
+            self.status.remove(&Status::Limits);
+        }
+        self.limits_form = form;
+    }
+
+    pub const fn get_limits_form(&mut self) -> Option<&mut LimitsForm> {
+        self.limits_form.as_mut()
+    }
+
+    pub const fn get_limits_form_ref(&self) -> Option<&LimitsForm> {
+        self.limits_form.as_ref()
+    }
+
+    /// Set, or clear, the layout popup
+    /// If Some, will also insert the Layout status into self.status
+    pub fn set_layout_pane(&mut self, pane: Option<Pane>) {
+        if pane.is_some() {
+            self.status.insert(Status::Layout);
+        } else {
+            self.status.remove(&Status::Layout);
+        }
+        self.layout_pane = pane;
+    }
+
+    pub const fn get_layout_pane(&self) -> Option<Pane> {
+        self.layout_pane
+    }
+
+    /// Set, or clear, the filters panel
+    /// If Some, will also insert the Filters status into self.status
+    pub fn set_filter_form(&mut self, form: Option<FilterForm>) {
+        if form.is_some() {
+            self.status.insert(Status::Filters);
+        } else {
+            self.status.remove(&Status::Filters);
+        }
+        self.filter_form = form;
+    }
+
+    pub const fn get_filter_form(&mut self) -> Option<&mut FilterForm> {
+        self.filter_form.as_mut()
+    }
+
+    pub const fn get_filter_form_ref(&self) -> Option<&FilterForm> {
+        self.filter_form.as_ref()
+    }
+
+    /// Set, or clear, the schedules panel
+    /// If Some, will also insert the Schedules status into self.status
+    pub fn set_schedule_form(&mut self, form: Option<ScheduleForm>) {
+        if form.is_some() {
+            self.status.insert(Status::Schedules);
+        } else {
+            self.status.remove(&Status::Schedules);
+        }
+        self.schedule_form = form;
+    }
+
+    pub const fn get_schedule_form(&mut self) -> Option<&mut ScheduleForm> {
+        self.schedule_form.as_mut()
+    }
+
+    pub const fn get_schedule_form_ref(&self) -> Option<&ScheduleForm> {
+        self.schedule_form.as_ref()
+    }
+
+    /// Set, or clear, the command form
+    /// If Some, will also insert the Command status into self.status
+    pub fn set_command_form(&mut self, form: Option<CommandForm>) {
+        if form.is_some() {
+            self.status.insert(Status::Command);
+        } else {
+            self.status.remove(&Status::Command);
+        }
+        self.command_form = form;
+    }
+
+    pub const fn get_command_form(&mut self) -> Option<&mut CommandForm> {
+        self.command_form.as_mut()
+    }
+
+    pub const fn get_command_form_ref(&self) -> Option<&CommandForm> {
+        self.command_form.as_ref()
+    }
+
+    pub const fn get_match_view(&self) -> MatchView {
+        self.match_view
+    }
+
+    pub const fn get_match_view_mut(&mut self) -> &mut MatchView {
+        &mut self.match_view
+    }
+
+    /// Request that the next drawn frame is saved as a snapshot
+    pub const fn request_snapshot(&mut self) {
+        self.snapshot_requested = true;
+    }
+
+    /// Check if a snapshot has been requested, and reset the request
+    pub fn take_snapshot_request(&mut self) -> bool {
+        std::mem::take(&mut self.snapshot_requested)
+    }
+
+    /// Ring the bell, before the next frame is drawn
+    pub const fn ring_bell(&mut self) {
+        self.bell_rung = true;
+    }
+
+    /// Check if the bell has been rung, and reset it
+    pub fn take_bell(&mut self) -> bool {
+        std::mem::take(&mut self.bell_rung)
+    }
+
+    /// Send a desktop notification, before the next frame is drawn
+    pub fn notify(&mut self, text: String) {
+        self.notifications.push(text);
+    }
+
+    /// The notifications sent since the last frame, which are reset
+    pub fn take_notifications(&mut self) -> Vec<String> {
+        std::mem::take(&mut self.notifications)
+    }
+
+    /// Get the dry run preview of the container currently in the delete confirm dialog
+    pub const fn get_delete_preview(&self) -> Option<DeletePreview> {
+        self.delete_preview
+    }
+
+    /// Set the dry run preview of the container currently in the delete confirm dialog
+    pub const fn set_delete_preview(&mut self, preview: Option<DeletePreview>) {
+        self.delete_preview = preview;
+    }
+
+    /// Add the dry run preview of some of the marked containers, each host previews its own, to the preview of the delete confirm dialog, ignored if the dialog has since been closed
+    pub fn add_delete_preview(&mut self, preview: DeletePreview) {
+        if let Some(current) = self.delete_preview.as_mut() {
+            current.add(preview);
+        }
+    }
+
+    /// Confirm the delete dialog, if an unconfirmed dry run preview is shown, it's marked as confirmed, and false is returned, as another confirmation is required
+    pub const fn delete_confirm_ready(&mut self) -> bool {
+        match self.delete_preview.as_mut() {
+            Some(preview) if !preview.confirmed => {
+                preview.confirmed = true;
+                false
+            }
+            _ => true,
         }
-        self.delete_container = id;
     }
 
     /// Check if the current gui_status contains any of the given status'
@@ -272,15 +1409,127 @@ impl GuiState {
 
     /// Remove a gui_status into the current gui_status HashSet
     /// Remove exec mode & deleteConfirm is required
+    #[allow(clippy::too_many_lines)]
     pub fn status_del(&mut self, status: Status) {
         self.status.remove(&status);
         match status {
-            Status::DeleteConfirm => {
-                self.status.remove(&Status::DeleteConfirm);
+            Status::Confirm => {
+                self.batch_confirm = None;
             }
             Status::Exec => {
                 self.exec_mode = None;
             }
+            Status::ExpandedLog => {
+                self.expanded_log = None;
+            }
+            Status::Note => {
+                self.note_form = None;
+            }
+            Status::Schedules => {
+                self.schedule_form = None;
+            }
+            Status::Stdin => {
+                self.stdin_form = None;
+            }
+            Status::Capture => {
+                // However the pane is closed, the capture is stopped, & its pcap saved
+                if let Some(capture) = self.capture.take() {
+                    capture.stop();
+                }
+            }
+            Status::Rename => {
+                self.rename_form = None;
+            }
+            Status::Scale => {
+                self.scale_form = None;
+            }
+            Status::TaskLogs => {
+                self.task_logs = None;
+            }
+            Status::Kill => {
+                self.kill_picker = None;
+            }
+            Status::Limits => {
+                self.limits_form = None;
+            }
+            Status::Run => {
+                self.run_form = None;
+            }
+            Status::Failed => {
+                self.failed = None;
+            }
+            Status::Command => {
+                self.command_form = None;
+            }
+            Status::BuildCache => {
+                self.build_cache = None;
+            }
+            Status::SecretScan => {
+                self.secret_scan = None;
+            }
+            Status::Health => {
+                self.health_view = None;
+            }
+            Status::Files => {
+                self.file_browser = None;
+            }
+            Status::Filters => {
+                self.filter_form = None;
+            }
+            Status::Layout => {
+                self.layout_pane = None;
+            }
+            Status::Finder => {
+                self.finder = None;
+            }
+            Status::Ports => {
+                self.port_view = None;
+            }
+            Status::Cleanup => {
+                self.cleanup = None;
+            }
+            Status::LogExport => {
+                self.log_export = None;
+            }
+            Status::Prune => {
+                self.prune_preview = None;
+            }
+            Status::Pull => {
+                self.pull_progress = None;
+            }
+            Status::Hosts => {
+                self.host_picker = None;
+            }
+            Status::Contexts => {
+                self.context_picker = None;
+            }
+            Status::Reach => {
+                self.reach_picker = None;
+            }
+            Status::Recent => {
+                self.recent_picker = None;
+            }
+            Status::Recreate => {
+                self.recreate_form = None;
+            }
+            Status::Tags => {
+                self.tag_picker = None;
+            }
+            Status::Timeline => {
+                self.timeline = None;
+            }
+            Status::ImageDetail => {
+                self.image_detail = None;
+            }
+            Status::Inspect => {
+                self.inspect_view = None;
+            }
+            Status::Compare => {
+                self.inspect_diff = None;
+            }
+            Status::LogMatches => {
+                self.match_view = MatchView::default();
+            }
             _ => (),
         }
     }
@@ -309,13 +1558,20 @@ impl GuiState {
     }
 
     /// Change to next selectable panel
+    /// The images, volumes, & networks panels are skipped when they aren't shown
     pub fn next_panel(&mut self) {
         self.selected_panel = self.selected_panel.next();
+        while !self.is_shown(self.selected_panel) {
+            self.selected_panel = self.selected_panel.next();
+        }
     }
 
     /// Change to previous selectable panel
     pub fn previous_panel(&mut self) {
         self.selected_panel = self.selected_panel.prev();
+        while !self.is_shown(self.selected_panel) {
+            self.selected_panel = self.selected_panel.prev();
+        }
     }
 
     /// Insert a new loading_uuid into HashSet, and advance the loading_index by one frame, or reset to 0 if at end of array
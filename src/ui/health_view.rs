@@ -0,0 +1,86 @@
+/// A single run of a container's health check
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HealthProbe {
+    /// How long the probe ran, in milliseconds
+    pub duration: Option<u64>,
+    /// Zero is a pass, anything else is a failure
+    pub exit_code: Option<i64>,
+    pub output: String,
+    /// Unix timestamp, in seconds
+    pub start: Option<u64>,
+}
+
+impl HealthProbe {
+    pub fn passed(&self) -> bool {
+        self.exit_code == Some(0)
+    }
+}
+
+/// The health check of a container, from an inspect, its configured test, & the probes that the daemon keeps, newest first
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HealthView {
+    pub failing_streak: i64,
+    /// Seconds between probes
+    pub interval: Option<u64>,
+    pub name: String,
+    /// Unix timestamp, in seconds, that the age of each probe is measured from
+    pub now: u64,
+    pub probes: Vec<HealthProbe>,
+    /// Consecutive failures before the container is unhealthy
+    pub retries: Option<i64>,
+    pub selected: usize,
+    /// As reported by the daemon, e.g. `unhealthy`
+    pub status: String,
+    /// The command that is run, e.g. `curl -f localhost/health`
+    pub test: String,
+    /// Seconds before a probe is counted as failed
+    pub timeout: Option<u64>,
+}
+
+impl HealthView {
+    pub fn next(&mut self) {
+        self.selected = (self.selected + 1).min(self.probes.len().saturating_sub(1));
+    }
+
+    pub const fn previous(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    pub fn selected_probe(&self) -> Option<&HealthProbe> {
+        self.probes.get(self.selected)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{HealthProbe, HealthView};
+
+    #[test]
+    /// The selection stays within the probes
+    fn test_health_view_select() {
+        let probe = |exit_code| HealthProbe {
+            duration: None,
+            exit_code: Some(exit_code),
+            output: String::new(),
+            start: None,
+        };
+        let mut view = HealthView {
+            failing_streak: 1,
+            interval: None,
+            name: "web".to_owned(),
+            now: 0,
+            probes: vec![probe(1), probe(0)],
+            retries: None,
+            selected: 0,
+            status: "healthy".to_owned(),
+            test: String::new(),
+            timeout: None,
+        };
+        view.previous();
+        assert!(!view.selected_probe().is_some_and(HealthProbe::passed));
+        view.next();
+        view.next();
+        assert_eq!(view.selected, 1);
+        assert!(view.selected_probe().is_some_and(HealthProbe::passed));
+    }
+}
@@ -0,0 +1,89 @@
+/// A host tab that can be switched to, with how many of its containers are running
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HostRow {
+    /// None is the merged view of all hosts
+    pub host: Option<String>,
+    pub running: usize,
+    pub total: usize,
+}
+
+impl HostRow {
+    pub fn name(&self) -> &str {
+        self.host.as_deref().unwrap_or("all hosts")
+    }
+}
+
+/// The host tabs, all hosts first, then the primary host, then each extra host, the current tab is marked
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HostPicker {
+    /// Index of the current tab
+    pub current: usize,
+    pub rows: Vec<HostRow>,
+    pub selected: usize,
+}
+
+impl HostPicker {
+    /// The tab after the current tab is selected, so that ( v ) then ( enter ) still cycles the tabs
+    pub const fn new(rows: Vec<HostRow>, current: usize) -> Self {
+        let selected = if rows.is_empty() {
+            0
+        } else {
+            (current + 1) % rows.len()
+        };
+        Self {
+            current,
+            rows,
+            selected,
+        }
+    }
+
+    pub fn next(&mut self) {
+        self.selected = (self.selected + 1).min(self.rows.len().saturating_sub(1));
+    }
+
+    pub const fn previous(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    /// Select the next tab, wrapping around to the first
+    pub const fn cycle(&mut self) {
+        if !self.rows.is_empty() {
+            self.selected = (self.selected + 1) % self.rows.len();
+        }
+    }
+
+    pub fn chosen(&self) -> Option<&HostRow> {
+        self.rows.get(self.selected)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::{HostPicker, HostRow};
+
+    #[test]
+    /// The tab after the current tab is selected, cycling wraps around, next & previous don't
+    fn test_host_picker() {
+        let rows = [None, Some("local"), Some("ssh://build")]
+            .into_iter()
+            .map(|host| HostRow {
+                host: host.map(ToOwned::to_owned),
+                running: 1,
+                total: 2,
+            })
+            .collect::<Vec<_>>();
+        let mut picker = HostPicker::new(rows, 2);
+        assert_eq!(picker.selected, 0);
+        assert_eq!(picker.chosen().unwrap().name(), "all hosts");
+        picker.previous();
+        assert_eq!(picker.selected, 0);
+        picker.cycle();
+        picker.cycle();
+        assert_eq!(picker.chosen().unwrap().name(), "ssh://build");
+        picker.next();
+        assert_eq!(picker.selected, 2);
+        picker.cycle();
+        assert_eq!(picker.selected, 0);
+    }
+}
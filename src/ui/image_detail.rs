@@ -0,0 +1,27 @@
+use crate::app_data::ImageItem;
+
+/// An inspected image, as shown in the image detail popup, from which the image can be pulled, or removed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImageDetail {
+    /// The first ( r ) arms the removal, and the second removes
+    pub armed: bool,
+    /// e.g. `linux/amd64`
+    pub platform: Option<String>,
+    pub cmd: Vec<String>,
+    pub entrypoint: Vec<String>,
+    pub env: Vec<String>,
+    pub exposed_ports: Vec<String>,
+    /// The host that the image is on, the primary host is None
+    pub host: Option<String>,
+    pub image: ImageItem,
+    pub layers: usize,
+    pub user: Option<String>,
+    pub working_dir: Option<String>,
+}
+
+impl ImageDetail {
+    /// The tag that's pulled, the first tag of the image, a dangling image can't be pulled
+    pub fn pull_tag(&self) -> Option<&str> {
+        self.image.tags.first().map(String::as_str)
+    }
+}
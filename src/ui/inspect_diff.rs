@@ -0,0 +1,165 @@
+/// The parts of the inspect output that are compared, in the order shown
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum DiffSection {
+    Image,
+    Env,
+    Mounts,
+    Limits,
+    Networks,
+}
+
+impl DiffSection {
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Image => "image",
+            Self::Env => "env",
+            Self::Mounts => "mounts",
+            Self::Limits => "limits",
+            Self::Networks => "networks",
+        }
+    }
+}
+
+/// A single setting of both containers, None when it isn't set on that container
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiffRow {
+    pub section: DiffSection,
+    pub key: String,
+    pub left: Option<String>,
+    pub right: Option<String>,
+}
+
+impl DiffRow {
+    pub fn changed(&self) -> bool {
+        self.left != self.right
+    }
+}
+
+/// A line of the diff, as drawn, a section heading, or a row of that section
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiffLine<'a> {
+    Section(DiffSection),
+    Row(&'a DiffRow),
+}
+
+/// The inspect output of two containers, side by side, setting by setting, to see why two replicas behave differently
+/// Every setting is shown, with the differences highlighted, ( space ) hides the settings that are the same
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InspectDiff {
+    pub left: String,
+    pub right: String,
+    /// Sorted by section, then key
+    pub rows: Vec<DiffRow>,
+    pub offset: usize,
+    pub only_changed: bool,
+}
+
+impl InspectDiff {
+    pub fn new(left: String, right: String, mut rows: Vec<DiffRow>) -> Self {
+        rows.sort_by(|a, b| a.section.cmp(&b.section).then_with(|| a.key.cmp(&b.key)));
+        Self {
+            left,
+            right,
+            rows,
+            offset: 0,
+            only_changed: false,
+        }
+    }
+
+    /// Number of settings that differ
+    pub fn changed(&self) -> usize {
+        self.rows.iter().filter(|i| i.changed()).count()
+    }
+
+    /// The lines to draw, a section is only shown if it has any rows to show
+    pub fn lines(&self) -> Vec<DiffLine<'_>> {
+        let mut lines = vec![];
+        let mut section = None;
+        for row in self
+            .rows
+            .iter()
+            .filter(|i| !self.only_changed || i.changed())
+        {
+            if section != Some(row.section) {
+                section = Some(row.section);
+                lines.push(DiffLine::Section(row.section));
+            }
+            lines.push(DiffLine::Row(row));
+        }
+        lines
+    }
+
+    pub const fn toggle_changed(&mut self) {
+        self.only_changed = !self.only_changed;
+        self.offset = 0;
+    }
+
+    pub fn next(&mut self, count: usize) {
+        self.offset = self
+            .offset
+            .saturating_add(count)
+            .min(self.lines().len().saturating_sub(1));
+    }
+
+    pub const fn previous(&mut self, count: usize) {
+        self.offset = self.offset.saturating_sub(count);
+    }
+
+    pub fn end(&mut self) {
+        self.offset = self.lines().len().saturating_sub(1);
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::{DiffLine, DiffRow, DiffSection, InspectDiff};
+
+    fn row(section: DiffSection, key: &str, left: Option<&str>, right: Option<&str>) -> DiffRow {
+        DiffRow {
+            section,
+            key: key.to_owned(),
+            left: left.map(ToOwned::to_owned),
+            right: right.map(ToOwned::to_owned),
+        }
+    }
+
+    #[test]
+    /// Rows are grouped under their section, & the unchanged rows, & any section left empty, are hidden once toggled
+    fn test_inspect_diff_lines() {
+        let mut diff = InspectDiff::new(
+            "web-1".to_owned(),
+            "web-2".to_owned(),
+            vec![
+                row(
+                    DiffSection::Networks,
+                    "shop",
+                    Some("connected"),
+                    Some("connected"),
+                ),
+                row(DiffSection::Env, "TZ", Some("UTC"), None),
+                row(DiffSection::Env, "LOG", Some("info"), Some("debug")),
+                row(DiffSection::Image, "image", Some("nginx"), Some("nginx")),
+            ],
+        );
+        assert_eq!(diff.changed(), 2);
+        let lines = diff.lines();
+        assert_eq!(lines.len(), 7);
+        assert_eq!(lines[0], DiffLine::Section(DiffSection::Image));
+        assert_eq!(lines[2], DiffLine::Section(DiffSection::Env));
+        assert!(matches!(lines[3], DiffLine::Row(i) if i.key == "LOG"));
+        assert_eq!(lines[5], DiffLine::Section(DiffSection::Networks));
+
+        diff.end();
+        assert_eq!(diff.offset, 6);
+        diff.toggle_changed();
+        assert_eq!(diff.offset, 0);
+        let lines = diff.lines();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0], DiffLine::Section(DiffSection::Env));
+        diff.next(10);
+        assert_eq!(diff.offset, 2);
+        diff.previous(5);
+        assert_eq!(diff.offset, 0);
+    }
+}
@@ -0,0 +1,217 @@
+use std::collections::HashSet;
+
+use serde_json::Value;
+
+/// A single node of the inspect tree, as drawn, one per row
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InspectRow {
+    /// The keys, & indexes, from the root, joined by `/`, e.g. `Config/Env/0`, used to remember which nodes are expanded
+    pub path: String,
+    pub depth: usize,
+    pub key: String,
+    /// A scalar as JSON, e.g. `"nginx"` or `true`, or the number of children of an object, `{3}`, or array, `[3]`
+    pub value: String,
+    /// None for a scalar, else if the children are shown
+    pub expanded: Option<bool>,
+}
+
+/// The full `docker inspect` output of a container, as a tree, each object & array can be expanded, or collapsed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InspectView {
+    pub name: String,
+    value: Value,
+    expanded: HashSet<String>,
+    pub rows: Vec<InspectRow>,
+    pub selected: usize,
+}
+
+/// The children of an object, or array, with their keys, None for a scalar
+fn children(value: &Value) -> Option<Vec<(String, &Value)>> {
+    match value {
+        Value::Object(map) => Some(map.iter().map(|(k, v)| (k.clone(), v)).collect()),
+        Value::Array(items) => Some(
+            items
+                .iter()
+                .enumerate()
+                .map(|(i, v)| (i.to_string(), v))
+                .collect(),
+        ),
+        _ => None,
+    }
+}
+
+impl InspectView {
+    /// Only the top level keys are shown to begin with
+    pub fn new(name: String, value: Value) -> Self {
+        let mut output = Self {
+            name,
+            value,
+            expanded: HashSet::new(),
+            rows: vec![],
+            selected: 0,
+        };
+        output.rebuild();
+        output
+    }
+
+    fn push_rows(
+        rows: &mut Vec<InspectRow>,
+        expanded: &HashSet<String>,
+        value: &Value,
+        parent: &str,
+        depth: usize,
+    ) {
+        for (key, child) in children(value).unwrap_or_default() {
+            let path = if parent.is_empty() {
+                key.clone()
+            } else {
+                format!("{parent}/{key}")
+            };
+            let is_expanded = expanded.contains(&path);
+            let (value, state) = match child {
+                Value::Object(map) => (format!("{{{}}}", map.len()), Some(is_expanded)),
+                Value::Array(items) => (format!("[{}]", items.len()), Some(is_expanded)),
+                scalar => (scalar.to_string(), None),
+            };
+            rows.push(InspectRow {
+                path: path.clone(),
+                depth,
+                key,
+                value,
+                expanded: state,
+            });
+            if state == Some(true) {
+                Self::push_rows(rows, expanded, child, &path, depth + 1);
+            }
+        }
+    }
+
+    /// Flatten the tree into rows, keeping the selected row, if it's still shown
+    fn rebuild(&mut self) {
+        let selected = self.rows.get(self.selected).map(|i| i.path.clone());
+        let mut rows = vec![];
+        Self::push_rows(&mut rows, &self.expanded, &self.value, "", 0);
+        self.rows = rows;
+        self.selected = selected
+            .and_then(|path| self.rows.iter().position(|i| i.path == path))
+            .unwrap_or_default();
+    }
+
+    pub fn next(&mut self, count: usize) {
+        self.selected = self
+            .selected
+            .saturating_add(count)
+            .min(self.rows.len().saturating_sub(1));
+    }
+
+    pub const fn previous(&mut self, count: usize) {
+        self.selected = self.selected.saturating_sub(count);
+    }
+
+    pub const fn end(&mut self) {
+        self.selected = self.rows.len().saturating_sub(1);
+    }
+
+    /// Expand, or collapse, the selected node, collapsing a node that's already collapsed, or a scalar, selects its parent
+    pub fn expand(&mut self, expand: Option<bool>) {
+        let Some(row) = self.rows.get(self.selected) else {
+            return;
+        };
+        let path = row.path.clone();
+        match (row.expanded, expand) {
+            (Some(current), expand) if expand != Some(current) => {
+                if current {
+                    self.expanded.remove(&path);
+                } else {
+                    self.expanded.insert(path);
+                }
+            }
+            (_, Some(false)) => {
+                if let Some((parent, _)) = path.rsplit_once('/') {
+                    if let Some(index) = self.rows.iter().position(|i| i.path == parent) {
+                        self.selected = index;
+                    }
+                }
+            }
+            _ => (),
+        }
+        self.rebuild();
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::InspectView;
+
+    fn gen_view() -> InspectView {
+        let value = serde_json::json!({
+            "Name": "/api",
+            "Config": {"Env": ["A=1", "B=2"], "Tty": false},
+            "RestartCount": 0,
+        });
+        InspectView::new("api".to_owned(), value)
+    }
+
+    #[test]
+    /// Only the top level keys are shown, in key order, with the size of each object & array
+    fn test_inspect_view_new() {
+        let view = gen_view();
+        let rows = view
+            .rows
+            .iter()
+            .map(|i| (i.key.as_str(), i.value.as_str(), i.expanded))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            rows,
+            [
+                ("Config", "{2}", Some(false)),
+                ("Name", "\"/api\"", None),
+                ("RestartCount", "0", None),
+            ]
+        );
+    }
+
+    #[test]
+    /// Expanding shows the children, collapsing a collapsed node, or a scalar, selects its parent
+    fn test_inspect_view_expand() {
+        let mut view = gen_view();
+        view.expand(None);
+        view.next(1);
+        view.expand(Some(true));
+        let paths = view
+            .rows
+            .iter()
+            .map(|i| i.path.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            paths,
+            [
+                "Config",
+                "Config/Env",
+                "Config/Env/0",
+                "Config/Env/1",
+                "Config/Tty",
+                "Name",
+                "RestartCount"
+            ]
+        );
+        assert_eq!(view.rows[2].depth, 2);
+
+        view.next(1);
+        view.expand(Some(false));
+        assert_eq!(view.rows[view.selected].path, "Config/Env");
+        view.expand(Some(false));
+        assert_eq!(view.rows.len(), 5);
+        view.expand(Some(false));
+        assert_eq!(view.selected, 0);
+        view.expand(None);
+        assert_eq!(view.rows.len(), 3);
+
+        view.end();
+        view.next(5);
+        assert_eq!(view.selected, 2);
+        view.previous(10);
+        assert_eq!(view.selected, 0);
+    }
+}
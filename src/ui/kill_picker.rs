@@ -0,0 +1,52 @@
+use crate::app_data::{ContainerId, Signal};
+
+/// The signal to kill a container with, SIGTERM is selected, as it's the signal that `docker stop` sends first
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KillPicker {
+    pub id: ContainerId,
+    pub name: String,
+    pub selected: usize,
+}
+
+impl KillPicker {
+    pub const fn new(id: ContainerId, name: String) -> Self {
+        Self {
+            id,
+            name,
+            selected: 0,
+        }
+    }
+
+    pub fn next(&mut self) {
+        self.selected = (self.selected + 1).min(Signal::ALL.len() - 1);
+    }
+
+    pub const fn previous(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    pub const fn chosen(&self) -> Signal {
+        Signal::ALL[self.selected]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::KillPicker;
+    use crate::app_data::{ContainerId, Signal};
+
+    #[test]
+    /// SIGTERM is selected first, the selection stops at either end
+    fn test_kill_picker() {
+        let mut picker = KillPicker::new(ContainerId::from("1"), "web".to_owned());
+        assert_eq!(picker.chosen(), Signal::Term);
+        picker.previous();
+        assert_eq!(picker.chosen(), Signal::Term);
+        for _ in 0..Signal::ALL.len() {
+            picker.next();
+        }
+        assert_eq!(picker.chosen(), Signal::Hup);
+        picker.previous();
+        assert_eq!(picker.chosen(), Signal::Kill);
+    }
+}
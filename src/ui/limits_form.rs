@@ -0,0 +1,106 @@
+use crate::app_data::{parse_cpus, parse_memory, ContainerId, Limits};
+
+/// The cpu & memory limits of a running container, filled in with its current limits, an empty field leaves that limit unchanged
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LimitsForm {
+    pub cpus: String,
+    /// Why the limits couldn't be parsed
+    pub error: Option<String>,
+    pub id: ContainerId,
+    pub memory: String,
+    /// The memory field is being typed into, otherwise the cpus field
+    pub memory_selected: bool,
+    pub name: String,
+}
+
+impl LimitsForm {
+    pub fn new(id: ContainerId, name: String, limits: Limits) -> Self {
+        Self {
+            cpus: limits.cpus().unwrap_or_default(),
+            error: None,
+            id,
+            memory: limits.memory_text().unwrap_or_default(),
+            memory_selected: false,
+            name,
+        }
+    }
+
+    const fn field(&mut self) -> &mut String {
+        if self.memory_selected {
+            &mut self.memory
+        } else {
+            &mut self.cpus
+        }
+    }
+
+    pub fn push(&mut self, c: char) {
+        self.field().push(c);
+    }
+
+    pub fn pop(&mut self) {
+        self.field().pop();
+    }
+
+    pub const fn toggle(&mut self) {
+        self.memory_selected = !self.memory_selected;
+    }
+
+    /// The nano cpus, & memory bytes, to update, None if either field can't be parsed, or both are empty, with the reason set as the error
+    pub fn submit(&mut self) -> Option<(Option<u64>, Option<u64>)> {
+        let parsed = parse_cpus(&self.cpus).and_then(|cpus| {
+            let memory = parse_memory(&self.memory)?;
+            if cpus.is_none() && memory.is_none() {
+                Err("set the cpus, or the memory, limit".to_owned())
+            } else {
+                Ok((cpus, memory))
+            }
+        });
+        match parsed {
+            Ok(limits) => Some(limits),
+            Err(e) => {
+                self.error = Some(e);
+                None
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::LimitsForm;
+    use crate::app_data::{ContainerId, Limits};
+
+    #[test]
+    /// The form is filled in with the current limits, typing edits the selected field, & an invalid, or empty, form is an error
+    fn test_limits_form_submit() {
+        let limits = Limits {
+            nano_cpus: Some(500_000_000),
+            memory: Some(256 << 20),
+        };
+        let mut form = LimitsForm::new(ContainerId::from("1"), "web".to_owned(), limits);
+        assert_eq!(form.cpus, "0.5");
+        assert_eq!(form.memory, "256m");
+
+        form.push('5');
+        form.toggle();
+        form.pop();
+        form.pop();
+        form.push('g');
+        assert_eq!(form.submit().unwrap(), (Some(550_000_000), Some(25 << 30)));
+
+        form.memory = "lots".to_owned();
+        assert!(form.submit().is_none());
+        assert_eq!(
+            form.error.as_deref(),
+            Some("lots: expected a size of memory, e.g. 512m")
+        );
+
+        let mut form = LimitsForm::new(ContainerId::from("1"), "web".to_owned(), Limits::default());
+        assert!(form.submit().is_none());
+        assert_eq!(
+            form.error.as_deref(),
+            Some("set the cpus, or the memory, limit")
+        );
+    }
+}
@@ -0,0 +1,278 @@
+use serde_json::json;
+
+use super::timeline::csv_quote;
+use crate::app_data::{ContainerId, LogFilter, LogSearch};
+
+/// The format that logs are saved in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Text,
+    JsonLines,
+    Csv,
+}
+
+impl ExportFormat {
+    const ALL: [Self; 3] = [Self::Text, Self::JsonLines, Self::Csv];
+
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Text => "text",
+            Self::JsonLines => "json lines",
+            Self::Csv => "csv",
+        }
+    }
+
+    pub const fn extension(self) -> &'static str {
+        match self {
+            Self::Text => "log",
+            Self::JsonLines => "jsonl",
+            Self::Csv => "csv",
+        }
+    }
+}
+
+/// Which lines are saved, every line, or only those that the filter shows & the search matches
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportLines {
+    All,
+    Shown,
+}
+
+impl ExportLines {
+    const ALL: [Self; 2] = [Self::All, Self::Shown];
+
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::All => "every line",
+            Self::Shown => "filtered & searched lines",
+        }
+    }
+}
+
+/// How far back the saved logs reach
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportRange {
+    All,
+    Minutes(u64),
+}
+
+impl ExportRange {
+    const ALL: [Self; 4] = [
+        Self::All,
+        Self::Minutes(15),
+        Self::Minutes(60),
+        Self::Minutes(60 * 24),
+    ];
+
+    pub fn label(self) -> String {
+        match self {
+            Self::All => "all time".to_owned(),
+            Self::Minutes(x) if x % 60 == 0 => format!("last {}h", x / 60),
+            Self::Minutes(x) => format!("last {x}m"),
+        }
+    }
+
+    /// The unix timestamp, in seconds, that logs are requested since, 0 for every log
+    pub const fn since(self, now: u64) -> u64 {
+        match self {
+            Self::All => 0,
+            Self::Minutes(x) => now.saturating_sub(x * 60),
+        }
+    }
+}
+
+/// Choose the next, or previous, option, wrapping around at either end
+fn cycle<T: Copy + PartialEq>(options: &[T], current: T, forward: bool) -> T {
+    let index = options.iter().position(|i| *i == current).unwrap_or(0);
+    let next = if forward {
+        (index + 1) % options.len()
+    } else {
+        (index + options.len() - 1) % options.len()
+    };
+    options[next]
+}
+
+/// The save logs popup, the format, lines, & time range, chosen before the logs of a container are saved
+/// The filter & search are those of the container's logs when the popup was opened
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogExport {
+    filter: Option<LogFilter>,
+    pub format: ExportFormat,
+    pub id: ContainerId,
+    pub lines: ExportLines,
+    pub name: String,
+    pub range: ExportRange,
+    search: Option<LogSearch>,
+    /// The selected option, format, lines, then range
+    pub selected: usize,
+}
+
+impl LogExport {
+    /// Every line, of all time, as plain text, as saved before there was a choice
+    pub const fn new(
+        id: ContainerId,
+        name: String,
+        filter: Option<LogFilter>,
+        search: Option<LogSearch>,
+    ) -> Self {
+        Self {
+            filter,
+            format: ExportFormat::Text,
+            id,
+            lines: ExportLines::All,
+            name,
+            range: ExportRange::All,
+            search,
+            selected: 0,
+        }
+    }
+
+    pub fn next(&mut self) {
+        self.selected = (self.selected + 1).min(2);
+    }
+
+    pub const fn previous(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    /// Change the selected option
+    pub fn change(&mut self, forward: bool) {
+        match self.selected {
+            0 => self.format = cycle(&ExportFormat::ALL, self.format, forward),
+            1 => self.lines = cycle(&ExportLines::ALL, self.lines, forward),
+            _ => self.range = cycle(&ExportRange::ALL, self.range, forward),
+        }
+    }
+
+    /// The filter, or search, is active, so that saving only the shown lines differs from saving every line
+    pub fn has_shown(&self) -> bool {
+        self.filter.as_ref().is_some_and(LogFilter::is_active)
+            || self.search.as_ref().is_some_and(|i| !i.input.is_empty())
+    }
+
+    /// The line is saved, with ExportLines::Shown it must be shown by the filter, & match the search
+    pub fn keep(&self, line: &str) -> bool {
+        self.lines == ExportLines::All
+            || self.filter.as_ref().is_none_or(|i| i.is_match(line))
+                && self
+                    .search
+                    .as_ref()
+                    .filter(|i| !i.input.is_empty())
+                    .is_none_or(|i| i.is_match(line))
+    }
+
+    pub fn file_name(&self, now: u64) -> String {
+        format!("{}_{now}.{}", self.name, self.format.extension())
+    }
+
+    /// The first line of the file, the csv column names
+    pub fn header(&self) -> Option<&'static str> {
+        (self.format == ExportFormat::Csv).then_some("timestamp,stream,message\n")
+    }
+
+    /// Format a single log line, the timestamp is from the daemon, the text already has a trailing newline removed
+    pub fn format_line(&self, timestamp: Option<&str>, stream: &str, text: &str) -> String {
+        let timestamp = timestamp.unwrap_or_default();
+        match self.format {
+            ExportFormat::Text if timestamp.is_empty() => format!("{text}\n"),
+            ExportFormat::Text => format!("{timestamp} {text}\n"),
+            ExportFormat::JsonLines => format!(
+                "{}\n",
+                json!({"timestamp": timestamp, "stream": stream, "message": text})
+            ),
+            ExportFormat::Csv => format!(
+                "{},{},{}\n",
+                csv_quote(timestamp),
+                csv_quote(stream),
+                csv_quote(text)
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::{ExportFormat, ExportLines, ExportRange, LogExport};
+    use crate::app_data::{ContainerId, LogFilter, LogSearch};
+
+    fn gen_export() -> LogExport {
+        let mut search = LogSearch::new("db".to_owned(), false);
+        search.scan_all(std::iter::empty());
+        LogExport::new(
+            ContainerId::from("1"),
+            "api".to_owned(),
+            Some(LogFilter::new("error".to_owned())),
+            Some(search),
+        )
+    }
+
+    #[test]
+    /// Options are changed on the selected row, wrapping around, & the extension follows the format
+    fn test_log_export_change() {
+        let mut export = gen_export();
+        assert_eq!(export.file_name(10), "api_10.log");
+        export.change(false);
+        assert_eq!(export.format, ExportFormat::Csv);
+        export.change(false);
+        assert_eq!(export.file_name(10), "api_10.jsonl");
+
+        export.next();
+        export.change(true);
+        assert_eq!(export.lines, ExportLines::Shown);
+        export.next();
+        export.next();
+        export.change(true);
+        export.change(true);
+        assert_eq!(export.range, ExportRange::Minutes(60));
+        assert_eq!(export.range.label(), "last 1h");
+        assert_eq!(export.range.since(10_000), 6400);
+        assert_eq!(ExportRange::Minutes(15).label(), "last 15m");
+        assert_eq!(ExportRange::All.since(10_000), 0);
+        export.previous();
+        assert_eq!(export.selected, 1);
+    }
+
+    #[test]
+    /// Only shown lines must match both the filter & the search
+    fn test_log_export_keep() {
+        let mut export = gen_export();
+        assert!(export.has_shown());
+        assert!(export.keep("info: started"));
+        export.lines = ExportLines::Shown;
+        assert!(export.keep("error: db timeout"));
+        assert!(!export.keep("error: disk full"));
+        assert!(!export.keep("info: db connected"));
+
+        let mut export = LogExport::new(ContainerId::from("1"), "api".to_owned(), None, None);
+        export.lines = ExportLines::Shown;
+        assert!(!export.has_shown());
+        assert!(export.keep("anything"));
+    }
+
+    #[test]
+    /// Each format escapes the message as needed, csv has a header
+    fn test_log_export_format_line() {
+        let mut export = gen_export();
+        let timestamp = Some("2024-01-01T00:00:00Z");
+        assert_eq!(export.header(), None);
+        assert_eq!(
+            export.format_line(timestamp, "stdout", "a \"quote\", here"),
+            "2024-01-01T00:00:00Z a \"quote\", here\n"
+        );
+        assert_eq!(export.format_line(None, "stdout", "plain"), "plain\n");
+
+        export.format = ExportFormat::JsonLines;
+        assert_eq!(
+            export.format_line(timestamp, "stderr", "a \"quote\", here"),
+            "{\"message\":\"a \\\"quote\\\", here\",\"stream\":\"stderr\",\"timestamp\":\"2024-01-01T00:00:00Z\"}\n"
+        );
+
+        export.format = ExportFormat::Csv;
+        assert_eq!(export.header(), Some("timestamp,stream,message\n"));
+        assert_eq!(
+            export.format_line(timestamp, "stdout", "a \"quote\", here"),
+            "2024-01-01T00:00:00Z,stdout,\"a \"\"quote\"\", here\"\n"
+        );
+    }
+}
@@ -0,0 +1,94 @@
+/// Lines of context shown either side of a match, when the context is first shown
+const DEFAULT_CONTEXT: usize = 5;
+
+/// Most lines of context that can be shown either side of a match
+const MAX_CONTEXT: usize = 50;
+
+/// The selected match of the log matches view, & the lines of context shown around it in the container's logs, as with `grep -C`
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct MatchView {
+    /// Lines shown before, & after, the selected match, None hides the context
+    pub context: Option<usize>,
+    /// Index into the matches, oldest first, None follows the newest match
+    selected: Option<usize>,
+}
+
+impl MatchView {
+    /// The selected match, the newest if none has been selected, or the selected match has since been removed
+    pub fn selected(&self, len: usize) -> Option<usize> {
+        self.selected
+            .filter(|i| *i < len)
+            .or_else(|| len.checked_sub(1))
+    }
+
+    /// Select the next oldest match
+    pub fn previous(&mut self, len: usize) {
+        if let Some(i) = self.selected(len) {
+            self.selected = Some(i.saturating_sub(1));
+        }
+    }
+
+    /// Select the next newest match, selecting the newest follows new matches again
+    pub fn next(&mut self, len: usize) {
+        self.selected = self.selected(len).map(|i| i + 1).filter(|i| i + 1 < len);
+    }
+
+    /// Show, or hide, the lines logged around the selected match
+    pub const fn toggle_context(&mut self) {
+        self.context = match self.context {
+            Some(_) => None,
+            None => Some(DEFAULT_CONTEXT),
+        };
+    }
+
+    /// Show more, or fewer, lines of context, at least a single line is shown
+    pub fn change_context(&mut self, more: bool) {
+        if let Some(context) = self.context.as_mut() {
+            *context = if more {
+                (*context + 1).min(MAX_CONTEXT)
+            } else {
+                context.saturating_sub(1).max(1)
+            };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MatchView, DEFAULT_CONTEXT};
+
+    #[test]
+    /// The newest match is followed until an older match is selected, & the context is kept within its limits
+    fn test_match_view() {
+        let mut view = MatchView::default();
+        assert_eq!(view.selected(0), None);
+        assert_eq!(view.selected(4), Some(3));
+        view.previous(4);
+        view.previous(4);
+        assert_eq!(view.selected(4), Some(1));
+        assert_eq!(view.selected(6), Some(1));
+        view.previous(4);
+        view.previous(4);
+        assert_eq!(view.selected(4), Some(0));
+        view.next(4);
+        view.next(4);
+        view.next(4);
+        assert_eq!(view.selected(4), Some(3));
+        assert_eq!(view.selected(6), Some(5));
+        view.previous(6);
+        assert_eq!(view.selected(2), Some(1));
+
+        view.change_context(true);
+        assert!(view.context.is_none());
+        view.toggle_context();
+        assert_eq!(view.context, Some(DEFAULT_CONTEXT));
+        for _ in 0..10 {
+            view.change_context(false);
+        }
+        assert_eq!(view.context, Some(1));
+        view.change_context(true);
+        assert_eq!(view.context, Some(2));
+        view.toggle_context();
+        assert!(view.context.is_none());
+    }
+}
@@ -1,54 +1,143 @@
 use anyhow::Result;
 use crossterm::{
-    event::{self, DisableMouseCapture, Event},
+    event::{
+        self, DisableFocusChange, DisableMouseCapture, EnableFocusChange, Event, KeyEventKind,
+    },
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
 use parking_lot::{Mutex, MutexGuard};
 use ratatui::{
     backend::CrosstermBackend,
+    buffer::Buffer,
     layout::{Constraint, Direction, Layout},
     Frame, Terminal,
 };
 use std::{
-    io::{self, Stdout, Write},
+    fs::File,
+    io::{self, BufWriter, Stdout, Write},
     sync::{atomic::Ordering, Arc},
-    time::Duration,
+    time::{Duration, SystemTime, UNIX_EPOCH},
 };
 use std::{sync::atomic::AtomicBool, time::Instant};
 use tokio::sync::mpsc::Sender;
 use tracing::error;
 
+mod bell;
+mod build_cache;
+mod capture;
+mod cleanup;
 mod color_match;
+mod command_form;
+mod context_picker;
 mod draw_blocks;
+mod file_browser;
+mod finder;
 mod gui_state;
-
+mod health_view;
+mod host_picker;
+mod image_detail;
+mod inspect_diff;
+mod inspect_view;
+mod kill_picker;
+mod limits_form;
+mod log_export;
+mod match_view;
+mod notify;
+mod palette;
+mod pane_layout;
+mod port_view;
+mod prune;
+mod pull_progress;
+mod reach;
+mod recent_picker;
+mod recorder;
+mod recreate;
+mod rename_form;
+mod run_form;
+mod scale_form;
+mod secret_scan;
+mod snapshot;
+mod status_bar;
+mod task_logs;
+mod theme;
+mod timeline;
+
+pub use self::bell::Bell;
+pub use self::build_cache::{BuildCache, CacheRecord};
+pub use self::capture::Capture;
+pub use self::cleanup::{Cleanup, CleanupKind, CleanupRow, DiskUsage};
 pub use self::color_match::*;
-pub use self::gui_state::{DeleteButton, GuiState, SelectablePanel, Status};
+pub use self::command_form::{CommandField, CommandForm};
+pub use self::context_picker::ContextPicker;
+pub use self::draw_blocks::format_duration;
+pub use self::file_browser::{FileBrowser, FileEntry};
+pub use self::finder::{Finder, FinderField, FinderRow};
+pub use self::gui_state::{
+    DeleteButton, DeletePreview, FailedControl, FilterForm, GuiState, NoteForm, ScheduleForm,
+    SelectablePanel, Status, StdinForm,
+};
+pub use self::health_view::{HealthProbe, HealthView};
+pub use self::host_picker::{HostPicker, HostRow};
+pub use self::image_detail::ImageDetail;
+pub use self::inspect_diff::{DiffLine, DiffRow, DiffSection, InspectDiff};
+pub use self::inspect_view::InspectView;
+pub use self::kill_picker::KillPicker;
+pub use self::limits_form::LimitsForm;
+pub use self::log_export::{ExportFormat, LogExport};
+pub use self::match_view::MatchView;
+pub use self::notify::{NotifyEvent, NotifyOsc};
+pub use self::palette::Palette;
+pub use self::pane_layout::{Pane, PaneLayout, CHARTS_HEIGHT};
+pub use self::port_view::{PortRow, PortView};
+pub use self::prune::{PruneImage, PrunePreview};
+pub use self::pull_progress::{PullLayer, PullProgress};
+pub use self::reach::{ReachPicker, ReachTarget};
+pub use self::recent_picker::{RecentPicker, RecentRow};
+pub use self::recorder::cast_time;
+pub use self::recreate::{EnvOrigin, FieldKind, PortSpec, RecreateForm, TagPicker};
+pub use self::rename_form::RenameForm;
+pub use self::run_form::{Restart, RunForm};
+pub use self::scale_form::ScaleForm;
+pub use self::secret_scan::{SecretFinding, SecretKind, SecretScan};
+pub use self::status_bar::DEFAULT_TEMPLATE as DEFAULT_STATUS_BAR;
+pub use self::task_logs::TaskLogs;
+pub use self::theme::{StateColors, Theme, ThemePreset};
+pub use self::timeline::{csv_quote, Timeline, TimelineEvent, TimelineKind, TIMELINE_SPAN};
+use self::{recorder::Recorder, status_bar::StatusValues};
 use crate::{
-    app_data::{AppData, Columns, ContainerId, Header, SortedOrder},
+    app_data::{
+        AppData, Columns, ContainerId, DockerControls, GroupBy, Header, IoView, SortedOrder,
+        TopMode,
+    },
     app_error::AppError,
+    config_file::ConfigError,
     exec::TerminalSize,
     input_handler::InputMessages,
 };
 
 pub struct Ui {
     app_data: Arc<Mutex<AppData>>,
+    /// The screen is flashed, by the visual bell, until then
+    flash_until: Option<Instant>,
     gui_state: Arc<Mutex<GuiState>>,
     input_poll_rate: Duration,
     input_tx: Sender<InputMessages>,
     is_running: Arc<AtomicBool>,
     now: Instant,
+    recorder: Option<Recorder<BufWriter<File>>>,
     terminal: Terminal<CrosstermBackend<Stdout>>,
     cursor_position: (u16, u16),
 }
 
 impl Ui {
     /// Enable mouse capture, but don't enable capture of all the mouse movements, doing so will improve performance, and is part of the fix for the weird mouse event output bug
+    /// Movement is only captured while a button is held, so that the border between the containers, & the logs, can be dragged
     pub fn enable_mouse_capture() -> Result<()> {
         Ok(io::stdout().write_all(
             concat!(
                 crossterm::csi!("?1000h"),
+                crossterm::csi!("?1002h"),
                 crossterm::csi!("?1015h"),
                 crossterm::csi!("?1006h"),
             )
@@ -63,17 +152,25 @@ impl Ui {
         input_tx: Sender<InputMessages>,
         is_running: Arc<AtomicBool>,
     ) {
-        if let Ok(mut terminal) = Self::setup_terminal() {
-            // let args = app_data.lock().args.clone();
+        let mouse = app_data.lock().args.mouse;
+        if let Ok(mut terminal) = Self::setup_terminal(mouse) {
+            let record = app_data.lock().args.record.clone();
+            let recorder = record.and_then(|path| {
+                Recorder::create(&path)
+                    .map_err(|e| error!("Unable to record to {}: {e}", path.display()))
+                    .ok()
+            });
             let cursor_position = terminal.get_cursor().unwrap_or_default();
             let mut ui = Self {
                 app_data,
+                flash_until: None,
                 cursor_position,
                 gui_state,
                 input_poll_rate: std::time::Duration::from_millis(100),
                 input_tx,
                 is_running,
                 now: Instant::now(),
+                recorder,
                 terminal,
             };
             if let Err(e) = ui.draw_ui().await {
@@ -87,18 +184,20 @@ impl Ui {
         }
     }
 
-    /// Setup the terminal for full-screen drawing mode, with mouse capture
-    fn setup_terminal() -> Result<Terminal<CrosstermBackend<Stdout>>> {
-        let stdout = Self::init_terminal()?;
+    /// Setup the terminal for full-screen drawing mode, with mouse capture, unless `--no-mouse`
+    fn setup_terminal(mouse: bool) -> Result<Terminal<CrosstermBackend<Stdout>>> {
+        let stdout = Self::init_terminal(mouse)?;
         let backend = CrosstermBackend::new(stdout);
         Ok(Terminal::new(backend)?)
     }
 
-    fn init_terminal() -> Result<Stdout> {
+    fn init_terminal(mouse: bool) -> Result<Stdout> {
         enable_raw_mode()?;
         let mut stdout = io::stdout();
-        execute!(stdout, EnterAlternateScreen)?;
-        Self::enable_mouse_capture()?;
+        execute!(stdout, EnterAlternateScreen, EnableFocusChange)?;
+        if mouse {
+            Self::enable_mouse_capture()?;
+        }
         Ok(stdout)
     }
 
@@ -109,7 +208,8 @@ impl Ui {
         execute!(
             self.terminal.backend_mut(),
             LeaveAlternateScreen,
-            DisableMouseCapture
+            DisableMouseCapture,
+            DisableFocusChange
         )?;
         disable_raw_mode()?;
         self.terminal.clear().ok();
@@ -118,9 +218,33 @@ impl Ui {
         Ok(self.terminal.show_cursor()?)
     }
 
+    /// Show a config file error on a screen of its own, until a key is pressed, as the config file is read before the gui, & its theme, are set up
+    pub fn config_error(error: &ConfigError) -> Result<()> {
+        let mut terminal = Self::setup_terminal(false)?;
+        let theme = Theme::default();
+        let mut show = || -> Result<()> {
+            loop {
+                terminal.draw(|f| draw_blocks::config_error(f, error, &theme))?;
+                if matches!(event::read()?, Event::Key(key) if key.kind == KeyEventKind::Press) {
+                    return Ok(());
+                }
+            }
+        };
+        let result = show();
+        execute!(
+            terminal.backend_mut(),
+            LeaveAlternateScreen,
+            DisableFocusChange
+        )?;
+        disable_raw_mode()?;
+        terminal.show_cursor()?;
+        result
+    }
+
     /// Draw the the error message ui, for 5 seconds, with a countdown
     fn err_loop(&mut self) -> Result<(), AppError> {
         let mut seconds = 5;
+        let theme = self.app_data.lock().args.theme;
         loop {
             if self.now.elapsed() >= std::time::Duration::from_secs(1) {
                 seconds -= 1;
@@ -132,7 +256,7 @@ impl Ui {
 
             if self
                 .terminal
-                .draw(|f| draw_blocks::error(f, AppError::DockerConnect, Some(seconds)))
+                .draw(|f| draw_blocks::error(f, AppError::DockerConnect, Some(seconds), &theme))
                 .is_err()
             {
                 return Err(AppError::Terminal);
@@ -156,24 +280,61 @@ impl Ui {
         }
         self.terminal.clear().ok();
         self.reset_terminal().ok();
-        Self::init_terminal().ok();
+        let mouse = self.app_data.lock().args.mouse;
+        Self::init_terminal(mouse).ok();
         self.gui_state.lock().status_del(Status::Exec);
     }
 
+    /// Save the given frame buffer, as text & html, into the save_dir
+    fn save_snapshot(&self, buffer: &Buffer) {
+        let save_dir = self.app_data.lock().args.save_dir.clone();
+        if let Some(dir) = save_dir {
+            let now = SystemTime::now()
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .map_or(0, |i| i.as_secs());
+            match snapshot::save(buffer, &dir, now) {
+                Ok(path) => self
+                    .gui_state
+                    .lock()
+                    .set_info_box(&format!("snapshot saved to {} & .html", path.display())),
+                Err(_) => {
+                    self.app_data.lock().set_error(
+                        AppError::Snapshot,
+                        &self.gui_state,
+                        Status::Error,
+                    );
+                }
+            }
+        }
+    }
+
     /// The loop for drawing the main UI to the terminal
+    /// In standby, a single frame is drawn, with the standby notice, and then drawing is paused until standby ends
     async fn gui_loop(&mut self) -> Result<(), AppError> {
+        let mut standby_drawn = false;
         while self.is_running.load(Ordering::SeqCst) {
+            // Docker is connected to in the background, so that cached containers can be shown straight away, and the connection can fail after the gui has started
+            let docker_connect = self
+                .gui_state
+                .lock()
+                .status_contains(&[Status::DockerConnect]);
+            if docker_connect {
+                return self.err_loop();
+            }
             let exec = self.gui_state.lock().status_contains(&[Status::Exec]);
             if exec {
                 self.exec().await;
             }
 
-            if self
-                .terminal
-                .draw(|frame| draw_frame(frame, &self.app_data, &self.gui_state))
-                .is_err()
-            {
-                return Err(AppError::Terminal);
+            let standby = self.gui_state.lock().status_contains(&[Status::Standby]);
+            let buffer = if standby && standby_drawn {
+                None
+            } else {
+                standby_drawn = standby;
+                self.draw()?
+            };
+            if let Some(buffer) = buffer {
+                self.save_snapshot(&buffer);
             }
 
             if crossterm::event::poll(self.input_poll_rate).unwrap_or(false) {
@@ -186,6 +347,8 @@ impl Ui {
                     } else if let Event::Mouse(m) = event {
                         match m.kind {
                             event::MouseEventKind::Down(_)
+                            | event::MouseEventKind::Drag(_)
+                            | event::MouseEventKind::Up(_)
                             | event::MouseEventKind::ScrollDown
                             | event::MouseEventKind::ScrollUp => {
                                 self.input_tx.send(InputMessages::MouseEvent(m)).await.ok();
@@ -195,6 +358,12 @@ impl Ui {
                     } else if let Event::Resize(_, _) = event {
                         self.gui_state.lock().clear_area_map();
                         self.terminal.autoresize().ok();
+                        standby_drawn = false;
+                    } else if let Event::FocusGained | Event::FocusLost = event {
+                        self.input_tx
+                            .send(InputMessages::Focus(event == Event::FocusGained))
+                            .await
+                            .ok();
                     }
                 }
             }
@@ -202,6 +371,69 @@ impl Ui {
         Ok(())
     }
 
+    /// Ring the terminal bell, and start the flash of the screen, for an alert that fired since the last frame, as set by `--bell`
+    /// The `--notify` notifications are sent to the terminal too, each rings the bell, so that a terminal multiplexer marks the window that oxker is in
+    fn ring_bell(&mut self) {
+        let (rung, notifications) = {
+            let mut gui_state = self.gui_state.lock();
+            (gui_state.take_bell(), gui_state.take_notifications())
+        };
+        let (bell, osc) = {
+            let app_data = self.app_data.lock();
+            (
+                app_data.args.bell.filter(|_| rung),
+                app_data.args.notify_osc,
+            )
+        };
+        let tmux = std::env::var_os("TMUX").is_some();
+        let mut output = notifications
+            .iter()
+            .flat_map(|i| notify::sequence(osc, i, tmux))
+            .collect::<Vec<_>>();
+        if !notifications.is_empty() || bell.is_some_and(Bell::audible) {
+            output.extend_from_slice(bell::BEL);
+        }
+        if !output.is_empty() {
+            let backend = self.terminal.backend_mut();
+            if backend
+                .write_all(&output)
+                .and_then(|()| backend.flush())
+                .is_err()
+            {
+                error!("Unable to ring the terminal bell");
+            }
+        }
+        if bell.is_some_and(Bell::visual) {
+            self.flash_until = Some(Instant::now() + bell::FLASH);
+        }
+    }
+
+    /// Draw a frame, recording it if recording, returns the frame buffer if a snapshot has been requested
+    fn draw(&mut self) -> Result<Option<Buffer>, AppError> {
+        self.ring_bell();
+        let flash = self.flash_until.is_some_and(|i| i > Instant::now());
+        let frame = self
+            .terminal
+            .draw(|frame| {
+                draw_frame(frame, &self.app_data, &self.gui_state);
+                if flash {
+                    bell::flash(frame.buffer_mut());
+                }
+            })
+            .map_err(|_| AppError::Terminal)?;
+        if let Some(recorder) = self.recorder.as_mut() {
+            if let Err(e) = recorder.frame(frame.buffer) {
+                error!("Unable to record frame: {e}");
+                self.recorder = None;
+            }
+        }
+        Ok(self
+            .gui_state
+            .lock()
+            .take_snapshot_request()
+            .then(|| frame.buffer.clone()))
+    }
+
     /// Draw either the Error, or main oxker ui, to the terminal
     async fn draw_ui(&mut self) -> Result<(), AppError> {
         let status_dockerconnect = self
@@ -219,53 +451,251 @@ impl Ui {
 
 /// Frequent data required by multiple framde drawing functions, can reduce mutex reads by placing it all in here
 #[derive(Debug)]
+#[allow(clippy::struct_excessive_bools)]
 pub struct FrameData {
+    theme: Theme,
+    build_cache: bool,
+    capture: bool,
+    cleanup: bool,
+    clock_skew: Option<i64>,
     columns: Columns,
-    delete_confirm: Option<ContainerId>,
+    command: bool,
+    confirm: Option<(DockerControls, ContainerId)>,
+    batch_confirm: Option<(DockerControls, Vec<ContainerId>)>,
+    /// The recent controls, from elsewhere, of the containers in the confirm dialog
+    concurrent: Vec<String>,
+    daemon: bool,
+    events: bool,
+    expanded_log: bool,
+    failed: bool,
+    files: bool,
+    finder: bool,
+    group_by: Option<GroupBy>,
+    contexts: bool,
+    cores: bool,
     has_containers: bool,
     has_error: Option<AppError>,
+    health: bool,
     height: u16,
     help_visible: bool,
+    host: String,
+    host_usage: Option<String>,
+    hosts: bool,
+    image_detail: bool,
     init: bool,
+    inspect: bool,
+    inspect_diff: bool,
+    io_view: Option<IoView>,
     info_text: Option<(String, Instant)>,
+    kill: bool,
+    label_columns: Vec<String>,
+    layout: PaneLayout,
+    layout_pane: Option<Pane>,
+    latency: Option<Duration>,
+    limits: bool,
     loading_icon: String,
+    log_export: bool,
+    log_matches: Option<MatchView>,
+    memory: bool,
+    note: bool,
+    now: u64,
+    ports: bool,
+    prune: bool,
+    pull: bool,
+    reach: bool,
+    read_only: bool,
+    recent: bool,
+    recreate: bool,
+    rename: bool,
+    run: bool,
+    scale: bool,
+    schedules: bool,
+    filters: bool,
+    secret_scan: bool,
+    refresh_stretch: Option<Duration>,
+    resources: Option<SelectablePanel>,
     selected_panel: SelectablePanel,
     sorted_by: Option<(Header, SortedOrder)>,
+    stale_limit: u64,
+    standby: bool,
+    state_colors: StateColors,
+    status_bar: Option<String>,
+    stdin: bool,
+    tags: bool,
+    task_logs: bool,
+    timeline: bool,
+    top_mode: Option<TopMode>,
+    traffic: bool,
 }
 
 impl From<(MutexGuard<'_, AppData>, MutexGuard<'_, GuiState>)> for FrameData {
     fn from(data: (MutexGuard<'_, AppData>, MutexGuard<'_, GuiState>)) -> Self {
-        // set max height for container section, needs +5 to deal with docker commands list and borders
-        let height = data.0.get_container_len();
-        let height = if height < 12 {
-            u16::try_from(height + 5).unwrap_or_default()
-        } else {
-            12
-        };
+        // set max height for container section, needs +5 to deal with docker commands list and borders, unless the border has been dragged
+        let height = data.0.get_container_rows().len();
+        let height = data.1.get_layout().containers.unwrap_or_else(|| {
+            if height < 12 {
+                u16::try_from(height + 5).unwrap_or_default()
+            } else {
+                12
+            }
+        });
 
         Self {
+            theme: data.0.args.theme,
+            build_cache: data.1.status_contains(&[Status::BuildCache]),
+            capture: data.1.status_contains(&[Status::Capture]),
+            cleanup: data.1.status_contains(&[Status::Cleanup]),
             columns: data.0.get_width(),
-            delete_confirm: data.1.get_delete_container(),
+            command: data.1.status_contains(&[Status::Command]),
+            clock_skew: data.0.get_clock_skew(),
+            confirm: data.1.get_confirm(),
+            batch_confirm: data.1.get_batch_confirm(),
+            concurrent: data
+                .1
+                .get_confirm()
+                .map(|(_, id)| vec![id])
+                .or_else(|| data.1.get_batch_confirm().map(|(_, ids)| ids))
+                .unwrap_or_default()
+                .iter()
+                .filter_map(|id| data.0.concurrent_event(id))
+                .map(|(event, ago)| format!("{event} {ago}s ago, not by this oxker"))
+                .collect(),
+            daemon: data.1.status_contains(&[Status::Daemon]),
+            events: data.1.status_contains(&[Status::Events]),
+            expanded_log: data.1.status_contains(&[Status::ExpandedLog]),
+            failed: data.1.status_contains(&[Status::Failed]),
+            files: data.1.status_contains(&[Status::Files]),
+            finder: data.1.status_contains(&[Status::Finder]),
+            group_by: data.0.get_group_by(),
             has_containers: data.0.get_container_len() > 0,
             has_error: data.0.get_error(),
+            health: data.1.status_contains(&[Status::Health]),
+            contexts: data.1.status_contains(&[Status::Contexts]),
+            cores: data.1.status_contains(&[Status::Cores]),
             height,
+            layout: data.1.get_layout(),
+            layout_pane: data.1.get_layout_pane(),
             help_visible: data.1.status_contains(&[Status::Help]),
+            image_detail: data.1.status_contains(&[Status::ImageDetail]),
+            host: data.0.get_host().to_owned(),
+            host_usage: data.0.get_host_usage().map(|i| i.to_string()),
+            hosts: data.1.status_contains(&[Status::Hosts]),
             init: data.1.status_contains(&[Status::Init]),
+            inspect: data.1.status_contains(&[Status::Inspect]),
+            inspect_diff: data.1.status_contains(&[Status::Compare]),
+            io_view: data.0.get_io_view(),
             info_text: data.1.info_box_text.clone(),
+            kill: data.1.status_contains(&[Status::Kill]),
+            label_columns: data.0.args.label_columns.clone(),
+            latency: data.0.get_latency(),
+            limits: data.1.status_contains(&[Status::Limits]),
             loading_icon: data.1.get_loading().to_string(),
+            log_export: data.1.status_contains(&[Status::LogExport]),
+            log_matches: data
+                .1
+                .status_contains(&[Status::LogMatches])
+                .then(|| data.1.get_match_view()),
+            memory: data.1.status_contains(&[Status::Memory]),
+            note: data.1.status_contains(&[Status::Note]),
+            now: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |i| i.as_secs()),
+            ports: data.1.status_contains(&[Status::Ports]),
+            prune: data.1.status_contains(&[Status::Prune]),
+            pull: data.1.status_contains(&[Status::Pull]),
+            reach: data.1.status_contains(&[Status::Reach]),
+            read_only: data.0.args.read_only,
+            recent: data.1.status_contains(&[Status::Recent]),
+            recreate: data.1.status_contains(&[Status::Recreate]),
+            rename: data.1.status_contains(&[Status::Rename]),
+            run: data.1.status_contains(&[Status::Run]),
+            scale: data.1.status_contains(&[Status::Scale]),
+            schedules: data.1.status_contains(&[Status::Schedules]),
+            filters: data.1.status_contains(&[Status::Filters]),
+            secret_scan: data.1.status_contains(&[Status::SecretScan]),
+            refresh_stretch: data.0.get_refresh_stretch(),
+            resources: data.1.get_resources_panel(),
             selected_panel: data.1.get_selected_panel(),
-            sorted_by: data.0.get_sorted(),
+            sorted_by: data.0.get_active_sort(),
+            stale_limit: data.0.get_stale_limit(),
+            standby: data.1.status_contains(&[Status::Standby]),
+            state_colors: data.0.args.state_colors,
+            status_bar: Self::status_bar(&data.0),
+            stdin: data.1.status_contains(&[Status::Stdin]),
+            tags: data.1.status_contains(&[Status::Tags]),
+            task_logs: data.1.status_contains(&[Status::TaskLogs]),
+            timeline: data.1.status_contains(&[Status::Timeline]),
+            top_mode: data.0.get_top_mode(),
+            traffic: data.1.status_contains(&[Status::Traffic]),
         }
     }
 }
 
+impl FrameData {
+    /// Render the status bar template, if one has been set
+    fn status_bar(app_data: &AppData) -> Option<String> {
+        app_data.args.status_bar.as_ref().map(|template| {
+            let mut alerts = vec![];
+            if let Some(skew) = app_data.get_clock_skew() {
+                alerts.push(format!("daemon clock {}", draw_blocks::format_skew(skew)));
+            }
+            if let Some(interval) = app_data.get_refresh_stretch() {
+                alerts.push(format!(
+                    "api limit, refreshing every {}s",
+                    interval.as_secs()
+                ));
+            }
+            match app_data.get_daemon_warnings_len() {
+                0 => (),
+                1 => alerts.push("1 daemon warning".to_owned()),
+                x => alerts.push(format!("{x} daemon warnings")),
+            }
+            if let Some(error) = app_data.get_error() {
+                alerts.push(error.to_string());
+            }
+            match app_data.get_unseen_matches() {
+                0 => (),
+                1 => alerts.push("1 log match".to_owned()),
+                x => alerts.push(format!("{x} log matches")),
+            }
+            match app_data.get_restart_storms_len() {
+                0 => (),
+                1 => alerts.push("1 restart storm".to_owned()),
+                x => alerts.push(format!("{x} restart storms")),
+            }
+            match app_data.get_rw_over_len() {
+                0 => (),
+                1 => alerts.push("1 writable layer over the disk alert".to_owned()),
+                x => alerts.push(format!("{x} writable layers over the disk alert")),
+            }
+            let (running, total) = app_data.get_shown_len();
+            StatusValues {
+                alerts,
+                host: app_data.get_host().to_owned(),
+                latency: app_data.get_latency(),
+                refresh: app_data.get_last_update(),
+                resources: app_data.get_host_usage().map(|i| i.to_string()),
+                running,
+                total,
+            }
+            .render(template)
+        })
+    }
+}
+
 /// Draw the main ui to a frame of the terminal
+#[allow(clippy::too_many_lines)]
 fn draw_frame(f: &mut Frame, app_data: &Arc<Mutex<AppData>>, gui_state: &Arc<Mutex<GuiState>>) {
     let fd = FrameData::from((app_data.lock(), gui_state.lock()));
 
+    let whole_split = if fd.status_bar.is_some() {
+        vec![Constraint::Max(1), Constraint::Min(1), Constraint::Max(1)]
+    } else {
+        vec![Constraint::Max(1), Constraint::Min(1)]
+    };
     let whole_layout = Layout::default()
         .direction(Direction::Vertical)
-        .constraints([Constraint::Max(1), Constraint::Min(1)].as_ref())
+        .constraints(whole_split)
         .split(f.size());
 
     // Split into 3, containers+controls, logs, then graphs
@@ -286,8 +716,11 @@ fn draw_frame(f: &mut Frame, app_data: &Arc<Mutex<AppData>>, gui_state: &Arc<Mut
         .constraints(top_split)
         .split(upper_main[0]);
 
-    let lower_split = if fd.has_containers {
-        vec![Constraint::Percentage(70), Constraint::Percentage(30)]
+    let lower_split = if fd.has_containers && !fd.layout.charts_hidden {
+        vec![
+            Constraint::Percentage(100 - fd.layout.charts),
+            Constraint::Percentage(fd.layout.charts),
+        ]
     } else {
         vec![Constraint::Percentage(100)]
     };
@@ -300,27 +733,81 @@ fn draw_frame(f: &mut Frame, app_data: &Arc<Mutex<AppData>>, gui_state: &Arc<Mut
 
     draw_blocks::containers(app_data, top_panel[0], f, &fd, gui_state);
 
-    draw_blocks::logs(app_data, lower_main[0], f, &fd, gui_state);
+    // The images, volumes, networks, or services panel, and the events pane, are shown beside the logs, one above the other when both are shown
+    if fd.resources.is_some() || fd.events {
+        let logs_split = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Min(1), Constraint::Percentage(40)])
+            .split(lower_main[0]);
+        draw_blocks::logs(app_data, logs_split[0], f, &fd, gui_state);
+        let side_split = if fd.resources.is_some() && fd.events {
+            vec![Constraint::Percentage(50), Constraint::Percentage(50)]
+        } else {
+            vec![Constraint::Percentage(100)]
+        };
+        let side = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(side_split)
+            .split(logs_split[1]);
+        match fd.resources {
+            Some(SelectablePanel::Volumes) => {
+                draw_blocks::volumes(app_data, side[0], f, &fd, gui_state);
+            }
+            Some(SelectablePanel::Networks) => {
+                draw_blocks::networks(app_data, side[0], f, &fd, gui_state);
+            }
+            Some(SelectablePanel::Services) => {
+                draw_blocks::services(app_data, side[0], f, &fd, gui_state);
+            }
+            Some(_) => draw_blocks::images(app_data, side[0], f, &fd, gui_state),
+            None => (),
+        }
+        if fd.events {
+            draw_blocks::events(f, side[side.len() - 1], app_data, fd.now);
+        }
+    } else {
+        draw_blocks::logs(app_data, lower_main[0], f, &fd, gui_state);
+    }
 
     draw_blocks::heading_bar(whole_layout[0], f, &fd, gui_state);
 
-    if let Some(id) = fd.delete_confirm.as_ref() {
+    if let Some(text) = fd.status_bar.as_ref() {
+        draw_blocks::status_bar(whole_layout[2], f, text, &fd.theme);
+    }
+
+    if let Some((control, id)) = fd.confirm.as_ref() {
         app_data.lock().get_container_name_by_id(id).map_or_else(
             || {
-                // If a container is deleted outside of oxker but whilst the Delete Confirm dialog is open, it can get caught in kind of a dead lock situation
-                // so if in that unique situation, just clear the delete_container id
-                gui_state.lock().set_delete_container(None);
+                // If a container is deleted outside of oxker but whilst the Confirm dialog is open, it can get caught in kind of a dead lock situation
+                // so if in that unique situation, just clear the confirm
+                gui_state.lock().set_confirm(None);
             },
             |name| {
-                draw_blocks::delete_confirm(f, gui_state, &name);
+                draw_blocks::confirm(f, gui_state, *control, &[name], &fd.concurrent, &fd.theme);
             },
         );
     }
 
-    // only draw commands + charts if there are containers
+    if let Some((control, ids)) = fd.batch_confirm.as_ref() {
+        let names = {
+            let mut app_data = app_data.lock();
+            ids.iter()
+                .filter_map(|id| app_data.get_container_name_by_id(id))
+                .collect::<Vec<_>>()
+        };
+        // Every marked container has been deleted outside of oxker, whilst the dialog was open
+        if names.is_empty() {
+            gui_state.lock().set_batch_confirm(None);
+        } else {
+            draw_blocks::confirm(f, gui_state, *control, &names, &fd.concurrent, &fd.theme);
+        }
+    }
+
+    // only draw commands + charts if there are containers, the charts can be hidden
     if fd.has_containers {
         draw_blocks::commands(app_data, top_panel[1], f, &fd, gui_state);
-
+    }
+    if fd.has_containers && !fd.layout.charts_hidden {
         // Can calculate the max string length here, and then use that to keep the ports section as small as possible (+4 for some padding + border)
         let max_lens = app_data.lock().get_longest_port();
         let ports_len = u16::try_from(max_lens.0 + max_lens.1 + max_lens.2 + 2).unwrap_or(26);
@@ -330,20 +817,194 @@ fn draw_frame(f: &mut Frame, app_data: &Arc<Mutex<AppData>>, gui_state: &Arc<Mut
             .constraints([Constraint::Min(1), Constraint::Max(ports_len)])
             .split(lower_main[1]);
 
-        draw_blocks::chart(f, lower[0], app_data);
+        // The network & disk charts are drawn beside the cpu & memory charts
+        if fd.io_view == Some(IoView::Charts) {
+            let charts = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+                .split(lower[0]);
+            draw_blocks::chart(f, charts[0], app_data);
+            draw_blocks::io_chart(f, charts[1], app_data);
+        } else {
+            draw_blocks::chart(f, lower[0], app_data);
+        }
         draw_blocks::ports(f, lower[1], app_data, max_lens);
     }
 
+    if fd.recreate {
+        draw_blocks::recreate_form(f, gui_state, &fd.theme);
+    }
+
+    if fd.run {
+        draw_blocks::run_form(f, gui_state, &fd.theme);
+    }
+
+    if let Some(view) = fd.log_matches {
+        draw_blocks::log_matches(f, app_data, view, &fd.theme);
+    }
+
+    if fd.daemon {
+        draw_blocks::daemon(f, app_data, &fd.theme);
+    }
+
+    if fd.traffic {
+        draw_blocks::traffic(f, app_data, &fd.theme);
+    }
+
+    if fd.memory {
+        draw_blocks::memory(f, app_data, &fd.theme);
+    }
+
+    if fd.cores {
+        draw_blocks::cores(f, app_data, &fd.theme);
+    }
+
+    if let Some(pane) = fd.layout_pane {
+        draw_blocks::layout(f, fd.layout, pane, &fd.theme);
+    }
+
+    if fd.note {
+        draw_blocks::note_form(f, gui_state, &fd.theme);
+    }
+
+    if fd.stdin {
+        draw_blocks::stdin_form(f, gui_state, &fd.theme);
+    }
+
+    if fd.rename {
+        draw_blocks::rename_form(f, gui_state, &fd.theme);
+    }
+
+    if fd.scale {
+        draw_blocks::scale_form(f, gui_state, &fd.theme);
+    }
+
+    if fd.task_logs {
+        draw_blocks::task_logs(f, gui_state, &fd.theme);
+    }
+
+    if fd.kill {
+        draw_blocks::kill_picker(f, gui_state, &fd.theme);
+    }
+
+    if fd.capture {
+        draw_blocks::capture(f, gui_state, &fd.theme);
+    }
+
+    if fd.limits {
+        draw_blocks::limits_form(f, gui_state, &fd.theme);
+    }
+
+    if fd.failed {
+        draw_blocks::failed(f, app_data, gui_state, fd.now, &fd.theme);
+    }
+
+    if fd.command {
+        draw_blocks::command_form(f, gui_state, &fd.theme);
+    }
+
+    if fd.tags {
+        draw_blocks::tag_picker(f, gui_state, &fd.theme);
+    }
+
+    if fd.reach {
+        draw_blocks::reach_picker(f, gui_state, &fd.theme);
+    }
+
+    if fd.hosts {
+        draw_blocks::host_picker(f, gui_state, &fd.theme);
+    }
+
+    if fd.recent {
+        draw_blocks::recent_picker(f, gui_state, &fd.theme);
+    }
+
+    if fd.schedules {
+        draw_blocks::schedules(f, app_data, gui_state, fd.now, &fd.theme);
+    }
+
+    if fd.filters {
+        draw_blocks::filters(f, app_data, gui_state, &fd.theme);
+    }
+
+    if fd.contexts {
+        draw_blocks::context_picker(f, gui_state, &fd.theme);
+    }
+
+    if fd.timeline {
+        draw_blocks::timeline(f, gui_state, &fd.theme);
+    }
+
+    if fd.prune {
+        draw_blocks::prune(f, gui_state, &fd.theme);
+    }
+
+    if fd.build_cache {
+        draw_blocks::build_cache(f, gui_state, &fd.theme);
+    }
+
+    if fd.secret_scan {
+        draw_blocks::secret_scan(f, gui_state, &fd.theme);
+    }
+
+    if fd.health {
+        draw_blocks::health(f, gui_state, &fd.theme);
+    }
+
+    if fd.ports {
+        draw_blocks::port_mappings(f, gui_state, &fd.theme);
+    }
+
+    if fd.files {
+        draw_blocks::file_browser(f, gui_state, &fd.theme);
+    }
+
+    if fd.finder {
+        draw_blocks::finder(f, gui_state, &fd.theme);
+    }
+
+    if fd.cleanup {
+        draw_blocks::cleanup(f, gui_state, &fd.theme);
+    }
+
+    if fd.log_export {
+        draw_blocks::log_export(f, gui_state, &fd.theme);
+    }
+
+    if fd.image_detail {
+        draw_blocks::image_detail(f, gui_state, &fd.theme);
+    }
+
+    if fd.inspect {
+        draw_blocks::inspect_view(f, gui_state, &fd.theme);
+    }
+
+    if fd.inspect_diff {
+        draw_blocks::inspect_diff(f, gui_state, &fd.theme);
+    }
+
+    if fd.pull {
+        draw_blocks::pull_progress(f, gui_state, Instant::now(), &fd.theme);
+    }
+
     if let Some((text, instant)) = fd.info_text {
-        draw_blocks::info(f, &text, instant, gui_state);
+        draw_blocks::info(f, &text, instant, gui_state, &fd.theme);
+    }
+
+    if fd.expanded_log {
+        draw_blocks::expanded_log(f, gui_state, &fd.theme);
     }
 
     // Check if error, and show popup if so
     if fd.help_visible {
-        draw_blocks::help_box(f);
+        draw_blocks::help_box(f, &fd.theme);
     }
 
     if let Some(error) = fd.has_error {
-        draw_blocks::error(f, error, None);
+        draw_blocks::error(f, error, None, &fd.theme);
+    }
+
+    if fd.standby {
+        draw_blocks::standby(f, &fd.theme);
     }
 }
@@ -0,0 +1,115 @@
+use clap::ValueEnum;
+
+use super::TimelineKind;
+use crate::app_data::{DockerEvent, EventKind};
+
+/// A change of a container's state that a desktop notification is shown for, set with `--notify`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum NotifyEvent {
+    /// A container exits with a non-zero code
+    Exit,
+    /// A container is killed for running out of memory
+    Oom,
+    /// A container's health check turns unhealthy
+    Unhealthy,
+}
+
+impl NotifyEvent {
+    /// The type of an event, None for an event that is never notified
+    pub const fn of(event: &DockerEvent) -> Option<Self> {
+        if event.failed_exit().is_some() {
+            return Some(Self::Exit);
+        }
+        match event.kind {
+            EventKind::Container(TimelineKind::Oom) => Some(Self::Oom),
+            EventKind::Container(TimelineKind::Unhealthy) => Some(Self::Unhealthy),
+            _ => None,
+        }
+    }
+}
+
+/// The escape sequence that the terminal is sent, to show a desktop notification, set with `--notify-osc`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum NotifyOsc {
+    /// `OSC 9`, understood by iTerm2, kitty, WezTerm, Ghostty, & Windows Terminal
+    #[value(name = "9")]
+    Osc9,
+    /// `OSC 777`, understood by VTE based terminals, e.g. GNOME Terminal, foot, & urxvt
+    #[value(name = "777")]
+    Osc777,
+}
+
+/// Remove control characters, which would end the sequence early, & `;`, which `OSC 777` separates the title from the body with
+fn sanitize(text: &str) -> String {
+    text.chars()
+        .filter(|c| !c.is_control())
+        .map(|c| if c == ';' { ',' } else { c })
+        .collect()
+}
+
+/// The sequence that shows a notification, titled oxker, when in tmux it's wrapped in tmux's passthrough, so that it reaches the terminal that tmux runs in, which needs tmux's `allow-passthrough` option
+pub fn sequence(osc: NotifyOsc, body: &str, tmux: bool) -> Vec<u8> {
+    let body = sanitize(body);
+    let sequence = match osc {
+        NotifyOsc::Osc9 => format!("\x1b]9;{body}\x07"),
+        NotifyOsc::Osc777 => format!("\x1b]777;notify;{};{body}\x07", env!("CARGO_PKG_NAME")),
+    };
+    if tmux {
+        format!("\x1bPtmux;{}\x1b\\", sequence.replace('\x1b', "\x1b\x1b")).into_bytes()
+    } else {
+        sequence.into_bytes()
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::{sequence, NotifyEvent, NotifyOsc};
+    use crate::{
+        app_data::{DockerEvent, EventKind},
+        ui::TimelineKind,
+    };
+
+    #[test]
+    /// Only a non-zero exit, running out of memory, & turning unhealthy, are notified
+    fn test_notify_event() {
+        let event = |kind| DockerEvent {
+            time: 0,
+            host: None,
+            id: None,
+            name: "web".to_owned(),
+            kind: EventKind::Container(kind),
+        };
+        assert_eq!(
+            NotifyEvent::of(&event(TimelineKind::Die(Some(137)))),
+            Some(NotifyEvent::Exit)
+        );
+        assert_eq!(NotifyEvent::of(&event(TimelineKind::Die(Some(0)))), None);
+        assert_eq!(
+            NotifyEvent::of(&event(TimelineKind::Oom)),
+            Some(NotifyEvent::Oom)
+        );
+        assert_eq!(
+            NotifyEvent::of(&event(TimelineKind::Unhealthy)),
+            Some(NotifyEvent::Unhealthy)
+        );
+        assert_eq!(NotifyEvent::of(&event(TimelineKind::Healthy)), None);
+    }
+
+    #[test]
+    /// Control characters are removed from the body, & in tmux the sequence is wrapped in the passthrough, with its escapes doubled
+    fn test_notify_sequence() {
+        assert_eq!(
+            sequence(NotifyOsc::Osc9, "web died\x07; exit code 1", false),
+            b"\x1b]9;web died, exit code 1\x07"
+        );
+        assert_eq!(
+            sequence(NotifyOsc::Osc777, "web unhealthy", false),
+            b"\x1b]777;notify;oxker;web unhealthy\x07"
+        );
+        assert_eq!(
+            sequence(NotifyOsc::Osc9, "web", true),
+            b"\x1bPtmux;\x1b\x1b]9;web\x07\x1b\\"
+        );
+    }
+}
@@ -0,0 +1,247 @@
+use std::{
+    fmt::Write as _,
+    io::{self, IsTerminal, Read, Write},
+    sync::mpsc,
+    time::Duration,
+};
+
+use crossterm::terminal::{disable_raw_mode, enable_raw_mode};
+use ratatui::style::Color;
+
+/// How long to wait for the terminal to reply to the color queries
+const REPLY_TIMEOUT: Duration = Duration::from_millis(250);
+
+/// The minimum contrast ratio of text against the background, below it a named color is swapped for its bright, or dim, variant
+const MIN_CONTRAST: f64 = 3.0;
+
+/// Ask for the foreground, OSC 10, the background, OSC 11, & the 16 ansi colors, OSC 4, then the device attributes, DA1, which every terminal replies to, so that the reply is known to be complete
+fn queries() -> String {
+    let mut output = "\x1b]10;?\x1b\\\x1b]11;?\x1b\\".to_owned();
+    for index in 0..16 {
+        let _ = write!(output, "\x1b]4;{index};?\x1b\\");
+    }
+    output.push_str("\x1b[c");
+    output
+}
+
+/// Parse an X11 color spec, `rgb:r/g/b`, each channel is 1 to 4 hex digits, scaled to 8 bits
+fn parse_rgb(input: &str) -> Option<(u8, u8, u8)> {
+    let mut channels = input.strip_prefix("rgb:")?.split('/').map(|i| {
+        let value = u32::from_str_radix(i, 16).ok()?;
+        let max = 16_u32.checked_pow(u32::try_from(i.len()).ok()?)? - 1;
+        (1..=4)
+            .contains(&i.len())
+            .then(|| u8::try_from(value * 255 / max).ok())?
+    });
+    let rgb = (channels.next()??, channels.next()??, channels.next()??);
+    channels.next().is_none().then_some(rgb)
+}
+
+/// Relative luminance of a color, as defined by WCAG
+fn luminance((r, g, b): (u8, u8, u8)) -> f64 {
+    let linear = |i: u8| {
+        let i = f64::from(i) / 255.0;
+        if i <= 0.039_28 {
+            i / 12.92
+        } else {
+            ((i + 0.055) / 1.055).powf(2.4)
+        }
+    };
+    0.0722f64.mul_add(linear(b), 0.2126f64.mul_add(linear(r), 0.7152 * linear(g)))
+}
+
+/// Contrast ratio of two colors, from 1, the same, to 21, black on white
+fn contrast(a: (u8, u8, u8), b: (u8, u8, u8)) -> f64 {
+    let (a, b) = (luminance(a), luminance(b));
+    (a.max(b) + 0.05) / (a.min(b) + 0.05)
+}
+
+/// The ansi index of a named color
+const fn ansi_index(color: Color) -> Option<usize> {
+    match color {
+        Color::Black => Some(0),
+        Color::Red => Some(1),
+        Color::Green => Some(2),
+        Color::Yellow => Some(3),
+        Color::Blue => Some(4),
+        Color::Magenta => Some(5),
+        Color::Cyan => Some(6),
+        Color::Gray => Some(7),
+        Color::DarkGray => Some(8),
+        Color::LightRed => Some(9),
+        Color::LightGreen => Some(10),
+        Color::LightYellow => Some(11),
+        Color::LightBlue => Some(12),
+        Color::LightMagenta => Some(13),
+        Color::LightCyan => Some(14),
+        Color::White => Some(15),
+        _ => None,
+    }
+}
+
+const ANSI_COLORS: [Color; 16] = [
+    Color::Black,
+    Color::Red,
+    Color::Green,
+    Color::Yellow,
+    Color::Blue,
+    Color::Magenta,
+    Color::Cyan,
+    Color::Gray,
+    Color::DarkGray,
+    Color::LightRed,
+    Color::LightGreen,
+    Color::LightYellow,
+    Color::LightBlue,
+    Color::LightMagenta,
+    Color::LightCyan,
+    Color::White,
+];
+
+/// The colors that the terminal actually draws, as queried at startup, so that the default theme can be built from them, rather than assuming a dark terminal
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Palette {
+    pub fg: (u8, u8, u8),
+    pub bg: (u8, u8, u8),
+    /// The 16 ansi colors, None for any the terminal didn't reply with
+    pub ansi: [Option<(u8, u8, u8)>; 16],
+}
+
+impl Palette {
+    /// Parse the replies to the queries, each is `ESC ] code ; [index ;] spec`, ended by either BEL, or ST, None without both the foreground & background
+    fn parse(reply: &str) -> Option<Self> {
+        let (mut fg, mut bg) = (None, None);
+        let mut ansi = [None; 16];
+        for reply in reply.split("\x1b]").skip(1) {
+            let reply = reply.split(['\x07', '\x1b']).next().unwrap_or_default();
+            match reply.split(';').collect::<Vec<_>>().as_slice() {
+                ["10", spec] => fg = parse_rgb(spec),
+                ["11", spec] => bg = parse_rgb(spec),
+                ["4", index, spec] => {
+                    if let Some(color) = index.parse::<usize>().ok().and_then(|i| ansi.get_mut(i)) {
+                        *color = parse_rgb(spec);
+                    }
+                }
+                _ => (),
+            }
+        }
+        Some(Self {
+            fg: fg?,
+            bg: bg?,
+            ansi,
+        })
+    }
+
+    /// Query the terminal, only when both stdin & stdout are a terminal, None if it doesn't reply in time
+    /// A terminal that doesn't reply to the device attributes either leaves the reader waiting, so that the first key press is lost, `--no-palette` skips the query
+    pub fn query() -> Option<Self> {
+        if !io::stdin().is_terminal() || !io::stdout().is_terminal() {
+            return None;
+        }
+        enable_raw_mode().ok()?;
+        let mut stdout = io::stdout();
+        let written = stdout
+            .write_all(queries().as_bytes())
+            .and_then(|()| stdout.flush());
+        let (sender, receiver) = mpsc::channel();
+        if written.is_ok() {
+            std::thread::spawn(move || {
+                let mut reply = vec![];
+                let mut buf = [0; 256];
+                while let Ok(count @ 1..) = io::stdin().read(&mut buf) {
+                    reply.extend_from_slice(&buf[..count]);
+                    // The device attributes reply, `ESC [ ? ... c`, is always last
+                    let text = String::from_utf8_lossy(&reply);
+                    if text
+                        .rsplit_once("\x1b[?")
+                        .is_some_and(|(_, i)| i.contains('c'))
+                    {
+                        sender.send(text.into_owned()).ok();
+                        return;
+                    }
+                }
+            });
+        }
+        let reply = receiver.recv_timeout(REPLY_TIMEOUT).ok();
+        disable_raw_mode().ok();
+        Self::parse(&reply?)
+    }
+
+    /// A named color that draws with too little contrast on the background is swapped for its bright, or dim, variant, if that has more contrast
+    pub fn readable(&self, color: Color) -> Color {
+        let Some(index) = ansi_index(color) else {
+            return color;
+        };
+        let other = index ^ 8;
+        match (self.ansi[index], self.ansi[other]) {
+            (Some(rgb), Some(other_rgb))
+                if contrast(rgb, self.bg) < MIN_CONTRAST
+                    && contrast(other_rgb, self.bg) > contrast(rgb, self.bg) =>
+            {
+                ANSI_COLORS[other]
+            }
+            _ => color,
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use std::fmt::Write as _;
+
+    use ratatui::style::Color;
+
+    use super::{contrast, parse_rgb, Palette};
+
+    /// Solarized light, as replied by a terminal, ended by ST, or by BEL
+    fn solarized_light() -> Palette {
+        let mut reply = "\x1b]10;rgb:6565/7b7b/8383\x1b\\\x1b]11;rgb:fdfd/f6f6/e3e3\x07".to_owned();
+        for (index, spec) in [
+            "073642", "dc322f", "859900", "b58900", "268bd2", "d33682", "2aa198", "eee8d5",
+            "002b36", "cb4b16", "586e75", "657b83", "839496", "6c71c4", "93a1a1", "fdf6e3",
+        ]
+        .iter()
+        .enumerate()
+        {
+            write!(
+                reply,
+                "\x1b]4;{index};rgb:{}/{}/{}\x1b\\",
+                &spec[0..2],
+                &spec[2..4],
+                &spec[4..6]
+            )
+            .unwrap();
+        }
+        reply.push_str("\x1b[?62;22c");
+        Palette::parse(&reply).unwrap()
+    }
+
+    #[test]
+    /// X11 color specs of 1 to 4 hex digits a channel are scaled to 8 bits
+    fn test_palette_parse_rgb() {
+        assert_eq!(parse_rgb("rgb:ffff/8080/0000"), Some((255, 128, 0)));
+        assert_eq!(parse_rgb("rgb:f/8/0"), Some((255, 136, 0)));
+        assert_eq!(parse_rgb("rgb:fd/f6/e3"), Some((253, 246, 227)));
+        assert!(parse_rgb("rgb:ff/ff").is_none());
+        assert!(parse_rgb("rgb:ff/ff/ff/ff").is_none());
+        assert!(parse_rgb("rgb:fffff/0/0").is_none());
+        assert!(parse_rgb("#ffffff").is_none());
+        assert!(contrast((0, 0, 0), (255, 255, 255)) > 20.9);
+    }
+
+    #[test]
+    /// The replies are parsed, & named colors that are hard to read on the background are swapped for their other variant
+    fn test_palette_readable() {
+        let palette = solarized_light();
+        assert_eq!(palette.bg, (253, 246, 227));
+        assert_eq!(palette.ansi[1], Some((220, 50, 47)));
+
+        // Light cyan, base1, is hard to read on base3, cyan isn't
+        assert_eq!(palette.readable(Color::LightCyan), Color::Cyan);
+        assert_eq!(palette.readable(Color::Red), Color::Red);
+        assert_eq!(palette.readable(Color::Rgb(1, 2, 3)), Color::Rgb(1, 2, 3));
+
+        assert!(Palette::parse("\x1b]11;rgb:0/0/0\x07\x1b[?1;2c").is_none());
+    }
+}
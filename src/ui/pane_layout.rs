@@ -0,0 +1,128 @@
+/// Default percentage of the space below the containers that the charts take
+pub const CHARTS_HEIGHT: u16 = 30;
+
+/// The charts are resized in steps of this many percent, between CHARTS_MIN & CHARTS_MAX
+const CHARTS_STEP: u16 = 5;
+const CHARTS_MIN: u16 = 10;
+const CHARTS_MAX: u16 = 90;
+
+/// The containers panel is at least its borders, & a single row
+const CONTAINERS_MIN: u16 = 3;
+
+/// A pane that can be resized with ( ` )
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Pane {
+    Containers,
+    Charts,
+}
+
+/// Sizes of the panes, set by `--containers-height`, `--charts-height`, & `--hide-charts`, changed with ( ` ), or by dragging the border below the containers panel
+/// The logs take whatever space is left
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PaneLayout {
+    /// Rows of the containers, & commands, panels, including their borders, None sizes them to the containers
+    pub containers: Option<u16>,
+    /// Percentage of the space below the containers that the charts take
+    pub charts: u16,
+    pub charts_hidden: bool,
+}
+
+impl Default for PaneLayout {
+    fn default() -> Self {
+        Self {
+            containers: None,
+            charts: CHARTS_HEIGHT,
+            charts_hidden: false,
+        }
+    }
+}
+
+impl PaneLayout {
+    /// Set the rows of the containers panel, None to size it to the containers again
+    pub fn set_containers(&mut self, rows: Option<u16>) {
+        self.containers = rows.map(|i| i.max(CONTAINERS_MIN));
+    }
+
+    /// Grow, or shrink, a pane by a step, the containers panel by a row, from `drawn`, its current height, when it's sized to the containers
+    pub fn resize(&mut self, pane: Pane, grow: bool, drawn: u16) {
+        match pane {
+            Pane::Containers => {
+                let rows = self.containers.unwrap_or(drawn);
+                self.set_containers(Some(if grow {
+                    rows.saturating_add(1)
+                } else {
+                    rows.saturating_sub(1)
+                }));
+            }
+            Pane::Charts => {
+                self.charts_hidden = false;
+                self.charts = if grow {
+                    self.charts.saturating_add(CHARTS_STEP)
+                } else {
+                    self.charts.saturating_sub(CHARTS_STEP)
+                }
+                .clamp(CHARTS_MIN, CHARTS_MAX);
+            }
+        }
+    }
+
+    /// Collapse, or show again, the charts, the containers panel is sized to the containers again
+    pub const fn toggle(&mut self, pane: Pane) {
+        match pane {
+            Pane::Containers => self.containers = None,
+            Pane::Charts => self.charts_hidden = !self.charts_hidden,
+        }
+    }
+
+    /// The config file keys of the layout, None for a key that is at its default, so is removed from the file
+    pub fn config(self) -> [(&'static str, Option<String>); 3] {
+        [
+            ("containers_height", self.containers.map(|i| i.to_string())),
+            (
+                "charts_height",
+                (self.charts != CHARTS_HEIGHT).then(|| self.charts.to_string()),
+            ),
+            ("hide_charts", self.charts_hidden.then(|| "true".to_owned())),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Pane, PaneLayout, CHARTS_HEIGHT};
+
+    #[test]
+    /// Panes are resized within their limits, the containers panel from its drawn height, & only the changed sizes are saved
+    fn test_pane_layout_resize() {
+        let mut layout = PaneLayout::default();
+        assert!(layout.config().iter().all(|(_, i)| i.is_none()));
+
+        layout.resize(Pane::Containers, true, 8);
+        assert_eq!(layout.containers, Some(9));
+        for _ in 0..10 {
+            layout.resize(Pane::Containers, false, 8);
+        }
+        assert_eq!(layout.containers, Some(3));
+
+        layout.toggle(Pane::Charts);
+        layout.resize(Pane::Charts, true, 8);
+        assert!(!layout.charts_hidden);
+        assert_eq!(layout.charts, CHARTS_HEIGHT + 5);
+        for _ in 0..20 {
+            layout.resize(Pane::Charts, true, 8);
+        }
+        assert_eq!(layout.charts, 90);
+        layout.toggle(Pane::Charts);
+        assert_eq!(
+            layout.config().map(|(_, i)| i),
+            [
+                Some("3".to_owned()),
+                Some("90".to_owned()),
+                Some("true".to_owned())
+            ]
+        );
+
+        layout.toggle(Pane::Containers);
+        assert!(layout.containers.is_none());
+    }
+}
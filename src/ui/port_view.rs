@@ -0,0 +1,68 @@
+use crate::app_data::ContainerId;
+
+/// A single port of a container, a published port has the url it's opened at
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PortRow {
+    pub ip: Option<String>,
+    pub private: u16,
+    pub public: Option<u16>,
+    pub url: Option<String>,
+}
+
+impl PortRow {
+    pub const fn is_published(&self) -> bool {
+        self.public.is_some()
+    }
+}
+
+/// The published, & exposed, ports of a container, a published port is opened in the browser, an exposed port can be forwarded to the host
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PortView {
+    pub id: ContainerId,
+    pub name: String,
+    pub rows: Vec<PortRow>,
+    pub selected: usize,
+}
+
+impl PortView {
+    pub fn next(&mut self) {
+        self.selected = (self.selected + 1).min(self.rows.len().saturating_sub(1));
+    }
+
+    pub const fn previous(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    pub fn selected_row(&self) -> Option<&PortRow> {
+        self.rows.get(self.selected)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PortRow, PortView};
+    use crate::app_data::ContainerId;
+
+    #[test]
+    /// The selection stays within the ports
+    fn test_port_view_select() {
+        let row = |public| PortRow {
+            ip: None,
+            private: 80,
+            public,
+            url: None,
+        };
+        let mut view = PortView {
+            id: ContainerId::from("1"),
+            name: "web".to_owned(),
+            rows: vec![row(Some(8080)), row(None)],
+            selected: 0,
+        };
+        view.previous();
+        assert!(view.selected_row().is_some_and(PortRow::is_published));
+        view.next();
+        view.next();
+        assert_eq!(view.selected, 1);
+        assert!(!view.selected_row().is_some_and(PortRow::is_published));
+    }
+}
@@ -0,0 +1,170 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::app_data::ByteStats;
+
+/// Seconds in a day, the age rule is set in days
+const DAY: i64 = 60 * 60 * 24;
+
+/// A local image, as needed to decide whether the prune rules remove it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PruneImage {
+    pub id: String,
+    /// Unix timestamp, in seconds
+    pub created: i64,
+    /// Used by a container, running or not
+    pub in_use: bool,
+    /// The repository of each tag, e.g. `redis` for `redis:7`
+    pub repositories: Vec<String>,
+    pub size: u64,
+    pub tags: Vec<String>,
+}
+
+/// The rule being edited in the prune preview
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum PruneRule {
+    #[default]
+    Age,
+    Keep,
+}
+
+/// Prune rules, an image is removed when it's unused, older than `days`, and not one of the `keep` newest images of any of its repositories
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrunePreview {
+    /// With `--dry-run`, the first ( enter ) arms the removal, and the second removes
+    pub armed: bool,
+    pub days: u16,
+    /// The host that the images are on, the primary host is None
+    pub host: Option<String>,
+    pub images: Vec<PruneImage>,
+    pub keep: u16,
+    /// Unix timestamp, in seconds, that the image ages are measured from
+    pub now: i64,
+    pub selected: PruneRule,
+}
+
+impl PrunePreview {
+    /// Rules default to removing unused images older than 30 days, keeping the last 2 tags per repository
+    pub const fn new(images: Vec<PruneImage>, now: i64, host: Option<String>) -> Self {
+        Self {
+            armed: false,
+            days: 30,
+            host,
+            images,
+            keep: 2,
+            now,
+            selected: PruneRule::Age,
+        }
+    }
+
+    pub const fn next(&mut self) {
+        self.armed = false;
+        self.selected = match self.selected {
+            PruneRule::Age => PruneRule::Keep,
+            PruneRule::Keep => PruneRule::Age,
+        };
+    }
+
+    /// Change the value of the selected rule, by `step`, age is at least 0 days, keep is at least 0 tags
+    pub fn change(&mut self, step: i16) {
+        self.armed = false;
+        let change = |value: u16| value.saturating_add_signed(step);
+        match self.selected {
+            PruneRule::Age => self.days = change(self.days),
+            PruneRule::Keep => self.keep = change(self.keep),
+        }
+    }
+
+    /// The images kept by the keep rule, the `keep` newest images of each repository
+    fn kept(&self) -> HashSet<&str> {
+        let mut repositories = HashMap::<&str, Vec<&PruneImage>>::new();
+        for image in &self.images {
+            for repository in &image.repositories {
+                repositories.entry(repository).or_default().push(image);
+            }
+        }
+        repositories
+            .into_values()
+            .flat_map(|mut images| {
+                images.sort_by_key(|i| std::cmp::Reverse(i.created));
+                images.truncate(usize::from(self.keep));
+                images
+            })
+            .map(|i| i.id.as_str())
+            .collect()
+    }
+
+    /// The images that the rules would remove, oldest first
+    pub fn matches(&self) -> Vec<&PruneImage> {
+        let kept = self.kept();
+        let cutoff = self.now - i64::from(self.days) * DAY;
+        let mut output = self
+            .images
+            .iter()
+            .filter(|i| !i.in_use && i.created < cutoff && !kept.contains(i.id.as_str()))
+            .collect::<Vec<_>>();
+        output.sort_by_key(|i| i.created);
+        output
+    }
+
+    /// Total size of the images that the rules would remove, layers shared with other images aren't reclaimed, so this is an upper bound
+    pub fn reclaimed(&self) -> ByteStats {
+        ByteStats::new(self.matches().iter().map(|i| i.size).sum())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PruneImage, PrunePreview, DAY};
+
+    fn gen_image(id: &str, days_old: i64, in_use: bool, tags: &[&str]) -> PruneImage {
+        PruneImage {
+            id: id.to_owned(),
+            created: 100 * DAY - days_old * DAY,
+            in_use,
+            repositories: tags
+                .iter()
+                .map(|i| i.split_once(':').map_or(*i, |i| i.0).to_owned())
+                .collect(),
+            size: 1_000_000,
+            tags: tags.iter().map(|i| (*i).to_owned()).collect(),
+        }
+    }
+
+    #[test]
+    /// Only unused images, older than the age rule, and not one of the newest tags of a repository, are removed
+    fn test_prune_matches() {
+        let mut preview = PrunePreview::new(
+            vec![
+                gen_image("a", 90, false, &["app:1"]),
+                gen_image("b", 60, false, &["app:2"]),
+                gen_image("c", 45, false, &["app:3"]),
+                gen_image("d", 40, false, &["app:4"]),
+                gen_image("e", 80, true, &["db:1"]),
+                gen_image("f", 70, false, &[]),
+                gen_image("g", 5, false, &[]),
+            ],
+            100 * DAY,
+            None,
+        );
+        let ids = |preview: &PrunePreview| {
+            preview
+                .matches()
+                .iter()
+                .map(|i| i.id.clone())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(ids(&preview), ["a", "f", "b"]);
+        assert_eq!(preview.reclaimed().to_string().trim(), "3.00 MB");
+
+        // Keep 0 only leaves the age & in use rules
+        preview.next();
+        preview.change(-5);
+        assert_eq!(preview.keep, 0);
+        assert_eq!(ids(&preview), ["a", "f", "b", "c", "d"]);
+
+        preview.next();
+        preview.change(30);
+        assert_eq!(preview.days, 60);
+        assert_eq!(ids(&preview), ["a", "f"]);
+    }
+}
@@ -0,0 +1,205 @@
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use tokio::sync::Notify;
+
+/// Status of a layer that's being downloaded, the only status with download progress
+const DOWNLOADING: &str = "Downloading";
+
+/// Status of a layer that's been downloaded, but not yet extracted, has no progress of its own
+const DOWNLOADED: &str = "Download complete";
+
+/// A single layer of an image that's being pulled
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PullLayer {
+    /// Short id of the layer, as reported by the daemon
+    pub id: String,
+    /// e.g. `Waiting`, `Downloading`, `Extracting`, `Pull complete`
+    pub status: String,
+    /// Bytes done, & total bytes, of the current step, either downloading or extracting
+    pub progress: Option<(u64, u64)>,
+    /// Bytes downloaded, & the size of the download, unknown until the layer starts downloading
+    pub downloaded: Option<(u64, u64)>,
+    /// When the first bytes of the layer were downloaded, & when the latest bytes were downloaded
+    downloading: Option<(Instant, Instant)>,
+}
+
+impl PullLayer {
+    /// The layer is downloading, rather than waiting, extracting, or done
+    pub fn is_downloading(&self) -> bool {
+        self.status == DOWNLOADING
+    }
+
+    /// Average download speed of the layer, in bytes per second, only while it's downloading
+    pub fn speed(&self) -> Option<u64> {
+        let (downloaded, _) = self.downloaded?;
+        let (first, latest) = self.downloading.filter(|_| self.is_downloading())?;
+        per_second(downloaded, latest.duration_since(first))
+    }
+}
+
+/// Bytes per second, None until there's been time to measure
+fn per_second(bytes: u64, elapsed: Duration) -> Option<u64> {
+    let millis = u64::try_from(elapsed.as_millis()).unwrap_or(u64::MAX);
+    (millis > 0).then(|| bytes.saturating_mul(1000) / millis)
+}
+
+/// The pull of an image, with the progress of each of its layers, shown until the pull completes, fails, or is cancelled
+#[derive(Debug, Clone)]
+pub struct PullProgress {
+    /// Notified to cancel the pull
+    cancel: Arc<Notify>,
+    /// The host that the image is pulled to, the primary host is None
+    pub host: Option<String>,
+    pub image: String,
+    /// Layers in the order the daemon first reports them
+    pub layers: Vec<PullLayer>,
+    started: Instant,
+    /// The latest status that isn't about a single layer, e.g. `Pulling from library/redis`
+    pub status: Option<String>,
+}
+
+impl PullProgress {
+    pub fn new(image: &str, host: Option<String>, now: Instant) -> Self {
+        Self {
+            cancel: Arc::new(Notify::new()),
+            host,
+            image: image.to_owned(),
+            layers: vec![],
+            started: now,
+            status: None,
+        }
+    }
+
+    /// Cancel the pull, the pull is notified even if it's not currently waiting on the daemon
+    pub fn cancel(&self) {
+        self.cancel.notify_one();
+    }
+
+    /// Awaited, alongside the pull, by whatever is pulling the image
+    pub fn cancelled(&self) -> Arc<Notify> {
+        Arc::clone(&self.cancel)
+    }
+
+    /// Time since the pull started
+    pub fn elapsed(&self, now: Instant) -> Duration {
+        now.duration_since(self.started)
+    }
+
+    /// Apply a single progress message from the daemon, a message with an id is about that layer, apart from `Pulling from [repository]`, whose id is the tag
+    pub fn update(
+        &mut self,
+        id: Option<&str>,
+        status: &str,
+        progress: Option<(u64, u64)>,
+        now: Instant,
+    ) {
+        let Some(id) = id.filter(|_| !status.starts_with("Pulling from")) else {
+            self.status = Some(status.to_owned());
+            return;
+        };
+        let index = self
+            .layers
+            .iter()
+            .position(|i| i.id == id)
+            .unwrap_or_else(|| {
+                self.layers.push(PullLayer {
+                    id: id.to_owned(),
+                    status: String::new(),
+                    progress: None,
+                    downloaded: None,
+                    downloading: None,
+                });
+                self.layers.len() - 1
+            });
+        let layer = &mut self.layers[index];
+        status.clone_into(&mut layer.status);
+        layer.progress = progress.filter(|(_, total)| *total > 0);
+        match status {
+            DOWNLOADING => {
+                if let Some(progress) = layer.progress {
+                    layer.downloaded = Some(progress);
+                    layer.downloading = Some((layer.downloading.map_or(now, |i| i.0), now));
+                }
+            }
+            DOWNLOADED => {
+                if let Some((_, total)) = layer.downloaded {
+                    layer.downloaded = Some((total, total));
+                }
+            }
+            _ => (),
+        }
+    }
+
+    /// Bytes downloaded, & the total download size, of every layer that's started downloading
+    pub fn downloaded(&self) -> (u64, u64) {
+        self.layers
+            .iter()
+            .filter_map(|i| i.downloaded)
+            .fold((0, 0), |acc, i| (acc.0 + i.0, acc.1 + i.1))
+    }
+
+    /// Combined download speed of every layer, in bytes per second, since the first layer started downloading
+    pub fn speed(&self, now: Instant) -> Option<u64> {
+        let first = self.layers.iter().filter_map(|i| i.downloading).min()?.0;
+        per_second(self.downloaded().0, now.duration_since(first))
+    }
+
+    /// Estimated time until every layer is downloaded, at the combined speed, layers that are still waiting aren't of a known size, so aren't included
+    pub fn eta(&self, now: Instant) -> Option<Duration> {
+        let (downloaded, total) = self.downloaded();
+        let speed = self.speed(now).filter(|i| *i > 0)?;
+        Some(Duration::from_secs(
+            total.saturating_sub(downloaded).div_ceil(speed),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use super::PullProgress;
+
+    #[test]
+    /// Layers are tracked in the order they're reported, with a per layer & combined speed, and an eta for the known layers
+    fn test_pull_progress() {
+        let start = Instant::now();
+        let at = |secs: u64| start + Duration::from_secs(secs);
+        let mut pull = PullProgress::new("redis:7", None, start);
+
+        pull.update(Some("7"), "Pulling from library/redis", None, start);
+        pull.update(Some("a1"), "Pulling fs layer", None, start);
+        pull.update(Some("b2"), "Already exists", None, start);
+        pull.update(Some("c3"), "Waiting", None, start);
+        assert_eq!(pull.status.as_deref(), Some("Pulling from library/redis"));
+        assert_eq!(pull.layers.len(), 3);
+        assert!(pull.speed(at(1)).is_none());
+        assert!(pull.eta(at(1)).is_none());
+
+        pull.update(Some("a1"), "Downloading", Some((1000, 10_000)), at(1));
+        pull.update(Some("a1"), "Downloading", Some((5000, 10_000)), at(3));
+        assert!(pull.layers[0].is_downloading());
+        assert_eq!(pull.layers[0].speed(), Some(2500));
+        assert_eq!(pull.downloaded(), (5000, 10_000));
+        assert_eq!(pull.speed(at(3)), Some(2500));
+        assert_eq!(pull.eta(at(3)), Some(Duration::from_secs(2)));
+
+        pull.update(Some("c3"), "Downloading", Some((500, 2000)), at(3));
+        assert_eq!(pull.downloaded(), (5500, 12_000));
+
+        pull.update(Some("a1"), "Download complete", None, at(4));
+        assert_eq!(pull.layers[0].downloaded, Some((10_000, 10_000)));
+        assert!(pull.layers[0].speed().is_none());
+        pull.update(Some("a1"), "Extracting", Some((32, 10_000)), at(4));
+        assert_eq!(pull.layers[0].progress, Some((32, 10_000)));
+        assert_eq!(pull.layers[0].downloaded, Some((10_000, 10_000)));
+
+        pull.update(None, "Digest: sha256:abc", None, at(5));
+        assert_eq!(pull.status.as_deref(), Some("Digest: sha256:abc"));
+        assert_eq!(pull.layers.len(), 3);
+        assert_eq!(pull.elapsed(at(5)), Duration::from_secs(5));
+    }
+}
@@ -0,0 +1,43 @@
+use crate::app_data::ContainerId;
+
+/// A container, and one of its ports, that the source container can try to reach
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReachTarget {
+    pub name: String,
+    pub ip: String,
+    /// A private port of the target, checked with a tcp connect, None is checked with a ping
+    pub port: Option<u16>,
+    /// The target is on a network shared with the source container, a target that isn't is usually unreachable
+    pub shared: bool,
+}
+
+impl ReachTarget {
+    /// `ip:port`, or just the ip when checked with a ping
+    pub fn address(&self) -> String {
+        self.port
+            .map_or_else(|| self.ip.clone(), |port| format!("{}:{port}", self.ip))
+    }
+}
+
+/// The other running containers, & their ports, that a connectivity check can be run against, the check is exec'd in the source container
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReachPicker {
+    pub id: ContainerId,
+    pub name: String,
+    pub selected: usize,
+    pub targets: Vec<ReachTarget>,
+}
+
+impl ReachPicker {
+    pub fn next(&mut self) {
+        self.selected = (self.selected + 1).min(self.targets.len().saturating_sub(1));
+    }
+
+    pub const fn previous(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    pub fn chosen(&self) -> Option<&ReachTarget> {
+        self.targets.get(self.selected)
+    }
+}
@@ -0,0 +1,41 @@
+use crate::app_data::{ContainerId, ContainerName, State};
+
+/// A recently viewed container that can be jumped back to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecentRow {
+    pub id: ContainerId,
+    pub name: ContainerName,
+    pub state: State,
+}
+
+/// The recently viewed containers, most recent first, the most recent is selected, so that ( \ ) then ( enter ) is the same as ( backspace )
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecentPicker {
+    pub rows: Vec<RecentRow>,
+    pub selected: usize,
+}
+
+impl RecentPicker {
+    pub const fn new(rows: Vec<RecentRow>) -> Self {
+        Self { rows, selected: 0 }
+    }
+
+    pub fn next(&mut self) {
+        self.selected = (self.selected + 1).min(self.rows.len().saturating_sub(1));
+    }
+
+    pub const fn previous(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    /// Select the next container, wrapping around to the first, as alt-tab would
+    pub const fn cycle(&mut self) {
+        if !self.rows.is_empty() {
+            self.selected = (self.selected + 1) % self.rows.len();
+        }
+    }
+
+    pub fn chosen(&self) -> Option<&RecentRow> {
+        self.rows.get(self.selected)
+    }
+}
@@ -0,0 +1,122 @@
+use std::{
+    fs::File,
+    io::{BufWriter, Write},
+    path::Path,
+    time::{Duration, Instant, SystemTime},
+};
+
+use ratatui::buffer::Buffer;
+use serde_json::json;
+
+use super::snapshot;
+
+/// Seconds since the start of a cast, to the microsecond, as asciinema writes them
+pub fn cast_time(elapsed: Duration) -> f64 {
+    (elapsed.as_secs_f64() * 1_000_000.0).round() / 1_000_000.0
+}
+
+/// Record every drawn frame, with timings, into an asciinema v2 cast file
+/// Each frame is written as a full screen redraw, identical consecutive frames are skipped
+pub struct Recorder<W: Write> {
+    last: Option<Buffer>,
+    start: Instant,
+    writer: W,
+}
+
+impl Recorder<BufWriter<File>> {
+    /// Create the cast file, the header is written once the first frame is received, as the terminal size is needed
+    pub fn create(path: &Path) -> std::io::Result<Self> {
+        Ok(Self::new(BufWriter::new(File::create(path)?)))
+    }
+}
+
+impl<W: Write> Recorder<W> {
+    pub fn new(writer: W) -> Self {
+        Self {
+            last: None,
+            start: Instant::now(),
+            writer,
+        }
+    }
+
+    /// Write a single event line, `[time, code, data]`
+    fn event(&mut self, code: &str, data: &str) -> std::io::Result<()> {
+        writeln!(
+            self.writer,
+            "{}",
+            json!([cast_time(self.start.elapsed()), code, data])
+        )
+    }
+
+    /// Record a frame, if the terminal has been resized, a resize event is also written
+    pub fn frame(&mut self, buffer: &Buffer) -> std::io::Result<()> {
+        let area = buffer.area;
+        match self.last.as_ref() {
+            None => {
+                self.start = Instant::now();
+                let timestamp = SystemTime::now()
+                    .duration_since(SystemTime::UNIX_EPOCH)
+                    .map_or(0, |i| i.as_secs());
+                let header = json!({
+                    "version": 2,
+                    "width": area.width,
+                    "height": area.height,
+                    "timestamp": timestamp,
+                    "env": {"TERM": std::env::var("TERM").unwrap_or_default()},
+                });
+                writeln!(self.writer, "{header}")?;
+            }
+            Some(last) if last == buffer => return Ok(()),
+            Some(last) if last.area != area => {
+                self.event("r", &format!("{}x{}", area.width, area.height))?;
+            }
+            Some(_) => (),
+        }
+        self.event("o", &snapshot::to_ansi(buffer))?;
+        self.writer.flush()?;
+        self.last = Some(buffer.clone());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use ratatui::{buffer::Buffer, layout::Rect};
+
+    use std::time::Duration;
+
+    use super::{cast_time, Recorder};
+
+    #[test]
+    /// Event times are rounded to the microsecond
+    fn test_recorder_cast_time() {
+        assert!((cast_time(Duration::from_nanos(1_234_567_890)) - 1.234_568).abs() < f64::EPSILON);
+        assert!(cast_time(Duration::ZERO).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    /// Header written with first frame, identical frames skipped, resizes recorded
+    fn test_recorder_frames() {
+        let mut recorder = Recorder::new(vec![]);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 2));
+        recorder.frame(&buffer).unwrap();
+        recorder.frame(&buffer).unwrap();
+        buffer.set_string(0, 0, "a", ratatui::style::Style::default());
+        recorder.frame(&buffer).unwrap();
+        recorder
+            .frame(&Buffer::empty(Rect::new(0, 0, 6, 1)))
+            .unwrap();
+
+        let output = String::from_utf8(recorder.writer).unwrap();
+        let lines = output.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 5);
+        assert!(lines[0].starts_with(r#"{"env":{"TERM":"#));
+        assert!(lines[0].contains(r#""height":2,"#));
+        assert!(lines[0].ends_with(r#""version":2,"width":4}"#));
+        assert!(lines[1].contains(r#","o",""#));
+        assert!(lines[2].contains('a'));
+        assert!(lines[3].ends_with(r#","r","6x1"]"#));
+        assert!(lines[4].contains(r#","o",""#));
+    }
+}
@@ -0,0 +1,442 @@
+use std::{collections::HashMap, fmt};
+
+use crate::app_data::ContainerId;
+
+/// The section of the recreate, or run, form that a field belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldKind {
+    Image,
+    /// Only in the run form
+    Name,
+    Env,
+    Port,
+    Mount,
+    /// Only in the run form
+    Restart,
+}
+
+impl FieldKind {
+    /// A list section can hold any number of values, each its own field
+    pub const fn is_list(self) -> bool {
+        matches!(self, Self::Env | Self::Port | Self::Mount)
+    }
+}
+
+impl fmt::Display for FieldKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let disp = match self {
+            Self::Image => "image",
+            Self::Name => "name",
+            Self::Env => "env",
+            Self::Port => "port",
+            Self::Mount => "mount",
+            Self::Restart => "restart",
+        };
+        write!(f, "{disp:>x$}", x = f.width().unwrap_or(1))
+    }
+}
+
+/// Where an env var comes from, compared against the default env of the container's image
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EnvOrigin {
+    /// The same as the image default
+    Image,
+    /// Overrides the image default, with a different value
+    Changed,
+    /// Not set by the image, so was injected when the container was created
+    Runtime,
+}
+
+impl EnvOrigin {
+    pub const fn symbol(self) -> &'static str {
+        match self {
+            Self::Image => "=",
+            Self::Changed => "~",
+            Self::Runtime => "+",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormField {
+    pub kind: FieldKind,
+    pub value: String,
+}
+
+/// A published port, `[ip:]host_port:container_port[/protocol]`, or just `container_port[/protocol]` to only expose it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PortSpec {
+    /// Container port with protocol, e.g. `80/tcp`, as used by the Docker API
+    pub container: String,
+    pub host_ip: Option<String>,
+    pub host_port: Option<String>,
+}
+
+impl PortSpec {
+    /// Parse a port field, None if either port isn't a valid port number, or the protocol is unknown
+    pub fn parse(input: &str) -> Option<Self> {
+        let input = input.trim();
+        let (host, container) = input
+            .rsplit_once(':')
+            .map_or((None, input), |(host, container)| (Some(host), container));
+        let (port, protocol) = container.split_once('/').unwrap_or((container, "tcp"));
+        port.parse::<u16>().ok()?;
+        if !["tcp", "udp", "sctp"].contains(&protocol) {
+            return None;
+        }
+        let (host_ip, host_port) = match host {
+            Some(host) => {
+                let (ip, port) = host
+                    .rsplit_once(':')
+                    .map_or((None, host), |(ip, port)| (Some(ip.to_owned()), port));
+                // An empty host port means a random port is published
+                if !port.is_empty() {
+                    port.parse::<u16>().ok()?;
+                }
+                (ip, Some(port.to_owned()))
+            }
+            None => (None, None),
+        };
+        Some(Self {
+            container: format!("{port}/{protocol}"),
+            host_ip,
+            host_port,
+        })
+    }
+
+    /// Format a port binding, as returned by inspect, into a port field, the default tcp protocol, and all interface ip's, are left out
+    pub fn format(container: &str, host_ip: &str, host_port: &str) -> String {
+        let container = container.strip_suffix("/tcp").unwrap_or(container);
+        match host_ip {
+            "" | "0.0.0.0" | "::" => format!("{host_port}:{container}"),
+            ip => format!("{ip}:{host_port}:{container}"),
+        }
+    }
+}
+
+/// Type a char into a field, when it's the last, empty, field of a list section, a new empty field is added after it
+pub fn push_field(fields: &mut Vec<FormField>, index: usize, c: char) {
+    let Some(field) = fields.get_mut(index) else {
+        return;
+    };
+    let kind = field.kind;
+    let was_empty = field.value.is_empty();
+    field.value.push(c);
+    let last_of_section = !matches!(fields.get(index + 1), Some(i) if i.kind == kind);
+    if was_empty && last_of_section && kind.is_list() {
+        fields.insert(
+            index + 1,
+            FormField {
+                kind,
+                value: String::new(),
+            },
+        );
+    }
+}
+
+/// The non-empty, trimmed, values of a section
+pub fn field_values(fields: &[FormField], kind: FieldKind) -> Vec<String> {
+    fields
+        .iter()
+        .filter(|i| i.kind == kind)
+        .map(|i| i.value.trim().to_owned())
+        .filter(|i| !i.is_empty())
+        .collect()
+}
+
+/// Check the env, port, & mount fields, returns the first problem found
+pub fn invalid_fields(fields: &[FormField]) -> Option<String> {
+    if let Some(env) = field_values(fields, FieldKind::Env)
+        .into_iter()
+        .find(|i| !i.contains('=') || i.starts_with('='))
+    {
+        return Some(format!("invalid env: {env}, expected KEY=value"));
+    }
+    if let Some(port) = field_values(fields, FieldKind::Port)
+        .into_iter()
+        .find(|i| PortSpec::parse(i).is_none())
+    {
+        return Some(format!("invalid port: {port}"));
+    }
+    field_values(fields, FieldKind::Mount)
+        .into_iter()
+        .find(|i| !i.contains(':'))
+        .map(|mount| format!("invalid mount: {mount}, expected source:target"))
+}
+
+/// The "recreate with changes" form, pre-filled from the inspected container, each env var, port, and mount is its own field
+/// Empty fields are ignored when the container is recreated, and each list section always ends with an empty field, for adding a new value
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecreateForm {
+    pub fields: Vec<FormField>,
+    pub id: ContainerId,
+    /// The default env of the container's image, None if the image couldn't be inspected
+    pub image_env: Option<HashMap<String, String>>,
+    pub name: String,
+    pub selected: usize,
+}
+
+impl RecreateForm {
+    pub fn new(
+        id: ContainerId,
+        name: String,
+        image: String,
+        env: Vec<String>,
+        ports: Vec<String>,
+        mounts: Vec<String>,
+    ) -> Self {
+        let mut fields = vec![FormField {
+            kind: FieldKind::Image,
+            value: image,
+        }];
+        for (kind, values) in [
+            (FieldKind::Env, env),
+            (FieldKind::Port, ports),
+            (FieldKind::Mount, mounts),
+        ] {
+            fields.extend(
+                values
+                    .into_iter()
+                    .chain(std::iter::once(String::new()))
+                    .map(|value| FormField { kind, value }),
+            );
+        }
+        Self {
+            fields,
+            id,
+            image_env: None,
+            name,
+            selected: 0,
+        }
+    }
+
+    pub fn next(&mut self) {
+        self.selected = (self.selected + 1).min(self.fields.len().saturating_sub(1));
+    }
+
+    pub const fn previous(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    /// Type a char into the selected field, when it's the last, empty, field of a section, a new empty field is added after it
+    pub fn push(&mut self, c: char) {
+        push_field(&mut self.fields, self.selected, c);
+    }
+
+    /// Delete the last char of the selected field
+    pub fn pop(&mut self) {
+        if let Some(field) = self.fields.get_mut(self.selected) {
+            field.value.pop();
+        }
+    }
+
+    /// The non-empty, trimmed, values of a section
+    pub fn values(&self, kind: FieldKind) -> Vec<String> {
+        field_values(&self.fields, kind)
+    }
+
+    /// Replace the image, used to recreate the container on a different tag
+    pub fn set_image(&mut self, image: &str) {
+        if let Some(field) = self.fields.iter_mut().find(|i| i.kind == FieldKind::Image) {
+            image.clone_into(&mut field.value);
+        }
+    }
+
+    pub fn image(&self) -> String {
+        self.values(FieldKind::Image)
+            .into_iter()
+            .next()
+            .unwrap_or_default()
+    }
+
+    /// Compare an env field against the image's default env, None if the image env isn't known, or the field isn't `KEY=value`
+    pub fn env_origin(&self, value: &str) -> Option<EnvOrigin> {
+        let (key, value) = value.trim().split_once('=')?;
+        let default = self.image_env.as_ref()?.get(key);
+        Some(match default {
+            Some(default) if default == value => EnvOrigin::Image,
+            Some(_) => EnvOrigin::Changed,
+            None => EnvOrigin::Runtime,
+        })
+    }
+
+    /// Check the fields before recreating, returns the first problem found, so that the container isn't removed only for the create to fail
+    pub fn invalid(&self) -> Option<String> {
+        if self.image().is_empty() {
+            return Some("image is required".to_owned());
+        }
+        invalid_fields(&self.fields)
+    }
+}
+
+/// The locally available tags of a container's image, the container is recreated on the chosen tag
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TagPicker {
+    pub current: String,
+    pub id: ContainerId,
+    pub name: String,
+    pub selected: usize,
+    pub tags: Vec<String>,
+}
+
+impl TagPicker {
+    /// The current tag is selected, so that the neighbouring tags are easy to reach
+    pub fn new(id: ContainerId, name: String, current: String, tags: Vec<String>) -> Self {
+        Self {
+            selected: tags.iter().position(|i| i == &current).unwrap_or_default(),
+            current,
+            id,
+            name,
+            tags,
+        }
+    }
+
+    pub fn next(&mut self) {
+        self.selected = (self.selected + 1).min(self.tags.len().saturating_sub(1));
+    }
+
+    pub const fn previous(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    /// The selected tag, None if it's the tag the container is already using
+    pub fn chosen(&self) -> Option<&str> {
+        self.tags
+            .get(self.selected)
+            .filter(|i| *i != &self.current)
+            .map(String::as_str)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::{EnvOrigin, FieldKind, PortSpec, RecreateForm};
+    use crate::app_data::ContainerId;
+
+    fn gen_form() -> RecreateForm {
+        RecreateForm::new(
+            ContainerId::from("1"),
+            "container_1".to_owned(),
+            "redis:7".to_owned(),
+            vec!["A=1".to_owned()],
+            vec!["6379:6379".to_owned()],
+            vec![],
+        )
+    }
+
+    #[test]
+    /// Ports parsed with, and without, a host ip, host port, and protocol
+    fn test_recreate_port_parse() {
+        assert_eq!(
+            PortSpec::parse("8080:80"),
+            Some(PortSpec {
+                container: "80/tcp".to_owned(),
+                host_ip: None,
+                host_port: Some("8080".to_owned()),
+            })
+        );
+        assert_eq!(
+            PortSpec::parse("127.0.0.1:53:53/udp"),
+            Some(PortSpec {
+                container: "53/udp".to_owned(),
+                host_ip: Some("127.0.0.1".to_owned()),
+                host_port: Some("53".to_owned()),
+            })
+        );
+        assert_eq!(PortSpec::parse("80").unwrap().host_port, None);
+        assert_eq!(
+            PortSpec::parse(":80").unwrap().host_port,
+            Some(String::new())
+        );
+        assert!(PortSpec::parse("80/http").is_none());
+        assert!(PortSpec::parse("99999:80").is_none());
+        assert!(PortSpec::parse("a:b").is_none());
+        assert_eq!(PortSpec::format("80/tcp", "0.0.0.0", "8080"), "8080:80");
+        assert_eq!(
+            PortSpec::format("53/udp", "127.0.0.1", "53"),
+            "127.0.0.1:53:53/udp"
+        );
+    }
+
+    #[test]
+    /// Each list section ends with an empty field, typing into it adds another
+    fn test_recreate_form_edit() {
+        let mut form = gen_form();
+        let kinds = form.fields.iter().map(|i| i.kind).collect::<Vec<_>>();
+        assert_eq!(
+            kinds,
+            [
+                FieldKind::Image,
+                FieldKind::Env,
+                FieldKind::Env,
+                FieldKind::Port,
+                FieldKind::Port,
+                FieldKind::Mount
+            ]
+        );
+
+        form.next();
+        form.next();
+        for c in "B=2".chars() {
+            form.push(c);
+        }
+        assert_eq!(form.fields.len(), 7);
+        assert_eq!(form.values(FieldKind::Env), ["A=1", "B=2"]);
+
+        form.previous();
+        for _ in 0..3 {
+            form.pop();
+        }
+        assert_eq!(form.values(FieldKind::Env), ["B=2"]);
+        assert!(form.invalid().is_none());
+
+        // Image field never grows a second field
+        form.selected = 0;
+        form.push('x');
+        assert_eq!(form.fields.len(), 7);
+        assert_eq!(form.image(), "redis:7x");
+
+        for _ in 0..10 {
+            form.next();
+        }
+        assert_eq!(form.selected, 6);
+    }
+
+    #[test]
+    /// Env is compared against the image defaults, only once the image env is known
+    fn test_recreate_form_env_origin() {
+        let mut form = gen_form();
+        assert!(form.env_origin("A=1").is_none());
+        form.image_env = Some(
+            [("A", "1"), ("B", "2")]
+                .into_iter()
+                .map(|(k, v)| (k.to_owned(), v.to_owned()))
+                .collect(),
+        );
+        assert_eq!(form.env_origin("A=1"), Some(EnvOrigin::Image));
+        assert_eq!(form.env_origin("B=3"), Some(EnvOrigin::Changed));
+        assert_eq!(form.env_origin("B="), Some(EnvOrigin::Changed));
+        assert_eq!(form.env_origin("C=1=2"), Some(EnvOrigin::Runtime));
+        assert!(form.env_origin("NOEQUALS").is_none());
+    }
+
+    #[test]
+    /// Invalid values are reported before anything is recreated
+    fn test_recreate_form_invalid() {
+        let mut form = gen_form();
+        form.fields[1].value = "NOEQUALS".to_owned();
+        assert_eq!(
+            form.invalid(),
+            Some("invalid env: NOEQUALS, expected KEY=value".to_owned())
+        );
+        form.fields[1].value.clear();
+        form.fields[3].value = "http".to_owned();
+        assert_eq!(form.invalid(), Some("invalid port: http".to_owned()));
+        form.fields[3].value.clear();
+        form.fields[5].value = "/data".to_owned();
+        assert!(form.invalid().unwrap().starts_with("invalid mount"));
+        form.fields[0].value.clear();
+        assert_eq!(form.invalid(), Some("image is required".to_owned()));
+    }
+}
@@ -0,0 +1,72 @@
+use crate::app_data::ContainerId;
+
+/// The new name of a container, filled in with its current name
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenameForm {
+    /// Why the name can't be used
+    pub error: Option<String>,
+    pub id: ContainerId,
+    pub name: String,
+    pub text: String,
+}
+
+impl RenameForm {
+    pub fn new(id: ContainerId, name: String) -> Self {
+        Self {
+            error: None,
+            id,
+            text: name.clone(),
+            name,
+        }
+    }
+
+    /// The new name, None if it's unchanged, or isn't a name the daemon accepts, `[a-zA-Z0-9][a-zA-Z0-9_.-]*`, with the reason set as the error
+    pub fn submit(&mut self) -> Option<String> {
+        let text = self.text.trim().trim_start_matches('/');
+        let error = if text.is_empty() {
+            Some("type a name".to_owned())
+        } else if text == self.name {
+            Some("the name is unchanged".to_owned())
+        } else if !text.starts_with(|c: char| c.is_ascii_alphanumeric())
+            || !text
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || "_.-".contains(c))
+        {
+            Some("letters, digits, & _ . - only, starting with a letter or digit".to_owned())
+        } else {
+            None
+        };
+        if error.is_some() {
+            self.error = error;
+            return None;
+        }
+        Some(text.to_owned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RenameForm;
+    use crate::app_data::ContainerId;
+
+    #[test]
+    /// The form is filled in with the current name, an unchanged, or invalid, name is an error
+    fn test_rename_form_submit() {
+        let mut form = RenameForm::new(ContainerId::from("1"), "api".to_owned());
+        assert_eq!(form.text, "api");
+        assert_eq!(form.submit(), None);
+        assert_eq!(form.error.as_deref(), Some("the name is unchanged"));
+
+        form.text = "api v2".to_owned();
+        assert_eq!(form.submit(), None);
+        form.text = "-api".to_owned();
+        assert_eq!(form.submit(), None);
+        assert_eq!(
+            form.error.as_deref(),
+            Some("letters, digits, & _ . - only, starting with a letter or digit")
+        );
+
+        form.text = " /api_v2.1 ".to_owned();
+        assert_eq!(form.submit().as_deref(), Some("api_v2.1"));
+    }
+}
@@ -0,0 +1,258 @@
+use super::recreate::{field_values, invalid_fields, push_field, FieldKind, FormField};
+
+/// The restart policies cycled through in the restart field, `on-failure` can also be typed with a maximum retry count, e.g. `on-failure:3`
+const RESTARTS: [&str; 4] = ["no", "always", "unless-stopped", "on-failure"];
+
+/// The restart policy of a new container, as given to `docker run --restart`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Restart {
+    No,
+    Always,
+    UnlessStopped,
+    /// With an optional maximum retry count
+    OnFailure(Option<i64>),
+}
+
+impl Restart {
+    /// Parse a restart policy, an empty policy is `no`
+    pub fn parse(input: &str) -> Option<Self> {
+        match input.trim() {
+            "" | "no" => Some(Self::No),
+            "always" => Some(Self::Always),
+            "unless-stopped" => Some(Self::UnlessStopped),
+            "on-failure" => Some(Self::OnFailure(None)),
+            x => x
+                .strip_prefix("on-failure:")?
+                .parse::<i64>()
+                .ok()
+                .filter(|i| *i >= 0)
+                .map(|i| Self::OnFailure(Some(i))),
+        }
+    }
+}
+
+/// A container name, as accepted by the daemon, an empty name is given a random name by the daemon
+fn valid_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next().is_some_and(|i| i.is_ascii_alphanumeric())
+        && chars.all(|i| i.is_ascii_alphanumeric() || matches!(i, '_' | '.' | '-'))
+}
+
+/// The choice after, or before, the current value, the first choice if the current value isn't one of them
+fn cycle_value<'a>(choices: &[&'a str], current: &str, forward: bool) -> Option<&'a str> {
+    let len = choices.len();
+    let index = match choices.iter().position(|i| *i == current) {
+        Some(i) if forward => (i + 1) % len,
+        Some(i) => (i + len - 1) % len,
+        None => 0,
+    };
+    choices.get(index).copied()
+}
+
+/// The form to run a new container, from an image, with a name, env vars, ports, mounts, and a restart policy
+/// As with the recreate form, each env var, port, and mount is its own field, and each list section always ends with an empty field
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RunForm {
+    pub fields: Vec<FormField>,
+    /// The host the container is run on, None for the primary host
+    pub host: Option<String>,
+    /// The local image tags, that the image field cycles through
+    pub images: Vec<String>,
+    pub selected: usize,
+}
+
+impl RunForm {
+    pub fn new(image: String, images: Vec<String>, host: Option<String>) -> Self {
+        let fields = [
+            (FieldKind::Image, image),
+            (FieldKind::Name, String::new()),
+            (FieldKind::Env, String::new()),
+            (FieldKind::Port, String::new()),
+            (FieldKind::Mount, String::new()),
+            (FieldKind::Restart, RESTARTS[0].to_owned()),
+        ]
+        .into_iter()
+        .map(|(kind, value)| FormField { kind, value })
+        .collect();
+        Self {
+            fields,
+            host,
+            images,
+            selected: 0,
+        }
+    }
+
+    pub fn next(&mut self) {
+        self.selected = (self.selected + 1).min(self.fields.len().saturating_sub(1));
+    }
+
+    pub const fn previous(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    /// Type a char into the selected field
+    pub fn push(&mut self, c: char) {
+        push_field(&mut self.fields, self.selected, c);
+    }
+
+    /// Delete the last char of the selected field
+    pub fn pop(&mut self) {
+        if let Some(field) = self.fields.get_mut(self.selected) {
+            field.value.pop();
+        }
+    }
+
+    /// Replace the selected field with the next, or previous, local image, or restart policy, other fields are left as they are
+    pub fn cycle(&mut self, forward: bool) {
+        let Some(field) = self.fields.get_mut(self.selected) else {
+            return;
+        };
+        let images = self.images.iter().map(String::as_str).collect::<Vec<_>>();
+        let choices = match field.kind {
+            FieldKind::Image => images.as_slice(),
+            FieldKind::Restart => RESTARTS.as_slice(),
+            _ => return,
+        };
+        if let Some(value) = cycle_value(choices, field.value.trim(), forward) {
+            value.clone_into(&mut field.value);
+        }
+    }
+
+    /// The non-empty, trimmed, values of a section
+    pub fn values(&self, kind: FieldKind) -> Vec<String> {
+        field_values(&self.fields, kind)
+    }
+
+    /// The value of a single field section, empty if it hasn't been filled in
+    fn value(&self, kind: FieldKind) -> String {
+        self.values(kind).into_iter().next().unwrap_or_default()
+    }
+
+    pub fn image(&self) -> String {
+        self.value(FieldKind::Image)
+    }
+
+    /// None if left empty, so that the daemon generates a name
+    pub fn name(&self) -> Option<String> {
+        Some(self.value(FieldKind::Name)).filter(|i| !i.is_empty())
+    }
+
+    pub fn restart(&self) -> Option<Restart> {
+        Restart::parse(&self.value(FieldKind::Restart))
+    }
+
+    /// Check the fields before running, returns the first problem found
+    pub fn invalid(&self) -> Option<String> {
+        if self.image().is_empty() {
+            return Some("image is required".to_owned());
+        }
+        if let Some(name) = self.name().filter(|i| !valid_name(i)) {
+            return Some(format!("invalid name: {name}"));
+        }
+        if self.restart().is_none() {
+            return Some(format!(
+                "invalid restart policy: {}, expected one of {}, or on-failure:count",
+                self.value(FieldKind::Restart),
+                RESTARTS.join(", ")
+            ));
+        }
+        invalid_fields(&self.fields)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::{Restart, RunForm};
+    use crate::ui::FieldKind;
+
+    fn gen_form() -> RunForm {
+        RunForm::new(
+            String::new(),
+            vec!["nginx:latest".to_owned(), "redis:7".to_owned()],
+            None,
+        )
+    }
+
+    #[test]
+    /// Restart policies as given to docker run, on-failure with an optional count
+    fn test_run_form_restart_parse() {
+        assert_eq!(Restart::parse(""), Some(Restart::No));
+        assert_eq!(Restart::parse(" always "), Some(Restart::Always));
+        assert_eq!(
+            Restart::parse("unless-stopped"),
+            Some(Restart::UnlessStopped)
+        );
+        assert_eq!(Restart::parse("on-failure"), Some(Restart::OnFailure(None)));
+        assert_eq!(
+            Restart::parse("on-failure:3"),
+            Some(Restart::OnFailure(Some(3)))
+        );
+        assert!(Restart::parse("on-failure:-1").is_none());
+        assert!(Restart::parse("sometimes").is_none());
+    }
+
+    #[test]
+    /// The image, & restart, fields cycle through their choices, list sections grow as they're typed into
+    fn test_run_form_edit() {
+        let mut form = gen_form();
+        assert_eq!(form.invalid(), Some("image is required".to_owned()));
+        form.cycle(true);
+        assert_eq!(form.image(), "nginx:latest");
+        form.cycle(true);
+        assert_eq!(form.image(), "redis:7");
+        form.cycle(true);
+        assert_eq!(form.image(), "nginx:latest");
+        form.cycle(false);
+        assert_eq!(form.image(), "redis:7");
+
+        form.next();
+        for c in "cache".chars() {
+            form.push(c);
+        }
+        // Name isn't a list section, so never grows a second field
+        assert_eq!(form.fields.len(), 6);
+        assert_eq!(form.name(), Some("cache".to_owned()));
+        form.cycle(true);
+        assert_eq!(form.name(), Some("cache".to_owned()));
+
+        form.next();
+        for c in "A=1".chars() {
+            form.push(c);
+        }
+        assert_eq!(form.fields.len(), 7);
+        assert_eq!(form.values(FieldKind::Env), ["A=1"]);
+
+        for _ in 0..10 {
+            form.next();
+        }
+        assert_eq!(form.fields[form.selected].kind, FieldKind::Restart);
+        assert_eq!(form.restart(), Some(Restart::No));
+        form.cycle(false);
+        assert_eq!(form.restart(), Some(Restart::OnFailure(None)));
+        for c in ":2".chars() {
+            form.push(c);
+        }
+        assert_eq!(form.restart(), Some(Restart::OnFailure(Some(2))));
+        assert!(form.invalid().is_none());
+    }
+
+    #[test]
+    /// Invalid names, & restart policies, are reported before anything is run
+    fn test_run_form_invalid() {
+        let mut form = gen_form();
+        form.fields[0].value = "redis:7".to_owned();
+        form.fields[1].value = "-cache".to_owned();
+        assert_eq!(form.invalid(), Some("invalid name: -cache".to_owned()));
+        form.fields[1].value = "cache_1.a-b".to_owned();
+        assert!(form.invalid().is_none());
+        form.fields[5].value = "sometimes".to_owned();
+        assert!(form
+            .invalid()
+            .unwrap()
+            .starts_with("invalid restart policy: sometimes"));
+        form.fields[5].value.clear();
+        form.fields[3].value = "http".to_owned();
+        assert_eq!(form.invalid(), Some("invalid port: http".to_owned()));
+    }
+}
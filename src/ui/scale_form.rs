@@ -0,0 +1,142 @@
+use crate::app_data::ServiceItem;
+
+/// Most replicas that a service can be scaled to from the form, a typo of an extra digit would otherwise start a great many tasks
+const MAX_REPLICAS: u64 = 100;
+
+/// The replicas a swarm service, or every replicated service of its stack, is scaled to, filled in with its current desired replicas
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScaleForm {
+    /// Why the replicas can't be used
+    pub error: Option<String>,
+    /// The host of the services panel, the primary host is None
+    pub host: Option<String>,
+    pub service: ServiceItem,
+    /// The replicated services of the service's stack, empty if it isn't in a stack
+    pub stack: Vec<ServiceItem>,
+    pub text: String,
+    /// Scale every replicated service of the stack, rather than only the service, toggled with ( tab )
+    pub whole_stack: bool,
+}
+
+impl ScaleForm {
+    /// The global services of the stack are left out, as they can't be scaled
+    pub fn new(host: Option<String>, service: ServiceItem, stack: Vec<ServiceItem>) -> Self {
+        Self {
+            error: None,
+            host,
+            text: service.desired.to_string(),
+            service,
+            stack: stack.into_iter().filter(|i| !i.global).collect(),
+            whole_stack: false,
+        }
+    }
+
+    /// Only digits can be typed
+    pub fn push(&mut self, c: char) {
+        if c.is_ascii_digit() {
+            self.text.push(c);
+        }
+    }
+
+    /// Switch between scaling the service, & its whole stack, a service that isn't in a stack is only scaled itself
+    pub fn toggle_stack(&mut self) {
+        self.whole_stack = !self.whole_stack && self.service.stack.is_some();
+        self.error = None;
+    }
+
+    /// The stack that's scaled, None if only the service is
+    pub fn scaled_stack(&self) -> Option<&str> {
+        self.service.stack.as_deref().filter(|_| self.whole_stack)
+    }
+
+    /// The replicas, None if they're unchanged, or more than MAX_REPLICAS, with the reason set as the error
+    /// A stack is unchanged if every one of its replicated services already has the replicas
+    pub fn submit(&mut self) -> Option<u64> {
+        let unchanged = |i: u64| {
+            if self.whole_stack {
+                self.stack.iter().all(|service| service.desired == i)
+            } else {
+                i == self.service.desired
+            }
+        };
+        let error = match self.text.parse::<u64>() {
+            Err(_) => Some("type a number of replicas".to_owned()),
+            Ok(i) if unchanged(i) => Some("the replicas are unchanged".to_owned()),
+            Ok(i) if i > MAX_REPLICAS => Some(format!("at most {MAX_REPLICAS} replicas")),
+            Ok(i) => return Some(i),
+        };
+        self.error = error;
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ScaleForm, MAX_REPLICAS};
+    use crate::app_data::ServiceItem;
+
+    fn gen_service(name: &str, desired: u64, global: bool) -> ServiceItem {
+        ServiceItem {
+            desired,
+            global,
+            id: "q8k2xv1n4m7b".to_owned(),
+            image: "nginx:1.27".to_owned(),
+            name: name.to_owned(),
+            running: desired,
+            stack: Some("site".to_owned()),
+            update: None,
+        }
+    }
+
+    #[test]
+    /// The form is filled in with the desired replicas, only digits can be typed, & unchanged, or too many, replicas are an error
+    fn test_scale_form_submit() {
+        let mut form = ScaleForm::new(None, gen_service("site_web", 3, false), vec![]);
+        assert_eq!(form.text, "3");
+        assert_eq!(form.submit(), None);
+        assert_eq!(form.error.as_deref(), Some("the replicas are unchanged"));
+
+        form.text.clear();
+        form.push('x');
+        assert_eq!(form.submit(), None);
+        assert_eq!(form.error.as_deref(), Some("type a number of replicas"));
+
+        form.text = (MAX_REPLICAS + 1).to_string();
+        assert_eq!(form.submit(), None);
+        form.text.clear();
+        form.push('0');
+        assert_eq!(form.submit(), Some(0));
+    }
+
+    #[test]
+    /// The whole stack is only unchanged if each of its replicated services already has the replicas, a service without a stack can't toggle to it
+    fn test_scale_form_stack() {
+        let service = gen_service("site_web", 3, false);
+        let stack = vec![
+            service.clone(),
+            gen_service("site_api", 2, false),
+            gen_service("site_agent", 1, true),
+        ];
+        let mut form = ScaleForm::new(None, service.clone(), stack);
+        assert_eq!(form.stack.len(), 2);
+        assert_eq!(form.scaled_stack(), None);
+        form.toggle_stack();
+        assert_eq!(form.scaled_stack(), Some("site"));
+        assert_eq!(form.submit(), Some(3));
+        form.text = "2".to_owned();
+        assert_eq!(form.submit(), Some(2));
+        form.toggle_stack();
+        assert_eq!(form.scaled_stack(), None);
+
+        let mut form = ScaleForm::new(
+            None,
+            ServiceItem {
+                stack: None,
+                ..service
+            },
+            vec![],
+        );
+        form.toggle_stack();
+        assert!(!form.whole_stack);
+    }
+}
@@ -0,0 +1,119 @@
+use std::fmt;
+
+/// Why the value of an environment variable looks like a plaintext credential
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SecretKind {
+    /// Has the prefix of a well known token, with what the token is
+    Token(&'static str),
+    /// A url with a password in it
+    UrlCredentials,
+    /// The variable name says it's a secret
+    Named,
+    /// Looks random, like a generated key
+    Entropy,
+}
+
+impl fmt::Display for SecretKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Token(name) => write!(f, "{name}"),
+            Self::UrlCredentials => write!(f, "password in url"),
+            Self::Named => write!(f, "secret name"),
+            Self::Entropy => write!(f, "high entropy"),
+        }
+    }
+}
+
+/// An environment variable that looks like a plaintext credential, only a masked preview of the value is kept
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SecretFinding {
+    pub container: String,
+    pub key: String,
+    pub kind: SecretKind,
+    pub preview: String,
+}
+
+/// The findings of a scan of the environment of every container on a host, sorted by container, then variable
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SecretScan {
+    pub findings: Vec<SecretFinding>,
+    /// The host that was scanned, the primary host is None
+    pub host: Option<String>,
+    /// The number of containers that were scanned
+    pub scanned: usize,
+    pub selected: usize,
+}
+
+impl SecretScan {
+    pub fn new(mut findings: Vec<SecretFinding>, scanned: usize, host: Option<String>) -> Self {
+        findings.sort_by(|a, b| {
+            a.container
+                .cmp(&b.container)
+                .then_with(|| a.key.cmp(&b.key))
+        });
+        Self {
+            findings,
+            host,
+            scanned,
+            selected: 0,
+        }
+    }
+
+    pub fn next(&mut self) {
+        self.selected = (self.selected + 1).min(self.findings.len().saturating_sub(1));
+    }
+
+    pub const fn previous(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    /// The number of containers with at least one finding
+    pub fn flagged(&self) -> usize {
+        let mut containers = self
+            .findings
+            .iter()
+            .map(|i| &i.container)
+            .collect::<Vec<_>>();
+        containers.dedup();
+        containers.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SecretFinding, SecretKind, SecretScan};
+
+    #[test]
+    /// Findings are sorted by container then variable, & the selection stays within the findings
+    fn test_secret_scan_new() {
+        let finding = |container: &str, key: &str| SecretFinding {
+            container: container.to_owned(),
+            key: key.to_owned(),
+            kind: SecretKind::Named,
+            preview: String::new(),
+        };
+        let mut scan = SecretScan::new(
+            vec![
+                finding("web", "TOKEN"),
+                finding("db", "PASSWORD"),
+                finding("web", "API_KEY"),
+            ],
+            4,
+            None,
+        );
+        assert_eq!(
+            scan.findings
+                .iter()
+                .map(|i| i.key.as_str())
+                .collect::<Vec<_>>(),
+            ["PASSWORD", "API_KEY", "TOKEN"]
+        );
+        assert_eq!(scan.flagged(), 2);
+        scan.previous();
+        assert_eq!(scan.selected, 0);
+        scan.next();
+        scan.next();
+        scan.next();
+        assert_eq!(scan.selected, 2);
+    }
+}
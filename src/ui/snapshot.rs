@@ -0,0 +1,267 @@
+use std::{
+    fmt::Write as _,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use ratatui::{
+    buffer::Buffer,
+    style::{Color, Modifier},
+};
+
+/// Default colors used when a cell has no set foreground or background, matches a typical dark terminal
+const DEFAULT_FG: &str = "#d0d0d0";
+const DEFAULT_BG: &str = "#000000";
+
+/// Convert the buffer into plain text, one line per row, with trailing whitespace removed
+pub fn to_text(buffer: &Buffer) -> String {
+    let area = buffer.area;
+    let mut output = String::new();
+    for y in area.top()..area.bottom() {
+        let row = (area.left()..area.right())
+            .map(|x| buffer.get(x, y).symbol())
+            .collect::<String>();
+        output.push_str(row.trim_end());
+        output.push('\n');
+    }
+    output
+}
+
+/// Convert a ratatui color into a css hex color, using the xterm palette for the named & indexed colors
+fn to_css(color: Color, default: &str) -> String {
+    let named = |i: u8| -> String {
+        [
+            "#000000", "#cd0000", "#00cd00", "#cdcd00", "#0000ee", "#cd00cd", "#00cdcd", "#e5e5e5",
+            "#7f7f7f", "#ff0000", "#00ff00", "#ffff00", "#5c5cff", "#ff00ff", "#00ffff", "#ffffff",
+        ]
+        .get(usize::from(i))
+        .map_or_else(|| default.to_owned(), |i| (*i).to_owned())
+    };
+    match color {
+        Color::Reset => default.to_owned(),
+        Color::Black => named(0),
+        Color::Red => named(1),
+        Color::Green => named(2),
+        Color::Yellow => named(3),
+        Color::Blue => named(4),
+        Color::Magenta => named(5),
+        Color::Cyan => named(6),
+        Color::Gray => named(7),
+        Color::DarkGray => named(8),
+        Color::LightRed => named(9),
+        Color::LightGreen => named(10),
+        Color::LightYellow => named(11),
+        Color::LightBlue => named(12),
+        Color::LightMagenta => named(13),
+        Color::LightCyan => named(14),
+        Color::White => named(15),
+        Color::Rgb(r, g, b) => format!("#{r:02x}{g:02x}{b:02x}"),
+        Color::Indexed(i) if i < 16 => named(i),
+        // 6x6x6 color cube
+        Color::Indexed(i) if i < 232 => {
+            let cube = |x: u8| if x == 0 { 0 } else { x * 40 + 55 };
+            let i = i - 16;
+            format!(
+                "#{:02x}{:02x}{:02x}",
+                cube(i / 36),
+                cube(i / 6 % 6),
+                cube(i % 6)
+            )
+        }
+        // Grayscale ramp
+        Color::Indexed(i) => {
+            let x = (i - 232) * 10 + 8;
+            format!("#{x:02x}{x:02x}{x:02x}")
+        }
+    }
+}
+
+/// Convert a ratatui color into the parameters of an ansi SGR escape sequence, base is 30 for foreground colors, and 40 for background colors
+fn to_sgr(color: Color, base: u8) -> String {
+    let offset = |i: u8| (base + i).to_string();
+    let bright = |i: u8| (base + 60 + i).to_string();
+    match color {
+        Color::Reset => offset(9),
+        Color::Black => offset(0),
+        Color::Red => offset(1),
+        Color::Green => offset(2),
+        Color::Yellow => offset(3),
+        Color::Blue => offset(4),
+        Color::Magenta => offset(5),
+        Color::Cyan => offset(6),
+        Color::Gray => offset(7),
+        Color::DarkGray => bright(0),
+        Color::LightRed => bright(1),
+        Color::LightGreen => bright(2),
+        Color::LightYellow => bright(3),
+        Color::LightBlue => bright(4),
+        Color::LightMagenta => bright(5),
+        Color::LightCyan => bright(6),
+        Color::White => bright(7),
+        Color::Rgb(r, g, b) => format!("{};2;{r};{g};{b}", base + 8),
+        Color::Indexed(i) => format!("{};5;{i}", base + 8),
+    }
+}
+
+/// Convert the buffer into a string of ansi escape sequences, that, when written to a terminal, redraws the whole screen
+pub fn to_ansi(buffer: &Buffer) -> String {
+    let area = buffer.area;
+    let mut output = String::from("\x1b[H\x1b[2J");
+    for y in area.top()..area.bottom() {
+        write!(output, "\x1b[{};1H", y - area.top() + 1).ok();
+        let mut current = None;
+        for x in area.left()..area.right() {
+            let cell = buffer.get(x, y);
+            let mut sgr = vec!["0".to_owned(), to_sgr(cell.fg, 30), to_sgr(cell.bg, 40)];
+            for (modifier, code) in [
+                (Modifier::BOLD, "1"),
+                (Modifier::DIM, "2"),
+                (Modifier::ITALIC, "3"),
+                (Modifier::UNDERLINED, "4"),
+                (Modifier::REVERSED, "7"),
+                (Modifier::CROSSED_OUT, "9"),
+            ] {
+                if cell.modifier.contains(modifier) {
+                    sgr.push(code.to_owned());
+                }
+            }
+            let sgr = sgr.join(";");
+            if current.as_ref() != Some(&sgr) {
+                write!(output, "\x1b[{sgr}m").ok();
+                current = Some(sgr);
+            }
+            output.push_str(cell.symbol());
+        }
+    }
+    output.push_str("\x1b[0m");
+    output
+}
+
+/// Escape the html special characters
+fn escape(input: &str) -> String {
+    input
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// Convert the buffer into a self contained html page, consecutive cells with the same style are placed into a single span
+pub fn to_html(buffer: &Buffer, title: &str) -> String {
+    let area = buffer.area;
+    let mut output = format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n</head>\n<body style=\"background:{DEFAULT_BG}\">\n<pre style=\"font-family:monospace;color:{DEFAULT_FG};background:{DEFAULT_BG}\">",
+        escape(title)
+    );
+    for y in area.top()..area.bottom() {
+        let mut current: Option<(String, String)> = None;
+        for x in area.left()..area.right() {
+            let cell = buffer.get(x, y);
+            let (mut fg, mut bg) = (to_css(cell.fg, DEFAULT_FG), to_css(cell.bg, DEFAULT_BG));
+            if cell.modifier.contains(Modifier::REVERSED) {
+                std::mem::swap(&mut fg, &mut bg);
+            }
+            let mut style = format!("color:{fg};background:{bg}");
+            if cell.modifier.contains(Modifier::BOLD) {
+                style.push_str(";font-weight:bold");
+            }
+            if cell.modifier.contains(Modifier::ITALIC) {
+                style.push_str(";font-style:italic");
+            }
+            if cell.modifier.contains(Modifier::UNDERLINED) {
+                style.push_str(";text-decoration:underline");
+            }
+            match current.as_mut() {
+                Some((current_style, text)) if current_style == &style => {
+                    text.push_str(cell.symbol());
+                }
+                _ => {
+                    if let Some((style, text)) = current.take() {
+                        write!(output, "<span style=\"{style}\">{}</span>", escape(&text)).ok();
+                    }
+                    current = Some((style, cell.symbol().to_owned()));
+                }
+            }
+        }
+        if let Some((style, text)) = current.take() {
+            write!(output, "<span style=\"{style}\">{}</span>", escape(&text)).ok();
+        }
+        output.push('\n');
+    }
+    output.push_str("</pre>\n</body>\n</html>\n");
+    output
+}
+
+/// Save the buffer as both a plain text, and a html, file, into the given directory, returns the path of the text file
+pub fn save(buffer: &Buffer, dir: &Path, timestamp: u64) -> std::io::Result<PathBuf> {
+    let name = format!("oxker_snapshot_{timestamp}");
+    let text_path = dir.join(format!("{name}.txt"));
+    fs::write(&text_path, to_text(buffer))?;
+    fs::write(dir.join(format!("{name}.html")), to_html(buffer, &name))?;
+    Ok(text_path)
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::{
+        buffer::Buffer,
+        layout::Rect,
+        style::{Color, Modifier, Style},
+    };
+
+    use super::{to_ansi, to_css, to_html, to_text};
+
+    fn gen_buffer() -> Buffer {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 2));
+        buffer.set_string(0, 0, "a<b", Style::default().fg(Color::Red));
+        buffer.set_string(
+            3,
+            0,
+            "ok",
+            Style::default()
+                .fg(Color::Rgb(1, 2, 3))
+                .add_modifier(Modifier::BOLD),
+        );
+        buffer.set_string(0, 1, "  x", Style::default());
+        buffer
+    }
+
+    #[test]
+    /// Plain text has trailing whitespace removed
+    fn test_snapshot_text() {
+        assert_eq!(to_text(&gen_buffer()), "a<bok\n  x\n");
+    }
+
+    #[test]
+    /// Ansi output redraws the whole screen, only changing the style when needed
+    fn test_snapshot_ansi() {
+        let result = to_ansi(&gen_buffer());
+        assert_eq!(
+            result,
+            "\x1b[H\x1b[2J\x1b[1;1H\x1b[0;31;49ma<b\x1b[0;38;2;1;2;3;49;1mok\x1b[0;39;49m   \x1b[2;1H\x1b[0;39;49m  x     \x1b[0m"
+        );
+    }
+
+    #[test]
+    /// Colors converted to css hex values
+    fn test_snapshot_css() {
+        assert_eq!(to_css(Color::Reset, "#123456"), "#123456");
+        assert_eq!(to_css(Color::Red, "#123456"), "#cd0000");
+        assert_eq!(to_css(Color::Rgb(255, 233, 193), ""), "#ffe9c1");
+        assert_eq!(to_css(Color::Indexed(9), ""), "#ff0000");
+        assert_eq!(to_css(Color::Indexed(196), ""), "#ff0000");
+        assert_eq!(to_css(Color::Indexed(232), ""), "#080808");
+    }
+
+    #[test]
+    /// Html is escaped, and cells of the same style are placed in a single span
+    fn test_snapshot_html() {
+        let result = to_html(&gen_buffer(), "title");
+        assert!(result.contains("<title>title</title>"));
+        assert!(result.contains(
+            "<span style=\"color:#cd0000;background:#000000\">a&lt;b</span><span style=\"color:#010203;background:#000000;font-weight:bold\">ok</span>"
+        ));
+        assert!(
+            result.contains("<span style=\"color:#d0d0d0;background:#000000\">  x     </span>\n")
+        );
+    }
+}
@@ -0,0 +1,116 @@
+use std::time::Duration;
+
+/// Template used when `--status-bar` is given without a value
+pub const DEFAULT_TEMPLATE: &str =
+    "{host} {latency} | {running}/{total} running | updated {refresh} ago | {alerts} | {keys}";
+
+/// Short key hints, for the {keys} segment
+const KEY_HINTS: &str = "( h ) help ( q ) quit";
+
+/// Values available to the status bar template, each segment, in braces, is replaced by its value
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatusValues {
+    pub alerts: Vec<String>,
+    pub host: String,
+    pub latency: Option<Duration>,
+    pub refresh: Option<Duration>,
+    pub resources: Option<String>,
+    pub running: usize,
+    pub total: usize,
+}
+
+impl StatusValues {
+    /// Format the value of a single segment, None if the segment is unknown
+    fn segment(&self, name: &str) -> Option<String> {
+        Some(match name {
+            "alerts" => {
+                if self.alerts.is_empty() {
+                    "no alerts".to_owned()
+                } else {
+                    self.alerts.join(", ")
+                }
+            }
+            "host" => self.host.clone(),
+            "keys" => KEY_HINTS.to_owned(),
+            "latency" => self
+                .latency
+                .map_or_else(|| "-".to_owned(), |i| format!("{}ms", i.as_millis())),
+            "refresh" => self
+                .refresh
+                .map_or_else(|| "-".to_owned(), |i| format!("{}s", i.as_secs())),
+            "resources" => self.resources.clone().unwrap_or_else(|| "-".to_owned()),
+            "running" => self.running.to_string(),
+            "stopped" => self.total.saturating_sub(self.running).to_string(),
+            "total" => self.total.to_string(),
+            _ => return None,
+        })
+    }
+
+    /// Replace each `{segment}` in the template, unknown segments, and unclosed braces, are left as is
+    pub fn render(&self, template: &str) -> String {
+        let mut output = String::with_capacity(template.len());
+        let mut rest = template;
+        while let Some(start) = rest.find('{') {
+            output.push_str(&rest[..start]);
+            rest = &rest[start..];
+            if let Some(end) = rest.find('}') {
+                let name = &rest[1..end];
+                if let Some(value) = self.segment(name) {
+                    output.push_str(&value);
+                } else {
+                    output.push_str(&rest[..=end]);
+                }
+                rest = &rest[end + 1..];
+            } else {
+                break;
+            }
+        }
+        output.push_str(rest);
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{StatusValues, DEFAULT_TEMPLATE};
+
+    fn gen_values() -> StatusValues {
+        StatusValues {
+            alerts: vec![],
+            host: "local".to_owned(),
+            latency: Some(Duration::from_millis(12)),
+            refresh: Some(Duration::from_millis(2500)),
+            resources: Some("8 cpu 23%, 15.50 GB 41%".to_owned()),
+            running: 2,
+            total: 5,
+        }
+    }
+
+    #[test]
+    /// All segments replaced in the default template
+    fn test_status_bar_default() {
+        assert_eq!(
+            gen_values().render(DEFAULT_TEMPLATE),
+            "local 12ms | 2/5 running | updated 2s ago | no alerts | ( h ) help ( q ) quit"
+        );
+    }
+
+    #[test]
+    /// Unknown segments, and unclosed braces, left in place
+    fn test_status_bar_custom() {
+        let mut values = gen_values();
+        values.alerts = vec!["clock skew +10s".to_owned(), "one".to_owned()];
+        values.latency = None;
+        values.refresh = None;
+        assert_eq!(
+            values.render("{alerts} {stopped} {nope} {refresh} {latency} {host"),
+            "clock skew +10s, one 3 {nope} - - {host"
+        );
+        assert_eq!(values.render("{resources}"), "8 cpu 23%, 15.50 GB 41%");
+        values.resources = None;
+        assert_eq!(values.render("{resources}"), "-");
+        assert_eq!(values.render("no segments"), "no segments");
+    }
+}
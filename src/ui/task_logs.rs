@@ -0,0 +1,58 @@
+/// The logs of the tasks of a swarm service that run on the host, merged oldest first, each line with the name of the task that logged it
+/// The daemon's service logs aren't available, so the tasks on other nodes of the swarm aren't included
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaskLogs {
+    pub lines: Vec<String>,
+    pub name: String,
+    /// First row shown, the newest lines are shown to begin with
+    pub scroll: usize,
+    /// Number of the service's task containers on the host, running or not
+    pub tasks: usize,
+}
+
+impl TaskLogs {
+    pub const fn new(name: String, tasks: usize, lines: Vec<String>) -> Self {
+        Self {
+            lines,
+            name,
+            scroll: usize::MAX,
+            tasks,
+        }
+    }
+
+    /// Scroll by a number of rows
+    pub const fn scroll(&mut self, up: bool, rows: usize) {
+        self.scroll = if up {
+            self.scroll.saturating_sub(rows)
+        } else {
+            self.scroll.saturating_add(rows)
+        };
+    }
+
+    /// The rows that fit in the height, the scroll is kept within the lines, so that the last row is never above the bottom
+    pub fn visible(&mut self, height: usize) -> &[String] {
+        self.scroll = self.scroll.min(self.lines.len().saturating_sub(height));
+        let end = (self.scroll + height).min(self.lines.len());
+        &self.lines[self.scroll..end]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TaskLogs;
+
+    #[test]
+    /// The newest lines are shown to begin with, & scrolling stays within the lines
+    fn test_task_logs_visible() {
+        let lines = (0..10).map(|i| format!("line {i}")).collect();
+        let mut logs = TaskLogs::new("site_web".to_owned(), 2, lines);
+        assert_eq!(logs.visible(4), ["line 6", "line 7", "line 8", "line 9"]);
+        logs.scroll(true, 2);
+        assert_eq!(logs.visible(4)[0], "line 4");
+        logs.scroll(true, usize::MAX);
+        assert_eq!(logs.visible(4)[0], "line 0");
+        logs.scroll(false, 100);
+        assert_eq!(logs.visible(4)[3], "line 9");
+        assert_eq!(logs.visible(20).len(), 10);
+    }
+}
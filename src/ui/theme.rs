@@ -0,0 +1,582 @@
+use std::str::FromStr;
+
+use ratatui::style::{Color, Style};
+
+use super::Palette;
+use crate::app_data::State;
+
+/// Preset ui, & state, colors, set with `--theme`, the color-blind presets avoid the color pairs that each type of color blindness confuses
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum ThemePreset {
+    /// The original colors, running in green, exited in red, adapted to the colors of the terminal, if it replies to being queried for them
+    #[value(alias = "dark")]
+    Default,
+    /// For a light terminal, darker state colors, & grey popups
+    Light,
+    /// The Solarized dark palette
+    Solarized,
+    /// The Dracula palette
+    Dracula,
+    /// Red-green safe, for reduced green sensitivity, running in blue, exited in vermillion
+    Deuteranopia,
+    /// Red-green safe, for reduced red sensitivity, running in blue, exited in orange, as red appears dark
+    Protanopia,
+    /// Blue-yellow safe, running in green, exited in red, paused in pink rather than yellow
+    Tritanopia,
+}
+
+/// Colors used to display each container state, can be customised with `--state-color`
+/// With a color-blind preset, state information that was only shown by color also gets a text cue
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StateColors {
+    pub cues: bool,
+    pub dead: Color,
+    pub exited: Color,
+    pub paused: Color,
+    pub removing: Color,
+    pub restarting: Color,
+    pub running: Color,
+    pub unhealthy: Color,
+    pub unknown: Color,
+}
+
+impl Default for StateColors {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl StateColors {
+    pub const fn new() -> Self {
+        Self {
+            cues: false,
+            dead: State::Dead.get_color(),
+            exited: State::Exited.get_color(),
+            paused: State::Paused.get_color(),
+            removing: State::Removing.get_color(),
+            restarting: State::Restarting.get_color(),
+            running: State::Running.get_color(),
+            unhealthy: Color::LightRed,
+            unknown: State::Unknown.get_color(),
+        }
+    }
+
+    /// The colors of a preset, the colors are from the Okabe-Ito palette, which stays distinct with each type of color blindness
+    pub const fn preset(preset: ThemePreset) -> Self {
+        let (blue, orange, pink, sky, vermillion, yellow, green) = (
+            Color::Rgb(0, 114, 178),
+            Color::Rgb(230, 159, 0),
+            Color::Rgb(204, 121, 167),
+            Color::Rgb(86, 180, 233),
+            Color::Rgb(213, 94, 0),
+            Color::Rgb(240, 228, 66),
+            Color::Rgb(0, 158, 115),
+        );
+        match preset {
+            ThemePreset::Default => Self::new(),
+            ThemePreset::Light => Self {
+                cues: false,
+                dead: Color::Rgb(175, 0, 0),
+                exited: Color::Rgb(175, 0, 0),
+                paused: Color::Rgb(175, 95, 0),
+                removing: Color::Rgb(135, 0, 135),
+                restarting: Color::Rgb(0, 95, 175),
+                running: Color::Rgb(0, 135, 0),
+                unhealthy: Color::Rgb(215, 0, 0),
+                unknown: Color::Rgb(88, 88, 88),
+            },
+            ThemePreset::Solarized => Self {
+                cues: false,
+                dead: Color::Rgb(220, 50, 47),
+                exited: Color::Rgb(220, 50, 47),
+                paused: Color::Rgb(181, 137, 0),
+                removing: Color::Rgb(211, 54, 130),
+                restarting: Color::Rgb(42, 161, 152),
+                running: Color::Rgb(133, 153, 0),
+                unhealthy: Color::Rgb(203, 75, 22),
+                unknown: Color::Rgb(131, 148, 150),
+            },
+            ThemePreset::Dracula => Self {
+                cues: false,
+                dead: Color::Rgb(255, 85, 85),
+                exited: Color::Rgb(255, 85, 85),
+                paused: Color::Rgb(241, 250, 140),
+                removing: Color::Rgb(255, 121, 198),
+                restarting: Color::Rgb(139, 233, 253),
+                running: Color::Rgb(80, 250, 123),
+                unhealthy: Color::Rgb(255, 184, 108),
+                unknown: Color::Rgb(98, 114, 164),
+            },
+            ThemePreset::Deuteranopia => Self {
+                cues: true,
+                dead: vermillion,
+                exited: vermillion,
+                paused: yellow,
+                removing: pink,
+                restarting: sky,
+                running: blue,
+                unhealthy: orange,
+                unknown: Color::Gray,
+            },
+            ThemePreset::Protanopia => Self {
+                cues: true,
+                dead: orange,
+                exited: orange,
+                paused: yellow,
+                removing: pink,
+                restarting: sky,
+                running: blue,
+                unhealthy: pink,
+                unknown: Color::Gray,
+            },
+            ThemePreset::Tritanopia => Self {
+                cues: true,
+                dead: vermillion,
+                exited: vermillion,
+                paused: pink,
+                removing: orange,
+                restarting: sky,
+                running: green,
+                unhealthy: orange,
+                unknown: Color::Gray,
+            },
+        }
+    }
+
+    /// Keep the state colors readable on the terminal's background, see `Palette::readable`
+    pub fn with_palette(self, palette: &Palette) -> Self {
+        Self {
+            dead: palette.readable(self.dead),
+            exited: palette.readable(self.exited),
+            paused: palette.readable(self.paused),
+            removing: palette.readable(self.removing),
+            restarting: palette.readable(self.restarting),
+            running: palette.readable(self.running),
+            unhealthy: palette.readable(self.unhealthy),
+            unknown: palette.readable(self.unknown),
+            ..self
+        }
+    }
+
+    /// Text of a given state, with cues a running container with an unhealthy healthcheck is shown as unhealthy, rather than only by color
+    pub fn label(&self, state: State, unhealthy: bool) -> String {
+        if self.cues && unhealthy && state == State::Running {
+            "! unhealthy".to_owned()
+        } else {
+            state.to_string()
+        }
+    }
+
+    /// With cues, the state appended to a title that is otherwise only colored by state, nothing for a running container
+    pub fn cue(&self, state: State) -> String {
+        if self.cues && state != State::Running {
+            format!(" - {state}")
+        } else {
+            String::new()
+        }
+    }
+
+    /// Get the color for a given state, a running container with an unhealthy healthcheck uses the unhealthy color
+    pub const fn get(&self, state: State, unhealthy: bool) -> Color {
+        match state {
+            State::Dead => self.dead,
+            State::Exited => self.exited,
+            State::Paused => self.paused,
+            State::Removing => self.removing,
+            State::Restarting => self.restarting,
+            State::Running if unhealthy => self.unhealthy,
+            State::Running => self.running,
+            State::Unknown => self.unknown,
+        }
+    }
+
+    /// Set a color from a `state=color` string, color can be a name, `#rrggbb`, or an ansi index
+    pub fn set(&mut self, input: &str) -> Result<(), String> {
+        let (state, color) = input
+            .split_once('=')
+            .ok_or_else(|| format!("\"{input}\" should be in the format state=color"))?;
+        let color = Color::from_str(color.trim())
+            .map_err(|_| format!("\"{}\" is not a valid color", color.trim()))?;
+        let target = match state.trim().to_lowercase().as_str() {
+            "dead" => &mut self.dead,
+            "exited" => &mut self.exited,
+            "paused" => &mut self.paused,
+            "removing" => &mut self.removing,
+            "restarting" => &mut self.restarting,
+            "running" => &mut self.running,
+            "unhealthy" => &mut self.unhealthy,
+            "unknown" => &mut self.unknown,
+            x => return Err(format!("\"{x}\" is not a valid state")),
+        };
+        *target = color;
+        Ok(())
+    }
+}
+
+/// Colors of the ui, rather than of a container state, set by `--theme`, each can be overridden with `--ui-color`, or the `[colors]` table of the config file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Theme {
+    /// Background of the header, the status bar, & the help panel
+    pub accent: Color,
+    /// Crash loops, restart storms, heavy throttling, & rising values in top mode
+    pub alert: Color,
+    /// Border of the selected panel
+    pub border: Color,
+    pub chart_cpu: Color,
+    pub chart_memory: Color,
+    /// Disk reads, in the io chart
+    pub chart_read: Color,
+    /// Network received, in the io chart, & the containers panel
+    pub chart_rx: Color,
+    /// Network sent, in the io chart, & the containers panel
+    pub chart_tx: Color,
+    /// Disk writes, in the io chart
+    pub chart_write: Color,
+    /// Errors, & warnings, shown in the popups
+    pub error: Color,
+    /// Text on the accent
+    pub header_fg: Color,
+    /// The sorted column of the header, & the titles of the help panel, on the accent
+    pub header_highlight: Color,
+    /// Matched, & changed, text, ids, & the background of the info box
+    pub highlight: Color,
+    pub log_text: Color,
+    /// Ready containers, passed probes, running periods of the timeline, & falling values in top mode
+    pub ok: Color,
+    pub popup_bg: Color,
+    pub popup_fg: Color,
+    /// Background of the selected row of a panel, reset leaves it as is
+    pub selected: Color,
+    /// Text that stands out from the log text, the ports, & the group rows of the containers panel
+    pub text: Color,
+    /// Notes, port conflicts, latency, clock skew, & pending service updates
+    pub warning: Color,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Theme {
+    /// Names of the ui elements, as given to `--ui-color`, in the order of the fields
+    const ELEMENTS: [&'static str; 20] = [
+        "accent",
+        "alert",
+        "border",
+        "chart_cpu",
+        "chart_memory",
+        "chart_read",
+        "chart_rx",
+        "chart_tx",
+        "chart_write",
+        "error",
+        "header_fg",
+        "header_highlight",
+        "highlight",
+        "log_text",
+        "ok",
+        "popup_bg",
+        "popup_fg",
+        "selected",
+        "text",
+        "warning",
+    ];
+
+    pub const fn new() -> Self {
+        Self {
+            accent: Color::Magenta,
+            alert: Color::LightRed,
+            border: Color::LightCyan,
+            chart_cpu: Color::Magenta,
+            chart_memory: Color::Cyan,
+            chart_read: Color::Green,
+            chart_rx: Color::Rgb(255, 233, 193),
+            chart_tx: Color::Rgb(205, 140, 140),
+            chart_write: Color::Yellow,
+            error: Color::Red,
+            header_fg: Color::Black,
+            header_highlight: Color::White,
+            highlight: Color::Blue,
+            log_text: Color::Reset,
+            ok: Color::Green,
+            popup_bg: Color::White,
+            popup_fg: Color::Black,
+            selected: Color::Reset,
+            text: Color::White,
+            warning: Color::Yellow,
+        }
+    }
+
+    /// The ui colors of a preset, the color-blind presets only change the state colors
+    pub const fn preset(preset: ThemePreset) -> Self {
+        match preset {
+            ThemePreset::Default
+            | ThemePreset::Deuteranopia
+            | ThemePreset::Protanopia
+            | ThemePreset::Tritanopia => Self::new(),
+            ThemePreset::Light => Self {
+                accent: Color::Rgb(95, 135, 215),
+                alert: Color::Rgb(215, 0, 0),
+                border: Color::Rgb(0, 95, 175),
+                chart_cpu: Color::Rgb(135, 0, 135),
+                chart_memory: Color::Rgb(0, 95, 175),
+                chart_read: Color::Rgb(0, 135, 0),
+                chart_rx: Color::Rgb(175, 95, 0),
+                chart_tx: Color::Rgb(135, 0, 0),
+                chart_write: Color::Rgb(175, 135, 0),
+                error: Color::Rgb(175, 0, 0),
+                header_fg: Color::Black,
+                header_highlight: Color::White,
+                highlight: Color::Rgb(0, 95, 175),
+                log_text: Color::Reset,
+                ok: Color::Rgb(0, 135, 0),
+                popup_bg: Color::Rgb(228, 228, 228),
+                popup_fg: Color::Black,
+                selected: Color::Rgb(208, 208, 208),
+                text: Color::Black,
+                warning: Color::Rgb(175, 95, 0),
+            },
+            ThemePreset::Solarized => Self {
+                accent: Color::Rgb(108, 113, 196),
+                alert: Color::Rgb(203, 75, 22),
+                border: Color::Rgb(38, 139, 210),
+                chart_cpu: Color::Rgb(211, 54, 130),
+                chart_memory: Color::Rgb(42, 161, 152),
+                chart_read: Color::Rgb(133, 153, 0),
+                chart_rx: Color::Rgb(181, 137, 0),
+                chart_tx: Color::Rgb(203, 75, 22),
+                chart_write: Color::Rgb(211, 54, 130),
+                error: Color::Rgb(220, 50, 47),
+                header_fg: Color::Rgb(0, 43, 54),
+                header_highlight: Color::Rgb(253, 246, 227),
+                highlight: Color::Rgb(38, 139, 210),
+                log_text: Color::Rgb(131, 148, 150),
+                ok: Color::Rgb(133, 153, 0),
+                popup_bg: Color::Rgb(238, 232, 213),
+                popup_fg: Color::Rgb(7, 54, 66),
+                selected: Color::Rgb(7, 54, 66),
+                text: Color::Rgb(147, 161, 161),
+                warning: Color::Rgb(181, 137, 0),
+            },
+            ThemePreset::Dracula => Self {
+                accent: Color::Rgb(189, 147, 249),
+                alert: Color::Rgb(255, 184, 108),
+                border: Color::Rgb(139, 233, 253),
+                chart_cpu: Color::Rgb(255, 121, 198),
+                chart_memory: Color::Rgb(139, 233, 253),
+                chart_read: Color::Rgb(80, 250, 123),
+                chart_rx: Color::Rgb(241, 250, 140),
+                chart_tx: Color::Rgb(255, 184, 108),
+                chart_write: Color::Rgb(255, 121, 198),
+                error: Color::Rgb(255, 85, 85),
+                header_fg: Color::Rgb(40, 42, 54),
+                header_highlight: Color::Rgb(248, 248, 242),
+                highlight: Color::Rgb(98, 114, 164),
+                log_text: Color::Rgb(248, 248, 242),
+                ok: Color::Rgb(80, 250, 123),
+                popup_bg: Color::Rgb(68, 71, 90),
+                popup_fg: Color::Rgb(248, 248, 242),
+                selected: Color::Rgb(68, 71, 90),
+                text: Color::Rgb(248, 248, 242),
+                warning: Color::Rgb(241, 250, 140),
+            },
+        }
+    }
+
+    /// Build the colors from the terminal's own palette, the popups are drawn in its text color, with its background color as their text, rather than always black on white, & the named colors are kept readable on its background
+    pub fn with_palette(self, palette: &Palette) -> Self {
+        let (fg, bg) = (palette.fg, palette.bg);
+        Self {
+            accent: palette.readable(self.accent),
+            alert: palette.readable(self.alert),
+            border: palette.readable(self.border),
+            chart_cpu: palette.readable(self.chart_cpu),
+            chart_memory: palette.readable(self.chart_memory),
+            chart_read: palette.readable(self.chart_read),
+            chart_write: palette.readable(self.chart_write),
+            error: palette.readable(self.error),
+            highlight: palette.readable(self.highlight),
+            ok: palette.readable(self.ok),
+            text: palette.readable(self.text),
+            warning: palette.readable(self.warning),
+            popup_bg: Color::Rgb(fg.0, fg.1, fg.2),
+            popup_fg: Color::Rgb(bg.0, bg.1, bg.2),
+            ..self
+        }
+    }
+
+    /// Style of the popups, their text on their background
+    pub fn popup(&self) -> Style {
+        Style::default().bg(self.popup_bg).fg(self.popup_fg)
+    }
+
+    /// Set a color from an `element=color` string, color can be a name, `#rrggbb`, or an ansi index, `-` & `_` are the same in the element
+    pub fn set(&mut self, input: &str) -> Result<(), String> {
+        let (element, color) = input
+            .split_once('=')
+            .ok_or_else(|| format!("\"{input}\" should be in the format element=color"))?;
+        let color = Color::from_str(color.trim())
+            .map_err(|_| format!("\"{}\" is not a valid color", color.trim()))?;
+        let target = match element.trim().to_lowercase().replace('-', "_").as_str() {
+            "accent" => &mut self.accent,
+            "alert" => &mut self.alert,
+            "border" => &mut self.border,
+            "chart_cpu" => &mut self.chart_cpu,
+            "chart_memory" => &mut self.chart_memory,
+            "chart_read" => &mut self.chart_read,
+            "chart_rx" => &mut self.chart_rx,
+            "chart_tx" => &mut self.chart_tx,
+            "chart_write" => &mut self.chart_write,
+            "error" => &mut self.error,
+            "header_fg" => &mut self.header_fg,
+            "header_highlight" => &mut self.header_highlight,
+            "highlight" => &mut self.highlight,
+            "log_text" => &mut self.log_text,
+            "ok" => &mut self.ok,
+            "popup_bg" => &mut self.popup_bg,
+            "popup_fg" => &mut self.popup_fg,
+            "selected" => &mut self.selected,
+            "text" => &mut self.text,
+            "warning" => &mut self.warning,
+            x => {
+                return Err(format!(
+                    "\"{x}\" is not a valid element, expected one of {}",
+                    Self::ELEMENTS.join(", ")
+                ))
+            }
+        };
+        *target = color;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::style::Color;
+
+    use super::{StateColors, Theme, ThemePreset};
+    use crate::{app_data::State, ui::Palette};
+
+    #[test]
+    /// Default colors match the original state colors, unhealthy only applies to running containers
+    fn test_theme_state_colors_default() {
+        let colors = StateColors::default();
+        assert_eq!(colors.get(State::Running, false), Color::Green);
+        assert_eq!(colors.get(State::Running, true), Color::LightRed);
+        assert_eq!(colors.get(State::Paused, true), Color::Yellow);
+        assert_eq!(colors.get(State::Exited, false), Color::Red);
+    }
+
+    #[test]
+    /// Colors set by name, hex, or index, invalid input returns an error
+    fn test_theme_state_colors_set() {
+        let mut colors = StateColors::default();
+        assert!(colors.set("running=blue").is_ok());
+        assert!(colors.set("Paused = #ff8800").is_ok());
+        assert!(colors.set("dead=201").is_ok());
+        assert!(colors.set("unhealthy=light-magenta").is_ok());
+        assert_eq!(colors.get(State::Running, false), Color::Blue);
+        assert_eq!(colors.get(State::Paused, false), Color::Rgb(255, 136, 0));
+        assert_eq!(colors.get(State::Dead, false), Color::Indexed(201));
+        assert_eq!(colors.get(State::Running, true), Color::LightMagenta);
+
+        assert!(colors.set("running").is_err());
+        assert!(colors.set("running=notacolor").is_err());
+        assert!(colors.set("sleeping=red").is_err());
+    }
+
+    #[test]
+    /// Color-blind presets don't show running & exited in red & green, and add text cues, which `--state-color` keeps
+    fn test_theme_state_colors_preset() {
+        assert_eq!(
+            StateColors::preset(ThemePreset::Default),
+            StateColors::new()
+        );
+        let colors = StateColors::default();
+        assert_eq!(colors.label(State::Running, true), "✓ running");
+        assert!(colors.cue(State::Exited).is_empty());
+
+        for preset in [ThemePreset::Deuteranopia, ThemePreset::Protanopia] {
+            let colors = StateColors::preset(preset);
+            assert!(colors.cues);
+            for state in [State::Running, State::Exited] {
+                assert!(![Color::Red, Color::Green].contains(&colors.get(state, false)));
+            }
+            assert_ne!(
+                colors.get(State::Running, false),
+                colors.get(State::Exited, false)
+            );
+        }
+        let colors = StateColors::preset(ThemePreset::Tritanopia);
+        assert_ne!(colors.get(State::Paused, false), Color::Yellow);
+
+        let mut colors = StateColors::preset(ThemePreset::Deuteranopia);
+        assert!(colors.set("running=white").is_ok());
+        assert!(colors.cues);
+        assert_eq!(colors.label(State::Running, true), "! unhealthy");
+        assert_eq!(colors.label(State::Running, false), "✓ running");
+        assert_eq!(colors.label(State::Exited, true), "✖ exited");
+        assert_eq!(colors.cue(State::Exited), " - ✖ exited");
+        assert!(colors.cue(State::Running).is_empty());
+    }
+
+    #[test]
+    /// The default theme is the original colors, presets change the ui colors, & elements can be set by name, with either `-` or `_`
+    fn test_theme_set() {
+        let theme = Theme::default();
+        assert_eq!(theme.popup_bg, Color::White);
+        assert_eq!(theme.border, Color::LightCyan);
+        assert_eq!(Theme::preset(ThemePreset::Protanopia), theme);
+        assert_ne!(Theme::preset(ThemePreset::Light).popup_bg, Color::White);
+        assert_ne!(
+            StateColors::preset(ThemePreset::Light).get(State::Paused, false),
+            Color::Yellow
+        );
+
+        let mut theme = Theme::preset(ThemePreset::Dracula);
+        assert!(theme.set("chart-cpu=green").is_ok());
+        assert!(theme.set("POPUP_BG = #102030").is_ok());
+        assert_eq!(theme.chart_cpu, Color::Green);
+        assert_eq!(theme.popup().bg, Some(Color::Rgb(16, 32, 48)));
+        assert_eq!(theme.popup().fg, Some(Color::Rgb(248, 248, 242)));
+        assert!(theme.set("header-fg=blue").is_ok());
+        assert!(theme.set("warning=#ff8000").is_ok());
+        assert!(theme.set("chart_rx=red").is_ok());
+        assert_eq!(theme.header_fg, Color::Blue);
+        assert_eq!(theme.warning, Color::Rgb(255, 128, 0));
+        assert_eq!(theme.chart_rx, Color::Red);
+
+        assert!(theme.set("border").is_err());
+        assert!(theme.set("border=notacolor").is_err());
+        assert!(theme.set("title=red").is_err());
+    }
+
+    #[test]
+    /// With the terminal's palette, the popups use its colors, & colors that are hard to read on its background are swapped for their other variant
+    fn test_theme_with_palette() {
+        let mut ansi = [Some((128, 128, 128)); 16];
+        // A light terminal, with a light cyan that is nearly its background
+        ansi[14] = Some((240, 250, 250));
+        ansi[6] = Some((0, 110, 110));
+        // Light red is hard to read, but red isn't any better
+        ansi[9] = Some((250, 230, 230));
+        ansi[1] = Some((250, 240, 240));
+        let palette = Palette {
+            fg: (40, 40, 40),
+            bg: (250, 250, 245),
+            ansi,
+        };
+        let theme = Theme::default().with_palette(&palette);
+        assert_eq!(theme.border, Color::Cyan);
+        assert_eq!(theme.popup_bg, Color::Rgb(40, 40, 40));
+        assert_eq!(theme.popup_fg, Color::Rgb(250, 250, 245));
+        assert_eq!(theme.log_text, Color::Reset);
+        assert_eq!(theme.accent, Color::Magenta);
+
+        let colors = StateColors::default().with_palette(&palette);
+        assert_eq!(colors.get(State::Running, true), Color::LightRed);
+        assert_eq!(colors.get(State::Running, false), Color::Green);
+    }
+}
@@ -0,0 +1,467 @@
+use std::{
+    fmt::Write as _,
+    path::{Path, PathBuf},
+};
+
+use ratatui::style::Color;
+use serde_json::json;
+
+use crate::docker_data::format_rfc3339;
+
+/// How far back, in seconds, the lifecycle timeline reaches
+pub const TIMELINE_SPAN: i64 = 60 * 60 * 24;
+
+/// Spans of time, in seconds, that the timeline can be narrowed to, TIMELINE_SPAN is the widest
+const TIMELINE_SPANS: [i64; 3] = [60 * 60, 60 * 60 * 6, TIMELINE_SPAN];
+
+/// Maximum number of recent events listed under the timeline
+pub const TIMELINE_RECENT: usize = 8;
+
+/// A single lifecycle event of a container, as parsed from the Docker events api
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimelineKind {
+    Die(Option<i64>),
+    Healthy,
+    Kill,
+    Oom,
+    Pause,
+    Restart,
+    Start,
+    Stop,
+    Unhealthy,
+    Unpause,
+}
+
+impl TimelineKind {
+    /// Parse a Docker event action, e.g. `start`, or `health_status: healthy`, None for actions that aren't part of the lifecycle
+    pub fn from_action(action: &str, exit_code: Option<i64>) -> Option<Self> {
+        Some(match action.trim() {
+            "die" => Self::Die(exit_code),
+            "health_status: healthy" => Self::Healthy,
+            "health_status: unhealthy" => Self::Unhealthy,
+            "kill" => Self::Kill,
+            "oom" => Self::Oom,
+            "pause" => Self::Pause,
+            "restart" => Self::Restart,
+            "start" => Self::Start,
+            "stop" => Self::Stop,
+            "unpause" => Self::Unpause,
+            _ => return None,
+        })
+    }
+
+    /// The Docker event action, as used in exports, the inverse of `from_action`
+    pub const fn action(self) -> &'static str {
+        match self {
+            Self::Die(_) => "die",
+            Self::Healthy => "health_status: healthy",
+            Self::Kill => "kill",
+            Self::Oom => "oom",
+            Self::Pause => "pause",
+            Self::Restart => "restart",
+            Self::Start => "start",
+            Self::Stop => "stop",
+            Self::Unhealthy => "health_status: unhealthy",
+            Self::Unpause => "unpause",
+        }
+    }
+
+    pub const fn exit_code(self) -> Option<i64> {
+        match self {
+            Self::Die(code) => code,
+            _ => None,
+        }
+    }
+
+    /// Marker drawn on the event row of the timeline
+    pub const fn symbol(self) -> char {
+        match self {
+            Self::Die(_) => '✖',
+            Self::Healthy => '♥',
+            Self::Kill | Self::Stop => '■',
+            Self::Oom => '!',
+            Self::Pause | Self::Unpause => '‖',
+            Self::Restart => '↻',
+            Self::Start => '▲',
+            Self::Unhealthy => '?',
+        }
+    }
+
+    pub const fn color(self) -> Color {
+        match self {
+            Self::Die(Some(0)) | Self::Kill | Self::Stop | Self::Pause | Self::Unpause => {
+                Color::Blue
+            }
+            Self::Die(_) | Self::Oom | Self::Unhealthy => Color::Red,
+            Self::Healthy | Self::Start => Color::Green,
+            Self::Restart => Color::Magenta,
+        }
+    }
+
+    /// When several events share a column of the timeline, the marker of the highest priority is drawn
+    const fn priority(self) -> u8 {
+        match self {
+            Self::Oom => 6,
+            Self::Die(Some(0)) => 3,
+            Self::Die(_) => 5,
+            Self::Restart => 4,
+            Self::Unhealthy => 2,
+            Self::Kill | Self::Stop | Self::Start => 1,
+            Self::Healthy | Self::Pause | Self::Unpause => 0,
+        }
+    }
+
+    /// Would the container be running after this event, None if the event doesn't change it
+    const fn running(self) -> Option<bool> {
+        match self {
+            Self::Die(_) | Self::Stop => Some(false),
+            Self::Restart | Self::Start => Some(true),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for TimelineKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Die(Some(code)) => write!(f, "died, exit code {code}"),
+            Self::Die(None) => write!(f, "died"),
+            Self::Healthy => write!(f, "healthy"),
+            Self::Kill => write!(f, "killed"),
+            Self::Oom => write!(f, "out of memory"),
+            Self::Pause => write!(f, "paused"),
+            Self::Restart => write!(f, "restarted"),
+            Self::Start => write!(f, "started"),
+            Self::Stop => write!(f, "stopped"),
+            Self::Unhealthy => write!(f, "unhealthy"),
+            Self::Unpause => write!(f, "resumed"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimelineEvent {
+    /// Unix timestamp, in seconds, according to the daemon
+    pub time: i64,
+    pub kind: TimelineKind,
+}
+
+/// The lifecycle events of a single container, fetched over the last TIMELINE_SPAN seconds, drawn on a horizontal axis
+/// The span drawn, and exported, can be narrowed to one of TIMELINE_SPANS
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Timeline {
+    pub end: i64,
+    pub events: Vec<TimelineEvent>,
+    pub name: String,
+    pub running: bool,
+    pub span: i64,
+}
+
+impl Timeline {
+    /// Events are sorted by time, `running` is the current state, used to work out the state before the first event
+    pub fn new(name: String, end: i64, running: bool, mut events: Vec<TimelineEvent>) -> Self {
+        events.sort_by_key(|i| i.time);
+        Self {
+            end,
+            events,
+            name,
+            running,
+            span: TIMELINE_SPAN,
+        }
+    }
+
+    pub const fn start(&self) -> i64 {
+        self.end - self.span
+    }
+
+    /// Show a shorter span of time
+    pub fn narrower(&mut self) {
+        if let Some(i) = TIMELINE_SPANS.iter().rev().find(|i| **i < self.span) {
+            self.span = *i;
+        }
+    }
+
+    /// Show a longer span of time, up to the TIMELINE_SPAN that was fetched
+    pub fn wider(&mut self) {
+        if let Some(i) = TIMELINE_SPANS.iter().find(|i| **i > self.span) {
+            self.span = *i;
+        }
+    }
+
+    /// The events within the current span
+    pub fn visible(&self) -> &[TimelineEvent] {
+        let start = self.start();
+        &self.events[self.events.partition_point(|i| i.time < start)..]
+    }
+
+    /// The column, of a timeline `width` columns wide, that a timestamp falls in
+    fn column(&self, time: i64, width: usize) -> usize {
+        let width = i64::try_from(width.max(1)).unwrap_or(1);
+        let offset = (time - self.start()).clamp(0, self.span - 1);
+        usize::try_from(offset * width / self.span).unwrap_or_default()
+    }
+
+    /// The event marker drawn in each column, the highest priority event wins when several share a column
+    pub fn markers(&self, width: usize) -> Vec<Option<TimelineKind>> {
+        let mut output = vec![None::<TimelineKind>; width];
+        for event in self.visible() {
+            if let Some(cell) = output.get_mut(self.column(event.time, width)) {
+                if !matches!(cell, Some(i) if i.priority() > event.kind.priority()) {
+                    *cell = Some(event.kind);
+                }
+            }
+        }
+        output
+    }
+
+    /// Whether the container was running in each column, a restart boundary shows up as a gap
+    /// The state at the start of the span is that of the last state changing event before it, else the opposite of the first within it
+    pub fn states(&self, width: usize) -> Vec<bool> {
+        let visible = self.visible();
+        let before = &self.events[..self.events.len() - visible.len()];
+        let mut state = before
+            .iter()
+            .rev()
+            .find_map(|i| i.kind.running())
+            .or_else(|| visible.iter().find_map(|i| i.kind.running()).map(|i| !i))
+            .unwrap_or(self.running);
+        let mut output = vec![false; width];
+        let mut events = visible.iter().peekable();
+        for (index, cell) in output.iter_mut().enumerate() {
+            let mut stopped = false;
+            while let Some(event) = events.next_if(|i| self.column(i.time, width) <= index) {
+                if let Some(running) = event.kind.running() {
+                    stopped |= !running;
+                    state = running;
+                }
+            }
+            // A column that contains a stop is drawn as stopped, so that a quick restart is still visible
+            *cell = state && !stopped;
+        }
+        output
+    }
+
+    /// Number of times the container was started again after dying, or being stopped, whether by a restart policy, or by hand
+    pub fn restarts(&self) -> usize {
+        let mut stopped = false;
+        let mut restarts = 0;
+        for event in self.visible() {
+            match event.kind.running() {
+                Some(false) => stopped = true,
+                Some(true) if stopped => {
+                    restarts += 1;
+                    stopped = false;
+                }
+                _ => (),
+            }
+        }
+        restarts
+    }
+
+    /// Short summary of the timeline, e.g. `3 restarts, 1 oom, 2 unhealthy`
+    pub fn summary(&self) -> String {
+        let count = |kind: fn(&TimelineKind) -> bool| {
+            self.visible().iter().filter(|i| kind(&i.kind)).count()
+        };
+        let restarts = self.restarts();
+        let mut output = vec![format!(
+            "{restarts} restart{}",
+            if restarts == 1 { "" } else { "s" }
+        )];
+        let oom = count(|i| i == &TimelineKind::Oom);
+        if oom > 0 {
+            output.push(format!("{oom} oom"));
+        }
+        let failed = count(|i| matches!(i, TimelineKind::Die(Some(x)) if *x != 0));
+        if failed > 0 {
+            output.push(format!("{failed} failed"));
+        }
+        let unhealthy = count(|i| i == &TimelineKind::Unhealthy);
+        if unhealthy > 0 {
+            output.push(format!("{unhealthy} unhealthy"));
+        }
+        output.join(", ")
+    }
+
+    /// The events within the current span as csv, one event per row, with a header row
+    fn to_csv(&self) -> String {
+        let mut output = "time,timestamp,container,event,exit_code\n".to_owned();
+        for i in self.visible() {
+            writeln!(
+                output,
+                "{},{},{},{},{}",
+                format_rfc3339(i.time),
+                i.time,
+                csv_quote(&self.name),
+                csv_quote(i.kind.action()),
+                i.kind
+                    .exit_code()
+                    .map_or_else(String::new, |i| i.to_string())
+            )
+            .ok();
+        }
+        output
+    }
+
+    /// The events within the current span as json, with the container name, & the span, so that the file stands alone
+    fn to_json(&self) -> String {
+        let events = self
+            .visible()
+            .iter()
+            .map(|i| {
+                json!({
+                    "time": format_rfc3339(i.time),
+                    "timestamp": i.time,
+                    "event": i.kind.action(),
+                    "exit_code": i.kind.exit_code(),
+                })
+            })
+            .collect::<Vec<_>>();
+        let json = json!({
+            "container": self.name,
+            "since": format_rfc3339(self.start()),
+            "until": format_rfc3339(self.end),
+            "events": events,
+        });
+        format!("{json}\n")
+    }
+
+    /// Save the events within the current span, as both csv & json, named after the container and the end of the span
+    pub fn save(&self, dir: &Path) -> std::io::Result<PathBuf> {
+        let name = format!("{}_events_{}", self.name, self.end);
+        let csv_path = dir.join(format!("{name}.csv"));
+        std::fs::write(&csv_path, self.to_csv())?;
+        std::fs::write(dir.join(format!("{name}.json")), self.to_json())?;
+        Ok(csv_path)
+    }
+}
+
+/// Quote a csv field, only if it contains a comma, quote, or newline
+pub fn csv_quote(input: &str) -> String {
+    if input.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", input.replace('"', "\"\""))
+    } else {
+        input.to_owned()
+    }
+}
+
+/// Format a span of seconds for the axis labels, e.g. `24h`, or `30m`
+pub fn format_span(secs: i64) -> String {
+    if secs >= 3600 {
+        format!("{}h", secs / 3600)
+    } else {
+        format!("{}m", secs / 60)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{csv_quote, format_span, Timeline, TimelineEvent, TimelineKind, TIMELINE_SPAN};
+
+    fn gen_timeline() -> Timeline {
+        let end = 1_000_000;
+        let event = |hours_ago: i64, kind| TimelineEvent {
+            time: end - hours_ago * 3600,
+            kind,
+        };
+        Timeline::new(
+            "container_1".to_owned(),
+            end,
+            true,
+            vec![
+                event(12, TimelineKind::Start),
+                event(18, TimelineKind::Oom),
+                event(18, TimelineKind::Die(Some(137))),
+                event(6, TimelineKind::Restart),
+                event(20, TimelineKind::Start),
+                event(12, TimelineKind::Unhealthy),
+            ],
+        )
+    }
+
+    #[test]
+    /// Events are placed on the axis by time, the highest priority event wins a shared column
+    fn test_timeline_markers() {
+        let timeline = gen_timeline();
+        assert_eq!(timeline.start(), 1_000_000 - TIMELINE_SPAN);
+        let markers = timeline.markers(12);
+        assert_eq!(
+            markers,
+            vec![
+                None,
+                None,
+                Some(TimelineKind::Start),
+                Some(TimelineKind::Oom),
+                None,
+                None,
+                Some(TimelineKind::Unhealthy),
+                None,
+                None,
+                Some(TimelineKind::Restart),
+                None,
+                None,
+            ]
+        );
+    }
+
+    #[test]
+    /// The running state is inferred before the first event, and a stop is a gap on the axis
+    fn test_timeline_states_summary() {
+        let timeline = gen_timeline();
+        assert_eq!(
+            timeline.states(12),
+            vec![false, false, true, false, false, false, true, true, true, true, true, true]
+        );
+        assert_eq!(timeline.restarts(), 1);
+        assert_eq!(
+            timeline.summary(),
+            "1 restart, 1 oom, 1 failed, 1 unhealthy"
+        );
+        assert_eq!(
+            TimelineKind::from_action("health_status: healthy", None),
+            Some(TimelineKind::Healthy)
+        );
+        assert_eq!(TimelineKind::from_action("exec_start: sh", None), None);
+    }
+
+    #[test]
+    /// Narrowing the span filters the events, the state at the start of the span comes from the events before it
+    fn test_timeline_span() {
+        let mut timeline = gen_timeline();
+        timeline.wider();
+        assert_eq!(timeline.span, TIMELINE_SPAN);
+        timeline.narrower();
+        assert_eq!(format_span(timeline.span), "6h");
+        assert_eq!(timeline.visible().len(), 1);
+        assert_eq!(timeline.states(3), vec![true, true, true]);
+        assert_eq!(timeline.restarts(), 0);
+        timeline.narrower();
+        timeline.narrower();
+        assert_eq!(format_span(timeline.span), "1h");
+        assert!(timeline.visible().is_empty());
+        assert_eq!(format_span(1800), "30m");
+    }
+
+    #[test]
+    /// Only the events within the span are exported, as csv rows, & as a json document with the span
+    fn test_timeline_export() {
+        let mut timeline = gen_timeline();
+        assert!(timeline
+            .to_csv()
+            .contains("\n1970-01-11T19:46:40Z,935200,container_1,die,137\n"));
+        timeline.narrower();
+        assert_eq!(
+            timeline.to_csv(),
+            "time,timestamp,container,event,exit_code\n1970-01-12T07:46:40Z,978400,container_1,restart,\n"
+        );
+        assert_eq!(
+            timeline.to_json(),
+            "{\"container\":\"container_1\",\"events\":[{\"event\":\"restart\",\"exit_code\":null,\"time\":\"1970-01-12T07:46:40Z\",\"timestamp\":978400}],\"since\":\"1970-01-12T07:46:40Z\",\"until\":\"1970-01-12T13:46:40Z\"}\n"
+        );
+        assert_eq!(
+            csv_quote("health_status: healthy"),
+            "health_status: healthy"
+        );
+        assert_eq!(csv_quote("a,\"b\""), "\"a,\"\"b\"\"\"");
+    }
+}
@@ -0,0 +1,151 @@
+use std::{
+    path::{Path, PathBuf},
+    process::Stdio,
+    sync::Arc,
+    time::Duration,
+};
+
+use parking_lot::Mutex;
+use tokio::process::Command;
+
+use crate::ui::GuiState;
+
+/// The latest release, pre-releases, & drafts, aren't included
+const RELEASES_URL: &str = "https://api.github.com/repos/mrjackwills/oxker/releases/latest";
+
+/// How long curl is given to fetch the latest release, the check is silently skipped on a slow, or no, connection
+const CHECK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Packaged installs are updated by their package manager, so a package can be built with `OXKER_NO_UPDATE_CHECK` set, which removes the check entirely
+pub const DISABLED: bool = option_env!("OXKER_NO_UPDATE_CHECK").is_some();
+
+/// Location of the file holding the last version that was notified, in the state directory, or the local data directory on platforms without one
+pub fn path() -> Option<PathBuf> {
+    directories::BaseDirs::new().map(|i| {
+        i.state_dir()
+            .unwrap_or_else(|| i.data_local_dir())
+            .join(env!("CARGO_PKG_NAME"))
+            .join("update_notified")
+    })
+}
+
+/// Parse a version, with, or without, a leading `v`, e.g. `v0.6.2`, a pre-release suffix is ignored
+fn parse_version(input: &str) -> Option<(u64, u64, u64)> {
+    let input = input.trim().trim_start_matches('v');
+    let input = input.split(['-', '+']).next()?;
+    let mut parts = input.splitn(3, '.').map(str::parse::<u64>);
+    Some((
+        parts.next()?.ok()?,
+        parts.next()?.ok()?,
+        parts.next()?.ok()?,
+    ))
+}
+
+/// The tag of the latest release, from the GitHub releases api response
+fn latest_tag(response: &str) -> Option<String> {
+    serde_json::from_str::<serde_json::Value>(response)
+        .ok()?
+        .get("tag_name")?
+        .as_str()
+        .map(ToOwned::to_owned)
+}
+
+/// The latest version, if it's newer than the current version, & hasn't already been notified
+fn to_notify(latest: &str, current: &str, notified: Option<&str>) -> Option<String> {
+    let version = parse_version(latest)?;
+    if version <= parse_version(current)? || notified.and_then(parse_version) == Some(version) {
+        return None;
+    }
+    Some(latest.trim().to_owned())
+}
+
+/// Fetch the latest release with curl, so that no http, or TLS, client needs to be built into oxker, None if curl isn't installed, or the request fails
+async fn fetch() -> Option<String> {
+    let output = Command::new("curl")
+        .args([
+            "-fsSL",
+            "-H",
+            "Accept: application/vnd.github+json",
+            RELEASES_URL,
+        ])
+        .stdin(Stdio::null())
+        .stderr(Stdio::null())
+        .kill_on_drop(true)
+        .output();
+    let output = tokio::time::timeout(CHECK_TIMEOUT, output)
+        .await
+        .ok()?
+        .ok()?;
+    if output.status.success() {
+        String::from_utf8(output.stdout).ok()
+    } else {
+        None
+    }
+}
+
+/// With `--update-check`, check for a newer release, a newer version is shown once, in the info box, & then recorded, so it isn't shown again
+pub async fn check(gui_state: Arc<Mutex<GuiState>>, path: Option<PathBuf>) {
+    let Some(latest) = fetch().await.and_then(|i| latest_tag(&i)) else {
+        return;
+    };
+    let notified = path
+        .as_deref()
+        .and_then(|i| std::fs::read_to_string(i).ok());
+    let current = env!("CARGO_PKG_VERSION");
+    if let Some(version) = to_notify(&latest, current, notified.as_deref()) {
+        gui_state.lock().set_info_box(&format!(
+            "oxker {version} is available, v{current} is running"
+        ));
+        if let Some(path) = path.as_deref() {
+            save(path, &version).ok();
+        }
+    }
+}
+
+fn save(path: &Path, version: &str) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(path, version)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{latest_tag, parse_version, to_notify};
+
+    #[test]
+    fn test_update_check_parse_version() {
+        assert_eq!(parse_version("v0.6.2"), Some((0, 6, 2)));
+        assert_eq!(parse_version("1.10.0\n"), Some((1, 10, 0)));
+        assert_eq!(parse_version("v2.0.0-rc.1"), Some((2, 0, 0)));
+        assert_eq!(parse_version("v1.2"), None);
+        assert_eq!(parse_version("latest"), None);
+    }
+
+    #[test]
+    fn test_update_check_latest_tag() {
+        assert_eq!(
+            latest_tag(r#"{"tag_name":"v0.7.0","name":"v0.7.0"}"#).as_deref(),
+            Some("v0.7.0")
+        );
+        assert_eq!(latest_tag(r#"{"message":"rate limited"}"#), None);
+        assert_eq!(latest_tag("<html>"), None);
+    }
+
+    #[test]
+    /// Only a newer version is notified, & only once, a version newer than the one already notified is notified again
+    fn test_update_check_to_notify() {
+        assert_eq!(
+            to_notify("v0.7.0", "0.6.2", None).as_deref(),
+            Some("v0.7.0")
+        );
+        assert_eq!(to_notify("v0.6.2", "0.6.2", None), None);
+        assert_eq!(to_notify("v0.6.1", "0.6.2", None), None);
+        assert_eq!(to_notify("v0.7.0", "0.6.2", Some("v0.7.0")), None);
+        assert_eq!(
+            to_notify("v0.7.1", "0.6.2", Some("v0.7.0")).as_deref(),
+            Some("v0.7.1")
+        );
+        assert_eq!(to_notify("nightly", "0.6.2", None), None);
+    }
+}